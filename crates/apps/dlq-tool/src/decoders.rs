@@ -0,0 +1,176 @@
+//! The decode half of the dry run: every `event-topology` consumer edge mapped
+//! to the payload type that consumer hands to `run_consumer` — `Proto<T>` for
+//! the protobuf streams, so the dry run honours each record's `content-type`.
+//!
+//! Keep this in step with the registry — the tests below fail when a consumer
+//! edge is added without a decoder here, and when a consumer cannot decode the
+//! payload its producer registered in [`event_topology::SCHEMAS`] (the
+//! consumer half of the payload-shape check; the producer half runs in each
+//! producer's own tests).
+
+use transport::kafka::dlq::PayloadDecoders;
+use transport::kafka::Proto;
+
+/// One decoder per `(topic, consumer)` edge in [`event_topology::CONSUMERS`].
+pub fn fleet() -> PayloadDecoders {
+    PayloadDecoders::new()
+        // account
+        .register::<audit::infrastructure::account_decode::AccountEventWire>("account.v1.events", "audit")
+        .register::<profile::infrastructure::consumer::account_event_consumer::AccountEvent>(
+            "account.v1.events",
+            "profile",
+        )
+        .register::<account::infrastructure::consumer::data_export_consumer::AccountEventWire>(
+            "account.v1.events",
+            "account",
+        )
+        .register::<subject_erasure::AccountEventWire>("account.v1.events", "post")
+        .register::<subject_erasure::AccountEventWire>("account.v1.events", "comment")
+        .register::<subject_erasure::AccountEventWire>("account.v1.events", "chat")
+        .register::<subject_erasure::AccountEventWire>("account.v1.events", "social-graph")
+        .register::<subject_erasure::AccountEventWire>("account.v1.events", "engagement")
+        .register::<subject_erasure::AccountEventWire>("account.v1.events", "notification")
+        .register::<subject_erasure::AccountEventWire>("account.v1.events", "search")
+        .register::<subject_erasure::AccountEventWire>("account.v1.events", "media")
+        // profile
+        .register::<search::infrastructure::decode::ProfileWireEvent>("profile.v1.events", "search")
+        .register::<post::infrastructure::consumer::author_tier_consumer::ProfileV1Event>(
+            "profile.v1.events",
+            "post",
+        )
+        // post
+        .register::<timeline::infrastructure::worker::post_published_worker::PostV1Event>(
+            "post.v1.events",
+            "timeline",
+        )
+        .register::<search::infrastructure::decode::PostWireEvent>("post.v1.events", "search")
+        .register::<realtime::infrastructure::decode::PostWire>("post.v1.events", "realtime")
+        .register::<notification::infrastructure::worker::mention_worker::PostEvent>(
+            "post.v1.events",
+            "notification",
+        )
+        .register::<geo_discovery::infrastructure::worker::post_indexer::PostEvent>(
+            "post.v1.events",
+            "geo-discovery",
+        )
+        // notification
+        .register::<realtime::infrastructure::decode::NotificationWire>("notification.v1.events", "realtime")
+        // comment
+        .register::<notification::infrastructure::worker::comment_worker::CommentEventPayload>(
+            "comment.created",
+            "notification",
+        )
+        .register::<engagement::infrastructure::worker::comment_consumer::CommentEngagementPayload>(
+            "comment.created",
+            "engagement",
+        )
+        .register::<engagement::infrastructure::worker::comment_consumer::CommentEngagementPayload>(
+            "comment.deleted",
+            "engagement",
+        )
+        // engagement
+        .register::<Proto<counter::infrastructure::decode::ReactionWire>>("engagement.reactions", "counter")
+        .register::<Proto<notification::infrastructure::worker::reaction_worker::ReactionKafkaEvent>>(
+            "engagement.reactions",
+            "notification",
+        )
+        .register::<Proto<engagement::domain::event::reaction_event::ReactionKafkaEvent>>(
+            "engagement.reactions",
+            "engagement",
+        )
+        // social-graph
+        .register::<timeline::infrastructure::worker::follow_created_worker::ProfileFollowedEvent>(
+            "social-graph.followed",
+            "timeline",
+        )
+        .register::<timeline::infrastructure::worker::follow_deleted_worker::ProfileUnfollowedEvent>(
+            "social-graph.unfollowed",
+            "timeline",
+        )
+        .register::<profile::infrastructure::consumer::author_tier_consumer::AuthorTierChangedEvent>(
+            "social-graph.author_tier_changed",
+            "profile",
+        )
+        // chat
+        .register::<chat::domain::event::conversation_event::ConversationUnpublishedEvent>(
+            "chat.conversation.unpublished",
+            "chat",
+        )
+        // counter
+        .register::<Proto<realtime::infrastructure::decode::PopularityWire>>("counter.v1.popularity", "realtime")
+        .register::<Proto<geo_discovery::infrastructure::worker::score_updater::PopularityEvent>>(
+            "counter.v1.popularity",
+            "geo-discovery",
+        )
+        // auth
+        .register::<audit::infrastructure::auth_decode::AuthEventWire>("auth.v1.events", "audit")
+        // moderation
+        .register::<audit::infrastructure::moderation_decode::ModerationEventWire>(
+            "moderation.v1.events",
+            "audit",
+        )
+        .register::<search::infrastructure::decode::ModerationWireEvent>("moderation.v1.events", "search")
+        .register::<media::infrastructure::consumer::moderation_consumer::ModerationWireEvent>(
+            "moderation.v1.events",
+            "media",
+        )
+        // media
+        .register::<media::domain::event::DomainEvent>("media.v1.events", "media")
+        // audit
+        .register::<audit::infrastructure::decode::AuditEventWire>("audit.v1.events", "audit")
+        // moderation intake
+        .register::<moderation::infrastructure::consumer::report_consumer::ReportEvent>(
+            "moderation.reports",
+            "moderation",
+        )
+        .register::<moderation::infrastructure::consumer::signal_consumer::SignalEvent>(
+            "moderation.signals",
+            "moderation",
+        )
+        // counter telemetry + follow folds
+        .register::<counter::infrastructure::decode::HitWire>("view.v1.events", "counter")
+        .register::<counter::infrastructure::decode::HitWire>("impression.v1.events", "counter")
+        .register::<counter::infrastructure::decode::HitWire>("click.v1.events", "counter")
+        .register::<counter::infrastructure::decode::FollowWire>("social-graph.follows", "counter")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+
+    #[test]
+    fn every_registry_consumer_edge_has_a_decoder() {
+        let decoders = fleet();
+        let missing: Vec<_> = event_topology::CONSUMERS
+            .iter()
+            .filter(|(topic, consumer)| !decoders.covers(topic, consumer))
+            .collect();
+        assert!(missing.is_empty(), "consumer edges without a decoder: {missing:?}");
+    }
+
+    /// Every consumer decodes every compatibility sample of the payload registered
+    /// for its topic: the minimal one (nothing it requires is optional), the full
+    /// one (it tolerates every field and an unknown one) and each enum value.
+    #[test]
+    fn every_consumer_decodes_its_producers_registered_payload() {
+        let decoders = fleet();
+        let mut broken = Vec::new();
+        for schema in event_topology::SCHEMAS {
+            for sample in schema.samples() {
+                for verdict in decoders.decode(schema.topic, Some(sample.json.as_bytes()), &HashMap::new()) {
+                    if let Err(e) = verdict.result {
+                        broken.push(format!("{} → {} ({sample}): {e}", schema.topic, verdict.consumer));
+                    }
+                }
+            }
+        }
+        assert!(
+            broken.is_empty(),
+            "BREAKING PAYLOAD CHANGE: these consumers cannot decode what their producer \
+             registered — fix the consumer's wire type or the producer:\n{}",
+            broken.join("\n")
+        );
+    }
+}
@@ -0,0 +1,318 @@
+//! `dlq-tool` — inspects and replays the consumer runtime's dead-letter topics.
+//!
+//! `run_consumer` parks a poison record on `<topic>.dlq`, verbatim, with `x-dlq-*`
+//! headers saying where it came from and why (`transport::kafka::dlq`). This
+//! binary is the operator side of that contract: it reads a DLQ without joining
+//! a consumer group, dry-runs the parked payloads against the payload types of
+//! every consumer of the origin topic (the same decode path those consumers
+//! use), and republishes a selection back to the origin topic.
+//!
+//! Usage:
+//! ```text
+//! dlq-tool list   <dlq-topic> [filters]        # one line per parked record
+//! dlq-tool stats  <dlq-topic> [filters]        # counts by reason and origin partition
+//! dlq-tool decode <dlq-topic> [filters]        # per-consumer decode verdicts
+//! dlq-tool replay <dlq-topic> [filters] [--rate N] [--max-replays N] [--force] [--execute]
+//! ```
+//!
+//! Filters: `--reason decode|reject|retry-exhausted`, `--partition N` (origin
+//! partition), `--since T` / `--until T` (failure time: epoch millis, or a
+//! relative `45s`, `30m`, `2h`, `1d` back from now), `--at P:O` (one DLQ
+//! position), `--limit N` (default 100).
+//!
+//! `replay` is a dry run unless `--execute` is given. It only republishes
+//! records every origin consumer can now decode — replaying one that still
+//! fails decode just parks it again — unless `--force` is given. Replays are
+//! paced at `--rate` records/s (default 50) and carry `x-replay-*` headers;
+//! records already replayed `--max-replays` times (default 3) are skipped.
+//!
+//! Env: `KAFKA_BROKERS` / `KAFKA_SECURITY_PROTOCOL` / `KAFKA_SASL_*` — the same
+//! client settings the fleet uses (`transport::kafka::KafkaClientConfig`).
+
+mod decoders;
+
+use std::collections::BTreeMap;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use anyhow::{bail, Context, Result};
+use transport::kafka::config::{producer::ProducerConfig, KafkaClientConfig};
+use transport::kafka::dlq::{
+    DlqBrowser, DlqFilter, DlqReason, DlqRecord, DlqReplayer, PayloadDecoders, ReplayConfig,
+};
+use transport::kafka::producer::KafkaProducerBuilder;
+use transport::kafka::DLQ_SUFFIX;
+
+const USAGE: &str = "usage: dlq-tool <list|stats|decode|replay> <dlq-topic> \
+[--reason R] [--partition N] [--since T] [--until T] [--at P:O] [--limit N] \
+[--rate N] [--max-replays N] [--force] [--execute]";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Command {
+    List,
+    Stats,
+    Decode,
+    Replay,
+}
+
+#[derive(Debug)]
+struct Args {
+    command: Command,
+    topic:   String,
+    filter:  DlqFilter,
+    limit:   usize,
+    replay:  ReplayConfig,
+    force:   bool,
+    execute: bool,
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let args = parse_args(std::env::args().skip(1), now_ms())?;
+    if !args.topic.ends_with(DLQ_SUFFIX) {
+        bail!("{} is not a dead-letter topic (expected a *{DLQ_SUFFIX} name)", args.topic);
+    }
+
+    let client = KafkaClientConfig::from_env();
+    let browser = DlqBrowser::new(client.clone()).context("build DLQ browser")?;
+    let records = browser
+        .scan(&args.topic, &args.filter, args.limit)
+        .await
+        .with_context(|| format!("scan {}", args.topic))?;
+    if records.len() == args.limit {
+        println!("(showing the first {} matches; raise --limit for more)", args.limit);
+    }
+
+    match args.command {
+        Command::List => {
+            for record in &records {
+                println!("{}", summary(record));
+            }
+        }
+        Command::Stats => print_stats(&records),
+        Command::Decode => {
+            let decoders = decoders::fleet();
+            for record in &records {
+                println!("{}", summary(record));
+                print_verdicts(&decoders, record);
+            }
+        }
+        Command::Replay => {
+            let decoders = decoders::fleet();
+            let mut selected = Vec::new();
+            for record in &records {
+                let failing: Vec<String> = decoders
+                    .dry_run(record)
+                    .into_iter()
+                    .filter(|v| v.result.is_err())
+                    .map(|v| v.consumer)
+                    .collect();
+                if failing.is_empty() || args.force {
+                    selected.push(record.clone());
+                } else {
+                    println!("  [hold]   {}: still fails decode for {}", record.position(), failing.join(", "));
+                }
+            }
+
+            if !args.execute {
+                for record in &selected {
+                    println!("  [would]  {}", summary(record));
+                }
+                println!("dry run: {} of {} record(s) selected; pass --execute to replay", selected.len(), records.len());
+                return Ok(());
+            }
+
+            let producer = KafkaProducerBuilder::new(ProducerConfig::new(client))
+                .build()
+                .context("build Kafka producer")?;
+            let report = DlqReplayer::new(producer, args.replay).replay(&selected).await;
+            for position in &report.replayed {
+                println!("  [replayed] {position}");
+            }
+            for (position, reason) in &report.skipped {
+                println!("  [skipped]  {position}: {reason}");
+            }
+            println!("done: replayed={} skipped={}", report.replayed.len(), report.skipped.len());
+            if let Some((position, error)) = report.aborted {
+                bail!("replay stopped at {position}: {error}");
+            }
+        }
+    }
+    Ok(())
+}
+
+fn summary(record: &DlqRecord) -> String {
+    match &record.metadata {
+        Some(meta) => format!(
+            "{} <- {}/{}@{} reason={} attempts={} failed_at_ms={} replays={} key={} error={}",
+            record.position(),
+            meta.origin_topic,
+            meta.origin_partition,
+            meta.origin_offset,
+            meta.reason,
+            meta.attempts,
+            meta.failed_at_ms,
+            record.replay_count(),
+            record.key,
+            meta.error,
+        ),
+        None => format!("{} (no x-dlq-* headers) key={}", record.position(), record.key),
+    }
+}
+
+fn print_stats(records: &[DlqRecord]) {
+    let mut by_reason: BTreeMap<String, usize> = BTreeMap::new();
+    let mut by_partition: BTreeMap<Option<i32>, usize> = BTreeMap::new();
+    for record in records {
+        let meta = record.metadata.as_ref();
+        *by_reason.entry(meta.map_or_else(|| "unknown".to_owned(), |m| m.reason.to_string())).or_default() += 1;
+        *by_partition.entry(meta.map(|m| m.origin_partition)).or_default() += 1;
+    }
+    println!("records: {}", records.len());
+    println!("by reason:");
+    for (reason, n) in &by_reason {
+        println!("  {reason:<16} {n}");
+    }
+    println!("by origin partition:");
+    for (partition, n) in &by_partition {
+        let label = partition.map_or_else(|| "unknown".to_owned(), |p| p.to_string());
+        println!("  {label:<16} {n}");
+    }
+}
+
+fn print_verdicts(decoders: &PayloadDecoders, record: &DlqRecord) {
+    let verdicts = decoders.dry_run(record);
+    if verdicts.is_empty() {
+        println!("    (no decoder registered for the origin topic)");
+    }
+    for verdict in verdicts {
+        match verdict.result {
+            Ok(()) => println!("    [ok]   {}", verdict.consumer),
+            Err(e) => println!("    [fail] {}: {e}", verdict.consumer),
+        }
+    }
+}
+
+fn parse_args(mut argv: impl Iterator<Item = String>, now_ms: i64) -> Result<Args> {
+    let command = match argv.next().as_deref() {
+        Some("list") => Command::List,
+        Some("stats") => Command::Stats,
+        Some("decode") => Command::Decode,
+        Some("replay") => Command::Replay,
+        Some(other) => bail!("unknown command '{other}'\n{USAGE}"),
+        None => bail!("{USAGE}"),
+    };
+    let topic = argv.next().with_context(|| format!("missing <dlq-topic>\n{USAGE}"))?;
+
+    let mut args = Args {
+        command,
+        topic,
+        filter: DlqFilter::default(),
+        limit: 100,
+        replay: ReplayConfig::default(),
+        force: false,
+        execute: false,
+    };
+    while let Some(flag) = argv.next() {
+        let mut value = || argv.next().with_context(|| format!("{flag} needs a value"));
+        match flag.as_str() {
+            "--reason" => args.filter.reason = Some(DlqReason::parse(&value()?)),
+            "--partition" => args.filter.origin_partition = Some(number(&flag, &value()?)?),
+            "--since" => args.filter.since_ms = Some(instant(&value()?, now_ms)?),
+            "--until" => args.filter.until_ms = Some(instant(&value()?, now_ms)?),
+            "--at" => args.filter.position = Some(position(&value()?)?),
+            "--limit" => args.limit = number(&flag, &value()?)?,
+            "--rate" => args.replay.rate_per_sec = number(&flag, &value()?)?,
+            "--max-replays" => args.replay.max_replays = number(&flag, &value()?)?,
+            "--force" => args.force = true,
+            "--execute" => args.execute = true,
+            other => bail!("unknown flag '{other}'\n{USAGE}"),
+        }
+    }
+    if (args.force || args.execute) && command != Command::Replay {
+        bail!("--force and --execute only apply to replay");
+    }
+    Ok(args)
+}
+
+fn number<T: std::str::FromStr>(flag: &str, value: &str) -> Result<T> {
+    value.parse().ok().with_context(|| format!("{flag} must be a number, got '{value}'"))
+}
+
+/// Epoch millis, or a duration back from `now_ms` (`45s`, `30m`, `2h`, `1d`).
+fn instant(value: &str, now_ms: i64) -> Result<i64> {
+    if let Ok(ms) = value.parse::<i64>() {
+        return Ok(ms);
+    }
+    let split = value.len().saturating_sub(1);
+    let (amount, unit) = value.split_at(split);
+    let unit_ms = match unit {
+        "s" => 1_000,
+        "m" => 60_000,
+        "h" => 3_600_000,
+        "d" => 86_400_000,
+        _ => bail!("'{value}' is neither epoch millis nor a relative time like 30m"),
+    };
+    let amount: i64 = amount
+        .parse()
+        .ok()
+        .with_context(|| format!("'{value}' is neither epoch millis nor a relative time like 30m"))?;
+    Ok(now_ms - amount * unit_ms)
+}
+
+/// `P:O` → (partition, offset).
+fn position(value: &str) -> Result<(i32, i64)> {
+    let (partition, offset) = value
+        .split_once(':')
+        .with_context(|| format!("--at expects PARTITION:OFFSET, got '{value}'"))?;
+    Ok((number("--at", partition)?, number("--at", offset)?))
+}
+
+fn now_ms() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as i64)
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(argv: &str) -> Result<Args> {
+        parse_args(argv.split_whitespace().map(str::to_owned), 10_000_000)
+    }
+
+    #[test]
+    fn filters_and_replay_settings_are_parsed() {
+        let args = parse(
+            "replay post.v1.events.dlq --reason decode --partition 3 --since 2h --until 9000000 \
+             --limit 10 --rate 5 --max-replays 1 --execute",
+        )
+        .unwrap();
+        assert_eq!(args.command, Command::Replay);
+        assert_eq!(args.topic, "post.v1.events.dlq");
+        assert_eq!(args.filter.reason, Some(DlqReason::Decode));
+        assert_eq!(args.filter.origin_partition, Some(3));
+        assert_eq!(args.filter.since_ms, Some(10_000_000 - 7_200_000));
+        assert_eq!(args.filter.until_ms, Some(9_000_000));
+        assert_eq!(args.limit, 10);
+        assert_eq!((args.replay.rate_per_sec, args.replay.max_replays), (5, 1));
+        assert!(args.execute && !args.force);
+    }
+
+    #[test]
+    fn a_position_selects_one_record() {
+        let args = parse("decode comment.created.dlq --at 2:417").unwrap();
+        assert_eq!(args.filter.position, Some((2, 417)));
+    }
+
+    #[test]
+    fn bad_input_is_rejected() {
+        assert!(parse("").is_err());
+        assert!(parse("purge x.dlq").is_err());
+        assert!(parse("list x.dlq --since yesterday").is_err());
+        assert!(parse("list x.dlq --at 2").is_err());
+        assert!(parse("list x.dlq --limit").is_err());
+        assert!(parse("list x.dlq --execute").is_err());
+    }
+}
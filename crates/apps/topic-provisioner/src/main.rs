@@ -5,7 +5,8 @@
 //! auto-creation off anyway, so an unprovisioned cluster can't be published to at
 //! all). This binary derives the complete topic set from the `event-topology`
 //! registry — every produced/consumed stream topic, plus the consumer runtime's
-//! `<topic>.dlq` counterpart for each consumed topic — and creates them in one
+//! `<topic>.dlq` counterpart and `<topic>.retry.1..=RETRY_TIERS` ladder tiers for
+//! each consumed topic — and creates them in one
 //! idempotent admin call. `TOPIC_ALREADY_EXISTS` is success; anything else fails
 //! the run (non-zero exit) so the deploy that depends on it does not proceed.
 //!
@@ -28,6 +29,7 @@ use rdkafka::admin::{AdminClient, AdminOptions, NewTopic, TopicReplication};
 use rdkafka::client::DefaultClientContext;
 use rdkafka::types::RDKafkaErrorCode;
 use transport::kafka::config::KafkaClientConfig;
+use transport::kafka::consumer::retry_topics;
 use transport::kafka::DLQ_SUFFIX;
 
 #[tokio::main]
@@ -96,8 +98,8 @@ async fn main() -> Result<()> {
     Ok(())
 }
 
-/// The full broker topic set: every registry stream topic + a `.dlq` per
-/// consumed topic. Sorted for stable, diffable logs.
+/// The full broker topic set: every registry stream topic + a `.dlq` and the
+/// retry-ladder tiers per consumed topic. Sorted for stable, diffable logs.
 fn topic_names() -> Vec<String> {
     let mut names: Vec<String> = event_topology::all_stream_topics()
         .into_iter()
@@ -107,6 +109,11 @@ fn topic_names() -> Vec<String> {
                 .into_iter()
                 .map(|topic| format!("{topic}{DLQ_SUFFIX}")),
         )
+        .chain(
+            event_topology::consumed_stream_topics()
+                .into_iter()
+                .flat_map(retry_topics),
+        )
         .collect();
     names.sort_unstable();
     names.dedup();
@@ -118,7 +125,7 @@ mod tests {
     use super::*;
 
     #[test]
-    fn topic_set_covers_registry_and_dlqs_without_duplicates() {
+    fn topic_set_covers_registry_dlqs_and_retry_tiers_without_duplicates() {
         let names = topic_names();
 
         for topic in event_topology::all_stream_topics() {
@@ -127,6 +134,9 @@ mod tests {
         for topic in event_topology::consumed_stream_topics() {
             let dlq = format!("{topic}{DLQ_SUFFIX}");
             assert!(names.contains(&dlq), "missing {dlq}");
+            for tier in retry_topics(topic) {
+                assert!(names.contains(&tier), "missing {tier}");
+            }
         }
 
         let mut deduped = names.clone();
@@ -12,6 +12,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             &[
                 "../proto/counter/v1/enums.proto",
                 "../proto/counter/v1/messages.proto",
+                "../proto/counter/v1/events.proto",
                 "../proto/counter/v1/service.proto",
             ],
             &["../proto/"],
@@ -12,6 +12,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             &[
                 "../proto/engagement/v1/enums.proto",
                 "../proto/engagement/v1/messages.proto",
+                "../proto/engagement/v1/events.proto",
                 "../proto/engagement/v1/service.proto",
             ],
             &["../proto/"],
@@ -0,0 +1,24 @@
+//! Print the JSON Schema of produced topics' payloads, rendered from the event-topology
+//! `SCHEMAS` registry. With a topic argument, that topic's document; without, a JSON object of
+//! every produced topic's document keyed by topic.
+//!
+//!   cargo run -p event-topology --bin gen-event-schemas [topic]
+
+fn main() {
+    match std::env::args().nth(1) {
+        Some(topic) => match event_topology::schema_for(&topic) {
+            Some(schema) => println!("{}", schema.to_json_schema()),
+            None => {
+                eprintln!("no payload schema is registered for {topic:?}");
+                std::process::exit(2);
+            }
+        },
+        None => {
+            let documents: Vec<String> = event_topology::SCHEMAS
+                .iter()
+                .map(|schema| format!("\"{}\":{}", schema.topic, schema.to_json_schema()))
+                .collect();
+            println!("{{{}}}", documents.join(","));
+        }
+    }
+}
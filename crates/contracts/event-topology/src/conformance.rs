@@ -0,0 +1,214 @@
+//! Producer conformance: does a producer's payload type emit exactly the shape
+//! registered for its topic in [`SCHEMAS`](crate::SCHEMAS)?
+//!
+//! Each sample of the schema is decoded into the producer's type and encoded
+//! back; the re-encoded JSON must carry every required field, type every field
+//! as registered, and nothing unregistered. A producer that drops, retypes or
+//! adds a field fails its own test until the schema follows — and a schema
+//! change is what the consumer-compatibility test reacts to.
+//!
+//! Behind the `conformance` feature: producers take it as a dev-dependency, so
+//! the registry itself stays dependency-free.
+
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::{Map, Value};
+
+use crate::schema::{Field, FieldType, PayloadSchema, SampleKind, PROBE_FIELD};
+
+/// Every way `T` disagrees with the schema registered for `topic`; empty when it
+/// conforms. An unregistered topic is itself a violation.
+///
+/// ```rust,ignore
+/// #[test]
+/// fn account_events_conform_to_the_registered_schema() {
+///     let violations = event_topology::conformance::producer_violations::<DomainEvent>(TOPIC);
+///     assert!(violations.is_empty(), "{violations:#?}");
+/// }
+/// ```
+pub fn producer_violations<T: Serialize + DeserializeOwned>(topic: &str) -> Vec<String> {
+    let Some(schema) = crate::schema_for(topic) else {
+        return vec![format!("no payload schema is registered for {topic:?}")];
+    };
+
+    let mut violations = Vec::new();
+    for sample in schema.samples() {
+        let emitted = match serde_json::from_str::<T>(&sample.json) {
+            Ok(payload) => serde_json::to_value(&payload),
+            Err(e) => {
+                violations.push(format!("{topic} {sample}: the producer type rejects the registered shape: {e}"));
+                continue;
+            }
+        };
+        let emitted = match emitted {
+            Ok(value) => value,
+            Err(e) => {
+                violations.push(format!("{topic} {sample}: the producer type fails to serialize: {e}"));
+                continue;
+            }
+        };
+        let full = !matches!(sample.kind, SampleKind::Minimal);
+        for problem in validate(schema, &emitted, full) {
+            violations.push(format!("{topic} {sample}: {problem}"));
+        }
+    }
+    violations
+}
+
+/// Checks one emitted payload against `schema`. With `full`, every registered
+/// field must come out too: the producer was handed all of them, so one that
+/// disappears is not emitted at all.
+fn validate(schema: &PayloadSchema, value: &Value, full: bool) -> Vec<String> {
+    let mut problems = Vec::new();
+    let Some(object) = value.as_object() else {
+        return vec![format!("expected a JSON object, got {value}")];
+    };
+
+    let (variant, tag_field) = match schema.tag_field() {
+        Some(field) => match object.get(field).and_then(Value::as_str) {
+            Some(tag) => match schema.variant(tag) {
+                Some(variant) => (variant, Some(field)),
+                None => return vec![format!("emits unregistered {field} {tag:?}")],
+            },
+            None => return vec![format!("missing the {field:?} tag")],
+        },
+        None => match schema.variant("") {
+            Some(variant) => (variant, None),
+            None => return vec!["schema has no record shape".to_owned()],
+        },
+    };
+
+    check_object(variant.fields, object, tag_field, "", full, &mut problems);
+    problems
+}
+
+fn check_object(
+    fields:   &[Field],
+    object:   &Map<String, Value>,
+    tag:      Option<&str>,
+    prefix:   &str,
+    full:     bool,
+    problems: &mut Vec<String>,
+) {
+    for field in fields {
+        let path = join(prefix, field.name);
+        match object.get(field.name) {
+            None | Some(Value::Null) if field.required => {
+                problems.push(format!("required field {path} is not emitted"));
+            }
+            None if full => problems.push(format!("field {path} is registered but never emitted")),
+            None | Some(Value::Null) => {}
+            Some(value) => check_value(&field.ty, value, &path, full, problems),
+        }
+    }
+    for key in object.keys() {
+        let registered = fields.iter().any(|f| f.name == key) || Some(key.as_str()) == tag;
+        if !registered && key != PROBE_FIELD {
+            problems.push(format!("emits unregistered field {}", join(prefix, key)));
+        }
+    }
+}
+
+fn check_value(ty: &FieldType, value: &Value, path: &str, full: bool, problems: &mut Vec<String>) {
+    let ok = match ty {
+        FieldType::String => value.is_string(),
+        FieldType::Uuid => value.as_str().is_some_and(is_uuid),
+        FieldType::DateTime => value.as_str().is_some_and(|s| s.contains('T')),
+        FieldType::Integer => value.is_i64() || value.is_u64(),
+        FieldType::Number => value.is_number(),
+        FieldType::Boolean => value.is_boolean(),
+        FieldType::Enum(values) => value.as_str().is_some_and(|s| values.contains(&s)),
+        FieldType::Object(fields) => match value.as_object() {
+            Some(object) => {
+                check_object(fields, object, None, path, full, problems);
+                true
+            }
+            None => false,
+        },
+        FieldType::OneOf(fields) => match value.as_object() {
+            Some(object) if object.len() == 1 => {
+                let (key, inner) = object.iter().next().expect("one entry");
+                match fields.iter().find(|f| f.name == key) {
+                    Some(field) => check_value(&field.ty, inner, &join(path, key), full, problems),
+                    None => problems.push(format!("emits unregistered alternative {}", join(path, key))),
+                }
+                true
+            }
+            _ => false,
+        },
+        FieldType::Array(elem) => match value.as_array() {
+            Some(items) => {
+                for (i, item) in items.iter().enumerate() {
+                    check_value(elem, item, &format!("{path}[{i}]"), full, problems);
+                }
+                true
+            }
+            None => false,
+        },
+        FieldType::Any => true,
+    };
+    if !ok {
+        problems.push(format!("field {path} is registered as {ty:?} but emitted as {value}"));
+    }
+}
+
+/// Hyphenated 8-4-4-4-12 hex, the only form the fleet's `Uuid`s serialize to.
+fn is_uuid(s: &str) -> bool {
+    let groups: Vec<&str> = s.split('-').collect();
+    groups.len() == 5
+        && groups.iter().zip([8, 4, 4, 4, 12]).all(|(g, len)| {
+            g.len() == len && g.bytes().all(|b| b.is_ascii_hexdigit())
+        })
+}
+
+fn join(prefix: &str, name: &str) -> String {
+    if prefix.is_empty() { name.to_owned() } else { format!("{prefix}.{name}") }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::Deserialize;
+
+    use super::*;
+
+    #[derive(Serialize, Deserialize)]
+    struct Faithful {
+        comment_id:    String,
+        post_id:       String,
+        author_id:     String,
+        parent_id:     Option<String>,
+        created_at_ms: i64,
+    }
+
+    #[derive(Serialize, Deserialize)]
+    struct DropsParent {
+        comment_id:    String,
+        post_id:       String,
+        author_id:     String,
+        created_at_ms: i64,
+    }
+
+    #[derive(Serialize, Deserialize)]
+    struct RetypesTimestamp {
+        comment_id:    String,
+        post_id:       String,
+        author_id:     String,
+        parent_id:     Option<String>,
+        created_at_ms: String,
+    }
+
+    #[test]
+    fn a_faithful_producer_conforms_and_a_drifted_one_does_not() {
+        assert_eq!(producer_violations::<Faithful>("comment.created"), Vec::<String>::new());
+
+        let dropped = producer_violations::<DropsParent>("comment.created");
+        assert!(dropped.iter().any(|v| v.contains("parent_id is registered but never emitted")), "{dropped:?}");
+
+        let retyped = producer_violations::<RetypesTimestamp>("comment.created");
+        assert!(retyped.iter().any(|v| v.contains("rejects the registered shape")), "{retyped:?}");
+    }
+
+    #[test]
+    fn an_unregistered_topic_is_a_violation() {
+        assert_eq!(producer_violations::<Faithful>("nope").len(), 1);
+    }
+}
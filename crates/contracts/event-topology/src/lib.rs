@@ -32,10 +32,21 @@
 //! since wired a producer for, or an `ORPHAN_PRODUCERS` topic that now has a
 //! consumer, both fail — so the registry can't rot into a pile of excuses.
 //!
-//! NB: this guards topic **names and wiring**, not payload **shapes**. A
-//! producer and consumer can agree on a topic and still disagree on the JSON
-//! body (see the post→geo/notification payload gap). Shape compatibility is a
-//! separate concern (per-stream wire-lock tests live with each service).
+//! ## Payload shapes
+//!
+//! Wiring alone does not stop a producer and consumer that agree on a topic
+//! from disagreeing on its body (the post→geo/notification payload gap). Every
+//! produced topic therefore also registers its payload in [`SCHEMAS`]; the
+//! [`schema`] module describes the model and the two checks built on it —
+//! producer conformance (`conformance` feature, run from each producer's tests)
+//! and consumer compatibility (run over the `dlq-tool` fleet decoders).
+
+#[cfg(feature = "conformance")]
+pub mod conformance;
+mod payloads;
+pub mod schema;
+
+pub use payloads::SCHEMAS;
 
 /// Every Kafka topic that some fleet service emits, paired with its owning
 /// (producing) service. A topic should have exactly one producer service.
@@ -46,10 +57,7 @@ pub const PRODUCERS: &[(&str, &str)] = &[
     ("profile.v1.events", "profile"),
     // notification — per-recipient realtime push stream (consumed by realtime)
     ("notification.v1.events", "notification"),
-    // post — legacy per-type topics + the unified v1 stream (mid-migration)
-    ("post.published", "post"),
-    ("post.updated", "post"),
-    ("post.deleted", "post"),
+    // post — the unified v1 stream (the legacy per-type topics are retired)
     ("post.v1.events", "post"),
     // comment
     ("comment.created", "comment"),
@@ -81,20 +89,28 @@ pub const PRODUCERS: &[(&str, &str)] = &[
 /// Every Kafka subscription in the fleet, paired with its consuming service.
 /// A topic may have several consumers.
 pub const CONSUMERS: &[(&str, &str)] = &[
-    // account lifecycle → compliance plane + profile projection
+    // account lifecycle → compliance plane + profile projection + data-export
+    // fulfilment (self-consume) + the GDPR erasure fan-out to every owning service
     ("account.v1.events", "audit"),
     ("account.v1.events", "profile"),
+    ("account.v1.events", "account"),
+    ("account.v1.events", "post"),
+    ("account.v1.events", "comment"),
+    ("account.v1.events", "chat"),
+    ("account.v1.events", "social-graph"),
+    ("account.v1.events", "engagement"),
+    ("account.v1.events", "notification"),
+    ("account.v1.events", "search"),
+    ("account.v1.events", "media"),
     // profile lifecycle → search index + post author-tier denormalization
     ("profile.v1.events", "search"),
     ("profile.v1.events", "post"),
-    // post (legacy)
-    ("post.published", "notification"),
-    ("post.published", "geo-discovery"),
-    ("post.deleted", "timeline"),
-    // post (unified v1)
+    // post lifecycle → feeds, index, live broadcast, mentions, map
     ("post.v1.events", "timeline"),
     ("post.v1.events", "search"),
     ("post.v1.events", "realtime"),
+    ("post.v1.events", "notification"),
+    ("post.v1.events", "geo-discovery"),
     // notification → realtime device push
     ("notification.v1.events", "realtime"),
     // comment
@@ -179,11 +195,6 @@ pub const DEFERRED: &[(&str, &str)] = &[
 /// Topics a producer emits that have **no** in-repo consumer: intentional
 /// headroom or read-path-enforced concerns. Each entry needs a reason.
 pub const ORPHAN_PRODUCERS: &[(&str, &str)] = &[
-    (
-        "post.updated",
-        "No stream consumer — search/timeline/realtime act on post.v1.events \
-         PostUpdated; the legacy per-type topic is emitted for completeness.",
-    ),
     (
         "social-graph.blocked",
         "Block is enforced on the gRPC read path; no stream consumer yet.",
@@ -366,6 +377,18 @@ pub fn consumed_stream_topics() -> Vec<&'static str> {
     topics
 }
 
+// ---------------------------------------------------------------------------------------------
+// Payload schemas
+//
+// SCHEMAS (src/payloads.rs) pairs every PRODUCERS topic with the JSON body it carries. Render
+// them as JSON Schema with `cargo run -p event-topology --bin gen-event-schemas [topic]`.
+// ---------------------------------------------------------------------------------------------
+
+/// The payload schema registered for `topic`, if it is produced in-repo.
+pub fn schema_for(topic: &str) -> Option<&'static schema::PayloadSchema> {
+    SCHEMAS.iter().find(|s| s.topic == topic)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -539,6 +562,101 @@ mod tests {
         }
     }
 
+    /// Every produced topic registers exactly one payload schema, and only produced topics do.
+    #[test]
+    fn every_produced_topic_has_exactly_one_payload_schema() {
+        let registered: Vec<&str> = SCHEMAS.iter().map(|s| s.topic).collect();
+        let unique: HashSet<&str> = registered.iter().copied().collect();
+        assert_eq!(unique.len(), registered.len(), "a topic registers more than one schema");
+
+        let missing: Vec<_> = produced_topics().into_iter().filter(|t| !unique.contains(t)).collect();
+        assert!(
+            missing.is_empty(),
+            "UNDESCRIBED PAYLOAD: these topics are produced without a payload schema — \
+             register one in SCHEMAS: {missing:?}"
+        );
+
+        let stray: Vec<_> = unique.difference(&produced_topics()).copied().collect();
+        assert!(stray.is_empty(), "payload schemas for topics nobody produces: {stray:?}");
+    }
+
+    /// Field names are unique within an object, and a tagged topic's tags are unique and do
+    /// not collide with a field.
+    #[test]
+    fn payload_schemas_are_well_formed() {
+        fn unique_names(topic: &str, fields: &[schema::Field]) {
+            let mut seen = HashSet::new();
+            for field in fields {
+                assert!(seen.insert(field.name), "{topic}: field {:?} is declared twice", field.name);
+                if let schema::FieldType::Object(nested) | schema::FieldType::OneOf(nested) = field.ty {
+                    unique_names(topic, nested);
+                }
+            }
+        }
+
+        for schema in SCHEMAS {
+            let variants = schema.variants();
+            assert!(!variants.is_empty(), "{}: no shapes registered", schema.topic);
+            let tags: HashSet<&str> = variants.iter().map(|v| v.tag).collect();
+            assert_eq!(tags.len(), variants.len(), "{}: a tag is registered twice", schema.topic);
+            for variant in &variants {
+                unique_names(schema.topic, variant.fields);
+                if let Some(tag) = schema.tag_field() {
+                    assert!(!variant.tag.is_empty(), "{}: a tagged shape without a tag", schema.topic);
+                    assert!(
+                        variant.fields.iter().all(|f| f.name != tag),
+                        "{}: field shadows the {tag:?} tag",
+                        schema.topic
+                    );
+                }
+            }
+        }
+    }
+
+    /// Samples and JSON Schema documents are valid JSON, and the full sample of every shape
+    /// carries every registered field.
+    #[test]
+    fn samples_and_json_schemas_are_valid_json() {
+        for schema in SCHEMAS {
+            serde_json::from_str::<serde_json::Value>(&schema.to_json_schema())
+                .unwrap_or_else(|e| panic!("{}: JSON Schema does not parse: {e}", schema.topic));
+            for sample in schema.samples() {
+                let value: serde_json::Value = serde_json::from_str(&sample.json)
+                    .unwrap_or_else(|e| panic!("{} {sample}: sample does not parse: {e}", schema.topic));
+                if sample.kind == schema::SampleKind::Full {
+                    let fields = schema.variant(sample.variant).expect("sampled variant").fields;
+                    for field in fields {
+                        assert!(value.get(field.name).is_some(), "{} {sample}: {} missing", schema.topic, field.name);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn minimal_samples_omit_optional_fields_and_enum_values_are_swept() {
+        let reactions = schema_for("engagement.reactions").unwrap();
+        let samples = reactions.samples();
+        let minimal = samples
+            .iter()
+            .find(|s| s.variant == "upserted" && s.kind == schema::SampleKind::Minimal)
+            .unwrap();
+        assert!(minimal.json.starts_with(r#"{"event_type":"upserted","post_id":"#));
+        assert!(!minimal.json.contains("old_kind"));
+        assert!(!minimal.json.contains(schema::PROBE_FIELD));
+
+        let swept: HashSet<&str> = samples
+            .iter()
+            .filter_map(|s| match &s.kind {
+                schema::SampleKind::EnumValue { path, value } if s.variant == "removed" && path == "kind" => {
+                    Some(*value)
+                }
+                _ => None,
+            })
+            .collect();
+        assert_eq!(swept, HashSet::from(["fire", "rocket", "clap", "sad"]));
+    }
+
     // --- generated event-catalog block stays in sync with the registry -----------------------
 
     const EVENT_CATALOG: &str =
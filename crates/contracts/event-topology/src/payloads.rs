@@ -0,0 +1,458 @@
+//! The registered payload of every produced topic — one [`PayloadSchema`] per
+//! [`PRODUCERS`](crate::PRODUCERS) entry, in the same order.
+//!
+//! Describe what the producer *emits*, not what a consumer happens to read:
+//! `required` means always present and non-null, and an id that is a UUID on the
+//! wire is [`Uuid`](FieldType::Uuid) even where the producer holds it as a
+//! `String`. The producer's conformance test keeps each entry honest.
+
+use crate::schema::{
+    Field, FieldType::{self, *}, PayloadSchema, Variant,
+};
+
+const fn req(name: &'static str, ty: FieldType) -> Field {
+    Field::req(name, ty)
+}
+
+const fn opt(name: &'static str, ty: FieldType) -> Field {
+    Field::opt(name, ty)
+}
+
+const fn variant(tag: &'static str, fields: &'static [Field]) -> Variant {
+    Variant { tag, fields }
+}
+
+// ── account ────────────────────────────────────────────────────────────────────────────────
+
+const ACCOUNT_ROLE: FieldType =
+    Enum(&["user", "content_moderator", "support_agent", "finance_operator", "admin", "super_admin"]);
+const ACCOUNT_STATUS: FieldType =
+    Enum(&["pending_verification", "active", "suspended", "deactivated", "deleted"]);
+const KYC_STATUS: FieldType = Enum(&["not_started", "submitted", "in_review", "approved", "rejected"]);
+
+/// `account_id` / `occurred_at` / `correlation_id` around each event's own fields.
+macro_rules! account_event {
+    ($tag:literal $(, $field:expr)* $(,)?) => {
+        variant($tag, &[
+            req("account_id", Uuid),
+            $($field,)*
+            req("occurred_at", DateTime),
+            req("correlation_id", Uuid),
+        ])
+    };
+}
+
+const ACCOUNT_EVENTS: &[Variant] = &[
+    account_event!(
+        "account_created",
+        req("identity_id", String),
+        req("email", String),
+        req("role", ACCOUNT_ROLE),
+        req("status", ACCOUNT_STATUS),
+        opt("country_of_residence", String),
+    ),
+    account_event!("email_verified", req("email", String), req("verified_at", DateTime)),
+    account_event!("password_changed"),
+    account_event!("email_changed", req("old_email", String), req("new_email", String)),
+    account_event!("phone_changed", opt("new_phone", String)),
+    account_event!("mfa_enrolled", req("recovery_codes_count", Integer)),
+    account_event!("mfa_revoked"),
+    account_event!("role_assigned", req("role", ACCOUNT_ROLE)),
+    account_event!("role_revoked", req("role", ACCOUNT_ROLE)),
+    account_event!("account_suspended", req("reason", String)),
+    account_event!("account_activated"),
+    account_event!("account_deactivated"),
+    account_event!("account_deleted", opt("deleted_by", Uuid)),
+    account_event!("kyc_status_changed", req("old_status", KYC_STATUS), req("new_status", KYC_STATUS)),
+    account_event!(
+        "gdpr_deletion_requested",
+        req("profile_ids", Array(&String)),
+        req("requested_at", DateTime),
+        req("retention_days", Integer),
+        req("scheduled_deletion_at", DateTime),
+    ),
+    account_event!("gdpr_erasure_confirmed", req("requested_at", DateTime), req("service", String)),
+    account_event!("gdpr_data_export_requested", req("requested_at", DateTime)),
+    account_event!(
+        "gdpr_data_export_completed",
+        req("requested_at", DateTime),
+        req("download_ref", String),
+        req("expires_at", DateTime),
+    ),
+];
+
+// ── profile ────────────────────────────────────────────────────────────────────────────────
+
+/// `profile_id` / `occurred_at_ms` around each event's own fields.
+macro_rules! profile_event {
+    ($tag:literal $(, $field:expr)* $(,)?) => {
+        variant($tag, &[req("profile_id", Uuid), $($field,)* req("occurred_at_ms", Integer)])
+    };
+}
+
+const PROFILE_EVENTS: &[Variant] = &[
+    profile_event!(
+        "ProfileCreated",
+        req("account_id", Uuid),
+        req("handle", String),
+        req("profile_kind", Enum(&["personal", "professional", "brand", "bot"])),
+    ),
+    profile_event!("ProfileUpdated"),
+    profile_event!("HandleChanged", req("new_handle", String)),
+    profile_event!("ProfileVerified"),
+    profile_event!("ProfileHidden", req("masking_reason", String)),
+    profile_event!("ProfileRestored"),
+    profile_event!("ProfileDeleted"),
+    profile_event!("ProfileTierChanged", req("tier", Integer)),
+];
+
+// ── notification ───────────────────────────────────────────────────────────────────────────
+
+const NOTIFICATION_EVENT: &[Field] = &[
+    req("recipient_id", String),
+    req("notification_id", String),
+    req("kind", String),
+    req("created_at_ms", Integer),
+    req("payload", Any),
+];
+
+// ── post ───────────────────────────────────────────────────────────────────────────────────
+
+const POST_KIND: FieldType = Enum(&["TextOnly", "Carousel", "MainVideo"]);
+
+const POST_PUBLISHED: &[Field] = &[
+    req("post_id", Uuid),
+    req("profile_id", Uuid),
+    req("kind", POST_KIND),
+    req("published_at_ms", Integer),
+    req("author_tier", Integer),
+    opt("audio_id", String),
+    opt("audio_kind", Integer),
+    req("caption", String),
+    opt("thumbnail_url", String),
+    opt("lat", Number),
+    opt("lng", Number),
+];
+
+const POST_UPDATED: &[Field] = &[req("post_id", Uuid), req("profile_id", Uuid), req("updated_at_ms", Integer)];
+
+const POST_DELETED: &[Field] = &[req("post_id", Uuid), req("profile_id", Uuid), req("deleted_at_ms", Integer)];
+
+const POST_EVENTS: &[Variant] = &[
+    variant("PostPublished", POST_PUBLISHED),
+    variant("PostUpdated", POST_UPDATED),
+    variant("PostDeleted", POST_DELETED),
+];
+
+// ── comment ────────────────────────────────────────────────────────────────────────────────
+
+const COMMENT_CREATED: &[Field] = &[
+    req("comment_id", Uuid),
+    req("post_id", Uuid),
+    req("author_id", Uuid),
+    opt("parent_id", Uuid),
+    req("created_at_ms", Integer),
+];
+
+const COMMENT_DELETED: &[Field] = &[
+    req("comment_id", Uuid),
+    req("post_id", Uuid),
+    req("author_id", Uuid),
+    req("deleted_at_ms", Integer),
+];
+
+// ── engagement ─────────────────────────────────────────────────────────────────────────────
+
+const REACTION_KIND: FieldType = Enum(&["heart", "fire", "rocket", "clap", "sad"]);
+
+const REACTION_EVENTS: &[Variant] = &[
+    variant("upserted", &[
+        req("post_id", Uuid),
+        req("profile_id", Uuid),
+        req("new_kind", REACTION_KIND),
+        req("new_weight", Integer),
+        opt("old_kind", REACTION_KIND),
+        opt("old_weight", Integer),
+        req("event_at_ms", Integer),
+    ]),
+    variant("removed", &[
+        req("post_id", Uuid),
+        req("profile_id", Uuid),
+        req("kind", REACTION_KIND),
+        req("weight", Integer),
+        req("event_at_ms", Integer),
+    ]),
+];
+
+// ── social-graph ───────────────────────────────────────────────────────────────────────────
+
+const PROFILE_FOLLOWED: &[Field] =
+    &[req("actor_id", Uuid), req("target_id", Uuid), req("followed_at", DateTime)];
+
+const PROFILE_UNFOLLOWED: &[Field] =
+    &[req("actor_id", Uuid), req("target_id", Uuid), req("unfollowed_at", DateTime)];
+
+const PROFILE_BLOCKED: &[Field] = &[
+    req("actor_id", Uuid),
+    req("target_id", Uuid),
+    req("blocked_at", DateTime),
+    req("severed_actor_follow", Boolean),
+    req("severed_target_follow", Boolean),
+];
+
+const AUTHOR_TIER_CHANGED: &[Field] = &[
+    req("profile_id", Uuid),
+    req("new_tier", Integer),
+    req("follower_count", Integer),
+    req("changed_at_ms", Integer),
+];
+
+// ── chat ───────────────────────────────────────────────────────────────────────────────────
+
+const CONVERSATION_CREATED: &[Field] = &[
+    req("conversation_id", Uuid),
+    req("kind", String),
+    req("visibility", String),
+    req("owner_id", Uuid),
+    req("created_at_ms", Integer),
+];
+
+const CONVERSATION_PUBLISHED: &[Field] = &[
+    req("conversation_id", Uuid),
+    req("public_since", String),
+    req("published_at_ms", Integer),
+];
+
+const CONVERSATION_UNPUBLISHED: &[Field] =
+    &[req("conversation_id", Uuid), req("unpublished_at_ms", Integer)];
+
+const MEMBER_JOINED: &[Field] = &[
+    req("conversation_id", Uuid),
+    req("profile_id", Uuid),
+    req("role", String),
+    req("joined_at_ms", Integer),
+];
+
+const MEMBER_LEFT: &[Field] =
+    &[req("conversation_id", Uuid), req("profile_id", Uuid), req("left_at_ms", Integer)];
+
+const MESSAGE_SENT: &[Field] = &[
+    req("conversation_id", Uuid),
+    req("message_id", Uuid),
+    req("sender_id", Uuid),
+    req("content_type", String),
+    req("body", String),
+    opt("media_ref", String),
+    opt("reply_to", Uuid),
+    req("created_at_ms", Integer),
+];
+
+// ── counter ────────────────────────────────────────────────────────────────────────────────
+
+const POPULARITY: &[Field] = &[req("entity_type", String), req("entity_id", String), req("score", Number)];
+
+// ── moderation ─────────────────────────────────────────────────────────────────────────────
+
+const SUBJECT: FieldType = Object(&[
+    req("entity_type", Enum(&["post", "comment", "chat_message", "media", "account", "profile"])),
+    req("entity_id", String),
+    req("actor_id", Uuid),
+    req("surface", String),
+]);
+const ACTION: FieldType = Enum(&[
+    "no_action",
+    "warn",
+    "visibility_limit",
+    "age_gate",
+    "remove_content",
+    "restrict_actor",
+    "suspend",
+    "ban",
+]);
+const CATEGORY: FieldType = Enum(&[
+    "spam",
+    "harassment",
+    "hate",
+    "violent_extremism",
+    "csam",
+    "ncii",
+    "self_harm",
+    "misinformation",
+    "other",
+]);
+
+/// Each event's own fields, then `occurred_at` / `correlation_id`.
+macro_rules! moderation_event {
+    ($tag:literal $(, $field:expr)* $(,)?) => {
+        variant($tag, &[$($field,)* req("occurred_at", DateTime), req("correlation_id", Uuid)])
+    };
+}
+
+const MODERATION_EVENTS: &[Variant] = &[
+    moderation_event!(
+        "case_opened",
+        req("case_id", Uuid),
+        req("subject", SUBJECT),
+        req("actor_id", Uuid),
+        req("category", CATEGORY),
+    ),
+    moderation_event!(
+        "case_resolved",
+        req("case_id", Uuid),
+        req("decision_id", Uuid),
+        req("actor_id", Uuid),
+        req("action", ACTION),
+        req("category", CATEGORY),
+    ),
+    moderation_event!(
+        "decision_recorded",
+        req("decision_id", Uuid),
+        req("subject", SUBJECT),
+        req("author", OneOf(&[req("Reviewer", String), req("Rule", String)])),
+        req("action", ACTION),
+        req("category", CATEGORY),
+        req("policy_version", String),
+        req("rationale", String),
+        opt("reverses", Uuid),
+    ),
+    moderation_event!(
+        "enforcement_applied",
+        req("enforcement_id", Uuid),
+        req("subject", SUBJECT),
+        req("actor_id", Uuid),
+        req("action", ACTION),
+        req("version", Integer),
+        req("applied_at", DateTime),
+        opt("expires_at", DateTime),
+    ),
+    moderation_event!(
+        "enforcement_reversed",
+        req("enforcement_id", Uuid),
+        req("subject", SUBJECT),
+        req("actor_id", Uuid),
+        req("version", Integer),
+    ),
+    moderation_event!(
+        "appeal_resolved",
+        req("appeal_id", Uuid),
+        req("decision_id", Uuid),
+        req("actor_id", Uuid),
+        req("overturned", Boolean),
+    ),
+];
+
+// ── auth ───────────────────────────────────────────────────────────────────────────────────
+
+const IDP_SUBJECT: FieldType = Object(&[req("issuer", String), req("subject", String)]);
+
+const AUTH_EVENTS: &[Variant] = &[
+    variant("session_issued", &[
+        req("session_id", Uuid),
+        req("account_id", Uuid),
+        req("subject", IDP_SUBJECT),
+        req("generation", Integer),
+        req("issued_at", DateTime),
+        req("expires_at", DateTime),
+        req("absolute_expiry", DateTime),
+        req("occurred_at", DateTime),
+        req("correlation_id", Uuid),
+    ]),
+    variant("session_revoked", &[
+        req("session_id", Uuid),
+        req("account_id", Uuid),
+        req("generation", Integer),
+        req("reason", Enum(&["logout", "global_logout", "refresh_reuse", "administrative"])),
+        req("occurred_at", DateTime),
+        req("correlation_id", Uuid),
+    ]),
+    variant("subject_linked", &[
+        req("account_id", Uuid),
+        req("subject", IDP_SUBJECT),
+        req("occurred_at", DateTime),
+        req("correlation_id", Uuid),
+    ]),
+    variant("new_device_login", &[
+        req("session_id", Uuid),
+        req("account_id", Uuid),
+        opt("device_id", String),
+        opt("user_agent", String),
+        opt("country", String),
+        opt("city", String),
+        req("occurred_at", DateTime),
+        req("correlation_id", Uuid),
+    ]),
+    variant("refresh_token_reuse_detected", &[
+        req("session_id", Uuid),
+        req("account_id", Uuid),
+        req("generation", Integer),
+        opt("user_agent", String),
+        opt("country", String),
+        opt("city", String),
+        req("occurred_at", DateTime),
+        req("correlation_id", Uuid),
+    ]),
+];
+
+// ── media ──────────────────────────────────────────────────────────────────────────────────
+
+/// `asset_id` / `owner_id` around each event's own fields, then `occurred_at`.
+macro_rules! media_event {
+    ($tag:literal $(, $field:expr)* $(,)?) => {
+        variant($tag, &[req("asset_id", Uuid), req("owner_id", Uuid), $($field,)* req("occurred_at", DateTime)])
+    };
+}
+
+const MEDIA_EVENTS: &[Variant] = &[
+    media_event!(
+        "asset_uploaded",
+        req("kind", Enum(&["avatar", "post_image", "video"])),
+        req("content_hash", String),
+        req("byte_size", Integer),
+    ),
+    media_event!(
+        "asset_variant_ready",
+        req("rendition", Enum(&["original", "thumbnail", "small", "medium", "large", "manifest", "poster"])),
+    ),
+    media_event!("asset_ready"),
+    media_event!("asset_failed", req("reason", String)),
+    media_event!("asset_quarantined"),
+    media_event!("asset_restored"),
+    media_event!("asset_deleted"),
+];
+
+/// One payload schema per produced topic.
+pub const SCHEMAS: &[PayloadSchema] = &[
+    // account
+    PayloadSchema::tagged("account.v1.events", "type", ACCOUNT_EVENTS),
+    // profile
+    PayloadSchema::tagged("profile.v1.events", "type", PROFILE_EVENTS),
+    // notification
+    PayloadSchema::record("notification.v1.events", NOTIFICATION_EVENT),
+    // post
+    PayloadSchema::tagged("post.v1.events", "type", POST_EVENTS),
+    // comment
+    PayloadSchema::record("comment.created", COMMENT_CREATED),
+    PayloadSchema::record("comment.deleted", COMMENT_DELETED),
+    // engagement (JSON form; protobuf by default — see engagement/v1/events.proto)
+    PayloadSchema::tagged("engagement.reactions", "event_type", REACTION_EVENTS),
+    // social-graph
+    PayloadSchema::record("social-graph.followed", PROFILE_FOLLOWED),
+    PayloadSchema::record("social-graph.unfollowed", PROFILE_UNFOLLOWED),
+    PayloadSchema::record("social-graph.blocked", PROFILE_BLOCKED),
+    PayloadSchema::record("social-graph.author_tier_changed", AUTHOR_TIER_CHANGED),
+    // chat
+    PayloadSchema::record("chat.conversation.created", CONVERSATION_CREATED),
+    PayloadSchema::record("chat.conversation.published", CONVERSATION_PUBLISHED),
+    PayloadSchema::record("chat.conversation.unpublished", CONVERSATION_UNPUBLISHED),
+    PayloadSchema::record("chat.member.joined", MEMBER_JOINED),
+    PayloadSchema::record("chat.member.left", MEMBER_LEFT),
+    PayloadSchema::record("chat.message.sent", MESSAGE_SENT),
+    // counter (JSON form; protobuf by default — see counter/v1/events.proto)
+    PayloadSchema::record("counter.v1.popularity", POPULARITY),
+    // moderation
+    PayloadSchema::tagged("moderation.v1.events", "type", MODERATION_EVENTS),
+    // auth
+    PayloadSchema::tagged("auth.v1.events", "type", AUTH_EVENTS),
+    // media
+    PayloadSchema::tagged("media.v1.events", "type", MEDIA_EVENTS),
+];
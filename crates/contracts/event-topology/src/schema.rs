@@ -0,0 +1,358 @@
+//! Payload shapes — the half of the contract [`PRODUCERS`](crate::PRODUCERS)
+//! does not cover.
+//!
+//! Every produced topic registers one [`PayloadSchema`] in
+//! [`SCHEMAS`](crate::SCHEMAS): the JSON body its producer emits, field by
+//! field. The registry is const data like the wiring tables, and renders to a
+//! JSON Schema document ([`PayloadSchema::to_json_schema`]) for tooling outside
+//! the workspace.
+//!
+//! Two checks hang off it, and together they fail the build when a producer
+//! drops or retypes a field a consumer reads:
+//!
+//! - **producer conformance** — each producer's test round-trips the
+//!   [`samples`](PayloadSchema::samples) through its own payload type and
+//!   validates what comes out (`conformance` feature). A producer that drops,
+//!   adds or retypes a field fails until the schema says so.
+//! - **consumer compatibility** — the `dlq-tool` fleet decoders decode every
+//!   sample with every consumer's wire type. *Backward*: the
+//!   [`Minimal`](SampleKind::Minimal) sample (optional fields absent) must
+//!   decode, so no consumer requires what the producer may omit. *Forward*: the
+//!   [`Full`](SampleKind::Full) sample (every field, plus an unknown
+//!   [`PROBE_FIELD`]) must decode, so no consumer chokes on what the producer
+//!   adds. One [`EnumValue`](SampleKind::EnumValue) sample per enumerated value
+//!   catches a consumer that does not know a value the producer emits.
+//!
+//! Samples are JSON. The two protobuf streams also accept JSON records, and
+//! their `.proto` messages are guarded by `buf breaking` like the gRPC contracts.
+
+/// The JSON type of a field's value.
+#[derive(Debug, Clone, Copy)]
+pub enum FieldType {
+    String,
+    /// A string holding a UUID.
+    Uuid,
+    /// An RFC 3339 timestamp string.
+    DateTime,
+    Integer,
+    Number,
+    Boolean,
+    /// A string restricted to these values.
+    Enum(&'static [&'static str]),
+    /// A nested object.
+    Object(&'static [Field]),
+    /// An object with exactly one of these keys (serde's externally tagged enum).
+    OneOf(&'static [Field]),
+    /// A JSON array whose every element has this type.
+    Array(&'static FieldType),
+    /// Any JSON value, passed through opaquely.
+    Any,
+}
+
+/// One named field of a payload object.
+#[derive(Debug, Clone, Copy)]
+pub struct Field {
+    pub name:     &'static str,
+    pub ty:       FieldType,
+    /// `true` when the producer always emits the field with a non-null value;
+    /// an optional field may be absent or `null`.
+    pub required: bool,
+}
+
+impl Field {
+    /// A field the producer always emits.
+    pub const fn req(name: &'static str, ty: FieldType) -> Self {
+        Self { name, ty, required: true }
+    }
+
+    /// A field the producer may omit or send as `null`.
+    pub const fn opt(name: &'static str, ty: FieldType) -> Self {
+        Self { name, ty, required: false }
+    }
+}
+
+/// One shape a topic carries: the only one for an untagged topic, or the body
+/// of one tag value for a tagged one.
+#[derive(Debug, Clone, Copy)]
+pub struct Variant {
+    /// Value of the schema's tag field; empty for an untagged topic.
+    pub tag:    &'static str,
+    pub fields: &'static [Field],
+}
+
+/// How a topic's records are shaped.
+#[derive(Debug, Clone, Copy)]
+pub enum Shape {
+    /// Every record has these fields.
+    Record(&'static [Field]),
+    /// Internally tagged (`{"<field>": "<tag>", …}`): one body per tag value.
+    Tagged { field: &'static str, variants: &'static [Variant] },
+}
+
+/// The registered payload of one produced topic.
+#[derive(Debug, Clone, Copy)]
+pub struct PayloadSchema {
+    pub topic: &'static str,
+    pub shape: Shape,
+}
+
+/// Unknown top-level field added to every [`SampleKind::Full`] sample: a
+/// consumer must ignore fields it does not read.
+pub const PROBE_FIELD: &str = "x_schema_probe";
+
+/// Which compatibility direction a [`Sample`] exercises.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SampleKind {
+    /// Required fields only — what a consumer can count on (backward).
+    Minimal,
+    /// Every field plus [`PROBE_FIELD`] — all a consumer may be sent (forward).
+    Full,
+    /// The full sample with the enum field at `path` set to `value`.
+    EnumValue { path: String, value: &'static str },
+}
+
+/// A JSON payload generated from a schema.
+#[derive(Debug, Clone)]
+pub struct Sample {
+    /// The variant's tag (empty for an untagged topic).
+    pub variant: &'static str,
+    pub kind:    SampleKind,
+    pub json:    String,
+}
+
+impl std::fmt::Display for Sample {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let variant = if self.variant.is_empty() { "payload" } else { self.variant };
+        match &self.kind {
+            SampleKind::Minimal => write!(f, "minimal {variant}"),
+            SampleKind::Full => write!(f, "full {variant}"),
+            SampleKind::EnumValue { path, value } => write!(f, "{variant} with {path}={value:?}"),
+        }
+    }
+}
+
+impl PayloadSchema {
+    /// A topic whose every record has the same shape.
+    pub const fn record(topic: &'static str, fields: &'static [Field]) -> Self {
+        Self { topic, shape: Shape::Record(fields) }
+    }
+
+    /// A topic carrying several shapes, discriminated by the `field` tag.
+    pub const fn tagged(topic: &'static str, field: &'static str, variants: &'static [Variant]) -> Self {
+        Self { topic, shape: Shape::Tagged { field, variants } }
+    }
+
+    /// Name of the discriminant field, for a tagged topic.
+    pub fn tag_field(&self) -> Option<&'static str> {
+        match self.shape {
+            Shape::Record(_) => None,
+            Shape::Tagged { field, .. } => Some(field),
+        }
+    }
+
+    /// The shapes this topic carries; an untagged topic has one, with an empty tag.
+    pub fn variants(&self) -> Vec<Variant> {
+        match self.shape {
+            Shape::Record(fields) => vec![Variant { tag: "", fields }],
+            Shape::Tagged { variants, .. } => variants.to_vec(),
+        }
+    }
+
+    /// The variant carrying `tag` (an untagged topic matches only `""`).
+    pub fn variant(&self, tag: &str) -> Option<Variant> {
+        self.variants().into_iter().find(|v| v.tag == tag)
+    }
+
+    /// The compatibility samples: per variant, the minimal and the full payload,
+    /// then one payload per enumerated value past the first (the first is already
+    /// in the full sample).
+    pub fn samples(&self) -> Vec<Sample> {
+        let mut out = Vec::new();
+        for variant in self.variants() {
+            out.push(Sample {
+                variant: variant.tag,
+                kind:    SampleKind::Minimal,
+                json:    self.render(&variant, Fill::Minimal),
+            });
+            out.push(Sample {
+                variant: variant.tag,
+                kind:    SampleKind::Full,
+                json:    self.render(&variant, Fill::Full),
+            });
+            let mut enums = Vec::new();
+            enum_paths(variant.fields, "", &mut enums);
+            for (path, values) in enums {
+                for value in values.iter().skip(1) {
+                    out.push(Sample {
+                        variant: variant.tag,
+                        kind:    SampleKind::EnumValue { path: path.clone(), value },
+                        json:    self.render(&variant, Fill::Enum(&path, value)),
+                    });
+                }
+            }
+        }
+        out
+    }
+
+    fn render(&self, variant: &Variant, fill: Fill<'_>) -> String {
+        let mut members = Vec::new();
+        if let Some(tag) = self.tag_field() {
+            members.push(format!("{}:{}", quote(tag), quote(variant.tag)));
+        }
+        render_members(variant.fields, "", fill, &mut members);
+        if matches!(fill, Fill::Full | Fill::Enum(..)) {
+            members.push(format!("{}:true", quote(PROBE_FIELD)));
+        }
+        format!("{{{}}}", members.join(","))
+    }
+
+    /// This topic's payload as a JSON Schema (draft 2020-12) document.
+    pub fn to_json_schema(&self) -> String {
+        let shapes: Vec<String> = self
+            .variants()
+            .iter()
+            .map(|variant| {
+                let mut properties = Vec::new();
+                let mut required = Vec::new();
+                if let Some(tag) = self.tag_field() {
+                    properties.push(format!("{}:{{\"const\":{}}}", quote(tag), quote(variant.tag)));
+                    required.push(quote(tag));
+                }
+                object_properties(variant.fields, &mut properties, &mut required);
+                object_schema(&properties, &required)
+            })
+            .collect();
+        let body = match shapes.as_slice() {
+            [single] => single[1..single.len() - 1].to_owned(),
+            _ => format!("\"oneOf\":[{}]", shapes.join(",")),
+        };
+        format!(
+            "{{\"$schema\":\"https://json-schema.org/draft/2020-12/schema\",\"$id\":{},\"title\":{},{body}}}",
+            quote(&format!("urn:event-topology:{}", self.topic)),
+            quote(self.topic),
+        )
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Fill<'a> {
+    Minimal,
+    Full,
+    Enum(&'a str, &'static str),
+}
+
+fn render_members(fields: &[Field], prefix: &str, fill: Fill<'_>, out: &mut Vec<String>) {
+    for field in fields {
+        if !field.required && matches!(fill, Fill::Minimal) {
+            continue;
+        }
+        let path = join(prefix, field.name);
+        out.push(format!("{}:{}", quote(field.name), render_value(&field.ty, &path, fill)));
+    }
+}
+
+fn render_value(ty: &FieldType, path: &str, fill: Fill<'_>) -> String {
+    match ty {
+        FieldType::String => quote("sample"),
+        FieldType::Uuid => quote("0190a4b2-7c3d-7e5f-8a1b-2c3d4e5f6a7b"),
+        FieldType::DateTime => quote("2026-01-01T00:00:00Z"),
+        FieldType::Integer => "1".to_owned(),
+        FieldType::Number => "1.5".to_owned(),
+        FieldType::Boolean => "true".to_owned(),
+        FieldType::Enum(values) => match fill {
+            Fill::Enum(target, value) if target == path => quote(value),
+            _ => quote(values.first().copied().unwrap_or_default()),
+        },
+        FieldType::Object(fields) => {
+            let mut members = Vec::new();
+            render_members(fields, path, fill, &mut members);
+            format!("{{{}}}", members.join(","))
+        }
+        FieldType::OneOf(fields) => {
+            let mut members = Vec::new();
+            if let Some(first) = fields.first() {
+                render_members(std::slice::from_ref(first), path, fill, &mut members);
+            }
+            format!("{{{}}}", members.join(","))
+        }
+        FieldType::Array(elem) => format!("[{}]", render_value(elem, path, fill)),
+        FieldType::Any => "{}".to_owned(),
+    }
+}
+
+fn enum_paths(fields: &[Field], prefix: &str, out: &mut Vec<(String, &'static [&'static str])>) {
+    for field in fields {
+        let path = join(prefix, field.name);
+        match field.ty {
+            FieldType::Enum(values) => out.push((path, values)),
+            FieldType::Object(nested) => enum_paths(nested, &path, out),
+            FieldType::OneOf(nested) => enum_paths(&nested[..nested.len().min(1)], &path, out),
+            _ => {}
+        }
+    }
+}
+
+fn object_properties(fields: &[Field], properties: &mut Vec<String>, required: &mut Vec<String>) {
+    for field in fields {
+        let mut schema = type_schema(&field.ty);
+        if field.required {
+            required.push(quote(field.name));
+        } else {
+            schema = format!("{{\"anyOf\":[{schema},{{\"type\":\"null\"}}]}}");
+        }
+        properties.push(format!("{}:{schema}", quote(field.name)));
+    }
+}
+
+fn object_schema(properties: &[String], required: &[String]) -> String {
+    format!(
+        "{{\"type\":\"object\",\"properties\":{{{}}},\"required\":[{}]}}",
+        properties.join(","),
+        required.join(",")
+    )
+}
+
+fn type_schema(ty: &FieldType) -> String {
+    match ty {
+        FieldType::String => "{\"type\":\"string\"}".to_owned(),
+        FieldType::Uuid => "{\"type\":\"string\",\"format\":\"uuid\"}".to_owned(),
+        FieldType::DateTime => "{\"type\":\"string\",\"format\":\"date-time\"}".to_owned(),
+        FieldType::Integer => "{\"type\":\"integer\"}".to_owned(),
+        FieldType::Number => "{\"type\":\"number\"}".to_owned(),
+        FieldType::Boolean => "{\"type\":\"boolean\"}".to_owned(),
+        FieldType::Enum(values) => {
+            let values: Vec<String> = values.iter().map(|v| quote(v)).collect();
+            format!("{{\"type\":\"string\",\"enum\":[{}]}}", values.join(","))
+        }
+        FieldType::Object(fields) => {
+            let (mut properties, mut required) = (Vec::new(), Vec::new());
+            object_properties(fields, &mut properties, &mut required);
+            object_schema(&properties, &required)
+        }
+        FieldType::OneOf(fields) => {
+            let shapes: Vec<String> = fields
+                .iter()
+                .map(|field| {
+                    let property = format!("{}:{}", quote(field.name), type_schema(&field.ty));
+                    format!(
+                        "{{\"type\":\"object\",\"properties\":{{{property}}},\"required\":[{}],\"additionalProperties\":false}}",
+                        quote(field.name)
+                    )
+                })
+                .collect();
+            format!("{{\"oneOf\":[{}]}}", shapes.join(","))
+        }
+        FieldType::Array(elem) => format!("{{\"type\":\"array\",\"items\":{}}}", type_schema(elem)),
+        FieldType::Any => "{}".to_owned(),
+    }
+}
+
+fn join(prefix: &str, name: &str) -> String {
+    if prefix.is_empty() { name.to_owned() } else { format!("{prefix}.{name}") }
+}
+
+/// A JSON string literal. Schema names and enum values are plain identifiers,
+/// so only the two characters JSON requires are escaped.
+fn quote(s: &str) -> String {
+    format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""))
+}
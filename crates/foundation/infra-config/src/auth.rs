@@ -0,0 +1,198 @@
+//! The `[auth]` section: fleet-wide inbound caller authentication and per-RPC
+//! authorization.
+//!
+//! The section carries only the *deployment* half of the policy — where the edge
+//! token's verification keys live, which issuer/audience to accept, and whether
+//! denials are enforced or merely observed. The per-method permission table is
+//! code, supplied by each service (`service_runtime::Service::access_policy`),
+//! because it changes with the proto surface rather than with the environment.
+//! This crate stays free of any `auth-context` dependency: the serving binary
+//! builds the verifier from [`AuthRegistry`]'s boot values.
+//!
+//! ```toml
+//! [auth]
+//! enforce  = false   # shadow mode: log + count denials, admit the request
+//! jwks_url = "http://auth-server:8081/.well-known/jwks.json"
+//! issuer   = "https://auth.core-platform"
+//! audience = "core-platform"
+//!
+//! [auth.peers]
+//! audience       = "core-platform-peers"
+//! namespace      = "default"
+//! account_prefix = "prod-"
+//! ```
+//!
+//! `[auth.peers]` is how a peer service proves who it is on internal RPCs: it
+//! presents a projected Kubernetes ServiceAccount token minted for `audience`,
+//! verified against the cluster's service-account issuer (`jwks_url`, default the
+//! API server's own JWKS). Without it no internal call can be authenticated, so
+//! every internal-only method is denied.
+//!
+//! Only `enforce` hot-reloads. The verifiers (JWKS endpoints, expected issuer and
+//! audience, peer identity mapping) are wired once at boot; a reloaded document
+//! that changes them is accepted but logged, and takes effect on the next restart.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use serde::Deserialize;
+use tracing::warn;
+
+use crate::error::ConfigError;
+
+fn default_enforce() -> bool {
+    true
+}
+
+/// The `[auth]` section.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct AuthSection {
+    /// `true` rejects denied calls (`UNAUTHENTICATED` / `PERMISSION_DENIED`);
+    /// `false` is shadow mode — the denial is logged and counted, the call admitted.
+    /// Defaults to enforcing, so adding the section is fail-closed unless a rollout
+    /// opts into shadow explicitly.
+    #[serde(default = "default_enforce")]
+    pub enforce: bool,
+
+    /// JWKS endpoint publishing the edge-token verification keys.
+    pub jwks_url: String,
+
+    /// Expected `iss` claim. Absent disables the issuer check.
+    #[serde(default)]
+    pub issuer: Option<String>,
+
+    /// Expected `aud` claim. Absent disables the audience check.
+    #[serde(default)]
+    pub audience: Option<String>,
+
+    /// Verification of the peer-service tokens internal RPCs carry. Absent: no
+    /// peer can authenticate.
+    #[serde(default)]
+    pub peers: Option<PeerAuthSection>,
+}
+
+/// The `[auth.peers]` subsection.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct PeerAuthSection {
+    /// JWKS of the ServiceAccount token issuer. Absent: the in-cluster API server
+    /// (`/openid/v1/jwks`), fetched with the pod's own credentials.
+    #[serde(default)]
+    pub jwks_url: Option<String>,
+
+    /// Expected `iss` claim. Absent disables the issuer check; the audience, which
+    /// only the fleet's projected tokens are minted for, still scopes the token.
+    #[serde(default)]
+    pub issuer: Option<String>,
+
+    /// Audience the projected tokens are minted for.
+    pub audience: String,
+
+    /// Namespace the fleet's ServiceAccounts live in; tokens from any other
+    /// namespace are rejected.
+    pub namespace: String,
+
+    /// Overlay `namePrefix` stripped from the ServiceAccount name to recover the
+    /// service name (`prod-account-server` → `account-server`).
+    #[serde(default)]
+    pub account_prefix: String,
+}
+
+impl PeerAuthSection {
+    fn validate(&self) -> Result<(), ConfigError> {
+        if matches!(&self.jwks_url, Some(url) if url.trim().is_empty()) {
+            return Err(ConfigError::validation("[auth.peers] jwks_url must not be empty when set"));
+        }
+        if matches!(&self.issuer, Some(iss) if iss.trim().is_empty()) {
+            return Err(ConfigError::validation("[auth.peers] issuer must not be empty when set"));
+        }
+        if self.audience.trim().is_empty() {
+            return Err(ConfigError::validation("[auth.peers] audience must not be empty"));
+        }
+        if self.namespace.trim().is_empty() {
+            return Err(ConfigError::validation("[auth.peers] namespace must not be empty"));
+        }
+        Ok(())
+    }
+}
+
+impl AuthSection {
+    /// A verifier with no key source can only ever deny, so an empty `jwks_url` is a
+    /// configuration error rather than a silent deny-all.
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        if self.jwks_url.trim().is_empty() {
+            return Err(ConfigError::validation("[auth] jwks_url must not be empty"));
+        }
+        if matches!(&self.issuer, Some(iss) if iss.trim().is_empty()) {
+            return Err(ConfigError::validation("[auth] issuer must not be empty when set"));
+        }
+        if matches!(&self.audience, Some(aud) if aud.trim().is_empty()) {
+            return Err(ConfigError::validation("[auth] audience must not be empty when set"));
+        }
+        if let Some(peers) = &self.peers {
+            peers.validate()?;
+        }
+        Ok(())
+    }
+}
+
+/// Boot-resolved verifier settings plus the live enforce/shadow flag.
+pub struct AuthRegistry {
+    enforce: AtomicBool,
+    boot: AuthSection,
+}
+
+impl AuthRegistry {
+    /// Validates and resolves an `[auth]` section.
+    pub fn from_section(section: AuthSection) -> Result<Self, ConfigError> {
+        section.validate()?;
+        Ok(Self { enforce: AtomicBool::new(section.enforce), boot: section })
+    }
+
+    /// Whether denials are currently enforced (`false` = shadow mode).
+    pub fn enforce(&self) -> bool {
+        self.enforce.load(Ordering::Relaxed)
+    }
+
+    /// JWKS endpoint the verifier was built against at boot.
+    pub fn jwks_url(&self) -> &str {
+        &self.boot.jwks_url
+    }
+
+    /// Expected issuer the verifier was built against at boot.
+    pub fn issuer(&self) -> Option<&str> {
+        self.boot.issuer.as_deref()
+    }
+
+    /// Expected audience the verifier was built against at boot.
+    pub fn audience(&self) -> Option<&str> {
+        self.boot.audience.as_deref()
+    }
+
+    /// Peer-token verification settings read at boot; `None` when no peer can
+    /// authenticate.
+    pub fn peers(&self) -> Option<&PeerAuthSection> {
+        self.boot.peers.as_ref()
+    }
+
+    /// Hot-applies a reloaded `[auth]` section: flips the enforce flag; verifier
+    /// changes are logged and deferred to the next restart.
+    pub fn apply(&self, section: AuthSection) -> Result<(), ConfigError> {
+        section.validate()?;
+
+        if section.jwks_url != self.boot.jwks_url
+            || section.issuer != self.boot.issuer
+            || section.audience != self.boot.audience
+            || section.peers != self.boot.peers
+        {
+            warn!(
+                "[auth] jwks_url/issuer/audience/peers changed in reloaded config — ignored until \
+                 restart (the token verifiers are wired at boot)"
+            );
+        }
+
+        let previous = self.enforce.swap(section.enforce, Ordering::Relaxed);
+        if previous != section.enforce {
+            warn!(enforce = section.enforce, "[auth] enforce flag changed");
+        }
+        Ok(())
+    }
+}
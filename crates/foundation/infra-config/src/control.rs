@@ -0,0 +1,152 @@
+//! The single writer every config source applies through: versioned apply, status, and
+//! rollback to the last good document.
+
+use std::{
+    sync::{Arc, Mutex},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use serde::Serialize;
+use tracing::{info, warn};
+
+use crate::{error::ConfigError, reload::Reloadable, source::ConfigDocument};
+
+/// Serializes every swap of a [`Reloadable`] target and remembers what it is running.
+///
+/// Sources ([`spawn_source`](crate::spawn_source)) and operators ([`rollback`](Self::rollback))
+/// both go through here, so reloads never race and the target's fail-closed
+/// [`reload`](Reloadable::reload) stays the only way config reaches the data path.
+///
+/// # Rollback
+///
+/// The document running before the current one is kept as the *last good* config (it
+/// passed the same validation). [`rollback`](Self::rollback) re-applies it and pins the
+/// replica away from the version it replaced: the source re-offering that version is
+/// ignored until it publishes a different one, so a bad-but-valid push isn't re-applied
+/// behind the operator's back. Rollback is per replica; a fleet-wide revert is a new push.
+pub struct ConfigControl<R> {
+    target: Arc<R>,
+    source: String,
+    state: Mutex<State>,
+}
+
+struct State {
+    current: ConfigDocument,
+    previous: Option<ConfigDocument>,
+    rolled_back_from: Option<String>,
+    applied_at: SystemTime,
+    last_error: Option<String>,
+}
+
+/// Snapshot of what a replica is running, served on the admin port.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ConfigStatus {
+    /// Where documents come from (see [`ConfigSource::describe`](crate::ConfigSource::describe)).
+    pub source: String,
+    /// Version (etag / content hash) of the live document.
+    pub version: String,
+    /// When the live document was applied, in Unix seconds.
+    pub applied_at_unix: u64,
+    /// Why the most recent document was rejected; cleared by the next successful apply.
+    pub last_error: Option<String>,
+    /// Version of the last good document a [`rollback`](ConfigControl::rollback) would restore.
+    pub rollback_to: Option<String>,
+    /// Version rolled back from, ignored until the source publishes another.
+    pub pinned_away_from: Option<String>,
+}
+
+impl<R: Reloadable> ConfigControl<R> {
+    /// Wraps `target`, which was built from `booted` (no re-apply happens here).
+    pub fn booted(target: Arc<R>, source: String, booted: ConfigDocument) -> Self {
+        Self {
+            target,
+            source,
+            state: Mutex::new(State {
+                current: booted,
+                previous: None,
+                rolled_back_from: None,
+                applied_at: SystemTime::now(),
+                last_error: None,
+            }),
+        }
+    }
+
+    /// The reload target this control drives.
+    pub fn target(&self) -> Arc<R> {
+        Arc::clone(&self.target)
+    }
+
+    /// Applies a freshly-fetched document (the reload entry point for every source).
+    ///
+    /// Fail-closed: a rejected document leaves the running config untouched and is recorded
+    /// as `last_error`. The live version and the version rolled back from are skipped.
+    pub fn apply(&self, document: ConfigDocument) -> Result<(), ConfigError> {
+        let mut state = self.lock();
+        if document.version == state.current.version {
+            return Ok(());
+        }
+        if state.rolled_back_from.as_deref() == Some(document.version.as_str()) {
+            warn!(version = %document.version, "skipping rolled-back infrastructure config");
+            return Ok(());
+        }
+
+        if let Err(e) = self.target.reload(&document.raw) {
+            warn!(error = %e, version = %document.version,
+                "rejected reloaded infrastructure config — keeping previous");
+            state.last_error = Some(format!("{}: {e}", document.version));
+            return Err(e);
+        }
+
+        info!(version = %document.version, "infrastructure config hot-reloaded");
+        state.previous = Some(std::mem::replace(&mut state.current, document));
+        state.rolled_back_from = None;
+        state.applied_at = SystemTime::now();
+        state.last_error = None;
+        Ok(())
+    }
+
+    /// Re-applies the last good document and pins the replica away from the current one.
+    ///
+    /// Returns the restored version. Errors with [`ConfigError::NoRollbackTarget`] when no
+    /// document preceded the current one (nothing has been reloaded since boot, or a
+    /// rollback already happened).
+    pub fn rollback(&self) -> Result<String, ConfigError> {
+        let mut state = self.lock();
+        let previous = state.previous.take().ok_or(ConfigError::NoRollbackTarget)?;
+
+        // Still through the fail-closed `reload`: should it reject the document now, the
+        // current config stays live and the rollback target is kept.
+        if let Err(e) = self.target.reload(&previous.raw) {
+            state.previous = Some(previous);
+            return Err(e);
+        }
+
+        let version = previous.version.clone();
+        let replaced = std::mem::replace(&mut state.current, previous);
+        warn!(from = %replaced.version, to = %version, "infrastructure config rolled back");
+        state.rolled_back_from = Some(replaced.version);
+        state.applied_at = SystemTime::now();
+        state.last_error = None;
+        Ok(version)
+    }
+
+    /// What this replica is running.
+    pub fn status(&self) -> ConfigStatus {
+        let state = self.lock();
+        ConfigStatus {
+            source: self.source.clone(),
+            version: state.current.version.clone(),
+            applied_at_unix: state
+                .applied_at
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |since| since.as_secs()),
+            last_error: state.last_error.clone(),
+            rollback_to: state.previous.as_ref().map(|previous| previous.version.clone()),
+            pinned_away_from: state.rolled_back_from.clone(),
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
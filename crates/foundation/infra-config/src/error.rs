@@ -12,6 +12,16 @@ pub enum ConfigError {
     #[error("filesystem watch error: {0}")]
     Watch(#[from] notify::Error),
 
+    /// A [`ConfigSource`](crate::ConfigSource) couldn't produce a document (control plane
+    /// unreachable, unexpected status). Retried by the source driver; the running config
+    /// stays live.
+    #[error("config source error: {0}")]
+    Source(String),
+
+    /// A rollback was requested with no previous good document to restore.
+    #[error("no previous infrastructure config to roll back to")]
+    NoRollbackTarget,
+
     /// A structurally-valid config that violates a semantic invariant. Reported
     /// *before* any live swap, so the running fleet keeps its previous values.
     #[error("invalid infrastructure config: {0}")]
@@ -0,0 +1,291 @@
+//! The `[flags]` section: hot-reloadable feature flags with percentage rollouts and
+//! per-principal / per-tenant targeting.
+//!
+//! Behaviour switches that used to be an env var plus a restart (media's upload dedup,
+//! post's legacy-topic dual publishing) become a config push: every replica watching the
+//! same document flips within one reload, and a bad push rolls back like any other
+//! section.
+//!
+//! ```toml
+//! # Plain boolean.
+//! [flags."post.dual_publish_legacy_topics"]
+//! enabled = true
+//!
+//! # Percentage rollout, bucketed by principal, with targeting rules evaluated first.
+//! [flags."media.upload_dedup"]
+//! enabled = true
+//! rollout = { percent = 10, by = "principal" }
+//! rules = [
+//!   { tenants = ["internal"], enabled = true },
+//!   { principals = ["0b7c…"], enabled = false },
+//! ]
+//! ```
+//!
+//! # Evaluation
+//!
+//! `enabled = false` is the kill switch: the flag is off for everyone, rules included.
+//! Otherwise the first rule naming the caller's principal or tenant decides; failing
+//! that, the rollout admits the caller's stable bucket; without a rollout the flag is on.
+//! An undeclared flag is off, so code paths guarded by a flag are default-off until a
+//! deployment opts in.
+//!
+//! Buckets hash the flag name with the rollout key, so two flags at 10% don't select the
+//! same 10% of users, and raising `percent` only ever adds callers. A caller without the
+//! rollout key (no principal on a background job) is outside any partial rollout.
+
+use std::{collections::HashMap, fmt, sync::Arc};
+
+use arc_swap::ArcSwap;
+use serde::Deserialize;
+use tracing::info;
+
+use crate::error::ConfigError;
+
+/// Rollout resolution: `percent` is honoured to two decimal places.
+const BUCKETS: u64 = 10_000;
+
+/// The `[flags]` section: flag name → spec.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(transparent)]
+pub struct FlagsSection {
+    pub flags: HashMap<String, FlagSpec>,
+}
+
+/// One flag.
+#[derive(Debug, Clone, Deserialize)]
+pub struct FlagSpec {
+    /// Master switch. `false` turns the flag off for every caller, whatever the rules say.
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Admits only a stable share of callers. Absent = everyone.
+    #[serde(default)]
+    pub rollout: Option<RolloutSpec>,
+
+    /// Targeting overrides, evaluated in order before the rollout; the first match wins.
+    #[serde(default)]
+    pub rules: Vec<FlagRule>,
+}
+
+/// Percentage rollout.
+#[derive(Debug, Clone, Deserialize)]
+pub struct RolloutSpec {
+    /// Share of callers admitted, in `[0, 100]`.
+    pub percent: f64,
+
+    /// Which caller attribute the bucket is derived from.
+    #[serde(default)]
+    pub by: RolloutKey,
+}
+
+/// The caller attribute a rollout buckets on.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RolloutKey {
+    /// Per user: the same principal gets the same answer on every replica.
+    #[default]
+    Principal,
+    /// Per tenant: a tenant's users are all in or all out.
+    Tenant,
+}
+
+/// A targeting override: matches when the caller's principal *or* tenant is listed.
+#[derive(Debug, Clone, Deserialize)]
+pub struct FlagRule {
+    #[serde(default)]
+    pub principals: Vec<String>,
+    #[serde(default)]
+    pub tenants: Vec<String>,
+    /// The answer for a matching caller.
+    pub enabled: bool,
+}
+
+impl FlagRule {
+    fn matches(&self, context: &FlagContext<'_>) -> bool {
+        context.principal.is_some_and(|p| self.principals.iter().any(|listed| listed == p))
+            || context.tenant.is_some_and(|t| self.tenants.iter().any(|listed| listed == t))
+    }
+}
+
+impl FlagsSection {
+    /// Rejects rollouts outside `[0, 100]` and rules that can never match.
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        for (name, spec) in &self.flags {
+            let err = |msg: String| ConfigError::validation(format!("[flags] '{name}': {msg}"));
+
+            if name.trim().is_empty() {
+                return Err(ConfigError::validation("[flags] flag names must not be empty"));
+            }
+            if let Some(rollout) = &spec.rollout
+                && !(0.0..=100.0).contains(&rollout.percent)
+            {
+                return Err(err(format!("rollout percent ({}) must be in [0, 100]", rollout.percent)));
+            }
+            for (index, rule) in spec.rules.iter().enumerate() {
+                if rule.principals.is_empty() && rule.tenants.is_empty() {
+                    return Err(err(format!("rule {index} lists no principals or tenants")));
+                }
+                if rule.principals.iter().chain(&rule.tenants).any(|id| id.trim().is_empty()) {
+                    return Err(err(format!("rule {index} lists an empty principal or tenant")));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl FlagSpec {
+    fn evaluate(&self, name: &str, context: &FlagContext<'_>) -> bool {
+        if !self.enabled {
+            return false;
+        }
+        if let Some(rule) = self.rules.iter().find(|rule| rule.matches(context)) {
+            return rule.enabled;
+        }
+        let Some(rollout) = &self.rollout else {
+            return true;
+        };
+        if rollout.percent >= 100.0 {
+            return true;
+        }
+        let key = match rollout.by {
+            RolloutKey::Principal => context.principal,
+            RolloutKey::Tenant => context.tenant,
+        };
+        key.is_some_and(|key| (bucket(name, key) as f64) < rollout.percent * (BUCKETS / 100) as f64)
+    }
+}
+
+/// Stable bucket in `[0, BUCKETS)` for `key` under flag `name` (64-bit FNV-1a), identical
+/// on every replica and across restarts.
+fn bucket(name: &str, key: &str) -> u64 {
+    let hash = name
+        .bytes()
+        .chain([0])
+        .chain(key.bytes())
+        .fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(0x0000_0100_0000_01b3)
+        });
+    hash % BUCKETS
+}
+
+/// Who a flag is being evaluated for. Handlers fill it from the authenticated caller.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FlagContext<'a> {
+    pub principal: Option<&'a str>,
+    pub tenant: Option<&'a str>,
+}
+
+impl<'a> FlagContext<'a> {
+    /// A context carrying only the caller's principal.
+    pub fn principal(principal: &'a str) -> Self {
+        Self { principal: Some(principal), tenant: None }
+    }
+
+    /// Adds the caller's tenant.
+    pub fn with_tenant(mut self, tenant: &'a str) -> Self {
+        self.tenant = Some(tenant);
+        self
+    }
+}
+
+/// Live flag table, swapped whole on every reload.
+///
+/// Unlike the catalog sections, flags are looked up by name at evaluation time, so a flag
+/// added by a push is live at once and a flag dropped from one evaluates as off.
+pub struct FlagRegistry {
+    flags: ArcSwap<HashMap<String, FlagSpec>>,
+}
+
+impl FlagRegistry {
+    /// Validates and resolves a `[flags]` section.
+    pub fn from_section(section: FlagsSection) -> Result<Self, ConfigError> {
+        section.validate()?;
+        Ok(Self { flags: ArcSwap::from_pointee(section.flags) })
+    }
+
+    /// Whether `name` is on for a caller with no principal or tenant — plain booleans and
+    /// full rollouts; partial rollouts and rules need [`is_enabled_for`](Self::is_enabled_for).
+    pub fn is_enabled(&self, name: &str) -> bool {
+        self.is_enabled_for(name, &FlagContext::default())
+    }
+
+    /// Whether `name` is on for `context`. Undeclared flags are off.
+    pub fn is_enabled_for(&self, name: &str, context: &FlagContext<'_>) -> bool {
+        self.flags.load().get(name).is_some_and(|spec| spec.evaluate(name, context))
+    }
+
+    /// A cloneable handle on one flag, for components that evaluate it per request.
+    pub fn flag(self: &Arc<Self>, name: impl Into<String>) -> Flag {
+        Flag(FlagSource::Live { registry: Arc::clone(self), name: name.into() })
+    }
+
+    /// Hot-applies a reloaded `[flags]` section.
+    pub fn apply(&self, section: FlagsSection) -> Result<(), ConfigError> {
+        section.validate()?;
+        let previous = self.flags.swap(Arc::new(section.flags));
+        let current = self.flags.load();
+        for name in current.keys().filter(|name| !previous.contains_key(*name)) {
+            info!(flag = %name, "[flags] flag added");
+        }
+        for name in previous.keys().filter(|name| !current.contains_key(*name)) {
+            info!(flag = %name, "[flags] flag removed — now off");
+        }
+        Ok(())
+    }
+}
+
+/// One named flag, evaluated on demand against the live registry — or a fixed answer, for
+/// deployments without a `[flags]` section and for tests.
+#[derive(Clone)]
+pub struct Flag(FlagSource);
+
+#[derive(Clone)]
+enum FlagSource {
+    Fixed(bool),
+    Live { registry: Arc<FlagRegistry>, name: String },
+}
+
+impl Flag {
+    /// A flag pinned to `enabled`, independent of any config.
+    pub fn fixed(enabled: bool) -> Self {
+        Self(FlagSource::Fixed(enabled))
+    }
+
+    /// See [`FlagRegistry::is_enabled`].
+    pub fn is_enabled(&self) -> bool {
+        self.is_enabled_for(&FlagContext::default())
+    }
+
+    /// See [`FlagRegistry::is_enabled_for`].
+    pub fn is_enabled_for(&self, context: &FlagContext<'_>) -> bool {
+        match &self.0 {
+            FlagSource::Fixed(enabled) => *enabled,
+            FlagSource::Live { registry, name } => registry.is_enabled_for(name, context),
+        }
+    }
+}
+
+impl fmt::Debug for Flag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.0 {
+            FlagSource::Fixed(enabled) => f.debug_tuple("Flag::fixed").field(enabled).finish(),
+            FlagSource::Live { name, .. } => f.debug_tuple("Flag").field(name).finish(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn buckets_are_stable_and_independent_per_flag() {
+        assert_eq!(bucket("a", "user-1"), bucket("a", "user-1"));
+        let differs = (0..100).any(|i| {
+            let user = format!("user-{i}");
+            bucket("a", &user) != bucket("b", &user)
+        });
+        assert!(differs);
+    }
+}
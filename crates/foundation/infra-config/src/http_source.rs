@@ -0,0 +1,119 @@
+//! Control-plane [`ConfigSource`]: long-polls an HTTP endpoint serving `infrastructure.toml`.
+
+use std::time::Duration;
+
+use reqwest::{header, Client, StatusCode};
+
+use crate::{
+    error::ConfigError,
+    source::{ConfigDocument, ConfigSource},
+};
+
+/// How long the server may hold a poll open when nothing has changed.
+const DEFAULT_WAIT: Duration = Duration::from_secs(55);
+/// Extra client-side allowance on top of the wait, so a held poll isn't cut off by our
+/// own timeout just before the server answers.
+const TIMEOUT_SLACK: Duration = Duration::from_secs(10);
+/// Delay before re-polling a server that answered instantly with an unchanged document —
+/// one that ignores the wait hint would otherwise be hammered in a hot loop.
+const MIN_POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Long-polls a config control plane, so one push reaches every deployment watching the
+/// same URL instead of one ConfigMap edit per deployment.
+///
+/// Protocol (plain HTTP, any server can implement it):
+///
+/// * `GET <url>` with `If-None-Match: "<current version>"` and `Prefer: wait=<secs>`;
+/// * the server holds the request until the document changes or the wait elapses;
+/// * `200` carries the document, versioned by its `ETag` (or by content hash without one);
+///   `304 Not Modified` means unchanged — poll again.
+///
+/// The first fetch (boot) sends no `If-None-Match` and expects the document at once.
+pub struct HttpSource {
+    client: Client,
+    url: String,
+    wait: Duration,
+}
+
+impl HttpSource {
+    pub fn new(url: impl Into<String>) -> Result<Self, ConfigError> {
+        Self::with_wait(url, DEFAULT_WAIT)
+    }
+
+    /// A source asking the server to hold each poll for up to `wait`.
+    pub fn with_wait(url: impl Into<String>, wait: Duration) -> Result<Self, ConfigError> {
+        let client = Client::builder()
+            .timeout(wait + TIMEOUT_SLACK)
+            .build()
+            .map_err(|e| ConfigError::Source(format!("build HTTP client: {e}")))?;
+        Ok(Self { client, url: url.into(), wait })
+    }
+
+    async fn poll(&self, current: Option<&str>) -> Result<Option<ConfigDocument>, ConfigError> {
+        let mut request = self.client.get(&self.url);
+        if let Some(version) = current {
+            request = request
+                .header(header::IF_NONE_MATCH, format!("\"{version}\""))
+                .header("Prefer", format!("wait={}", self.wait.as_secs()));
+        }
+        let response = request
+            .send()
+            .await
+            .map_err(|e| ConfigError::Source(format!("GET {}: {e}", self.url)))?;
+
+        match response.status() {
+            StatusCode::NOT_MODIFIED => Ok(None),
+            status if status.is_success() => {
+                let etag = response
+                    .headers()
+                    .get(header::ETAG)
+                    .and_then(|value| value.to_str().ok())
+                    .map(parse_etag);
+                let raw = response
+                    .text()
+                    .await
+                    .map_err(|e| ConfigError::Source(format!("read {}: {e}", self.url)))?;
+                Ok(Some(match etag {
+                    Some(version) => ConfigDocument { raw, version },
+                    None => ConfigDocument::from_content(raw),
+                }))
+            }
+            status => Err(ConfigError::Source(format!("GET {}: {status}", self.url))),
+        }
+    }
+}
+
+impl ConfigSource for HttpSource {
+    fn describe(&self) -> String {
+        self.url.clone()
+    }
+
+    async fn fetch(&mut self, current: Option<&str>) -> Result<ConfigDocument, ConfigError> {
+        loop {
+            let started = tokio::time::Instant::now();
+            match self.poll(current).await? {
+                Some(document) if current != Some(document.version.as_str()) => {
+                    return Ok(document);
+                }
+                _ => tokio::time::sleep_until(started + MIN_POLL_INTERVAL).await,
+            }
+        }
+    }
+}
+
+/// `"v42"` / `W/"v42"` → `v42`: the version is compared and echoed back, not the syntax.
+fn parse_etag(raw: &str) -> String {
+    raw.trim().trim_start_matches("W/").trim_matches('"').to_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn etag_quotes_and_weak_prefix_are_stripped() {
+        assert_eq!(parse_etag("\"v42\""), "v42");
+        assert_eq!(parse_etag("W/\"v42\""), "v42");
+        assert_eq!(parse_etag("v42"), "v42");
+    }
+}
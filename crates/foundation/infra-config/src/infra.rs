@@ -6,8 +6,8 @@ use std::sync::Arc;
 use tracing::warn;
 
 use crate::{
-    cache::CacheRegistry, error::ConfigError, reload::Reloadable, registry::ResilienceRegistry,
-    schema::InfrastructureConfig, telemetry::TelemetryRegistry, traffic::TrafficRegistry,
+    auth::AuthRegistry, cache::CacheRegistry, error::ConfigError, flags::FlagRegistry, reload::Reloadable,
+    registry::ResilienceRegistry, schema::InfrastructureConfig, telemetry::TelemetryRegistry, traffic::TrafficRegistry,
 };
 
 /// Owns one resolved registry per `infrastructure.toml` section and presents them as a
@@ -29,6 +29,8 @@ pub struct InfraRegistry {
     cache: Option<Arc<CacheRegistry>>,
     traffic: Option<Arc<TrafficRegistry>>,
     telemetry: Option<Arc<TelemetryRegistry>>,
+    auth: Option<Arc<AuthRegistry>>,
+    flags: Option<Arc<FlagRegistry>>,
 }
 
 impl InfraRegistry {
@@ -51,7 +53,17 @@ impl InfraRegistry {
             None => None,
         };
 
-        Ok(Self { resilience, cache, traffic, telemetry })
+        let auth = match config.auth {
+            Some(section) => Some(Arc::new(AuthRegistry::from_section(section)?)),
+            None => None,
+        };
+
+        let flags = match config.flags {
+            Some(section) => Some(Arc::new(FlagRegistry::from_section(section)?)),
+            None => None,
+        };
+
+        Ok(Self { resilience, cache, traffic, telemetry, auth, flags })
     }
 
     /// Shared resilience registry (always present).
@@ -76,6 +88,19 @@ impl InfraRegistry {
         self.telemetry.clone()
     }
 
+    /// Shared auth registry, if the deployment configured an `[auth]` section. The
+    /// serving binary builds the inbound token verifier from its boot values and reads
+    /// the live enforce/shadow flag per request.
+    pub fn auth(&self) -> Option<Arc<AuthRegistry>> {
+        self.auth.clone()
+    }
+
+    /// Shared feature-flag registry, if the deployment configured a `[flags]` section.
+    /// Handlers evaluate flags through it (or a [`Flag`](crate::Flag) handle) per request.
+    pub fn flags(&self) -> Option<Arc<FlagRegistry>> {
+        self.flags.clone()
+    }
+
     /// Hot-applies a freshly-parsed document to every live section (the reload entry point).
     ///
     /// Validates all sections first and bails before any mutation on failure.
@@ -106,6 +131,28 @@ impl InfraRegistry {
             (None, None) => {}
         }
 
+        match (&self.auth, config.auth) {
+            (Some(registry), Some(section)) => registry.apply(section)?,
+            (Some(_), None) => warn!(
+                "[auth] section removed from reloaded config — keeping previous values"
+            ),
+            (None, Some(_)) => warn!(
+                "[auth] section added at runtime — ignored (adding a section requires a restart)"
+            ),
+            (None, None) => {}
+        }
+
+        match (&self.flags, config.flags) {
+            (Some(registry), Some(section)) => registry.apply(section)?,
+            (Some(_), None) => warn!(
+                "[flags] section removed from reloaded config — keeping previous values"
+            ),
+            (None, Some(_)) => warn!(
+                "[flags] section added at runtime — ignored (adding a section requires a restart)"
+            ),
+            (None, None) => {}
+        }
+
         // Applied last: a bad log-filter directive can only surface here (it can't
         // be validated up front without a tracing dependency), and telemetry is
         // the one section whose apply has an external side effect (the live pipeline).
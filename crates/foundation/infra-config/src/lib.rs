@@ -4,42 +4,62 @@
 //! The pure crates own the *mechanism* — `resilience` (client-side Tower layers), `traffic`
 //! (server-side rate limiter) — plus their serde-able wire types. This crate owns the
 //! *policy plumbing* they must stay free of: file IO, TOML parsing, validation, fleet
-//! bindings, and `notify`-based hot-reload. It is **multi-tenant**: each infrastructure
+//! bindings, and hot-reload from a pluggable [`ConfigSource`] (the local file via `notify`,
+//! or a control plane long-polled over HTTP). It is **multi-tenant**: each infrastructure
 //! category is a `[section]` sharing one catalog shape ([`catalog`]), one watcher, and one
-//! fail-closed reload path. Today: `[resilience]`, `[cache]`, `[traffic]`.
+//! fail-closed reload path. Today: `[resilience]`, `[cache]`, `[traffic]`, plus the
+//! non-catalog `[telemetry]`, `[auth]` and `[flags]` sections.
 //!
 //! # Flow
 //!
 //! ```text
-//! infrastructure.toml ──load──▶ InfrastructureConfig ──resolve──▶ InfraRegistry
-//!                                       ▲                          ├─ ResilienceRegistry
-//!                                  notify file event               ├─ CacheRegistry
-//!                                  spawn_watcher ──reload──▶ apply()└─ TrafficRegistry
-//!                                  (single writer, fail-closed, all-sections-or-nothing)
+//! ConfigSource ──fetch──▶ ConfigDocument ──parse──▶ InfrastructureConfig ──resolve──▶ InfraRegistry
+//!  ├─ FileSource (notify)        (raw + version)                                     ├─ ResilienceRegistry
+//!  └─ HttpSource (long-poll)          │                                              ├─ CacheRegistry
+//!                                     └─ spawn_source ──▶ ConfigControl::apply() ───▶└─ TrafficRegistry
+//!                                  (single writer, fail-closed, all-sections-or-nothing;
+//!                                   version + last error reported, rollback to last good)
 //! ```
 //!
+//! [`spawn_watcher`] remains the minimal file-only path (no versioning, no rollback).
+//!
 //! See [`schema`] for the TOML shape and `examples/infrastructure.toml` for a full sample.
 
+pub mod auth;
 pub mod cache;
 pub mod catalog;
+pub mod control;
 pub mod error;
+pub mod flags;
+#[cfg(feature = "http-source")]
+pub mod http_source;
 pub mod infra;
 pub mod registry;
 pub mod reload;
 pub mod schema;
+pub mod source;
 pub mod telemetry;
 pub mod traffic;
 pub mod watcher;
 
+pub use auth::{AuthRegistry, AuthSection, PeerAuthSection};
 pub use cache::{CacheConfig, CacheProfile, CacheProfileSpec, CacheRegistry, CacheSection};
 pub use catalog::Catalog;
+pub use control::{ConfigControl, ConfigStatus};
 pub use error::ConfigError;
+pub use flags::{Flag, FlagContext, FlagRegistry, FlagRule, FlagSpec, FlagsSection, RolloutKey, RolloutSpec};
+#[cfg(feature = "http-source")]
+pub use http_source::HttpSource;
 pub use infra::InfraRegistry;
 pub use registry::ResilienceRegistry;
 pub use reload::Reloadable;
 pub use schema::{InfrastructureConfig, ResilienceSection};
+pub use source::{content_version, spawn_source, ConfigDocument, ConfigSource};
 pub use telemetry::{
     TelemetryRegistry, TelemetrySamplingSpec, TelemetrySection, TelemetrySettings, TelemetrySink,
 };
-pub use traffic::{TrafficRegistry, TrafficSection};
-pub use watcher::{load_from_path, spawn_watcher};
+pub use traffic::{
+    ConcurrencySection, TrafficBackendKind, TrafficBackendSink, TrafficBackendSpec, TrafficBackendTopology,
+    TrafficRegistry, TrafficSection,
+};
+pub use watcher::{load_from_path, spawn_watcher, FileSource};
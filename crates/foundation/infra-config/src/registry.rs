@@ -18,9 +18,9 @@ use crate::{
 /// The *topology* — which profiles exist and which dependency binds to which — is fixed at
 /// construction. Tower layers capture a [`ResilienceProfile`]'s shared handles when they're
 /// built, so re-binding a dependency would require rebuilding those layers and can't be done
-/// in flight. What [`apply`](Self::apply) hot-swaps is each profile's *contents* (timeout +
-/// circuit-breaker thresholds), which is the incident-critical path. Adding/removing profiles
-/// or changing bindings needs a restart.
+/// in flight. What [`apply`](Self::apply) hot-swaps is each profile's *contents* (timeout,
+/// circuit-breaker thresholds, retry attempts/backoff/budget), which is the incident-critical
+/// path. Adding/removing profiles or changing bindings needs a restart.
 pub struct ResilienceRegistry {
     profiles: HashMap<String, ResilienceProfile>,
     bindings: HashMap<String, String>,
@@ -85,11 +85,7 @@ impl ResilienceRegistry {
 
         for (name, profile) in &self.profiles {
             match section.profiles.get(name) {
-                Some(spec) => {
-                    // Retry config is returned for callers that rebuild the retry layer;
-                    // timeout + circuit-breaker swaps take effect immediately.
-                    let _new_retry = profile.apply(spec.clone());
-                }
+                Some(spec) => profile.apply(spec.clone()),
                 None => warn!(
                     profile = %name,
                     "profile absent from reloaded config — keeping previous values (topology change requires restart)"
@@ -20,8 +20,8 @@ use resilience::{retry::backoff::spec::BackoffSpec, ResilienceProfileSpec};
 use serde::Deserialize;
 
 use crate::{
-    cache::CacheSection, catalog::validate_bindings, error::ConfigError,
-    telemetry::TelemetrySection, traffic::TrafficSection,
+    auth::AuthSection, cache::CacheSection, catalog::validate_bindings, error::ConfigError,
+    flags::FlagsSection, telemetry::TelemetrySection, traffic::TrafficSection,
 };
 
 /// Top-level `infrastructure.toml` document.
@@ -45,6 +45,16 @@ pub struct InfrastructureConfig {
     /// deployments that don't hot-tune telemetry.
     #[serde(default)]
     pub telemetry: Option<TelemetrySection>,
+
+    /// Inbound caller authentication (edge-token verifier + enforce/shadow flag).
+    /// Absent in deployments that don't gate their gRPC surface in the runtime.
+    #[serde(default)]
+    pub auth: Option<AuthSection>,
+
+    /// Hot-reloadable feature flags. Absent in deployments that don't toggle behaviour
+    /// from config.
+    #[serde(default)]
+    pub flags: Option<FlagsSection>,
 }
 
 impl InfrastructureConfig {
@@ -61,6 +71,12 @@ impl InfrastructureConfig {
         if let Some(telemetry) = &self.telemetry {
             telemetry.validate()?;
         }
+        if let Some(auth) = &self.auth {
+            auth.validate()?;
+        }
+        if let Some(flags) = &self.flags {
+            flags.validate()?;
+        }
         Ok(())
     }
 }
@@ -133,5 +149,24 @@ fn validate_profile(name: &str, spec: &ResilienceProfileSpec) -> Result<(), Conf
         }
     }
 
+    if let Some(budget) = &spec.retry.budget {
+        if !(0.0..=1.0).contains(&budget.ratio) {
+            return Err(err(format!("retry budget ratio ({}) must be in [0, 1]", budget.ratio)));
+        }
+        if budget.max_tokens == 0 {
+            return Err(err("retry budget max_tokens must be > 0".into()));
+        }
+    }
+
+    if spec.bulkhead.is_some_and(|bulkhead| bulkhead.max_concurrent == 0) {
+        return Err(err("bulkhead max_concurrent must be > 0".into()));
+    }
+
+    if let Some(hedge) = &spec.hedge
+        && !(f64::MIN_POSITIVE..1.0).contains(&hedge.percentile)
+    {
+        return Err(err(format!("hedge percentile ({}) must be in (0, 1)", hedge.percentile)));
+    }
+
     Ok(())
 }
@@ -0,0 +1,120 @@
+//! Pluggable config sources: where the raw document comes from, decoupled from how it is
+//! applied.
+//!
+//! A [`ConfigSource`] only *fetches* versioned documents — the local file
+//! ([`FileSource`](crate::FileSource)) or a control-plane endpoint long-polled over HTTP
+//! ([`HttpSource`](crate::HttpSource), `http-source` feature). Applying them stays with
+//! [`ConfigControl`](crate::ConfigControl), so every source inherits the same single-writer,
+//! fail-closed, all-sections-or-nothing reload path.
+
+use std::{future::Future, sync::Arc, time::Duration};
+
+use tracing::warn;
+
+use crate::{control::ConfigControl, error::ConfigError, reload::Reloadable};
+
+/// First delay after a failed fetch; doubled on each consecutive failure.
+const RETRY_BASE: Duration = Duration::from_secs(1);
+/// Cap on the delay between fetch attempts while a source stays unreachable.
+const RETRY_MAX: Duration = Duration::from_secs(30);
+
+/// A raw `infrastructure.toml` document plus the version it was published under.
+///
+/// The version is the source's change token — an HTTP `ETag` for a control plane, a content
+/// hash for a file — reported on the admin port so an operator can see which push a replica
+/// is running.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigDocument {
+    pub raw: String,
+    pub version: String,
+}
+
+impl ConfigDocument {
+    /// A document versioned by its content ([`content_version`]), for sources without a
+    /// change token of their own.
+    pub fn from_content(raw: String) -> Self {
+        let version = content_version(&raw);
+        Self { raw, version }
+    }
+}
+
+/// Where raw config documents come from.
+///
+/// Implementations only fetch; parsing, validation and the swap happen in
+/// [`ConfigControl::apply`], behind its single writer.
+pub trait ConfigSource: Send + 'static {
+    /// Human-readable origin (`file:/etc/infra/infrastructure.toml`, the control-plane URL),
+    /// reported alongside the version.
+    fn describe(&self) -> String;
+
+    /// Resolves with a document whose version differs from `current` — at once when
+    /// `current` is `None` (boot), otherwise once the source has changed. Sources may
+    /// return early on a spurious wake-up; the driver skips an unchanged version.
+    fn fetch(
+        &mut self,
+        current: Option<&str>,
+    ) -> impl Future<Output = Result<ConfigDocument, ConfigError>> + Send;
+}
+
+/// Stable content hash (64-bit FNV-1a, hex) used as the version of a document whose source
+/// has no change token. Identical bytes hash identically on every replica, so the fleet can
+/// be compared by version.
+pub fn content_version(raw: &str) -> String {
+    let hash = raw.bytes().fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0000_0100_0000_01b3)
+    });
+    format!("{hash:016x}")
+}
+
+/// Drives `source` for the process lifetime, handing every new document to `control`.
+///
+/// The document the registry booted from must already be recorded on `control` (see
+/// [`ConfigControl::booted`]); the loop starts by waiting for a version past it. A failed
+/// fetch is logged and retried with capped exponential backoff — the running config stays
+/// live throughout. A rejected document is not re-fetched until the source changes again.
+pub fn spawn_source<S: ConfigSource, R: Reloadable>(
+    mut source: S,
+    control: Arc<ConfigControl<R>>,
+) -> tokio::task::JoinHandle<()> {
+    tokio::spawn(async move {
+        // The source's view, not the control's: after a rollback the two differ, and
+        // polling with the live version would re-fetch the rolled-back push forever.
+        let mut seen = Some(control.status().version);
+        let mut retry = RETRY_BASE;
+
+        loop {
+            let document = match source.fetch(seen.as_deref()).await {
+                Ok(document) => document,
+                Err(e) => {
+                    warn!(error = %e, source = %source.describe(), retry_in = ?retry,
+                        "failed to fetch infrastructure config — keeping previous");
+                    tokio::time::sleep(retry).await;
+                    retry = (retry * 2).min(RETRY_MAX);
+                    continue;
+                }
+            };
+            retry = RETRY_BASE;
+
+            if seen.as_deref() == Some(document.version.as_str()) {
+                continue;
+            }
+            seen = Some(document.version.clone());
+
+            // `apply` logs the outcome (swapped, rejected, or skipped after a rollback);
+            // a rejection needs no further handling here — the previous config stays live.
+            let _ = control.apply(document);
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn content_version_is_stable_and_content_sensitive() {
+        assert_eq!(content_version(""), "cbf29ce484222325");
+        assert_eq!(content_version("a = 1"), content_version("a = 1"));
+        assert_ne!(content_version("a = 1"), content_version("a = 2"));
+    }
+}
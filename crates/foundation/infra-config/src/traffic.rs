@@ -17,15 +17,57 @@
 //! burst = 10
 //! scope = "per_caller"
 //!
+//! [traffic.profiles.comment-flood]
+//! rps       = 5
+//! burst     = 5
+//! scope     = "per_attribute"
+//! attribute = "post_id"
+//!
 //! [traffic.bindings]
-//! "/post.PostService/CreatePost" = "write-tight"
+//! "/post.PostService/CreatePost"             = "write-tight"
+//! "/comment.v1.CommentService/CreateComment" = "comment-flood"
+//! ```
+//!
+//! Scopes: `per_method` (default), `per_caller`, `per_ip`, `per_tenant`, and `per_attribute`,
+//! which keys on the request field named by `attribute` — the field must be declared for the
+//! bound method by the serving binary (see `transport`'s `TrafficAttributes`).
+//!
+//! `distributed` profiles charge a fleet-global budget through the coordination store named by
+//! an optional `[traffic.backend]` block. This crate only parses it; the serving binary builds
+//! the backend and registers a [`TrafficBackendSink`] so a changed block is hot-swapped:
+//!
+//! ```toml
+//! [traffic.backend]
+//! kind       = "redis"
+//! hosts      = ["traffic-redis:6379"]
+//! timeout_ms = 50
+//! ```
+//!
+//! An optional `[traffic.concurrency]` block caps the replica's requests *in flight* with an
+//! adaptive limit (see [`traffic::concurrency`]), and ranks methods for shedding. Unlisted
+//! methods are `normal`; gRPC health checks are always `critical`:
+//!
+//! ```toml
+//! [traffic.concurrency]
+//! algorithm            = "aimd"
+//! max_limit            = 400
+//! latency_threshold_ms = 250
+//!
+//! [traffic.concurrency.priorities]
+//! "/account.v1.AccountService/GetAccountStatus" = "critical"
+//! "/timeline.v1.TimelineService/GetAudioFeed"   = "sheddable"
 //! ```
 
 use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
 
+use arc_swap::ArcSwap;
 use serde::Deserialize;
 use tracing::warn;
-use traffic::{Mode, TrafficProfile, TrafficProfileSpec};
+use traffic::{
+    ConcurrencyLimiter, ConcurrencySpec, Mode, Priority, Scope, TrafficProfile,
+    TrafficProfileSpec,
+};
 
 use crate::{
     catalog::{validate_bindings, Catalog},
@@ -39,6 +81,10 @@ fn default_traffic_profile() -> String {
     "standard".to_string()
 }
 
+fn default_backend_timeout_ms() -> u64 {
+    50
+}
+
 /// The `[traffic]` section: a rate-limit-profile catalog plus per-method bindings.
 #[derive(Debug, Clone, Deserialize)]
 pub struct TrafficSection {
@@ -53,11 +99,122 @@ pub struct TrafficSection {
     /// means *every* method is limited (unbound ≠ unlimited; fail-closed by default).
     #[serde(default = "default_traffic_profile")]
     pub default_profile: String,
+
+    /// Coordination store for `distributed` profiles. Absent → they degrade to the local
+    /// limiter.
+    #[serde(default)]
+    pub backend: Option<TrafficBackendSpec>,
+
+    /// Adaptive in-flight limit for the whole replica. Absent → in-flight requests are
+    /// bounded only by the rate profiles.
+    #[serde(default)]
+    pub concurrency: Option<ConcurrencySection>,
+}
+
+/// The `[traffic.concurrency]` block: the limiter's dials plus the shedding order.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct ConcurrencySection {
+    #[serde(flatten)]
+    pub limits: ConcurrencySpec,
+
+    /// Maps an inbound gRPC method path to its shedding class; unlisted methods are `normal`.
+    #[serde(default)]
+    pub priorities: HashMap<String, Priority>,
+}
+
+impl ConcurrencySection {
+    fn validate(&self) -> Result<(), ConfigError> {
+        let limits = &self.limits;
+        let invalid = |rule: &str| {
+            Err(ConfigError::validation(format!("[traffic.concurrency] {rule}")))
+        };
+        if limits.min_limit == 0 {
+            return invalid("min_limit must be >= 1");
+        }
+        if !(limits.min_limit <= limits.initial_limit && limits.initial_limit <= limits.max_limit) {
+            return invalid("requires min_limit <= initial_limit <= max_limit");
+        }
+        if limits.latency_threshold_ms == 0 {
+            return invalid("latency_threshold_ms must be > 0");
+        }
+        if !(0.5..1.0).contains(&limits.backoff_ratio) {
+            return invalid("backoff_ratio must be in [0.5, 1)");
+        }
+        if !(1.0..=f64::MAX).contains(&limits.tolerance) {
+            return invalid("tolerance must be >= 1");
+        }
+        if !(f64::MIN_POSITIVE..=1.0).contains(&limits.sheddable_share) {
+            return invalid("sheddable_share must be in (0, 1]");
+        }
+        Ok(())
+    }
+}
+
+/// The `[traffic.backend]` block: where `distributed` profiles lease their global budget.
+///
+/// Only the address and dials live here — credentials stay in the pod environment and are
+/// read by the serving binary when it connects.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct TrafficBackendSpec {
+    /// Store implementation.
+    pub kind: TrafficBackendKind,
+    /// `host:port` seeds. Standalone uses the first entry; cluster treats all as seed nodes.
+    pub hosts: Vec<String>,
+    /// Deployment topology of the store.
+    #[serde(default)]
+    pub topology: TrafficBackendTopology,
+    /// Connect over TLS (managed Redis enforces transit encryption).
+    #[serde(default)]
+    pub tls: bool,
+    /// Per-claim deadline. Kept tight: a slow claim stalls the request it is admitting, and
+    /// a timed-out claim falls back per the profile's `on_backend_error`.
+    #[serde(default = "default_backend_timeout_ms")]
+    pub timeout_ms: u64,
+}
+
+/// Store implementation behind `[traffic.backend]`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TrafficBackendKind {
+    /// The `traffic-redis` lease backend.
+    Redis,
+}
+
+/// Topology of the `[traffic.backend]` store.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TrafficBackendTopology {
+    #[default]
+    Standalone,
+    Cluster,
+}
+
+impl TrafficBackendSpec {
+    fn validate(&self) -> Result<(), ConfigError> {
+        if self.hosts.iter().all(|host| host.trim().is_empty()) {
+            return Err(ConfigError::validation(
+                "[traffic.backend] hosts must name at least one host:port",
+            ));
+        }
+        if self.timeout_ms == 0 {
+            return Err(ConfigError::validation("[traffic.backend] timeout_ms must be > 0"));
+        }
+        Ok(())
+    }
+}
+
+/// Rebuilds the live quota backend when `[traffic.backend]` changes on reload.
+///
+/// Implemented by the serving binary (this crate links no Redis client) and registered via
+/// [`TrafficRegistry::set_backend_sink`] once the boot-time backend is connected.
+pub trait TrafficBackendSink: Send + Sync + 'static {
+    fn apply(&self, spec: &TrafficBackendSpec) -> Result<(), ConfigError>;
 }
 
 impl TrafficSection {
     /// Enforces invariants the type system can't: references resolve, quotas are positive,
-    /// and (Step 1) distributed mode is rejected rather than silently under-enforced.
+    /// distributed profiles carry a lease window, `per_attribute` profiles name their field,
+    /// and the backend and concurrency blocks are usable.
     /// Run before resolving and before every hot-swap (fail-closed).
     pub fn validate(&self) -> Result<(), ConfigError> {
         validate_bindings(SECTION, &self.profiles, &self.bindings, &self.default_profile)?;
@@ -74,13 +231,26 @@ impl TrafficSection {
                 )));
             }
             // Distributed mode requires a lease window; without it the backend can't size
-            // its per-window global budget. (The backend itself lives in `traffic-redis`
-            // and is wired into the transport layer by the serving binary.)
+            // its per-window global budget.
             if matches!(spec.mode, Mode::Distributed) && !matches!(spec.lease_ms, Some(ms) if ms > 0) {
                 return Err(ConfigError::validation(format!(
                     "[traffic] profile '{name}': mode = \"distributed\" requires lease_ms > 0"
                 )));
             }
+            if matches!(spec.scope, Scope::PerAttribute)
+                && !matches!(&spec.attribute, Some(field) if !field.is_empty())
+            {
+                return Err(ConfigError::validation(format!(
+                    "[traffic] profile '{name}': scope = \"per_attribute\" requires attribute"
+                )));
+            }
+        }
+
+        if let Some(backend) = &self.backend {
+            backend.validate()?;
+        }
+        if let Some(concurrency) = &self.concurrency {
+            concurrency.validate()?;
         }
 
         Ok(())
@@ -90,6 +260,12 @@ impl TrafficSection {
 /// The boot-time-resolved set of live rate-limit profiles plus the binding table.
 pub struct TrafficRegistry {
     catalog: Catalog<TrafficProfile>,
+    backend: Mutex<Option<TrafficBackendSpec>>,
+    // Written once (set_backend_sink) and read on each reload — same shape as the telemetry
+    // sink.
+    backend_sink: Mutex<Option<Arc<dyn TrafficBackendSink>>>,
+    concurrency: Option<ConcurrencyLimiter>,
+    priorities: ArcSwap<HashMap<String, Priority>>,
 }
 
 impl TrafficRegistry {
@@ -103,12 +279,47 @@ impl TrafficRegistry {
             .iter()
             .map(|(name, spec)| (name.clone(), spec.resolve()))
             .collect();
+        let (concurrency, priorities) = match section.concurrency {
+            Some(block) => (Some(block.limits.resolve()), block.priorities),
+            None => (None, HashMap::new()),
+        };
 
         Ok(Self {
             catalog: Catalog::new(profiles, section.bindings, section.default_profile),
+            backend: Mutex::new(section.backend),
+            backend_sink: Mutex::new(None),
+            concurrency,
+            priorities: ArcSwap::from_pointee(priorities),
         })
     }
 
+    /// The current `[traffic.backend]` block, if one is configured.
+    pub fn backend(&self) -> Option<TrafficBackendSpec> {
+        self.backend.lock().expect("traffic backend mutex poisoned").clone()
+    }
+
+    /// Registers the sink that rebuilds the live backend on reload. Unlike the telemetry
+    /// sink it does not apply the current block — the binary connects the boot-time backend
+    /// itself so a bad address fails the boot.
+    pub fn set_backend_sink(&self, sink: Arc<dyn TrafficBackendSink>) {
+        *self.backend_sink.lock().expect("traffic backend sink mutex poisoned") = Some(sink);
+    }
+
+    /// The replica's adaptive concurrency limiter, when `[traffic.concurrency]` is configured.
+    pub fn concurrency(&self) -> Option<ConcurrencyLimiter> {
+        self.concurrency.clone()
+    }
+
+    /// The shedding class of a gRPC `method`; unlisted methods are [`Priority::Normal`].
+    pub fn priority_for(&self, method: &str) -> Priority {
+        self.priorities.load().get(method).copied().unwrap_or_default()
+    }
+
+    /// Whether any profile is currently in `distributed` mode.
+    pub fn has_distributed(&self) -> bool {
+        self.catalog.iter().any(|(_, profile)| profile.is_distributed())
+    }
+
     /// Returns the live profile bound to a gRPC `method`, falling back to the default.
     pub fn profile_for(&self, method: &str) -> TrafficProfile {
         self.catalog.profile_for(method)
@@ -145,9 +356,14 @@ impl TrafficRegistry {
     ///
     /// Validates first and bails before any mutation on failure. Only profiles known at
     /// boot are updated; a quota change rebuilds that profile's limiter (see
-    /// [`traffic::TrafficProfile::apply`]).
+    /// [`traffic::TrafficProfile::apply`]). A changed `[traffic.backend]` block is pushed to
+    /// the registered sink before any profile is touched, so a rejected push leaves the whole
+    /// section at its previous values. `[traffic.concurrency]` dials and priorities swap in
+    /// place, keeping the discovered limit.
     pub fn apply(&self, section: TrafficSection) -> Result<(), ConfigError> {
         section.validate()?;
+        self.apply_backend(section.backend)?;
+        self.apply_concurrency(section.concurrency);
 
         for (name, profile) in self.catalog.iter() {
             match section.profiles.get(name) {
@@ -162,4 +378,46 @@ impl TrafficRegistry {
 
         Ok(())
     }
+
+    fn apply_concurrency(&self, next: Option<ConcurrencySection>) {
+        match (&self.concurrency, next) {
+            (Some(limiter), Some(next)) => {
+                limiter.apply(&next.limits);
+                self.priorities.store(Arc::new(next.priorities));
+            }
+            (None, None) => {}
+            (Some(_), None) => warn!(
+                section = SECTION,
+                "[traffic.concurrency] removed from reloaded config — keeping the live limiter"
+            ),
+            (None, Some(_)) => warn!(
+                section = SECTION,
+                "[traffic.concurrency] added at runtime — ignored (adding a limiter requires a restart)"
+            ),
+        }
+    }
+
+    fn apply_backend(&self, next: Option<TrafficBackendSpec>) -> Result<(), ConfigError> {
+        let mut current = self.backend.lock().expect("traffic backend mutex poisoned");
+        match (current.as_ref(), next) {
+            (Some(live), Some(next)) if *live != next => {
+                if let Some(sink) =
+                    self.backend_sink.lock().expect("traffic backend sink mutex poisoned").as_ref()
+                {
+                    sink.apply(&next)?;
+                }
+                *current = Some(next);
+            }
+            (Some(_), Some(_)) | (None, None) => {}
+            (Some(_), None) => warn!(
+                section = SECTION,
+                "[traffic.backend] removed from reloaded config — keeping the live backend"
+            ),
+            (None, Some(_)) => warn!(
+                section = SECTION,
+                "[traffic.backend] added at runtime — ignored (adding a backend requires a restart)"
+            ),
+        }
+        Ok(())
+    }
 }
@@ -1,4 +1,5 @@
-//! `notify`-based hot-reload: a single-writer task that re-applies the config on file change.
+//! `notify`-based hot-reload: a single-writer task that re-applies the config on file change,
+//! and the [`FileSource`] flavour of it for the versioned [`ConfigSource`] path.
 
 use std::{
     path::{Path, PathBuf},
@@ -9,7 +10,12 @@ use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
 use tokio::sync::mpsc;
 use tracing::{error, info, warn};
 
-use crate::{error::ConfigError, reload::Reloadable, schema::InfrastructureConfig};
+use crate::{
+    error::ConfigError,
+    reload::Reloadable,
+    schema::InfrastructureConfig,
+    source::{ConfigDocument, ConfigSource},
+};
 
 /// Reads, parses, and validates (all sections) a config file in one shot.
 pub fn load_from_path(path: &Path) -> Result<InfrastructureConfig, ConfigError> {
@@ -77,6 +83,66 @@ pub fn spawn_watcher<R: Reloadable>(
     Ok(watcher)
 }
 
+/// The local `infrastructure.toml` as a [`ConfigSource`], versioned by content hash.
+///
+/// Same watch semantics as [`spawn_watcher`] (parent directory, so K8s ConfigMap symlink
+/// swaps are seen; bursts coalesced), but it only fetches — applying goes through
+/// [`ConfigControl`](crate::ConfigControl), which adds version reporting and rollback. The
+/// `notify` guard lives inside the source, so the watch lasts as long as the task driving it.
+pub struct FileSource {
+    path: PathBuf,
+    changes: mpsc::UnboundedReceiver<()>,
+    /// A change was seen but not yet read back (the read failed, e.g. mid-swap), so the
+    /// next fetch re-reads instead of waiting for another event.
+    unread: bool,
+    _watcher: RecommendedWatcher,
+}
+
+impl FileSource {
+    /// Starts watching `path`'s parent directory. Changes made from here on are seen by
+    /// [`fetch`](ConfigSource::fetch), including ones landing before the first call.
+    pub fn new(path: PathBuf) -> Result<Self, ConfigError> {
+        let (tx, changes) = mpsc::unbounded_channel::<()>();
+        let mut watcher =
+            notify::recommended_watcher(move |res: notify::Result<Event>| match res {
+                Ok(event) if is_relevant(&event.kind) => {
+                    let _ = tx.send(());
+                }
+                Ok(_) => {}
+                Err(e) => error!(error = %e, "infrastructure config watch error"),
+            })?;
+        let watch_dir = path.parent().unwrap_or_else(|| Path::new(".")).to_path_buf();
+        watcher.watch(&watch_dir, RecursiveMode::NonRecursive)?;
+
+        Ok(Self { path, changes, unread: false, _watcher: watcher })
+    }
+}
+
+impl ConfigSource for FileSource {
+    fn describe(&self) -> String {
+        format!("file:{}", self.path.display())
+    }
+
+    async fn fetch(&mut self, current: Option<&str>) -> Result<ConfigDocument, ConfigError> {
+        loop {
+            if current.is_some() && !self.unread {
+                if self.changes.recv().await.is_none() {
+                    return Err(ConfigError::Source("file watch closed".into()));
+                }
+                while self.changes.try_recv().is_ok() {}
+            }
+            self.unread = true;
+            let document = ConfigDocument::from_content(std::fs::read_to_string(&self.path)?);
+            self.unread = false;
+            // Sibling files in the watched directory wake us too; only a changed document
+            // counts.
+            if current != Some(document.version.as_str()) {
+                return Ok(document);
+            }
+        }
+    }
+}
+
 /// File create/modify/remove are the events that can change config contents; access/other
 /// events are ignored to avoid needless reloads.
 fn is_relevant(kind: &EventKind) -> bool {
@@ -0,0 +1,103 @@
+//! Auth section: defaults, fail-closed validation, hot-reloadable enforce flag.
+
+use infra_config::{InfraRegistry, InfrastructureConfig, Reloadable};
+
+const SAMPLE: &str = r#"
+[resilience]
+default_profile = "standard"
+[resilience.profiles.standard]
+timeout = { duration_ms = 10000 }
+circuit_breaker = { failure_threshold = 5, success_threshold = 2, open_duration_ms = 30000, half_open_max_calls = 1 }
+retry = { max_attempts = 3, backoff = { kind = "exponential", base_ms = 50, max_ms = 10000, jitter = "full" } }
+
+[auth]
+enforce  = false
+jwks_url = "http://auth-server:8081/.well-known/jwks.json"
+audience = "core-platform"
+"#;
+
+fn registry(toml: &str) -> InfraRegistry {
+    InfraRegistry::from_config(InfrastructureConfig::from_toml(toml).unwrap()).unwrap()
+}
+
+#[test]
+fn resolves_verifier_settings_and_shadow_flag() {
+    let reg = registry(SAMPLE);
+    let auth = reg.auth().expect("[auth] configured");
+
+    assert!(!auth.enforce());
+    assert_eq!(auth.jwks_url(), "http://auth-server:8081/.well-known/jwks.json");
+    assert_eq!(auth.issuer(), None);
+    assert_eq!(auth.audience(), Some("core-platform"));
+}
+
+#[test]
+fn enforce_defaults_to_true() {
+    let reg = registry(&SAMPLE.replace("enforce  = false\n", ""));
+    assert!(reg.auth().unwrap().enforce());
+}
+
+#[test]
+fn absent_section_leaves_auth_unconfigured() {
+    let without = SAMPLE.split("[auth]").next().unwrap();
+    assert!(registry(without).auth().is_none());
+}
+
+#[test]
+fn rejects_empty_jwks_url() {
+    let bad = SAMPLE.replace("http://auth-server:8081/.well-known/jwks.json", " ");
+    let err = InfraRegistry::from_config(InfrastructureConfig::from_toml(&bad).unwrap())
+        .err()
+        .expect("expected error");
+    assert!(err.to_string().contains("jwks_url must not be empty"), "got: {err}");
+}
+
+#[test]
+fn hot_reload_flips_shadow_to_enforce() {
+    let reg = registry(SAMPLE);
+    let auth = reg.auth().unwrap();
+    assert!(!auth.enforce());
+
+    reg.reload(&SAMPLE.replace("enforce  = false", "enforce  = true")).unwrap();
+    assert!(auth.enforce());
+}
+
+#[test]
+fn verifier_changes_are_deferred_to_restart() {
+    let reg = registry(SAMPLE);
+    let auth = reg.auth().unwrap();
+
+    reg.reload(&SAMPLE.replace("auth-server:8081", "elsewhere:8081")).unwrap();
+    assert_eq!(auth.jwks_url(), "http://auth-server:8081/.well-known/jwks.json");
+}
+
+const PEERS: &str = r#"
+[auth.peers]
+audience       = "core-platform-peers"
+namespace      = "default"
+account_prefix = "prod-"
+"#;
+
+#[test]
+fn peers_default_to_the_in_cluster_issuer() {
+    let reg = registry(&format!("{SAMPLE}{PEERS}"));
+    let auth = reg.auth().unwrap();
+    let peers = auth.peers().expect("[auth.peers] configured");
+
+    assert_eq!(peers.jwks_url, None);
+    assert_eq!(peers.audience, "core-platform-peers");
+    assert_eq!(peers.namespace, "default");
+    assert_eq!(peers.account_prefix, "prod-");
+
+    let without = registry(SAMPLE);
+    assert!(without.auth().unwrap().peers().is_none());
+}
+
+#[test]
+fn rejects_peers_without_a_namespace() {
+    let bad = format!("{SAMPLE}{}", PEERS.replace("\"default\"", "\"\""));
+    let err = InfraRegistry::from_config(InfrastructureConfig::from_toml(&bad).unwrap())
+        .err()
+        .expect("expected error");
+    assert!(err.to_string().contains("namespace must not be empty"), "got: {err}");
+}
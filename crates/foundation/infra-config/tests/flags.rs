@@ -0,0 +1,123 @@
+//! Flags section: kill switch, targeting rules, stable percentage rollouts, hot reload.
+
+use infra_config::{Flag, FlagContext, InfraRegistry, InfrastructureConfig, Reloadable};
+
+const SAMPLE: &str = r#"
+[resilience]
+default_profile = "standard"
+[resilience.profiles.standard]
+timeout = { duration_ms = 10000 }
+circuit_breaker = { failure_threshold = 5, success_threshold = 2, open_duration_ms = 30000, half_open_max_calls = 1 }
+retry = { max_attempts = 3, backoff = { kind = "exponential", base_ms = 50, max_ms = 10000, jitter = "full" } }
+
+[flags."post.dual_publish_legacy_topics"]
+enabled = true
+
+[flags."media.upload_dedup"]
+enabled = true
+rollout = { percent = 25, by = "principal" }
+rules = [
+  { tenants = ["internal"], enabled = true },
+  { principals = ["opted-out"], enabled = false },
+]
+"#;
+
+fn registry(toml: &str) -> InfraRegistry {
+    InfraRegistry::from_config(InfrastructureConfig::from_toml(toml).unwrap()).unwrap()
+}
+
+fn admitted(flags: &infra_config::FlagRegistry, name: &str) -> usize {
+    (0..1_000)
+        .filter(|i| flags.is_enabled_for(name, &FlagContext::principal(&format!("user-{i}"))))
+        .count()
+}
+
+#[test]
+fn boolean_flags_and_undeclared_flags() {
+    let flags = registry(SAMPLE).flags().expect("[flags] configured");
+
+    assert!(flags.is_enabled("post.dual_publish_legacy_topics"));
+    assert!(!flags.is_enabled("no.such.flag"), "undeclared flags are off");
+}
+
+#[test]
+fn rules_take_precedence_over_the_rollout() {
+    let flags = registry(SAMPLE).flags().unwrap();
+    let name = "media.upload_dedup";
+
+    assert!(flags.is_enabled_for(name, &FlagContext::principal("anyone").with_tenant("internal")));
+    assert!(!flags.is_enabled_for(name, &FlagContext::principal("opted-out")));
+    assert!(flags.is_enabled_for(name, &FlagContext::principal("opted-out").with_tenant("internal")),
+        "the first matching rule wins");
+    assert!(!flags.is_enabled(name), "no principal: outside a partial rollout");
+}
+
+#[test]
+fn rollout_admits_a_stable_share_of_principals() {
+    let flags = registry(SAMPLE).flags().unwrap();
+    let name = "media.upload_dedup";
+
+    let admitted_now = admitted(&flags, name);
+    assert!((180..=320).contains(&admitted_now), "25% of 1000, got {admitted_now}");
+    assert_eq!(admitted(&flags, name), admitted_now, "same principals, same answers");
+
+    let user = (0..1_000)
+        .map(|i| format!("user-{i}"))
+        .find(|u| flags.is_enabled_for(name, &FlagContext::principal(u)))
+        .unwrap();
+    let widened = registry(&SAMPLE.replace("percent = 25", "percent = 60")).flags().unwrap();
+    assert!(widened.is_enabled_for(name, &FlagContext::principal(&user)), "widening only adds callers");
+    assert_eq!(admitted(&registry(&SAMPLE.replace("percent = 25", "percent = 100")).flags().unwrap(), name), 1_000);
+}
+
+#[test]
+fn kill_switch_overrides_rules() {
+    let off = SAMPLE.replace("enabled = true\nrollout", "enabled = false\nrollout");
+    let flags = registry(&off).flags().unwrap();
+    assert!(!flags.is_enabled_for("media.upload_dedup", &FlagContext::default().with_tenant("internal")));
+}
+
+#[test]
+fn absent_section_leaves_flags_unconfigured() {
+    let without = SAMPLE.split("[flags.").next().unwrap();
+    assert!(registry(without).flags().is_none());
+}
+
+#[test]
+fn rejects_out_of_range_rollouts_and_empty_rules() {
+    for (bad, expected) in [
+        (SAMPLE.replace("percent = 25", "percent = 120"), "rollout percent (120) must be in [0, 100]"),
+        (SAMPLE.replace(r#"principals = ["opted-out"]"#, "principals = []"), "rule 1 lists no principals or tenants"),
+    ] {
+        let err = InfraRegistry::from_config(InfrastructureConfig::from_toml(&bad).unwrap())
+            .err()
+            .expect("expected error");
+        assert!(err.to_string().contains(expected), "got: {err}");
+    }
+}
+
+#[test]
+fn handles_follow_hot_reloads() {
+    let reg = registry(SAMPLE);
+    let flag = reg.flags().unwrap().flag("post.dual_publish_legacy_topics");
+    let added = reg.flags().unwrap().flag("search.new_ranker");
+    assert!(flag.is_enabled());
+    assert!(!added.is_enabled());
+
+    let pushed = SAMPLE.replace(
+        "[flags.\"post.dual_publish_legacy_topics\"]\nenabled = true",
+        "[flags.\"post.dual_publish_legacy_topics\"]\nenabled = false\n\n[flags.\"search.new_ranker\"]\nenabled = true",
+    );
+    reg.reload(&pushed).unwrap();
+    assert!(!flag.is_enabled());
+    assert!(added.is_enabled(), "flags added by a push are live at once");
+
+    assert!(reg.reload(&pushed.replace("percent = 25", "percent = -1")).is_err());
+    assert!(added.is_enabled(), "fail-closed: a rejected push keeps the previous flags");
+}
+
+#[test]
+fn fixed_handles_ignore_config() {
+    assert!(Flag::fixed(true).is_enabled_for(&FlagContext::principal("anyone")));
+    assert!(!Flag::fixed(false).is_enabled());
+}
@@ -34,8 +34,8 @@ fn resolves_bindings_and_default() {
     // unbound dependency -> default_profile ("standard")
     assert_eq!(registry.profile_for("some-unbound-dep").timeout.load().duration.as_millis(), 10000);
 
-    // resolved (non-hot-reloadable) retry comes through too
-    assert_eq!(registry.profile("critical").unwrap().retry.max_attempts, 1);
+    // resolved retry comes through too
+    assert_eq!(registry.profile("critical").unwrap().retry.load().max_attempts, 1);
 }
 
 #[test]
@@ -100,3 +100,80 @@ fn apply_rejects_invalid_config_and_keeps_previous() {
         "values must be unchanged after a rejected apply"
     );
 }
+
+#[test]
+fn apply_hot_swaps_retry_and_budget() {
+    let registry = registry_from(SAMPLE);
+    let standard = registry.profile_for("timeline-read");
+    assert_eq!(standard.retry.load().max_attempts, 3);
+    assert!(standard.retry.load().budget.is_none(), "no budget unless configured");
+
+    // Incident dial-down: fewer retries, capped at 5% of traffic.
+    let dialed = SAMPLE.replacen(
+        "retry = { max_attempts = 3, backoff = { kind = \"exponential\", base_ms = 50, max_ms = 10000, jitter = \"full\" } }",
+        "retry = { max_attempts = 1, budget = { ratio = 0.05 } }",
+        1,
+    );
+    registry.apply(InfrastructureConfig::from_toml(&dialed).unwrap()).unwrap();
+
+    let retry = standard.retry.load();
+    assert_eq!(retry.max_attempts, 1);
+    let budget = retry.budget.expect("budget applied");
+    assert_eq!((budget.ratio, budget.min_per_sec, budget.max_tokens), (0.05, 10, 100));
+}
+
+#[test]
+fn rejects_out_of_range_retry_budget() {
+    for budget in ["{ ratio = 1.5 }", "{ ratio = -0.1 }", "{ max_tokens = 0 }"] {
+        let bad = SAMPLE.replacen(
+            "retry = { max_attempts = 1,",
+            &format!("retry = {{ budget = {budget}, max_attempts = 1,"),
+            1,
+        );
+        let err = ResilienceRegistry::from_config(InfrastructureConfig::from_toml(&bad).unwrap());
+        assert!(err.is_err(), "budget {budget} must be rejected");
+    }
+}
+
+#[test]
+fn apply_switches_bulkhead_and_hedge_on_and_off() {
+    let registry = registry_from(SAMPLE);
+    let standard = registry.profile_for("timeline-read");
+    assert!(standard.bulkhead.load().is_none(), "no bulkhead unless configured");
+    assert!(standard.hedge.load().is_none(), "no hedging unless configured");
+
+    let isolated = SAMPLE.replacen(
+        "[resilience.profiles.critical]",
+        "bulkhead = { max_concurrent = 32, max_wait_ms = 5 }\n\
+         hedge = { percentile = 0.9 }\n\n[resilience.profiles.critical]",
+        1,
+    );
+    registry.apply(InfrastructureConfig::from_toml(&isolated).unwrap()).unwrap();
+
+    let bulkhead = standard.bulkhead.load_full().expect("bulkhead applied");
+    assert_eq!(bulkhead.max_concurrent, 32);
+    assert_eq!(bulkhead.max_wait.as_millis(), 5);
+    let hedge = standard.hedge.load_full().expect("hedge applied");
+    assert_eq!((hedge.percentile, hedge.min_samples), (0.9, 20));
+
+    registry.apply(InfrastructureConfig::from_toml(SAMPLE).unwrap()).unwrap();
+    assert!(standard.bulkhead.load().is_none(), "removing the table switches it off");
+    assert!(standard.hedge.load().is_none());
+}
+
+#[test]
+fn rejects_empty_bulkhead_and_out_of_range_hedge() {
+    for section in [
+        "bulkhead = { max_concurrent = 0 }",
+        "hedge = { percentile = 1.0 }",
+        "hedge = { percentile = 0.0 }",
+    ] {
+        let bad = SAMPLE.replacen(
+            "[resilience.profiles.critical]",
+            &format!("{section}\n\n[resilience.profiles.critical]"),
+            1,
+        );
+        let err = ResilienceRegistry::from_config(InfrastructureConfig::from_toml(&bad).unwrap());
+        assert!(err.is_err(), "{section} must be rejected");
+    }
+}
@@ -0,0 +1,106 @@
+//! ConfigControl (versioned apply, rollback) and the FileSource driven by spawn_source.
+
+use std::{sync::Arc, time::Duration};
+
+use infra_config::{
+    spawn_source, ConfigControl, ConfigDocument, ConfigError, ConfigSource, FileSource,
+    InfraRegistry, InfrastructureConfig,
+};
+
+fn config(timeout_ms: u64) -> String {
+    format!(
+        r#"
+[resilience]
+default_profile = "standard"
+[resilience.profiles.standard]
+timeout = {{ duration_ms = {timeout_ms} }}
+circuit_breaker = {{ failure_threshold = 5, success_threshold = 2, open_duration_ms = 30000, half_open_max_calls = 1 }}
+retry = {{ max_attempts = 3, backoff = {{ kind = "exponential", base_ms = 50, max_ms = 10000, jitter = "full" }} }}
+"#
+    )
+}
+
+fn document(timeout_ms: u64, version: &str) -> ConfigDocument {
+    ConfigDocument { raw: config(timeout_ms), version: version.into() }
+}
+
+fn control(booted: ConfigDocument) -> ConfigControl<InfraRegistry> {
+    let registry =
+        InfraRegistry::from_config(InfrastructureConfig::from_toml(&booted.raw).unwrap()).unwrap();
+    ConfigControl::booted(Arc::new(registry), "test".into(), booted)
+}
+
+fn live_timeout_ms(control: &ConfigControl<InfraRegistry>) -> u128 {
+    control.target().resilience().profile_for("x").timeout.load().duration.as_millis()
+}
+
+#[test]
+fn apply_tracks_version_and_rejections_keep_the_previous_config() {
+    let control = control(document(10_000, "v1"));
+    assert_eq!(control.status().version, "v1");
+    assert_eq!(control.status().rollback_to, None);
+
+    control.apply(document(500, "v2")).unwrap();
+    assert_eq!(live_timeout_ms(&control), 500);
+    let status = control.status();
+    assert_eq!((status.version.as_str(), status.rollback_to.as_deref()), ("v2", Some("v1")));
+
+    let err = control.apply(document(0, "v3")).unwrap_err();
+    assert!(matches!(err, ConfigError::Validation(_)), "got: {err}");
+    let status = control.status();
+    assert_eq!(status.version, "v2", "fail-closed: the rejected push isn't live");
+    assert!(status.last_error.unwrap().starts_with("v3: "));
+    assert_eq!(live_timeout_ms(&control), 500);
+}
+
+#[test]
+fn rollback_restores_the_last_good_config_and_pins_away_from_the_bad_one() {
+    let control = control(document(10_000, "v1"));
+    assert!(matches!(control.rollback(), Err(ConfigError::NoRollbackTarget)));
+
+    control.apply(document(1, "v2")).unwrap();
+    assert_eq!(control.rollback().unwrap(), "v1");
+    assert_eq!(live_timeout_ms(&control), 10_000);
+    let status = control.status();
+    assert_eq!(status.pinned_away_from.as_deref(), Some("v2"));
+    assert_eq!(status.rollback_to, None, "one step back only");
+
+    // The source re-offering the rolled-back push is ignored…
+    control.apply(document(1, "v2")).unwrap();
+    assert_eq!((control.status().version.as_str(), live_timeout_ms(&control)), ("v1", 10_000));
+
+    // …until it publishes something else.
+    control.apply(document(750, "v3")).unwrap();
+    assert_eq!(live_timeout_ms(&control), 750);
+    assert_eq!(control.status().pinned_away_from, None);
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn file_source_reloads_through_the_control() {
+    let dir = std::env::temp_dir().join(format!("infra-cfg-source-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("infrastructure.toml");
+    std::fs::write(&path, config(10_000)).unwrap();
+
+    let mut source = FileSource::new(path.clone()).unwrap();
+    assert!(source.describe().starts_with("file:"));
+    let booted = source.fetch(None).await.unwrap();
+    let control = Arc::new(control(booted.clone()));
+    let _driver = spawn_source(source, Arc::clone(&control));
+
+    std::fs::write(&path, config(750)).unwrap();
+
+    let mut reloaded = false;
+    for _ in 0..100 {
+        if live_timeout_ms(&control) == 750 {
+            reloaded = true;
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+
+    let _ = std::fs::remove_dir_all(&dir);
+    assert!(reloaded, "file change should have hot-reloaded through the control within 5s");
+    assert_ne!(control.status().version, booted.version);
+    assert_eq!(control.status().rollback_to, Some(booted.version));
+}
@@ -1,7 +1,12 @@
 //! Traffic section: catalog resolution, scope/quota parsing, fail-closed validation.
 
-use infra_config::{InfrastructureConfig, TrafficRegistry};
-use traffic::{Scope, TrafficDecision};
+use std::sync::{Arc, Mutex};
+
+use infra_config::{
+    ConfigError, InfrastructureConfig, TrafficBackendSink, TrafficBackendSpec,
+    TrafficBackendTopology, TrafficRegistry,
+};
+use traffic::{Algorithm, Priority, Scope, TrafficDecision};
 
 const SAMPLE: &str = r#"
 [resilience]
@@ -25,6 +30,21 @@ scope = "per_caller"
 "/post.PostService/CreatePost" = "write-tight"
 "#;
 
+const BACKEND: &str = r#"
+[traffic.backend]
+kind  = "redis"
+hosts = ["traffic-redis:6379"]
+"#;
+
+const CONCURRENCY: &str = r#"
+[traffic.concurrency]
+algorithm = "gradient"
+max_limit = 40
+[traffic.concurrency.priorities]
+"/account.v1.AccountService/GetAccountStatus" = "critical"
+"/timeline.v1.TimelineService/GetAudioFeed" = "sheddable"
+"#;
+
 fn traffic_registry(toml: &str) -> TrafficRegistry {
     let cfg = InfrastructureConfig::from_toml(toml).unwrap();
     TrafficRegistry::from_section(cfg.traffic.expect("[traffic] present")).unwrap()
@@ -99,6 +119,33 @@ fn distributed_requires_lease_ms() {
     assert!(TrafficRegistry::from_section(cfg.traffic.unwrap()).is_ok());
 }
 
+#[test]
+fn per_attribute_requires_a_field() {
+    let no_field = SAMPLE.replace("scope = \"per_caller\"", "scope = \"per_attribute\"");
+    let cfg = InfrastructureConfig::from_toml(&no_field).unwrap();
+    let err = TrafficRegistry::from_section(cfg.traffic.unwrap()).err().expect("expected error");
+    assert!(err.to_string().contains("requires attribute"), "got: {err}");
+
+    let with_field = SAMPLE.replace(
+        "scope = \"per_caller\"",
+        "scope = \"per_attribute\"\nattribute = \"post_id\"",
+    );
+    let registry = traffic_registry(&with_field);
+    let tight = registry.profile_for("/post.PostService/CreatePost");
+    assert_eq!(tight.scope(), Scope::PerAttribute);
+    assert_eq!(tight.attribute().as_deref(), Some("post_id"));
+}
+
+#[test]
+fn parses_ip_and_tenant_scopes() {
+    let toml = SAMPLE
+        .replace("scope = \"per_method\"", "scope = \"per_ip\"")
+        .replace("scope = \"per_caller\"", "scope = \"per_tenant\"");
+    let registry = traffic_registry(&toml);
+    assert_eq!(registry.profile_for("/some.Unbound/Method").scope(), Scope::PerIp);
+    assert_eq!(registry.profile_for("/post.PostService/CreatePost").scope(), Scope::PerTenant);
+}
+
 #[test]
 fn rejects_binding_to_unknown_profile() {
     let bad = SAMPLE.replace(r#"= "write-tight""#, r#"= "nope""#);
@@ -106,3 +153,129 @@ fn rejects_binding_to_unknown_profile() {
     let err = TrafficRegistry::from_section(cfg.traffic.unwrap()).err().expect("expected error");
     assert!(err.to_string().contains("unknown profile 'nope'"), "got: {err}");
 }
+
+#[test]
+fn parses_backend_block_with_defaults() {
+    let registry = traffic_registry(&format!("{SAMPLE}{BACKEND}"));
+    let backend = registry.backend().expect("[traffic.backend] present");
+    assert_eq!(backend.hosts, vec!["traffic-redis:6379".to_string()]);
+    assert_eq!(backend.topology, TrafficBackendTopology::Standalone);
+    assert!(!backend.tls);
+    assert_eq!(backend.timeout_ms, 50);
+
+    assert!(traffic_registry(SAMPLE).backend().is_none());
+}
+
+#[test]
+fn rejects_backend_without_hosts() {
+    let bad = format!("{SAMPLE}{}", BACKEND.replace(r#"["traffic-redis:6379"]"#, "[]"));
+    let cfg = InfrastructureConfig::from_toml(&bad).unwrap();
+    let err = TrafficRegistry::from_section(cfg.traffic.unwrap()).err().expect("expected error");
+    assert!(err.to_string().contains("hosts must name at least one"), "got: {err}");
+}
+
+#[derive(Default)]
+struct RecordingSink {
+    pushed: Mutex<Vec<TrafficBackendSpec>>,
+}
+
+impl TrafficBackendSink for RecordingSink {
+    fn apply(&self, spec: &TrafficBackendSpec) -> Result<(), ConfigError> {
+        self.pushed.lock().unwrap().push(spec.clone());
+        Ok(())
+    }
+}
+
+#[test]
+fn hot_reload_pushes_only_a_changed_backend() {
+    let registry = traffic_registry(&format!("{SAMPLE}{BACKEND}"));
+    let sink = Arc::new(RecordingSink::default());
+    registry.set_backend_sink(sink.clone());
+
+    // Unchanged block → no rebuild.
+    let same = InfrastructureConfig::from_toml(&format!("{SAMPLE}{BACKEND}")).unwrap();
+    registry.apply(same.traffic.unwrap()).unwrap();
+    assert!(sink.pushed.lock().unwrap().is_empty());
+
+    // Moved store → pushed once and recorded as the live block.
+    let moved = format!("{SAMPLE}{}", BACKEND.replace("traffic-redis:6379", "traffic-redis-2:6379"));
+    let cfg = InfrastructureConfig::from_toml(&moved).unwrap();
+    registry.apply(cfg.traffic.unwrap()).unwrap();
+    let pushed = sink.pushed.lock().unwrap();
+    assert_eq!(pushed.len(), 1);
+    assert_eq!(pushed[0].hosts, vec!["traffic-redis-2:6379".to_string()]);
+    assert_eq!(registry.backend().unwrap().hosts, pushed[0].hosts);
+}
+
+#[test]
+fn backend_added_at_runtime_is_ignored() {
+    let registry = traffic_registry(SAMPLE);
+    let sink = Arc::new(RecordingSink::default());
+    registry.set_backend_sink(sink.clone());
+
+    let cfg = InfrastructureConfig::from_toml(&format!("{SAMPLE}{BACKEND}")).unwrap();
+    registry.apply(cfg.traffic.unwrap()).unwrap();
+    assert!(sink.pushed.lock().unwrap().is_empty());
+    assert!(registry.backend().is_none());
+}
+
+#[test]
+fn parses_concurrency_block_with_defaults_and_priorities() {
+    let registry = traffic_registry(&format!("{SAMPLE}{CONCURRENCY}"));
+    let limiter = registry.concurrency().expect("[traffic.concurrency] present");
+    let config = limiter.config();
+    assert_eq!(config.algorithm, Algorithm::Gradient);
+    assert_eq!(config.max_limit, 40);
+    assert_eq!(config.initial_limit, 20, "unset dials take their defaults");
+    assert!(config.enforce);
+
+    let status = "/account.v1.AccountService/GetAccountStatus";
+    assert_eq!(registry.priority_for(status), Priority::Critical);
+    let feed = "/timeline.v1.TimelineService/GetAudioFeed";
+    assert_eq!(registry.priority_for(feed), Priority::Sheddable);
+    assert_eq!(registry.priority_for("/post.PostService/CreatePost"), Priority::Normal);
+
+    assert!(traffic_registry(SAMPLE).concurrency().is_none());
+}
+
+#[test]
+fn rejects_inconsistent_concurrency_dials() {
+    for (dial, rule) in [
+        ("initial_limit = 100", "min_limit <= initial_limit <= max_limit"),
+        ("backoff_ratio = 1.0", "backoff_ratio must be in [0.5, 1)"),
+        ("sheddable_share = 0.0", "sheddable_share must be in (0, 1]"),
+        ("tolerance = 0.5", "tolerance must be >= 1"),
+    ] {
+        let block = CONCURRENCY.replace("max_limit = 40", &format!("max_limit = 40\n{dial}"));
+        let cfg = InfrastructureConfig::from_toml(&format!("{SAMPLE}{block}")).unwrap();
+        let err = TrafficRegistry::from_section(cfg.traffic.unwrap()).err().expect("expected error");
+        assert!(err.to_string().contains(rule), "{dial}: got {err}");
+    }
+}
+
+#[test]
+fn hot_reload_retunes_concurrency_and_swaps_priorities() {
+    let registry = traffic_registry(&format!("{SAMPLE}{CONCURRENCY}"));
+    let limiter = registry.concurrency().unwrap();
+    limiter.try_acquire(Priority::Normal).unwrap().dropped();
+    let backed_off = limiter.limit();
+
+    let reloaded = CONCURRENCY
+        .replace("max_limit = 40", "max_limit = 40\nenforce = false")
+        .replace("\"critical\"", "\"sheddable\"");
+    let cfg = InfrastructureConfig::from_toml(&format!("{SAMPLE}{reloaded}")).unwrap();
+    registry.apply(cfg.traffic.unwrap()).unwrap();
+
+    assert!(!limiter.enforce(), "the boot-time handle sees the reload");
+    assert_eq!(limiter.limit(), backed_off, "the discovered limit survives a reload");
+    let status = "/account.v1.AccountService/GetAccountStatus";
+    assert_eq!(registry.priority_for(status), Priority::Sheddable);
+}
+
+#[test]
+fn concurrency_added_at_runtime_is_ignored() {
+    let registry = traffic_registry(SAMPLE);
+    let cfg = InfrastructureConfig::from_toml(&format!("{SAMPLE}{CONCURRENCY}")).unwrap();
+    registry.apply(cfg.traffic.unwrap()).unwrap();
+    assert!(registry.concurrency().is_none());
+}
@@ -0,0 +1,39 @@
+use std::time::Duration;
+
+/// Configuration for the bulkhead middleware.
+///
+/// ```toml
+/// bulkhead = { max_concurrent = 64, max_wait_ms = 20 }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BulkheadConfig {
+    /// Calls admitted to the downstream at once.
+    pub max_concurrent: u32,
+    /// How long a call queues for a free slot before it's rejected; zero rejects at once.
+    /// Serialized as a flat `max_wait_ms` integer.
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "max_wait_ms", default, with = "crate::serde_util::duration_millis")
+    )]
+    pub max_wait: Duration,
+}
+
+impl Default for BulkheadConfig {
+    /// 64 concurrent calls, no queueing.
+    fn default() -> Self {
+        Self::new(64)
+    }
+}
+
+impl BulkheadConfig {
+    /// A bulkhead of `max_concurrent` slots that rejects without queueing.
+    pub fn new(max_concurrent: u32) -> Self {
+        Self { max_concurrent, max_wait: Duration::ZERO }
+    }
+
+    pub fn max_wait(mut self, max_wait: Duration) -> Self {
+        self.max_wait = max_wait;
+        self
+    }
+}
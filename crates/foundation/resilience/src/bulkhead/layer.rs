@@ -0,0 +1,59 @@
+use std::sync::Arc;
+
+use arc_swap::ArcSwapOption;
+use tower::Layer;
+
+use super::{
+    config::BulkheadConfig,
+    service::{BulkheadService, Slots},
+};
+
+/// Tower [`Layer`] that bounds concurrent calls to a downstream.
+///
+/// A single `BulkheadLayer` owns one slot counter shared (via [`Arc`]) across every service
+/// clone it produces, so the bound holds per logical downstream dependency however many
+/// clones a generated client makes. The config sits behind an [`ArcSwapOption`]: `None` turns
+/// the layer into a pass-through, which lets a profile enable, retune or drop its bulkhead on
+/// hot-reload without rebuilding the stack. Slots held by in-flight calls survive a swap.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use tower::ServiceBuilder;
+/// use resilience::bulkhead::{BulkheadLayer, BulkheadConfig};
+///
+/// let svc = ServiceBuilder::new()
+///     .layer(BulkheadLayer::new(BulkheadConfig::new(32)))
+///     .service(my_inner_service);
+/// ```
+#[derive(Clone)]
+pub struct BulkheadLayer {
+    config: Arc<ArcSwapOption<BulkheadConfig>>,
+    slots: Arc<Slots>,
+}
+
+impl BulkheadLayer {
+    /// Builds a layer owning a fresh handle seeded with `config`.
+    pub fn new(config: BulkheadConfig) -> Self {
+        Self::from_handle(Arc::new(ArcSwapOption::from_pointee(config)))
+    }
+
+    /// Builds a layer that shares an externally-owned handle (e.g. one held by
+    /// [`crate::ResilienceProfile`]) so swaps propagate to this stack.
+    pub fn from_handle(config: Arc<ArcSwapOption<BulkheadConfig>>) -> Self {
+        Self { config, slots: Arc::new(Slots::default()) }
+    }
+
+    /// Returns the shared handle so a control plane can `store()` new configs at runtime.
+    pub fn handle(&self) -> Arc<ArcSwapOption<BulkheadConfig>> {
+        Arc::clone(&self.config)
+    }
+}
+
+impl<S> Layer<S> for BulkheadLayer {
+    type Service = BulkheadService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        BulkheadService::new(inner, Arc::clone(&self.config), Arc::clone(&self.slots))
+    }
+}
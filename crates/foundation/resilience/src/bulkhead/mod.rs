@@ -0,0 +1,7 @@
+pub mod config;
+pub mod layer;
+pub mod service;
+
+pub use config::*;
+pub use layer::*;
+pub use service::*;
@@ -0,0 +1,252 @@
+use std::{
+    future::Future,
+    pin::{pin, Pin},
+    sync::{
+        atomic::{AtomicU32, Ordering},
+        Arc,
+    },
+    task::{Context, Poll},
+};
+
+use arc_swap::ArcSwapOption;
+use tokio::{sync::Notify, time::Instant};
+use tower::Service;
+
+use super::config::BulkheadConfig;
+use crate::error::ResilienceError;
+
+/// Occupied slots plus a wake-up for calls queued on a full bulkhead.
+///
+/// A counter rather than a `Semaphore` because the bound is read from the config on every
+/// acquisition: a hot-swapped `max_concurrent` applies at once, and shrinking it never has
+/// to claw back permits already handed out — calls above the new bound simply finish.
+#[derive(Default)]
+pub struct Slots {
+    in_use: AtomicU32,
+    freed: Notify,
+}
+
+impl Slots {
+    /// Calls currently holding a slot.
+    pub fn in_use(&self) -> u32 {
+        self.in_use.load(Ordering::Acquire)
+    }
+
+    fn try_take(self: &Arc<Self>, max_concurrent: u32) -> Option<Slot> {
+        self.in_use
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |n| {
+                (n < max_concurrent).then_some(n + 1)
+            })
+            .ok()
+            .map(|_| Slot(Arc::clone(self)))
+    }
+
+    /// Takes a slot, queueing up to `max_wait` for one to free up.
+    async fn take(self: &Arc<Self>, config: &BulkheadConfig) -> Option<Slot> {
+        if let Some(slot) = self.try_take(config.max_concurrent) {
+            return Some(slot);
+        }
+        let deadline = Instant::now() + config.max_wait;
+        while Instant::now() < deadline {
+            // Register for the wake-up *before* re-checking, so a slot freed between the
+            // check and the wait isn't missed.
+            let mut freed = pin!(self.freed.notified());
+            freed.as_mut().enable();
+            if let Some(slot) = self.try_take(config.max_concurrent) {
+                return Some(slot);
+            }
+            if tokio::time::timeout_at(deadline, freed).await.is_err() {
+                break;
+            }
+        }
+        self.try_take(config.max_concurrent)
+    }
+}
+
+/// An occupied slot; dropping it (completion or cancellation) frees it for a queued call.
+struct Slot(Arc<Slots>);
+
+impl Drop for Slot {
+    fn drop(&mut self) {
+        self.0.in_use.fetch_sub(1, Ordering::AcqRel);
+        self.0.freed.notify_one();
+    }
+}
+
+/// Tower [`Service`] that rejects calls once the downstream's concurrency bound is reached.
+///
+/// Like the circuit breaker, the slot state is shared via [`Arc`] across clones (tonic
+/// clones the service per RPC), and the inner instance is taken with the clone-in-call
+/// rule so the readiness the caller polled travels with the request while it queues.
+#[derive(Clone)]
+pub struct BulkheadService<S> {
+    inner: S,
+    config: Arc<ArcSwapOption<BulkheadConfig>>,
+    slots: Arc<Slots>,
+}
+
+impl<S> BulkheadService<S> {
+    pub(crate) fn new(
+        inner: S,
+        config: Arc<ArcSwapOption<BulkheadConfig>>,
+        slots: Arc<Slots>,
+    ) -> Self {
+        Self { inner, config, slots }
+    }
+}
+
+impl<S, Req> Service<Req> for BulkheadService<S>
+where
+    S: Service<Req> + Clone + Send + 'static,
+    S::Future: Send,
+    Req: Send + 'static,
+{
+    type Response = S::Response;
+    type Error = ResilienceError<S::Error>;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx).map_err(ResilienceError::Inner)
+    }
+
+    fn call(&mut self, req: Req) -> Self::Future {
+        // Request-scoped snapshot; `None` means no bulkhead on this profile.
+        let Some(config) = self.config.load_full() else {
+            let fut = self.inner.call(req);
+            return Box::pin(async move { fut.await.map_err(ResilienceError::Inner) });
+        };
+        let slots = Arc::clone(&self.slots);
+        let clone = self.inner.clone();
+        let mut svc = std::mem::replace(&mut self.inner, clone);
+
+        Box::pin(async move {
+            let Some(_slot) = slots.take(&config).await else {
+                tracing::warn!(
+                    max_concurrent = config.max_concurrent,
+                    "bulkhead full, rejecting request"
+                );
+                return Err(ResilienceError::BulkheadFull);
+            };
+            svc.call(req).await.map_err(ResilienceError::Inner)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use tokio::sync::Semaphore;
+    use tower::{service_fn, ServiceExt};
+
+    use super::*;
+
+    /// An inner service whose calls block until `gate` hands out a permit.
+    fn gated(
+        gate: Arc<Semaphore>,
+    ) -> impl Service<(), Response = (), Error = &'static str, Future: Send> + Clone + Send {
+        service_fn(move |_: ()| {
+            let gate = Arc::clone(&gate);
+            async move {
+                gate.acquire().await.unwrap().forget();
+                Ok::<_, &str>(())
+            }
+        })
+    }
+
+    fn handle(config: BulkheadConfig) -> Arc<ArcSwapOption<BulkheadConfig>> {
+        Arc::new(ArcSwapOption::from_pointee(config))
+    }
+
+    async fn until_in_use(slots: &Slots, n: u32) {
+        while slots.in_use() < n {
+            tokio::task::yield_now().await;
+        }
+    }
+
+    #[tokio::test]
+    async fn rejects_past_max_concurrent_and_frees_on_completion() {
+        let gate = Arc::new(Semaphore::new(0));
+        let slots = Arc::new(Slots::default());
+        let svc = BulkheadService::new(
+            gated(Arc::clone(&gate)),
+            handle(BulkheadConfig::new(2)),
+            Arc::clone(&slots),
+        );
+
+        let held: Vec<_> = (0..2).map(|_| tokio::spawn(svc.clone().oneshot(()))).collect();
+        until_in_use(&slots, 2).await;
+        let err = svc.clone().oneshot(()).await.unwrap_err();
+        assert!(matches!(err, ResilienceError::BulkheadFull));
+
+        gate.add_permits(3);
+        for call in held {
+            call.await.unwrap().unwrap();
+        }
+        assert_eq!(slots.in_use(), 0);
+        svc.oneshot(()).await.expect("a slot is free again");
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn queued_call_takes_a_freed_slot_within_max_wait() {
+        let gate = Arc::new(Semaphore::new(0));
+        let slots = Arc::new(Slots::default());
+        let config = BulkheadConfig::new(1).max_wait(Duration::from_millis(50));
+        let svc = BulkheadService::new(
+            gated(Arc::clone(&gate)),
+            handle(config),
+            Arc::clone(&slots),
+        );
+
+        let first = tokio::spawn(svc.clone().oneshot(()));
+        until_in_use(&slots, 1).await;
+        let queued = tokio::spawn(svc.clone().oneshot(()));
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        gate.add_permits(2);
+
+        first.await.unwrap().unwrap();
+        queued.await.unwrap().expect("admitted once the first call freed its slot");
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn queued_call_is_rejected_after_max_wait() {
+        let gate = Arc::new(Semaphore::new(0));
+        let slots = Arc::new(Slots::default());
+        let config = BulkheadConfig::new(1).max_wait(Duration::from_millis(50));
+        let svc = BulkheadService::new(
+            gated(Arc::clone(&gate)),
+            handle(config),
+            Arc::clone(&slots),
+        );
+
+        let _held = tokio::spawn(svc.clone().oneshot(()));
+        until_in_use(&slots, 1).await;
+        let err = svc.oneshot(()).await.unwrap_err();
+        assert!(matches!(err, ResilienceError::BulkheadFull));
+    }
+
+    #[tokio::test]
+    async fn hot_swap_retunes_and_none_disables() {
+        let gate = Arc::new(Semaphore::new(0));
+        let slots = Arc::new(Slots::default());
+        let config = handle(BulkheadConfig::new(1));
+        let svc = BulkheadService::new(
+            gated(Arc::clone(&gate)),
+            Arc::clone(&config),
+            Arc::clone(&slots),
+        );
+
+        let _held = tokio::spawn(svc.clone().oneshot(()));
+        until_in_use(&slots, 1).await;
+        assert!(matches!(svc.clone().oneshot(()).await, Err(ResilienceError::BulkheadFull)));
+
+        config.store(Some(Arc::new(BulkheadConfig::new(2))));
+        let widened = tokio::spawn(svc.clone().oneshot(()));
+        until_in_use(&slots, 2).await;
+
+        config.store(None);
+        gate.add_permits(3);
+        svc.oneshot(()).await.expect("no bulkhead, no rejection");
+        widened.await.unwrap().unwrap();
+    }
+}
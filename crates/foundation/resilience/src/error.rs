@@ -17,6 +17,9 @@ pub enum ResilienceError<E> {
     #[error("max retry attempts ({0}) exhausted")]
     MaxRetriesExhausted(u32),
 
+    #[error("bulkhead full — request rejected")]
+    BulkheadFull,
+
     #[error(transparent)]
     Inner(E),
 }
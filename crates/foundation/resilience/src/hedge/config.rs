@@ -0,0 +1,34 @@
+use std::time::Duration;
+
+/// Configuration for the hedging middleware.
+///
+/// A call still pending after the `percentile` latency of recent calls gets one duplicate
+/// (the *hedge*) sent to the downstream; whichever succeeds first answers. Only hedge
+/// idempotent reads: both copies may execute.
+///
+/// ```toml
+/// hedge = { percentile = 0.95, min_delay_ms = 10, min_samples = 20 }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct HedgeConfig {
+    /// Latency percentile of recent calls after which the hedge is sent, in `(0, 1)`.
+    pub percentile: f64,
+    /// Floor on the hedge delay, so a fast downstream isn't hedged on jitter.
+    /// Serialized as a flat `min_delay_ms` integer.
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "min_delay_ms", with = "crate::serde_util::duration_millis")
+    )]
+    pub min_delay: Duration,
+    /// Calls observed before hedging starts — no percentile without a sample.
+    pub min_samples: u32,
+}
+
+impl Default for HedgeConfig {
+    /// Hedge past p95, never sooner than 10ms, after 20 samples.
+    fn default() -> Self {
+        Self { percentile: 0.95, min_delay: Duration::from_millis(10), min_samples: 20 }
+    }
+}
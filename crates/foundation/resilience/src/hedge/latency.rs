@@ -0,0 +1,79 @@
+use std::{sync::Mutex, time::Duration};
+
+/// Samples the window keeps; older ones are overwritten, so the percentile tracks the
+/// downstream's recent behaviour rather than its lifetime average.
+const WINDOW: usize = 256;
+
+/// Ring buffer of recent call latencies a [`HedgeLayer`](super::HedgeLayer) derives its
+/// hedge delay from. Shared (via `Arc`) by every service the layer produces.
+pub struct LatencyWindow {
+    ring: Mutex<Ring>,
+}
+
+struct Ring {
+    samples: Vec<Duration>,
+    next: usize,
+}
+
+impl Default for LatencyWindow {
+    fn default() -> Self {
+        Self { ring: Mutex::new(Ring { samples: Vec::with_capacity(WINDOW), next: 0 }) }
+    }
+}
+
+impl LatencyWindow {
+    pub fn record(&self, latency: Duration) {
+        let mut ring = self.ring.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        if ring.samples.len() < WINDOW {
+            ring.samples.push(latency);
+        } else {
+            let next = ring.next;
+            ring.samples[next] = latency;
+        }
+        ring.next = (ring.next + 1) % WINDOW;
+    }
+
+    /// The `percentile` latency of the window, or `None` below `min_samples`.
+    pub fn percentile(&self, percentile: f64, min_samples: u32) -> Option<Duration> {
+        let mut samples = {
+            let ring = self.ring.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+            if ring.samples.is_empty() || ring.samples.len() < min_samples as usize {
+                return None;
+            }
+            ring.samples.clone()
+        };
+        let rank = (percentile * samples.len() as f64).ceil() as usize;
+        let index = rank.clamp(1, samples.len()) - 1;
+        Some(*samples.select_nth_unstable(index).1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn percentile_needs_min_samples_then_ranks_the_window() {
+        let window = LatencyWindow::default();
+        for ms in 1..=19 {
+            window.record(Duration::from_millis(ms));
+        }
+        assert_eq!(window.percentile(0.95, 20), None);
+
+        window.record(Duration::from_millis(20));
+        assert_eq!(window.percentile(0.95, 20), Some(Duration::from_millis(19)));
+        assert_eq!(window.percentile(0.5, 20), Some(Duration::from_millis(10)));
+    }
+
+    #[test]
+    fn old_samples_roll_out_of_the_window() {
+        let window = LatencyWindow::default();
+        for _ in 0..WINDOW {
+            window.record(Duration::from_secs(5));
+        }
+        for _ in 0..WINDOW {
+            window.record(Duration::from_millis(1));
+        }
+        assert_eq!(window.percentile(0.99, 1), Some(Duration::from_millis(1)));
+    }
+}
@@ -0,0 +1,54 @@
+use std::sync::Arc;
+
+use arc_swap::ArcSwapOption;
+use tower::Layer;
+
+use super::{config::HedgeConfig, latency::LatencyWindow, service::HedgeService};
+
+/// Tower [`Layer`] that hedges slow calls with a duplicate request.
+///
+/// One `HedgeLayer` owns one [`LatencyWindow`], shared (via [`Arc`]) across every service it
+/// produces, so the hedge delay is learned per logical downstream call. The config sits
+/// behind an [`ArcSwapOption`]: `None` disables hedging, which lets a profile turn it on,
+/// retune it or switch it off on hot-reload. The window keeps learning while disabled.
+///
+/// Wrap **idempotent reads only**, at the RPC layer (the request must be `Clone`; tonic's
+/// HTTP/2 bodies aren't, for the same reason `RetryLayer` stays off the channel):
+///
+/// ```rust,ignore
+/// let hedged = profile.hedge_layer().layer(service_fn(move |req: ListFollowingRequest| {
+///     let mut client = client.clone();
+///     async move { client.list_following(req).await }
+/// }));
+/// ```
+#[derive(Clone)]
+pub struct HedgeLayer {
+    config: Arc<ArcSwapOption<HedgeConfig>>,
+    window: Arc<LatencyWindow>,
+}
+
+impl HedgeLayer {
+    /// Builds a layer owning a fresh handle seeded with `config`.
+    pub fn new(config: HedgeConfig) -> Self {
+        Self::from_handle(Arc::new(ArcSwapOption::from_pointee(config)))
+    }
+
+    /// Builds a layer that shares an externally-owned handle (e.g. one held by
+    /// [`crate::ResilienceProfile`]) so swaps propagate to this stack.
+    pub fn from_handle(config: Arc<ArcSwapOption<HedgeConfig>>) -> Self {
+        Self { config, window: Arc::new(LatencyWindow::default()) }
+    }
+
+    /// Returns the shared handle so a control plane can `store()` new configs at runtime.
+    pub fn handle(&self) -> Arc<ArcSwapOption<HedgeConfig>> {
+        Arc::clone(&self.config)
+    }
+}
+
+impl<S> Layer<S> for HedgeLayer {
+    type Service = HedgeService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        HedgeService::new(inner, Arc::clone(&self.config), Arc::clone(&self.window))
+    }
+}
@@ -0,0 +1,9 @@
+pub mod config;
+pub mod latency;
+pub mod layer;
+pub mod service;
+
+pub use config::*;
+pub use latency::*;
+pub use layer::*;
+pub use service::*;
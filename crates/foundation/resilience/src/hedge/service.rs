@@ -0,0 +1,234 @@
+use std::{
+    future::{poll_fn, Future},
+    pin::{pin, Pin},
+    sync::Arc,
+    task::{Context, Poll},
+    time::Instant,
+};
+
+use arc_swap::ArcSwapOption;
+use tower::{Service, ServiceExt};
+
+use super::{config::HedgeConfig, latency::LatencyWindow};
+use crate::error::ResilienceError;
+
+/// Tower [`Service`] that sends a second copy of a call still pending after the hedge delay.
+///
+/// The primary goes to the instance the caller polled (clone-in-call rule); the hedge goes
+/// to a fresh clone whose readiness is driven before its call. The first *success* answers
+/// and the other copy is dropped (cancelled); if one copy fails the other is still awaited,
+/// and only when both fail does the last error surface.
+///
+/// Latencies feed the shared [`LatencyWindow`]: a primary that completes records its
+/// latency; a primary cancelled because the hedge won records the time it had run — a lower
+/// bound that keeps slow calls in the window instead of letting hedging hide them.
+#[derive(Clone)]
+pub struct HedgeService<S> {
+    inner: S,
+    config: Arc<ArcSwapOption<HedgeConfig>>,
+    window: Arc<LatencyWindow>,
+}
+
+impl<S> HedgeService<S> {
+    pub(crate) fn new(
+        inner: S,
+        config: Arc<ArcSwapOption<HedgeConfig>>,
+        window: Arc<LatencyWindow>,
+    ) -> Self {
+        Self { inner, config, window }
+    }
+}
+
+impl<S, Req> Service<Req> for HedgeService<S>
+where
+    S: Service<Req> + Clone + Send + 'static,
+    S::Future: Send,
+    S::Response: Send,
+    S::Error: Send,
+    Req: Clone + Send + 'static,
+{
+    type Response = S::Response;
+    type Error = ResilienceError<S::Error>;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx).map_err(ResilienceError::Inner)
+    }
+
+    fn call(&mut self, req: Req) -> Self::Future {
+        let window = Arc::clone(&self.window);
+        // Request-scoped snapshot: no config, or too few samples yet, means no hedge.
+        let delay = self.config.load_full().and_then(|config| {
+            let percentile = window.percentile(config.percentile, config.min_samples)?;
+            Some(percentile.max(config.min_delay))
+        });
+        let hedge = delay.map(|_| (self.inner.clone(), req.clone()));
+        let clone = self.inner.clone();
+        let mut svc = std::mem::replace(&mut self.inner, clone);
+        let primary = svc.call(req);
+
+        Box::pin(async move {
+            let started = Instant::now();
+            let mut primary = pin!(primary);
+            let (Some(delay), Some((mut hedge_svc, hedge_req))) = (delay, hedge) else {
+                let result = primary.await;
+                if result.is_ok() {
+                    window.record(started.elapsed());
+                }
+                return result.map_err(ResilienceError::Inner);
+            };
+
+            if let Ok(result) = tokio::time::timeout(delay, primary.as_mut()).await {
+                if result.is_ok() {
+                    window.record(started.elapsed());
+                }
+                return result.map_err(ResilienceError::Inner);
+            }
+
+            tracing::debug!(delay_ms = delay.as_millis(), "slow call — sending hedge");
+            let mut hedge = pin!(async move {
+                match hedge_svc.ready().await {
+                    Ok(svc) => svc.call(hedge_req).await,
+                    Err(e) => Err(e),
+                }
+            });
+
+            // First success wins; a failed copy leaves the other to finish.
+            let (mut primary_done, mut hedge_done, mut last_error) = (false, false, None);
+            poll_fn(|cx| {
+                if !primary_done && let Poll::Ready(result) = primary.as_mut().poll(cx) {
+                    primary_done = true;
+                    match result {
+                        Ok(response) => {
+                            window.record(started.elapsed());
+                            return Poll::Ready(Ok(response));
+                        }
+                        Err(e) => last_error = Some(e),
+                    }
+                }
+                if !hedge_done && let Poll::Ready(result) = hedge.as_mut().poll(cx) {
+                    hedge_done = true;
+                    match result {
+                        Ok(response) => {
+                            if !primary_done {
+                                window.record(started.elapsed());
+                            }
+                            tracing::debug!("hedge answered first");
+                            return Poll::Ready(Ok(response));
+                        }
+                        Err(e) => last_error = Some(e),
+                    }
+                }
+                match last_error.take() {
+                    Some(e) if primary_done && hedge_done => {
+                        Poll::Ready(Err(ResilienceError::Inner(e)))
+                    }
+                    pending => {
+                        last_error = pending;
+                        Poll::Pending
+                    }
+                }
+            })
+            .await
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        sync::atomic::{AtomicU32, Ordering},
+        time::Duration,
+    };
+
+    use tower::service_fn;
+
+    use super::*;
+
+    /// A window primed with `n` samples of `latency`.
+    fn primed(n: u32, latency: Duration) -> Arc<LatencyWindow> {
+        let window = Arc::new(LatencyWindow::default());
+        for _ in 0..n {
+            window.record(latency);
+        }
+        window
+    }
+
+    fn hedging(min_samples: u32) -> Arc<ArcSwapOption<HedgeConfig>> {
+        let config = HedgeConfig { min_samples, ..HedgeConfig::default() };
+        Arc::new(ArcSwapOption::from_pointee(config))
+    }
+
+    /// The first call sleeps `first`; every later call answers after `rest`. Returns which
+    /// call (1-indexed) answered.
+    fn staged(
+        calls: Arc<AtomicU32>,
+        first: Duration,
+        rest: Duration,
+    ) -> impl Service<(), Response = u32, Error = &'static str, Future: Send> + Clone + Send {
+        service_fn(move |_: ()| {
+            let n = calls.fetch_add(1, Ordering::SeqCst) + 1;
+            async move {
+                tokio::time::sleep(if n == 1 { first } else { rest }).await;
+                Ok::<_, &str>(n)
+            }
+        })
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn slow_primary_is_hedged_and_the_hedge_answers() {
+        let calls = Arc::new(AtomicU32::new(0));
+        let inner = staged(Arc::clone(&calls), Duration::from_secs(5), Duration::from_millis(20));
+        let mut svc = HedgeService::new(inner, hedging(5), primed(5, Duration::from_millis(20)));
+
+        let answered = svc.ready().await.unwrap().call(()).await.unwrap();
+        assert_eq!(answered, 2, "the hedge, sent after ~20ms, beat the 5s primary");
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn fast_primary_sends_no_hedge() {
+        let calls = Arc::new(AtomicU32::new(0));
+        let inner = staged(Arc::clone(&calls), Duration::from_millis(5), Duration::from_millis(5));
+        let mut svc = HedgeService::new(inner, hedging(5), primed(5, Duration::from_millis(20)));
+
+        assert_eq!(svc.ready().await.unwrap().call(()).await.unwrap(), 1);
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn no_hedge_until_min_samples_or_when_disabled() {
+        let calls = Arc::new(AtomicU32::new(0));
+        let inner = staged(Arc::clone(&calls), Duration::from_secs(5), Duration::from_millis(20));
+        let config = hedging(20);
+        let mut svc = HedgeService::new(inner, Arc::clone(&config), primed(5, Duration::ZERO));
+
+        assert_eq!(svc.ready().await.unwrap().call(()).await.unwrap(), 1, "5 < min_samples");
+
+        config.store(None);
+        svc.ready().await.unwrap().call(()).await.unwrap();
+        assert_eq!(calls.load(Ordering::SeqCst), 2, "one call each, never a hedge");
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn a_failed_copy_waits_for_the_other() {
+        let calls = Arc::new(AtomicU32::new(0));
+        let counter = Arc::clone(&calls);
+        // The primary fails slowly (after the hedge delay); the hedge succeeds later still.
+        let inner = service_fn(move |_: ()| {
+            let n = counter.fetch_add(1, Ordering::SeqCst) + 1;
+            async move {
+                if n == 1 {
+                    tokio::time::sleep(Duration::from_millis(50)).await;
+                    Err("primary failed")
+                } else {
+                    tokio::time::sleep(Duration::from_millis(100)).await;
+                    Ok(n)
+                }
+            }
+        });
+        let mut svc = HedgeService::new(inner, hedging(5), primed(5, Duration::from_millis(20)));
+
+        assert_eq!(svc.ready().await.unwrap().call(()).await.unwrap(), 2);
+    }
+}
@@ -1,5 +1,7 @@
+pub mod bulkhead;
 pub mod circuit_breaker;
 pub mod error;
+pub mod hedge;
 pub mod profile;
 pub mod retry;
 pub mod timeout;
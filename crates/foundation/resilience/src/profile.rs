@@ -1,7 +1,8 @@
 //! Named resilience profiles — the bridge between externalized config and Tower layers.
 //!
 //! A *profile* is a fleet-meaningful class-of-service (`"standard"`, `"critical"`,
-//! `"aggressive"`, …) that bundles a timeout, a circuit breaker, and a retry policy.
+//! `"aggressive"`, …) that bundles a timeout, a circuit breaker, and a retry policy, plus an
+//! optional bulkhead and hedging policy.
 //!
 //! Two representations, deliberately split:
 //!
@@ -19,13 +20,16 @@
 
 use std::sync::Arc;
 
-use arc_swap::ArcSwap;
+use arc_swap::{ArcSwap, ArcSwapOption};
 
 use crate::{
+    bulkhead::{config::BulkheadConfig, layer::BulkheadLayer},
     circuit_breaker::{config::CircuitBreakerConfig, layer::CircuitBreakerLayer},
+    hedge::{config::HedgeConfig, layer::HedgeLayer},
     retry::{
         backoff::exponential::ExponentialBackoff,
         config::{RetryConfig, RetrySpec},
+        layer::RetryLayer,
     },
     timeout::{config::TimeoutConfig, layer::TimeoutLayer},
 };
@@ -37,6 +41,11 @@ use crate::{
 /// timeout = { duration_ms = 2_000 }
 /// circuit_breaker = { failure_threshold = 5, success_threshold = 2, open_duration_ms = 30_000, half_open_max_calls = 1 }
 /// retry = { max_attempts = 1, backoff = { kind = "exponential", base_ms = 20, max_ms = 500, jitter = "full" } }
+/// # optional: cap retries at 10% of traffic
+/// # retry = { max_attempts = 1, budget = { ratio = 0.1, min_per_sec = 10, max_tokens = 100 } }
+/// # optional: bound concurrent calls; hedge idempotent reads past p95
+/// bulkhead = { max_concurrent = 64, max_wait_ms = 20 }
+/// hedge = { percentile = 0.95, min_delay_ms = 10, min_samples = 20 }
 /// ```
 #[derive(Debug, Clone, Default)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -44,6 +53,10 @@ pub struct ResilienceProfileSpec {
     pub timeout: TimeoutConfig,
     pub circuit_breaker: CircuitBreakerConfig,
     pub retry: RetrySpec,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub bulkhead: Option<BulkheadConfig>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub hedge: Option<HedgeConfig>,
 }
 
 impl ResilienceProfileSpec {
@@ -53,7 +66,9 @@ impl ResilienceProfileSpec {
         ResilienceProfile {
             timeout: Arc::new(ArcSwap::from_pointee(self.timeout)),
             circuit_breaker: Arc::new(ArcSwap::from_pointee(self.circuit_breaker)),
-            retry: self.retry.resolve(),
+            retry: Arc::new(ArcSwap::from_pointee(self.retry.resolve())),
+            bulkhead: Arc::new(ArcSwapOption::new(self.bulkhead.map(Arc::new))),
+            hedge: Arc::new(ArcSwapOption::new(self.hedge.map(Arc::new))),
         }
     }
 }
@@ -68,10 +83,14 @@ pub struct ResilienceProfile {
     pub timeout: Arc<ArcSwap<TimeoutConfig>>,
     /// Hot-swappable. Shared with every [`CircuitBreakerLayer`] built via [`circuit_breaker_layer`](Self::circuit_breaker_layer).
     pub circuit_breaker: Arc<ArcSwap<CircuitBreakerConfig>>,
-    /// Resolved retry policy. Retry hot-reload is intentionally out of scope for this slice
-    /// (the retry layer is generic over the backoff strategy); changing it requires rebuilding
-    /// the retry layer. Timeout + circuit breaker cover the incident-critical levers.
-    pub retry: RetryConfig<ExponentialBackoff>,
+    /// Hot-swappable. Shared with every [`RetryLayer`] built via [`retry_layer`](Self::retry_layer).
+    pub retry: Arc<ArcSwap<RetryConfig<ExponentialBackoff>>>,
+    /// Hot-swappable; `None` leaves calls unbounded. Shared with every [`BulkheadLayer`]
+    /// built via [`bulkhead_layer`](Self::bulkhead_layer).
+    pub bulkhead: Arc<ArcSwapOption<BulkheadConfig>>,
+    /// Hot-swappable; `None` disables hedging. Shared with every [`HedgeLayer`] built via
+    /// [`hedge_layer`](Self::hedge_layer).
+    pub hedge: Arc<ArcSwapOption<HedgeConfig>>,
 }
 
 impl ResilienceProfile {
@@ -85,16 +104,37 @@ impl ResilienceProfile {
         CircuitBreakerLayer::from_handle(Arc::clone(&self.circuit_breaker))
     }
 
+    /// Builds a [`RetryLayer`] bound to this profile's shared handle. The policy (which
+    /// errors are retryable) is the caller's; attempts, backoff and budget come from the
+    /// profile. Each layer owns its own retry budget.
+    pub fn retry_layer<P>(&self, policy: P) -> RetryLayer<P, ExponentialBackoff> {
+        RetryLayer::from_handle(Arc::clone(&self.retry), policy)
+    }
+
+    /// Builds a [`BulkheadLayer`] bound to this profile's shared handle. Each layer owns its
+    /// own slot count, so build one per downstream dependency.
+    pub fn bulkhead_layer(&self) -> BulkheadLayer {
+        BulkheadLayer::from_handle(Arc::clone(&self.bulkhead))
+    }
+
+    /// Builds a [`HedgeLayer`] bound to this profile's shared handle. Each layer learns its
+    /// own latency window; wrap idempotent reads only.
+    pub fn hedge_layer(&self) -> HedgeLayer {
+        HedgeLayer::from_handle(Arc::clone(&self.hedge))
+    }
+
     /// Applies a freshly-loaded spec to the live handles (the hot-reload entry point).
     ///
     /// Lock-free: each `store()` publishes a new snapshot that subsequent `call()`s pick
     /// up. In-flight requests keep the snapshot they captured at their own `call()`, so no
-    /// future is torn and no semantics change mid-request. Returns the resolved retry
-    /// config, which the caller must apply by rebuilding the retry layer if it changed.
-    pub fn apply(&self, spec: ResilienceProfileSpec) -> RetryConfig<ExponentialBackoff> {
+    /// future is torn and no semantics change mid-request — a request already retrying
+    /// finishes on the attempt count and backoff it started with.
+    pub fn apply(&self, spec: ResilienceProfileSpec) {
         self.timeout.store(Arc::new(spec.timeout));
         self.circuit_breaker.store(Arc::new(spec.circuit_breaker));
-        spec.retry.resolve()
+        self.retry.store(Arc::new(spec.retry.resolve()));
+        self.bulkhead.store(spec.bulkhead.map(Arc::new));
+        self.hedge.store(spec.hedge.map(Arc::new));
     }
 }
 
@@ -103,7 +143,9 @@ mod tests {
     use std::time::Duration;
 
     use super::*;
-    use crate::retry::backoff::exponential::JitterKind;
+    use crate::retry::{
+        backoff::exponential::JitterKind, budget::RetryBudgetConfig, policy::NeverRetryPolicy,
+    };
 
     fn sample_spec() -> ResilienceProfileSpec {
         ResilienceProfileSpec {
@@ -116,7 +158,10 @@ mod tests {
                     max_ms: 500,
                     jitter: JitterKind::Full,
                 },
+                budget: None,
             },
+            bulkhead: None,
+            hedge: None,
         }
     }
 
@@ -124,19 +169,23 @@ mod tests {
     fn resolve_then_hot_swap_is_visible_to_new_loads() {
         let profile = sample_spec().resolve();
         assert_eq!(profile.timeout.load().duration, Duration::from_millis(2_000));
-        assert_eq!(profile.retry.max_attempts, 1);
+        assert_eq!(profile.retry.load().max_attempts, 1);
 
-        // Simulate a control-plane push tightening the deadline + tripping point.
+        // Simulate a control-plane push tightening the deadline + tripping point, and
+        // dialing retries down under a budget.
         let mut tighter = sample_spec();
         tighter.timeout = TimeoutConfig::from_millis(500);
         tighter.circuit_breaker.failure_threshold = 2;
+        tighter.retry.max_attempts = 0;
+        tighter.retry.budget = Some(RetryBudgetConfig::default());
 
-        let new_retry = profile.apply(tighter);
+        profile.apply(tighter);
 
         // A *new* load (i.e. the next request) observes the swapped values, lock-free.
         assert_eq!(profile.timeout.load().duration, Duration::from_millis(500));
         assert_eq!(profile.circuit_breaker.load().failure_threshold, 2);
-        assert_eq!(new_retry.max_attempts, 1);
+        assert_eq!(profile.retry.load().max_attempts, 0);
+        assert_eq!(profile.retry.load().budget, Some(RetryBudgetConfig::default()));
     }
 
     #[test]
@@ -148,6 +197,33 @@ mod tests {
         // observed through the layer-side handle.
         profile.timeout.store(Arc::new(TimeoutConfig::from_millis(123)));
         assert_eq!(layer.handle().load().duration, Duration::from_millis(123));
+
+        let retry = profile.retry_layer(NeverRetryPolicy);
+        profile.apply(ResilienceProfileSpec {
+            retry: RetrySpec { max_attempts: 7, ..sample_spec().retry },
+            ..sample_spec()
+        });
+        assert_eq!(retry.handle().load().max_attempts, 7);
+    }
+
+    #[test]
+    fn bulkhead_and_hedge_can_be_switched_on_and_off_by_apply() {
+        let profile = sample_spec().resolve();
+        let bulkhead = profile.bulkhead_layer();
+        let hedge = profile.hedge_layer();
+        assert!(bulkhead.handle().load().is_none() && hedge.handle().load().is_none());
+
+        profile.apply(ResilienceProfileSpec {
+            bulkhead: Some(BulkheadConfig::new(8)),
+            hedge: Some(HedgeConfig::default()),
+            ..sample_spec()
+        });
+        assert_eq!(bulkhead.handle().load_full().map(|b| b.max_concurrent), Some(8));
+        assert_eq!(hedge.handle().load_full().map(|h| h.percentile), Some(0.95));
+
+        profile.apply(sample_spec());
+        assert!(bulkhead.handle().load().is_none(), "dropped from the spec ⇒ pass-through");
+        assert!(hedge.handle().load().is_none());
     }
 
     #[cfg(feature = "serde")]
@@ -161,8 +237,11 @@ mod tests {
             },
             "retry": {
                 "max_attempts": 1,
-                "backoff": { "kind": "exponential", "base_ms": 20, "max_ms": 500, "jitter": "full" }
-            }
+                "backoff": { "kind": "exponential", "base_ms": 20, "max_ms": 500, "jitter": "full" },
+                "budget": { "ratio": 0.2 }
+            },
+            "bulkhead": { "max_concurrent": 32 },
+            "hedge": { "percentile": 0.9 }
         }"#;
 
         let spec: ResilienceProfileSpec = serde_json::from_str(json).unwrap();
@@ -170,6 +249,11 @@ mod tests {
 
         assert_eq!(profile.timeout.load().duration, Duration::from_millis(2_000));
         assert_eq!(profile.circuit_breaker.load().open_duration, Duration::from_secs(30));
-        assert_eq!(profile.retry.backoff.max_ms, 500);
+        assert_eq!(profile.retry.load().backoff.max_ms, 500);
+        let budget = profile.retry.load().budget.expect("budget parsed");
+        assert_eq!((budget.ratio, budget.max_tokens), (0.2, 100), "unset fields default");
+        assert_eq!(profile.bulkhead.load_full().map(|b| b.max_wait), Some(Duration::ZERO));
+        let hedge = profile.hedge.load_full().expect("hedge parsed");
+        assert_eq!((hedge.percentile, hedge.min_samples), (0.9, 20));
     }
 }
@@ -0,0 +1,145 @@
+use std::{sync::Mutex, time::Instant};
+
+/// Configuration for the retry budget: a token bucket that caps retries to a fraction of
+/// the traffic a [`RetryLayer`](super::RetryLayer) carries.
+///
+/// Every original request deposits `ratio` tokens and every retry withdraws one, so in
+/// steady state retries add at most `ratio` × the request rate on top of it. When a
+/// downstream fails wholesale, the bucket drains and further failures surface immediately
+/// instead of multiplying load on the struggling dependency (fleet-wide retry storms).
+/// `min_per_sec` tokens trickle in regardless of traffic, so a low-volume caller can still
+/// retry the occasional transient failure.
+///
+/// ```toml
+/// retry = { max_attempts = 3, budget = { ratio = 0.1, min_per_sec = 10, max_tokens = 100 } }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct RetryBudgetConfig {
+    /// Tokens earned per original request — the retry-to-request ratio the budget allows.
+    pub ratio: f64,
+    /// Tokens earned per second independent of traffic.
+    pub min_per_sec: u32,
+    /// Bucket capacity: the largest burst of retries the budget ever admits.
+    pub max_tokens: u32,
+}
+
+impl Default for RetryBudgetConfig {
+    /// 10% retries on top of traffic, a 10/s floor, bursts of up to 100.
+    fn default() -> Self {
+        Self { ratio: 0.1, min_per_sec: 10, max_tokens: 100 }
+    }
+}
+
+struct Bucket {
+    tokens: f64,
+    refilled: Instant,
+}
+
+/// Live token bucket backing a [`RetryBudgetConfig`].
+///
+/// Owned by a [`RetryLayer`](super::RetryLayer) and shared (via `Arc`) by every service it
+/// produces, so one budget covers one logical downstream dependency. The config is passed in
+/// per operation rather than stored, so a hot-swap retunes the bucket without resetting its
+/// balance. A fresh budget starts full.
+pub struct RetryBudget {
+    bucket: Mutex<Bucket>,
+}
+
+impl Default for RetryBudget {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RetryBudget {
+    pub fn new() -> Self {
+        Self {
+            // Clamped to `max_tokens` by the first refill.
+            bucket: Mutex::new(Bucket { tokens: f64::INFINITY, refilled: Instant::now() }),
+        }
+    }
+
+    /// Credits one original request.
+    pub fn deposit(&self, config: &RetryBudgetConfig) {
+        let mut bucket = self.refill(config);
+        bucket.tokens = (bucket.tokens + config.ratio).min(f64::from(config.max_tokens));
+    }
+
+    /// Spends one token for a retry; `false` when the budget is exhausted.
+    pub fn try_withdraw(&self, config: &RetryBudgetConfig) -> bool {
+        let mut bucket = self.refill(config);
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Current balance, after accruing the time-based floor.
+    pub fn balance(&self, config: &RetryBudgetConfig) -> f64 {
+        self.refill(config).tokens
+    }
+
+    /// Accrues `min_per_sec` for the time since the last touch, capped at `max_tokens`. The
+    /// guard is returned so the caller's update lands in the same critical section.
+    fn refill(&self, config: &RetryBudgetConfig) -> std::sync::MutexGuard<'_, Bucket> {
+        let mut bucket = self.bucket.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        let now = Instant::now();
+        let elapsed = now.duration_since(bucket.refilled).as_secs_f64();
+        let earned = elapsed * f64::from(config.min_per_sec);
+        bucket.tokens = (bucket.tokens + earned).min(f64::from(config.max_tokens));
+        bucket.refilled = now;
+        bucket
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// No time-based floor, so the balance only moves on deposits and withdrawals.
+    fn config(ratio: f64, max_tokens: u32) -> RetryBudgetConfig {
+        RetryBudgetConfig { ratio, min_per_sec: 0, max_tokens }
+    }
+
+    #[test]
+    fn starts_full_and_drains_one_token_per_retry() {
+        let budget = RetryBudget::new();
+        let cfg = config(0.1, 3);
+
+        assert_eq!(budget.balance(&cfg), 3.0);
+        for _ in 0..3 {
+            assert!(budget.try_withdraw(&cfg));
+        }
+        assert!(!budget.try_withdraw(&cfg), "exhausted after max_tokens retries");
+    }
+
+    #[test]
+    fn deposits_refill_at_the_configured_ratio() {
+        let budget = RetryBudget::new();
+        let cfg = config(0.5, 1);
+        assert!(budget.try_withdraw(&cfg));
+
+        budget.deposit(&cfg);
+        assert!(!budget.try_withdraw(&cfg), "half a token is not a retry");
+        budget.deposit(&cfg);
+        assert!(budget.try_withdraw(&cfg), "two requests at 0.5 earn one retry");
+    }
+
+    #[test]
+    fn a_tighter_config_clamps_the_live_balance() {
+        let budget = RetryBudget::new();
+        assert_eq!(budget.balance(&config(0.1, 100)), 100.0);
+        assert_eq!(budget.balance(&config(0.1, 5)), 5.0, "hot-swap to a smaller bucket");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn deserializes_with_defaults() {
+        let cfg: RetryBudgetConfig = serde_json::from_str(r#"{ "ratio": 0.2 }"#).unwrap();
+        assert_eq!(cfg, RetryBudgetConfig { ratio: 0.2, ..RetryBudgetConfig::default() });
+    }
+}
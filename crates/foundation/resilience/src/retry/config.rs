@@ -1,4 +1,7 @@
-use super::backoff::{exponential::ExponentialBackoff, spec::BackoffSpec};
+use super::{
+    backoff::{exponential::ExponentialBackoff, spec::BackoffSpec},
+    budget::RetryBudgetConfig,
+};
 
 /// Configuration for the retry middleware.
 ///
@@ -11,21 +14,27 @@ pub struct RetryConfig<B> {
     pub max_attempts: u32,
     /// Backoff strategy used to compute the inter-attempt delay.
     pub backoff: B,
+    /// Token-bucket cap on retries as a fraction of traffic. `None` leaves retries bounded
+    /// only by `max_attempts`.
+    pub budget: Option<RetryBudgetConfig>,
 }
 
 impl RetryConfig<ExponentialBackoff> {
     /// Sensible production default: 3 retries, exponential backoff with full jitter.
     pub fn default_exponential() -> Self {
-        Self {
-            max_attempts: 3,
-            backoff: ExponentialBackoff::default(),
-        }
+        Self::new(3, ExponentialBackoff::default())
     }
 }
 
 impl<B> RetryConfig<B> {
     pub fn new(max_attempts: u32, backoff: B) -> Self {
-        Self { max_attempts, backoff }
+        Self { max_attempts, backoff, budget: None }
+    }
+
+    /// Caps retries with a [`RetryBudget`](super::budget::RetryBudget).
+    pub fn with_budget(mut self, budget: RetryBudgetConfig) -> Self {
+        self.budget = Some(budget);
+        self
     }
 }
 
@@ -40,10 +49,13 @@ pub struct RetrySpec {
     pub max_attempts: u32,
     #[cfg_attr(feature = "serde", serde(default))]
     pub backoff: BackoffSpec,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub budget: Option<RetryBudgetConfig>,
 }
 
 impl RetrySpec {
     pub fn resolve(self) -> RetryConfig<ExponentialBackoff> {
-        RetryConfig::new(self.max_attempts, self.backoff.resolve())
+        let config = RetryConfig::new(self.max_attempts, self.backoff.resolve());
+        RetryConfig { budget: self.budget, ..config }
     }
 }
@@ -1,9 +1,19 @@
+use std::sync::Arc;
+
+use arc_swap::ArcSwap;
 use tower::Layer;
 
-use super::{config::RetryConfig, service::RetryService};
+use super::{budget::RetryBudget, config::RetryConfig, service::RetryService};
 
 /// Tower [`Layer`] that wraps an inner service with retry logic.
 ///
+/// The config lives behind a shared [`ArcSwap`] handle, like the timeout and circuit-breaker
+/// layers: [`new`](RetryLayer::new) seeds a fresh one, [`from_handle`](RetryLayer::from_handle)
+/// shares an externally-owned one (e.g. [`crate::ResilienceProfile`]'s) so the control plane
+/// can retune `max_attempts`, backoff and budget at runtime. One layer owns one
+/// [`RetryBudget`], shared across every service clone it produces, so the budget covers one
+/// logical downstream dependency and a config swap never resets its balance.
+///
 /// # Example
 ///
 /// ```rust,ignore
@@ -14,26 +24,51 @@ use super::{config::RetryConfig, service::RetryService};
 ///     .layer(RetryLayer::new(RetryConfig::default_exponential(), DefaultRetryPolicy))
 ///     .service(my_inner_service);
 /// ```
-#[derive(Clone)]
 pub struct RetryLayer<P, B> {
-    config: RetryConfig<B>,
+    config: Arc<ArcSwap<RetryConfig<B>>>,
+    budget: Arc<RetryBudget>,
     policy: P,
 }
 
+impl<P: Clone, B> Clone for RetryLayer<P, B> {
+    fn clone(&self) -> Self {
+        Self {
+            config: Arc::clone(&self.config),
+            budget: Arc::clone(&self.budget),
+            policy: self.policy.clone(),
+        }
+    }
+}
+
 impl<P, B> RetryLayer<P, B> {
+    /// Builds a layer owning a fresh [`ArcSwap`] seeded with `config`.
     pub fn new(config: RetryConfig<B>, policy: P) -> Self {
-        Self { config, policy }
+        Self::from_handle(Arc::new(ArcSwap::from_pointee(config)), policy)
+    }
+
+    /// Builds a layer that shares an externally-owned handle so swaps propagate to this stack.
+    pub fn from_handle(config: Arc<ArcSwap<RetryConfig<B>>>, policy: P) -> Self {
+        Self { config, budget: Arc::new(RetryBudget::new()), policy }
+    }
+
+    /// Returns the shared handle so a control plane can `store()` new configs at runtime.
+    pub fn handle(&self) -> Arc<ArcSwap<RetryConfig<B>>> {
+        Arc::clone(&self.config)
     }
 }
 
 impl<S, P, B> Layer<S> for RetryLayer<P, B>
 where
     P: Clone,
-    B: Clone,
 {
     type Service = RetryService<S, P, B>;
 
     fn layer(&self, inner: S) -> Self::Service {
-        RetryService::new(inner, self.config.clone(), self.policy.clone())
+        RetryService::new(
+            inner,
+            Arc::clone(&self.config),
+            Arc::clone(&self.budget),
+            self.policy.clone(),
+        )
     }
 }
@@ -1,10 +1,12 @@
 pub mod backoff;
+pub mod budget;
 pub mod config;
 pub mod layer;
 pub mod policy;
 pub mod service;
 
 pub use backoff::*;
+pub use budget::*;
 pub use config::*;
 pub use layer::*;
 pub use policy::*;
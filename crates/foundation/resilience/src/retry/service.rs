@@ -1,12 +1,17 @@
 use std::{
     future::Future,
     pin::Pin,
+    sync::Arc,
     task::{Context, Poll},
 };
 
+use arc_swap::ArcSwap;
 use tower::{Service, ServiceExt};
 
-use super::{backoff::strategy::BackoffStrategy, config::RetryConfig, policy::RetryPolicy};
+use super::{
+    backoff::strategy::BackoffStrategy, budget::RetryBudget, config::RetryConfig,
+    policy::RetryPolicy,
+};
 use crate::error::ResilienceError;
 
 /// Tower [`Service`] that retries the inner service on transient failures.
@@ -16,15 +21,24 @@ use crate::error::ResilienceError;
 /// instance before calling — readiness belongs to the instance the caller
 /// polled, and a Buffer-backed inner (tonic's `Channel`) panics when `call`ed
 /// on a clone whose slot was never reserved.
+///
+/// The config is sampled once per `call`, so a hot-swap applies from the next request and
+/// never changes the attempt count or backoff of a request already retrying.
 pub struct RetryService<S, P, B> {
     inner: S,
-    config: RetryConfig<B>,
+    config: Arc<ArcSwap<RetryConfig<B>>>,
+    budget: Arc<RetryBudget>,
     policy: P,
 }
 
 impl<S, P, B> RetryService<S, P, B> {
-    pub(crate) fn new(inner: S, config: RetryConfig<B>, policy: P) -> Self {
-        Self { inner, config, policy }
+    pub(crate) fn new(
+        inner: S,
+        config: Arc<ArcSwap<RetryConfig<B>>>,
+        budget: Arc<RetryBudget>,
+        policy: P,
+    ) -> Self {
+        Self { inner, config, budget, policy }
     }
 }
 
@@ -50,8 +64,12 @@ where
         // clone behind for the next poll_ready.
         let clone = self.inner.clone();
         let mut svc = std::mem::replace(&mut self.inner, clone);
-        let config = self.config.clone();
+        let config = self.config.load_full(); // request-scoped snapshot
+        let budget = Arc::clone(&self.budget);
         let policy = self.policy.clone();
+        if let Some(limits) = &config.budget {
+            budget.deposit(limits);
+        }
 
         Box::pin(async move {
             let mut attempt = 0u32;
@@ -88,6 +106,14 @@ where
                                     Err(ResilienceError::Inner(e))
                                 };
                             }
+                            if let Some(limits) = &config.budget
+                                && !budget.try_withdraw(limits)
+                            {
+                                // Retrying now would add load the budget says the
+                                // downstream can't take — surface the failure as-is.
+                                tracing::warn!(attempt, "retry budget exhausted — not retrying");
+                                return Err(ResilienceError::Inner(e));
+                            }
 
                             config.backoff.next_delay(attempt)
                         }
@@ -115,9 +141,13 @@ mod tests {
 
     use tower::{service_fn, ServiceExt};
 
+    use tower::Layer;
+
     use super::*;
     use crate::retry::{
         backoff::exponential::{ExponentialBackoff, JitterKind},
+        budget::RetryBudgetConfig,
+        layer::RetryLayer,
         policy::{AlwaysRetryPolicy, NeverRetryPolicy},
     };
 
@@ -126,6 +156,14 @@ mod tests {
         RetryConfig::new(max_attempts, ExponentialBackoff::new(1, 1, JitterKind::None))
     }
 
+    fn retrying<S, P: Clone>(
+        inner: S,
+        config: RetryConfig<ExponentialBackoff>,
+        policy: P,
+    ) -> RetryService<S, P, ExponentialBackoff> {
+        RetryLayer::new(config, policy).layer(inner)
+    }
+
     #[tokio::test]
     async fn succeeds_after_transient_failures() {
         let calls = Arc::new(AtomicU32::new(0));
@@ -141,7 +179,7 @@ mod tests {
                 }
             }
         });
-        let mut svc = RetryService::new(inner, fast_config(5), AlwaysRetryPolicy);
+        let mut svc = retrying(inner, fast_config(5), AlwaysRetryPolicy);
 
         let out = svc.ready().await.unwrap().call(()).await.unwrap();
         assert_eq!(out, "ok");
@@ -151,7 +189,7 @@ mod tests {
     #[tokio::test]
     async fn exhausts_budget() {
         let inner = service_fn(|_: ()| async { Err::<(), &str>("always") });
-        let mut svc = RetryService::new(inner, fast_config(2), AlwaysRetryPolicy);
+        let mut svc = retrying(inner, fast_config(2), AlwaysRetryPolicy);
 
         let err = svc.ready().await.unwrap().call(()).await.unwrap_err();
         assert!(matches!(err, ResilienceError::MaxRetriesExhausted(2)));
@@ -165,13 +203,53 @@ mod tests {
             counter.fetch_add(1, Ordering::SeqCst);
             async { Err::<(), &str>("fatal") }
         });
-        let mut svc = RetryService::new(inner, fast_config(5), NeverRetryPolicy);
+        let mut svc = retrying(inner, fast_config(5), NeverRetryPolicy);
 
         let err = svc.ready().await.unwrap().call(()).await.unwrap_err();
         assert!(matches!(err, ResilienceError::Inner("fatal")));
         assert_eq!(calls.load(Ordering::SeqCst), 1, "no retries for a non-retryable error");
     }
 
+    #[tokio::test]
+    async fn exhausted_budget_surfaces_the_failure_without_retrying() {
+        let calls = Arc::new(AtomicU32::new(0));
+        let counter = Arc::clone(&calls);
+        let inner = service_fn(move |_: ()| {
+            counter.fetch_add(1, Ordering::SeqCst);
+            async { Err::<(), &str>("down") }
+        });
+        // Two tokens, no floor, negligible deposits: the bucket funds exactly two retries.
+        let budget = RetryBudgetConfig { ratio: 0.01, min_per_sec: 0, max_tokens: 2 };
+        let mut svc = retrying(inner, fast_config(5).with_budget(budget), AlwaysRetryPolicy);
+
+        let err = svc.ready().await.unwrap().call(()).await.unwrap_err();
+        assert!(matches!(err, ResilienceError::Inner("down")), "budget, not max_attempts");
+        assert_eq!(calls.load(Ordering::SeqCst), 3, "1 call + the 2 budgeted retries");
+
+        let err = svc.ready().await.unwrap().call(()).await.unwrap_err();
+        assert!(matches!(err, ResilienceError::Inner("down")));
+        assert_eq!(calls.load(Ordering::SeqCst), 4, "an empty budget stops retries outright");
+    }
+
+    #[tokio::test]
+    async fn hot_swapped_config_applies_to_the_next_call() {
+        let calls = Arc::new(AtomicU32::new(0));
+        let counter = Arc::clone(&calls);
+        let inner = service_fn(move |_: ()| {
+            counter.fetch_add(1, Ordering::SeqCst);
+            async { Err::<(), &str>("always") }
+        });
+        let layer = RetryLayer::new(fast_config(4), AlwaysRetryPolicy);
+        let mut svc = layer.layer(inner);
+
+        // An incident dial-down: no more retries for this dependency.
+        layer.handle().store(Arc::new(fast_config(0)));
+
+        let err = svc.ready().await.unwrap().call(()).await.unwrap_err();
+        assert!(matches!(err, ResilienceError::MaxRetriesExhausted(0)));
+        assert_eq!(calls.load(Ordering::SeqCst), 1, "the swapped max_attempts applies");
+    }
+
     /// Regression (staging soak finding #16): same Buffer-backed-inner panic as
     /// the circuit breaker. The retry loop re-drives `ready()` on the taken
     /// instance each attempt; this must not panic and must still retry.
@@ -192,7 +270,7 @@ mod tests {
             }
         });
         let buffered = Buffer::new(inner, 8);
-        let mut svc = RetryService::new(buffered, fast_config(5), AlwaysRetryPolicy);
+        let mut svc = retrying(buffered, fast_config(5), AlwaysRetryPolicy);
 
         let out = svc.ready().await.unwrap().call(()).await.unwrap();
         assert_eq!(out, "ok");
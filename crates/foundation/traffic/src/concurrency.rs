@@ -0,0 +1,328 @@
+//! Adaptive concurrency limiting — load shedding by requests in flight rather than by rate.
+//!
+//! A rate limit caps *arrivals*; it can't see a replica slowing down. When a backend's
+//! latency spikes, the same arrival rate turns into ever more requests in flight (Little's
+//! law) until the replica falls over. A [`ConcurrencyLimiter`] caps in-flight requests at a
+//! limit it *discovers* from completion latency, in the style of TCP congestion control:
+//!
+//! * [`Algorithm::Aimd`] — grows the limit by one per limit's worth of on-time completions
+//!   and multiplies it by `backoff_ratio` on an overload signal: a completion slower than
+//!   `latency_threshold_ms`, or one reported [`dropped`](ConcurrencyPermit::dropped).
+//! * [`Algorithm::Gradient`] — tracks a slow-moving latency baseline and scales the limit by
+//!   `tolerance × baseline / sample` (capped at 1) plus a √limit queue allowance, so it
+//!   shrinks in proportion once samples run past `tolerance` × the baseline, with no
+//!   threshold to tune. A dropped completion backs off like AIMD.
+//!
+//! Both grow the limit only while at least half of it is in use, so an idle replica never
+//! inflates a limit it hasn't tested. The limit stays within `[min_limit, max_limit]`.
+//!
+//! # Priority classes
+//!
+//! Shedding is ordered by [`Priority`]: `sheddable` requests are admitted while in-flight
+//! stays below `sheddable_share` of the limit, `normal` ones up to the limit, and `critical`
+//! ones (health checks, account-status lookups) up to `max_limit` — shed last, once the
+//! replica is past even its configured ceiling.
+//!
+//! # Hot reload
+//!
+//! [`apply`](ConcurrencyLimiter::apply) swaps the dials in place. The discovered limit
+//! survives (clamped into the new bounds), so a reload never resets a limit that has backed
+//! off under load; switching algorithm drops the gradient baseline.
+
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use arc_swap::ArcSwap;
+
+/// Smoothing factor of the gradient baseline — slow, so a sustained spike still reads as
+/// one for a while before the baseline absorbs it.
+const BASELINE_SMOOTHING: f64 = 0.05;
+
+/// Weight of each gradient estimate in the limit — damps sample-to-sample noise.
+const LIMIT_SMOOTHING: f64 = 0.2;
+
+/// Floor of the gradient: one sample never more than halves the limit.
+const MIN_GRADIENT: f64 = 0.5;
+
+/// Shedding order of a request when the replica is at its concurrency limit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Priority {
+    /// Shed last — admitted up to `max_limit` (health checks, account-status lookups).
+    Critical,
+    /// Admitted up to the discovered limit.
+    #[default]
+    Normal,
+    /// Shed first — admitted up to `sheddable_share` of the limit (feeds, prefetches).
+    Sheddable,
+}
+
+impl Priority {
+    /// Snake-case name, as written in config — for metric labels.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Critical => "critical",
+            Self::Normal => "normal",
+            Self::Sheddable => "sheddable",
+        }
+    }
+}
+
+/// How the limit reacts to completion latency.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Algorithm {
+    /// Additive increase, multiplicative decrease past a latency threshold.
+    #[default]
+    Aimd,
+    /// Scales with the ratio of a latency baseline to each sample.
+    Gradient,
+}
+
+/// The adaptive concurrency dials — the `[traffic.concurrency]` block.
+///
+/// ```toml
+/// [traffic.concurrency]
+/// algorithm            = "aimd"
+/// initial_limit        = 40
+/// min_limit            = 8
+/// max_limit            = 400
+/// latency_threshold_ms = 250
+/// ```
+///
+/// Every field has a default, so an empty block enables AIMD with the defaults below.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct ConcurrencySpec {
+    pub algorithm: Algorithm,
+    /// Limit before any latency has been observed.
+    pub initial_limit: u32,
+    /// The limit never backs off below this.
+    pub min_limit: u32,
+    /// The limit never grows past this; `critical` requests are admitted up to it.
+    pub max_limit: u32,
+    /// AIMD-only: a completion slower than this is an overload signal.
+    pub latency_threshold_ms: u64,
+    /// Factor the limit is multiplied by on an overload signal, in `[0.5, 1)`.
+    pub backoff_ratio: f64,
+    /// Gradient-only: how far past the baseline latency may run before the limit shrinks.
+    pub tolerance: f64,
+    /// Fraction of the limit `sheddable` requests may occupy, in `(0, 1]`.
+    pub sheddable_share: f64,
+    /// Whether shed decisions are acted on; `false` is shadow mode, as for rate profiles.
+    pub enforce: bool,
+}
+
+impl Default for ConcurrencySpec {
+    fn default() -> Self {
+        Self {
+            algorithm: Algorithm::Aimd,
+            initial_limit: 20,
+            min_limit: 4,
+            max_limit: 500,
+            latency_threshold_ms: 1_000,
+            backoff_ratio: 0.9,
+            tolerance: 2.0,
+            sheddable_share: 0.75,
+            enforce: true,
+        }
+    }
+}
+
+impl ConcurrencySpec {
+    /// Lowers the spec into a live [`ConcurrencyLimiter`] starting at `initial_limit`.
+    pub fn resolve(&self) -> ConcurrencyLimiter {
+        let limit = f64::from(self.initial_limit.clamp(self.min_limit, self.max_limit).max(1));
+        ConcurrencyLimiter {
+            inner: Arc::new(Inner {
+                config: ArcSwap::from_pointee(self.clone()),
+                in_flight: AtomicU32::new(0),
+                limit: AtomicU32::new(limit as u32),
+                estimate: Mutex::new(Estimate { limit, baseline_ms: None }),
+            }),
+        }
+    }
+}
+
+/// Live, replica-wide concurrency limiter. Cheap to clone; clones share one limit.
+#[derive(Clone)]
+pub struct ConcurrencyLimiter {
+    inner: Arc<Inner>,
+}
+
+struct Inner {
+    config: ArcSwap<ConcurrencySpec>,
+    in_flight: AtomicU32,
+    /// The rounded limit, read lock-free on admission.
+    limit: AtomicU32,
+    /// The fractional limit and gradient baseline, updated on completion.
+    estimate: Mutex<Estimate>,
+}
+
+struct Estimate {
+    limit: f64,
+    baseline_ms: Option<f64>,
+}
+
+impl ConcurrencyLimiter {
+    /// Takes a slot for a `priority` request, or `None` when the request should be shed.
+    pub fn try_acquire(&self, priority: Priority) -> Option<ConcurrencyPermit> {
+        let ceiling = self.ceiling(priority);
+        self.inner
+            .in_flight
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |n| (n < ceiling).then_some(n + 1))
+            .ok()
+            .map(|_| self.permit())
+    }
+
+    /// Takes a slot regardless of the limit — for requests admitted in shadow mode, so
+    /// in-flight and latency stay truthful while nothing is shed.
+    pub fn acquire(&self) -> ConcurrencyPermit {
+        self.inner.in_flight.fetch_add(1, Ordering::AcqRel);
+        self.permit()
+    }
+
+    /// The current discovered limit.
+    pub fn limit(&self) -> u32 {
+        self.inner.limit.load(Ordering::Acquire)
+    }
+
+    /// Requests currently holding a permit.
+    pub fn in_flight(&self) -> u32 {
+        self.inner.in_flight.load(Ordering::Acquire)
+    }
+
+    /// Whether shed decisions should be acted on (`false` = shadow mode).
+    pub fn enforce(&self) -> bool {
+        self.inner.config.load().enforce
+    }
+
+    /// A snapshot of the current dials.
+    pub fn config(&self) -> ConcurrencySpec {
+        (**self.inner.config.load()).clone()
+    }
+
+    /// Applies freshly-loaded dials (the hot-reload entry point); see the module note.
+    pub fn apply(&self, spec: &ConcurrencySpec) {
+        let mut estimate = self.inner.estimate.lock().expect("concurrency estimate poisoned");
+        if spec.algorithm != self.inner.config.load().algorithm {
+            estimate.baseline_ms = None;
+        }
+        self.inner.config.store(Arc::new(spec.clone()));
+        let current = estimate.limit;
+        self.inner.set_limit(&mut estimate, spec, current);
+    }
+
+    /// In-flight ceiling for `priority` under the current limit.
+    fn ceiling(&self, priority: Priority) -> u32 {
+        let limit = self.limit();
+        match priority {
+            Priority::Critical => limit.max(self.inner.config.load().max_limit),
+            Priority::Normal => limit,
+            Priority::Sheddable => {
+                let share = self.inner.config.load().sheddable_share;
+                ((f64::from(limit) * share) as u32).max(1)
+            }
+        }
+    }
+
+    fn permit(&self) -> ConcurrencyPermit {
+        ConcurrencyPermit {
+            inner: Arc::clone(&self.inner),
+            started: Instant::now(),
+            outcome: None,
+        }
+    }
+}
+
+impl Inner {
+    /// Feeds one completion into the estimator. `in_flight` counts this request.
+    fn record(&self, latency: Duration, dropped: bool, in_flight: u32) {
+        let config = self.config.load();
+        let mut estimate = self.estimate.lock().expect("concurrency estimate poisoned");
+        let limit = estimate.limit;
+        let in_use = f64::from(in_flight) >= limit / 2.0;
+        let sample_ms = latency.as_secs_f64() * 1_000.0;
+
+        let next = match (dropped, config.algorithm) {
+            (true, _) => limit * config.backoff_ratio,
+            (false, Algorithm::Aimd) => {
+                if sample_ms > config.latency_threshold_ms as f64 {
+                    limit * config.backoff_ratio
+                } else if in_use {
+                    limit + 1.0 / limit
+                } else {
+                    limit
+                }
+            }
+            (false, Algorithm::Gradient) => {
+                let baseline = match estimate.baseline_ms {
+                    Some(baseline) => baseline + (sample_ms - baseline) * BASELINE_SMOOTHING,
+                    None => sample_ms,
+                };
+                estimate.baseline_ms = Some(baseline);
+                let gradient = if sample_ms > 0.0 {
+                    (config.tolerance * baseline / sample_ms).clamp(MIN_GRADIENT, 1.0)
+                } else {
+                    1.0
+                };
+                let mut target = limit * gradient + limit.sqrt();
+                if !in_use {
+                    target = target.min(limit);
+                }
+                limit + (target - limit) * LIMIT_SMOOTHING
+            }
+        };
+        self.set_limit(&mut estimate, &config, next);
+    }
+
+    fn set_limit(&self, estimate: &mut Estimate, config: &ConcurrencySpec, next: f64) {
+        let floor = f64::from(config.min_limit.max(1));
+        let ceiling = f64::from(config.max_limit).max(floor);
+        estimate.limit = next.clamp(floor, ceiling);
+        self.limit.store(estimate.limit as u32, Ordering::Release);
+    }
+}
+
+/// How a request holding a permit ended.
+#[derive(Debug, Clone, Copy)]
+enum Outcome {
+    Success,
+    Dropped,
+}
+
+/// One admitted request's slot. Report how it ended with [`success`](Self::success) or
+/// [`dropped`](Self::dropped); dropping the permit unreported (a cancelled request) frees
+/// the slot without feeding the estimator.
+pub struct ConcurrencyPermit {
+    inner: Arc<Inner>,
+    started: Instant,
+    outcome: Option<Outcome>,
+}
+
+impl ConcurrencyPermit {
+    /// The request completed; its latency is a limit sample.
+    pub fn success(mut self) {
+        self.outcome = Some(Outcome::Success);
+    }
+
+    /// The request failed in a way that signals overload (a downstream timeout or
+    /// unavailability) — backs the limit off whatever its latency.
+    pub fn dropped(mut self) {
+        self.outcome = Some(Outcome::Dropped);
+    }
+}
+
+impl Drop for ConcurrencyPermit {
+    fn drop(&mut self) {
+        let in_flight = self.inner.in_flight.fetch_sub(1, Ordering::AcqRel);
+        if let Some(outcome) = self.outcome {
+            let dropped = matches!(outcome, Outcome::Dropped);
+            self.inner.record(self.started.elapsed(), dropped, in_flight);
+        }
+    }
+}
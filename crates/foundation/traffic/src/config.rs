@@ -13,6 +13,17 @@ pub enum Scope {
     /// One bucket per authenticated caller per method. Requires an upstream layer to have
     /// established the principal; falls back to method-level keying when none is present.
     PerCaller,
+    /// One bucket per client IP per method — for pre-auth abuse (`Login`, handshakes). Falls
+    /// back to method-level keying when no address is known.
+    PerIp,
+    /// One bucket per caller tenant per method. Falls back to method-level keying for
+    /// callers outside any tenant.
+    PerTenant,
+    /// One bucket per value of a request field per method (e.g. every comment on one
+    /// `post_id` shares a bucket). The field is named by the profile's
+    /// [`attribute`](TrafficConfig::attribute); falls back to method-level keying when the
+    /// method has no such field or the request leaves it empty.
+    PerAttribute,
 }
 
 /// Where the limiter's counter state lives.
@@ -20,16 +31,17 @@ pub enum Scope {
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum Mode {
-    /// In-process, per-replica. The only mode enforced today.
+    /// In-process, per-replica.
     #[default]
     Local,
-    /// Redis-coordinated global lease. Parsed for forward-compatibility but not yet
-    /// enforced — `infra-config` validation rejects it until Step 2 ships the backend.
+    /// Fleet-global budget coordinated through a [`QuotaBackend`](crate::QuotaBackend)
+    /// (`traffic-redis` leases it from Redis). Degrades to the local limiter when no backend
+    /// is installed.
     Distributed,
 }
 
 /// What a distributed profile does when its coordination backend is unreachable.
-/// Parsed now so adding the distributed backend needs no schema migration; inert until then.
+/// Consulted only when a backend call fails; without a backend the profile is local.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
@@ -50,6 +62,8 @@ pub struct TrafficConfig {
     pub burst: u32,
     /// Key dimension.
     pub scope: Scope,
+    /// `per_attribute`-only: the request field to key on (e.g. `post_id`).
+    pub attribute: Option<String>,
     /// State-locality mode.
     pub mode: Mode,
     /// Whether throttle decisions are *acted on*. `true` (default) rejects; `false` is
@@ -57,9 +71,9 @@ pub struct TrafficConfig {
     /// the request is admitted. Hot-reloadable, so a pilot promotes shadow → enforce (or
     /// rolls back) by editing the ConfigMap, with no redeploy.
     pub enforce: bool,
-    /// Distributed-only (Step 2): replica↔backend lease sync cadence, milliseconds.
+    /// Distributed-only: replica↔backend lease sync cadence, milliseconds.
     pub lease_ms: Option<u64>,
-    /// Distributed-only (Step 2): backend-failure policy.
+    /// Distributed-only: backend-failure policy.
     pub on_backend_error: Option<BackendError>,
 }
 
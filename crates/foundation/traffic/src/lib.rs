@@ -10,16 +10,27 @@
 //! plumbing. The gRPC layer that extracts a key from a request and translates a `Throttle`
 //! into `RESOURCE_EXHAUSTED` lives in `transport`, where the tonic/http coupling belongs.
 //!
-//! # State locality (Step 1)
+//! # State locality
 //!
-//! Only [`Mode::Local`] — in-process, per-replica `governor` (GCRA) limiters — is enforced.
-//! [`Mode::Distributed`] is parsed for forward-compatibility but rejected by `infra-config`
-//! validation until the Redis-lease backend ships (Step 2).
+//! [`Mode::Local`] is an in-process, per-replica `governor` (GCRA) limiter.
+//! [`Mode::Distributed`] charges a fleet-global budget through an injected [`QuotaBackend`]
+//! (the Redis-lease implementation lives in `traffic-redis`); with no backend installed it
+//! degrades to the local limiter.
+//!
+//! # Concurrency
+//!
+//! Rate profiles cap arrivals; the [`ConcurrencyLimiter`] caps requests *in flight* at a
+//! limit discovered from completion latency (AIMD or gradient), shedding by [`Priority`]
+//! class. It is replica-wide — one limiter per process, configured by `[traffic.concurrency]`.
 
 pub mod backend;
+pub mod concurrency;
 pub mod config;
 pub mod profile;
 
 pub use backend::{Quota, QuotaBackend, QuotaError, DEFAULT_LEASE_MS};
+pub use concurrency::{
+    Algorithm, ConcurrencyLimiter, ConcurrencyPermit, ConcurrencySpec, Priority,
+};
 pub use config::{BackendError, Mode, Scope, TrafficConfig, TrafficDecision};
 pub use profile::{TrafficProfile, TrafficProfileSpec};
@@ -35,6 +35,12 @@ use crate::config::{BackendError, Mode, Scope, TrafficConfig, TrafficDecision};
 /// rps = 50
 /// burst = 10
 /// scope = "per_caller"
+///
+/// [traffic.profiles.comment-flood]
+/// rps = 5
+/// burst = 5
+/// scope = "per_attribute"
+/// attribute = "post_id"
 /// ```
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -43,6 +49,9 @@ pub struct TrafficProfileSpec {
     pub burst: u32,
     #[cfg_attr(feature = "serde", serde(default))]
     pub scope: Scope,
+    /// The request field a `per_attribute` profile keys on; ignored by other scopes.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub attribute: Option<String>,
     #[cfg_attr(feature = "serde", serde(default))]
     pub mode: Mode,
     /// Whether throttle decisions are enforced; `false` is shadow mode. Defaults to `true`
@@ -67,6 +76,7 @@ impl TrafficProfileSpec {
             rps: self.rps,
             burst: self.burst,
             scope: self.scope,
+            attribute: self.attribute.clone(),
             mode: self.mode,
             enforce: self.enforce,
             lease_ms: self.lease_ms,
@@ -110,6 +120,11 @@ impl TrafficProfile {
         self.config.load().scope
     }
 
+    /// The request field a `per_attribute` profile keys on.
+    pub fn attribute(&self) -> Option<String> {
+        self.config.load().attribute.clone()
+    }
+
     /// The state-locality mode.
     pub fn mode(&self) -> Mode {
         self.config.load().mode
@@ -138,7 +153,7 @@ impl TrafficProfile {
     }
 
     /// Drops keys that are no longer rate-limiting (idle). Call periodically to bound memory
-    /// for unbounded keyspaces (`per_caller`); a no-op-cheap sweep otherwise.
+    /// for unbounded keyspaces (every scope but `per_method`); a no-op-cheap sweep otherwise.
     pub fn prune(&self) {
         self.limiter.load().retain_recent();
     }
@@ -163,12 +178,12 @@ impl TrafficProfile {
         self.config.store(Arc::new(next));
     }
 
-    /// Helper for callers that only care whether distributed mode is requested (Step 2).
+    /// Helper for callers that only care whether distributed mode is requested.
     pub fn is_distributed(&self) -> bool {
         matches!(self.mode(), Mode::Distributed)
     }
 
-    /// Helper exposing the backend-failure policy (Step 2); `None` outside distributed mode.
+    /// Helper exposing the backend-failure policy; `None` outside distributed mode.
     pub fn on_backend_error(&self) -> Option<BackendError> {
         self.config.load().on_backend_error
     }
@@ -0,0 +1,110 @@
+//! Behavioural tests for the adaptive concurrency limiter: priority shedding, AIMD and
+//! gradient adaptation, shadow admission, hot-reload.
+
+use std::thread::sleep;
+use std::time::Duration;
+
+use traffic::{Algorithm, ConcurrencySpec, Priority};
+
+fn spec(initial_limit: u32) -> ConcurrencySpec {
+    ConcurrencySpec { initial_limit, min_limit: 1, max_limit: 20, ..ConcurrencySpec::default() }
+}
+
+#[test]
+fn sheds_sheddable_first_and_critical_last() {
+    // limit 4, sheddable_share 0.75 → sheddable capped at 3, normal at 4, critical at 20.
+    let limiter = spec(4).resolve();
+    let mut held = Vec::new();
+    for _ in 0..3 {
+        held.push(limiter.try_acquire(Priority::Sheddable).expect("under the sheddable share"));
+    }
+    assert!(limiter.try_acquire(Priority::Sheddable).is_none(), "sheddable shed at 3");
+
+    held.push(limiter.try_acquire(Priority::Normal).expect("normal admitted up to the limit"));
+    assert!(limiter.try_acquire(Priority::Normal).is_none(), "normal shed at the limit");
+
+    held.push(limiter.try_acquire(Priority::Critical).expect("critical admitted past the limit"));
+    assert_eq!(limiter.in_flight(), 5);
+
+    drop(held);
+    assert_eq!(limiter.in_flight(), 0, "every permit frees its slot");
+}
+
+#[test]
+fn aimd_backs_off_on_dropped_and_slow_completions() {
+    let limiter = ConcurrencySpec { latency_threshold_ms: 1, ..spec(10) }.resolve();
+
+    limiter.try_acquire(Priority::Normal).unwrap().dropped();
+    assert_eq!(limiter.limit(), 9, "10 × 0.9");
+
+    let slow = limiter.try_acquire(Priority::Normal).unwrap();
+    sleep(Duration::from_millis(5));
+    slow.success();
+    assert_eq!(limiter.limit(), 8, "9 × 0.9, past the latency threshold");
+}
+
+#[test]
+fn aimd_grows_only_while_the_limit_is_in_use() {
+    let limiter = spec(2).resolve();
+
+    // A long request holds a slot, so each on-time completion finds the limit in use.
+    let _long = limiter.acquire();
+    for _ in 0..4 {
+        limiter.acquire().success();
+    }
+    assert!(limiter.limit() > 2, "grew from 2 to {}", limiter.limit());
+
+    let idle = spec(10).resolve();
+    for _ in 0..20 {
+        idle.try_acquire(Priority::Normal).unwrap().success();
+    }
+    assert_eq!(idle.limit(), 10, "one in flight out of 10 never tests the limit");
+}
+
+#[test]
+fn gradient_shrinks_when_latency_outruns_the_baseline() {
+    let limiter = ConcurrencySpec { algorithm: Algorithm::Gradient, max_limit: 100, ..spec(100) }
+        .resolve();
+    limiter.try_acquire(Priority::Normal).unwrap().success(); // sets a ~0 ms baseline
+
+    for _ in 0..3 {
+        let slow = limiter.try_acquire(Priority::Normal).unwrap();
+        sleep(Duration::from_millis(10));
+        slow.success();
+    }
+    assert!(limiter.limit() < 100, "shrank to {}", limiter.limit());
+}
+
+#[test]
+fn cancelled_requests_free_their_slot_without_a_sample() {
+    let limiter = ConcurrencySpec { latency_threshold_ms: 1, ..spec(10) }.resolve();
+    let cancelled = limiter.try_acquire(Priority::Normal).unwrap();
+    sleep(Duration::from_millis(5));
+    drop(cancelled);
+    assert_eq!(limiter.in_flight(), 0);
+    assert_eq!(limiter.limit(), 10, "an unreported permit never moves the limit");
+}
+
+#[test]
+fn shadow_acquire_counts_in_flight_past_the_limit() {
+    let limiter = ConcurrencySpec { enforce: false, ..spec(1) }.resolve();
+    let _first = limiter.try_acquire(Priority::Normal).unwrap();
+    assert!(limiter.try_acquire(Priority::Normal).is_none(), "would shed");
+    assert!(!limiter.enforce());
+    let _admitted = limiter.acquire();
+    assert_eq!(limiter.in_flight(), 2);
+}
+
+#[test]
+fn reload_keeps_the_discovered_limit_within_new_bounds() {
+    let limiter = spec(10).resolve();
+    limiter.try_acquire(Priority::Normal).unwrap().dropped();
+    assert_eq!(limiter.limit(), 9);
+
+    limiter.apply(&ConcurrencySpec { max_limit: 6, ..spec(10) });
+    assert_eq!(limiter.limit(), 6, "clamped to the new ceiling, not reset to initial_limit");
+    assert_eq!(limiter.config().max_limit, 6);
+
+    limiter.apply(&spec(10));
+    assert_eq!(limiter.limit(), 6, "a wider ceiling doesn't restore the old limit");
+}
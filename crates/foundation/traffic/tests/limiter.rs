@@ -7,6 +7,7 @@ fn spec(rps: u32, burst: u32) -> TrafficProfileSpec {
         rps,
         burst,
         scope: Scope::PerMethod,
+        attribute: None,
         mode: traffic::Mode::Local,
         enforce: true,
         lease_ms: None,
@@ -62,3 +63,17 @@ fn non_quota_reload_preserves_buckets() {
     assert_eq!(profile.scope(), Scope::PerCaller);
     assert!(matches!(profile.check("k"), TrafficDecision::Throttle { .. }));
 }
+
+#[test]
+fn attribute_scope_hot_reloads_with_its_field() {
+    let profile = spec(1, 1).resolve();
+    assert_eq!(profile.attribute(), None);
+
+    let mut per_post = spec(1, 1);
+    per_post.scope = Scope::PerAttribute;
+    per_post.attribute = Some("post_id".into());
+    profile.apply(&per_post);
+
+    assert_eq!(profile.scope(), Scope::PerAttribute);
+    assert_eq!(profile.attribute().as_deref(), Some("post_id"));
+}
@@ -1,6 +1,8 @@
 pub mod task_local;
 
-pub use task_local::{AnyPrincipal, current_principal, inject_into_span, with_principal};
+pub use task_local::{
+    AnyPrincipal, current_peer, current_principal, inject_into_span, with_peer, with_principal,
+};
 
 #[cfg(feature = "cqrs-integration")]
 pub use task_local::inject_into_envelope;
@@ -51,6 +51,12 @@ tokio::task_local! {
     /// Set by [`with_principal`]; read by [`current_principal`].
     /// Absent outside of a [`with_principal`] scope — `try_with` returns `None`.
     static CURRENT_PRINCIPAL: Arc<dyn AnyPrincipal>;
+
+    /// The authenticated peer *service* bound to the current async task.
+    ///
+    /// Set by [`with_peer`]; read by [`current_peer`]. Distinct from the
+    /// principal: a peer call carries no end user, only the calling service.
+    static CURRENT_PEER: Arc<str>;
 }
 
 // ── Public lifecycle API ──────────────────────────────────────────────────────
@@ -90,6 +96,21 @@ pub fn current_principal() -> Option<Arc<dyn AnyPrincipal>> {
     CURRENT_PRINCIPAL.try_with(Arc::clone).ok()
 }
 
+/// Runs `future` with `service` bound as the authenticated calling service —
+/// the identity an internal RPC proved with its peer token.
+pub fn with_peer<Fut>(service: Arc<str>, future: Fut) -> impl Future<Output = Fut::Output>
+where
+    Fut: Future,
+{
+    CURRENT_PEER.scope(service, future)
+}
+
+/// Returns the peer service bound to this task, or `None` outside a
+/// [`with_peer`] scope (the call did not come from an authenticated peer).
+pub fn current_peer() -> Option<Arc<str>> {
+    CURRENT_PEER.try_with(Arc::clone).ok()
+}
+
 // ── Integration helpers ───────────────────────────────────────────────────────
 
 /// Enriches the *current* `tracing` span with the identity fields of the
@@ -139,7 +160,8 @@ pub fn inject_into_span() {
 /// | `principal.user_id`    | [`PrincipalId`] string        |
 /// | `principal.tenant_id`  | Tenant string (if present)    |
 ///
-/// This is a no-op when called outside a [`with_principal`] scope.
+/// The user id is what [`cqrs::IdempotencyLayer`] scopes client idempotency
+/// keys by. This is a no-op when called outside a [`with_principal`] scope.
 ///
 /// Requires the `cqrs-integration` Cargo feature.
 ///
@@ -148,7 +170,7 @@ pub fn inject_into_span() {
 pub fn inject_into_envelope<T>(envelope: &mut cqrs::Envelope<T>) {
     if let Some(p) = current_principal() {
         envelope.metadata.insert(
-            "principal.user_id".to_owned(),
+            cqrs::PRINCIPAL_METADATA.to_owned(),
             p.user_id().to_string(),
         );
         if let Some(tid) = p.tenant_id() {
@@ -53,11 +53,34 @@ pub struct OidcClaims {
     /// [`OidcExtractorConfig::tenant_id_claim`].
     pub tid: Option<String>,
 
+    /// Authentication context class reference (OIDC Core §2) — the assurance
+    /// level the session was authenticated at, e.g. `aal2` after a second factor.
+    pub acr: Option<String>,
+
+    /// Authentication methods references (RFC 8176), e.g. `["pwd", "otp", "mfa"]`.
+    pub amr: Option<Vec<String>>,
+
+    /// When the subject last authenticated interactively (Unix seconds). Unlike
+    /// `iat` it survives token refreshes, so it dates the *authentication*, not
+    /// the token.
+    pub auth_time: Option<i64>,
+
     /// Catch-all for provider-specific or custom claims not listed above.
     #[serde(flatten)]
     pub extra: HashMap<String, serde_json::Value>,
 }
 
+impl OidcClaims {
+    /// `true` when the token asserts assurance level `acr` and the subject
+    /// authenticated no more than `max_age_secs` before `now` (Unix seconds) —
+    /// the step-up check in front of sensitive operations. A token without
+    /// `auth_time` never qualifies.
+    pub fn authenticated_within(&self, acr: &str, max_age_secs: i64, now: i64) -> bool {
+        self.acr.as_deref() == Some(acr)
+            && self.auth_time.is_some_and(|at| now.saturating_sub(at) <= max_age_secs)
+    }
+}
+
 /// Keycloak realm or resource access block containing a `roles` array.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RealmAccess {
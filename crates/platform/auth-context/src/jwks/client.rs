@@ -1,12 +1,19 @@
 use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 use std::time::Duration;
 
 use jsonwebtoken::DecodingKey;
-use reqwest::Client;
+use reqwest::{Certificate, Client};
+use reqwest::header::CACHE_CONTROL;
 use serde::Deserialize;
 
 use crate::AuthError;
 
+/// Floor on a publisher-advertised freshness lifetime. A `max-age=0` (or
+/// `no-cache`) JWKS would otherwise turn the refresher into a busy loop
+/// against the IdP.
+pub const MIN_REFRESH_INTERVAL: Duration = Duration::from_secs(5);
+
 // ── Wire types ───────────────────────────────────────────────────────────────
 
 #[derive(Deserialize)]
@@ -29,6 +36,56 @@ struct JwkKey {
 
 // ── Public API ───────────────────────────────────────────────────────────────
 
+/// One JWKS fetch: the decoded key set plus the freshness lifetime the
+/// publisher advertised in `Cache-Control`, if any.
+pub struct FetchedJwks {
+    pub keys: HashMap<String, DecodingKey>,
+    pub max_age: Option<Duration>,
+}
+
+impl FetchedJwks {
+    /// How long to wait before the next fetch: the advertised `max-age`,
+    /// clamped to `[MIN_REFRESH_INTERVAL, ceiling]`, or `ceiling` when the
+    /// publisher said nothing.
+    ///
+    /// The ceiling is the configured refresh interval — a publisher can make
+    /// verifiers poll sooner (so a newly published signing key is picked up
+    /// before it signs anything), never later.
+    pub fn refresh_after(&self, ceiling: Duration) -> Duration {
+        match self.max_age {
+            Some(max_age) => max_age.clamp(MIN_REFRESH_INTERVAL.min(ceiling), ceiling),
+            None => ceiling,
+        }
+    }
+}
+
+/// Parses the freshness lifetime out of a `Cache-Control` header value.
+///
+/// `s-maxage` wins over `max-age` (the verifier is a shared cache of the key
+/// set); `no-cache` and `no-store` mean "revalidate now" and read as zero.
+/// Returns `None` when the header carries no freshness directive.
+pub fn max_age(cache_control: &str) -> Option<Duration> {
+    let mut max_age = None;
+    let mut s_maxage = None;
+    for directive in cache_control.split(',') {
+        let directive = directive.trim();
+        let (name, value) = match directive.split_once('=') {
+            Some((name, value)) => (name.trim(), Some(value.trim().trim_matches('"'))),
+            None => (directive, None),
+        };
+        if name.eq_ignore_ascii_case("no-cache") || name.eq_ignore_ascii_case("no-store") {
+            return Some(Duration::ZERO);
+        }
+        let seconds = value.and_then(|v| v.parse::<u64>().ok()).map(Duration::from_secs);
+        if name.eq_ignore_ascii_case("s-maxage") {
+            s_maxage = seconds;
+        } else if name.eq_ignore_ascii_case("max-age") {
+            max_age = seconds;
+        }
+    }
+    s_maxage.or(max_age)
+}
+
 /// Stateless HTTP client that fetches a JWKS document and converts each key
 /// into a [`DecodingKey`] ready for use by [`JwtDecoder`].
 ///
@@ -40,8 +97,17 @@ struct JwkKey {
 pub struct JwksClient {
     http: Client,
     url: String,
+    /// File re-read before every fetch for an `Authorization: Bearer` credential
+    /// (the kubelet rotates the in-cluster ServiceAccount token in place).
+    bearer_file: Option<PathBuf>,
 }
 
+/// JWKS the Kubernetes API server publishes for its ServiceAccount token issuer.
+pub const IN_CLUSTER_JWKS_URL: &str = "https://kubernetes.default.svc/openid/v1/jwks";
+
+/// Where the kubelet mounts a pod's own ServiceAccount credentials.
+const SERVICE_ACCOUNT_DIR: &str = "/var/run/secrets/kubernetes.io/serviceaccount";
+
 impl JwksClient {
     /// Constructs a client targeting `url` with the given per-request `timeout`.
     ///
@@ -62,11 +128,57 @@ impl JwksClient {
         Self {
             http,
             url: url.into(),
+            bearer_file: None,
         }
     }
 
+    /// A client for the API server's ServiceAccount-issuer JWKS
+    /// ([`IN_CLUSTER_JWKS_URL`]), trusting the cluster CA and authenticating with
+    /// the pod's own ServiceAccount token — the endpoint is only open to
+    /// ServiceAccounts.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AuthError::JwksUnavailable`] when the pod has no ServiceAccount
+    /// credentials mounted (not running in a cluster, or automount disabled).
+    pub fn in_cluster(timeout: Duration) -> Result<Self, AuthError> {
+        Self::with_service_account(IN_CLUSTER_JWKS_URL, Path::new(SERVICE_ACCOUNT_DIR), timeout)
+    }
+
+    fn with_service_account(url: &str, dir: &Path, timeout: Duration) -> Result<Self, AuthError> {
+        let unavailable = |e: &dyn std::fmt::Display| {
+            AuthError::JwksUnavailable(format!("ServiceAccount credentials in {}: {e}", dir.display()))
+        };
+        let ca = std::fs::read(dir.join("ca.crt")).map_err(|e| unavailable(&e))?;
+        let ca = Certificate::from_pem(&ca).map_err(|e| unavailable(&e))?;
+        let http = Client::builder()
+            .timeout(timeout)
+            .add_root_certificate(ca)
+            .build()
+            .map_err(|e| unavailable(&e))?;
+
+        Ok(Self {
+            http,
+            url: url.to_owned(),
+            bearer_file: Some(dir.join("token")),
+        })
+    }
+
     /// Fetches the JWKS document and returns a map of `kid → DecodingKey`.
     ///
+    /// Shorthand for [`fetch_document`](Self::fetch_document) when the
+    /// caller does not care about the advertised freshness lifetime.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AuthError::JwksUnavailable`] on any HTTP or parse failure.
+    pub async fn fetch(&self) -> Result<HashMap<String, DecodingKey>, AuthError> {
+        self.fetch_document().await.map(|doc| doc.keys)
+    }
+
+    /// Fetches the JWKS document along with the `Cache-Control` freshness
+    /// lifetime the publisher attached to it.
+    ///
     /// Keys whose `kty` is unsupported (e.g. `oct`, `OKP`) are skipped with a
     /// `WARN` log rather than causing the entire fetch to fail. This tolerates
     /// mixed-type JWKS responses from providers that publish both signing and
@@ -75,10 +187,15 @@ impl JwksClient {
     /// # Errors
     ///
     /// Returns [`AuthError::JwksUnavailable`] on any HTTP or parse failure.
-    pub async fn fetch(&self) -> Result<HashMap<String, DecodingKey>, AuthError> {
-        let response = self
-            .http
-            .get(&self.url)
+    pub async fn fetch_document(&self) -> Result<FetchedJwks, AuthError> {
+        let mut request = self.http.get(&self.url);
+        if let Some(path) = &self.bearer_file {
+            let token = std::fs::read_to_string(path).map_err(|e| {
+                AuthError::JwksUnavailable(format!("bearer token {}: {e}", path.display()))
+            })?;
+            request = request.bearer_auth(token.trim());
+        }
+        let response = request
             .send()
             .await
             .map_err(|e| AuthError::JwksUnavailable(e.to_string()))?;
@@ -90,6 +207,12 @@ impl JwksClient {
             )));
         }
 
+        let max_age = response
+            .headers()
+            .get(CACHE_CONTROL)
+            .and_then(|value| value.to_str().ok())
+            .and_then(max_age);
+
         let body: JwksResponse = response
             .json()
             .await
@@ -109,7 +232,7 @@ impl JwksClient {
             }
         }
 
-        Ok(keys)
+        Ok(FetchedJwks { keys, max_age })
     }
 
     fn build_decoding_key(jwk: &JwkKey) -> Result<DecodingKey, String> {
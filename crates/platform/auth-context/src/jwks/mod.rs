@@ -3,5 +3,5 @@ pub mod client;
 pub mod refresher;
 
 pub use cache::JwksCache;
-pub use client::JwksClient;
+pub use client::{FetchedJwks, IN_CLUSTER_JWKS_URL, JwksClient, MIN_REFRESH_INTERVAL};
 pub use refresher::JwksRefresher;
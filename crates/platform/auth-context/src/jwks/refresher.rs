@@ -11,7 +11,12 @@ use super::{JwksCache, JwksClient};
 ///
 /// 1. [`JwksRefresher::spawn`] immediately schedules the first fetch so that
 ///    the cache is warm before the first HTTP request arrives at the service.
-/// 2. After each successful fetch the task sleeps for `refresh_interval`.
+/// 2. After each successful fetch the task sleeps for the `max-age` the JWKS
+///    publisher advertised in `Cache-Control`, clamped between
+///    [`MIN_REFRESH_INTERVAL`](super::MIN_REFRESH_INTERVAL) and `refresh_interval`; with no such header it
+///    sleeps for `refresh_interval`. A publisher that rotates its signing keys
+///    can therefore shorten its advertised lifetime and have verifiers pick
+///    up a newly published key well before it signs anything.
 /// 3. On failure the task applies exponential backoff starting at 1 s, doubling
 ///    on each consecutive error, capped at `max_backoff`. The stale key set
 ///    remains in the cache throughout — requests continue to be served until
@@ -22,7 +27,8 @@ use super::{JwksCache, JwksClient};
 ///
 /// ## Observability
 ///
-/// Every successful refresh emits `tracing::info!` with the `key_count` field.
+/// Every successful refresh emits `tracing::info!` with the `key_count` and
+/// `next_refresh_secs` fields.
 /// Every failed refresh emits `tracing::warn!` with the error and next
 /// retry interval, enabling alert rules on repeated WARN bursts.
 pub struct JwksRefresher {
@@ -67,19 +73,19 @@ impl JwksRefresher {
         loop {
             time::sleep(delay).await;
 
-            match client.fetch().await {
-                Ok(keys) => {
-                    let count = keys.len();
-                    cache.replace(keys).await;
+            match client.fetch_document().await {
+                Ok(document) => {
+                    let count = document.keys.len();
+                    delay = document.refresh_after(refresh_interval);
+                    cache.replace(document.keys).await;
 
                     tracing::info!(
                         key_count = count,
-                        next_refresh_secs = refresh_interval.as_secs(),
+                        next_refresh_secs = delay.as_secs(),
                         "JWKS cache refreshed successfully"
                     );
 
                     backoff = Duration::from_secs(1);
-                    delay = refresh_interval;
                 }
 
                 Err(err) => {
@@ -7,14 +7,16 @@ pub mod jwks;
 pub mod principal;
 
 pub use config::AuthContextConfig;
-pub use context::{AnyPrincipal, current_principal, inject_into_span, with_principal};
+pub use context::{
+    AnyPrincipal, current_peer, current_principal, inject_into_span, with_peer, with_principal,
+};
 pub use decoder::JwtDecoder;
 pub use error::AuthError;
 pub use extractor::{
     ClaimsExtractor, OidcClaims, OidcClaimsExtractor, OidcExtractorConfig, RealmAccess,
     RoleSource,
 };
-pub use jwks::{JwksCache, JwksClient, JwksRefresher};
+pub use jwks::{FetchedJwks, IN_CLUSTER_JWKS_URL, JwksCache, JwksClient, JwksRefresher};
 pub use principal::{CurrentPrincipal, Permission, PrincipalId};
 
 #[cfg(feature = "cqrs-integration")]
@@ -26,6 +26,9 @@ fn base_claims() -> OidcClaims {
         permissions: None,
         groups: None,
         tid: None,
+        acr: None,
+        amr: None,
+        auth_time: None,
         extra: HashMap::new(),
     }
 }
@@ -177,3 +180,37 @@ fn has_any_permission_returns_true_when_one_matches() {
     let principal = extractor.extract(claims).unwrap();
     assert!(principal.has_any_permission(&["posts:write", "openid"]));
 }
+
+#[test]
+fn recent_strong_authentication_requires_acr_and_fresh_auth_time() {
+    let now = 1_700_000_000;
+    let mut claims = base_claims();
+    claims.acr = Some("aal2".to_owned());
+    claims.auth_time = Some(now - 120);
+    assert!(claims.authenticated_within("aal2", 300, now));
+    assert!(!claims.authenticated_within("aal2", 60, now));
+
+    claims.acr = Some("aal1".to_owned());
+    assert!(!claims.authenticated_within("aal2", 300, now));
+
+    claims.acr = Some("aal2".to_owned());
+    claims.auth_time = None;
+    assert!(!claims.authenticated_within("aal2", 300, now));
+}
+
+#[test]
+fn assurance_claims_deserialize_when_present_and_default_when_absent() {
+    let claims: OidcClaims = serde_json::from_value(serde_json::json!({
+        "sub": SUB, "exp": 9_999_999_999_i64,
+        "acr": "aal2", "amr": ["pwd", "otp", "mfa"], "auth_time": 1_700_000_000,
+    }))
+    .unwrap();
+    assert_eq!(claims.acr.as_deref(), Some("aal2"));
+    assert_eq!(claims.amr.as_deref(), Some(&["pwd".to_owned(), "otp".to_owned(), "mfa".to_owned()][..]));
+    assert_eq!(claims.auth_time, Some(1_700_000_000));
+    assert!(!claims.extra.contains_key("acr"));
+
+    let bare: OidcClaims =
+        serde_json::from_value(serde_json::json!({ "sub": SUB, "exp": 9_999_999_999_i64 })).unwrap();
+    assert!(bare.acr.is_none() && bare.amr.is_none() && bare.auth_time.is_none());
+}
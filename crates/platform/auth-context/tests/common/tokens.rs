@@ -42,6 +42,9 @@ impl<'a> TokenFactory<'a> {
             permissions: None,
             groups: None,
             tid: None,
+            acr: None,
+            amr: None,
+            auth_time: None,
             extra: Default::default(),
         };
         self.sign(claims)
@@ -65,6 +68,9 @@ impl<'a> TokenFactory<'a> {
             permissions: None,
             groups: None,
             tid: None,
+            acr: None,
+            amr: None,
+            auth_time: None,
             extra: Default::default(),
         };
         self.sign(claims)
@@ -113,6 +119,9 @@ impl<'a> TokenFactory<'a> {
             permissions: None,
             groups: None,
             tid: None,
+            acr: None,
+            amr: None,
+            auth_time: None,
             extra: Default::default(),
         };
         self.sign(claims)
@@ -141,6 +150,9 @@ impl<'a> TokenFactory<'a> {
             permissions: None,
             groups: None,
             tid: Some(tenant_id.to_owned()),
+            acr: None,
+            amr: None,
+            auth_time: None,
             extra: Default::default(),
         };
         self.sign(claims)
@@ -3,7 +3,8 @@ mod common;
 use std::sync::Arc;
 
 use auth_context::{
-    current_principal, inject_into_span, with_principal, CurrentPrincipal, Permission, PrincipalId,
+    current_peer, current_principal, inject_into_span, with_peer, with_principal, CurrentPrincipal,
+    Permission, PrincipalId,
 };
 
 fn make_principal(user_id: &str, tenant_id: Option<&str>, perms: &[&str]) -> CurrentPrincipal<()> {
@@ -142,3 +143,16 @@ async fn spawned_task_does_not_inherit_principal() {
     })
     .await;
 }
+
+#[tokio::test]
+async fn peer_is_bound_apart_from_the_principal() {
+    assert!(current_peer().is_none());
+
+    with_peer(Arc::from("search-server"), async {
+        assert_eq!(current_peer().as_deref(), Some("search-server"));
+        assert!(current_principal().is_none(), "a peer call carries no end user");
+    })
+    .await;
+
+    assert!(current_peer().is_none());
+}
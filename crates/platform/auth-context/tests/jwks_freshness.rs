@@ -0,0 +1,57 @@
+use std::collections::HashMap;
+use std::time::Duration;
+
+use auth_context::FetchedJwks;
+use auth_context::jwks::MIN_REFRESH_INTERVAL;
+use auth_context::jwks::client::max_age;
+
+const CEILING: Duration = Duration::from_secs(300);
+
+fn fetched(max_age: Option<Duration>) -> FetchedJwks {
+    FetchedJwks { keys: HashMap::new(), max_age }
+}
+
+#[test]
+fn max_age_is_read_from_cache_control() {
+    assert_eq!(max_age("public, max-age=60"), Some(Duration::from_secs(60)));
+    assert_eq!(max_age("Max-Age=\"30\""), Some(Duration::from_secs(30)));
+}
+
+#[test]
+fn s_maxage_wins_over_max_age() {
+    assert_eq!(max_age("max-age=600, s-maxage=45"), Some(Duration::from_secs(45)));
+}
+
+#[test]
+fn no_cache_and_no_store_mean_revalidate_now() {
+    assert_eq!(max_age("no-cache"), Some(Duration::ZERO));
+    assert_eq!(max_age("private, no-store, max-age=600"), Some(Duration::ZERO));
+}
+
+#[test]
+fn header_without_freshness_directive_is_ignored() {
+    assert_eq!(max_age("public, must-revalidate"), None);
+    assert_eq!(max_age("max-age=soon"), None);
+}
+
+#[test]
+fn advertised_lifetime_shortens_the_refresh_interval() {
+    let delay = fetched(Some(Duration::from_secs(60))).refresh_after(CEILING);
+    assert_eq!(delay, Duration::from_secs(60));
+}
+
+#[test]
+fn advertised_lifetime_never_lengthens_the_refresh_interval() {
+    let delay = fetched(Some(Duration::from_secs(86_400))).refresh_after(CEILING);
+    assert_eq!(delay, CEILING);
+}
+
+#[test]
+fn zero_lifetime_is_floored_to_avoid_a_busy_loop() {
+    assert_eq!(fetched(Some(Duration::ZERO)).refresh_after(CEILING), MIN_REFRESH_INTERVAL);
+}
+
+#[test]
+fn missing_lifetime_falls_back_to_the_refresh_interval() {
+    assert_eq!(fetched(None).refresh_after(CEILING), CEILING);
+}
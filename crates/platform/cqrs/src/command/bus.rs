@@ -9,7 +9,8 @@ use super::command::Command;
 /// The single entry point for command dispatch.
 ///
 /// Callers never reference handlers directly — they go through the bus,
-/// which runs the full middleware pipeline before invoking the handler.
+/// which runs the full middleware pipeline before invoking the handler, and
+/// get the command's [`Output`](Command::Output) back on success.
 ///
 /// ## Error semantics
 ///
@@ -27,7 +28,7 @@ pub trait CommandBus: Send + Sync {
     fn dispatch<C: Command>(
         &self,
         envelope: Envelope<C>,
-    ) -> impl Future<Output = Result<(), CqrsError>> + Send + '_;
+    ) -> impl Future<Output = Result<C::Output, CqrsError>> + Send + '_;
 }
 
 /// Forwarding impl so a single registered bus can be shared by reference-counted
@@ -36,7 +37,7 @@ impl<B: CommandBus> CommandBus for Arc<B> {
     fn dispatch<C: Command>(
         &self,
         envelope: Envelope<C>,
-    ) -> impl Future<Output = Result<(), CqrsError>> + Send + '_ {
+    ) -> impl Future<Output = Result<C::Output, CqrsError>> + Send + '_ {
         (**self).dispatch(envelope)
     }
 }
@@ -1,3 +1,6 @@
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
 /// Marker trait for all command types.
 ///
 /// A command represents a user's intent to change system state. It is named
@@ -7,6 +10,16 @@
 /// Commands are dispatched **exactly once** to a single registered handler.
 /// For fan-out or event broadcasting, use the event/messaging layer instead.
 ///
+/// ## Associated type `Output`
+///
+/// What a successful dispatch hands back — `()` for most commands, or the
+/// identifiers a create generated (`CreatePost` → the new post id). Outputs are
+/// small acknowledgements, not read models: anything richer belongs behind a
+/// query.
+///
+/// `Output` must round-trip through serde so the `IdempotencyLayer` can record
+/// it and replay it to a retried dispatch.
+///
 /// ## Requirements
 ///
 /// - `Validate` — every command must be self-validating. Commands that carry
@@ -16,4 +29,6 @@
 ///   with zero dynamic-dispatch overhead.
 /// - `Send + Sync + 'static` — required so commands can be moved across
 ///   thread and task boundaries and stored in the type-erased registry.
-pub trait Command: validate_core::Validate + Send + Sync + 'static {}
+pub trait Command: validate_core::Validate + Send + Sync + 'static {
+    type Output: Serialize + DeserializeOwned + Send + 'static;
+}
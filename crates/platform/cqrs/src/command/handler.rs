@@ -9,7 +9,8 @@ use super::command::Command;
 /// Handles a single command type `C`.
 ///
 /// Each concrete handler is registered with a [`CommandBus`] for exactly
-/// one command type. All domain mutation logic lives inside `handle`.
+/// one command type. All domain mutation logic lives inside `handle`, which
+/// returns the command's [`Output`](Command::Output).
 ///
 /// ## Error contract
 ///
@@ -24,7 +25,9 @@ use super::command::Command;
 /// use cqrs::{Command, CommandHandler, Envelope};
 ///
 /// struct CreatePostCommand { title: String }
-/// impl Command for CreatePostCommand {}
+/// impl Command for CreatePostCommand {
+///     type Output = PostId;
+/// }
 ///
 /// struct CreatePostHandler { /* db, repo, etc. */ }
 ///
@@ -34,9 +37,9 @@ use super::command::Command;
 ///     async fn handle(
 ///         &self,
 ///         envelope: Envelope<CreatePostCommand>,
-///     ) -> Result<(), Self::Error> {
+///     ) -> Result<PostId, Self::Error> {
 ///         // domain logic here
-///         Ok(())
+///         Ok(post.id().clone())
 ///     }
 /// }
 /// ```
@@ -46,5 +49,5 @@ pub trait CommandHandler<C: Command>: Send + Sync + 'static {
     fn handle(
         &self,
         envelope: Envelope<C>,
-    ) -> impl Future<Output = Result<(), Self::Error>> + Send + '_;
+    ) -> impl Future<Output = Result<C::Output, Self::Error>> + Send + '_;
 }
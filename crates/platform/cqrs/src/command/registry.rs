@@ -21,11 +21,12 @@ use super::handler::CommandHandler;
 /// Sealed to this crate — external code never implements or names this trait.
 pub(crate) trait ErasedCommandHandler: Send + Sync {
     /// Accepts an already-boxed `Envelope<C>` (erased as `dyn Any`) and
-    /// dispatches it to the concrete handler, returning a boxed future.
+    /// dispatches it to the concrete handler, returning a boxed future that
+    /// resolves to the boxed `C::Output`.
     fn handle_erased<'a>(
         &'a self,
         envelope: Box<dyn Any + Send>,
-    ) -> BoxFuture<'a, Result<(), CqrsError>>;
+    ) -> BoxFuture<'a, Result<Box<dyn Any + Send>, CqrsError>>;
 }
 
 /// Bridges a concrete [`CommandHandler<C>`] to [`ErasedCommandHandler`].
@@ -45,7 +46,7 @@ where
     fn handle_erased<'a>(
         &'a self,
         envelope: Box<dyn Any + Send>,
-    ) -> BoxFuture<'a, Result<(), CqrsError>> {
+    ) -> BoxFuture<'a, Result<Box<dyn Any + Send>, CqrsError>> {
         let handler = Arc::clone(&self.handler);
         Box::pin(async move {
             // Safety: the registry only stores a TypedHandlerBridge<H, C>
@@ -53,7 +54,11 @@ where
             let typed = *envelope
                 .downcast::<Envelope<C>>()
                 .expect("cqrs invariant: TypeId key matches Envelope<C> — this is a bug");
-            handler.handle(typed).await.map_err(CqrsError::from_handler)
+            handler
+                .handle(typed)
+                .await
+                .map(|output| Box::new(output) as Box<dyn Any + Send>)
+                .map_err(CqrsError::from_handler)
         })
     }
 }
@@ -69,15 +74,16 @@ where
 /// ## Dispatch cost
 ///
 /// One `HashMap::get` lookup (by `TypeId`) followed by a heap allocation
-/// for the boxed `Envelope<C>` passed to the erased handler. The actual
-/// handler invocation is statically dispatched inside the bridge closure.
+/// for the boxed `Envelope<C>` passed to the erased handler, and one for the
+/// boxed output on the way back. The actual handler invocation is statically
+/// dispatched inside the bridge closure.
 #[derive(Clone)]
 pub struct InMemoryCommandBus {
     handlers: Arc<HashMap<TypeId, Arc<dyn ErasedCommandHandler>>>,
 }
 
 impl CommandBus for InMemoryCommandBus {
-    async fn dispatch<C: Command>(&self, envelope: Envelope<C>) -> Result<(), CqrsError> {
+    async fn dispatch<C: Command>(&self, envelope: Envelope<C>) -> Result<C::Output, CqrsError> {
         let type_id = TypeId::of::<C>();
         let handler = self
             .handlers
@@ -86,7 +92,11 @@ impl CommandBus for InMemoryCommandBus {
                 type_name: std::any::type_name::<C>(),
             })?;
         let boxed = Box::new(envelope) as Box<dyn Any + Send>;
-        handler.handle_erased(boxed).await
+        let output = handler.handle_erased(boxed).await?;
+        // Safety: the bridge boxes `C::Output` and we downcast back to the same type.
+        Ok(*output
+            .downcast::<C::Output>()
+            .expect("cqrs invariant: output type matches C::Output — this is a bug"))
     }
 }
 
@@ -3,6 +3,18 @@ use std::collections::HashMap;
 use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
+/// `metadata` entry holding a client-supplied idempotency key (see
+/// [`Envelope::with_idempotency_key`]).
+pub const IDEMPOTENCY_KEY_METADATA: &str = "idempotency-key";
+
+/// `metadata` entry holding the fingerprint of the request an idempotency key
+/// was sent with (see [`Envelope::with_idempotency_key`]).
+pub const IDEMPOTENCY_FINGERPRINT_METADATA: &str = "idempotency-fingerprint";
+
+/// `metadata` entry holding the authenticated caller's user id (see
+/// [`Envelope::with_principal`]).
+pub const PRINCIPAL_METADATA: &str = "principal.user_id";
+
 /// Wraps any Command or Query payload with the distributed-tracing and
 /// idempotency metadata that must travel with every message dispatched
 /// through the bus.
@@ -11,7 +23,7 @@ use uuid::Uuid;
 ///
 /// | Field            | Responsibility                                                   |
 /// |------------------|------------------------------------------------------------------|
-/// | `message_id`     | Unique per message instance — used as the idempotency key.       |
+/// | `message_id`     | Unique per message instance — the default idempotency key.       |
 /// | `correlation_id` | Propagated across the full request flow (gRPC → bus → handler). |
 /// | `causation_id`   | The `message_id` of the upstream message that triggered this one.|
 /// | `issued_at`      | Wall-clock creation time (UTC).                                  |
@@ -89,6 +101,45 @@ impl<T> Envelope<T> {
         }
     }
 
+    /// Replaces the `message_id`, for a retry that must be recognised as the same
+    /// message (e.g. one keyed on an upstream event id) by the [`IdempotencyLayer`].
+    pub fn with_message_id(mut self, message_id: Uuid) -> Self {
+        self.message_id = message_id;
+        self
+    }
+
+    /// Records a client-supplied idempotency key (e.g. the gRPC `idempotency-key`
+    /// header) and the fingerprint of the request it came with. The
+    /// [`IdempotencyLayer`] then dedups on the key — scoped to the command type and
+    /// the [`principal`](Self::principal) — instead of `message_id`, replays the
+    /// first dispatch's output to every retry that carries it, and rejects a reuse
+    /// of the key whose fingerprint differs.
+    pub fn with_idempotency_key(self, key: impl Into<String>, fingerprint: impl Into<String>) -> Self {
+        self.with_metadata(IDEMPOTENCY_KEY_METADATA, key)
+            .with_metadata(IDEMPOTENCY_FINGERPRINT_METADATA, fingerprint)
+    }
+
+    /// The client-supplied idempotency key, if any.
+    pub fn idempotency_key(&self) -> Option<&str> {
+        self.metadata.get(IDEMPOTENCY_KEY_METADATA).map(String::as_str)
+    }
+
+    /// The fingerprint of the request the idempotency key was sent with, if any.
+    pub fn idempotency_fingerprint(&self) -> Option<&str> {
+        self.metadata.get(IDEMPOTENCY_FINGERPRINT_METADATA).map(String::as_str)
+    }
+
+    /// Records the authenticated caller, so client idempotency keys are scoped to
+    /// it: two users sending the same key never see each other's results.
+    pub fn with_principal(self, user_id: impl Into<String>) -> Self {
+        self.with_metadata(PRINCIPAL_METADATA, user_id)
+    }
+
+    /// The authenticated caller's user id, if the ingress recorded one.
+    pub fn principal(&self) -> Option<&str> {
+        self.metadata.get(PRINCIPAL_METADATA).map(String::as_str)
+    }
+
     /// Attaches a metadata entry and returns `self` for chaining.
     pub fn with_metadata(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
         self.metadata.insert(key.into(), value.into());
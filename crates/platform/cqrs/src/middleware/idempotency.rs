@@ -1,9 +1,18 @@
+use std::fmt;
 use std::future::Future;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 
+use dashmap::mapref::entry::Entry;
 use dashmap::DashMap;
+use http::StatusCode;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use ::error::{AppError, Severity};
+
 use crate::command::bus::CommandBus;
 use crate::command::command::Command;
 use crate::envelope::Envelope;
@@ -11,77 +20,306 @@ use crate::error::CqrsError;
 
 use super::layer::CommandLayer;
 
+/// How long a claim protects an in-flight dispatch before another one may take
+/// it over (the claimant crashed or hung).
+pub const DEFAULT_CLAIM_TTL: Duration = Duration::from_secs(30);
+
+/// How long a processed key is remembered. A redelivery older than this runs
+/// again.
+pub const DEFAULT_RETENTION: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// UUIDv5 namespace for keys derived from a client-supplied idempotency key.
+const CLIENT_KEY_NAMESPACE: Uuid = Uuid::from_u128(0x6f1c_2a4e_93b7_5d08_8e21_c4f0_7a9b_3d56);
+
+// ── Claim ─────────────────────────────────────────────────────────────────────
+
+/// What [`IdempotencyStore::claim`] found for a key.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Claim {
+    /// The caller now holds the claim: it must run the command, then
+    /// [`complete`](IdempotencyStore::complete) or
+    /// [`release`](IdempotencyStore::release) it.
+    Acquired,
+    /// Another dispatch (typically on another replica) holds an unexpired claim.
+    InFlight,
+    /// The command already ran to success within the retention window. Carries
+    /// the encoded output passed to [`complete`](IdempotencyStore::complete), or
+    /// `None` for a bare mark written without one.
+    Processed(Option<Vec<u8>>),
+}
+
+// ── IdempotencyError ──────────────────────────────────────────────────────────
+
+/// Failure surfaced by the [`IdempotencyLayer`], wrapped in [`CqrsError::Handler`]
+/// so callers map it like any handler error.
+#[derive(Debug)]
+pub enum IdempotencyError {
+    /// The same key is executing elsewhere right now. Retryable: once that
+    /// dispatch settles, a retry either replays its output (success) or runs
+    /// (failure).
+    InFlight { message_id: Uuid },
+    /// The backing store failed or answered with something it should not hold.
+    Store(String),
+    /// The recorded output no longer decodes as the command's `Output` (the type
+    /// changed shape since it was recorded). Not retryable until the record
+    /// expires.
+    UndecodableResult { message_id: Uuid, reason: String },
+    /// The client key was already used for a request with a different payload.
+    /// Not retryable: the client must mint a new key for a new operation.
+    KeyReused { message_id: Uuid },
+}
+
+impl fmt::Display for IdempotencyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InFlight { message_id } => {
+                write!(f, "message {message_id} is already being processed")
+            }
+            Self::Store(reason) => write!(f, "idempotency store error: {reason}"),
+            Self::UndecodableResult { message_id, reason } => {
+                write!(f, "recorded result for message {message_id} does not decode: {reason}")
+            }
+            Self::KeyReused { message_id } => {
+                write!(f, "idempotency key {message_id} was already used for a different request")
+            }
+        }
+    }
+}
+
+impl std::error::Error for IdempotencyError {}
+
+impl AppError for IdempotencyError {
+    fn error_code(&self) -> &'static str {
+        match self {
+            Self::InFlight { .. } => "CQRS_IDEMPOTENCY_IN_FLIGHT",
+            Self::Store(_) => "CQRS_IDEMPOTENCY_STORE_UNAVAILABLE",
+            Self::UndecodableResult { .. } => "CQRS_IDEMPOTENCY_RESULT_UNDECODABLE",
+            Self::KeyReused { .. } => "CQRS_IDEMPOTENCY_KEY_REUSED",
+        }
+    }
+
+    fn http_status(&self) -> StatusCode {
+        match self {
+            Self::InFlight { .. } => StatusCode::CONFLICT,
+            Self::Store(_) => StatusCode::SERVICE_UNAVAILABLE,
+            Self::UndecodableResult { .. } => StatusCode::INTERNAL_SERVER_ERROR,
+            Self::KeyReused { .. } => StatusCode::UNPROCESSABLE_ENTITY,
+        }
+    }
+
+    fn severity(&self) -> Severity {
+        match self {
+            Self::InFlight { .. } | Self::KeyReused { .. } => Severity::Low,
+            Self::Store(_) | Self::UndecodableResult { .. } => Severity::High,
+        }
+    }
+
+    fn is_retryable(&self) -> bool {
+        !matches!(self, Self::UndecodableResult { .. } | Self::KeyReused { .. })
+    }
+
+    fn category(&self) -> &'static str {
+        "CQRS"
+    }
+
+    fn user_facing_message(&self) -> &'static str {
+        match self {
+            Self::InFlight { .. } => "This request is already being processed.",
+            Self::Store(_) => "A temporary error occurred. Please retry.",
+            Self::UndecodableResult { .. } => "An internal error occurred.",
+            Self::KeyReused { .. } => "This idempotency key was already used for a different request.",
+        }
+    }
+}
+
 // ── IdempotencyStore ──────────────────────────────────────────────────────────
 
 /// Pluggable backend for the [`IdempotencyLayer`] deduplication check.
 ///
-/// Implement this trait to replace the default in-memory store with a Redis,
-/// Postgres, or ScyllaDB-backed store for distributed deployments.
+/// `cqrs` ships [`InMemoryIdempotencyStore`]; the `idempotency` crate provides
+/// the Redis and Postgres stores replicas share.
 ///
 /// ## Semantics
 ///
-/// `mark_processed` is called **only on success** (when the inner bus returns
-/// `Ok(())`). This means a failed command can be retried — the idempotency
-/// record is only committed once it has been successfully processed.
+/// A key moves through two states. [`claim`](Self::claim) atomically takes an
+/// unclaimed key into **claimed** (for a claim TTL), so two replicas handed the
+/// same message cannot both run it. [`complete`](Self::complete) is called
+/// **only on success** and moves it to **processed** — together with the
+/// command's encoded output — for the retention window;
+/// [`release`](Self::release) drops a failed dispatch's claim, so the command
+/// stays retryable. A claim whose holder dies expires and can be taken over.
 pub trait IdempotencyStore: Send + Sync + 'static {
-    /// Returns `true` if `message_id` was already successfully processed.
-    fn is_processed(
+    /// Claims `message_id`, or reports who already has it.
+    fn claim(
+        &self,
+        message_id: Uuid,
+    ) -> impl Future<Output = Result<Claim, IdempotencyError>> + Send + '_;
+
+    /// Marks a claimed `message_id` as successfully processed, recording the
+    /// command's encoded output for replay.
+    fn complete(
         &self,
         message_id: Uuid,
-    ) -> impl Future<Output = bool> + Send + '_;
+        result: Vec<u8>,
+    ) -> impl Future<Output = Result<(), IdempotencyError>> + Send + '_;
 
-    /// Marks `message_id` as successfully processed.
-    fn mark_processed(
+    /// Drops the claim on `message_id` after a failed dispatch. A no-op when the
+    /// id is not (or no longer) claimed.
+    fn release(
         &self,
         message_id: Uuid,
-    ) -> impl Future<Output = ()> + Send + '_;
+    ) -> impl Future<Output = Result<(), IdempotencyError>> + Send + '_;
 }
 
 // ── InMemoryIdempotencyStore ──────────────────────────────────────────────────
 
+#[derive(Debug, Clone)]
+struct Slot {
+    /// `None` while claimed; the recorded output once processed.
+    processed:  Option<Vec<u8>>,
+    expires_at: Instant,
+}
+
+/// Expired entries are swept from the map once every this many claims.
+const SWEEP_EVERY: u64 = 1024;
+
 /// Lock-free in-process idempotency store backed by [`DashMap`].
 ///
-/// Suitable for single-instance deployments and testing. For distributed
-/// scenarios where multiple replicas run concurrently, replace with a
-/// Redis-backed implementation using atomic SET NX / EXPIRE.
+/// Claims and processed marks expire after their TTLs: an expired entry reads
+/// as absent, and the map is swept of them every [`SWEEP_EVERY`] claims, so
+/// memory stays bounded by the traffic of one retention window.
 ///
-/// Note: the seen set grows unbounded. Add a TTL-based eviction layer when
-/// deploying in long-running services.
-#[derive(Debug, Default)]
+/// Deduplicates within one process only. Replicas that can receive the same
+/// message need a shared store (`idempotency::RedisIdempotencyStore` or
+/// `idempotency::PgIdempotencyStore`).
+#[derive(Debug)]
 pub struct InMemoryIdempotencyStore {
-    seen: DashMap<Uuid, ()>,
+    slots:     DashMap<Uuid, Slot>,
+    claim_ttl: Duration,
+    retention: Duration,
+    claims:    AtomicU64,
+}
+
+impl Default for InMemoryIdempotencyStore {
+    fn default() -> Self {
+        Self {
+            slots:     DashMap::new(),
+            claim_ttl: DEFAULT_CLAIM_TTL,
+            retention: DEFAULT_RETENTION,
+            claims:    AtomicU64::new(0),
+        }
+    }
 }
 
 impl InMemoryIdempotencyStore {
+    /// A store with [`DEFAULT_CLAIM_TTL`] and [`DEFAULT_RETENTION`].
     pub fn new() -> Self {
         Self::default()
     }
+
+    /// Overrides how long an unfinished claim blocks other dispatches.
+    pub fn with_claim_ttl(mut self, ttl: Duration) -> Self {
+        self.claim_ttl = ttl;
+        self
+    }
+
+    /// Overrides how long a processed key is remembered.
+    pub fn with_retention(mut self, retention: Duration) -> Self {
+        self.retention = retention;
+        self
+    }
+
+    /// Live (unexpired) entries, claimed or processed.
+    pub fn len(&self) -> usize {
+        let now = Instant::now();
+        self.slots.iter().filter(|slot| slot.expires_at > now).count()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Drops every expired entry.
+    pub fn evict_expired(&self) {
+        let now = Instant::now();
+        self.slots.retain(|_, slot| slot.expires_at > now);
+    }
 }
 
 impl IdempotencyStore for InMemoryIdempotencyStore {
-    async fn is_processed(&self, message_id: Uuid) -> bool {
-        self.seen.contains_key(&message_id)
+    async fn claim(&self, message_id: Uuid) -> Result<Claim, IdempotencyError> {
+        if self.claims.fetch_add(1, Ordering::Relaxed) % SWEEP_EVERY == SWEEP_EVERY - 1 {
+            self.evict_expired();
+        }
+
+        let now = Instant::now();
+        let claimed = Slot { processed: None, expires_at: now + self.claim_ttl };
+        Ok(match self.slots.entry(message_id) {
+            Entry::Occupied(mut entry) if entry.get().expires_at <= now => {
+                entry.insert(claimed);
+                Claim::Acquired
+            }
+            Entry::Occupied(entry) => match &entry.get().processed {
+                Some(result) => Claim::Processed(Some(result.clone())),
+                None => Claim::InFlight,
+            },
+            Entry::Vacant(entry) => {
+                entry.insert(claimed);
+                Claim::Acquired
+            }
+        })
     }
 
-    async fn mark_processed(&self, message_id: Uuid) {
-        self.seen.insert(message_id, ());
+    async fn complete(&self, message_id: Uuid, result: Vec<u8>) -> Result<(), IdempotencyError> {
+        let expires_at = Instant::now() + self.retention;
+        self.slots.insert(message_id, Slot { processed: Some(result), expires_at });
+        Ok(())
+    }
+
+    async fn release(&self, message_id: Uuid) -> Result<(), IdempotencyError> {
+        self.slots.remove_if(&message_id, |_, slot| slot.processed.is_none());
+        Ok(())
     }
 }
 
 // ── IdempotencyLayer ──────────────────────────────────────────────────────────
 
-/// Command-only middleware that deduplicates dispatches by `envelope.message_id`.
+/// Command-only middleware that deduplicates dispatches and replays the first
+/// dispatch's output to every duplicate.
 ///
 /// Queries are naturally idempotent (read-only) and do not need this layer.
 ///
+/// ## Keys
+///
+/// - An envelope carrying a client-supplied key
+///   ([`Envelope::with_idempotency_key`], e.g. from the gRPC `idempotency-key`
+///   header) is keyed on that key, scoped to the command type and the
+///   [`principal`](Envelope::principal): a client that retries `CreatePost` over
+///   a flaky link gets the original post id back, and another user sending the
+///   same key runs their own command. The key's request fingerprint is recorded
+///   with the output, and a later dispatch under the key with a different
+///   fingerprint fails with [`IdempotencyError::KeyReused`] instead of replaying.
+/// - Otherwise the key is `envelope.message_id`, which only dedups when a retry
+///   reuses the original id (see [`Envelope::with_message_id`] — e.g. a Kafka
+///   redelivery); [`Envelope::new`] mints a fresh one.
+///
 /// ## Algorithm
 ///
-/// 1. Check `store.is_processed(message_id)`.
-/// 2. If **already processed** → return `Ok(())` immediately without calling
-///    the inner bus (transparent skip).
-/// 3. If **not processed** → forward to the inner bus.
-/// 4. On `Ok(())` → call `store.mark_processed(message_id)`.
-/// 5. On `Err(_)` → do **not** mark (allow retry).
+/// 1. `store.claim(key)`.
+/// 2. **Processed** → decode the recorded output and return it without calling
+///    the inner bus (transparent replay), unless it was recorded for a different
+///    request fingerprint.
+/// 3. **In flight** → fail with the retryable [`IdempotencyError::InFlight`].
+/// 4. **Acquired** → forward to the inner bus; on `Ok(output)` call
+///    `store.complete` with the JSON-encoded output and fingerprint, on `Err(_)` call
+///    `store.release` (allow retry).
+///
+/// ## Store failures
+///
+/// By default a failing store fails the dispatch
+/// ([`IdempotencyError::Store`]). [`fail_open`](Self::fail_open) instead runs
+/// the command undeduplicated — for services whose store is a softer
+/// dependency than the write itself.
 ///
 /// ## Example
 ///
@@ -92,25 +330,32 @@ impl IdempotencyStore for InMemoryIdempotencyStore {
 ///     .build();
 /// ```
 pub struct IdempotencyLayer<Store> {
-    store: Arc<Store>,
+    store:     Arc<Store>,
+    fail_open: bool,
 }
 
 impl<Store: IdempotencyStore> IdempotencyLayer<Store> {
     pub fn new(store: Store) -> Self {
-        Self {
-            store: Arc::new(store),
-        }
+        Self::with_shared(Arc::new(store))
     }
 
     pub fn with_shared(store: Arc<Store>) -> Self {
-        Self { store }
+        Self { store, fail_open: false }
+    }
+
+    /// Dispatch without deduplication when the store is unavailable, instead of
+    /// failing the command.
+    pub fn fail_open(mut self) -> Self {
+        self.fail_open = true;
+        self
     }
 }
 
 impl<Store: IdempotencyStore> Clone for IdempotencyLayer<Store> {
     fn clone(&self) -> Self {
         Self {
-            store: Arc::clone(&self.store),
+            store:     Arc::clone(&self.store),
+            fail_open: self.fail_open,
         }
     }
 }
@@ -118,8 +363,9 @@ impl<Store: IdempotencyStore> Clone for IdempotencyLayer<Store> {
 // ── IdempotencyCommandBus ─────────────────────────────────────────────────────
 
 pub struct IdempotencyCommandBus<S, Store> {
-    inner: S,
-    store: Arc<Store>,
+    inner:     S,
+    store:     Arc<Store>,
+    fail_open: bool,
 }
 
 impl<S, Store: IdempotencyStore> CommandLayer<S> for IdempotencyLayer<Store> {
@@ -128,37 +374,394 @@ impl<S, Store: IdempotencyStore> CommandLayer<S> for IdempotencyLayer<Store> {
     fn layer(&self, inner: S) -> Self::Service {
         IdempotencyCommandBus {
             inner,
-            store: Arc::clone(&self.store),
+            store:     Arc::clone(&self.store),
+            fail_open: self.fail_open,
         }
     }
 }
 
+/// The store key for `envelope`: its client-supplied idempotency key scoped to
+/// `C` and the principal, else its `message_id`.
+///
+/// An envelope without a principal (an unauthenticated or internal caller)
+/// shares one anonymous scope per command type.
+///
+/// Public for handlers that settle the key inside their own write transaction
+/// (e.g. `PgIdempotencyStore::claim_in`): they must mark the key the layer claimed.
+pub fn dedup_key<C>(envelope: &Envelope<C>) -> Uuid {
+    match envelope.idempotency_key() {
+        Some(key) => {
+            let principal = envelope.principal().unwrap_or_default();
+            let scoped = format!("{}\n{principal}\n{key}", std::any::type_name::<C>());
+            Uuid::new_v5(&CLIENT_KEY_NAMESPACE, scoped.as_bytes())
+        }
+        None => envelope.message_id,
+    }
+}
+
+/// What [`IdempotencyStore::complete`] records: the output, and the fingerprint
+/// of the request that produced it.
+#[derive(Serialize)]
+struct Recorded<'a, T> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    fingerprint: Option<&'a str>,
+    output:      &'a T,
+}
+
+#[derive(Deserialize)]
+struct Replayed<T> {
+    #[serde(default)]
+    fingerprint: Option<String>,
+    output:      T,
+}
+
+/// Decodes a recorded output for a dispatch carrying `fingerprint`. A bare mark
+/// replays as `null` — exactly `()` for the unit-output commands such marks are
+/// written for.
+fn replay<T: DeserializeOwned>(
+    key: Uuid,
+    fingerprint: Option<&str>,
+    recorded: Option<&[u8]>,
+) -> Result<T, CqrsError> {
+    let Some(recorded) = recorded else {
+        return serde_json::from_slice(b"null").map_err(|e| undecodable(key, e));
+    };
+    let replayed: Replayed<T> = serde_json::from_slice(recorded).map_err(|e| undecodable(key, e))?;
+    if replayed.fingerprint.as_deref() != fingerprint {
+        return Err(CqrsError::from_handler(IdempotencyError::KeyReused { message_id: key }));
+    }
+    Ok(replayed.output)
+}
+
+fn undecodable(key: Uuid, e: serde_json::Error) -> CqrsError {
+    CqrsError::from_handler(IdempotencyError::UndecodableResult {
+        message_id: key,
+        reason:     e.to_string(),
+    })
+}
+
 impl<S: CommandBus, Store: IdempotencyStore> CommandBus for IdempotencyCommandBus<S, Store> {
     fn dispatch<C: Command>(
         &self,
         envelope: Envelope<C>,
-    ) -> impl Future<Output = Result<(), CqrsError>> + Send + '_ {
+    ) -> impl Future<Output = Result<C::Output, CqrsError>> + Send + '_ {
         let store = Arc::clone(&self.store);
 
         async move {
             let message_id = envelope.message_id;
+            let key = dedup_key(&envelope);
+            let fingerprint = envelope.idempotency_fingerprint().map(str::to_owned);
 
-            if store.is_processed(message_id).await {
-                tracing::info!(
-                    %message_id,
-                    message.type = std::any::type_name::<C>(),
-                    "idempotency: duplicate command skipped",
-                );
-                return Ok(());
+            match store.claim(key).await {
+                Ok(Claim::Acquired) => {}
+                Ok(Claim::Processed(recorded)) => {
+                    tracing::info!(
+                        %message_id,
+                        idempotency.key = %key,
+                        message.type = std::any::type_name::<C>(),
+                        "idempotency: duplicate command answered from the recorded result",
+                    );
+                    return replay(key, fingerprint.as_deref(), recorded.as_deref());
+                }
+                Ok(Claim::InFlight) => {
+                    tracing::info!(
+                        %message_id,
+                        idempotency.key = %key,
+                        message.type = std::any::type_name::<C>(),
+                        "idempotency: command already in flight",
+                    );
+                    return Err(CqrsError::from_handler(IdempotencyError::InFlight { message_id: key }));
+                }
+                Err(error) if self.fail_open => {
+                    tracing::warn!(
+                        %message_id,
+                        %error,
+                        "idempotency: store unavailable, dispatching without deduplication",
+                    );
+                    return self.inner.dispatch(envelope).await;
+                }
+                Err(error) => return Err(CqrsError::from_handler(error)),
             }
 
             let result = self.inner.dispatch(envelope).await;
 
-            if result.is_ok() {
-                store.mark_processed(message_id).await;
+            // The command's outcome stands either way; a store that cannot record
+            // it only costs a later duplicate a re-run (or a claim-TTL wait).
+            // Encoded before any await: the borrowed output need not be `Sync`.
+            let recorded = result.as_ref().ok().map(|output| {
+                serde_json::to_vec(&Recorded { fingerprint: fingerprint.as_deref(), output })
+            });
+            let settled = match recorded {
+                Some(Ok(encoded)) => store.complete(key, encoded).await,
+                // Nothing replayable to record: free the key for a re-run
+                // rather than mark it processed without an answer.
+                Some(Err(e)) => store
+                    .release(key)
+                    .await
+                    .and(Err(IdempotencyError::Store(format!("output does not encode: {e}")))),
+                None => store.release(key).await,
+            };
+            if let Err(error) = settled {
+                tracing::warn!(%message_id, %error, "idempotency: failed to settle the claim");
             }
 
             result
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicBool, AtomicUsize};
+
+    use futures::executor::block_on;
+
+    use crate::command::handler::CommandHandler;
+    use crate::command::registry::{CommandBusBuilder, InMemoryCommandBus};
+
+    use super::*;
+
+    #[derive(Clone)]
+    struct Ping;
+    impl validate_core::Validate for Ping {}
+    impl Command for Ping {
+        type Output = ();
+    }
+
+    /// Returns a fresh number per run, so a replay is observable.
+    #[derive(Clone)]
+    struct Mint;
+    impl validate_core::Validate for Mint {}
+    impl Command for Mint {
+        type Output = usize;
+    }
+
+    /// Counts handler runs; fails them while `failing` is set.
+    #[derive(Default)]
+    struct Counters {
+        calls:   AtomicUsize,
+        failing: AtomicBool,
+    }
+
+    struct Counting(Arc<Counters>);
+
+    impl Counting {
+        fn run(&self) -> Result<usize, IdempotencyError> {
+            let n = self.0.calls.fetch_add(1, Ordering::SeqCst) + 1;
+            if self.0.failing.load(Ordering::SeqCst) {
+                Err(IdempotencyError::Store("handler down".into()))
+            } else {
+                Ok(n)
+            }
+        }
+    }
+
+    impl CommandHandler<Ping> for Counting {
+        type Error = IdempotencyError;
+
+        async fn handle(&self, _: Envelope<Ping>) -> Result<(), IdempotencyError> {
+            self.run().map(drop)
+        }
+    }
+
+    impl CommandHandler<Mint> for Counting {
+        type Error = IdempotencyError;
+
+        async fn handle(&self, _: Envelope<Mint>) -> Result<usize, IdempotencyError> {
+            self.run()
+        }
+    }
+
+    fn counting_bus() -> (InMemoryCommandBus, Arc<Counters>) {
+        let counters = Arc::new(Counters::default());
+        let bus = CommandBusBuilder::new()
+            .register::<Ping, _>(Counting(Arc::clone(&counters)))
+            .unwrap()
+            .register::<Mint, _>(Counting(Arc::clone(&counters)))
+            .unwrap()
+            .build();
+        (bus, counters)
+    }
+
+    fn calls(counters: &Counters) -> usize {
+        counters.calls.load(Ordering::SeqCst)
+    }
+
+    struct DownStore;
+
+    impl IdempotencyStore for DownStore {
+        async fn claim(&self, _: Uuid) -> Result<Claim, IdempotencyError> {
+            Err(IdempotencyError::Store("unreachable".into()))
+        }
+        async fn complete(&self, _: Uuid, _: Vec<u8>) -> Result<(), IdempotencyError> {
+            Err(IdempotencyError::Store("unreachable".into()))
+        }
+        async fn release(&self, _: Uuid) -> Result<(), IdempotencyError> {
+            Err(IdempotencyError::Store("unreachable".into()))
+        }
+    }
+
+    fn ping() -> Envelope<Ping> {
+        Envelope::new(Uuid::now_v7(), Ping)
+    }
+
+    fn code<T: fmt::Debug>(result: Result<T, CqrsError>) -> &'static str {
+        match result {
+            Err(CqrsError::Handler(e)) => e.error_code(),
+            other => panic!("expected a handler error, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn a_redelivered_message_runs_once() {
+        let (inner, counters) = counting_bus();
+        let bus = IdempotencyLayer::new(InMemoryIdempotencyStore::new()).layer(inner);
+        let envelope = ping();
+
+        block_on(bus.dispatch(envelope.clone())).unwrap();
+        block_on(bus.dispatch(envelope)).unwrap();
+        block_on(bus.dispatch(ping())).unwrap();
+
+        assert_eq!(calls(&counters), 2);
+    }
+
+    #[test]
+    fn a_retry_under_the_same_client_key_replays_the_first_output() {
+        let (inner, counters) = counting_bus();
+        let bus = IdempotencyLayer::new(InMemoryIdempotencyStore::new()).layer(inner);
+        let keyed = || Envelope::new(Uuid::now_v7(), Mint).with_idempotency_key("create-1", "fp");
+
+        let first = block_on(bus.dispatch(keyed())).unwrap();
+        let retry = block_on(bus.dispatch(keyed())).unwrap();
+        let other = block_on(bus.dispatch(Envelope::new(Uuid::now_v7(), Mint))).unwrap();
+
+        assert_eq!(retry, first, "the retry gets the original output back");
+        assert_ne!(other, first, "an unkeyed dispatch runs on its own");
+        assert_eq!(calls(&counters), 2);
+    }
+
+    #[test]
+    fn client_keys_are_scoped_to_the_command_type() {
+        let (inner, counters) = counting_bus();
+        let bus = IdempotencyLayer::new(InMemoryIdempotencyStore::new()).layer(inner);
+
+        block_on(bus.dispatch(ping().with_idempotency_key("k", "fp"))).unwrap();
+        block_on(bus.dispatch(Envelope::new(Uuid::now_v7(), Mint).with_idempotency_key("k", "fp"))).unwrap();
+
+        assert_eq!(calls(&counters), 2);
+    }
+
+    #[test]
+    fn client_keys_are_scoped_to_the_principal() {
+        let (inner, counters) = counting_bus();
+        let bus = IdempotencyLayer::new(InMemoryIdempotencyStore::new()).layer(inner);
+        let keyed = |user: &str| {
+            Envelope::new(Uuid::now_v7(), Mint).with_principal(user).with_idempotency_key("k", "fp")
+        };
+
+        let alice = block_on(bus.dispatch(keyed("alice"))).unwrap();
+        let bob = block_on(bus.dispatch(keyed("bob"))).unwrap();
+
+        assert_ne!(bob, alice, "another user's key never replays alice's output");
+        assert_eq!(block_on(bus.dispatch(keyed("alice"))).unwrap(), alice);
+        assert_eq!(calls(&counters), 2);
+    }
+
+    #[test]
+    fn a_key_reused_with_a_different_payload_is_rejected() {
+        let (inner, counters) = counting_bus();
+        let bus = IdempotencyLayer::new(InMemoryIdempotencyStore::new()).layer(inner);
+        let keyed = |fingerprint: &str| Envelope::new(Uuid::now_v7(), Mint).with_idempotency_key("k", fingerprint);
+
+        block_on(bus.dispatch(keyed("first"))).unwrap();
+        let err = block_on(bus.dispatch(keyed("second")));
+
+        assert_eq!(code(err), "CQRS_IDEMPOTENCY_KEY_REUSED");
+        assert_eq!(calls(&counters), 1);
+    }
+
+    #[test]
+    fn a_failed_dispatch_releases_its_claim_for_the_retry() {
+        let (inner, counters) = counting_bus();
+        let bus = IdempotencyLayer::new(InMemoryIdempotencyStore::new()).layer(inner);
+        let envelope = ping();
+
+        counters.failing.store(true, Ordering::SeqCst);
+        assert!(block_on(bus.dispatch(envelope.clone())).is_err());
+        counters.failing.store(false, Ordering::SeqCst);
+        block_on(bus.dispatch(envelope)).unwrap();
+
+        assert_eq!(calls(&counters), 2);
+    }
+
+    #[test]
+    fn an_in_flight_duplicate_is_rejected_as_retryable() {
+        let (inner, counters) = counting_bus();
+        let store = Arc::new(InMemoryIdempotencyStore::new());
+        let bus = IdempotencyLayer::with_shared(Arc::clone(&store)).layer(inner);
+        let envelope = ping();
+
+        // Another replica holds the claim.
+        assert_eq!(block_on(store.claim(envelope.message_id)).unwrap(), Claim::Acquired);
+        let err = block_on(bus.dispatch(envelope));
+
+        assert_eq!(code(err), "CQRS_IDEMPOTENCY_IN_FLIGHT");
+        assert_eq!(calls(&counters), 0);
+    }
+
+    #[test]
+    fn a_recorded_output_of_the_wrong_shape_is_reported() {
+        let (inner, counters) = counting_bus();
+        let store = Arc::new(InMemoryIdempotencyStore::new());
+        let bus = IdempotencyLayer::with_shared(Arc::clone(&store)).layer(inner);
+        let envelope = Envelope::new(Uuid::now_v7(), Mint);
+
+        block_on(store.claim(envelope.message_id)).unwrap();
+        block_on(store.complete(envelope.message_id, br#"{"output":"not a number"}"#.to_vec())).unwrap();
+
+        assert_eq!(code(block_on(bus.dispatch(envelope))), "CQRS_IDEMPOTENCY_RESULT_UNDECODABLE");
+        assert_eq!(calls(&counters), 0);
+    }
+
+    #[test]
+    fn a_down_store_fails_closed_unless_configured_open() {
+        let (inner, counters) = counting_bus();
+        let closed = IdempotencyLayer::new(DownStore).layer(inner);
+        assert_eq!(code(block_on(closed.dispatch(ping()))), "CQRS_IDEMPOTENCY_STORE_UNAVAILABLE");
+        assert_eq!(calls(&counters), 0);
+
+        let (inner, counters) = counting_bus();
+        let open = IdempotencyLayer::new(DownStore).fail_open().layer(inner);
+        block_on(open.dispatch(ping())).unwrap();
+        assert_eq!(calls(&counters), 1);
+    }
+
+    #[test]
+    fn claims_and_processed_marks_expire() {
+        let store = InMemoryIdempotencyStore::new()
+            .with_claim_ttl(Duration::ZERO)
+            .with_retention(Duration::ZERO);
+        let id = Uuid::now_v7();
+
+        assert_eq!(block_on(store.claim(id)).unwrap(), Claim::Acquired);
+        assert_eq!(block_on(store.claim(id)).unwrap(), Claim::Acquired, "an expired claim is taken over");
+        block_on(store.complete(id, b"null".to_vec())).unwrap();
+        assert_eq!(block_on(store.claim(id)).unwrap(), Claim::Acquired, "an expired mark is forgotten");
+
+        store.evict_expired();
+        assert!(store.is_empty());
+    }
+
+    #[test]
+    fn release_never_drops_a_processed_mark() {
+        let store = InMemoryIdempotencyStore::new();
+        let id = Uuid::now_v7();
+
+        block_on(store.claim(id)).unwrap();
+        block_on(store.complete(id, b"7".to_vec())).unwrap();
+        block_on(store.release(id)).unwrap();
+
+        assert_eq!(block_on(store.claim(id)).unwrap(), Claim::Processed(Some(b"7".to_vec())));
+        assert_eq!(store.len(), 1);
+    }
+}
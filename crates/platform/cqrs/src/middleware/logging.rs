@@ -51,7 +51,7 @@ impl<S: CommandBus> CommandBus for LoggingCommandBus<S> {
     fn dispatch<C: Command>(
         &self,
         envelope: Envelope<C>,
-    ) -> impl Future<Output = Result<(), CqrsError>> + Send + '_ {
+    ) -> impl Future<Output = Result<C::Output, CqrsError>> + Send + '_ {
         let type_name = std::any::type_name::<C>();
         let correlation_id = envelope.correlation_id;
         let message_id = envelope.message_id;
@@ -69,7 +69,7 @@ impl<S: CommandBus> CommandBus for LoggingCommandBus<S> {
             let elapsed_ms = started.elapsed().as_millis();
 
             match &result {
-                Ok(()) => tracing::info!(
+                Ok(_) => tracing::info!(
                     message.type = type_name,
                     %correlation_id,
                     %message_id,
@@ -0,0 +1,157 @@
+use std::future::Future;
+use std::time::Instant;
+
+use ::error::AppError;
+use opentelemetry::{global, metrics::Histogram, KeyValue};
+
+use crate::command::bus::CommandBus;
+use crate::command::command::Command;
+use crate::envelope::Envelope;
+use crate::error::CqrsError;
+use crate::query::bus::QueryBus;
+use crate::query::query::Query;
+
+use super::layer::{CommandLayer, QueryLayer};
+
+/// Histogram buckets (seconds): 0.5 ms … 5 s — a dispatch is one handler, not a whole RPC.
+const DURATION_BUCKETS: &[f64] = &[
+    0.0005, 0.001, 0.0025, 0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0,
+];
+
+/// Middleware that records the wall-clock duration of every dispatch in an
+/// OpenTelemetry histogram.
+///
+/// Like [`TracingLayer`](super::TracingLayer) it uses only the `opentelemetry`
+/// façade: the instruments bind to the global meter provider installed by
+/// `telemetry::init()`, and are no-ops before it (or in tests).
+///
+/// ## Instruments
+///
+/// | Instrument              | Prometheus series                       |
+/// |-------------------------|-----------------------------------------|
+/// | `cqrs_command_duration` | `cqrs_command_duration_seconds` (histogram) |
+/// | `cqrs_query_duration`   | `cqrs_query_duration_seconds` (histogram)   |
+///
+/// The histogram's `_count` doubles as the dispatch rate, so no separate
+/// counter is kept.
+///
+/// ## Labels
+///
+/// | Label          | Value                                              |
+/// |----------------|----------------------------------------------------|
+/// | `message_type` | Fully qualified Rust type name of the command/query |
+/// | `outcome`      | `"ok"` or `"error"`                                |
+/// | `error_code`   | The error's stable code — present only on failure  |
+///
+/// Place it outside [`IdempotencyLayer`](super::IdempotencyLayer) to time
+/// replays as the caller experiences them, or inside to time only real runs.
+#[derive(Clone)]
+pub struct MetricsLayer {
+    command_duration: Histogram<f64>,
+    query_duration: Histogram<f64>,
+}
+
+impl MetricsLayer {
+    pub fn new() -> Self {
+        let meter = global::meter("cqrs");
+        Self {
+            command_duration: meter
+                .f64_histogram("cqrs_command_duration")
+                .with_unit("s")
+                .with_description("Command dispatch duration, by command type and outcome.")
+                .with_boundaries(DURATION_BUCKETS.to_vec())
+                .build(),
+            query_duration: meter
+                .f64_histogram("cqrs_query_duration")
+                .with_unit("s")
+                .with_description("Query dispatch duration, by query type and outcome.")
+                .with_boundaries(DURATION_BUCKETS.to_vec())
+                .build(),
+        }
+    }
+}
+
+impl Default for MetricsLayer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Attribute set for one dispatch.
+fn dispatch_attrs<T>(message_type: &'static str, result: &Result<T, CqrsError>) -> Vec<KeyValue> {
+    match result {
+        Ok(_) => vec![
+            KeyValue::new("message_type", message_type),
+            KeyValue::new("outcome", "ok"),
+        ],
+        Err(e) => vec![
+            KeyValue::new("message_type", message_type),
+            KeyValue::new("outcome", "error"),
+            KeyValue::new("error_code", e.error_code()),
+        ],
+    }
+}
+
+// ── Command side ──────────────────────────────────────────────────────────────
+
+pub struct MetricsCommandBus<S> {
+    inner: S,
+    duration: Histogram<f64>,
+}
+
+impl<S> CommandLayer<S> for MetricsLayer {
+    type Service = MetricsCommandBus<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        MetricsCommandBus { inner, duration: self.command_duration.clone() }
+    }
+}
+
+impl<S: CommandBus> CommandBus for MetricsCommandBus<S> {
+    fn dispatch<C: Command>(
+        &self,
+        envelope: Envelope<C>,
+    ) -> impl Future<Output = Result<C::Output, CqrsError>> + Send + '_ {
+        let message_type = std::any::type_name::<C>();
+
+        async move {
+            let started = Instant::now();
+            let result = self.inner.dispatch(envelope).await;
+            self.duration
+                .record(started.elapsed().as_secs_f64(), &dispatch_attrs(message_type, &result));
+            result
+        }
+    }
+}
+
+// ── Query side ────────────────────────────────────────────────────────────────
+
+pub struct MetricsQueryBus<S> {
+    inner: S,
+    duration: Histogram<f64>,
+}
+
+impl<S> QueryLayer<S> for MetricsLayer {
+    type Service = MetricsQueryBus<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        MetricsQueryBus { inner, duration: self.query_duration.clone() }
+    }
+}
+
+impl<S: QueryBus> QueryBus for MetricsQueryBus<S> {
+    fn dispatch<Q: Query>(
+        &self,
+        envelope: Envelope<Q>,
+    ) -> impl Future<Output = Result<Q::Response, CqrsError>> + Send + '_ {
+        let message_type = std::any::type_name::<Q>();
+
+        async move {
+            let started = Instant::now();
+            let result = self.inner.dispatch(envelope).await;
+            self.duration
+                .record(started.elapsed().as_secs_f64(), &dispatch_attrs(message_type, &result));
+            result
+        }
+    }
+}
@@ -1,11 +1,13 @@
 pub(crate) mod idempotency;
 pub(crate) mod layer;
 pub(crate) mod logging;
+pub(crate) mod metrics;
 pub(crate) mod pipeline;
 pub(crate) mod tracing;
 
 pub use idempotency::*;
 pub use layer::*;
 pub use logging::*;
+pub use metrics::*;
 pub use pipeline::*;
 pub use tracing::*;
@@ -53,7 +53,7 @@ impl<S: CommandBus> CommandBus for TracingCommandBus<S> {
     fn dispatch<C: Command>(
         &self,
         envelope: Envelope<C>,
-    ) -> impl Future<Output = Result<(), CqrsError>> + Send + '_ {
+    ) -> impl Future<Output = Result<C::Output, CqrsError>> + Send + '_ {
         let span = tracing::info_span!(
             "cqrs.command.dispatch",
             otel.kind = "INTERNAL",
@@ -0,0 +1,7 @@
+use thiserror::Error;
+
+/// A table prefix that is not a safe identifier. Store I/O failures surface as
+/// [`cqrs::IdempotencyError::Store`] instead, since the layer is their only caller.
+#[derive(Debug, Error)]
+#[error("invalid idempotency table definition: {0}")]
+pub struct InvalidTable(pub String);
@@ -0,0 +1,53 @@
+//! Distributed backends for `cqrs`'s [`IdempotencyLayer`](cqrs::IdempotencyLayer).
+//!
+//! `cqrs` ships only the in-process `InMemoryIdempotencyStore`, which deduplicates
+//! within one replica. A command retried against another replica — or the same Kafka
+//! record redelivered after a rebalance — needs a store every replica shares:
+//!
+//! | Store                       | Backing                        | Claim                            |
+//! |-----------------------------|--------------------------------|----------------------------------|
+//! | [`RedisIdempotencyStore`]   | one key per `message_id`       | one Lua script (GET, else SET PX)|
+//! | [`PgIdempotencyStore`]      | `<prefix>_idempotency` table   | unique insert (`ON CONFLICT`)    |
+//! | [`ScyllaIdempotencyStore`]  | `<ks>.idempotency` table       | LWT `INSERT … IF NOT EXISTS`     |
+//!
+//! # States
+//!
+//! ```text
+//!            claim                complete
+//! (absent) ───────► claimed ──────────────► processed ──(retention)──► (absent)
+//!    ▲                 │  release / claim TTL
+//!    └─────────────────┘
+//! ```
+//!
+//! A **claimed** id blocks every other replica (they get `Claim::InFlight`) until the
+//! holder completes it, releases it after a failure, or dies and the claim TTL lapses.
+//! A **processed** id is skipped for the retention window.
+//!
+//! # Postgres, in the command's own transaction
+//!
+//! The detached [`IdempotencyStore`](cqrs::IdempotencyStore) impl claims and completes
+//! in statements of their own, so a crash between the write and `complete` leaves the
+//! id claimed until the TTL and then re-runs it. A writer that already runs in
+//! `TransactionManager::run_on_shard` can close that gap with
+//! [`PgIdempotencyStore::claim_in`], keyed on [`cqrs::dedup_key`]: the layer's claim
+//! turns processed in the same transaction as the write, so the two commit or roll
+//! back together, and a concurrent duplicate waits on the row instead of writing.
+//!
+//! # Schema
+//!
+//! As with the outbox, each Postgres service owns its table, named from a prefix
+//! (`account` → `account_idempotency`). [`IdempotencyTable::ddl`] renders the canonical
+//! schema; service migrations are verbatim copies of it. The ScyllaDB services keep
+//! theirs in their own keyspace ([`ScyllaIdempotencyTable::ddl`]).
+
+pub mod error;
+pub mod postgres;
+pub mod redis;
+pub mod scylla;
+pub mod table;
+
+pub use error::InvalidTable;
+pub use postgres::PgIdempotencyStore;
+pub use redis::RedisIdempotencyStore;
+pub use self::scylla::ScyllaIdempotencyStore;
+pub use table::{IdempotencyTable, ScyllaIdempotencyTable};
@@ -0,0 +1,196 @@
+//! [`IdempotencyStore`] over a service's `<prefix>_idempotency` table.
+
+use std::time::Duration;
+
+use cqrs::{Claim, IdempotencyError, IdempotencyStore, DEFAULT_CLAIM_TTL, DEFAULT_RETENTION};
+use postgres_storage::{PgTransaction, StorageError, TransactionManager};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::table::IdempotencyTable;
+
+/// Postgres-backed idempotency store. Cheap to clone (the table definition, a
+/// [`TransactionManager`] clone and the rendered statements).
+///
+/// Every statement for a `message_id` is routed with
+/// [`TransactionManager::run_on_shard`] on that id, so a sharded deployment keeps
+/// each id's row on one shard.
+#[derive(Clone)]
+pub struct PgIdempotencyStore {
+    table:       IdempotencyTable,
+    tx:          TransactionManager,
+    claim_ttl:   Duration,
+    retention:   Duration,
+    claim_sql:   String,
+    take_sql:    String,
+    state_sql:   String,
+    process_sql: String,
+    release_sql: String,
+    purge_sql:   String,
+}
+
+impl PgIdempotencyStore {
+    /// A store on `table` with [`DEFAULT_CLAIM_TTL`] and [`DEFAULT_RETENTION`].
+    pub fn new(table: IdempotencyTable, tx: TransactionManager) -> Self {
+        let t = table.table();
+        Self {
+            claim_sql: insert_unless_live(t, "claimed"),
+            take_sql: format!(
+                "INSERT INTO {t} AS t (message_id, state, expires_at) \
+                 VALUES ($1, 'processed', now() + make_interval(secs => $2)) \
+                 ON CONFLICT (message_id) DO UPDATE \
+                 SET state = 'processed', expires_at = EXCLUDED.expires_at \
+                 WHERE t.state = 'claimed' OR t.expires_at <= now() \
+                 RETURNING message_id"
+            ),
+            state_sql: format!("SELECT state, result FROM {t} WHERE message_id = $1"),
+            process_sql: format!(
+                "INSERT INTO {t} AS t (message_id, state, expires_at, result) \
+                 VALUES ($1, 'processed', now() + make_interval(secs => $2), $3) \
+                 ON CONFLICT (message_id) DO UPDATE \
+                 SET state = 'processed', expires_at = EXCLUDED.expires_at, result = EXCLUDED.result"
+            ),
+            release_sql: format!("DELETE FROM {t} WHERE message_id = $1 AND state = 'claimed'"),
+            purge_sql: format!("DELETE FROM {t} WHERE expires_at <= now()"),
+            table,
+            tx,
+            claim_ttl: DEFAULT_CLAIM_TTL,
+            retention: DEFAULT_RETENTION,
+        }
+    }
+
+    /// Overrides how long an unfinished claim blocks other replicas.
+    pub fn with_claim_ttl(mut self, ttl: Duration) -> Self {
+        self.claim_ttl = ttl;
+        self
+    }
+
+    /// Overrides how long a processed `message_id` is remembered.
+    pub fn with_retention(mut self, retention: Duration) -> Self {
+        self.retention = retention;
+        self
+    }
+
+    pub fn table(&self) -> &IdempotencyTable {
+        &self.table
+    }
+
+    /// Records `message_id` as processed **inside the caller's transaction**, for
+    /// writers that run in [`TransactionManager::run_on_shard`] (route on the
+    /// `message_id`, or keep the table on the writer's shard).
+    ///
+    /// Built to run under the [`IdempotencyLayer`](cqrs::IdempotencyLayer): pass
+    /// [`cqrs::dedup_key`] of the envelope, and the layer's `claimed` row turns
+    /// `processed` in the same commit as the write. A crash between that commit and
+    /// the layer's `complete` then leaves the mark instead of an expiring claim, so
+    /// the command never re-runs; `complete` still records the output when it gets
+    /// there.
+    ///
+    /// Returns `false` when the id was already processed — the caller should
+    /// return without writing. Otherwise the row commits or rolls back with the
+    /// write, and a concurrent transaction marking the same id waits on the row
+    /// until this one settles, so the pair can never both write.
+    ///
+    /// A mark whose `complete` never ran records no output, so a duplicate
+    /// replays `null` — `()` for unit-output commands, `UndecodableResult` for
+    /// the rest.
+    pub async fn claim_in(
+        &self,
+        tx: &mut PgTransaction,
+        message_id: Uuid,
+    ) -> Result<bool, StorageError> {
+        let taken: Option<Uuid> = sqlx::query_scalar(&self.take_sql)
+            .bind(message_id)
+            .bind(self.retention.as_secs_f64())
+            .fetch_optional(&mut **tx)
+            .await?;
+        Ok(taken.is_some())
+    }
+
+    /// Deletes every expired claim and processed mark; returns how many rows went.
+    /// Run it on a timer against each shard's pool to keep the table bounded.
+    pub async fn purge_expired(&self, pool: &PgPool) -> Result<u64, StorageError> {
+        let done = sqlx::query(&self.purge_sql).execute(pool).await?;
+        Ok(done.rows_affected())
+    }
+}
+
+/// Inserts `message_id` in `state`, or takes over its row once expired; returns
+/// the id only when this statement won.
+fn insert_unless_live(table: &str, state: &str) -> String {
+    format!(
+        "INSERT INTO {table} AS t (message_id, state, expires_at) \
+         VALUES ($1, '{state}', now() + make_interval(secs => $2)) \
+         ON CONFLICT (message_id) DO UPDATE \
+         SET state = '{state}', expires_at = EXCLUDED.expires_at \
+         WHERE t.expires_at <= now() \
+         RETURNING message_id"
+    )
+}
+
+fn store_err(e: StorageError) -> IdempotencyError {
+    IdempotencyError::Store(e.to_string())
+}
+
+impl IdempotencyStore for PgIdempotencyStore {
+    async fn claim(&self, message_id: Uuid) -> Result<Claim, IdempotencyError> {
+        // Owned captures: the transaction closure must be valid for any `'tx`.
+        let this = self.clone();
+        self.tx
+            .run_on_shard(&message_id, |tx| {
+                Box::pin(async move {
+                    let acquired: Option<Uuid> = sqlx::query_scalar(&this.claim_sql)
+                        .bind(message_id)
+                        .bind(this.claim_ttl.as_secs_f64())
+                        .fetch_optional(&mut **tx)
+                        .await?;
+                    if acquired.is_some() {
+                        return Ok(Claim::Acquired);
+                    }
+                    let state: Option<(String, Option<Vec<u8>>)> = sqlx::query_as(&this.state_sql)
+                        .bind(message_id)
+                        .fetch_optional(&mut **tx)
+                        .await?;
+                    Ok(match state {
+                        Some((state, result)) if state == "processed" => Claim::Processed(result),
+                        // `None`: the holder released it between the two statements.
+                        // Report it in flight; the retry will acquire it.
+                        _ => Claim::InFlight,
+                    })
+                })
+            })
+            .await
+            .map_err(store_err)
+    }
+
+    async fn complete(&self, message_id: Uuid, result: Vec<u8>) -> Result<(), IdempotencyError> {
+        let this = self.clone();
+        self.tx
+            .run_on_shard(&message_id, |tx| {
+                Box::pin(async move {
+                    sqlx::query(&this.process_sql)
+                        .bind(message_id)
+                        .bind(this.retention.as_secs_f64())
+                        .bind(result)
+                        .execute(&mut **tx)
+                        .await?;
+                    Ok::<_, StorageError>(())
+                })
+            })
+            .await
+            .map_err(store_err)
+    }
+
+    async fn release(&self, message_id: Uuid) -> Result<(), IdempotencyError> {
+        let this = self.clone();
+        self.tx
+            .run_on_shard(&message_id, |tx| {
+                Box::pin(async move {
+                    sqlx::query(&this.release_sql).bind(message_id).execute(&mut **tx).await?;
+                    Ok::<_, StorageError>(())
+                })
+            })
+            .await
+            .map_err(store_err)
+    }
+}
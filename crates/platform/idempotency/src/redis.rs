@@ -0,0 +1,131 @@
+//! [`IdempotencyStore`] over one Redis key per `message_id`.
+
+use std::time::Duration;
+
+use cqrs::{Claim, IdempotencyError, IdempotencyStore, DEFAULT_CLAIM_TTL, DEFAULT_RETENTION};
+use fred::interfaces::{KeysInterface, LuaInterface};
+use fred::types::Expiration;
+use redis_storage::RedisClient;
+use uuid::Uuid;
+
+/// Returns the live value, or takes the claim (`SET … PX claim_ttl`) when there
+/// is none. A single key keeps the script cluster-slot-safe.
+const CLAIM_SCRIPT: &str = r#"
+local state = redis.call('GET', KEYS[1])
+if state then return state end
+redis.call('SET', KEYS[1], 'claimed', 'PX', tonumber(ARGV[1]))
+return 'acquired'
+"#;
+
+/// Deletes the key only while it is still a claim, so a late release can never
+/// erase a processed mark.
+const RELEASE_SCRIPT: &str = r#"
+if redis.call('GET', KEYS[1]) == 'claimed' then
+  return redis.call('DEL', KEYS[1])
+end
+return 0
+"#;
+
+/// Redis-backed idempotency store: `idempotency:{namespace}:{message_id}` holds
+/// `claimed` (for the claim TTL) or `processed:` followed by the encoded output
+/// (for the retention window), and Redis expiry does the eviction.
+///
+/// The namespace keeps services that share a Redis deployment apart.
+pub struct RedisIdempotencyStore {
+    client:    RedisClient,
+    namespace: String,
+    claim_ttl: Duration,
+    retention: Duration,
+}
+
+impl RedisIdempotencyStore {
+    /// A store under `namespace` with [`DEFAULT_CLAIM_TTL`] and [`DEFAULT_RETENTION`].
+    pub fn new(client: RedisClient, namespace: impl Into<String>) -> Self {
+        Self {
+            client,
+            namespace: namespace.into(),
+            claim_ttl: DEFAULT_CLAIM_TTL,
+            retention: DEFAULT_RETENTION,
+        }
+    }
+
+    /// Overrides how long an unfinished claim blocks other replicas.
+    pub fn with_claim_ttl(mut self, ttl: Duration) -> Self {
+        self.claim_ttl = ttl;
+        self
+    }
+
+    /// Overrides how long a processed `message_id` is remembered.
+    pub fn with_retention(mut self, retention: Duration) -> Self {
+        self.retention = retention;
+        self
+    }
+
+    fn key(&self, message_id: Uuid) -> String {
+        format!("idempotency:{}:{message_id}", self.namespace)
+    }
+}
+
+/// Prefix of a processed value; the recorded output follows it.
+const PROCESSED: &[u8] = b"processed:";
+
+fn millis(d: Duration) -> i64 {
+    i64::try_from(d.as_millis()).unwrap_or(i64::MAX).max(1)
+}
+
+fn store_err(e: fred::error::Error) -> IdempotencyError {
+    IdempotencyError::Store(e.to_string())
+}
+
+impl IdempotencyStore for RedisIdempotencyStore {
+    async fn claim(&self, message_id: Uuid) -> Result<Claim, IdempotencyError> {
+        let value: Vec<u8> = self
+            .client
+            .inner
+            .eval(
+                CLAIM_SCRIPT,
+                vec![self.key(message_id)],
+                vec![millis(self.claim_ttl).to_string()],
+            )
+            .await
+            .map_err(store_err)?;
+        match value.as_slice() {
+            b"acquired" => Ok(Claim::Acquired),
+            b"claimed" => Ok(Claim::InFlight),
+            other => match other.strip_prefix(PROCESSED) {
+                Some(result) => Ok(Claim::Processed(Some(result.to_vec()))),
+                None => Err(IdempotencyError::Store(format!(
+                    "unexpected value '{}' under {}",
+                    String::from_utf8_lossy(other),
+                    self.key(message_id)
+                ))),
+            },
+        }
+    }
+
+    async fn complete(&self, message_id: Uuid, result: Vec<u8>) -> Result<(), IdempotencyError> {
+        let _: () = self
+            .client
+            .inner
+            .set(
+                self.key(message_id),
+                [PROCESSED, &result].concat(),
+                Some(Expiration::PX(millis(self.retention))),
+                None,
+                false,
+            )
+            .await
+            .map_err(store_err)?;
+        Ok(())
+    }
+
+    async fn release(&self, message_id: Uuid) -> Result<(), IdempotencyError> {
+        let _: i64 = self
+            .client
+            .inner
+            .eval(RELEASE_SCRIPT, vec![self.key(message_id)], Vec::<String>::new())
+            .await
+            .map_err(store_err)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,191 @@
+//! [`IdempotencyStore`] over a ScyllaDB service keyspace's `<ks>.idempotency` table.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use cqrs::{Claim, IdempotencyError, IdempotencyStore, DEFAULT_CLAIM_TTL, DEFAULT_RETENTION};
+use scylla::observability::history::HistoryListener;
+use scylla::response::query_result::QueryRowsResult;
+use scylla::statement::unprepared::Statement;
+use scylla::value::{CqlValue, Row};
+use scylla_storage::{ProfileKind, ScyllaClient};
+use uuid::Uuid;
+
+use crate::table::ScyllaIdempotencyTable;
+
+/// ScyllaDB-backed idempotency store, for the keyspace-per-service writers that
+/// have no Redis. Cheap to clone (the client handle and the rendered statements).
+///
+/// Every transition is a lightweight transaction, so Paxos orders them on the
+/// row instead of write timestamps:
+///
+/// - **claim** — `INSERT … IF NOT EXISTS USING TTL claim_ttl`; when it does not
+///   apply, the returned row says whether the id is claimed or processed.
+/// - **complete** — `UPDATE … USING TTL retention … IF EXISTS`, falling back to
+///   `INSERT … IF NOT EXISTS` when the claim already expired.
+/// - **release** — `DELETE … IF state = 'claimed'`, so a late release can never
+///   erase a processed mark.
+///
+/// Expiry is the rows' TTL; nothing sweeps the table.
+#[derive(Clone)]
+pub struct ScyllaIdempotencyStore {
+    client:       Arc<ScyllaClient>,
+    table:        ScyllaIdempotencyTable,
+    claim_ttl:    Duration,
+    retention:    Duration,
+    claim_cql:    String,
+    complete_cql: String,
+    insert_cql:   String,
+    release_cql:  String,
+}
+
+impl ScyllaIdempotencyStore {
+    /// A store on `table` with [`DEFAULT_CLAIM_TTL`] and [`DEFAULT_RETENTION`].
+    pub fn new(client: Arc<ScyllaClient>, table: ScyllaIdempotencyTable) -> Self {
+        let t = table.table();
+        Self {
+            claim_cql: format!(
+                "INSERT INTO {t} (message_id, state) VALUES (?, 'claimed') IF NOT EXISTS USING TTL ?"
+            ),
+            complete_cql: format!(
+                "UPDATE {t} USING TTL ? SET state = 'processed', result = ? WHERE message_id = ? IF EXISTS"
+            ),
+            insert_cql: format!(
+                "INSERT INTO {t} (message_id, state, result) VALUES (?, 'processed', ?) \
+                 IF NOT EXISTS USING TTL ?"
+            ),
+            release_cql: format!("DELETE FROM {t} WHERE message_id = ? IF state = 'claimed'"),
+            client,
+            table,
+            claim_ttl: DEFAULT_CLAIM_TTL,
+            retention: DEFAULT_RETENTION,
+        }
+    }
+
+    /// Overrides how long an unfinished claim blocks other replicas.
+    pub fn with_claim_ttl(mut self, ttl: Duration) -> Self {
+        self.claim_ttl = ttl;
+        self
+    }
+
+    /// Overrides how long a processed `message_id` is remembered.
+    pub fn with_retention(mut self, retention: Duration) -> Self {
+        self.retention = retention;
+        self
+    }
+
+    pub fn table(&self) -> &ScyllaIdempotencyTable {
+        &self.table
+    }
+
+    /// A statement on the `Strict` profile (LocalQuorum, LocalSerial for the
+    /// Paxos round) with the client's tracing listener attached.
+    fn strict_stmt(&self, cql: &str) -> Statement {
+        let mut stmt = Statement::new(cql);
+        stmt.set_execution_profile_handle(Some(
+            self.client
+                .profiles
+                .get(ProfileKind::Strict)
+                .clone()
+                .into_handle_with_label("strict".to_owned()),
+        ));
+        stmt.set_history_listener(Arc::clone(&self.client.history_listener) as Arc<dyn HistoryListener>);
+        stmt
+    }
+
+    /// Runs a conditional statement and returns its `[applied]` row.
+    async fn lwt(
+        &self,
+        cql: &str,
+        values: impl scylla::serialize::row::SerializeRow,
+    ) -> Result<LwtRow, IdempotencyError> {
+        let rows = self
+            .client
+            .session
+            .execute_unpaged(self.strict_stmt(cql), values)
+            .await
+            .map_err(store_err)?
+            .into_rows_result()
+            .map_err(store_err)?;
+        LwtRow::read(&rows)
+    }
+}
+
+/// The row a lightweight transaction answers with: whether it applied and, when
+/// it did not, the existing row's columns.
+struct LwtRow {
+    applied: bool,
+    state:   Option<String>,
+    result:  Option<Vec<u8>>,
+}
+
+impl LwtRow {
+    /// Reads the columns by name: ScyllaDB returns the table's columns alongside
+    /// `[applied]` whether or not the statement applied, Cassandra only when it
+    /// did not.
+    fn read(rows: &QueryRowsResult) -> Result<Self, IdempotencyError> {
+        let specs = rows.column_specs();
+        let index = |name: &str| specs.get_by_name(name).map(|(i, _)| i);
+        let (applied, state, result) = (index("[applied]"), index("state"), index("result"));
+        let mut columns = rows
+            .maybe_first_row::<Row>()
+            .map_err(store_err)?
+            .ok_or_else(|| IdempotencyError::Store("conditional statement returned no row".into()))?
+            .columns;
+        let mut take = |i: Option<usize>| i.and_then(|i| columns.get_mut(i).and_then(Option::take));
+        Ok(Self {
+            applied: matches!(take(applied), Some(CqlValue::Boolean(true))),
+            state:   match take(state) {
+                Some(CqlValue::Text(state)) => Some(state),
+                _ => None,
+            },
+            result:  match take(result) {
+                Some(CqlValue::Blob(result)) => Some(result),
+                _ => None,
+            },
+        })
+    }
+}
+
+/// A TTL in whole seconds, rounded up, at least one.
+fn ttl_secs(ttl: Duration) -> i32 {
+    let secs = ttl.as_secs() + u64::from(ttl.subsec_nanos() > 0);
+    i32::try_from(secs.max(1)).unwrap_or(i32::MAX)
+}
+
+fn store_err(e: impl ToString) -> IdempotencyError {
+    IdempotencyError::Store(e.to_string())
+}
+
+impl IdempotencyStore for ScyllaIdempotencyStore {
+    async fn claim(&self, message_id: Uuid) -> Result<Claim, IdempotencyError> {
+        let row = self.lwt(&self.claim_cql, (message_id, ttl_secs(self.claim_ttl))).await?;
+        if row.applied {
+            return Ok(Claim::Acquired);
+        }
+        match row.state.as_deref() {
+            Some("claimed") => Ok(Claim::InFlight),
+            Some("processed") => Ok(Claim::Processed(row.result)),
+            other => Err(IdempotencyError::Store(format!(
+                "unexpected state {other:?} for {message_id} in {}",
+                self.table.table()
+            ))),
+        }
+    }
+
+    async fn complete(&self, message_id: Uuid, result: Vec<u8>) -> Result<(), IdempotencyError> {
+        let retention = ttl_secs(self.retention);
+        let updated = self.lwt(&self.complete_cql, (retention, &result, message_id)).await?;
+        if !updated.applied {
+            // The claim outlived its TTL, so there is no row to update: write the
+            // mark fresh. Losing that insert means another dispatch recorded one.
+            self.lwt(&self.insert_cql, (message_id, &result, retention)).await?;
+        }
+        Ok(())
+    }
+
+    async fn release(&self, message_id: Uuid) -> Result<(), IdempotencyError> {
+        self.lwt(&self.release_cql, (message_id,)).await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,155 @@
+//! The per-service idempotency table.
+
+use crate::error::InvalidTable;
+
+/// Names a service's idempotency table (`<prefix>_idempotency`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IdempotencyTable {
+    prefix: String,
+    table:  String,
+}
+
+impl IdempotencyTable {
+    /// The table for `prefix`. The prefix is interpolated into SQL, so it must be
+    /// a lowercase identifier (`[a-z][a-z0-9_]*`) — anything else is rejected here
+    /// rather than quoted.
+    pub fn new(prefix: &str) -> Result<Self, InvalidTable> {
+        if !is_identifier(prefix) {
+            return Err(InvalidTable(format!("prefix '{prefix}' must match [a-z][a-z0-9_]*")));
+        }
+        Ok(Self {
+            prefix: prefix.to_owned(),
+            table:  format!("{prefix}_idempotency"),
+        })
+    }
+
+    pub fn prefix(&self) -> &str {
+        &self.prefix
+    }
+
+    pub fn table(&self) -> &str {
+        &self.table
+    }
+
+    /// The canonical schema. Service migrations are verbatim renderings of it.
+    ///
+    /// `state` is `claimed` or `processed`; `expires_at` is the claim TTL or the
+    /// retention deadline respectively; `result` is the processed command's
+    /// encoded output. The expiry index backs
+    /// [`PgIdempotencyStore::purge_expired`](crate::PgIdempotencyStore::purge_expired).
+    ///
+    /// Columns added after the first rendering are appended as idempotent
+    /// `ALTER`s rather than folded into the `CREATE`, so a service that shipped
+    /// an earlier rendering upgrades by shipping the new tail as its next
+    /// migration.
+    pub fn ddl(&self) -> String {
+        let table = &self.table;
+        format!(
+            "CREATE TABLE IF NOT EXISTS {table} (\n    \
+                 message_id UUID        PRIMARY KEY,\n    \
+                 state      TEXT        NOT NULL,\n    \
+                 expires_at TIMESTAMPTZ NOT NULL\n\
+             );\n\n\
+             CREATE INDEX IF NOT EXISTS {table}_expiry\n    \
+                 ON {table} (expires_at);\n\n\
+             ALTER TABLE {table}\n    \
+                 ADD COLUMN IF NOT EXISTS result BYTEA;\n"
+        )
+    }
+}
+
+/// Names a ScyllaDB service keyspace's idempotency table (`<ks>.idempotency`).
+///
+/// The ScyllaDB services each own a keyspace, so — unlike the shared Postgres
+/// database — the table name needs no per-service prefix.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScyllaIdempotencyTable {
+    keyspace: String,
+    table:    String,
+}
+
+impl ScyllaIdempotencyTable {
+    /// The table in `keyspace`. The keyspace is interpolated into CQL, so it must
+    /// match `[a-z][a-z0-9_]*`.
+    pub fn new(keyspace: &str) -> Result<Self, InvalidTable> {
+        if !is_identifier(keyspace) {
+            return Err(InvalidTable(format!("keyspace '{keyspace}' must match [a-z][a-z0-9_]*")));
+        }
+        Ok(Self {
+            keyspace: keyspace.to_owned(),
+            table:    format!("{keyspace}.idempotency"),
+        })
+    }
+
+    pub fn keyspace(&self) -> &str {
+        &self.keyspace
+    }
+
+    pub fn table(&self) -> &str {
+        &self.table
+    }
+
+    /// The canonical CQL. Service migrations are verbatim renderings of it.
+    ///
+    /// `state` is `claimed` or `processed` and `result` the processed command's
+    /// encoded output; every row is written `USING TTL` (the claim TTL or the
+    /// retention window), so expiry needs no sweeper. `gc_grace_seconds` is short
+    /// because the only deletes are released claims: one lost on a replica
+    /// resurrects a claim that still expires with its TTL.
+    pub fn ddl(&self) -> String {
+        let table = &self.table;
+        format!(
+            "CREATE TABLE IF NOT EXISTS {table} (\n    \
+                 message_id uuid PRIMARY KEY,\n    \
+                 state      text,\n    \
+                 result     blob\n\
+             ) WITH gc_grace_seconds = 3600\n  \
+               AND compression = {{'sstable_compression': 'LZ4Compressor'}};\n"
+        )
+    }
+}
+
+/// `[a-z][a-z0-9_]*` — the names this crate is willing to interpolate into a
+/// statement unquoted.
+fn is_identifier(name: &str) -> bool {
+    name.chars().next().is_some_and(|c| c.is_ascii_lowercase())
+        && name
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn the_table_name_derives_from_the_prefix() {
+        let t = IdempotencyTable::new("account").unwrap();
+        assert_eq!(t.prefix(), "account");
+        assert_eq!(t.table(), "account_idempotency");
+    }
+
+    #[test]
+    fn rejects_prefixes_that_are_not_plain_identifiers() {
+        for bad in ["", "Account", "1acct", "acct; DROP TABLE x", "acct-idem"] {
+            assert!(IdempotencyTable::new(bad).is_err(), "accepted {bad:?}");
+        }
+    }
+
+    #[test]
+    fn the_scylla_table_is_qualified_by_the_keyspace() {
+        let t = ScyllaIdempotencyTable::new("social_graph").unwrap();
+        assert_eq!(t.table(), "social_graph.idempotency");
+        assert!(t.ddl().contains("CREATE TABLE IF NOT EXISTS social_graph.idempotency ("));
+        assert!(ScyllaIdempotencyTable::new("post; DROP").is_err());
+    }
+
+    #[test]
+    fn ddl_names_the_table_and_the_expiry_index() {
+        let ddl = IdempotencyTable::new("account").unwrap().ddl();
+        assert!(ddl.contains("CREATE TABLE IF NOT EXISTS account_idempotency ("));
+        assert!(ddl.contains("message_id UUID        PRIMARY KEY"));
+        assert!(ddl.contains("ON account_idempotency (expires_at)"));
+        assert!(ddl.contains("ADD COLUMN IF NOT EXISTS result BYTEA"));
+    }
+}
@@ -0,0 +1,71 @@
+//! Every service's idempotency migrations, read in order, claim to be a verbatim
+//! rendering of [`IdempotencyTable::ddl`] (the `CREATE` plus any `ALTER` tail
+//! added since) or, for the ScyllaDB services, [`ScyllaIdempotencyTable::ddl`]. This keeps that claim true: a column added to the crate without
+//! a matching migration (or a hand-edited migration) fails here rather than at
+//! the first claim in staging.
+
+use idempotency::{IdempotencyTable, ScyllaIdempotencyTable};
+
+const WORKSPACE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../../..");
+
+/// (prefix, migration paths relative to the workspace root, in apply order)
+const MIGRATIONS: &[(&str, &[&str])] = &[
+    (
+        "account",
+        &[
+            "crates/services/account/migrations/0004_idempotency.sql",
+            "crates/services/account/migrations/0005_idempotency_result.sql",
+        ],
+    ),
+    ("idempotency_it", &["crates/platform/idempotency/tests/migrations/0001_idempotency_it.sql"]),
+];
+
+/// (keyspace, migration path relative to the workspace root)
+const CQL_MIGRATIONS: &[(&str, &str)] = &[
+    ("post", "crates/services/post/migrations/0008_create_idempotency_table.cql"),
+    ("comment", "crates/services/comment/migrations/0006_create_idempotency_table.cql"),
+    ("idempotency_it", "crates/platform/idempotency/tests/cql_migrations/0002_idempotency_it.cql"),
+];
+
+/// The migration minus its leading `--` comment block.
+fn schema_of(sql: &str) -> String {
+    sql.lines()
+        .skip_while(|line| line.starts_with("--"))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[test]
+fn service_idempotency_migrations_match_the_canonical_ddl() {
+    for (prefix, paths) in MIGRATIONS {
+        let schema = paths
+            .iter()
+            .map(|path| {
+                let sql = std::fs::read_to_string(format!("{WORKSPACE}/{path}"))
+                    .unwrap_or_else(|e| panic!("read {path}: {e}"));
+                schema_of(&sql).trim_end().to_owned()
+            })
+            .collect::<Vec<_>>()
+            .join("\n\n");
+        let expected = IdempotencyTable::new(prefix).unwrap().ddl();
+        assert_eq!(
+            schema,
+            expected.trim_end(),
+            "{paths:?} drifted from IdempotencyTable::new({prefix:?}).ddl()"
+        );
+    }
+}
+
+#[test]
+fn service_cql_idempotency_migrations_match_the_canonical_ddl() {
+    for (keyspace, path) in CQL_MIGRATIONS {
+        let cql = std::fs::read_to_string(format!("{WORKSPACE}/{path}"))
+            .unwrap_or_else(|e| panic!("read {path}: {e}"));
+        let expected = ScyllaIdempotencyTable::new(keyspace).unwrap().ddl();
+        assert_eq!(
+            schema_of(&cql).trim_end(),
+            expected.trim_end(),
+            "{path} drifted from ScyllaIdempotencyTable::new({keyspace:?}).ddl()"
+        );
+    }
+}
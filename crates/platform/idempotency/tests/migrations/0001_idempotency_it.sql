@@ -0,0 +1,13 @@
+-- Fixture table for the live Postgres suite (a verbatim rendering of
+-- IdempotencyTable::new("idempotency_it").ddl()).
+CREATE TABLE IF NOT EXISTS idempotency_it_idempotency (
+    message_id UUID        PRIMARY KEY,
+    state      TEXT        NOT NULL,
+    expires_at TIMESTAMPTZ NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idempotency_it_idempotency_expiry
+    ON idempotency_it_idempotency (expires_at);
+
+ALTER TABLE idempotency_it_idempotency
+    ADD COLUMN IF NOT EXISTS result BYTEA;
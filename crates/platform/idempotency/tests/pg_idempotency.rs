@@ -0,0 +1,109 @@
+//! Live Postgres suite for [`PgIdempotencyStore`].
+//!
+//! Gated behind `integration-idempotency` so `cargo test -p idempotency` stays
+//! hermetic:
+//!
+//! ```text
+//! cargo test -p idempotency --features integration-idempotency -- --nocapture
+//! ```
+#![cfg(feature = "integration-idempotency")]
+
+use std::time::Duration;
+
+use cqrs::{Claim, IdempotencyStore};
+use idempotency::{IdempotencyTable, PgIdempotencyStore};
+use postgres_storage::TransactionManager;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+/// An encoded command output, as the layer records it.
+const OUTPUT: &[u8] = br#"{"post_id":"p1"}"#;
+
+const MIGRATIONS_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/migrations");
+
+async fn store() -> (PgPool, PgIdempotencyStore) {
+    let url = test_support::containers::postgres_ready(MIGRATIONS_DIR).await;
+    let pool = PgPool::connect(&url).await.expect("connect");
+    let table = IdempotencyTable::new("idempotency_it").unwrap();
+    let store = PgIdempotencyStore::new(table, TransactionManager::new(pool.clone()));
+    (pool, store)
+}
+
+#[tokio::test]
+async fn a_claim_blocks_others_until_completed_or_released() {
+    let (_, store) = store().await;
+    let id = Uuid::now_v7();
+
+    assert_eq!(store.claim(id).await.unwrap(), Claim::Acquired);
+    assert_eq!(store.claim(id).await.unwrap(), Claim::InFlight);
+
+    store.release(id).await.unwrap();
+    assert_eq!(store.claim(id).await.unwrap(), Claim::Acquired, "a released id runs again");
+
+    store.complete(id, OUTPUT.to_vec()).await.unwrap();
+    assert_eq!(store.claim(id).await.unwrap(), Claim::Processed(Some(OUTPUT.to_vec())));
+    store.release(id).await.unwrap();
+    assert_eq!(store.claim(id).await.unwrap(), Claim::Processed(Some(OUTPUT.to_vec())), "release never drops a processed mark");
+}
+
+#[tokio::test]
+async fn an_expired_claim_is_taken_over() {
+    let (_, store) = store().await;
+    let store = store.with_claim_ttl(Duration::from_millis(200));
+    let id = Uuid::now_v7();
+
+    assert_eq!(store.claim(id).await.unwrap(), Claim::Acquired);
+    tokio::time::sleep(Duration::from_millis(400)).await;
+    assert_eq!(store.claim(id).await.unwrap(), Claim::Acquired);
+}
+
+#[tokio::test]
+async fn claim_in_commits_and_rolls_back_with_the_callers_transaction() {
+    let (pool, store) = store().await;
+    let id = Uuid::now_v7();
+
+    let mut tx = pool.begin().await.unwrap();
+    assert!(store.claim_in(&mut tx, id).await.unwrap());
+    tx.rollback().await.unwrap();
+
+    let mut tx = pool.begin().await.unwrap();
+    assert!(store.claim_in(&mut tx, id).await.unwrap(), "a rolled-back claim leaves no mark");
+    tx.commit().await.unwrap();
+
+    let mut tx = pool.begin().await.unwrap();
+    assert!(!store.claim_in(&mut tx, id).await.unwrap(), "a committed claim is a duplicate");
+    tx.rollback().await.unwrap();
+    assert_eq!(store.claim(id).await.unwrap(), Claim::Processed(None), "claim_in marks record no output");
+}
+
+#[tokio::test]
+async fn claim_in_settles_the_layers_claim_even_if_complete_never_runs() {
+    let (pool, store) = store().await;
+    let id = Uuid::now_v7();
+    assert_eq!(store.claim(id).await.unwrap(), Claim::Acquired);
+
+    let mut tx = pool.begin().await.unwrap();
+    assert!(store.claim_in(&mut tx, id).await.unwrap(), "the layer's own claim is taken over");
+    tx.commit().await.unwrap();
+
+    // The process dies here, before the layer's `complete`.
+    assert_eq!(store.claim(id).await.unwrap(), Claim::Processed(None));
+    let mut tx = pool.begin().await.unwrap();
+    assert!(!store.claim_in(&mut tx, id).await.unwrap(), "a settled claim is a duplicate");
+    tx.rollback().await.unwrap();
+}
+
+#[tokio::test]
+async fn purge_drops_only_expired_rows() {
+    let (pool, store) = store().await;
+    let store = store.with_claim_ttl(Duration::from_millis(100));
+    let (stale, live) = (Uuid::now_v7(), Uuid::now_v7());
+
+    store.claim(stale).await.unwrap();
+    tokio::time::sleep(Duration::from_millis(300)).await;
+    store.claim(live).await.unwrap();
+    store.complete(live, OUTPUT.to_vec()).await.unwrap();
+
+    assert!(store.purge_expired(&pool).await.unwrap() >= 1);
+    assert_eq!(store.claim(live).await.unwrap(), Claim::Processed(Some(OUTPUT.to_vec())));
+}
@@ -0,0 +1,80 @@
+//! Live-Redis suite for [`RedisIdempotencyStore`]: the Lua claim and release
+//! against a real server.
+//!
+//! Gated behind `integration-idempotency` (needs a Docker daemon).
+//! Run with: `cargo test -p idempotency --features integration-idempotency`.
+#![cfg(feature = "integration-idempotency")]
+
+use std::time::Duration;
+
+use cqrs::{Claim, IdempotencyStore};
+use idempotency::RedisIdempotencyStore;
+use redis_storage::{RedisClientBuilder, RedisConfig};
+use uuid::Uuid;
+
+/// An encoded command output, as the layer records it.
+const OUTPUT: &[u8] = br#"{"post_id":"p1"}"#;
+
+async fn store() -> RedisIdempotencyStore {
+    let endpoint = test_support::containers::redis_endpoint().await;
+    let client = RedisClientBuilder::new(RedisConfig {
+        hosts: vec![endpoint],
+        ..RedisConfig::default()
+    })
+    .build()
+    .await
+    .expect("connect redis");
+    RedisIdempotencyStore::new(client, "it")
+}
+
+#[tokio::test]
+async fn a_claim_blocks_others_until_completed_or_released() {
+    let store = store().await;
+    let id = Uuid::now_v7();
+
+    assert_eq!(store.claim(id).await.unwrap(), Claim::Acquired);
+    assert_eq!(store.claim(id).await.unwrap(), Claim::InFlight);
+
+    store.release(id).await.unwrap();
+    assert_eq!(store.claim(id).await.unwrap(), Claim::Acquired, "a released id runs again");
+
+    store.complete(id, OUTPUT.to_vec()).await.unwrap();
+    assert_eq!(store.claim(id).await.unwrap(), Claim::Processed(Some(OUTPUT.to_vec())));
+    store.release(id).await.unwrap();
+    assert_eq!(store.claim(id).await.unwrap(), Claim::Processed(Some(OUTPUT.to_vec())), "release never drops a processed mark");
+}
+
+#[tokio::test]
+async fn claims_and_processed_marks_expire() {
+    let store = store()
+        .await
+        .with_claim_ttl(Duration::from_millis(200))
+        .with_retention(Duration::from_millis(200));
+    let (claimed, processed) = (Uuid::now_v7(), Uuid::now_v7());
+
+    store.claim(claimed).await.unwrap();
+    store.claim(processed).await.unwrap();
+    store.complete(processed, OUTPUT.to_vec()).await.unwrap();
+    tokio::time::sleep(Duration::from_millis(400)).await;
+
+    assert_eq!(store.claim(claimed).await.unwrap(), Claim::Acquired);
+    assert_eq!(store.claim(processed).await.unwrap(), Claim::Acquired);
+}
+
+#[tokio::test]
+async fn exactly_one_of_many_concurrent_claims_wins() {
+    let store = std::sync::Arc::new(store().await);
+    let id = Uuid::now_v7();
+
+    let claims = (0..16).map(|_| {
+        let store = std::sync::Arc::clone(&store);
+        tokio::spawn(async move { store.claim(id).await.unwrap() })
+    });
+    let mut acquired = 0;
+    for claim in claims {
+        if claim.await.unwrap() == Claim::Acquired {
+            acquired += 1;
+        }
+    }
+    assert_eq!(acquired, 1);
+}
@@ -0,0 +1,95 @@
+//! Live-ScyllaDB suite for [`ScyllaIdempotencyStore`]: the LWT claim, complete
+//! and release against a real node.
+//!
+//! Gated behind `integration-idempotency` (needs a Docker daemon).
+//! Run with: `cargo test -p idempotency --features integration-idempotency`.
+#![cfg(feature = "integration-idempotency")]
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use cqrs::{Claim, IdempotencyStore};
+use idempotency::{ScyllaIdempotencyStore, ScyllaIdempotencyTable};
+use scylla_storage::{ScyllaConfig, ScyllaSessionBuilder};
+use uuid::Uuid;
+
+const KEYSPACE: &str = "idempotency_it";
+const MIGRATIONS_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/cql_migrations");
+
+/// An encoded command output, as the layer records it.
+const OUTPUT: &[u8] = br#"{"output":{"post_id":"p1"}}"#;
+
+async fn store() -> ScyllaIdempotencyStore {
+    let contact_point = test_support::containers::scylla_ready(KEYSPACE, MIGRATIONS_DIR).await;
+    let config = ScyllaConfig {
+        contact_points: vec![contact_point],
+        keyspace: None,
+        ..ScyllaConfig::default()
+    };
+    let client = Arc::new(ScyllaSessionBuilder::new(config).build().await.expect("connect"));
+    ScyllaIdempotencyStore::new(client, ScyllaIdempotencyTable::new(KEYSPACE).unwrap())
+}
+
+#[tokio::test]
+async fn a_claim_blocks_others_until_completed_or_released() {
+    let store = store().await;
+    let id = Uuid::now_v7();
+
+    assert_eq!(store.claim(id).await.unwrap(), Claim::Acquired);
+    assert_eq!(store.claim(id).await.unwrap(), Claim::InFlight);
+
+    store.release(id).await.unwrap();
+    assert_eq!(store.claim(id).await.unwrap(), Claim::Acquired, "a released id runs again");
+
+    store.complete(id, OUTPUT.to_vec()).await.unwrap();
+    assert_eq!(store.claim(id).await.unwrap(), Claim::Processed(Some(OUTPUT.to_vec())));
+    store.release(id).await.unwrap();
+    assert_eq!(store.claim(id).await.unwrap(), Claim::Processed(Some(OUTPUT.to_vec())), "release never drops a processed mark");
+}
+
+#[tokio::test]
+async fn claims_and_processed_marks_expire() {
+    let store = store()
+        .await
+        .with_claim_ttl(Duration::from_secs(1))
+        .with_retention(Duration::from_secs(1));
+    let (claimed, processed) = (Uuid::now_v7(), Uuid::now_v7());
+
+    store.claim(claimed).await.unwrap();
+    store.claim(processed).await.unwrap();
+    store.complete(processed, OUTPUT.to_vec()).await.unwrap();
+    tokio::time::sleep(Duration::from_millis(2500)).await;
+
+    assert_eq!(store.claim(claimed).await.unwrap(), Claim::Acquired);
+    assert_eq!(store.claim(processed).await.unwrap(), Claim::Acquired);
+}
+
+#[tokio::test]
+async fn completing_an_expired_claim_still_records_the_output() {
+    let store = store().await.with_claim_ttl(Duration::from_secs(1));
+    let id = Uuid::now_v7();
+
+    store.claim(id).await.unwrap();
+    tokio::time::sleep(Duration::from_millis(2500)).await;
+    store.complete(id, OUTPUT.to_vec()).await.unwrap();
+
+    assert_eq!(store.claim(id).await.unwrap(), Claim::Processed(Some(OUTPUT.to_vec())));
+}
+
+#[tokio::test]
+async fn exactly_one_of_many_concurrent_claims_wins() {
+    let store = Arc::new(store().await);
+    let id = Uuid::now_v7();
+
+    let claims = (0..16).map(|_| {
+        let store = Arc::clone(&store);
+        tokio::spawn(async move { store.claim(id).await.unwrap() })
+    });
+    let mut acquired = 0;
+    for claim in claims {
+        if claim.await.unwrap() == Claim::Acquired {
+            acquired += 1;
+        }
+    }
+    assert_eq!(acquired, 1);
+}
@@ -0,0 +1,45 @@
+use postgres_storage::StorageError;
+use scylla_storage::ScyllaStorageError;
+use thiserror::Error;
+
+/// Every failure the outbox surfaces. Services flatten it into their own
+/// `EventPublishFailed`/`Storage` variants at the adapter boundary.
+#[derive(Debug, Error)]
+pub enum OutboxError {
+    /// The enqueue, lease, drain or delete statement failed.
+    #[error("outbox storage error: {0}")]
+    Storage(#[from] StorageError),
+
+    /// The ScyllaDB-backed equivalent of [`OutboxError::Storage`].
+    #[error("outbox storage error: {0}")]
+    ScyllaStorage(#[from] ScyllaStorageError),
+
+    /// A ScyllaDB result set did not have the shape the relay reads.
+    #[error("outbox row decode error: {0}")]
+    Decode(String),
+
+    /// The payload could not be serialized to (or a stored row decoded from) JSON.
+    #[error("outbox payload serialization error: {0}")]
+    Serialize(#[from] serde_json::Error),
+
+    /// The sink rejected the record (broker down, topic missing, …). Retried on the
+    /// next tick; the row stays in place.
+    #[error("outbox publish failed: {0}")]
+    Publish(String),
+
+    /// A table prefix or keyspace that is not a safe identifier, or a zero slot count.
+    #[error("invalid outbox table definition: {0}")]
+    InvalidTable(String),
+}
+
+impl From<sqlx::Error> for OutboxError {
+    fn from(e: sqlx::Error) -> Self {
+        Self::Storage(StorageError::from(e))
+    }
+}
+
+impl From<scylla::errors::ExecutionError> for OutboxError {
+    fn from(e: scylla::errors::ExecutionError) -> Self {
+        Self::ScyllaStorage(ScyllaStorageError::from(e))
+    }
+}
@@ -0,0 +1,64 @@
+//! Shared transactional outbox for the fleet's event producers — PostgreSQL for the
+//! relational services, ScyllaDB ([`scylla`]) for the keyspace-per-service ones.
+//!
+//! Extracted from `auth`'s hand-rolled `auth_outbox` once `account`, `media`,
+//! `moderation` and `counter` all needed the same guarantee: a domain event is
+//! published **if and only if** the write that produced it committed. Publishing to
+//! Kafka after the commit (the old `KafkaEventPublisher` shape) loses the event on a
+//! crash between the two; publishing before it announces writes that never happened.
+//!
+//! # Layering
+//!
+//! ```text
+//! writer ──► PgOutbox::enqueue(tx, msg)   — INSERT in the caller's transaction
+//!                  │                          (or enqueue_detached: own statement)
+//!                  ▼
+//!            <prefix>_outbox  (slot = hash(aggregate_key) % slots)
+//!                  │
+//! OutboxRelay::tick ── heartbeat in <prefix>_outbox_member
+//!                  ├─ lease a fair share of slots in <prefix>_outbox_lease
+//!                  ├─ drain leased slots in seq order
+//!                  ├─ OutboxSink::publish (Kafka in prod)
+//!                  └─ DELETE published rows
+//! ```
+//!
+//! # Ordering and replicas
+//!
+//! Every row carries a **slot** derived from its aggregate key. A relay replica only
+//! drains slots it holds a time-bounded lease on, so exactly one replica publishes a
+//! given key at a time and per-key order is preserved end to end (the Kafka key is the
+//! aggregate key, so it is preserved on the partition too). Leases are spread evenly
+//! across live replicas (each heartbeats a membership row) and expire if a replica
+//! dies, so a crashed pod's slots are picked up after one lease TTL. Within a tick a
+//! publish failure *blocks that key only*: later rows of the same key wait for the
+//! next tick, other keys keep flowing.
+//!
+//! Delivery is **at-least-once** — a relay that publishes and then loses its
+//! connection before the `DELETE` republishes on the next tick. Every record carries
+//! an `event_id` header (the outbox row id) so consumers can deduplicate.
+//!
+//! # Schema
+//!
+//! Each service owns its three tables, named from a prefix (`account` →
+//! `account_outbox` / `account_outbox_lease` / `account_outbox_member`) because the
+//! fleet's Postgres services share one database. [`OutboxTable::ddl`] renders the canonical schema; every
+//! service's migration is a verbatim copy of it. The ScyllaDB services keep theirs in
+//! their own keyspace ([`ScyllaOutboxTable::ddl`]).
+
+mod drain;
+pub mod error;
+pub mod message;
+pub mod metrics;
+pub mod relay;
+pub mod scylla;
+pub mod sink;
+pub mod store;
+pub mod table;
+
+pub use error::OutboxError;
+pub use message::{OutboxMessage, OutboxRecord, EVENT_ID_HEADER, EVENT_TYPE_HEADER};
+pub use relay::{OutboxRelay, RelayConfig};
+pub use self::scylla::{ScyllaOutbox, ScyllaOutboxBatch, ScyllaOutboxRelay, ScyllaOutboxTable};
+pub use sink::{KafkaOutboxSink, LogOutboxSink, OutboxSink};
+pub use store::PgOutbox;
+pub use table::OutboxTable;
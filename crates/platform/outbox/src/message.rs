@@ -0,0 +1,150 @@
+//! What goes into the outbox and what the relay hands the sink.
+
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::error::OutboxError;
+
+/// Header carrying the dotted routing key — the same header every
+/// `KafkaEventPublisher` in the fleet already sets.
+pub const EVENT_TYPE_HEADER: &str = "event_type";
+
+/// Header carrying the outbox row id: stable across relay re-deliveries, so it is
+/// the deduplication key for idempotent consumers.
+pub const EVENT_ID_HEADER: &str = "event_id";
+
+/// A domain event ready to be enqueued: the Kafka coordinates (topic + key), the
+/// routing type, the JSON payload, and any extra headers.
+///
+/// The payload is serialized at construction, so a type that cannot serialize
+/// fails the *write* (inside the caller's transaction) rather than poisoning the
+/// relay later.
+#[derive(Debug, Clone)]
+pub struct OutboxMessage {
+    pub(crate) id: Uuid,
+    pub(crate) topic: String,
+    pub(crate) key: String,
+    pub(crate) event_type: String,
+    pub(crate) payload: serde_json::Value,
+    pub(crate) headers: BTreeMap<String, String>,
+}
+
+impl OutboxMessage {
+    /// Serializes `payload` and stamps a fresh UUIDv7 id, the row's identity and
+    /// the `event_id` header consumers deduplicate on.
+    pub fn new<T: Serialize>(
+        topic: impl Into<String>,
+        key: impl Into<String>,
+        event_type: impl Into<String>,
+        payload: &T,
+    ) -> Result<Self, OutboxError> {
+        Ok(Self {
+            id: Uuid::now_v7(),
+            topic: topic.into(),
+            key: key.into(),
+            event_type: event_type.into(),
+            payload: serde_json::to_value(payload)?,
+            headers: BTreeMap::new(),
+        })
+    }
+
+    /// Adds a record header (e.g. `account_id`). `event_type` and `event_id` are
+    /// set by the sink from the row itself and need not be repeated here.
+    pub fn with_header(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.insert(key.into(), value.into());
+        self
+    }
+
+    pub fn id(&self) -> Uuid {
+        self.id
+    }
+
+    pub fn topic(&self) -> &str {
+        &self.topic
+    }
+
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// The enqueue time encoded in the UUIDv7 id. Deriving it (rather than reading
+    /// the clock at write time) makes a retried enqueue of the same message write
+    /// the same row, which the ScyllaDB outbox relies on for idempotent retries.
+    pub(crate) fn created_at(&self) -> DateTime<Utc> {
+        self.id
+            .get_timestamp()
+            .and_then(|ts| {
+                let (secs, nanos) = ts.to_unix();
+                DateTime::from_timestamp(secs as i64, nanos)
+            })
+            .unwrap_or_else(Utc::now)
+    }
+}
+
+/// A drained outbox row, as handed to the [`crate::OutboxSink`].
+#[derive(Debug, Clone)]
+pub struct OutboxRecord {
+    pub id: Uuid,
+    pub topic: String,
+    pub key: String,
+    pub event_type: String,
+    pub payload: serde_json::Value,
+    pub headers: BTreeMap<String, String>,
+    pub attempts: i32,
+    pub created_at: DateTime<Utc>,
+}
+
+impl OutboxRecord {
+    /// The full header set to publish: the stored headers plus `event_type` and
+    /// `event_id`, which always reflect the row (a stored header cannot override them).
+    pub fn wire_headers(&self) -> std::collections::HashMap<String, String> {
+        let mut headers: std::collections::HashMap<String, String> =
+            self.headers.iter().map(|(k, v)| (k.clone(), v.clone())).collect();
+        headers.insert(EVENT_TYPE_HEADER.to_owned(), self.event_type.clone());
+        headers.insert(EVENT_ID_HEADER.to_owned(), self.id.to_string());
+        headers
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Serialize)]
+    struct Ev {
+        account_id: &'static str,
+    }
+
+    #[test]
+    fn wire_headers_always_carry_the_row_identity() {
+        let msg = OutboxMessage::new("account.v1.events", "a-1", "account.created", &Ev { account_id: "a-1" })
+            .unwrap()
+            .with_header("account_id", "a-1")
+            .with_header(EVENT_TYPE_HEADER, "spoofed");
+        let record = OutboxRecord {
+            id: msg.id,
+            topic: msg.topic,
+            key: msg.key,
+            event_type: msg.event_type,
+            payload: msg.payload,
+            headers: msg.headers,
+            attempts: 0,
+            created_at: Utc::now(),
+        };
+
+        let headers = record.wire_headers();
+        assert_eq!(headers["event_type"], "account.created");
+        assert_eq!(headers["event_id"], record.id.to_string());
+        assert_eq!(headers["account_id"], "a-1");
+    }
+
+    #[test]
+    fn ids_are_time_ordered() {
+        let a = OutboxMessage::new("t", "k", "e", &1).unwrap();
+        let b = OutboxMessage::new("t", "k", "e", &2).unwrap();
+        assert!(a.id() < b.id());
+    }
+}
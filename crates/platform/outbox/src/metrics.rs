@@ -0,0 +1,83 @@
+//! Relay instruments, bound to the global meter `telemetry::init` installs (a no-op
+//! meter before that, and in tests). Every series is labelled by `table` (the
+//! outbox prefix), so one dashboard serves every producer.
+//!
+//! | Instrument (Prometheus name) | Kind | Meaning |
+//! |---|---|---|
+//! | `outbox_enqueued_total` | counter | rows written by `PgOutbox` |
+//! | `outbox_published_total` | counter | rows the sink acknowledged (+ `topic`) |
+//! | `outbox_publish_failures_total` | counter | sink rejections (+ `topic`) |
+//! | `outbox_relay_lag_seconds` | histogram | enqueue → publish latency |
+//! | `outbox_pending` | gauge | rows waiting in this replica's leased slots |
+//! | `outbox_leased_slots` | gauge | slots this replica currently drains |
+
+use opentelemetry::metrics::{Counter, Gauge, Histogram};
+use opentelemetry::{global, KeyValue};
+
+#[derive(Clone)]
+pub(crate) struct OutboxMetrics {
+    table: KeyValue,
+    enqueued: Counter<u64>,
+    published: Counter<u64>,
+    failures: Counter<u64>,
+    lag: Histogram<f64>,
+    pending: Gauge<u64>,
+    leased: Gauge<u64>,
+}
+
+impl OutboxMetrics {
+    pub(crate) fn new(table: &str) -> Self {
+        let meter = global::meter("outbox");
+        Self {
+            table: KeyValue::new("table", table.to_owned()),
+            enqueued: meter
+                .u64_counter("outbox_enqueued")
+                .with_description("Events written to the transactional outbox.")
+                .build(),
+            published: meter
+                .u64_counter("outbox_published")
+                .with_description("Outbox events acknowledged by the sink, by topic.")
+                .build(),
+            failures: meter
+                .u64_counter("outbox_publish_failures")
+                .with_description("Outbox publish attempts the sink rejected, by topic.")
+                .build(),
+            lag: meter
+                .f64_histogram("outbox_relay_lag")
+                .with_unit("s")
+                .with_description("Time between enqueue and successful publish.")
+                .build(),
+            pending: meter
+                .u64_gauge("outbox_pending")
+                .with_description("Outbox rows waiting in this replica's leased slots.")
+                .build(),
+            leased: meter
+                .u64_gauge("outbox_leased_slots")
+                .with_description("Outbox lease slots this relay replica currently holds.")
+                .build(),
+        }
+    }
+
+    pub(crate) fn enqueued(&self, n: u64) {
+        self.enqueued.add(n, std::slice::from_ref(&self.table));
+    }
+
+    pub(crate) fn published(&self, topic: &str, lag_secs: f64) {
+        let attrs = [self.table.clone(), KeyValue::new("topic", topic.to_owned())];
+        self.published.add(1, &attrs);
+        self.lag.record(lag_secs.max(0.0), std::slice::from_ref(&self.table));
+    }
+
+    pub(crate) fn failed(&self, topic: &str) {
+        let attrs = [self.table.clone(), KeyValue::new("topic", topic.to_owned())];
+        self.failures.add(1, &attrs);
+    }
+
+    pub(crate) fn pending(&self, rows: u64) {
+        self.pending.record(rows, std::slice::from_ref(&self.table));
+    }
+
+    pub(crate) fn leased(&self, slots: u64) {
+        self.leased.record(slots, std::slice::from_ref(&self.table));
+    }
+}
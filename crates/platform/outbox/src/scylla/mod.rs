@@ -0,0 +1,58 @@
+//! ScyllaDB backend, for the keyspace-per-service producers (`post`, `comment`,
+//! `chat`, `social-graph`, `profile`).
+//!
+//! Same contract as the Postgres side — enqueue next to the write, a leased relay
+//! drains per key in order, the sink is Kafka — with the storage primitives
+//! ScyllaDB offers instead of transactions and row locks:
+//!
+//! ```text
+//! writer ──► ScyllaOutboxBatch   LOGGED batch: the domain write(s) + one row per
+//!                 │                 event in <ks>.outbox, partition = slot
+//!                 ▼
+//!   ScyllaOutboxRelay::tick ── heartbeat <ks>.outbox_member (USING TTL)
+//!                 ├─ claim/renew slots in <ks>.outbox_lease (LWT, USING TTL)
+//!                 ├─ read each leased partition in (created_at, event_id) order
+//!                 ├─ OutboxSink::publish
+//!                 └─ DELETE published rows
+//! ```
+//!
+//! A logged batch is ScyllaDB's all-or-nothing multi-partition write: once it is
+//! acknowledged, every statement in it eventually applies, even if the
+//! coordinator dies mid-way. That is the property the outbox needs from the
+//! write path: a repository folds its events into the batch that carries its
+//! own rows with [`ScyllaOutbox::batch`], so the events exist exactly when the
+//! write does. [`ScyllaOutbox::enqueue`] (the event rows alone, in one batch) is
+//! only for an event derived after the write, from state the batch cannot see.
+//!
+//! Enqueue is idempotent: a message's partition, clustering key and `event_id`
+//! all derive from its UUIDv7 id, so a retried batch overwrites the rows it
+//! already wrote instead of duplicating them.
+
+mod relay;
+mod store;
+mod table;
+
+use std::sync::Arc;
+
+use scylla::observability::history::HistoryListener;
+use scylla::statement::unprepared::Statement;
+use scylla_storage::{ProfileKind, ScyllaClient};
+
+pub use relay::ScyllaOutboxRelay;
+pub use store::{ScyllaOutbox, ScyllaOutboxBatch};
+pub use table::ScyllaOutboxTable;
+
+/// A statement on the `Strict` (LocalQuorum, LocalSerial for LWT) profile with the
+/// client's tracing listener attached — the profile every outbox statement uses.
+fn strict_stmt(client: &ScyllaClient, cql: &str) -> Statement {
+    let mut stmt = Statement::new(cql);
+    stmt.set_execution_profile_handle(Some(
+        client
+            .profiles
+            .get(ProfileKind::Strict)
+            .clone()
+            .into_handle_with_label("strict".to_owned()),
+    ));
+    stmt.set_history_listener(Arc::clone(&client.history_listener) as Arc<dyn HistoryListener>);
+    stmt
+}
@@ -0,0 +1,340 @@
+//! Drains `<ks>.outbox` to an [`OutboxSink`] from any number of replicas.
+//!
+//! The tick has the Postgres relay's shape — lease, drain in key order, delete —
+//! with the lease bookkeeping rebuilt on ScyllaDB primitives:
+//!
+//! - **Membership** is a row per replica written `USING TTL`; a replica that stops
+//!   heartbeating simply disappears from `<ks>.outbox_member`.
+//! - **Leases** are lightweight transactions on `<ks>.outbox_lease`, also written
+//!   `USING TTL`: a free slot is one with no row, claimed with `IF NOT EXISTS`,
+//!   renewed and released with `IF owner = ?`. Paxos makes each claim exclusive,
+//!   and the TTL expires a dead replica's slots without anyone cleaning up.
+//! - **Drain** reads each leased slot's partition, which is clustered in
+//!   `(created_at, event_id)` order, so per-key order needs no sort.
+//!
+//! Deletes are per row (`slot`, `created_at`, `event_id`). A delete that is lost
+//! re-delivers the row under the same `event_id` header on the next tick. The
+//! failure bookkeeping is `IF EXISTS`: a CQL `UPDATE` is an upsert, and after a
+//! lease overlap the row may already be published and deleted — a plain update
+//! would recreate it without `topic` or `aggregate_key`, and the slot would stop
+//! decoding.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::sync::Arc;
+
+use chrono::{TimeZone, Utc};
+use scylla::response::query_result::QueryRowsResult;
+use scylla::value::{CqlTimestamp, CqlValue, Row};
+use scylla::DeserializeRow;
+use scylla_storage::ScyllaClient;
+use uuid::Uuid;
+
+use super::strict_stmt;
+use super::table::ScyllaOutboxTable;
+use crate::drain::{fair_share, publish_in_key_order, run_ticks};
+use crate::error::OutboxError;
+use crate::message::OutboxRecord;
+use crate::metrics::OutboxMetrics;
+use crate::relay::RelayConfig;
+use crate::sink::OutboxSink;
+use crate::table::hash_slot;
+
+/// CQL rendered once per keyspace.
+struct Statements {
+    heartbeat: String,
+    leave: String,
+    members: String,
+    leases: String,
+    claim: String,
+    renew: String,
+    release: String,
+    fetch: String,
+    delete: String,
+    record_failure: String,
+    pending: String,
+}
+
+impl Statements {
+    fn new(table: &ScyllaOutboxTable) -> Self {
+        let outbox = table.outbox();
+        let lease = table.lease();
+        let member = table.member();
+        Self {
+            heartbeat: format!("INSERT INTO {member} (owner, seen_at) VALUES (?, ?) USING TTL ?"),
+            leave: format!("DELETE FROM {member} WHERE owner = ?"),
+            members: format!("SELECT owner FROM {member}"),
+            leases: format!("SELECT slot, owner FROM {lease}"),
+            claim: format!("INSERT INTO {lease} (slot, owner) VALUES (?, ?) IF NOT EXISTS USING TTL ?"),
+            renew: format!("UPDATE {lease} USING TTL ? SET owner = ? WHERE slot = ? IF owner = ?"),
+            release: format!("DELETE FROM {lease} WHERE slot = ? IF owner = ?"),
+            fetch: format!(
+                "SELECT event_id, created_at, topic, aggregate_key, event_type, payload, headers, attempts \
+                 FROM {outbox} WHERE slot = ? LIMIT ?"
+            ),
+            delete: format!("DELETE FROM {outbox} WHERE slot = ? AND created_at = ? AND event_id = ?"),
+            record_failure: format!(
+                "UPDATE {outbox} SET attempts = ?, last_error = ? \
+                 WHERE slot = ? AND created_at = ? AND event_id = ? IF EXISTS"
+            ),
+            pending: format!("SELECT count(*) FROM {outbox} WHERE slot = ?"),
+        }
+    }
+}
+
+#[derive(DeserializeRow)]
+struct OutboxRow {
+    event_id: Uuid,
+    created_at: CqlTimestamp,
+    topic: String,
+    aggregate_key: String,
+    event_type: String,
+    payload: String,
+    headers: Option<BTreeMap<String, String>>,
+    attempts: Option<i32>,
+}
+
+impl TryFrom<OutboxRow> for OutboxRecord {
+    type Error = OutboxError;
+
+    fn try_from(row: OutboxRow) -> Result<Self, OutboxError> {
+        let created_at = Utc
+            .timestamp_millis_opt(row.created_at.0)
+            .single()
+            .ok_or_else(|| OutboxError::Decode(format!("created_at out of range: {}", row.created_at.0)))?;
+        Ok(Self {
+            id: row.event_id,
+            topic: row.topic,
+            key: row.aggregate_key,
+            event_type: row.event_type,
+            payload: serde_json::from_str(&row.payload)
+                .map_err(|e| OutboxError::Decode(format!("payload of {}: {e}", row.event_id)))?,
+            headers: row.headers.unwrap_or_default(),
+            attempts: row.attempts.unwrap_or(0),
+            created_at,
+        })
+    }
+}
+
+/// Where a fetched row lives, for the delete / failure write that follows.
+struct RowKey {
+    slot: i32,
+    created_at: CqlTimestamp,
+    attempts: i32,
+}
+
+/// The leased, key-ordered relay over one keyspace. Cheap to clone; spawn
+/// [`ScyllaOutboxRelay::run`] next to the service's server.
+#[derive(Clone)]
+pub struct ScyllaOutboxRelay {
+    client: Arc<ScyllaClient>,
+    table: ScyllaOutboxTable,
+    sink: Arc<dyn OutboxSink>,
+    config: RelayConfig,
+    cql: Arc<Statements>,
+    metrics: OutboxMetrics,
+}
+
+impl ScyllaOutboxRelay {
+    pub fn new(
+        client: Arc<ScyllaClient>,
+        table: ScyllaOutboxTable,
+        sink: Arc<dyn OutboxSink>,
+        config: RelayConfig,
+    ) -> Self {
+        let cql = Arc::new(Statements::new(&table));
+        let metrics = OutboxMetrics::new(table.keyspace());
+        Self { client, table, sink, config, cql, metrics }
+    }
+
+    pub fn config(&self) -> &RelayConfig {
+        &self.config
+    }
+
+    /// One lease → drain → delete cycle. Returns how many events were published.
+    ///
+    /// Same error contract as [`crate::OutboxRelay::tick`]: `Err` only when the
+    /// cycle made no progress.
+    pub async fn tick(&self) -> Result<usize, OutboxError> {
+        let slots = self.acquire_leases().await?;
+        self.metrics.leased(slots.len() as u64);
+        if slots.is_empty() {
+            return Ok(0);
+        }
+
+        // Spread the batch over the held partitions; each is already in drain
+        // order, and a key never spans two partitions.
+        let batch = self.config.batch_size.max(1) as u64;
+        let per_slot = i32::try_from(batch.div_ceil(slots.len() as u64)).unwrap_or(i32::MAX);
+        let mut records = Vec::new();
+        let mut keys: HashMap<Uuid, RowKey> = HashMap::new();
+        for &slot in &slots {
+            let rows = self.query(&self.cql.fetch, (slot, per_slot)).await?;
+            for row in rows.rows::<OutboxRow>().map_err(decode_err)? {
+                let row = row.map_err(decode_err)?;
+                keys.insert(
+                    row.event_id,
+                    RowKey { slot, created_at: row.created_at, attempts: row.attempts.unwrap_or(0) },
+                );
+                records.push(OutboxRecord::try_from(row)?);
+            }
+        }
+
+        let outcome = publish_in_key_order(self.sink.as_ref(), &records, &self.metrics).await;
+
+        for id in &outcome.published {
+            let key = &keys[id];
+            self.execute(&self.cql.delete, (key.slot, key.created_at, *id)).await?;
+        }
+        for (id, error) in &outcome.failed {
+            let key = &keys[id];
+            self.execute(
+                &self.cql.record_failure,
+                (key.attempts + 1, error.as_str(), key.slot, key.created_at, *id),
+            )
+            .await?;
+        }
+
+        let mut pending = 0u64;
+        for &slot in &slots {
+            let rows = self.query(&self.cql.pending, (slot,)).await?;
+            let (count,) = rows.single_row::<(i64,)>().map_err(decode_err)?;
+            pending += count.max(0) as u64;
+        }
+        self.metrics.pending(pending);
+
+        match outcome.failed.first() {
+            Some((id, error)) if outcome.published.is_empty() => Err(OutboxError::Publish(
+                format!("{} stalled at row {id}: {error}", self.table.outbox()),
+            )),
+            _ => Ok(outcome.published.len()),
+        }
+    }
+
+    /// Leaves the relay group and hands this replica's slots back immediately
+    /// (graceful shutdown), instead of making peers wait out the lease TTL.
+    pub async fn release_leases(&self) -> Result<(), OutboxError> {
+        let owner = &self.config.owner;
+        self.execute(&self.cql.leave, (owner,)).await?;
+        for (slot, holder) in self.lease_snapshot().await? {
+            if holder == *owner {
+                self.execute(&self.cql.release, (slot, owner)).await?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Runs forever; spawned by the service adapter next to the gRPC server.
+    pub async fn run(self) {
+        run_ticks(self.table.outbox(), &self.config, || self.tick()).await
+    }
+
+    /// Heartbeats, renews, tops up to the fair share from free slots, and sheds
+    /// excess. Returns the slots this replica drains this tick.
+    async fn acquire_leases(&self) -> Result<Vec<i32>, OutboxError> {
+        let owner = &self.config.owner;
+        let ttl = self.ttl_secs();
+
+        self.execute(&self.cql.heartbeat, (owner, CqlTimestamp(Utc::now().timestamp_millis()), ttl))
+            .await?;
+
+        let snapshot = self.lease_snapshot().await?;
+        let mut held = Vec::new();
+        for (slot, _) in snapshot.iter().filter(|(_, holder)| holder == owner) {
+            let rows = self.query(&self.cql.renew, (ttl, owner, *slot, owner)).await?;
+            if lwt_applied(rows)? {
+                held.push(*slot);
+            }
+        }
+
+        let members = self.query(&self.cql.members, ()).await?;
+        let mut peers = 0i64;
+        for row in members.rows::<(String,)>().map_err(decode_err)? {
+            if row.map_err(decode_err)?.0 != *owner {
+                peers += 1;
+            }
+        }
+        let share = fair_share(self.table.slots(), peers);
+
+        if held.len() < share {
+            // Start the scan at an owner-specific offset so replicas starting
+            // together do not all race for slot 0.
+            let taken: HashSet<i32> = snapshot.iter().map(|(slot, _)| *slot).collect();
+            let slots = i32::from(self.table.slots());
+            let start = hash_slot(owner, self.table.slots());
+            for slot in (0..slots).map(|i| (start + i) % slots) {
+                if held.len() >= share {
+                    break;
+                }
+                if taken.contains(&slot) {
+                    continue;
+                }
+                let rows = self.query(&self.cql.claim, (slot, owner, ttl)).await?;
+                if lwt_applied(rows)? {
+                    held.push(slot);
+                }
+            }
+        } else if held.len() > share {
+            held.sort_unstable();
+            for slot in held.split_off(share) {
+                self.execute(&self.cql.release, (slot, owner)).await?;
+            }
+        }
+
+        held.sort_unstable();
+        Ok(held)
+    }
+
+    /// Every live lease as `(slot, owner)`; expired leases have no row.
+    async fn lease_snapshot(&self) -> Result<Vec<(i32, String)>, OutboxError> {
+        let rows = self.query(&self.cql.leases, ()).await?;
+        rows.rows::<(i32, String)>()
+            .map_err(decode_err)?
+            .map(|row| row.map_err(decode_err))
+            .collect()
+    }
+
+    /// The lease TTL in whole seconds, rounded up, at least one.
+    fn ttl_secs(&self) -> i32 {
+        let ttl = self.config.lease_ttl;
+        let secs = ttl.as_secs() + u64::from(ttl.subsec_nanos() > 0);
+        i32::try_from(secs.max(1)).unwrap_or(i32::MAX)
+    }
+
+    async fn execute(
+        &self,
+        cql: &str,
+        values: impl scylla::serialize::row::SerializeRow,
+    ) -> Result<(), OutboxError> {
+        self.client
+            .session
+            .execute_unpaged(strict_stmt(&self.client, cql), values)
+            .await?;
+        Ok(())
+    }
+
+    async fn query(
+        &self,
+        cql: &str,
+        values: impl scylla::serialize::row::SerializeRow,
+    ) -> Result<QueryRowsResult, OutboxError> {
+        self.client
+            .session
+            .execute_unpaged(strict_stmt(&self.client, cql), values)
+            .await?
+            .into_rows_result()
+            .map_err(decode_err)
+    }
+}
+
+/// Whether a conditional statement applied (the `[applied]` column).
+fn lwt_applied(rows: QueryRowsResult) -> Result<bool, OutboxError> {
+    let row = rows.maybe_first_row::<Row>().map_err(decode_err)?;
+    Ok(matches!(
+        row.and_then(|r| r.columns.into_iter().next().flatten()),
+        Some(CqlValue::Boolean(true))
+    ))
+}
+
+fn decode_err(e: impl ToString) -> OutboxError {
+    OutboxError::Decode(e.to_string())
+}
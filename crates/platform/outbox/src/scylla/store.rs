@@ -0,0 +1,159 @@
+//! The enqueue side: logged batches that carry the pending-event rows.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use scylla::observability::history::HistoryListener;
+use scylla::serialize::row::SerializeRow as SerializeRowValues;
+use scylla::statement::batch::{Batch, BatchType};
+use scylla::statement::unprepared::Statement;
+use scylla::value::CqlTimestamp;
+use scylla::SerializeRow;
+use scylla_storage::{ProfileKind, ScyllaClient};
+use uuid::Uuid;
+
+use super::strict_stmt;
+use super::table::ScyllaOutboxTable;
+use crate::error::OutboxError;
+use crate::message::OutboxMessage;
+use crate::metrics::OutboxMetrics;
+
+/// Values for the `<ks>.outbox` INSERT, in placeholder order.
+#[derive(SerializeRow)]
+#[scylla(flavor = "enforce_order")]
+struct OutboxInsert {
+    slot: i32,
+    created_at: CqlTimestamp,
+    event_id: Uuid,
+    topic: String,
+    aggregate_key: String,
+    event_type: String,
+    payload: String,
+    headers: BTreeMap<String, String>,
+}
+
+/// Handle a writer uses to enqueue events. Cheap to clone.
+#[derive(Clone)]
+pub struct ScyllaOutbox {
+    client: Arc<ScyllaClient>,
+    table: ScyllaOutboxTable,
+    insert_cql: String,
+    metrics: OutboxMetrics,
+}
+
+impl ScyllaOutbox {
+    pub fn new(client: Arc<ScyllaClient>, table: ScyllaOutboxTable) -> Self {
+        let insert_cql = format!(
+            "INSERT INTO {} \
+             (slot, created_at, event_id, topic, aggregate_key, event_type, payload, headers) \
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
+            table.outbox()
+        );
+        let metrics = OutboxMetrics::new(table.keyspace());
+        Self { client, table, insert_cql, metrics }
+    }
+
+    pub fn table(&self) -> &ScyllaOutboxTable {
+        &self.table
+    }
+
+    /// Starts a logged batch. Add the domain write(s) with
+    /// [`ScyllaOutboxBatch::write`] and the events they produce with
+    /// [`ScyllaOutboxBatch::enqueue`]; [`ScyllaOutboxBatch::execute`] applies all
+    /// of it or none of it.
+    pub fn batch(&self) -> ScyllaOutboxBatch<'_> {
+        ScyllaOutboxBatch {
+            outbox: self,
+            batch: Batch::new(BatchType::Logged),
+            values: Vec::new(),
+            writes: 0,
+            events: 0,
+        }
+    }
+
+    /// Enqueues `messages` on their own, in one logged batch — every event of a
+    /// command lands, or none does.
+    ///
+    /// Only for an event derived after the write (e.g. from a counter the batch
+    /// cannot carry): it shares the write's cluster but not its batch, so call it
+    /// only once the write has been acknowledged. Events of the write itself go
+    /// in its batch, through [`ScyllaOutbox::batch`].
+    pub async fn enqueue(&self, messages: &[OutboxMessage]) -> Result<(), OutboxError> {
+        let mut batch = self.batch();
+        batch.enqueue(messages)?;
+        batch.execute().await
+    }
+
+    fn row(&self, msg: &OutboxMessage) -> Result<OutboxInsert, OutboxError> {
+        Ok(OutboxInsert {
+            slot: self.table.slot_for(&msg.key),
+            created_at: CqlTimestamp(msg.created_at().timestamp_millis()),
+            event_id: msg.id,
+            topic: msg.topic.clone(),
+            aggregate_key: msg.key.clone(),
+            event_type: msg.event_type.clone(),
+            payload: serde_json::to_string(&msg.payload)?,
+            headers: msg.headers.clone(),
+        })
+    }
+}
+
+/// A logged batch under construction: the caller's writes plus outbox rows.
+pub struct ScyllaOutboxBatch<'a> {
+    outbox: &'a ScyllaOutbox,
+    batch: Batch,
+    values: Vec<Box<dyn SerializeRowValues + Send + Sync + 'a>>,
+    writes: usize,
+    events: usize,
+}
+
+impl<'a> ScyllaOutboxBatch<'a> {
+    /// Adds a domain write. Counter updates and conditional (`IF`) statements
+    /// cannot share a logged batch with other tables, and ScyllaDB rejects the
+    /// batch if one is added.
+    pub fn write(&mut self, statement: Statement, values: impl SerializeRowValues + Send + Sync + 'a) -> &mut Self {
+        self.batch.append_statement(statement);
+        self.values.push(Box::new(values));
+        self.writes += 1;
+        self
+    }
+
+    /// Adds one outbox row per message. Serialization happens here, so a payload
+    /// that cannot be encoded fails before anything is written.
+    pub fn enqueue(&mut self, messages: &[OutboxMessage]) -> Result<&mut Self, OutboxError> {
+        for msg in messages {
+            let row = self.outbox.row(msg)?;
+            self.batch
+                .append_statement(strict_stmt(&self.outbox.client, &self.outbox.insert_cql));
+            self.values.push(Box::new(row));
+            self.events += 1;
+        }
+        Ok(self)
+    }
+
+    /// Applies the batch. An empty batch is a no-op.
+    ///
+    /// A batch of outbox rows alone is marked idempotent, so the driver may retry
+    /// it after a timeout: it rewrites identical rows. With caller writes in it the
+    /// batch is only as idempotent as those writes, and is left unmarked.
+    pub async fn execute(mut self) -> Result<(), OutboxError> {
+        if self.values.is_empty() {
+            return Ok(());
+        }
+        let client = &self.outbox.client;
+        self.batch.set_execution_profile_handle(Some(
+            client
+                .profiles
+                .get(ProfileKind::Strict)
+                .clone()
+                .into_handle_with_label("strict".to_owned()),
+        ));
+        self.batch
+            .set_history_listener(Arc::clone(&client.history_listener) as Arc<dyn HistoryListener>);
+        self.batch.set_is_idempotent(self.writes == 0);
+
+        client.session.batch(&self.batch, &self.values).await?;
+        self.outbox.metrics.enqueued(self.events as u64);
+        Ok(())
+    }
+}
@@ -0,0 +1,138 @@
+//! The per-keyspace outbox tables on ScyllaDB.
+
+use crate::error::OutboxError;
+use crate::table::{hash_slot, is_identifier, DEFAULT_SLOTS};
+
+/// Names a service keyspace's outbox (`<ks>.outbox`), lease (`<ks>.outbox_lease`)
+/// and relay-membership (`<ks>.outbox_member`) tables, and fixes the slot count.
+///
+/// The ScyllaDB services each own a keyspace, so — unlike the shared Postgres
+/// database — the table names need no per-service prefix. As with
+/// [`crate::OutboxTable`], the writer and the relay must be built from the same
+/// definition.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScyllaOutboxTable {
+    keyspace: String,
+    outbox: String,
+    lease: String,
+    member: String,
+    slots: u16,
+}
+
+impl ScyllaOutboxTable {
+    /// The tables in `keyspace`, with [`DEFAULT_SLOTS`] slots. The keyspace is
+    /// interpolated into CQL, so it must match `[a-z][a-z0-9_]*`.
+    pub fn new(keyspace: &str) -> Result<Self, OutboxError> {
+        if !is_identifier(keyspace) {
+            return Err(OutboxError::InvalidTable(format!(
+                "keyspace '{keyspace}' must match [a-z][a-z0-9_]*"
+            )));
+        }
+        Ok(Self {
+            keyspace: keyspace.to_owned(),
+            outbox: format!("{keyspace}.outbox"),
+            lease: format!("{keyspace}.outbox_lease"),
+            member: format!("{keyspace}.outbox_member"),
+            slots: DEFAULT_SLOTS,
+        })
+    }
+
+    /// Overrides the slot count. Changing it on a live table re-homes keys onto
+    /// different partitions, so drain the backlog first.
+    pub fn with_slots(mut self, slots: u16) -> Result<Self, OutboxError> {
+        if slots == 0 {
+            return Err(OutboxError::InvalidTable("slot count must be > 0".into()));
+        }
+        self.slots = slots;
+        Ok(self)
+    }
+
+    pub fn keyspace(&self) -> &str {
+        &self.keyspace
+    }
+
+    pub fn outbox(&self) -> &str {
+        &self.outbox
+    }
+
+    pub fn lease(&self) -> &str {
+        &self.lease
+    }
+
+    pub fn member(&self) -> &str {
+        &self.member
+    }
+
+    pub fn slots(&self) -> u16 {
+        self.slots
+    }
+
+    /// The slot — and therefore the outbox partition — `key` is written to.
+    pub fn slot_for(&self, key: &str) -> i32 {
+        hash_slot(key, self.slots)
+    }
+
+    /// The canonical CQL for these tables; each service's `.cql` migration is a
+    /// verbatim rendering of it.
+    ///
+    /// One partition per slot, clustered in drain order. Published rows are
+    /// deleted, so the partition head accumulates tombstones until compaction;
+    /// `gc_grace_seconds` is kept short for that reason. The usual risk of a short
+    /// grace period — a delete lost on a replica resurrecting the row — costs only
+    /// a re-delivery under the same `event_id`, which consumers already absorb.
+    pub fn ddl(&self) -> String {
+        let outbox = &self.outbox;
+        let lease = &self.lease;
+        let member = &self.member;
+        format!(
+            "CREATE TABLE IF NOT EXISTS {outbox} (\n    \
+                 slot          int,\n    \
+                 created_at    timestamp,\n    \
+                 event_id      uuid,\n    \
+                 topic         text,\n    \
+                 aggregate_key text,\n    \
+                 event_type    text,\n    \
+                 payload       text,\n    \
+                 headers       map<text, text>,\n    \
+                 attempts      int,\n    \
+                 last_error    text,\n    \
+                 PRIMARY KEY ((slot), created_at, event_id)\n\
+             ) WITH CLUSTERING ORDER BY (created_at ASC, event_id ASC)\n  \
+               AND gc_grace_seconds = 3600\n  \
+               AND compression = {{'sstable_compression': 'LZ4Compressor'}};\n\n\
+             CREATE TABLE IF NOT EXISTS {lease} (\n    \
+                 slot  int PRIMARY KEY,\n    \
+                 owner text\n\
+             );\n\n\
+             CREATE TABLE IF NOT EXISTS {member} (\n    \
+                 owner   text PRIMARY KEY,\n    \
+                 seen_at timestamp\n\
+             );\n"
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::OutboxTable;
+
+    #[test]
+    fn names_are_qualified_by_the_keyspace() {
+        let t = ScyllaOutboxTable::new("social_graph").unwrap();
+        assert_eq!(t.outbox(), "social_graph.outbox");
+        assert_eq!(t.lease(), "social_graph.outbox_lease");
+        assert_eq!(t.member(), "social_graph.outbox_member");
+        assert!(ScyllaOutboxTable::new("post; DROP").is_err());
+    }
+
+    #[test]
+    fn keys_hash_onto_the_same_slots_as_the_postgres_backend() {
+        let cql = ScyllaOutboxTable::new("post").unwrap();
+        let pg = OutboxTable::new("post").unwrap();
+        for i in 0..100 {
+            let key = format!("key-{i}");
+            assert_eq!(cql.slot_for(&key), pg.slot_for(&key));
+        }
+    }
+}
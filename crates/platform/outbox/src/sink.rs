@@ -0,0 +1,97 @@
+//! Where the relay delivers drained records.
+
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use serde::de::DeserializeOwned;
+use transport::codec::proto_encode;
+use transport::kafka::producer::handle::KafkaProducerHandle;
+use transport::kafka::{ContentType, ToProto, CONTENT_TYPE_HEADER};
+
+use crate::error::OutboxError;
+use crate::message::OutboxRecord;
+
+/// The relay's delivery seam. Implementations must be **per-record synchronous**:
+/// `publish` returns only once the record is durably accepted (Kafka `acks=all`),
+/// because the relay deletes the row on `Ok`.
+#[async_trait]
+pub trait OutboxSink: Send + Sync + 'static {
+    async fn publish(&self, record: &OutboxRecord) -> Result<(), OutboxError>;
+}
+
+/// Publishes each record to its stored topic, keyed by its aggregate key, with the
+/// stored headers plus `event_type` / `event_id` and a `content-type`. The payload
+/// bytes are the stored JSON — the same encoding `KafkaProducerHandle::publish`
+/// produces — so consumers cannot tell an outbox-relayed event from a
+/// directly-published one.
+///
+/// A topic registered with [`with_protobuf`](Self::with_protobuf) is transcoded on
+/// the way out instead: the row stays readable JSON, the record is protobuf.
+pub struct KafkaOutboxSink {
+    producer: KafkaProducerHandle,
+    protobuf: HashMap<String, Transcode>,
+}
+
+/// Re-encodes a stored JSON payload as protobuf bytes.
+type Transcode = fn(&serde_json::Value) -> Result<Vec<u8>, OutboxError>;
+
+fn transcode<T>(payload: &serde_json::Value) -> Result<Vec<u8>, OutboxError>
+where
+    T: DeserializeOwned + ToProto,
+{
+    let event: T = serde_json::from_value(payload.clone())?;
+    let bytes = proto_encode(&event.to_proto()).map_err(|e| OutboxError::Publish(e.to_string()))?;
+    Ok(bytes.to_vec())
+}
+
+impl KafkaOutboxSink {
+    pub fn new(producer: KafkaProducerHandle) -> Self {
+        Self { producer, protobuf: HashMap::new() }
+    }
+
+    /// Publishes `topic` as protobuf: each stored payload is read back as `T` and
+    /// sent as its [`ToProto`] message, labelled `application/x-protobuf`.
+    pub fn with_protobuf<T>(mut self, topic: impl Into<String>) -> Self
+    where
+        T: DeserializeOwned + ToProto,
+    {
+        self.protobuf.insert(topic.into(), transcode::<T>);
+        self
+    }
+}
+
+#[async_trait]
+impl OutboxSink for KafkaOutboxSink {
+    async fn publish(&self, record: &OutboxRecord) -> Result<(), OutboxError> {
+        let (bytes, content_type) = match self.protobuf.get(&record.topic) {
+            Some(transcode) => (transcode(&record.payload)?, ContentType::Protobuf),
+            None => (serde_json::to_vec(&record.payload)?, ContentType::Json),
+        };
+        let mut headers = record.wire_headers();
+        headers.insert(CONTENT_TYPE_HEADER.to_owned(), content_type.as_str().to_owned());
+        self.producer
+            .publish_raw(&record.topic, &record.key, &bytes, headers)
+            .await
+            .map_err(|e| OutboxError::Publish(e.to_string()))
+    }
+}
+
+/// A no-broker sink that traces records instead of emitting them. Used for local
+/// development where `KAFKA_BROKERS` is unset — the relay still drains, so the
+/// table does not grow without bound.
+#[derive(Default)]
+pub struct LogOutboxSink;
+
+#[async_trait]
+impl OutboxSink for LogOutboxSink {
+    async fn publish(&self, record: &OutboxRecord) -> Result<(), OutboxError> {
+        tracing::info!(
+            topic = %record.topic,
+            key = %record.key,
+            event_type = %record.event_type,
+            event_id = %record.id,
+            "outbox event (log sink)"
+        );
+        Ok(())
+    }
+}
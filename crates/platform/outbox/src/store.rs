@@ -0,0 +1,98 @@
+//! The enqueue side: writes [`OutboxMessage`]s into `<prefix>_outbox`.
+
+use std::collections::BTreeSet;
+
+use postgres_storage::{PgTransaction, TransactionManager};
+use sqlx::types::Json;
+
+use crate::error::OutboxError;
+use crate::message::OutboxMessage;
+use crate::metrics::OutboxMetrics;
+use crate::table::OutboxTable;
+
+/// Holds the aggregate key's advisory lock until the enclosing transaction ends.
+const LOCK_KEY_SQL: &str = "SELECT pg_advisory_xact_lock(hashtextextended($1, 0))";
+
+/// Handle a writer uses to enqueue events. Cheap to clone (the table definition,
+/// a [`TransactionManager`] clone, and instrument handles).
+#[derive(Clone)]
+pub struct PgOutbox {
+    table: OutboxTable,
+    tx: TransactionManager,
+    insert_sql: String,
+    metrics: OutboxMetrics,
+}
+
+impl PgOutbox {
+    pub fn new(table: OutboxTable, tx: TransactionManager) -> Self {
+        let insert_sql = format!(
+            "INSERT INTO {} (id, topic, aggregate_key, slot, event_type, payload, headers) \
+             VALUES ($1, $2, $3, $4, $5, $6, $7)",
+            table.outbox()
+        );
+        let metrics = OutboxMetrics::new(table.prefix());
+        Self { table, tx, insert_sql, metrics }
+    }
+
+    pub fn table(&self) -> &OutboxTable {
+        &self.table
+    }
+
+    /// Enqueues `messages` inside the caller's transaction — the event rows commit
+    /// or roll back together with the state change that produced them. This is the
+    /// entry point for writers that already run in
+    /// [`TransactionManager::run_on_shard`].
+    ///
+    /// The relay drains in `seq` order, and `seq` is drawn at insert, not at
+    /// commit. So each key is first locked until the caller's transaction ends
+    /// (a transaction-scoped advisory lock, taken in key order): a concurrent
+    /// writer of the same key draws its `seq` only after this one committed or
+    /// rolled back, and a key's `seq` order is its commit order.
+    pub async fn enqueue(
+        &self,
+        tx: &mut PgTransaction,
+        messages: &[OutboxMessage],
+    ) -> Result<(), OutboxError> {
+        let keys: BTreeSet<&str> = messages.iter().map(|m| m.key.as_str()).collect();
+        for key in keys {
+            sqlx::query(LOCK_KEY_SQL).bind(key).execute(&mut **tx).await?;
+        }
+        for msg in messages {
+            sqlx::query(&self.insert_sql)
+                .bind(msg.id)
+                .bind(&msg.topic)
+                .bind(&msg.key)
+                .bind(self.table.slot_for(&msg.key))
+                .bind(&msg.event_type)
+                .bind(&msg.payload)
+                .bind(Json(&msg.headers))
+                .execute(&mut **tx)
+                .await?;
+        }
+        self.metrics.enqueued(messages.len() as u64);
+        Ok(())
+    }
+
+    /// Enqueues `messages` in a transaction of their own, on the shard owning the
+    /// first message's key.
+    ///
+    /// For writers whose state change spans several repositories and cannot share
+    /// one transaction with the enqueue: the event is then in the *same fault
+    /// domain* as the write (same database, no broker in the request path) but not
+    /// the same transaction, so the caller must enqueue only after its writes
+    /// committed.
+    pub async fn enqueue_detached(&self, messages: &[OutboxMessage]) -> Result<(), OutboxError> {
+        let Some(first) = messages.first() else {
+            return Ok(());
+        };
+        let key = first.key.clone();
+        // Owned captures: the transaction closure must be valid for any `'tx`.
+        let this = self.clone();
+        let messages = messages.to_vec();
+        self.tx
+            .run_on_shard(key.as_str(), |tx| {
+                Box::pin(async move { this.enqueue(tx, &messages).await })
+            })
+            .await
+    }
+}
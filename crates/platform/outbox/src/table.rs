@@ -0,0 +1,170 @@
+//! The per-service outbox tables and how keys map onto lease slots.
+
+use crate::error::OutboxError;
+
+/// Default number of lease slots. Enough to spread a backlog over a handful of
+/// relay replicas; small enough that the lease table is a single page.
+pub const DEFAULT_SLOTS: u16 = 16;
+
+/// Names a service's outbox (`<prefix>_outbox`), lease (`<prefix>_outbox_lease`)
+/// and relay-membership (`<prefix>_outbox_member`) tables, and fixes the slot count
+/// keys are hashed onto.
+///
+/// The enqueue side and the relay **must** be built from the same `OutboxTable`:
+/// the slot is computed at enqueue time, and a relay configured with a different
+/// count would lease slots no writer uses (or leave some undrained).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutboxTable {
+    prefix: String,
+    outbox: String,
+    lease: String,
+    member: String,
+    slots: u16,
+}
+
+impl OutboxTable {
+    /// The tables for `prefix`, with [`DEFAULT_SLOTS`] slots. The prefix is
+    /// interpolated into SQL, so it must be a lowercase identifier
+    /// (`[a-z][a-z0-9_]*`) — anything else is rejected here rather than quoted.
+    pub fn new(prefix: &str) -> Result<Self, OutboxError> {
+        if !is_identifier(prefix) {
+            return Err(OutboxError::InvalidTable(format!(
+                "prefix '{prefix}' must match [a-z][a-z0-9_]*"
+            )));
+        }
+        Ok(Self {
+            prefix: prefix.to_owned(),
+            outbox: format!("{prefix}_outbox"),
+            lease: format!("{prefix}_outbox_lease"),
+            member: format!("{prefix}_outbox_member"),
+            slots: DEFAULT_SLOTS,
+        })
+    }
+
+    /// Overrides the slot count. Changing it on a live table re-homes keys onto
+    /// different slots, so drain the backlog first.
+    pub fn with_slots(mut self, slots: u16) -> Result<Self, OutboxError> {
+        if slots == 0 {
+            return Err(OutboxError::InvalidTable("slot count must be > 0".into()));
+        }
+        self.slots = slots;
+        Ok(self)
+    }
+
+    pub fn prefix(&self) -> &str {
+        &self.prefix
+    }
+
+    pub fn outbox(&self) -> &str {
+        &self.outbox
+    }
+
+    pub fn lease(&self) -> &str {
+        &self.lease
+    }
+
+    pub fn member(&self) -> &str {
+        &self.member
+    }
+
+    pub fn slots(&self) -> u16 {
+        self.slots
+    }
+
+    /// The slot `key` is drained from. SeaHash is the fleet's stable hash (the
+    /// Postgres shard router uses it too), so a key's slot never changes between
+    /// releases.
+    pub fn slot_for(&self, key: &str) -> i32 {
+        hash_slot(key, self.slots)
+    }
+
+    /// The canonical schema for these tables. Service migrations are verbatim
+    /// renderings of it; the relay seeds the lease rows itself at start-up.
+    pub fn ddl(&self) -> String {
+        let outbox = &self.outbox;
+        let lease = &self.lease;
+        let member = &self.member;
+        format!(
+            "CREATE TABLE IF NOT EXISTS {outbox} (\n    \
+                 id            UUID        PRIMARY KEY,\n    \
+                 seq           BIGSERIAL,\n    \
+                 topic         TEXT        NOT NULL,\n    \
+                 aggregate_key TEXT        NOT NULL,\n    \
+                 slot          INT         NOT NULL,\n    \
+                 event_type    TEXT        NOT NULL,\n    \
+                 payload       JSONB       NOT NULL,\n    \
+                 headers       JSONB       NOT NULL DEFAULT '{{}}',\n    \
+                 attempts      INT         NOT NULL DEFAULT 0,\n    \
+                 last_error    TEXT,\n    \
+                 created_at    TIMESTAMPTZ NOT NULL DEFAULT now()\n\
+             );\n\n\
+             CREATE INDEX IF NOT EXISTS {outbox}_drain_order\n    \
+                 ON {outbox} (slot, seq);\n\n\
+             CREATE TABLE IF NOT EXISTS {lease} (\n    \
+                 slot       INT         PRIMARY KEY,\n    \
+                 owner      TEXT        NOT NULL DEFAULT '',\n    \
+                 expires_at TIMESTAMPTZ NOT NULL DEFAULT 'epoch'\n\
+             );\n\n\
+             CREATE TABLE IF NOT EXISTS {member} (\n    \
+                 owner      TEXT        PRIMARY KEY,\n    \
+                 expires_at TIMESTAMPTZ NOT NULL\n\
+             );\n"
+        )
+    }
+}
+
+/// `[a-z][a-z0-9_]*` — the names this crate is willing to interpolate into a
+/// statement unquoted.
+pub(crate) fn is_identifier(name: &str) -> bool {
+    name.chars().next().is_some_and(|c| c.is_ascii_lowercase())
+        && name
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+}
+
+/// SeaHash of `key` modulo `slots`, shared by both backends.
+pub(crate) fn hash_slot(key: &str, slots: u16) -> i32 {
+    (seahash::hash(key.as_bytes()) % u64::from(slots)) as i32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn names_derive_from_the_prefix() {
+        let t = OutboxTable::new("account").unwrap();
+        assert_eq!(t.outbox(), "account_outbox");
+        assert_eq!(t.lease(), "account_outbox_lease");
+        assert_eq!(t.member(), "account_outbox_member");
+        assert_eq!(t.slots(), DEFAULT_SLOTS);
+    }
+
+    #[test]
+    fn rejects_prefixes_that_are_not_plain_identifiers() {
+        for bad in ["", "Account", "1acct", "acct; DROP TABLE x", "acct-outbox"] {
+            assert!(OutboxTable::new(bad).is_err(), "accepted {bad:?}");
+        }
+        assert!(OutboxTable::new("acct").unwrap().with_slots(0).is_err());
+    }
+
+    #[test]
+    fn a_key_always_lands_on_the_same_slot_within_range() {
+        let t = OutboxTable::new("media").unwrap().with_slots(8).unwrap();
+        let slot = t.slot_for("0190c3e0-0000-7000-8000-000000000001");
+        assert_eq!(slot, t.slot_for("0190c3e0-0000-7000-8000-000000000001"));
+        for i in 0..1_000 {
+            assert!((0..8).contains(&t.slot_for(&format!("key-{i}"))));
+        }
+    }
+
+    #[test]
+    fn ddl_names_every_table_and_the_drain_index() {
+        let ddl = OutboxTable::new("counter").unwrap().ddl();
+        assert!(ddl.contains("CREATE TABLE IF NOT EXISTS counter_outbox ("));
+        assert!(ddl.contains("ON counter_outbox (slot, seq)"));
+        assert!(ddl.contains("CREATE TABLE IF NOT EXISTS counter_outbox_lease ("));
+        assert!(ddl.contains("CREATE TABLE IF NOT EXISTS counter_outbox_member ("));
+        assert!(ddl.contains("DEFAULT '{}'"));
+    }
+}
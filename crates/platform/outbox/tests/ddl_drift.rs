@@ -0,0 +1,64 @@
+//! Every service migration that creates an outbox claims to be a verbatim
+//! rendering of [`OutboxTable::ddl`] (Postgres) or [`ScyllaOutboxTable::ddl`]
+//! (ScyllaDB). This keeps that claim true: a column added
+//! to the crate without a matching migration (or a hand-edited migration) fails
+//! here rather than at the first relay tick in staging.
+
+use outbox::{OutboxTable, ScyllaOutboxTable};
+
+const WORKSPACE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../../..");
+
+/// (prefix, migration path relative to the workspace root)
+const MIGRATIONS: &[(&str, &str)] = &[
+    ("account", "crates/services/account/migrations/0003_outbox.sql"),
+    ("media", "crates/services/media/migrations/0002_outbox.sql"),
+    ("moderation", "crates/services/moderation/migrations/postgres/0002_outbox.sql"),
+    ("counter", "crates/services/counter/migrations/postgres/0002_outbox.sql"),
+    ("outbox_it", "crates/platform/outbox/tests/migrations/0001_outbox_it.sql"),
+];
+
+/// (keyspace, migration path relative to the workspace root)
+const CQL_MIGRATIONS: &[(&str, &str)] = &[
+    ("post", "crates/services/post/migrations/0007_create_outbox_tables.cql"),
+    ("comment", "crates/services/comment/migrations/0004_create_outbox_tables.cql"),
+    ("chat", "crates/services/chat/migrations/0007_create_outbox_tables.cql"),
+    ("social_graph", "crates/services/social-graph/migrations/0006_create_outbox_tables.cql"),
+    ("profile", "crates/services/profile/migrations/0006_create_outbox_tables.cql"),
+    ("outbox_it", "crates/platform/outbox/tests/cql_migrations/0002_outbox_it.cql"),
+];
+
+/// The migration minus its leading `--` comment block.
+fn schema_of(sql: &str) -> String {
+    sql.lines()
+        .skip_while(|line| line.starts_with("--"))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[test]
+fn service_outbox_migrations_match_the_canonical_ddl() {
+    for (prefix, path) in MIGRATIONS {
+        let sql = std::fs::read_to_string(format!("{WORKSPACE}/{path}"))
+            .unwrap_or_else(|e| panic!("read {path}: {e}"));
+        let expected = OutboxTable::new(prefix).unwrap().ddl();
+        assert_eq!(
+            schema_of(&sql).trim_end(),
+            expected.trim_end(),
+            "{path} drifted from OutboxTable::new({prefix:?}).ddl()"
+        );
+    }
+}
+
+#[test]
+fn service_cql_outbox_migrations_match_the_canonical_ddl() {
+    for (keyspace, path) in CQL_MIGRATIONS {
+        let cql = std::fs::read_to_string(format!("{WORKSPACE}/{path}"))
+            .unwrap_or_else(|e| panic!("read {path}: {e}"));
+        let expected = ScyllaOutboxTable::new(keyspace).unwrap().ddl();
+        assert_eq!(
+            schema_of(&cql).trim_end(),
+            expected.trim_end(),
+            "{path} drifted from ScyllaOutboxTable::new({keyspace:?}).ddl()"
+        );
+    }
+}
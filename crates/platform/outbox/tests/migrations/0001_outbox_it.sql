@@ -0,0 +1,31 @@
+-- Fixture tables for the outbox live suite: OutboxTable::new("outbox_it").ddl().
+-- Scenarios that need isolation create their own uniquely-prefixed tables at
+-- runtime from the same `ddl()`; this one proves a migration-applied schema
+-- works end to end.
+CREATE TABLE IF NOT EXISTS outbox_it_outbox (
+    id            UUID        PRIMARY KEY,
+    seq           BIGSERIAL,
+    topic         TEXT        NOT NULL,
+    aggregate_key TEXT        NOT NULL,
+    slot          INT         NOT NULL,
+    event_type    TEXT        NOT NULL,
+    payload       JSONB       NOT NULL,
+    headers       JSONB       NOT NULL DEFAULT '{}',
+    attempts      INT         NOT NULL DEFAULT 0,
+    last_error    TEXT,
+    created_at    TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+CREATE INDEX IF NOT EXISTS outbox_it_outbox_drain_order
+    ON outbox_it_outbox (slot, seq);
+
+CREATE TABLE IF NOT EXISTS outbox_it_outbox_lease (
+    slot       INT         PRIMARY KEY,
+    owner      TEXT        NOT NULL DEFAULT '',
+    expires_at TIMESTAMPTZ NOT NULL DEFAULT 'epoch'
+);
+
+CREATE TABLE IF NOT EXISTS outbox_it_outbox_member (
+    owner      TEXT        PRIMARY KEY,
+    expires_at TIMESTAMPTZ NOT NULL
+);
@@ -0,0 +1,250 @@
+//! Live outbox suite against a real PostgreSQL container.
+//!
+//! Gated behind `integration-outbox` so `cargo test -p outbox` stays hermetic:
+//!
+//! ```text
+//! cargo test -p outbox --features integration-outbox -- --nocapture
+//! ```
+//!
+//! Covers what the unit suite cannot: enqueue atomicity with the caller's
+//! transaction, the SQL drain/delete/failure bookkeeping, and slot leasing across
+//! replicas (fair split, takeover after expiry).
+#![cfg(feature = "integration-outbox")]
+
+use std::collections::HashSet;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use async_trait::async_trait;
+use outbox::{
+    OutboxError, OutboxMessage, OutboxRecord, OutboxRelay, OutboxSink, OutboxTable, PgOutbox,
+    RelayConfig,
+};
+use postgres_storage::TransactionManager;
+use sqlx::{Executor as _, PgPool};
+use uuid::Uuid;
+
+const MIGRATIONS_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/migrations");
+
+/// Records what it publishes; can reject one key or everything.
+#[derive(Default)]
+struct RecordingSink {
+    down: AtomicBool,
+    poisoned_key: Mutex<Option<String>>,
+    seen: Mutex<Vec<OutboxRecord>>,
+}
+
+#[async_trait]
+impl OutboxSink for RecordingSink {
+    async fn publish(&self, record: &OutboxRecord) -> Result<(), OutboxError> {
+        if self.down.load(Ordering::SeqCst)
+            || self.poisoned_key.lock().unwrap().as_deref() == Some(record.key.as_str())
+        {
+            return Err(OutboxError::Publish("sink rejected".into()));
+        }
+        self.seen.lock().unwrap().push(record.clone());
+        Ok(())
+    }
+}
+
+async fn pool() -> PgPool {
+    let url = test_support::containers::postgres_ready(MIGRATIONS_DIR).await;
+    PgPool::connect(&url).await.expect("connect")
+}
+
+/// Fresh tables per test, created from the canonical DDL, so scenarios
+/// never see each other's rows or leases.
+async fn fresh_table(pool: &PgPool) -> OutboxTable {
+    let table = OutboxTable::new(&format!("it_{}", Uuid::now_v7().simple())).unwrap();
+    pool.execute(sqlx::raw_sql(&table.ddl())).await.expect("create outbox tables");
+    table
+}
+
+fn relay(pool: &PgPool, table: &OutboxTable, sink: Arc<RecordingSink>, ttl: Duration) -> OutboxRelay {
+    let config = RelayConfig {
+        owner: format!("it-{}", Uuid::now_v7().simple()),
+        interval: Duration::from_millis(50),
+        batch_size: 100,
+        lease_ttl: ttl,
+    };
+    OutboxRelay::new(pool.clone(), table.clone(), sink, config)
+}
+
+fn message(key: &str, seq: u32) -> OutboxMessage {
+    OutboxMessage::new("it.v1.events", key, "it.happened", &serde_json::json!({ "seq": seq }))
+        .unwrap()
+        .with_header("aggregate_id", key)
+}
+
+async fn pending(pool: &PgPool, table: &OutboxTable) -> i64 {
+    sqlx::query_scalar(&format!("SELECT count(*) FROM {}", table.outbox()))
+        .fetch_one(pool)
+        .await
+        .expect("count outbox")
+}
+
+#[tokio::test]
+async fn the_migration_applied_fixture_table_drains() {
+    let pool = pool().await;
+    let table = OutboxTable::new("outbox_it").unwrap();
+    let outbox = PgOutbox::new(table.clone(), TransactionManager::new(pool.clone()));
+    let key = Uuid::now_v7().to_string();
+    outbox.enqueue_detached(&[message(&key, 1)]).await.expect("enqueue");
+
+    let sink = Arc::new(RecordingSink::default());
+    let relay = relay(&pool, &table, sink.clone(), Duration::from_secs(30));
+    assert!(relay.tick().await.expect("drain") >= 1);
+    assert!(sink.seen.lock().unwrap().iter().any(|r| r.key == key));
+}
+
+#[tokio::test]
+async fn enqueue_commits_and_rolls_back_with_the_callers_transaction() {
+    let pool = pool().await;
+    let table = fresh_table(&pool).await;
+    let outbox = PgOutbox::new(table.clone(), TransactionManager::new(pool.clone()));
+
+    let mut tx = pool.begin().await.unwrap();
+    outbox.enqueue(&mut tx, &[message("k", 1)]).await.expect("enqueue");
+    tx.rollback().await.unwrap();
+    assert_eq!(pending(&pool, &table).await, 0, "a rolled-back write leaves no event");
+
+    let mut tx = pool.begin().await.unwrap();
+    outbox.enqueue(&mut tx, &[message("k", 1), message("k", 2)]).await.expect("enqueue");
+    tx.commit().await.unwrap();
+    assert_eq!(pending(&pool, &table).await, 2);
+}
+
+#[tokio::test]
+async fn relay_publishes_in_key_order_and_blocks_only_the_failing_key() {
+    let pool = pool().await;
+    let table = fresh_table(&pool).await;
+    let outbox = PgOutbox::new(table.clone(), TransactionManager::new(pool.clone()));
+    for seq in 1..=3 {
+        outbox.enqueue_detached(&[message("ok", seq)]).await.unwrap();
+        outbox.enqueue_detached(&[message("bad", seq)]).await.unwrap();
+    }
+
+    let sink = Arc::new(RecordingSink::default());
+    *sink.poisoned_key.lock().unwrap() = Some("bad".into());
+    let relay = relay(&pool, &table, sink.clone(), Duration::from_secs(30));
+
+    assert_eq!(relay.tick().await.expect("partial progress is progress"), 3);
+    let seqs: Vec<u64> = sink.seen.lock().unwrap().iter().map(|r| r.payload["seq"].as_u64().unwrap()).collect();
+    assert_eq!(seqs, vec![1, 2, 3]);
+
+    // Only the head of the blocked key was attempted, and it carries the failure.
+    let (attempts, error): (i32, Option<String>) = sqlx::query_as(&format!(
+        "SELECT attempts, last_error FROM {} WHERE aggregate_key = 'bad' ORDER BY seq LIMIT 1",
+        table.outbox()
+    ))
+    .fetch_one(&pool)
+    .await
+    .unwrap();
+    assert_eq!(attempts, 1);
+    assert!(error.unwrap().contains("sink rejected"));
+    assert_eq!(pending(&pool, &table).await, 3);
+
+    // Key recovers: its backlog drains, still in order, and the table empties.
+    *sink.poisoned_key.lock().unwrap() = None;
+    assert_eq!(relay.tick().await.expect("drain"), 3);
+    let bad: Vec<u64> = sink
+        .seen
+        .lock()
+        .unwrap()
+        .iter()
+        .filter(|r| r.key == "bad")
+        .map(|r| r.payload["seq"].as_u64().unwrap())
+        .collect();
+    assert_eq!(bad, vec![1, 2, 3]);
+    assert_eq!(pending(&pool, &table).await, 0);
+
+    let headers = sink.seen.lock().unwrap()[0].wire_headers();
+    assert_eq!(headers["event_type"], "it.happened");
+    assert_eq!(headers["aggregate_id"], "ok");
+}
+
+/// A transaction that started first but committed last must not publish
+/// first: `created_at` is its start time, the drain order is not.
+#[tokio::test]
+async fn a_key_publishes_in_commit_order_not_transaction_start_order() {
+    let pool = pool().await;
+    let table = fresh_table(&pool).await;
+    let outbox = PgOutbox::new(table.clone(), TransactionManager::new(pool.clone()));
+
+    let mut late = pool.begin().await.unwrap();
+    sqlx::query("SELECT now()").execute(&mut *late).await.unwrap();
+    tokio::time::sleep(Duration::from_millis(20)).await;
+
+    let mut early = pool.begin().await.unwrap();
+    outbox.enqueue(&mut early, &[message("k", 1)]).await.expect("enqueue");
+    early.commit().await.unwrap();
+    outbox.enqueue(&mut late, &[message("k", 2)]).await.expect("enqueue");
+    late.commit().await.unwrap();
+
+    let sink = Arc::new(RecordingSink::default());
+    let relay = relay(&pool, &table, sink.clone(), Duration::from_secs(30));
+    assert_eq!(relay.tick().await.expect("drain"), 2);
+    let seqs: Vec<u64> = sink.seen.lock().unwrap().iter().map(|r| r.payload["seq"].as_u64().unwrap()).collect();
+    assert_eq!(seqs, vec![1, 2]);
+}
+
+#[tokio::test]
+async fn a_total_outage_surfaces_and_loses_nothing() {
+    let pool = pool().await;
+    let table = fresh_table(&pool).await;
+    let outbox = PgOutbox::new(table.clone(), TransactionManager::new(pool.clone()));
+    outbox.enqueue_detached(&[message("a", 1), message("a", 2)]).await.unwrap();
+
+    let sink = Arc::new(RecordingSink::default());
+    sink.down.store(true, Ordering::SeqCst);
+    let relay = relay(&pool, &table, sink.clone(), Duration::from_secs(30));
+    assert!(relay.tick().await.is_err(), "a stalled tick must surface");
+    assert_eq!(pending(&pool, &table).await, 2);
+
+    sink.down.store(false, Ordering::SeqCst);
+    assert_eq!(relay.tick().await.unwrap(), 2);
+    assert_eq!(pending(&pool, &table).await, 0);
+}
+
+async fn owners(pool: &PgPool, table: &OutboxTable) -> Vec<(String, i64)> {
+    sqlx::query_as(&format!(
+        "SELECT owner, count(*) FROM {} WHERE expires_at > now() GROUP BY owner ORDER BY owner",
+        table.lease()
+    ))
+    .fetch_all(pool)
+    .await
+    .unwrap()
+}
+
+#[tokio::test]
+async fn replicas_split_the_slots_and_take_over_expired_leases() {
+    let pool = pool().await;
+    let table = fresh_table(&pool).await;
+    let sink = Arc::new(RecordingSink::default());
+    let a = relay(&pool, &table, sink.clone(), Duration::from_secs(30));
+    let b = relay(&pool, &table, sink.clone(), Duration::from_millis(500));
+
+    // A alone takes every slot; once B shows up, A sheds down to its share on
+    // its next tick and B picks the released slots up.
+    a.tick().await.unwrap();
+    assert_eq!(owners(&pool, &table).await.len(), 1);
+    b.tick().await.unwrap();
+    a.tick().await.unwrap();
+    b.tick().await.unwrap();
+    let split = owners(&pool, &table).await;
+    let counts: HashSet<i64> = split.iter().map(|(_, n)| *n).collect();
+    assert_eq!(split.len(), 2, "both replicas hold leases: {split:?}");
+    assert_eq!(counts, HashSet::from([i64::from(table.slots()) / 2]));
+
+    // B dies (stops renewing). After its short TTL, A's next tick takes over.
+    tokio::time::sleep(Duration::from_millis(700)).await;
+    a.tick().await.unwrap();
+    let after = owners(&pool, &table).await;
+    assert_eq!(after.len(), 1);
+    assert_eq!(after[0].1, i64::from(table.slots()));
+
+    // Graceful shutdown hands everything back immediately.
+    a.release_leases().await.unwrap();
+    assert!(owners(&pool, &table).await.is_empty());
+}
@@ -0,0 +1,285 @@
+//! Live outbox suite against a real ScyllaDB container.
+//!
+//! Gated behind `integration-outbox` alongside the Postgres suite:
+//!
+//! ```text
+//! cargo test -p outbox --features integration-outbox --test scylla_outbox -- --nocapture
+//! ```
+//!
+//! Covers the CQL half: logged-batch atomicity with a domain write, idempotent
+//! re-enqueue, the per-partition drain/delete/failure bookkeeping, and LWT slot
+//! leasing across replicas (fair split, takeover after TTL expiry).
+#![cfg(feature = "integration-outbox")]
+
+use std::collections::HashSet;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use async_trait::async_trait;
+use outbox::{
+    OutboxError, OutboxMessage, OutboxRecord, OutboxSink, RelayConfig, ScyllaOutbox,
+    ScyllaOutboxRelay, ScyllaOutboxTable,
+};
+use scylla::statement::unprepared::Statement;
+use scylla_storage::{ScyllaClient, ScyllaConfig, ScyllaSessionBuilder};
+use uuid::Uuid;
+
+const KEYSPACE: &str = "outbox_it";
+const MIGRATIONS_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/cql_migrations");
+
+/// Records what it publishes; can reject one key or everything.
+#[derive(Default)]
+struct RecordingSink {
+    down: AtomicBool,
+    poisoned_key: Mutex<Option<String>>,
+    seen: Mutex<Vec<OutboxRecord>>,
+}
+
+#[async_trait]
+impl OutboxSink for RecordingSink {
+    async fn publish(&self, record: &OutboxRecord) -> Result<(), OutboxError> {
+        if self.down.load(Ordering::SeqCst)
+            || self.poisoned_key.lock().unwrap().as_deref() == Some(record.key.as_str())
+        {
+            return Err(OutboxError::Publish("sink rejected".into()));
+        }
+        self.seen.lock().unwrap().push(record.clone());
+        Ok(())
+    }
+}
+
+async fn client() -> Arc<ScyllaClient> {
+    let contact_point = test_support::containers::scylla_ready(KEYSPACE, MIGRATIONS_DIR).await;
+    let config = ScyllaConfig {
+        contact_points: vec![contact_point],
+        keyspace: None,
+        ..ScyllaConfig::default()
+    };
+    Arc::new(ScyllaSessionBuilder::new(config).build().await.expect("connect"))
+}
+
+/// A fresh keyspace per test, with tables from the canonical DDL, so scenarios
+/// never see each other's rows or leases.
+async fn fresh_table(client: &ScyllaClient) -> ScyllaOutboxTable {
+    let table = ScyllaOutboxTable::new(&format!("it_{}", Uuid::now_v7().simple())).unwrap();
+    let session = client.session.get_session();
+    session
+        .query_unpaged(
+            format!(
+                "CREATE KEYSPACE {} WITH replication = {{'class': 'SimpleStrategy', 'replication_factor': 1}}",
+                table.keyspace()
+            ),
+            &[],
+        )
+        .await
+        .expect("create keyspace");
+    for statement in table.ddl().split(';').map(str::trim).filter(|s| !s.is_empty()) {
+        session.query_unpaged(statement, &[]).await.expect("create outbox tables");
+    }
+    session.await_schema_agreement().await.expect("schema agreement");
+    table
+}
+
+fn relay(
+    client: &Arc<ScyllaClient>,
+    table: &ScyllaOutboxTable,
+    sink: Arc<RecordingSink>,
+    ttl: Duration,
+) -> ScyllaOutboxRelay {
+    let config = RelayConfig {
+        owner: format!("it-{}", Uuid::now_v7().simple()),
+        interval: Duration::from_millis(50),
+        batch_size: 100,
+        lease_ttl: ttl,
+    };
+    ScyllaOutboxRelay::new(Arc::clone(client), table.clone(), sink, config)
+}
+
+fn message(key: &str, seq: u32) -> OutboxMessage {
+    OutboxMessage::new("it.v1.events", key, "it.happened", &serde_json::json!({ "seq": seq }))
+        .unwrap()
+        .with_header("aggregate_id", key)
+}
+
+async fn count(client: &ScyllaClient, cql: String) -> i64 {
+    client
+        .session
+        .get_session()
+        .query_unpaged(cql, &[])
+        .await
+        .unwrap()
+        .into_rows_result()
+        .unwrap()
+        .single_row::<(i64,)>()
+        .unwrap()
+        .0
+}
+
+async fn pending(client: &ScyllaClient, table: &ScyllaOutboxTable) -> i64 {
+    count(client, format!("SELECT count(*) FROM {}", table.outbox())).await
+}
+
+#[tokio::test]
+async fn a_domain_write_and_its_events_land_in_one_logged_batch() {
+    let client = client().await;
+    let table = ScyllaOutboxTable::new(KEYSPACE).unwrap();
+    let outbox = ScyllaOutbox::new(Arc::clone(&client), table.clone());
+    let id = Uuid::now_v7();
+    let key = id.to_string();
+
+    let mut batch = outbox.batch();
+    batch.write(
+        Statement::new("INSERT INTO outbox_it.items (id, label) VALUES (?, ?)"),
+        (id, "first".to_owned()),
+    );
+    batch.enqueue(&[message(&key, 1)]).unwrap();
+    batch.execute().await.expect("batch");
+
+    let items = count(&client, format!("SELECT count(*) FROM outbox_it.items WHERE id = {id}")).await;
+    assert_eq!(items, 1);
+
+    let sink = Arc::new(RecordingSink::default());
+    let relay = relay(&client, &table, sink.clone(), Duration::from_secs(30));
+    assert!(relay.tick().await.expect("drain") >= 1);
+    assert!(sink.seen.lock().unwrap().iter().any(|r| r.key == key));
+}
+
+#[tokio::test]
+async fn re_enqueueing_the_same_message_does_not_duplicate_it() {
+    let client = client().await;
+    let table = fresh_table(&client).await;
+    let outbox = ScyllaOutbox::new(Arc::clone(&client), table.clone());
+    let msg = message("k", 1);
+
+    outbox.enqueue(std::slice::from_ref(&msg)).await.unwrap();
+    outbox.enqueue(std::slice::from_ref(&msg)).await.unwrap();
+    assert_eq!(pending(&client, &table).await, 1, "a retried enqueue overwrites its row");
+}
+
+#[tokio::test]
+async fn relay_publishes_in_key_order_and_blocks_only_the_failing_key() {
+    let client = client().await;
+    let table = fresh_table(&client).await;
+    let outbox = ScyllaOutbox::new(Arc::clone(&client), table.clone());
+    for seq in 1..=3 {
+        outbox.enqueue(&[message("ok", seq)]).await.unwrap();
+        outbox.enqueue(&[message("bad", seq)]).await.unwrap();
+    }
+
+    let sink = Arc::new(RecordingSink::default());
+    *sink.poisoned_key.lock().unwrap() = Some("bad".into());
+    let relay = relay(&client, &table, sink.clone(), Duration::from_secs(30));
+
+    assert_eq!(relay.tick().await.expect("partial progress is progress"), 3);
+    let seqs: Vec<u64> =
+        sink.seen.lock().unwrap().iter().map(|r| r.payload["seq"].as_u64().unwrap()).collect();
+    assert_eq!(seqs, vec![1, 2, 3]);
+
+    // Only the head of the blocked key was attempted, and it carries the failure.
+    let (attempts, error): (Option<i32>, Option<String>) = client
+        .session
+        .get_session()
+        .query_unpaged(
+            format!(
+                "SELECT attempts, last_error FROM {} WHERE slot = {} LIMIT 1",
+                table.outbox(),
+                table.slot_for("bad")
+            ),
+            &[],
+        )
+        .await
+        .unwrap()
+        .into_rows_result()
+        .unwrap()
+        .single_row()
+        .unwrap();
+    assert_eq!(attempts, Some(1));
+    assert!(error.unwrap().contains("sink rejected"));
+    assert_eq!(pending(&client, &table).await, 3);
+
+    // Key recovers: its backlog drains, still in order, and the table empties.
+    *sink.poisoned_key.lock().unwrap() = None;
+    assert_eq!(relay.tick().await.expect("drain"), 3);
+    let bad: Vec<u64> = sink
+        .seen
+        .lock()
+        .unwrap()
+        .iter()
+        .filter(|r| r.key == "bad")
+        .map(|r| r.payload["seq"].as_u64().unwrap())
+        .collect();
+    assert_eq!(bad, vec![1, 2, 3]);
+    assert_eq!(pending(&client, &table).await, 0);
+
+    let headers = sink.seen.lock().unwrap()[0].wire_headers();
+    assert_eq!(headers["event_type"], "it.happened");
+    assert_eq!(headers["aggregate_id"], "ok");
+}
+
+#[tokio::test]
+async fn a_total_outage_surfaces_and_loses_nothing() {
+    let client = client().await;
+    let table = fresh_table(&client).await;
+    let outbox = ScyllaOutbox::new(Arc::clone(&client), table.clone());
+    outbox.enqueue(&[message("a", 1), message("a", 2)]).await.unwrap();
+
+    let sink = Arc::new(RecordingSink::default());
+    sink.down.store(true, Ordering::SeqCst);
+    let relay = relay(&client, &table, sink.clone(), Duration::from_secs(30));
+    assert!(relay.tick().await.is_err(), "a stalled tick must surface");
+    assert_eq!(pending(&client, &table).await, 2);
+
+    sink.down.store(false, Ordering::SeqCst);
+    assert_eq!(relay.tick().await.unwrap(), 2);
+    assert_eq!(pending(&client, &table).await, 0);
+}
+
+async fn owners(client: &ScyllaClient, table: &ScyllaOutboxTable) -> Vec<(String, i64)> {
+    let rows = client
+        .session
+        .get_session()
+        .query_unpaged(format!("SELECT owner FROM {}", table.lease()), &[])
+        .await
+        .unwrap()
+        .into_rows_result()
+        .unwrap();
+    let mut counts = std::collections::BTreeMap::<String, i64>::new();
+    for row in rows.rows::<(String,)>().unwrap() {
+        *counts.entry(row.unwrap().0).or_default() += 1;
+    }
+    counts.into_iter().collect()
+}
+
+#[tokio::test]
+async fn replicas_split_the_slots_and_take_over_expired_leases() {
+    let client = client().await;
+    let table = fresh_table(&client).await;
+    let sink = Arc::new(RecordingSink::default());
+    let a = relay(&client, &table, sink.clone(), Duration::from_secs(30));
+    let b = relay(&client, &table, sink.clone(), Duration::from_secs(1));
+
+    // A alone takes every slot; once B shows up, A sheds down to its share on
+    // its next tick and B picks the released slots up.
+    a.tick().await.unwrap();
+    assert_eq!(owners(&client, &table).await.len(), 1);
+    b.tick().await.unwrap();
+    a.tick().await.unwrap();
+    b.tick().await.unwrap();
+    let split = owners(&client, &table).await;
+    let counts: HashSet<i64> = split.iter().map(|(_, n)| *n).collect();
+    assert_eq!(split.len(), 2, "both replicas hold leases: {split:?}");
+    assert_eq!(counts, HashSet::from([i64::from(table.slots()) / 2]));
+
+    // B dies (stops renewing). Once its lease and membership TTLs lapse, A's
+    // next tick takes over.
+    tokio::time::sleep(Duration::from_millis(1_500)).await;
+    a.tick().await.unwrap();
+    let after = owners(&client, &table).await;
+    assert_eq!(after.len(), 1);
+    assert_eq!(after[0].1, i64::from(table.slots()));
+
+    // Graceful shutdown hands everything back immediately.
+    a.release_leases().await.unwrap();
+    assert!(owners(&client, &table).await.is_empty());
+}
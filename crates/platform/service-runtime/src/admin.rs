@@ -0,0 +1,246 @@
+//! The admin HTTP listener:
+//! * `GET /metrics` — the Prometheus scrape of every instrument registered on the
+//!   global meter: the runtime's RED series, the consumer runners'
+//!   lag/processing/DLQ counters, and anything the service adds;
+//! * `GET /health/config` — the version (etag / content hash) of the live
+//!   infrastructure config, where it came from, and the last rejected push.
+//!
+//! Separate from the gRPC port on purpose: scrapers speak plain HTTP/1.1, and a
+//! NetworkPolicy can admit the monitoring namespace to this port alone. Everything
+//! on it is read-only, since anything that namespace runs can reach it.
+//!
+//! The control listener serves the one mutating route:
+//! * `POST /config/rollback` — re-applies the last good config on this replica.
+//!
+//! It binds loopback only (default `127.0.0.1:9465`), so reaching it takes
+//! `kubectl exec` or `kubectl port-forward` into the pod, and both are gated by
+//! RBAC. A non-loopback address is refused rather than bound.
+
+use std::net::SocketAddr;
+use std::sync::{Arc, OnceLock};
+
+use axum::{
+    extract::State,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing::{get, post},
+    Json, Router,
+};
+use infra_config::{ConfigControl, InfraRegistry};
+use telemetry::metrics::exporter::{metrics_route, PrometheusHandle};
+
+/// The config control, filled once the document has loaded. The listener comes up
+/// first, so until then the config routes answer `503`.
+pub(crate) type ConfigSlot = Arc<OnceLock<Arc<ConfigControl<InfraRegistry>>>>;
+
+/// Environment variable naming the admin listen address; `off` disables it.
+pub(crate) const METRICS_ADDR_ENV: &str = "METRICS_ADDR";
+/// Default admin listen address (the OTel Prometheus exporter's registered port).
+const DEFAULT_METRICS_ADDR: &str = "0.0.0.0:9464";
+/// Environment variable naming the control listen address; `off` disables it.
+pub(crate) const CONTROL_ADDR_ENV: &str = "CONTROL_ADDR";
+/// Default control listen address: loopback, next to the admin port.
+const DEFAULT_CONTROL_ADDR: &str = "127.0.0.1:9465";
+
+/// Resolves the admin address from [`METRICS_ADDR_ENV`].
+pub(crate) fn metrics_addr_from_env() -> Option<SocketAddr> {
+    resolve_metrics_addr(std::env::var(METRICS_ADDR_ENV).ok().as_deref())
+}
+
+/// Pure core of [`metrics_addr_from_env`]: unset → the default, `off` (or empty) →
+/// disabled, unparseable → the default (logged), like the runtime's other dials.
+fn resolve_metrics_addr(raw: Option<&str>) -> Option<SocketAddr> {
+    let default = || DEFAULT_METRICS_ADDR.parse().ok();
+    match raw.map(str::trim) {
+        None => default(),
+        Some("") | Some("off") => None,
+        Some(value) => value.parse().ok().or_else(|| {
+            tracing::warn!(value, "unparseable {METRICS_ADDR_ENV}; using {DEFAULT_METRICS_ADDR}");
+            default()
+        }),
+    }
+}
+
+/// Resolves the control address from [`CONTROL_ADDR_ENV`].
+pub(crate) fn control_addr_from_env() -> Option<SocketAddr> {
+    resolve_control_addr(std::env::var(CONTROL_ADDR_ENV).ok().as_deref())
+}
+
+/// Pure core of [`control_addr_from_env`]: like [`resolve_metrics_addr`], except a
+/// parseable address off loopback disables the listener (logged) — exposing
+/// rollback to the pod network is never the intended outcome.
+fn resolve_control_addr(raw: Option<&str>) -> Option<SocketAddr> {
+    let default = || DEFAULT_CONTROL_ADDR.parse().ok();
+    let addr: SocketAddr = match raw.map(str::trim) {
+        None => default()?,
+        Some("") | Some("off") => return None,
+        Some(value) => match value.parse() {
+            Ok(addr) => addr,
+            Err(_) => {
+                tracing::warn!(value, "unparseable {CONTROL_ADDR_ENV}; using {DEFAULT_CONTROL_ADDR}");
+                default()?
+            }
+        },
+    };
+    if !addr.ip().is_loopback() {
+        tracing::error!(%addr, "{CONTROL_ADDR_ENV} is not a loopback address; the control listener is not served");
+        return None;
+    }
+    Some(addr)
+}
+
+/// Binds the admin listener and serves it in the background. `/metrics` is only
+/// routed when there is a Prometheus exporter to scrape.
+///
+/// A bind failure is logged, not fatal: losing the scrape must not take the
+/// service out of rotation.
+pub(crate) async fn spawn_admin(
+    addr: SocketAddr,
+    handle: Option<Arc<PrometheusHandle>>,
+    config: ConfigSlot,
+) {
+    spawn_listener("admin", addr, router(handle, config)).await;
+}
+
+/// Binds the loopback control listener and serves it in the background. Same
+/// failure policy as [`spawn_admin`].
+pub(crate) async fn spawn_control(addr: SocketAddr, config: ConfigSlot) {
+    spawn_listener("control", addr, control_router(config)).await;
+}
+
+async fn spawn_listener(name: &'static str, addr: SocketAddr, router: Router) {
+    let listener = match tokio::net::TcpListener::bind(addr).await {
+        Ok(listener) => listener,
+        Err(error) => {
+            tracing::error!(%addr, %error, "{name} listener bind failed; its routes are not served");
+            return;
+        }
+    };
+
+    tracing::info!(%addr, "{name} listener serving");
+    tokio::spawn(async move {
+        if let Err(error) = axum::serve(listener, router).await {
+            tracing::error!(%error, "{name} listener terminated");
+        }
+    });
+}
+
+fn router(handle: Option<Arc<PrometheusHandle>>, config: ConfigSlot) -> Router {
+    let router = Router::new().route("/health/config", get(config_status)).with_state(config);
+    match handle {
+        Some(handle) => router.route("/metrics", get(metrics_route(handle))),
+        None => router,
+    }
+}
+
+fn control_router(config: ConfigSlot) -> Router {
+    Router::new()
+        .route("/health/config", get(config_status))
+        .route("/config/rollback", post(config_rollback))
+        .with_state(config)
+}
+
+async fn config_status(State(config): State<ConfigSlot>) -> Response {
+    match config.get() {
+        Some(control) => Json(control.status()).into_response(),
+        None => not_loaded(),
+    }
+}
+
+async fn config_rollback(State(config): State<ConfigSlot>) -> Response {
+    let Some(control) = config.get() else {
+        return not_loaded();
+    };
+    match control.rollback() {
+        Ok(_) => Json(control.status()).into_response(),
+        Err(error) => (StatusCode::CONFLICT, error.to_string()).into_response(),
+    }
+}
+
+fn not_loaded() -> Response {
+    (StatusCode::SERVICE_UNAVAILABLE, "infrastructure config not loaded yet").into_response()
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::body::Body;
+    use infra_config::{ConfigDocument, InfrastructureConfig};
+    use tower::ServiceExt;
+
+    use super::*;
+
+    const CONFIG: &str = r#"
+[resilience]
+[resilience.profiles.standard]
+timeout = { duration_ms = 1000 }
+circuit_breaker = { failure_threshold = 5, success_threshold = 2, open_duration_ms = 30000, half_open_max_calls = 1 }
+retry = { max_attempts = 3, backoff = { kind = "exponential", base_ms = 50, max_ms = 10000, jitter = "full" } }
+"#;
+
+    async fn send(router: &Router, method: &str, uri: &str) -> StatusCode {
+        let request = axum::http::Request::builder().method(method).uri(uri);
+        let response = router.clone().oneshot(request.body(Body::empty()).unwrap()).await.unwrap();
+        response.status()
+    }
+
+    #[test]
+    fn unset_binds_the_default_port() {
+        assert_eq!(resolve_metrics_addr(None), Some("0.0.0.0:9464".parse().unwrap()));
+    }
+
+    #[test]
+    fn off_or_empty_disables_the_listener() {
+        assert_eq!(resolve_metrics_addr(Some("off")), None);
+        assert_eq!(resolve_metrics_addr(Some("")), None);
+    }
+
+    #[test]
+    fn an_override_is_honoured_and_garbage_falls_back() {
+        assert_eq!(
+            resolve_metrics_addr(Some("127.0.0.1:9100")),
+            Some("127.0.0.1:9100".parse().unwrap())
+        );
+        assert_eq!(
+            resolve_metrics_addr(Some("not-an-addr")),
+            Some("0.0.0.0:9464".parse().unwrap())
+        );
+    }
+
+    #[test]
+    fn control_defaults_to_loopback_and_refuses_anything_else() {
+        assert_eq!(resolve_control_addr(None), Some("127.0.0.1:9465".parse().unwrap()));
+        assert_eq!(resolve_control_addr(Some("off")), None);
+        assert_eq!(resolve_control_addr(Some("[::1]:9000")), Some("[::1]:9000".parse().unwrap()));
+        assert_eq!(resolve_control_addr(Some("0.0.0.0:9465")), None);
+        assert_eq!(resolve_control_addr(Some("10.0.0.7:9465")), None);
+    }
+
+    #[tokio::test]
+    async fn the_admin_port_does_not_serve_rollback() {
+        let slot: ConfigSlot = Arc::new(OnceLock::new());
+        let router = router(None, slot);
+        assert_eq!(send(&router, "POST", "/config/rollback").await, StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn config_routes_answer_once_the_config_has_loaded() {
+        let slot: ConfigSlot = Arc::new(OnceLock::new());
+        let admin = router(None, Arc::clone(&slot));
+        let control = control_router(Arc::clone(&slot));
+        assert_eq!(send(&admin, "GET", "/health/config").await, StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(send(&admin, "GET", "/metrics").await, StatusCode::NOT_FOUND);
+        assert_eq!(send(&control, "POST", "/config/rollback").await, StatusCode::SERVICE_UNAVAILABLE);
+
+        let registry =
+            InfraRegistry::from_config(InfrastructureConfig::from_toml(CONFIG).unwrap()).unwrap();
+        let document = ConfigDocument::from_content(CONFIG.to_owned());
+        let _ = slot.set(Arc::new(ConfigControl::booted(Arc::new(registry), "test".into(), document)));
+
+        assert_eq!(send(&control, "GET", "/health/config").await, StatusCode::OK);
+        assert_eq!(
+            send(&control, "POST", "/config/rollback").await,
+            StatusCode::CONFLICT,
+            "nothing to roll back to right after boot"
+        );
+    }
+}
@@ -0,0 +1,916 @@
+//! Fleet-wide inbound authentication and per-RPC authorization.
+//!
+//! [`AuthLayer`] is the Tower layer [`serve`](crate::serve) installs innermost on
+//! every gRPC request (inside trace and traffic, so denials are traced and floods
+//! are shed before any crypto runs). Per request it:
+//!
+//! 1. admits the runtime-owned surfaces — `grpc.health.v1.Health` and server
+//!    reflection — unconditionally;
+//! 2. resolves the method against the service's [`AccessPolicy`]; an **unlisted
+//!    method is denied** (`PERMISSION_DENIED`), so a new RPC is dark until someone
+//!    decides who may call it;
+//! 3. for [`Access::Authenticated`] / [`Access::Permission`], verifies the
+//!    `authorization: Bearer <edge token>` with `auth-context` (`UNAUTHENTICATED`
+//!    on a missing or invalid token), checks the permission (`PERMISSION_DENIED`),
+//!    and runs the handler inside [`with_principal`] so
+//!    [`current_principal`](auth_context::current_principal) is the verified caller.
+//! 4. for [`Access::StepUp`], additionally demands a *recent strong*
+//!    authentication: the token must assert `acr = aal2` (a second factor was
+//!    presented) with an `auth_time` inside the rule's window, else
+//!    `UNAUTHENTICATED` with a `step-up authentication required` message — the
+//!    client's cue to call auth's `StepUp` and retry with the fresh token.
+//!
+//! # Shadow mode
+//!
+//! With `[auth] enforce = false` every denial is logged and counted but the call is
+//! admitted (still under the principal, when one was verified). The flag
+//! hot-reloads, so a rollout pilots in shadow, watches the `shadow` series, then
+//! flips to enforcing with no redeploy.
+//!
+//! # Observability
+//!
+//! Every denial (enforced or shadowed) increments `infra_auth_denied` — surfaced
+//! by the Prometheus exporter as `infra_auth_denied_total` — labelled by `route`,
+//! `reason`, and `status` (`enforced` | `shadow`). Unlisted methods collapse to a
+//! single `<unlisted>` route so arbitrary paths can't inflate cardinality. The
+//! token itself is never logged.
+//!
+//! # Internal RPCs
+//!
+//! [`Access::Internal`] marks peer-to-peer calls (e.g. `search` hydrating through
+//! `post.GetPost`). Peers don't forward the end user's token; they prove who *they*
+//! are instead, with the projected ServiceAccount token transport's
+//! `PeerTokenLayer` attaches as `x-peer-token`. The layer verifies it against the
+//! `[auth.peers]` issuer — audience, expiry, and the fleet's namespace — and runs
+//! the handler inside [`with_peer`] so [`current_peer`](auth_context::current_peer)
+//! names the calling service. A missing or unverifiable peer token is
+//! `UNAUTHENTICATED`: absence of the edge header proves nothing, since anything
+//! in the cluster can omit it. Without `[auth.peers]` no peer can authenticate and
+//! every internal call is denied. A call arriving **through the edge** (the
+//! edge-injected identity header, default `x-edge-user`) is denied outright.
+//! [`Access::InternalOrAuthenticated`] covers reads both peers and end users make
+//! (e.g. `GetPost`): a call presenting a peer token (and no edge header) is
+//! admitted as above, any other call must carry a valid edge token.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use auth_context::{
+    with_peer, with_principal, AuthContextConfig, CurrentPrincipal, JwksCache, JwksClient,
+    JwksRefresher, JwtDecoder, OidcClaims, OidcClaimsExtractor, OidcExtractorConfig, RoleSource,
+};
+use futures::future::BoxFuture;
+use http::header::{HeaderName, AUTHORIZATION};
+use http::HeaderMap;
+use infra_config::{AuthRegistry, PeerAuthSection};
+use jsonwebtoken::Algorithm;
+use opentelemetry::{global, metrics::Counter, KeyValue};
+use tonic::{body::Body, Status};
+use tower::{Layer, Service};
+use transport::grpc::layer::PEER_TOKEN_HEADER;
+
+/// Instrument name; the Prometheus exporter surfaces it as `infra_auth_denied_total`.
+const DENIED_METRIC: &str = "infra_auth_denied";
+
+/// Route label for methods absent from the policy — bounds metric cardinality.
+const UNLISTED_ROUTE: &str = "<unlisted>";
+
+/// Path prefixes of the runtime-owned surfaces that are always admitted.
+const RUNTIME_OWNED_PREFIXES: [&str; 2] = ["/grpc.health.v1.Health/", "/grpc.reflection."];
+
+/// Claim carrying the permission set on the edge token minted by `auth`.
+const EDGE_PERMISSIONS_CLAIM: &str = "perms";
+
+/// Assurance level `auth` mints once a second factor was verified (NIST AAL2).
+pub const STEP_UP_ACR: &str = "aal2";
+
+/// Who may call a method.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Access {
+    /// Anyone, no token required (e.g. `Login`).
+    Public,
+    /// Peer services presenting a valid peer token; denied through the edge.
+    Internal,
+    /// Peer services presenting a valid peer token, or callers presenting a valid
+    /// edge token.
+    InternalOrAuthenticated,
+    /// Any caller presenting a valid edge token.
+    Authenticated,
+    /// A valid edge token carrying this permission (e.g. `"moderation:decide"`).
+    Permission(&'static str),
+    /// A valid edge token asserting [`STEP_UP_ACR`] from an authentication no
+    /// older than the window (e.g. `ChangePassword`).
+    StepUp(Duration),
+}
+
+/// A service's declarative per-method access table.
+///
+/// Keys are full gRPC paths (`/moderation.v1.ModerationService/DecideCase`); the
+/// builder methods take bare method names and prefix the service name given to
+/// [`for_service`](Self::for_service). Methods not listed are denied.
+#[derive(Debug, Clone, Default)]
+pub struct AccessPolicy {
+    service: String,
+    rules: HashMap<String, Access>,
+}
+
+impl AccessPolicy {
+    /// An empty policy for `grpc_service` (conventionally `Self::GRPC_SERVICE_NAME`).
+    pub fn for_service(grpc_service: &str) -> Self {
+        Self { service: grpc_service.to_owned(), rules: HashMap::new() }
+    }
+
+    /// Grants `method` to anyone.
+    pub fn public(self, method: &str) -> Self {
+        self.rule(method, Access::Public)
+    }
+
+    /// Grants `method` to peer services only.
+    pub fn internal(self, method: &str) -> Self {
+        self.rule(method, Access::Internal)
+    }
+
+    /// Grants `method` to peer services, and to edge callers once authenticated.
+    pub fn internal_or_authenticated(self, method: &str) -> Self {
+        self.rule(method, Access::InternalOrAuthenticated)
+    }
+
+    /// Grants `method` to any authenticated caller.
+    pub fn authenticated(self, method: &str) -> Self {
+        self.rule(method, Access::Authenticated)
+    }
+
+    /// Grants `method` to authenticated callers holding `permission`.
+    pub fn require(self, method: &str, permission: &'static str) -> Self {
+        self.rule(method, Access::Permission(permission))
+    }
+
+    /// Grants `method` to callers who completed a second factor within `max_age`.
+    pub fn step_up(self, method: &str, max_age: Duration) -> Self {
+        self.rule(method, Access::StepUp(max_age))
+    }
+
+    /// The rule for a full gRPC `path`, if listed.
+    pub fn access_for(&self, path: &str) -> Option<&Access> {
+        self.rules.get(path)
+    }
+
+    fn rule(mut self, method: &str, access: Access) -> Self {
+        self.rules.insert(format!("/{}/{method}", self.service), access);
+        self
+    }
+}
+
+/// Why a call was denied — the `reason` metric label.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Denial {
+    Unlisted,
+    InternalFromEdge,
+    MissingPeerToken,
+    InvalidPeerToken,
+    MissingToken,
+    InvalidToken,
+    MissingPermission,
+    StepUpRequired,
+}
+
+impl Denial {
+    fn label(self) -> &'static str {
+        match self {
+            Denial::Unlisted => "unlisted",
+            Denial::InternalFromEdge => "internal_from_edge",
+            Denial::MissingPeerToken => "missing_peer_token",
+            Denial::InvalidPeerToken => "invalid_peer_token",
+            Denial::MissingToken => "missing_token",
+            Denial::InvalidToken => "invalid_token",
+            Denial::MissingPermission => "missing_permission",
+            Denial::StepUpRequired => "step_up_required",
+        }
+    }
+
+    fn status(self) -> Status {
+        match self {
+            Denial::MissingToken => Status::unauthenticated("missing bearer token"),
+            Denial::InvalidToken => Status::unauthenticated("invalid bearer token"),
+            Denial::MissingPeerToken => Status::unauthenticated("missing peer token"),
+            Denial::InvalidPeerToken => Status::unauthenticated("invalid peer token"),
+            Denial::Unlisted | Denial::InternalFromEdge => {
+                Status::permission_denied("method is not exposed to this caller")
+            }
+            Denial::MissingPermission => Status::permission_denied("missing required permission"),
+            Denial::StepUpRequired => Status::unauthenticated("step-up authentication required"),
+        }
+    }
+}
+
+type EdgeDecoder = JwtDecoder<OidcClaims, OidcClaimsExtractor>;
+
+/// Builds the edge-token decoder from the `[auth]` boot values and starts its JWKS
+/// refresher. The refresher is detached (dropping the handle keeps it running); a
+/// cold start does not need the JWKS endpoint — verification fails closed until the
+/// first successful fetch.
+fn build_decoder(registry: &AuthRegistry) -> Arc<EdgeDecoder> {
+    let config = AuthContextConfig {
+        jwks_url: registry.jwks_url().to_owned(),
+        expected_issuer: registry.issuer().map(str::to_owned),
+        expected_audience: registry.audience().map(str::to_owned),
+        ..AuthContextConfig::default()
+    };
+    let cache = JwksCache::new();
+    let client = JwksClient::new(config.jwks_url.clone(), config.fetch_timeout);
+    let _refresher =
+        JwksRefresher::spawn(client, cache.clone(), config.refresh_interval, config.max_backoff);
+    Arc::new(edge_decoder(&config, cache, vec![Algorithm::ES256, Algorithm::RS256]))
+}
+
+/// The decoder over `cache`, harvesting permissions from the edge token's `perms`
+/// claim on top of the standard OIDC sources.
+fn edge_decoder(
+    config: &AuthContextConfig,
+    cache: JwksCache,
+    algorithms: Vec<Algorithm>,
+) -> EdgeDecoder {
+    let mut extractor = OidcExtractorConfig::default();
+    extractor.role_sources.push(RoleSource::Custom(EDGE_PERMISSIONS_CLAIM.to_owned()));
+    JwtDecoder::with_algorithms(config, cache, OidcClaimsExtractor::new(extractor), algorithms)
+}
+
+/// Prefix of the `sub` claim on a Kubernetes ServiceAccount token:
+/// `system:serviceaccount:<namespace>:<name>`.
+const SERVICE_ACCOUNT_SUBJECT: &str = "system:serviceaccount:";
+
+/// Verifies peer tokens and maps them to the calling service's name.
+struct PeerVerifier {
+    decoder: EdgeDecoder,
+    namespace: String,
+    account_prefix: String,
+}
+
+impl PeerVerifier {
+    fn new(section: &PeerAuthSection, decoder: EdgeDecoder) -> Self {
+        Self {
+            decoder,
+            namespace: section.namespace.clone(),
+            account_prefix: section.account_prefix.clone(),
+        }
+    }
+
+    /// The calling service's name, once `token` verifies and names a ServiceAccount
+    /// in the fleet's namespace.
+    async fn service(&self, token: &str) -> Option<Arc<str>> {
+        let principal = match self.decoder.decode(token).await {
+            Ok(principal) => principal,
+            Err(error) => {
+                tracing::debug!(%error, "auth: peer token rejected");
+                return None;
+            }
+        };
+        self.service_of(&principal.raw_claims.sub)
+    }
+
+    fn service_of(&self, subject: &str) -> Option<Arc<str>> {
+        let (namespace, account) = subject.strip_prefix(SERVICE_ACCOUNT_SUBJECT)?.split_once(':')?;
+        if namespace != self.namespace {
+            tracing::debug!(subject, "auth: peer token from outside the fleet namespace");
+            return None;
+        }
+        let service = account.strip_prefix(self.account_prefix.as_str()).unwrap_or(account);
+        (!service.is_empty()).then(|| Arc::from(service))
+    }
+}
+
+/// Builds the peer-token verifier from `[auth.peers]` and starts its JWKS refresher.
+/// `None` (logged) when the section is absent or the in-cluster issuer's credentials
+/// are missing — internal calls are then denied.
+fn build_peer_verifier(registry: &AuthRegistry) -> Option<PeerVerifier> {
+    let Some(section) = registry.peers() else {
+        tracing::warn!("no [auth.peers] section — internal RPCs cannot authenticate and are denied");
+        return None;
+    };
+    let mut config = AuthContextConfig {
+        expected_issuer: section.issuer.clone(),
+        expected_audience: Some(section.audience.clone()),
+        ..AuthContextConfig::default()
+    };
+    let client = match &section.jwks_url {
+        Some(url) => {
+            config.jwks_url = url.clone();
+            JwksClient::new(url.clone(), config.fetch_timeout)
+        }
+        None => match JwksClient::in_cluster(config.fetch_timeout) {
+            Ok(client) => client,
+            Err(error) => {
+                tracing::error!(%error, "no in-cluster issuer for [auth.peers] — internal RPCs are denied");
+                return None;
+            }
+        },
+    };
+    let cache = JwksCache::new();
+    let _refresher =
+        JwksRefresher::spawn(client, cache.clone(), config.refresh_interval, config.max_backoff);
+    let decoder = edge_decoder(&config, cache, vec![Algorithm::RS256, Algorithm::ES256]);
+    Some(PeerVerifier::new(section, decoder))
+}
+
+fn denied_counter() -> Counter<u64> {
+    global::meter("service-runtime")
+        .u64_counter(DENIED_METRIC)
+        .with_description(
+            "Requests denied by the inbound auth layer, labelled by route, reason, and \
+             status (enforced|shadow).",
+        )
+        .build()
+}
+
+struct Gate {
+    registry: Arc<AuthRegistry>,
+    decoder: Arc<EdgeDecoder>,
+    peers: Option<PeerVerifier>,
+    policy: AccessPolicy,
+    identity_header: HeaderName,
+    counter: Counter<u64>,
+}
+
+/// Tower [`Layer`] enforcing a service's [`AccessPolicy`] on inbound gRPC requests.
+///
+/// Holds an `Option`: when `None` (no `[auth]` section configured) the layer is a
+/// transparent pass-through, so the server's type is identical either way.
+#[derive(Clone)]
+pub struct AuthLayer {
+    gate: Option<Arc<Gate>>,
+}
+
+impl AuthLayer {
+    /// A pass-through layer (no authentication). Used when no `[auth]` section is configured.
+    pub fn disabled() -> Self {
+        Self { gate: None }
+    }
+
+    /// A layer verifying edge tokens with `decoder`, peer tokens with `peers`, and
+    /// enforcing `policy`, in the enforce/shadow mode `registry` currently holds.
+    /// `identity_header` is the edge-injected header that marks a call as having
+    /// come through the edge.
+    fn new(
+        registry: Arc<AuthRegistry>,
+        decoder: Arc<EdgeDecoder>,
+        peers: Option<PeerVerifier>,
+        policy: AccessPolicy,
+        identity_header: HeaderName,
+    ) -> Self {
+        Self {
+            gate: Some(Arc::new(Gate {
+                registry,
+                decoder,
+                peers,
+                policy,
+                identity_header,
+                counter: denied_counter(),
+            })),
+        }
+    }
+
+    /// The layer for a configured `[auth]` section: builds the JWKS-backed edge and
+    /// peer decoders.
+    pub(crate) fn from_registry(
+        registry: Arc<AuthRegistry>,
+        policy: AccessPolicy,
+        identity_header: HeaderName,
+    ) -> Self {
+        let decoder = build_decoder(&registry);
+        let peers = build_peer_verifier(&registry);
+        Self::new(registry, decoder, peers, policy, identity_header)
+    }
+}
+
+impl<S> Layer<S> for AuthLayer {
+    type Service = AuthService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        AuthService { inner, gate: self.gate.clone() }
+    }
+}
+
+/// The concrete service produced by [`AuthLayer`].
+#[derive(Clone)]
+pub struct AuthService<S> {
+    inner: S,
+    gate: Option<Arc<Gate>>,
+}
+
+impl<S> Service<http::Request<Body>> for AuthService<S>
+where
+    S: Service<http::Request<Body>, Response = http::Response<Body>> + Clone + Send + 'static,
+    S::Future: Send + 'static,
+    S::Error: Send + 'static,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = BoxFuture<'static, Result<S::Response, S::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, req: http::Request<Body>) -> Self::Future {
+        let Some(gate) = self.gate.clone() else {
+            return Box::pin(self.inner.call(req));
+        };
+
+        let path = req.uri().path();
+        if RUNTIME_OWNED_PREFIXES.iter().any(|prefix| path.starts_with(prefix)) {
+            return Box::pin(self.inner.call(req));
+        }
+
+        let access = gate.policy.access_for(path).cloned();
+        let route = if access.is_some() { path.to_owned() } else { UNLISTED_ROUTE.to_owned() };
+        let method = path.to_owned();
+
+        let (required, step_up) = match access {
+            None => {
+                return admit_or_deny(&gate, Denial::Unlisted, &route, &method, &mut self.inner, req);
+            }
+            Some(Access::Public) => return Box::pin(self.inner.call(req)),
+            Some(Access::Internal) => {
+                if req.headers().contains_key(&gate.identity_header) {
+                    return admit_or_deny(
+                        &gate,
+                        Denial::InternalFromEdge,
+                        &route,
+                        &method,
+                        &mut self.inner,
+                        req,
+                    );
+                }
+                return self.call_as_peer(gate, route, method, req);
+            }
+            Some(Access::InternalOrAuthenticated) => {
+                if !req.headers().contains_key(&gate.identity_header)
+                    && req.headers().contains_key(PEER_TOKEN_HEADER)
+                {
+                    return self.call_as_peer(gate, route, method, req);
+                }
+                (None, None)
+            }
+            Some(Access::Authenticated) => (None, None),
+            Some(Access::Permission(permission)) => (Some(permission), None),
+            Some(Access::StepUp(max_age)) => (None, Some(max_age)),
+        };
+
+        let Some(token) = bearer(req.headers()).map(str::to_owned) else {
+            return admit_or_deny(&gate, Denial::MissingToken, &route, &method, &mut self.inner, req);
+        };
+
+        // Move a ready clone of the inner service into the future (tower readiness idiom).
+        let clone = self.inner.clone();
+        let mut inner = std::mem::replace(&mut self.inner, clone);
+
+        Box::pin(async move {
+            let principal = match gate.decoder.decode(&token).await {
+                Ok(principal) => principal,
+                Err(error) => {
+                    tracing::debug!(rpc.method = %method, %error, "auth: token rejected");
+                    if gate.deny(Denial::InvalidToken, &route, &method) {
+                        return Ok(Denial::InvalidToken.status().into_http());
+                    }
+                    return inner.call(req).await;
+                }
+            };
+
+            if let Some(permission) = required
+                && !principal.has_permission(permission)
+                && gate.deny(Denial::MissingPermission, &route, &method)
+            {
+                return Ok(Denial::MissingPermission.status().into_http());
+            }
+
+            if let Some(max_age) = step_up
+                && !recently_stepped_up(&principal.raw_claims, max_age)
+                && gate.deny(Denial::StepUpRequired, &route, &method)
+            {
+                return Ok(Denial::StepUpRequired.status().into_http());
+            }
+
+            call_as(principal, inner, req).await
+        })
+    }
+}
+
+impl<S> AuthService<S>
+where
+    S: Service<http::Request<Body>, Response = http::Response<Body>> + Clone + Send + 'static,
+    S::Future: Send + 'static,
+    S::Error: Send + 'static,
+{
+    /// Admits `req` once its peer token verifies, running the handler inside
+    /// [`with_peer`]; denies a missing or unverifiable one.
+    fn call_as_peer(
+        &mut self,
+        gate: Arc<Gate>,
+        route: String,
+        method: String,
+        req: http::Request<Body>,
+    ) -> BoxFuture<'static, Result<S::Response, S::Error>> {
+        let Some(token) = peer_token(req.headers()).map(str::to_owned) else {
+            return admit_or_deny(&gate, Denial::MissingPeerToken, &route, &method, &mut self.inner, req);
+        };
+
+        let clone = self.inner.clone();
+        let mut inner = std::mem::replace(&mut self.inner, clone);
+
+        Box::pin(async move {
+            let service = match &gate.peers {
+                Some(peers) => peers.service(&token).await,
+                None => None,
+            };
+            match service {
+                Some(service) => with_peer(service, inner.call(req)).await,
+                None if gate.deny(Denial::InvalidPeerToken, &route, &method) => {
+                    Ok(Denial::InvalidPeerToken.status().into_http())
+                }
+                None => inner.call(req).await,
+            }
+        })
+    }
+}
+
+/// Whether `claims` assert [`STEP_UP_ACR`] from an authentication within `max_age`.
+fn recently_stepped_up(claims: &OidcClaims, max_age: Duration) -> bool {
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs() as i64);
+    claims.authenticated_within(STEP_UP_ACR, max_age.as_secs() as i64, now)
+}
+
+/// Runs the inner call with `principal` bound as the task-local caller identity.
+async fn call_as<S>(
+    principal: CurrentPrincipal<OidcClaims>,
+    mut inner: S,
+    req: http::Request<Body>,
+) -> Result<S::Response, S::Error>
+where
+    S: Service<http::Request<Body>>,
+{
+    with_principal(Arc::new(principal), inner.call(req)).await
+}
+
+/// Records a synchronous denial; short-circuits when enforcing, otherwise forwards.
+fn admit_or_deny<S>(
+    gate: &Gate,
+    denial: Denial,
+    route: &str,
+    method: &str,
+    inner: &mut S,
+    req: http::Request<Body>,
+) -> BoxFuture<'static, Result<S::Response, S::Error>>
+where
+    S: Service<http::Request<Body>, Response = http::Response<Body>>,
+    S::Future: Send + 'static,
+{
+    if gate.deny(denial, route, method) {
+        let response = denial.status().into_http();
+        return Box::pin(async move { Ok(response) });
+    }
+    Box::pin(inner.call(req))
+}
+
+impl Gate {
+    /// Records `denial` and returns whether it is enforced (`false` = shadow, admit).
+    fn deny(&self, denial: Denial, route: &str, method: &str) -> bool {
+        let enforce = self.registry.enforce();
+        self.counter.add(1, &denied_attrs(route, denial, enforce));
+        if enforce {
+            tracing::debug!(rpc.method = %method, reason = denial.label(), "auth: request denied");
+        } else {
+            tracing::info!(
+                rpc.method = %method,
+                reason = denial.label(),
+                "auth: would deny (shadow mode — admitted)"
+            );
+        }
+        enforce
+    }
+}
+
+fn denied_attrs(route: &str, denial: Denial, enforce: bool) -> [KeyValue; 3] {
+    [
+        KeyValue::new("route", route.to_owned()),
+        KeyValue::new("reason", denial.label()),
+        KeyValue::new("status", if enforce { "enforced" } else { "shadow" }),
+    ]
+}
+
+/// The token from an `authorization: Bearer <token>` header (scheme
+/// case-insensitive); `None` when absent, non-ASCII, another scheme, or empty.
+fn bearer(headers: &HeaderMap) -> Option<&str> {
+    let value = headers.get(AUTHORIZATION)?.to_str().ok()?;
+    let (scheme, token) = value.split_once(' ')?;
+    let token = token.trim();
+    (scheme.eq_ignore_ascii_case("bearer") && !token.is_empty()).then_some(token)
+}
+
+/// The token from the [`PEER_TOKEN_HEADER`]; `None` when absent, non-ASCII, or empty.
+fn peer_token(headers: &HeaderMap) -> Option<&str> {
+    let token = headers.get(PEER_TOKEN_HEADER)?.to_str().ok()?.trim();
+    (!token.is_empty()).then_some(token)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap as Map;
+    use std::convert::Infallible;
+
+    use auth_context::{current_peer, current_principal};
+    use infra_config::AuthSection;
+    use jsonwebtoken::{encode, DecodingKey, EncodingKey, Header};
+    use tower::ServiceExt;
+
+    const KID: &str = "test-hs256";
+    const SECRET: &[u8] = b"service-runtime-auth-layer-test-secret";
+    const SERVICE: &str = "moderation.v1.ModerationService";
+
+    fn policy() -> AccessPolicy {
+        AccessPolicy::for_service(SERVICE)
+            .internal("Screen")
+            .internal_or_authenticated("GetEnforcementState")
+            .authenticated("FileAppeal")
+            .require("DecideCase", "moderation:decide")
+            .public("GetStatementOfReasons")
+            .step_up("LiftSanction", Duration::from_secs(300))
+    }
+
+    async fn layer(enforce: bool) -> AuthLayer {
+        let registry = AuthRegistry::from_section(AuthSection {
+            enforce,
+            jwks_url: "http://unused.invalid/jwks.json".into(),
+            issuer: None,
+            audience: None,
+            peers: None,
+        })
+        .unwrap();
+        let cache = JwksCache::new();
+        let mut keys = Map::new();
+        keys.insert(KID.to_owned(), DecodingKey::from_secret(SECRET));
+        cache.replace(keys).await;
+        let decoder =
+            edge_decoder(&AuthContextConfig::default(), cache.clone(), vec![Algorithm::HS256]);
+        let peer_config =
+            AuthContextConfig { expected_audience: Some(PEER_AUDIENCE.into()), ..Default::default() };
+        let peers = PeerVerifier::new(
+            &PeerAuthSection {
+                jwks_url: None,
+                issuer: None,
+                audience: PEER_AUDIENCE.into(),
+                namespace: "default".into(),
+                account_prefix: "prod-".into(),
+            },
+            edge_decoder(&peer_config, cache, vec![Algorithm::HS256]),
+        );
+        AuthLayer::new(
+            Arc::new(registry),
+            Arc::new(decoder),
+            Some(peers),
+            policy(),
+            HeaderName::from_static("x-edge-user"),
+        )
+    }
+
+    const PEER_AUDIENCE: &str = "core-platform-peers";
+
+    /// A projected ServiceAccount token for `subject`, minted for `audience`.
+    fn peer_token_for(subject: &str, audience: &str) -> String {
+        sign(serde_json::json!({ "sub": subject, "aud": audience, "exp": unix_now() + 600 }))
+    }
+
+    fn search_peer() -> String {
+        peer_token_for("system:serviceaccount:default:prod-search-server", PEER_AUDIENCE)
+    }
+
+    fn token(perms: &[&str]) -> String {
+        sign(serde_json::json!({ "sub": "acct-1", "exp": unix_now() + 600, "perms": perms }))
+    }
+
+    /// A token asserting `acr` from an authentication `age_secs` ago.
+    fn stepped_up_token(acr: &str, age_secs: u64) -> String {
+        let now = unix_now();
+        sign(serde_json::json!({
+            "sub": "acct-1", "exp": now + 600, "acr": acr, "auth_time": now - age_secs,
+        }))
+    }
+
+    fn sign(claims: serde_json::Value) -> String {
+        let mut header = Header::new(Algorithm::HS256);
+        header.kid = Some(KID.to_owned());
+        encode(&header, &claims, &EncodingKey::from_secret(SECRET)).unwrap()
+    }
+
+    fn unix_now() -> u64 {
+        SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs()
+    }
+
+    fn request(method: &str, headers: &[(&str, &str)]) -> http::Request<Body> {
+        let mut builder = http::Request::builder().uri(format!("http://svc/{SERVICE}/{method}"));
+        for (name, value) in headers {
+            builder = builder.header(*name, *value);
+        }
+        builder.body(Body::empty()).unwrap()
+    }
+
+    /// Calls through the layer; returns the `grpc-status` the layer set (`None` = the
+    /// inner handler ran) and the principal the handler observed.
+    async fn call(layer: &AuthLayer, req: http::Request<Body>) -> (Option<String>, Option<String>) {
+        let seen = Arc::new(std::sync::Mutex::new(None));
+        let observed = Arc::clone(&seen);
+        let inner = tower::service_fn(move |_req: http::Request<Body>| {
+            let observed = Arc::clone(&observed);
+            async move {
+                *observed.lock().unwrap() =
+                    Some(current_principal().map(|p| p.user_id().as_str().to_owned()));
+                Ok::<_, Infallible>(http::Response::new(Body::empty()))
+            }
+        });
+        let response = layer.layer(inner).oneshot(req).await.unwrap();
+        let status = response
+            .headers()
+            .get("grpc-status")
+            .map(|v| v.to_str().unwrap().to_owned());
+        let principal = seen.lock().unwrap().clone().flatten();
+        (status, principal)
+    }
+
+    /// Calls through the layer; returns the `grpc-status` and the peer service the
+    /// handler observed.
+    async fn call_peer(layer: &AuthLayer, req: http::Request<Body>) -> (Option<String>, Option<String>) {
+        let seen = Arc::new(std::sync::Mutex::new(None));
+        let observed = Arc::clone(&seen);
+        let inner = tower::service_fn(move |_req: http::Request<Body>| {
+            let observed = Arc::clone(&observed);
+            async move {
+                *observed.lock().unwrap() = current_peer().map(|peer| peer.to_string());
+                Ok::<_, Infallible>(http::Response::new(Body::empty()))
+            }
+        });
+        let response = layer.layer(inner).oneshot(req).await.unwrap();
+        let status = response
+            .headers()
+            .get("grpc-status")
+            .map(|v| v.to_str().unwrap().to_owned());
+        let peer = seen.lock().unwrap().clone();
+        (status, peer)
+    }
+
+    const UNAUTHENTICATED: &str = "16";
+    const PERMISSION_DENIED: &str = "7";
+
+    #[tokio::test]
+    async fn permission_rule_admits_a_holder_and_installs_the_principal() {
+        let layer = layer(true).await;
+        let auth = format!("Bearer {}", token(&["moderation:decide"]));
+        let (status, principal) = call(&layer, request("DecideCase", &[("authorization", &auth)])).await;
+        assert_eq!(status, None);
+        assert_eq!(principal.as_deref(), Some("acct-1"));
+    }
+
+    #[tokio::test]
+    async fn permission_rule_denies_a_caller_without_it() {
+        let layer = layer(true).await;
+        let auth = format!("Bearer {}", token(&["posts:write"]));
+        let (status, _) = call(&layer, request("DecideCase", &[("authorization", &auth)])).await;
+        assert_eq!(status.as_deref(), Some(PERMISSION_DENIED));
+    }
+
+    #[tokio::test]
+    async fn missing_or_invalid_token_is_unauthenticated() {
+        let layer = layer(true).await;
+        let (status, _) = call(&layer, request("FileAppeal", &[])).await;
+        assert_eq!(status.as_deref(), Some(UNAUTHENTICATED));
+
+        let (status, _) =
+            call(&layer, request("FileAppeal", &[("authorization", "Bearer not.a.jwt")])).await;
+        assert_eq!(status.as_deref(), Some(UNAUTHENTICATED));
+    }
+
+    #[tokio::test]
+    async fn unlisted_methods_are_denied_by_default() {
+        let layer = layer(true).await;
+        let auth = format!("Bearer {}", token(&["moderation:decide"]));
+        let (status, _) = call(&layer, request("AssignCase", &[("authorization", &auth)])).await;
+        assert_eq!(status.as_deref(), Some(PERMISSION_DENIED));
+    }
+
+    #[tokio::test]
+    async fn public_and_runtime_owned_routes_need_no_token() {
+        let layer = layer(true).await;
+        let (status, principal) = call(&layer, request("GetStatementOfReasons", &[])).await;
+        assert_eq!((status, principal), (None, None));
+
+        let health = http::Request::builder()
+            .uri("http://svc/grpc.health.v1.Health/Check")
+            .body(Body::empty())
+            .unwrap();
+        assert_eq!(call(&layer, health).await.0, None);
+    }
+
+    #[tokio::test]
+    async fn internal_rules_admit_authenticated_peers_as_their_service() {
+        let layer = layer(true).await;
+        let token = search_peer();
+        let (status, peer) = call_peer(&layer, request("Screen", &[("x-peer-token", &token)])).await;
+        assert_eq!(status, None);
+        assert_eq!(peer.as_deref(), Some("search-server"));
+    }
+
+    #[tokio::test]
+    async fn internal_rules_deny_callers_without_a_valid_peer_token() {
+        let layer = layer(true).await;
+        let (status, _) = call(&layer, request("Screen", &[])).await;
+        assert_eq!(status.as_deref(), Some(UNAUTHENTICATED), "no header is not proof of a peer");
+
+        for token in [
+            "not.a.jwt".to_owned(),
+            peer_token_for("system:serviceaccount:default:prod-search-server", "elsewhere"),
+            peer_token_for("system:serviceaccount:sandbox:prod-search-server", PEER_AUDIENCE),
+            peer_token_for("acct-1", PEER_AUDIENCE),
+        ] {
+            let (status, _) = call(&layer, request("Screen", &[("x-peer-token", &token)])).await;
+            assert_eq!(status.as_deref(), Some(UNAUTHENTICATED));
+        }
+
+        let token = search_peer();
+        let edge = [("x-edge-user", "acct-1"), ("x-peer-token", token.as_str())];
+        let (status, _) = call(&layer, request("Screen", &edge)).await;
+        assert_eq!(status.as_deref(), Some(PERMISSION_DENIED));
+    }
+
+    #[tokio::test]
+    async fn shared_reads_admit_peers_and_authenticated_edge_callers() {
+        let layer = layer(true).await;
+        let peer_token = search_peer();
+        let (status, peer) =
+            call_peer(&layer, request("GetEnforcementState", &[("x-peer-token", &peer_token)])).await;
+        assert_eq!((status, peer.as_deref()), (None, Some("search-server")));
+
+        let (status, _) = call(&layer, request("GetEnforcementState", &[])).await;
+        assert_eq!(status.as_deref(), Some(UNAUTHENTICATED));
+
+        let edge = [("x-edge-user", "acct-1")];
+        let (status, _) = call(&layer, request("GetEnforcementState", &edge)).await;
+        assert_eq!(status.as_deref(), Some(UNAUTHENTICATED));
+
+        let auth = format!("Bearer {}", token(&[]));
+        let with_token = [("x-edge-user", "acct-1"), ("authorization", auth.as_str())];
+        let (status, principal) = call(&layer, request("GetEnforcementState", &with_token)).await;
+        assert_eq!(status, None);
+        assert_eq!(principal.as_deref(), Some("acct-1"));
+    }
+
+    #[tokio::test]
+    async fn step_up_rule_demands_a_recent_second_factor() {
+        let layer = layer(true).await;
+        let fresh = format!("Bearer {}", stepped_up_token(STEP_UP_ACR, 60));
+        let (status, principal) =
+            call(&layer, request("LiftSanction", &[("authorization", &fresh)])).await;
+        assert_eq!(status, None);
+        assert_eq!(principal.as_deref(), Some("acct-1"));
+
+        for auth in [
+            format!("Bearer {}", token(&[])),
+            format!("Bearer {}", stepped_up_token("aal1", 60)),
+            format!("Bearer {}", stepped_up_token(STEP_UP_ACR, 600)),
+        ] {
+            let (status, _) = call(&layer, request("LiftSanction", &[("authorization", &auth)])).await;
+            assert_eq!(status.as_deref(), Some(UNAUTHENTICATED));
+        }
+    }
+
+    #[tokio::test]
+    async fn shadow_mode_admits_what_it_would_deny() {
+        let layer = layer(false).await;
+        assert_eq!(call(&layer, request("AssignCase", &[])).await.0, None);
+        assert_eq!(call(&layer, request("FileAppeal", &[])).await.0, None);
+
+        // A verified caller lacking the permission still runs as themselves.
+        let auth = format!("Bearer {}", token(&[]));
+        let (status, principal) =
+            call(&layer, request("DecideCase", &[("authorization", &auth)])).await;
+        assert_eq!(status, None);
+        assert_eq!(principal.as_deref(), Some("acct-1"));
+    }
+
+    #[tokio::test]
+    async fn disabled_layer_is_a_pass_through() {
+        let (status, principal) = call(&AuthLayer::disabled(), request("AssignCase", &[])).await;
+        assert_eq!((status, principal), (None, None));
+    }
+
+    #[test]
+    fn bearer_scheme_is_case_insensitive_and_rejects_others() {
+        let mut headers = HeaderMap::new();
+        headers.insert(AUTHORIZATION, "bEaReR abc".parse().unwrap());
+        assert_eq!(bearer(&headers), Some("abc"));
+        headers.insert(AUTHORIZATION, "Basic abc".parse().unwrap());
+        assert_eq!(bearer(&headers), None);
+        headers.insert(AUTHORIZATION, "Bearer ".parse().unwrap());
+        assert_eq!(bearer(&headers), None);
+    }
+}
@@ -30,6 +30,54 @@
 //! keyspaces. With no `[traffic]` section the layer is a transparent pass-through
 //! and no prune loop runs.
 //!
+//! A `[traffic.backend]` block makes `distributed` profiles fleet-global: [`serve`]
+//! connects the `traffic-redis` lease backend at boot (an unreachable store fails
+//! the boot), hands it to the traffic layer, adds a `traffic-backend` readiness
+//! probe, prunes its lease book on the same loop, and reconnects it when a reload
+//! changes the block. Distributed profiles without a backend degrade to the local
+//! limiter (warned at boot).
+//!
+//! A `[traffic.concurrency]` block adds transport's `ConcurrencyLayer` just inside
+//! the traffic layer: an adaptive (AIMD or gradient) cap on requests in flight that
+//! sheds `sheddable` methods first and `critical` ones — health checks among them —
+//! last, answering `UNAVAILABLE`. Its dials and priority table hot-reload with the
+//! rest of `[traffic]`.
+//!
+//! ## Inbound authentication
+//!
+//! When the loaded config has an `[auth]` section, [`serve`] installs
+//! [`AuthLayer`] innermost: it verifies the caller's edge token, binds the
+//! principal via `auth_context::with_principal`, and enforces the service's
+//! [`Service::access_policy`] — unlisted methods are denied, health and
+//! reflection stay open. `[auth] enforce = false` is hot-reloadable shadow mode.
+//! With no `[auth]` section the layer is a pass-through (logged at boot).
+//!
+//! ## Config source
+//!
+//! The infrastructure document comes from the file at `INFRA_CONFIG_PATH`
+//! (watched with `notify`) or, when `INFRA_CONFIG_URL` is set, from a config
+//! control plane long-polled over HTTP — one push then reaches every deployment
+//! instead of one ConfigMap edit each. Either way reloads go through one
+//! [`ConfigControl`](infra_config::ConfigControl): fail-closed, all sections or
+//! nothing, with the live version and last rejection reported on the admin port
+//! and a per-replica rollback to the last good document.
+//!
+//! ## Admin port
+//!
+//! [`serve`] also binds a plain-HTTP admin listener (default `0.0.0.0:9464`,
+//! overridden by `METRICS_ADDR`, disabled by `METRICS_ADDR=off`) serving
+//! `GET /metrics` — the Prometheus scrape of the global meter: transport's gRPC
+//! server/client RED series and Kafka consumer counters, the CQRS dispatch
+//! histograms, and anything the service records itself; the route is skipped
+//! when the telemetry pipeline exports metrics over OTLP instead. The same
+//! listener serves `GET /health/config` (config version, source, last error).
+//!
+//! `POST /config/rollback` is not on the admin port, which the monitoring
+//! namespace can reach: it is served by a control listener bound to loopback
+//! (default `127.0.0.1:9465`, overridden by `CONTROL_ADDR`, disabled by
+//! `CONTROL_ADDR=off`, refused off loopback), so only `kubectl exec` or
+//! `kubectl port-forward` into the pod reaches it.
+//!
 //! ## Dynamic health
 //!
 //! The gRPC `grpc.health.v1.Health` status is **not** pinned to `SERVING` at
@@ -37,16 +85,21 @@
 //! has passed at least once, and is demoted to `NOT_SERVING` the moment any probe
 //! fails — so Kubernetes readiness reflects real backend reachability.
 
+mod admin;
+mod auth;
+mod traffic_backend;
+
 use std::net::SocketAddr;
 use std::path::PathBuf;
-use std::sync::Arc;
+use std::sync::{Arc, OnceLock};
 use std::time::Duration;
 
 use anyhow::Context;
 use async_trait::async_trait;
 use infra_config::{
-    load_from_path, spawn_watcher, ConfigError, TelemetrySamplingSpec, TelemetrySettings,
-    TelemetrySink, TrafficRegistry,
+    spawn_source, ConfigControl, ConfigError, ConfigSource, FileSource, HttpSource,
+    InfrastructureConfig, TelemetrySamplingSpec, TelemetrySettings, TelemetrySink,
+    TrafficRegistry,
 };
 // Re-exported so services name the build() parameter type without each taking a
 // direct `infra-config` dependency.
@@ -57,10 +110,26 @@ use tonic_health::server::{health_reporter, HealthReporter};
 use tonic_health::ServingStatus;
 use transport::grpc::server::{GrpcServerBuilder, GrpcServerConfig};
 
+pub use auth::{Access, AccessPolicy, AuthLayer, STEP_UP_ACR};
+/// The calling service on an `internal` RPC, bound by the auth layer once its peer
+/// token verifies; handlers read it instead of trusting a name in the payload.
+pub use auth_context::{current_peer, with_peer};
+/// The edge caller on an authenticated RPC, bound by the auth layer once its token
+/// verifies; a handler serving the caller's own data compares against it.
+pub use auth_context::current_principal;
+/// Records the authenticated caller on a command envelope, so the idempotency
+/// layer scopes client keys to it.
+pub use auth_context::inject_into_envelope;
+pub use transport::grpc::layer::TrafficAttributes;
+use traffic_backend::LiveTrafficBackend;
+
 /// Environment variable naming the externalized-config document.
 const INFRA_CONFIG_PATH_ENV: &str = "INFRA_CONFIG_PATH";
 /// Path used when [`INFRA_CONFIG_PATH_ENV`] is unset (relative to the working dir).
 const DEFAULT_INFRA_CONFIG_PATH: &str = "infrastructure.toml";
+/// Environment variable naming a config control-plane URL; when set it replaces the
+/// file as the config source.
+const INFRA_CONFIG_URL_ENV: &str = "INFRA_CONFIG_URL";
 /// Environment variable overriding how often [`HealthProbe`]s are polled.
 const HEALTH_INTERVAL_ENV: &str = "HEALTH_PROBE_INTERVAL_SECS";
 /// Default readiness poll cadence.
@@ -116,6 +185,21 @@ pub trait Service: Sized + Send + 'static {
         Vec::new()
     }
 
+    /// Who may call each of the service's RPCs. Enforced by the runtime's
+    /// [`AuthLayer`] when `[auth]` is configured; a method missing from the policy
+    /// is denied. Default: empty — every domain RPC is denied, only health and
+    /// reflection are served.
+    fn access_policy(&self) -> AccessPolicy {
+        AccessPolicy::for_service(Self::GRPC_SERVICE_NAME)
+    }
+
+    /// Where the request fields `per_attribute` traffic profiles key on live, per RPC
+    /// (e.g. `CreateComment`'s `post_id`). Which RPCs are limited that way is `[traffic]`
+    /// config; a profile naming a field not declared here keys per-method. Default: none.
+    fn traffic_attributes(&self) -> TrafficAttributes {
+        TrafficAttributes::for_service(Self::GRPC_SERVICE_NAME)
+    }
+
     /// Register the service's concrete gRPC service(s) (typically the service plus
     /// reflection) onto the type-erased `routes`. The runtime applies the shared
     /// layer stack and serves, so the layer types never reach this signature.
@@ -133,35 +217,52 @@ pub async fn serve<S: Service>(addr: SocketAddr) -> anyhow::Result<()> {
     let telemetry_guard = telemetry::init(TelemetryConfig::from_env(S::NAME, S::VERSION))
         .context("telemetry init")?;
 
-    // ── Externalized config + hot-reload ───────────────────────────────────────
-    // Fail-closed at boot: a malformed document stops the pod from ever serving.
-    // `_watcher` must stay alive for the process lifetime — dropping it ends the
-    // watch and freezes config at its boot value.
-    let path = PathBuf::from(
-        std::env::var(INFRA_CONFIG_PATH_ENV)
-            .unwrap_or_else(|_| DEFAULT_INFRA_CONFIG_PATH.to_owned()),
-    );
-    let document = load_from_path(&path)
-        .with_context(|| format!("load infrastructure config from {}", path.display()))?;
-    let infra = Arc::new(
-        InfraRegistry::from_config(document).context("resolve infrastructure config")?,
-    );
-
-    // Bridge the `[telemetry]` section to the live pipeline: registering the sink
-    // applies the boot-time dials immediately, and the watcher pushes every
-    // subsequent change — so a config push retunes log filter + sampling with no
-    // restart, fleet-wide.
-    if let Some(registry) = infra.telemetry() {
-        registry
-            .set_sink(Arc::new(TelemetryControlSink { control: telemetry_guard.control() }))
-            .context("register telemetry control sink")?;
+    // The admin endpoint comes up before config and composition, so a pod that
+    // stalls at boot is still observable.
+    let config_slot: admin::ConfigSlot = Arc::new(OnceLock::new());
+    let prometheus = telemetry_guard.prometheus_handle();
+    if prometheus.is_none() {
+        tracing::info!(service = S::NAME, "no Prometheus exporter — /metrics is not served");
+    }
+    match admin::metrics_addr_from_env() {
+        Some(admin_addr) => {
+            admin::spawn_admin(admin_addr, prometheus, Arc::clone(&config_slot)).await
+        }
+        None => tracing::info!(
+            service = S::NAME,
+            "{} is off — the admin port is not served",
+            admin::METRICS_ADDR_ENV
+        ),
+    }
+    if let Some(control_addr) = admin::control_addr_from_env() {
+        admin::spawn_control(control_addr, Arc::clone(&config_slot)).await;
     }
 
-    let _watcher = spawn_watcher(path, Arc::clone(&infra)).context("spawn config watcher")?;
+    // ── Externalized config + hot-reload ───────────────────────────────────────
+    // Fail-closed at boot: a malformed or unreachable document stops the pod from
+    // ever serving. The source then keeps driving reloads for the process lifetime.
+    let control = match std::env::var(INFRA_CONFIG_URL_ENV) {
+        Ok(url) => {
+            let source = HttpSource::new(url).context("build config control-plane source")?;
+            load_config(source, &telemetry_guard).await?
+        }
+        Err(_) => {
+            let path = PathBuf::from(
+                std::env::var(INFRA_CONFIG_PATH_ENV)
+                    .unwrap_or_else(|_| DEFAULT_INFRA_CONFIG_PATH.to_owned()),
+            );
+            let source = FileSource::new(path).context("watch infrastructure config")?;
+            load_config(source, &telemetry_guard).await?
+        }
+    };
+    let infra = control.target();
+    let _ = config_slot.set(control);
 
     // ── Compose the service graph ──────────────────────────────────────────────
     let service = S::build(Arc::clone(&infra)).await.context("service build")?;
-    let probes = service.health_probes();
+    let mut probes = service.health_probes();
+    let policy = service.access_policy();
+    let traffic_attributes = service.traffic_attributes();
 
     // ── Routes: health (runtime-owned) + the service's own services ────────────
     let (health, health_service) = health_reporter();
@@ -171,7 +272,7 @@ pub async fn serve<S: Service>(addr: SocketAddr) -> anyhow::Result<()> {
         .register(&mut routes)
         .context("register grpc routes")?;
 
-    // ── gRPC server: inbound-trace (outer) + traffic (inner) layers ────────────
+    // ── gRPC server: inbound-trace (outer) + traffic + concurrency + auth (inner) ─
     // Connection recycling (GOAWAY after max_connection_age) is on by default:
     // it is what re-spreads long-lived HTTP/2 channels across replicas after a
     // scale-out. In-flight streams are never severed unless the grace env is set.
@@ -182,18 +283,51 @@ pub async fn serve<S: Service>(addr: SocketAddr) -> anyhow::Result<()> {
     if let Some(grace) = max_connection_age_grace_from_env() {
         grpc_config = grpc_config.with_max_connection_age_grace(grace);
     }
+    // Auth sits innermost, so a flood is shed by traffic before any token is verified.
+    let auth = match infra.auth() {
+        Some(registry) => {
+            AuthLayer::from_registry(registry, policy, grpc_config.identity_header.clone())
+        }
+        None => {
+            tracing::warn!(
+                service = S::NAME,
+                "no [auth] section — inbound calls are not authenticated by the runtime"
+            );
+            AuthLayer::disabled()
+        }
+    };
     let traffic = infra.traffic();
+    let traffic_backend = match &traffic {
+        Some(registry) => connect_traffic_backend::<S>(registry).await?,
+        None => None,
+    };
     let mut server_builder = GrpcServerBuilder::new(grpc_config);
     if let Some(registry) = &traffic {
-        server_builder = server_builder.with_traffic(Arc::clone(registry));
+        if let Some(limiter) = registry.concurrency() {
+            let config = limiter.config();
+            tracing::info!(
+                service = S::NAME,
+                algorithm = ?config.algorithm,
+                limit = limiter.limit(),
+                max_limit = config.max_limit,
+                "adaptive concurrency limit enabled"
+            );
+        }
+        server_builder = server_builder
+            .with_traffic(Arc::clone(registry))
+            .with_traffic_attributes(traffic_attributes);
     }
-    let mut server = server_builder.build().context("build gRPC server")?;
+    if let Some(backend) = &traffic_backend {
+        server_builder = server_builder.with_traffic_backend(Arc::clone(backend) as _);
+        probes.push(backend.probe());
+    }
+    let mut server = server_builder.build().context("build gRPC server")?.layer(auth);
     let router = server.add_routes(routes.routes());
 
     // ── Background loops: readiness health + traffic-memory bounding ────────────
     spawn_readiness(S::GRPC_SERVICE_NAME, health, probes);
     if let Some(registry) = traffic {
-        spawn_traffic_prune(registry);
+        spawn_traffic_prune(registry, traffic_backend);
     }
 
     tracing::info!(service = S::NAME, version = S::VERSION, %addr, "gRPC server listening");
@@ -207,6 +341,39 @@ pub async fn serve<S: Service>(addr: SocketAddr) -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Boots the registry from `source`'s current document, bridges `[telemetry]` to
+/// the live pipeline, then hands the source to its reload driver.
+async fn load_config<Src: ConfigSource>(
+    mut source: Src,
+    telemetry_guard: &telemetry::TelemetryGuard,
+) -> anyhow::Result<Arc<ConfigControl<InfraRegistry>>> {
+    let origin = source.describe();
+    let document = source
+        .fetch(None)
+        .await
+        .with_context(|| format!("load infrastructure config from {origin}"))?;
+    let config = InfrastructureConfig::from_toml(&document.raw)
+        .with_context(|| format!("parse infrastructure config from {origin}"))?;
+    let infra = Arc::new(
+        InfraRegistry::from_config(config).context("resolve infrastructure config")?,
+    );
+
+    // Bridge the `[telemetry]` section to the live pipeline: registering the sink
+    // applies the boot-time dials immediately, and the source pushes every
+    // subsequent change — so a config push retunes log filter + sampling with no
+    // restart, fleet-wide.
+    if let Some(registry) = infra.telemetry() {
+        registry
+            .set_sink(Arc::new(TelemetryControlSink { control: telemetry_guard.control() }))
+            .context("register telemetry control sink")?;
+    }
+
+    tracing::info!(source = %origin, version = %document.version, "infrastructure config loaded");
+    let control = Arc::new(ConfigControl::booted(infra, origin, document));
+    spawn_source(source, Arc::clone(&control));
+    Ok(control)
+}
+
 /// Spawns the background loop that maps backend [`HealthProbe`] results onto the
 /// service's gRPC health status.
 ///
@@ -267,11 +434,32 @@ fn spawn_readiness(
     });
 }
 
+/// Connects the `[traffic.backend]` store, if configured, and registers the sink
+/// that reconnects it on reload.
+async fn connect_traffic_backend<S: Service>(
+    registry: &TrafficRegistry,
+) -> anyhow::Result<Option<Arc<LiveTrafficBackend>>> {
+    let Some(spec) = registry.backend() else {
+        if registry.has_distributed() {
+            tracing::warn!(
+                service = S::NAME,
+                "distributed traffic profiles but no [traffic.backend] — limiting per replica"
+            );
+        }
+        return Ok(None);
+    };
+
+    let backend = LiveTrafficBackend::connect(&spec).await?;
+    registry.set_backend_sink(backend.sink(tokio::runtime::Handle::current()));
+    tracing::info!(service = S::NAME, hosts = ?spec.hosts, "traffic backend connected");
+    Ok(Some(backend))
+}
+
 /// Spawns the background loop that bounds rate-limiter memory by dropping idle
-/// keys across every traffic profile on [`TRAFFIC_PRUNE_INTERVAL_ENV`] cadence.
-/// Cheap for bounded (`per_method`) profiles; essential for unbounded
-/// (`per_caller`) ones.
-fn spawn_traffic_prune(registry: Arc<TrafficRegistry>) {
+/// keys across every traffic profile — and the backend's lease book, when one is
+/// installed — on [`TRAFFIC_PRUNE_INTERVAL_ENV`] cadence. Cheap for bounded
+/// (`per_method`) profiles; essential for unbounded (`per_caller`) ones.
+fn spawn_traffic_prune(registry: Arc<TrafficRegistry>, backend: Option<Arc<LiveTrafficBackend>>) {
     let interval = interval_from_env(
         TRAFFIC_PRUNE_INTERVAL_ENV,
         DEFAULT_TRAFFIC_PRUNE_INTERVAL_SECS,
@@ -284,6 +472,10 @@ fn spawn_traffic_prune(registry: Arc<TrafficRegistry>) {
             ticker.tick().await;
             registry.prune_all();
             tracing::debug!(tracked_keys = registry.tracked_keys(), "traffic registry pruned");
+            if let Some(backend) = &backend {
+                backend.prune();
+                tracing::debug!(leased_keys = backend.tracked_keys(), "traffic lease book pruned");
+            }
         }
     });
 }
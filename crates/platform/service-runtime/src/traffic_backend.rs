@@ -0,0 +1,191 @@
+//! The `[traffic.backend]` quota backend: built at boot, hot-swapped on reload.
+//!
+//! `distributed` traffic profiles charge a fleet-global budget through a
+//! [`QuotaBackend`]. The transport layer is handed one [`LiveTrafficBackend`] for
+//! the life of the process; what it delegates to — a `traffic-redis` lease
+//! backend over its own Redis connection — is replaced wholesale when the
+//! reloaded block names a different store. The new connection is made off the
+//! watcher task and swapped in only once it is up, so a bad push keeps the
+//! previous backend serving.
+
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+
+use anyhow::Context;
+use async_trait::async_trait;
+use health::HealthProbe;
+use infra_config::{
+    ConfigError, TrafficBackendKind, TrafficBackendSink, TrafficBackendSpec,
+    TrafficBackendTopology,
+};
+use redis_storage::{RedisClient, RedisClientBuilder, RedisConfig, TopologyKind};
+use traffic::{Quota, QuotaBackend, QuotaError, TrafficDecision};
+use traffic_redis::RedisLeaseBackend;
+
+/// Environment variables carrying the backend store's credentials — kept out of
+/// the shared config document.
+const USERNAME_ENV: &str = "TRAFFIC_REDIS_USERNAME";
+const PASSWORD_ENV: &str = "TRAFFIC_REDIS_PASSWORD";
+
+/// One connected backend: the lease book and the client it claims through.
+struct Installed {
+    backend: RedisLeaseBackend,
+    client: RedisClient,
+}
+
+/// The process-lifetime [`QuotaBackend`] handed to the transport layer.
+pub(crate) struct LiveTrafficBackend {
+    current: RwLock<Arc<Installed>>,
+}
+
+impl LiveTrafficBackend {
+    /// Connects the backend named by `spec`. Fails when the store is unreachable,
+    /// so a misaddressed block stops the pod at boot like any other bad config.
+    pub(crate) async fn connect(spec: &TrafficBackendSpec) -> anyhow::Result<Arc<Self>> {
+        let installed = install(spec).await?;
+        Ok(Arc::new(Self { current: RwLock::new(Arc::new(installed)) }))
+    }
+
+    fn load(&self) -> Arc<Installed> {
+        Arc::clone(&self.current.read().expect("traffic backend lock poisoned"))
+    }
+
+    fn swap(&self, installed: Installed) {
+        *self.current.write().expect("traffic backend lock poisoned") = Arc::new(installed);
+    }
+
+    /// Drops lease entries whose window has passed.
+    pub(crate) fn prune(&self) {
+        self.load().backend.prune();
+    }
+
+    /// Keys with a live local lease.
+    pub(crate) fn tracked_keys(&self) -> usize {
+        self.load().backend.tracked_keys()
+    }
+
+    /// Readiness probe pinging whichever store is currently installed.
+    pub(crate) fn probe(self: &Arc<Self>) -> Arc<dyn HealthProbe> {
+        Arc::new(BackendProbe { live: Arc::clone(self) })
+    }
+
+    /// Sink that rebuilds the backend when the reloaded block changes. `runtime`
+    /// is where the reconnect runs — the watcher calls the sink synchronously.
+    pub(crate) fn sink(
+        self: &Arc<Self>,
+        runtime: tokio::runtime::Handle,
+    ) -> Arc<dyn TrafficBackendSink> {
+        Arc::new(BackendSink { live: Arc::clone(self), runtime })
+    }
+}
+
+#[async_trait]
+impl QuotaBackend for LiveTrafficBackend {
+    async fn check(&self, key: &str, quota: Quota) -> Result<TrafficDecision, QuotaError> {
+        self.load().backend.check(key, quota).await
+    }
+}
+
+struct BackendProbe {
+    live: Arc<LiveTrafficBackend>,
+}
+
+#[async_trait]
+impl HealthProbe for BackendProbe {
+    fn name(&self) -> &str {
+        "traffic-backend"
+    }
+
+    async fn check(&self) -> anyhow::Result<()> {
+        let installed = self.live.load();
+        redis_storage::health::health_check(&*installed.client)
+            .await
+            .map_err(|e| anyhow::anyhow!("traffic backend: {e}"))
+    }
+}
+
+struct BackendSink {
+    live: Arc<LiveTrafficBackend>,
+    runtime: tokio::runtime::Handle,
+}
+
+impl TrafficBackendSink for BackendSink {
+    fn apply(&self, spec: &TrafficBackendSpec) -> Result<(), ConfigError> {
+        let live = Arc::clone(&self.live);
+        let spec = spec.clone();
+        self.runtime.spawn(async move {
+            match install(&spec).await {
+                Ok(installed) => {
+                    live.swap(installed);
+                    tracing::info!(hosts = ?spec.hosts, "traffic backend swapped");
+                }
+                Err(error) => tracing::error!(
+                    hosts = ?spec.hosts,
+                    error = %format!("{error:#}"),
+                    "traffic backend reconnect failed — keeping the previous backend"
+                ),
+            }
+        });
+        Ok(())
+    }
+}
+
+async fn install(spec: &TrafficBackendSpec) -> anyhow::Result<Installed> {
+    let client = match spec.kind {
+        TrafficBackendKind::Redis => RedisClientBuilder::new(redis_config(spec))
+            .build()
+            .await
+            .with_context(|| format!("connect traffic backend {:?}", spec.hosts))?,
+    };
+    Ok(Installed { backend: RedisLeaseBackend::new(client.clone()), client })
+}
+
+/// Maps the block onto a [`RedisConfig`]; everything it doesn't name keeps the
+/// `redis-storage` defaults. The claim deadline doubles as the command timeout.
+fn redis_config(spec: &TrafficBackendSpec) -> RedisConfig {
+    RedisConfig {
+        topology: match spec.topology {
+            TrafficBackendTopology::Standalone => TopologyKind::Standalone,
+            TrafficBackendTopology::Cluster => TopologyKind::Cluster,
+        },
+        hosts: spec.hosts.clone(),
+        username: std::env::var(USERNAME_ENV).ok(),
+        password: std::env::var(PASSWORD_ENV).ok(),
+        tls: spec.tls,
+        command_timeout: Duration::from_millis(spec.timeout_ms),
+        ..RedisConfig::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn spec(topology: TrafficBackendTopology) -> TrafficBackendSpec {
+        TrafficBackendSpec {
+            kind: TrafficBackendKind::Redis,
+            hosts: vec!["a:6379".into(), "b:6379".into()],
+            topology,
+            tls: true,
+            timeout_ms: 25,
+        }
+    }
+
+    #[test]
+    fn redis_config_carries_the_block() {
+        let config = redis_config(&spec(TrafficBackendTopology::Cluster));
+        assert!(matches!(config.topology, TopologyKind::Cluster));
+        assert_eq!(config.hosts, vec!["a:6379".to_string(), "b:6379".to_string()]);
+        assert!(config.tls);
+        assert_eq!(config.command_timeout, Duration::from_millis(25));
+    }
+
+    #[test]
+    fn redis_config_keeps_storage_defaults_elsewhere() {
+        let config = redis_config(&spec(TrafficBackendTopology::Standalone));
+        let defaults = RedisConfig::default();
+        assert!(matches!(config.topology, TopologyKind::Standalone));
+        assert_eq!(config.connection_timeout, defaults.connection_timeout);
+        assert_eq!(config.fail_fast, defaults.fail_fast);
+    }
+}
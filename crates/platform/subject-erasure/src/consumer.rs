@@ -0,0 +1,211 @@
+use std::sync::Arc;
+
+use error::AppError;
+use tracing::{error, info, warn};
+use transport::kafka::consumer::{run_consumer, KafkaConsumerHandle, ProcessOutcome, RetryPolicy};
+use transport::kafka::producer::KafkaProducerHandle;
+
+use crate::eraser::SubjectEraser;
+use crate::reporter::ErasureReporter;
+use crate::wire::{AccountEventWire, DeletionRequest};
+
+/// Runs `participant`'s part of a GDPR erasure on `account.v1.events`: each
+/// `gdpr_deletion_requested` is erased through `eraser`, then confirmed to
+/// account through `reporter`.
+///
+/// The handle must be built with `enable_auto_commit = false`. Returns when the
+/// stream ends or on an unrecoverable broker/dead-letter error; the supervising
+/// task respawns it.
+pub async fn run_subject_erasure_consumer<E: SubjectEraser>(
+    participant: &'static str,
+    consumer:    KafkaConsumerHandle,
+    eraser:      Arc<E>,
+    reporter:    Arc<dyn ErasureReporter>,
+    producer:    KafkaProducerHandle,
+) {
+    info!(participant, "subject-erasure consumer started");
+    let policy = RetryPolicy::default();
+    let result = run_consumer::<AccountEventWire, _>(&consumer, &producer, &policy, move |event| {
+        let eraser = Arc::clone(&eraser);
+        let reporter = Arc::clone(&reporter);
+        Box::pin(async move {
+            match event {
+                AccountEventWire::GdprDeletionRequested(request) => {
+                    erase_and_confirm(eraser.as_ref(), reporter.as_ref(), request).await
+                }
+                AccountEventWire::Other => ProcessOutcome::Done,
+            }
+        })
+    })
+    .await;
+    if let Err(e) = result {
+        error!(participant, error = %e, "subject-erasure consumer stopped");
+    }
+}
+
+/// Erases, then confirms. The erasure is idempotent, so a retried record simply
+/// finds nothing left and confirms again. A rejected confirmation is committed:
+/// the data is gone either way, and a superseded request is confirmed by its
+/// successor's own event.
+pub async fn erase_and_confirm<E: SubjectEraser>(
+    eraser:   &E,
+    reporter: &dyn ErasureReporter,
+    request:  &DeletionRequest,
+) -> ProcessOutcome {
+    match eraser.erase(request).await {
+        Ok(()) => {}
+        Err(e) if e.is_retryable() => return ProcessOutcome::Retry(e.to_string()),
+        Err(e) => return ProcessOutcome::Reject(e.to_string()),
+    }
+    match reporter.confirm(&request.account_id, request.requested_at).await {
+        Ok(()) => {
+            info!(account_id = %request.account_id, "subject data erased and confirmed");
+            ProcessOutcome::Done
+        }
+        Err(e) if e.is_retryable() => ProcessOutcome::Retry(e.to_string()),
+        Err(e) => {
+            warn!(account_id = %request.account_id, error = %e, "erasure confirmation rejected");
+            ProcessOutcome::Done
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use async_trait::async_trait;
+    use chrono::{DateTime, TimeZone, Utc};
+    use cqrs::{Command, CommandBusBuilder, CommandHandler, Envelope};
+    use http::StatusCode;
+
+    use super::*;
+    use crate::eraser::EraseCommand;
+    use crate::reporter::ErasureReportError;
+
+    #[derive(Debug, thiserror::Error)]
+    #[error("erase failed")]
+    struct EraseFailed {
+        retryable: bool,
+    }
+
+    impl AppError for EraseFailed {
+        fn error_code(&self) -> &'static str {
+            "TST-0001"
+        }
+
+        fn http_status(&self) -> StatusCode {
+            StatusCode::SERVICE_UNAVAILABLE
+        }
+
+        fn is_retryable(&self) -> bool {
+            self.retryable
+        }
+    }
+
+    /// The test's erase command; records the profiles it was asked to erase.
+    struct Erase {
+        profile_ids: Vec<String>,
+    }
+
+    impl Command for Erase {
+        type Output = ();
+    }
+
+    impl validate_core::Validate for Erase {}
+
+    struct EraseHandler {
+        erased: Arc<Mutex<Vec<String>>>,
+        fail:   Option<bool>,
+    }
+
+    impl CommandHandler<Erase> for EraseHandler {
+        type Error = EraseFailed;
+
+        async fn handle(&self, envelope: Envelope<Erase>) -> Result<(), EraseFailed> {
+            if let Some(retryable) = self.fail {
+                return Err(EraseFailed { retryable });
+            }
+            self.erased.lock().unwrap().extend(envelope.payload.profile_ids);
+            Ok(())
+        }
+    }
+
+    #[derive(Default)]
+    struct RecordingReporter {
+        confirmed: Mutex<Vec<(String, DateTime<Utc>)>>,
+        answer:    Mutex<Option<ErasureReportError>>,
+    }
+
+    #[async_trait]
+    impl ErasureReporter for RecordingReporter {
+        async fn confirm(&self, account_id: &str, requested_at: DateTime<Utc>) -> Result<(), ErasureReportError> {
+            if let Some(e) = self.answer.lock().unwrap().take() {
+                return Err(e);
+            }
+            self.confirmed.lock().unwrap().push((account_id.to_owned(), requested_at));
+            Ok(())
+        }
+    }
+
+    fn request() -> DeletionRequest {
+        DeletionRequest {
+            account_id:   "acc-1".to_owned(),
+            requested_at: Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap(),
+            profile_ids:  vec!["prof-1".to_owned(), "prof-2".to_owned()],
+        }
+    }
+
+    fn eraser(
+        erased: &Arc<Mutex<Vec<String>>>,
+        fail:   Option<bool>,
+    ) -> impl SubjectEraser<Error = cqrs::CqrsError> {
+        let bus = CommandBusBuilder::new()
+            .register::<Erase, _>(EraseHandler { erased: Arc::clone(erased), fail })
+            .unwrap()
+            .build();
+        EraseCommand::new(bus, |r: &DeletionRequest| Erase { profile_ids: r.profile_ids.clone() })
+    }
+
+    #[tokio::test]
+    async fn erases_through_the_command_then_confirms_the_request() {
+        let erased = Arc::default();
+        let reporter = RecordingReporter::default();
+
+        let outcome = erase_and_confirm(&eraser(&erased, None), &reporter, &request()).await;
+
+        assert!(matches!(outcome, ProcessOutcome::Done));
+        assert_eq!(*erased.lock().unwrap(), ["prof-1", "prof-2"]);
+        assert_eq!(
+            *reporter.confirmed.lock().unwrap(),
+            [("acc-1".to_owned(), request().requested_at)]
+        );
+    }
+
+    #[tokio::test]
+    async fn a_failed_erasure_is_never_confirmed() {
+        let erased = Arc::default();
+        let reporter = RecordingReporter::default();
+
+        let retried = erase_and_confirm(&eraser(&erased, Some(true)), &reporter, &request()).await;
+        let rejected = erase_and_confirm(&eraser(&erased, Some(false)), &reporter, &request()).await;
+
+        assert!(matches!(retried, ProcessOutcome::Retry(_)));
+        assert!(matches!(rejected, ProcessOutcome::Reject(_)));
+        assert!(reporter.confirmed.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn an_unreachable_account_is_retried_and_a_refusal_committed() {
+        let erased = Arc::default();
+        let reporter = RecordingReporter::default();
+
+        *reporter.answer.lock().unwrap() = Some(ErasureReportError::Unavailable("down".to_owned()));
+        let unavailable = erase_and_confirm(&eraser(&erased, None), &reporter, &request()).await;
+        *reporter.answer.lock().unwrap() = Some(ErasureReportError::Rejected("superseded".to_owned()));
+        let rejected = erase_and_confirm(&eraser(&erased, None), &reporter, &request()).await;
+
+        assert!(matches!(unavailable, ProcessOutcome::Retry(_)));
+        assert!(matches!(rejected, ProcessOutcome::Done));
+    }
+}
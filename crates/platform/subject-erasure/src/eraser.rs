@@ -0,0 +1,47 @@
+use std::future::Future;
+
+use cqrs::{Command, CommandBus, CqrsError, Envelope};
+use error::AppError;
+use uuid::Uuid;
+
+use crate::wire::DeletionRequest;
+
+/// The erase step of a participant: deletes or anonymizes everything the
+/// subject of `request` has in this service.
+///
+/// Must be idempotent — a redelivered request runs it again, and a run that
+/// finds nothing left is what lets it confirm again. A retryable error is
+/// retried, then dead-lettered; any other is dead-lettered at once. Either way
+/// nothing is confirmed.
+pub trait SubjectEraser: Send + Sync + 'static {
+    type Error: AppError;
+
+    fn erase(&self, request: &DeletionRequest) -> impl Future<Output = Result<(), Self::Error>> + Send;
+}
+
+/// A [`SubjectEraser`] that builds the service's erase command from the request
+/// and dispatches it through the command bus, middleware included.
+pub struct EraseCommand<CB, F> {
+    bus:     CB,
+    command: F,
+}
+
+impl<CB, F> EraseCommand<CB, F> {
+    pub fn new(bus: CB, command: F) -> Self {
+        Self { bus, command }
+    }
+}
+
+impl<CB, F, C> SubjectEraser for EraseCommand<CB, F>
+where
+    CB: CommandBus + 'static,
+    F:  Fn(&DeletionRequest) -> C + Send + Sync + 'static,
+    C:  Command<Output = ()>,
+{
+    type Error = CqrsError;
+
+    async fn erase(&self, request: &DeletionRequest) -> Result<(), CqrsError> {
+        let command = (self.command)(request);
+        self.bus.dispatch(Envelope::new(Uuid::now_v7(), command)).await
+    }
+}
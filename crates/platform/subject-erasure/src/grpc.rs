@@ -0,0 +1,53 @@
+use account_api::account_service_client::AccountServiceClient;
+use account_api::ConfirmSubjectErasureRequest;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use tonic::Code;
+use transport::grpc::client::ResilientChannel;
+
+use crate::reporter::{ErasureReportError, ErasureReporter};
+
+/// [`ErasureReporter`] over account's `ConfirmSubjectErasure`, on a
+/// [`ResilientChannel`] resolved from the service's `account` resilience
+/// binding.
+///
+/// The request names `participant`, the service's entry in account's
+/// participant list. Account checks it against the caller's verified peer
+/// identity, so it is only ever this service's own name.
+pub struct GrpcErasureReporter {
+    channel:     ResilientChannel,
+    participant: &'static str,
+}
+
+impl GrpcErasureReporter {
+    pub fn new(channel: ResilientChannel, participant: &'static str) -> Self {
+        Self { channel, participant }
+    }
+}
+
+#[async_trait]
+impl ErasureReporter for GrpcErasureReporter {
+    async fn confirm(&self, account_id: &str, requested_at: DateTime<Utc>) -> Result<(), ErasureReportError> {
+        let request = ConfirmSubjectErasureRequest {
+            account_id:   account_id.to_owned(),
+            service:      self.participant.to_owned(),
+            requested_at: Some(prost_types::Timestamp {
+                seconds: requested_at.timestamp(),
+                nanos:   requested_at.timestamp_subsec_nanos() as i32,
+            }),
+        };
+        match AccountServiceClient::new(self.channel.clone()).confirm_subject_erasure(request).await {
+            Ok(_) => Ok(()),
+            // Superseded or cancelled request, or an account that no longer
+            // exists: retrying cannot change the answer.
+            Err(status) if matches!(status.code(), Code::FailedPrecondition | Code::NotFound | Code::InvalidArgument) => {
+                Err(ErasureReportError::Rejected(status.message().to_owned()))
+            }
+            Err(status) => Err(ErasureReportError::Unavailable(format!(
+                "{}: {}",
+                status.code(),
+                status.message()
+            ))),
+        }
+    }
+}
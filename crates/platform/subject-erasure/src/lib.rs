@@ -0,0 +1,30 @@
+//! A service's part of a GDPR erasure.
+//!
+//! Account publishes `gdpr_deletion_requested` on `account.v1.events` and
+//! anonymizes the account only once every participant has confirmed the
+//! request. Each participant runs the same loop — erase the subject's data, then
+//! confirm over account's `ConfirmSubjectErasure` — and this crate is that loop,
+//! parameterised by the participant's name and how it erases:
+//!
+//! - [`run_subject_erasure_consumer`] — the consumer on the shared at-least-once
+//!   runner; every other account event is committed as a no-op.
+//! - [`SubjectEraser`] — the erase step. [`EraseCommand`] dispatches the
+//!   service's own erase command through its command bus.
+//! - [`ErasureReporter`] — the confirm step. `GrpcErasureReporter` (feature
+//!   `grpc`) calls account.
+//! - [`erase_and_confirm`] — one request, for a consumer that also handles other
+//!   account events.
+
+pub mod consumer;
+pub mod eraser;
+#[cfg(feature = "grpc")]
+pub mod grpc;
+pub mod reporter;
+pub mod wire;
+
+pub use consumer::{erase_and_confirm, run_subject_erasure_consumer};
+pub use eraser::{EraseCommand, SubjectEraser};
+#[cfg(feature = "grpc")]
+pub use grpc::GrpcErasureReporter;
+pub use reporter::{ErasureReportError, ErasureReporter};
+pub use wire::{AccountEventWire, DeletionRequest};
@@ -0,0 +1,29 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+/// Why a confirmation did not reach account.
+#[derive(Debug, thiserror::Error)]
+pub enum ErasureReportError {
+    /// Account was unreachable or failed transiently; the record is retried.
+    #[error("account unavailable for erasure confirmation: {0}")]
+    Unavailable(String),
+    /// Account refused it: a superseded or cancelled request, or an account
+    /// that no longer exists. Retrying cannot change the answer.
+    #[error("erasure confirmation rejected by account: {0}")]
+    Rejected(String),
+}
+
+impl ErasureReportError {
+    pub fn is_retryable(&self) -> bool {
+        matches!(self, Self::Unavailable(_))
+    }
+}
+
+/// The confirm step: tells account this participant has erased the subject of
+/// the deletion request identified by `requested_at`. Account anonymizes only
+/// once every participant has confirmed; a repeated confirmation is a no-op
+/// there.
+#[async_trait]
+pub trait ErasureReporter: Send + Sync + 'static {
+    async fn confirm(&self, account_id: &str, requested_at: DateTime<Utc>) -> Result<(), ErasureReportError>;
+}
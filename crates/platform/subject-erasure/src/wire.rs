@@ -0,0 +1,46 @@
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+
+/// Lenient wire view of `account.v1.events`: only the deletion request is read,
+/// every other event kind decodes to `Other` and is committed as a no-op.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum AccountEventWire {
+    GdprDeletionRequested(DeletionRequest),
+    #[serde(other)]
+    Other,
+}
+
+/// A `gdpr_deletion_requested`. Account identifies it by `requested_at`, so a
+/// confirmation names both.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct DeletionRequest {
+    pub account_id:   String,
+    pub requested_at: DateTime<Utc>,
+    /// The subject's profiles, as account resolved them for the request.
+    #[serde(default)]
+    pub profile_ids:  Vec<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decodes_a_deletion_request() {
+        let json = r#"{"type":"gdpr_deletion_requested","account_id":"acc-1","requested_at":"2026-01-01T00:00:00Z","profile_ids":["prof-1","prof-2"],"retention_days":7,"scheduled_deletion_at":"2026-01-08T00:00:00Z"}"#;
+        let AccountEventWire::GdprDeletionRequested(request) = serde_json::from_str(json).unwrap()
+        else {
+            panic!("expected a deletion request");
+        };
+        assert_eq!(request.account_id, "acc-1");
+        assert_eq!(request.requested_at.to_rfc3339(), "2026-01-01T00:00:00+00:00");
+        assert_eq!(request.profile_ids, ["prof-1", "prof-2"]);
+    }
+
+    #[test]
+    fn other_account_events_are_skipped() {
+        let json = r#"{"type":"account_suspended","account_id":"acc-1","reason":"spam"}"#;
+        assert!(matches!(serde_json::from_str(json).unwrap(), AccountEventWire::Other));
+    }
+}
@@ -0,0 +1,24 @@
+//! Offline Kafka: the transport crate's in-process broker, for scenarios that
+//! exercise publish → consume → dead-letter without booting a container.
+//!
+//! [`MemoryBroker`] hands out the same `KafkaProducerHandle` /
+//! `KafkaConsumerHandle` types the rdkafka builders return, so a service's
+//! worker and `run_consumer` run unchanged against it. It is deterministic — the
+//! same produce order yields the same partitions, offsets and delivery order — so
+//! assertions can read the broker state directly ([`MemoryBroker::records`],
+//! [`MemoryBroker::committed`]) instead of polling a live one.
+
+use transport::kafka::consumer::DLQ_SUFFIX;
+
+pub use transport::kafka::memory::{MemoryBroker, MemoryRecord};
+
+/// A fresh broker with each topic and its `.dlq` created (one partition each),
+/// the in-process analogue of [`ensure_topics`](crate::containers::ensure_topics).
+pub fn memory_broker(topics: &[&str]) -> MemoryBroker {
+    let broker = MemoryBroker::new();
+    for topic in topics {
+        broker.create_topic(topic, 1);
+        broker.create_topic(&format!("{topic}{DLQ_SUFFIX}"), 1);
+    }
+    broker
+}
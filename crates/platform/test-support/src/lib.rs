@@ -21,8 +21,12 @@
 //! - **Isolation by namespacing, not teardown.** Scenarios mint fresh UUID keys
 //!   so the suite runs in parallel against the shared containers; this crate
 //!   only provides the infra, the namespacing discipline lives in each harness.
+//!
+//! Kafka scenarios that don't need a real broker can run offline against the
+//! transport crate's in-process broker instead (see [`kafka`]).
 
 pub mod containers;
+pub mod kafka;
 pub mod migrate;
 pub mod wait;
 
@@ -8,12 +8,16 @@ use tokio::sync::Mutex;
 use traffic::{Quota, QuotaError, TrafficDecision};
 
 use crate::claim::ClaimSource;
+use crate::metrics::{ClaimResult, LeaseMetrics};
 
 /// One replica's view of a key's lease for the current window.
 #[derive(Clone, Copy)]
 struct Lease {
     /// The window this lease state belongs to (`now_ms / lease_ms`).
     window: u64,
+    /// The profile's lease window, kept per entry so one book can serve profiles with
+    /// different windows and still prune each entry against its own.
+    lease_ms: u64,
     /// Tokens still available locally without contacting the backend.
     remaining: u64,
     /// The window's *global* budget is spent — short-circuit further requests this window
@@ -24,9 +28,15 @@ struct Lease {
 /// Per-key local lease cache. Each replica serves requests from a locally-held chunk of the
 /// global budget, only crossing to the [`ClaimSource`] when its chunk runs out — so backend
 /// I/O is amortized over `burst` requests per key per replica.
-#[derive(Default)]
 pub struct LeaseBook {
     leases: DashMap<String, Arc<Mutex<Lease>>>,
+    metrics: LeaseMetrics,
+}
+
+impl Default for LeaseBook {
+    fn default() -> Self {
+        Self { leases: DashMap::new(), metrics: LeaseMetrics::new() }
+    }
 }
 
 impl LeaseBook {
@@ -56,46 +66,56 @@ impl LeaseBook {
         let cell = self
             .leases
             .entry(key.to_owned())
-            .or_insert_with(|| Arc::new(Mutex::new(Lease { window, remaining: 0, exhausted: false })))
+            .or_insert_with(|| {
+                Arc::new(Mutex::new(Lease { window, lease_ms, remaining: 0, exhausted: false }))
+            })
             .clone();
         let mut lease = cell.lock().await;
 
         // Window rolled forward — the global budget reset, so does our local view.
-        if lease.window != window {
+        if lease.window != window || lease.lease_ms != lease_ms {
             lease.window = window;
+            lease.lease_ms = lease_ms;
             lease.remaining = 0;
             lease.exhausted = false;
         }
 
         if lease.remaining > 0 {
             lease.remaining -= 1;
+            self.metrics.hit(true);
             return Ok(TrafficDecision::Allow);
         }
 
         // Budget is monotonic within a window, so once spent it stays spent — serve the
         // throttle locally rather than re-claiming on every excess request.
         if lease.exhausted {
+            self.metrics.hit(false);
             return Ok(TrafficDecision::Throttle { retry_after: until_next_window(now_ms, lease_ms) });
         }
 
-        match claims.claim(key, window, budget, want, ttl_ms).await? {
+        let granted = claims.claim(key, window, budget, want, ttl_ms).await.inspect_err(|_| {
+            self.metrics.claim(ClaimResult::Error);
+        })?;
+        match granted {
             0 => {
+                self.metrics.claim(ClaimResult::Exhausted);
                 lease.exhausted = true;
                 Ok(TrafficDecision::Throttle { retry_after: until_next_window(now_ms, lease_ms) })
             }
             granted => {
+                self.metrics.claim(ClaimResult::Granted);
                 lease.remaining = granted - 1; // consume one for this request
                 Ok(TrafficDecision::Allow)
             }
         }
     }
 
-    /// Drop lease entries whose window has passed — best-effort and lock-free (entries
-    /// currently in use are kept). Call periodically to bound memory under churny keyspaces.
-    pub fn prune(&self, now_ms: u64, lease_ms: u64) {
-        let current = now_ms / lease_ms.max(1);
+    /// Drop lease entries whose window has passed, each judged against its own profile's
+    /// lease window — best-effort and lock-free (entries currently in use are kept). Call
+    /// periodically to bound memory under churny keyspaces.
+    pub fn prune(&self, now_ms: u64) {
         self.leases.retain(|_, cell| match cell.try_lock() {
-            Ok(lease) => lease.window >= current,
+            Ok(lease) => lease.window >= now_ms / lease.lease_ms,
             Err(_) => true,
         });
     }
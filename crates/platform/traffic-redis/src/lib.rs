@@ -1,4 +1,4 @@
-//! Redis-lease distributed backend for the `traffic` rate limiter (Step 2).
+//! Redis-lease distributed backend for the `traffic` rate limiter.
 //!
 //! Implements [`traffic::QuotaBackend`] so `distributed` profiles enforce a *fleet-global*
 //! budget **without a Redis round-trip per request**: each replica leases a chunk of the
@@ -26,9 +26,17 @@
 //! profile's `on_backend_error` policy (degrade to the local limiter, or reject). Requests
 //! served from an existing local lease never touch Redis, so a Redis blip only affects
 //! requests that need a refill.
+//!
+//! # Observability
+//!
+//! [`LeaseBook`] counts local lease hits (`infra_traffic_lease_hits_total{decision}`) and
+//! backend claims (`infra_traffic_lease_claims_total{result}`); their ratio is the
+//! amortization actually achieved. Fallbacks taken on a claim error are counted by the
+//! transport layer, which owns the `on_backend_error` policy.
 
 pub mod claim;
 pub mod lease;
+mod metrics;
 pub mod redis;
 
 pub use claim::ClaimSource;
@@ -0,0 +1,93 @@
+//! Lease-hit and claim counters — how often the amortization actually keeps requests off Redis.
+//!
+//! Instruments bind to the global meter; before `telemetry::init` (or in tests) they are no-ops.
+//! The Prometheus exporter appends `_total`, so these surface as
+//! `infra_traffic_lease_hits_total` and `infra_traffic_lease_claims_total`.
+
+use opentelemetry::{global, metrics::Counter, KeyValue};
+
+const LEASE_HITS_METRIC: &str = "infra_traffic_lease_hits";
+const LEASE_CLAIMS_METRIC: &str = "infra_traffic_lease_claims";
+
+/// How a claim round-trip ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ClaimResult {
+    /// Tokens were granted; the replica holds a fresh lease.
+    Granted,
+    /// The window's global budget is spent.
+    Exhausted,
+    /// The store was unreachable — the caller falls back per `on_backend_error`.
+    Error,
+}
+
+impl ClaimResult {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Granted => "granted",
+            Self::Exhausted => "exhausted",
+            Self::Error => "error",
+        }
+    }
+}
+
+#[derive(Clone)]
+pub(crate) struct LeaseMetrics {
+    hits: Counter<u64>,
+    claims: Counter<u64>,
+}
+
+impl LeaseMetrics {
+    pub(crate) fn new() -> Self {
+        let meter = global::meter("traffic-redis");
+        Self {
+            hits: meter
+                .u64_counter(LEASE_HITS_METRIC)
+                .with_description(
+                    "Distributed checks answered locally — from a held lease or a cached spent \
+                     window — without a backend claim, labelled by decision (allow|throttle).",
+                )
+                .build(),
+            claims: meter
+                .u64_counter(LEASE_CLAIMS_METRIC)
+                .with_description(
+                    "Backend lease claims, labelled by result (granted|exhausted|error).",
+                )
+                .build(),
+        }
+    }
+
+    pub(crate) fn hit(&self, allowed: bool) {
+        self.hits.add(1, &[hit_attr(allowed)]);
+    }
+
+    pub(crate) fn claim(&self, result: ClaimResult) {
+        self.claims.add(1, &[claim_attr(result)]);
+    }
+}
+
+fn hit_attr(allowed: bool) -> KeyValue {
+    KeyValue::new("decision", if allowed { "allow" } else { "throttle" })
+}
+
+fn claim_attr(result: ClaimResult) -> KeyValue {
+    KeyValue::new("result", result.as_str())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use opentelemetry::Value;
+
+    #[test]
+    fn hit_attr_names_the_decision() {
+        assert_eq!(hit_attr(true).value, Value::from("allow"));
+        assert_eq!(hit_attr(false).value, Value::from("throttle"));
+    }
+
+    #[test]
+    fn claim_attr_names_the_result() {
+        assert_eq!(claim_attr(ClaimResult::Granted).value, Value::from("granted"));
+        assert_eq!(claim_attr(ClaimResult::Exhausted).value, Value::from("exhausted"));
+        assert_eq!(claim_attr(ClaimResult::Error).value, Value::from("error"));
+    }
+}
@@ -81,8 +81,8 @@ impl RedisLeaseBackend {
     }
 
     /// Drop idle lease entries — call periodically (the binary's prune loop) to bound memory.
-    pub fn prune(&self, lease_ms: u64) {
-        self.book.prune(now_ms(), lease_ms);
+    pub fn prune(&self) {
+        self.book.prune(now_ms());
     }
 
     /// Keys with a live local lease — for a cardinality gauge.
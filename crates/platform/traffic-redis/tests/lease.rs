@@ -143,3 +143,20 @@ async fn backend_failure_propagates() {
     let result = book.check("k", quota(10, 5, 1_000), &claims, 0).await;
     assert!(result.is_err(), "claim failure surfaces so the layer can apply fail policy");
 }
+
+#[tokio::test]
+async fn prune_judges_each_entry_by_its_own_lease_window() {
+    let book = LeaseBook::new();
+    let claims = FakeClaims::default();
+
+    book.check("short", quota(10, 5, 1_000), &claims, 0).await.unwrap();
+    book.check("long", quota(10, 5, 60_000), &claims, 0).await.unwrap();
+    assert_eq!(book.tracked_keys(), 2);
+
+    // 1.5s later the 1s window has passed but the 60s one is still live.
+    book.prune(1_500);
+    assert_eq!(book.tracked_keys(), 1);
+
+    book.prune(60_000);
+    assert_eq!(book.tracked_keys(), 0);
+}
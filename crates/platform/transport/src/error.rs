@@ -15,6 +15,12 @@ pub enum CodecError {
 
     #[error("protobuf decode error: {0}")]
     ProtobufDecode(#[from] prost::DecodeError),
+
+    #[error("protobuf message does not map onto the payload type: {0}")]
+    ProtobufMapping(String),
+
+    #[error("unsupported payload content-type: {0}")]
+    UnsupportedContentType(String),
 }
 
 /// Top-level transport error.
@@ -41,11 +47,14 @@ pub enum TransportError {
 
     #[error("all {0} retry attempts exhausted")]
     MaxRetriesExhausted(u32),
+
+    #[error("bulkhead full — request rejected")]
+    BulkheadFull,
 }
 
 impl TransportError {
-    /// Flattens `ResilienceError<tonic::transport::Error>` (produced by circuit-breaker or
-    /// timeout layers wrapping the raw channel) into `TransportError`.
+    /// Flattens `ResilienceError<tonic::transport::Error>` (produced by the circuit-breaker
+    /// layer wrapping the raw channel) into `TransportError`.
     pub fn from_resilience_connect(
         e: resilience::error::ResilienceError<tonic::transport::Error>,
     ) -> Self {
@@ -54,6 +63,7 @@ impl TransportError {
             ResilienceError::CircuitOpen => Self::CircuitOpen,
             ResilienceError::Timeout(d) => Self::Timeout(d),
             ResilienceError::MaxRetriesExhausted(n) => Self::MaxRetriesExhausted(n),
+            ResilienceError::BulkheadFull => Self::BulkheadFull,
             ResilienceError::Inner(e) => Self::Grpc(GrpcTransportError::Connect(e)),
         }
     }
@@ -66,6 +76,7 @@ impl TransportError {
             ResilienceError::CircuitOpen => Self::CircuitOpen,
             ResilienceError::Timeout(d) => Self::Timeout(d),
             ResilienceError::MaxRetriesExhausted(n) => Self::MaxRetriesExhausted(n),
+            ResilienceError::BulkheadFull => Self::BulkheadFull,
             ResilienceError::Inner(t) => t,
         }
     }
@@ -10,15 +10,19 @@ use crate::{
             config::GrpcClientConfig,
             sync_box::BoxCloneSyncService,
         },
-        layer::outbound::{OutboundTraceLayer, OutboundTraceService},
+        layer::{
+            metrics::ClientMetricsLayer,
+            outbound::{OutboundTraceLayer, OutboundTraceService},
+            peer_token::PeerTokenLayer,
+        },
     },
 };
 
-/// A fully-composed, cloneable gRPC client stack — trace injection + circuit breaker +
-/// timeout — type-erased and flattened to a single [`TransportError`].
+/// A fully-composed, cloneable gRPC client stack — RED metrics + trace injection + peer token +
+/// circuit breaker + bulkhead + timeout — type-erased and flattened to a single [`TransportError`].
 ///
 /// Plugs straight into a generated tonic client: `PostServiceClient::new(channel)`.
-/// Because the circuit-breaker and timeout layers read their config from the originating
+/// Because the circuit-breaker, bulkhead and timeout layers read their config from the originating
 /// [`ResilienceProfile`]'s shared handles, a control-plane hot-swap reconfigures this live
 /// stack with no rebuild.
 ///
@@ -34,18 +38,33 @@ pub type ResilientChannel = BoxCloneSyncService<
 /// Composes the resilience stack over a connected channel.
 ///
 /// Layer order (outermost → innermost) matches [`OutboundTraceLayer`]'s documented placement:
-/// `Timeout → CircuitBreaker → OutboundTrace → Channel`. The interleaved `map_err`s flatten
+/// `ClientMetrics → Timeout → Bulkhead → CircuitBreaker → PeerToken → OutboundTrace → Channel`.
+/// [`PeerTokenLayer`] presents this pod's identity, which internal RPCs require. Metrics sit
+/// outside the resilience layers so a timed-out, bulkhead-rejected or short-circuited call is
+/// counted with the code the caller saw, labelled with `peer` (the dependency name). The
+/// bulkhead sits inside the timeout so time queued for a slot counts toward the deadline, and
+/// is a pass-through unless the profile sets `bulkhead`. The interleaved `map_err`s flatten
 /// each layer's `ResilienceError<_>` back into `TransportError` so the erased service exposes
 /// one error type. Function-pointer mappers keep the whole stack `Clone`.
-fn compose_resilient(channel: Channel, profile: &ResilienceProfile) -> ResilientChannel {
-    let traced = OutboundTraceLayer.layer(channel);
+fn compose_resilient(
+    channel: Channel,
+    peer:    &str,
+    profile: &ResilienceProfile,
+) -> ResilientChannel {
+    let traced = PeerTokenLayer::from_env().layer(OutboundTraceLayer.layer(channel));
 
     let svc = ServiceBuilder::new()
+        .layer(ClientMetricsLayer::new(peer))
         .map_err(
             TransportError::from_resilience
                 as fn(ResilienceError<TransportError>) -> TransportError,
         )
         .layer(profile.timeout_layer())
+        .map_err(
+            TransportError::from_resilience
+                as fn(ResilienceError<TransportError>) -> TransportError,
+        )
+        .layer(profile.bulkhead_layer())
         .map_err(
             TransportError::from_resilience_connect
                 as fn(ResilienceError<tonic::transport::Error>) -> TransportError,
@@ -64,7 +83,7 @@ fn compose_resilient(channel: Channel, profile: &ResilienceProfile) -> Resilient
 /// |--------|---------|-----------|-----------|
 /// | `connect()` | raw `Channel` | ✅ | none |
 /// | `build_traced()` | `OutboundTraceService<Channel>` | ✅ | trace injection only |
-/// | `build_resilient(&profile)` | [`ResilientChannel`] | ✅ | trace + circuit breaker + timeout |
+/// | `build_resilient(&profile)` | [`ResilientChannel`] | ✅ | metrics + trace + circuit breaker + bulkhead + timeout |
 /// | `build_from_registry(&registry)` | [`ResilientChannel`] | ✅ | resolves the profile from bindings, then as above |
 ///
 /// `build_resilient` / `build_from_registry` connect eagerly; their `*_lazy` counterparts
@@ -75,12 +94,13 @@ fn compose_resilient(channel: Channel, profile: &ResilienceProfile) -> Resilient
 ///
 /// `build_resilient` / `build_from_registry` compose the full stack for you and erase it to
 /// a single [`ResilientChannel`] that drops straight into a generated tonic client. The
-/// circuit-breaker and timeout configs come from a [`ResilienceProfile`] (resolved from
+/// circuit-breaker, bulkhead and timeout configs come from a [`ResilienceProfile`] (resolved from
 /// `infrastructure.toml` bindings via the registry), so a control-plane hot-swap reconfigures
 /// the live channel without a rebuild.
 ///
-/// `RetryLayer` is intentionally absent at the transport level: HTTP/2 request bodies are
-/// streams that can't be replayed without buffering. Apply retry at the RPC application layer.
+/// `RetryLayer` and `HedgeLayer` are intentionally absent at the transport level: HTTP/2
+/// request bodies are streams that can't be replayed without buffering. Apply them at the RPC
+/// application layer, over the cloneable proto request (see `ResilienceProfile::hedge_layer`).
 ///
 /// ```rust,ignore
 /// let registry = std::sync::Arc::new(ResilienceRegistry::from_config(infra_cfg)?);
@@ -139,7 +159,7 @@ impl GrpcClientBuilder {
         profile: &ResilienceProfile,
     ) -> Result<ResilientChannel, TransportError> {
         let channel = build_channel(&self.config).await?;
-        Ok(compose_resilient(channel, profile))
+        Ok(compose_resilient(channel, &self.config.dependency, profile))
     }
 
     /// Lazy counterpart to [`build_resilient`](Self::build_resilient): composes the same
@@ -155,7 +175,7 @@ impl GrpcClientBuilder {
         profile: &ResilienceProfile,
     ) -> Result<ResilientChannel, TransportError> {
         let channel = build_channel_lazy(&self.config)?;
-        Ok(compose_resilient(channel, profile))
+        Ok(compose_resilient(channel, &self.config.dependency, profile))
     }
 
     /// Resolves this client's resilience profile from the registry — keyed by
@@ -282,7 +302,7 @@ retry = { max_attempts = 1, backoff = { kind = "exponential", base_ms = 20, max_
 
         // Lazy channel — composes the full stack without needing a live server.
         let channel = Channel::from_static("http://127.0.0.1:50051").connect_lazy();
-        let svc: ResilientChannel = compose_resilient(channel, &profile);
+        let svc: ResilientChannel = compose_resilient(channel, "post-command", &profile);
 
         // Must be cloneable: tonic clones the service per RPC.
         let _clone = svc.clone();
@@ -295,4 +315,12 @@ retry = { max_attempts = 1, backoff = { kind = "exponential", base_ms = 20, max_
             .unwrap();
         assert_eq!(profile.timeout.load().duration.as_millis(), 250);
     }
+
+    #[test]
+    fn bulkhead_rejection_flattens_to_transport_error() {
+        let err = TransportError::from_resilience(ResilienceError::BulkheadFull);
+        assert!(matches!(err, TransportError::BulkheadFull));
+        let err = TransportError::from_resilience_connect(ResilienceError::BulkheadFull);
+        assert!(matches!(err, TransportError::BulkheadFull));
+    }
 }
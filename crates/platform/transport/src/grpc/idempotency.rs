@@ -0,0 +1,95 @@
+use prost::Message;
+use tonic::metadata::MetadataMap;
+use tonic::Status;
+use uuid::Uuid;
+
+/// Request header a client sets so a retried command replays the original
+/// response instead of running again.
+pub const IDEMPOTENCY_KEY_HEADER: &str = "idempotency-key";
+
+/// Longest key accepted — room for any UUID or ULID rendering with a prefix.
+pub const MAX_IDEMPOTENCY_KEY_LEN: usize = 255;
+
+/// UUIDv5 namespace for request fingerprints.
+const FINGERPRINT_NAMESPACE: Uuid = Uuid::from_u128(0x2d7e_91c3_5a40_4f6b_b813_0c9e_64f2_a7d1);
+
+/// Reads the `idempotency-key` header from an inbound request's metadata.
+///
+/// `Ok(None)` when the header is absent. A present key must be 1–255 visible
+/// ASCII characters; anything else is `INVALID_ARGUMENT` rather than silently
+/// ignored, since a client that sent a key is relying on it.
+///
+/// ```rust,ignore
+/// let key = idempotency_key(request.metadata())?;
+/// let req = request.into_inner();
+/// let fingerprint = payload_fingerprint(&req);
+/// let mut envelope = Envelope::new(Uuid::now_v7(), cmd);
+/// if let Some(key) = key {
+///     envelope = envelope.with_idempotency_key(key, fingerprint);
+/// }
+/// ```
+pub fn idempotency_key(metadata: &MetadataMap) -> Result<Option<String>, Status> {
+    let Some(value) = metadata.get(IDEMPOTENCY_KEY_HEADER) else {
+        return Ok(None);
+    };
+    let key = value
+        .to_str()
+        .ok()
+        .filter(|key| {
+            (1..=MAX_IDEMPOTENCY_KEY_LEN).contains(&key.len())
+                && key.bytes().all(|b| b.is_ascii_graphic())
+        })
+        .ok_or_else(|| {
+            Status::invalid_argument(format!(
+                "{IDEMPOTENCY_KEY_HEADER} must be 1-{MAX_IDEMPOTENCY_KEY_LEN} visible ASCII characters"
+            ))
+        })?;
+    Ok(Some(key.to_owned()))
+}
+
+/// A stable fingerprint of a request message, recorded with its idempotency key
+/// so a reuse of the key for a different request is rejected rather than answered
+/// with the first request's response.
+///
+/// Taken over the protobuf encoding of the message as received — before the
+/// handler fills in anything server-side, such as a minted id.
+pub fn payload_fingerprint<M: Message>(request: &M) -> String {
+    Uuid::new_v5(&FINGERPRINT_NAMESPACE, &request.encode_to_vec()).simple().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn with_key(key: &str) -> MetadataMap {
+        let mut metadata = MetadataMap::new();
+        metadata.insert(IDEMPOTENCY_KEY_HEADER, key.parse().unwrap());
+        metadata
+    }
+
+    #[test]
+    fn an_absent_header_is_no_key() {
+        assert_eq!(idempotency_key(&MetadataMap::new()).unwrap(), None);
+    }
+
+    #[test]
+    fn a_visible_ascii_key_is_returned_verbatim() {
+        let key = "0192f3c4-7d1e-7b2a-9c4d-5e6f7a8b9c0d";
+        assert_eq!(idempotency_key(&with_key(key)).unwrap().as_deref(), Some(key));
+    }
+
+    #[test]
+    fn fingerprints_follow_the_payload() {
+        let a = payload_fingerprint(&"caption a".to_owned());
+        assert_eq!(a, payload_fingerprint(&"caption a".to_owned()));
+        assert_ne!(a, payload_fingerprint(&"caption b".to_owned()));
+    }
+
+    #[test]
+    fn malformed_keys_are_rejected() {
+        for bad in ["", "has space", &"k".repeat(MAX_IDEMPOTENCY_KEY_LEN + 1)] {
+            let status = idempotency_key(&with_key(bad)).unwrap_err();
+            assert_eq!(status.code(), tonic::Code::InvalidArgument, "accepted {bad:?}");
+        }
+    }
+}
@@ -0,0 +1,255 @@
+//! Server-side adaptive concurrency Tower layer — load shedding by requests in flight.
+//!
+//! Where [`TrafficLayer`](super::TrafficLayer) caps arrival *rate*, this layer caps how
+//! many requests the replica works on at once, at the limit the replica's
+//! [`ConcurrencyLimiter`] discovers from latency. Each request takes a permit for its
+//! method's [`Priority`] class (from `[traffic.concurrency.priorities]`; gRPC health checks
+//! are always `critical`). A request that finds its class full is shed with `UNAVAILABLE` —
+//! the code a resilient caller retries on another replica — without calling the inner
+//! service.
+//!
+//! # Placement
+//!
+//! Installed by [`crate::grpc::server::GrpcServerBuilder`] just inside the traffic layer, so
+//! a rate-limited flood never occupies a slot, and outside the runtime's auth layer, so
+//! token verification counts toward the latency the limit adapts to.
+//!
+//! # What is measured
+//!
+//! The permit is released when the response **head** is ready — the whole call for unary
+//! RPCs, time-to-first-byte for streams. A response carrying `UNAVAILABLE` or
+//! `DEADLINE_EXCEEDED` (a struggling downstream) is reported as dropped and backs the limit
+//! off; other codes are latency samples. A call the client cancels frees its slot without
+//! a sample.
+//!
+//! # Observability
+//!
+//! | Instrument | Prometheus series | Labels |
+//! |---|---|---|
+//! | `infra_traffic_shed` | `infra_traffic_shed_total` | `priority`, `status` (`enforced` \| `shadow`) |
+//! | `infra_traffic_concurrency_limit` | gauge | — |
+//! | `infra_traffic_concurrency_in_flight` | gauge | — |
+//!
+//! In shadow mode (`enforce = false`) a would-shed request is counted and admitted, so the
+//! `shadow` series shows what enforcing would reject at the current limit.
+
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+use futures::future::BoxFuture;
+use infra_config::TrafficRegistry;
+use opentelemetry::{
+    global,
+    metrics::{Counter, Gauge},
+    KeyValue,
+};
+use tonic::{body::Body, Code, Status};
+use tower::{Layer, Service};
+use traffic::{ConcurrencyLimiter, ConcurrencyPermit, Priority};
+
+use super::metrics::response_code;
+
+/// Shed counter name; surfaces as `infra_traffic_shed_total`.
+const SHED_METRIC: &str = "infra_traffic_shed";
+
+/// Path prefix of the gRPC health service — always `critical`, so an overloaded replica
+/// still answers its probes.
+const HEALTH_PREFIX: &str = "/grpc.health.v1.Health/";
+
+/// The shed counter and limit gauges, bound to the global meter (no-op before telemetry).
+#[derive(Clone)]
+struct ConcurrencyInstruments {
+    shed: Counter<u64>,
+    limit: Gauge<u64>,
+    in_flight: Gauge<u64>,
+}
+
+impl ConcurrencyInstruments {
+    fn new() -> Self {
+        let meter = global::meter("transport");
+        Self {
+            shed: meter
+                .u64_counter(SHED_METRIC)
+                .with_description(
+                    "Requests shed by the adaptive concurrency limit, labelled by priority \
+                     and status (enforced|shadow).",
+                )
+                .build(),
+            limit: meter
+                .u64_gauge("infra_traffic_concurrency_limit")
+                .with_description("The replica's current adaptive concurrency limit.")
+                .build(),
+            in_flight: meter
+                .u64_gauge("infra_traffic_concurrency_in_flight")
+                .with_description("Requests holding a concurrency permit.")
+                .build(),
+        }
+    }
+
+    fn observe(&self, limiter: &ConcurrencyLimiter) {
+        self.limit.record(u64::from(limiter.limit()), &[]);
+        self.in_flight.record(u64::from(limiter.in_flight()), &[]);
+    }
+}
+
+/// The limiter and the registry its priority table is read from.
+#[derive(Clone)]
+struct Limiting {
+    limiter: ConcurrencyLimiter,
+    registry: Arc<TrafficRegistry>,
+}
+
+/// Tower [`Layer`] that sheds inbound gRPC requests past the replica's concurrency limit.
+///
+/// Holds an `Option`: without a `[traffic.concurrency]` block the layer is a transparent
+/// pass-through, so the server's type is identical whether or not the limit is enabled.
+#[derive(Clone)]
+pub struct ConcurrencyLayer {
+    limiting: Option<Limiting>,
+    instruments: ConcurrencyInstruments,
+}
+
+impl ConcurrencyLayer {
+    /// A pass-through layer (no limit).
+    pub fn disabled() -> Self {
+        Self { limiting: None, instruments: ConcurrencyInstruments::new() }
+    }
+
+    /// A layer enforcing `registry`'s concurrency limiter — a pass-through when the
+    /// registry has none.
+    pub fn new(registry: Arc<TrafficRegistry>) -> Self {
+        let limiting = registry.concurrency().map(|limiter| Limiting { limiter, registry });
+        Self { limiting, instruments: ConcurrencyInstruments::new() }
+    }
+}
+
+impl<S> Layer<S> for ConcurrencyLayer {
+    type Service = ConcurrencyService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        ConcurrencyService {
+            inner,
+            limiting: self.limiting.clone(),
+            instruments: self.instruments.clone(),
+        }
+    }
+}
+
+/// The concrete service produced by [`ConcurrencyLayer`].
+#[derive(Clone)]
+pub struct ConcurrencyService<S> {
+    inner: S,
+    limiting: Option<Limiting>,
+    instruments: ConcurrencyInstruments,
+}
+
+impl<S> Service<http::Request<Body>> for ConcurrencyService<S>
+where
+    S: Service<http::Request<Body>, Response = http::Response<Body>> + Send + 'static,
+    S::Future: Send + 'static,
+    S::Error: Send + 'static,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = BoxFuture<'static, Result<S::Response, S::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, req: http::Request<Body>) -> Self::Future {
+        let Some(Limiting { limiter, registry }) = self.limiting.as_ref() else {
+            return Box::pin(self.inner.call(req));
+        };
+
+        let method = req.uri().path();
+        let priority = if method.starts_with(HEALTH_PREFIX) {
+            Priority::Critical
+        } else {
+            registry.priority_for(method)
+        };
+        let permit = match limiter.try_acquire(priority) {
+            Some(permit) => permit,
+            None => {
+                let enforce = limiter.enforce();
+                self.instruments.shed.add(1, &shed_attrs(priority, enforce));
+                if enforce {
+                    tracing::debug!(
+                        rpc.method = %method,
+                        priority = priority.as_str(),
+                        limit = limiter.limit(),
+                        "traffic: request shed at the concurrency limit"
+                    );
+                    self.instruments.observe(limiter);
+                    return Box::pin(async { Ok(shed_response()) });
+                }
+                tracing::debug!(
+                    rpc.method = %method,
+                    priority = priority.as_str(),
+                    "traffic: would shed (shadow mode — admitted)"
+                );
+                limiter.acquire()
+            }
+        };
+
+        let limiter = limiter.clone();
+        let instruments = self.instruments.clone();
+        let future = self.inner.call(req);
+        Box::pin(async move {
+            let result = future.await;
+            match &result {
+                Ok(response) => report(permit, response_code(response)),
+                // A connection-level failure: free the slot without a sample.
+                Err(_) => drop(permit),
+            }
+            instruments.observe(&limiter);
+            result
+        })
+    }
+}
+
+/// Reports how a call ended: codes that signal a struggling downstream back the limit off.
+fn report(permit: ConcurrencyPermit, code: Code) {
+    match code {
+        Code::Unavailable | Code::DeadlineExceeded => permit.dropped(),
+        _ => permit.success(),
+    }
+}
+
+/// Attribute set for the shed counter.
+fn shed_attrs(priority: Priority, enforce: bool) -> [KeyValue; 2] {
+    [
+        KeyValue::new("priority", priority.as_str()),
+        KeyValue::new("status", if enforce { "enforced" } else { "shadow" }),
+    ]
+}
+
+/// The `UNAVAILABLE` response for a shed request.
+fn shed_response() -> http::Response<Body> {
+    Status::unavailable("server overloaded").into_http()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use opentelemetry::Value;
+
+    fn has(attrs: &[KeyValue], key: &str, val: &str) -> bool {
+        attrs
+            .iter()
+            .any(|kv| kv.key.as_str() == key && kv.value == Value::from(val.to_string()))
+    }
+
+    #[test]
+    fn shed_attrs_carry_priority_and_status() {
+        let attrs = shed_attrs(Priority::Sheddable, false);
+        assert!(has(&attrs, "priority", "sheddable"));
+        assert!(has(&attrs, "status", "shadow"));
+    }
+
+    #[test]
+    fn shed_response_is_unavailable() {
+        let response = shed_response();
+        assert_eq!(response_code(&response), Code::Unavailable);
+    }
+}
@@ -0,0 +1,347 @@
+//! RED (rate / errors / duration) Tower layers for gRPC servers and clients.
+//!
+//! Every served and every issued RPC is counted and timed, labelled by the full method path
+//! and its gRPC status code name (`OK`, `NOT_FOUND`, …) — the error rate is the share of
+//! non-`OK` codes. The instruments come from the OTel global meter, so the Prometheus
+//! exporter installed by `telemetry::init` surfaces them as:
+//!
+//! | Instrument | Prometheus series | Labels |
+//! |---|---|---|
+//! | `rpc_server_requests` | `rpc_server_requests_total` | `rpc_method`, `grpc_code` |
+//! | `rpc_server_duration` | `rpc_server_duration_seconds` (histogram) | `rpc_method`, `grpc_code` |
+//! | `rpc_client_requests` | `rpc_client_requests_total` | `peer`, `rpc_method`, `grpc_code` |
+//! | `rpc_client_duration` | `rpc_client_duration_seconds` (histogram) | `peer`, `rpc_method`, `grpc_code` |
+//!
+//! # What is measured
+//!
+//! Both layers stop the clock when the response **head** arrives and read the status from
+//! its `grpc-status` header. That is exact for unary RPCs — tonic sends a handler's error as
+//! a trailers-only response, and a success carries no header status (`OK`) — and measures
+//! time-to-first-byte for streams; a stream that fails after its head was sent is counted
+//! by its head.
+//!
+//! # Cardinality
+//!
+//! Method paths are bounded by the proto surface, except for calls to methods the server
+//! does not implement: those collapse to a single [`UNIMPLEMENTED_ROUTE`] label so a scan of
+//! arbitrary paths can't grow the time-series database.
+
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use std::time::Instant;
+
+use futures::future::BoxFuture;
+use opentelemetry::{
+    global,
+    metrics::{Counter, Histogram},
+    KeyValue,
+};
+use tonic::{body::Body, Code};
+use tower::{Layer, Service};
+
+use crate::error::TransportError;
+use crate::grpc::error::GrpcTransportError;
+
+/// Route label for calls the server answered with `UNIMPLEMENTED` — bounds cardinality.
+pub const UNIMPLEMENTED_ROUTE: &str = "<unimplemented>";
+
+/// Histogram buckets (seconds): 1 ms … 10 s, dense where the fleet's p95s live.
+const DURATION_BUCKETS: &[f64] = &[
+    0.001, 0.0025, 0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0,
+];
+
+/// A counter + latency histogram pair for one side of the wire.
+#[derive(Clone)]
+struct RedInstruments {
+    requests: Counter<u64>,
+    duration: Histogram<f64>,
+}
+
+impl RedInstruments {
+    /// Builds the instruments from the global meter. Before `telemetry::init` (or in tests)
+    /// this binds to a no-op meter, so recording is harmless rather than a panic.
+    fn new(side: &str) -> Self {
+        let meter = global::meter("transport");
+        Self {
+            requests: meter
+                .u64_counter(format!("rpc_{side}_requests"))
+                .with_description("gRPC calls, labelled by method and gRPC status code.")
+                .build(),
+            duration: meter
+                .f64_histogram(format!("rpc_{side}_duration"))
+                .with_unit("s")
+                .with_description("gRPC call latency to the response head, in seconds.")
+                .with_boundaries(DURATION_BUCKETS.to_vec())
+                .build(),
+        }
+    }
+
+    fn record(&self, started: Instant, attrs: &[KeyValue]) {
+        self.requests.add(1, attrs);
+        self.duration.record(started.elapsed().as_secs_f64(), attrs);
+    }
+}
+
+/// The `grpc-status` carried by a response head, or `OK` when it carries none (the status
+/// then travels in the trailers of a successful call).
+pub(super) fn response_code<B>(response: &http::Response<B>) -> Code {
+    response
+        .headers()
+        .get("grpc-status")
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.parse::<i32>().ok())
+        .map(Code::from_i32)
+        .unwrap_or(Code::Ok)
+}
+
+/// The status a caller observes for a call that failed below the gRPC layer.
+fn transport_error_code(error: &TransportError) -> Code {
+    match error {
+        TransportError::Timeout(_) => Code::DeadlineExceeded,
+        TransportError::Grpc(GrpcTransportError::Status { code, .. }) => *code,
+        _ => Code::Unavailable,
+    }
+}
+
+/// `SCREAMING_SNAKE_CASE` label for a status code, matching the gRPC spec's code names.
+fn code_label(code: Code) -> &'static str {
+    match code {
+        Code::Ok => "OK",
+        Code::Cancelled => "CANCELLED",
+        Code::Unknown => "UNKNOWN",
+        Code::InvalidArgument => "INVALID_ARGUMENT",
+        Code::DeadlineExceeded => "DEADLINE_EXCEEDED",
+        Code::NotFound => "NOT_FOUND",
+        Code::AlreadyExists => "ALREADY_EXISTS",
+        Code::PermissionDenied => "PERMISSION_DENIED",
+        Code::ResourceExhausted => "RESOURCE_EXHAUSTED",
+        Code::FailedPrecondition => "FAILED_PRECONDITION",
+        Code::Aborted => "ABORTED",
+        Code::OutOfRange => "OUT_OF_RANGE",
+        Code::Unimplemented => "UNIMPLEMENTED",
+        Code::Internal => "INTERNAL",
+        Code::Unavailable => "UNAVAILABLE",
+        Code::DataLoss => "DATA_LOSS",
+        Code::Unauthenticated => "UNAUTHENTICATED",
+    }
+}
+
+/// Attribute set for a served call; unimplemented paths collapse to one route label.
+fn server_attrs(method: &str, code: Code) -> [KeyValue; 2] {
+    let route = if code == Code::Unimplemented { UNIMPLEMENTED_ROUTE } else { method };
+    [
+        KeyValue::new("rpc_method", route.to_string()),
+        KeyValue::new("grpc_code", code_label(code)),
+    ]
+}
+
+/// Attribute set for an issued call, scoped by the logical dependency it targets.
+fn client_attrs(peer: &str, method: &str, code: Code) -> [KeyValue; 3] {
+    [
+        KeyValue::new("peer", peer.to_string()),
+        KeyValue::new("rpc_method", method.to_string()),
+        KeyValue::new("grpc_code", code_label(code)),
+    ]
+}
+
+// ── Server ────────────────────────────────────────────────────────────────────
+
+/// Tower [`Layer`] recording request count, status code and latency for every served RPC.
+///
+/// Installed by [`crate::grpc::server::GrpcServerBuilder`] just inside the trace span and
+/// outside rate-limiting, so throttled and denied calls are counted with their status.
+#[derive(Clone)]
+pub struct ServerMetricsLayer {
+    instruments: RedInstruments,
+}
+
+impl ServerMetricsLayer {
+    pub fn new() -> Self {
+        Self { instruments: RedInstruments::new("server") }
+    }
+}
+
+impl Default for ServerMetricsLayer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S> Layer<S> for ServerMetricsLayer {
+    type Service = ServerMetricsService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        ServerMetricsService { inner, instruments: self.instruments.clone() }
+    }
+}
+
+/// The concrete service produced by [`ServerMetricsLayer`].
+#[derive(Clone)]
+pub struct ServerMetricsService<S> {
+    inner: S,
+    instruments: RedInstruments,
+}
+
+impl<S, B, ResBody> Service<http::Request<B>> for ServerMetricsService<S>
+where
+    S: Service<http::Request<B>, Response = http::Response<ResBody>> + Send + 'static,
+    S::Future: Send + 'static,
+    S::Error: Send + 'static,
+    ResBody: Send + 'static,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = BoxFuture<'static, Result<S::Response, S::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, req: http::Request<B>) -> Self::Future {
+        let method = req.uri().path().to_owned();
+        let instruments = self.instruments.clone();
+        let started = Instant::now();
+        let future = self.inner.call(req);
+
+        Box::pin(async move {
+            let result = future.await;
+            // An `Err` here is a connection-level failure hyper will surface as a reset;
+            // the caller sees it as UNAVAILABLE.
+            let code = match &result {
+                Ok(response) => response_code(response),
+                Err(_) => Code::Unavailable,
+            };
+            instruments.record(started, &server_attrs(&method, code));
+            result
+        })
+    }
+}
+
+// ── Client ────────────────────────────────────────────────────────────────────
+
+/// Tower [`Layer`] recording request count, status code and latency for every issued RPC.
+///
+/// Composed outermost in the resilient client stack, so timeouts and open-circuit
+/// rejections are counted (as `DEADLINE_EXCEEDED` / `UNAVAILABLE`) alongside the
+/// server's answers.
+#[derive(Clone)]
+pub struct ClientMetricsLayer {
+    peer: Arc<str>,
+    instruments: RedInstruments,
+}
+
+impl ClientMetricsLayer {
+    /// `peer` is the logical dependency name ([`crate::grpc::client::GrpcClientConfig::dependency`]).
+    pub fn new(peer: impl Into<Arc<str>>) -> Self {
+        Self { peer: peer.into(), instruments: RedInstruments::new("client") }
+    }
+}
+
+impl<S> Layer<S> for ClientMetricsLayer {
+    type Service = ClientMetricsService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        ClientMetricsService {
+            inner,
+            peer: Arc::clone(&self.peer),
+            instruments: self.instruments.clone(),
+        }
+    }
+}
+
+/// The concrete service produced by [`ClientMetricsLayer`].
+#[derive(Clone)]
+pub struct ClientMetricsService<S> {
+    inner: S,
+    peer: Arc<str>,
+    instruments: RedInstruments,
+}
+
+impl<S> Service<http::Request<Body>> for ClientMetricsService<S>
+where
+    S: Service<http::Request<Body>, Response = http::Response<Body>, Error = TransportError>
+        + Send
+        + 'static,
+    S::Future: Send + 'static,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = BoxFuture<'static, Result<S::Response, S::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, req: http::Request<Body>) -> Self::Future {
+        let method = req.uri().path().to_owned();
+        let peer = Arc::clone(&self.peer);
+        let instruments = self.instruments.clone();
+        let started = Instant::now();
+        let future = self.inner.call(req);
+
+        Box::pin(async move {
+            let result = future.await;
+            let code = match &result {
+                Ok(response) => response_code(response),
+                Err(error) => transport_error_code(error),
+            };
+            instruments.record(started, &client_attrs(&peer, &method, code));
+            result
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use opentelemetry::Value;
+
+    fn has(attrs: &[KeyValue], key: &str, val: &str) -> bool {
+        attrs
+            .iter()
+            .any(|kv| kv.key.as_str() == key && kv.value == Value::from(val.to_string()))
+    }
+
+    fn response(status: Option<&'static str>) -> http::Response<()> {
+        let mut builder = http::Response::builder();
+        if let Some(status) = status {
+            builder = builder.header("grpc-status", status);
+        }
+        builder.body(()).unwrap()
+    }
+
+    #[test]
+    fn head_status_is_read_and_absent_means_ok() {
+        assert_eq!(response_code(&response(None)), Code::Ok);
+        assert_eq!(response_code(&response(Some("5"))), Code::NotFound);
+        assert_eq!(response_code(&response(Some("16"))), Code::Unauthenticated);
+    }
+
+    #[test]
+    fn server_attrs_carry_method_and_code_name() {
+        let attrs = server_attrs("/post.v1.PostService/GetPost", Code::NotFound);
+        assert!(has(&attrs, "rpc_method", "/post.v1.PostService/GetPost"));
+        assert!(has(&attrs, "grpc_code", "NOT_FOUND"));
+    }
+
+    #[test]
+    fn unimplemented_paths_collapse_to_one_route() {
+        let attrs = server_attrs("/scanner/whatever-123", Code::Unimplemented);
+        assert!(has(&attrs, "rpc_method", UNIMPLEMENTED_ROUTE));
+    }
+
+    #[test]
+    fn client_failures_below_grpc_map_to_the_code_the_caller_sees() {
+        assert_eq!(
+            transport_error_code(&TransportError::Timeout(Duration::from_millis(250))),
+            Code::DeadlineExceeded
+        );
+        assert_eq!(transport_error_code(&TransportError::CircuitOpen), Code::Unavailable);
+
+        let attrs = client_attrs("post-command", "/post.v1.PostService/GetPost", Code::Unavailable);
+        assert!(has(&attrs, "peer", "post-command"));
+        assert!(has(&attrs, "grpc_code", "UNAVAILABLE"));
+    }
+}
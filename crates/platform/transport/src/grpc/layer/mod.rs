@@ -1,7 +1,13 @@
+pub mod concurrency;
 pub mod inbound;
+pub mod metrics;
 pub mod outbound;
+pub mod peer_token;
 pub mod traffic;
 
+pub use concurrency::ConcurrencyLayer;
 pub use inbound::InboundTraceLayer;
+pub use metrics::{ClientMetricsLayer, ServerMetricsLayer};
 pub use outbound::OutboundTraceLayer;
-pub use traffic::TrafficLayer;
+pub use peer_token::{PeerTokenLayer, PEER_TOKEN_HEADER};
+pub use traffic::{TrafficAttributes, TrafficLayer};
@@ -0,0 +1,165 @@
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex, OnceLock};
+use std::task::{Context, Poll};
+use std::time::{Duration, Instant};
+
+use http::HeaderValue;
+use tower::{Layer, Service};
+
+/// Header carrying the calling service's identity token on internal RPCs.
+pub const PEER_TOKEN_HEADER: &str = "x-peer-token";
+
+/// Environment variable naming the peer-token file.
+pub const PEER_TOKEN_PATH_ENV: &str = "PEER_TOKEN_PATH";
+
+/// Where the fleet's deployments mount the projected ServiceAccount token.
+const DEFAULT_PEER_TOKEN_PATH: &str = "/var/run/secrets/core-platform/peer/token";
+
+/// How long a read of the token file is reused. The kubelet rewrites the file at
+/// 80% of the token's lifetime (an hour), so a minute never serves an expired one.
+const REREAD_AFTER: Duration = Duration::from_secs(60);
+
+/// Tower [`Layer`] that attaches this pod's peer token to every outgoing gRPC request
+/// as [`PEER_TOKEN_HEADER`] — the proof of identity the callee's auth layer requires
+/// on internal RPCs.
+///
+/// The token is a projected Kubernetes ServiceAccount token read from
+/// [`PEER_TOKEN_PATH_ENV`] (default `/var/run/secrets/core-platform/peer/token`) and
+/// re-read once a minute, since the kubelet rotates it in place. With no file (local
+/// runs) the request goes out without the header and the callee decides.
+///
+/// # Placement in the stack
+///
+/// Beside [`OutboundTraceLayer`](super::OutboundTraceLayer), directly around the
+/// channel, so every attempt carries the current token:
+///
+/// ```text
+/// CircuitBreakerLayer
+///   └─ PeerTokenLayer        ← here
+///       └─ OutboundTraceLayer
+///           └─ tonic::transport::Channel
+/// ```
+#[derive(Debug, Clone)]
+pub struct PeerTokenLayer {
+    source: Arc<PeerTokenSource>,
+}
+
+impl PeerTokenLayer {
+    /// The process-wide layer over [`PEER_TOKEN_PATH_ENV`]; every client shares one
+    /// cached read.
+    pub fn from_env() -> Self {
+        static SHARED: OnceLock<Arc<PeerTokenSource>> = OnceLock::new();
+        let source = SHARED.get_or_init(|| {
+            let path = std::env::var(PEER_TOKEN_PATH_ENV)
+                .unwrap_or_else(|_| DEFAULT_PEER_TOKEN_PATH.to_owned());
+            Arc::new(PeerTokenSource::new(path.into()))
+        });
+        Self { source: Arc::clone(source) }
+    }
+
+    /// A layer reading the token from `path`.
+    pub fn from_path(path: impl Into<PathBuf>) -> Self {
+        Self { source: Arc::new(PeerTokenSource::new(path.into())) }
+    }
+}
+
+impl<S> Layer<S> for PeerTokenLayer {
+    type Service = PeerTokenService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        PeerTokenService { inner, source: Arc::clone(&self.source) }
+    }
+}
+
+/// The concrete service produced by [`PeerTokenLayer`].
+#[derive(Debug, Clone)]
+pub struct PeerTokenService<S> {
+    inner: S,
+    source: Arc<PeerTokenSource>,
+}
+
+impl<S, B> Service<http::Request<B>> for PeerTokenService<S>
+where
+    S: Service<http::Request<B>>,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = S::Future;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, mut req: http::Request<B>) -> Self::Future {
+        if let Some(token) = self.source.current() {
+            req.headers_mut().insert(PEER_TOKEN_HEADER, token);
+        }
+        self.inner.call(req)
+    }
+}
+
+/// The token file plus the last read of it.
+#[derive(Debug)]
+struct PeerTokenSource {
+    path: PathBuf,
+    cached: Mutex<Option<(Instant, Option<HeaderValue>)>>,
+}
+
+impl PeerTokenSource {
+    fn new(path: PathBuf) -> Self {
+        Self { path, cached: Mutex::new(None) }
+    }
+
+    /// The token, re-read when the cached read is older than [`REREAD_AFTER`]. The
+    /// file is a few hundred bytes on a tmpfs, so the read stays on the calling task.
+    fn current(&self) -> Option<HeaderValue> {
+        let mut cached = self.cached.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        if let Some((read_at, token)) = cached.as_ref()
+            && read_at.elapsed() < REREAD_AFTER
+        {
+            return token.clone();
+        }
+        let token = match std::fs::read_to_string(&self.path) {
+            Ok(raw) => HeaderValue::from_str(raw.trim()).ok().filter(|v| !v.is_empty()),
+            Err(error) => {
+                tracing::debug!(path = %self.path.display(), %error, "no peer token to present");
+                None
+            }
+        };
+        *cached = Some((Instant::now(), token.clone()));
+        token
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::Infallible;
+
+    use tower::ServiceExt;
+
+    use super::*;
+
+    async fn sent_token(layer: &PeerTokenLayer) -> Option<String> {
+        let inner = tower::service_fn(|req: http::Request<()>| async move {
+            let token = req.headers().get(PEER_TOKEN_HEADER).map(|v| v.to_str().unwrap().to_owned());
+            Ok::<_, Infallible>(token)
+        });
+        layer.layer(inner).oneshot(http::Request::new(())).await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn attaches_the_token_file_contents() {
+        let path = std::env::temp_dir().join(format!("peer-token-{}", std::process::id()));
+        std::fs::write(&path, "header.claims.signature\n").unwrap();
+
+        let token = sent_token(&PeerTokenLayer::from_path(&path)).await;
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(token.as_deref(), Some("header.claims.signature"));
+    }
+
+    #[tokio::test]
+    async fn sends_no_header_without_a_token_file() {
+        let layer = PeerTokenLayer::from_path("/nonexistent/peer/token");
+        assert_eq!(sent_token(&layer).await, None);
+    }
+}
@@ -20,6 +20,11 @@
 //! cardinality is bounded — unbound methods collapse to a single `<unbound>` label so a
 //! flood of arbitrary paths can't blow up the time-series database.
 //!
+//! A `distributed` profile that could not get a global decision — the backend errored, or
+//! none is installed — increments `infra_traffic_backend_fallback_total`, labelled by
+//! `profile` and `outcome` (`local` when it degraded to the per-replica limiter, `rejected`
+//! under `fail_closed`, `unwired` when no backend exists).
+//!
 //! # `per_caller` and identity
 //!
 //! `per_caller` keys on the caller identity carried in an inbound header injected by the
@@ -29,29 +34,67 @@
 //! absent (an unauthenticated method, or — wrongly — a request that bypassed the mesh) the
 //! layer **degrades to method-level keying** rather than collapsing all callers into one
 //! bucket: it still limits, just not per-identity. This is logged at debug.
+//!
+//! # Other scopes
+//!
+//! Every scope keys *within* the method, and every scope degrades to method-level keying
+//! when its source is missing — a request is never exempt because it lacks a key.
+//!
+//! * `per_ip` reads the edge-injected client-IP header (default
+//!   [`DEFAULT_CLIENT_IP_HEADER`]), then the connection's peer address. Behind a proxy
+//!   without the header, the peer is the proxy, so all its clients share one bucket.
+//! * `per_tenant` reads the edge-injected tenant header (default [`DEFAULT_TENANT_HEADER`]),
+//!   which carries the verified principal's `tenant_id`. The layer runs before the runtime's
+//!   auth layer — floods are shed before any token is verified — so it relies on the edge
+//!   rather than the in-process principal.
+//! * `per_attribute` keys on a request field, named by the profile's `attribute`. Services
+//!   declare which protobuf field that name maps to per method in [`TrafficAttributes`];
+//!   the layer buffers the body, reads the field from the first message, and hands the
+//!   buffered body on. Declare unary methods only — a stream would be drained to its end
+//!   before the handler saw it. Buffering stops at [`MAX_BUFFERED_BODY`] (tonic's decoding
+//!   limit plus the frame header, which no service raises): a larger body is answered with
+//!   `RESOURCE_EXHAUSTED`, as the server would have answered it.
 
+use std::collections::HashMap;
 use std::sync::Arc;
 use std::task::{Context, Poll};
 use std::time::Duration;
 
+use bytes::Bytes;
 use futures::future::BoxFuture;
 use http::header::HeaderName;
 use http::HeaderMap;
+use http_body_util::{BodyExt, Full, LengthLimitError, Limited};
 use infra_config::TrafficRegistry;
 use opentelemetry::{global, metrics::Counter, KeyValue};
+use tonic::transport::server::{TcpConnectInfo, TlsConnectInfo};
 use tonic::{body::Body, Status};
 use tower::{Layer, Service};
 use traffic::{BackendError, QuotaBackend, Scope, TrafficDecision, TrafficProfile};
 
-use crate::grpc::server::config::DEFAULT_IDENTITY_HEADER;
+use crate::grpc::server::config::{
+    DEFAULT_CLIENT_IP_HEADER, DEFAULT_IDENTITY_HEADER, DEFAULT_TENANT_HEADER,
+};
 
 /// Instrument name. The Prometheus exporter appends `_total` for monotonic sums, so this
 /// surfaces as `infra_traffic_throttled_total`; OTLP/collector backends see it as-is.
 const THROTTLE_METRIC: &str = "infra_traffic_throttled";
 
+/// Fallback instrument name; surfaces as `infra_traffic_backend_fallback_total`.
+const FALLBACK_METRIC: &str = "infra_traffic_backend_fallback";
+
 /// Route label for methods with no explicit binding — bounds metric cardinality.
 const UNBOUND_ROUTE: &str = "<unbound>";
 
+/// Longest attribute value keyed on; longer values (never a real id) key per-method, so a
+/// client can't mint arbitrarily large limiter keys.
+const MAX_ATTRIBUTE_LEN: usize = 128;
+
+/// Most bytes `per_attribute` keying buffers: one message at tonic's default decoding
+/// limit (4 MiB) plus its 5-byte frame header. Anything larger would be rejected by the
+/// server's decoder anyway, so it is rejected before it is held in memory.
+pub const MAX_BUFFERED_BODY: usize = 4 * 1024 * 1024 + 5;
+
 /// Builds the throttle counter from the global meter. The global provider is installed by
 /// `telemetry::init`; before that (or in tests) this binds to a no-op meter, so `add` is a
 /// harmless no-op rather than a panic.
@@ -65,6 +108,93 @@ fn throttle_counter() -> Counter<u64> {
         .build()
 }
 
+/// Builds the distributed-fallback counter from the global meter (no-op before telemetry).
+fn fallback_counter() -> Counter<u64> {
+    global::meter("transport")
+        .u64_counter(FALLBACK_METRIC)
+        .with_description(
+            "Distributed-profile checks that fell back because the quota backend errored or \
+             none is installed, labelled by profile and outcome (local|rejected|unwired).",
+        )
+        .build()
+}
+
+/// How a distributed check was settled without a global decision.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Fallback {
+    /// Backend errored under `fail_open` — the local limiter decided.
+    Local,
+    /// Backend errored under `fail_closed` — the request was throttled.
+    Rejected,
+    /// No backend installed — the local limiter decided.
+    Unwired,
+}
+
+impl Fallback {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Local => "local",
+            Self::Rejected => "rejected",
+            Self::Unwired => "unwired",
+        }
+    }
+}
+
+/// A service's declaration of the request fields `per_attribute` profiles may key on.
+///
+/// Maps `(method, attribute)` to the top-level protobuf field number carrying it. Keys are
+/// full gRPC paths; the builder takes bare method names and prefixes the service name given
+/// to [`for_service`](Self::for_service), like the runtime's access policy. Which attribute
+/// a method is *limited* on stays in `[traffic]` config — this only says where to find it.
+///
+/// ```rust,ignore
+/// TrafficAttributes::for_service("comment.v1.CommentService")
+///     .field("CreateComment", "post_id", 2)
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct TrafficAttributes {
+    service: String,
+    fields: HashMap<String, HashMap<String, u32>>,
+}
+
+impl TrafficAttributes {
+    /// An empty declaration for `grpc_service` (conventionally `GRPC_SERVICE_NAME`).
+    pub fn for_service(grpc_service: &str) -> Self {
+        Self { service: grpc_service.to_owned(), fields: HashMap::new() }
+    }
+
+    /// Declares that `method`'s request message carries `attribute` as field `number`.
+    /// String and integer fields are supported; only declare unary methods.
+    pub fn field(mut self, method: &str, attribute: &str, number: u32) -> Self {
+        self.fields
+            .entry(format!("/{}/{method}", self.service))
+            .or_default()
+            .insert(attribute.to_owned(), number);
+        self
+    }
+
+    fn number(&self, path: &str, attribute: &str) -> Option<u32> {
+        self.fields.get(path)?.get(attribute).copied()
+    }
+}
+
+/// Where each scope's key comes from; shared by the layer and every service it produces.
+#[derive(Clone)]
+struct KeySources {
+    identity_header: HeaderName,
+    tenant_header: HeaderName,
+    client_ip_header: HeaderName,
+    attributes: Arc<TrafficAttributes>,
+}
+
+/// How a request's rate-limit key is obtained once its profile is known.
+enum Keying {
+    /// Derived from the request head.
+    Ready(String),
+    /// Read from protobuf field `number` of the request body.
+    Body { attribute: String, number: u32 },
+}
+
 /// Tower [`Layer`] that rate-limits inbound gRPC requests from a [`TrafficRegistry`].
 ///
 /// Holds an `Option`: when `None` (no `[traffic]` section configured) the layer is a
@@ -74,7 +204,8 @@ fn throttle_counter() -> Counter<u64> {
 pub struct TrafficLayer {
     registry: Option<Arc<TrafficRegistry>>,
     counter: Counter<u64>,
-    identity_header: HeaderName,
+    fallbacks: Counter<u64>,
+    keys: KeySources,
     /// Distributed-mode coordination backend (`traffic-redis`). `None` → `distributed`
     /// profiles degrade to the local limiter (logged); `local` profiles never use it.
     backend: Option<Arc<dyn QuotaBackend>>,
@@ -86,7 +217,8 @@ impl TrafficLayer {
         Self {
             registry: None,
             counter: throttle_counter(),
-            identity_header: HeaderName::from_static(DEFAULT_IDENTITY_HEADER),
+            fallbacks: fallback_counter(),
+            keys: KeySources::new(HeaderName::from_static(DEFAULT_IDENTITY_HEADER)),
             backend: None,
         }
     }
@@ -97,11 +229,31 @@ impl TrafficLayer {
         Self {
             registry: Some(registry),
             counter: throttle_counter(),
-            identity_header,
+            fallbacks: fallback_counter(),
+            keys: KeySources::new(identity_header),
             backend: None,
         }
     }
 
+    /// Overrides the edge-mesh header `per_tenant` keys on.
+    pub fn with_tenant_header(mut self, header: HeaderName) -> Self {
+        self.keys.tenant_header = header;
+        self
+    }
+
+    /// Overrides the edge-mesh header `per_ip` keys on.
+    pub fn with_client_ip_header(mut self, header: HeaderName) -> Self {
+        self.keys.client_ip_header = header;
+        self
+    }
+
+    /// Declares the request fields `per_attribute` profiles may key on. Without it every
+    /// `per_attribute` profile keys per-method.
+    pub fn with_attributes(mut self, attributes: TrafficAttributes) -> Self {
+        self.keys.attributes = Arc::new(attributes);
+        self
+    }
+
     /// Attaches the distributed-mode coordination backend (e.g. `traffic-redis`). Required
     /// for `distributed` profiles to enforce a fleet-global budget; without it they degrade
     /// to local per-replica limiting.
@@ -119,7 +271,8 @@ impl<S> Layer<S> for TrafficLayer {
             inner,
             registry: self.registry.clone(),
             counter: self.counter.clone(),
-            identity_header: self.identity_header.clone(),
+            fallbacks: self.fallbacks.clone(),
+            keys: self.keys.clone(),
             backend: self.backend.clone(),
         }
     }
@@ -131,7 +284,8 @@ pub struct TrafficService<S> {
     inner: S,
     registry: Option<Arc<TrafficRegistry>>,
     counter: Counter<u64>,
-    identity_header: HeaderName,
+    fallbacks: Counter<u64>,
+    keys: KeySources,
     backend: Option<Arc<dyn QuotaBackend>>,
 }
 
@@ -157,40 +311,65 @@ where
 
         let method = req.uri().path();
         let (profile_name, bound, profile) = registry.resolve(method);
-        let key = extract_key(profile.scope(), method, req.headers(), &self.identity_header);
+        let keying = self.keys.keying(&profile, method, &req);
         let route = if bound { method } else { UNBOUND_ROUTE };
 
-        // Distributed profiles consult the (async) backend, so their decision is made inside
-        // the returned future; local profiles decide synchronously here on the hot path.
-        if profile.is_distributed() {
-            let backend = self.backend.clone();
-            let counter = self.counter.clone();
-            let profile_name = profile_name.to_owned();
-            let route = route.to_owned();
-            let method = method.to_owned();
-            // Move a ready clone of the inner service into the future (tower readiness idiom).
-            let clone = self.inner.clone();
-            let mut inner = std::mem::replace(&mut self.inner, clone);
-
-            return Box::pin(async move {
-                let decision = distributed_check(&profile, &key, backend.as_ref()).await;
-                let enforce = profile.enforce();
-                if let Some(response) =
-                    handle_decision(decision, &counter, &profile_name, &route, enforce, &method)
-                {
-                    return Ok(response);
-                }
-                inner.call(req).await
-            });
+        // Local profiles keyed from the head decide synchronously here on the hot path.
+        // Distributed profiles consult the (async) backend and `per_attribute` keys need the
+        // body, so those decide inside the returned future.
+        if let (Keying::Ready(key), false) = (&keying, profile.is_distributed()) {
+            let enforce = profile.enforce();
+            if let Some(response) = handle_decision(
+                profile.check(key),
+                &self.counter,
+                profile_name,
+                route,
+                enforce,
+                method,
+            ) {
+                return Box::pin(async move { Ok(response) });
+            }
+            return Box::pin(self.inner.call(req));
         }
 
-        let enforce = profile.enforce();
-        if let Some(response) =
-            handle_decision(profile.check(&key), &self.counter, profile_name, route, enforce, method)
-        {
-            return Box::pin(async move { Ok(response) });
-        }
-        Box::pin(self.inner.call(req))
+        let backend = self.backend.clone();
+        let counter = self.counter.clone();
+        let fallbacks = self.fallbacks.clone();
+        let profile_name = profile_name.to_owned();
+        let route = route.to_owned();
+        let method = method.to_owned();
+        // Move a ready clone of the inner service into the future (tower readiness idiom).
+        let clone = self.inner.clone();
+        let mut inner = std::mem::replace(&mut self.inner, clone);
+
+        Box::pin(async move {
+            let (req, key) = match keying {
+                Keying::Ready(key) => (req, key),
+                Keying::Body { attribute, number } => {
+                    match key_from_body(req, &method, &attribute, number).await {
+                        Ok(keyed) => keyed,
+                        Err(status) => return Ok(status.into_http()),
+                    }
+                }
+            };
+            let decision = if profile.is_distributed() {
+                let (decision, fallback) =
+                    distributed_check(&profile, &key, backend.as_ref()).await;
+                if let Some(fallback) = fallback {
+                    fallbacks.add(1, &fallback_attrs(&profile_name, fallback));
+                }
+                decision
+            } else {
+                profile.check(&key)
+            };
+            let enforce = profile.enforce();
+            if let Some(response) =
+                handle_decision(decision, &counter, &profile_name, &route, enforce, &method)
+            {
+                return Ok(response);
+            }
+            inner.call(req).await
+        })
     }
 }
 
@@ -226,30 +405,45 @@ fn handle_decision(
 }
 
 /// Resolves a `distributed` profile's decision via the global backend, applying the
-/// `on_backend_error` policy when the backend is unreachable.
+/// `on_backend_error` policy when the backend is unreachable. The second element names the
+/// fallback taken when the backend did not decide.
 async fn distributed_check(
     profile: &TrafficProfile,
     key: &str,
     backend: Option<&Arc<dyn QuotaBackend>>,
-) -> TrafficDecision {
+) -> (TrafficDecision, Option<Fallback>) {
     let Some(backend) = backend else {
         tracing::debug!("traffic: distributed profile but no backend wired — using local limiter");
-        return profile.check(key);
+        return (profile.check(key), Some(Fallback::Unwired));
     };
 
     match backend.check(key, profile.quota()).await {
-        Ok(decision) => decision,
-        Err(_unavailable) => match profile.on_backend_error() {
-            // Reject: precision/safety over availability (hard abuse/billing quotas).
-            Some(BackendError::FailClosed) => TrafficDecision::Throttle {
-                retry_after: Duration::from_millis(profile.quota().lease_ms),
-            },
-            // Degrade to the local per-replica limiter (availability over precision).
-            _ => profile.check(key),
-        },
+        Ok(decision) => (decision, None),
+        Err(unavailable) => {
+            tracing::debug!(%unavailable, "traffic: applying on_backend_error");
+            match profile.on_backend_error() {
+                // Reject: precision/safety over availability (hard abuse/billing quotas).
+                Some(BackendError::FailClosed) => (
+                    TrafficDecision::Throttle {
+                        retry_after: Duration::from_millis(profile.quota().lease_ms),
+                    },
+                    Some(Fallback::Rejected),
+                ),
+                // Degrade to the local per-replica limiter (availability over precision).
+                _ => (profile.check(key), Some(Fallback::Local)),
+            }
+        }
     }
 }
 
+/// Attribute set for the fallback counter.
+fn fallback_attrs(profile: &str, fallback: Fallback) -> [KeyValue; 2] {
+    [
+        KeyValue::new("profile", profile.to_string()),
+        KeyValue::new("outcome", fallback.as_str()),
+    ]
+}
+
 /// Attribute set for the throttle counter. `status` distinguishes a real rejection from a
 /// shadow-mode observation; `profile`/`route` scope it.
 fn throttle_attrs(profile: &str, route: &str, enforce: bool) -> [KeyValue; 3] {
@@ -260,36 +454,188 @@ fn throttle_attrs(profile: &str, route: &str, enforce: bool) -> [KeyValue; 3] {
     ]
 }
 
-/// Builds the rate-limit key for `method` under `scope`.
+impl KeySources {
+    fn new(identity_header: HeaderName) -> Self {
+        Self {
+            identity_header,
+            tenant_header: HeaderName::from_static(DEFAULT_TENANT_HEADER),
+            client_ip_header: HeaderName::from_static(DEFAULT_CLIENT_IP_HEADER),
+            attributes: Arc::new(TrafficAttributes::default()),
+        }
+    }
+
+    /// Resolves how `req` is keyed under `profile`'s scope. Every head-derived key is
+    /// prefixed with `method` so scopes never share buckets across methods.
+    fn keying(&self, profile: &TrafficProfile, method: &str, req: &http::Request<Body>) -> Keying {
+        let scoped = |label: &str, value: Option<String>| match value {
+            Some(value) => Keying::Ready(format!("{method}|{label}{value}")),
+            None => {
+                tracing::debug!(
+                    rpc.method = %method,
+                    scope = label.trim_end_matches(':'),
+                    "traffic: no key for the profile's scope — keying per-method"
+                );
+                Keying::Ready(method.to_owned())
+            }
+        };
+        match profile.scope() {
+            Scope::PerMethod => Keying::Ready(method.to_owned()),
+            Scope::PerCaller => caller_key(method, req.headers(), &self.identity_header),
+            Scope::PerIp => scoped("ip:", client_ip(req, &self.client_ip_header)),
+            Scope::PerTenant => scoped("tenant:", header_value(req.headers(), &self.tenant_header)),
+            Scope::PerAttribute => {
+                let attribute = profile.attribute().unwrap_or_default();
+                match self.attributes.number(method, &attribute) {
+                    Some(number) => Keying::Body { attribute, number },
+                    None => {
+                        tracing::debug!(
+                            rpc.method = %method,
+                            %attribute,
+                            "traffic: per_attribute profile but the method declares no such field — keying per-method"
+                        );
+                        Keying::Ready(method.to_owned())
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Builds the `per_caller` key for `method`.
 ///
-/// `per_caller` reads the edge-mesh identity header; absent/non-ASCII/empty values degrade
-/// to method-level keying (see module docs).
-fn extract_key(
-    scope: Scope,
+/// Reads the edge-mesh identity header; absent/non-ASCII/empty values degrade to
+/// method-level keying (see module docs).
+fn caller_key(method: &str, headers: &HeaderMap, identity_header: &HeaderName) -> Keying {
+    match header_value(headers, identity_header) {
+        Some(id) => Keying::Ready(format!("{method}|{id}")),
+        None => {
+            tracing::debug!(
+                rpc.method = %method,
+                identity_header = %identity_header,
+                "traffic: per_caller profile but no edge identity header — keying per-method"
+            );
+            Keying::Ready(method.to_owned())
+        }
+    }
+}
+
+/// A non-empty ASCII header value.
+fn header_value(headers: &HeaderMap, name: &HeaderName) -> Option<String> {
+    headers
+        .get(name)
+        .and_then(|value| value.to_str().ok())
+        .filter(|value| !value.is_empty())
+        .map(str::to_owned)
+}
+
+/// The client IP: the edge-injected header if present, else the connection's peer address.
+fn client_ip(req: &http::Request<Body>, header: &HeaderName) -> Option<String> {
+    header_value(req.headers(), header).or_else(|| {
+        let extensions = req.extensions();
+        extensions
+            .get::<TcpConnectInfo>()
+            .and_then(TcpConnectInfo::remote_addr)
+            .or_else(|| {
+                extensions
+                    .get::<TlsConnectInfo<TcpConnectInfo>>()
+                    .and_then(|info| info.get_ref().remote_addr())
+            })
+            .map(|addr| addr.ip().to_string())
+    })
+}
+
+/// Buffers the request body, keys on `attribute` (field `number`) of its first message, and
+/// rebuilds the request around the buffered bytes. A missing, empty, or oversized value keys
+/// per-method; a body past [`MAX_BUFFERED_BODY`] is answered with `RESOURCE_EXHAUSTED`, an
+/// unreadable one with `INVALID_ARGUMENT`.
+async fn key_from_body(
+    req: http::Request<Body>,
     method: &str,
-    headers: &HeaderMap,
-    identity_header: &HeaderName,
-) -> String {
-    match scope {
-        Scope::PerMethod => method.to_owned(),
-        Scope::PerCaller => {
-            match headers
-                .get(identity_header)
-                .and_then(|value| value.to_str().ok())
-                .filter(|id| !id.is_empty())
-            {
-                Some(id) => format!("{method}|{id}"),
-                None => {
-                    tracing::debug!(
-                        rpc.method = %method,
-                        identity_header = %identity_header,
-                        "traffic: per_caller profile but no edge identity header — keying per-method"
-                    );
-                    method.to_owned()
+    attribute: &str,
+    number: u32,
+) -> Result<(http::Request<Body>, String), Status> {
+    let (parts, body) = req.into_parts();
+    let bytes = Limited::new(body, MAX_BUFFERED_BODY)
+        .collect()
+        .await
+        .map_err(|e| {
+            if e.is::<LengthLimitError>() {
+                Status::resource_exhausted("request body exceeds the maximum message size")
+            } else {
+                Status::invalid_argument("unreadable request body")
+            }
+        })?
+        .to_bytes();
+
+    let key = match first_message(&bytes).and_then(|message| proto_field(message, number)) {
+        Some(value) => format!("{method}|{attribute}={value}"),
+        None => {
+            tracing::debug!(
+                rpc.method = %method,
+                %attribute,
+                "traffic: request carries no usable attribute value — keying per-method"
+            );
+            method.to_owned()
+        }
+    };
+    Ok((http::Request::from_parts(parts, Body::new(Full::new(bytes))), key))
+}
+
+/// The first gRPC length-prefixed message in `body`, or `None` if it is compressed or
+/// truncated.
+fn first_message(body: &Bytes) -> Option<&[u8]> {
+    let (&compressed, rest) = body.split_first()?;
+    let len = u32::from_be_bytes(rest.get(..4)?.try_into().ok()?);
+    if compressed != 0 {
+        return None;
+    }
+    rest.get(4..4 + usize::try_from(len).ok()?)
+}
+
+/// Reads top-level field `number` from a protobuf-encoded message as a string: UTF-8 for
+/// length-delimited fields, decimal for varints. The last occurrence wins, as in protobuf
+/// decoding. `None` when absent, empty, longer than [`MAX_ATTRIBUTE_LEN`], or malformed.
+fn proto_field(mut message: &[u8], number: u32) -> Option<String> {
+    let mut found = None;
+    while !message.is_empty() {
+        let tag = read_varint(&mut message)?;
+        let matches = tag >> 3 == u64::from(number);
+        match tag & 0x7 {
+            0 => {
+                let value = read_varint(&mut message)?;
+                if matches {
+                    found = Some(value.to_string());
+                }
+            }
+            1 => message = message.get(8..)?,
+            2 => {
+                let len = usize::try_from(read_varint(&mut message)?).ok()?;
+                let value = message.get(..len)?;
+                message = &message[len..];
+                if matches {
+                    found = std::str::from_utf8(value).ok().map(str::to_owned);
                 }
             }
+            5 => message = message.get(4..)?,
+            // Groups (3/4) are proto2-only and unused by our contracts.
+            _ => return None,
+        }
+    }
+    found.filter(|value| !value.is_empty() && value.len() <= MAX_ATTRIBUTE_LEN)
+}
+
+/// Decodes one base-128 varint, advancing `buf`.
+fn read_varint(buf: &mut &[u8]) -> Option<u64> {
+    let mut value = 0u64;
+    for shift in (0..64).step_by(7) {
+        let (&byte, rest) = buf.split_first()?;
+        *buf = rest;
+        value |= u64::from(byte & 0x7f) << shift;
+        if byte & 0x80 == 0 {
+            return Some(value);
         }
     }
+    None
 }
 
 /// A trailers-only gRPC `RESOURCE_EXHAUSTED` response carrying a `retry-after-ms` hint.
@@ -320,6 +666,81 @@ mod tests {
         assert!(has(&attrs, "status", "enforced"));
     }
 
+    #[test]
+    fn fallback_attrs_carry_profile_and_outcome() {
+        let attrs = fallback_attrs("login-abuse", Fallback::Rejected);
+        assert!(has(&attrs, "profile", "login-abuse"));
+        assert!(has(&attrs, "outcome", "rejected"));
+        assert!(has(&fallback_attrs("x", Fallback::Local), "outcome", "local"));
+        assert!(has(&fallback_attrs("x", Fallback::Unwired), "outcome", "unwired"));
+    }
+
+    /// A gRPC frame around a message with `post_id` (field 2) and a varint field 8.
+    fn frame(post_id: &str) -> Bytes {
+        let mut message = vec![0x0a, 1, b'c', 0x12, post_id.len() as u8];
+        message.extend_from_slice(post_id.as_bytes());
+        message.extend_from_slice(&[0x40, 0x96, 0x01]);
+        let mut frame = vec![0];
+        frame.extend_from_slice(&(message.len() as u32).to_be_bytes());
+        frame.extend_from_slice(&message);
+        Bytes::from(frame)
+    }
+
+    #[test]
+    fn proto_field_reads_strings_and_varints() {
+        let body = frame("post-42");
+        let message = first_message(&body).unwrap();
+        assert_eq!(proto_field(message, 2).as_deref(), Some("post-42"));
+        assert_eq!(proto_field(message, 8).as_deref(), Some("150"));
+        assert_eq!(proto_field(message, 3), None);
+    }
+
+    #[test]
+    fn proto_field_rejects_empty_oversized_and_malformed_values() {
+        assert_eq!(proto_field(first_message(&frame("")).unwrap(), 2), None);
+        let long = "x".repeat(MAX_ATTRIBUTE_LEN + 1);
+        assert_eq!(proto_field(first_message(&frame(&long)).unwrap(), 2), None);
+        // Truncated: length prefix claims more bytes than remain.
+        assert_eq!(proto_field(&[0x12, 9, b'a'], 2), None);
+    }
+
+    fn request(body: Bytes) -> http::Request<Body> {
+        http::Request::new(Body::new(Full::new(body)))
+    }
+
+    #[tokio::test]
+    async fn key_from_body_keys_on_the_attribute_and_keeps_the_body() {
+        let (req, key) = key_from_body(request(frame("post-42")), "/m", "post_id", 2).await.unwrap();
+        assert_eq!(key, "/m|post_id=post-42");
+        let forwarded = req.into_body().collect().await.unwrap().to_bytes();
+        assert_eq!(forwarded, frame("post-42"));
+    }
+
+    #[tokio::test]
+    async fn key_from_body_rejects_a_body_past_the_buffer_cap() {
+        let oversized = Bytes::from(vec![0u8; MAX_BUFFERED_BODY + 1]);
+        let status = key_from_body(request(oversized), "/m", "post_id", 2).await.unwrap_err();
+        assert_eq!(status.code(), tonic::Code::ResourceExhausted);
+    }
+
+    #[test]
+    fn first_message_skips_compressed_frames() {
+        let mut compressed = frame("p").to_vec();
+        compressed[0] = 1;
+        assert_eq!(first_message(&Bytes::from(compressed)), None);
+        assert_eq!(first_message(&Bytes::from_static(&[0, 0, 0])), None);
+    }
+
+    #[test]
+    fn attributes_resolve_by_full_path() {
+        let attributes = TrafficAttributes::for_service("comment.v1.CommentService")
+            .field("CreateComment", "post_id", 2);
+        let create = "/comment.v1.CommentService/CreateComment";
+        assert_eq!(attributes.number(create, "post_id"), Some(2));
+        assert_eq!(attributes.number(create, "author_id"), None);
+        assert_eq!(attributes.number("/comment.v1.CommentService/DeleteComment", "post_id"), None);
+    }
+
     #[test]
     fn attrs_distinguish_shadow_and_bounded_route() {
         let attrs = throttle_attrs("standard", UNBOUND_ROUTE, false);
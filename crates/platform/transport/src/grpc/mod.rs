@@ -1,6 +1,8 @@
 pub mod client;
 pub mod error;
+pub mod idempotency;
 pub mod layer;
 pub mod server;
 
 pub use error::GrpcTransportError;
+pub use idempotency::{idempotency_key, payload_fingerprint, IDEMPOTENCY_KEY_HEADER, MAX_IDEMPOTENCY_KEY_LEN};
@@ -9,24 +9,40 @@ use traffic::QuotaBackend;
 use crate::{
     error::TransportError,
     grpc::{
-        layer::{inbound::InboundTraceLayer, traffic::TrafficLayer},
+        layer::{
+            concurrency::ConcurrencyLayer,
+            inbound::InboundTraceLayer,
+            metrics::ServerMetricsLayer,
+            traffic::{TrafficAttributes, TrafficLayer},
+        },
         server::config::GrpcServerConfig,
     },
 };
 
 /// Concrete server type produced by [`GrpcServerBuilder::build`].
 ///
-/// The tonic type after applying [`InboundTraceLayer`] then [`TrafficLayer`]: trace is the
-/// outer layer (so throttled requests are still traced), rate-limiting the inner. The
-/// [`TrafficLayer`] is always present in the type — it's a transparent pass-through unless a
-/// registry was supplied via [`GrpcServerBuilder::with_traffic`], keeping the return type
+/// The tonic type after applying [`InboundTraceLayer`], [`ServerMetricsLayer`],
+/// [`TrafficLayer`], then [`ConcurrencyLayer`]: trace is the outer layer (so throttled requests
+/// are still traced), metrics next (so throttled requests are counted as `RESOURCE_EXHAUSTED`),
+/// rate-limiting inside that, and the concurrency limit innermost (so a throttled flood never
+/// holds a slot). Both limiting layers are always present in the type — each is a transparent
+/// pass-through unless a registry was supplied via [`GrpcServerBuilder::with_traffic`] (and,
+/// for [`ConcurrencyLayer`], it configures `[traffic.concurrency]`), keeping the return type
 /// stable regardless of whether limiting is enabled.
-pub type TracedGrpcServer = Server<Stack<TrafficLayer, Stack<InboundTraceLayer, Identity>>>;
+pub type TracedGrpcServer = Server<
+    Stack<
+        ConcurrencyLayer,
+        Stack<TrafficLayer, Stack<ServerMetricsLayer, Stack<InboundTraceLayer, Identity>>>,
+    >,
+>;
 
-/// Builds a Tonic gRPC server with [`InboundTraceLayer`] and [`TrafficLayer`] pre-installed.
+/// Builds a Tonic gRPC server with [`InboundTraceLayer`], [`ServerMetricsLayer`],
+/// [`TrafficLayer`] and [`ConcurrencyLayer`] pre-installed.
 ///
-/// Every request has its W3C TraceContext extracted and linked as the parent span; if a
-/// traffic registry was supplied, it is also rate-limited per the bound `[traffic]` profile.
+/// Every request has its W3C TraceContext extracted and linked as the parent span, and is
+/// counted and timed per method and status code; if a
+/// traffic registry was supplied, it is also rate-limited per the bound `[traffic]` profile,
+/// and shed past the adaptive concurrency limit when `[traffic.concurrency]` is configured.
 ///
 /// # Example
 ///
@@ -42,15 +58,17 @@ pub struct GrpcServerBuilder {
     config: GrpcServerConfig,
     traffic: Option<Arc<TrafficRegistry>>,
     traffic_backend: Option<Arc<dyn QuotaBackend>>,
+    traffic_attributes: Option<TrafficAttributes>,
 }
 
 impl GrpcServerBuilder {
     pub fn new(config: GrpcServerConfig) -> Self {
-        Self { config, traffic: None, traffic_backend: None }
+        Self { config, traffic: None, traffic_backend: None, traffic_attributes: None }
     }
 
-    /// Enables ingress rate limiting from the given registry. Without this call the server
-    /// installs a transparent (no-op) traffic layer.
+    /// Enables ingress rate limiting from the given registry, plus the adaptive concurrency
+    /// limit when it configures one. Without this call the server installs transparent
+    /// (no-op) traffic and concurrency layers.
     pub fn with_traffic(mut self, registry: Arc<TrafficRegistry>) -> Self {
         self.traffic = Some(registry);
         self
@@ -64,14 +82,32 @@ impl GrpcServerBuilder {
         self
     }
 
-    /// Returns a [`TracedGrpcServer`] with the trace and traffic layers applied.
+    /// Declares the request fields `per_attribute` traffic profiles may key on. Without it
+    /// those profiles key per-method. No effect unless [`with_traffic`](Self::with_traffic)
+    /// is also set.
+    pub fn with_traffic_attributes(mut self, attributes: TrafficAttributes) -> Self {
+        self.traffic_attributes = Some(attributes);
+        self
+    }
+
+    /// Returns a [`TracedGrpcServer`] with the trace, metrics, traffic and concurrency layers
+    /// applied.
     ///
     /// Call `.add_service(...)` and `.serve(addr)` on the returned server to start
     /// accepting connections.
     pub fn build(self) -> Result<TracedGrpcServer, TransportError> {
+        let concurrency_layer = match &self.traffic {
+            Some(registry) => ConcurrencyLayer::new(Arc::clone(registry)),
+            None => ConcurrencyLayer::disabled(),
+        };
         let traffic_layer = match self.traffic {
             Some(registry) => {
-                let layer = TrafficLayer::new(registry, self.config.identity_header.clone());
+                let mut layer = TrafficLayer::new(registry, self.config.identity_header.clone())
+                    .with_tenant_header(self.config.tenant_header.clone())
+                    .with_client_ip_header(self.config.client_ip_header.clone());
+                if let Some(attributes) = self.traffic_attributes {
+                    layer = layer.with_attributes(attributes);
+                }
                 match self.traffic_backend {
                     Some(backend) => layer.with_backend(backend),
                     None => layer,
@@ -79,9 +115,14 @@ impl GrpcServerBuilder {
             }
             None => TrafficLayer::disabled(),
         };
-        // `.layer(InboundTraceLayer)` first makes trace the outer layer; `.layer(traffic)`
-        // nests rate-limiting inside the span.
-        let mut server = Server::builder().layer(InboundTraceLayer).layer(traffic_layer);
+        // `.layer(InboundTraceLayer)` first makes trace the outer layer; metrics then time
+        // everything beneath it, rate-limiting sheds floods next, and the concurrency limit
+        // nests innermost.
+        let mut server = Server::builder()
+            .layer(InboundTraceLayer)
+            .layer(ServerMetricsLayer::new())
+            .layer(traffic_layer)
+            .layer(concurrency_layer);
 
         if let Some(age) = self.config.max_connection_age {
             server = server.max_connection_age(age);
@@ -10,6 +10,16 @@ use http::header::HeaderName;
 /// the cluster it is authoritative; never accept it from untrusted ingress.
 pub const DEFAULT_IDENTITY_HEADER: &str = "x-edge-user";
 
+/// Default inbound header carrying the verified caller's tenant
+/// (`CurrentPrincipal::tenant_id`), injected by the edge next to the identity header.
+/// Same trust contract as [`DEFAULT_IDENTITY_HEADER`].
+pub const DEFAULT_TENANT_HEADER: &str = "x-edge-tenant";
+
+/// Default inbound header carrying the client IP as the edge observed it. Same trust
+/// contract as [`DEFAULT_IDENTITY_HEADER`] — the edge must overwrite it, never append to a
+/// client-supplied value the way `x-forwarded-for` does.
+pub const DEFAULT_CLIENT_IP_HEADER: &str = "x-edge-client-ip";
+
 /// Configuration for a Tonic gRPC server.
 #[derive(Debug, Clone)]
 pub struct GrpcServerConfig {
@@ -26,6 +36,15 @@ pub struct GrpcServerConfig {
     /// layer for `per_caller` rate-limit keying. Defaults to [`DEFAULT_IDENTITY_HEADER`].
     pub identity_header: HeaderName,
 
+    /// Inbound header the edge mesh injects with the caller's tenant. Read by the traffic
+    /// layer for `per_tenant` keying. Defaults to [`DEFAULT_TENANT_HEADER`].
+    pub tenant_header: HeaderName,
+
+    /// Inbound header the edge mesh injects with the client IP. Read by the traffic layer for
+    /// `per_ip` keying, which falls back to the connection's peer address when it is absent.
+    /// Defaults to [`DEFAULT_CLIENT_IP_HEADER`].
+    pub client_ip_header: HeaderName,
+
     /// Maximum lifetime of an accepted connection. At the deadline the server GOAWAYs it:
     /// in-flight streams keep running (never severed unless [`max_connection_age_grace`]
     /// is also set) but the caller's *next* stream goes over a fresh connection — which
@@ -52,6 +71,8 @@ impl Default for GrpcServerConfig {
             tls: None,
             enable_reflection: false,
             identity_header: HeaderName::from_static(DEFAULT_IDENTITY_HEADER),
+            tenant_header: HeaderName::from_static(DEFAULT_TENANT_HEADER),
+            client_ip_header: HeaderName::from_static(DEFAULT_CLIENT_IP_HEADER),
             max_connection_age: None,
             max_connection_age_grace: None,
         }
@@ -80,6 +101,18 @@ impl GrpcServerConfig {
         self
     }
 
+    /// Overrides the edge-mesh tenant header used for `per_tenant` keying.
+    pub fn with_tenant_header(mut self, header: HeaderName) -> Self {
+        self.tenant_header = header;
+        self
+    }
+
+    /// Overrides the edge-mesh client-IP header used for `per_ip` keying.
+    pub fn with_client_ip_header(mut self, header: HeaderName) -> Self {
+        self.client_ip_header = header;
+        self
+    }
+
     /// Bounds connection lifetime (GOAWAY-based recycling; see the field docs).
     pub fn with_max_connection_age(mut self, age: Duration) -> Self {
         self.max_connection_age = Some(age);
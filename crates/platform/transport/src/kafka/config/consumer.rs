@@ -43,6 +43,10 @@ pub struct ConsumerConfig {
     /// Maximum time (ms) the broker waits before considering a consumer dead.
     /// Default: `10000`.
     pub session_timeout_ms: u32,
+
+    /// Interval (ms) at which librdkafka emits statistics, from which the
+    /// `kafka_consumer_lag` gauge is refreshed. `0` disables them. Default: `15000`.
+    pub statistics_interval_ms: u32,
 }
 
 impl ConsumerConfig {
@@ -54,6 +58,7 @@ impl ConsumerConfig {
             enable_auto_commit: false,
             heartbeat_interval_ms: 3_000,
             session_timeout_ms: 10_000,
+            statistics_interval_ms: 15_000,
         }
     }
 
@@ -66,7 +71,8 @@ impl ConsumerConfig {
                 if self.enable_auto_commit { "true" } else { "false" },
             )
             .set("heartbeat.interval.ms", self.heartbeat_interval_ms.to_string())
-            .set("session.timeout.ms", self.session_timeout_ms.to_string());
+            .set("session.timeout.ms", self.session_timeout_ms.to_string())
+            .set("statistics.interval.ms", self.statistics_interval_ms.to_string());
         cfg
     }
 }
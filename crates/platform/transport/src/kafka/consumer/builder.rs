@@ -4,7 +4,7 @@ use crate::{
     error::TransportError,
     kafka::{
         config::consumer::ConsumerConfig,
-        consumer::handle::KafkaConsumerHandle,
+        consumer::{handle::KafkaConsumerHandle, metrics::LagContext},
         error::KafkaTransportError,
     },
 };
@@ -34,12 +34,14 @@ impl KafkaConsumerBuilder {
     }
 
     /// Creates the rdkafka [`StreamConsumer`], subscribes to the configured topics,
-    /// and returns a [`KafkaConsumerHandle`].
+    /// and returns a [`KafkaConsumerHandle`]. The consumer's statistics callback feeds
+    /// the `kafka_consumer_lag` gauge.
     pub fn build(self) -> Result<KafkaConsumerHandle, TransportError> {
-        let consumer: StreamConsumer = self
+        let context = LagContext::new(self.config.group_id.clone());
+        let consumer: StreamConsumer<LagContext> = self
             .config
             .to_rdkafka()
-            .create()
+            .create_with_context(context)
             .map_err(|e| TransportError::Kafka(KafkaTransportError::Config(e.to_string())))?;
 
         let topic_refs: Vec<&str> = self.topics.iter().map(String::as_str).collect();
@@ -53,6 +55,6 @@ impl KafkaConsumerBuilder {
             "Kafka consumer subscribed"
         );
 
-        Ok(KafkaConsumerHandle::new(consumer))
+        Ok(KafkaConsumerHandle::new(consumer, self.config.group_id))
     }
 }
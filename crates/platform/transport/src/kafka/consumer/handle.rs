@@ -1,5 +1,6 @@
 use std::collections::HashMap;
 
+use futures_util::stream::BoxStream;
 use futures_util::StreamExt;
 use rdkafka::{
     consumer::{Consumer, StreamConsumer},
@@ -9,11 +10,22 @@ use rdkafka::{
 use tracing_opentelemetry::OpenTelemetrySpanExt;
 
 use crate::{
-    error::{CodecError, TransportError},
-    kafka::{envelope::ConsumablePayload, error::KafkaTransportError},
+    error::TransportError,
+    kafka::{
+        consumer::metrics::LagContext,
+        content_type::ContentType,
+        envelope::ConsumablePayload,
+        error::KafkaTransportError,
+    },
     propagation::{carrier::extract_context, kafka::KafkaHeaderExtractor},
 };
 
+#[cfg(feature = "memory-broker")]
+use crate::kafka::memory::{MemoryConsumer, MemoryRecord};
+
+/// W3C trace-context headers: transport-internal, never surfaced as user headers.
+const TRACE_HEADERS: [&str; 2] = ["traceparent", "tracestate"];
+
 /// A single record consumed from Kafka, carrying both the typed payload (or the
 /// decode error) **and** the offset coordinates required to commit it.
 ///
@@ -40,6 +52,10 @@ pub struct ConsumedMessage<T> {
     pub offset: i64,
     /// Record key (empty string when absent or non-UTF-8).
     pub key: String,
+    /// The record key's bytes, `None` when the record has no key. A republish
+    /// (retry tier, dead-letter) sends these, not [`key`](Self::key), so a key
+    /// that is not UTF-8 keeps its partition.
+    pub key_bytes: Option<Vec<u8>>,
     /// User headers, excluding the W3C trace-context headers.
     pub headers: HashMap<String, String>,
     /// Broker/producer timestamp in milliseconds, when available.
@@ -64,12 +80,30 @@ pub struct ConsumedMessage<T> {
 /// 3. Sets it as the parent of the current `tracing` span, establishing a continuous
 ///    distributed trace from the producer to this consumer.
 pub struct KafkaConsumerHandle {
-    consumer: StreamConsumer,
+    backend: Backend,
+    group_id: String,
+}
+
+enum Backend {
+    Kafka(StreamConsumer<LagContext>),
+    #[cfg(feature = "memory-broker")]
+    Memory(MemoryConsumer),
 }
 
 impl KafkaConsumerHandle {
-    pub(crate) fn new(consumer: StreamConsumer) -> Self {
-        Self { consumer }
+    pub(crate) fn new(consumer: StreamConsumer<LagContext>, group_id: String) -> Self {
+        Self { backend: Backend::Kafka(consumer), group_id }
+    }
+
+    /// A handle reading as one member of `group_id` on an in-process broker.
+    #[cfg(feature = "memory-broker")]
+    pub(crate) fn in_memory(consumer: MemoryConsumer, group_id: String) -> Self {
+        Self { backend: Backend::Memory(consumer), group_id }
+    }
+
+    /// The consumer group this handle reads and commits under.
+    pub fn group_id(&self) -> &str {
+        &self.group_id
     }
 
     /// Returns an infinite async stream of [`ConsumedMessage<T>`].
@@ -114,81 +148,76 @@ impl KafkaConsumerHandle {
     pub fn stream<T: ConsumablePayload>(
         &self,
     ) -> impl futures::Stream<Item = Result<ConsumedMessage<T>, TransportError>> + '_ {
-        self.consumer.stream().map(|msg_result| {
-            // A broker/stream-level error (rebalance, transport failure, …) has no
-            // offset to commit. Surface it so the worker can restart its loop.
-            let msg = msg_result
-                .map_err(|e| TransportError::Kafka(KafkaTransportError::Consumer(e)))?;
-
-            // ── Trace context extraction ─────────────────────────────────────────────
-            // Extract the remote context and set it as the parent of the current span.
-            // Any `tracing` span opened after this point within the same task will be a
-            // child of the upstream producer's span.
-            if let Some(headers) = msg.headers() {
-                let parent_cx = extract_context(&KafkaHeaderExtractor(headers));
-                tracing::Span::current().set_parent(parent_cx);
-            }
+        let stream: BoxStream<'_, _> = match &self.backend {
+            Backend::Kafka(consumer) => consumer
+                .stream()
+                .map(|msg_result| {
+                    // A broker/stream-level error (rebalance, transport failure, …) has no
+                    // offset to commit. Surface it so the worker can restart its loop.
+                    let msg = msg_result
+                        .map_err(|e| TransportError::Kafka(KafkaTransportError::Consumer(e)))?;
 
-            // ── Reconstruct user headers (excluding trace headers) ───────────────────
-            let user_headers: HashMap<String, String> = msg
-                .headers()
-                .map(|h| {
-                    (0..h.count())
-                        .filter_map(|i| {
-                            let header = h.get(i);
-                            // Skip W3C trace context headers — those are transport-internal.
-                            if header.key == "traceparent" || header.key == "tracestate" {
-                                return None;
-                            }
-                            let value = header
-                                .value
-                                .and_then(|v| std::str::from_utf8(v).ok())
-                                .unwrap_or("")
-                                .to_string();
-                            Some((header.key.to_string(), value))
+                    // ── Trace context extraction ─────────────────────────────────────
+                    // Extract the remote context and set it as the parent of the current
+                    // span. Any `tracing` span opened after this point within the same
+                    // task will be a child of the upstream producer's span.
+                    if let Some(headers) = msg.headers() {
+                        let parent_cx = extract_context(&KafkaHeaderExtractor(headers));
+                        tracing::Span::current().set_parent(parent_cx);
+                    }
+
+                    // ── Reconstruct user headers (excluding trace headers) ───────────
+                    let user_headers: HashMap<String, String> = msg
+                        .headers()
+                        .map(|h| {
+                            (0..h.count())
+                                .filter_map(|i| {
+                                    let header = h.get(i);
+                                    if TRACE_HEADERS.contains(&header.key) {
+                                        return None;
+                                    }
+                                    let value = header
+                                        .value
+                                        .and_then(|v| std::str::from_utf8(v).ok())
+                                        .unwrap_or("")
+                                        .to_string();
+                                    Some((header.key.to_string(), value))
+                                })
+                                .collect()
                         })
-                        .collect()
+                        .unwrap_or_default();
+
+                    Ok(consumed(
+                        msg.topic().to_string(),
+                        msg.partition(),
+                        msg.offset(),
+                        msg.key().map(<[u8]>::to_vec),
+                        user_headers,
+                        msg.timestamp().to_millis(),
+                        msg.payload(),
+                    ))
                 })
-                .unwrap_or_default();
-
-            // ── Payload deserialization (deferred error) ─────────────────────────────
-            // A decode failure is captured in `payload` rather than aborting the stream,
-            // so the worker can still see the offset and commit past the poison record.
-            // The raw bytes are retained regardless, so the record can be forwarded to
-            // a dead-letter topic even when it fails to decode.
-            let raw_payload = msg.payload().map(<[u8]>::to_vec).unwrap_or_default();
-
-            let payload = match msg.payload() {
-                None => Err(TransportError::Kafka(KafkaTransportError::EmptyPayload)),
-                Some(bytes) => serde_json::from_slice::<T>(bytes)
-                    .map_err(|e| TransportError::Codec(CodecError::Json(e))),
-            };
-
-            let consumed = ConsumedMessage {
-                topic: msg.topic().to_string(),
-                partition: msg.partition(),
-                offset: msg.offset(),
-                key: msg
-                    .key()
-                    .and_then(|k| std::str::from_utf8(k).ok())
-                    .unwrap_or("")
-                    .to_string(),
-                headers: user_headers,
-                timestamp_ms: msg.timestamp().to_millis(),
-                raw_payload,
-                payload,
-            };
-
-            tracing::debug!(
-                topic = %consumed.topic,
-                partition = consumed.partition,
-                offset = consumed.offset,
-                key = %consumed.key,
-                "Kafka message received"
-            );
-
-            Ok(consumed)
-        })
+                .boxed(),
+            #[cfg(feature = "memory-broker")]
+            Backend::Memory(consumer) => consumer
+                .stream()
+                .map(|record: MemoryRecord| {
+                    tracing::Span::current().set_parent(extract_context(&record.headers));
+                    let mut user_headers = record.headers;
+                    user_headers.retain(|key, _| !TRACE_HEADERS.contains(&key.as_str()));
+                    Ok(consumed(
+                        record.topic,
+                        record.partition,
+                        record.offset,
+                        (!record.key.is_empty()).then(|| record.key.into_bytes()),
+                        user_headers,
+                        Some(record.timestamp_ms),
+                        Some(&record.payload),
+                    ))
+                })
+                .boxed(),
+        };
+        stream
     }
 
     /// Commits the offset *past* `msg`, marking it (and everything before it on the
@@ -200,12 +229,93 @@ impl KafkaConsumerHandle {
     /// Uses async commit mode, so the call returns as soon as the request is queued;
     /// the offset is durably committed by the next group commit.
     pub fn commit<T>(&self, msg: &ConsumedMessage<T>) -> Result<(), TransportError> {
-        let mut tpl = TopicPartitionList::new();
-        tpl.add_partition_offset(&msg.topic, msg.partition, Offset::Offset(msg.offset + 1))
-            .map_err(|e| TransportError::Kafka(KafkaTransportError::Config(e.to_string())))?;
+        self.commit_position(&msg.topic, msg.partition, msg.offset + 1)
+    }
 
-        self.consumer
-            .commit(&tpl, rdkafka::consumer::CommitMode::Async)
-            .map_err(|e| TransportError::Kafka(KafkaTransportError::Consumer(e)))
+    /// Commits `next_offset` — the next offset to consume — on one partition.
+    ///
+    /// The keyed concurrent runner settles messages out of order and commits the
+    /// contiguous low watermark with this; [`commit`](Self::commit) is the
+    /// one-message shorthand.
+    pub fn commit_position(
+        &self,
+        topic:       &str,
+        partition:   i32,
+        next_offset: i64,
+    ) -> Result<(), TransportError> {
+        match &self.backend {
+            Backend::Kafka(consumer) => {
+                let mut tpl = TopicPartitionList::new();
+                tpl.add_partition_offset(topic, partition, Offset::Offset(next_offset))
+                    .map_err(|e| TransportError::Kafka(KafkaTransportError::Config(e.to_string())))?;
+
+                consumer
+                    .commit(&tpl, rdkafka::consumer::CommitMode::Async)
+                    .map_err(|e| TransportError::Kafka(KafkaTransportError::Consumer(e)))
+            }
+            #[cfg(feature = "memory-broker")]
+            Backend::Memory(consumer) => {
+                consumer.commit(topic, partition, next_offset);
+                Ok(())
+            }
+        }
     }
 }
+
+/// Assembles a [`ConsumedMessage`] from a record's parts, whichever backend read it.
+///
+/// A decode failure is captured in `payload` rather than aborting the stream, so
+/// the worker can still see the offset and commit past the poison record. The raw
+/// bytes are retained regardless, so the record can be forwarded to a dead-letter
+/// topic even when it fails to decode.
+fn consumed<T: ConsumablePayload>(
+    topic: String,
+    partition: i32,
+    offset: i64,
+    key_bytes: Option<Vec<u8>>,
+    headers: HashMap<String, String>,
+    timestamp_ms: Option<i64>,
+    payload: Option<&[u8]>,
+) -> ConsumedMessage<T> {
+    let key = key_bytes
+        .as_deref()
+        .and_then(|k| std::str::from_utf8(k).ok())
+        .unwrap_or("")
+        .to_owned();
+    let raw_payload = payload.map(<[u8]>::to_vec).unwrap_or_default();
+    let decoded = decode_payload::<T>(payload, &headers);
+
+    tracing::debug!(
+        topic = %topic,
+        partition,
+        offset,
+        key = %key,
+        "Kafka message received"
+    );
+
+    ConsumedMessage {
+        topic,
+        partition,
+        offset,
+        key,
+        key_bytes,
+        headers,
+        timestamp_ms,
+        raw_payload,
+        payload: decoded,
+    }
+}
+
+/// Decodes a record payload exactly as [`KafkaConsumerHandle::stream`] does, with
+/// the codec named by the record's `content-type` header (JSON when absent).
+///
+/// Public so tooling that inspects parked records (the dead-letter browser's dry
+/// run) reaches the same verdict the consumer did, rather than a look-alike.
+pub fn decode_payload<T: ConsumablePayload>(
+    payload: Option<&[u8]>,
+    headers: &HashMap<String, String>,
+) -> Result<T, TransportError> {
+    let bytes = payload.ok_or(TransportError::Kafka(KafkaTransportError::EmptyPayload))?;
+    let content_type = ContentType::of(headers)?;
+    Ok(T::decode(content_type, bytes)?)
+}
@@ -0,0 +1,242 @@
+//! Keyed-ordered, partition-concurrent processing mode for the consumer runtime.
+//!
+//! [`run_consumer`](super::runner::run_consumer) settles one message at a time, so
+//! a slow key — or a key sleeping through its retry backoff — stalls every key
+//! behind it on the partition, and a group cannot do useful work on more
+//! replicas than it has partitions. [`run_consumer_keyed`] keeps the same
+//! per-message state machine (decode → process → retry/dead-letter, same
+//! [`RetryPolicy`], same DLQ headers) but runs different keys concurrently:
+//!
+//! - **Lanes.** A message's lane is its `(topic, partition, key)`. A lane has at
+//!   most one message in flight; later messages for a busy lane queue behind it
+//!   in offset order, so per-key order is exactly what the sequential runner
+//!   gives. Keyless records share their partition's lane and stay sequential.
+//! - **Low-watermark commits.** Messages settle out of order, so a partition's
+//!   offset only advances to its lowest unsettled offset: everything below the
+//!   committed position has reached a terminal outcome. A crash redelivers the
+//!   settled-but-uncommitted tail, which at-least-once consumers already absorb.
+//! - **Bounded buffering.** At most [`KeyedConcurrency::max_in_flight`] messages
+//!   are held (running or queued); the stream is not polled while the buffer is
+//!   full.
+//!
+//! Errors behave as in the sequential runner: a broker/stream error or a failed
+//! dead-letter publish returns `Err`, and nothing past the watermark is
+//! committed, so the caller's restart loop resumes from the last safe offset.
+
+use std::collections::{BTreeSet, HashMap, VecDeque};
+
+use futures_util::stream::FuturesUnordered;
+use futures_util::StreamExt;
+
+use crate::error::TransportError;
+use crate::kafka::consumer::handle::{ConsumedMessage, KafkaConsumerHandle};
+use crate::kafka::consumer::runner::{settle, ProcessFuture, RetryPolicy};
+use crate::kafka::envelope::ConsumablePayload;
+use crate::kafka::producer::handle::KafkaProducerHandle;
+
+/// Sizing for [`run_consumer_keyed`].
+#[derive(Debug, Clone)]
+pub struct KeyedConcurrency {
+    /// Messages held at once across all lanes, running or queued behind a busy
+    /// key. Bounds memory and the redelivery tail after a crash.
+    pub max_in_flight: usize,
+}
+
+impl Default for KeyedConcurrency {
+    /// 64 messages in flight.
+    fn default() -> Self {
+        Self { max_in_flight: 64 }
+    }
+}
+
+impl KeyedConcurrency {
+    /// Reads `KAFKA_CONSUMER_MAX_IN_FLIGHT`, falling back to the default on an
+    /// absent, unparsable or zero value.
+    pub fn from_env() -> Self {
+        std::env::var("KAFKA_CONSUMER_MAX_IN_FLIGHT")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .filter(|n: &usize| *n > 0)
+            .map_or_else(Self::default, |max_in_flight| Self { max_in_flight })
+    }
+}
+
+/// Drives one consume cycle with keyed concurrency. Same contract and closure
+/// shape as [`run_consumer`](super::runner::run_consumer); see the module docs
+/// for the ordering and commit rules.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// run_consumer_keyed::<ReactionEvent, _>(&handle, &producer, &policy, &KeyedConcurrency::from_env(), |event| {
+///     Box::pin(async move { ProcessOutcome::from_result(self.process(event).await) })
+/// })
+/// .await?;
+/// ```
+pub async fn run_consumer_keyed<T, F>(
+    handle:      &KafkaConsumerHandle,
+    producer:    &KafkaProducerHandle,
+    policy:      &RetryPolicy,
+    concurrency: &KeyedConcurrency,
+    process:     F,
+) -> Result<(), TransportError>
+where
+    T: ConsumablePayload,
+    F: for<'a> Fn(&'a T) -> ProcessFuture<'a>,
+{
+    let max_in_flight = concurrency.max_in_flight.max(1);
+    let mut stream = handle.stream::<T>();
+    let mut running = FuturesUnordered::new();
+    // A lane is busy while it has an entry; the queue holds what waits behind
+    // its in-flight message.
+    let mut lanes: HashMap<Lane, VecDeque<ConsumedMessage<T>>> = HashMap::new();
+    let mut progress: HashMap<(String, i32), PartitionProgress> = HashMap::new();
+    let mut buffered = 0usize;
+    let mut exhausted = false;
+
+    loop {
+        tokio::select! {
+            // Settle before reading more, so the watermark and the buffer drain
+            // ahead of new work.
+            biased;
+
+            Some(settled) = running.next(), if !running.is_empty() => {
+                let msg: ConsumedMessage<T> = settled?;
+                buffered -= 1;
+
+                let partition = progress
+                    .get_mut(&(msg.topic.clone(), msg.partition))
+                    .expect("a running message's partition is tracked");
+                if let Some(next_offset) = partition.settle(msg.offset) {
+                    handle.commit_position(&msg.topic, msg.partition, next_offset)?;
+                }
+
+                let lane = Lane::of(&msg);
+                match lanes.get_mut(&lane).and_then(VecDeque::pop_front) {
+                    Some(next) => running.push(settle_owned(next, producer, policy, &process)),
+                    None => {
+                        lanes.remove(&lane);
+                    }
+                }
+            }
+
+            item = stream.next(), if !exhausted && buffered < max_in_flight => {
+                let Some(item) = item else {
+                    exhausted = true;
+                    continue;
+                };
+                // A broker/stream-level error carries no offset. Surface it so the
+                // caller can rebuild the consumer; nothing past the watermark is
+                // committed.
+                let msg = item?;
+                buffered += 1;
+                progress
+                    .entry((msg.topic.clone(), msg.partition))
+                    .or_default()
+                    .track(msg.offset);
+
+                let lane = Lane::of(&msg);
+                match lanes.get_mut(&lane) {
+                    Some(queue) => queue.push_back(msg),
+                    None => {
+                        lanes.insert(lane, VecDeque::new());
+                        running.push(settle_owned(msg, producer, policy, &process));
+                    }
+                }
+            }
+
+            else => break,
+        }
+    }
+
+    Ok(())
+}
+
+/// [`settle`] over an owned message, handing it back for the bookkeeping. One
+/// `async fn` so every future in the running set has the same type.
+async fn settle_owned<T, F>(
+    msg:      ConsumedMessage<T>,
+    producer: &KafkaProducerHandle,
+    policy:   &RetryPolicy,
+    process:  &F,
+) -> Result<ConsumedMessage<T>, TransportError>
+where
+    F: for<'a> Fn(&'a T) -> ProcessFuture<'a>,
+{
+    let mut process = process;
+    settle(&msg, producer, policy, &mut process).await?;
+    Ok(msg)
+}
+
+/// The ordering scope of a message: its key within its partition.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct Lane {
+    topic:     String,
+    partition: i32,
+    key:       String,
+}
+
+impl Lane {
+    fn of<T>(msg: &ConsumedMessage<T>) -> Self {
+        Self { topic: msg.topic.clone(), partition: msg.partition, key: msg.key.clone() }
+    }
+}
+
+/// One partition's unsettled offsets and the position committed so far.
+#[derive(Debug, Default)]
+struct PartitionProgress {
+    unsettled: BTreeSet<i64>,
+    highest:   i64,
+    /// The next offset to consume as last committed — or, before the first
+    /// commit, the first offset this cycle read (the group's resume position).
+    committed: Option<i64>,
+}
+
+impl PartitionProgress {
+    /// Records a message handed to the lanes.
+    fn track(&mut self, offset: i64) {
+        self.unsettled.insert(offset);
+        self.highest = self.highest.max(offset);
+        self.committed.get_or_insert(offset);
+    }
+
+    /// Marks `offset` settled. Returns the new commit position (the next offset
+    /// to consume) when the contiguous low watermark moved.
+    fn settle(&mut self, offset: i64) -> Option<i64> {
+        self.unsettled.remove(&offset);
+        let next = self.unsettled.first().copied().unwrap_or(self.highest + 1);
+        if self.committed.is_some_and(|c| c >= next) {
+            return None;
+        }
+        self.committed = Some(next);
+        Some(next)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn the_watermark_waits_for_the_lowest_unsettled_offset() {
+        let mut p = PartitionProgress::default();
+        for offset in 10..=13 {
+            p.track(offset);
+        }
+        assert_eq!(p.settle(12), None, "10 still running: nothing to commit");
+        assert_eq!(p.settle(11), None);
+        assert_eq!(p.settle(10), Some(13), "10–12 settled: resume at 13");
+        assert_eq!(p.settle(13), Some(14));
+    }
+
+    #[test]
+    fn later_arrivals_do_not_pull_the_position_back() {
+        let mut p = PartitionProgress::default();
+        p.track(0);
+        p.track(1);
+        assert_eq!(p.settle(0), Some(1));
+        p.track(2);
+        assert_eq!(p.settle(2), None, "1 is still running");
+        assert_eq!(p.settle(1), Some(3));
+    }
+}
@@ -0,0 +1,415 @@
+//! Non-blocking retry ladder: delayed retry topics instead of in-place backoff.
+//!
+//! [`run_consumer`](super::runner::run_consumer) retries a transient failure in
+//! place, sleeping up to [`RetryPolicy::max_backoff`](super::runner::RetryPolicy)
+//! between attempts, so during a downstream outage every message behind the
+//! failing one waits. [`run_consumer_laddered`] processes each message once and,
+//! on [`ProcessOutcome::Retry`], republishes it to the next tier of a ladder of
+//! delayed topics and commits, so the partition keeps moving:
+//!
+//! ```text
+//! <topic> ─Retry─▶ <topic>.retry.1 ─Retry─▶ <topic>.retry.2 ─ … ─Retry─▶ <topic>.dlq
+//! ```
+//!
+//! - **Tiers.** Tier `n` is the topic `<topic>.retry.<n>`, with its own delay
+//!   ([`RetryLadder`]). Each tier is read by its own runner — one consumer handle
+//!   per tier — which holds a record until its `x-retry-due-at-ms`. A tier has a
+//!   single delay, so records reach a tier partition in due order and the wait
+//!   only ever blocks records that are due later anyway.
+//! - **Ownership.** A topic read by several groups shares its retry topics. Every
+//!   retry record carries `x-retry-group`; a tier runner commits past records
+//!   another group escalated without touching them.
+//! - **Exhaustion.** A `Retry` on the last tier dead-letters to the *origin*
+//!   topic's `.dlq` as `retry-exhausted`, with the origin coordinates the ladder
+//!   carried, so `dlq-tool` replays it where it first arrived. `Reject` and decode
+//!   failures dead-letter from any tier, as in the sequential runner.
+//! - **Ordering.** An escalated record is overtaken by later records of the same
+//!   key. Use the ladder only for handlers that tolerate reordering (idempotent
+//!   upserts, commutative folds); order-sensitive consumers stay on the
+//!   sequential or keyed runner.
+//!
+//! The provisioner creates [`RETRY_TIERS`] tiers for every consumed topic; a
+//! ladder may use fewer. Tier delays are capped at [`MAX_TIER_DELAY`], below
+//! librdkafka's default `max.poll.interval.ms`, because a tier runner does not
+//! poll while it waits.
+
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use futures_util::StreamExt;
+
+use crate::error::TransportError;
+use crate::kafka::consumer::handle::{ConsumedMessage, KafkaConsumerHandle};
+use crate::kafka::consumer::metrics::{consumer_metrics, Settled};
+use crate::kafka::consumer::runner::{
+    dead_letter, Origin, ProcessFuture, ProcessOutcome, MAX_DLQ_ERROR_LEN,
+};
+use crate::kafka::envelope::ConsumablePayload;
+use crate::kafka::error::KafkaTransportError;
+use crate::kafka::producer::handle::KafkaProducerHandle;
+
+/// Infix between an origin topic and a tier number
+/// (e.g. `post.v1.events` → `post.v1.events.retry.2`). Public so provisioning
+/// tooling derives the exact names the runner publishes to.
+pub const RETRY_TOPIC_INFIX: &str = ".retry.";
+
+/// Tiers provisioned per consumed topic — the longest ladder a runner may use.
+pub const RETRY_TIERS: u32 = 3;
+
+/// Upper bound on a tier delay: a tier runner does not poll while it waits, so
+/// the wait must stay below `max.poll.interval.ms` (300 s by default).
+pub const MAX_TIER_DELAY: Duration = Duration::from_secs(240);
+
+/// Prefix shared by every ladder header.
+pub const RETRY_HEADER_PREFIX: &str = "x-retry-";
+
+pub const HEADER_RETRY_GROUP: &str            = "x-retry-group";
+pub const HEADER_RETRY_TIER: &str             = "x-retry-tier";
+pub const HEADER_RETRY_DUE_AT_MS: &str        = "x-retry-due-at-ms";
+pub const HEADER_RETRY_ORIGIN_TOPIC: &str     = "x-retry-origin-topic";
+pub const HEADER_RETRY_ORIGIN_PARTITION: &str = "x-retry-origin-partition";
+pub const HEADER_RETRY_ORIGIN_OFFSET: &str    = "x-retry-origin-offset";
+pub const HEADER_RETRY_ERROR: &str            = "x-retry-error";
+
+/// The tier-`tier` retry topic of `origin`.
+pub fn retry_topic(origin: &str, tier: u32) -> String {
+    format!("{origin}{RETRY_TOPIC_INFIX}{tier}")
+}
+
+/// Every provisioned retry topic of `origin`, tier 1 to [`RETRY_TIERS`].
+pub fn retry_topics(origin: &str) -> Vec<String> {
+    (1..=RETRY_TIERS).map(|tier| retry_topic(origin, tier)).collect()
+}
+
+/// Splits `<origin>.retry.<n>` into `(origin, n)`; `None` for any other topic.
+pub fn parse_retry_topic(topic: &str) -> Option<(&str, u32)> {
+    let (origin, tier) = topic.rsplit_once(RETRY_TOPIC_INFIX)?;
+    let tier: u32 = tier.parse().ok()?;
+    (tier >= 1 && !origin.is_empty()).then_some((origin, tier))
+}
+
+/// The delay of each tier, tier 1 first.
+#[derive(Debug, Clone)]
+pub struct RetryLadder {
+    delays: Vec<Duration>,
+}
+
+impl Default for RetryLadder {
+    /// 5 s, 30 s, 2 min — about two and a half minutes of cover before a
+    /// record is dead-lettered.
+    fn default() -> Self {
+        Self {
+            delays: vec![Duration::from_secs(5), Duration::from_secs(30), Duration::from_secs(120)],
+        }
+    }
+}
+
+impl RetryLadder {
+    /// A ladder with one tier per delay. Rejects an empty ladder, more tiers
+    /// than are provisioned, and any delay above [`MAX_TIER_DELAY`].
+    pub fn new(delays: Vec<Duration>) -> Result<Self, KafkaTransportError> {
+        if delays.is_empty() || delays.len() > RETRY_TIERS as usize {
+            return Err(KafkaTransportError::Config(format!(
+                "a retry ladder needs 1 to {RETRY_TIERS} tiers, got {}",
+                delays.len()
+            )));
+        }
+        if let Some(delay) = delays.iter().find(|d| **d > MAX_TIER_DELAY) {
+            return Err(KafkaTransportError::Config(format!(
+                "retry tier delay {delay:?} exceeds the {MAX_TIER_DELAY:?} cap"
+            )));
+        }
+        Ok(Self { delays })
+    }
+
+    /// Number of tiers before a record is dead-lettered.
+    pub fn tiers(&self) -> u32 {
+        self.delays.len() as u32
+    }
+
+    /// Delay of `tier` (1-based), or `None` past the last tier.
+    pub fn delay(&self, tier: u32) -> Option<Duration> {
+        let index = tier.checked_sub(1)? as usize;
+        self.delays.get(index).copied()
+    }
+
+    /// The retry topics this ladder publishes to for `origin` — what its tier
+    /// runners subscribe to, one handle each.
+    pub fn topics(&self, origin: &str) -> Vec<String> {
+        (1..=self.tiers()).map(|tier| retry_topic(origin, tier)).collect()
+    }
+}
+
+/// Drives one consume cycle of an origin topic or of one retry tier: each
+/// message is processed once, then committed, escalated to the next tier, or
+/// dead-lettered. Same closure shape and error contract as
+/// [`run_consumer`](super::runner::run_consumer); see the module docs for the
+/// tier, ownership and ordering rules.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// // One runner on the origin topic, plus one per tier, each on its own handle.
+/// run_consumer_laddered::<ReactionEvent, _>(&handle, &producer, &ladder, |event| {
+///     Box::pin(async move { ProcessOutcome::from_result(self.process(event).await) })
+/// })
+/// .await?;
+/// ```
+pub async fn run_consumer_laddered<T, F>(
+    handle:   &KafkaConsumerHandle,
+    producer: &KafkaProducerHandle,
+    ladder:   &RetryLadder,
+    process:  F,
+) -> Result<(), TransportError>
+where
+    T: ConsumablePayload,
+    F: for<'a> Fn(&'a T) -> ProcessFuture<'a>,
+{
+    let mut process = process;
+    let group = handle.group_id();
+    let mut stream = handle.stream::<T>();
+
+    while let Some(item) = stream.next().await {
+        // A broker/stream-level error carries no offset. Surface it so the caller
+        // can rebuild the consumer; nothing is committed.
+        let msg = item?;
+        let started = Instant::now();
+        let result = step(&msg, group, producer, ladder, &mut process).await;
+        consumer_metrics().settled(&msg.topic, Settled::of(&result), started);
+        result?;
+
+        // Settled, escalated, dead-lettered or not ours → advance the offset.
+        handle.commit(&msg)?;
+    }
+
+    Ok(())
+}
+
+/// Takes one message one rung: wait if it came off a tier, process it once,
+/// then escalate or dead-letter, reporting which. `Err` is a failed publish, after
+/// which the offset must not be committed. `process` is taken by `&mut` for the same
+/// reason as in [`settle`](super::runner::settle): the future stays `Send` for a
+/// closure that is not `Sync`.
+async fn step<T, F>(
+    msg:      &ConsumedMessage<T>,
+    group:    &str,
+    producer: &KafkaProducerHandle,
+    ladder:   &RetryLadder,
+    process:  &mut F,
+) -> Result<Settled, TransportError>
+where
+    F: for<'a> Fn(&'a T) -> ProcessFuture<'a>,
+{
+    let stamp = match parse_retry_topic(&msg.topic) {
+        None => None,
+        Some(_) => match RetryStamp::from_headers(&msg.headers) {
+            Some(stamp) if stamp.group == group => Some(stamp),
+            Some(_) => return Ok(Settled::Done),
+            None => {
+                tracing::warn!(
+                    topic     = %msg.topic,
+                    partition = msg.partition,
+                    offset    = msg.offset,
+                    "record on a retry topic without ladder headers — skipping"
+                );
+                return Ok(Settled::Done);
+            }
+        },
+    };
+
+    let (tier, origin) = match &stamp {
+        None => (0, Origin::of(msg)),
+        Some(stamp) => {
+            wait_until(stamp.due_at_ms).await;
+            let origin = Origin {
+                topic:     &stamp.origin_topic,
+                partition: stamp.origin_partition,
+                offset:    stamp.origin_offset,
+            };
+            (stamp.tier, origin)
+        }
+    };
+
+    // Undecodable record → poison. Dead-letter it.
+    let event = match &msg.payload {
+        Ok(event) => event,
+        Err(decode_err) => {
+            return dead_letter(producer, msg, origin, "decode", &decode_err.to_string(), tier)
+                .await
+                .map(|()| Settled::DeadLettered);
+        }
+    };
+
+    let attempts = tier + 1;
+    match process(event).await {
+        ProcessOutcome::Done => Ok(Settled::Done),
+        ProcessOutcome::Reject(reason) => dead_letter(producer, msg, origin, "reject", &reason, attempts)
+            .await
+            .map(|()| Settled::DeadLettered),
+        ProcessOutcome::Retry(reason) => match ladder.delay(tier + 1) {
+            None => dead_letter(producer, msg, origin, "retry-exhausted", &reason, attempts)
+                .await
+                .map(|()| Settled::DeadLettered),
+            Some(delay) => {
+                let next = RetryStamp {
+                    group:            group.to_owned(),
+                    tier:             tier + 1,
+                    due_at_ms:        now_ms() + delay.as_millis() as i64,
+                    origin_topic:     origin.topic.to_owned(),
+                    origin_partition: origin.partition,
+                    origin_offset:    origin.offset,
+                };
+                escalate(producer, msg, &next, &reason).await.map(|()| Settled::Escalated)
+            }
+        },
+    }
+}
+
+/// Republishes a record verbatim to its next tier, stamped with the ladder
+/// headers. The origin headers travel with it, as they do to the DLQ.
+async fn escalate<T>(
+    producer: &KafkaProducerHandle,
+    msg:      &ConsumedMessage<T>,
+    next:     &RetryStamp,
+    reason:   &str,
+) -> Result<(), TransportError> {
+    let topic = retry_topic(&next.origin_topic, next.tier);
+    let mut headers = strip_retry_headers(&msg.headers);
+    headers.extend(next.to_headers(reason));
+
+    producer
+        .republish(&topic, msg.key_bytes.as_deref(), &msg.raw_payload, headers)
+        .await?;
+
+    tracing::warn!(
+        retry_topic = %topic,
+        origin    = %next.origin_topic,
+        partition = next.origin_partition,
+        offset    = next.origin_offset,
+        key       = %msg.key,
+        due_at_ms = next.due_at_ms,
+        reason    = %reason,
+        "transient processing failure — escalated to the next retry tier"
+    );
+
+    Ok(())
+}
+
+/// Sleeps until `due_at_ms`, never longer than [`MAX_TIER_DELAY`] — a skewed
+/// clock or a hand-edited header must not outlast the poll interval.
+async fn wait_until(due_at_ms: i64) {
+    let remaining = due_at_ms.saturating_sub(now_ms());
+    if remaining > 0 {
+        let wait = Duration::from_millis(remaining as u64).min(MAX_TIER_DELAY);
+        tokio::time::sleep(wait).await;
+    }
+}
+
+/// `headers` without the ladder's `x-retry-*` bookkeeping.
+pub(super) fn strip_retry_headers(headers: &HashMap<String, String>) -> HashMap<String, String> {
+    headers
+        .iter()
+        .filter(|(k, _)| !k.starts_with(RETRY_HEADER_PREFIX))
+        .map(|(k, v)| (k.clone(), v.clone()))
+        .collect()
+}
+
+fn now_ms() -> i64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_millis() as i64)
+        .unwrap_or(0)
+}
+
+/// The ladder headers of a record sitting on a retry tier.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct RetryStamp {
+    group:            String,
+    tier:             u32,
+    due_at_ms:        i64,
+    origin_topic:     String,
+    origin_partition: i32,
+    origin_offset:    i64,
+}
+
+impl RetryStamp {
+    /// Parses the `x-retry-*` headers; `None` if any is missing or malformed.
+    fn from_headers(headers: &HashMap<String, String>) -> Option<Self> {
+        fn num<N: std::str::FromStr>(h: &HashMap<String, String>, k: &str) -> Option<N> {
+            h.get(k)?.parse().ok()
+        }
+
+        Some(Self {
+            group:            headers.get(HEADER_RETRY_GROUP)?.clone(),
+            tier:             num(headers, HEADER_RETRY_TIER)?,
+            due_at_ms:        num(headers, HEADER_RETRY_DUE_AT_MS)?,
+            origin_topic:     headers.get(HEADER_RETRY_ORIGIN_TOPIC)?.clone(),
+            origin_partition: num(headers, HEADER_RETRY_ORIGIN_PARTITION)?,
+            origin_offset:    num(headers, HEADER_RETRY_ORIGIN_OFFSET)?,
+        })
+    }
+
+    /// Renders the `x-retry-*` headers, with `error` as the last failure.
+    fn to_headers(&self, error: &str) -> HashMap<String, String> {
+        let error: String = error.chars().take(MAX_DLQ_ERROR_LEN).collect();
+        HashMap::from([
+            (HEADER_RETRY_GROUP.to_owned(),            self.group.clone()),
+            (HEADER_RETRY_TIER.to_owned(),             self.tier.to_string()),
+            (HEADER_RETRY_DUE_AT_MS.to_owned(),        self.due_at_ms.to_string()),
+            (HEADER_RETRY_ORIGIN_TOPIC.to_owned(),     self.origin_topic.clone()),
+            (HEADER_RETRY_ORIGIN_PARTITION.to_owned(), self.origin_partition.to_string()),
+            (HEADER_RETRY_ORIGIN_OFFSET.to_owned(),    self.origin_offset.to_string()),
+            (HEADER_RETRY_ERROR.to_owned(),            error),
+        ])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn retry_topic_names_round_trip() {
+        assert_eq!(retry_topic("post.v1.events", 2), "post.v1.events.retry.2");
+        assert_eq!(parse_retry_topic("post.v1.events.retry.2"), Some(("post.v1.events", 2)));
+        assert_eq!(parse_retry_topic("post.v1.events"), None);
+        assert_eq!(parse_retry_topic("post.v1.events.retry.0"), None);
+        assert_eq!(parse_retry_topic("post.v1.events.retry.x"), None);
+        assert_eq!(parse_retry_topic("post.v1.events.dlq"), None);
+        assert_eq!(retry_topics("a").len(), RETRY_TIERS as usize);
+    }
+
+    #[test]
+    fn ladders_are_bounded_by_the_provisioned_tiers_and_the_poll_interval() {
+        let ladder = RetryLadder::default();
+        assert_eq!(ladder.tiers(), 3);
+        assert_eq!(ladder.delay(0), None);
+        assert_eq!(ladder.delay(1), Some(Duration::from_secs(5)));
+        assert_eq!(ladder.delay(4), None);
+        assert_eq!(ladder.topics("t"), vec!["t.retry.1", "t.retry.2", "t.retry.3"]);
+
+        assert!(RetryLadder::new(vec![]).is_err());
+        assert!(RetryLadder::new(vec![Duration::from_secs(1); 4]).is_err());
+        assert!(RetryLadder::new(vec![Duration::from_secs(300)]).is_err());
+        assert!(RetryLadder::new(vec![Duration::from_millis(200)]).is_ok());
+    }
+
+    #[test]
+    fn a_stamp_round_trips_and_is_stripped_before_the_next_hop() {
+        let stamp = RetryStamp {
+            group:            "timeline".into(),
+            tier:             2,
+            due_at_ms:        1_700_000_000_000,
+            origin_topic:     "post.v1.events".into(),
+            origin_partition: 4,
+            origin_offset:    918,
+        };
+        let mut headers = HashMap::from([("event_id".to_owned(), "e-1".to_owned())]);
+        headers.extend(stamp.to_headers("timeout"));
+        assert_eq!(RetryStamp::from_headers(&headers), Some(stamp));
+
+        let stripped = strip_retry_headers(&headers);
+        assert_eq!(stripped, HashMap::from([("event_id".to_owned(), "e-1".to_owned())]));
+        assert_eq!(RetryStamp::from_headers(&stripped), None);
+    }
+}
@@ -0,0 +1,243 @@
+//! Consumer-side signals shared by every runner: throughput, processing time,
+//! retries, dead-letters, and committed lag.
+//!
+//! Recorded where the per-message state machine lives — [`settle`](super::runner::settle)
+//! and the ladder's per-rung step, and [`dead_letter`](super::runner::dead_letter) — so the
+//! sequential, keyed and laddered runners report identically. Lag comes from the consumer's
+//! own statistics callback ([`LagContext`]). Surfaced by the
+//! Prometheus exporter as:
+//!
+//! | Instrument | Prometheus series | Labels |
+//! |---|---|---|
+//! | `kafka_consumer_messages` | `kafka_consumer_messages_total` | `topic`, `outcome` (`done` \| `escalated` \| `dead_lettered` \| `failed`) |
+//! | `kafka_consumer_processing_duration` | `kafka_consumer_processing_duration_seconds` (histogram) | `topic`, `outcome` |
+//! | `kafka_consumer_retries` | `kafka_consumer_retries_total` | `topic` |
+//! | `kafka_consumer_dead_lettered` | `kafka_consumer_dead_lettered_total` | `topic` (origin), `reason` |
+//! | `kafka_consumer_lag` | `kafka_consumer_lag` (gauge) | `topic`, `partition`, `group` |
+//!
+//! Processing time spans the whole settle — retries, backoff and the dead-letter publish
+//! included — because that is how long the message held its partition. Lag is librdkafka's
+//! `consumer_lag` (high watermark minus committed offset) for every assigned partition,
+//! emitted every `statistics.interval.ms`
+//! ([`ConsumerConfig::statistics_interval_ms`](crate::kafka::config::consumer::ConsumerConfig::statistics_interval_ms)),
+//! so it costs no broker round-trip and keeps reporting while a runner is stuck.
+
+use std::sync::OnceLock;
+use std::time::Instant;
+
+use opentelemetry::{
+    global,
+    metrics::{Counter, Gauge, Histogram},
+    KeyValue,
+};
+use rdkafka::{consumer::ConsumerContext, statistics::Statistics, ClientContext};
+
+/// Histogram buckets (seconds): 1 ms … 60 s — a retried message can hold its partition
+/// for the whole backoff envelope.
+const DURATION_BUCKETS: &[f64] = &[
+    0.001, 0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0, 30.0, 60.0,
+];
+
+/// Terminal outcome label for a settled message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Settled {
+    /// Processed (or intentionally skipped); the offset may advance.
+    Done,
+    /// Republished to the next retry-ladder tier; the offset may advance.
+    Escalated,
+    /// Parked on the dead-letter topic; the offset may advance.
+    DeadLettered,
+    /// The dead-letter publish failed; the offset must not advance.
+    Failed,
+}
+
+impl Settled {
+    /// The outcome of a settle attempt: a publish error means the message is
+    /// [`Failed`](Self::Failed) whatever it was on its way to.
+    pub(crate) fn of<E>(result: &Result<Self, E>) -> Self {
+        match result {
+            Ok(outcome) => *outcome,
+            Err(_) => Self::Failed,
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            Self::Done => "done",
+            Self::Escalated => "escalated",
+            Self::DeadLettered => "dead_lettered",
+            Self::Failed => "failed",
+        }
+    }
+}
+
+pub(crate) struct ConsumerMetrics {
+    messages: Counter<u64>,
+    duration: Histogram<f64>,
+    retries: Counter<u64>,
+    dead_lettered: Counter<u64>,
+    lag: Gauge<i64>,
+}
+
+/// The process-wide instruments, built on first use. Consumers start after
+/// `telemetry::init` has installed the global meter provider (`service_runtime::serve`
+/// initialises telemetry before composing the service); before that — or in tests — the
+/// instruments bind to a no-op meter.
+pub(crate) fn consumer_metrics() -> &'static ConsumerMetrics {
+    static METRICS: OnceLock<ConsumerMetrics> = OnceLock::new();
+    METRICS.get_or_init(|| {
+        let meter = global::meter("transport");
+        ConsumerMetrics {
+            messages: meter
+                .u64_counter("kafka_consumer_messages")
+                .with_description("Messages settled by a consumer runner, by terminal outcome.")
+                .build(),
+            duration: meter
+                .f64_histogram("kafka_consumer_processing_duration")
+                .with_unit("s")
+                .with_description("Time from receipt to terminal outcome, retries included.")
+                .with_boundaries(DURATION_BUCKETS.to_vec())
+                .build(),
+            retries: meter
+                .u64_counter("kafka_consumer_retries")
+                .with_description("In-place retries after a transient processing failure.")
+                .build(),
+            dead_lettered: meter
+                .u64_counter("kafka_consumer_dead_lettered")
+                .with_description("Records parked on a dead-letter topic, by reason.")
+                .build(),
+            lag: meter
+                .i64_gauge("kafka_consumer_lag")
+                .with_description("Records between the committed position and the high watermark.")
+                .build(),
+        }
+    })
+}
+
+impl ConsumerMetrics {
+    pub(crate) fn settled(&self, topic: &str, outcome: Settled, started: Instant) {
+        let attrs = [
+            KeyValue::new("topic", topic.to_string()),
+            KeyValue::new("outcome", outcome.label()),
+        ];
+        self.messages.add(1, &attrs);
+        self.duration.record(started.elapsed().as_secs_f64(), &attrs);
+    }
+
+    pub(crate) fn retried(&self, topic: &str) {
+        self.retries.add(1, &[KeyValue::new("topic", topic.to_string())]);
+    }
+
+    pub(crate) fn dead_lettered(&self, origin_topic: &str, reason: &str) {
+        self.dead_lettered.add(
+            1,
+            &[
+                KeyValue::new("topic", origin_topic.to_string()),
+                KeyValue::new("reason", reason.to_string()),
+            ],
+        );
+    }
+
+    pub(crate) fn lag(&self, topic: &str, partition: i32, group: &str, lag: i64) {
+        self.lag.record(
+            lag,
+            &[
+                KeyValue::new("topic", topic.to_string()),
+                KeyValue::new("partition", i64::from(partition)),
+                KeyValue::new("group", group.to_string()),
+            ],
+        );
+    }
+}
+
+/// Consumer context that publishes each statistics report's per-partition lag.
+/// Otherwise behaves as rdkafka's default context.
+pub(crate) struct LagContext {
+    group: String,
+}
+
+impl LagContext {
+    pub(crate) fn new(group: String) -> Self {
+        Self { group }
+    }
+}
+
+impl ClientContext for LagContext {
+    fn stats(&self, statistics: Statistics) {
+        let metrics = consumer_metrics();
+        for (topic, partition, lag) in partition_lags(&statistics) {
+            metrics.lag(topic, partition, &self.group, lag);
+        }
+    }
+}
+
+impl ConsumerContext for LagContext {}
+
+/// The known lag of every real partition in a statistics report. librdkafka lists
+/// an internal `-1` partition per topic, and reports `-1` lag until a partition has
+/// both a committed offset and a watermark; both are skipped.
+fn partition_lags(statistics: &Statistics) -> Vec<(&str, i32, i64)> {
+    statistics
+        .topics
+        .iter()
+        .flat_map(|(name, topic)| {
+            topic
+                .partitions
+                .values()
+                .filter(|p| p.partition >= 0 && p.consumer_lag >= 0)
+                .map(move |p| (name.as_str(), p.partition, p.consumer_lag))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rdkafka::statistics::{Partition, Topic};
+
+    fn stats_with(partitions: &[(i32, i64)]) -> Statistics {
+        let topic = Topic {
+            topic: "post.v1.events".into(),
+            partitions: partitions
+                .iter()
+                .map(|&(partition, consumer_lag)| {
+                    (partition, Partition { partition, consumer_lag, ..Default::default() })
+                })
+                .collect(),
+            ..Default::default()
+        };
+        Statistics {
+            topics: [("post.v1.events".to_string(), topic)].into_iter().collect(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn lag_is_reported_per_assigned_partition() {
+        let stats = stats_with(&[(0, 20), (1, 0)]);
+        let mut lags = partition_lags(&stats);
+        lags.sort();
+        assert_eq!(lags, vec![("post.v1.events", 0, 20), ("post.v1.events", 1, 0)]);
+    }
+
+    #[test]
+    fn the_internal_partition_and_unknown_lag_are_skipped() {
+        let stats = stats_with(&[(-1, 5), (2, -1), (3, 7)]);
+        assert_eq!(partition_lags(&stats), vec![("post.v1.events", 3, 7)]);
+    }
+
+    #[test]
+    fn a_failed_publish_settles_as_failed() {
+        assert_eq!(Settled::of::<()>(&Ok(Settled::DeadLettered)), Settled::DeadLettered);
+        assert_eq!(Settled::of(&Err(())), Settled::Failed);
+    }
+
+    #[test]
+    fn outcome_labels_are_stable() {
+        assert_eq!(Settled::Done.label(), "done");
+        assert_eq!(Settled::Escalated.label(), "escalated");
+        assert_eq!(Settled::DeadLettered.label(), "dead_lettered");
+        assert_eq!(Settled::Failed.label(), "failed");
+    }
+}
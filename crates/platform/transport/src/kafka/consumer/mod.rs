@@ -1,9 +1,16 @@
 pub mod builder;
 pub mod handle;
+pub mod keyed;
+pub mod ladder;
+mod metrics;
 pub mod runner;
 
 pub use builder::KafkaConsumerBuilder;
 pub use handle::{ConsumedMessage, KafkaConsumerHandle};
+pub use keyed::{run_consumer_keyed, KeyedConcurrency};
+pub use ladder::{
+    retry_topic, retry_topics, run_consumer_laddered, RetryLadder, RETRY_TIERS, RETRY_TOPIC_INFIX,
+};
 pub use runner::{
     run_consumer, ClassifyError, ProcessFuture, ProcessOutcome, RetryPolicy, DLQ_SUFFIX,
 };
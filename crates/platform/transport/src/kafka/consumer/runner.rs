@@ -20,21 +20,32 @@
 //! Committing only after a terminal outcome (success or successful dead-letter) is
 //! what evacuates a poison message from its partition without ever losing it: the
 //! record is durably parked on the dead-letter topic before its offset advances.
+//!
+//! [`run_consumer`] settles one message at a time, so a retry backoff stalls its
+//! whole partition. [`run_consumer_keyed`](super::keyed::run_consumer_keyed) is the
+//! opt-in concurrent mode with the same per-message semantics: keys run in parallel,
+//! each key stays in order, and commits follow the contiguous low watermark.
+//! [`run_consumer_laddered`](super::ladder::run_consumer_laddered) does not retry
+//! in place at all: a transient failure is republished to a delayed
+//! `<topic>.retry.<n>` tier and the partition moves on.
 
 use std::future::Future;
 use std::pin::Pin;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use futures_util::StreamExt;
 use rand::Rng;
 
 use crate::error::TransportError;
 use crate::kafka::consumer::handle::{ConsumedMessage, KafkaConsumerHandle};
+use crate::kafka::consumer::ladder::strip_retry_headers;
+use crate::kafka::consumer::metrics::{consumer_metrics, Settled};
+use crate::kafka::dlq::record::{DlqMetadata, DlqReason};
 use crate::kafka::envelope::ConsumablePayload;
 use crate::kafka::producer::handle::KafkaProducerHandle;
 
 /// Suffix appended to an origin topic to form its dead-letter topic
-/// (e.g. `post.published` → `post.published.dlq`). Per-origin-topic dead-letter
+/// (e.g. `post.v1.events` → `post.v1.events.dlq`). Per-origin-topic dead-letter
 /// topics preserve each stream's schema and make targeted replay trivial.
 /// Public so provisioning tooling (topic-provisioner) derives the exact same
 /// names the runner publishes to — brokers run with auto-creation disabled.
@@ -42,7 +53,7 @@ pub const DLQ_SUFFIX: &str = ".dlq";
 
 /// Maximum length of the human-readable error string copied into the
 /// `x-dlq-error` header, to keep record headers bounded.
-const MAX_DLQ_ERROR_LEN: usize = 1024;
+pub(super) const MAX_DLQ_ERROR_LEN: usize = 1024;
 
 /// Outcome of processing a single message. This is the classification surface a
 /// worker maps its domain errors onto; see [`ProcessOutcome::from_result`].
@@ -151,72 +162,131 @@ where
     T: ConsumablePayload,
     F: for<'a> Fn(&'a T) -> ProcessFuture<'a>,
 {
+    let mut process = process;
     let mut stream = handle.stream::<T>();
 
     while let Some(item) = stream.next().await {
         // A broker/stream-level error carries no offset. Surface it so the caller can
         // rebuild the consumer; nothing is committed.
         let msg = item?;
+        settle(&msg, producer, policy, &mut process).await?;
+
+        // Terminal outcome (success or dead-lettered) → advance the offset.
+        handle.commit(&msg)?;
+    }
+
+    Ok(())
+}
+
+/// Drives one message to a terminal outcome: decode check, `process` with bounded,
+/// jittered retry, and dead-lettering. `Ok` means the offset may be committed; `Err`
+/// is a dead-letter publish failure, after which it must not be.
+///
+/// Shared by [`run_consumer`] and the keyed concurrent mode, so both honour the
+/// same [`RetryPolicy`] and DLQ contract. `process` is taken by `&mut` so the
+/// sequential runner's future stays `Send` for a closure that is not `Sync`.
+pub(super) async fn settle<T, P>(
+    msg:      &ConsumedMessage<T>,
+    producer: &KafkaProducerHandle,
+    policy:   &RetryPolicy,
+    process:  &mut P,
+) -> Result<(), TransportError>
+where
+    P: for<'a> Fn(&'a T) -> ProcessFuture<'a>,
+{
+    let started = Instant::now();
+    let result = drive(msg, producer, policy, process).await;
+    consumer_metrics().settled(&msg.topic, Settled::of(&result), started);
+    result.map(|_| ())
+}
 
-        // Undecodable record → poison. Dead-letter it and commit past it.
-        let event = match &msg.payload {
-            Ok(event) => event,
-            Err(decode_err) => {
-                dead_letter(producer, &msg, "decode", &decode_err.to_string(), 0).await?;
-                handle.commit(&msg)?;
-                continue;
+/// The state machine behind [`settle`], reporting which terminal outcome it reached.
+async fn drive<T, P>(
+    msg:      &ConsumedMessage<T>,
+    producer: &KafkaProducerHandle,
+    policy:   &RetryPolicy,
+    process:  &mut P,
+) -> Result<Settled, TransportError>
+where
+    P: for<'a> Fn(&'a T) -> ProcessFuture<'a>,
+{
+    // Undecodable record → poison. Dead-letter it.
+    let event = match &msg.payload {
+        Ok(event) => event,
+        Err(decode_err) => {
+            return dead_letter(producer, msg, Origin::of(msg), "decode", &decode_err.to_string(), 0)
+                .await
+                .map(|()| Settled::DeadLettered);
+        }
+    };
+
+    let mut attempt: u32 = 1;
+    loop {
+        match process(event).await {
+            ProcessOutcome::Done => return Ok(Settled::Done),
+            ProcessOutcome::Reject(reason) => {
+                return dead_letter(producer, msg, Origin::of(msg), "reject", &reason, attempt)
+                    .await
+                    .map(|()| Settled::DeadLettered);
             }
-        };
-
-        // Process with bounded, jittered retry.
-        let mut attempt: u32 = 1;
-        loop {
-            match process(event).await {
-                ProcessOutcome::Done => break,
-                ProcessOutcome::Reject(reason) => {
-                    dead_letter(producer, &msg, "reject", &reason, attempt).await?;
-                    break;
-                }
-                ProcessOutcome::Retry(reason) => {
-                    if attempt >= policy.max_attempts {
-                        dead_letter(producer, &msg, "retry-exhausted", &reason, attempt)
-                            .await?;
-                        break;
-                    }
-                    let backoff = policy.backoff_for(attempt);
-                    tracing::warn!(
-                        topic     = %msg.topic,
-                        partition = msg.partition,
-                        offset    = msg.offset,
-                        attempt,
-                        backoff_ms = backoff.as_millis() as u64,
-                        reason    = %reason,
-                        "transient processing failure — retrying after backoff"
-                    );
-                    tokio::time::sleep(backoff).await;
-                    attempt += 1;
+            ProcessOutcome::Retry(reason) => {
+                if attempt >= policy.max_attempts {
+                    return dead_letter(producer, msg, Origin::of(msg), "retry-exhausted", &reason, attempt)
+                        .await
+                        .map(|()| Settled::DeadLettered);
                 }
+                let backoff = policy.backoff_for(attempt);
+                tracing::warn!(
+                    topic     = %msg.topic,
+                    partition = msg.partition,
+                    offset    = msg.offset,
+                    key       = %msg.key,
+                    attempt,
+                    backoff_ms = backoff.as_millis() as u64,
+                    reason    = %reason,
+                    "transient processing failure — retrying after backoff"
+                );
+                consumer_metrics().retried(&msg.topic);
+                tokio::time::sleep(backoff).await;
+                attempt += 1;
             }
         }
-
-        // Terminal outcome (success or dead-lettered) → advance the offset.
-        handle.commit(&msg)?;
     }
+}
 
-    Ok(())
+/// Where a record first entered the runtime: its own coordinates, or — for a
+/// record replayed off a retry tier — the origin coordinates the ladder carried.
+/// Dead-letters always land on the origin's `.dlq` under these.
+#[derive(Debug, Clone, Copy)]
+pub(super) struct Origin<'a> {
+    pub topic:     &'a str,
+    pub partition: i32,
+    pub offset:    i64,
+}
+
+impl<'a> Origin<'a> {
+    pub fn of<T>(msg: &'a ConsumedMessage<T>) -> Self {
+        Self { topic: &msg.topic, partition: msg.partition, offset: msg.offset }
+    }
 }
 
 /// Republishes a record verbatim to its origin topic's dead-letter topic, annotated
 /// with diagnostic headers. Returns `Err` if the publish fails, so the caller can
 /// withhold the commit and let the record be redelivered rather than lost.
-async fn dead_letter<T>(
+///
+/// The origin headers travel with the record (the diagnostics overwrite any
+/// stale `x-dlq-*` ones from an earlier replay), so a replay from the DLQ
+/// reaches the consumer with the same `event_id` it first had. Retry-ladder
+/// bookkeeping (`x-retry-*`) is dropped: a replay starts the ladder afresh.
+pub(super) async fn dead_letter<T>(
     producer: &KafkaProducerHandle,
     msg:      &ConsumedMessage<T>,
+    origin:   Origin<'_>,
     reason_kind: &str,
     reason:   &str,
     attempts: u32,
 ) -> Result<(), TransportError> {
-    let dlq_topic = format!("{}{}", msg.topic, DLQ_SUFFIX);
+    let dlq_topic = format!("{}{}", origin.topic, DLQ_SUFFIX);
 
     let truncated: String = reason.chars().take(MAX_DLQ_ERROR_LEN).collect();
     let now_ms = std::time::SystemTime::now()
@@ -224,24 +294,28 @@ async fn dead_letter<T>(
         .map(|d| d.as_millis() as i64)
         .unwrap_or(0);
 
-    let mut headers = std::collections::HashMap::new();
-    headers.insert("x-dlq-origin-topic".to_string(), msg.topic.clone());
-    headers.insert("x-dlq-partition".to_string(),    msg.partition.to_string());
-    headers.insert("x-dlq-offset".to_string(),       msg.offset.to_string());
-    headers.insert("x-dlq-reason".to_string(),       reason_kind.to_string());
-    headers.insert("x-dlq-error".to_string(),        truncated);
-    headers.insert("x-dlq-attempts".to_string(),     attempts.to_string());
-    headers.insert("x-dlq-failed-at-ms".to_string(), now_ms.to_string());
+    let diagnostics = DlqMetadata {
+        origin_topic:     origin.topic.to_owned(),
+        origin_partition: origin.partition,
+        origin_offset:    origin.offset,
+        reason:           DlqReason::parse(reason_kind),
+        error:            truncated,
+        attempts,
+        failed_at_ms:     now_ms,
+    };
+    let mut headers = strip_retry_headers(&msg.headers);
+    headers.extend(diagnostics.to_headers());
 
     producer
-        .publish_raw(&dlq_topic, &msg.key, &msg.raw_payload, headers)
+        .republish(&dlq_topic, msg.key_bytes.as_deref(), &msg.raw_payload, headers)
         .await?;
+    consumer_metrics().dead_lettered(origin.topic, reason_kind);
 
     tracing::error!(
         dlq_topic = %dlq_topic,
-        origin    = %msg.topic,
-        partition = msg.partition,
-        offset    = msg.offset,
+        origin    = %origin.topic,
+        partition = origin.partition,
+        offset    = origin.offset,
         reason    = reason_kind,
         attempts,
         "message dead-lettered"
@@ -0,0 +1,90 @@
+//! The `content-type` record header: which codec a payload was encoded with.
+//!
+//! [`KafkaProducerHandle::publish`](crate::kafka::producer::KafkaProducerHandle::publish)
+//! stamps `application/json`, [`publish_proto`](crate::kafka::producer::KafkaProducerHandle::publish_proto)
+//! stamps `application/x-protobuf`, and the consumer stream decodes by it. A
+//! record without the header predates it and is JSON, so streams migrate to
+//! protobuf without a flag day: consumers learn both encodings first, then the
+//! producer switches.
+
+use std::collections::HashMap;
+
+use crate::error::CodecError;
+
+/// Record header naming the payload encoding.
+pub const CONTENT_TYPE_HEADER: &str = "content-type";
+
+/// A payload encoding the transport can produce and decode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ContentType {
+    /// `serde_json` — the fleet default and the encoding of unlabelled records.
+    #[default]
+    Json,
+    /// `prost` — a `*.v1` protobuf message.
+    Protobuf,
+}
+
+impl ContentType {
+    /// The header value.
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Json     => "application/json",
+            Self::Protobuf => "application/x-protobuf",
+        }
+    }
+
+    /// Parses a header value (parameters such as `; charset=utf-8` are ignored).
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.split(';').next().unwrap_or("").trim() {
+            "application/json"                               => Some(Self::Json),
+            "application/x-protobuf" | "application/protobuf" => Some(Self::Protobuf),
+            _ => None,
+        }
+    }
+
+    /// The encoding of a record with these headers: `Json` when the header is
+    /// absent, an error when it names an encoding the transport cannot decode.
+    pub fn of(headers: &HashMap<String, String>) -> Result<Self, CodecError> {
+        match headers.get(CONTENT_TYPE_HEADER) {
+            None => Ok(Self::Json),
+            Some(value) => {
+                Self::parse(value).ok_or_else(|| CodecError::UnsupportedContentType(value.clone()))
+            }
+        }
+    }
+
+    /// Reads a producer's encoding choice (`json` or `protobuf`) from `var`,
+    /// falling back to `default` when the variable is absent or unrecognised.
+    /// Lets an operator pin a stream back to JSON while its consumers catch up.
+    pub fn from_env(var: &str, default: Self) -> Self {
+        match std::env::var(var).ok().as_deref().map(str::trim) {
+            Some("json")     => Self::Json,
+            Some("protobuf") => Self::Protobuf,
+            _ => default,
+        }
+    }
+}
+
+impl std::fmt::Display for ContentType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unlabelled_records_are_json_and_unknown_labels_are_refused() {
+        assert_eq!(ContentType::of(&HashMap::new()).unwrap(), ContentType::Json);
+
+        let labelled = |v: &str| HashMap::from([(CONTENT_TYPE_HEADER.to_owned(), v.to_owned())]);
+        assert_eq!(ContentType::of(&labelled("application/x-protobuf")).unwrap(), ContentType::Protobuf);
+        assert_eq!(ContentType::of(&labelled("application/json; charset=utf-8")).unwrap(), ContentType::Json);
+        assert!(matches!(
+            ContentType::of(&labelled("application/avro")),
+            Err(CodecError::UnsupportedContentType(_))
+        ));
+    }
+}
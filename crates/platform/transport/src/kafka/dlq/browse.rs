@@ -0,0 +1,189 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use std::time::Duration;
+
+use rdkafka::{
+    consumer::{BaseConsumer, Consumer},
+    message::{Headers, Message},
+    Offset, TopicPartitionList,
+};
+
+use crate::{
+    error::TransportError,
+    kafka::{
+        config::{client::KafkaClientConfig, consumer::ConsumerConfig},
+        dlq::record::{DlqFilter, DlqRecord},
+        error::KafkaTransportError,
+    },
+};
+
+/// Broker round-trip budget for metadata, watermark and poll calls.
+const BROKER_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Reads a dead-letter topic from its start (or from the filter's `since`) up to
+/// the high watermark observed when the scan began.
+///
+/// The browser *assigns* partitions instead of joining a group and never
+/// commits, so inspecting a DLQ leaves no consumer-group state behind and
+/// cannot disturb anyone else reading it. A scan is bounded: records produced
+/// after it started are not returned.
+pub struct DlqBrowser {
+    consumer: Arc<BaseConsumer>,
+}
+
+impl DlqBrowser {
+    pub fn new(client: KafkaClientConfig) -> Result<Self, TransportError> {
+        // librdkafka wants a group id even for a pure assign(); it is never
+        // joined, so any unique value will do.
+        let mut config = ConsumerConfig::new(client, format!("dlq-browser-{}", rand::random::<u64>()))
+            .to_rdkafka();
+        config.set("enable.partition.eof", "false");
+        let consumer: BaseConsumer = config
+            .create()
+            .map_err(|e| TransportError::Kafka(KafkaTransportError::Config(e.to_string())))?;
+        Ok(Self { consumer: Arc::new(consumer) })
+    }
+
+    /// Returns up to `limit` records of `dlq_topic` matching `filter`, ordered by
+    /// partition then offset.
+    pub async fn scan(
+        &self,
+        dlq_topic: &str,
+        filter:    &DlqFilter,
+        limit:     usize,
+    ) -> Result<Vec<DlqRecord>, TransportError> {
+        let consumer = Arc::clone(&self.consumer);
+        let topic = dlq_topic.to_owned();
+        let filter = filter.clone();
+        // rdkafka's metadata, watermark and poll calls block; keep them off the
+        // async workers.
+        tokio::task::spawn_blocking(move || scan_blocking(&consumer, &topic, &filter, limit))
+            .await
+            .map_err(|e| TransportError::Kafka(KafkaTransportError::Config(format!("scan task: {e}"))))?
+    }
+}
+
+fn scan_blocking(
+    consumer: &BaseConsumer,
+    topic:    &str,
+    filter:   &DlqFilter,
+    limit:    usize,
+) -> Result<Vec<DlqRecord>, TransportError> {
+    let consumer_err = |e| TransportError::Kafka(KafkaTransportError::Consumer(e));
+
+    let metadata = consumer.fetch_metadata(Some(topic), BROKER_TIMEOUT).map_err(consumer_err)?;
+    let partitions: Vec<i32> = metadata
+        .topics()
+        .iter()
+        .find(|t| t.name() == topic && t.error().is_none())
+        .map(|t| t.partitions().iter().map(|p| p.id()).collect())
+        .ok_or_else(|| TransportError::Kafka(KafkaTransportError::UnknownTopic(topic.to_owned())))?;
+
+    // Where each partition ends right now, and where to start reading it.
+    let mut end: HashMap<i32, i64> = HashMap::new();
+    let mut start = TopicPartitionList::new();
+    for &partition in &partitions {
+        if filter.position.is_some_and(|(p, _)| p != partition) {
+            continue;
+        }
+        let (low, high) = consumer
+            .fetch_watermarks(topic, partition, BROKER_TIMEOUT)
+            .map_err(consumer_err)?;
+        let past_end = filter.position.is_some_and(|(_, offset)| offset >= high);
+        if high > low && !past_end {
+            end.insert(partition, high);
+            let from = match filter.position {
+                Some((_, offset)) => Offset::Offset(offset.max(low)),
+                None => Offset::Offset(low),
+            };
+            start
+                .add_partition_offset(topic, partition, from)
+                .map_err(|e| TransportError::Kafka(KafkaTransportError::Config(e.to_string())))?;
+        }
+    }
+
+    // Skip what failed before `since`: the record timestamp is the dead-letter
+    // publish time, which is never earlier than the failure it records.
+    if let Some(since) = filter.since_ms.filter(|_| filter.position.is_none()) {
+        let mut query = TopicPartitionList::new();
+        for &partition in end.keys() {
+            query
+                .add_partition_offset(topic, partition, Offset::Offset(since))
+                .map_err(|e| TransportError::Kafka(KafkaTransportError::Config(e.to_string())))?;
+        }
+        for elem in consumer.offsets_for_times(query, BROKER_TIMEOUT).map_err(consumer_err)?.elements() {
+            match elem.offset() {
+                Offset::Offset(offset) => start
+                    .set_partition_offset(topic, elem.partition(), Offset::Offset(offset))
+                    .map_err(|e| TransportError::Kafka(KafkaTransportError::Config(e.to_string())))?,
+                // Nothing at or after `since` on this partition.
+                _ => {
+                    end.remove(&elem.partition());
+                }
+            }
+        }
+    }
+
+    let mut assignment = TopicPartitionList::new();
+    for elem in start.elements().iter().filter(|e| end.contains_key(&e.partition())) {
+        assignment
+            .add_partition_offset(topic, elem.partition(), elem.offset())
+            .map_err(|e| TransportError::Kafka(KafkaTransportError::Config(e.to_string())))?;
+    }
+    consumer.assign(&assignment).map_err(consumer_err)?;
+
+    let mut pending: HashSet<i32> = end.keys().copied().collect();
+    let mut records = Vec::new();
+    while !pending.is_empty() && records.len() < limit {
+        let Some(result) = consumer.poll(BROKER_TIMEOUT) else {
+            // Nothing arrived within a full round-trip budget: the remaining
+            // partitions are behind a compaction gap or a retention cut.
+            break;
+        };
+        let msg = result.map_err(consumer_err)?;
+        let partition = msg.partition();
+        let high = end.get(&partition).copied().unwrap_or(0);
+        if msg.offset() + 1 >= high {
+            pending.remove(&partition);
+        }
+        if msg.offset() >= high {
+            continue;
+        }
+
+        let headers: HashMap<String, String> = msg
+            .headers()
+            .map(|h| {
+                h.iter()
+                    .filter(|header| header.key != "traceparent" && header.key != "tracestate")
+                    .map(|header| {
+                        let value = header
+                            .value
+                            .and_then(|v| std::str::from_utf8(v).ok())
+                            .unwrap_or("")
+                            .to_owned();
+                        (header.key.to_owned(), value)
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        let record = DlqRecord::new(
+            msg.topic().to_owned(),
+            partition,
+            msg.offset(),
+            msg.key().map(<[u8]>::to_vec),
+            headers,
+            msg.payload().map(<[u8]>::to_vec).unwrap_or_default(),
+            msg.timestamp().to_millis(),
+        );
+        if filter.matches(&record) {
+            records.push(record);
+        }
+        if filter.position.is_some() {
+            break;
+        }
+    }
+    consumer.unassign().map_err(consumer_err)?;
+
+    records.sort_by_key(|r| (r.partition, r.offset));
+    Ok(records)
+}
@@ -0,0 +1,142 @@
+use std::collections::{BTreeMap, HashMap};
+
+use crate::{
+    error::TransportError,
+    kafka::{consumer::handle::decode_payload, dlq::record::DlqRecord, envelope::ConsumablePayload},
+};
+
+type DecodeFn = fn(Option<&[u8]>, &HashMap<String, String>) -> Result<(), TransportError>;
+
+fn decode_as<T: ConsumablePayload>(
+    payload: Option<&[u8]>,
+    headers: &HashMap<String, String>,
+) -> Result<(), TransportError> {
+    decode_payload::<T>(payload, headers).map(drop)
+}
+
+/// The payload types the fleet's consumers decode, by origin topic.
+///
+/// A dead-lettered record is decoded against every consumer registered for its
+/// origin topic, through the same [`decode_payload`] the consumer stream uses, so
+/// a dry run answers "would this record get past decode now?" — for instance
+/// after a consumer was fixed to accept a new field — before anything is
+/// republished.
+#[derive(Default)]
+pub struct PayloadDecoders {
+    by_topic: BTreeMap<String, Vec<(String, DecodeFn)>>,
+}
+
+/// One consumer's verdict on a record.
+#[derive(Debug)]
+pub struct DecodeVerdict {
+    pub consumer: String,
+    pub result:   Result<(), TransportError>,
+}
+
+impl PayloadDecoders {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers `consumer`'s payload type `T` for `origin_topic`.
+    pub fn register<T: ConsumablePayload>(mut self, origin_topic: &str, consumer: &str) -> Self {
+        self.by_topic
+            .entry(origin_topic.to_owned())
+            .or_default()
+            .push((consumer.to_owned(), decode_as::<T>));
+        self
+    }
+
+    /// Whether `consumer` has a decoder registered for `origin_topic`.
+    pub fn covers(&self, origin_topic: &str, consumer: &str) -> bool {
+        self.by_topic
+            .get(origin_topic)
+            .is_some_and(|decoders| decoders.iter().any(|(c, _)| c == consumer))
+    }
+
+    /// Decodes `record`'s payload with every consumer of its origin topic. Empty
+    /// when the origin is unknown (no runner headers) or has no decoder.
+    pub fn dry_run(&self, record: &DlqRecord) -> Vec<DecodeVerdict> {
+        let Some(origin) = record.metadata.as_ref().map(|m| m.origin_topic.as_str()) else {
+            return Vec::new();
+        };
+        let payload = (!record.payload.is_empty()).then_some(record.payload.as_slice());
+        self.decode(origin, payload, &record.headers)
+    }
+
+    /// Decodes `payload` with every consumer of `origin_topic`. Empty when the
+    /// topic has no decoder. The registry's payload-schema samples go through
+    /// here too, so they meet the consumers' real decode path.
+    pub fn decode(
+        &self,
+        origin_topic: &str,
+        payload:      Option<&[u8]>,
+        headers:      &HashMap<String, String>,
+    ) -> Vec<DecodeVerdict> {
+        self.by_topic
+            .get(origin_topic)
+            .into_iter()
+            .flatten()
+            .map(|(consumer, decode)| DecodeVerdict { consumer: consumer.clone(), result: decode(payload, headers) })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::Deserialize;
+
+    use super::*;
+    use crate::kafka::dlq::record::{DlqMetadata, DlqReason};
+
+    #[derive(Deserialize)]
+    #[allow(dead_code)]
+    struct Strict {
+        id: u64,
+    }
+
+    #[derive(Deserialize)]
+    #[allow(dead_code)]
+    struct Lenient {
+        id: Option<String>,
+    }
+
+    fn parked(payload: &[u8]) -> DlqRecord {
+        let meta = DlqMetadata {
+            origin_topic:     "t".into(),
+            origin_partition: 0,
+            origin_offset:    0,
+            reason:           DlqReason::Decode,
+            error:            String::new(),
+            attempts:         0,
+            failed_at_ms:     0,
+        };
+        DlqRecord::new("t.dlq".into(), 0, 0, None, meta.to_headers(), payload.to_vec(), None)
+    }
+
+    #[test]
+    fn every_consumer_of_the_origin_topic_gives_a_verdict() {
+        let decoders = PayloadDecoders::new()
+            .register::<Strict>("t", "strict-svc")
+            .register::<Lenient>("t", "lenient-svc")
+            .register::<Strict>("other", "x");
+
+        let verdicts = decoders.dry_run(&parked(br#"{"id":"abc"}"#));
+        let by_consumer: HashMap<_, _> =
+            verdicts.iter().map(|v| (v.consumer.as_str(), v.result.is_ok())).collect();
+        assert_eq!(by_consumer, HashMap::from([("strict-svc", false), ("lenient-svc", true)]));
+
+        assert!(decoders.covers("t", "lenient-svc"));
+        assert!(!decoders.covers("t", "x"));
+    }
+
+    #[test]
+    fn an_empty_payload_fails_like_it_does_in_the_consumer() {
+        let decoders = PayloadDecoders::new().register::<Lenient>("t", "svc");
+        let verdicts = decoders.dry_run(&parked(b""));
+        assert!(matches!(
+            verdicts[0].result,
+            Err(TransportError::Kafka(crate::kafka::error::KafkaTransportError::EmptyPayload))
+        ));
+    }
+}
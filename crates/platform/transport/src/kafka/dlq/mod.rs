@@ -0,0 +1,25 @@
+//! Inspection and replay of the `<topic>.dlq` dead-letter topics written by
+//! [`run_consumer`](crate::kafka::consumer::run_consumer).
+//!
+//! The runner parks a poison record verbatim, under its original key and
+//! headers, with the `x-dlq-*` diagnostics ([`DlqMetadata`]) added. This module
+//! is the other half of that contract:
+//!
+//! - [`DlqBrowser`] reads a DLQ without joining a consumer group, filtered by
+//!   reason, origin partition, failure time or exact position ([`DlqFilter`]);
+//! - [`PayloadDecoders`] dry-runs the origin consumers' payload types against a
+//!   parked record, through the same decode path the consumer uses;
+//! - [`DlqReplayer`] republishes selected records to their origin topic, rate
+//!   limited, with replay markers in place of the diagnostics.
+//!
+//! The `dlq-tool` binary is the operator front end.
+
+pub mod browse;
+pub mod decode;
+pub mod record;
+pub mod replay;
+
+pub use browse::DlqBrowser;
+pub use decode::{DecodeVerdict, PayloadDecoders};
+pub use record::{DlqFilter, DlqMetadata, DlqReason, DlqRecord};
+pub use replay::{DlqReplayer, ReplayConfig, ReplayReport};
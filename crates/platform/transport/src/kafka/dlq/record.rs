@@ -0,0 +1,309 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::kafka::error::KafkaTransportError;
+
+/// Prefix shared by every diagnostic header the runner stamps on a dead-letter
+/// record. Replay strips these so a record that fails again is re-annotated from
+/// scratch rather than carrying stale diagnostics.
+pub const DLQ_HEADER_PREFIX: &str = "x-dlq-";
+
+pub const HEADER_ORIGIN_TOPIC: &str = "x-dlq-origin-topic";
+pub const HEADER_PARTITION: &str    = "x-dlq-partition";
+pub const HEADER_OFFSET: &str       = "x-dlq-offset";
+pub const HEADER_REASON: &str       = "x-dlq-reason";
+pub const HEADER_ERROR: &str        = "x-dlq-error";
+pub const HEADER_ATTEMPTS: &str     = "x-dlq-attempts";
+pub const HEADER_FAILED_AT_MS: &str = "x-dlq-failed-at-ms";
+
+/// How many times a record has been replayed out of a dead-letter topic. Carried
+/// across re-dead-lettering, so a record that keeps failing can be capped.
+pub const HEADER_REPLAY_COUNT: &str     = "x-replay-count";
+/// `<dlq-topic>/<partition>@<offset>` of the dead-letter record a replay came from.
+pub const HEADER_REPLAY_SOURCE: &str    = "x-replay-source";
+pub const HEADER_REPLAYED_AT_MS: &str   = "x-replayed-at-ms";
+
+/// Why the runner parked a record — the `x-dlq-reason` header.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum DlqReason {
+    /// The payload did not decode into the consumer's type.
+    Decode,
+    /// The worker returned [`ProcessOutcome::Reject`](crate::kafka::consumer::ProcessOutcome::Reject).
+    Reject,
+    /// Every attempt of the [`RetryPolicy`](crate::kafka::consumer::RetryPolicy) failed.
+    RetryExhausted,
+    /// A reason this build does not know, kept verbatim.
+    Other(String),
+}
+
+impl DlqReason {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Decode         => "decode",
+            Self::Reject         => "reject",
+            Self::RetryExhausted => "retry-exhausted",
+            Self::Other(s)       => s,
+        }
+    }
+
+    pub fn parse(s: &str) -> Self {
+        match s {
+            "decode"          => Self::Decode,
+            "reject"          => Self::Reject,
+            "retry-exhausted" => Self::RetryExhausted,
+            other             => Self::Other(other.to_owned()),
+        }
+    }
+}
+
+impl fmt::Display for DlqReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// The diagnostics the runner attaches to a dead-lettered record: where it came
+/// from and why it was parked.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DlqMetadata {
+    pub origin_topic:     String,
+    pub origin_partition: i32,
+    pub origin_offset:    i64,
+    pub reason:           DlqReason,
+    /// Human-readable failure, truncated by the runner.
+    pub error:            String,
+    /// Processing attempts made (0 for a decode failure).
+    pub attempts:         u32,
+    pub failed_at_ms:     i64,
+}
+
+impl DlqMetadata {
+    /// Parses the `x-dlq-*` headers. Fails when a coordinate is missing or
+    /// malformed — a record produced to a `.dlq` topic by hand, not by the runner.
+    pub fn from_headers(headers: &HashMap<String, String>) -> Result<Self, KafkaTransportError> {
+        fn get<'h>(h: &'h HashMap<String, String>, k: &str) -> Result<&'h str, KafkaTransportError> {
+            h.get(k)
+                .map(String::as_str)
+                .ok_or_else(|| KafkaTransportError::DeadLetterHeader(format!("missing {k}")))
+        }
+        fn num<N: std::str::FromStr>(h: &HashMap<String, String>, k: &str) -> Result<N, KafkaTransportError> {
+            let raw = get(h, k)?;
+            raw.parse()
+                .map_err(|_| KafkaTransportError::DeadLetterHeader(format!("{k} is not a number: {raw:?}")))
+        }
+
+        Ok(Self {
+            origin_topic:     get(headers, HEADER_ORIGIN_TOPIC)?.to_owned(),
+            origin_partition: num(headers, HEADER_PARTITION)?,
+            origin_offset:    num(headers, HEADER_OFFSET)?,
+            reason:           DlqReason::parse(get(headers, HEADER_REASON)?),
+            error:            headers.get(HEADER_ERROR).cloned().unwrap_or_default(),
+            attempts:         num(headers, HEADER_ATTEMPTS)?,
+            failed_at_ms:     num(headers, HEADER_FAILED_AT_MS)?,
+        })
+    }
+
+    /// Renders the `x-dlq-*` headers, the inverse of [`DlqMetadata::from_headers`].
+    pub fn to_headers(&self) -> HashMap<String, String> {
+        HashMap::from([
+            (HEADER_ORIGIN_TOPIC.to_owned(), self.origin_topic.clone()),
+            (HEADER_PARTITION.to_owned(),    self.origin_partition.to_string()),
+            (HEADER_OFFSET.to_owned(),       self.origin_offset.to_string()),
+            (HEADER_REASON.to_owned(),       self.reason.to_string()),
+            (HEADER_ERROR.to_owned(),        self.error.clone()),
+            (HEADER_ATTEMPTS.to_owned(),     self.attempts.to_string()),
+            (HEADER_FAILED_AT_MS.to_owned(), self.failed_at_ms.to_string()),
+        ])
+    }
+}
+
+/// One record read from a dead-letter topic, with its diagnostics parsed.
+#[derive(Debug, Clone)]
+pub struct DlqRecord {
+    /// The dead-letter topic the record was read from.
+    pub topic:        String,
+    pub partition:    i32,
+    pub offset:       i64,
+    /// The origin record's key as UTF-8 (empty when absent or non-UTF-8), for
+    /// display and filtering.
+    pub key:          String,
+    /// The origin record's key bytes, preserved by the runner for partition
+    /// affinity; replay sends these. `None` for a keyless record.
+    pub key_bytes:    Option<Vec<u8>>,
+    /// Every header on the record (origin headers plus the `x-dlq-*` set),
+    /// excluding the W3C trace-context headers.
+    pub headers:      HashMap<String, String>,
+    /// The origin payload, byte for byte.
+    pub payload:      Vec<u8>,
+    pub timestamp_ms: Option<i64>,
+    /// `None` when the `x-dlq-*` headers are missing or malformed.
+    pub metadata:     Option<DlqMetadata>,
+}
+
+impl DlqRecord {
+    pub fn new(
+        topic:        String,
+        partition:    i32,
+        offset:       i64,
+        key_bytes:    Option<Vec<u8>>,
+        headers:      HashMap<String, String>,
+        payload:      Vec<u8>,
+        timestamp_ms: Option<i64>,
+    ) -> Self {
+        let key = key_bytes
+            .as_deref()
+            .and_then(|k| std::str::from_utf8(k).ok())
+            .unwrap_or("")
+            .to_owned();
+        let metadata = DlqMetadata::from_headers(&headers).ok();
+        Self { topic, partition, offset, key, key_bytes, headers, payload, timestamp_ms, metadata }
+    }
+
+    /// `<dlq-topic>/<partition>@<offset>` — how replays and the tool refer to it.
+    pub fn position(&self) -> String {
+        format!("{}/{}@{}", self.topic, self.partition, self.offset)
+    }
+
+    /// When the record failed: the runner's `x-dlq-failed-at-ms`, else the broker
+    /// timestamp.
+    pub fn failed_at_ms(&self) -> Option<i64> {
+        self.metadata.as_ref().map(|m| m.failed_at_ms).or(self.timestamp_ms)
+    }
+
+    /// Times this record has already been replayed (0 for a first failure).
+    pub fn replay_count(&self) -> u32 {
+        self.headers
+            .get(HEADER_REPLAY_COUNT)
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(0)
+    }
+
+    /// The headers a replay republishes with: the origin headers without the
+    /// `x-dlq-*` diagnostics, plus the replay markers.
+    pub fn replay_headers(&self, now_ms: i64) -> HashMap<String, String> {
+        let mut headers: HashMap<String, String> = self
+            .headers
+            .iter()
+            .filter(|(k, _)| !k.starts_with(DLQ_HEADER_PREFIX))
+            .map(|(k, v)| (k.clone(), v.clone()))
+            .collect();
+        headers.insert(HEADER_REPLAY_COUNT.to_owned(), (self.replay_count() + 1).to_string());
+        headers.insert(HEADER_REPLAY_SOURCE.to_owned(), self.position());
+        headers.insert(HEADER_REPLAYED_AT_MS.to_owned(), now_ms.to_string());
+        headers
+    }
+}
+
+/// Which dead-letter records to return. Every set field must match; an empty
+/// filter matches everything.
+#[derive(Debug, Clone, Default)]
+pub struct DlqFilter {
+    pub reason:           Option<DlqReason>,
+    /// Partition of the *origin* topic the record was consumed from.
+    pub origin_partition: Option<i32>,
+    /// Inclusive lower bound on the failure time (epoch ms).
+    pub since_ms:         Option<i64>,
+    /// Exclusive upper bound on the failure time (epoch ms).
+    pub until_ms:         Option<i64>,
+    /// One exact dead-letter record, as `(partition, offset)`.
+    pub position:         Option<(i32, i64)>,
+}
+
+impl DlqFilter {
+    pub fn matches(&self, record: &DlqRecord) -> bool {
+        if let Some((partition, offset)) = self.position
+            && (record.partition, record.offset) != (partition, offset)
+        {
+            return false;
+        }
+        let meta = record.metadata.as_ref();
+        if let Some(reason) = &self.reason
+            && meta.map(|m| &m.reason) != Some(reason)
+        {
+            return false;
+        }
+        if let Some(partition) = self.origin_partition
+            && meta.map(|m| m.origin_partition) != Some(partition)
+        {
+            return false;
+        }
+        let failed_at = record.failed_at_ms();
+        if let Some(since) = self.since_ms
+            && failed_at.is_none_or(|t| t < since)
+        {
+            return false;
+        }
+        if let Some(until) = self.until_ms
+            && failed_at.is_none_or(|t| t >= until)
+        {
+            return false;
+        }
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn metadata() -> DlqMetadata {
+        DlqMetadata {
+            origin_topic:     "post.v1.events".into(),
+            origin_partition: 3,
+            origin_offset:    42,
+            reason:           DlqReason::RetryExhausted,
+            error:            "scylla timeout".into(),
+            attempts:         5,
+            failed_at_ms:     1_000,
+        }
+    }
+
+    fn record(meta: &DlqMetadata, extra: &[(&str, &str)]) -> DlqRecord {
+        let mut headers = meta.to_headers();
+        headers.extend(extra.iter().map(|(k, v)| (k.to_string(), v.to_string())));
+        DlqRecord::new("post.v1.events.dlq".into(), 1, 7, Some(b"p-1".to_vec()), headers, b"{}".to_vec(), Some(900))
+    }
+
+    #[test]
+    fn metadata_round_trips_through_headers() {
+        let meta = metadata();
+        assert_eq!(DlqMetadata::from_headers(&meta.to_headers()).unwrap(), meta);
+        assert_eq!(DlqReason::parse("something-new"), DlqReason::Other("something-new".into()));
+    }
+
+    #[test]
+    fn a_record_without_runner_headers_has_no_metadata() {
+        let rec = DlqRecord::new("t.dlq".into(), 0, 0, None, HashMap::new(), Vec::new(), Some(5));
+        assert!(rec.metadata.is_none());
+        assert_eq!(rec.failed_at_ms(), Some(5), "falls back to the broker timestamp");
+        assert!(!DlqFilter { reason: Some(DlqReason::Decode), ..Default::default() }.matches(&rec));
+        assert!(DlqFilter::default().matches(&rec));
+    }
+
+    #[test]
+    fn filter_fields_all_have_to_match() {
+        let rec = record(&metadata(), &[]);
+        let by = |f: DlqFilter| f.matches(&rec);
+
+        assert!(by(DlqFilter { reason: Some(DlqReason::RetryExhausted), ..Default::default() }));
+        assert!(!by(DlqFilter { reason: Some(DlqReason::Reject), ..Default::default() }));
+        assert!(by(DlqFilter { origin_partition: Some(3), ..Default::default() }));
+        assert!(!by(DlqFilter { origin_partition: Some(1), ..Default::default() }));
+        assert!(by(DlqFilter { since_ms: Some(1_000), until_ms: Some(1_001), ..Default::default() }));
+        assert!(!by(DlqFilter { until_ms: Some(1_000), ..Default::default() }));
+        assert!(by(DlqFilter { position: Some((1, 7)), ..Default::default() }));
+        assert!(!by(DlqFilter { position: Some((1, 8)), reason: Some(DlqReason::RetryExhausted), ..Default::default() }));
+    }
+
+    #[test]
+    fn replay_headers_drop_diagnostics_and_count_replays() {
+        let rec = record(&metadata(), &[("event_id", "e-1"), (HEADER_REPLAY_COUNT, "2")]);
+        let headers = rec.replay_headers(5_000);
+
+        assert!(headers.keys().all(|k| !k.starts_with(DLQ_HEADER_PREFIX)));
+        assert_eq!(headers["event_id"], "e-1", "origin headers survive for consumer dedup");
+        assert_eq!(headers[HEADER_REPLAY_COUNT], "3");
+        assert_eq!(headers[HEADER_REPLAY_SOURCE], "post.v1.events.dlq/1@7");
+        assert_eq!(headers[HEADER_REPLAYED_AT_MS], "5000");
+    }
+}
@@ -0,0 +1,112 @@
+use std::time::Duration;
+
+use tokio::time::MissedTickBehavior;
+
+use crate::{
+    error::TransportError,
+    kafka::{dlq::record::DlqRecord, producer::handle::KafkaProducerHandle},
+};
+
+/// Replay pacing and the re-poisoning guard.
+#[derive(Debug, Clone)]
+pub struct ReplayConfig {
+    /// Records republished per second. A replay goes straight back into the
+    /// origin consumer's backlog, so it is paced to stay well under the rate
+    /// that consumer can absorb.
+    pub rate_per_sec: u32,
+    /// Records already replayed this many times are skipped: a record that
+    /// keeps coming back needs a code fix, not another replay.
+    pub max_replays:  u32,
+}
+
+impl Default for ReplayConfig {
+    /// 50 records/s, at most 3 replays per record.
+    fn default() -> Self {
+        Self { rate_per_sec: 50, max_replays: 3 }
+    }
+}
+
+/// What a replay run did.
+#[derive(Debug, Default)]
+pub struct ReplayReport {
+    /// Positions (`<dlq>/<partition>@<offset>`) republished to their origin topic.
+    pub replayed: Vec<String>,
+    /// Positions not republished, with the reason.
+    pub skipped:  Vec<(String, String)>,
+    /// The position and error of the publish that stopped the run, if one did.
+    /// Everything listed in `replayed` before it was delivered.
+    pub aborted:  Option<(String, TransportError)>,
+}
+
+/// Republishes dead-letter records to their origin topics.
+///
+/// The record goes back byte for byte, under its original key (so it lands on
+/// the partition it came from) and with its original headers — notably
+/// `event_id`, so an idempotent consumer that *did* partially apply it dedups
+/// the second delivery. The `x-dlq-*` diagnostics are replaced by the replay
+/// markers (see [`DlqRecord::replay_headers`]). Kafka offers no way to remove a
+/// record from a topic, so the DLQ itself is left as is.
+pub struct DlqReplayer {
+    producer: KafkaProducerHandle,
+    config:   ReplayConfig,
+}
+
+impl DlqReplayer {
+    pub fn new(producer: KafkaProducerHandle, config: ReplayConfig) -> Self {
+        Self { producer, config }
+    }
+
+    /// Replays `records` in order, paced to [`ReplayConfig::rate_per_sec`]. Stops
+    /// at the first failed publish, so a broker outage does not silently skip
+    /// half the selection.
+    pub async fn replay(&self, records: &[DlqRecord]) -> ReplayReport {
+        let period = Duration::from_secs(1) / self.config.rate_per_sec.max(1);
+        let mut pace = tokio::time::interval(period);
+        pace.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        let mut report = ReplayReport::default();
+        for record in records {
+            let Some(meta) = &record.metadata else {
+                report.skipped.push((record.position(), "no x-dlq-* headers: origin unknown".into()));
+                continue;
+            };
+            if record.replay_count() >= self.config.max_replays {
+                report.skipped.push((
+                    record.position(),
+                    format!("already replayed {} times", record.replay_count()),
+                ));
+                continue;
+            }
+
+            pace.tick().await;
+            let headers = record.replay_headers(now_ms());
+            match self
+                .producer
+                .republish(&meta.origin_topic, record.key_bytes.as_deref(), &record.payload, headers)
+                .await
+            {
+                Ok(()) => {
+                    tracing::info!(
+                        source = %record.position(),
+                        origin = %meta.origin_topic,
+                        key    = %record.key,
+                        "dead-letter record replayed"
+                    );
+                    report.replayed.push(record.position());
+                }
+                Err(e) => {
+                    report.aborted = Some((record.position(), e));
+                    break;
+                }
+            }
+        }
+        report
+    }
+}
+
+fn now_ms() -> i64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_millis() as i64)
+        .unwrap_or(0)
+}
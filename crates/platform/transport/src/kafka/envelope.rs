@@ -2,6 +2,10 @@ use std::collections::HashMap;
 
 use serde::{de::DeserializeOwned, Serialize};
 
+use crate::codec::{json_decode, proto_decode};
+use crate::error::CodecError;
+use crate::kafka::content_type::ContentType;
+
 /// A typed Kafka message wrapper used by both the producer and the consumer.
 ///
 /// `T` is the domain payload type (must be serializable to send, deserializable to receive).
@@ -75,7 +79,125 @@ impl<T> EventEnvelope<T> {
 pub trait PublishablePayload: Serialize + Send + Sync + 'static {}
 impl<T: Serialize + Send + Sync + 'static> PublishablePayload for T {}
 
-/// Marker trait bound used by [`crate::kafka::consumer::handle::KafkaConsumerHandle`].
-/// Every receivable payload must be deserializable from JSON bytes.
-pub trait ConsumablePayload: DeserializeOwned + Send + Sync + 'static {}
-impl<T: DeserializeOwned + Send + Sync + 'static> ConsumablePayload for T {}
+/// Trait bound used by [`crate::kafka::consumer::handle::KafkaConsumerHandle`]: a
+/// payload decodable from a record of a given [`ContentType`].
+///
+/// Every `DeserializeOwned` type is a JSON payload and refuses protobuf records.
+/// A stream migrating to protobuf is consumed as [`Proto<T>`], which accepts both.
+pub trait ConsumablePayload: Send + Sync + Sized + 'static {
+    fn decode(content_type: ContentType, bytes: &[u8]) -> Result<Self, CodecError>;
+}
+
+impl<T: DeserializeOwned + Send + Sync + 'static> ConsumablePayload for T {
+    fn decode(content_type: ContentType, bytes: &[u8]) -> Result<Self, CodecError> {
+        match content_type {
+            ContentType::Json => json_decode(bytes),
+            other => Err(CodecError::UnsupportedContentType(other.to_string())),
+        }
+    }
+}
+
+/// A payload with a protobuf wire form, for
+/// [`KafkaProducerHandle::publish_proto`](crate::kafka::producer::handle::KafkaProducerHandle::publish_proto).
+pub trait ToProto {
+    /// The `*.v1` message the payload is published as.
+    type Message: prost::Message + Send;
+
+    fn to_proto(&self) -> Self::Message;
+}
+
+/// A payload decodable from a protobuf message — the consumer half of [`ToProto`].
+pub trait FromProto: Sized {
+    type Message: prost::Message + Default;
+
+    /// Fails when the message is well-formed but violates the payload's own
+    /// rules (an unset oneof, an unknown enum value): the record is poison.
+    fn from_proto(message: Self::Message) -> Result<Self, CodecError>;
+}
+
+/// A consumer payload that decodes protobuf records through [`FromProto`] and
+/// JSON records (unlabelled or `application/json`) through serde, so a stream can
+/// switch encodings under a running consumer. Derefs to the payload.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Proto<T>(pub T);
+
+impl<T> Proto<T> {
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> std::ops::Deref for Proto<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> ConsumablePayload for Proto<T>
+where
+    T: FromProto + DeserializeOwned + Send + Sync + 'static,
+{
+    fn decode(content_type: ContentType, bytes: &[u8]) -> Result<Self, CodecError> {
+        match content_type {
+            ContentType::Json => json_decode(bytes).map(Proto),
+            ContentType::Protobuf => T::from_proto(proto_decode::<T::Message>(bytes)?).map(Proto),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::Deserialize;
+
+    use super::*;
+    use crate::codec::proto_encode;
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    struct HitMessage {
+        #[prost(string, tag = "1")]
+        entity_id: String,
+    }
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Hit {
+        entity_id: String,
+    }
+
+    impl FromProto for Hit {
+        type Message = HitMessage;
+
+        fn from_proto(message: HitMessage) -> Result<Self, CodecError> {
+            if message.entity_id.is_empty() {
+                return Err(CodecError::ProtobufMapping("entity_id is empty".into()));
+            }
+            Ok(Self { entity_id: message.entity_id })
+        }
+    }
+
+    #[test]
+    fn a_proto_payload_decodes_both_encodings() {
+        let json = br#"{"entity_id":"p-1"}"#;
+        let proto = proto_encode(&HitMessage { entity_id: "p-1".into() }).unwrap();
+
+        let from_json = Proto::<Hit>::decode(ContentType::Json, json).unwrap();
+        let from_proto = Proto::<Hit>::decode(ContentType::Protobuf, &proto).unwrap();
+        assert_eq!(from_json, from_proto);
+
+        let empty = proto_encode(&HitMessage::default()).unwrap();
+        assert!(matches!(
+            Proto::<Hit>::decode(ContentType::Protobuf, &empty),
+            Err(CodecError::ProtobufMapping(_))
+        ));
+    }
+
+    #[test]
+    fn a_plain_serde_payload_refuses_protobuf() {
+        let proto = proto_encode(&HitMessage { entity_id: "p-1".into() }).unwrap();
+        assert!(matches!(
+            Hit::decode(ContentType::Protobuf, &proto),
+            Err(CodecError::UnsupportedContentType(_))
+        ));
+    }
+}
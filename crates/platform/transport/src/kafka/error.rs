@@ -19,6 +19,12 @@ pub enum KafkaTransportError {
 
     #[error("topic subscription error: {0}")]
     Subscribe(rdkafka::error::KafkaError),
+
+    #[error("malformed dead-letter headers: {0}")]
+    DeadLetterHeader(String),
+
+    #[error("topic {0} does not exist")]
+    UnknownTopic(String),
 }
 
 impl From<rdkafka::error::KafkaError> for KafkaTransportError {
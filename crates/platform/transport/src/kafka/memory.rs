@@ -0,0 +1,423 @@
+//! Deterministic in-process broker behind the [`KafkaProducerHandle`] /
+//! [`KafkaConsumerHandle`] API, for tests that must run without Docker.
+//!
+//! A [`MemoryBroker`] hands out the *same* handle types the rdkafka builders
+//! return, so a worker, [`run_consumer`](super::consumer::run_consumer) and the
+//! dead-letter path run unchanged against it. It models the parts of Kafka the
+//! consumer runtime depends on:
+//!
+//! - **Topics and partitions.** Topics are created explicitly (brokers run with
+//!   auto-creation disabled, so producing to an unknown topic fails with
+//!   [`KafkaTransportError::UnknownTopic`], like a missing `.dlq`). A keyed record
+//!   lands on a stable hash of its key; keyless records rotate across partitions.
+//! - **Consumer groups.** Members of one group split the subscribed partitions
+//!   (partition `p` of a topic goes to its `p % members`-th subscriber, ordered by
+//!   join time) and rebalance when a handle joins or is dropped. Separate groups
+//!   read independently.
+//! - **Committed offsets.** A member starts each newly-assigned partition at the
+//!   group's committed offset (`earliest` when none), so a rebuilt consumer
+//!   resumes exactly where commits left off and redelivers the rest.
+//! - **Headers.** User headers round-trip, and the W3C trace context is injected
+//!   and extracted as on a real broker.
+//!
+//! Everything is in-process and ordered by the calling tasks: with the same
+//! produce order, a test sees the same partitions, offsets and delivery order on
+//! every run. There is no retention, compaction, transaction or lag metric.
+
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use futures::Stream;
+use tokio::sync::Notify;
+
+use crate::{
+    error::TransportError,
+    kafka::{
+        consumer::KafkaConsumerHandle,
+        error::KafkaTransportError,
+        producer::KafkaProducerHandle,
+    },
+};
+
+/// A record as stored on the in-memory broker.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MemoryRecord {
+    pub topic: String,
+    pub partition: i32,
+    pub offset: i64,
+    pub key: String,
+    /// Every header, the trace context included.
+    pub headers: HashMap<String, String>,
+    pub payload: Vec<u8>,
+    pub timestamp_ms: i64,
+}
+
+/// A cheaply cloneable in-process broker. Clones share the same topics, groups
+/// and offsets.
+#[derive(Clone, Default)]
+pub struct MemoryBroker {
+    inner: Arc<Inner>,
+}
+
+#[derive(Default)]
+struct Inner {
+    state: Mutex<State>,
+    /// Woken on every produce and rebalance, so idle consumers re-poll.
+    changed: Notify,
+}
+
+#[derive(Default)]
+struct State {
+    topics: BTreeMap<String, Topic>,
+    groups: HashMap<String, Group>,
+    next_member: u64,
+}
+
+struct Topic {
+    partitions: Vec<Vec<MemoryRecord>>,
+    next_keyless: usize,
+}
+
+#[derive(Default)]
+struct Group {
+    committed: HashMap<(String, i32), i64>,
+    /// Keyed by join order, which fixes the partition assignment.
+    members: BTreeMap<u64, Member>,
+}
+
+/// One group member's connection to the broker; leaves the group when dropped.
+pub(crate) struct MemoryConsumer {
+    broker: MemoryBroker,
+    group_id: String,
+    member: u64,
+}
+
+struct Member {
+    topics: Vec<String>,
+    positions: HashMap<(String, i32), i64>,
+    /// Where the next round-robin scan across assigned partitions starts.
+    cursor: usize,
+}
+
+impl MemoryBroker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates `topic` with `partitions` partitions. Re-creating an existing topic
+    /// is a no-op, like a provisioner's `TopicAlreadyExists`.
+    pub fn create_topic(&self, topic: &str, partitions: i32) {
+        let partitions = usize::try_from(partitions.max(1)).unwrap_or(1);
+        self.lock().topics.entry(topic.to_owned()).or_insert_with(|| Topic {
+            partitions: vec![Vec::new(); partitions],
+            next_keyless: 0,
+        });
+        self.inner.changed.notify_waiters();
+    }
+
+    /// A producer handle publishing onto this broker.
+    pub fn producer(&self) -> KafkaProducerHandle {
+        KafkaProducerHandle::in_memory(self.clone())
+    }
+
+    /// A consumer handle joining `group_id`, subscribed to `topics`. Topics created
+    /// later are picked up on the next poll.
+    pub fn consumer(
+        &self,
+        group_id: impl Into<String>,
+        topics: impl IntoIterator<Item = impl Into<String>>,
+    ) -> KafkaConsumerHandle {
+        let group_id = group_id.into();
+        let topics: Vec<String> = topics.into_iter().map(Into::into).collect();
+
+        let member = {
+            let mut state = self.lock();
+            let member = state.next_member;
+            state.next_member += 1;
+            state.groups.entry(group_id.clone()).or_default().members.insert(
+                member,
+                Member { topics, positions: HashMap::new(), cursor: 0 },
+            );
+            member
+        };
+        self.inner.changed.notify_waiters();
+
+        KafkaConsumerHandle::in_memory(
+            MemoryConsumer { broker: self.clone(), group_id: group_id.clone(), member },
+            group_id,
+        )
+    }
+
+    /// Every record on `topic`, ordered by partition then offset. Empty for an
+    /// unknown topic.
+    pub fn records(&self, topic: &str) -> Vec<MemoryRecord> {
+        self.lock()
+            .topics
+            .get(topic)
+            .map(|t| t.partitions.iter().flatten().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    /// The next offset `group_id` will read on `topic`/`partition`, once committed.
+    pub fn committed(&self, group_id: &str, topic: &str, partition: i32) -> Option<i64> {
+        self.lock()
+            .groups
+            .get(group_id)
+            .and_then(|g| g.committed.get(&(topic.to_owned(), partition)).copied())
+    }
+
+    pub(crate) fn produce(
+        &self,
+        topic: &str,
+        key: &str,
+        payload: &[u8],
+        headers: HashMap<String, String>,
+        timestamp_ms: Option<i64>,
+    ) -> Result<(), TransportError> {
+        {
+            let mut state = self.lock();
+            let t = state.topics.get_mut(topic).ok_or_else(|| {
+                TransportError::Kafka(KafkaTransportError::UnknownTopic(topic.to_owned()))
+            })?;
+
+            let count = t.partitions.len();
+            let index = if key.is_empty() {
+                let index = t.next_keyless % count;
+                t.next_keyless += 1;
+                index
+            } else {
+                (key_hash(key) % count as u64) as usize
+            };
+            let log = &mut t.partitions[index];
+            log.push(MemoryRecord {
+                topic: topic.to_owned(),
+                partition: index as i32,
+                offset: log.len() as i64,
+                key: key.to_owned(),
+                headers,
+                payload: payload.to_vec(),
+                timestamp_ms: timestamp_ms.unwrap_or_else(now_ms),
+            });
+        }
+        self.inner.changed.notify_waiters();
+        Ok(())
+    }
+
+    pub(crate) fn commit(&self, group_id: &str, topic: &str, partition: i32, next_offset: i64) {
+        self.lock()
+            .groups
+            .entry(group_id.to_owned())
+            .or_default()
+            .committed
+            .insert((topic.to_owned(), partition), next_offset);
+    }
+
+    /// Hands `member` its next record, after re-deriving its assignment.
+    fn poll(&self, group_id: &str, member: u64) -> Option<MemoryRecord> {
+        let mut state = self.lock();
+        let State { topics, groups, .. } = &mut *state;
+        let group = groups.get_mut(group_id)?;
+
+        let assigned = assignment(topics, &group.members, member);
+        let Group { committed, members } = group;
+        let me = members.get_mut(&member)?;
+
+        // Revoked partitions are forgotten; newly-assigned ones resume from the
+        // group's committed offset, so another member's uncommitted tail is redelivered.
+        me.positions.retain(|tp, _| assigned.contains(tp));
+        for tp in &assigned {
+            me.positions
+                .entry(tp.clone())
+                .or_insert_with(|| committed.get(tp).copied().unwrap_or(0));
+        }
+
+        let n = assigned.len();
+        for step in 0..n {
+            let index = (me.cursor + step) % n;
+            let tp = &assigned[index];
+            let position = me.positions[tp];
+            let log = &topics[&tp.0].partitions[tp.1 as usize];
+            if let Some(record) = log.get(position as usize) {
+                me.positions.insert(tp.clone(), position + 1);
+                me.cursor = index + 1;
+                return Some(record.clone());
+            }
+        }
+        None
+    }
+
+    fn leave(&self, group_id: &str, member: u64) {
+        if let Some(group) = self.lock().groups.get_mut(group_id) {
+            group.members.remove(&member);
+        }
+        self.inner.changed.notify_waiters();
+    }
+
+    fn lock(&self) -> MutexGuard<'_, State> {
+        self.inner.state.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl MemoryConsumer {
+    /// Records for this member, waiting for new ones when none is available.
+    pub(crate) fn stream(&self) -> impl Stream<Item = MemoryRecord> + Send + '_ {
+        futures::stream::unfold(self, |consumer| async move {
+            loop {
+                // Registered before polling, so a produce landing in between still wakes us.
+                let mut changed = std::pin::pin!(consumer.broker.inner.changed.notified());
+                changed.as_mut().enable();
+
+                if let Some(record) = consumer.broker.poll(&consumer.group_id, consumer.member) {
+                    return Some((record, consumer));
+                }
+                changed.await;
+            }
+        })
+    }
+
+    pub(crate) fn commit(&self, topic: &str, partition: i32, next_offset: i64) {
+        self.broker.commit(&self.group_id, topic, partition, next_offset);
+    }
+}
+
+impl Drop for MemoryConsumer {
+    fn drop(&mut self) {
+        self.broker.leave(&self.group_id, self.member);
+    }
+}
+
+/// The `(topic, partition)`s `member` owns: partition `p` of each topic goes to the
+/// topic's `p % n`-th subscriber in join order.
+fn assignment(
+    topics: &BTreeMap<String, Topic>,
+    members: &BTreeMap<u64, Member>,
+    member: u64,
+) -> Vec<(String, i32)> {
+    let mut assigned = Vec::new();
+    for (name, topic) in topics {
+        let subscribers: Vec<u64> = members
+            .iter()
+            .filter(|(_, m)| m.topics.contains(name))
+            .map(|(id, _)| *id)
+            .collect();
+        let Some(rank) = subscribers.iter().position(|id| *id == member) else {
+            continue;
+        };
+        for partition in 0..topic.partitions.len() {
+            if partition % subscribers.len() == rank {
+                assigned.push((name.clone(), partition as i32));
+            }
+        }
+    }
+    assigned
+}
+
+/// Stable key → partition hash (64-bit FNV-1a). Not Kafka's murmur2: a record's
+/// partition is deterministic here, not identical to a real cluster's.
+fn key_hash(key: &str) -> u64 {
+    key.bytes().fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0000_0100_0000_01b3)
+    })
+}
+
+fn now_ms() -> i64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_millis() as i64)
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::StreamExt;
+    use serde::{Deserialize, Serialize};
+
+    use super::*;
+    use crate::kafka::EventEnvelope;
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Event {
+        id: u32,
+    }
+
+    fn envelope(topic: &str, key: &str, id: u32) -> EventEnvelope<Event> {
+        EventEnvelope::new(topic, key, Event { id }).with_header("h", id.to_string())
+    }
+
+    #[tokio::test]
+    async fn keys_pin_partitions_and_headers_round_trip() {
+        let broker = MemoryBroker::new();
+        broker.create_topic("t", 4);
+        let producer = broker.producer();
+        for id in 0..8 {
+            producer.publish(envelope("t", "same-key", id)).await.unwrap();
+        }
+
+        let records = broker.records("t");
+        assert_eq!(records.len(), 8);
+        assert!(records.iter().all(|r| r.partition == records[0].partition));
+        assert_eq!(records.iter().map(|r| r.offset).collect::<Vec<_>>(), (0..8).collect::<Vec<_>>());
+
+        let consumer = broker.consumer("g", ["t"]);
+        let msg = consumer.stream::<Event>().next().await.unwrap().unwrap();
+        assert_eq!(msg.payload.unwrap(), Event { id: 0 });
+        assert_eq!(msg.headers.get("h").map(String::as_str), Some("0"));
+        assert!(!msg.headers.contains_key("traceparent"));
+    }
+
+    #[tokio::test]
+    async fn unknown_topics_are_rejected() {
+        let broker = MemoryBroker::new();
+        let err = broker.producer().publish(envelope("missing", "k", 1)).await.unwrap_err();
+        assert!(matches!(err, TransportError::Kafka(KafkaTransportError::UnknownTopic(t)) if t == "missing"));
+    }
+
+    #[tokio::test]
+    async fn a_rebuilt_consumer_resumes_from_the_committed_offset() {
+        let broker = MemoryBroker::new();
+        broker.create_topic("t", 1);
+        let producer = broker.producer();
+        for id in 0..3 {
+            producer.publish(envelope("t", "k", id)).await.unwrap();
+        }
+
+        {
+            let consumer = broker.consumer("g", ["t"]);
+            let mut stream = consumer.stream::<Event>();
+            let first = stream.next().await.unwrap().unwrap();
+            consumer.commit(&first).unwrap();
+            let _uncommitted = stream.next().await.unwrap().unwrap();
+        }
+        assert_eq!(broker.committed("g", "t", 0), Some(1));
+
+        let consumer = broker.consumer("g", ["t"]);
+        let redelivered = consumer.stream::<Event>().next().await.unwrap().unwrap();
+        assert_eq!(redelivered.offset, 1, "the uncommitted record is redelivered");
+
+        let other_group = broker.consumer("other", ["t"]);
+        assert_eq!(other_group.stream::<Event>().next().await.unwrap().unwrap().offset, 0);
+    }
+
+    #[tokio::test]
+    async fn group_members_split_partitions_and_rebalance_on_leave() {
+        let broker = MemoryBroker::new();
+        broker.create_topic("t", 2);
+        let a = broker.consumer("g", ["t"]);
+        let b = broker.consumer("g", ["t"]);
+        let producer = broker.producer();
+        for id in 0..2 {
+            // Keyless records rotate, one per partition.
+            producer.publish_raw("t", "", &serde_json::to_vec(&Event { id }).unwrap(), HashMap::new())
+                .await
+                .unwrap();
+        }
+
+        let from_a = a.stream::<Event>().next().await.unwrap().unwrap();
+        let from_b = b.stream::<Event>().next().await.unwrap().unwrap();
+        assert_eq!((from_a.partition, from_b.partition), (0, 1));
+
+        drop(b);
+        let taken_over = a.stream::<Event>().next().await.unwrap().unwrap();
+        assert_eq!((taken_over.partition, taken_over.offset), (1, 0), "b never committed");
+    }
+}
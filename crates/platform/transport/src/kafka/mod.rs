@@ -1,9 +1,14 @@
 pub mod config;
 pub mod consumer;
+pub mod content_type;
+pub mod dlq;
 pub mod envelope;
 pub mod error;
+#[cfg(feature = "memory-broker")]
+pub mod memory;
 pub mod producer;
 
-pub use consumer::DLQ_SUFFIX;
-pub use envelope::EventEnvelope;
+pub use consumer::{DLQ_SUFFIX, RETRY_TIERS, RETRY_TOPIC_INFIX};
+pub use content_type::{ContentType, CONTENT_TYPE_HEADER};
+pub use envelope::{EventEnvelope, FromProto, Proto, ToProto};
 pub use error::KafkaTransportError;
@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use rdkafka::{
     message::OwnedHeaders,
     producer::{FutureProducer, FutureRecord},
@@ -5,9 +7,11 @@ use rdkafka::{
 };
 
 use crate::{
+    codec::proto_encode,
     error::{CodecError, TransportError},
     kafka::{
-        envelope::{EventEnvelope, PublishablePayload},
+        content_type::{ContentType, CONTENT_TYPE_HEADER},
+        envelope::{EventEnvelope, PublishablePayload, ToProto},
         error::KafkaTransportError,
     },
     propagation::{
@@ -16,10 +20,14 @@ use crate::{
     },
 };
 
+#[cfg(feature = "memory-broker")]
+use crate::kafka::memory::MemoryBroker;
+
 /// A cheaply cloneable handle to a Kafka producer.
 ///
 /// Holds an `Arc`-backed [`FutureProducer`] so handles can be shared across Tokio tasks
-/// without additional synchronisation overhead.
+/// without additional synchronisation overhead. Tests built with the `memory-broker`
+/// feature can get the same handle from an in-process `MemoryBroker` instead.
 ///
 /// # Trace context propagation
 ///
@@ -31,16 +39,30 @@ use crate::{
 /// [`publish`]: KafkaProducerHandle::publish
 #[derive(Clone)]
 pub struct KafkaProducerHandle {
-    producer: FutureProducer,
+    backend: Backend,
+}
+
+#[derive(Clone)]
+enum Backend {
+    Kafka(FutureProducer),
+    #[cfg(feature = "memory-broker")]
+    Memory(MemoryBroker),
 }
 
 impl KafkaProducerHandle {
     pub(crate) fn new(producer: FutureProducer) -> Self {
-        Self { producer }
+        Self { backend: Backend::Kafka(producer) }
+    }
+
+    /// A handle producing onto an in-process [`MemoryBroker`].
+    #[cfg(feature = "memory-broker")]
+    pub(crate) fn in_memory(broker: MemoryBroker) -> Self {
+        Self { backend: Backend::Memory(broker) }
     }
 
     /// Serialises `envelope.payload` to JSON and publishes the record to Kafka,
-    /// injecting the current span's trace context into the record's headers.
+    /// labelled `content-type: application/json`, injecting the current span's
+    /// trace context into the record's headers.
     ///
     /// # Delivery guarantee
     ///
@@ -61,58 +83,117 @@ impl KafkaProducerHandle {
         let payload_bytes =
             serde_json::to_vec(&payload).map_err(|e| TransportError::Codec(CodecError::Json(e)))?;
 
-        let headers = build_headers_with_trace(user_headers);
+        self.send(&topic, &key, &payload_bytes, ContentType::Json, user_headers, timestamp_ms)
+            .await
+    }
 
-        let mut record = FutureRecord::to(&topic)
-            .key(&key)
-            .payload(&payload_bytes)
-            .headers(headers);
+    /// [`publish`](Self::publish) with the payload's protobuf form
+    /// ([`ToProto`]), labelled `content-type: application/x-protobuf`. Consumers
+    /// read such a stream as [`Proto<T>`](crate::kafka::envelope::Proto), which
+    /// still accepts the JSON records published before the switch.
+    pub async fn publish_proto<T: ToProto + Send + Sync + 'static>(
+        &self,
+        envelope: EventEnvelope<T>,
+    ) -> Result<(), TransportError> {
+        let (topic, key, payload, user_headers, timestamp_ms) = envelope.into_parts();
 
-        if let Some(ts) = timestamp_ms {
-            record = record.timestamp(ts);
-        }
+        let payload_bytes = proto_encode(&payload.to_proto())?;
 
-        self.producer
-            .send(record, Timeout::Never)
+        self.send(&topic, &key, &payload_bytes, ContentType::Protobuf, user_headers, timestamp_ms)
             .await
-            .map_err(|(e, _msg)| TransportError::Kafka(KafkaTransportError::Producer(e)))?;
+    }
 
-        tracing::debug!(topic = %topic, key = %key, "Kafka message published");
+    async fn send(
+        &self,
+        topic: &str,
+        key: &str,
+        payload: &[u8],
+        content_type: ContentType,
+        mut user_headers: HashMap<String, String>,
+        timestamp_ms: Option<i64>,
+    ) -> Result<(), TransportError> {
+        user_headers.insert(CONTENT_TYPE_HEADER.to_owned(), content_type.as_str().to_owned());
+        self.produce(topic, Some(key.as_bytes()), payload, user_headers, timestamp_ms).await?;
+
+        tracing::debug!(topic = %topic, key = %key, content_type = %content_type, "Kafka message published");
 
         Ok(())
     }
 
-    /// Publishes a raw byte payload, bypassing JSON serialisation.
+    /// Publishes a raw byte payload, bypassing serialisation.
     ///
-    /// Trace context is still injected automatically.
+    /// Trace context is still injected automatically. The caller's headers are
+    /// sent as given, so a republished record keeps its original `content-type`.
     pub async fn publish_raw(
         &self,
         topic: &str,
         key: &str,
         payload: &[u8],
-        user_headers: std::collections::HashMap<String, String>,
+        user_headers: HashMap<String, String>,
     ) -> Result<(), TransportError> {
-        let headers = build_headers_with_trace(user_headers);
+        self.produce(topic, Some(key.as_bytes()), payload, user_headers, None).await
+    }
 
-        self.producer
-            .send(
-                FutureRecord::to(topic)
-                    .key(key)
-                    .payload(payload)
-                    .headers(headers),
-                Timeout::Never,
-            )
-            .await
-            .map_err(|(e, _msg)| TransportError::Kafka(KafkaTransportError::Producer(e)))?;
+    /// Republishes a consumed record's bytes under its original key bytes —
+    /// `None` for a keyless record — so a key that is not UTF-8 still lands on
+    /// the partition it hashes to. The retry ladder, the dead-letter path and
+    /// DLQ replay go through here.
+    pub async fn republish(
+        &self,
+        topic: &str,
+        key: Option<&[u8]>,
+        payload: &[u8],
+        user_headers: HashMap<String, String>,
+    ) -> Result<(), TransportError> {
+        self.produce(topic, key, payload, user_headers, None).await
+    }
 
-        Ok(())
+    /// Hands one record, trace context injected, to the backend.
+    async fn produce(
+        &self,
+        topic: &str,
+        key: Option<&[u8]>,
+        payload: &[u8],
+        user_headers: HashMap<String, String>,
+        timestamp_ms: Option<i64>,
+    ) -> Result<(), TransportError> {
+        match &self.backend {
+            Backend::Kafka(producer) => {
+                let headers = build_headers_with_trace(user_headers);
+
+                let mut record = FutureRecord::<[u8], [u8]>::to(topic)
+                    .payload(payload)
+                    .headers(headers);
+                if let Some(key) = key {
+                    record = record.key(key);
+                }
+
+                if let Some(ts) = timestamp_ms {
+                    record = record.timestamp(ts);
+                }
+
+                producer
+                    .send(record, Timeout::Never)
+                    .await
+                    .map_err(|(e, _msg)| TransportError::Kafka(KafkaTransportError::Producer(e)))?;
+
+                Ok(())
+            }
+            #[cfg(feature = "memory-broker")]
+            Backend::Memory(broker) => {
+                let mut headers = user_headers;
+                inject_context(&mut headers);
+                let key = key.map(String::from_utf8_lossy).unwrap_or_default();
+                broker.produce(topic, &key, payload, headers, timestamp_ms)
+            }
+        }
     }
 }
 
 /// Builds an [`OwnedHeaders`] instance from user-defined key-value pairs, then
 /// injects the current span's W3C trace context as additional headers.
 fn build_headers_with_trace(
-    user_headers: std::collections::HashMap<String, String>,
+    user_headers: HashMap<String, String>,
 ) -> OwnedHeaders {
     use opentelemetry::propagation::Injector;
 
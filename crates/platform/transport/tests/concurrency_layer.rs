@@ -0,0 +1,146 @@
+//! gRPC adaptive concurrency layer: sheds past the limit with UNAVAILABLE in priority order,
+//! admits in shadow mode, backs off on a struggling handler. Drives `ConcurrencyService` as
+//! a plain tower service (no live server needed).
+
+use std::convert::Infallible;
+use std::sync::Arc;
+
+use infra_config::{InfrastructureConfig, TrafficRegistry};
+use tokio::sync::Semaphore;
+use tonic::body::Body;
+use tower::{Layer, ServiceExt};
+use transport::grpc::layer::ConcurrencyLayer;
+
+const TOML: &str = r#"
+[resilience]
+default_profile = "standard"
+[resilience.profiles.standard]
+timeout = { duration_ms = 10000 }
+circuit_breaker = { failure_threshold = 5, success_threshold = 2, open_duration_ms = 30000, half_open_max_calls = 1 }
+retry = { max_attempts = 3, backoff = { kind = "exponential", base_ms = 50, max_ms = 10000, jitter = "full" } }
+
+[traffic]
+default_profile = "standard"
+[traffic.profiles.standard]
+rps = 1000
+burst = 1000
+
+[traffic.concurrency]
+initial_limit = 2
+min_limit = 1
+max_limit = 3
+[traffic.concurrency.priorities]
+"/svc/Feed" = "sheddable"
+"#;
+
+fn registry(toml: &str) -> Arc<TrafficRegistry> {
+    let cfg = InfrastructureConfig::from_toml(toml).unwrap();
+    Arc::new(TrafficRegistry::from_section(cfg.traffic.unwrap()).unwrap())
+}
+
+/// The inner service stand-in: `/svc/Slow` waits for a `gate` permit, `/svc/Failing`
+/// answers UNAVAILABLE, everything else answers at once with no gRPC error status.
+macro_rules! gated_service {
+    ($gate:expr) => {{
+        let gate: Arc<Semaphore> = $gate;
+        tower::service_fn(move |req: http::Request<Body>| {
+            let gate = Arc::clone(&gate);
+            async move {
+                match req.uri().path() {
+                    "/svc/Slow" => drop(gate.acquire().await.unwrap()),
+                    "/svc/Failing" => {
+                        return Ok::<_, Infallible>(
+                            tonic::Status::unavailable("scylla down").into_http(),
+                        );
+                    }
+                    _ => {}
+                }
+                Ok(http::Response::new(Body::empty()))
+            }
+        })
+    }};
+}
+
+fn req(path: &str) -> http::Request<Body> {
+    http::Request::builder().uri(path).body(Body::empty()).unwrap()
+}
+
+fn grpc_status(resp: &http::Response<Body>) -> Option<&str> {
+    resp.headers().get("grpc-status").and_then(|v| v.to_str().ok())
+}
+
+/// Waits until `n` requests hold a permit.
+async fn until_in_flight(registry: &TrafficRegistry, n: u32) {
+    let limiter = registry.concurrency().unwrap();
+    while limiter.in_flight() < n {
+        tokio::task::yield_now().await;
+    }
+}
+
+#[tokio::test]
+async fn sheds_past_the_limit_in_priority_order() {
+    let registry = registry(TOML);
+    let gate = Arc::new(Semaphore::new(0));
+    let svc = ConcurrencyLayer::new(Arc::clone(&registry)).layer(gated_service!(gate.clone()));
+
+    let held: Vec<_> =
+        (0..2).map(|_| tokio::spawn(svc.clone().oneshot(req("/svc/Slow")))).collect();
+    until_in_flight(&registry, 2).await;
+
+    let normal = svc.clone().oneshot(req("/svc/M")).await.unwrap();
+    assert_eq!(grpc_status(&normal), Some("14"), "UNAVAILABLE at the limit");
+    let sheddable = svc.clone().oneshot(req("/svc/Feed")).await.unwrap();
+    assert_eq!(grpc_status(&sheddable), Some("14"));
+    let health = svc.clone().oneshot(req("/grpc.health.v1.Health/Check")).await.unwrap();
+    assert_eq!(grpc_status(&health), None, "health checks are critical — admitted up to max_limit");
+
+    gate.add_permits(2);
+    for call in held {
+        assert_eq!(grpc_status(&call.await.unwrap().unwrap()), None);
+    }
+    let normal = svc.clone().oneshot(req("/svc/M")).await.unwrap();
+    assert_eq!(grpc_status(&normal), None, "slots freed once the slow calls finish");
+}
+
+#[tokio::test]
+async fn shadow_mode_admits_what_it_would_shed() {
+    let registry = registry(&TOML.replace("max_limit = 3", "max_limit = 3\nenforce = false"));
+    let gate = Arc::new(Semaphore::new(0));
+    let svc = ConcurrencyLayer::new(Arc::clone(&registry)).layer(gated_service!(gate.clone()));
+
+    let held: Vec<_> =
+        (0..2).map(|_| tokio::spawn(svc.clone().oneshot(req("/svc/Slow")))).collect();
+    until_in_flight(&registry, 2).await;
+
+    let resp = svc.clone().oneshot(req("/svc/M")).await.unwrap();
+    assert_eq!(grpc_status(&resp), None, "shadow mode never sheds");
+
+    gate.add_permits(2);
+    for call in held {
+        call.await.unwrap().unwrap();
+    }
+}
+
+#[tokio::test]
+async fn unavailable_handlers_back_the_limit_off() {
+    let registry = registry(TOML);
+    let svc = ConcurrencyLayer::new(Arc::clone(&registry))
+        .layer(gated_service!(Arc::new(Semaphore::new(0))));
+
+    let resp = svc.clone().oneshot(req("/svc/Failing")).await.unwrap();
+    assert_eq!(grpc_status(&resp), Some("14"));
+    assert_eq!(registry.concurrency().unwrap().limit(), 1, "2 × 0.9, floored");
+}
+
+#[tokio::test]
+async fn passes_through_without_a_concurrency_block() {
+    let toml = TOML.split("[traffic.concurrency]").next().unwrap();
+    let gate = Arc::new(Semaphore::new(0));
+    for layer in [ConcurrencyLayer::new(registry(toml)), ConcurrencyLayer::disabled()] {
+        let svc = layer.layer(gated_service!(gate.clone()));
+        for _ in 0..5 {
+            let resp = svc.clone().oneshot(req("/svc/M")).await.unwrap();
+            assert_eq!(grpc_status(&resp), None);
+        }
+    }
+}
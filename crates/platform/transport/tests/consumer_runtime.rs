@@ -1,4 +1,6 @@
-//! Live-broker integration suite for the generic `run_consumer` runtime (Scenarios A–K).
+//! Live-broker integration suite for the generic `run_consumer` runtime (Scenarios A–K)
+//! its keyed concurrent mode, `run_consumer_keyed` (Scenarios L–M), and the retry ladder,
+//! `run_consumer_laddered` (Scenarios N–O).
 //!
 //! Each test mints an isolated [`TestContext`] (unique topic / `.dlq` / group), drives the
 //! runner against the shared single-node broker, and asserts on durable broker state —
@@ -27,7 +29,8 @@ use tokio::task::JoinHandle;
 use transport::error::TransportError;
 use transport::kafka::EventEnvelope;
 use transport::kafka::consumer::{
-    ConsumedMessage, KafkaConsumerHandle, ProcessFuture, ProcessOutcome, RetryPolicy, run_consumer,
+    ConsumedMessage, KafkaConsumerHandle, KeyedConcurrency, ProcessFuture, ProcessOutcome,
+    RetryLadder, RetryPolicy, run_consumer, run_consumer_keyed, run_consumer_laddered,
 };
 use transport::kafka::envelope::ConsumablePayload;
 use transport::kafka::producer::KafkaProducerHandle;
@@ -79,6 +82,63 @@ where
     tokio::spawn(async move { run_consumer::<T, F>(&consumer, &producer, &policy, process).await })
 }
 
+/// [`spawn_runner`] for the keyed concurrent mode. Its lanes share `process`, so the
+/// closure must also be `Sync`.
+fn spawn_keyed_runner<T, F>(
+    consumer: KafkaConsumerHandle,
+    producer: KafkaProducerHandle,
+    policy: RetryPolicy,
+    process: F,
+) -> JoinHandle<Result<(), TransportError>>
+where
+    T: ConsumablePayload,
+    F: for<'a> Fn(&'a T) -> ProcessFuture<'a> + Send + Sync + 'static,
+{
+    tokio::spawn(async move {
+        let concurrency = KeyedConcurrency { max_in_flight: 16 };
+        run_consumer_keyed::<T, F>(&consumer, &producer, &policy, &concurrency, process).await
+    })
+}
+
+/// Spawns `run_consumer_laddered` on `consumer` — the origin topic or one retry tier.
+fn spawn_laddered_runner<T, F>(
+    consumer: KafkaConsumerHandle,
+    producer: KafkaProducerHandle,
+    ladder: RetryLadder,
+    process: F,
+) -> JoinHandle<Result<(), TransportError>>
+where
+    T: ConsumablePayload,
+    F: for<'a> Fn(&'a T) -> ProcessFuture<'a> + Send + 'static,
+{
+    tokio::spawn(async move { run_consumer_laddered::<T, F>(&consumer, &producer, &ladder, process).await })
+}
+
+/// A ladder processor shared by the origin and tier runners: event 0 asks for a retry on
+/// its first `failures` attempts (every attempt is counted in `tries`); any other event
+/// succeeds and is counted in `done`.
+fn flaky_first_event(
+    failures: usize,
+    tries: Arc<AtomicUsize>,
+    done: Arc<AtomicUsize>,
+) -> impl for<'a> Fn(&'a TestEvent) -> ProcessFuture<'a> + Send + 'static {
+    classify::<TestEvent, _>(move |e: &TestEvent| -> ProcessFuture<'_> {
+        let (tries, done) = (tries.clone(), done.clone());
+        let id = e.id;
+        Box::pin(async move {
+            if id != 0 {
+                done.fetch_add(1, Ordering::SeqCst);
+                return ProcessOutcome::Done;
+            }
+            if tries.fetch_add(1, Ordering::SeqCst) < failures {
+                ProcessOutcome::Retry("downstream unavailable".into())
+            } else {
+                ProcessOutcome::Done
+            }
+        })
+    })
+}
+
 /// Publishes a well-formed `TestEvent`, awaiting the broker ack so produce order — and
 /// therefore offset order — is deterministic regardless of in-flight settings.
 async fn produce_valid(producer: &KafkaProducerHandle, topic: &str, key: &str, id: u32) {
@@ -644,6 +704,205 @@ async fn scenario_j_dlq_publish_failure_withholds_commit() {
     );
 }
 
+// ── Scenario L: keyed mode runs other keys past a stalled one, in key order ───────────
+
+#[tokio::test]
+async fn scenario_l_keyed_mode_overtakes_a_stalled_key_and_holds_the_watermark() {
+    let ctx = TestContext::new().await;
+    let seen = Arc::new(Mutex::new(Vec::<u32>::new()));
+    let gate = Arc::new(tokio::sync::Notify::new());
+
+    let (s, g) = (seen.clone(), gate.clone());
+    let process = classify::<TestEvent, _>(move |e: &TestEvent| -> ProcessFuture<'_> {
+        let (s, g) = (s.clone(), g.clone());
+        let id = e.id;
+        Box::pin(async move {
+            // Offset 0 (key "slow") blocks until released.
+            if id == 0 {
+                g.notified().await;
+            }
+            s.lock().unwrap().push(id);
+            ProcessOutcome::Done
+        })
+    });
+
+    let task = spawn_keyed_runner::<TestEvent, _>(
+        ctx.consumer(),
+        ctx.producer(),
+        RetryPolicy::default(),
+        process,
+    );
+
+    let seed = ctx.producer();
+    produce_valid(&seed, &ctx.topic, "slow", 0).await;
+    for id in 1..=4 {
+        produce_valid(&seed, &ctx.topic, "fast", id).await;
+    }
+    produce_valid(&seed, &ctx.topic, "slow", 5).await;
+
+    // Key "fast" completes while "slow" is stuck on offset 0.
+    let observed = &seen;
+    let fast_done = await_until(WAIT, POLL, move || async move {
+        let seen = observed.lock().unwrap().clone();
+        (seen == vec![1, 2, 3, 4]).then_some(())
+    })
+    .await;
+    assert!(fast_done.is_some(), "the fast key is not blocked by the slow one");
+    assert_eq!(
+        ctx.committed_offset(0).await,
+        None,
+        "offset 0 is unsettled, so nothing is committed",
+    );
+
+    gate.notify_one();
+    let committed = await_commit(&ctx, 6).await;
+    task.abort();
+
+    assert_eq!(committed, Some(6), "the watermark catches up once offset 0 settles");
+    let seen = seen.lock().unwrap().clone();
+    let slow: Vec<u32> = seen.iter().copied().filter(|id| [0, 5].contains(id)).collect();
+    assert_eq!(slow, vec![0, 5], "the slow key kept its order");
+    assert_dlq_empty(&ctx).await;
+}
+
+// ── Scenario M: keyed mode keeps the retry/DLQ contract per lane ──────────────────────
+
+#[tokio::test]
+async fn scenario_m_keyed_mode_dead_letters_without_stalling_other_keys() {
+    let ctx = TestContext::new().await;
+    let done = Arc::new(AtomicUsize::new(0));
+
+    let d = done.clone();
+    let process = classify::<TestEvent, _>(move |e: &TestEvent| -> ProcessFuture<'_> {
+        let d = d.clone();
+        let id = e.id;
+        Box::pin(async move {
+            if id == 0 {
+                ProcessOutcome::Retry("still down".into())
+            } else {
+                d.fetch_add(1, Ordering::SeqCst);
+                ProcessOutcome::Done
+            }
+        })
+    });
+
+    let policy = RetryPolicy {
+        max_attempts: 3,
+        base_backoff: Duration::from_millis(300),
+        max_backoff: Duration::from_secs(1),
+    };
+    let task = spawn_keyed_runner::<TestEvent, _>(ctx.consumer(), ctx.producer(), policy, process);
+
+    let seed = ctx.producer();
+    produce_valid(&seed, &ctx.topic, "flaky", 0).await;
+    produce_poison(&seed, &ctx.topic, "poison").await;
+    for id in 1..=3 {
+        produce_valid(&seed, &ctx.topic, "ok", id).await;
+    }
+
+    let committed = await_commit(&ctx, 5).await;
+    task.abort();
+
+    assert_eq!(committed, Some(5), "every offset settles");
+    assert_eq!(done.load(Ordering::SeqCst), 3);
+
+    let parked = drain_dlq(&ctx, 2).await;
+    let mut reasons: Vec<&str> = parked
+        .iter()
+        .map(|m| m.headers.get("x-dlq-reason").map(String::as_str).unwrap_or(""))
+        .collect();
+    reasons.sort_unstable();
+    assert_eq!(reasons, vec!["decode", "retry-exhausted"]);
+}
+
+// ── Scenario N: the ladder moves the partition on and retries from the tiers ──────────
+
+#[tokio::test]
+async fn scenario_n_ladder_escalates_without_blocking_the_partition() {
+    let ctx = TestContext::new().await;
+    let tiers = ctx.create_retry_tiers(2).await;
+    let ladder = RetryLadder::new(vec![Duration::from_secs(2), Duration::from_millis(300)])
+        .expect("a valid ladder");
+    let (tries, done) = (Arc::new(AtomicUsize::new(0)), Arc::new(AtomicUsize::new(0)));
+
+    let mut tasks = vec![spawn_laddered_runner::<TestEvent, _>(
+        ctx.consumer(),
+        ctx.producer(),
+        ladder.clone(),
+        flaky_first_event(2, tries.clone(), done.clone()),
+    )];
+    for tier in &tiers {
+        tasks.push(spawn_laddered_runner::<TestEvent, _>(
+            ctx.consumer_on(tier),
+            ctx.producer(),
+            ladder.clone(),
+            flaky_first_event(2, tries.clone(), done.clone()),
+        ));
+    }
+
+    let seed = ctx.producer();
+    for id in 0..4 {
+        produce_valid(&seed, &ctx.topic, "k", id).await;
+    }
+
+    let committed = await_commit(&ctx, 4).await;
+    let tries_at_commit = tries.load(Ordering::SeqCst);
+    let recovered = await_until(WAIT, POLL, || async {
+        (tries.load(Ordering::SeqCst) == 3).then_some(())
+    })
+    .await;
+    for task in tasks {
+        task.abort();
+    }
+
+    assert_eq!(committed, Some(4), "the origin partition commits past the failing record");
+    assert_eq!(tries_at_commit, 1, "the tier-1 delay had not elapsed yet");
+    assert_eq!(done.load(Ordering::SeqCst), 3);
+    assert!(recovered.is_some(), "event 0 succeeded on its third attempt, from tier 2");
+    assert_dlq_empty(&ctx).await;
+}
+
+// ── Scenario O: the last tier dead-letters under the origin coordinates ───────────────
+
+#[tokio::test]
+async fn scenario_o_ladder_exhaustion_dead_letters_to_the_origin_dlq() {
+    let ctx = TestContext::new().await;
+    let tiers = ctx.create_retry_tiers(2).await;
+    let ladder = RetryLadder::new(vec![Duration::from_millis(200), Duration::from_millis(200)])
+        .expect("a valid ladder");
+    let (tries, done) = (Arc::new(AtomicUsize::new(0)), Arc::new(AtomicUsize::new(0)));
+
+    let mut tasks = vec![spawn_laddered_runner::<TestEvent, _>(
+        ctx.consumer(),
+        ctx.producer(),
+        ladder.clone(),
+        flaky_first_event(usize::MAX, tries.clone(), done.clone()),
+    )];
+    for tier in &tiers {
+        tasks.push(spawn_laddered_runner::<TestEvent, _>(
+            ctx.consumer_on(tier),
+            ctx.producer(),
+            ladder.clone(),
+            flaky_first_event(usize::MAX, tries.clone(), done.clone()),
+        ));
+    }
+
+    produce_valid(&ctx.producer(), &ctx.topic, "k", 0).await;
+
+    let parked = ctx.next_dlq_record(WAIT * 2).await;
+    for task in tasks {
+        task.abort();
+    }
+
+    let parked = parked.expect("the exhausted record reaches the origin .dlq");
+    assert_eq!(tries.load(Ordering::SeqCst), 3, "origin + two tiers");
+    assert_dlq_headers(&parked, &ctx, "0", "retry-exhausted", "3");
+    assert!(
+        parked.headers.keys().all(|k| !k.starts_with("x-retry-")),
+        "ladder bookkeeping is stripped from the dead-letter",
+    );
+}
+
 // ── Scenario K: mid-stream broker fault (stretch) ─────────────────────────────────────
 
 #[tokio::test]
@@ -0,0 +1,187 @@
+//! Live-broker suite for the dead-letter browse / dry-run / replay module.
+//!
+//! Parks records with the real `run_consumer`, then drives [`DlqBrowser`],
+//! [`PayloadDecoders`] and [`DlqReplayer`] against the resulting `.dlq` topic —
+//! so the browser is tested against the headers the runner actually writes.
+//!
+//! Gated behind `--features integration-kafka`; requires a Docker daemon.
+#![cfg(feature = "integration-kafka")]
+
+mod harness;
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use futures::StreamExt;
+use serde::{Deserialize, Serialize};
+
+use transport::kafka::EventEnvelope;
+use transport::kafka::config::KafkaClientConfig;
+use transport::kafka::config::consumer::{AutoOffsetReset, ConsumerConfig};
+use transport::kafka::consumer::{
+    KafkaConsumerBuilder, ProcessFuture, ProcessOutcome, RetryPolicy, run_consumer,
+};
+use transport::kafka::dlq::record::{HEADER_REPLAY_COUNT, HEADER_REPLAY_SOURCE};
+use transport::kafka::dlq::{
+    DlqBrowser, DlqFilter, DlqReason, DlqReplayer, PayloadDecoders, ReplayConfig,
+};
+
+use harness::{TestContext, await_until};
+
+const WAIT: Duration = Duration::from_secs(10);
+const POLL: Duration = Duration::from_millis(100);
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct TestEvent {
+    id: u32,
+}
+
+/// Parks one rejected (decodable) and one poison record, and waits until the
+/// runner has committed past both.
+async fn park_two(ctx: &TestContext) {
+    let consumer = ctx.consumer();
+    let producer = ctx.producer();
+    let task = tokio::spawn(async move {
+        run_consumer::<TestEvent, _>(&consumer, &producer, &RetryPolicy::default(), |_e| -> ProcessFuture<'_> {
+            Box::pin(async { ProcessOutcome::Reject("downstream said no".into()) })
+        })
+        .await
+    });
+
+    let producer = ctx.producer();
+    producer
+        .publish(EventEnvelope::new(&ctx.topic, "k-1", TestEvent { id: 1 }).with_header("event_id", "e-1"))
+        .await
+        .expect("publish valid");
+    producer
+        .publish_raw(&ctx.topic, "k-2", b"not-json", HashMap::new())
+        .await
+        .expect("publish poison");
+
+    await_until(WAIT, POLL, || async { ctx.committed_offset(0).await.filter(|o| *o >= 2) })
+        .await
+        .expect("runner committed past both records");
+    task.abort();
+}
+
+#[tokio::test]
+async fn browse_filters_on_the_runner_diagnostics() {
+    let ctx = TestContext::new().await;
+    park_two(&ctx).await;
+    let browser = DlqBrowser::new(KafkaClientConfig::new(&ctx.brokers)).unwrap();
+
+    let all = browser.scan(&ctx.dlq_topic, &DlqFilter::default(), 100).await.unwrap();
+    assert_eq!(all.len(), 2);
+    let rejected = &all[0];
+    let meta = rejected.metadata.as_ref().expect("runner headers parse");
+    assert_eq!(meta.origin_topic, ctx.topic);
+    assert_eq!(meta.reason, DlqReason::Reject);
+    assert_eq!(rejected.key, "k-1");
+    assert_eq!(rejected.headers["event_id"], "e-1", "origin headers are carried into the DLQ");
+
+    let decode_only = DlqFilter { reason: Some(DlqReason::Decode), ..Default::default() };
+    let poison = browser.scan(&ctx.dlq_topic, &decode_only, 100).await.unwrap();
+    assert_eq!(poison.len(), 1);
+    assert_eq!(poison[0].payload, b"not-json");
+
+    let at = DlqFilter { position: Some((0, 1)), ..Default::default() };
+    assert_eq!(browser.scan(&ctx.dlq_topic, &at, 100).await.unwrap()[0].key, "k-2");
+
+    let future = DlqFilter { since_ms: Some(i64::MAX / 2), ..Default::default() };
+    assert!(browser.scan(&ctx.dlq_topic, &future, 100).await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn dry_run_uses_the_consumer_payload_type() {
+    let ctx = TestContext::new().await;
+    park_two(&ctx).await;
+    let browser = DlqBrowser::new(KafkaClientConfig::new(&ctx.brokers)).unwrap();
+    let records = browser.scan(&ctx.dlq_topic, &DlqFilter::default(), 100).await.unwrap();
+
+    let decoders = PayloadDecoders::new().register::<TestEvent>(&ctx.topic, "it");
+    let verdicts: Vec<bool> = records
+        .iter()
+        .map(|r| decoders.dry_run(r).iter().all(|v| v.result.is_ok()))
+        .collect();
+    assert_eq!(verdicts, vec![true, false]);
+}
+
+#[tokio::test]
+async fn replay_republishes_to_the_origin_with_markers() {
+    let ctx = TestContext::new().await;
+    park_two(&ctx).await;
+    let browser = DlqBrowser::new(KafkaClientConfig::new(&ctx.brokers)).unwrap();
+    let rejected = browser
+        .scan(&ctx.dlq_topic, &DlqFilter { reason: Some(DlqReason::Reject), ..Default::default() }, 100)
+        .await
+        .unwrap();
+
+    let replayer = DlqReplayer::new(ctx.producer(), ReplayConfig::default());
+    let report = replayer.replay(&rejected).await;
+    assert!(report.aborted.is_none());
+    assert_eq!(report.replayed, vec![format!("{}/0@0", ctx.dlq_topic)]);
+
+    // The origin topic now holds the two originals plus the replay at offset 2.
+    let mut config = ConsumerConfig::new(KafkaClientConfig::new(&ctx.brokers), format!("{}-check", ctx.group_id));
+    config.auto_offset_reset = AutoOffsetReset::Earliest;
+    let handle = KafkaConsumerBuilder::new(config).subscribe(&ctx.topic).build().unwrap();
+    let mut stream = handle.stream::<TestEvent>();
+    let mut replayed = None;
+    while let Ok(Some(Ok(msg))) = tokio::time::timeout(WAIT, stream.next()).await {
+        if msg.offset == 2 {
+            replayed = Some(msg);
+            break;
+        }
+    }
+    let replayed = replayed.expect("replayed record on the origin topic");
+    assert_eq!(replayed.key, "k-1");
+    assert_eq!(replayed.payload.unwrap().id, 1);
+    assert_eq!(replayed.headers["event_id"], "e-1");
+    assert_eq!(replayed.headers[HEADER_REPLAY_COUNT], "1");
+    assert_eq!(replayed.headers[HEADER_REPLAY_SOURCE], format!("{}/0@0", ctx.dlq_topic));
+    assert!(replayed.headers.keys().all(|k| !k.starts_with("x-dlq-")));
+}
+
+#[tokio::test]
+async fn a_key_that_is_not_utf8_survives_the_dlq_and_the_replay() {
+    const KEY: &[u8] = &[0xff, 0xfe, 0x01];
+    let ctx = TestContext::new().await;
+    let consumer = ctx.consumer();
+    let producer = ctx.producer();
+    let task = tokio::spawn(async move {
+        run_consumer::<TestEvent, _>(&consumer, &producer, &RetryPolicy::default(), |_e| -> ProcessFuture<'_> {
+            Box::pin(async { ProcessOutcome::Reject("downstream said no".into()) })
+        })
+        .await
+    });
+    ctx.producer()
+        .republish(&ctx.topic, Some(KEY), br#"{"id":3}"#, HashMap::new())
+        .await
+        .expect("publish");
+    await_until(WAIT, POLL, || async { ctx.committed_offset(0).await.filter(|o| *o >= 1) })
+        .await
+        .expect("runner committed past the record");
+    task.abort();
+
+    let browser = DlqBrowser::new(KafkaClientConfig::new(&ctx.brokers)).unwrap();
+    let parked = browser.scan(&ctx.dlq_topic, &DlqFilter::default(), 100).await.unwrap();
+    assert_eq!(parked[0].key_bytes.as_deref(), Some(KEY));
+
+    let report = DlqReplayer::new(ctx.producer(), ReplayConfig::default()).replay(&parked).await;
+    assert!(report.aborted.is_none());
+
+    let mut config = ConsumerConfig::new(KafkaClientConfig::new(&ctx.brokers), format!("{}-check", ctx.group_id));
+    config.auto_offset_reset = AutoOffsetReset::Earliest;
+    let handle = KafkaConsumerBuilder::new(config).subscribe(&ctx.topic).build().unwrap();
+    let mut stream = handle.stream::<TestEvent>();
+    let mut replayed = None;
+    while let Ok(Some(Ok(msg))) = tokio::time::timeout(WAIT, stream.next()).await {
+        if msg.offset == 1 {
+            replayed = Some(msg);
+            break;
+        }
+    }
+    let replayed = replayed.expect("replayed record on the origin topic");
+    assert_eq!(replayed.key_bytes.as_deref(), Some(KEY));
+    assert_eq!(replayed.payload.unwrap().id, 3);
+}
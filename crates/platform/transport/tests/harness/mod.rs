@@ -45,7 +45,9 @@ use uuid::Uuid;
 use transport::kafka::config::client::KafkaClientConfig;
 use transport::kafka::config::consumer::{AutoOffsetReset, ConsumerConfig};
 use transport::kafka::config::producer::ProducerConfig;
-use transport::kafka::consumer::{ConsumedMessage, KafkaConsumerBuilder, KafkaConsumerHandle};
+use transport::kafka::consumer::{
+    ConsumedMessage, KafkaConsumerBuilder, KafkaConsumerHandle, retry_topic,
+};
 use transport::kafka::producer::{KafkaProducerBuilder, KafkaProducerHandle};
 
 /// Dead-letter topic suffix. Mirrors the (private) `DLQ_SUFFIX` in the runner so the
@@ -181,14 +183,29 @@ impl TestContext {
     /// context's group, reading from `earliest`, with auto-commit disabled (the runner
     /// owns every commit). This is the handle a scenario passes to `run_consumer`.
     pub fn consumer(&self) -> KafkaConsumerHandle {
+        self.consumer_on(&self.topic)
+    }
+
+    /// [`TestContext::consumer`] subscribed to `topic` instead — a retry tier's runner
+    /// reads its tier under the same group as the origin runner.
+    pub fn consumer_on(&self, topic: &str) -> KafkaConsumerHandle {
         let mut config = ConsumerConfig::new(KafkaClientConfig::new(&self.brokers), &self.group_id);
         config.auto_offset_reset = AutoOffsetReset::Earliest;
         KafkaConsumerBuilder::new(config)
-            .subscribe(&self.topic)
+            .subscribe(topic)
             .build()
             .expect("failed to build the Kafka consumer")
     }
 
+    /// Creates the retry-ladder tiers `{topic}.retry.1..=tiers` and returns their names,
+    /// tier 1 first. Only the ladder scenarios need them, so [`TestContext::new`] does not.
+    pub async fn create_retry_tiers(&self, tiers: u32) -> Vec<String> {
+        let names: Vec<String> = (1..=tiers).map(|tier| retry_topic(&self.topic, tier)).collect();
+        let refs: Vec<&str> = names.iter().map(String::as_str).collect();
+        create_topics(&self.brokers, &refs).await;
+        names
+    }
+
     /// A read-only consumer on the dead-letter topic, in its own group so each call reads
     /// the `.dlq` from the beginning. Used to assert evacuated records and their
     /// `x-dlq-*` diagnostic headers. See [`TestContext::next_dlq_record`].
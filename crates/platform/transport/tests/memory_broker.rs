@@ -0,0 +1,133 @@
+//! Offline counterpart to `consumer_runtime.rs`: the same `run_consumer` runtime driven
+//! against the in-process [`MemoryBroker`] — publish → consume → commit → dead-letter, no
+//! Docker. The runner is an infinite loop, so it is spawned and aborted once the
+//! side-effect under test is visible on the broker.
+//!
+//! Gated behind `--features memory-broker`.
+#![cfg(feature = "memory-broker")]
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use transport::error::TransportError;
+use transport::kafka::EventEnvelope;
+use transport::kafka::consumer::{ProcessFuture, ProcessOutcome, RetryPolicy, run_consumer};
+use transport::kafka::memory::MemoryBroker;
+
+const TOPIC: &str = "orders";
+const GROUP: &str = "orders-worker";
+/// Upper bound on waiting for the runner; everything is in-process, so this only
+/// trips on a hang.
+const WAIT: Duration = Duration::from_secs(5);
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct TestEvent {
+    id: u32,
+}
+
+/// Counts processed events; rejects id 13.
+fn counting(count: Arc<AtomicUsize>) -> impl for<'a> Fn(&'a TestEvent) -> ProcessFuture<'a> + Send + 'static {
+    move |e: &TestEvent| -> ProcessFuture<'_> {
+        let count = count.clone();
+        let id = e.id;
+        Box::pin(async move {
+            if id == 13 {
+                return ProcessOutcome::Reject("unlucky".into());
+            }
+            count.fetch_add(1, Ordering::SeqCst);
+            ProcessOutcome::Done
+        })
+    }
+}
+
+fn spawn_runner(
+    broker: &MemoryBroker,
+    count: Arc<AtomicUsize>,
+) -> tokio::task::JoinHandle<Result<(), TransportError>> {
+    let (consumer, producer) = (broker.consumer(GROUP, [TOPIC]), broker.producer());
+    tokio::spawn(async move {
+        run_consumer::<TestEvent, _>(&consumer, &producer, &RetryPolicy::default(), counting(count)).await
+    })
+}
+
+async fn await_commit(broker: &MemoryBroker, at_least: i64) -> Option<i64> {
+    tokio::time::timeout(WAIT, async {
+        loop {
+            match broker.committed(GROUP, TOPIC, 0) {
+                Some(offset) if offset >= at_least => return offset,
+                _ => tokio::time::sleep(Duration::from_millis(1)).await,
+            }
+        }
+    })
+    .await
+    .ok()
+}
+
+#[tokio::test]
+async fn poison_and_rejected_records_are_dead_lettered_and_committed_past() {
+    let broker = MemoryBroker::new();
+    broker.create_topic(TOPIC, 1);
+    broker.create_topic(&format!("{TOPIC}.dlq"), 1);
+    let count = Arc::new(AtomicUsize::new(0));
+    let task = spawn_runner(&broker, count.clone());
+
+    let producer = broker.producer();
+    producer.publish(EventEnvelope::new(TOPIC, "k", TestEvent { id: 1 })).await.unwrap();
+    producer.publish_raw(TOPIC, "k", b"not-json", HashMap::new()).await.unwrap();
+    producer.publish(EventEnvelope::new(TOPIC, "k", TestEvent { id: 13 })).await.unwrap();
+    producer.publish(EventEnvelope::new(TOPIC, "k", TestEvent { id: 2 })).await.unwrap();
+
+    let committed = await_commit(&broker, 4).await;
+    task.abort();
+
+    assert_eq!(committed, Some(4), "every offset committed, poison included");
+    assert_eq!(count.load(Ordering::SeqCst), 2, "valid records processed once each");
+
+    let dlq = broker.records(&format!("{TOPIC}.dlq"));
+    assert_eq!(dlq.len(), 2);
+    assert_eq!(dlq[0].payload, b"not-json", "raw bytes preserved verbatim");
+    assert_eq!(dlq[0].key, "k", "source key preserved");
+    assert_eq!(dlq[0].headers.get("x-dlq-offset").map(String::as_str), Some("1"));
+    assert_eq!(dlq[0].headers.get("x-dlq-reason").map(String::as_str), Some("decode"));
+    assert_eq!(dlq[1].headers.get("x-dlq-offset").map(String::as_str), Some("2"));
+    assert_eq!(dlq[1].headers.get("x-dlq-reason").map(String::as_str), Some("reject"));
+    assert_eq!(dlq[1].headers.get("x-dlq-origin-topic").map(String::as_str), Some(TOPIC));
+}
+
+#[tokio::test]
+async fn missing_dlq_topic_withholds_the_commit() {
+    let broker = MemoryBroker::new();
+    broker.create_topic(TOPIC, 1);
+    let task = spawn_runner(&broker, Arc::new(AtomicUsize::new(0)));
+
+    broker.producer().publish_raw(TOPIC, "k", b"not-json", HashMap::new()).await.unwrap();
+
+    let result = tokio::time::timeout(WAIT, task).await.expect("runner returns").unwrap();
+    assert!(result.is_err(), "an unpublishable dead-letter surfaces to the caller");
+    assert_eq!(broker.committed(GROUP, TOPIC, 0), None, "the poison offset is not committed");
+}
+
+#[tokio::test]
+async fn a_restarted_worker_resumes_from_the_last_commit() {
+    let broker = MemoryBroker::new();
+    broker.create_topic(TOPIC, 1);
+    let producer = broker.producer();
+    let count = Arc::new(AtomicUsize::new(0));
+
+    let first = spawn_runner(&broker, count.clone());
+    producer.publish(EventEnvelope::new(TOPIC, "k", TestEvent { id: 1 })).await.unwrap();
+    assert_eq!(await_commit(&broker, 1).await, Some(1));
+    first.abort();
+    let _ = first.await;
+
+    producer.publish(EventEnvelope::new(TOPIC, "k", TestEvent { id: 2 })).await.unwrap();
+    let second = spawn_runner(&broker, count.clone());
+    assert_eq!(await_commit(&broker, 2).await, Some(2));
+    second.abort();
+
+    assert_eq!(count.load(Ordering::SeqCst), 2, "nothing redelivered after the commit");
+}
@@ -8,7 +8,7 @@ use http::header::HeaderName;
 use infra_config::{InfrastructureConfig, TrafficRegistry};
 use tonic::body::Body;
 use tower::{Layer, ServiceExt};
-use transport::grpc::layer::TrafficLayer;
+use transport::grpc::layer::{TrafficAttributes, TrafficLayer};
 
 /// Edge-mesh identity header the tests inject (matches the transport default).
 const ID_HEADER: &str = "x-edge-user";
@@ -171,6 +171,90 @@ async fn per_caller_without_identity_falls_back_to_method_bucket() {
     );
 }
 
+// ── per_ip / per_tenant / per_attribute ───────────────────────────────────────
+
+/// A single rps=1/burst=1 profile under `scope`, bound to every method.
+fn scoped_registry(scope: &str) -> Arc<TrafficRegistry> {
+    let toml = PER_CALLER_TOML.replace("scope = \"per_caller\"", scope);
+    let cfg = InfrastructureConfig::from_toml(&toml).unwrap();
+    Arc::new(TrafficRegistry::from_section(cfg.traffic.unwrap()).unwrap())
+}
+
+fn req_with_header(path: &str, name: &str, value: &str) -> http::Request<Body> {
+    http::Request::builder().uri(path).header(name, value).body(Body::empty()).unwrap()
+}
+
+/// A unary gRPC request whose message carries `value` in string field 2.
+fn req_with_field(path: &str, value: &str) -> http::Request<Body> {
+    let mut message = vec![0x12, value.len() as u8];
+    message.extend_from_slice(value.as_bytes());
+    let mut frame = vec![0];
+    frame.extend_from_slice(&(message.len() as u32).to_be_bytes());
+    frame.extend_from_slice(&message);
+    let body = Body::new(http_body_util::Full::new(bytes::Bytes::from(frame)));
+    http::Request::builder().uri(path).body(body).unwrap()
+}
+
+/// Sends `req` through a clone of `svc` and reports whether it was throttled.
+async fn throttled<S>(svc: &S, req: http::Request<Body>) -> bool
+where
+    S: tower::Service<http::Request<Body>, Response = http::Response<Body>> + Clone,
+    S::Error: std::fmt::Debug,
+{
+    let resp = svc.clone().oneshot(req).await.unwrap();
+    resp.headers().get("grpc-status").and_then(|v| v.to_str().ok()) == Some("8")
+}
+
+#[tokio::test]
+async fn per_ip_isolates_clients_by_edge_header() {
+    let svc = layer(scoped_registry("scope = \"per_ip\"")).layer(ok_service!());
+    let ip = "x-edge-client-ip";
+
+    assert!(!throttled(&svc, req_with_header("/svc/M", ip, "10.0.0.1")).await);
+    assert!(!throttled(&svc, req_with_header("/svc/M", ip, "10.0.0.2")).await);
+    assert!(throttled(&svc, req_with_header("/svc/M", ip, "10.0.0.1")).await);
+}
+
+#[tokio::test]
+async fn per_tenant_reads_a_configurable_header() {
+    let svc = layer(scoped_registry("scope = \"per_tenant\""))
+        .with_tenant_header(HeaderName::from_static("x-tenant"))
+        .layer(ok_service!());
+
+    assert!(!throttled(&svc, req_with_header("/svc/M", "x-tenant", "acme")).await);
+    assert!(!throttled(&svc, req_with_header("/svc/M", "x-tenant", "globex")).await);
+    assert!(throttled(&svc, req_with_header("/svc/M", "x-tenant", "acme")).await);
+    // No tenant → the shared method bucket, still limited.
+    assert!(!throttled(&svc, req("/svc/M")).await);
+    assert!(throttled(&svc, req("/svc/M")).await);
+}
+
+#[tokio::test]
+async fn per_attribute_keys_on_the_declared_request_field() {
+    let attributes = TrafficAttributes::for_service("svc").field("M", "post_id", 2);
+    let svc = layer(scoped_registry("scope = \"per_attribute\"\nattribute = \"post_id\""))
+        .with_attributes(attributes)
+        .layer(tower::service_fn(|req: http::Request<Body>| async move {
+            // The handler still receives the full, buffered message.
+            let body = http_body_util::BodyExt::collect(req.into_body()).await.unwrap().to_bytes();
+            assert!(!body.is_empty(), "body forwarded after keying");
+            Ok::<_, Infallible>(http::Response::new(Body::empty()))
+        }));
+
+    assert!(!throttled(&svc, req_with_field("/svc/M", "post-1")).await);
+    assert!(!throttled(&svc, req_with_field("/svc/M", "post-2")).await);
+    assert!(throttled(&svc, req_with_field("/svc/M", "post-1")).await);
+}
+
+#[tokio::test]
+async fn per_attribute_without_a_declared_field_keys_per_method() {
+    let svc = layer(scoped_registry("scope = \"per_attribute\"\nattribute = \"post_id\""))
+        .layer(ok_service!());
+
+    assert!(!throttled(&svc, req_with_field("/svc/M", "post-1")).await);
+    assert!(throttled(&svc, req_with_field("/svc/M", "post-2")).await);
+}
+
 // ── distributed mode (Step 2 backend) ─────────────────────────────────────────
 
 use std::time::Duration;
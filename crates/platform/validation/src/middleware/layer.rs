@@ -69,7 +69,7 @@ impl<S: CommandBus> CommandBus for ValidationCommandBus<S> {
     fn dispatch<C: Command>(
         &self,
         envelope: Envelope<C>,
-    ) -> impl Future<Output = Result<(), CqrsError>> + Send + '_ {
+    ) -> impl Future<Output = Result<C::Output, CqrsError>> + Send + '_ {
         // `C: Command` implies `C: validate_core::Validate` via the supertrait
         // bound on `Command`. The call is statically dispatched — zero overhead.
         async move {
@@ -19,8 +19,11 @@ use validation::{VAL_1001_REQUIRED, VAL_1002_LENGTH, VAL_1004_RANGE};
 
 // ── InlineCommandBus ──────────────────────────────────────────────────────────
 
-/// Stub [`CommandBus`] that immediately returns `Ok(())` and flips a flag so
-/// tests can assert that the inner bus was (or was not) reached.
+/// Stub [`CommandBus`] that immediately succeeds and flips a flag so tests can
+/// assert that the inner bus was (or was not) reached.
+///
+/// Every test command outputs `()`, which the stub produces by decoding `null`
+/// — the one value it can build for any `C::Output` without a handler.
 #[derive(Clone, Default)]
 pub struct InlineCommandBus {
     pub reached: Arc<AtomicBool>,
@@ -40,9 +43,9 @@ impl CommandBus for InlineCommandBus {
     fn dispatch<C: Command>(
         &self,
         _envelope: Envelope<C>,
-    ) -> impl Future<Output = Result<(), CqrsError>> + Send + '_ {
+    ) -> impl Future<Output = Result<C::Output, CqrsError>> + Send + '_ {
         self.reached.store(true, Ordering::SeqCst);
-        async { Ok(()) }
+        async { Ok(serde_json::from_value(serde_json::Value::Null).expect("test commands output ()")) }
     }
 }
 
@@ -55,7 +58,9 @@ pub struct AlwaysValidCommand {
 }
 
 impl Validate for AlwaysValidCommand {}
-impl Command for AlwaysValidCommand {}
+impl Command for AlwaysValidCommand {
+    type Output = ();
+}
 
 // ── AlwaysInvalidCommand ──────────────────────────────────────────────────────
 
@@ -76,7 +81,9 @@ impl Validate for AlwaysInvalidCommand {
     }
 }
 
-impl Command for AlwaysInvalidCommand {}
+impl Command for AlwaysInvalidCommand {
+    type Output = ();
+}
 
 // ── MultiViolationCommand ─────────────────────────────────────────────────────
 
@@ -94,4 +101,6 @@ impl Validate for MultiViolationCommand {
     }
 }
 
-impl Command for MultiViolationCommand {}
+impl Command for MultiViolationCommand {
+    type Output = ();
+}
@@ -0,0 +1,35 @@
+-- Transactional outbox for account.v1.events (shared `outbox` crate schema —
+-- a verbatim rendering of OutboxTable::new("account").ddl()).
+--
+-- WHY: the repository used to publish to Kafka AFTER the row committed. A crash
+-- or broker failure between the two lost the event for good (audit and auth
+-- consume account.v1.events), while the RPC had already reported failure for a
+-- change that did commit. Events are now enqueued in the row write's own
+-- transaction and relayed to Kafka in the background.
+CREATE TABLE IF NOT EXISTS account_outbox (
+    id            UUID        PRIMARY KEY,
+    seq           BIGSERIAL,
+    topic         TEXT        NOT NULL,
+    aggregate_key TEXT        NOT NULL,
+    slot          INT         NOT NULL,
+    event_type    TEXT        NOT NULL,
+    payload       JSONB       NOT NULL,
+    headers       JSONB       NOT NULL DEFAULT '{}',
+    attempts      INT         NOT NULL DEFAULT 0,
+    last_error    TEXT,
+    created_at    TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+CREATE INDEX IF NOT EXISTS account_outbox_drain_order
+    ON account_outbox (slot, seq);
+
+CREATE TABLE IF NOT EXISTS account_outbox_lease (
+    slot       INT         PRIMARY KEY,
+    owner      TEXT        NOT NULL DEFAULT '',
+    expires_at TIMESTAMPTZ NOT NULL DEFAULT 'epoch'
+);
+
+CREATE TABLE IF NOT EXISTS account_outbox_member (
+    owner      TEXT        PRIMARY KEY,
+    expires_at TIMESTAMPTZ NOT NULL
+);
@@ -0,0 +1,15 @@
+-- Command idempotency for the account service (shared `idempotency` crate schema —
+-- a verbatim rendering of IdempotencyTable::new("account").ddl()).
+--
+-- WHY: the command bus deduplicates retried commands by message_id. The old
+-- in-process seen-set forgot everything on restart and was invisible to the other
+-- replicas, so a retry routed elsewhere ran the command twice. Claims and
+-- processed marks now live here, shared by every replica, and expire on their own.
+CREATE TABLE IF NOT EXISTS account_idempotency (
+    message_id UUID        PRIMARY KEY,
+    state      TEXT        NOT NULL,
+    expires_at TIMESTAMPTZ NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS account_idempotency_expiry
+    ON account_idempotency (expires_at);
@@ -0,0 +1,9 @@
+-- Records each processed command's output on account_idempotency (the tail of
+-- IdempotencyTable::new("account").ddl() after 0004_idempotency.sql).
+--
+-- WHY: a duplicate used to be acknowledged with an empty Ok, which is useless to
+-- a client retrying a command whose response carries data. The layer now stores
+-- the encoded output alongside the processed mark and replays it to the retry.
+-- Rows marked before this migration read back as NULL and replay as `null`.
+ALTER TABLE account_idempotency
+    ADD COLUMN IF NOT EXISTS result BYTEA;
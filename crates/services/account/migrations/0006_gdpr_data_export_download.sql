@@ -0,0 +1,9 @@
+-- Where a fulfilled Art. 20 export can be fetched from, and until when.
+--
+-- WHY: the export pipeline (infrastructure::export) assembles the sealed archive
+-- in object storage and completes the request on the account row; the holder's
+-- download reference and its expiry live beside data_export_completed_at so
+-- GetGdprRecord can hand them out. Both are NULL until the first completion.
+ALTER TABLE accounts
+    ADD COLUMN IF NOT EXISTS gdpr_data_export_download_ref TEXT,
+    ADD COLUMN IF NOT EXISTS gdpr_data_export_expires_at   TIMESTAMPTZ;
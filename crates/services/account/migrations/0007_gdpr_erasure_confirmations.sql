@@ -0,0 +1,9 @@
+-- Which contexts have confirmed erasure for the pending Art. 17 request.
+--
+-- WHY: every data-owning service consumes gdpr_deletion_requested, erases or
+-- pseudonymises its own copy of the subject and confirms back through
+-- ConfirmSubjectErasure. AnonymizeAccount refuses to run until every participant
+-- is listed here, so the set lives beside gdpr_deletion_requested_at on the row.
+-- Empty for accounts with no deletion request.
+ALTER TABLE accounts
+    ADD COLUMN IF NOT EXISTS gdpr_erasure_confirmed_by TEXT[] NOT NULL DEFAULT '{}';
@@ -0,0 +1,8 @@
+-- The RFC 6238 time step of the last TOTP code VerifyMfaFactor accepted.
+--
+-- WHY: a code stays valid for its own step and one either side, so without a
+-- record of what was accepted the same code can be replayed for up to ~90 s
+-- (RFC 6238 §5.2). VerifyMfaFactor refuses any code whose step is at or before
+-- this one. NULL until the first TOTP check after enrolment; cleared on revoke.
+ALTER TABLE accounts
+    ADD COLUMN IF NOT EXISTS mfa_totp_last_step BIGINT;
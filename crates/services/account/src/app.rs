@@ -1,9 +1,10 @@
 //! The account service's composition root.
 //!
-//! [`App::build`] is *pure composition*: a Postgres connection pool in, a
-//! fully-wired CQRS graph out. It binds no socket and reads no environment, so a
-//! binary entrypoint and the live integration harness assemble the exact same
-//! graph.
+//! [`App::build`] is *pure composition*: a Postgres connection pool, an outbox
+//! sink, the profile directory, the second-factor verifier and the export archive
+//! store and sealer in, a fully-wired CQRS graph (plus its outbox relay) out. It
+//! binds no socket and reads no environment, so a binary entrypoint and the live
+//! integration harness assemble the exact same graph.
 //!
 //! Account is the platform's only relational service — its repository is backed
 //! by a [`TransactionManager`] over a `sqlx` pool rather than ScyllaDB/Redis, so
@@ -13,83 +14,137 @@
 use std::sync::Arc;
 
 use cqrs::command::{CommandBusBuilder, InMemoryCommandBus};
+use cqrs::middleware::{
+    IdempotencyCommandBus, IdempotencyLayer, MetricsCommandBus, MetricsLayer,
+    MetricsQueryBus, MiddlewarePipeline,
+};
 use cqrs::query::{InMemoryQueryBus, QueryBusBuilder};
+use idempotency::{IdempotencyTable, PgIdempotencyStore};
+use outbox::{OutboxRelay, OutboxSink, OutboxTable, PgOutbox, RelayConfig};
 use postgres_storage::TransactionManager;
 use sqlx::PgPool;
 
 use crate::application::command::{
     AnonymizeAccountCommand, AnonymizeAccountHandler, AssignRoleCommand, AssignRoleHandler,
-    ChangePasswordCommand, ChangePasswordHandler, CreateAccountCommand, CreateAccountHandler,
+    ChangePasswordCommand, ChangePasswordHandler, CompleteDataExportCommand,
+    CompleteDataExportHandler, ConfirmSubjectErasureCommand, ConfirmSubjectErasureHandler,
+    CreateAccountCommand, CreateAccountHandler,
     DeactivateAccountCommand, DeactivateAccountHandler, EnrollMfaCommand, EnrollMfaHandler,
     ReactivateAccountCommand, ReactivateAccountHandler, RecordFailedLoginCommand,
     RecordFailedLoginHandler, RecordLoginCommand, RecordLoginHandler, RequestDataExportCommand,
     RequestDataExportHandler, RequestGdprDeletionCommand, RequestGdprDeletionHandler,
     RevokeMfaCommand, RevokeMfaHandler, RevokeRoleCommand, RevokeRoleHandler, SuspendAccountCommand,
     SuspendAccountHandler, UpdateKycStatusCommand, UpdateKycStatusHandler, VerifyEmailCommand,
-    VerifyEmailHandler, VerifyPhoneCommand, VerifyPhoneHandler,
+    VerifyEmailHandler, VerifyMfaFactorCommand, VerifyMfaFactorHandler, VerifyPhoneCommand,
+    VerifyPhoneHandler,
+};
+use crate::application::port::{
+    AccountRepository, ArchiveSealer, ExportArchiveStore, ProfileDirectory, SecondFactorVerifier,
 };
-use crate::application::port::{AccountRepository, EventPublisher};
 use crate::application::query::{
-    GetAccountByIdHandler, GetAccountByIdQuery, GetAccountByIdentityIdHandler,
+    DownloadDataExportHandler, DownloadDataExportQuery, GetAccountByIdHandler, GetAccountByIdQuery, GetAccountByIdentityIdHandler,
     GetAccountByIdentityIdQuery, GetAccountStatusHandler, GetAccountStatusQuery,
     GetGdprRecordHandler, GetGdprRecordQuery, ListAccountsByStatusHandler,
     ListAccountsByStatusQuery,
 };
+use crate::infrastructure::event::OUTBOX_PREFIX;
 use crate::infrastructure::persistence::PgAccountRepository;
 
+/// The command bus every entrypoint dispatches through: the registered handlers
+/// behind an [`IdempotencyLayer`] on `account_idempotency`, so a retried
+/// `message_id` runs once across replicas. It fails closed, like the writes
+/// themselves: without the database there is nothing to deduplicate against.
+/// A [`MetricsLayer`] outermost times every dispatch, replays included.
+pub type AppCommandBus =
+    MetricsCommandBus<IdempotencyCommandBus<InMemoryCommandBus, PgIdempotencyStore>>;
+
+/// The query bus every read path dispatches through: the registered handlers,
+/// timed by a [`MetricsLayer`].
+pub type AppQueryBus = MetricsQueryBus<InMemoryQueryBus>;
+
 /// A fully-wired account service bound to its Postgres pool. The buses exposed
 /// here are the *same* instances the handlers are registered into; `repository`
 /// is the shared port handle for direct assertions.
 pub struct App {
-    pub command_bus: Arc<InMemoryCommandBus>,
-    pub query_bus:   Arc<InMemoryQueryBus>,
+    pub command_bus: Arc<AppCommandBus>,
+    pub query_bus:   Arc<AppQueryBus>,
     pub repository:  Arc<dyn AccountRepository>,
+    /// The bus's claim store, exposed so the runtime adapter can schedule
+    /// [`PgIdempotencyStore::purge_expired`] (Postgres rows do not expire on their own).
+    pub idempotency: PgIdempotencyStore,
+    /// Drains `account_outbox` into `sink`; spawned by the runtime adapter (the
+    /// integration harness ticks it by hand).
+    pub relay:       OutboxRelay,
 }
 
 impl App {
     /// Wraps `pool` in a [`TransactionManager`], builds the Postgres-backed
-    /// repository, and registers every account command and query.
+    /// repository over the account outbox, and registers every account command
+    /// and query. `profiles` resolves a subject's profiles for erasure requests;
+    /// `verifier` checks the second factors `auth` relays; `archives` and
+    /// `sealer` serve a completed data export back to its owner.
     pub async fn build(
         pool: PgPool,
-        publisher: Arc<dyn EventPublisher>,
+        sink: Arc<dyn OutboxSink>,
+        profiles: Arc<dyn ProfileDirectory>,
+        verifier: Arc<dyn SecondFactorVerifier>,
+        archives: Arc<dyn ExportArchiveStore>,
+        sealer: Arc<dyn ArchiveSealer>,
     ) -> Result<Self, Box<dyn std::error::Error>> {
+        let table = OutboxTable::new(OUTBOX_PREFIX)?;
+        let tx = TransactionManager::new(pool.clone());
+        let idempotency = PgIdempotencyStore::new(IdempotencyTable::new("account")?, tx.clone());
+        let relay = OutboxRelay::new(pool, table.clone(), sink, RelayConfig::from_env());
         let repository: Arc<dyn AccountRepository> = Arc::new(PgAccountRepository::new(
-            TransactionManager::new(pool),
-            publisher,
+            tx.clone(),
+            PgOutbox::new(table, tx),
+            idempotency.clone(),
         ));
 
+        let handlers = CommandBusBuilder::new()
+            .register::<CreateAccountCommand, _>(CreateAccountHandler::new(Arc::clone(&repository)))?
+            .register::<VerifyEmailCommand, _>(VerifyEmailHandler::new(Arc::clone(&repository)))?
+            .register::<VerifyPhoneCommand, _>(VerifyPhoneHandler::new(Arc::clone(&repository)))?
+            .register::<ChangePasswordCommand, _>(ChangePasswordHandler::new(Arc::clone(&repository)))?
+            .register::<EnrollMfaCommand, _>(EnrollMfaHandler::new(Arc::clone(&repository)))?
+            .register::<RevokeMfaCommand, _>(RevokeMfaHandler::new(Arc::clone(&repository)))?
+            .register::<VerifyMfaFactorCommand, _>(VerifyMfaFactorHandler::new(Arc::clone(&repository), verifier))?
+            .register::<UpdateKycStatusCommand, _>(UpdateKycStatusHandler::new(Arc::clone(&repository)))?
+            .register::<SuspendAccountCommand, _>(SuspendAccountHandler::new(Arc::clone(&repository)))?
+            .register::<ReactivateAccountCommand, _>(ReactivateAccountHandler::new(Arc::clone(&repository)))?
+            .register::<DeactivateAccountCommand, _>(DeactivateAccountHandler::new(Arc::clone(&repository)))?
+            .register::<RecordLoginCommand, _>(RecordLoginHandler::new(Arc::clone(&repository)))?
+            .register::<RecordFailedLoginCommand, _>(RecordFailedLoginHandler::new(Arc::clone(&repository)))?
+            .register::<RequestGdprDeletionCommand, _>(RequestGdprDeletionHandler::new(Arc::clone(&repository), profiles))?
+            .register::<AnonymizeAccountCommand, _>(AnonymizeAccountHandler::new(Arc::clone(&repository)))?
+            .register::<RequestDataExportCommand, _>(RequestDataExportHandler::new(Arc::clone(&repository)))?
+            .register::<CompleteDataExportCommand, _>(CompleteDataExportHandler::new(Arc::clone(&repository)))?
+            .register::<ConfirmSubjectErasureCommand, _>(ConfirmSubjectErasureHandler::new(Arc::clone(&repository)))?
+            .register::<AssignRoleCommand, _>(AssignRoleHandler::new(Arc::clone(&repository)))?
+            .register::<RevokeRoleCommand, _>(RevokeRoleHandler::new(Arc::clone(&repository)))?
+            .build();
         let command_bus = Arc::new(
-            CommandBusBuilder::new()
-                .register::<CreateAccountCommand, _>(CreateAccountHandler::new(Arc::clone(&repository)))?
-                .register::<VerifyEmailCommand, _>(VerifyEmailHandler::new(Arc::clone(&repository)))?
-                .register::<VerifyPhoneCommand, _>(VerifyPhoneHandler::new(Arc::clone(&repository)))?
-                .register::<ChangePasswordCommand, _>(ChangePasswordHandler::new(Arc::clone(&repository)))?
-                .register::<EnrollMfaCommand, _>(EnrollMfaHandler::new(Arc::clone(&repository)))?
-                .register::<RevokeMfaCommand, _>(RevokeMfaHandler::new(Arc::clone(&repository)))?
-                .register::<UpdateKycStatusCommand, _>(UpdateKycStatusHandler::new(Arc::clone(&repository)))?
-                .register::<SuspendAccountCommand, _>(SuspendAccountHandler::new(Arc::clone(&repository)))?
-                .register::<ReactivateAccountCommand, _>(ReactivateAccountHandler::new(Arc::clone(&repository)))?
-                .register::<DeactivateAccountCommand, _>(DeactivateAccountHandler::new(Arc::clone(&repository)))?
-                .register::<RecordLoginCommand, _>(RecordLoginHandler::new(Arc::clone(&repository)))?
-                .register::<RecordFailedLoginCommand, _>(RecordFailedLoginHandler::new(Arc::clone(&repository)))?
-                .register::<RequestGdprDeletionCommand, _>(RequestGdprDeletionHandler::new(Arc::clone(&repository)))?
-                .register::<AnonymizeAccountCommand, _>(AnonymizeAccountHandler::new(Arc::clone(&repository)))?
-                .register::<RequestDataExportCommand, _>(RequestDataExportHandler::new(Arc::clone(&repository)))?
-                .register::<AssignRoleCommand, _>(AssignRoleHandler::new(Arc::clone(&repository)))?
-                .register::<RevokeRoleCommand, _>(RevokeRoleHandler::new(Arc::clone(&repository)))?
+            MiddlewarePipeline::new(handlers)
+                .layer(IdempotencyLayer::new(idempotency.clone()))
+                .layer(MetricsLayer::new())
                 .build(),
         );
 
         let query_bus = Arc::new(
-            QueryBusBuilder::new()
-                .register::<GetAccountByIdQuery, _>(GetAccountByIdHandler::new(Arc::clone(&repository)))?
-                .register::<GetAccountByIdentityIdQuery, _>(GetAccountByIdentityIdHandler::new(Arc::clone(&repository)))?
-                .register::<GetAccountStatusQuery, _>(GetAccountStatusHandler::new(Arc::clone(&repository)))?
-                .register::<GetGdprRecordQuery, _>(GetGdprRecordHandler::new(Arc::clone(&repository)))?
-                .register::<ListAccountsByStatusQuery, _>(ListAccountsByStatusHandler::new(Arc::clone(&repository)))?
-                .build(),
+            MiddlewarePipeline::new(
+                QueryBusBuilder::new()
+                    .register::<GetAccountByIdQuery, _>(GetAccountByIdHandler::new(Arc::clone(&repository)))?
+                    .register::<GetAccountByIdentityIdQuery, _>(GetAccountByIdentityIdHandler::new(Arc::clone(&repository)))?
+                    .register::<GetAccountStatusQuery, _>(GetAccountStatusHandler::new(Arc::clone(&repository)))?
+                    .register::<GetGdprRecordQuery, _>(GetGdprRecordHandler::new(Arc::clone(&repository)))?
+                    .register::<DownloadDataExportQuery, _>(DownloadDataExportHandler::new(Arc::clone(&repository), archives, sealer))?
+                    .register::<ListAccountsByStatusQuery, _>(ListAccountsByStatusHandler::new(Arc::clone(&repository)))?
+                    .build(),
+            )
+            .query_layer(MetricsLayer::new())
+            .build(),
         );
 
-        Ok(Self { command_bus, query_bus, repository })
+        Ok(Self { command_bus, query_bus, repository, idempotency, relay })
     }
 }
@@ -1,6 +1,6 @@
 use std::sync::Arc;
 
-use cqrs::{Command, CommandHandler, Envelope};
+use cqrs::{dedup_key, Command, CommandHandler, Envelope};
 use validate_core::Validate;
 
 use crate::application::command::helpers::load_account;
@@ -15,7 +15,9 @@ pub struct AnonymizeAccountCommand {
     pub account_id: String,
 }
 
-impl Command for AnonymizeAccountCommand {}
+impl Command for AnonymizeAccountCommand {
+    type Output = ();
+}
 impl Validate for AnonymizeAccountCommand {}
 
 pub struct AnonymizeAccountHandler {
@@ -34,6 +36,6 @@ impl CommandHandler<AnonymizeAccountCommand> for AnonymizeAccountHandler {
     async fn handle(&self, envelope: Envelope<AnonymizeAccountCommand>) -> Result<(), Self::Error> {
         let mut account = load_account(&self.repo, &envelope.payload.account_id).await?;
         account.anonymize(envelope.correlation_id)?;
-        self.repo.save(&account).await
+        self.repo.save(&account, dedup_key(&envelope)).await
     }
 }
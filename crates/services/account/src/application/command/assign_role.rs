@@ -1,6 +1,6 @@
 use std::sync::Arc;
 
-use cqrs::{Command, CommandHandler, Envelope};
+use cqrs::{dedup_key, Command, CommandHandler, Envelope};
 use validate_core::{FieldViolation, Validate};
 
 use crate::application::command::helpers::load_account;
@@ -15,7 +15,9 @@ pub struct AssignRoleCommand {
     pub role: String,
 }
 
-impl Command for AssignRoleCommand {}
+impl Command for AssignRoleCommand {
+    type Output = ();
+}
 
 impl Validate for AssignRoleCommand {
     fn validate(&self) -> Result<(), Vec<FieldViolation>> {
@@ -48,6 +50,6 @@ impl CommandHandler<AssignRoleCommand> for AssignRoleHandler {
 
         let mut account = load_account(&self.repo, &cmd.account_id).await?;
         account.assign_role(role, envelope.correlation_id)?;
-        self.repo.save(&account).await
+        self.repo.save(&account, dedup_key(&envelope)).await
     }
 }
@@ -1,6 +1,6 @@
 use std::sync::Arc;
 
-use cqrs::{Command, CommandHandler, Envelope};
+use cqrs::{dedup_key, Command, CommandHandler, Envelope};
 use validate_core::{FieldViolation, Validate};
 
 use crate::application::command::helpers::load_account;
@@ -15,7 +15,9 @@ pub struct ChangePasswordCommand {
     pub new_password_hash: String,
 }
 
-impl Command for ChangePasswordCommand {}
+impl Command for ChangePasswordCommand {
+    type Output = ();
+}
 
 impl Validate for ChangePasswordCommand {
     fn validate(&self) -> Result<(), Vec<FieldViolation>> {
@@ -49,6 +51,6 @@ impl CommandHandler<ChangePasswordCommand> for ChangePasswordHandler {
         let mut account = load_account(&self.repo, &cmd.account_id).await?;
         let hash = PasswordHash::from_hash(cmd.new_password_hash.clone());
         account.change_password(hash, envelope.correlation_id)?;
-        self.repo.save(&account).await
+        self.repo.save(&account, dedup_key(&envelope)).await
     }
 }
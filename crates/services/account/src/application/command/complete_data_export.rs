@@ -0,0 +1,60 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use cqrs::{dedup_key, Command, CommandHandler, Envelope};
+use validate_core::Validate;
+
+use crate::application::command::helpers::load_account;
+use crate::application::port::AccountRepository;
+use crate::error::AccountError;
+
+/// Completes the Art. 20 export requested at `requested_at` with the sealed
+/// archive's object-storage reference. Dispatched by the export pipeline once
+/// the archive is written; a superseded request fails with `StaleDataExport`.
+#[derive(Debug, Clone)]
+pub struct CompleteDataExportCommand {
+    pub account_id: String,
+    pub requested_at: DateTime<Utc>,
+    pub download_ref: String,
+    pub expires_at: DateTime<Utc>,
+}
+
+impl Command for CompleteDataExportCommand {
+    type Output = ();
+}
+impl Validate for CompleteDataExportCommand {}
+
+pub struct CompleteDataExportHandler {
+    repo: Arc<dyn AccountRepository>,
+}
+
+impl CompleteDataExportHandler {
+    pub fn new(repo: Arc<dyn AccountRepository>) -> Self {
+        Self { repo }
+    }
+}
+
+impl CommandHandler<CompleteDataExportCommand> for CompleteDataExportHandler {
+    type Error = AccountError;
+
+    async fn handle(
+        &self,
+        envelope: Envelope<CompleteDataExportCommand>,
+    ) -> Result<(), Self::Error> {
+        let key = dedup_key(&envelope);
+        let cmd = envelope.payload;
+        let mut account = load_account(&self.repo, &cmd.account_id).await?;
+        account.complete_gdpr_data_export(
+            cmd.requested_at,
+            cmd.download_ref,
+            cmd.expires_at,
+            envelope.correlation_id,
+        )?;
+        // A replayed completion changes nothing (and bumps no version), so
+        // saving it would only trip the CAS.
+        if account.events().is_empty() {
+            return Ok(());
+        }
+        self.repo.save(&account, key).await
+    }
+}
@@ -0,0 +1,54 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use cqrs::{dedup_key, Command, CommandHandler, Envelope};
+use validate_core::Validate;
+
+use crate::application::command::helpers::load_account;
+use crate::application::port::AccountRepository;
+use crate::error::AccountError;
+
+/// Records that `service` has erased its copy of the subject's data for the
+/// Art. 17 request made at `requested_at`. Sent by each participant's
+/// `gdpr_deletion_requested` consumer once its own erasure has committed;
+/// `AnonymizeAccount` is refused until every participant has confirmed.
+#[derive(Debug, Clone)]
+pub struct ConfirmSubjectErasureCommand {
+    pub account_id: String,
+    pub service: String,
+    pub requested_at: DateTime<Utc>,
+}
+
+impl Command for ConfirmSubjectErasureCommand {
+    type Output = ();
+}
+impl Validate for ConfirmSubjectErasureCommand {}
+
+pub struct ConfirmSubjectErasureHandler {
+    repo: Arc<dyn AccountRepository>,
+}
+
+impl ConfirmSubjectErasureHandler {
+    pub fn new(repo: Arc<dyn AccountRepository>) -> Self {
+        Self { repo }
+    }
+}
+
+impl CommandHandler<ConfirmSubjectErasureCommand> for ConfirmSubjectErasureHandler {
+    type Error = AccountError;
+
+    async fn handle(
+        &self,
+        envelope: Envelope<ConfirmSubjectErasureCommand>,
+    ) -> Result<(), Self::Error> {
+        let key = dedup_key(&envelope);
+        let cmd = envelope.payload;
+        let mut account = load_account(&self.repo, &cmd.account_id).await?;
+        account.confirm_subject_erasure(&cmd.service, cmd.requested_at, envelope.correlation_id)?;
+        // A repeated confirmation changes nothing (and bumps no version).
+        if account.events().is_empty() {
+            return Ok(());
+        }
+        self.repo.save(&account, key).await
+    }
+}
@@ -1,6 +1,6 @@
 use std::sync::Arc;
 
-use cqrs::{Command, CommandHandler, Envelope};
+use cqrs::{dedup_key, Command, CommandHandler, Envelope};
 use validate_core::{FieldViolation, Validate};
 
 use crate::application::port::AccountRepository;
@@ -28,7 +28,9 @@ pub struct CreateAccountCommand {
     pub created_by: Option<String>,
 }
 
-impl Command for CreateAccountCommand {}
+impl Command for CreateAccountCommand {
+    type Output = ();
+}
 
 impl Validate for CreateAccountCommand {
     fn validate(&self) -> Result<(), Vec<FieldViolation>> {
@@ -149,6 +151,6 @@ impl CommandHandler<CreateAccountCommand> for CreateAccountHandler {
         };
 
         let account = Account::create(params);
-        self.repo.save(&account).await
+        self.repo.save(&account, dedup_key(&envelope)).await
     }
 }
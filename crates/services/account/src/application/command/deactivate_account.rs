@@ -1,6 +1,6 @@
 use std::sync::Arc;
 
-use cqrs::{Command, CommandHandler, Envelope};
+use cqrs::{dedup_key, Command, CommandHandler, Envelope};
 use validate_core::Validate;
 
 use crate::application::command::helpers::load_account;
@@ -12,7 +12,9 @@ pub struct DeactivateAccountCommand {
     pub account_id: String,
 }
 
-impl Command for DeactivateAccountCommand {}
+impl Command for DeactivateAccountCommand {
+    type Output = ();
+}
 impl Validate for DeactivateAccountCommand {}
 
 pub struct DeactivateAccountHandler {
@@ -34,6 +36,6 @@ impl CommandHandler<DeactivateAccountCommand> for DeactivateAccountHandler {
     ) -> Result<(), Self::Error> {
         let mut account = load_account(&self.repo, &envelope.payload.account_id).await?;
         account.deactivate(envelope.correlation_id)?;
-        self.repo.save(&account).await
+        self.repo.save(&account, dedup_key(&envelope)).await
     }
 }
@@ -1,6 +1,6 @@
 use std::sync::Arc;
 
-use cqrs::{Command, CommandHandler, Envelope};
+use cqrs::{dedup_key, Command, CommandHandler, Envelope};
 use validate_core::{FieldViolation, Validate};
 
 use crate::application::command::helpers::load_account;
@@ -17,7 +17,9 @@ pub struct EnrollMfaCommand {
     pub recovery_code_hashes: Vec<String>,
 }
 
-impl Command for EnrollMfaCommand {}
+impl Command for EnrollMfaCommand {
+    type Output = ();
+}
 
 impl Validate for EnrollMfaCommand {
     fn validate(&self) -> Result<(), Vec<FieldViolation>> {
@@ -62,6 +64,6 @@ impl CommandHandler<EnrollMfaCommand> for EnrollMfaHandler {
             cmd.recovery_code_hashes.iter().map(|h| RecoveryCodeHash::from_hash(h.clone())).collect();
 
         account.enroll_mfa(secret, codes, envelope.correlation_id)?;
-        self.repo.save(&account).await
+        self.repo.save(&account, dedup_key(&envelope)).await
     }
 }
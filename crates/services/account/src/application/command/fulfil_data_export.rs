@@ -0,0 +1,162 @@
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use chrono::{DateTime, Duration, Utc};
+use cqrs::{Command, CommandHandler, Envelope};
+use futures::future::try_join_all;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use validate_core::Validate;
+
+use crate::application::command::helpers::load_account;
+use crate::application::port::{
+    AccountRepository, ArchiveSealer, ExportArchiveStore, ProfileDirectory, SubjectDataSource,
+};
+use crate::application::query::AccountView;
+use crate::domain::value_object::AccountId;
+use crate::error::AccountError;
+
+/// Version tag of the archive document, bumped on any breaking layout change.
+pub const ARCHIVE_FORMAT: &str = "account-data-export/v1";
+
+/// Produces the Art. 20 export requested at `requested_at`: collects every
+/// owning service's slice, assembles the archive, seals it and writes it to
+/// object storage. Driven by the `gdpr_data_export_requested` consumer, which
+/// then records the result through
+/// [`CompleteDataExportCommand`](super::CompleteDataExportCommand).
+#[derive(Debug, Clone)]
+pub struct FulfilDataExportCommand {
+    pub account_id: String,
+    pub requested_at: DateTime<Utc>,
+}
+
+/// Where a fulfilled export's sealed archive was written, and until when it is kept.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DataExportArchive {
+    pub download_ref: String,
+    pub expires_at: DateTime<Utc>,
+}
+
+impl Command for FulfilDataExportCommand {
+    /// `None` when there is nothing to do: the request was superseded by a newer
+    /// one, or it has already been completed.
+    type Output = Option<DataExportArchive>;
+}
+impl Validate for FulfilDataExportCommand {}
+
+pub struct FulfilDataExportHandler {
+    repo: Arc<dyn AccountRepository>,
+    profiles: Arc<dyn ProfileDirectory>,
+    sources: Vec<Arc<dyn SubjectDataSource>>,
+    sealer: Arc<dyn ArchiveSealer>,
+    store: Arc<dyn ExportArchiveStore>,
+    retention: Duration,
+}
+
+impl FulfilDataExportHandler {
+    /// `retention` is how long the archive stays downloadable once written.
+    pub fn new(
+        repo: Arc<dyn AccountRepository>,
+        profiles: Arc<dyn ProfileDirectory>,
+        sources: Vec<Arc<dyn SubjectDataSource>>,
+        sealer: Arc<dyn ArchiveSealer>,
+        store: Arc<dyn ExportArchiveStore>,
+        retention: Duration,
+    ) -> Self {
+        Self { repo, profiles, sources, sealer, store, retention }
+    }
+}
+
+impl CommandHandler<FulfilDataExportCommand> for FulfilDataExportHandler {
+    type Error = AccountError;
+
+    async fn handle(
+        &self,
+        envelope: Envelope<FulfilDataExportCommand>,
+    ) -> Result<Option<DataExportArchive>, Self::Error> {
+        let cmd = envelope.payload;
+        let account = load_account(&self.repo, &cmd.account_id).await?;
+        let gdpr = account.gdpr();
+        if !gdpr.is_current_export(cmd.requested_at)
+            || gdpr.data_export_completed_at().is_some_and(|at| at >= cmd.requested_at)
+        {
+            return Ok(None);
+        }
+
+        let account_id = account.id();
+        let profile_ids = self.profiles.profile_ids(&account_id).await?;
+        let slices = try_join_all(
+            self.sources.iter().map(|source| source.export(&account_id, &profile_ids)),
+        )
+        .await?;
+
+        let generated_at = Utc::now();
+        let own = serde_json::to_value(AccountView::from(&account))
+            .map_err(|e| AccountError::ExportSealFailed(format!("encode account section: {e}")))?;
+        let mut archive = Archive {
+            format: ARCHIVE_FORMAT,
+            account_id: account_id.to_string(),
+            requested_at: cmd.requested_at,
+            generated_at,
+            profile_ids: &profile_ids,
+            manifest: vec![ManifestEntry {
+                service: "account",
+                record_count: 1,
+                sha256: sha256_hex(own.to_string().as_bytes()),
+            }],
+            sections: BTreeMap::from([("account", own)]),
+        };
+        for (source, slice) in self.sources.iter().zip(slices) {
+            let section = serde_json::from_slice(&slice.document).map_err(|e| {
+                AccountError::ExportSourceRejected {
+                    service: source.service().to_owned(),
+                    reason: format!("export document is not JSON: {e}"),
+                }
+            })?;
+            archive.manifest.push(ManifestEntry {
+                service: source.service(),
+                record_count: slice.record_count,
+                sha256: sha256_hex(&slice.document),
+            });
+            archive.sections.insert(source.service(), section);
+        }
+
+        let document = serde_json::to_vec(&archive)
+            .map_err(|e| AccountError::ExportSealFailed(format!("encode archive: {e}")))?;
+        let sealed = self.sealer.seal(&document)?;
+        let download_ref = archive_key(&account_id, cmd.requested_at);
+        self.store.put(&download_ref, sealed).await?;
+
+        Ok(Some(DataExportArchive { download_ref, expires_at: generated_at + self.retention }))
+    }
+}
+
+/// The archive document: a manifest of every section (its record count and the
+/// digest of the bytes the owning service returned) and the sections themselves.
+#[derive(Serialize)]
+struct Archive<'a> {
+    format: &'static str,
+    account_id: String,
+    requested_at: DateTime<Utc>,
+    generated_at: DateTime<Utc>,
+    profile_ids: &'a [String],
+    manifest: Vec<ManifestEntry>,
+    sections: BTreeMap<&'static str, serde_json::Value>,
+}
+
+#[derive(Serialize)]
+struct ManifestEntry {
+    service: &'static str,
+    record_count: u32,
+    sha256: String,
+}
+
+/// One object per request: a redelivered export overwrites its own archive and
+/// a newer request never clobbers an older one still being downloaded.
+fn archive_key(account_id: &AccountId, requested_at: DateTime<Utc>) -> String {
+    format!("exports/{account_id}/{}.json.sealed", requested_at.timestamp_millis())
+}
+
+fn sha256_hex(bytes: &[u8]) -> String {
+    Sha256::digest(bytes).iter().map(|b| format!("{b:02x}")).collect()
+}
@@ -3,9 +3,12 @@ pub(crate) mod helpers;
 pub mod anonymize_account;
 pub mod assign_role;
 pub mod change_password;
+pub mod complete_data_export;
+pub mod confirm_subject_erasure;
 pub mod create_account;
 pub mod deactivate_account;
 pub mod enroll_mfa;
+pub mod fulfil_data_export;
 pub mod reactivate_account;
 pub mod record_failed_login;
 pub mod record_login;
@@ -16,14 +19,20 @@ pub mod revoke_role;
 pub mod suspend_account;
 pub mod update_kyc_status;
 pub mod verify_email;
+pub mod verify_mfa_factor;
 pub mod verify_phone;
 
 pub use anonymize_account::{AnonymizeAccountCommand, AnonymizeAccountHandler};
 pub use assign_role::{AssignRoleCommand, AssignRoleHandler};
 pub use change_password::{ChangePasswordCommand, ChangePasswordHandler};
+pub use complete_data_export::{CompleteDataExportCommand, CompleteDataExportHandler};
+pub use confirm_subject_erasure::{ConfirmSubjectErasureCommand, ConfirmSubjectErasureHandler};
 pub use create_account::{CreateAccountCommand, CreateAccountHandler};
 pub use deactivate_account::{DeactivateAccountCommand, DeactivateAccountHandler};
 pub use enroll_mfa::{EnrollMfaCommand, EnrollMfaHandler};
+pub use fulfil_data_export::{
+    DataExportArchive, FulfilDataExportCommand, FulfilDataExportHandler,
+};
 pub use reactivate_account::{ReactivateAccountCommand, ReactivateAccountHandler};
 pub use record_failed_login::{RecordFailedLoginCommand, RecordFailedLoginHandler};
 pub use record_login::{RecordLoginCommand, RecordLoginHandler};
@@ -34,4 +43,7 @@ pub use revoke_role::{RevokeRoleCommand, RevokeRoleHandler};
 pub use suspend_account::{SuspendAccountCommand, SuspendAccountHandler};
 pub use update_kyc_status::{UpdateKycStatusCommand, UpdateKycStatusHandler};
 pub use verify_email::{VerifyEmailCommand, VerifyEmailHandler};
+pub use verify_mfa_factor::{
+    MfaFactor, MfaVerification, VerifyMfaFactorCommand, VerifyMfaFactorHandler,
+};
 pub use verify_phone::{VerifyPhoneCommand, VerifyPhoneHandler};
@@ -1,6 +1,6 @@
 use std::sync::Arc;
 
-use cqrs::{Command, CommandHandler, Envelope};
+use cqrs::{dedup_key, Command, CommandHandler, Envelope};
 use validate_core::Validate;
 
 use crate::application::command::helpers::load_account;
@@ -12,7 +12,9 @@ pub struct ReactivateAccountCommand {
     pub account_id: String,
 }
 
-impl Command for ReactivateAccountCommand {}
+impl Command for ReactivateAccountCommand {
+    type Output = ();
+}
 impl Validate for ReactivateAccountCommand {}
 
 pub struct ReactivateAccountHandler {
@@ -34,6 +36,6 @@ impl CommandHandler<ReactivateAccountCommand> for ReactivateAccountHandler {
     ) -> Result<(), Self::Error> {
         let mut account = load_account(&self.repo, &envelope.payload.account_id).await?;
         account.activate(envelope.correlation_id)?;
-        self.repo.save(&account).await
+        self.repo.save(&account, dedup_key(&envelope)).await
     }
 }
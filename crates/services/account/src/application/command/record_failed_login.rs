@@ -1,6 +1,6 @@
 use std::sync::Arc;
 
-use cqrs::{Command, CommandHandler, Envelope};
+use cqrs::{dedup_key, Command, CommandHandler, Envelope};
 use validate_core::Validate;
 
 use crate::application::command::helpers::load_account;
@@ -18,7 +18,9 @@ pub struct RecordFailedLoginCommand {
     pub lockout_duration_secs: u64,
 }
 
-impl Command for RecordFailedLoginCommand {}
+impl Command for RecordFailedLoginCommand {
+    type Output = ();
+}
 impl Validate for RecordFailedLoginCommand {}
 
 pub struct RecordFailedLoginHandler {
@@ -41,6 +43,6 @@ impl CommandHandler<RecordFailedLoginCommand> for RecordFailedLoginHandler {
         let cmd = &envelope.payload;
         let mut account = load_account(&self.repo, &cmd.account_id).await?;
         account.record_failed_login(cmd.max_attempts, cmd.lockout_duration_secs);
-        self.repo.save(&account).await
+        self.repo.save(&account, dedup_key(&envelope)).await
     }
 }
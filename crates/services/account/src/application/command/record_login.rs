@@ -1,6 +1,6 @@
 use std::sync::Arc;
 
-use cqrs::{Command, CommandHandler, Envelope};
+use cqrs::{dedup_key, Command, CommandHandler, Envelope};
 use validate_core::Validate;
 
 use crate::application::command::helpers::load_account;
@@ -14,7 +14,9 @@ pub struct RecordLoginCommand {
     pub account_id: String,
 }
 
-impl Command for RecordLoginCommand {}
+impl Command for RecordLoginCommand {
+    type Output = ();
+}
 impl Validate for RecordLoginCommand {}
 
 pub struct RecordLoginHandler {
@@ -33,6 +35,6 @@ impl CommandHandler<RecordLoginCommand> for RecordLoginHandler {
     async fn handle(&self, envelope: Envelope<RecordLoginCommand>) -> Result<(), Self::Error> {
         let mut account = load_account(&self.repo, &envelope.payload.account_id).await?;
         account.record_login();
-        self.repo.save(&account).await
+        self.repo.save(&account, dedup_key(&envelope)).await
     }
 }
@@ -1,6 +1,6 @@
 use std::sync::Arc;
 
-use cqrs::{Command, CommandHandler, Envelope};
+use cqrs::{dedup_key, Command, CommandHandler, Envelope};
 use validate_core::Validate;
 
 use crate::application::command::helpers::load_account;
@@ -8,13 +8,16 @@ use crate::application::port::AccountRepository;
 use crate::error::AccountError;
 
 /// Records an Art. 20 GDPR data-portability request. The data export pipeline
-/// picks this up asynchronously and sets `data_export_completed_at` when done.
+/// picks this up asynchronously and completes it through
+/// [`CompleteDataExportCommand`](super::CompleteDataExportCommand).
 #[derive(Debug, Clone)]
 pub struct RequestDataExportCommand {
     pub account_id: String,
 }
 
-impl Command for RequestDataExportCommand {}
+impl Command for RequestDataExportCommand {
+    type Output = ();
+}
 impl Validate for RequestDataExportCommand {}
 
 pub struct RequestDataExportHandler {
@@ -36,6 +39,6 @@ impl CommandHandler<RequestDataExportCommand> for RequestDataExportHandler {
     ) -> Result<(), Self::Error> {
         let mut account = load_account(&self.repo, &envelope.payload.account_id).await?;
         account.request_gdpr_data_export(envelope.correlation_id)?;
-        self.repo.save(&account).await
+        self.repo.save(&account, dedup_key(&envelope)).await
     }
 }
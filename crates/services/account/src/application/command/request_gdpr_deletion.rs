@@ -1,14 +1,19 @@
 use std::sync::Arc;
 
-use cqrs::{Command, CommandHandler, Envelope};
+use cqrs::{dedup_key, Command, CommandHandler, Envelope};
 use validate_core::Validate;
 
 use crate::application::command::helpers::load_account;
-use crate::application::port::AccountRepository;
+use crate::application::port::{AccountRepository, ProfileDirectory};
 use crate::error::AccountError;
 
 /// Records an Art. 17 GDPR right-to-erasure request and schedules the
 /// anonymisation deadline at `retention_days` from now.
+///
+/// The account's profiles are resolved here, once, and travel on
+/// `gdpr_deletion_requested`: every erasure participant keyed by profile reads
+/// them from the event, so none of them races profile's own erasure of the
+/// mapping.
 #[derive(Debug, Clone)]
 pub struct RequestGdprDeletionCommand {
     pub account_id: String,
@@ -16,16 +21,19 @@ pub struct RequestGdprDeletionCommand {
     pub retention_days: u32,
 }
 
-impl Command for RequestGdprDeletionCommand {}
+impl Command for RequestGdprDeletionCommand {
+    type Output = ();
+}
 impl Validate for RequestGdprDeletionCommand {}
 
 pub struct RequestGdprDeletionHandler {
     repo: Arc<dyn AccountRepository>,
+    profiles: Arc<dyn ProfileDirectory>,
 }
 
 impl RequestGdprDeletionHandler {
-    pub fn new(repo: Arc<dyn AccountRepository>) -> Self {
-        Self { repo }
+    pub fn new(repo: Arc<dyn AccountRepository>, profiles: Arc<dyn ProfileDirectory>) -> Self {
+        Self { repo, profiles }
     }
 }
 
@@ -38,7 +46,11 @@ impl CommandHandler<RequestGdprDeletionCommand> for RequestGdprDeletionHandler {
     ) -> Result<(), Self::Error> {
         let cmd = &envelope.payload;
         let mut account = load_account(&self.repo, &cmd.account_id).await?;
-        account.request_gdpr_deletion(cmd.retention_days, envelope.correlation_id)?;
-        self.repo.save(&account).await
+        if account.gdpr().has_pending_deletion() {
+            return Err(AccountError::GdprDeletionAlreadyRequested);
+        }
+        let profile_ids = self.profiles.profile_ids(&account.id()).await?;
+        account.request_gdpr_deletion(cmd.retention_days, profile_ids, envelope.correlation_id)?;
+        self.repo.save(&account, dedup_key(&envelope)).await
     }
 }
@@ -1,6 +1,6 @@
 use std::sync::Arc;
 
-use cqrs::{Command, CommandHandler, Envelope};
+use cqrs::{dedup_key, Command, CommandHandler, Envelope};
 use validate_core::Validate;
 
 use crate::application::command::helpers::load_account;
@@ -12,7 +12,9 @@ pub struct RevokeMfaCommand {
     pub account_id: String,
 }
 
-impl Command for RevokeMfaCommand {}
+impl Command for RevokeMfaCommand {
+    type Output = ();
+}
 impl Validate for RevokeMfaCommand {}
 
 pub struct RevokeMfaHandler {
@@ -31,6 +33,6 @@ impl CommandHandler<RevokeMfaCommand> for RevokeMfaHandler {
     async fn handle(&self, envelope: Envelope<RevokeMfaCommand>) -> Result<(), Self::Error> {
         let mut account = load_account(&self.repo, &envelope.payload.account_id).await?;
         account.revoke_mfa(envelope.correlation_id)?;
-        self.repo.save(&account).await
+        self.repo.save(&account, dedup_key(&envelope)).await
     }
 }
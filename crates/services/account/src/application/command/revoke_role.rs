@@ -1,6 +1,6 @@
 use std::sync::Arc;
 
-use cqrs::{Command, CommandHandler, Envelope};
+use cqrs::{dedup_key, Command, CommandHandler, Envelope};
 use validate_core::{FieldViolation, Validate};
 
 use crate::application::command::helpers::load_account;
@@ -14,7 +14,9 @@ pub struct RevokeRoleCommand {
     pub role: String,
 }
 
-impl Command for RevokeRoleCommand {}
+impl Command for RevokeRoleCommand {
+    type Output = ();
+}
 
 impl Validate for RevokeRoleCommand {
     fn validate(&self) -> Result<(), Vec<FieldViolation>> {
@@ -47,6 +49,6 @@ impl CommandHandler<RevokeRoleCommand> for RevokeRoleHandler {
 
         let mut account = load_account(&self.repo, &cmd.account_id).await?;
         account.revoke_role(role, envelope.correlation_id)?;
-        self.repo.save(&account).await
+        self.repo.save(&account, dedup_key(&envelope)).await
     }
 }
@@ -1,6 +1,6 @@
 use std::sync::Arc;
 
-use cqrs::{Command, CommandHandler, Envelope};
+use cqrs::{dedup_key, Command, CommandHandler, Envelope};
 use validate_core::{FieldViolation, Validate};
 
 use crate::application::command::helpers::load_account;
@@ -14,7 +14,9 @@ pub struct SuspendAccountCommand {
     pub reason: String,
 }
 
-impl Command for SuspendAccountCommand {}
+impl Command for SuspendAccountCommand {
+    type Output = ();
+}
 
 impl Validate for SuspendAccountCommand {
     fn validate(&self) -> Result<(), Vec<FieldViolation>> {
@@ -43,6 +45,6 @@ impl CommandHandler<SuspendAccountCommand> for SuspendAccountHandler {
         let cmd = &envelope.payload;
         let mut account = load_account(&self.repo, &cmd.account_id).await?;
         account.suspend(cmd.reason.clone(), envelope.correlation_id)?;
-        self.repo.save(&account).await
+        self.repo.save(&account, dedup_key(&envelope)).await
     }
 }
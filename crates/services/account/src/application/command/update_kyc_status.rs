@@ -1,6 +1,6 @@
 use std::sync::Arc;
 
-use cqrs::{Command, CommandHandler, Envelope};
+use cqrs::{dedup_key, Command, CommandHandler, Envelope};
 use validate_core::{FieldViolation, Validate};
 use uuid::Uuid;
 
@@ -18,7 +18,9 @@ pub struct UpdateKycStatusCommand {
     pub reviewer_id: String,
 }
 
-impl Command for UpdateKycStatusCommand {}
+impl Command for UpdateKycStatusCommand {
+    type Output = ();
+}
 
 impl Validate for UpdateKycStatusCommand {
     fn validate(&self) -> Result<(), Vec<FieldViolation>> {
@@ -69,6 +71,6 @@ impl CommandHandler<UpdateKycStatusCommand> for UpdateKycStatusHandler {
 
         let mut account = load_account(&self.repo, &cmd.account_id).await?;
         account.update_kyc_status(new_status, reviewer_id, envelope.correlation_id)?;
-        self.repo.save(&account).await
+        self.repo.save(&account, dedup_key(&envelope)).await
     }
 }
@@ -1,6 +1,6 @@
 use std::sync::Arc;
 
-use cqrs::{Command, CommandHandler, Envelope};
+use cqrs::{dedup_key, Command, CommandHandler, Envelope};
 use validate_core::Validate;
 
 use crate::application::command::helpers::load_account;
@@ -12,7 +12,9 @@ pub struct VerifyEmailCommand {
     pub account_id: String,
 }
 
-impl Command for VerifyEmailCommand {}
+impl Command for VerifyEmailCommand {
+    type Output = ();
+}
 impl Validate for VerifyEmailCommand {}
 
 pub struct VerifyEmailHandler {
@@ -31,6 +33,6 @@ impl CommandHandler<VerifyEmailCommand> for VerifyEmailHandler {
     async fn handle(&self, envelope: Envelope<VerifyEmailCommand>) -> Result<(), Self::Error> {
         let mut account = load_account(&self.repo, &envelope.payload.account_id).await?;
         account.verify_email(envelope.correlation_id)?;
-        self.repo.save(&account).await
+        self.repo.save(&account, dedup_key(&envelope)).await
     }
 }
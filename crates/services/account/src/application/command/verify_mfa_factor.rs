@@ -0,0 +1,115 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+use cqrs::{dedup_key, Command, CommandHandler, Envelope};
+use serde::{Deserialize, Serialize};
+use validate_core::{FieldViolation, Validate};
+
+use crate::application::command::helpers::load_account;
+use crate::application::port::{AccountRepository, SecondFactorVerifier};
+use crate::error::AccountError;
+
+/// The second factor presented at login or step-up.
+#[derive(Debug, Clone)]
+pub enum MfaFactor {
+    /// The authenticator app's current 6-digit code.
+    Totp(String),
+    /// One of the enrolment's one-time recovery codes; spent on success.
+    RecoveryCode(String),
+}
+
+/// Checks a second factor for `auth`. A wrong factor is an answer, not an
+/// error: the outcome says `verified: false` and nothing is stored. A replayed
+/// TOTP code — its time step not after the last accepted one — is answered the
+/// same way.
+#[derive(Debug, Clone)]
+pub struct VerifyMfaFactorCommand {
+    pub account_id: String,
+    pub factor: MfaFactor,
+}
+
+/// Outcome of a [`VerifyMfaFactorCommand`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MfaVerification {
+    pub verified: bool,
+    /// Unspent recovery codes left after this check.
+    pub recovery_codes_remaining: u32,
+}
+
+impl Command for VerifyMfaFactorCommand {
+    type Output = MfaVerification;
+}
+
+impl Validate for VerifyMfaFactorCommand {
+    fn validate(&self) -> Result<(), Vec<FieldViolation>> {
+        let blank = match &self.factor {
+            MfaFactor::Totp(code) | MfaFactor::RecoveryCode(code) => code.trim().is_empty(),
+        };
+        if blank {
+            return Err(vec![FieldViolation::new(
+                "factor",
+                "VAL-2022",
+                "a TOTP code or a recovery code is required",
+            )]);
+        }
+        Ok(())
+    }
+}
+
+pub struct VerifyMfaFactorHandler {
+    repo: Arc<dyn AccountRepository>,
+    verifier: Arc<dyn SecondFactorVerifier>,
+}
+
+impl VerifyMfaFactorHandler {
+    pub fn new(repo: Arc<dyn AccountRepository>, verifier: Arc<dyn SecondFactorVerifier>) -> Self {
+        Self { repo, verifier }
+    }
+}
+
+impl CommandHandler<VerifyMfaFactorCommand> for VerifyMfaFactorHandler {
+    type Error = AccountError;
+
+    async fn handle(
+        &self,
+        envelope: Envelope<VerifyMfaFactorCommand>,
+    ) -> Result<MfaVerification, Self::Error> {
+        let key = dedup_key(&envelope);
+        let cmd = envelope.payload;
+        let mut account = load_account(&self.repo, &cmd.account_id).await?;
+        let secret = account.totp_secret_to_verify()?;
+
+        let verified = match &cmd.factor {
+            MfaFactor::Totp(code) => {
+                match self.verifier.matching_totp_step(secret, code, Utc::now()).await? {
+                    Some(step) => {
+                        let fresh = account.accept_totp_step(step)?;
+                        if fresh {
+                            self.repo.save(&account, key).await?;
+                        }
+                        fresh
+                    }
+                    None => false,
+                }
+            }
+            MfaFactor::RecoveryCode(code) => {
+                let stored = account.mfa().recovery_codes();
+                match self.verifier.matching_recovery_code(code, stored).await {
+                    Some(hash) => {
+                        let spent = account.consume_recovery_code(&hash)?;
+                        if spent {
+                            self.repo.save(&account, key).await?;
+                        }
+                        spent
+                    }
+                    None => false,
+                }
+            }
+        };
+
+        Ok(MfaVerification {
+            verified,
+            recovery_codes_remaining: account.mfa().recovery_codes().len() as u32,
+        })
+    }
+}
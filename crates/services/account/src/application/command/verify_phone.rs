@@ -1,6 +1,6 @@
 use std::sync::Arc;
 
-use cqrs::{Command, CommandHandler, Envelope};
+use cqrs::{dedup_key, Command, CommandHandler, Envelope};
 use validate_core::Validate;
 
 use crate::application::command::helpers::load_account;
@@ -12,7 +12,9 @@ pub struct VerifyPhoneCommand {
     pub account_id: String,
 }
 
-impl Command for VerifyPhoneCommand {}
+impl Command for VerifyPhoneCommand {
+    type Output = ();
+}
 impl Validate for VerifyPhoneCommand {}
 
 pub struct VerifyPhoneHandler {
@@ -31,6 +33,6 @@ impl CommandHandler<VerifyPhoneCommand> for VerifyPhoneHandler {
     async fn handle(&self, envelope: Envelope<VerifyPhoneCommand>) -> Result<(), Self::Error> {
         let mut account = load_account(&self.repo, &envelope.payload.account_id).await?;
         account.verify_phone(envelope.correlation_id)?;
-        self.repo.save(&account).await
+        self.repo.save(&account, dedup_key(&envelope)).await
     }
 }
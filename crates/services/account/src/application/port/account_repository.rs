@@ -1,4 +1,5 @@
 use async_trait::async_trait;
+use uuid::Uuid;
 
 use crate::domain::aggregate::Account;
 use crate::domain::value_object::{AccountId, AccountStatus, EmailAddress, IdentityId};
@@ -15,13 +16,22 @@ use crate::error::AccountError;
 /// `save` must enforce the aggregate's `version` counter: it should issue an
 /// `UPDATE ... WHERE version = $old_version` and return
 /// [`AccountError::OptimisticLockConflict`] when zero rows are affected.
+///
+/// # Idempotency
+///
+/// `save` is handed the dispatch's idempotency key (`cqrs::dedup_key`) and must
+/// mark it processed in the same transaction as the write, so a crash between
+/// the commit and the idempotency layer's `complete` cannot re-run the command.
+/// A key already marked processed fails the save with
+/// [`AccountError::ConcurrentModification`]: the retry is answered by the layer.
 #[async_trait]
 pub trait AccountRepository: Send + Sync + 'static {
     /// Upserts the account aggregate state.
     ///
     /// New accounts (version == 0) are inserted; existing accounts are updated
-    /// with optimistic-lock protection on the version column.
-    async fn save(&self, account: &Account) -> Result<(), AccountError>;
+    /// with optimistic-lock protection on the version column. `idempotency_key`
+    /// is marked processed in the same transaction.
+    async fn save(&self, account: &Account, idempotency_key: Uuid) -> Result<(), AccountError>;
 
     /// Returns the account with `id`, or `None` if it does not exist.
     async fn find_by_id(&self, id: &AccountId) -> Result<Option<Account>, AccountError>;
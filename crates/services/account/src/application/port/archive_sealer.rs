@@ -0,0 +1,13 @@
+use crate::error::AccountError;
+
+/// Seals an assembled export archive before it leaves the service: encrypted
+/// under a per-archive key and signed, so the stored object is neither readable
+/// nor forgeable by the bucket's operators.
+pub trait ArchiveSealer: Send + Sync + 'static {
+    /// The sealed envelope for `archive`, ready to store.
+    fn seal(&self, archive: &[u8]) -> Result<Vec<u8>, AccountError>;
+
+    /// Verifies and decrypts an envelope produced by [`seal`](Self::seal), for
+    /// serving the archive to its owner. A tampered or foreign envelope fails.
+    fn open(&self, sealed: &[u8]) -> Result<Vec<u8>, AccountError>;
+}
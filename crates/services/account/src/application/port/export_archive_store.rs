@@ -0,0 +1,16 @@
+use async_trait::async_trait;
+
+use crate::error::AccountError;
+
+/// Object storage for sealed export archives (S3/MinIO in production).
+#[async_trait]
+pub trait ExportArchiveStore: Send + Sync + 'static {
+    /// Writes `sealed` under `key`, replacing any previous object — a redelivered
+    /// export rewrites the same key. An unreachable store fails with the
+    /// retryable [`AccountError::ExportStoreUnavailable`].
+    async fn put(&self, key: &str, sealed: Vec<u8>) -> Result<(), AccountError>;
+
+    /// Reads the sealed archive under `key`; `None` once the bucket's lifecycle
+    /// rule has purged it.
+    async fn get(&self, key: &str) -> Result<Option<Vec<u8>>, AccountError>;
+}
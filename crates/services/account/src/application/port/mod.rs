@@ -1,5 +1,13 @@
 pub mod account_repository;
-pub mod event_publisher;
+pub mod archive_sealer;
+pub mod export_archive_store;
+pub mod profile_directory;
+pub mod second_factor_verifier;
+pub mod subject_data_source;
 
 pub use account_repository::AccountRepository;
-pub use event_publisher::EventPublisher;
+pub use archive_sealer::ArchiveSealer;
+pub use export_archive_store::ExportArchiveStore;
+pub use profile_directory::ProfileDirectory;
+pub use second_factor_verifier::SecondFactorVerifier;
+pub use subject_data_source::{SubjectDataSlice, SubjectDataSource};
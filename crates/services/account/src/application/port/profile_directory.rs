@@ -0,0 +1,13 @@
+use async_trait::async_trait;
+
+use crate::domain::value_object::AccountId;
+use crate::error::AccountError;
+
+/// Resolves the profiles an account owns. Profile is the owner of that mapping;
+/// the export pipeline and the erasure request need it because most services
+/// key their data by profile.
+#[async_trait]
+pub trait ProfileDirectory: Send + Sync + 'static {
+    /// Every profile id of `account_id`, including hidden ones.
+    async fn profile_ids(&self, account_id: &AccountId) -> Result<Vec<String>, AccountError>;
+}
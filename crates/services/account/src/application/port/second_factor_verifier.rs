@@ -0,0 +1,30 @@
+//! The crypto behind a second-factor check: unsealing the TOTP seed and
+//! matching recovery codes against their hashes. The adapter lives in
+//! `infrastructure::mfa`.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use crate::domain::value_object::{EncryptedBytes, RecoveryCodeHash};
+use crate::error::AccountError;
+
+#[async_trait]
+pub trait SecondFactorVerifier: Send + Sync + 'static {
+    /// The time step at which `code` is the TOTP of the seed sealed in
+    /// `secret`, if any, looking at `at` and one step of clock drift either
+    /// way. A seed that no longer unseals fails with
+    /// [`AccountError::MfaSecretUnreadable`].
+    async fn matching_totp_step(
+        &self,
+        secret: &EncryptedBytes,
+        code: &str,
+        at: DateTime<Utc>,
+    ) -> Result<Option<i64>, AccountError>;
+
+    /// The stored hash `code` matches, if any.
+    async fn matching_recovery_code(
+        &self,
+        code: &str,
+        hashes: &[RecoveryCodeHash],
+    ) -> Option<RecoveryCodeHash>;
+}
@@ -0,0 +1,32 @@
+//! The fan-out port of the data-export pipeline: one implementation per owning
+//! service, each answering that service's `ExportSubjectData` contract. The gRPC
+//! adapters live in `infrastructure::export`.
+
+use async_trait::async_trait;
+
+use crate::domain::value_object::AccountId;
+use crate::error::AccountError;
+
+/// One service's slice of a person's data: its JSON export document, verbatim,
+/// and the number of records it holds.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SubjectDataSlice {
+    pub document: Vec<u8>,
+    pub record_count: u32,
+}
+
+#[async_trait]
+pub trait SubjectDataSource: Send + Sync + 'static {
+    /// The owning service, used as the slice's section name in the archive.
+    fn service(&self) -> &'static str;
+
+    /// Exports everything the service holds about `account_id` and its
+    /// `profile_ids`. An unreachable service fails with the retryable
+    /// [`AccountError::ExportSourceUnavailable`]; a refusal with
+    /// [`AccountError::ExportSourceRejected`].
+    async fn export(
+        &self,
+        account_id: &AccountId,
+        profile_ids: &[String],
+    ) -> Result<SubjectDataSlice, AccountError>;
+}
@@ -0,0 +1,72 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use cqrs::{Envelope, Query, QueryHandler};
+
+use crate::application::command::helpers::load_account;
+use crate::application::port::{AccountRepository, ArchiveSealer, ExportArchiveStore};
+use crate::error::AccountError;
+
+/// The opened archive of the account's latest completed export.
+#[derive(Debug, Clone)]
+pub struct DataExportDownload {
+    /// The archive document (`account-data-export/v1` JSON), decrypted and
+    /// signature-checked.
+    pub archive: Vec<u8>,
+    pub expires_at: DateTime<Utc>,
+}
+
+/// Serves the Art. 20 archive to its owner. The caller is the account holder;
+/// the gRPC layer checks that before dispatching.
+#[derive(Debug, Clone)]
+pub struct DownloadDataExportQuery {
+    pub account_id: String,
+}
+
+impl Query for DownloadDataExportQuery {
+    type Response = DataExportDownload;
+}
+
+pub struct DownloadDataExportHandler {
+    repo: Arc<dyn AccountRepository>,
+    store: Arc<dyn ExportArchiveStore>,
+    sealer: Arc<dyn ArchiveSealer>,
+}
+
+impl DownloadDataExportHandler {
+    pub fn new(
+        repo: Arc<dyn AccountRepository>,
+        store: Arc<dyn ExportArchiveStore>,
+        sealer: Arc<dyn ArchiveSealer>,
+    ) -> Self {
+        Self { repo, store, sealer }
+    }
+}
+
+impl QueryHandler<DownloadDataExportQuery> for DownloadDataExportHandler {
+    type Error = AccountError;
+
+    async fn handle(
+        &self,
+        envelope: Envelope<DownloadDataExportQuery>,
+    ) -> Result<DataExportDownload, Self::Error> {
+        let account = load_account(&self.repo, &envelope.payload.account_id).await?;
+        let gdpr = account.gdpr();
+        let (Some(download_ref), Some(expires_at)) =
+            (gdpr.data_export_download_ref(), gdpr.data_export_expires_at())
+        else {
+            return Err(AccountError::DataExportNotAvailable);
+        };
+        // The bucket's lifecycle rule runs on its own schedule; the recorded
+        // expiry is what the owner was promised, so it is enforced here.
+        if Utc::now() >= expires_at {
+            return Err(AccountError::DataExportExpired { expired_at: expires_at.to_rfc3339() });
+        }
+        let sealed = self
+            .store
+            .get(download_ref)
+            .await?
+            .ok_or(AccountError::DataExportNotAvailable)?;
+        Ok(DataExportDownload { archive: self.sealer.open(&sealed)?, expires_at })
+    }
+}
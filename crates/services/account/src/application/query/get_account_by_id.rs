@@ -2,6 +2,7 @@ use std::sync::Arc;
 
 use chrono::{DateTime, Utc};
 use cqrs::{Envelope, Query, QueryHandler};
+use serde::Serialize;
 
 use crate::application::port::AccountRepository;
 use crate::domain::aggregate::Account;
@@ -12,8 +13,9 @@ use uuid::Uuid;
 /// Flat read-model of an Account, safe to send over the wire.
 ///
 /// All value objects are serialised to their string/primitive representations
-/// so the caller (gRPC mapper, REST controller) needs no domain imports.
-#[derive(Debug, Clone)]
+/// so the caller (gRPC mapper, REST controller, data-export archive) needs no
+/// domain imports.
+#[derive(Debug, Clone, Serialize)]
 pub struct AccountView {
     pub id: String,
     pub identity_id: String,
@@ -16,8 +16,14 @@ pub struct GdprRecordView {
     pub deletion_requested_at: Option<DateTime<Utc>>,
     pub deletion_scheduled_at: Option<DateTime<Utc>>,
     pub anonymized_at: Option<DateTime<Utc>>,
+    /// Participants that have confirmed erasure for the pending request.
+    pub erasure_confirmed_by: Vec<String>,
+    /// Participants still to confirm; empty when no deletion is pending.
+    pub erasure_pending: Vec<String>,
     pub data_export_requested_at: Option<DateTime<Utc>>,
     pub data_export_completed_at: Option<DateTime<Utc>>,
+    pub data_export_download_ref: Option<String>,
+    pub data_export_expires_at: Option<DateTime<Utc>>,
     pub last_consent_version: Option<String>,
 }
 
@@ -67,8 +73,16 @@ impl QueryHandler<GetGdprRecordQuery> for GetGdprRecordHandler {
             deletion_requested_at: gdpr.deletion_requested_at(),
             deletion_scheduled_at: gdpr.deletion_scheduled_at(),
             anonymized_at: gdpr.anonymized_at(),
+            erasure_confirmed_by: gdpr.erasure_confirmed_by().iter().cloned().collect(),
+            erasure_pending: if gdpr.has_pending_deletion() {
+                gdpr.pending_erasure().into_iter().map(str::to_owned).collect()
+            } else {
+                Vec::new()
+            },
             data_export_requested_at: gdpr.data_export_requested_at(),
             data_export_completed_at: gdpr.data_export_completed_at(),
+            data_export_download_ref: gdpr.data_export_download_ref().map(str::to_owned),
+            data_export_expires_at: gdpr.data_export_expires_at(),
             last_consent_version: gdpr.last_consent_version().map(str::to_owned),
         })
     }
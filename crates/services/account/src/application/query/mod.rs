@@ -1,3 +1,4 @@
+pub mod download_data_export;
 pub mod get_account_by_id;
 pub mod get_account_by_identity_id;
 pub mod get_account_status;
@@ -5,12 +6,14 @@ pub mod get_gdpr_record;
 pub mod list_accounts_by_status;
 
 // ── View types ────────────────────────────────────────────────────────────────
+pub use download_data_export::DataExportDownload;
 pub use get_account_by_id::AccountView;
 pub use get_account_status::AccountStatusView;
 pub use get_gdpr_record::GdprRecordView;
 pub use list_accounts_by_status::AccountListView;
 
 // ── Query types ───────────────────────────────────────────────────────────────
+pub use download_data_export::{DownloadDataExportHandler, DownloadDataExportQuery};
 pub use get_account_by_id::{GetAccountByIdHandler, GetAccountByIdQuery};
 pub use get_account_by_identity_id::{
     GetAccountByIdentityIdHandler, GetAccountByIdentityIdQuery,
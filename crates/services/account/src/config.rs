@@ -0,0 +1,120 @@
+//! Environment-sourced configuration of the GDPR paths and the MFA key, resolved
+//! once at boot in [`crate::service`]. The rest of account reads its backends'
+//! `from_env` directly; only the export fan-out, the profile lookup and the MFA
+//! key have knobs of their own.
+
+use std::time::Duration;
+
+use base64::Engine as _;
+use sha2::{Digest, Sha256};
+
+use crate::infrastructure::export::ExportStoreConfig;
+
+const DEFAULT_RETENTION_DAYS: u64 = 7;
+const DEFAULT_PRESIGN_TTL_S: u64 = 900;
+const DEFAULT_OBJECT_TIMEOUT_MS: u64 = 10_000;
+
+/// Where each owning service's `ExportSubjectData` is served.
+pub struct ExportEndpoints {
+    pub profile: String,
+    pub post: String,
+    pub comment: String,
+    pub chat: String,
+    pub social_graph: String,
+    pub engagement: String,
+    pub notification: String,
+    pub media: String,
+}
+
+/// Fully-resolved data-export configuration.
+pub struct ExportConfig {
+    pub endpoints: ExportEndpoints,
+    pub store: ExportStoreConfig,
+    /// How long a written archive stays downloadable (the completed record's
+    /// `expires_at`, enforced on download); the bucket's lifecycle rule purges
+    /// it afterwards.
+    pub retention: Duration,
+    /// Key-encryption key (32 bytes) wrapping each archive's random DEK.
+    pub kek: [u8; 32],
+    /// HMAC-SHA256 key signing the sealed envelope.
+    pub signing_key: [u8; 32],
+}
+
+impl ExportConfig {
+    pub fn from_env() -> Self {
+        Self {
+            endpoints: ExportEndpoints {
+                profile: env_str("ACCOUNT_EXPORT_PROFILE_ENDPOINT", "http://profile:50052"),
+                post: env_str("ACCOUNT_EXPORT_POST_ENDPOINT", "http://post:50056"),
+                comment: env_str("ACCOUNT_EXPORT_COMMENT_ENDPOINT", "http://comment:50057"),
+                chat: env_str("ACCOUNT_EXPORT_CHAT_ENDPOINT", "http://chat:50051"),
+                social_graph: env_str(
+                    "ACCOUNT_EXPORT_SOCIAL_GRAPH_ENDPOINT",
+                    "http://social-graph:50053",
+                ),
+                engagement: env_str("ACCOUNT_EXPORT_ENGAGEMENT_ENDPOINT", "http://engagement:50058"),
+                notification: env_str(
+                    "ACCOUNT_EXPORT_NOTIFICATION_ENDPOINT",
+                    "http://notification:50055",
+                ),
+                media: env_str("ACCOUNT_EXPORT_MEDIA_ENDPOINT", "http://media:50063"),
+            },
+            store: ExportStoreConfig {
+                endpoint: env_str("ACCOUNT_EXPORT_OBJECT_STORE_ENDPOINT", "http://localhost:9000"),
+                region: env_str("ACCOUNT_EXPORT_OBJECT_STORE_REGION", "us-east-1"),
+                bucket: env_str("ACCOUNT_EXPORT_OBJECT_STORE_BUCKET", "account-data-exports"),
+                access_key: env_str("ACCOUNT_EXPORT_OBJECT_STORE_ACCESS_KEY", "minioadmin"),
+                secret_key: env_str("ACCOUNT_EXPORT_OBJECT_STORE_SECRET_KEY", "minioadmin"),
+                presign_ttl: Duration::from_secs(env_u64(
+                    "ACCOUNT_EXPORT_OBJECT_STORE_PRESIGN_TTL_S",
+                    DEFAULT_PRESIGN_TTL_S,
+                )),
+                request_timeout: Duration::from_millis(env_u64(
+                    "ACCOUNT_EXPORT_OBJECT_STORE_TIMEOUT_MS",
+                    DEFAULT_OBJECT_TIMEOUT_MS,
+                )),
+            },
+            retention: Duration::from_secs(
+                env_u64("ACCOUNT_EXPORT_RETENTION_DAYS", DEFAULT_RETENTION_DAYS) * 24 * 60 * 60,
+            ),
+            kek: key_from_env("ACCOUNT_EXPORT_KEK_BASE64", b"account-dev-export-kek-do-not-use-in-prod"),
+            signing_key: key_from_env(
+                "ACCOUNT_EXPORT_SIGNING_KEY_BASE64",
+                b"account-dev-export-signing-key-do-not-use-in-prod",
+            ),
+        }
+    }
+}
+
+/// Key (32 bytes) the enrolled TOTP seeds are sealed under — the MFA key
+/// `VerifyMfaFactor` unseals them with.
+pub fn mfa_kek() -> [u8; 32] {
+    key_from_env("ACCOUNT_MFA_KEK_BASE64", b"account-dev-mfa-kek-do-not-use-in-prod")
+}
+
+/// Where profile's `ListProfilesByAccount` is served for erasure requests
+/// (resilience binding `profile`).
+pub fn profile_endpoint() -> String {
+    env_str("ACCOUNT_PROFILE_ENDPOINT", "http://profile:50052")
+}
+
+/// Resolve a 32-byte key from `var` (base64 of exactly 32 bytes). Absent or
+/// malformed → a deterministic **dev** key derived from `dev_phrase` (sha256),
+/// so local/test runs work; this MUST be overridden in production.
+fn key_from_env(var: &str, dev_phrase: &[u8]) -> [u8; 32] {
+    if let Ok(encoded) = std::env::var(var)
+        && let Ok(bytes) = base64::engine::general_purpose::STANDARD.decode(encoded.trim())
+        && let Ok(key) = <[u8; 32]>::try_from(bytes.as_slice())
+    {
+        return key;
+    }
+    Sha256::digest(dev_phrase).into()
+}
+
+fn env_u64(key: &str, default: u64) -> u64 {
+    std::env::var(key).ok().and_then(|v| v.parse().ok()).unwrap_or(default)
+}
+
+fn env_str(key: &str, default: &str) -> String {
+    std::env::var(key).unwrap_or_else(|_| default.to_owned())
+}
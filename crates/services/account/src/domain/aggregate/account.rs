@@ -2,10 +2,11 @@ use chrono::{DateTime, Duration, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-use crate::domain::entity::{GdprRecord, MfaState};
+use crate::domain::entity::{GdprRecord, MfaState, ERASURE_PARTICIPANTS};
 use crate::domain::event::{
     AccountActivated, AccountCreated, AccountDeactivated, AccountDeleted, AccountSuspended,
-    DomainEvent, EmailChanged, EmailVerified, GdprDataExportRequested, GdprDeletionRequested,
+    DomainEvent, EmailChanged, EmailVerified, GdprDataExportCompleted, GdprDataExportRequested,
+    GdprDeletionRequested, GdprErasureConfirmed,
     KycStatusChanged, MfaEnrolled, MfaRevoked, PasswordChanged, PhoneChanged, RoleAssigned,
     RoleRevoked,
 };
@@ -371,6 +372,43 @@ impl Account {
         Ok(())
     }
 
+    /// The sealed TOTP seed a presented second factor is checked against.
+    ///
+    /// Requires `Active` status and enrolled MFA.
+    pub fn totp_secret_to_verify(&self) -> Result<&EncryptedBytes, AccountError> {
+        self.require_active()?;
+        self.mfa.totp_secret().ok_or(AccountError::MfaNotEnrolled)
+    }
+
+    /// Accepts a TOTP code that matched at time step `step`; `false` when
+    /// that step is at or before the last one accepted — a replayed code
+    /// (nothing changes).
+    ///
+    /// Requires `Active` status and enrolled MFA. Emits nothing — the login it
+    /// completes is `auth`'s to report.
+    pub fn accept_totp_step(&mut self, step: i64) -> Result<bool, AccountError> {
+        self.totp_secret_to_verify()?;
+        if !self.mfa.accept_totp_step(step) {
+            return Ok(false);
+        }
+        self.touch_now();
+        Ok(true)
+    }
+
+    /// Spends the recovery code stored as `code_hash`; `false` when it is not
+    /// an unspent code (nothing changes).
+    ///
+    /// Requires `Active` status and enrolled MFA. Emits nothing — the login it
+    /// completes is `auth`'s to report.
+    pub fn consume_recovery_code(&mut self, code_hash: &RecoveryCodeHash) -> Result<bool, AccountError> {
+        self.totp_secret_to_verify()?;
+        if !self.mfa.consume_recovery_code(code_hash.as_str()) {
+            return Ok(false);
+        }
+        self.touch_now();
+        Ok(true)
+    }
+
     /// Assigns a role to this account. Emits [`RoleAssigned`].
     pub fn assign_role(
         &mut self,
@@ -513,20 +551,27 @@ impl Account {
 
     /// Records a GDPR Art. 17 erasure request and schedules anonymisation.
     ///
+    /// `profile_ids` are the account's profiles at request time; they travel on
+    /// the event for the participants that key the subject's data by profile.
+    ///
     /// Emits [`GdprDeletionRequested`].
     pub fn request_gdpr_deletion(
         &mut self,
         retention_days: u32,
+        profile_ids: Vec<String>,
         correlation_id: Uuid,
     ) -> Result<(), AccountError> {
         if self.gdpr.has_pending_deletion() {
             return Err(AccountError::GdprDeletionAlreadyRequested);
         }
         self.gdpr.request_deletion(retention_days);
+        let requested_at = self.gdpr.deletion_requested_at.expect("just set");
         let scheduled = self.gdpr.deletion_scheduled_at.expect("just set");
         let now = self.touch_now();
         self.pending_events.push(DomainEvent::GdprDeletionRequested(GdprDeletionRequested {
             account_id: self.id,
+            requested_at,
+            profile_ids,
             retention_days,
             scheduled_deletion_at: scheduled,
             occurred_at: now,
@@ -552,14 +597,100 @@ impl Account {
         Ok(())
     }
 
+    /// Records delivery of the export for the request made at `requested_at`:
+    /// the archive's `download_ref` and the instant it expires.
+    ///
+    /// Only the latest request can be completed — an older one is answered with
+    /// [`AccountError::StaleDataExport`] since its archive is superseded.
+    /// Completing the same request with the same reference again is a no-op, so
+    /// a redelivered export event does not emit a second completion.
+    ///
+    /// Emits [`GdprDataExportCompleted`].
+    pub fn complete_gdpr_data_export(
+        &mut self,
+        requested_at: DateTime<Utc>,
+        download_ref: String,
+        expires_at: DateTime<Utc>,
+        correlation_id: Uuid,
+    ) -> Result<(), AccountError> {
+        if !self.gdpr.is_current_export(requested_at) {
+            return Err(AccountError::StaleDataExport { requested_at: requested_at.to_rfc3339() });
+        }
+        if self.gdpr.data_export_download_ref.as_deref() == Some(download_ref.as_str()) {
+            return Ok(());
+        }
+        let now = self.touch_now();
+        self.gdpr.data_export_completed_at = Some(now);
+        self.gdpr.data_export_download_ref = Some(download_ref.clone());
+        self.gdpr.data_export_expires_at = Some(expires_at);
+        self.pending_events.push(DomainEvent::GdprDataExportCompleted(
+            GdprDataExportCompleted {
+                account_id: self.id,
+                requested_at,
+                download_ref,
+                expires_at,
+                occurred_at: now,
+                correlation_id,
+            },
+        ));
+        Ok(())
+    }
+
+    /// Records that `service` has erased (or pseudonymised) its copy of the
+    /// subject's data for the deletion request made at `requested_at`.
+    ///
+    /// Only the pending request can be confirmed, and only by one of the
+    /// [`ERASURE_PARTICIPANTS`]. A repeated confirmation from the same service is
+    /// a no-op, so a redelivered deletion event does not emit twice.
+    ///
+    /// Emits [`GdprErasureConfirmed`].
+    pub fn confirm_subject_erasure(
+        &mut self,
+        service: &str,
+        requested_at: DateTime<Utc>,
+        correlation_id: Uuid,
+    ) -> Result<(), AccountError> {
+        if !ERASURE_PARTICIPANTS.contains(&service) {
+            return Err(AccountError::UnknownErasureParticipant(service.to_owned()));
+        }
+        if !self.gdpr.is_current_deletion(requested_at) {
+            return Err(AccountError::StaleErasureConfirmation {
+                requested_at: requested_at.to_rfc3339(),
+            });
+        }
+        if !self.gdpr.erasure_confirmed_by.insert(service.to_owned()) {
+            return Ok(());
+        }
+        let now = self.touch_now();
+        self.pending_events.push(DomainEvent::GdprErasureConfirmed(GdprErasureConfirmed {
+            account_id: self.id,
+            requested_at,
+            service: service.to_owned(),
+            occurred_at: now,
+            correlation_id,
+        }));
+        Ok(())
+    }
+
     /// Anonymises the account: clears PII fields and marks as deleted.
     ///
     /// Called by the GDPR janitor worker once `deletion_scheduled_at` has elapsed.
+    /// Refused with [`AccountError::ErasureIncomplete`] until every one of the
+    /// [`ERASURE_PARTICIPANTS`] has confirmed erasure of the pending request.
     /// Emits [`AccountDeleted`].
     pub fn anonymize(&mut self, correlation_id: Uuid) -> Result<(), AccountError> {
         if self.gdpr.is_anonymized() {
             return Err(AccountError::AccountAlreadyAnonymized);
         }
+        if !self.gdpr.has_pending_deletion() {
+            return Err(AccountError::ErasureIncomplete {
+                pending: "no deletion has been requested".to_owned(),
+            });
+        }
+        let pending = self.gdpr.pending_erasure();
+        if !pending.is_empty() {
+            return Err(AccountError::ErasureIncomplete { pending: pending.join(", ") });
+        }
         let now = Utc::now();
         self.gdpr.anonymized_at = Some(now);
         self.phone = None;
@@ -832,4 +963,120 @@ mod tests {
         );
         assert!(account.effective_permissions().is_empty());
     }
+
+    /// Enrolment turns enforcement on; each recovery code is spent exactly once.
+    #[test]
+    fn enrolled_mfa_is_enforced_and_recovery_codes_spend_once() {
+        let mut account = admin_account_with_overrides(Vec::new());
+        assert!(matches!(account.totp_secret_to_verify(), Err(AccountError::MfaNotEnrolled)));
+
+        let codes: Vec<RecoveryCodeHash> =
+            (0..6).map(|i| RecoveryCodeHash::from_hash(format!("$2b$hash-{i}"))).collect();
+        account
+            .enroll_mfa(EncryptedBytes::from_ciphertext(vec![1; 40]), codes.clone(), Uuid::now_v7())
+            .expect("enroll");
+        assert!(account.mfa().enforced());
+        assert!(account.totp_secret_to_verify().is_ok());
+
+        let version = account.version();
+        assert!(account.consume_recovery_code(&codes[2]).expect("consume"));
+        assert!(!account.consume_recovery_code(&codes[2]).expect("replay"));
+        assert_eq!(account.mfa().recovery_codes().len(), 5);
+        assert!(account.mfa().backup_verified_at().is_some());
+        assert_eq!(account.version(), version + 1);
+
+        account.revoke_mfa(Uuid::now_v7()).expect("revoke");
+        assert!(!account.mfa().enforced());
+    }
+
+    /// A TOTP step is accepted once; it and every earlier step are refused
+    /// afterwards, and re-enrolment starts the count over.
+    #[test]
+    fn totp_steps_are_accepted_once_and_in_order() {
+        let mut account = admin_account_with_overrides(Vec::new());
+        assert!(matches!(account.accept_totp_step(10), Err(AccountError::MfaNotEnrolled)));
+        let secret = EncryptedBytes::from_ciphertext(vec![1; 40]);
+        account.enroll_mfa(secret.clone(), Vec::new(), Uuid::now_v7()).expect("enroll");
+
+        let version = account.version();
+        assert!(account.accept_totp_step(10).expect("accept"));
+        assert!(!account.accept_totp_step(10).expect("replay"));
+        assert!(!account.accept_totp_step(9).expect("older step"));
+        assert_eq!(account.version(), version + 1);
+        assert!(account.accept_totp_step(11).expect("next step"));
+        assert_eq!(account.mfa().totp_last_step(), Some(11));
+
+        account.revoke_mfa(Uuid::now_v7()).expect("revoke");
+        account.enroll_mfa(secret, Vec::new(), Uuid::now_v7()).expect("re-enroll");
+        assert_eq!(account.mfa().totp_last_step(), None);
+    }
+
+    /// Completion is bound to the latest request: an older `requested_at` is
+    /// stale, and replaying the current one with the same reference emits nothing.
+    #[test]
+    fn data_export_completes_only_the_latest_request_once() {
+        let mut account = admin_account_with_overrides(Vec::new());
+        account.request_gdpr_data_export(Uuid::now_v7()).expect("request");
+        let requested_at = account.gdpr().data_export_requested_at().expect("requested");
+        let expires_at = requested_at + Duration::days(7);
+
+        let stale = account.complete_gdpr_data_export(
+            requested_at - Duration::seconds(1),
+            "exports/old".to_owned(),
+            expires_at,
+            Uuid::now_v7(),
+        );
+        assert!(matches!(stale, Err(AccountError::StaleDataExport { .. })));
+
+        for _ in 0..2 {
+            account
+                .complete_gdpr_data_export(requested_at, "exports/a".to_owned(), expires_at, Uuid::now_v7())
+                .expect("complete");
+        }
+        assert_eq!(account.gdpr().data_export_download_ref(), Some("exports/a"));
+        assert_eq!(account.gdpr().data_export_expires_at(), Some(expires_at));
+        let completions = account
+            .events()
+            .iter()
+            .filter(|e| matches!(e, DomainEvent::GdprDataExportCompleted(_)))
+            .count();
+        assert_eq!(completions, 1);
+    }
+
+    /// Anonymisation waits for every participant; a confirmation must name the
+    /// pending request and a known participant, and confirming twice is a no-op.
+    #[test]
+    fn anonymize_requires_every_erasure_confirmation() {
+        let mut account = admin_account_with_overrides(Vec::new());
+        account.request_gdpr_deletion(0, Vec::new(), Uuid::now_v7()).expect("request");
+        let requested_at = account.gdpr().deletion_requested_at().expect("requested");
+
+        assert!(matches!(
+            account.confirm_subject_erasure("ledger", requested_at, Uuid::now_v7()),
+            Err(AccountError::UnknownErasureParticipant(_))
+        ));
+        assert!(matches!(
+            account.confirm_subject_erasure("post", requested_at - Duration::seconds(1), Uuid::now_v7()),
+            Err(AccountError::StaleErasureConfirmation { .. })
+        ));
+
+        let (last, rest) = ERASURE_PARTICIPANTS.split_last().expect("participants");
+        for service in rest {
+            account.confirm_subject_erasure(service, requested_at, Uuid::now_v7()).expect("confirm");
+        }
+        account.confirm_subject_erasure(rest[0], requested_at, Uuid::now_v7()).expect("reconfirm");
+        match account.anonymize(Uuid::now_v7()) {
+            Err(AccountError::ErasureIncomplete { pending }) => assert_eq!(pending, *last),
+            other => panic!("expected ErasureIncomplete, got {other:?}"),
+        }
+
+        account.confirm_subject_erasure(last, requested_at, Uuid::now_v7()).expect("confirm");
+        account.anonymize(Uuid::now_v7()).expect("anonymize");
+        let confirmations = account
+            .events()
+            .iter()
+            .filter(|e| matches!(e, DomainEvent::GdprErasureConfirmed(_)))
+            .count();
+        assert_eq!(confirmations, ERASURE_PARTICIPANTS.len());
+    }
 }
@@ -1,6 +1,23 @@
+use std::collections::BTreeSet;
+
 use chrono::{DateTime, Duration, Utc};
 use serde::{Deserialize, Serialize};
 
+/// Every context that holds personal data of its own and must confirm its
+/// erasure before the account may be anonymised (Art. 17). Each one consumes
+/// `gdpr_deletion_requested` and reports back through `ConfirmSubjectErasure`.
+pub const ERASURE_PARTICIPANTS: &[&str] = &[
+    "profile",
+    "post",
+    "comment",
+    "chat",
+    "social-graph",
+    "engagement",
+    "notification",
+    "search",
+    "media",
+];
+
 /// GDPR and data-protection state for an account.
 ///
 /// Tracks consent timestamps, erasure requests, and anonymisation status.
@@ -36,11 +53,23 @@ pub struct GdprRecord {
     /// `Deleted`.
     pub anonymized_at: Option<DateTime<Utc>>,
 
+    /// Contexts (from [`ERASURE_PARTICIPANTS`]) that have confirmed erasure of
+    /// the subject's data for the current deletion request.
+    pub erasure_confirmed_by: BTreeSet<String>,
+
     /// Art. 20: Right to Data Portability — timestamp of the export request.
     pub data_export_requested_at: Option<DateTime<Utc>>,
 
     /// Timestamp at which the data export was delivered to the account holder.
     pub data_export_completed_at: Option<DateTime<Utc>>,
+
+    /// Object-storage reference of the sealed export archive for the latest
+    /// completed request.
+    pub data_export_download_ref: Option<String>,
+
+    /// After this instant the archive is no longer served to its owner, and the
+    /// bucket's lifecycle rule purges it.
+    pub data_export_expires_at: Option<DateTime<Utc>>,
 }
 
 impl GdprRecord {
@@ -54,8 +83,11 @@ impl GdprRecord {
         deletion_requested_at: Option<DateTime<Utc>>,
         deletion_scheduled_at: Option<DateTime<Utc>>,
         anonymized_at: Option<DateTime<Utc>>,
+        erasure_confirmed_by: BTreeSet<String>,
         data_export_requested_at: Option<DateTime<Utc>>,
         data_export_completed_at: Option<DateTime<Utc>>,
+        data_export_download_ref: Option<String>,
+        data_export_expires_at: Option<DateTime<Utc>>,
     ) -> Self {
         Self {
             data_processing_consented_at,
@@ -65,8 +97,11 @@ impl GdprRecord {
             deletion_requested_at,
             deletion_scheduled_at,
             anonymized_at,
+            erasure_confirmed_by,
             data_export_requested_at,
             data_export_completed_at,
+            data_export_download_ref,
+            data_export_expires_at,
         }
     }
 
@@ -77,8 +112,11 @@ impl GdprRecord {
     pub fn deletion_requested_at(&self) -> Option<DateTime<Utc>> { self.deletion_requested_at }
     pub fn deletion_scheduled_at(&self) -> Option<DateTime<Utc>> { self.deletion_scheduled_at }
     pub fn anonymized_at(&self) -> Option<DateTime<Utc>> { self.anonymized_at }
+    pub fn erasure_confirmed_by(&self) -> &BTreeSet<String> { &self.erasure_confirmed_by }
     pub fn data_export_requested_at(&self) -> Option<DateTime<Utc>> { self.data_export_requested_at }
     pub fn data_export_completed_at(&self) -> Option<DateTime<Utc>> { self.data_export_completed_at }
+    pub fn data_export_download_ref(&self) -> Option<&str> { self.data_export_download_ref.as_deref() }
+    pub fn data_export_expires_at(&self) -> Option<DateTime<Utc>> { self.data_export_expires_at }
 
     /// Returns `true` once the PII scrub has completed.
     pub fn is_anonymized(&self) -> bool {
@@ -90,6 +128,34 @@ impl GdprRecord {
         self.deletion_requested_at.is_some() && self.anonymized_at.is_none()
     }
 
+    /// Returns `true` if `requested_at` identifies the pending deletion request.
+    ///
+    /// Same sub-millisecond tolerance as [`is_current_export`](Self::is_current_export).
+    pub fn is_current_deletion(&self, requested_at: DateTime<Utc>) -> bool {
+        self.has_pending_deletion()
+            && self
+                .deletion_requested_at
+                .is_some_and(|latest| (latest - requested_at).abs() < Duration::milliseconds(1))
+    }
+
+    /// Participants that have not yet confirmed erasure, in declaration order.
+    pub fn pending_erasure(&self) -> Vec<&'static str> {
+        ERASURE_PARTICIPANTS
+            .iter()
+            .copied()
+            .filter(|service| !self.erasure_confirmed_by.contains(*service))
+            .collect()
+    }
+
+    /// Returns `true` if `requested_at` identifies the latest export request.
+    ///
+    /// Tolerates sub-millisecond drift: the event carries the clock's full
+    /// precision while Postgres stores microseconds.
+    pub fn is_current_export(&self, requested_at: DateTime<Utc>) -> bool {
+        self.data_export_requested_at
+            .is_some_and(|latest| (latest - requested_at).abs() < Duration::milliseconds(1))
+    }
+
     /// Records an erasure request and schedules the anonymisation deadline.
     ///
     /// `retention_days` is the legal retention period that must elapse before
@@ -99,5 +165,6 @@ impl GdprRecord {
         let now = Utc::now();
         self.deletion_requested_at = Some(now);
         self.deletion_scheduled_at = Some(now + Duration::days(i64::from(retention_days)));
+        self.erasure_confirmed_by.clear();
     }
 }
@@ -22,6 +22,10 @@ pub struct MfaState {
     /// Timestamp at which TOTP was first successfully enrolled.
     pub totp_enrolled_at: Option<DateTime<Utc>>,
 
+    /// RFC 6238 time step of the last accepted TOTP code.
+    /// Codes at or before it are refused, so an accepted code cannot be replayed.
+    pub totp_last_step: Option<i64>,
+
     /// Hashed one-time recovery codes.
     /// Each code is consumed exactly once and removed from this list.
     pub recovery_codes: Vec<RecoveryCodeHash>,
@@ -36,15 +40,24 @@ impl MfaState {
         enforced: bool,
         totp_secret: Option<EncryptedBytes>,
         totp_enrolled_at: Option<DateTime<Utc>>,
+        totp_last_step: Option<i64>,
         recovery_codes: Vec<RecoveryCodeHash>,
         backup_verified_at: Option<DateTime<Utc>>,
     ) -> Self {
-        Self { enforced, totp_secret, totp_enrolled_at, recovery_codes, backup_verified_at }
+        Self {
+            enforced,
+            totp_secret,
+            totp_enrolled_at,
+            totp_last_step,
+            recovery_codes,
+            backup_verified_at,
+        }
     }
 
     pub fn enforced(&self) -> bool { self.enforced }
     pub fn totp_secret(&self) -> Option<&EncryptedBytes> { self.totp_secret.as_ref() }
     pub fn totp_enrolled_at(&self) -> Option<DateTime<Utc>> { self.totp_enrolled_at }
+    pub fn totp_last_step(&self) -> Option<i64> { self.totp_last_step }
     pub fn recovery_codes(&self) -> &[RecoveryCodeHash] { &self.recovery_codes }
     pub fn backup_verified_at(&self) -> Option<DateTime<Utc>> { self.backup_verified_at }
 
@@ -68,17 +81,33 @@ impl MfaState {
         }
     }
 
-    /// Enrolls TOTP: stores the encrypted secret and the initial recovery codes.
+    /// Records a TOTP code accepted at time step `step`.
+    ///
+    /// Returns `false` (and changes nothing) when `step` is at or before the
+    /// last accepted one — the code, or an older one, has already been used.
+    pub fn accept_totp_step(&mut self, step: i64) -> bool {
+        if self.totp_last_step.is_some_and(|last| step <= last) {
+            return false;
+        }
+        self.totp_last_step = Some(step);
+        true
+    }
+
+    /// Enrolls TOTP: stores the encrypted secret and the initial recovery codes,
+    /// and turns enforcement on — from here every login asks for a second factor.
     pub fn enroll(&mut self, secret: EncryptedBytes, recovery_codes: Vec<RecoveryCodeHash>) {
         self.totp_secret = Some(secret);
         self.recovery_codes = recovery_codes;
         self.totp_enrolled_at = Some(Utc::now());
+        self.totp_last_step = None;
+        self.enforced = true;
     }
 
     /// Revokes all MFA state, returning the account to an unenrolled state.
     pub fn revoke(&mut self) {
         self.totp_secret = None;
         self.totp_enrolled_at = None;
+        self.totp_last_step = None;
         self.recovery_codes.clear();
         self.backup_verified_at = None;
         self.enforced = false;
@@ -1,5 +1,5 @@
 pub mod gdpr_record;
 pub mod mfa_state;
 
-pub use gdpr_record::GdprRecord;
+pub use gdpr_record::{GdprRecord, ERASURE_PARTICIPANTS};
 pub use mfa_state::MfaState;
@@ -0,0 +1,15 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::value_object::AccountId;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GdprDataExportCompleted {
+    pub account_id: AccountId,
+    pub requested_at: DateTime<Utc>,
+    pub download_ref: String,
+    pub expires_at: DateTime<Utc>,
+    pub occurred_at: DateTime<Utc>,
+    pub correlation_id: Uuid,
+}
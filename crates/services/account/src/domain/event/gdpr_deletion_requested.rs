@@ -7,6 +7,11 @@ use crate::domain::value_object::AccountId;
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GdprDeletionRequested {
     pub account_id: AccountId,
+    /// Identifies the request: every participant echoes it back when it
+    /// confirms erasure.
+    pub requested_at: DateTime<Utc>,
+    /// The account's profiles when erasure was requested.
+    pub profile_ids: Vec<String>,
     pub retention_days: u32,
     pub scheduled_deletion_at: DateTime<Utc>,
     pub occurred_at: DateTime<Utc>,
@@ -0,0 +1,14 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::value_object::AccountId;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GdprErasureConfirmed {
+    pub account_id: AccountId,
+    pub requested_at: DateTime<Utc>,
+    pub service: String,
+    pub occurred_at: DateTime<Utc>,
+    pub correlation_id: Uuid,
+}
@@ -5,8 +5,10 @@ pub mod account_deleted;
 pub mod account_suspended;
 pub mod email_changed;
 pub mod email_verified;
+pub mod gdpr_data_export_completed;
 pub mod gdpr_data_export_requested;
 pub mod gdpr_deletion_requested;
+pub mod gdpr_erasure_confirmed;
 pub mod kyc_status_changed;
 pub mod mfa_enrolled;
 pub mod mfa_revoked;
@@ -22,8 +24,10 @@ pub use account_deleted::AccountDeleted;
 pub use account_suspended::AccountSuspended;
 pub use email_changed::EmailChanged;
 pub use email_verified::EmailVerified;
+pub use gdpr_data_export_completed::GdprDataExportCompleted;
 pub use gdpr_data_export_requested::GdprDataExportRequested;
 pub use gdpr_deletion_requested::GdprDeletionRequested;
+pub use gdpr_erasure_confirmed::GdprErasureConfirmed;
 pub use kyc_status_changed::KycStatusChanged;
 pub use mfa_enrolled::MfaEnrolled;
 pub use mfa_revoked::MfaRevoked;
@@ -56,7 +60,9 @@ pub enum DomainEvent {
     AccountDeleted(AccountDeleted),
     KycStatusChanged(KycStatusChanged),
     GdprDeletionRequested(GdprDeletionRequested),
+    GdprErasureConfirmed(GdprErasureConfirmed),
     GdprDataExportRequested(GdprDataExportRequested),
+    GdprDataExportCompleted(GdprDataExportCompleted),
 }
 
 impl DomainEvent {
@@ -77,7 +83,9 @@ impl DomainEvent {
             Self::AccountDeleted(_)          => "account.deleted",
             Self::KycStatusChanged(_)        => "account.kyc_status_changed",
             Self::GdprDeletionRequested(_)   => "account.gdpr_deletion_requested",
+            Self::GdprErasureConfirmed(_)    => "account.gdpr_erasure_confirmed",
             Self::GdprDataExportRequested(_) => "account.gdpr_data_export_requested",
+            Self::GdprDataExportCompleted(_) => "account.gdpr_data_export_completed",
         }
     }
 }
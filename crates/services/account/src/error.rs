@@ -17,9 +17,20 @@ use thiserror::Error;
 /// | ACC-4001 | ConcurrentModification     | 409  | High     | **Yes**   |
 /// | ACC-5001 | MfaAlreadyEnrolled         | 409  | Low      | No        |
 /// | ACC-5002 | MfaNotEnrolled             | 422  | Low      | No        |
+/// | ACC-5003 | MfaSecretUnreadable        | 500  | High     | No        |
 /// | ACC-6001 | InvalidKycTransition       | 422  | Medium   | No        |
 /// | ACC-7001 | GdprDeletionAlreadyReq.    | 409  | Low      | No        |
 /// | ACC-7002 | AccountAlreadyAnonymized   | 422  | Low      | No        |
+/// | ACC-7003 | StaleDataExport            | 409  | Low      | No        |
+/// | ACC-7004 | ExportSourceUnavailable    | 503  | Medium   | **Yes**   |
+/// | ACC-7005 | ExportSourceRejected       | 502  | Medium   | No        |
+/// | ACC-7006 | ExportStoreUnavailable     | 503  | Medium   | **Yes**   |
+/// | ACC-7007 | ExportSealFailed           | 500  | High     | No        |
+/// | ACC-7008 | StaleErasureConfirmation   | 422  | Low      | No        |
+/// | ACC-7009 | UnknownErasureParticipant  | 422  | Low      | No        |
+/// | ACC-7010 | ErasureIncomplete          | 422  | Medium   | No        |
+/// | ACC-7011 | DataExportNotAvailable     | 404  | Low      | No        |
+/// | ACC-7012 | DataExportExpired          | 422  | Low      | No        |
 /// | ACC-8001 | RoleAlreadyAssigned        | 409  | Low      | No        |
 /// | ACC-8002 | RoleNotAssigned            | 422  | Low      | No        |
 /// | ACC-9001 | DomainViolation            | 422  | Medium   | No        |
@@ -79,6 +90,9 @@ pub enum AccountError {
     #[error("MFA is not enrolled for this account")]
     MfaNotEnrolled,
 
+    #[error("the enrolled TOTP seed cannot be unsealed: {0}")]
+    MfaSecretUnreadable(String),
+
     // ── KYC (ACC-6xxx) ────────────────────────────────────────────────────────
 
     #[error("KYC status transition from '{from}' to '{to}' is not permitted")]
@@ -92,6 +106,36 @@ pub enum AccountError {
     #[error("this account has already been anonymized")]
     AccountAlreadyAnonymized,
 
+    #[error("data export requested at {requested_at} is not the account's latest export request")]
+    StaleDataExport { requested_at: String },
+
+    #[error("data export source '{service}' is unavailable: {reason}")]
+    ExportSourceUnavailable { service: String, reason: String },
+
+    #[error("data export source '{service}' rejected the export: {reason}")]
+    ExportSourceRejected { service: String, reason: String },
+
+    #[error("data export archive store is unavailable: {0}")]
+    ExportStoreUnavailable(String),
+
+    #[error("failed to seal the data export archive: {0}")]
+    ExportSealFailed(String),
+
+    #[error("erasure confirmation for {requested_at} does not match the account's pending deletion request")]
+    StaleErasureConfirmation { requested_at: String },
+
+    #[error("'{0}' is not a GDPR erasure participant")]
+    UnknownErasureParticipant(String),
+
+    #[error("subject erasure is not complete; still pending: {pending}")]
+    ErasureIncomplete { pending: String },
+
+    #[error("no data export archive is available for this account")]
+    DataExportNotAvailable,
+
+    #[error("the data export archive expired at {expired_at}")]
+    DataExportExpired { expired_at: String },
+
     // ── Roles (ACC-8xxx) ──────────────────────────────────────────────────────
 
     #[error("role '{0}' is already assigned to this account")]
@@ -151,11 +195,22 @@ impl AppError for AccountError {
 
             AccountError::MfaAlreadyEnrolled               => "ACC-5001",
             AccountError::MfaNotEnrolled                   => "ACC-5002",
+            AccountError::MfaSecretUnreadable(_)           => "ACC-5003",
 
             AccountError::InvalidKycTransition { .. }      => "ACC-6001",
 
             AccountError::GdprDeletionAlreadyRequested     => "ACC-7001",
             AccountError::AccountAlreadyAnonymized         => "ACC-7002",
+            AccountError::StaleDataExport { .. }           => "ACC-7003",
+            AccountError::ExportSourceUnavailable { .. }   => "ACC-7004",
+            AccountError::ExportSourceRejected { .. }      => "ACC-7005",
+            AccountError::ExportStoreUnavailable(_)        => "ACC-7006",
+            AccountError::ExportSealFailed(_)              => "ACC-7007",
+            AccountError::StaleErasureConfirmation { .. }  => "ACC-7008",
+            AccountError::UnknownErasureParticipant(_)     => "ACC-7009",
+            AccountError::ErasureIncomplete { .. }         => "ACC-7010",
+            AccountError::DataExportNotAvailable           => "ACC-7011",
+            AccountError::DataExportExpired { .. }         => "ACC-7012",
 
             AccountError::RoleAlreadyAssigned(_)           => "ACC-8001",
             AccountError::RoleNotAssigned(_)               => "ACC-8002",
@@ -178,7 +233,8 @@ impl AppError for AccountError {
             AccountError::Storage(e)    => e.http_status(),
             AccountError::Validation(e) => e.http_status(),
 
-            AccountError::AccountNotFound { .. } => StatusCode::NOT_FOUND,
+            AccountError::AccountNotFound { .. }
+            | AccountError::DataExportNotAvailable => StatusCode::NOT_FOUND,
 
             AccountError::IdentityAlreadyRegistered { .. }
             | AccountError::EmailAlreadyRegistered { .. }
@@ -186,9 +242,17 @@ impl AppError for AccountError {
             | AccountError::ConcurrentModification
             | AccountError::MfaAlreadyEnrolled
             | AccountError::GdprDeletionAlreadyRequested
+            | AccountError::StaleDataExport { .. }
             | AccountError::RoleAlreadyAssigned(_) => StatusCode::CONFLICT,
 
-            AccountError::EventPublishFailed(_) => StatusCode::INTERNAL_SERVER_ERROR,
+            AccountError::ExportSourceUnavailable { .. }
+            | AccountError::ExportStoreUnavailable(_) => StatusCode::SERVICE_UNAVAILABLE,
+
+            AccountError::ExportSourceRejected { .. } => StatusCode::BAD_GATEWAY,
+
+            AccountError::EventPublishFailed(_)
+            | AccountError::ExportSealFailed(_)
+            | AccountError::MfaSecretUnreadable(_) => StatusCode::INTERNAL_SERVER_ERROR,
 
             _ => StatusCode::UNPROCESSABLE_ENTITY,
         }
@@ -199,11 +263,17 @@ impl AppError for AccountError {
             AccountError::Storage(e)    => e.severity(),
             AccountError::Validation(e) => e.severity(),
 
-            AccountError::ConcurrentModification => Severity::High,
+            AccountError::ConcurrentModification
+            | AccountError::ExportSealFailed(_)
+            | AccountError::MfaSecretUnreadable(_) => Severity::High,
 
             AccountError::AccountNotActive { .. }
             | AccountError::InvalidStatusTransition { .. }
             | AccountError::InvalidKycTransition { .. }
+            | AccountError::ExportSourceUnavailable { .. }
+            | AccountError::ExportSourceRejected { .. }
+            | AccountError::ExportStoreUnavailable(_)
+            | AccountError::ErasureIncomplete { .. }
             | AccountError::DomainViolation { .. } => Severity::Medium,
 
             _ => Severity::Low,
@@ -214,6 +284,8 @@ impl AppError for AccountError {
         match self {
             AccountError::Storage(e)             => e.is_retryable(),
             AccountError::ConcurrentModification => true,
+            AccountError::ExportSourceUnavailable { .. }
+            | AccountError::ExportStoreUnavailable(_) => true,
             _                                    => false,
         }
     }
@@ -240,9 +312,20 @@ impl AppError for AccountError {
             AccountError::ConcurrentModification           => "The account was modified concurrently. Please retry.",
             AccountError::MfaAlreadyEnrolled               => "Multi-factor authentication is already set up.",
             AccountError::MfaNotEnrolled                   => "Multi-factor authentication is not configured.",
+            AccountError::MfaSecretUnreadable(_)           => "Multi-factor authentication could not be checked.",
             AccountError::InvalidKycTransition { .. }      => "This KYC status transition is not permitted.",
             AccountError::GdprDeletionAlreadyRequested     => "A deletion request has already been submitted.",
             AccountError::AccountAlreadyAnonymized         => "This account has already been anonymized.",
+            AccountError::StaleDataExport { .. }           => "A newer data export request supersedes this one.",
+            AccountError::ExportSourceUnavailable { .. }
+            | AccountError::ExportStoreUnavailable(_)      => "The data export is temporarily unavailable. It will be retried.",
+            AccountError::ExportSourceRejected { .. }
+            | AccountError::ExportSealFailed(_)            => "The data export could not be produced.",
+            AccountError::StaleErasureConfirmation { .. }  => "This erasure confirmation does not match the pending deletion request.",
+            AccountError::UnknownErasureParticipant(_)     => "This service does not take part in account erasure.",
+            AccountError::ErasureIncomplete { .. }         => "The account cannot be anonymized until every service has erased its data.",
+            AccountError::DataExportNotAvailable           => "No data export is ready for this account.",
+            AccountError::DataExportExpired { .. }         => "This data export has expired. Request a new one.",
             AccountError::RoleAlreadyAssigned(_)           => "This role is already assigned to the account.",
             AccountError::RoleNotAssigned(_)               => "This role is not assigned to the account.",
             _                                              => "A domain constraint was violated.",
@@ -0,0 +1,87 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use cqrs::{CommandBus, CommandHandler, Envelope};
+use error::AppError;
+use serde::Deserialize;
+use tracing::{error, info};
+use uuid::Uuid;
+
+use transport::kafka::consumer::{run_consumer, KafkaConsumerHandle, ProcessOutcome, RetryPolicy};
+use transport::kafka::producer::KafkaProducerHandle;
+
+use crate::application::command::{
+    CompleteDataExportCommand, FulfilDataExportCommand, FulfilDataExportHandler,
+};
+use crate::error::AccountError;
+
+/// Lenient wire view of `account.v1.events`: only the export request is read,
+/// every other event kind decodes to `Other` and is committed as a no-op.
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum AccountEventWire {
+    GdprDataExportRequested { account_id: String, requested_at: DateTime<Utc> },
+    #[serde(other)]
+    Other,
+}
+
+/// Runs the data-export consumer: each `gdpr_data_export_requested` is fulfilled
+/// by `handler` (fan-out, seal, store) and then completed on the account through
+/// `command_bus`.
+///
+/// Generic over `CB` because `CommandBus` is not object-safe. Returns when the
+/// stream ends or on an unrecoverable broker/dead-letter error; the supervising
+/// task respawns it.
+pub async fn run_data_export_consumer<CB: CommandBus + 'static>(
+    consumer: KafkaConsumerHandle,
+    handler: Arc<FulfilDataExportHandler>,
+    command_bus: CB,
+    producer: KafkaProducerHandle,
+) {
+    info!("account data-export consumer started");
+    let command_bus = Arc::new(command_bus);
+    let policy = RetryPolicy::default();
+    let result = run_consumer::<AccountEventWire, _>(&consumer, &producer, &policy, move |event| {
+        let handler = Arc::clone(&handler);
+        let command_bus = Arc::clone(&command_bus);
+        Box::pin(async move { process(handler.as_ref(), command_bus.as_ref(), event).await })
+    })
+    .await;
+    if let Err(e) = result {
+        error!(error = %e, "account data-export consumer stopped");
+    }
+}
+
+/// Fulfils one export request. A superseded or already-completed request is a
+/// committed no-op; so is a completion that loses the race to a newer request,
+/// whose own event produces the archive the person will download.
+async fn process<CB: CommandBus>(
+    handler: &FulfilDataExportHandler,
+    command_bus: &CB,
+    event: &AccountEventWire,
+) -> ProcessOutcome {
+    let AccountEventWire::GdprDataExportRequested { account_id, requested_at } = event else {
+        return ProcessOutcome::Done;
+    };
+    let correlation_id = Uuid::now_v7();
+    let fulfil = FulfilDataExportCommand { account_id: account_id.clone(), requested_at: *requested_at };
+    let archive = match handler.handle(Envelope::new(correlation_id, fulfil)).await {
+        Ok(Some(archive)) => archive,
+        Ok(None) => return ProcessOutcome::Done,
+        Err(e) => return ProcessOutcome::from_result(Err::<(), AccountError>(e)),
+    };
+    info!(account_id = %account_id, download_ref = %archive.download_ref, "data export archived");
+
+    let complete = CompleteDataExportCommand {
+        account_id: account_id.clone(),
+        requested_at: *requested_at,
+        download_ref: archive.download_ref,
+        expires_at: archive.expires_at,
+    };
+    match command_bus.dispatch(Envelope::new(correlation_id, complete)).await {
+        Ok(()) => ProcessOutcome::Done,
+        Err(e) if e.error_code() == "ACC-7003" => ProcessOutcome::Done,
+        Err(e) if e.is_retryable() => ProcessOutcome::Retry(e.to_string()),
+        Err(e) => ProcessOutcome::Reject(e.to_string()),
+    }
+}
@@ -0,0 +1,21 @@
+//! Inbound consumers. Account self-consumes `account.v1.events` for the one
+//! asynchronous workflow it owns: `data_export_consumer` fulfils GDPR Art. 20
+//! export requests. Runs on the shared at-least-once
+//! [`run_consumer`](transport::kafka::consumer::run_consumer) runner and is
+//! spawned + supervised in [`crate::service`].
+
+pub mod data_export_consumer;
+
+pub use data_export_consumer::run_data_export_consumer;
+
+use crate::error::AccountError;
+
+/// Lets the at-least-once runner classify a failure: delegate to the error's own
+/// [`AppError::is_retryable`](error::AppError::is_retryable) verdict — an
+/// unreachable export source or bucket retries; a rejected export is poison and
+/// dead-letters immediately.
+impl transport::kafka::consumer::ClassifyError for AccountError {
+    fn is_retryable(&self) -> bool {
+        <Self as error::AppError>::is_retryable(self)
+    }
+}
@@ -1,22 +1,41 @@
-//! Event-publication adapters for the `account.v1.events` topic.
+//! Event publication for the `account.v1.events` topic.
 //!
 //! Every account domain event shares one topic, keyed by `account_id` so a
 //! person's account events keep per-account order on one partition; the
-//! `event_type` header carries the dotted routing key. The repository drains the
-//! aggregate's events after a successful durable write and publishes them here.
+//! `event_type` header carries the dotted routing key. The repository enqueues
+//! the aggregate's events into `account_outbox` **in the same transaction** as
+//! the row write, and the shared `outbox` relay publishes them — an account
+//! change and its event commit (or roll back) together.
 
-pub mod kafka_event_publisher;
-pub mod log_event_publisher;
-
-pub use kafka_event_publisher::KafkaEventPublisher;
-pub use log_event_publisher::LogEventPublisher;
+use outbox::{OutboxError, OutboxMessage};
 
 use crate::domain::event::DomainEvent;
 use crate::domain::value_object::AccountId;
+use crate::error::AccountError;
 
 /// The single Kafka topic every account domain event is published to.
 pub const TOPIC_ACCOUNT_EVENTS: &str = "account.v1.events";
 
+/// Prefix of the `account_outbox` table (and its `_lease` / `_member` companions).
+pub const OUTBOX_PREFIX: &str = "account";
+
+/// The outbox row for `event`: topic, `account_id` key and header, JSON payload.
+pub(crate) fn outbox_message(event: &DomainEvent) -> Result<OutboxMessage, AccountError> {
+    let key = event_key(event).as_str();
+    Ok(OutboxMessage::new(TOPIC_ACCOUNT_EVENTS, key.clone(), event.event_type(), event)
+        .map_err(outbox_err)?
+        .with_header("account_id", key))
+}
+
+/// A failed enqueue is a failed write (it ran in the write's transaction); only a
+/// payload that cannot serialize is reported as a publish failure.
+pub(crate) fn outbox_err(e: OutboxError) -> AccountError {
+    match e {
+        OutboxError::Storage(e) => AccountError::Storage(e),
+        other => AccountError::EventPublishFailed(other.to_string()),
+    }
+}
+
 /// The partition key (account id) for an event — keeps per-account ordering.
 pub(crate) fn event_key(event: &DomainEvent) -> AccountId {
     match event {
@@ -35,6 +54,20 @@ pub(crate) fn event_key(event: &DomainEvent) -> AccountId {
         DomainEvent::AccountDeleted(e) => e.account_id,
         DomainEvent::KycStatusChanged(e) => e.account_id,
         DomainEvent::GdprDeletionRequested(e) => e.account_id,
+        DomainEvent::GdprErasureConfirmed(e) => e.account_id,
         DomainEvent::GdprDataExportRequested(e) => e.account_id,
+        DomainEvent::GdprDataExportCompleted(e) => e.account_id,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// `account.v1.events` carries exactly the payload registered for it in event-topology.
+    #[test]
+    fn account_events_conform_to_the_registered_schema() {
+        let violations = event_topology::conformance::producer_violations::<DomainEvent>(TOPIC_ACCOUNT_EVENTS);
+        assert!(violations.is_empty(), "{violations:#?}");
     }
 }
@@ -0,0 +1,17 @@
+//! Adapters of the GDPR Art. 20 data-export pipeline: the per-service
+//! `ExportSubjectData` sources and the profile directory (`sources`), the
+//! archive envelope (`envelope`) and the archive bucket (`object_store`). The
+//! pipeline itself is [`FulfilDataExportHandler`](crate::application::command::FulfilDataExportHandler),
+//! driven by [`crate::infrastructure::consumer`].
+
+pub mod envelope;
+pub mod object_store;
+pub mod sources;
+
+pub use envelope::EnvelopeSealer;
+pub use object_store::{ExportStoreConfig, S3ExportArchiveStore};
+pub use sources::{
+    ChatExportSource, CommentExportSource, EngagementExportSource, GrpcProfileDirectory,
+    MediaExportSource, NotificationExportSource, PostExportSource, ProfileExportSource,
+    SocialGraphExportSource,
+};
@@ -0,0 +1,115 @@
+//! The export archive bucket over S3/MinIO. Archives are write-once per request
+//! key. The `expires_at` recorded on the account is enforced when the archive is
+//! served; the bucket's lifecycle rule (ops-owned) only reclaims the storage.
+//! Bytes flow store ⇄ this service only — the owner downloads the opened
+//! archive through account, never a URL into the bucket.
+
+use std::time::Duration as StdDuration;
+
+use async_trait::async_trait;
+use reqwest::header::CONTENT_TYPE;
+use rusty_s3::{Bucket, Credentials, S3Action, UrlStyle};
+use url::Url;
+
+use crate::application::port::ExportArchiveStore;
+use crate::error::AccountError;
+
+/// Connection settings for the export bucket, built from env in [`crate::config`].
+#[derive(Debug, Clone)]
+pub struct ExportStoreConfig {
+    pub endpoint: String,
+    pub region: String,
+    pub bucket: String,
+    pub access_key: String,
+    pub secret_key: String,
+    pub presign_ttl: StdDuration,
+    pub request_timeout: StdDuration,
+}
+
+pub struct S3ExportArchiveStore {
+    bucket: Bucket,
+    credentials: Credentials,
+    http: reqwest::Client,
+    presign_ttl: StdDuration,
+}
+
+impl S3ExportArchiveStore {
+    pub fn new(config: ExportStoreConfig) -> Result<Self, AccountError> {
+        let endpoint = Url::parse(&config.endpoint)
+            .map_err(|e| store_err(format!("invalid endpoint: {e}")))?;
+        let bucket = Bucket::new(endpoint, UrlStyle::Path, config.bucket, config.region)
+            .map_err(|e| store_err(format!("invalid bucket: {e}")))?;
+        let credentials = Credentials::new(config.access_key, config.secret_key);
+        let http = reqwest::Client::builder()
+            .timeout(config.request_timeout)
+            .build()
+            .map_err(|e| store_err(e.to_string()))?;
+        Ok(Self { bucket, credentials, http, presign_ttl: config.presign_ttl })
+    }
+
+    /// Verify the bucket is reachable, creating it only when absent (the
+    /// local/test path). Provisioned buckets are probed, never re-created, so
+    /// keys without `s3:CreateBucket` still boot.
+    pub async fn ensure_bucket(&self) -> Result<(), AccountError> {
+        let head = self.bucket.head_bucket(Some(&self.credentials)).sign(self.presign_ttl);
+        let resp = self.http.head(head).send().await.map_err(|e| store_err(e.to_string()))?;
+        if resp.status().is_success() {
+            return Ok(());
+        }
+        if resp.status() != reqwest::StatusCode::NOT_FOUND {
+            return Err(store_err(format!("bucket probe returned {}", resp.status())));
+        }
+        let url = self.bucket.create_bucket(&self.credentials).sign(self.presign_ttl);
+        let resp = self.http.put(url).send().await.map_err(|e| store_err(e.to_string()))?;
+        let status = resp.status();
+        if status.is_success() || status == reqwest::StatusCode::CONFLICT {
+            Ok(())
+        } else {
+            Err(store_err(format!("bucket create returned {status}")))
+        }
+    }
+}
+
+#[async_trait]
+impl ExportArchiveStore for S3ExportArchiveStore {
+    async fn put(&self, key: &str, sealed: Vec<u8>) -> Result<(), AccountError> {
+        let url: Url = self
+            .bucket
+            .put_object(Some(&self.credentials), key)
+            .sign(self.presign_ttl);
+        let resp = self
+            .http
+            .put(url)
+            .header(CONTENT_TYPE, "application/json")
+            .body(sealed)
+            .send()
+            .await
+            .map_err(|e| store_err(e.to_string()))?;
+        if resp.status().is_success() {
+            Ok(())
+        } else {
+            Err(store_err(format!("put {key} returned {}", resp.status())))
+        }
+    }
+
+    async fn get(&self, key: &str) -> Result<Option<Vec<u8>>, AccountError> {
+        let url: Url = self
+            .bucket
+            .get_object(Some(&self.credentials), key)
+            .sign(self.presign_ttl);
+        let resp = self.http.get(url).send().await.map_err(|e| store_err(e.to_string()))?;
+        let status = resp.status();
+        if status == reqwest::StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+        if !status.is_success() {
+            return Err(store_err(format!("get {key} returned {status}")));
+        }
+        let body = resp.bytes().await.map_err(|e| store_err(e.to_string()))?;
+        Ok(Some(body.to_vec()))
+    }
+}
+
+fn store_err(message: String) -> AccountError {
+    AccountError::ExportStoreUnavailable(message)
+}
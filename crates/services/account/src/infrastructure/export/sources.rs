@@ -0,0 +1,151 @@
+//! gRPC adapters for the export fan-out: one [`SubjectDataSource`] per owning
+//! service over its `ExportSubjectData` RPC, and the [`ProfileDirectory`] over
+//! profile's `ListProfilesByAccount`.
+//!
+//! Every channel is a [`ResilientChannel`] resolved from the service's own
+//! resilience binding (see [`crate::service`]), so the breaker and timeout wrap
+//! each call. Stubs come from the contracts tier (`*-api`).
+
+use async_trait::async_trait;
+use tonic::{Code, Status};
+use transport::grpc::client::ResilientChannel;
+
+use crate::application::port::{ProfileDirectory, SubjectDataSlice, SubjectDataSource};
+use crate::domain::value_object::AccountId;
+use crate::error::AccountError;
+
+/// Ceiling on one export response. A slice carries a person's whole history in
+/// one service (chat messages, notifications, …), far past tonic's 4 MiB default.
+pub const EXPORT_MAX_MESSAGE_BYTES: usize = 64 * 1024 * 1024;
+
+/// `ListProfilesByAccount` page size (profile's own cap).
+const PROFILE_PAGE_SIZE: i32 = 100;
+
+/// A transport-level or transient failure is retried by the consumer; anything
+/// else is the source refusing the request, which a retry will not change.
+fn source_error(service: &'static str, status: Status) -> AccountError {
+    let reason = format!("{}: {}", status.code(), status.message());
+    match status.code() {
+        Code::Unavailable
+        | Code::DeadlineExceeded
+        | Code::ResourceExhausted
+        | Code::Aborted
+        | Code::Cancelled
+        | Code::Unknown
+        | Code::Internal => AccountError::ExportSourceUnavailable { service: service.to_owned(), reason },
+        _ => AccountError::ExportSourceRejected { service: service.to_owned(), reason },
+    }
+}
+
+/// Declares a [`SubjectDataSource`] over `$api`'s `ExportSubjectData`: every
+/// service shares the request/response shape, only the generated types differ.
+macro_rules! grpc_source {
+    ($(#[$doc:meta])* $name:ident, $service:literal, $api:ident :: $client_mod:ident :: $client:ident) => {
+        $(#[$doc])*
+        pub struct $name {
+            channel: ResilientChannel,
+        }
+
+        impl $name {
+            pub fn new(channel: ResilientChannel) -> Self {
+                Self { channel }
+            }
+        }
+
+        #[async_trait]
+        impl SubjectDataSource for $name {
+            fn service(&self) -> &'static str {
+                $service
+            }
+
+            async fn export(
+                &self,
+                account_id: &AccountId,
+                profile_ids: &[String],
+            ) -> Result<SubjectDataSlice, AccountError> {
+                let resp = $api::$client_mod::$client::new(self.channel.clone())
+                    .max_decoding_message_size(EXPORT_MAX_MESSAGE_BYTES)
+                    .export_subject_data($api::ExportSubjectDataRequest {
+                        account_id: account_id.to_string(),
+                        profile_ids: profile_ids.to_vec(),
+                    })
+                    .await
+                    .map_err(|status| source_error($service, status))?
+                    .into_inner();
+                Ok(SubjectDataSlice { document: resp.document, record_count: resp.record_count })
+            }
+        }
+    };
+}
+
+grpc_source!(
+    /// Profiles, their settings and verification state.
+    ProfileExportSource, "profile", profile_api::profile_service_client::ProfileServiceClient
+);
+grpc_source!(
+    /// Posts authored by the person's profiles.
+    PostExportSource, "post", post_api::post_service_client::PostServiceClient
+);
+grpc_source!(
+    /// Comments authored by the person's profiles.
+    CommentExportSource, "comment", comment_api::comment_service_client::CommentServiceClient
+);
+grpc_source!(
+    /// Conversation memberships, subscriptions and sent messages.
+    ChatExportSource, "chat", chat_api::chat_service_client::ChatServiceClient
+);
+grpc_source!(
+    /// Follow and block edges in both directions.
+    SocialGraphExportSource, "social-graph",
+    social_graph_api::social_graph_service_client::SocialGraphServiceClient
+);
+grpc_source!(
+    /// Reactions the person's profiles left.
+    EngagementExportSource, "engagement",
+    engagement_api::engagement_service_client::EngagementServiceClient
+);
+grpc_source!(
+    /// Notifications delivered to the person's profiles.
+    NotificationExportSource, "notification",
+    notification_api::notification_service_client::NotificationServiceClient
+);
+grpc_source!(
+    /// Media assets the account uploaded.
+    MediaExportSource, "media", media_api::media_service_client::MediaServiceClient
+);
+
+/// [`ProfileDirectory`] over profile's `ListProfilesByAccount`, paged to the end.
+pub struct GrpcProfileDirectory {
+    channel: ResilientChannel,
+}
+
+impl GrpcProfileDirectory {
+    pub fn new(channel: ResilientChannel) -> Self {
+        Self { channel }
+    }
+}
+
+#[async_trait]
+impl ProfileDirectory for GrpcProfileDirectory {
+    async fn profile_ids(&self, account_id: &AccountId) -> Result<Vec<String>, AccountError> {
+        let mut client = profile_api::profile_service_client::ProfileServiceClient::new(self.channel.clone());
+        let mut ids = Vec::new();
+        let mut page_token = String::new();
+        loop {
+            let resp = client
+                .list_profiles_by_account(profile_api::ListProfilesByAccountRequest {
+                    account_id: account_id.to_string(),
+                    limit: PROFILE_PAGE_SIZE,
+                    page_token: page_token.clone(),
+                })
+                .await
+                .map_err(|status| source_error("profile", status))?
+                .into_inner();
+            ids.extend(resp.profiles.into_iter().map(|p| p.profile_id));
+            if resp.next_page_token.is_empty() {
+                return Ok(ids);
+            }
+            page_token = resp.next_page_token;
+        }
+    }
+}
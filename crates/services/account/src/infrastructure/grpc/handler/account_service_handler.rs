@@ -1,13 +1,18 @@
+use std::pin::Pin;
+
 use chrono::{DateTime, Utc};
+use futures::Stream;
 use tonic::{Request, Response, Status};
 use uuid::Uuid;
 
 use cqrs::{CommandBus, Envelope, QueryBus};
+use service_runtime::{current_peer, current_principal};
 
 use crate::application::command::{
     anonymize_account::AnonymizeAccountCommand,
     assign_role::AssignRoleCommand,
     change_password::ChangePasswordCommand,
+    confirm_subject_erasure::ConfirmSubjectErasureCommand,
     create_account::CreateAccountCommand,
     deactivate_account::DeactivateAccountCommand,
     enroll_mfa::EnrollMfaCommand,
@@ -21,20 +26,31 @@ use crate::application::command::{
     suspend_account::SuspendAccountCommand,
     update_kyc_status::UpdateKycStatusCommand,
     verify_email::VerifyEmailCommand,
+    verify_mfa_factor::{MfaFactor, VerifyMfaFactorCommand},
     verify_phone::VerifyPhoneCommand,
 };
 use crate::application::query::{
+    download_data_export::{DataExportDownload, DownloadDataExportQuery},
     get_account_by_id::{AccountView, GetAccountByIdQuery},
     get_account_by_identity_id::GetAccountByIdentityIdQuery,
     get_account_status::{AccountStatusView, GetAccountStatusQuery},
     get_gdpr_record::{GdprRecordView, GetGdprRecordQuery},
     list_accounts_by_status::{AccountListView, ListAccountsByStatusQuery},
 };
+use crate::domain::entity::ERASURE_PARTICIPANTS;
 // ── Proto inclusion ───────────────────────────────────────────────────────────
 pub use account_api as proto;
 
 pub use proto::account_service_server::AccountServiceServer;
 
+/// Chunk stream behind `DownloadDataExport`.
+pub type DownloadDataExportStream =
+    Pin<Box<dyn Stream<Item = Result<proto::DataExportChunk, Status>> + Send + 'static>>;
+
+/// Archive bytes per `DataExportChunk`, well under tonic's 4 MiB default
+/// message limit on the receiving side.
+const EXPORT_CHUNK_BYTES: usize = 1024 * 1024;
+
 /// gRPC request handler for the Account service.
 ///
 /// Converts every inbound Protobuf request into a CQRS `Envelope<Command>` or
@@ -146,8 +162,7 @@ where
         let cmd = EnrollMfaCommand {
             account_id: req.account_id.clone(),
             totp_secret_ciphertext: req.totp_secret,
-            // Recovery codes are generated server-side; none are sent via gRPC in this flow.
-            recovery_code_hashes: Vec::new(),
+            recovery_code_hashes: req.recovery_code_hashes,
         };
         self.command_bus
             .dispatch(Envelope::new(Uuid::now_v7(), cmd))
@@ -169,6 +184,30 @@ where
             .map_err(cqrs_error_to_status)
     }
 
+    pub async fn verify_mfa_factor(
+        &self,
+        request: Request<proto::VerifyMfaFactorRequest>,
+    ) -> Result<Response<proto::VerifyMfaFactorResponse>, Status> {
+        let req = request.into_inner();
+        let factor = match req.factor {
+            Some(proto::verify_mfa_factor_request::Factor::TotpCode(code)) => MfaFactor::Totp(code),
+            Some(proto::verify_mfa_factor_request::Factor::RecoveryCode(code)) => {
+                MfaFactor::RecoveryCode(code)
+            }
+            None => return Err(Status::invalid_argument("factor is required")),
+        };
+        let cmd = VerifyMfaFactorCommand { account_id: req.account_id, factor };
+        let outcome = self
+            .command_bus
+            .dispatch(Envelope::new(Uuid::now_v7(), cmd))
+            .await
+            .map_err(cqrs_error_to_status)?;
+        Ok(Response::new(proto::VerifyMfaFactorResponse {
+            verified: outcome.verified,
+            recovery_codes_remaining: outcome.recovery_codes_remaining as i32,
+        }))
+    }
+
     pub async fn update_kyc_status(
         &self,
         request: Request<proto::UpdateKycStatusRequest>,
@@ -289,6 +328,36 @@ where
             .map_err(cqrs_error_to_status)
     }
 
+    pub async fn confirm_subject_erasure(
+        &self,
+        request: Request<proto::ConfirmSubjectErasureRequest>,
+    ) -> Result<Response<proto::CommandResponse>, Status> {
+        let req = request.into_inner();
+        // The confirmer is whoever the peer token proved, never the payload's
+        // `service` — any internal caller could name another participant there.
+        let participant = current_peer()
+            .as_deref()
+            .and_then(erasure_participant)
+            .ok_or_else(|| Status::permission_denied("caller is not an erasure participant"))?;
+        if !req.service.is_empty() && req.service != participant {
+            return Err(Status::permission_denied("service does not match the calling peer"));
+        }
+        let requested_at = req
+            .requested_at
+            .and_then(|ts| DateTime::<Utc>::from_timestamp(ts.seconds, ts.nanos.max(0) as u32))
+            .ok_or_else(|| Status::invalid_argument("requested_at is required"))?;
+        let cmd = ConfirmSubjectErasureCommand {
+            account_id: req.account_id.clone(),
+            service: participant.to_owned(),
+            requested_at,
+        };
+        self.command_bus
+            .dispatch(Envelope::new(Uuid::now_v7(), cmd))
+            .await
+            .map(|_| Self::ok_command(&req.account_id))
+            .map_err(cqrs_error_to_status)
+    }
+
     pub async fn request_data_export(
         &self,
         request: Request<proto::RequestDataExportRequest>,
@@ -392,6 +461,37 @@ where
         }))
     }
 
+    pub async fn download_data_export(
+        &self,
+        request: Request<proto::DownloadDataExportRequest>,
+    ) -> Result<Response<DownloadDataExportStream>, Status> {
+        let req = request.into_inner();
+        // The archive is the subject's whole footprint: it goes to the account
+        // the edge token proved, never to whichever id the payload names.
+        let caller = current_principal()
+            .ok_or_else(|| Status::unauthenticated("an edge token is required"))?;
+        if caller.user_id().as_str() != req.account_id {
+            return Err(Status::permission_denied("a data export is served only to its owner"));
+        }
+        let query = DownloadDataExportQuery { account_id: req.account_id };
+        let download: DataExportDownload = self
+            .query_bus
+            .dispatch(Envelope::new(Uuid::now_v7(), query))
+            .await
+            .map_err(cqrs_error_to_status)?;
+        let chunks: Vec<_> = download
+            .archive
+            .chunks(EXPORT_CHUNK_BYTES)
+            .map(|data| {
+                Ok(proto::DataExportChunk {
+                    data: data.to_vec(),
+                    expires_at: Some(dt_to_ts(download.expires_at)),
+                })
+            })
+            .collect();
+        Ok(Response::new(Box::pin(futures::stream::iter(chunks))))
+    }
+
     pub async fn get_gdpr_record(
         &self,
         request: Request<proto::GetGdprRecordRequest>,
@@ -409,8 +509,12 @@ where
             marketing_consented: view.marketing_consented_at.is_some(),
             deletion_requested_at: view.deletion_requested_at.map(dt_to_ts),
             anonymized_at: view.anonymized_at.map(dt_to_ts),
+            erasure_confirmed_by: view.erasure_confirmed_by,
+            erasure_pending: view.erasure_pending,
             data_export_requested_at: view.data_export_requested_at.map(dt_to_ts),
             data_export_completed_at: view.data_export_completed_at.map(dt_to_ts),
+            data_export_download_ref: view.data_export_download_ref.unwrap_or_default(),
+            data_export_expires_at: view.data_export_expires_at.map(dt_to_ts),
         }))
     }
 
@@ -461,6 +565,7 @@ fn account_view_to_proto(v: AccountView) -> proto::AccountView {
         country_of_residence: v.country_of_residence.unwrap_or_default(),
         roles: v.roles,
         permissions: v.permissions,
+        mfa_enforced: v.mfa_enforced,
         version: v.version,
         created_at: Some(dt_to_ts(v.created_at)),
         updated_at: Some(dt_to_ts(v.updated_at)),
@@ -523,6 +628,14 @@ fn account_role_i32_to_str(v: i32) -> Option<&'static str> {
     }
 }
 
+/// The erasure participant a peer service speaks for: its deployment name minus
+/// the `-server` suffix (`post-server` → `post`), when that is one of
+/// [`ERASURE_PARTICIPANTS`].
+fn erasure_participant(peer: &str) -> Option<&'static str> {
+    let name = peer.strip_suffix("-server")?;
+    ERASURE_PARTICIPANTS.iter().copied().find(|participant| *participant == name)
+}
+
 // ── Error mapping ─────────────────────────────────────────────────────────────
 
 pub fn cqrs_error_to_status(err: cqrs::error::CqrsError) -> Status {
@@ -550,3 +663,17 @@ pub fn cqrs_error_to_status(err: cqrs::error::CqrsError) -> Status {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_participant_servers_speak_for_a_participant() {
+        assert_eq!(erasure_participant("post-server"), Some("post"));
+        assert_eq!(erasure_participant("social-graph-server"), Some("social-graph"));
+        assert_eq!(erasure_participant("post"), None);
+        assert_eq!(erasure_participant("timeline-server"), None);
+        assert_eq!(erasure_participant("post-worker"), None);
+    }
+}
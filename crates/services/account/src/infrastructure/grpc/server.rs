@@ -2,7 +2,9 @@ use tonic::{Request, Response, Status};
 
 use cqrs::{CommandBus, QueryBus};
 
-use super::handler::account_service_handler::{proto, AccountServiceHandler};
+use super::handler::account_service_handler::{
+    proto, AccountServiceHandler, DownloadDataExportStream,
+};
 
 // Import the tonic-generated trait from the bundled proto module.
 use proto::account_service_server::AccountService;
@@ -17,6 +19,8 @@ where
     CB: CommandBus + Send + Sync + 'static,
     QB: QueryBus + Send + Sync + 'static,
 {
+    type DownloadDataExportStream = DownloadDataExportStream;
+
     // ── Registration & identity ───────────────────────────────────────────────
 
     async fn create_account(
@@ -65,6 +69,13 @@ where
         self.revoke_mfa(request).await
     }
 
+    async fn verify_mfa_factor(
+        &self,
+        request: Request<proto::VerifyMfaFactorRequest>,
+    ) -> Result<Response<proto::VerifyMfaFactorResponse>, Status> {
+        self.verify_mfa_factor(request).await
+    }
+
     // ── KYC ───────────────────────────────────────────────────────────────────
 
     async fn update_kyc_status(
@@ -129,6 +140,13 @@ where
         self.anonymize_account(request).await
     }
 
+    async fn confirm_subject_erasure(
+        &self,
+        request: Request<proto::ConfirmSubjectErasureRequest>,
+    ) -> Result<Response<proto::CommandResponse>, Status> {
+        self.confirm_subject_erasure(request).await
+    }
+
     async fn request_data_export(
         &self,
         request: Request<proto::RequestDataExportRequest>,
@@ -136,6 +154,13 @@ where
         self.request_data_export(request).await
     }
 
+    async fn download_data_export(
+        &self,
+        request: Request<proto::DownloadDataExportRequest>,
+    ) -> Result<Response<Self::DownloadDataExportStream>, Status> {
+        self.download_data_export(request).await
+    }
+
     // ── Roles & permissions ───────────────────────────────────────────────────
 
     async fn assign_role(
@@ -0,0 +1,8 @@
+//! Adapters of the second-factor check behind `VerifyMfaFactor`: the TOTP seed
+//! sealed under the service MFA key and the bcrypt recovery-code hashes
+//! (`totp_verifier`). The check itself is
+//! [`VerifyMfaFactorHandler`](crate::application::command::VerifyMfaFactorHandler).
+
+pub mod totp_verifier;
+
+pub use totp_verifier::TotpSecondFactorVerifier;
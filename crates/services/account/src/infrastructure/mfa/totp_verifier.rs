@@ -0,0 +1,211 @@
+//! RFC 6238 TOTP and bcrypt recovery codes — the [`SecondFactorVerifier`]
+//! adapter.
+//!
+//! The enrolled seed is stored as `nonce (12 bytes) ‖ AES-256-GCM(seed)` under
+//! the service **MFA key**; it is unsealed only for the duration of a check.
+//! Codes are HMAC-SHA1, 30-second steps, 6 digits — what every authenticator
+//! app defaults to — and the step either side of `at` is accepted for clock
+//! drift. The matched step is returned so the account can refuse it, and every
+//! step before it, the next time (RFC 6238 §5.2). Recovery codes are compared
+//! after dropping separators and upper-casing, the same normalisation the
+//! enrolling client hashes.
+
+use aes_gcm::aead::Aead;
+use aes_gcm::{Aes256Gcm, Key, KeyInit, Nonce};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sha1::{Digest, Sha1};
+
+use crate::application::port::SecondFactorVerifier;
+use crate::domain::value_object::{EncryptedBytes, RecoveryCodeHash};
+use crate::error::AccountError;
+use crate::infrastructure::export::envelope::{KEY_LEN, NONCE_LEN, constant_time_eq};
+
+/// TOTP time step (RFC 6238 `X`).
+const STEP_SECS: i64 = 30;
+/// Code length.
+const DIGITS: usize = 6;
+/// Steps accepted either side of the current one.
+const DRIFT_STEPS: i64 = 1;
+
+const HMAC_BLOCK: usize = 64;
+const SHA1_LEN: usize = 20;
+
+/// Checks TOTP codes against seeds sealed under `kek` and recovery codes
+/// against their bcrypt hashes.
+pub struct TotpSecondFactorVerifier {
+    kek: [u8; KEY_LEN],
+}
+
+impl TotpSecondFactorVerifier {
+    pub fn new(kek: [u8; KEY_LEN]) -> Self {
+        Self { kek }
+    }
+
+    fn unseal(&self, secret: &EncryptedBytes) -> Result<Vec<u8>, AccountError> {
+        let sealed = secret.as_bytes();
+        if sealed.len() <= NONCE_LEN {
+            return Err(AccountError::MfaSecretUnreadable("sealed seed is truncated".to_owned()));
+        }
+        let (nonce, ciphertext) = sealed.split_at(NONCE_LEN);
+        Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(&self.kek))
+            .decrypt(Nonce::from_slice(nonce), ciphertext)
+            .map_err(|_| AccountError::MfaSecretUnreadable("AEAD open failed".to_owned()))
+    }
+}
+
+#[async_trait]
+impl SecondFactorVerifier for TotpSecondFactorVerifier {
+    async fn matching_totp_step(
+        &self,
+        secret: &EncryptedBytes,
+        code: &str,
+        at: DateTime<Utc>,
+    ) -> Result<Option<i64>, AccountError> {
+        if code.len() != DIGITS || !code.bytes().all(|b| b.is_ascii_digit()) {
+            return Ok(None);
+        }
+        let seed = self.unseal(secret)?;
+        let step = at.timestamp().div_euclid(STEP_SECS);
+        // Every candidate is computed and compared, so timing says nothing
+        // about which step (if any) matched.
+        let matched = (-DRIFT_STEPS..=DRIFT_STEPS).fold(None, |matched, drift| {
+            let candidate = format!("{:0DIGITS$}", totp(&seed, (step + drift) as u64));
+            let hit = constant_time_eq(candidate.as_bytes(), code.as_bytes());
+            if hit { Some(step + drift) } else { matched }
+        });
+        Ok(matched)
+    }
+
+    async fn matching_recovery_code(
+        &self,
+        code: &str,
+        hashes: &[RecoveryCodeHash],
+    ) -> Option<RecoveryCodeHash> {
+        let code = normalize_recovery_code(code);
+        if code.is_empty() {
+            return None;
+        }
+        let hashes = hashes.to_vec();
+        // bcrypt is deliberately slow; keep it off the async workers.
+        tokio::task::spawn_blocking(move || {
+            hashes
+                .into_iter()
+                .find(|hash| bcrypt::verify(&code, hash.as_str()).unwrap_or(false))
+        })
+        .await
+        .ok()
+        .flatten()
+    }
+}
+
+/// The RFC 4226 HOTP value of `seed` at `counter`, truncated to [`DIGITS`].
+fn totp(seed: &[u8], counter: u64) -> u32 {
+    let mac = hmac_sha1(seed, &counter.to_be_bytes());
+    let offset = (mac[SHA1_LEN - 1] & 0x0f) as usize;
+    let binary = u32::from_be_bytes([
+        mac[offset] & 0x7f,
+        mac[offset + 1],
+        mac[offset + 2],
+        mac[offset + 3],
+    ]);
+    binary % 10u32.pow(DIGITS as u32)
+}
+
+/// HMAC-SHA1 over the workspace `sha1`, shaped like the export envelope's
+/// HMAC-SHA256, so account carries no `hmac`/`digest` version pin.
+fn hmac_sha1(key: &[u8], msg: &[u8]) -> [u8; SHA1_LEN] {
+    let mut block = [0u8; HMAC_BLOCK];
+    if key.len() > HMAC_BLOCK {
+        block[..SHA1_LEN].copy_from_slice(&Sha1::digest(key));
+    } else {
+        block[..key.len()].copy_from_slice(key);
+    }
+
+    let mut ipad = [0x36u8; HMAC_BLOCK];
+    let mut opad = [0x5cu8; HMAC_BLOCK];
+    for i in 0..HMAC_BLOCK {
+        ipad[i] ^= block[i];
+        opad[i] ^= block[i];
+    }
+
+    let mut inner = Sha1::new();
+    inner.update(ipad);
+    inner.update(msg);
+    let inner = inner.finalize();
+
+    let mut outer = Sha1::new();
+    outer.update(opad);
+    outer.update(inner);
+    outer.finalize().into()
+}
+
+fn normalize_recovery_code(code: &str) -> String {
+    code.chars()
+        .filter(|c| !c.is_whitespace() && *c != '-')
+        .map(|c| c.to_ascii_uppercase())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The RFC 6238 Appendix B SHA-1 seed.
+    const RFC_SEED: &[u8] = b"12345678901234567890";
+
+    fn verifier() -> TotpSecondFactorVerifier {
+        TotpSecondFactorVerifier::new([3u8; KEY_LEN])
+    }
+
+    fn sealed(seed: &[u8]) -> EncryptedBytes {
+        let nonce = [5u8; NONCE_LEN];
+        let ciphertext = Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(&[3u8; KEY_LEN]))
+            .encrypt(Nonce::from_slice(&nonce), seed)
+            .unwrap();
+        EncryptedBytes::from_ciphertext([nonce.as_slice(), &ciphertext].concat())
+    }
+
+    fn at(unix: i64) -> DateTime<Utc> {
+        DateTime::from_timestamp(unix, 0).unwrap()
+    }
+
+    #[test]
+    fn matches_the_rfc_6238_vectors() {
+        // Appendix B lists 8-digit values; the 6-digit code is their suffix.
+        assert_eq!(totp(RFC_SEED, 59 / 30), 287_082);
+        assert_eq!(totp(RFC_SEED, 1_111_111_109 / 30), 81_804);
+        assert_eq!(totp(RFC_SEED, 2_000_000_000 / 30), 279_037);
+    }
+
+    #[tokio::test]
+    async fn accepts_one_step_of_drift_and_no_more() {
+        let secret = sealed(RFC_SEED);
+        let v = verifier();
+        assert_eq!(v.matching_totp_step(&secret, "287082", at(59)).await.unwrap(), Some(1));
+        assert_eq!(v.matching_totp_step(&secret, "287082", at(59 + 30)).await.unwrap(), Some(1));
+        assert_eq!(v.matching_totp_step(&secret, "287082", at(59 + 60)).await.unwrap(), None);
+        assert_eq!(v.matching_totp_step(&secret, "28708", at(59)).await.unwrap(), None);
+        assert_eq!(v.matching_totp_step(&secret, "28708a", at(59)).await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn a_seed_sealed_under_another_key_is_unreadable() {
+        let foreign = TotpSecondFactorVerifier::new([4u8; KEY_LEN]);
+        let err = foreign.matching_totp_step(&sealed(RFC_SEED), "287082", at(59)).await.unwrap_err();
+        assert!(matches!(err, AccountError::MfaSecretUnreadable(_)));
+    }
+
+    #[tokio::test]
+    async fn recovery_codes_match_their_hash_after_normalisation() {
+        let hashes: Vec<RecoveryCodeHash> = ["ABCD2345EFGH6789", "QRST2345UVWX6789"]
+            .iter()
+            .map(|code| RecoveryCodeHash::from_hash(bcrypt::hash(code, 4).unwrap()))
+            .collect();
+        let v = verifier();
+        let hit = v.matching_recovery_code("qrst-2345 uvwx-6789", &hashes).await;
+        assert_eq!(hit, Some(hashes[1].clone()));
+        assert_eq!(v.matching_recovery_code("ZZZZ2345ZZZZ6789", &hashes).await, None);
+        assert_eq!(v.matching_recovery_code(" - ", &hashes).await, None);
+    }
+}
@@ -1,3 +1,6 @@
+pub mod consumer;
 pub mod event;
+pub mod export;
 pub mod grpc;
+pub mod mfa;
 pub mod persistence;
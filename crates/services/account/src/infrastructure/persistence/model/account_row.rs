@@ -48,6 +48,7 @@ pub struct AccountRow {
     pub mfa_enforced: bool,
     pub mfa_totp_secret: Option<Vec<u8>>,
     pub mfa_totp_enrolled_at: Option<DateTime<Utc>>,
+    pub mfa_totp_last_step: Option<i64>,
     pub mfa_recovery_codes: Vec<String>,
     pub mfa_backup_verified_at: Option<DateTime<Utc>>,
 
@@ -64,8 +65,11 @@ pub struct AccountRow {
     pub gdpr_deletion_requested_at: Option<DateTime<Utc>>,
     pub gdpr_deletion_scheduled_at: Option<DateTime<Utc>>,
     pub gdpr_anonymized_at: Option<DateTime<Utc>>,
+    pub gdpr_erasure_confirmed_by: Vec<String>,
     pub gdpr_data_export_requested_at: Option<DateTime<Utc>>,
     pub gdpr_data_export_completed_at: Option<DateTime<Utc>>,
+    pub gdpr_data_export_download_ref: Option<String>,
+    pub gdpr_data_export_expires_at: Option<DateTime<Utc>>,
 
     pub roles: Vec<String>,
     pub permission_overrides: Vec<String>,
@@ -102,6 +106,7 @@ impl TryFrom<AccountRow> for Account {
             row.mfa_enforced,
             row.mfa_totp_secret.map(EncryptedBytes::from_ciphertext),
             row.mfa_totp_enrolled_at,
+            row.mfa_totp_last_step,
             mfa_recovery_codes,
             row.mfa_backup_verified_at,
         );
@@ -123,8 +128,11 @@ impl TryFrom<AccountRow> for Account {
             row.gdpr_deletion_requested_at,
             row.gdpr_deletion_scheduled_at,
             row.gdpr_anonymized_at,
+            row.gdpr_erasure_confirmed_by.into_iter().collect(),
             row.gdpr_data_export_requested_at,
             row.gdpr_data_export_completed_at,
+            row.gdpr_data_export_download_ref,
+            row.gdpr_data_export_expires_at,
         );
 
         let roles: Vec<AccountRole> = row
@@ -1,7 +1,10 @@
 use async_trait::async_trait;
 use tracing::instrument;
 
-use postgres_storage::{StorageError, TransactionManager};
+use idempotency::PgIdempotencyStore;
+use outbox::PgOutbox;
+use postgres_storage::{PgTransaction, StorageError, TransactionManager};
+use uuid::Uuid;
 
 use crate::application::port::account_repository::AccountRepository;
 use crate::domain::aggregate::account::Account;
@@ -10,6 +13,7 @@ use crate::domain::value_object::{
     identity_id::IdentityId,
 };
 use crate::error::AccountError;
+use crate::infrastructure::event::{outbox_err, outbox_message};
 
 use super::model::AccountRow;
 
@@ -32,21 +36,49 @@ use super::model::AccountRow;
 /// deployment where `identity_id` and `email` are the routing dimension, these
 /// queries would require an auxiliary index table or a secondary consistent hash.
 /// That routing strategy is intentionally deferred to the infrastructure evolution phase.
+///
+/// # Events
+///
+/// `save` enqueues the aggregate's pending events into `account_outbox` inside the
+/// same shard transaction as the row write — the outbox rows share the account's
+/// shard key, so they commit atomically with it even in `ApplicationSharded` mode.
+///
+/// # Idempotency
+///
+/// `save` also marks the dispatch's idempotency key processed through
+/// [`PgIdempotencyStore::claim_in`] in that transaction, so the write and the mark
+/// commit together. In `ApplicationSharded` mode the mark lands on the account's
+/// shard rather than the key's, out of the layer's sight — the same deferral as
+/// the fan-out queries above.
 #[derive(Clone)]
 pub struct PgAccountRepository {
     tx_manager: TransactionManager,
-    publisher: std::sync::Arc<dyn crate::application::port::EventPublisher>,
+    outbox: PgOutbox,
+    idempotency: PgIdempotencyStore,
 }
 
 impl PgAccountRepository {
     pub fn new(
         tx_manager: TransactionManager,
-        publisher: std::sync::Arc<dyn crate::application::port::EventPublisher>,
+        outbox: PgOutbox,
+        idempotency: PgIdempotencyStore,
     ) -> Self {
-        Self {
-            tx_manager,
-            publisher,
-        }
+        Self { tx_manager, outbox, idempotency }
+    }
+}
+
+/// Marks `key` processed inside the write's transaction. A key some earlier run
+/// already settled fails the write as a conflict: its retry is answered by the
+/// idempotency layer from the recorded result instead of running again.
+async fn mark_processed(
+    idempotency: &PgIdempotencyStore,
+    tx: &mut PgTransaction,
+    key: Uuid,
+) -> Result<(), AccountError> {
+    if idempotency.claim_in(tx, key).await? {
+        Ok(())
+    } else {
+        Err(AccountError::ConcurrentModification)
     }
 }
 
@@ -56,7 +88,7 @@ impl AccountRepository for PgAccountRepository {
         account.id = %account.id(),
         account.version = account.version(),
     ))]
-    async fn save(&self, account: &Account) -> Result<(), AccountError> {
+    async fn save(&self, account: &Account, idempotency_key: Uuid) -> Result<(), AccountError> {
         let id = account.id();
 
         // Pre-materialize all account data as owned values so the async move closures
@@ -89,6 +121,7 @@ impl AccountRepository for PgAccountRepository {
         let p_mfa_enforced        = mfa.enforced();
         let p_mfa_totp_secret     = mfa.totp_secret().map(|s| s.as_bytes().to_vec());
         let p_mfa_enrolled_at     = mfa.totp_enrolled_at();
+        let p_mfa_last_step       = mfa.totp_last_step();
         let p_mfa_backup_at       = mfa.backup_verified_at();
         let p_recovery_codes: Vec<String> = mfa.recovery_codes().iter().map(|c| c.as_str().to_owned()).collect();
 
@@ -102,15 +135,27 @@ impl AccountRepository for PgAccountRepository {
         let p_gdpr_anonymized     = gdpr.anonymized_at();
         let p_gdpr_export_req     = gdpr.data_export_requested_at();
         let p_gdpr_export_done    = gdpr.data_export_completed_at();
+        let p_gdpr_export_ref     = gdpr.data_export_download_ref().map(str::to_owned);
+        let p_gdpr_export_expiry  = gdpr.data_export_expires_at();
+        let p_gdpr_erasure_by: Vec<String> = gdpr.erasure_confirmed_by().iter().cloned().collect();
 
         let p_roles: Vec<String> = account.roles().iter().map(|r| r.as_str().to_owned()).collect();
         let p_perms: Vec<String> = account.permission_overrides().to_vec();
 
+        let p_events = account
+            .events()
+            .iter()
+            .map(outbox_message)
+            .collect::<Result<Vec<_>, _>>()?;
+        let outbox = self.outbox.clone();
+        let idempotency = self.idempotency.clone();
+
         let write_result = if account.version() == 0 {
             // New aggregate — INSERT.
             self.tx_manager
                 .run_on_shard(&id, |tx| {
                     Box::pin(async move {
+                        mark_processed(&idempotency, tx, idempotency_key).await?;
                         sqlx::query(
                             r#"
                             INSERT INTO accounts (
@@ -129,12 +174,15 @@ impl AccountRepository for PgAccountRepository {
                                 gdpr_anonymized_at,
                                 gdpr_data_export_requested_at, gdpr_data_export_completed_at,
                                 roles, permission_overrides,
-                                version, created_at, updated_at, created_by
+                                version, created_at, updated_at, created_by,
+                                gdpr_data_export_download_ref, gdpr_data_export_expires_at,
+                                gdpr_erasure_confirmed_by, mfa_totp_last_step
                             ) VALUES (
                                 $1,$2,$3,$4,$5,$6,$7,$8,$9,$10,$11,$12,$13,$14,$15,$16,
                                 $17,$18,$19,$20,$21,$22,$23,$24,$25,$26,$27,$28,$29,$30,
                                 $31,$32,$33,$34,$35,$36,$37,
-                                $38,$39,$40,$41
+                                $38,$39,$40,$41,
+                                $42,$43,$44,$45
                             )
                             "#,
                         )
@@ -179,10 +227,15 @@ impl AccountRepository for PgAccountRepository {
                         .bind(p_created_at)          // $39
                         .bind(p_updated_at)          // $40
                         .bind(p_created_by)          // $41
+                        .bind(p_gdpr_export_ref)     // $42
+                        .bind(p_gdpr_export_expiry)  // $43
+                        .bind(&p_gdpr_erasure_by)    // $44
+                        .bind(p_mfa_last_step)       // $45
                         .execute(&mut **tx)
                         .await
-                        .map(|_| ())
-                        .map_err(|e| AccountError::Storage(StorageError::from(e)))
+                        .map_err(|e| AccountError::Storage(StorageError::from(e)))?;
+
+                        outbox.enqueue(tx, &p_events).await.map_err(outbox_err)
                     })
                 })
                 .await
@@ -200,6 +253,7 @@ impl AccountRepository for PgAccountRepository {
             self.tx_manager
                 .run_on_shard(&id, |tx| {
                     Box::pin(async move {
+                        mark_processed(&idempotency, tx, idempotency_key).await?;
                         let affected = sqlx::query(
                             r#"
                             UPDATE accounts SET
@@ -238,6 +292,10 @@ impl AccountRepository for PgAccountRepository {
                                 gdpr_data_export_completed_at = $34,
                                 roles = $35,
                                 permission_overrides = $36,
+                                gdpr_data_export_download_ref = $38,
+                                gdpr_data_export_expires_at = $39,
+                                gdpr_erasure_confirmed_by = $40,
+                                mfa_totp_last_step = $41,
                                 version = version + 1,
                                 updated_at = NOW()
                             WHERE id = $1 AND version = $37
@@ -280,27 +338,27 @@ impl AccountRepository for PgAccountRepository {
                         .bind(&p_roles)             // $35
                         .bind(&p_perms)             // $36
                         .bind(expected_version)     // $37
+                        .bind(p_gdpr_export_ref)    // $38
+                        .bind(p_gdpr_export_expiry) // $39
+                        .bind(&p_gdpr_erasure_by)   // $40
+                        .bind(p_mfa_last_step)      // $41
                         .execute(&mut **tx)
                         .await
                         .map_err(|e| AccountError::Storage(StorageError::from(e)))?
                         .rows_affected();
 
                         if affected == 0 {
-                            Err(AccountError::ConcurrentModification)
-                        } else {
-                            Ok(())
+                            return Err(AccountError::ConcurrentModification);
                         }
+                        outbox.enqueue(tx, &p_events).await.map_err(outbox_err)
                     })
                 })
                 .await
         };
 
-        // Persist durably first; only on success publish the aggregate's events.
-        write_result?;
-        for event in account.events() {
-            self.publisher.publish(event).await?;
-        }
-        Ok(())
+        // The row, its events and the idempotency mark committed (or rolled back)
+        // together; the relay publishes from the outbox.
+        write_result
     }
 
     #[instrument(name = "account.repo.find_by_id", skip(self), fields(
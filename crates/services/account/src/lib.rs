@@ -1,5 +1,6 @@
 pub mod app;
 pub mod application;
+pub mod config;
 pub mod domain;
 pub mod error;
 pub mod infrastructure;
@@ -1,29 +1,55 @@
 //! Adapts the account composition root to the fleet [`service_runtime::Service`]
 //! contract. Account is PostgreSQL-backed; the pool is built here, shared into
 //! [`App::build`], and reused (it is `Clone`/`Arc`-backed) for the readiness probe.
+//!
+//! `RequestGdprDeletion` resolves the subject's profiles through profile's
+//! `ListProfilesByAccount` (binding `profile`) so `gdpr_deletion_requested` can
+//! carry them to every erasure participant.
+//!
+//! With a broker configured it also self-spawns the data-export consumer: the
+//! GDPR Art. 20 pipeline that fans out to every owning service's
+//! `ExportSubjectData`, seals the archive into object storage and completes the
+//! request. Its gRPC channels resolve their resilience stacks from the
+//! [`InfraRegistry`], one `<service>-export` binding per source service — bulk
+//! reads whose deadlines differ from the same service's interactive RPCs.
+//! Every replica, broker or not, serves the finished archive back to its owner
+//! through `DownloadDataExport`, opened from the same bucket with the same sealer.
 
 use std::sync::Arc;
+use std::time::Duration;
 
+use anyhow::Context;
 use async_trait::async_trait;
-use cqrs::command::InMemoryCommandBus;
-use cqrs::query::InMemoryQueryBus;
+use idempotency::PgIdempotencyStore;
 use postgres_storage::{PgPoolBuilder, PostgresConfig};
-use service_runtime::{HealthProbe, InfraRegistry, Service};
+use service_runtime::{AccessPolicy, HealthProbe, InfraRegistry, Service};
+use outbox::{KafkaOutboxSink, LogOutboxSink, OutboxSink};
 use sqlx::PgPool;
 use tonic::service::RoutesBuilder;
 use tonic_reflection::server::Builder as ReflectionBuilder;
+use transport::grpc::client::{GrpcClientBuilder, GrpcClientConfig, ResilientChannel};
+use transport::kafka::config::{ConsumerConfig, KafkaClientConfig, ProducerConfig};
+use transport::kafka::consumer::{KafkaConsumerBuilder, KafkaConsumerHandle};
+use transport::kafka::producer::{KafkaProducerBuilder, KafkaProducerHandle};
 
-use crate::app::App;
-use crate::application::port::EventPublisher;
-use crate::infrastructure::event::{KafkaEventPublisher, LogEventPublisher};
+use crate::app::{App, AppCommandBus, AppQueryBus};
+use crate::application::command::FulfilDataExportHandler;
+use crate::application::port::SubjectDataSource;
+use crate::config::{self, ExportConfig, ExportEndpoints};
+use crate::infrastructure::consumer::run_data_export_consumer;
+use crate::infrastructure::event::TOPIC_ACCOUNT_EVENTS;
+use crate::infrastructure::export::{
+    ChatExportSource, CommentExportSource, EngagementExportSource, EnvelopeSealer,
+    GrpcProfileDirectory, MediaExportSource, NotificationExportSource, PostExportSource,
+    ProfileExportSource, S3ExportArchiveStore, SocialGraphExportSource,
+};
 use crate::infrastructure::grpc::handler::account_service_handler::AccountServiceServer;
 use crate::infrastructure::grpc::handler::AccountServiceHandler;
 use crate::infrastructure::grpc::server::FILE_DESCRIPTOR_SET;
-use transport::kafka::config::{KafkaClientConfig, ProducerConfig};
-use transport::kafka::producer::KafkaProducerBuilder;
+use crate::infrastructure::mfa::TotpSecondFactorVerifier;
 
 type AccountServer =
-    AccountServiceServer<AccountServiceHandler<Arc<InMemoryCommandBus>, Arc<InMemoryQueryBus>>>;
+    AccountServiceServer<AccountServiceHandler<Arc<AppCommandBus>, Arc<AppQueryBus>>>;
 
 /// The account service as hosted by [`service_runtime`].
 pub struct AccountService {
@@ -31,25 +57,84 @@ pub struct AccountService {
     pool: PgPool,
 }
 
+/// Resilience binding of the interactive profile lookup behind erasure requests.
+const PROFILE_DEPENDENCY: &str = "profile";
+/// Consumer group of the data-export pipeline on `account.v1.events`.
+const DATA_EXPORT_GROUP: &str = "account-data-export";
+/// Backoff before respawning a consumer after the runner returns.
+const CONSUMER_RESPAWN_BACKOFF: Duration = Duration::from_secs(5);
+
+/// Back-office permission for KYC, suspension, roles, GDPR records and listings.
+const ACCOUNT_ADMIN: &str = "account:admin";
+/// How recent the second factor behind a password change or an erasure request
+/// must be; older sessions are sent through auth's `StepUp` first.
+const STEP_UP_MAX_AGE: Duration = Duration::from_secs(5 * 60);
+
 #[async_trait]
 impl Service for AccountService {
     const NAME: &'static str = "account";
     const VERSION: &'static str = env!("CARGO_PKG_VERSION");
     const GRPC_SERVICE_NAME: &'static str = <AccountServer as tonic::server::NamedService>::NAME;
 
-    async fn build(_infra: Arc<InfraRegistry>) -> anyhow::Result<Self> {
+    async fn build(infra: Arc<InfraRegistry>) -> anyhow::Result<Self> {
         let pool = PgPoolBuilder::build(PostgresConfig::from_env())
             .await
             .map_err(|e| anyhow::anyhow!("account postgres pool: {e}"))?;
 
-        // Publish account.v1.events to Kafka when a broker is configured; otherwise
-        // a no-op log publisher keeps local/dev runs broker-free.
-        let publisher = build_publisher()?;
+        // The outbox relay publishes account.v1.events to Kafka when a broker is
+        // configured; otherwise a log sink keeps local/dev runs broker-free.
+        let sink = build_sink()?;
+
+        // Erasure requests snapshot the subject's profiles; the channel connects
+        // lazily, so profile being down only fails those requests.
+        let profiles = GrpcClientBuilder::new(
+            GrpcClientConfig::new(config::profile_endpoint()).with_dependency(PROFILE_DEPENDENCY),
+        )
+        .build_from_registry_lazy(&infra.resilience())
+        .map_err(|e| anyhow::anyhow!("account profile channel: {e}"))?;
+
+        // Every replica serves `DownloadDataExport`, so the archive bucket and
+        // sealer are built whether or not this one runs the export pipeline. An
+        // unreachable bucket must not take the account RPCs down with it: the
+        // pipeline's writes retry, so only warn here.
+        let export = ExportConfig::from_env();
+        let sealer = Arc::new(EnvelopeSealer::new(export.kek, export.signing_key));
+        let archives = Arc::new(
+            S3ExportArchiveStore::new(export.store)
+                .map_err(|e| anyhow::anyhow!("account export store: {e}"))?,
+        );
+        if let Err(e) = archives.ensure_bucket().await {
+            tracing::warn!(error = %e, "account export bucket not ready");
+        }
 
         // `PgPool` is `Arc`-backed: one clone serves the app graph, one the probe.
-        let app = App::build(pool.clone(), publisher)
-            .await
-            .map_err(|e| anyhow::anyhow!("account app build: {e}"))?;
+        let verifier = Arc::new(TotpSecondFactorVerifier::new(config::mfa_kek()));
+        let app = App::build(
+            pool.clone(),
+            sink,
+            Arc::new(GrpcProfileDirectory::new(profiles)),
+            verifier,
+            archives.clone(),
+            sealer.clone(),
+        )
+        .await
+        .map_err(|e| anyhow::anyhow!("account app build: {e}"))?;
+        tokio::spawn(app.relay.clone().run());
+        tokio::spawn(purge_idempotency(app.idempotency.clone(), pool.clone()));
+
+        // The export pipeline consumes account's own events, so it runs only
+        // where they reach a broker.
+        if std::env::var("KAFKA_BROKERS").is_ok() {
+            let handler = build_export_handler(
+                &infra,
+                &app,
+                &export.endpoints,
+                export.retention,
+                sealer,
+                archives,
+            )?;
+            spawn_data_export_consumer(handler, Arc::clone(&app.command_bus));
+        }
 
         Ok(Self { app, pool })
     }
@@ -58,6 +143,39 @@ impl Service for AccountService {
         vec![postgres_storage::health::probe(self.pool.clone())]
     }
 
+    fn access_policy(&self) -> AccessPolicy {
+        // Sign-up is the one anonymous entry point; lookups by id and second-factor
+        // checks serve auth and moderation (peers); a password change or an erasure
+        // request wants a recent second factor; the back-office surface needs
+        // `account:admin`. `DownloadDataExport` serves only the caller's own archive.
+        AccessPolicy::for_service(Self::GRPC_SERVICE_NAME)
+            .public("CreateAccount")
+            .authenticated("VerifyEmail")
+            .authenticated("VerifyPhone")
+            .step_up("ChangePassword", STEP_UP_MAX_AGE)
+            .authenticated("EnrollMfa")
+            .authenticated("RevokeMfa")
+            .authenticated("DeactivateAccount")
+            .step_up("RequestGdprDeletion", STEP_UP_MAX_AGE)
+            .authenticated("RequestDataExport")
+            .authenticated("DownloadDataExport")
+            .authenticated("GetAccountStatus")
+            .internal("RecordLogin")
+            .internal("VerifyMfaFactor")
+            .internal("RecordFailedLogin")
+            .internal("GetAccountById")
+            .internal("GetAccountByIdentityId")
+            .internal("ConfirmSubjectErasure")
+            .require("UpdateKycStatus", ACCOUNT_ADMIN)
+            .require("SuspendAccount", ACCOUNT_ADMIN)
+            .require("ReactivateAccount", ACCOUNT_ADMIN)
+            .require("AnonymizeAccount", ACCOUNT_ADMIN)
+            .require("AssignRole", ACCOUNT_ADMIN)
+            .require("RevokeRole", ACCOUNT_ADMIN)
+            .require("GetGdprRecord", ACCOUNT_ADMIN)
+            .require("ListAccountsByStatus", ACCOUNT_ADMIN)
+    }
+
     fn register(self, routes: &mut RoutesBuilder) -> anyhow::Result<()> {
         let handler = AccountServiceHandler::new(
             Arc::clone(&self.app.command_bus),
@@ -73,16 +191,116 @@ impl Service for AccountService {
     }
 }
 
-/// Builds the account event publisher: Kafka when `KAFKA_BROKERS` is set,
-/// otherwise a no-op log publisher (broker-free local/dev).
-fn build_publisher() -> anyhow::Result<Arc<dyn EventPublisher>> {
+/// Drops expired `account_idempotency` claims and marks once an hour, so the
+/// table holds about one retention window of commands.
+async fn purge_idempotency(store: PgIdempotencyStore, pool: PgPool) {
+    let mut tick = tokio::time::interval(Duration::from_secs(60 * 60));
+    loop {
+        tick.tick().await;
+        match store.purge_expired(&pool).await {
+            Ok(purged) => tracing::debug!(purged, "account idempotency purge"),
+            Err(e) => tracing::warn!(error = %e, "account idempotency purge failed"),
+        }
+    }
+}
+
+/// Wires the data-export pipeline: one lazily-connected, resilience-wrapped
+/// channel per owning service (keyed `<service>-export` in
+/// `[resilience.bindings]`), plus the envelope sealer and archive bucket the
+/// download path shares.
+fn build_export_handler(
+    infra: &InfraRegistry,
+    app: &App,
+    endpoints: &ExportEndpoints,
+    retention: Duration,
+    sealer: Arc<EnvelopeSealer>,
+    store: Arc<S3ExportArchiveStore>,
+) -> anyhow::Result<Arc<FulfilDataExportHandler>> {
+    let channel = |service: &str, endpoint: &str| -> anyhow::Result<ResilientChannel> {
+        GrpcClientBuilder::new(
+            GrpcClientConfig::new(endpoint).with_dependency(format!("{service}-export")),
+        )
+        .build_from_registry_lazy(&infra.resilience())
+        .map_err(|e| anyhow::anyhow!("account export {service} channel: {e}"))
+    };
+    let profile = channel("profile", &endpoints.profile)?;
+    let sources: Vec<Arc<dyn SubjectDataSource>> = vec![
+        Arc::new(ProfileExportSource::new(profile.clone())),
+        Arc::new(PostExportSource::new(channel("post", &endpoints.post)?)),
+        Arc::new(CommentExportSource::new(channel("comment", &endpoints.comment)?)),
+        Arc::new(ChatExportSource::new(channel("chat", &endpoints.chat)?)),
+        Arc::new(SocialGraphExportSource::new(channel("social-graph", &endpoints.social_graph)?)),
+        Arc::new(EngagementExportSource::new(channel("engagement", &endpoints.engagement)?)),
+        Arc::new(NotificationExportSource::new(channel("notification", &endpoints.notification)?)),
+        Arc::new(MediaExportSource::new(channel("media", &endpoints.media)?)),
+    ];
+
+    let retention = chrono::Duration::from_std(retention)
+        .context("account export retention out of range")?;
+    Ok(Arc::new(FulfilDataExportHandler::new(
+        Arc::clone(&app.repository),
+        Arc::new(GrpcProfileDirectory::new(profile)),
+        sources,
+        sealer,
+        store,
+        retention,
+    )))
+}
+
+/// Spawns the supervised data-export consumer.
+fn spawn_data_export_consumer(
+    handler: Arc<FulfilDataExportHandler>,
+    command_bus: Arc<AppCommandBus>,
+) {
+    tokio::spawn(async move {
+        loop {
+            match build_consumer(TOPIC_ACCOUNT_EVENTS, DATA_EXPORT_GROUP) {
+                Ok((consumer, producer)) => {
+                    run_data_export_consumer(
+                        consumer,
+                        Arc::clone(&handler),
+                        Arc::clone(&command_bus),
+                        producer,
+                    )
+                    .await;
+                    tracing::warn!("account data-export consumer exited; respawning after backoff");
+                }
+                Err(error) => {
+                    tracing::error!(%error, "failed to build data-export consumer; retrying")
+                }
+            }
+            tokio::time::sleep(CONSUMER_RESPAWN_BACKOFF).await;
+        }
+    });
+}
+
+/// Builds a manual-commit consumer (subscribed to `topic`) and the dead-letter
+/// producer the runner needs.
+fn build_consumer(
+    topic: &str,
+    group: &str,
+) -> anyhow::Result<(KafkaConsumerHandle, KafkaProducerHandle)> {
+    let kafka = KafkaClientConfig::from_env();
+    let consumer = KafkaConsumerBuilder::new(ConsumerConfig::new(kafka.clone(), group))
+        .subscribe(topic)
+        .build()
+        .with_context(|| format!("build consumer for {topic}"))?;
+    let producer = KafkaProducerBuilder::new(ProducerConfig::new(kafka))
+        .build()
+        .with_context(|| format!("build dead-letter producer for {topic}"))?;
+    Ok((consumer, producer))
+}
+
+/// Builds the outbox relay's sink: Kafka when `KAFKA_BROKERS` is set, otherwise
+/// a log sink (broker-free local/dev).
+fn build_sink() -> anyhow::Result<Arc<dyn OutboxSink>> {
     if std::env::var("KAFKA_BROKERS").is_ok() {
         let producer =
             KafkaProducerBuilder::new(ProducerConfig::new(KafkaClientConfig::from_env()))
                 .build()
                 .map_err(|e| anyhow::anyhow!("account kafka producer: {e}"))?;
-        Ok(Arc::new(KafkaEventPublisher::new(producer)))
+        Ok(Arc::new(KafkaOutboxSink::new(producer)))
     } else {
-        Ok(Arc::new(LogEventPublisher))
+        Ok(Arc::new(LogOutboxSink))
     }
 }
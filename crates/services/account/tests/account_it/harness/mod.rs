@@ -11,17 +11,23 @@
 use std::sync::Arc;
 use std::time::Duration;
 
+use async_trait::async_trait;
 use uuid::Uuid;
 
-use cqrs::command::InMemoryCommandBus;
-use cqrs::query::InMemoryQueryBus;
 use cqrs::{CommandBus, CqrsError, Envelope, QueryBus};
 use postgres_storage::config::StatementLogLevel;
+use outbox::LogOutboxSink;
 use postgres_storage::{PgPoolBuilder, PostgresConfig};
+use sqlx::PgPool;
 
-use account::app::App;
+use account::app::{App, AppCommandBus, AppQueryBus};
 use account::application::command::{CreateAccountCommand, RecordLoginCommand, VerifyEmailCommand};
+use account::application::port::{AccountRepository, ExportArchiveStore, ProfileDirectory};
 use account::application::query::{AccountView, GetAccountByIdentityIdQuery};
+use account::domain::value_object::AccountId;
+use account::error::AccountError;
+use account::infrastructure::export::EnvelopeSealer;
+use account::infrastructure::mfa::TotpSecondFactorVerifier;
 
 pub use test_support::await_until;
 
@@ -31,10 +37,13 @@ pub const DEADLINE: Duration = Duration::from_secs(10);
 /// On-disk migration assets, resolved against *this* crate's manifest.
 const MIGRATIONS_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/migrations");
 
-/// A fully-wired account service bound to ephemeral Postgres, plus the buses.
+/// A fully-wired account service bound to ephemeral Postgres, plus the buses,
+/// the repository (to write past the bus) and the pool (for direct outbox assertions).
 pub struct TestHarness {
-    pub command_bus: Arc<InMemoryCommandBus>,
-    pub query_bus:   Arc<InMemoryQueryBus>,
+    pub command_bus: Arc<AppCommandBus>,
+    pub query_bus:   Arc<AppQueryBus>,
+    pub repository:  Arc<dyn AccountRepository>,
+    pub pool:        PgPool,
 }
 
 impl TestHarness {
@@ -55,13 +64,20 @@ impl TestHarness {
         };
         let pool = PgPoolBuilder::build(config).await.expect("integration: Postgres pool");
 
-        // Log publisher — this suite tests account behaviour, not event emission.
-        let publisher = std::sync::Arc::new(account::infrastructure::event::LogEventPublisher);
-        let app = App::build(pool, publisher)
-            .await
-            .expect("integration: build account app");
-
-        Self { command_bus: app.command_bus, query_bus: app.query_bus }
+        // Log sink, and the relay is never spawned — scenarios that care about
+        // event emission assert on `account_outbox` directly.
+        let app = App::build(
+            pool.clone(),
+            Arc::new(LogOutboxSink),
+            Arc::new(NoProfiles),
+            Arc::new(TotpSecondFactorVerifier::new([0; 32])),
+            Arc::new(NoArchives),
+            Arc::new(EnvelopeSealer::new([0; 32], [0; 32])),
+        )
+        .await
+        .expect("integration: build account app");
+
+        Self { command_bus: app.command_bus, query_bus: app.query_bus, repository: app.repository, pool }
     }
 
     /// Creates an account, expecting success.
@@ -105,10 +121,34 @@ impl TestHarness {
     }
 }
 
+/// Profile is not part of the Postgres suite: every account owns no profiles.
+struct NoProfiles;
+
+#[async_trait]
+impl ProfileDirectory for NoProfiles {
+    async fn profile_ids(&self, _account_id: &AccountId) -> Result<Vec<String>, AccountError> {
+        Ok(Vec::new())
+    }
+}
+
+/// The suite runs no export pipeline, so no archive is ever stored.
+struct NoArchives;
+
+#[async_trait]
+impl ExportArchiveStore for NoArchives {
+    async fn put(&self, _key: &str, _sealed: Vec<u8>) -> Result<(), AccountError> {
+        Ok(())
+    }
+
+    async fn get(&self, _key: &str) -> Result<Option<Vec<u8>>, AccountError> {
+        Ok(None)
+    }
+}
+
 /// Dispatches a create on a shared bus — a free function so scenarios can fire
 /// many concurrently from spawned tasks.
 pub async fn dispatch_create(
-    command_bus: Arc<InMemoryCommandBus>,
+    command_bus: Arc<AppCommandBus>,
     identity_id: String,
     email:       String,
 ) -> Result<(), CqrsError> {
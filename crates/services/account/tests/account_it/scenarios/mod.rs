@@ -1,6 +1,8 @@
 //! Scenario groups for the account live suite, mapping to the testing standard's
-//! axes: concurrency (uniqueness race) and durable persistence.
+//! axes: concurrency (uniqueness race), durable persistence, and transactional
+//! event emission (outbox).
 
 mod persistence_roundtrip;
 mod uniqueness_race;
 mod mutation_roundtrip;
+mod outbox_atomicity;
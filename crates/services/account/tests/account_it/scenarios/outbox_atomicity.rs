@@ -0,0 +1,124 @@
+//! Scenario — transactional event emission.
+//!
+//! The repository enqueues the aggregate's events into `account_outbox` inside
+//! the write's own transaction. Two consequences are asserted here: a committed
+//! change always has its event rows, and a write that rolls back — the losers of
+//! an email-uniqueness race — leaves no event behind. Before the outbox, the
+//! publish ran after the commit and could be lost on a crash in between.
+//!
+//! The same transaction marks the dispatch's idempotency key processed, so a
+//! crash after the commit but before the layer's `complete` cannot re-run it.
+//!
+//! The relay is never ticked (draining is covered by the `outbox` crate's own
+//! suite), so the rows stay put for the assertions.
+
+use std::sync::Arc;
+
+use cqrs::{CommandBus, Envelope};
+use uuid::Uuid;
+
+use account::application::command::VerifyEmailCommand;
+use account::domain::value_object::AccountId;
+
+use crate::account_it::harness::{self, TestHarness, DEADLINE};
+
+async fn outbox_rows(h: &TestHarness, account_id: &str) -> Vec<String> {
+    sqlx::query_scalar(
+        "SELECT event_type FROM account_outbox WHERE aggregate_key = $1 ORDER BY seq",
+    )
+    .bind(account_id)
+    .fetch_all(&h.pool)
+    .await
+    .expect("read account_outbox")
+}
+
+#[tokio::test]
+async fn committed_changes_enqueue_their_events_in_order() {
+    let h = TestHarness::start().await;
+    let identity = harness::random_identity();
+    h.create(&identity, &harness::random_email()).await;
+
+    let identity_q = identity.clone();
+    harness::await_until("account readable", DEADLINE, || {
+        let h = &h;
+        let identity_q = identity_q.clone();
+        async move { h.get_by_identity(&identity_q).await.is_ok() }
+    })
+    .await;
+    let view = h.get_by_identity(&identity).await.expect("account exists");
+    h.verify_email(&view.id).await.expect("verify_email");
+
+    let rows = outbox_rows(&h, &view.id).await;
+    assert_eq!(rows.first().map(String::as_str), Some("account.created"));
+    assert!(rows.iter().any(|t| t == "account.email_verified"), "got {rows:?}");
+}
+
+#[tokio::test]
+async fn a_rolled_back_create_leaves_no_event() {
+    let h = TestHarness::start().await;
+    let email = harness::random_email();
+    let identities: Vec<String> = (0..4).map(|_| harness::random_identity()).collect();
+
+    let mut handles = Vec::new();
+    for identity in &identities {
+        let bus = Arc::clone(&h.command_bus);
+        handles.push(tokio::spawn(harness::dispatch_create(bus, identity.clone(), email.clone())));
+    }
+    let winners = count_committed(handles).await;
+    assert_eq!(winners, 1, "exactly one create may commit");
+
+    let created: i64 = sqlx::query_scalar(
+        "SELECT count(*) FROM account_outbox \
+         WHERE event_type = 'account.created' AND payload->>'identity_id' = ANY($1)",
+    )
+    .bind(&identities)
+    .fetch_one(&h.pool)
+    .await
+    .expect("count created events");
+    assert_eq!(created, 1, "only the committed create may have an event");
+}
+
+#[tokio::test]
+async fn a_write_that_committed_is_not_rerun_when_complete_never_ran() {
+    let h = TestHarness::start().await;
+    let identity = harness::random_identity();
+    h.create(&identity, &harness::random_email()).await;
+    let view = h.get_by_identity(&identity).await.expect("account exists");
+
+    // The handler's write for dispatch `key`, with the process dying before the
+    // layer's `complete`: only the repository's in-transaction mark is left.
+    let key = Uuid::now_v7();
+    let id = AccountId::from_uuid(view.id.parse().expect("account id"));
+    let mut account = h.repository.find_by_id(&id).await.expect("load").expect("account exists");
+    account.verify_email(Uuid::now_v7()).expect("verify_email");
+    h.repository.save(&account, key).await.expect("save");
+
+    // The redelivery is answered from the mark instead of running again.
+    h.command_bus
+        .dispatch(
+            Envelope::new(Uuid::now_v7(), VerifyEmailCommand { account_id: view.id.clone() })
+                .with_message_id(key),
+        )
+        .await
+        .expect("the redelivery replays");
+    let rows = outbox_rows(&h, &view.id).await;
+    assert_eq!(rows.iter().filter(|t| *t == "account.email_verified").count(), 1, "got {rows:?}");
+
+    // A stale copy of the same dispatch cannot write past the mark either.
+    let mut stale = h.repository.find_by_id(&id).await.expect("load").expect("account exists");
+    stale.record_login();
+    let err = h.repository.save(&stale, key).await.expect_err("a settled key is a conflict");
+    assert!(matches!(err, account::error::AccountError::ConcurrentModification), "got {err:?}");
+}
+
+async fn count_committed(
+    handles: Vec<tokio::task::JoinHandle<Result<(), cqrs::CqrsError>>>,
+) -> usize {
+    let mut ok = 0;
+    for handle in handles {
+        if handle.await.expect("join").is_ok() {
+            ok += 1;
+        }
+    }
+    ok
+}
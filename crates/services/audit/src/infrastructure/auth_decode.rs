@@ -3,8 +3,9 @@
 //! not depend on the `auth` crate), so the wire shapes are lenient structs
 //! hand-matched to auth's published JSON.
 //!
-//! Scope (first cut): `session_issued` and `session_revoked` — the authentication
-//! lifecycle. Every other auth event (e.g. `subject_linked`) is a benign skip.
+//! Scope: `session_issued` and `session_revoked` — the authentication lifecycle —
+//! plus the `refresh_token_reuse_detected` security signal. Every other auth event
+//! (e.g. `subject_linked`, `new_device_login`) is a benign skip.
 //!
 //! Unlike moderation, auth events carry **no free-text PII** — only structured
 //! metadata over pseudonymous ids (the account id is the subject pseudonym; audit
@@ -38,6 +39,7 @@ const SOURCE: &str = "auth";
 pub enum AuthEventWire {
     SessionIssued(SessionIssuedWire),
     SessionRevoked(SessionRevokedWire),
+    RefreshTokenReuseDetected(RefreshTokenReuseDetectedWire),
     #[serde(other)]
     Other,
 }
@@ -67,6 +69,19 @@ pub struct SessionRevokedWire {
     pub correlation_id: String,
 }
 
+#[derive(Debug, Clone, Deserialize)]
+pub struct RefreshTokenReuseDetectedWire {
+    pub session_id: String,
+    pub account_id: String,
+    #[serde(default)]
+    pub generation: i64,
+    #[serde(default)]
+    pub country: Option<String>,
+    pub occurred_at: DateTime<Utc>,
+    #[serde(default)]
+    pub correlation_id: String,
+}
+
 // ── Mapping ────────────────────────────────────────────────────────────────────
 
 /// A successful login → an `Authentication` record. The actor is the authenticated
@@ -138,6 +153,48 @@ pub fn map_session_revoked(wire: &SessionRevokedWire) -> Result<AuditEvent, Audi
     })
 }
 
+/// A spent refresh token presented again → an `Authentication` record denied by
+/// the system. The presenter's user agent and city are deliberately not read;
+/// the coarse country is enough to place the attempt.
+pub fn map_refresh_token_reuse_detected(
+    wire: &RefreshTokenReuseDetectedWire,
+) -> Result<AuditEvent, AuditError> {
+    let generation = wire.generation.to_string();
+    let mut attributes = BTreeMap::new();
+    attributes.insert("session_id".to_owned(), wire.session_id.clone());
+    attributes.insert("generation".to_owned(), generation.clone());
+    if let Some(country) = &wire.country {
+        attributes.insert("country".to_owned(), country.clone());
+    }
+
+    AuditEvent::try_new(NewAuditEvent {
+        // A session can be replayed against more than once; each detection bumps
+        // the generation, so it keys the record.
+        event_id: EventId::new(derive_id(&[
+            "auth.refresh_token_reuse_detected",
+            &wire.session_id,
+            &generation,
+        ]))?,
+        category: EventCategory::Authentication,
+        subject: Some(SubjectPseudonym::new(wire.account_id.clone())?),
+        tenant: None,
+        actor: Actor::new(
+            ActorType::System,
+            ActorPseudonym::new(SOURCE.to_owned())?,
+            wire.session_id.clone(),
+        ),
+        action: "auth.refresh_token_reuse_detected".to_owned(),
+        resource: ResourceRef::new("session", wire.session_id.clone()),
+        outcome: Outcome::Denied,
+        lawful_basis: LawfulBasis::Unspecified,
+        source_service: SOURCE.to_owned(),
+        correlation_id: wire.correlation_id.clone(),
+        occurred_at: wire.occurred_at,
+        pii: None,
+        attributes,
+    })
+}
+
 fn derive_id(parts: &[&str]) -> String {
     Uuid::new_v5(&NS_AUDIT_AUTH, parts.join(":").as_bytes()).to_string()
 }
@@ -183,6 +240,25 @@ mod tests {
         .unwrap()
     }
 
+    fn reuse_detected(generation: i64) -> RefreshTokenReuseDetectedWire {
+        serde_json::from_value(serde_json::json!({
+            "type": "refresh_token_reuse_detected",
+            "session_id": "11111111-1111-1111-1111-111111111111",
+            "account_id": "22222222-2222-2222-2222-222222222222",
+            "generation": generation,
+            "user_agent": "Mozilla/5.0",
+            "country": "FR",
+            "city": null,
+            "occurred_at": "2026-06-26T12:00:00Z",
+            "correlation_id": "33333333-3333-3333-3333-333333333333"
+        }))
+        .map(|w| match w {
+            AuthEventWire::RefreshTokenReuseDetected(r) => r,
+            _ => panic!("expected RefreshTokenReuseDetected"),
+        })
+        .unwrap()
+    }
+
     #[test]
     fn session_issued_maps_to_an_authentication_record_with_no_pii() {
         let event = map_session_issued(&issued()).unwrap();
@@ -217,6 +293,21 @@ mod tests {
         assert_ne!(i1.event_id(), map_session_revoked(&revoked("logout")).unwrap().event_id());
     }
 
+    #[test]
+    fn refresh_reuse_is_a_denied_system_record_keyed_by_generation() {
+        let event = map_refresh_token_reuse_detected(&reuse_detected(4)).unwrap();
+        assert_eq!(event.action(), "auth.refresh_token_reuse_detected");
+        assert_eq!(event.actor().actor_type, ActorType::System);
+        assert_eq!(event.outcome(), Outcome::Denied);
+        assert_eq!(event.attributes().get("country").unwrap(), "FR");
+        assert!(!event.attributes().contains_key("user_agent"));
+        assert!(!event.has_pii());
+        assert_ne!(
+            event.event_id(),
+            map_refresh_token_reuse_detected(&reuse_detected(5)).unwrap().event_id()
+        );
+    }
+
     #[test]
     fn subject_linked_decodes_to_other() {
         let wire: AuthEventWire =
@@ -27,7 +27,9 @@ use crate::infrastructure::account_decode::{
     map_mfa_enrolled, map_mfa_revoked, map_password_changed, map_phone_changed, map_role_assigned,
     map_role_revoked,
 };
-use crate::infrastructure::auth_decode::{AuthEventWire, map_session_issued, map_session_revoked};
+use crate::infrastructure::auth_decode::{
+    AuthEventWire, map_refresh_token_reuse_detected, map_session_issued, map_session_revoked,
+};
 use crate::infrastructure::decode::{AuditEventWire, map_audit_event};
 use crate::infrastructure::moderation_decode::{
     ModerationEventWire, map_decision_recorded, map_enforcement_applied,
@@ -176,9 +178,9 @@ pub async fn run_account_ingest_consumer(
 }
 
 /// Run the `auth.v1.events` ingest consumer until the stream ends. Auth events
-/// carry no free-text PII, so there is no sealing — `session_issued` /
-/// `session_revoked` map directly and chain; every other auth event is a benign
-/// committed skip.
+/// carry no free-text PII, so there is no sealing — `session_issued`,
+/// `session_revoked` and `refresh_token_reuse_detected` map directly and chain;
+/// every other auth event is a benign committed skip.
 pub async fn run_auth_ingest_consumer(
     consumer: KafkaConsumerHandle,
     producer: KafkaProducerHandle,
@@ -198,6 +200,9 @@ pub async fn run_auth_ingest_consumer(
                     AuthEventWire::SessionRevoked(revoked) => {
                         handler.ingest(map_session_revoked(&revoked)?).await?;
                     }
+                    AuthEventWire::RefreshTokenReuseDetected(reuse) => {
+                        handler.ingest(map_refresh_token_reuse_detected(&reuse)?).await?;
+                    }
                     AuthEventWire::Other => {}
                 }
                 Ok::<(), AuditError>(())
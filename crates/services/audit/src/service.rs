@@ -19,7 +19,7 @@ use std::time::Duration;
 
 use anyhow::Context;
 use async_trait::async_trait;
-use service_runtime::{HealthProbe, InfraRegistry, Service};
+use service_runtime::{AccessPolicy, HealthProbe, InfraRegistry, Service};
 use sqlx::PgPool;
 use tonic::service::RoutesBuilder;
 use tonic_reflection::server::Builder as ReflectionBuilder;
@@ -92,6 +92,16 @@ impl Service for AuditServerService {
         vec![postgres_storage::health::probe(self.pool.clone())]
     }
 
+    fn access_policy(&self) -> AccessPolicy {
+        // Authentication only: the handler keeps the per-RPC `audit:*` checks so a
+        // missing permission still surfaces as its own AUD-3xxx denial.
+        AccessPolicy::for_service(Self::GRPC_SERVICE_NAME)
+            .authenticated("RecordPrivileged")
+            .authenticated("Query")
+            .authenticated("Export")
+            .authenticated("VerifyIntegrity")
+    }
+
     fn register(self, routes: &mut RoutesBuilder) -> anyhow::Result<()> {
         let reflection = ReflectionBuilder::configure()
             .register_encoded_file_descriptor_set(FILE_DESCRIPTOR_SET)
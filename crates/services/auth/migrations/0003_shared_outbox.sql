@@ -0,0 +1,64 @@
+-- Moves auth_outbox onto the shared `outbox` crate's schema (OutboxTable::ddl).
+--
+-- WHY: the hand-rolled relay claimed ONE global batch with FOR UPDATE SKIP
+-- LOCKED. With two replicas, two consecutive batches could be in flight at once
+-- and a later event for an account could reach Kafka before an earlier one.
+-- The shared relay leases hash slots of the aggregate key instead, so exactly
+-- one replica publishes a given account at a time.
+--
+-- Pending rows are backfilled in place: the topic was implicit (auth only ever
+-- published auth.v1.events), the key is the payload's account_id (every auth
+-- event carries it at top level), and the `account_id` header the old Kafka
+-- adapter set is now stored on the row. Legacy rows all land in slot 0 —
+-- SeaHash is not computable in SQL — which only matters for a backlog still
+-- pending at deploy time; the old relay drains it during the rolling update.
+--
+-- Old pods keep inserting `(id, event_type, payload)` until the rollout
+-- completes, so nothing here may reject that shape: `topic` and `slot` keep
+-- their defaults, `aggregate_key` stays nullable, and a trigger derives the key
+-- and header for such rows the same way as the backfill (the shared relay
+-- reads the key as NOT NULL). Tightening the columns and dropping the trigger
+-- belongs to a later migration, shipped once no pre-0003 pod remains.
+ALTER TABLE auth_outbox
+    ADD COLUMN IF NOT EXISTS seq           BIGSERIAL,
+    ADD COLUMN IF NOT EXISTS topic         TEXT  NOT NULL DEFAULT 'auth.v1.events',
+    ADD COLUMN IF NOT EXISTS aggregate_key TEXT,
+    ADD COLUMN IF NOT EXISTS slot          INT   NOT NULL DEFAULT 0,
+    ADD COLUMN IF NOT EXISTS headers       JSONB NOT NULL DEFAULT '{}',
+    ADD COLUMN IF NOT EXISTS attempts      INT   NOT NULL DEFAULT 0,
+    ADD COLUMN IF NOT EXISTS last_error    TEXT;
+
+UPDATE auth_outbox
+   SET aggregate_key = payload->>'account_id',
+       headers       = jsonb_build_object('account_id', payload->>'account_id')
+ WHERE aggregate_key IS NULL;
+
+CREATE OR REPLACE FUNCTION auth_outbox_legacy_key() RETURNS trigger AS $$
+BEGIN
+    IF NEW.aggregate_key IS NULL THEN
+        NEW.aggregate_key := NEW.payload->>'account_id';
+        NEW.headers       := jsonb_build_object('account_id', NEW.payload->>'account_id');
+    END IF;
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+DROP TRIGGER IF EXISTS auth_outbox_legacy_key ON auth_outbox;
+CREATE TRIGGER auth_outbox_legacy_key
+    BEFORE INSERT ON auth_outbox
+    FOR EACH ROW EXECUTE FUNCTION auth_outbox_legacy_key();
+
+DROP INDEX IF EXISTS auth_outbox_drain_order;
+CREATE INDEX IF NOT EXISTS auth_outbox_drain_order
+    ON auth_outbox (slot, seq);
+
+CREATE TABLE IF NOT EXISTS auth_outbox_lease (
+    slot       INT         PRIMARY KEY,
+    owner      TEXT        NOT NULL DEFAULT '',
+    expires_at TIMESTAMPTZ NOT NULL DEFAULT 'epoch'
+);
+
+CREATE TABLE IF NOT EXISTS auth_outbox_member (
+    owner      TEXT        PRIMARY KEY,
+    expires_at TIMESTAMPTZ NOT NULL
+);
@@ -0,0 +1,21 @@
+-- Records how each session was authenticated, for the edge token's `amr`,
+-- `acr` and `auth_time` claims.
+--
+-- WHY: step-up rules (password change, GDPR deletion) demand a second factor
+-- presented within the last few minutes. The token can only assert that if the
+-- session remembers which factors it has seen and when the last one was
+-- presented — and it must survive a refresh, which re-mints from this row.
+--
+-- `acr` is not stored: it is derived from `amr` (a second factor ⇒ aal2).
+-- Existing sessions predate second factors; they are backfilled as a single
+-- first factor authenticated at issue time. Password vs federated cannot be
+-- recovered for them, so they carry `fed` — the default grant.
+ALTER TABLE sessions
+    ADD COLUMN IF NOT EXISTS amr        TEXT[]       NOT NULL DEFAULT '{fed}',
+    ADD COLUMN IF NOT EXISTS auth_time  TIMESTAMPTZ;
+
+UPDATE sessions SET auth_time = issued_at WHERE auth_time IS NULL;
+
+ALTER TABLE sessions
+    ALTER COLUMN amr DROP DEFAULT,
+    ALTER COLUMN auth_time SET NOT NULL;
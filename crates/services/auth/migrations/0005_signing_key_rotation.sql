@@ -0,0 +1,36 @@
+-- Scheduled rotation of the edge-token signing keys.
+--
+-- WHY: the signing key used to come from config and rotating it was a
+-- redeploy. The key rotator now generates keys itself and walks each one
+-- through `published → active → retiring → retired`:
+--
+--   published  in the JWKS, not yet signing — verifiers learn it first
+--   active     signing new tokens (at most one)
+--   retiring   superseded; still in the JWKS until `not_after`, when the last
+--              token it can have signed has expired
+--   retired    out of the ring; `private_pem_enc` is dropped
+--
+-- `private_pem_enc` is the private PEM wrapped by the key custodian (local
+-- AES-256-GCM KEK or KMS); it is NULL for a verify-only key. `activated_at`
+-- starts the rotation clock. The partial unique indexes make the `active` and
+-- `published` slots single-occupancy, so replicas racing the same step settle
+-- on one winner.
+--
+-- The 0001 default status `active` predates the lifecycle and is dropped:
+-- every insert now states the stage it enters.
+ALTER TABLE signing_keys
+    ADD COLUMN IF NOT EXISTS activated_at TIMESTAMPTZ;
+
+UPDATE signing_keys SET activated_at = created_at
+    WHERE status = 'active' AND activated_at IS NULL;
+
+ALTER TABLE signing_keys
+    ALTER COLUMN status DROP DEFAULT,
+    ADD CONSTRAINT signing_keys_status_check
+        CHECK (status IN ('published', 'active', 'retiring', 'retired'));
+
+CREATE UNIQUE INDEX IF NOT EXISTS uq_signing_keys_active
+    ON signing_keys ((TRUE)) WHERE status = 'active';
+
+CREATE UNIQUE INDEX IF NOT EXISTS uq_signing_keys_published
+    ON signing_keys ((TRUE)) WHERE status = 'published';
@@ -0,0 +1,54 @@
+-- Device and session management for end users.
+--
+-- WHY: the device-management view could list sessions but not say where each
+-- one was last used, and nothing remembered which devices an account had
+-- signed in from, so a login from a new one went unnoticed.
+--
+-- `device_*` stay the context bound at login; `last_seen_*` are that context
+-- as most recently presented (login, then every Refresh). `*_country` is the
+-- ISO 3166-1 alpha-2 code the edge resolved from the client IP, `*_city` its
+-- city; this service does no GeoIP lookup itself. Existing sessions start out
+-- last seen at login.
+ALTER TABLE sessions
+    ADD COLUMN IF NOT EXISTS device_country        TEXT,
+    ADD COLUMN IF NOT EXISTS device_city           TEXT,
+    ADD COLUMN IF NOT EXISTS last_seen_at          TIMESTAMPTZ,
+    ADD COLUMN IF NOT EXISTS last_seen_user_agent  TEXT,
+    ADD COLUMN IF NOT EXISTS last_seen_ip          TEXT,
+    ADD COLUMN IF NOT EXISTS last_seen_country     TEXT,
+    ADD COLUMN IF NOT EXISTS last_seen_city        TEXT;
+
+UPDATE sessions
+    SET last_seen_at = issued_at,
+        last_seen_user_agent = device_user_agent,
+        last_seen_ip = device_ip
+    WHERE last_seen_at IS NULL;
+
+ALTER TABLE sessions
+    ALTER COLUMN last_seen_at SET NOT NULL;
+
+-- ── Known devices ────────────────────────────────────────────────────────────
+-- Every device an account has signed in from, keyed by its recognition key:
+-- `id:<device_id>` when the client sends one, else `ua:<user agent>`. A login
+-- whose key is missing here is a new-device login. Rows outlive the sessions,
+-- so revoking or expiring a device's sessions does not make it new again.
+CREATE TABLE IF NOT EXISTS known_devices (
+    account_id      UUID         NOT NULL,
+    device_key      TEXT         NOT NULL,
+    first_seen_at   TIMESTAMPTZ  NOT NULL,
+    last_seen_at    TIMESTAMPTZ  NOT NULL,
+    PRIMARY KEY (account_id, device_key)
+);
+
+-- Seeded from session history, so existing accounts' devices are not all
+-- reported as new on their next login.
+INSERT INTO known_devices (account_id, device_key, first_seen_at, last_seen_at)
+SELECT account_id,
+       CASE WHEN device_id IS NOT NULL THEN 'id:' || device_id
+            ELSE 'ua:' || device_user_agent END,
+       MIN(issued_at),
+       MAX(issued_at)
+FROM sessions
+WHERE device_id IS NOT NULL OR device_user_agent IS NOT NULL
+GROUP BY 1, 2
+ON CONFLICT DO NOTHING;
@@ -1,6 +1,6 @@
 //! The auth service's composition root.
 //!
-//! [`App::compose`] is *pure* wiring: eight port handles in, a fully-assembled
+//! [`App::compose`] is *pure* wiring: ten port handles in, a fully-assembled
 //! gRPC handler out — it binds no socket and reads no environment, so the live
 //! integration harness and the binary entrypoint build the exact same graph.
 //! [`App::build`] is the I/O variant that constructs the concrete adapters from
@@ -8,6 +8,9 @@
 
 use std::sync::Arc;
 
+use outbox::{
+    KafkaOutboxSink, LogOutboxSink, OutboxRelay, OutboxSink, OutboxTable, PgOutbox, RelayConfig,
+};
 use postgres_storage::{PgPoolBuilder, PostgresConfig, TransactionManager};
 use redis_storage::{RedisClient, RedisClientBuilder, RedisConfig};
 use sqlx::PgPool;
@@ -17,37 +20,39 @@ use transport::kafka::config::producer::ProducerConfig;
 use transport::kafka::producer::KafkaProducerBuilder;
 
 use crate::application::command::{
-    LoginHandler, LogoutAllSessionsHandler, LogoutHandler, RefreshHandler,
+    LoginHandler, LogoutAllSessionsHandler, LogoutHandler, RefreshHandler, RevokeSessionHandler,
+    StepUpHandler, VerifySecondFactorHandler,
 };
 use crate::application::port::{
-    AccountDirectory, EventPublisher, IdentityProvider, RefreshTokenRepository, SessionCache,
-    SessionRepository, SubjectLinkRepository, TokenMinter,
+    AccountDirectory, EventPublisher, IdentityProvider, KnownDeviceRepository, MfaChallengeStore,
+    RefreshTokenRepository, SessionCache, SessionRepository, SubjectLinkRepository, TokenMinter,
 };
 use crate::application::query::{IntrospectHandler, ListSessionsHandler};
 use crate::application::SessionPolicy;
 use crate::config::AuthConfig;
-use crate::infrastructure::cache::RedisSessionCache;
+use crate::infrastructure::cache::{RedisMfaChallengeStore, RedisSessionCache};
 use crate::infrastructure::directory::GrpcAccountDirectory;
-use crate::infrastructure::event::outbox_relay::OutboxRelay;
-use crate::infrastructure::event::pg_outbox_publisher::PgOutboxPublisher;
-use crate::infrastructure::event::{KafkaEventPublisher, LogEventPublisher};
+use crate::infrastructure::event::{PgOutboxPublisher, OUTBOX_PREFIX};
 use crate::infrastructure::grpc::handler::AuthServiceHandler;
 use crate::infrastructure::idp::KeycloakIdentityProvider;
 use crate::infrastructure::persistence::{
-    PgRefreshTokenRepository, PgSessionRepository, PgSubjectLinkRepository,
+    PgKnownDeviceRepository, PgRefreshTokenRepository, PgSessionRepository, PgSigningKeyStore,
+    PgSubjectLinkRepository,
 };
-use crate::infrastructure::token::Es256TokenMinter;
+use crate::infrastructure::token::{Es256TokenMinter, EsKeyMaterial, KeyRotator, LocalKek};
 
-/// The eight ports the application layer depends on, plus the token policy.
+/// The ten ports the application layer depends on, plus the token policy.
 pub struct AppDeps {
     pub idp: Arc<dyn IdentityProvider>,
     pub directory: Arc<dyn AccountDirectory>,
     pub links: Arc<dyn SubjectLinkRepository>,
     pub sessions: Arc<dyn SessionRepository>,
+    pub known_devices: Arc<dyn KnownDeviceRepository>,
     pub refresh_tokens: Arc<dyn RefreshTokenRepository>,
     pub cache: Arc<dyn SessionCache>,
     pub minter: Arc<dyn TokenMinter>,
     pub publisher: Arc<dyn EventPublisher>,
+    pub challenges: Arc<dyn MfaChallengeStore>,
     pub policy: SessionPolicy,
 }
 
@@ -64,18 +69,21 @@ pub struct App {
     pub handler: AuthServiceHandler,
     pub pool: PgPool,
     pub redis: RedisClient,
-    /// The key ring's JWKS, serialized once at build (the ring is fixed for the
-    /// process lifetime — rotation is a redeploy). Served over HTTP by the
-    /// runtime host (see `infrastructure::http::jwks`) for downstream
-    /// verifiers (realtime, audit) that fetch `AUTH_JWKS_URL`.
-    pub jwks_json: String,
-    /// Drains the auth_outbox table to the broker; spawned by the runtime
-    /// adapter. Handlers never touch the broker directly anymore.
+    /// The concrete minter behind the handlers' `TokenMinter` port — also the
+    /// live JWKS source served over HTTP by the runtime host (see
+    /// `infrastructure::http::jwks`) for downstream verifiers (realtime, audit)
+    /// that fetch `AUTH_JWKS_URL`.
+    pub minter: Arc<Es256TokenMinter>,
+    /// Rotates the signing key on schedule and reinstalls `minter`'s ring when
+    /// the persisted one changes; spawned by the runtime adapter.
+    pub rotator: KeyRotator,
+    /// Drains the auth_outbox table to the broker (the shared `outbox` relay);
+    /// spawned by the runtime adapter. Handlers never touch the broker directly.
     pub relay: OutboxRelay,
 }
 
 impl App {
-    /// Pure composition: assemble the six application handlers from the ports and
+    /// Pure composition: assemble the nine application handlers from the ports and
     /// wrap them in the gRPC handler. No I/O — drives the unit/integration graph.
     pub fn compose(deps: AppDeps) -> AuthServiceHandler {
         let login = Arc::new(LoginHandler::new(
@@ -83,6 +91,19 @@ impl App {
             Arc::clone(&deps.directory),
             Arc::clone(&deps.links),
             Arc::clone(&deps.sessions),
+            Arc::clone(&deps.known_devices),
+            Arc::clone(&deps.refresh_tokens),
+            Arc::clone(&deps.cache),
+            Arc::clone(&deps.minter),
+            Arc::clone(&deps.publisher),
+            Arc::clone(&deps.challenges),
+            deps.policy.clone(),
+        ));
+        let verify_second_factor = Arc::new(VerifySecondFactorHandler::new(
+            Arc::clone(&deps.directory),
+            Arc::clone(&deps.challenges),
+            Arc::clone(&deps.sessions),
+            Arc::clone(&deps.known_devices),
             Arc::clone(&deps.refresh_tokens),
             Arc::clone(&deps.cache),
             Arc::clone(&deps.minter),
@@ -98,6 +119,14 @@ impl App {
             Arc::clone(&deps.publisher),
             deps.policy.clone(),
         ));
+        let step_up = Arc::new(StepUpHandler::new(
+            Arc::clone(&deps.directory),
+            Arc::clone(&deps.challenges),
+            Arc::clone(&deps.sessions),
+            Arc::clone(&deps.cache),
+            Arc::clone(&deps.minter),
+            deps.policy.clone(),
+        ));
         let logout = Arc::new(LogoutHandler::new(
             Arc::clone(&deps.sessions),
             Arc::clone(&deps.refresh_tokens),
@@ -115,8 +144,26 @@ impl App {
         let introspect =
             Arc::new(IntrospectHandler::new(Arc::clone(&deps.minter), Arc::clone(&deps.cache)));
         let list_sessions = Arc::new(ListSessionsHandler::new(Arc::clone(&deps.sessions)));
+        let revoke_session = Arc::new(RevokeSessionHandler::new(
+            Arc::clone(&deps.sessions),
+            Arc::clone(&deps.refresh_tokens),
+            Arc::clone(&deps.cache),
+            Arc::clone(&deps.minter),
+            Arc::clone(&deps.publisher),
+            deps.policy.clone(),
+        ));
 
-        AuthServiceHandler::new(login, refresh, logout, logout_all, introspect, list_sessions)
+        AuthServiceHandler::new(
+            login,
+            verify_second_factor,
+            refresh,
+            step_up,
+            logout,
+            logout_all,
+            introspect,
+            list_sessions,
+            revoke_session,
+        )
     }
 
     /// Builds the concrete adapter graph from config + backend connections.
@@ -133,15 +180,17 @@ impl App {
         // session writes) and the relay drains it in the background — TIER-0
         // login no longer hangs or fails on broker trouble, and a committed
         // session can't lose its compliance event (audit consumes these).
-        let sink: Arc<dyn EventPublisher> = match backends.kafka {
+        let sink: Arc<dyn OutboxSink> = match backends.kafka {
             Some(cfg) => {
                 let producer = KafkaProducerBuilder::new(ProducerConfig::new(cfg)).build()?;
-                Arc::new(KafkaEventPublisher::new(producer))
+                Arc::new(KafkaOutboxSink::new(producer))
             }
-            None => Arc::new(LogEventPublisher),
+            None => Arc::new(LogOutboxSink),
         };
-        let relay = OutboxRelay::new(pool.clone(), sink);
-        let publisher: Arc<dyn EventPublisher> = Arc::new(PgOutboxPublisher::new(pool.clone()));
+        let table = OutboxTable::new(OUTBOX_PREFIX)?;
+        let relay = OutboxRelay::new(pool.clone(), table.clone(), sink, RelayConfig::from_env());
+        let publisher: Arc<dyn EventPublisher> =
+            Arc::new(PgOutboxPublisher::new(PgOutbox::new(table, tx.clone())));
 
         // Lazy connect: the channel dials `account` on first use, so a cold start
         // does not require the dependency to be up at boot. Both deadlines are
@@ -162,22 +211,42 @@ impl App {
         // Build the concrete minter first: the JWKS is published from the same
         // ring, and only the concrete type can serialize it (the port stays
         // JWKS-agnostic — a PASETO minter would distribute keys differently).
-        let minter = Es256TokenMinter::from_key_ring(config.signing, config.retiring_keys)?;
-        let jwks_json = minter.jwks_json()?;
+        // Its first ring is whatever `signing_keys` holds, bootstrapped from
+        // the config seed (or a fresh key) on an empty table.
+        let rotator = KeyRotator::new(
+            Arc::new(PgSigningKeyStore::new(tx.clone())),
+            Arc::new(LocalKek::new(config.signing_kek)),
+            config.rotation,
+        );
+        let ring = rotator
+            .bootstrap(config.signing_seed, config.retiring_keys, chrono::Utc::now())
+            .await?;
+        let minter = Arc::new(Es256TokenMinter::from_key_ring(
+            EsKeyMaterial {
+                private_pem: ring.active.private_pem,
+                public_pem: ring.active.public_pem,
+                key_id: ring.active.key_id,
+                issuer: config.token_issuer,
+                audience: config.token_audience,
+            },
+            ring.others,
+        )?);
 
         let deps = AppDeps {
             idp: Arc::new(KeycloakIdentityProvider::new(idp_client, config.keycloak)),
             directory: Arc::new(GrpcAccountDirectory::new(channel)),
             links: Arc::new(PgSubjectLinkRepository::new(tx.clone())),
             sessions: Arc::new(PgSessionRepository::new(tx.clone())),
+            known_devices: Arc::new(PgKnownDeviceRepository::new(tx.clone())),
             refresh_tokens: Arc::new(PgRefreshTokenRepository::new(tx.clone())),
             cache: Arc::new(RedisSessionCache::new(redis.clone())),
-            minter: Arc::new(minter),
+            minter: minter.clone(),
             publisher,
+            challenges: Arc::new(RedisMfaChallengeStore::new(redis.clone())),
             policy: config.policy,
         };
 
-        Ok(App { handler: App::compose(deps), pool, redis, jwks_json, relay })
+        Ok(App { handler: App::compose(deps), pool, redis, minter, rotator, relay })
     }
 }
 
@@ -196,14 +265,27 @@ mod tests {
             directory: fx.directory.clone(),
             links: fx.links.clone(),
             sessions: fx.sessions.clone(),
+            known_devices: fx.known_devices.clone(),
             refresh_tokens: fx.refresh_tokens.clone(),
             cache: fx.cache.clone(),
             minter: fx.minter.clone(),
             publisher: fx.publisher.clone(),
+            challenges: fx.challenges.clone(),
             policy: fx.policy.clone(),
         })
     }
 
+    fn password_login() -> proto::LoginRequest {
+        proto::LoginRequest {
+            device: None,
+            grant_type: proto::GrantType::Password as i32,
+            credential: Some(proto::login_request::Credential::Password(proto::PasswordGrant {
+                username: "user".into(),
+                password: "secret".into(),
+            })),
+        }
+    }
+
     #[tokio::test]
     async fn login_rpc_maps_request_and_response() {
         let fx = Fixture::new();
@@ -214,6 +296,8 @@ mod tests {
                 user_agent: "agent".into(),
                 ip_address: String::new(),
                 device_id: "dev-1".into(),
+                country: String::new(),
+                city: String::new(),
             }),
             grant_type: proto::GrantType::Password as i32,
             credential: Some(proto::login_request::Credential::Password(proto::PasswordGrant {
@@ -245,6 +329,96 @@ mod tests {
         assert_eq!(status.code(), Code::InvalidArgument);
     }
 
+    #[tokio::test]
+    async fn mfa_login_returns_a_challenge_then_tokens_for_the_second_factor() {
+        let fx = Fixture::new();
+        let handler = handler_from_fakes(&fx);
+        let subject =
+            crate::domain::value_object::IdpSubject::new("https://idp.test", "sub-123").unwrap();
+        fx.directory.with_mfa_account(
+            &subject,
+            crate::domain::value_object::AccountId::from_uuid(uuid::Uuid::now_v7()),
+            "123456",
+        );
+
+        let response = handler.login(Request::new(password_login())).await.unwrap().into_inner();
+        assert!(response.tokens.is_none());
+        let challenge = response.second_factor.expect("second-factor challenge");
+        assert_eq!(challenge.expires_in, 300);
+
+        let verify = |code: &str| {
+            Request::new(proto::VerifySecondFactorRequest {
+                challenge_id: challenge.challenge_id.clone(),
+                factor: Some(proto::SecondFactor {
+                    factor: Some(proto::second_factor::Factor::TotpCode(code.into())),
+                }),
+            })
+        };
+        let status = handler.verify_second_factor(verify("000000")).await.unwrap_err();
+        assert_eq!(status.code(), Code::Unauthenticated);
+
+        let verified = handler.verify_second_factor(verify("123456")).await.unwrap().into_inner();
+        assert_eq!(verified.account_id, response.account_id);
+        assert!(verified.tokens.is_some());
+    }
+
+    #[tokio::test]
+    async fn step_up_without_bearer_is_unauthenticated() {
+        let fx = Fixture::new();
+        let handler = handler_from_fakes(&fx);
+        let request = Request::new(proto::StepUpRequest {
+            factor: Some(proto::SecondFactor {
+                factor: Some(proto::second_factor::Factor::TotpCode("123456".into())),
+            }),
+        });
+        let status = handler.step_up(request).await.unwrap_err();
+        assert_eq!(status.code(), Code::Unauthenticated);
+    }
+
+    #[tokio::test]
+    async fn revoke_session_without_bearer_is_unauthenticated() {
+        let fx = Fixture::new();
+        let handler = handler_from_fakes(&fx);
+        let request = Request::new(proto::RevokeSessionRequest {
+            session_id: crate::domain::value_object::SessionId::new().as_str(),
+        });
+        let status = handler.revoke_session(request).await.unwrap_err();
+        assert_eq!(status.code(), Code::Unauthenticated);
+    }
+
+    #[tokio::test]
+    async fn list_sessions_groups_them_per_device_with_the_last_seen_location() {
+        let fx = Fixture::new();
+        let handler = handler_from_fakes(&fx);
+        let from_phone = || {
+            let mut request = password_login();
+            request.device = Some(proto::DeviceContext {
+                user_agent: "phone".into(),
+                ip_address: "203.0.113.7".into(),
+                device_id: "dev-phone".into(),
+                country: "fr".into(),
+                city: "Lyon".into(),
+            });
+            Request::new(request)
+        };
+        let first = handler.login(from_phone()).await.unwrap().into_inner();
+        handler.login(from_phone()).await.unwrap();
+
+        let listed = handler
+            .list_sessions(Request::new(proto::ListSessionsRequest {
+                account_id: first.account_id,
+            }))
+            .await
+            .unwrap()
+            .into_inner();
+        assert_eq!(listed.sessions.len(), 2);
+        let [device] = listed.devices.as_slice() else { panic!("one device group") };
+        assert_eq!(device.device_id, "dev-phone");
+        assert_eq!(device.session_ids.len(), 2);
+        let seen = device.last_seen.as_ref().expect("last-seen context");
+        assert_eq!((seen.country.as_str(), seen.city.as_str()), ("FR", "Lyon"));
+    }
+
     #[tokio::test]
     async fn logout_unknown_session_maps_to_not_found() {
         let fx = Fixture::new();
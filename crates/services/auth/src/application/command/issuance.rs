@@ -0,0 +1,111 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::application::command::IssuedSession;
+use crate::application::policy::SessionPolicy;
+use crate::application::port::{
+    EventPublisher, KnownDeviceRepository, RefreshTokenRepository, SessionCache,
+    SessionRepository, TokenMinter,
+};
+use crate::domain::aggregate::{RefreshToken, RefreshTokenIssueParams, Session, SessionIssueParams};
+use crate::domain::value_object::{
+    AccountId, AuthMethod, DeviceFingerprint, IdpSubject, Permission,
+};
+use crate::error::AuthError;
+
+/// Everything known about an authenticated, active account at the moment its
+/// session is issued.
+pub(crate) struct SessionGrant {
+    pub account_id: AccountId,
+    pub subject: IdpSubject,
+    pub device: DeviceFingerprint,
+    pub methods: Vec<AuthMethod>,
+    pub permissions: Vec<Permission>,
+    pub first_link: bool,
+}
+
+/// The tail shared by a single-factor `Login` and a completed
+/// `VerifySecondFactor`: issue the session under the account's current
+/// generation, flag it when its device is new to the account, then mint its
+/// refresh token and first edge token.
+pub(crate) struct SessionIssuer {
+    sessions: Arc<dyn SessionRepository>,
+    known_devices: Arc<dyn KnownDeviceRepository>,
+    refresh_tokens: Arc<dyn RefreshTokenRepository>,
+    cache: Arc<dyn SessionCache>,
+    minter: Arc<dyn TokenMinter>,
+    publisher: Arc<dyn EventPublisher>,
+    policy: SessionPolicy,
+}
+
+impl SessionIssuer {
+    pub(crate) fn new(
+        sessions: Arc<dyn SessionRepository>,
+        known_devices: Arc<dyn KnownDeviceRepository>,
+        refresh_tokens: Arc<dyn RefreshTokenRepository>,
+        cache: Arc<dyn SessionCache>,
+        minter: Arc<dyn TokenMinter>,
+        publisher: Arc<dyn EventPublisher>,
+        policy: SessionPolicy,
+    ) -> Self {
+        Self { sessions, known_devices, refresh_tokens, cache, minter, publisher, policy }
+    }
+
+    pub(crate) async fn issue(
+        &self,
+        grant: SessionGrant,
+        now: DateTime<Utc>,
+        correlation_id: Uuid,
+    ) -> Result<IssuedSession, AuthError> {
+        let generation = self.cache.current_generation(&grant.account_id).await?;
+        let mut session = Session::issue(SessionIssueParams {
+            account_id: grant.account_id,
+            subject: grant.subject,
+            generation,
+            device: grant.device,
+            methods: grant.methods,
+            issued_at: now,
+            expires_at: now + self.policy.session_ttl,
+            absolute_expiry: now + self.policy.absolute_ttl,
+            correlation_id,
+        })?;
+        self.sessions.save(&session).await?;
+
+        // Remembered after the session is durable, so a failed issue never
+        // swallows the device's first notification. An account's first login
+        // is not news to notify about.
+        if let Some(key) = session.device().recognition_key()
+            && self.known_devices.remember(&grant.account_id, &key, now).await?
+            && !grant.first_link
+        {
+            session.flag_new_device(now, correlation_id);
+        }
+        for event in &session.drain_events() {
+            self.publisher.publish(event).await?;
+        }
+
+        let generated = self.minter.generate_refresh()?;
+        let refresh = RefreshToken::issue(RefreshTokenIssueParams {
+            session_id: session.id(),
+            account_id: grant.account_id,
+            token_hash: generated.hash,
+            issued_at: now,
+            expires_at: now + self.policy.refresh_ttl,
+        })?;
+        self.refresh_tokens.save(&refresh).await?;
+
+        let claims = session.mint_access_token(now, self.policy.access_ttl, grant.permissions)?;
+        let access_token = self.minter.mint_access(&claims).await?;
+
+        Ok(IssuedSession {
+            account_id: grant.account_id,
+            session_id: session.id(),
+            access_token,
+            refresh_token: generated.plaintext,
+            access_expires_in: claims.expires_in_secs(now),
+            first_link: grant.first_link,
+        })
+    }
+}
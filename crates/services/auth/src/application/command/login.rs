@@ -4,16 +4,16 @@ use chrono::{DateTime, Utc};
 use cqrs::Envelope;
 use validate_core::{FieldViolation, Validate};
 
+use crate::application::command::issuance::{SessionGrant, SessionIssuer};
 use crate::application::ensure_valid;
 use crate::application::policy::SessionPolicy;
 use crate::application::port::{
-    AccountDirectory, AuthnGrant, EventPublisher, IdentityProvider, RefreshTokenRepository,
-    SessionCache, SessionRepository, SubjectLinkRepository, TokenMinter,
+    AccountDirectory, AuthnGrant, EventPublisher, IdentityProvider, KnownDeviceRepository,
+    MfaChallenge, MfaChallengeStore, RefreshTokenRepository, SessionCache, SessionRepository,
+    SubjectLinkRepository, TokenMinter,
 };
-use crate::domain::aggregate::{
-    RefreshToken, RefreshTokenIssueParams, Session, SessionIssueParams, SubjectLink,
-};
-use crate::domain::value_object::{AccountId, DeviceFingerprint, IdpSubject};
+use crate::domain::aggregate::SubjectLink;
+use crate::domain::value_object::{AccountId, AuthMethod, DeviceFingerprint, IdpSubject};
 use crate::error::AuthError;
 
 /// Establish a session by brokering a credential to the IdP.
@@ -73,17 +73,44 @@ pub struct IssuedSession {
     pub first_link: bool,
 }
 
-/// Orchestrates login: authenticate → resolve/link account → gate active → issue
-/// session + tokens. Persists durably, then publishes events.
+/// A login that passed the first factor on an account enforcing MFA: no
+/// session yet, only a challenge to answer through `VerifySecondFactor`.
+#[derive(Debug, Clone)]
+pub struct PendingSecondFactor {
+    pub account_id: AccountId,
+    pub challenge_id: String,
+    /// Seconds left to present the second factor.
+    pub expires_in: i64,
+}
+
+/// What a login produced: a session, or a demand for the second factor.
+#[derive(Debug, Clone)]
+pub enum LoginOutcome {
+    Issued(IssuedSession),
+    SecondFactorRequired(PendingSecondFactor),
+}
+
+impl LoginOutcome {
+    /// The issued session, if the login did not stop at a second factor.
+    pub fn issued(self) -> Option<IssuedSession> {
+        match self {
+            Self::Issued(issued) => Some(issued),
+            Self::SecondFactorRequired(_) => None,
+        }
+    }
+}
+
+/// Orchestrates login: authenticate → resolve/link account → gate active →
+/// either issue session + tokens, or — when the account enforces MFA — park
+/// the login in a second-factor challenge. Persists durably, then publishes
+/// events.
 pub struct LoginHandler {
     idp: Arc<dyn IdentityProvider>,
     directory: Arc<dyn AccountDirectory>,
     links: Arc<dyn SubjectLinkRepository>,
-    sessions: Arc<dyn SessionRepository>,
-    refresh_tokens: Arc<dyn RefreshTokenRepository>,
-    cache: Arc<dyn SessionCache>,
-    minter: Arc<dyn TokenMinter>,
+    challenges: Arc<dyn MfaChallengeStore>,
     publisher: Arc<dyn EventPublisher>,
+    issuer: SessionIssuer,
     policy: SessionPolicy,
 }
 
@@ -94,33 +121,38 @@ impl LoginHandler {
         directory: Arc<dyn AccountDirectory>,
         links: Arc<dyn SubjectLinkRepository>,
         sessions: Arc<dyn SessionRepository>,
+        known_devices: Arc<dyn KnownDeviceRepository>,
         refresh_tokens: Arc<dyn RefreshTokenRepository>,
         cache: Arc<dyn SessionCache>,
         minter: Arc<dyn TokenMinter>,
         publisher: Arc<dyn EventPublisher>,
+        challenges: Arc<dyn MfaChallengeStore>,
         policy: SessionPolicy,
     ) -> Self {
-        Self {
-            idp,
-            directory,
-            links,
+        let issuer = SessionIssuer::new(
             sessions,
+            known_devices,
             refresh_tokens,
             cache,
             minter,
-            publisher,
-            policy,
-        }
+            Arc::clone(&publisher),
+            policy.clone(),
+        );
+        Self { idp, directory, links, challenges, publisher, issuer, policy }
     }
 
     pub async fn handle(
         &self,
         envelope: Envelope<LoginCommand>,
         now: DateTime<Utc>,
-    ) -> Result<IssuedSession, AuthError> {
+    ) -> Result<LoginOutcome, AuthError> {
         ensure_valid(&envelope.payload)?;
         let cmd = envelope.payload;
         let correlation_id = envelope.correlation_id;
+        let first_factor = match cmd.grant {
+            AuthnGrant::Password { .. } => AuthMethod::Password,
+            AuthnGrant::AuthorizationCode { .. } => AuthMethod::Federated,
+        };
 
         // 1. Broker the credential to the IdP and normalize the identity.
         let claims = self.idp.authenticate(cmd.grant).await?;
@@ -143,7 +175,9 @@ impl LoginHandler {
             }
         };
 
-        // 4. Establish the immutable subject → account link on first login.
+        // 4. Establish the immutable subject → account link on first login. The
+        //    IdP has proven the subject, so this holds even if the second factor
+        //    below is never presented.
         let mut first_link = false;
         if needs_link {
             let mut link = SubjectLink::establish(subject.clone(), account_id, now, correlation_id);
@@ -152,43 +186,37 @@ impl LoginHandler {
             first_link = true;
         }
 
-        // 5. Issue the session under the account's current generation.
-        let generation = self.cache.current_generation(&account_id).await?;
-        let mut session = Session::issue(SessionIssueParams {
+        // 5. An enrolled second factor must be presented before any session exists.
+        if snapshot.mfa_enforced {
+            let challenge = MfaChallenge::open(
+                account_id,
+                subject,
+                cmd.device,
+                first_factor,
+                first_link,
+                now,
+                self.policy.mfa_challenge_ttl,
+            );
+            self.challenges.put(&challenge).await?;
+            return Ok(LoginOutcome::SecondFactorRequired(PendingSecondFactor {
+                account_id,
+                expires_in: challenge.expires_in_secs(now),
+                challenge_id: challenge.id,
+            }));
+        }
+
+        // 6. Issue the session under the account's current generation and mint
+        //    its refresh token and edge access token.
+        let grant = SessionGrant {
             account_id,
             subject,
-            generation,
             device: cmd.device,
-            issued_at: now,
-            expires_at: now + self.policy.session_ttl,
-            absolute_expiry: now + self.policy.absolute_ttl,
-            correlation_id,
-        })?;
-        self.sessions.save(&session).await?;
-        self.publish_all(session.drain_events()).await?;
-
-        // 5. Mint the refresh token and the edge access token.
-        let generated = self.minter.generate_refresh()?;
-        let refresh = RefreshToken::issue(RefreshTokenIssueParams {
-            session_id: session.id(),
-            account_id,
-            token_hash: generated.hash,
-            issued_at: now,
-            expires_at: now + self.policy.refresh_ttl,
-        })?;
-        self.refresh_tokens.save(&refresh).await?;
-
-        let claims = session.mint_access_token(now, self.policy.access_ttl, permissions)?;
-        let access_token = self.minter.mint_access(&claims).await?;
-
-        Ok(IssuedSession {
-            account_id,
-            session_id: session.id(),
-            access_token,
-            refresh_token: generated.plaintext,
-            access_expires_in: claims.expires_in_secs(now),
+            methods: vec![first_factor],
+            permissions,
             first_link,
-        })
+        };
+        let issued = self.issuer.issue(grant, now, correlation_id).await?;
+        Ok(LoginOutcome::Issued(issued))
     }
 
     async fn publish_all(
@@ -226,7 +254,7 @@ mod tests {
     #[tokio::test]
     async fn first_login_links_account_and_issues_tokens() {
         let fx = Fixture::new();
-        let issued = fx.login_handler().handle(password_login(), t0()).await.unwrap();
+        let issued = fx.login_handler().handle(password_login(), t0()).await.unwrap().issued().unwrap();
 
         assert!(issued.first_link);
         assert!(!issued.access_token.is_empty());
@@ -240,8 +268,8 @@ mod tests {
     #[tokio::test]
     async fn second_login_same_subject_does_not_relink() {
         let fx = Fixture::new();
-        let first = fx.login_handler().handle(password_login(), t0()).await.unwrap();
-        let second = fx.login_handler().handle(password_login(), t0()).await.unwrap();
+        let first = fx.login_handler().handle(password_login(), t0()).await.unwrap().issued().unwrap();
+        let second = fx.login_handler().handle(password_login(), t0()).await.unwrap().issued().unwrap();
 
         assert!(first.first_link);
         assert!(!second.first_link, "subject already linked");
@@ -252,6 +280,43 @@ mod tests {
         assert_eq!(linked, 1);
     }
 
+    fn login_from(device: DeviceFingerprint) -> Envelope<LoginCommand> {
+        let mut env = password_login();
+        env.payload.device = device;
+        env
+    }
+
+    fn new_device_logins(fx: &Fixture) -> usize {
+        fx.publisher.event_types().iter().filter(|t| **t == "auth.new_device_login").count()
+    }
+
+    #[tokio::test]
+    async fn login_from_an_unseen_device_is_flagged_once() {
+        let fx = Fixture::new();
+        let phone = DeviceFingerprint::new(Some("Mobile Safari".into()), None, Some("phone".into()));
+        let laptop = DeviceFingerprint::new(Some("Firefox".into()), None, Some("laptop".into()));
+
+        // The account's first login is not news, whatever the device.
+        fx.login_handler().handle(login_from(phone.clone()), t0()).await.unwrap();
+        assert_eq!(new_device_logins(&fx), 0);
+
+        fx.login_handler().handle(login_from(laptop.clone()), t0()).await.unwrap();
+        assert_eq!(new_device_logins(&fx), 1);
+
+        // Known devices stay quiet.
+        fx.login_handler().handle(login_from(laptop), t0()).await.unwrap();
+        fx.login_handler().handle(login_from(phone), t0()).await.unwrap();
+        assert_eq!(new_device_logins(&fx), 1);
+    }
+
+    #[tokio::test]
+    async fn login_with_nothing_to_recognise_is_not_flagged() {
+        let fx = Fixture::new();
+        fx.login_handler().handle(password_login(), t0()).await.unwrap();
+        fx.login_handler().handle(password_login(), t0()).await.unwrap();
+        assert_eq!(new_device_logins(&fx), 0);
+    }
+
     #[tokio::test]
     async fn login_rejected_for_inactive_account() {
         let fx = Fixture::new();
@@ -297,10 +362,41 @@ mod tests {
         assert!(matches!(err, AuthError::Validation(_)));
     }
 
+    #[tokio::test]
+    async fn mfa_enforced_account_gets_a_challenge_instead_of_a_session() {
+        let fx = Fixture::new();
+        let subject = IdpSubject::new("https://idp.test", "sub-123").unwrap();
+        let account = AccountId::from_uuid(Uuid::now_v7());
+        fx.directory.with_mfa_account(&subject, account, "123456");
+
+        let outcome = fx.login_handler().handle(password_login(), t0()).await.unwrap();
+        let LoginOutcome::SecondFactorRequired(pending) = outcome else {
+            panic!("expected a second-factor challenge");
+        };
+        assert_eq!(pending.account_id, account);
+        assert_eq!(pending.expires_in, 300);
+
+        let challenge = fx.challenges.find(&pending.challenge_id).await.unwrap().unwrap();
+        assert_eq!(challenge.first_factor, AuthMethod::Password);
+        assert!(challenge.first_link);
+        // The link is established; the session is not.
+        assert_eq!(fx.sessions.count(), 0);
+        assert_eq!(fx.publisher.event_types(), vec!["auth.subject_linked"]);
+    }
+
+    #[tokio::test]
+    async fn single_factor_session_carries_the_first_factor() {
+        let fx = Fixture::new();
+        let issued = fx.login_handler().handle(password_login(), t0()).await.unwrap().issued().unwrap();
+        let session = fx.sessions.find_by_id(&issued.session_id).await.unwrap().unwrap();
+        assert_eq!(session.methods(), &[AuthMethod::Password]);
+        assert_eq!(session.auth_time(), t0());
+    }
+
     #[tokio::test]
     async fn session_is_issued_under_current_generation() {
         let fx = Fixture::new();
-        let issued = fx.login_handler().handle(password_login(), t0()).await.unwrap();
+        let issued = fx.login_handler().handle(password_login(), t0()).await.unwrap().issued().unwrap();
         let session = fx.sessions.find_by_id(&issued.session_id).await.unwrap().unwrap();
         assert_eq!(session.generation(), Generation::INITIAL);
     }
@@ -108,7 +108,7 @@ mod tests {
                 device: DeviceFingerprint::default(),
             },
         );
-        h.handle(env, t0()).await.unwrap()
+        h.handle(env, t0()).await.unwrap().issued().unwrap()
     }
 
     fn logout_env(session_id: &str) -> Envelope<LogoutCommand> {
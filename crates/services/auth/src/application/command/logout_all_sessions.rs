@@ -113,7 +113,7 @@ mod tests {
                 device: DeviceFingerprint::default(),
             },
         );
-        fx.login_handler().handle(env, t0()).await.unwrap()
+        fx.login_handler().handle(env, t0()).await.unwrap().issued().unwrap()
     }
 
     #[tokio::test]
@@ -1,11 +1,20 @@
+mod issuance;
 pub mod login;
 pub mod logout;
 pub mod logout_all_sessions;
 pub mod refresh;
+pub mod revoke_session;
+pub mod step_up;
+pub mod verify_second_factor;
 
-pub use login::{IssuedSession, LoginCommand, LoginHandler};
+pub use login::{IssuedSession, LoginCommand, LoginHandler, LoginOutcome, PendingSecondFactor};
 pub use logout::{LogoutCommand, LogoutHandler, LogoutOutcome};
 pub use logout_all_sessions::{
     LogoutAllSessionsCommand, LogoutAllSessionsHandler, LogoutAllSessionsOutcome,
 };
 pub use refresh::{RefreshCommand, RefreshHandler};
+pub use revoke_session::{RevokeSessionCommand, RevokeSessionHandler};
+pub use step_up::{StepUpCommand, StepUpHandler, SteppedUp};
+pub use verify_second_factor::{
+    VerifiedLogin, VerifySecondFactorCommand, VerifySecondFactorHandler,
+};
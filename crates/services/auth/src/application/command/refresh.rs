@@ -36,7 +36,9 @@ impl Validate for RefreshCommand {
 }
 
 /// Orchestrates refresh-token rotation with reuse-detection. A re-presented
-/// (already-rotated) token triggers a full session-generation revocation.
+/// (already-rotated) token triggers a full session-generation revocation and is
+/// reported as a security event. Each successful refresh records the presented
+/// device context as where the session was last seen.
 pub struct RefreshHandler {
     directory: Arc<dyn AccountDirectory>,
     sessions: Arc<dyn SessionRepository>,
@@ -86,7 +88,8 @@ impl RefreshHandler {
         let successor = match token.rotate(now, generated.hash, now + self.policy.refresh_ttl) {
             Ok(successor) => successor,
             Err(AuthError::RefreshTokenReuseDetected) => {
-                self.revoke_generation(now, account_id, session_id, envelope.correlation_id).await?;
+                self.revoke_generation(now, account_id, session_id, &cmd.device, envelope.correlation_id)
+                    .await?;
                 return Err(AuthError::RefreshTokenReuseDetected);
             }
             Err(other) => return Err(other),
@@ -122,10 +125,11 @@ impl RefreshHandler {
             }
         };
 
-        // 6. Persist the rotation, slide the window, mint the new pair.
+        // 6. Persist the rotation, slide the window and record where the session
+        //    was seen, mint the new pair.
         self.refresh_tokens.save(&token).await?; // original, now Rotated
         self.refresh_tokens.save(&successor).await?; // successor, Active
-        session.extend(now, self.policy.session_ttl)?;
+        session.refresh(now, self.policy.session_ttl, &cmd.device)?;
         self.sessions.save(&session).await?;
 
         let claims = session.mint_access_token(now, self.policy.access_ttl, permissions)?;
@@ -142,25 +146,29 @@ impl RefreshHandler {
     }
 
     /// Reuse-detected: bump the account generation (instant global kill), revoke
-    /// the session and all its refresh tokens, and emit the revocation.
+    /// the session and all its refresh tokens, and emit the security event
+    /// (always) and the revocation (when the session was still active).
     async fn revoke_generation(
         &self,
         now: DateTime<Utc>,
         account_id: AccountId,
         session_id: SessionId,
+        presented: &DeviceFingerprint,
         correlation_id: uuid::Uuid,
     ) -> Result<(), AuthError> {
-        self.cache.bump_generation(&account_id).await?;
+        let generation = self.cache.bump_generation(&account_id).await?;
         self.refresh_tokens.revoke_all_for_session(&session_id).await?;
-        if let Some(mut session) = self.sessions.find_by_id(&session_id).await?
-            && session.status() == SessionStatus::Active {
+        if let Some(mut session) = self.sessions.find_by_id(&session_id).await? {
+            session.flag_refresh_reuse(now, generation, presented, correlation_id);
+            if session.status() == SessionStatus::Active {
                 session.revoke(now, RevocationReason::RefreshReuse, correlation_id)?;
                 self.sessions.save(&session).await?;
                 self.cache.blacklist_session(&session_id, self.policy.access_ttl).await?;
-                for event in &session.drain_events() {
-                    self.publisher.publish(event).await?;
-                }
             }
+            for event in &session.drain_events() {
+                self.publisher.publish(event).await?;
+            }
+        }
         Ok(())
     }
 }
@@ -183,7 +191,7 @@ mod tests {
                 device: DeviceFingerprint::default(),
             },
         );
-        login_handler(fx).handle(env, t0()).await.unwrap()
+        login_handler(fx).handle(env, t0()).await.unwrap().issued().unwrap()
     }
 
     fn login_handler(fx: &Fixture) -> LoginHandler {
@@ -233,10 +241,35 @@ mod tests {
             .unwrap_err();
         assert!(matches!(err, AuthError::RefreshTokenReuseDetected));
 
-        // Generation bumped (global kill) and the session revoked.
+        // Generation bumped (global kill), the reuse reported and the session revoked.
         assert_eq!(fx.cache.current_generation(&account).await.unwrap(), Generation::INITIAL.next());
         assert!(fx.sessions.list_active_by_account(&account).await.unwrap().is_empty());
-        assert!(fx.publisher.event_types().contains(&"auth.session_revoked"));
+        let types = fx.publisher.event_types();
+        assert_eq!(types[types.len() - 2..], ["auth.refresh_token_reuse_detected", "auth.session_revoked"]);
+    }
+
+    #[tokio::test]
+    async fn refresh_records_where_the_session_was_last_seen() {
+        let fx = Fixture::new();
+        let first = login(&fx).await;
+
+        let now = t0() + Duration::minutes(3);
+        let device = DeviceFingerprint::new(Some("Firefox".into()), Some("10.0.0.9".into()), None);
+        fx.refresh_handler()
+            .handle(
+                Envelope::new(
+                    Uuid::now_v7(),
+                    RefreshCommand { refresh_token: first.refresh_token, device },
+                ),
+                now,
+            )
+            .await
+            .unwrap();
+
+        let session = fx.sessions.find_by_id(&first.session_id).await.unwrap().unwrap();
+        assert_eq!(session.last_seen_at(), now);
+        assert_eq!(session.last_seen().user_agent(), Some("Firefox"));
+        assert_eq!(session.last_seen().ip_address(), Some("10.0.0.9"));
     }
 
     #[tokio::test]
@@ -0,0 +1,208 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use cqrs::Envelope;
+use validate_core::{FieldViolation, Validate};
+
+use crate::application::command::LogoutOutcome;
+use crate::application::ensure_valid;
+use crate::application::policy::SessionPolicy;
+use crate::application::port::{
+    EventPublisher, RefreshTokenRepository, SessionCache, SessionRepository, TokenMinter,
+};
+use crate::domain::value_object::{RevocationReason, SessionId, SessionStatus};
+use crate::error::AuthError;
+
+/// Revoke one of the caller's own sessions from the device-management view —
+/// typically another device's.
+#[derive(Debug, Clone)]
+pub struct RevokeSessionCommand {
+    /// The caller's edge token — identifies the account that must own the target.
+    pub access_token: String,
+    pub session_id: String,
+}
+
+impl Validate for RevokeSessionCommand {
+    fn validate(&self) -> Result<(), Vec<FieldViolation>> {
+        let mut v = Vec::new();
+        if self.access_token.trim().is_empty() {
+            v.push(FieldViolation::new(
+                "access_token",
+                "AUT-VAL-022",
+                "access_token must not be empty",
+            ));
+        }
+        if self.session_id.trim().is_empty() {
+            v.push(FieldViolation::new(
+                "session_id",
+                "AUT-VAL-020",
+                "session_id must not be empty",
+            ));
+        }
+        if v.is_empty() { Ok(()) } else { Err(v) }
+    }
+}
+
+/// Revokes a session of the caller's account exactly as `Logout` does. The
+/// caller's own session must be live; a session of another account is
+/// reported as not found, so ids cannot be probed across accounts. Idempotent.
+pub struct RevokeSessionHandler {
+    sessions: Arc<dyn SessionRepository>,
+    refresh_tokens: Arc<dyn RefreshTokenRepository>,
+    cache: Arc<dyn SessionCache>,
+    minter: Arc<dyn TokenMinter>,
+    publisher: Arc<dyn EventPublisher>,
+    policy: SessionPolicy,
+}
+
+impl RevokeSessionHandler {
+    pub fn new(
+        sessions: Arc<dyn SessionRepository>,
+        refresh_tokens: Arc<dyn RefreshTokenRepository>,
+        cache: Arc<dyn SessionCache>,
+        minter: Arc<dyn TokenMinter>,
+        publisher: Arc<dyn EventPublisher>,
+        policy: SessionPolicy,
+    ) -> Self {
+        Self { sessions, refresh_tokens, cache, minter, publisher, policy }
+    }
+
+    pub async fn handle(
+        &self,
+        envelope: Envelope<RevokeSessionCommand>,
+        now: DateTime<Utc>,
+    ) -> Result<LogoutOutcome, AuthError> {
+        ensure_valid(&envelope.payload)?;
+        let cmd = envelope.payload;
+        let target_id = SessionId::try_from(cmd.session_id.as_str())?;
+
+        // 1. The caller, from their edge token — which must still be live, the
+        //    same checks `Introspect` applies.
+        let claims = self.minter.verify_access(&cmd.access_token).await?;
+        let caller = self
+            .sessions
+            .find_by_id(&claims.session_id)
+            .await?
+            .ok_or(AuthError::SessionNotFound { id: claims.session_id.as_str() })?;
+        let current_generation = self.cache.current_generation(&claims.account_id).await?;
+        if !caller.is_valid_under(current_generation, now)
+            || self.cache.is_blacklisted(&claims.session_id).await?
+        {
+            return Err(AuthError::SessionRevoked);
+        }
+
+        // 2. The target, only if the caller's account owns it.
+        let mut session = self
+            .sessions
+            .find_by_id(&target_id)
+            .await?
+            .filter(|s| s.account_id() == claims.account_id)
+            .ok_or(AuthError::SessionNotFound { id: target_id.as_str() })?;
+
+        // Already terminal ⇒ idempotent success, nothing to do.
+        if session.status() != SessionStatus::Active {
+            return Ok(LogoutOutcome { success: true });
+        }
+
+        session.revoke(now, RevocationReason::Logout, envelope.correlation_id)?;
+        self.sessions.save(&session).await?;
+        self.cache.blacklist_session(&target_id, self.policy.access_ttl).await?;
+        self.refresh_tokens.revoke_all_for_session(&target_id).await?;
+        for event in &session.drain_events() {
+            self.publisher.publish(event).await?;
+        }
+
+        Ok(LogoutOutcome { success: true })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::command::{IssuedSession, LoginCommand};
+    use crate::application::fakes::{t0, Fixture, StubIdentityProvider};
+    use crate::application::port::AuthnGrant;
+    use crate::domain::value_object::DeviceFingerprint;
+    use uuid::Uuid;
+
+    async fn login(fx: &Fixture) -> IssuedSession {
+        let env = Envelope::new(
+            Uuid::now_v7(),
+            LoginCommand {
+                grant: AuthnGrant::Password { username: "u".into(), password: "p".into() },
+                device: DeviceFingerprint::default(),
+            },
+        );
+        fx.login_handler().handle(env, t0()).await.unwrap().issued().unwrap()
+    }
+
+    fn revoke_env(caller: &IssuedSession, session_id: &str) -> Envelope<RevokeSessionCommand> {
+        Envelope::new(
+            Uuid::now_v7(),
+            RevokeSessionCommand {
+                access_token: caller.access_token.clone(),
+                session_id: session_id.to_owned(),
+            },
+        )
+    }
+
+    #[tokio::test]
+    async fn revokes_another_session_of_the_callers_account() {
+        let fx = Fixture::new();
+        let phone = login(&fx).await;
+        let laptop = login(&fx).await;
+
+        let out = fx
+            .revoke_session_handler()
+            .handle(revoke_env(&laptop, &phone.session_id.as_str()), t0())
+            .await
+            .unwrap();
+        assert!(out.success);
+
+        let active = fx.sessions.list_active_by_account(&laptop.account_id).await.unwrap();
+        assert_eq!(active.iter().map(|s| s.id()).collect::<Vec<_>>(), [laptop.session_id]);
+        assert!(fx.cache.is_blacklisted(&phone.session_id).await.unwrap());
+        assert!(fx.publisher.event_types().contains(&"auth.session_revoked"));
+
+        // Idempotent: a second revoke succeeds without a second event.
+        let before = fx.publisher.count();
+        let env = revoke_env(&laptop, &phone.session_id.as_str());
+        assert!(fx.revoke_session_handler().handle(env, t0()).await.unwrap().success);
+        assert_eq!(fx.publisher.count(), before);
+    }
+
+    #[tokio::test]
+    async fn another_accounts_session_is_not_found() {
+        let mut fx = Fixture::new();
+        let victim = login(&fx).await;
+        fx.idp = Arc::new(StubIdentityProvider::returning("https://idp.test", "sub-other"));
+        let attacker = login(&fx).await;
+        assert_ne!(attacker.account_id, victim.account_id);
+
+        let err = fx
+            .revoke_session_handler()
+            .handle(revoke_env(&attacker, &victim.session_id.as_str()), t0())
+            .await
+            .unwrap_err();
+        assert!(matches!(err, AuthError::SessionNotFound { .. }));
+        assert_eq!(fx.sessions.list_active_by_account(&victim.account_id).await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn a_revoked_caller_cannot_revoke() {
+        let fx = Fixture::new();
+        let phone = login(&fx).await;
+        let laptop = login(&fx).await;
+        fx.revoke_session_handler()
+            .handle(revoke_env(&phone, &laptop.session_id.as_str()), t0())
+            .await
+            .unwrap();
+
+        let err = fx
+            .revoke_session_handler()
+            .handle(revoke_env(&laptop, &phone.session_id.as_str()), t0())
+            .await
+            .unwrap_err();
+        assert!(matches!(err, AuthError::SessionRevoked));
+    }
+}
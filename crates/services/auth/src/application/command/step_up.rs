@@ -0,0 +1,233 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use cqrs::Envelope;
+use validate_core::{FieldViolation, Validate};
+
+use crate::application::command::verify_second_factor::{check_second_factor, validate_factor};
+use crate::application::ensure_valid;
+use crate::application::policy::SessionPolicy;
+use crate::application::port::{
+    AccountActivation, AccountDirectory, MfaChallengeStore, SecondFactor, SessionCache,
+    SessionRepository, TokenMinter,
+};
+use crate::error::AuthError;
+
+/// Re-prove the caller's second factor on their current session, so sensitive
+/// RPCs guarded by a step-up rule admit the next token.
+#[derive(Debug, Clone)]
+pub struct StepUpCommand {
+    /// The caller's edge token — identifies the session being stepped up.
+    pub access_token: String,
+    pub factor: SecondFactor,
+}
+
+impl Validate for StepUpCommand {
+    fn validate(&self) -> Result<(), Vec<FieldViolation>> {
+        let mut v = Vec::new();
+        if self.access_token.trim().is_empty() {
+            v.push(FieldViolation::new(
+                "access_token",
+                "AUT-VAL-022",
+                "access_token must not be empty",
+            ));
+        }
+        validate_factor(&self.factor, &mut v);
+        if v.is_empty() { Ok(()) } else { Err(v) }
+    }
+}
+
+/// A fresh edge token for the stepped-up session. The refresh token is
+/// unchanged: refreshing keeps the second factor but not its recency, so
+/// step-up rules lapse on their own.
+#[derive(Debug, Clone)]
+pub struct SteppedUp {
+    pub access_token: String,
+    pub access_expires_in: i64,
+    pub recovery_codes_remaining: u32,
+}
+
+/// Checks the second factor against the session's account (sharing the login
+/// failure budget), records it on the session, and mints a token carrying
+/// `acr = aal2` and a current `auth_time`.
+pub struct StepUpHandler {
+    directory: Arc<dyn AccountDirectory>,
+    challenges: Arc<dyn MfaChallengeStore>,
+    sessions: Arc<dyn SessionRepository>,
+    cache: Arc<dyn SessionCache>,
+    minter: Arc<dyn TokenMinter>,
+    policy: SessionPolicy,
+}
+
+impl StepUpHandler {
+    pub fn new(
+        directory: Arc<dyn AccountDirectory>,
+        challenges: Arc<dyn MfaChallengeStore>,
+        sessions: Arc<dyn SessionRepository>,
+        cache: Arc<dyn SessionCache>,
+        minter: Arc<dyn TokenMinter>,
+        policy: SessionPolicy,
+    ) -> Self {
+        Self { directory, challenges, sessions, cache, minter, policy }
+    }
+
+    pub async fn handle(
+        &self,
+        envelope: Envelope<StepUpCommand>,
+        now: DateTime<Utc>,
+    ) -> Result<SteppedUp, AuthError> {
+        ensure_valid(&envelope.payload)?;
+        let cmd = envelope.payload;
+
+        // 1. The presented token names the session; it must still be live —
+        //    the same checks `Introspect` applies.
+        let claims = self.minter.verify_access(&cmd.access_token).await?;
+        let account_id = claims.account_id;
+        let mut session = self
+            .sessions
+            .find_by_id(&claims.session_id)
+            .await?
+            .ok_or(AuthError::SessionNotFound { id: claims.session_id.as_str() })?;
+        let current_generation = self.cache.current_generation(&account_id).await?;
+        if !session.is_valid_under(current_generation, now)
+            || self.cache.is_blacklisted(&claims.session_id).await?
+        {
+            return Err(AuthError::SessionRevoked);
+        }
+
+        // 2. The second factor itself.
+        let check = check_second_factor(
+            self.directory.as_ref(),
+            self.challenges.as_ref(),
+            &self.policy,
+            &account_id,
+            &cmd.factor,
+        )
+        .await?;
+
+        // 3. Re-read authoritative permissions, as a refresh would.
+        let snapshot = self.directory.lookup(&account_id).await?;
+        let permissions = match snapshot.activation {
+            AccountActivation::Active => snapshot.permissions,
+            AccountActivation::Inactive { reason } => {
+                return Err(AuthError::AccountNotActive { current: reason });
+            }
+        };
+
+        // 4. Record the factor and mint from the stepped-up session.
+        session.step_up(now, cmd.factor.method())?;
+        self.sessions.save(&session).await?;
+        let claims = session.mint_access_token(now, self.policy.access_ttl, permissions)?;
+        let access_token = self.minter.mint_access(&claims).await?;
+
+        Ok(SteppedUp {
+            access_token,
+            access_expires_in: claims.expires_in_secs(now),
+            recovery_codes_remaining: check.recovery_codes_remaining,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::command::{LoginCommand, LogoutCommand};
+    use crate::application::fakes::{t0, Fixture};
+    use crate::application::port::AuthnGrant;
+    use crate::domain::value_object::{AssuranceLevel, AuthMethod, DeviceFingerprint, IdpSubject};
+    use chrono::Duration;
+    use uuid::Uuid;
+
+    const CODE: &str = "654321";
+
+    /// A single-factor session on an account that has since enrolled MFA.
+    async fn login(fx: &Fixture) -> crate::application::command::IssuedSession {
+        let env = Envelope::new(
+            Uuid::now_v7(),
+            LoginCommand {
+                grant: AuthnGrant::Password { username: "u".into(), password: "p".into() },
+                device: DeviceFingerprint::default(),
+            },
+        );
+        let issued = fx.login_handler().handle(env, t0()).await.unwrap().issued().unwrap();
+        let subject = IdpSubject::new("https://idp.test", "sub-123").unwrap();
+        fx.directory.with_mfa_account(&subject, issued.account_id, CODE);
+        issued
+    }
+
+    fn step_up_env(token: &str, code: &str) -> Envelope<StepUpCommand> {
+        Envelope::new(
+            Uuid::now_v7(),
+            StepUpCommand { access_token: token.to_owned(), factor: SecondFactor::Totp(code.into()) },
+        )
+    }
+
+    #[tokio::test]
+    async fn step_up_mints_a_multi_factor_token_for_the_same_session() {
+        let fx = Fixture::new();
+        let issued = login(&fx).await;
+        let now = t0() + Duration::minutes(3);
+
+        let stepped =
+            fx.step_up_handler().handle(step_up_env(&issued.access_token, CODE), now).await.unwrap();
+
+        let claims = fx.minter.verify_access(&stepped.access_token).await.unwrap();
+        assert_eq!(claims.session_id, issued.session_id);
+        assert_eq!(claims.assurance(), AssuranceLevel::MultiFactor);
+        assert_eq!(claims.auth_time, now);
+        let session = fx.sessions.find_by_id(&issued.session_id).await.unwrap().unwrap();
+        assert_eq!(session.methods(), &[AuthMethod::Password, AuthMethod::Otp]);
+    }
+
+    #[tokio::test]
+    async fn wrong_code_leaves_the_session_single_factor() {
+        let fx = Fixture::new();
+        let issued = login(&fx).await;
+        let err = fx
+            .step_up_handler()
+            .handle(step_up_env(&issued.access_token, "000000"), t0())
+            .await
+            .unwrap_err();
+        assert!(matches!(err, AuthError::SecondFactorRejected));
+        let session = fx.sessions.find_by_id(&issued.session_id).await.unwrap().unwrap();
+        assert_eq!(session.assurance(), AssuranceLevel::SingleFactor);
+    }
+
+    #[tokio::test]
+    async fn account_without_enrolment_cannot_step_up() {
+        let fx = Fixture::new();
+        let env = Envelope::new(
+            Uuid::now_v7(),
+            LoginCommand {
+                grant: AuthnGrant::Password { username: "u".into(), password: "p".into() },
+                device: DeviceFingerprint::default(),
+            },
+        );
+        let issued = fx.login_handler().handle(env, t0()).await.unwrap().issued().unwrap();
+        let err = fx
+            .step_up_handler()
+            .handle(step_up_env(&issued.access_token, CODE), t0())
+            .await
+            .unwrap_err();
+        assert!(matches!(err, AuthError::SecondFactorNotEnrolled));
+    }
+
+    #[tokio::test]
+    async fn logged_out_session_cannot_step_up() {
+        let fx = Fixture::new();
+        let issued = login(&fx).await;
+        fx.logout_handler()
+            .handle(
+                Envelope::new(Uuid::now_v7(), LogoutCommand { session_id: issued.session_id.as_str() }),
+                t0(),
+            )
+            .await
+            .unwrap();
+        let err = fx
+            .step_up_handler()
+            .handle(step_up_env(&issued.access_token, CODE), t0() + Duration::minutes(1))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, AuthError::SessionRevoked));
+    }
+}
@@ -0,0 +1,302 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use cqrs::Envelope;
+use validate_core::{FieldViolation, Validate};
+
+use crate::application::command::issuance::{SessionGrant, SessionIssuer};
+use crate::application::command::IssuedSession;
+use crate::application::ensure_valid;
+use crate::application::policy::SessionPolicy;
+use crate::application::port::{
+    AccountActivation, AccountDirectory, EventPublisher, KnownDeviceRepository,
+    MfaChallengeStore, RefreshTokenRepository, SecondFactor, SecondFactorCheck, SessionCache,
+    SessionRepository, TokenMinter,
+};
+use crate::domain::value_object::AccountId;
+use crate::error::AuthError;
+
+/// Complete a login parked by `Login` with the account's second factor.
+#[derive(Debug, Clone)]
+pub struct VerifySecondFactorCommand {
+    pub challenge_id: String,
+    pub factor: SecondFactor,
+}
+
+impl Validate for VerifySecondFactorCommand {
+    fn validate(&self) -> Result<(), Vec<FieldViolation>> {
+        let mut v = Vec::new();
+        if self.challenge_id.trim().is_empty() {
+            v.push(FieldViolation::new(
+                "challenge_id",
+                "AUT-VAL-020",
+                "challenge_id must not be empty",
+            ));
+        }
+        validate_factor(&self.factor, &mut v);
+        if v.is_empty() { Ok(()) } else { Err(v) }
+    }
+}
+
+pub(crate) fn validate_factor(factor: &SecondFactor, v: &mut Vec<FieldViolation>) {
+    let (SecondFactor::Totp(code) | SecondFactor::RecoveryCode(code)) = factor;
+    if code.trim().is_empty() {
+        v.push(FieldViolation::new("factor", "AUT-VAL-021", "a second-factor code is required"));
+    }
+}
+
+/// A login completed by its second factor.
+#[derive(Debug, Clone)]
+pub struct VerifiedLogin {
+    pub issued: IssuedSession,
+    pub recovery_codes_remaining: u32,
+}
+
+/// Checks the second factor with `account`, then issues the multi-factor
+/// session the challenge was holding back. The challenge is single-use; wrong
+/// factors count against the account's budget and exhausting it discards the
+/// challenge.
+pub struct VerifySecondFactorHandler {
+    directory: Arc<dyn AccountDirectory>,
+    challenges: Arc<dyn MfaChallengeStore>,
+    issuer: SessionIssuer,
+    policy: SessionPolicy,
+}
+
+impl VerifySecondFactorHandler {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        directory: Arc<dyn AccountDirectory>,
+        challenges: Arc<dyn MfaChallengeStore>,
+        sessions: Arc<dyn SessionRepository>,
+        known_devices: Arc<dyn KnownDeviceRepository>,
+        refresh_tokens: Arc<dyn RefreshTokenRepository>,
+        cache: Arc<dyn SessionCache>,
+        minter: Arc<dyn TokenMinter>,
+        publisher: Arc<dyn EventPublisher>,
+        policy: SessionPolicy,
+    ) -> Self {
+        let issuer = SessionIssuer::new(
+            sessions,
+            known_devices,
+            refresh_tokens,
+            cache,
+            minter,
+            publisher,
+            policy.clone(),
+        );
+        Self { directory, challenges, issuer, policy }
+    }
+
+    pub async fn handle(
+        &self,
+        envelope: Envelope<VerifySecondFactorCommand>,
+        now: DateTime<Utc>,
+    ) -> Result<VerifiedLogin, AuthError> {
+        ensure_valid(&envelope.payload)?;
+        let cmd = envelope.payload;
+
+        // 1. The challenge must still be pending.
+        let challenge = self
+            .challenges
+            .find(&cmd.challenge_id)
+            .await?
+            .filter(|c| !c.is_expired(now))
+            .ok_or(AuthError::MfaChallengeNotFound)?;
+
+        // 2. Check the factor; running out of attempts ends the challenge too.
+        let check = match check_second_factor(
+            self.directory.as_ref(),
+            self.challenges.as_ref(),
+            &self.policy,
+            &challenge.account_id,
+            &cmd.factor,
+        )
+        .await
+        {
+            Err(AuthError::MfaChallengeExhausted) => {
+                self.challenges.take(&challenge.id).await?;
+                return Err(AuthError::MfaChallengeExhausted);
+            }
+            other => other?,
+        };
+
+        // 3. Claim the challenge; a concurrent submission that got here first
+        //    already issued the session.
+        let challenge =
+            self.challenges.take(&challenge.id).await?.ok_or(AuthError::MfaChallengeNotFound)?;
+
+        // 4. Re-read activation and permissions — they may have changed while
+        //    the user reached for their authenticator.
+        let snapshot = self.directory.lookup(&challenge.account_id).await?;
+        let permissions = match snapshot.activation {
+            AccountActivation::Active => snapshot.permissions,
+            AccountActivation::Inactive { reason } => {
+                return Err(AuthError::AccountNotActive { current: reason });
+            }
+        };
+
+        let grant = SessionGrant {
+            account_id: challenge.account_id,
+            subject: challenge.subject,
+            device: challenge.device,
+            methods: vec![challenge.first_factor, cmd.factor.method()],
+            permissions,
+            first_link: challenge.first_link,
+        };
+        let issued = self.issuer.issue(grant, now, envelope.correlation_id).await?;
+        Ok(VerifiedLogin { issued, recovery_codes_remaining: check.recovery_codes_remaining })
+    }
+}
+
+/// Checks `factor` for `account_id` against the account's failure budget:
+/// refused outright once the budget is spent, counted when wrong, and the
+/// count cleared when right. Shared by login completion and step-up.
+pub(crate) async fn check_second_factor(
+    directory: &dyn AccountDirectory,
+    challenges: &dyn MfaChallengeStore,
+    policy: &SessionPolicy,
+    account_id: &AccountId,
+    factor: &SecondFactor,
+) -> Result<SecondFactorCheck, AuthError> {
+    if challenges.failures(account_id).await? >= policy.mfa_max_failures {
+        return Err(AuthError::MfaChallengeExhausted);
+    }
+
+    let check = directory.verify_second_factor(account_id, factor).await?;
+    if !check.verified {
+        let failures = challenges.record_failure(account_id, policy.mfa_challenge_ttl).await?;
+        return Err(if failures >= policy.mfa_max_failures {
+            AuthError::MfaChallengeExhausted
+        } else {
+            AuthError::SecondFactorRejected
+        });
+    }
+
+    challenges.clear_failures(account_id).await?;
+    Ok(check)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::command::{LoginCommand, LoginOutcome};
+    use crate::application::fakes::{t0, Fixture};
+    use crate::application::port::AuthnGrant;
+    use crate::domain::value_object::{
+        AssuranceLevel, AuthMethod, DeviceFingerprint, IdpSubject,
+    };
+    use chrono::Duration;
+    use uuid::Uuid;
+
+    const CODE: &str = "123456";
+
+    /// Logs in against an MFA-enforcing account and returns the challenge id.
+    async fn pending(fx: &Fixture) -> String {
+        let subject = IdpSubject::new("https://idp.test", "sub-123").unwrap();
+        fx.directory.with_mfa_account(&subject, AccountId::from_uuid(Uuid::now_v7()), CODE);
+        let env = Envelope::new(
+            Uuid::now_v7(),
+            LoginCommand {
+                grant: AuthnGrant::Password { username: "u".into(), password: "p".into() },
+                device: DeviceFingerprint::default(),
+            },
+        );
+        match fx.login_handler().handle(env, t0()).await.unwrap() {
+            LoginOutcome::SecondFactorRequired(pending) => pending.challenge_id,
+            LoginOutcome::Issued(_) => panic!("expected a second-factor challenge"),
+        }
+    }
+
+    fn verify_env(challenge_id: &str, factor: SecondFactor) -> Envelope<VerifySecondFactorCommand> {
+        Envelope::new(
+            Uuid::now_v7(),
+            VerifySecondFactorCommand { challenge_id: challenge_id.to_owned(), factor },
+        )
+    }
+
+    #[tokio::test]
+    async fn correct_code_issues_a_multi_factor_session_once() {
+        let fx = Fixture::new();
+        let challenge = pending(&fx).await;
+        let now = t0() + Duration::minutes(1);
+
+        let verified = fx
+            .verify_second_factor_handler()
+            .handle(verify_env(&challenge, SecondFactor::Totp(CODE.into())), now)
+            .await
+            .unwrap();
+        assert!(verified.issued.first_link);
+
+        let session =
+            fx.sessions.find_by_id(&verified.issued.session_id).await.unwrap().unwrap();
+        assert_eq!(session.methods(), &[AuthMethod::Password, AuthMethod::Otp]);
+        assert_eq!(session.assurance(), AssuranceLevel::MultiFactor);
+        assert_eq!(session.auth_time(), now);
+        assert_eq!(fx.publisher.event_types(), vec!["auth.subject_linked", "auth.session_issued"]);
+
+        // Single-use: the same challenge cannot mint a second session.
+        let err = fx
+            .verify_second_factor_handler()
+            .handle(verify_env(&challenge, SecondFactor::Totp(CODE.into())), now)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, AuthError::MfaChallengeNotFound));
+        assert_eq!(fx.sessions.count(), 1);
+    }
+
+    #[tokio::test]
+    async fn wrong_codes_are_rejected_until_the_budget_runs_out() {
+        let fx = Fixture::new();
+        let challenge = pending(&fx).await;
+        let handler = fx.verify_second_factor_handler();
+        let wrong = || verify_env(&challenge, SecondFactor::RecoveryCode("nope".into()));
+
+        for _ in 1..fx.policy.mfa_max_failures {
+            let err = handler.handle(wrong(), t0()).await.unwrap_err();
+            assert!(matches!(err, AuthError::SecondFactorRejected));
+        }
+        let err = handler.handle(wrong(), t0()).await.unwrap_err();
+        assert!(matches!(err, AuthError::MfaChallengeExhausted));
+
+        // The challenge is gone, and a fresh login cannot reset the budget.
+        let err = handler
+            .handle(verify_env(&challenge, SecondFactor::Totp(CODE.into())), t0())
+            .await
+            .unwrap_err();
+        assert!(matches!(err, AuthError::MfaChallengeNotFound));
+        let again = pending(&fx).await;
+        let err = handler
+            .handle(verify_env(&again, SecondFactor::Totp(CODE.into())), t0())
+            .await
+            .unwrap_err();
+        assert!(matches!(err, AuthError::MfaChallengeExhausted));
+        assert_eq!(fx.sessions.count(), 0);
+    }
+
+    #[tokio::test]
+    async fn expired_challenge_is_not_found() {
+        let fx = Fixture::new();
+        let challenge = pending(&fx).await;
+        let err = fx
+            .verify_second_factor_handler()
+            .handle(
+                verify_env(&challenge, SecondFactor::Totp(CODE.into())),
+                t0() + fx.policy.mfa_challenge_ttl,
+            )
+            .await
+            .unwrap_err();
+        assert!(matches!(err, AuthError::MfaChallengeNotFound));
+    }
+
+    #[tokio::test]
+    async fn blank_code_fails_validation() {
+        let fx = Fixture::new();
+        let err = fx
+            .verify_second_factor_handler()
+            .handle(verify_env("c", SecondFactor::Totp("  ".into())), t0())
+            .await
+            .unwrap_err();
+        assert!(matches!(err, AuthError::Validation(_)));
+    }
+}
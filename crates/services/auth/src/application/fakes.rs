@@ -13,7 +13,8 @@ use uuid::Uuid;
 use super::policy::SessionPolicy;
 use super::port::{
     AccountActivation, AccountDirectory, AccountSnapshot, AuthnGrant, EventPublisher,
-    GeneratedRefresh, IdentityProvider, NormalizedClaims, RefreshTokenRepository, SessionCache,
+    GeneratedRefresh, IdentityProvider, KnownDeviceRepository, MfaChallenge, MfaChallengeStore,
+    NormalizedClaims, RefreshTokenRepository, SecondFactor, SecondFactorCheck, SessionCache,
     SessionRepository, SubjectLinkRepository, TokenMinter,
 };
 use crate::domain::aggregate::{RefreshToken, Session, SubjectLink};
@@ -66,6 +67,8 @@ impl IdentityProvider for StubIdentityProvider {
 pub struct StubAccountDirectory {
     subjects: Mutex<HashMap<IdpSubject, AccountId>>,
     snapshots: Mutex<HashMap<AccountId, AccountSnapshot>>,
+    /// account → the one code (TOTP or recovery) its enrolment accepts.
+    second_factors: Mutex<HashMap<AccountId, String>>,
 }
 
 impl Default for StubAccountDirectory {
@@ -76,7 +79,11 @@ impl Default for StubAccountDirectory {
 
 impl StubAccountDirectory {
     pub fn new() -> Self {
-        Self { subjects: Mutex::new(HashMap::new()), snapshots: Mutex::new(HashMap::new()) }
+        Self {
+            subjects: Mutex::new(HashMap::new()),
+            snapshots: Mutex::new(HashMap::new()),
+            second_factors: Mutex::new(HashMap::new()),
+        }
     }
 
     /// Pre-binds a subject to a known account with the given activation + perms.
@@ -91,7 +98,22 @@ impl StubAccountDirectory {
         self.snapshots
             .lock()
             .unwrap()
-            .insert(account_id, AccountSnapshot { activation, permissions });
+            .insert(account_id, AccountSnapshot { activation, permissions, mfa_enforced: false });
+    }
+
+    /// Pre-binds a subject to an active account that enforces MFA and accepts
+    /// `code` as its second factor.
+    pub fn with_mfa_account(&self, subject: &IdpSubject, account_id: AccountId, code: &str) {
+        self.subjects.lock().unwrap().insert(subject.clone(), account_id);
+        self.snapshots.lock().unwrap().insert(
+            account_id,
+            AccountSnapshot {
+                activation: AccountActivation::Active,
+                permissions: Vec::new(),
+                mfa_enforced: true,
+            },
+        );
+        self.second_factors.lock().unwrap().insert(account_id, code.to_owned());
     }
 }
 
@@ -106,7 +128,11 @@ impl AccountDirectory for StubAccountDirectory {
         subjects.insert(subject.clone(), id);
         self.snapshots.lock().unwrap().insert(
             id,
-            AccountSnapshot { activation: AccountActivation::Active, permissions: Vec::new() },
+            AccountSnapshot {
+                activation: AccountActivation::Active,
+                permissions: Vec::new(),
+                mfa_enforced: false,
+            },
         );
         Ok(id)
     }
@@ -115,8 +141,20 @@ impl AccountDirectory for StubAccountDirectory {
         Ok(self.snapshots.lock().unwrap().get(account_id).cloned().unwrap_or(AccountSnapshot {
             activation: AccountActivation::Active,
             permissions: Vec::new(),
+            mfa_enforced: false,
         }))
     }
+
+    async fn verify_second_factor(
+        &self,
+        account_id: &AccountId,
+        factor: &SecondFactor,
+    ) -> Result<SecondFactorCheck, AuthError> {
+        let factors = self.second_factors.lock().unwrap();
+        let expected = factors.get(account_id).ok_or(AuthError::SecondFactorNotEnrolled)?;
+        let (SecondFactor::Totp(code) | SecondFactor::RecoveryCode(code)) = factor;
+        Ok(SecondFactorCheck { verified: code == expected, recovery_codes_remaining: 10 })
+    }
 }
 
 // ─── SubjectLinkRepository ───────────────────────────────────────────────────
@@ -212,6 +250,36 @@ impl SessionRepository for InMemorySessionRepository {
     }
 }
 
+// ─── KnownDeviceRepository ───────────────────────────────────────────────────
+
+pub struct InMemoryKnownDeviceRepository {
+    devices: Mutex<HashSet<(AccountId, String)>>,
+}
+
+impl Default for InMemoryKnownDeviceRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl InMemoryKnownDeviceRepository {
+    pub fn new() -> Self {
+        Self { devices: Mutex::new(HashSet::new()) }
+    }
+}
+
+#[async_trait]
+impl KnownDeviceRepository for InMemoryKnownDeviceRepository {
+    async fn remember(
+        &self,
+        account_id: &AccountId,
+        device_key: &str,
+        _seen_at: DateTime<Utc>,
+    ) -> Result<bool, AuthError> {
+        Ok(self.devices.lock().unwrap().insert((*account_id, device_key.to_owned())))
+    }
+}
+
 // ─── RefreshTokenRepository ──────────────────────────────────────────────────
 
 pub struct InMemoryRefreshTokenRepository {
@@ -344,6 +412,62 @@ impl TokenMinter for StubTokenMinter {
     }
 }
 
+// ─── MfaChallengeStore ───────────────────────────────────────────────────────
+
+pub struct InMemoryMfaChallengeStore {
+    challenges: Mutex<HashMap<String, MfaChallenge>>,
+    failures: Mutex<HashMap<AccountId, u32>>,
+}
+
+impl Default for InMemoryMfaChallengeStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl InMemoryMfaChallengeStore {
+    pub fn new() -> Self {
+        Self { challenges: Mutex::new(HashMap::new()), failures: Mutex::new(HashMap::new()) }
+    }
+}
+
+#[async_trait]
+impl MfaChallengeStore for InMemoryMfaChallengeStore {
+    async fn put(&self, challenge: &MfaChallenge) -> Result<(), AuthError> {
+        self.challenges.lock().unwrap().insert(challenge.id.clone(), challenge.clone());
+        Ok(())
+    }
+
+    async fn find(&self, id: &str) -> Result<Option<MfaChallenge>, AuthError> {
+        Ok(self.challenges.lock().unwrap().get(id).cloned())
+    }
+
+    async fn take(&self, id: &str) -> Result<Option<MfaChallenge>, AuthError> {
+        Ok(self.challenges.lock().unwrap().remove(id))
+    }
+
+    // Windows never lapse here; tests that need a fresh budget use a new account.
+    async fn record_failure(
+        &self,
+        account_id: &AccountId,
+        _window: Duration,
+    ) -> Result<u32, AuthError> {
+        let mut failures = self.failures.lock().unwrap();
+        let count = failures.entry(*account_id).or_insert(0);
+        *count += 1;
+        Ok(*count)
+    }
+
+    async fn failures(&self, account_id: &AccountId) -> Result<u32, AuthError> {
+        Ok(self.failures.lock().unwrap().get(account_id).copied().unwrap_or(0))
+    }
+
+    async fn clear_failures(&self, account_id: &AccountId) -> Result<(), AuthError> {
+        self.failures.lock().unwrap().remove(account_id);
+        Ok(())
+    }
+}
+
 // ─── EventPublisher ──────────────────────────────────────────────────────────
 
 pub struct RecordingEventPublisher {
@@ -388,10 +512,12 @@ pub struct Fixture {
     pub directory: Arc<StubAccountDirectory>,
     pub links: Arc<InMemorySubjectLinkRepository>,
     pub sessions: Arc<InMemorySessionRepository>,
+    pub known_devices: Arc<InMemoryKnownDeviceRepository>,
     pub refresh_tokens: Arc<InMemoryRefreshTokenRepository>,
     pub cache: Arc<InMemorySessionCache>,
     pub minter: Arc<StubTokenMinter>,
     pub publisher: Arc<RecordingEventPublisher>,
+    pub challenges: Arc<InMemoryMfaChallengeStore>,
     pub policy: SessionPolicy,
 }
 
@@ -409,10 +535,12 @@ impl Fixture {
             directory: Arc::new(StubAccountDirectory::new()),
             links: Arc::new(InMemorySubjectLinkRepository::new()),
             sessions: Arc::new(InMemorySessionRepository::new()),
+            known_devices: Arc::new(InMemoryKnownDeviceRepository::new()),
             refresh_tokens: Arc::new(InMemoryRefreshTokenRepository::new()),
             cache: Arc::new(InMemorySessionCache::new()),
             minter: Arc::new(StubTokenMinter::new()),
             publisher: Arc::new(RecordingEventPublisher::new()),
+            challenges: Arc::new(InMemoryMfaChallengeStore::new()),
             policy: SessionPolicy::test_default(),
         }
     }
@@ -423,10 +551,37 @@ impl Fixture {
             Arc::clone(&self.directory) as _,
             Arc::clone(&self.links) as _,
             Arc::clone(&self.sessions) as _,
+            Arc::clone(&self.known_devices) as _,
             Arc::clone(&self.refresh_tokens) as _,
             Arc::clone(&self.cache) as _,
             Arc::clone(&self.minter) as _,
             Arc::clone(&self.publisher) as _,
+            Arc::clone(&self.challenges) as _,
+            self.policy.clone(),
+        )
+    }
+
+    pub fn verify_second_factor_handler(&self) -> super::command::VerifySecondFactorHandler {
+        super::command::VerifySecondFactorHandler::new(
+            Arc::clone(&self.directory) as _,
+            Arc::clone(&self.challenges) as _,
+            Arc::clone(&self.sessions) as _,
+            Arc::clone(&self.known_devices) as _,
+            Arc::clone(&self.refresh_tokens) as _,
+            Arc::clone(&self.cache) as _,
+            Arc::clone(&self.minter) as _,
+            Arc::clone(&self.publisher) as _,
+            self.policy.clone(),
+        )
+    }
+
+    pub fn step_up_handler(&self) -> super::command::StepUpHandler {
+        super::command::StepUpHandler::new(
+            Arc::clone(&self.directory) as _,
+            Arc::clone(&self.challenges) as _,
+            Arc::clone(&self.sessions) as _,
+            Arc::clone(&self.cache) as _,
+            Arc::clone(&self.minter) as _,
             self.policy.clone(),
         )
     }
@@ -453,6 +608,17 @@ impl Fixture {
         )
     }
 
+    pub fn revoke_session_handler(&self) -> super::command::RevokeSessionHandler {
+        super::command::RevokeSessionHandler::new(
+            Arc::clone(&self.sessions) as _,
+            Arc::clone(&self.refresh_tokens) as _,
+            Arc::clone(&self.cache) as _,
+            Arc::clone(&self.minter) as _,
+            Arc::clone(&self.publisher) as _,
+            self.policy.clone(),
+        )
+    }
+
     pub fn logout_all_handler(&self) -> super::command::LogoutAllSessionsHandler {
         super::command::LogoutAllSessionsHandler::new(
             Arc::clone(&self.sessions) as _,
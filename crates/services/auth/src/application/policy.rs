@@ -6,6 +6,9 @@ use chrono::Duration;
 /// Relationships the handlers rely on: `access_ttl ≤ session_ttl ≤ absolute_ttl`
 /// (the domain clamps regardless), and the blacklist TTL equals `access_ttl` —
 /// long enough to outlive any access token a session could have minted.
+///
+/// The second-factor settings default to a 5-minute challenge and 5 wrong codes
+/// per window; [`SessionPolicy::with_second_factor`] overrides them.
 #[derive(Debug, Clone)]
 pub struct SessionPolicy {
     /// Edge access-token lifetime (short — minutes).
@@ -16,6 +19,12 @@ pub struct SessionPolicy {
     pub absolute_ttl: Duration,
     /// Refresh-token lifetime (long — days).
     pub refresh_ttl: Duration,
+    /// How long a login waits for its second factor; also the window wrong
+    /// second factors are counted over.
+    pub mfa_challenge_ttl: Duration,
+    /// Wrong second factors per window before the account's pending challenge
+    /// is discarded and further attempts are refused until the window ends.
+    pub mfa_max_failures: u32,
 }
 
 impl SessionPolicy {
@@ -25,7 +34,20 @@ impl SessionPolicy {
         absolute_ttl: Duration,
         refresh_ttl: Duration,
     ) -> Self {
-        Self { access_ttl, session_ttl, absolute_ttl, refresh_ttl }
+        Self {
+            access_ttl,
+            session_ttl,
+            absolute_ttl,
+            refresh_ttl,
+            mfa_challenge_ttl: Duration::minutes(5),
+            mfa_max_failures: 5,
+        }
+    }
+
+    pub fn with_second_factor(mut self, challenge_ttl: Duration, max_failures: u32) -> Self {
+        self.mfa_challenge_ttl = challenge_ttl;
+        self.mfa_max_failures = max_failures;
+        self
     }
 }
 
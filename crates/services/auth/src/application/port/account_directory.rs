@@ -1,6 +1,6 @@
 use async_trait::async_trait;
 
-use crate::domain::value_object::{AccountId, IdpSubject, Permission};
+use crate::domain::value_object::{AccountId, AuthMethod, IdpSubject, Permission};
 use crate::error::AuthError;
 
 /// Whether an account may currently establish or keep a session.
@@ -25,6 +25,45 @@ pub struct AccountSnapshot {
     /// re-read on every login and refresh (a role change takes effect at the next
     /// token mint, not at the next full sign-in).
     pub permissions: Vec<Permission>,
+    /// Whether the account enrolled a second factor — if so, every login must
+    /// present it before a session is issued.
+    pub mfa_enforced: bool,
+}
+
+/// A second factor typed by the user, relayed to `account` for checking. Auth
+/// never sees the enrolled secret or the recovery-code hashes.
+#[derive(Clone)]
+pub enum SecondFactor {
+    /// The authenticator app's current code.
+    Totp(String),
+    /// A one-time recovery code; spent by `account` when it matches.
+    RecoveryCode(String),
+}
+
+impl SecondFactor {
+    /// The `amr` method this factor adds to a session.
+    pub fn method(&self) -> AuthMethod {
+        match self {
+            Self::Totp(_) => AuthMethod::Otp,
+            Self::RecoveryCode(_) => AuthMethod::RecoveryCode,
+        }
+    }
+}
+
+// The code is a live credential: keep it out of logs and traces.
+impl std::fmt::Debug for SecondFactor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "SecondFactor({}, <redacted>)", self.method())
+    }
+}
+
+/// `account`'s answer to a second-factor check.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SecondFactorCheck {
+    pub verified: bool,
+    /// Unspent recovery codes left — clients nudge the user to regenerate
+    /// them when this runs low.
+    pub recovery_codes_remaining: u32,
 }
 
 /// Outbound port to the `account` service (gRPC adapter in Phase 4).
@@ -41,4 +80,13 @@ pub trait AccountDirectory: Send + Sync + 'static {
     /// Fetches the account's activation state and current permissions. Fails with
     /// [`AuthError::AccountDirectoryUnavailable`] if the SoR is unreachable.
     async fn lookup(&self, account_id: &AccountId) -> Result<AccountSnapshot, AuthError>;
+
+    /// Checks a second factor against the account's enrolment. A wrong code is
+    /// `verified: false`, not an error; an account without an enrolment fails
+    /// with [`AuthError::SecondFactorNotEnrolled`].
+    async fn verify_second_factor(
+        &self,
+        account_id: &AccountId,
+        factor: &SecondFactor,
+    ) -> Result<SecondFactorCheck, AuthError>;
 }
@@ -0,0 +1,23 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use crate::domain::value_object::AccountId;
+use crate::error::AuthError;
+
+/// The devices each account has signed in from, keyed by
+/// [`DeviceFingerprint::recognition_key`](crate::domain::value_object::DeviceFingerprint::recognition_key)
+/// (Postgres adapter, sharded by `account_id`). Backs new-device login
+/// detection; outlives the sessions themselves, so a device stays known after
+/// its sessions are revoked or expire.
+#[async_trait]
+pub trait KnownDeviceRepository: Send + Sync + 'static {
+    /// Records a sign-in from `device_key` at `seen_at`. `true` when the account
+    /// had never signed in from it before; concurrent first sightings of one
+    /// device settle on a single `true`.
+    async fn remember(
+        &self,
+        account_id: &AccountId,
+        device_key: &str,
+        seen_at: DateTime<Utc>,
+    ) -> Result<bool, AuthError>;
+}
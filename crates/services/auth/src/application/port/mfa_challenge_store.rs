@@ -0,0 +1,91 @@
+use async_trait::async_trait;
+use base64::Engine;
+use chrono::{DateTime, Duration, Utc};
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
+
+use crate::domain::value_object::{AccountId, AuthMethod, DeviceFingerprint, IdpSubject};
+use crate::error::AuthError;
+
+/// A login paused between the IdP and the second factor: the first factor
+/// succeeded, the account enforces MFA, and no session exists yet.
+///
+/// Everything the second leg needs to issue the session is carried here, so
+/// `VerifySecondFactor` does not re-run the IdP exchange. The `id` is the only
+/// thing the client holds — 256 random bits, single-use, short-lived.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MfaChallenge {
+    pub id: String,
+    pub account_id: AccountId,
+    pub subject: IdpSubject,
+    pub device: DeviceFingerprint,
+    /// How the first leg authenticated (`Password` / `Federated`).
+    pub first_factor: AuthMethod,
+    /// Whether the first leg established the subject link (reported back on
+    /// the issued session, as a single-factor login would).
+    pub first_link: bool,
+    pub expires_at: DateTime<Utc>,
+}
+
+impl MfaChallenge {
+    /// Opens a challenge for `account_id` that lapses `ttl` after `now`.
+    pub fn open(
+        account_id: AccountId,
+        subject: IdpSubject,
+        device: DeviceFingerprint,
+        first_factor: AuthMethod,
+        first_link: bool,
+        now: DateTime<Utc>,
+        ttl: Duration,
+    ) -> Self {
+        let mut bytes = [0u8; 32];
+        rand::rng().fill_bytes(&mut bytes);
+        Self {
+            id: base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(bytes),
+            account_id,
+            subject,
+            device,
+            first_factor,
+            first_link,
+            expires_at: now + ttl,
+        }
+    }
+
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        now >= self.expires_at
+    }
+
+    /// Remaining lifetime in whole seconds at `now` (saturating at zero).
+    pub fn expires_in_secs(&self, now: DateTime<Utc>) -> i64 {
+        (self.expires_at - now).num_seconds().max(0)
+    }
+}
+
+/// Short-lived second-factor state (Redis adapter): pending login challenges
+/// and the per-account count of wrong second factors.
+///
+/// The failure count is per account, not per challenge, so opening a fresh
+/// challenge (which only takes the password again) does not buy more guesses;
+/// step-ups count against the same budget.
+#[async_trait]
+pub trait MfaChallengeStore: Send + Sync + 'static {
+    /// Stores `challenge` until its `expires_at`.
+    async fn put(&self, challenge: &MfaChallenge) -> Result<(), AuthError>;
+
+    async fn find(&self, id: &str) -> Result<Option<MfaChallenge>, AuthError>;
+
+    /// Removes and returns the challenge in one step, so it completes at most
+    /// once even under concurrent submissions.
+    async fn take(&self, id: &str) -> Result<Option<MfaChallenge>, AuthError>;
+
+    /// Counts a wrong second factor for `account_id` and returns the failures
+    /// in the current window, which the first failure opens for `window`.
+    async fn record_failure(&self, account_id: &AccountId, window: Duration)
+        -> Result<u32, AuthError>;
+
+    /// Failures in the current window (0 when none is open).
+    async fn failures(&self, account_id: &AccountId) -> Result<u32, AuthError>;
+
+    /// Closes the window after a correct second factor.
+    async fn clear_failures(&self, account_id: &AccountId) -> Result<(), AuthError>;
+}
@@ -7,15 +7,21 @@
 pub mod account_directory;
 pub mod event_publisher;
 pub mod identity_provider;
+pub mod known_device_repository;
+pub mod mfa_challenge_store;
 pub mod refresh_token_repository;
 pub mod session_cache;
 pub mod session_repository;
 pub mod subject_link_repository;
 pub mod token_minter;
 
-pub use account_directory::{AccountActivation, AccountDirectory, AccountSnapshot};
+pub use account_directory::{
+    AccountActivation, AccountDirectory, AccountSnapshot, SecondFactor, SecondFactorCheck,
+};
 pub use event_publisher::EventPublisher;
 pub use identity_provider::{AuthnGrant, IdentityProvider, NormalizedClaims};
+pub use known_device_repository::KnownDeviceRepository;
+pub use mfa_challenge_store::{MfaChallenge, MfaChallengeStore};
 pub use refresh_token_repository::RefreshTokenRepository;
 pub use session_cache::SessionCache;
 pub use session_repository::SessionRepository;
@@ -27,6 +27,11 @@ pub struct IntrospectionView {
     pub generation: i64,
     pub permissions: Vec<String>,
     pub expires_at: Option<DateTime<Utc>>,
+    /// Assurance level (`aal1` / `aal2`), as carried in the token's `acr`.
+    pub acr: Option<String>,
+    /// Authentication methods, as carried in the token's `amr`.
+    pub amr: Vec<String>,
+    pub auth_time: Option<DateTime<Utc>>,
 }
 
 impl IntrospectionView {
@@ -38,6 +43,9 @@ impl IntrospectionView {
             generation: 0,
             permissions: Vec::new(),
             expires_at: None,
+            acr: None,
+            amr: Vec::new(),
+            auth_time: None,
         }
     }
 }
@@ -83,6 +91,9 @@ impl IntrospectHandler {
             generation: claims.generation.value(),
             permissions: claims.permissions.iter().map(|p| p.as_str().to_owned()).collect(),
             expires_at: Some(claims.expires_at),
+            acr: Some(claims.assurance().as_str().to_owned()),
+            amr: claims.amr(),
+            auth_time: Some(claims.auth_time),
         })
     }
 }
@@ -116,7 +127,7 @@ mod tests {
                 device: DeviceFingerprint::default(),
             },
         );
-        fx.login_handler().handle(env, t0()).await.unwrap()
+        fx.login_handler().handle(env, t0()).await.unwrap().issued().unwrap()
     }
 
     fn introspect_env(token: &str) -> Envelope<IntrospectQuery> {
@@ -137,6 +148,9 @@ mod tests {
         assert!(view.active);
         assert_eq!(view.account_id, Some(issued.account_id.as_str()));
         assert_eq!(view.session_id, Some(issued.session_id.as_str()));
+        assert_eq!(view.acr.as_deref(), Some("aal1"));
+        assert_eq!(view.amr, vec!["pwd".to_owned()]);
+        assert_eq!(view.auth_time, Some(t0()));
     }
 
     #[tokio::test]
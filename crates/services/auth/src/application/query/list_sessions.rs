@@ -4,7 +4,7 @@ use chrono::{DateTime, Utc};
 use cqrs::{Envelope, Query, QueryHandler};
 
 use crate::application::port::SessionRepository;
-use crate::domain::value_object::{AccountId, SessionStatus};
+use crate::domain::value_object::{AccountId, DeviceFingerprint, SessionStatus};
 use crate::error::AuthError;
 
 /// List an account's active sessions for a device-management view.
@@ -24,12 +24,58 @@ pub struct SessionSummary {
     pub session_id: String,
     pub status: SessionStatus,
     pub generation: i64,
+    /// The context bound at login.
+    pub device: DeviceFingerprint,
+    /// The context as last presented (login, then each refresh), and when.
+    pub last_seen: DeviceFingerprint,
+    pub last_seen_at: DateTime<Utc>,
     pub issued_at: DateTime<Utc>,
     pub expires_at: DateTime<Utc>,
     pub absolute_expiry: DateTime<Utc>,
     pub current: bool,
 }
 
+/// An account's sessions on one device: those sharing a client `device_id`,
+/// or a single session that carries none.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeviceSessions {
+    pub device_id: Option<String>,
+    /// The most recent `last_seen` among the group's sessions.
+    pub last_seen: DeviceFingerprint,
+    pub last_seen_at: DateTime<Utc>,
+    pub current: bool,
+    /// Most recently seen first.
+    pub session_ids: Vec<String>,
+}
+
+/// Groups `sessions` per device, most recently seen device first.
+pub fn group_by_device(sessions: &[SessionSummary]) -> Vec<DeviceSessions> {
+    let mut by_recency: Vec<&SessionSummary> = sessions.iter().collect();
+    by_recency.sort_by_key(|s| std::cmp::Reverse(s.last_seen_at));
+
+    let mut groups: Vec<DeviceSessions> = Vec::new();
+    for s in by_recency {
+        let device_id = s.device.device_id();
+        let existing = device_id
+            .and_then(|id| groups.iter_mut().find(|g| g.device_id.as_deref() == Some(id)));
+        match existing {
+            Some(group) => {
+                group.current |= s.current;
+                group.session_ids.push(s.session_id.clone());
+            }
+            // Visited newest first, so a group's first session is its latest.
+            None => groups.push(DeviceSessions {
+                device_id: device_id.map(str::to_owned),
+                last_seen: s.last_seen.clone(),
+                last_seen_at: s.last_seen_at,
+                current: s.current,
+                session_ids: vec![s.session_id.clone()],
+            }),
+        }
+    }
+    groups
+}
+
 pub struct ListSessionsHandler {
     sessions: Arc<dyn SessionRepository>,
 }
@@ -61,6 +107,9 @@ impl QueryHandler<ListSessionsQuery> for ListSessionsHandler {
                     session_id,
                     status: s.status(),
                     generation: s.generation().value(),
+                    device: s.device().clone(),
+                    last_seen: s.last_seen().clone(),
+                    last_seen_at: s.last_seen_at(),
                     issued_at: s.issued_at(),
                     expires_at: s.expires_at(),
                     absolute_expiry: s.absolute_expiry(),
@@ -81,14 +130,25 @@ mod tests {
     use uuid::Uuid;
 
     async fn login(fx: &Fixture) -> crate::application::command::IssuedSession {
+        login_from(fx, DeviceFingerprint::default()).await
+    }
+
+    async fn login_from(
+        fx: &Fixture,
+        device: DeviceFingerprint,
+    ) -> crate::application::command::IssuedSession {
         let env = Envelope::new(
             Uuid::now_v7(),
             LoginCommand {
                 grant: AuthnGrant::Password { username: "u".into(), password: "p".into() },
-                device: DeviceFingerprint::default(),
+                device,
             },
         );
-        fx.login_handler().handle(env, t0()).await.unwrap()
+        fx.login_handler().handle(env, t0()).await.unwrap().issued().unwrap()
+    }
+
+    fn on(device_id: &str) -> DeviceFingerprint {
+        DeviceFingerprint::new(None, None, Some(device_id.to_owned()))
     }
 
     #[tokio::test]
@@ -111,6 +171,55 @@ mod tests {
         assert!(sessions.iter().all(|s| s.status == SessionStatus::Active));
     }
 
+    #[tokio::test]
+    async fn groups_sessions_per_device_most_recent_first() {
+        let fx = Fixture::new();
+        let phone_a = login_from(&fx, on("phone")).await;
+        let laptop = login_from(&fx, on("laptop")).await;
+        let phone_b = login_from(&fx, on("phone")).await;
+        let _bare = login(&fx).await;
+
+        // The laptop refreshes, so it is the most recently seen device.
+        let later = t0() + chrono::Duration::minutes(5);
+        fx.refresh_handler()
+            .handle(
+                Envelope::new(
+                    Uuid::now_v7(),
+                    crate::application::command::RefreshCommand {
+                        refresh_token: laptop.refresh_token.clone(),
+                        device: on("laptop"),
+                    },
+                ),
+                later,
+            )
+            .await
+            .unwrap();
+
+        let env = Envelope::new(
+            Uuid::now_v7(),
+            ListSessionsQuery {
+                account_id: laptop.account_id.as_str(),
+                current_session_id: Some(phone_b.session_id.as_str()),
+            },
+        );
+        let sessions = fx.list_sessions_handler().handle(env).await.unwrap();
+        let devices = group_by_device(&sessions);
+
+        assert_eq!(devices.len(), 3, "phone, laptop, and the id-less session alone");
+        assert_eq!(devices[0].device_id.as_deref(), Some("laptop"));
+        assert_eq!(devices[0].last_seen_at, later);
+        assert!(!devices[0].current);
+
+        let phone = devices.iter().find(|d| d.device_id.as_deref() == Some("phone")).unwrap();
+        assert!(phone.current);
+        let mut ids = phone.session_ids.clone();
+        ids.sort();
+        let mut expected = vec![phone_a.session_id.as_str(), phone_b.session_id.as_str()];
+        expected.sort();
+        assert_eq!(ids, expected);
+        assert!(devices.iter().any(|d| d.device_id.is_none() && d.session_ids.len() == 1));
+    }
+
     #[tokio::test]
     async fn rejects_invalid_account_id() {
         let fx = Fixture::new();
@@ -2,4 +2,6 @@ pub mod introspect;
 pub mod list_sessions;
 
 pub use introspect::{IntrospectHandler, IntrospectQuery, IntrospectionView};
-pub use list_sessions::{ListSessionsHandler, ListSessionsQuery, SessionSummary};
+pub use list_sessions::{
+    DeviceSessions, ListSessionsHandler, ListSessionsQuery, SessionSummary, group_by_device,
+};
@@ -1,21 +1,34 @@
 //! Environment-sourced configuration for the auth service. Resolved once at boot
 //! and threaded into the composition root ([`crate::app::App::build`]).
 
+use base64::Engine;
 use chrono::Duration;
 
 use crate::application::SessionPolicy;
+use crate::infrastructure::http::jwks::JWKS_MAX_AGE_SECS;
 use crate::infrastructure::idp::KeycloakConfig;
-use crate::infrastructure::token::{EsKeyMaterial, EsVerifyingKey};
+use crate::infrastructure::token::{EsSigningKey, EsVerifyingKey, KEY_LEN, RotationPolicy};
 
-/// Fully-resolved auth configuration (token policy, signing material, IdP broker,
-/// and the `account` service endpoint). Backend connection configs (Postgres /
-/// Redis / Kafka) are resolved separately via their own `from_env`.
+/// Fully-resolved auth configuration (token policy, signing keys and their
+/// rotation, IdP broker, and the `account` service endpoint). Backend connection
+/// configs (Postgres / Redis / Kafka) are resolved separately via their own
+/// `from_env`.
 pub struct AuthConfig {
     pub policy: SessionPolicy,
-    pub signing: EsKeyMaterial,
-    /// Retiring keys still accepted for verification + published in the JWKS
-    /// during a rotation window. Empty in steady state.
+    /// `iss` stamped into (and required of) edge tokens.
+    pub token_issuer: String,
+    /// `aud` stamped into (and required of) edge tokens.
+    pub token_audience: String,
+    /// Seed for the first active key when `signing_keys` is empty — lets a
+    /// deployment that managed its key in config keep its `kid` across the
+    /// move to scheduled rotation. `None` ⇒ the rotator generates one.
+    pub signing_seed: Option<EsSigningKey>,
+    /// Verify-only keys seeded next to `signing_seed` (a rotation window that
+    /// was open under config-managed keys). Empty in steady state.
     pub retiring_keys: Vec<EsVerifyingKey>,
+    /// KEK wrapping signing-key private halves at rest (`LocalKek`).
+    pub signing_kek: [u8; KEY_LEN],
+    pub rotation: RotationPolicy,
     pub keycloak: KeycloakConfig,
     /// gRPC endpoint of the `account` service, e.g. `http://account:50059`.
     pub account_endpoint: String,
@@ -33,24 +46,70 @@ pub struct AuthConfig {
 impl AuthConfig {
     /// Resolves configuration from the environment.
     ///
-    /// Required: `AUTH_SIGNING_PRIVATE_PEM`, `AUTH_SIGNING_PUBLIC_PEM`. Everything
-    /// else has a production-shaped default.
+    /// Required: `AUTH_SIGNING_KEK_BASE64` (32 bytes). Everything else has a
+    /// production-shaped default; `AUTH_SIGNING_PRIVATE_PEM` /
+    /// `AUTH_SIGNING_PUBLIC_PEM` are an optional first-boot seed.
     pub fn from_env() -> anyhow::Result<Self> {
         let policy = SessionPolicy::new(
             Duration::seconds(env_secs("AUTH_ACCESS_TTL_SECS", 600)),
             Duration::seconds(env_secs("AUTH_SESSION_TTL_SECS", 1_800)),
             Duration::seconds(env_secs("AUTH_ABSOLUTE_TTL_SECS", 28_800)),
             Duration::seconds(env_secs("AUTH_REFRESH_TTL_SECS", 604_800)),
+        )
+        .with_second_factor(
+            Duration::seconds(env_secs("AUTH_MFA_CHALLENGE_TTL_SECS", 300)),
+            env_secs("AUTH_MFA_MAX_FAILURES", 5).clamp(1, i64::from(u32::MAX)) as u32,
         );
 
-        let signing = EsKeyMaterial {
-            private_pem: env_required("AUTH_SIGNING_PRIVATE_PEM")?.into_bytes(),
-            public_pem: env_required("AUTH_SIGNING_PUBLIC_PEM")?.into_bytes(),
-            key_id: env_or("AUTH_SIGNING_KID", "auth-es256-1"),
-            issuer: env_or("AUTH_TOKEN_ISSUER", "https://auth.core-platform"),
-            audience: env_or("AUTH_TOKEN_AUDIENCE", "core-platform"),
+        let signing_seed = match (
+            std::env::var("AUTH_SIGNING_PRIVATE_PEM").ok().filter(|v| !v.is_empty()),
+            std::env::var("AUTH_SIGNING_PUBLIC_PEM").ok().filter(|v| !v.is_empty()),
+        ) {
+            (Some(private_pem), Some(public_pem)) => Some(EsSigningKey {
+                key_id: env_or("AUTH_SIGNING_KID", "auth-es256-1"),
+                private_pem: private_pem.into_bytes(),
+                public_pem: public_pem.into_bytes(),
+            }),
+            (None, None) => None,
+            _ => anyhow::bail!(
+                "AUTH_SIGNING_PRIVATE_PEM and AUTH_SIGNING_PUBLIC_PEM must be set together"
+            ),
         };
 
+        let kek = base64::engine::general_purpose::STANDARD
+            .decode(env_required("AUTH_SIGNING_KEK_BASE64")?.trim())
+            .map_err(|e| anyhow::anyhow!("AUTH_SIGNING_KEK_BASE64 is not base64: {e}"))?;
+        let signing_kek: [u8; KEY_LEN] = kek
+            .try_into()
+            .map_err(|_| anyhow::anyhow!("AUTH_SIGNING_KEK_BASE64 must decode to {KEY_LEN} bytes"))?;
+
+        let check_every = std::time::Duration::from_secs(
+            env_secs("AUTH_SIGNING_CHECK_INTERVAL_SECS", 60).max(1) as u64,
+        );
+        // A superseded key must outlive every token it signed: the access TTL,
+        // plus slack for replicas still on the previous ring and verifier clock
+        // leeway.
+        let rotation = RotationPolicy {
+            rotate_every: Duration::seconds(env_secs("AUTH_SIGNING_ROTATE_EVERY_SECS", 2_592_000)),
+            publish_lead: Duration::seconds(env_secs("AUTH_SIGNING_PUBLISH_LEAD_SECS", 86_400)),
+            retire_after: policy.access_ttl
+                + Duration::seconds(env_secs("AUTH_SIGNING_RETIRE_SLACK_SECS", 300)),
+            check_every,
+        };
+        // A published key must reach every verifier before it signs: its JWKS
+        // can lag by one rotator tick on a replica plus one JWKS max-age.
+        let propagation =
+            Duration::seconds(check_every.as_secs() as i64 + JWKS_MAX_AGE_SECS as i64);
+        anyhow::ensure!(
+            rotation.publish_lead > propagation,
+            "AUTH_SIGNING_PUBLISH_LEAD_SECS must exceed the JWKS propagation delay ({}s)",
+            propagation.num_seconds()
+        );
+        anyhow::ensure!(
+            rotation.rotate_every > rotation.publish_lead,
+            "AUTH_SIGNING_ROTATE_EVERY_SECS must exceed AUTH_SIGNING_PUBLISH_LEAD_SECS"
+        );
+
         let keycloak = KeycloakConfig {
             token_endpoint: env_or("AUTH_KEYCLOAK_TOKEN_ENDPOINT", String::new()),
             client_id: env_or("AUTH_KEYCLOAK_CLIENT_ID", String::new()),
@@ -58,7 +117,7 @@ impl AuthConfig {
             scope: env_or("AUTH_KEYCLOAK_SCOPE", "openid".to_owned()),
         };
 
-        // Optional single retiring key for a rotation window.
+        // Optional single retiring key for a rotation window (seed only).
         let retiring_keys = match (
             std::env::var("AUTH_SIGNING_RETIRING_PUBLIC_PEM").ok(),
             std::env::var("AUTH_SIGNING_RETIRING_KID").ok(),
@@ -71,8 +130,12 @@ impl AuthConfig {
 
         Ok(Self {
             policy,
-            signing,
+            token_issuer: env_or("AUTH_TOKEN_ISSUER", "https://auth.core-platform"),
+            token_audience: env_or("AUTH_TOKEN_AUDIENCE", "core-platform"),
+            signing_seed,
             retiring_keys,
+            signing_kek,
+            rotation,
             keycloak,
             account_endpoint: env_or("AUTH_ACCOUNT_GRPC_ENDPOINT", "http://localhost:50059"),
             account_rpc_timeout: env_ms("AUTH_ACCOUNT_RPC_TIMEOUT_MS", 2_000),
@@ -2,10 +2,12 @@ use chrono::{DateTime, Duration, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-use crate::domain::event::{DomainEvent, SessionIssued, SessionRevoked};
+use crate::domain::event::{
+    DomainEvent, NewDeviceLogin, RefreshTokenReuseDetected, SessionIssued, SessionRevoked,
+};
 use crate::domain::value_object::{
-    AccessTokenClaims, AccountId, DeviceFingerprint, Generation, IdpSubject, Permission,
-    RevocationReason, SessionId, SessionStatus,
+    AccessTokenClaims, AccountId, AssuranceLevel, AuthMethod, DeviceFingerprint, Generation,
+    IdpSubject, Permission, RevocationReason, SessionId, SessionStatus,
 };
 use crate::error::AuthError;
 
@@ -17,6 +19,9 @@ pub struct SessionIssueParams {
     /// The account's current generation at issue time.
     pub generation: Generation,
     pub device: DeviceFingerprint,
+    /// How the user authenticated, first factor first (a second factor when
+    /// the account enforces MFA).
+    pub methods: Vec<AuthMethod>,
     pub issued_at: DateTime<Utc>,
     /// Sliding expiry; must be after `issued_at` and at/under `absolute_expiry`.
     pub expires_at: DateTime<Utc>,
@@ -40,6 +45,13 @@ pub struct SessionIssueParams {
 /// 4. A session is valid for a request only if its `generation` still matches the
 ///    account's current generation (`is_valid_under`) — a global sign-out bump
 ///    invalidates it without touching this row.
+/// 5. A session is authenticated by exactly one first factor, listed first in
+///    `methods`; a step-up only adds second factors and moves `auth_time`
+///    (`issue`, `step_up`).
+///
+/// `device` is the context bound at login and never changes; `last_seen` is
+/// that context as most recently presented (login, then every refresh), for
+/// the device-management view.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Session {
     id: SessionId,
@@ -48,6 +60,10 @@ pub struct Session {
     generation: Generation,
     status: SessionStatus,
     device: DeviceFingerprint,
+    last_seen: DeviceFingerprint,
+    last_seen_at: DateTime<Utc>,
+    methods: Vec<AuthMethod>,
+    auth_time: DateTime<Utc>,
     issued_at: DateTime<Utc>,
     expires_at: DateTime<Utc>,
     absolute_expiry: DateTime<Utc>,
@@ -63,8 +79,16 @@ impl Session {
 
     /// Establishes a new `Active` session. Emits [`SessionIssued`].
     ///
-    /// Rejects a non-positive lifetime or an `expires_at` beyond the absolute cap.
+    /// Rejects a non-positive lifetime, an `expires_at` beyond the absolute cap,
+    /// or `methods` that do not start with exactly one first factor.
     pub fn issue(params: SessionIssueParams) -> Result<Self, AuthError> {
+        let first_factors = params.methods.iter().filter(|m| !m.is_second_factor()).count();
+        if first_factors != 1 || params.methods.first().is_none_or(AuthMethod::is_second_factor) {
+            return Err(AuthError::DomainViolation {
+                field: "session.methods".into(),
+                message: "a session starts from exactly one first factor".into(),
+            });
+        }
         if params.expires_at <= params.issued_at {
             return Err(AuthError::DomainViolation {
                 field: "session.expires_at".into(),
@@ -97,7 +121,11 @@ impl Session {
             subject: params.subject,
             generation: params.generation,
             status: SessionStatus::Active,
+            last_seen: params.device.clone(),
+            last_seen_at: params.issued_at,
             device: params.device,
+            methods: params.methods,
+            auth_time: params.issued_at,
             issued_at: params.issued_at,
             expires_at: params.expires_at,
             absolute_expiry: params.absolute_expiry,
@@ -116,6 +144,10 @@ impl Session {
         generation: Generation,
         status: SessionStatus,
         device: DeviceFingerprint,
+        last_seen: DeviceFingerprint,
+        last_seen_at: DateTime<Utc>,
+        methods: Vec<AuthMethod>,
+        auth_time: DateTime<Utc>,
         issued_at: DateTime<Utc>,
         expires_at: DateTime<Utc>,
         absolute_expiry: DateTime<Utc>,
@@ -129,6 +161,10 @@ impl Session {
             generation,
             status,
             device,
+            last_seen,
+            last_seen_at,
+            methods,
+            auth_time,
             issued_at,
             expires_at,
             absolute_expiry,
@@ -164,6 +200,28 @@ impl Session {
         &self.device
     }
 
+    /// The device context as last presented — at login, then on each refresh.
+    pub fn last_seen(&self) -> &DeviceFingerprint {
+        &self.last_seen
+    }
+
+    pub fn last_seen_at(&self) -> DateTime<Utc> {
+        self.last_seen_at
+    }
+
+    pub fn methods(&self) -> &[AuthMethod] {
+        &self.methods
+    }
+
+    /// When the user last presented a factor — issue time, or the last step-up.
+    pub fn auth_time(&self) -> DateTime<Utc> {
+        self.auth_time
+    }
+
+    pub fn assurance(&self) -> AssuranceLevel {
+        AssuranceLevel::of(&self.methods)
+    }
+
     pub fn issued_at(&self) -> DateTime<Utc> {
         self.issued_at
     }
@@ -219,14 +277,35 @@ impl Session {
             return Err(AuthError::SessionExpired);
         }
 
-        Ok(AccessTokenClaims::new(
-            self.account_id,
-            self.id,
-            self.generation,
+        Ok(AccessTokenClaims {
+            account_id: self.account_id,
+            session_id: self.id,
+            generation: self.generation,
             permissions,
-            now,
+            issued_at: now,
             expires_at,
-        ))
+            methods: self.methods.clone(),
+            auth_time: self.auth_time,
+        })
+    }
+
+    /// Invariant 5: records a second factor presented at `now` on a live
+    /// session. The session becomes multi-factor and its `auth_time` moves to
+    /// `now`, so the next minted token satisfies step-up rules for a while.
+    pub fn step_up(&mut self, now: DateTime<Utc>, method: AuthMethod) -> Result<(), AuthError> {
+        self.ensure_mintable(now)?;
+        if !method.is_second_factor() {
+            return Err(AuthError::DomainViolation {
+                field: "session.methods".into(),
+                message: format!("'{method}' is not a second factor"),
+            });
+        }
+        if !self.methods.contains(&method) {
+            self.methods.push(method);
+        }
+        self.auth_time = now;
+        self.touch();
+        Ok(())
     }
 
     /// Invariant 3: slides the sliding expiry forward by `ttl`, never past the
@@ -241,6 +320,66 @@ impl Session {
         Ok(())
     }
 
+    /// Invariant 3 on the refresh path: slides the expiry like [`extend`](Self::extend)
+    /// and records `presented` (layered over the previous context) as where the
+    /// session was last seen — one change, one version.
+    pub fn refresh(
+        &mut self,
+        now: DateTime<Utc>,
+        ttl: Duration,
+        presented: &DeviceFingerprint,
+    ) -> Result<(), AuthError> {
+        self.ensure_mintable(now)?;
+        self.expires_at = self.expires_at.max((now + ttl).min(self.absolute_expiry));
+        self.last_seen = presented.seen_over(&self.last_seen);
+        self.last_seen_at = now;
+        self.touch();
+        Ok(())
+    }
+
+    /// Flags this freshly issued session as the account's first from its device.
+    /// Emits [`NewDeviceLogin`]; no state changes.
+    pub fn flag_new_device(&mut self, now: DateTime<Utc>, correlation_id: Uuid) {
+        let location = self.device.location();
+        self.pending_events.push(DomainEvent::NewDeviceLogin(NewDeviceLogin {
+            session_id: self.id,
+            account_id: self.account_id,
+            device_id: self.device.device_id().map(str::to_owned),
+            user_agent: self.device.user_agent().map(str::to_owned),
+            country: location.map(|l| l.country().to_owned()),
+            city: location.and_then(|l| l.city()).map(str::to_owned),
+            occurred_at: now,
+            correlation_id,
+        }));
+    }
+
+    /// Reports that a spent refresh token of this session was presented again,
+    /// by `presented`, after the account moved to `generation`. Emits
+    /// [`RefreshTokenReuseDetected`] whatever the status — the revocation itself
+    /// is [`revoke`](Self::revoke)'s.
+    pub fn flag_refresh_reuse(
+        &mut self,
+        now: DateTime<Utc>,
+        generation: Generation,
+        presented: &DeviceFingerprint,
+        correlation_id: Uuid,
+    ) {
+        let presenter = presented.seen_over(&self.last_seen);
+        let location = presenter.location();
+        self.pending_events.push(DomainEvent::RefreshTokenReuseDetected(
+            RefreshTokenReuseDetected {
+                session_id: self.id,
+                account_id: self.account_id,
+                generation,
+                user_agent: presenter.user_agent().map(str::to_owned),
+                country: location.map(|l| l.country().to_owned()),
+                city: location.and_then(|l| l.city()).map(str::to_owned),
+                occurred_at: now,
+                correlation_id,
+            },
+        ));
+    }
+
     /// Revokes the session (single-session logout, reuse-detection, or admin).
     /// Emits [`SessionRevoked`]. Illegal from a terminal state.
     pub fn revoke(
@@ -325,6 +464,7 @@ mod tests {
             subject: IdpSubject::new("iss", "sub").unwrap(),
             generation: Generation::INITIAL,
             device: DeviceFingerprint::default(),
+            methods: vec![AuthMethod::Password],
             issued_at: now,
             expires_at: now + Duration::minutes(30),
             absolute_expiry: now + Duration::hours(8),
@@ -361,6 +501,48 @@ mod tests {
         assert!(matches!(Session::issue(p).unwrap_err(), AuthError::DomainViolation { .. }));
     }
 
+    #[test]
+    fn issue_requires_a_single_leading_first_factor() {
+        let mut p = params();
+        p.methods = vec![AuthMethod::Otp];
+        assert!(matches!(Session::issue(p).unwrap_err(), AuthError::DomainViolation { .. }));
+        let mut p = params();
+        p.methods = vec![AuthMethod::Password, AuthMethod::Federated];
+        assert!(matches!(Session::issue(p).unwrap_err(), AuthError::DomainViolation { .. }));
+        let mut p = params();
+        p.methods = vec![AuthMethod::Federated, AuthMethod::Otp];
+        assert_eq!(Session::issue(p).unwrap().assurance(), AssuranceLevel::MultiFactor);
+    }
+
+    #[test]
+    fn step_up_adds_the_factor_and_moves_auth_time() {
+        let mut s = active_session();
+        assert_eq!(s.assurance(), AssuranceLevel::SingleFactor);
+        assert_eq!(s.auth_time(), t0());
+
+        let later = t0() + Duration::minutes(20);
+        s.step_up(later, AuthMethod::Otp).unwrap();
+        s.step_up(later, AuthMethod::Otp).unwrap();
+        assert_eq!(s.methods(), &[AuthMethod::Password, AuthMethod::Otp]);
+        assert_eq!(s.assurance(), AssuranceLevel::MultiFactor);
+
+        let claims = s.mint_access_token(later, Duration::minutes(5), vec![]).unwrap();
+        assert_eq!(claims.auth_time, later);
+        assert_eq!(claims.assurance(), AssuranceLevel::MultiFactor);
+        assert_eq!(claims.amr(), vec!["pwd", "otp", "mfa"]);
+    }
+
+    #[test]
+    fn step_up_rejects_first_factors_and_dead_sessions() {
+        let mut s = active_session();
+        assert!(matches!(
+            s.step_up(t0(), AuthMethod::Password).unwrap_err(),
+            AuthError::DomainViolation { .. }
+        ));
+        s.revoke(t0(), RevocationReason::Logout, Uuid::now_v7()).unwrap();
+        assert!(matches!(s.step_up(t0(), AuthMethod::Otp).unwrap_err(), AuthError::SessionRevoked));
+    }
+
     #[test]
     fn mint_clamps_to_sliding_expiry() {
         let s = active_session();
@@ -429,6 +611,51 @@ mod tests {
         assert_eq!(s.version(), 1, "extension bumps version");
     }
 
+    #[test]
+    fn refresh_slides_records_last_seen_and_bumps_version_once() {
+        let mut p = params();
+        p.device = DeviceFingerprint::new(Some("Firefox".into()), Some("10.0.0.1".into()), None);
+        let mut s = Session::issue(p).unwrap();
+        assert_eq!(s.last_seen(), s.device());
+        assert_eq!(s.last_seen_at(), t0());
+
+        let now = t0() + Duration::minutes(10);
+        let presented = DeviceFingerprint::new(None, Some("10.0.0.2".into()), None);
+        s.refresh(now, Duration::minutes(30), &presented).unwrap();
+
+        assert_eq!(s.expires_at(), now + Duration::minutes(30));
+        assert_eq!(s.last_seen_at(), now);
+        assert_eq!(s.last_seen().ip_address(), Some("10.0.0.2"));
+        assert_eq!(s.last_seen().user_agent(), Some("Firefox"));
+        assert_eq!(s.device().ip_address(), Some("10.0.0.1"), "the bound device never moves");
+        assert_eq!(s.version(), 1);
+    }
+
+    #[test]
+    fn refresh_at_the_cap_still_records_activity() {
+        let mut s = active_session();
+        let now = t0() + Duration::minutes(20);
+        s.extend(now, Duration::hours(20)).unwrap();
+        let capped = s.expires_at();
+        s.refresh(now + Duration::minutes(1), Duration::hours(20), &DeviceFingerprint::default())
+            .unwrap();
+        assert_eq!(s.expires_at(), capped);
+        assert_eq!(s.last_seen_at(), now + Duration::minutes(1));
+        assert_eq!(s.version(), 2, "recorded activity is a change even without a slide");
+    }
+
+    #[test]
+    fn flags_emit_their_events_without_touching_state() {
+        let mut s = active_session();
+        s.drain_events();
+        s.flag_new_device(t0(), Uuid::now_v7());
+        s.flag_refresh_reuse(t0(), s.generation().next(), &DeviceFingerprint::default(), Uuid::now_v7());
+        let types: Vec<_> = s.drain_events().iter().map(DomainEvent::event_type).collect();
+        assert_eq!(types, ["auth.new_device_login", "auth.refresh_token_reuse_detected"]);
+        assert_eq!(s.status(), SessionStatus::Active);
+        assert_eq!(s.version(), 0);
+    }
+
     #[test]
     fn extend_fails_on_revoked() {
         let mut s = active_session();
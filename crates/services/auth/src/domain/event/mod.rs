@@ -1,7 +1,11 @@
+pub mod new_device_login;
+pub mod refresh_token_reuse_detected;
 pub mod session_issued;
 pub mod session_revoked;
 pub mod subject_linked;
 
+pub use new_device_login::NewDeviceLogin;
+pub use refresh_token_reuse_detected::RefreshTokenReuseDetected;
 pub use session_issued::SessionIssued;
 pub use session_revoked::SessionRevoked;
 pub use subject_linked::SubjectLinked;
@@ -22,6 +26,8 @@ pub enum DomainEvent {
     SessionIssued(SessionIssued),
     SessionRevoked(SessionRevoked),
     SubjectLinked(SubjectLinked),
+    NewDeviceLogin(NewDeviceLogin),
+    RefreshTokenReuseDetected(RefreshTokenReuseDetected),
 }
 
 impl DomainEvent {
@@ -31,6 +37,8 @@ impl DomainEvent {
             Self::SessionIssued(_) => "auth.session_issued",
             Self::SessionRevoked(_) => "auth.session_revoked",
             Self::SubjectLinked(_) => "auth.subject_linked",
+            Self::NewDeviceLogin(_) => "auth.new_device_login",
+            Self::RefreshTokenReuseDetected(_) => "auth.refresh_token_reuse_detected",
         }
     }
 }
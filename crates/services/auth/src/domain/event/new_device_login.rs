@@ -0,0 +1,25 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::value_object::{AccountId, SessionId};
+
+/// A session was established from a device the account had not signed in from
+/// before — the cue for a "new sign-in" notification. Not emitted for an
+/// account's first login, nor when the login carried nothing to recognise the
+/// device by.
+///
+/// Carries the labels a notification shows; the raw IP stays on the session
+/// row and never goes on the wire.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NewDeviceLogin {
+    pub session_id: SessionId,
+    pub account_id: AccountId,
+    pub device_id: Option<String>,
+    pub user_agent: Option<String>,
+    /// ISO 3166-1 alpha-2, as resolved by the edge.
+    pub country: Option<String>,
+    pub city: Option<String>,
+    pub occurred_at: DateTime<Utc>,
+    pub correlation_id: Uuid,
+}
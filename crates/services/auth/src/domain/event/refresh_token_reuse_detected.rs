@@ -0,0 +1,24 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::value_object::{AccountId, Generation, SessionId};
+
+/// An already-rotated refresh token was presented again — the security signal
+/// behind a reuse revocation. Emitted on every detection, whether or not the
+/// session was still active to revoke (that is `SessionRevoked`'s job).
+///
+/// The device fields describe the *presenter* of the spent token, merged over
+/// the session's last-seen context; the raw IP stays on the session row.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RefreshTokenReuseDetected {
+    pub session_id: SessionId,
+    pub account_id: AccountId,
+    /// The account's generation after the bump that killed every edge token.
+    pub generation: Generation,
+    pub user_agent: Option<String>,
+    pub country: Option<String>,
+    pub city: Option<String>,
+    pub occurred_at: DateTime<Utc>,
+    pub correlation_id: Uuid,
+}
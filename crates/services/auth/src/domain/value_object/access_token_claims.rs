@@ -1,7 +1,7 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
-use super::{AccountId, Generation, Permission, SessionId};
+use super::{AccountId, AssuranceLevel, AuthMethod, Generation, Permission, SessionId};
 
 /// The normalized claim set for an edge access token, produced by
 /// [`Session::mint_access_token`](crate::domain::aggregate::Session::mint_access_token).
@@ -24,18 +24,26 @@ pub struct AccessTokenClaims {
     pub issued_at: DateTime<Utc>,
     /// Always ≤ the session's sliding and absolute expiry.
     pub expires_at: DateTime<Utc>,
+    /// `amr` — how the session was authenticated, first factor first.
+    pub methods: Vec<AuthMethod>,
+    /// `auth_time` — when the user last presented a factor (login or step-up).
+    pub auth_time: DateTime<Utc>,
 }
 
 impl AccessTokenClaims {
-    pub(crate) fn new(
-        account_id: AccountId,
-        session_id: SessionId,
-        generation: Generation,
-        permissions: Vec<Permission>,
-        issued_at: DateTime<Utc>,
-        expires_at: DateTime<Utc>,
-    ) -> Self {
-        Self { account_id, session_id, generation, permissions, issued_at, expires_at }
+    /// `acr` — the assurance the token's methods reach.
+    pub fn assurance(&self) -> AssuranceLevel {
+        AssuranceLevel::of(&self.methods)
+    }
+
+    /// The `amr` claim values: each method, plus RFC 8176 `mfa` once a second
+    /// factor is among them.
+    pub fn amr(&self) -> Vec<String> {
+        let mut amr: Vec<String> = self.methods.iter().map(|m| m.as_str().to_owned()).collect();
+        if self.assurance() == AssuranceLevel::MultiFactor {
+            amr.push("mfa".to_owned());
+        }
+        amr
     }
 
     /// Remaining lifetime in whole seconds at `now` (saturating at zero).
@@ -0,0 +1,63 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use super::AuthMethod;
+
+/// Authentication assurance of a session — the edge token's `acr` claim,
+/// named after the NIST SP 800-63B levels.
+///
+/// Derived from the session's [`AuthMethod`]s rather than stored, so it can
+/// never disagree with them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AssuranceLevel {
+    /// A single factor — the IdP login alone.
+    SingleFactor,
+    /// The IdP login plus a second factor. Services demanding step-up
+    /// (`service_runtime::STEP_UP_ACR`) require this level.
+    MultiFactor,
+}
+
+impl AssuranceLevel {
+    /// The level `methods` reach: multi-factor once any second factor is present.
+    pub fn of(methods: &[AuthMethod]) -> Self {
+        if methods.iter().any(AuthMethod::is_second_factor) {
+            Self::MultiFactor
+        } else {
+            Self::SingleFactor
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::SingleFactor => "aal1",
+            Self::MultiFactor => "aal2",
+        }
+    }
+}
+
+impl fmt::Display for AssuranceLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a_second_factor_raises_the_level() {
+        assert_eq!(AssuranceLevel::of(&[AuthMethod::Password]), AssuranceLevel::SingleFactor);
+        assert_eq!(
+            AssuranceLevel::of(&[AuthMethod::Federated, AuthMethod::RecoveryCode]),
+            AssuranceLevel::MultiFactor
+        );
+    }
+
+    #[test]
+    fn multi_factor_is_what_step_up_rules_demand() {
+        assert_eq!(AssuranceLevel::MultiFactor.as_str(), service_runtime::STEP_UP_ACR);
+    }
+}
@@ -0,0 +1,81 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::AuthError;
+
+/// How the holder of a session proved who they are — one entry of the edge
+/// token's `amr` claim (RFC 8176 values where one exists).
+///
+/// The first entry is always the first factor (`Password` or `Federated`);
+/// `Otp` / `RecoveryCode` are added by a second-factor check at login or by a
+/// step-up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AuthMethod {
+    /// Resource-owner password grant brokered to the IdP.
+    Password,
+    /// Authorization-code grant: the IdP authenticated the user on its own page.
+    Federated,
+    /// A TOTP code from the account's enrolled authenticator.
+    Otp,
+    /// One of the account's one-time recovery codes.
+    RecoveryCode,
+}
+
+impl AuthMethod {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Password => "pwd",
+            Self::Federated => "fed",
+            Self::Otp => "otp",
+            Self::RecoveryCode => "rcode",
+        }
+    }
+
+    /// Whether this method is a second factor (as opposed to the IdP login).
+    pub fn is_second_factor(&self) -> bool {
+        matches!(self, Self::Otp | Self::RecoveryCode)
+    }
+}
+
+impl fmt::Display for AuthMethod {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl TryFrom<&str> for AuthMethod {
+    type Error = AuthError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        match s {
+            "pwd" => Ok(Self::Password),
+            "fed" => Ok(Self::Federated),
+            "otp" => Ok(Self::Otp),
+            "rcode" => Ok(Self::RecoveryCode),
+            other => Err(AuthError::DomainViolation {
+                field: "auth_method".into(),
+                message: format!("unknown authentication method: '{other}'"),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_through_its_amr_value() {
+        for method in [
+            AuthMethod::Password,
+            AuthMethod::Federated,
+            AuthMethod::Otp,
+            AuthMethod::RecoveryCode,
+        ] {
+            assert_eq!(AuthMethod::try_from(method.as_str()).unwrap(), method);
+        }
+        assert!(AuthMethod::try_from("mfa").is_err());
+    }
+}
@@ -1,17 +1,23 @@
 use serde::{Deserialize, Serialize};
 
-/// Best-effort client/device metadata captured at login for session tracking
-/// and anomaly signalling.
+use super::GeoLocation;
+
+/// Best-effort client/device metadata captured at login (and again on every
+/// refresh) for session tracking and anomaly signalling.
 ///
 /// None of these fields are security-bearing on their own — they label sessions
-/// in the device-management view and let a refresh optionally re-check that the
-/// presenting device matches the one the session was bound to. All components
-/// are optional.
+/// in the device-management view, let a refresh optionally re-check that the
+/// presenting device matches the one the session was bound to, and let a login
+/// be recognised as coming from a device the account has used before. All
+/// components are optional.
 #[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct DeviceFingerprint {
     user_agent: Option<String>,
     ip_address: Option<String>,
     device_id: Option<String>,
+    /// Absent from MFA challenges parked before locations were captured.
+    #[serde(default)]
+    location: Option<GeoLocation>,
 }
 
 impl DeviceFingerprint {
@@ -20,7 +26,13 @@ impl DeviceFingerprint {
         ip_address: Option<String>,
         device_id: Option<String>,
     ) -> Self {
-        Self { user_agent, ip_address, device_id }
+        Self { user_agent, ip_address, device_id, location: None }
+    }
+
+    /// Attaches the edge-resolved location of the client IP.
+    pub fn with_location(mut self, location: Option<GeoLocation>) -> Self {
+        self.location = location;
+        self
     }
 
     pub fn user_agent(&self) -> Option<&str> {
@@ -35,6 +47,42 @@ impl DeviceFingerprint {
         self.device_id.as_deref()
     }
 
+    pub fn location(&self) -> Option<&GeoLocation> {
+        self.location.as_ref()
+    }
+
+    /// The key a login's device is remembered under for new-device detection:
+    /// the client `device_id` when present, else the user agent (a browser
+    /// that sends no id is recognised by its agent string). `None` when
+    /// neither is known — nothing to recognise, so no detection (fail-open,
+    /// as [`same_device_as`](Self::same_device_as)).
+    pub fn recognition_key(&self) -> Option<String> {
+        match (self.device_id(), self.user_agent()) {
+            (Some(id), _) => Some(format!("id:{id}")),
+            (None, Some(agent)) => Some(format!("ua:{agent}")),
+            (None, None) => None,
+        }
+    }
+
+    /// This context as presented on a later request, layered over `previous`:
+    /// each component present here wins, each absent one keeps the previous
+    /// value — a client that omits its agent on refresh does not blank it. The
+    /// location travels with the IP it was resolved from: a new IP without one
+    /// drops the previous location rather than mislabel the new address.
+    pub fn seen_over(&self, previous: &DeviceFingerprint) -> DeviceFingerprint {
+        let (ip_address, location) = if self.ip_address.is_some() || self.location.is_some() {
+            (self.ip_address.clone(), self.location.clone())
+        } else {
+            (previous.ip_address.clone(), previous.location.clone())
+        };
+        DeviceFingerprint {
+            user_agent: self.user_agent.clone().or_else(|| previous.user_agent.clone()),
+            ip_address,
+            device_id: self.device_id.clone().or_else(|| previous.device_id.clone()),
+            location,
+        }
+    }
+
     /// Whether `other` plausibly originates from the same device.
     ///
     /// Compares the stable `device_id` when both sides provide one; if either is
@@ -72,4 +120,31 @@ mod tests {
         assert!(with_device("d1").same_device_as(&none));
         assert!(none.same_device_as(&with_device("d1")));
     }
+
+    #[test]
+    fn recognition_prefers_the_device_id_over_the_agent() {
+        let agent = DeviceFingerprint::new(Some("Firefox".into()), None, None);
+        let both = DeviceFingerprint::new(Some("Firefox".into()), None, Some("d1".into()));
+        assert_eq!(agent.recognition_key().as_deref(), Some("ua:Firefox"));
+        assert_eq!(both.recognition_key().as_deref(), Some("id:d1"));
+        assert_eq!(DeviceFingerprint::default().recognition_key(), None);
+    }
+
+    #[test]
+    fn seen_over_keeps_previous_components_the_request_omits() {
+        let paris = GeoLocation::new("FR", Some("Paris".into()));
+        let login = DeviceFingerprint::new(Some("Firefox".into()), Some("10.0.0.1".into()), None)
+            .with_location(paris.clone());
+        let silent = DeviceFingerprint::new(None, None, None);
+        let seen = silent.seen_over(&login);
+        assert_eq!(seen.user_agent(), Some("Firefox"));
+        assert_eq!(seen.ip_address(), Some("10.0.0.1"));
+        assert_eq!(seen.location(), paris.as_ref());
+
+        let moved = DeviceFingerprint::new(None, Some("10.0.0.2".into()), None);
+        let seen = moved.seen_over(&login);
+        assert_eq!(seen.user_agent(), Some("Firefox"));
+        assert_eq!(seen.ip_address(), Some("10.0.0.2"));
+        assert_eq!(seen.location(), None, "the old location belonged to the old IP");
+    }
 }
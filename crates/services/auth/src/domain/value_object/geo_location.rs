@@ -0,0 +1,54 @@
+use serde::{Deserialize, Serialize};
+
+/// Coarse client location as resolved by the edge from the client IP — an
+/// ISO 3166-1 alpha-2 country and, when known, a city.
+///
+/// Best-effort labelling for the device-management view and new-device
+/// notifications, never a security decision. Malformed input is dropped rather
+/// than rejected: a bad hint must not fail a login.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GeoLocation {
+    country: String,
+    city: Option<String>,
+}
+
+impl GeoLocation {
+    /// `None` unless `country` is two ASCII letters (stored upper-case). A
+    /// blank `city` is treated as absent.
+    pub fn new(country: &str, city: Option<String>) -> Option<Self> {
+        let country = country.trim();
+        if country.len() != 2 || !country.bytes().all(|b| b.is_ascii_alphabetic()) {
+            return None;
+        }
+        let city = city.map(|c| c.trim().to_owned()).filter(|c| !c.is_empty());
+        Some(Self { country: country.to_ascii_uppercase(), city })
+    }
+
+    pub fn country(&self) -> &str {
+        &self.country
+    }
+
+    pub fn city(&self) -> Option<&str> {
+        self.city.as_deref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalizes_the_country_and_drops_a_blank_city() {
+        let geo = GeoLocation::new(" fr ", Some("  ".into())).unwrap();
+        assert_eq!(geo.country(), "FR");
+        assert_eq!(geo.city(), None);
+        assert_eq!(GeoLocation::new("DE", Some("Berlin".into())).unwrap().city(), Some("Berlin"));
+    }
+
+    #[test]
+    fn rejects_anything_but_an_alpha_2_code() {
+        assert!(GeoLocation::new("", None).is_none());
+        assert!(GeoLocation::new("FRA", None).is_none());
+        assert!(GeoLocation::new("4X", None).is_none());
+    }
+}
@@ -1,7 +1,10 @@
 pub mod access_token_claims;
 pub mod account_id;
+pub mod assurance_level;
+pub mod auth_method;
 pub mod device_fingerprint;
 pub mod generation;
+pub mod geo_location;
 pub mod idp_subject;
 pub mod permission;
 pub mod refresh_token_hash;
@@ -13,8 +16,11 @@ pub mod session_status;
 
 pub use access_token_claims::AccessTokenClaims;
 pub use account_id::AccountId;
+pub use assurance_level::AssuranceLevel;
+pub use auth_method::AuthMethod;
 pub use device_fingerprint::DeviceFingerprint;
 pub use generation::Generation;
+pub use geo_location::GeoLocation;
 pub use idp_subject::IdpSubject;
 pub use permission::Permission;
 pub use refresh_token_hash::RefreshTokenHash;
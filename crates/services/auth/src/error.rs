@@ -16,6 +16,8 @@ use thiserror::Error;
 /// | AUT-1002 | SessionRevoked               | 401  | Low      | No        |
 /// | AUT-1003 | SessionExpired               | 401  | Low      | No        |
 /// | AUT-1004 | InvalidSessionTransition     | 422  | Medium   | No        |
+/// | AUT-1005 | MfaChallengeNotFound         | 401  | Low      | No        |
+/// | AUT-1006 | MfaChallengeExhausted        | 429  | Medium   | No        |
 /// | AUT-2001 | RefreshTokenNotFound         | 401  | Low      | No        |
 /// | AUT-2002 | RefreshTokenExpired          | 401  | Low      | No        |
 /// | AUT-2003 | RefreshTokenReuseDetected    | 401  | **High** | No        |
@@ -31,6 +33,8 @@ use thiserror::Error;
 /// | AUT-5004 | ClaimsNormalizationFailed    | 502  | Medium   | No        |
 /// | AUT-6001 | AccountNotActive             | 403  | Medium   | No        |
 /// | AUT-6002 | AccountDirectoryUnavailable  | 503  | High     | **Yes**   |
+/// | AUT-6003 | SecondFactorRejected         | 401  | Low      | No        |
+/// | AUT-6004 | SecondFactorNotEnrolled      | 422  | Low      | No        |
 /// | AUT-9001 | DomainViolation              | 422  | Medium   | No        |
 /// | AUT-9002 | InvalidSessionId             | 422  | Low      | No        |
 /// | AUT-9003 | InvalidAccountId             | 422  | Low      | No        |
@@ -73,6 +77,15 @@ pub enum AuthError {
     #[error("session transition from '{from}' to '{to}' is not permitted")]
     InvalidSessionTransition { from: String, to: String },
 
+    /// Unknown, expired, or already completed — the client restarts at `Login`.
+    #[error("second-factor challenge not found or expired")]
+    MfaChallengeNotFound,
+
+    /// Too many wrong second factors for the account within the challenge
+    /// window; the pending challenge is discarded.
+    #[error("too many wrong second factors; try again later")]
+    MfaChallengeExhausted,
+
     // ── Refresh / rotation (AUT-2xxx) ─────────────────────────────────────────
     #[error("refresh token not found")]
     RefreshTokenNotFound,
@@ -125,6 +138,12 @@ pub enum AuthError {
     #[error("account directory service is unavailable")]
     AccountDirectoryUnavailable,
 
+    #[error("second factor rejected")]
+    SecondFactorRejected,
+
+    #[error("no second factor is enrolled for this account")]
+    SecondFactorNotEnrolled,
+
     // ── Domain invariants & parse errors (AUT-9xxx) ───────────────────────────
     #[error("domain invariant violated on '{field}': {message}")]
     DomainViolation { field: String, message: String },
@@ -150,6 +169,8 @@ impl AppError for AuthError {
             AuthError::SessionRevoked => "AUT-1002",
             AuthError::SessionExpired => "AUT-1003",
             AuthError::InvalidSessionTransition { .. } => "AUT-1004",
+            AuthError::MfaChallengeNotFound => "AUT-1005",
+            AuthError::MfaChallengeExhausted => "AUT-1006",
 
             AuthError::RefreshTokenNotFound => "AUT-2001",
             AuthError::RefreshTokenExpired => "AUT-2002",
@@ -170,6 +191,8 @@ impl AppError for AuthError {
 
             AuthError::AccountNotActive { .. } => "AUT-6001",
             AuthError::AccountDirectoryUnavailable => "AUT-6002",
+            AuthError::SecondFactorRejected => "AUT-6003",
+            AuthError::SecondFactorNotEnrolled => "AUT-6004",
 
             AuthError::DomainViolation { .. } => "AUT-9001",
             AuthError::InvalidSessionId(_) => "AUT-9002",
@@ -198,7 +221,11 @@ impl AppError for AuthError {
             | AuthError::RefreshTokenAlreadyRotated
             | AuthError::InvalidTokenGeneration
             | AuthError::IdpAuthenticationFailed
-            | AuthError::IdpTokenRejected => StatusCode::UNAUTHORIZED,
+            | AuthError::IdpTokenRejected
+            | AuthError::MfaChallengeNotFound
+            | AuthError::SecondFactorRejected => StatusCode::UNAUTHORIZED,
+
+            AuthError::MfaChallengeExhausted => StatusCode::TOO_MANY_REQUESTS,
 
             AuthError::SubjectAlreadyLinked { .. } => StatusCode::CONFLICT,
 
@@ -235,6 +262,7 @@ impl AppError for AuthError {
             | AuthError::RefreshTokenAlreadyRotated
             | AuthError::ClaimsNormalizationFailed(_)
             | AuthError::AccountNotActive { .. }
+            | AuthError::MfaChallengeExhausted
             | AuthError::DomainViolation { .. } => Severity::Medium,
 
             _ => Severity::Low,
@@ -288,6 +316,10 @@ impl AppError for AuthError {
             AuthError::ClaimsNormalizationFailed(_) => "We could not complete sign-in. Please try again.",
             AuthError::AccountNotActive { .. } => "This account cannot sign in at this time.",
             AuthError::AccountDirectoryUnavailable => "The account service is temporarily unavailable.",
+            AuthError::MfaChallengeNotFound => "This sign-in attempt has expired; please sign in again.",
+            AuthError::MfaChallengeExhausted => "Too many incorrect codes. Please wait a few minutes and try again.",
+            AuthError::SecondFactorRejected => "The verification code is incorrect.",
+            AuthError::SecondFactorNotEnrolled => "Two-step verification is not set up for this account.",
             _ => "A domain constraint was violated.",
         }
     }
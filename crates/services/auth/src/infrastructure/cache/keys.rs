@@ -12,6 +12,18 @@ pub fn blacklist_key(session_id: &SessionId) -> String {
     format!("auth:{{sess:{session_id}}}:revoked")
 }
 
+/// A pending second-factor challenge (JSON), set with a TTL equal to its
+/// lifetime. Tagged on the opaque challenge id — the only thing the client holds.
+pub fn mfa_challenge_key(challenge_id: &str) -> String {
+    format!("auth:{{mfa:{challenge_id}}}")
+}
+
+/// Wrong second factors in the account's current window (`INCR`, TTL opened
+/// by the first failure).
+pub fn mfa_failures_key(account_id: &AccountId) -> String {
+    format!("auth:{{acct:{account_id}}}:mfa_failures")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -25,5 +37,8 @@ mod tests {
         assert!(blacklist_key(&sess).contains("{sess:"));
         assert!(generation_key(&acct).ends_with(":gen"));
         assert!(blacklist_key(&sess).ends_with(":revoked"));
+        assert_eq!(mfa_challenge_key("abc"), "auth:{mfa:abc}");
+        assert!(mfa_failures_key(&acct).starts_with("auth:{acct:"));
+        assert!(mfa_failures_key(&acct).ends_with(":mfa_failures"));
     }
 }
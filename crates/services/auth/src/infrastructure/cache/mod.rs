@@ -1,11 +1,16 @@
-//! Redis Cluster adapter for the hot-path [`SessionCache`](crate::application::port::SessionCache).
+//! Redis Cluster adapters for the hot-path [`SessionCache`](crate::application::port::SessionCache)
+//! and the short-lived [`MfaChallengeStore`](crate::application::port::MfaChallengeStore).
 //!
 //! Keys are hash-tagged so each is slot-pinned and every operation is a
 //! single-key, single-round-trip command (no `CROSSSLOT`):
 //! * `auth:{acct:<id>}:gen`     — the account's revocation generation (`INCR`/`GET`).
 //! * `auth:{sess:<id>}:revoked` — per-session blacklist marker with TTL.
+//! * `auth:{mfa:<id>}`          — pending second-factor challenge (JSON) with TTL.
+//! * `auth:{acct:<id>}:mfa_failures` — wrong second factors in the current window.
 
 pub mod keys;
+pub mod redis_mfa_challenge_store;
 pub mod redis_session_cache;
 
+pub use redis_mfa_challenge_store::RedisMfaChallengeStore;
 pub use redis_session_cache::RedisSessionCache;
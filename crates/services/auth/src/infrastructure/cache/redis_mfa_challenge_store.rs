@@ -0,0 +1,99 @@
+use async_trait::async_trait;
+use chrono::{Duration, Utc};
+use fred::interfaces::KeysInterface;
+use fred::types::Expiration;
+use redis_storage::{RedisClient, RedisStorageError};
+use tracing::instrument;
+
+use crate::application::port::{MfaChallenge, MfaChallengeStore};
+use crate::domain::value_object::AccountId;
+use crate::error::AuthError;
+
+use super::keys::{mfa_challenge_key, mfa_failures_key};
+
+/// Redis Cluster implementation of [`MfaChallengeStore`].
+#[derive(Clone)]
+pub struct RedisMfaChallengeStore {
+    client: RedisClient,
+}
+
+impl RedisMfaChallengeStore {
+    pub fn new(client: RedisClient) -> Self {
+        Self { client }
+    }
+}
+
+fn cache_err(e: fred::error::Error) -> AuthError {
+    AuthError::Cache(RedisStorageError::from(e))
+}
+
+fn decode(raw: Option<String>) -> Result<Option<MfaChallenge>, AuthError> {
+    raw.map(|json| {
+        serde_json::from_str(&json)
+            .map_err(|e| AuthError::Cache(RedisStorageError::Parse { message: e.to_string() }))
+    })
+    .transpose()
+}
+
+#[async_trait]
+impl MfaChallengeStore for RedisMfaChallengeStore {
+    #[instrument(name = "auth.cache.mfa_put", skip_all, fields(account.id = %challenge.account_id))]
+    async fn put(&self, challenge: &MfaChallenge) -> Result<(), AuthError> {
+        let json = serde_json::to_string(challenge)
+            .map_err(|e| AuthError::Cache(RedisStorageError::Parse { message: e.to_string() }))?;
+        let secs = (challenge.expires_at - Utc::now()).num_seconds().max(1);
+        let _: () = self
+            .client
+            .set(mfa_challenge_key(&challenge.id), json, Some(Expiration::EX(secs)), None, false)
+            .await
+            .map_err(cache_err)?;
+        Ok(())
+    }
+
+    #[instrument(name = "auth.cache.mfa_find", skip_all)]
+    async fn find(&self, id: &str) -> Result<Option<MfaChallenge>, AuthError> {
+        let raw: Option<String> = self.client.get(mfa_challenge_key(id)).await.map_err(cache_err)?;
+        decode(raw)
+    }
+
+    #[instrument(name = "auth.cache.mfa_take", skip_all)]
+    async fn take(&self, id: &str) -> Result<Option<MfaChallenge>, AuthError> {
+        // GETDEL: of two concurrent completions exactly one sees the value.
+        let raw: Option<String> =
+            self.client.getdel(mfa_challenge_key(id)).await.map_err(cache_err)?;
+        decode(raw)
+    }
+
+    #[instrument(name = "auth.cache.mfa_record_failure", skip(self), fields(account.id = %account_id))]
+    async fn record_failure(
+        &self,
+        account_id: &AccountId,
+        window: Duration,
+    ) -> Result<u32, AuthError> {
+        let key = mfa_failures_key(account_id);
+        let count: i64 = self.client.incr(&key).await.map_err(cache_err)?;
+        // Only the failure that opens the window sets its expiry; later ones
+        // must not extend it.
+        if count == 1 {
+            let _: () = self
+                .client
+                .expire(&key, window.num_seconds().max(1), None)
+                .await
+                .map_err(cache_err)?;
+        }
+        Ok(u32::try_from(count).unwrap_or(u32::MAX))
+    }
+
+    #[instrument(name = "auth.cache.mfa_failures", skip(self), fields(account.id = %account_id))]
+    async fn failures(&self, account_id: &AccountId) -> Result<u32, AuthError> {
+        let value: Option<i64> =
+            self.client.get(mfa_failures_key(account_id)).await.map_err(cache_err)?;
+        Ok(value.map_or(0, |n| u32::try_from(n).unwrap_or(u32::MAX)))
+    }
+
+    #[instrument(name = "auth.cache.mfa_clear_failures", skip(self), fields(account.id = %account_id))]
+    async fn clear_failures(&self, account_id: &AccountId) -> Result<(), AuthError> {
+        let _: i64 = self.client.del(mfa_failures_key(account_id)).await.map_err(cache_err)?;
+        Ok(())
+    }
+}
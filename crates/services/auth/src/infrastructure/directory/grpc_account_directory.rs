@@ -1,11 +1,16 @@
 use account_api::account_service_client::AccountServiceClient;
-use account_api::{AccountStatus, GetAccountByIdRequest, GetAccountByIdentityIdRequest};
+use account_api::{
+    verify_mfa_factor_request, AccountStatus, GetAccountByIdRequest, GetAccountByIdentityIdRequest,
+    VerifyMfaFactorRequest,
+};
 use async_trait::async_trait;
 use tonic::transport::Channel;
 use tonic::Code;
 use tracing::instrument;
 
-use crate::application::port::{AccountActivation, AccountDirectory, AccountSnapshot};
+use crate::application::port::{
+    AccountActivation, AccountDirectory, AccountSnapshot, SecondFactor, SecondFactorCheck,
+};
 use crate::domain::value_object::{AccountId, IdpSubject, Permission};
 use crate::error::AuthError;
 
@@ -80,7 +85,39 @@ impl AccountDirectory for GrpcAccountDirectory {
         grants.dedup();
         let permissions = grants.into_iter().map(Permission::new).collect();
 
-        Ok(AccountSnapshot { activation, permissions })
+        Ok(AccountSnapshot { activation, permissions, mfa_enforced: view.mfa_enforced })
+    }
+
+    #[instrument(name = "auth.directory.verify_second_factor", skip(self, factor), fields(account.id = %account_id))]
+    async fn verify_second_factor(
+        &self,
+        account_id: &AccountId,
+        factor: &SecondFactor,
+    ) -> Result<SecondFactorCheck, AuthError> {
+        let factor = match factor {
+            SecondFactor::Totp(code) => verify_mfa_factor_request::Factor::TotpCode(code.clone()),
+            SecondFactor::RecoveryCode(code) => {
+                verify_mfa_factor_request::Factor::RecoveryCode(code.clone())
+            }
+        };
+        let mut client = self.client.clone();
+        let response = client
+            .verify_mfa_factor(VerifyMfaFactorRequest {
+                account_id: account_id.as_str(),
+                factor: Some(factor),
+            })
+            .await
+            .map_err(|status| match status.code() {
+                Code::FailedPrecondition => AuthError::SecondFactorNotEnrolled,
+                Code::NotFound => AuthError::AccountNotActive { current: "not_found".into() },
+                _ => AuthError::AccountDirectoryUnavailable,
+            })?
+            .into_inner();
+
+        Ok(SecondFactorCheck {
+            verified: response.verified,
+            recovery_codes_remaining: u32::try_from(response.recovery_codes_remaining).unwrap_or(0),
+        })
     }
 }
 
@@ -1,16 +1,18 @@
 //! Event-publication adapters for the `auth.v1.events` topic.
 //!
-//! All three domain events share one topic, keyed by `account_id` so a person's
+//! Every domain event shares one topic, keyed by `account_id` so a person's
 //! auth events keep per-account order on one partition; the `event_type` header
-//! carries the dotted routing key.
+//! carries the dotted routing key. Handlers enqueue into `auth_outbox`
+//! ([`PgOutboxPublisher`]); the shared `outbox` relay publishes to Kafka.
 
-pub mod kafka_event_publisher;
-pub mod outbox_relay;
-pub mod pg_outbox_publisher;
 pub mod log_event_publisher;
+pub mod pg_outbox_publisher;
 
-pub use kafka_event_publisher::KafkaEventPublisher;
 pub use log_event_publisher::LogEventPublisher;
+pub use pg_outbox_publisher::PgOutboxPublisher;
+
+/// Prefix of the `auth_outbox` table (and its `_lease` / `_member` companions).
+pub const OUTBOX_PREFIX: &str = "auth";
 
 /// The single Kafka topic every auth domain event is published to.
 pub const TOPIC_AUTH_EVENTS: &str = "auth.v1.events";
@@ -24,5 +26,19 @@ pub(crate) fn event_key(event: &DomainEvent) -> AccountId {
         DomainEvent::SessionIssued(e) => e.account_id,
         DomainEvent::SessionRevoked(e) => e.account_id,
         DomainEvent::SubjectLinked(e) => e.account_id,
+        DomainEvent::NewDeviceLogin(e) => e.account_id,
+        DomainEvent::RefreshTokenReuseDetected(e) => e.account_id,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// `auth.v1.events` carries exactly the payload registered for it in event-topology.
+    #[test]
+    fn auth_events_conform_to_the_registered_schema() {
+        let violations = event_topology::conformance::producer_violations::<DomainEvent>(TOPIC_AUTH_EVENTS);
+        assert!(violations.is_empty(), "{violations:#?}");
     }
 }
@@ -6,45 +6,46 @@
 //! writes already depend on — broker unavailability can no longer hang or
 //! fail the RPC (found live: a misconfigured broker held Login at deadline),
 //! and a committed session can no longer lose its compliance event (audit
-//! consumes `auth.v1.events`). The [`super::outbox_relay::OutboxRelay`]
-//! drains this table to the real broker in the background.
+//! consumes `auth.v1.events`). The shared [`outbox::OutboxRelay`] drains
+//! `auth_outbox` to the real broker in the background.
+//!
+//! Every envelope decision (topic, `account_id` key, headers) is made here at
+//! enqueue time and stored on the row, so the relay's sink is generic.
 
 use async_trait::async_trait;
-use sqlx::PgPool;
-use uuid::Uuid;
+use outbox::{OutboxError, OutboxMessage, PgOutbox};
 
 use crate::application::port::EventPublisher;
 use crate::domain::event::DomainEvent;
 use crate::error::AuthError;
 
+use super::{event_key, TOPIC_AUTH_EVENTS};
+
 pub struct PgOutboxPublisher {
-    pool: PgPool,
+    outbox: PgOutbox,
 }
 
 impl PgOutboxPublisher {
-    pub fn new(pool: PgPool) -> Self {
-        Self { pool }
+    pub fn new(outbox: PgOutbox) -> Self {
+        Self { outbox }
     }
 }
 
+fn enqueue_err(e: OutboxError) -> AuthError {
+    AuthError::EventPublishFailed(format!("outbox enqueue: {e}"))
+}
+
 #[async_trait]
 impl EventPublisher for PgOutboxPublisher {
     async fn publish(&self, event: &DomainEvent) -> Result<(), AuthError> {
-        let payload = serde_json::to_value(event)
-            .map_err(|e| AuthError::EventPublishFailed(format!("outbox serialize: {e}")))?;
-
-        // UUIDv7 id: time-ordered, so (created_at, id) is a stable drain order
-        // and per-account Kafka partition ordering survives the indirection.
-        sqlx::query(
-            "INSERT INTO auth_outbox (id, event_type, payload) VALUES ($1, $2, $3)",
-        )
-        .bind(Uuid::now_v7())
-        .bind(event.event_type())
-        .bind(payload)
-        .execute(&self.pool)
-        .await
-        .map_err(|e| AuthError::EventPublishFailed(format!("outbox enqueue: {e}")))?;
-
-        Ok(())
+        let key = event_key(event).as_str();
+        let message = OutboxMessage::new(TOPIC_AUTH_EVENTS, key.clone(), event.event_type(), event)
+            .map_err(enqueue_err)?
+            .with_header("account_id", key);
+
+        // Handlers persist through several repositories before publishing, so the
+        // enqueue is its own transaction on the same database (same fault domain)
+        // rather than part of theirs.
+        self.outbox.enqueue_detached(&[message]).await.map_err(enqueue_err)
     }
 }
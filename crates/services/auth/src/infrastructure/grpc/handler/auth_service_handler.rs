@@ -7,14 +7,17 @@ use tonic::{Request, Response, Status};
 use uuid::Uuid;
 
 use crate::application::command::{
-    IssuedSession, LoginCommand, LoginHandler, LogoutAllSessionsCommand, LogoutAllSessionsHandler,
-    LogoutCommand, LogoutHandler, RefreshCommand, RefreshHandler,
+    IssuedSession, LoginCommand, LoginHandler, LoginOutcome, LogoutAllSessionsCommand,
+    LogoutAllSessionsHandler, LogoutCommand, LogoutHandler, RefreshCommand, RefreshHandler,
+    RevokeSessionCommand, RevokeSessionHandler, StepUpCommand, StepUpHandler,
+    VerifySecondFactorCommand, VerifySecondFactorHandler,
 };
-use crate::application::port::AuthnGrant;
+use crate::application::port::{AuthnGrant, SecondFactor};
 use crate::application::query::{
-    IntrospectHandler, IntrospectQuery, ListSessionsHandler, ListSessionsQuery, SessionSummary,
+    DeviceSessions, IntrospectHandler, IntrospectQuery, ListSessionsHandler, ListSessionsQuery,
+    SessionSummary, group_by_device,
 };
-use crate::domain::value_object::{DeviceFingerprint, SessionStatus};
+use crate::domain::value_object::{DeviceFingerprint, GeoLocation, SessionStatus};
 use crate::error::AuthError;
 
 // ── Proto inclusion ───────────────────────────────────────────────────────────
@@ -30,23 +33,40 @@ pub use auth_api as proto;
 #[derive(Clone)]
 pub struct AuthServiceHandler {
     login: Arc<LoginHandler>,
+    verify_second_factor: Arc<VerifySecondFactorHandler>,
     refresh: Arc<RefreshHandler>,
+    step_up: Arc<StepUpHandler>,
     logout: Arc<LogoutHandler>,
     logout_all: Arc<LogoutAllSessionsHandler>,
     introspect: Arc<IntrospectHandler>,
     list_sessions: Arc<ListSessionsHandler>,
+    revoke_session: Arc<RevokeSessionHandler>,
 }
 
 impl AuthServiceHandler {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         login: Arc<LoginHandler>,
+        verify_second_factor: Arc<VerifySecondFactorHandler>,
         refresh: Arc<RefreshHandler>,
+        step_up: Arc<StepUpHandler>,
         logout: Arc<LogoutHandler>,
         logout_all: Arc<LogoutAllSessionsHandler>,
         introspect: Arc<IntrospectHandler>,
         list_sessions: Arc<ListSessionsHandler>,
+        revoke_session: Arc<RevokeSessionHandler>,
     ) -> Self {
-        Self { login, refresh, logout, logout_all, introspect, list_sessions }
+        Self {
+            login,
+            verify_second_factor,
+            refresh,
+            step_up,
+            logout,
+            logout_all,
+            introspect,
+            list_sessions,
+            revoke_session,
+        }
     }
 
     pub async fn login(
@@ -57,16 +77,52 @@ impl AuthServiceHandler {
         let grant = grant_from_proto(req.credential)?;
         let cmd = LoginCommand { grant, device: device_from_proto(req.device) };
 
-        let issued = self
+        let outcome = self
             .login
             .handle(Envelope::new(Uuid::now_v7(), cmd), Utc::now())
             .await
             .map_err(auth_error_to_status)?;
 
-        Ok(Response::new(proto::LoginResponse {
-            account_id: issued.account_id.as_str(),
-            tokens: Some(token_pair(&issued)),
-            first_link: issued.first_link,
+        Ok(Response::new(match outcome {
+            LoginOutcome::Issued(issued) => proto::LoginResponse {
+                account_id: issued.account_id.as_str(),
+                tokens: Some(token_pair(&issued)),
+                first_link: issued.first_link,
+                second_factor: None,
+            },
+            LoginOutcome::SecondFactorRequired(pending) => proto::LoginResponse {
+                account_id: pending.account_id.as_str(),
+                tokens: None,
+                first_link: false,
+                second_factor: Some(proto::SecondFactorChallenge {
+                    challenge_id: pending.challenge_id,
+                    expires_in: pending.expires_in,
+                }),
+            },
+        }))
+    }
+
+    pub async fn verify_second_factor(
+        &self,
+        request: Request<proto::VerifySecondFactorRequest>,
+    ) -> Result<Response<proto::VerifySecondFactorResponse>, Status> {
+        let req = request.into_inner();
+        let cmd = VerifySecondFactorCommand {
+            challenge_id: req.challenge_id,
+            factor: factor_from_proto(req.factor)?,
+        };
+
+        let verified = self
+            .verify_second_factor
+            .handle(Envelope::new(Uuid::now_v7(), cmd), Utc::now())
+            .await
+            .map_err(auth_error_to_status)?;
+
+        Ok(Response::new(proto::VerifySecondFactorResponse {
+            account_id: verified.issued.account_id.as_str(),
+            tokens: Some(token_pair(&verified.issued)),
+            first_link: verified.issued.first_link,
+            recovery_codes_remaining: count(verified.recovery_codes_remaining),
         }))
     }
 
@@ -89,6 +145,31 @@ impl AuthServiceHandler {
         Ok(Response::new(proto::RefreshResponse { tokens: Some(token_pair(&issued)) }))
     }
 
+    pub async fn step_up(
+        &self,
+        request: Request<proto::StepUpRequest>,
+    ) -> Result<Response<proto::StepUpResponse>, Status> {
+        // The session being stepped up is the caller's own: the edge token the
+        // runtime already authenticated names it.
+        let access_token = bearer(request.metadata())
+            .ok_or_else(|| Status::unauthenticated("missing bearer token"))?
+            .to_owned();
+        let factor = factor_from_proto(request.into_inner().factor)?;
+        let cmd = StepUpCommand { access_token, factor };
+
+        let stepped = self
+            .step_up
+            .handle(Envelope::new(Uuid::now_v7(), cmd), Utc::now())
+            .await
+            .map_err(auth_error_to_status)?;
+
+        Ok(Response::new(proto::StepUpResponse {
+            access_token: stepped.access_token,
+            expires_in: stepped.access_expires_in,
+            recovery_codes_remaining: count(stepped.recovery_codes_remaining),
+        }))
+    }
+
     pub async fn logout(
         &self,
         request: Request<proto::LogoutRequest>,
@@ -137,6 +218,9 @@ impl AuthServiceHandler {
             generation: view.generation,
             permissions: view.permissions,
             expires_at: view.expires_at.map(to_timestamp),
+            acr: view.acr.unwrap_or_default(),
+            amr: view.amr,
+            auth_time: view.auth_time.map(to_timestamp),
         }))
     }
 
@@ -155,10 +239,30 @@ impl AuthServiceHandler {
             .await
             .map_err(auth_error_to_status)?;
 
+        let devices = group_by_device(&sessions).into_iter().map(device_sessions).collect();
         Ok(Response::new(proto::ListSessionsResponse {
             sessions: sessions.into_iter().map(session_view).collect(),
+            devices,
         }))
     }
+
+    pub async fn revoke_session(
+        &self,
+        request: Request<proto::RevokeSessionRequest>,
+    ) -> Result<Response<proto::RevokeSessionResponse>, Status> {
+        // Ownership is checked against the caller's account, named by the edge
+        // token the runtime already authenticated.
+        let access_token = bearer(request.metadata())
+            .ok_or_else(|| Status::unauthenticated("missing bearer token"))?
+            .to_owned();
+        let cmd = RevokeSessionCommand { access_token, session_id: request.into_inner().session_id };
+        let out = self
+            .revoke_session
+            .handle(Envelope::new(Uuid::now_v7(), cmd), Utc::now())
+            .await
+            .map_err(auth_error_to_status)?;
+        Ok(Response::new(proto::RevokeSessionResponse { success: out.success }))
+    }
 }
 
 // ── Mapping helpers ───────────────────────────────────────────────────────────
@@ -181,6 +285,28 @@ fn grant_from_proto(
     }
 }
 
+fn factor_from_proto(factor: Option<proto::SecondFactor>) -> Result<SecondFactor, Status> {
+    match factor.and_then(|f| f.factor) {
+        Some(proto::second_factor::Factor::TotpCode(code)) => Ok(SecondFactor::Totp(code)),
+        Some(proto::second_factor::Factor::RecoveryCode(code)) => {
+            Ok(SecondFactor::RecoveryCode(code))
+        }
+        None => Err(Status::invalid_argument("a second factor is required")),
+    }
+}
+
+/// The token from an `authorization: Bearer <token>` metadata entry.
+fn bearer(metadata: &tonic::metadata::MetadataMap) -> Option<&str> {
+    let value = metadata.get("authorization")?.to_str().ok()?;
+    let (scheme, token) = value.split_once(' ')?;
+    let token = token.trim();
+    (scheme.eq_ignore_ascii_case("bearer") && !token.is_empty()).then_some(token)
+}
+
+fn count(n: u32) -> i32 {
+    i32::try_from(n).unwrap_or(i32::MAX)
+}
+
 fn device_from_proto(device: Option<proto::DeviceContext>) -> DeviceFingerprint {
     let non_empty = |s: String| if s.is_empty() { None } else { Some(s) };
     match device {
@@ -188,11 +314,23 @@ fn device_from_proto(device: Option<proto::DeviceContext>) -> DeviceFingerprint
             non_empty(d.user_agent),
             non_empty(d.ip_address),
             non_empty(d.device_id),
-        ),
+        )
+        .with_location(GeoLocation::new(&d.country, non_empty(d.city))),
         None => DeviceFingerprint::default(),
     }
 }
 
+fn device_to_proto(device: &DeviceFingerprint) -> proto::DeviceContext {
+    let owned = |s: Option<&str>| s.map(str::to_owned).unwrap_or_default();
+    proto::DeviceContext {
+        user_agent: owned(device.user_agent()),
+        ip_address: owned(device.ip_address()),
+        device_id: owned(device.device_id()),
+        country: owned(device.location().map(GeoLocation::country)),
+        city: owned(device.location().and_then(GeoLocation::city)),
+    }
+}
+
 fn token_pair(issued: &IssuedSession) -> proto::TokenPair {
     proto::TokenPair {
         access_token: issued.access_token.clone(),
@@ -208,11 +346,23 @@ fn session_view(summary: SessionSummary) -> proto::SessionView {
         session_id: summary.session_id,
         status: session_status_to_proto(summary.status),
         generation: summary.generation,
-        device: None,
+        device: Some(device_to_proto(&summary.device)),
         issued_at: Some(to_timestamp(summary.issued_at)),
         expires_at: Some(to_timestamp(summary.expires_at)),
         absolute_expiry: Some(to_timestamp(summary.absolute_expiry)),
         current: summary.current,
+        last_seen: Some(device_to_proto(&summary.last_seen)),
+        last_seen_at: Some(to_timestamp(summary.last_seen_at)),
+    }
+}
+
+fn device_sessions(group: DeviceSessions) -> proto::DeviceSessions {
+    proto::DeviceSessions {
+        device_id: group.device_id.unwrap_or_default(),
+        last_seen: Some(device_to_proto(&group.last_seen)),
+        last_seen_at: Some(to_timestamp(group.last_seen_at)),
+        current: group.current,
+        session_ids: group.session_ids,
     }
 }
 
@@ -241,6 +391,7 @@ pub fn auth_error_to_status(err: AuthError) -> Status {
         409 if retryable => Status::aborted(msg),
         409 => Status::already_exists(msg),
         400 | 422 => Status::failed_precondition(msg),
+        429 => Status::resource_exhausted(msg),
         502 | 503 => Status::unavailable(msg),
         _ => Status::internal(msg),
     }
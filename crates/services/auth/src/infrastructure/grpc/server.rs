@@ -18,6 +18,13 @@ impl AuthService for AuthServiceHandler {
         self.login(request).await
     }
 
+    async fn verify_second_factor(
+        &self,
+        request: Request<proto::VerifySecondFactorRequest>,
+    ) -> Result<Response<proto::VerifySecondFactorResponse>, Status> {
+        self.verify_second_factor(request).await
+    }
+
     async fn refresh(
         &self,
         request: Request<proto::RefreshRequest>,
@@ -25,6 +32,13 @@ impl AuthService for AuthServiceHandler {
         self.refresh(request).await
     }
 
+    async fn step_up(
+        &self,
+        request: Request<proto::StepUpRequest>,
+    ) -> Result<Response<proto::StepUpResponse>, Status> {
+        self.step_up(request).await
+    }
+
     async fn logout(
         &self,
         request: Request<proto::LogoutRequest>,
@@ -52,4 +66,11 @@ impl AuthService for AuthServiceHandler {
     ) -> Result<Response<proto::ListSessionsResponse>, Status> {
         self.list_sessions(request).await
     }
+
+    async fn revoke_session(
+        &self,
+        request: Request<proto::RevokeSessionRequest>,
+    ) -> Result<Response<proto::RevokeSessionResponse>, Status> {
+        self.revoke_session(request).await
+    }
 }
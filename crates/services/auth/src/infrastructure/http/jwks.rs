@@ -1,9 +1,9 @@
 //! The well-known JWKS endpoint.
 //!
-//! Serves the process's fixed key ring (serialized once at `App::build`) at
-//! `GET /.well-known/jwks.json`. The ring never changes within a process —
-//! key rotation is a redeploy — so the body is a static string and the
-//! handler is allocation-free per request. Listener address comes from
+//! Serves the minter's current key ring at `GET /.well-known/jwks.json`. The
+//! ring changes under scheduled rotation, so each request reads the live
+//! document through [`JwksSource`] — a pointer load, since the minter
+//! serializes the JWKS once per installed ring. Listener address comes from
 //! `AUTH_JWKS_HTTP_ADDR` (default `0.0.0.0:8081`); the port is cluster-internal
 //! (NetworkPolicy scoped to the verifying services + kubelet probes).
 
@@ -16,25 +16,50 @@ use axum::response::IntoResponse;
 use axum::routing::get;
 use axum::Router;
 
+use crate::infrastructure::token::Es256TokenMinter;
+
 /// The well-known path, shared with every downstream verifier's config.
 pub const JWKS_PATH: &str = "/.well-known/jwks.json";
 
-/// Builds the single-route JWKS router over the pre-serialized document.
-pub fn router(jwks_json: String) -> Router {
-    let body: Arc<str> = jwks_json.into();
-    Router::new().route(JWKS_PATH, get(serve_jwks)).with_state(body)
+/// Freshness lifetime advertised to verifiers. `auth-context` polls no slower
+/// than this, so a newly published key reaches every verifier within a minute
+/// — well inside the rotator's publish lead.
+pub const JWKS_MAX_AGE_SECS: u64 = 60;
+
+/// Where the served document comes from.
+pub trait JwksSource: Send + Sync + 'static {
+    /// The current serialized JWKS.
+    fn document(&self) -> Arc<str>;
+}
+
+impl JwksSource for Es256TokenMinter {
+    fn document(&self) -> Arc<str> {
+        self.jwks_document()
+    }
+}
+
+/// A fixed document (tests, static deployments).
+impl JwksSource for Arc<str> {
+    fn document(&self) -> Arc<str> {
+        Arc::clone(self)
+    }
+}
+
+/// Builds the single-route JWKS router over `source`.
+pub fn router(source: Arc<dyn JwksSource>) -> Router {
+    Router::new().route(JWKS_PATH, get(serve_jwks)).with_state(source)
 }
 
-async fn serve_jwks(State(body): State<Arc<str>>) -> impl IntoResponse {
-    // Verifiers poll on their own refresh interval (auth-context defaults);
-    // a short shared-cache TTL keeps any future proxy layer from pinning a
-    // stale ring across a redeploy longer than a probe cycle.
+async fn serve_jwks(State(source): State<Arc<dyn JwksSource>>) -> impl IntoResponse {
+    // Verifiers honour `max-age` as an upper bound on their own refresh
+    // interval (auth-context's `JwksRefresher`), which is what lets a rotated
+    // key propagate without anyone's restart.
     (
         [
-            (header::CONTENT_TYPE, "application/json"),
-            (header::CACHE_CONTROL, "public, max-age=60"),
+            (header::CONTENT_TYPE, "application/json".to_owned()),
+            (header::CACHE_CONTROL, format!("public, max-age={JWKS_MAX_AGE_SECS}")),
         ],
-        body.to_string(),
+        source.document().to_string(),
     )
 }
 
@@ -42,10 +67,10 @@ async fn serve_jwks(State(body): State<Arc<str>>) -> impl IntoResponse {
 /// background task by the runtime adapter; a bind failure is returned to the
 /// caller (fail-fast at boot — a fleet where verifiers cannot fetch keys is
 /// fail-closed everywhere downstream, better to crash-loop visibly here).
-pub async fn serve(addr: SocketAddr, jwks_json: String) -> anyhow::Result<()> {
+pub async fn serve(addr: SocketAddr, source: Arc<dyn JwksSource>) -> anyhow::Result<()> {
     let listener = tokio::net::TcpListener::bind(addr).await?;
     tracing::info!(%addr, path = JWKS_PATH, "auth.jwks http listener up");
-    axum::serve(listener, router(jwks_json)).await?;
+    axum::serve(listener, router(source)).await?;
     Ok(())
 }
 
@@ -59,7 +84,8 @@ mod tests {
     #[tokio::test]
     async fn serves_the_document_at_the_well_known_path() {
         let doc = r#"{"keys":[{"kty":"EC","kid":"k1"}]}"#;
-        let response = router(doc.to_owned())
+        let source: Arc<str> = doc.into();
+        let response = router(Arc::new(source))
             .oneshot(Request::get(JWKS_PATH).body(Body::empty()).unwrap())
             .await
             .unwrap();
@@ -69,16 +95,40 @@ mod tests {
             response.headers()[header::CONTENT_TYPE],
             "application/json"
         );
+        assert_eq!(response.headers()[header::CACHE_CONTROL], "public, max-age=60");
         let bytes = axum::body::to_bytes(response.into_body(), 1024).await.unwrap();
         assert_eq!(&bytes[..], doc.as_bytes());
     }
 
     #[tokio::test]
     async fn unknown_path_is_404() {
-        let response = router("{}".to_owned())
+        let source: Arc<str> = "{}".into();
+        let response = router(Arc::new(source))
             .oneshot(Request::get("/jwks").body(Body::empty()).unwrap())
             .await
             .unwrap();
         assert_eq!(response.status(), StatusCode::NOT_FOUND);
     }
+
+    /// A source whose document changes underneath the router, as the minter's
+    /// does on rotation.
+    struct Swappable(std::sync::Mutex<Arc<str>>);
+
+    impl JwksSource for Swappable {
+        fn document(&self) -> Arc<str> {
+            self.0.lock().unwrap().clone()
+        }
+    }
+
+    #[tokio::test]
+    async fn serves_the_current_document_after_a_swap() {
+        let source = Arc::new(Swappable(std::sync::Mutex::new(r#"{"keys":[]}"#.into())));
+        let app = router(source.clone());
+        *source.0.lock().unwrap() = r#"{"keys":[{"kid":"k2"}]}"#.into();
+
+        let response =
+            app.oneshot(Request::get(JWKS_PATH).body(Body::empty()).unwrap()).await.unwrap();
+        let bytes = axum::body::to_bytes(response.into_body(), 1024).await.unwrap();
+        assert_eq!(&bytes[..], br#"{"keys":[{"kid":"k2"}]}"#);
+    }
 }
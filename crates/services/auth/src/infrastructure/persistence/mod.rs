@@ -5,6 +5,8 @@
 //! co-locates on one shard. Lookups by `session_id` / `token_hash` / subject use
 //! the single pool — correct for SingleNode and CockroachDB; true app-sharding of
 //! those would need a secondary index (deferred, as in `account`).
+//! `signing_keys` is service-global (no account to key on) and lives on the
+//! single pool.
 //!
 //! ## Optimistic locking
 //! The in-memory aggregate `version` mirrors the persisted value. A new aggregate
@@ -13,10 +15,14 @@
 //! mapping a zero-row update to [`AuthError::ConcurrentModification`].
 
 pub mod model;
+pub mod pg_known_device_repository;
 pub mod pg_refresh_token_repository;
 pub mod pg_session_repository;
+pub mod pg_signing_key_store;
 pub mod pg_subject_link_repository;
 
+pub use pg_known_device_repository::PgKnownDeviceRepository;
 pub use pg_refresh_token_repository::PgRefreshTokenRepository;
 pub use pg_session_repository::PgSessionRepository;
+pub use pg_signing_key_store::PgSigningKeyStore;
 pub use pg_subject_link_repository::PgSubjectLinkRepository;
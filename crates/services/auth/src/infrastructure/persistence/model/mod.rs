@@ -1,7 +1,9 @@
 pub mod refresh_token_row;
 pub mod session_row;
+pub mod signing_key_row;
 pub mod subject_link_row;
 
 pub use refresh_token_row::RefreshTokenRow;
 pub use session_row::SessionRow;
+pub use signing_key_row::SigningKeyRow;
 pub use subject_link_row::SubjectLinkRow;
@@ -3,7 +3,8 @@ use uuid::Uuid;
 
 use crate::domain::aggregate::Session;
 use crate::domain::value_object::{
-    AccountId, DeviceFingerprint, Generation, IdpSubject, SessionId, SessionStatus,
+    AccountId, AuthMethod, DeviceFingerprint, Generation, GeoLocation, IdpSubject, SessionId,
+    SessionStatus,
 };
 use crate::error::AuthError;
 
@@ -21,6 +22,15 @@ pub struct SessionRow {
     pub device_user_agent: Option<String>,
     pub device_ip: Option<String>,
     pub device_id: Option<String>,
+    pub device_country: Option<String>,
+    pub device_city: Option<String>,
+    pub last_seen_at: DateTime<Utc>,
+    pub last_seen_user_agent: Option<String>,
+    pub last_seen_ip: Option<String>,
+    pub last_seen_country: Option<String>,
+    pub last_seen_city: Option<String>,
+    pub amr: Vec<String>,
+    pub auth_time: DateTime<Utc>,
     pub issued_at: DateTime<Utc>,
     pub expires_at: DateTime<Utc>,
     pub absolute_expiry: DateTime<Utc>,
@@ -33,8 +43,19 @@ impl TryFrom<SessionRow> for Session {
 
     fn try_from(row: SessionRow) -> Result<Self, Self::Error> {
         let subject = IdpSubject::new(row.issuer, row.subject)?;
-        let device = DeviceFingerprint::new(row.device_user_agent, row.device_ip, row.device_id);
+        let device =
+            DeviceFingerprint::new(row.device_user_agent, row.device_ip, row.device_id.clone())
+                .with_location(location(row.device_country, row.device_city));
+        // The device id is bound at login; only the other components move.
+        let last_seen =
+            DeviceFingerprint::new(row.last_seen_user_agent, row.last_seen_ip, row.device_id)
+                .with_location(location(row.last_seen_country, row.last_seen_city));
         let status = SessionStatus::try_from(row.status.as_str())?;
+        let methods = row
+            .amr
+            .iter()
+            .map(|m| AuthMethod::try_from(m.as_str()))
+            .collect::<Result<Vec<_>, _>>()?;
 
         Ok(Session::reconstitute(
             SessionId::from_uuid(row.id),
@@ -43,6 +64,10 @@ impl TryFrom<SessionRow> for Session {
             Generation::from_i64(row.generation),
             status,
             device,
+            last_seen,
+            row.last_seen_at,
+            methods,
+            row.auth_time,
             row.issued_at,
             row.expires_at,
             row.absolute_expiry,
@@ -51,3 +76,7 @@ impl TryFrom<SessionRow> for Session {
         ))
     }
 }
+
+fn location(country: Option<String>, city: Option<String>) -> Option<GeoLocation> {
+    country.and_then(|c| GeoLocation::new(&c, city))
+}
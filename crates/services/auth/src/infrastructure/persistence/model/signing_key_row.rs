@@ -0,0 +1,32 @@
+use chrono::{DateTime, Utc};
+
+use crate::error::AuthError;
+use crate::infrastructure::token::{SigningKeyStatus, StoredSigningKey};
+
+/// Flat projection of the `signing_keys` table.
+#[derive(Debug, sqlx::FromRow)]
+pub struct SigningKeyRow {
+    pub kid: String,
+    pub public_pem: String,
+    pub private_pem_enc: Option<Vec<u8>>,
+    pub status: String,
+    pub created_at: DateTime<Utc>,
+    pub activated_at: Option<DateTime<Utc>>,
+    pub not_after: Option<DateTime<Utc>>,
+}
+
+impl TryFrom<SigningKeyRow> for StoredSigningKey {
+    type Error = AuthError;
+
+    fn try_from(row: SigningKeyRow) -> Result<Self, Self::Error> {
+        Ok(StoredSigningKey {
+            status: SigningKeyStatus::try_from(row.status.as_str())?,
+            kid: row.kid,
+            public_pem: row.public_pem,
+            private_pem_enc: row.private_pem_enc,
+            created_at: row.created_at,
+            activated_at: row.activated_at,
+            not_after: row.not_after,
+        })
+    }
+}
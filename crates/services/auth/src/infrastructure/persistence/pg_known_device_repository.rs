@@ -0,0 +1,77 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use postgres_storage::{StorageError, TransactionManager};
+use tracing::instrument;
+
+use crate::application::port::KnownDeviceRepository;
+use crate::domain::value_object::AccountId;
+use crate::error::AuthError;
+
+/// PostgreSQL adapter for [`KnownDeviceRepository`]. Writes route on
+/// `account_id`; the `(account_id, device_key)` primary key decides which of
+/// two racing first sightings is the first.
+#[derive(Clone)]
+pub struct PgKnownDeviceRepository {
+    tx: TransactionManager,
+}
+
+impl PgKnownDeviceRepository {
+    pub fn new(tx: TransactionManager) -> Self {
+        Self { tx }
+    }
+}
+
+fn storage(e: sqlx::Error) -> AuthError {
+    AuthError::Storage(StorageError::from(e))
+}
+
+#[async_trait]
+impl KnownDeviceRepository for PgKnownDeviceRepository {
+    #[instrument(name = "auth.known_device.remember", skip(self, device_key), fields(account.id = %account_id))]
+    async fn remember(
+        &self,
+        account_id: &AccountId,
+        device_key: &str,
+        seen_at: DateTime<Utc>,
+    ) -> Result<bool, AuthError> {
+        let account_uuid = account_id.as_uuid();
+        let device_key = device_key.to_owned();
+
+        self.tx
+            .run_on_shard(account_id, move |tx| {
+                Box::pin(async move {
+                    let inserted = sqlx::query(
+                        r#"
+                        INSERT INTO known_devices (account_id, device_key, first_seen_at, last_seen_at)
+                        VALUES ($1, $2, $3, $3)
+                        ON CONFLICT (account_id, device_key) DO NOTHING
+                        "#,
+                    )
+                    .bind(account_uuid)
+                    .bind(&device_key)
+                    .bind(seen_at)
+                    .execute(&mut **tx)
+                    .await
+                    .map_err(storage)?
+                    .rows_affected();
+
+                    if inserted == 0 {
+                        sqlx::query(
+                            r#"
+                            UPDATE known_devices SET last_seen_at = $3
+                            WHERE account_id = $1 AND device_key = $2 AND last_seen_at < $3
+                            "#,
+                        )
+                        .bind(account_uuid)
+                        .bind(&device_key)
+                        .bind(seen_at)
+                        .execute(&mut **tx)
+                        .await
+                        .map_err(storage)?;
+                    }
+                    Ok(inserted == 1)
+                })
+            })
+            .await
+    }
+}
@@ -43,6 +43,17 @@ impl SessionRepository for PgSessionRepository {
         let p_ua = session.device().user_agent().map(str::to_owned);
         let p_ip = session.device().ip_address().map(str::to_owned);
         let p_did = session.device().device_id().map(str::to_owned);
+        let p_country = session.device().location().map(|l| l.country().to_owned());
+        let p_city = session.device().location().and_then(|l| l.city()).map(str::to_owned);
+        let p_seen_at = session.last_seen_at();
+        let p_seen_ua = session.last_seen().user_agent().map(str::to_owned);
+        let p_seen_ip = session.last_seen().ip_address().map(str::to_owned);
+        let p_seen_country = session.last_seen().location().map(|l| l.country().to_owned());
+        let p_seen_city =
+            session.last_seen().location().and_then(|l| l.city()).map(str::to_owned);
+        let p_amr: Vec<String> =
+            session.methods().iter().map(|m| m.as_str().to_owned()).collect();
+        let p_auth_time = session.auth_time();
         let p_issued = session.issued_at();
         let p_expires = session.expires_at();
         let p_absolute = session.absolute_expiry();
@@ -59,8 +70,12 @@ impl SessionRepository for PgSessionRepository {
                             INSERT INTO sessions (
                                 id, account_id, issuer, subject, generation, status,
                                 device_user_agent, device_ip, device_id,
-                                issued_at, expires_at, absolute_expiry, revoked_at, version
-                            ) VALUES ($1,$2,$3,$4,$5,$6,$7,$8,$9,$10,$11,$12,$13,$14)
+                                issued_at, expires_at, absolute_expiry, revoked_at, version,
+                                amr, auth_time, device_country, device_city,
+                                last_seen_at, last_seen_user_agent, last_seen_ip,
+                                last_seen_country, last_seen_city
+                            ) VALUES ($1,$2,$3,$4,$5,$6,$7,$8,$9,$10,$11,$12,$13,$14,$15,$16,
+                                      $17,$18,$19,$20,$21,$22,$23)
                             "#,
                         )
                         .bind(id_uuid)
@@ -77,6 +92,15 @@ impl SessionRepository for PgSessionRepository {
                         .bind(p_absolute)
                         .bind(p_revoked_at)
                         .bind(0i64)
+                        .bind(p_amr)
+                        .bind(p_auth_time)
+                        .bind(p_country)
+                        .bind(p_city)
+                        .bind(p_seen_at)
+                        .bind(p_seen_ua)
+                        .bind(p_seen_ip)
+                        .bind(p_seen_country)
+                        .bind(p_seen_city)
                         .execute(&mut **tx)
                         .await
                         .map(|_| ())
@@ -94,7 +118,14 @@ impl SessionRepository for PgSessionRepository {
                                 status = $2,
                                 expires_at = $3,
                                 revoked_at = $4,
-                                version = $5
+                                version = $5,
+                                amr = $7,
+                                auth_time = $8,
+                                last_seen_at = $9,
+                                last_seen_user_agent = $10,
+                                last_seen_ip = $11,
+                                last_seen_country = $12,
+                                last_seen_city = $13
                             WHERE id = $1 AND version = $6
                             "#,
                         )
@@ -104,6 +135,13 @@ impl SessionRepository for PgSessionRepository {
                         .bind(p_revoked_at)
                         .bind(new_version)
                         .bind(new_version - 1)
+                        .bind(p_amr)
+                        .bind(p_auth_time)
+                        .bind(p_seen_at)
+                        .bind(p_seen_ua)
+                        .bind(p_seen_ip)
+                        .bind(p_seen_country)
+                        .bind(p_seen_city)
                         .execute(&mut **tx)
                         .await
                         .map_err(storage)?
@@ -0,0 +1,135 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use postgres_storage::{StorageError, TransactionManager};
+use tracing::instrument;
+
+use crate::error::AuthError;
+use crate::infrastructure::token::{SigningKeyStatus, SigningKeyStore, StoredSigningKey};
+
+use super::model::SigningKeyRow;
+
+/// PostgreSQL adapter for [`SigningKeyStore`]. The ring is service-global, so
+/// every statement runs on the single pool; the partial unique indexes from
+/// migration 0005 arbitrate replicas racing the same rotation step.
+#[derive(Clone)]
+pub struct PgSigningKeyStore {
+    tx: TransactionManager,
+}
+
+impl PgSigningKeyStore {
+    pub fn new(tx: TransactionManager) -> Self {
+        Self { tx }
+    }
+}
+
+fn storage(e: sqlx::Error) -> AuthError {
+    AuthError::Storage(StorageError::from(e))
+}
+
+#[async_trait]
+impl SigningKeyStore for PgSigningKeyStore {
+    #[instrument(name = "auth.signing_key.live", skip(self))]
+    async fn live(&self) -> Result<Vec<StoredSigningKey>, AuthError> {
+        let rows = sqlx::query_as::<_, SigningKeyRow>(
+            r#"
+            SELECT kid, public_pem, private_pem_enc, status, created_at, activated_at, not_after
+            FROM signing_keys
+            WHERE status <> 'retired'
+            ORDER BY created_at, kid
+            "#,
+        )
+        .fetch_all(self.tx.pool())
+        .await
+        .map_err(storage)?;
+        rows.into_iter().map(StoredSigningKey::try_from).collect()
+    }
+
+    #[instrument(name = "auth.signing_key.insert", skip(self, key), fields(
+        kid = %key.kid, status = key.status.as_str()
+    ))]
+    async fn insert(&self, key: &StoredSigningKey) -> Result<bool, AuthError> {
+        // No conflict target: DO NOTHING covers the primary key and both
+        // single-occupancy partial indexes.
+        let affected = sqlx::query(
+            r#"
+            INSERT INTO signing_keys
+                (kid, algorithm, public_pem, private_pem_enc, status, created_at, activated_at, not_after)
+            VALUES ($1, 'ES256', $2, $3, $4, $5, $6, $7)
+            ON CONFLICT DO NOTHING
+            "#,
+        )
+        .bind(&key.kid)
+        .bind(&key.public_pem)
+        .bind(key.private_pem_enc.as_deref())
+        .bind(key.status.as_str())
+        .bind(key.created_at)
+        .bind(key.activated_at)
+        .bind(key.not_after)
+        .execute(self.tx.pool())
+        .await
+        .map_err(storage)?
+        .rows_affected();
+        Ok(affected == 1)
+    }
+
+    #[instrument(name = "auth.signing_key.promote", skip(self))]
+    async fn promote(
+        &self,
+        kid: &str,
+        now: DateTime<Utc>,
+        retiring_until: DateTime<Utc>,
+    ) -> Result<bool, AuthError> {
+        let mut tx = self.tx.pool().begin().await.map_err(storage)?;
+
+        // Demote first: the `active` slot must be free before the published
+        // key can take it.
+        sqlx::query(
+            "UPDATE signing_keys SET status = $1, not_after = $2 WHERE status = $3",
+        )
+        .bind(SigningKeyStatus::Retiring.as_str())
+        .bind(retiring_until)
+        .bind(SigningKeyStatus::Active.as_str())
+        .execute(&mut *tx)
+        .await
+        .map_err(storage)?;
+
+        let promoted = sqlx::query(
+            "UPDATE signing_keys SET status = $1, activated_at = $2 WHERE kid = $3 AND status = $4",
+        )
+        .bind(SigningKeyStatus::Active.as_str())
+        .bind(now)
+        .bind(kid)
+        .bind(SigningKeyStatus::Published.as_str())
+        .execute(&mut *tx)
+        .await
+        .map_err(storage)?
+        .rows_affected();
+
+        if promoted == 0 {
+            // Lost the race: leave the current active key where it was.
+            tx.rollback().await.map_err(storage)?;
+            return Ok(false);
+        }
+        tx.commit().await.map_err(storage)?;
+        Ok(true)
+    }
+
+    #[instrument(name = "auth.signing_key.retire_expired", skip(self))]
+    async fn retire_expired(&self, now: DateTime<Utc>) -> Result<u64, AuthError> {
+        let affected = sqlx::query(
+            r#"
+            UPDATE signing_keys
+            SET status = $1, private_pem_enc = NULL
+            WHERE status = $2 AND not_after <= $3
+            "#,
+        )
+        .bind(SigningKeyStatus::Retired.as_str())
+        .bind(SigningKeyStatus::Retiring.as_str())
+        .bind(now)
+        .execute(self.tx.pool())
+        .await
+        .map_err(storage)?
+        .rows_affected();
+        Ok(affected)
+    }
+}
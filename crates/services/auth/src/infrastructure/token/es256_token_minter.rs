@@ -1,5 +1,7 @@
 use std::collections::HashMap;
+use std::sync::Arc;
 
+use arc_swap::ArcSwap;
 use async_trait::async_trait;
 use base64::Engine;
 use chrono::{DateTime, TimeZone, Utc};
@@ -12,7 +14,7 @@ use sha2::{Digest, Sha256};
 
 use crate::application::port::{GeneratedRefresh, TokenMinter};
 use crate::domain::value_object::{
-    AccessTokenClaims, AccountId, Generation, Permission, RefreshTokenHash, SessionId,
+    AccessTokenClaims, AccountId, AuthMethod, Generation, Permission, RefreshTokenHash, SessionId,
 };
 use crate::error::AuthError;
 
@@ -28,10 +30,21 @@ pub struct EsKeyMaterial {
     pub audience: String,
 }
 
+/// A signing key without token addressing — the unit the key rotator hands to
+/// [`Es256TokenMinter::install`].
+pub struct EsSigningKey {
+    pub key_id: String,
+    /// PKCS#8 (or SEC1) PEM of the P-256 signing key.
+    pub private_pem: Vec<u8>,
+    /// SPKI PEM of the corresponding public key.
+    pub public_pem: Vec<u8>,
+}
+
 /// A public key still **accepted for verification** during a rotation, after it
 /// has stopped being the active signing key. Tokens minted under it stay valid
 /// until they expire; it is also published in the JWKS so downstream verifiers
-/// keep accepting them.
+/// keep accepting them. Keys published ahead of their promotion take the same
+/// shape.
 pub struct EsVerifyingKey {
     pub key_id: String,
     pub public_pem: Vec<u8>,
@@ -54,6 +67,16 @@ struct EdgeClaims {
     exp: i64,
     /// Normalized permissions.
     perms: Vec<String>,
+    /// Assurance level (`aal1` / `aal2`). Absent on tokens minted before
+    /// second factors existed, which read as `aal1`.
+    #[serde(default)]
+    acr: Option<String>,
+    /// Authentication methods (`pwd`/`fed`, then `otp`/`rcode` and `mfa`).
+    #[serde(default)]
+    amr: Vec<String>,
+    /// When the user last presented a factor. Absent ⇒ `iat`.
+    #[serde(default)]
+    auth_time: Option<i64>,
 }
 
 /// A verifying key plus the SPKI PEM it was built from (retained so the JWKS can
@@ -63,15 +86,50 @@ struct VerifyEntry {
     public_pem: Vec<u8>,
 }
 
-/// ES256 implementation of [`TokenMinter`] with a verifying **key ring** for
-/// zero-downtime rotation: one active key signs; any key in the ring (selected by
-/// the token's `kid`) verifies.
-pub struct Es256TokenMinter {
+/// One generation of the key ring: the active signing key plus every key still
+/// accepted for verification, and the JWKS serialized from exactly that set.
+/// Immutable — a rotation builds a new `Ring` and swaps it in whole, so a
+/// request never sees a signing key whose public half is not yet in the JWKS.
+struct Ring {
     encoding_key: EncodingKey,
     /// Header for newly minted tokens — carries the active `kid`.
     header: Header,
     /// kid → verifying key. Includes the active key plus any retiring keys.
     verifying: HashMap<String, VerifyEntry>,
+    /// The serialized JWKS for `verifying`, built once per ring.
+    jwks: Arc<str>,
+}
+
+impl Ring {
+    fn build(active: EsSigningKey, others: Vec<EsVerifyingKey>) -> Result<Self, AuthError> {
+        let encoding_key = EncodingKey::from_ec_pem(&active.private_pem)
+            .map_err(|_| AuthError::SigningKeyUnavailable)?;
+
+        let mut verifying = HashMap::new();
+        for key in others {
+            verifying.insert(key.key_id, verify_entry(&key.public_pem)?);
+        }
+        verifying.insert(active.key_id.clone(), verify_entry(&active.public_pem)?);
+
+        let jwks = serde_json::to_string(&jwks_of(&verifying)?)
+            .map_err(|e| AuthError::DomainViolation { field: "jwks".into(), message: e.to_string() })?;
+
+        let mut header = Header::new(Algorithm::ES256);
+        header.kid = Some(active.key_id);
+
+        Ok(Self { encoding_key, header, verifying, jwks: jwks.into() })
+    }
+}
+
+/// ES256 implementation of [`TokenMinter`] with a verifying **key ring** for
+/// zero-downtime rotation: one active key signs; any key in the ring (selected by
+/// the token's `kid`) verifies.
+///
+/// The ring is live: [`install`](Self::install) swaps in a new one without a
+/// restart (the key rotator calls it whenever the persisted ring changes).
+/// Readers take a lock-free snapshot per call.
+pub struct Es256TokenMinter {
+    ring: ArcSwap<Ring>,
     validation: Validation,
     issuer: String,
     audience: String,
@@ -89,50 +147,63 @@ impl Es256TokenMinter {
         active: EsKeyMaterial,
         retiring: Vec<EsVerifyingKey>,
     ) -> Result<Self, AuthError> {
-        let encoding_key = EncodingKey::from_ec_pem(&active.private_pem)
-            .map_err(|_| AuthError::SigningKeyUnavailable)?;
-
-        let mut verifying = HashMap::new();
-        verifying.insert(active.key_id.clone(), verify_entry(&active.public_pem)?);
-        for key in retiring {
-            verifying.insert(key.key_id, verify_entry(&key.public_pem)?);
-        }
+        let EsKeyMaterial { private_pem, public_pem, key_id, issuer, audience } = active;
+        let ring = Ring::build(EsSigningKey { key_id, private_pem, public_pem }, retiring)?;
 
         let mut validation = Validation::new(Algorithm::ES256);
-        validation.set_issuer(std::slice::from_ref(&active.issuer));
-        validation.set_audience(std::slice::from_ref(&active.audience));
+        validation.set_issuer(std::slice::from_ref(&issuer));
+        validation.set_audience(std::slice::from_ref(&audience));
         validation.set_required_spec_claims(&["exp", "iss", "aud", "sub"]);
 
-        let mut header = Header::new(Algorithm::ES256);
-        header.kid = Some(active.key_id);
+        Ok(Self { ring: ArcSwap::from_pointee(ring), validation, issuer, audience })
+    }
 
-        Ok(Self {
-            encoding_key,
-            header,
-            verifying,
-            validation,
-            issuer: active.issuer,
-            audience: active.audience,
-        })
+    /// Replaces the ring: new tokens are signed by `active`, and `others`
+    /// (keys published ahead of promotion, keys retiring after it) verify and
+    /// appear in the JWKS alongside it. Nothing changes if any key fails to
+    /// parse.
+    pub fn install(
+        &self,
+        active: EsSigningKey,
+        others: Vec<EsVerifyingKey>,
+    ) -> Result<(), AuthError> {
+        let ring = Ring::build(active, others)?;
+        tracing::info!(
+            kid = ring.header.kid.as_deref().unwrap_or_default(),
+            ring_size = ring.verifying.len(),
+            "auth.signing_key.ring_installed"
+        );
+        self.ring.store(Arc::new(ring));
+        Ok(())
+    }
+
+    /// `kid` of the key currently signing new tokens.
+    pub fn active_kid(&self) -> String {
+        self.ring.load().header.kid.clone().unwrap_or_default()
+    }
+
+    /// Every `kid` in the ring (active included), sorted.
+    pub fn ring_kids(&self) -> Vec<String> {
+        let mut kids: Vec<String> = self.ring.load().verifying.keys().cloned().collect();
+        kids.sort_unstable();
+        kids
     }
 
     /// The JWKS for every verifying key in the ring — what downstream services
     /// (via `auth-context`) fetch to validate edge tokens. Publish it at the
     /// service's well-known JWKS URL.
     pub fn jwks(&self) -> Result<Jwks, AuthError> {
-        let mut keys: Vec<Jwk> = self
-            .verifying
-            .iter()
-            .map(|(kid, entry)| jwk_from_pem(kid, &entry.public_pem))
-            .collect::<Result<_, _>>()?;
-        keys.sort_by(|a, b| a.kid.cmp(&b.kid)); // deterministic ordering
-        Ok(Jwks { keys })
+        jwks_of(&self.ring.load().verifying)
     }
 
     /// The JWKS as a JSON string.
     pub fn jwks_json(&self) -> Result<String, AuthError> {
-        serde_json::to_string(&self.jwks()?)
-            .map_err(|e| AuthError::DomainViolation { field: "jwks".into(), message: e.to_string() })
+        Ok(self.jwks_document().to_string())
+    }
+
+    /// The current ring's JWKS, serialized when the ring was installed.
+    pub fn jwks_document(&self) -> Arc<str> {
+        Arc::clone(&self.ring.load().jwks)
     }
 
     fn b64(bytes: &[u8]) -> String {
@@ -140,6 +211,15 @@ impl Es256TokenMinter {
     }
 }
 
+fn jwks_of(verifying: &HashMap<String, VerifyEntry>) -> Result<Jwks, AuthError> {
+    let mut keys: Vec<Jwk> = verifying
+        .iter()
+        .map(|(kid, entry)| jwk_from_pem(kid, &entry.public_pem))
+        .collect::<Result<_, _>>()?;
+    keys.sort_by(|a, b| a.kid.cmp(&b.kid)); // deterministic ordering
+    Ok(Jwks { keys })
+}
+
 fn verify_entry(public_pem: &[u8]) -> Result<VerifyEntry, AuthError> {
     let decoding_key =
         DecodingKey::from_ec_pem(public_pem).map_err(|_| AuthError::SigningKeyUnavailable)?;
@@ -197,15 +277,20 @@ impl TokenMinter for Es256TokenMinter {
             iat: claims.issued_at.timestamp(),
             exp: claims.expires_at.timestamp(),
             perms: claims.permissions.iter().map(|p| p.as_str().to_owned()).collect(),
+            acr: Some(claims.assurance().as_str().to_owned()),
+            amr: claims.amr(),
+            auth_time: Some(claims.auth_time.timestamp()),
         };
-        encode(&self.header, &edge, &self.encoding_key).map_err(|_| AuthError::TokenSigningFailed)
+        let ring = self.ring.load();
+        encode(&ring.header, &edge, &ring.encoding_key).map_err(|_| AuthError::TokenSigningFailed)
     }
 
     async fn verify_access(&self, token: &str) -> Result<AccessTokenClaims, AuthError> {
         // Select the verifying key by the token's `kid` (key-ring rotation).
         let header = decode_header(token).map_err(|_| AuthError::IdpTokenRejected)?;
         let kid = header.kid.ok_or(AuthError::IdpTokenRejected)?;
-        let entry = self.verifying.get(&kid).ok_or(AuthError::IdpTokenRejected)?;
+        let ring = self.ring.load();
+        let entry = ring.verifying.get(&kid).ok_or(AuthError::IdpTokenRejected)?;
 
         let data = decode::<EdgeClaims>(token, &entry.decoding_key, &self.validation)
             .map_err(|_| AuthError::IdpTokenRejected)?;
@@ -221,7 +306,11 @@ impl TokenMinter for Es256TokenMinter {
         };
         let issued_at: DateTime<Utc> = to_utc(c.iat)?;
         let expires_at: DateTime<Utc> = to_utc(c.exp)?;
+        let auth_time: DateTime<Utc> = to_utc(c.auth_time.unwrap_or(c.iat))?;
         let permissions = c.perms.into_iter().map(Permission::new).collect();
+        // `acr` and the `mfa` marker are derived from the methods, so only the
+        // methods themselves are read back.
+        let methods = c.amr.iter().filter_map(|m| AuthMethod::try_from(m.as_str()).ok()).collect();
 
         Ok(AccessTokenClaims {
             account_id,
@@ -230,6 +319,8 @@ impl TokenMinter for Es256TokenMinter {
             permissions,
             issued_at,
             expires_at,
+            methods,
+            auth_time,
         })
     }
 
@@ -288,6 +379,8 @@ mod tests {
             permissions: vec![Permission::new("posts:write")],
             issued_at: now,
             expires_at: now + ttl,
+            methods: vec![AuthMethod::Password, AuthMethod::Otp],
+            auth_time: now - Duration::minutes(1),
         }
     }
 
@@ -302,6 +395,21 @@ mod tests {
         assert_eq!(back.session_id, claims.session_id);
         assert_eq!(back.generation, claims.generation);
         assert_eq!(back.permissions, claims.permissions);
+        assert_eq!(back.methods, claims.methods);
+        assert_eq!(back.auth_time.timestamp(), claims.auth_time.timestamp());
+    }
+
+    #[tokio::test]
+    async fn assurance_claims_reach_the_wire_in_oidc_shape() {
+        let minter = single_key_minter();
+        let token = minter.mint_access(&claims_at(Utc::now(), Duration::minutes(10))).await.unwrap();
+        let payload = token.split('.').nth(1).unwrap();
+        let json: serde_json::Value =
+            serde_json::from_slice(&base64::engine::general_purpose::URL_SAFE_NO_PAD.decode(payload).unwrap())
+                .unwrap();
+        assert_eq!(json["acr"], "aal2");
+        assert_eq!(json["amr"], serde_json::json!(["pwd", "otp", "mfa"]));
+        assert!(json["auth_time"].as_i64().unwrap() < json["iat"].as_i64().unwrap());
     }
 
     #[tokio::test]
@@ -375,6 +483,37 @@ mod tests {
         assert!(!k.x.is_empty() && !k.y.is_empty());
     }
 
+    #[tokio::test]
+    async fn installed_ring_takes_over_signing_and_the_jwks_live() {
+        let (k1_private, k1_public) = keypair();
+        let (k2_private, k2_public) = keypair();
+        let minter =
+            Es256TokenMinter::from_pem(material(k1_private, k1_public.clone(), "k1")).unwrap();
+        let old = minter.mint_access(&claims_at(Utc::now(), Duration::minutes(10))).await.unwrap();
+        let before = minter.jwks_document();
+
+        let active = EsSigningKey { key_id: "k2".into(), private_pem: k2_private, public_pem: k2_public };
+        let retiring = vec![EsVerifyingKey { key_id: "k1".into(), public_pem: k1_public }];
+        minter.install(active, retiring).unwrap();
+
+        assert_eq!(minter.active_kid(), "k2");
+        assert_eq!(minter.ring_kids(), vec!["k1", "k2"]);
+        assert_ne!(minter.jwks_document(), before);
+        minter.verify_access(&old).await.unwrap();
+        let fresh = minter.mint_access(&claims_at(Utc::now(), Duration::minutes(10))).await.unwrap();
+        assert_eq!(decode_header(&fresh).unwrap().kid.as_deref(), Some("k2"));
+    }
+
+    #[test]
+    fn a_ring_that_fails_to_parse_is_not_installed() {
+        let minter = single_key_minter();
+        let before = minter.jwks_document();
+        let broken = EsSigningKey { key_id: "k2".into(), private_pem: b"nope".to_vec(), public_pem: b"nope".to_vec() };
+        assert!(matches!(minter.install(broken, Vec::new()).unwrap_err(), AuthError::SigningKeyUnavailable));
+        assert_eq!(minter.active_kid(), "k1");
+        assert_eq!(minter.jwks_document(), before);
+    }
+
     #[test]
     fn refresh_hash_is_deterministic_and_redacted() {
         let minter = single_key_minter();
@@ -0,0 +1,414 @@
+//! Scheduled signing-key rotation.
+//!
+//! Every replica runs a [`KeyRotator`]; the persisted ring in
+//! [`SigningKeyStore`] is the only shared state. Each tick walks the lifecycle
+//! one guarded step at a time:
+//!
+//! 1. **retire** — `retiring` keys past `not_after` leave the ring;
+//! 2. **publish** — `publish_lead` before the active key is due, a fresh key is
+//!    generated, wrapped by the [`SigningKeyCustodian`] and stored `published`
+//!    (it appears in the JWKS, so verifiers learn it before it signs anything);
+//! 3. **promote** — once due, the published key becomes `active` and the old
+//!    one `retiring` for `retire_after` (the longest a token it signed can
+//!    still be presented).
+//!
+//! Then the replica reloads the ring and, if it differs from what its minter
+//! holds, [`install`](Es256TokenMinter::install)s it — which is also how a
+//! replica that lost a race picks up the winner's step. Store uniqueness makes
+//! every step idempotent across replicas.
+
+use std::sync::Arc;
+
+use chrono::{DateTime, Duration, Utc};
+use p256::ecdsa::SigningKey;
+use p256::pkcs8::{EncodePrivateKey, EncodePublicKey, LineEnding};
+
+use crate::error::AuthError;
+
+use super::es256_token_minter::{Es256TokenMinter, EsSigningKey, EsVerifyingKey};
+use super::key_custody::SigningKeyCustodian;
+use super::signing_key_store::{SigningKeyStatus, SigningKeyStore, StoredSigningKey};
+
+/// Rotation timings.
+#[derive(Debug, Clone)]
+pub struct RotationPolicy {
+    /// How long a key signs before its successor takes over.
+    pub rotate_every: Duration,
+    /// How long a successor sits in the JWKS before it may sign. Must exceed
+    /// how stale a verifier's key set can get (JWKS `max-age` + `check_every`).
+    pub publish_lead: Duration,
+    /// How long a superseded key stays verifiable: the access-token TTL plus
+    /// slack for replicas still signing on the previous ring.
+    pub retire_after: Duration,
+    /// Tick interval.
+    pub check_every: std::time::Duration,
+}
+
+/// The ring as persisted: the key that signs plus every other live key.
+pub struct KeyRing {
+    pub active: EsSigningKey,
+    pub others: Vec<EsVerifyingKey>,
+}
+
+/// Drives the signing-key lifecycle against the store and keeps a minter's
+/// ring in step with it.
+#[derive(Clone)]
+pub struct KeyRotator {
+    store: Arc<dyn SigningKeyStore>,
+    custodian: Arc<dyn SigningKeyCustodian>,
+    policy: RotationPolicy,
+}
+
+impl KeyRotator {
+    pub fn new(
+        store: Arc<dyn SigningKeyStore>,
+        custodian: Arc<dyn SigningKeyCustodian>,
+        policy: RotationPolicy,
+    ) -> Self {
+        Self { store, custodian, policy }
+    }
+
+    /// Makes sure the store holds an active key and returns the ring to boot
+    /// the minter with.
+    ///
+    /// On an empty store the active key is `seed` when given — so a deployment
+    /// moving off config-managed keys keeps its current `kid` — and a freshly
+    /// generated key otherwise; `retiring` seeds enter as verify-only keys for
+    /// one `retire_after` window.
+    pub async fn bootstrap(
+        &self,
+        seed: Option<EsSigningKey>,
+        retiring: Vec<EsVerifyingKey>,
+        now: DateTime<Utc>,
+    ) -> Result<KeyRing, AuthError> {
+        self.store.retire_expired(now).await?;
+        let live = self.store.live().await?;
+        if !live.iter().any(|k| k.status == SigningKeyStatus::Active) {
+            let key = match seed {
+                Some(key) => key,
+                None => generate_key()?,
+            };
+            let inserted = self.store_key(key, SigningKeyStatus::Active, now).await?;
+            if inserted {
+                for key in retiring {
+                    self.store
+                        .insert(&StoredSigningKey {
+                            kid: key.key_id,
+                            public_pem: pem_string(key.public_pem)?,
+                            private_pem_enc: None,
+                            status: SigningKeyStatus::Retiring,
+                            created_at: now,
+                            activated_at: None,
+                            not_after: Some(now + self.policy.retire_after),
+                        })
+                        .await?;
+                }
+            }
+            // Not inserted ⇒ another replica bootstrapped first; use its key.
+        }
+        self.load().await
+    }
+
+    /// One pass over the lifecycle (retire, publish, promote) at `now`.
+    pub async fn tick(&self, now: DateTime<Utc>) -> Result<(), AuthError> {
+        let retired = self.store.retire_expired(now).await?;
+        if retired > 0 {
+            tracing::info!(retired, "auth.signing_key.retired");
+        }
+
+        let live = self.store.live().await?;
+        let Some(active) = live.iter().find(|k| k.status == SigningKeyStatus::Active) else {
+            return Err(AuthError::SigningKeyUnavailable);
+        };
+        let due = active.activated_at.unwrap_or(active.created_at) + self.policy.rotate_every;
+
+        match live.iter().find(|k| k.status == SigningKeyStatus::Published) {
+            None if now >= due - self.policy.publish_lead => {
+                let key = generate_key()?;
+                let kid = key.key_id.clone();
+                if self.store_key(key, SigningKeyStatus::Published, now).await? {
+                    tracing::info!(%kid, "auth.signing_key.published");
+                }
+            }
+            Some(next) if now >= due && now >= next.created_at + self.policy.publish_lead => {
+                let retiring_until = now + self.policy.retire_after;
+                if self.store.promote(&next.kid, now, retiring_until).await? {
+                    tracing::info!(kid = %next.kid, retired_kid = %active.kid, "auth.signing_key.promoted");
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Reads the persisted ring, unwrapping the active key's private half.
+    pub async fn load(&self) -> Result<KeyRing, AuthError> {
+        let live = self.store.live().await?;
+        let mut active = None;
+        let mut others = Vec::new();
+        for key in live {
+            if key.status == SigningKeyStatus::Active {
+                let wrapped = key.private_pem_enc.ok_or(AuthError::SigningKeyUnavailable)?;
+                active = Some(EsSigningKey {
+                    private_pem: self.custodian.unwrap(&wrapped).await?,
+                    public_pem: key.public_pem.into_bytes(),
+                    key_id: key.kid,
+                });
+            } else {
+                others.push(EsVerifyingKey { key_id: key.kid, public_pem: key.public_pem.into_bytes() });
+            }
+        }
+        let active = active.ok_or(AuthError::SigningKeyUnavailable)?;
+        Ok(KeyRing { active, others })
+    }
+
+    /// Ticks every `check_every` for the life of the process, installing the
+    /// persisted ring into `minter` whenever it changes. Errors are logged and
+    /// retried on the next tick; the minter keeps its current ring meanwhile.
+    pub async fn run(self, minter: Arc<Es256TokenMinter>) {
+        let mut interval = tokio::time::interval(self.policy.check_every);
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        loop {
+            interval.tick().await;
+            if let Err(error) = self.sync(&minter, Utc::now()).await {
+                tracing::warn!(%error, "auth.signing_key.rotation_failed");
+            }
+        }
+    }
+
+    /// One tick, then reinstall the ring if it no longer matches `minter`.
+    pub async fn sync(&self, minter: &Es256TokenMinter, now: DateTime<Utc>) -> Result<(), AuthError> {
+        self.tick(now).await?;
+        let ring = self.load().await?;
+        let mut kids: Vec<&str> = ring.others.iter().map(|k| k.key_id.as_str()).collect();
+        kids.push(&ring.active.key_id);
+        kids.sort_unstable();
+        if ring.active.key_id != minter.active_kid() || kids != minter.ring_kids() {
+            minter.install(ring.active, ring.others)?;
+        }
+        Ok(())
+    }
+
+    async fn store_key(
+        &self,
+        key: EsSigningKey,
+        status: SigningKeyStatus,
+        now: DateTime<Utc>,
+    ) -> Result<bool, AuthError> {
+        let private_pem_enc = self.custodian.wrap(&key.private_pem).await?;
+        self.store
+            .insert(&StoredSigningKey {
+                kid: key.key_id,
+                public_pem: pem_string(key.public_pem)?,
+                private_pem_enc: Some(private_pem_enc),
+                status,
+                created_at: now,
+                activated_at: (status == SigningKeyStatus::Active).then_some(now),
+                not_after: None,
+            })
+            .await
+    }
+}
+
+/// A fresh P-256 key under a time-ordered `kid`.
+fn generate_key() -> Result<EsSigningKey, AuthError> {
+    let signing = SigningKey::random(&mut rand_core::OsRng);
+    let private_pem = signing
+        .to_pkcs8_pem(LineEnding::LF)
+        .map_err(|_| AuthError::SigningKeyUnavailable)?
+        .as_bytes()
+        .to_vec();
+    let public_pem = signing
+        .verifying_key()
+        .to_public_key_pem(LineEnding::LF)
+        .map_err(|_| AuthError::SigningKeyUnavailable)?
+        .into_bytes();
+    let key_id = format!("auth-es256-{}", uuid::Uuid::now_v7().simple());
+    Ok(EsSigningKey { key_id, private_pem, public_pem })
+}
+
+fn pem_string(pem: Vec<u8>) -> Result<String, AuthError> {
+    String::from_utf8(pem).map_err(|_| AuthError::SigningKeyUnavailable)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    use async_trait::async_trait;
+
+    use crate::infrastructure::token::{EsKeyMaterial, LocalKek};
+
+    /// The store's uniqueness rules, in memory.
+    #[derive(Default)]
+    struct InMemorySigningKeyStore {
+        keys: Mutex<Vec<StoredSigningKey>>,
+    }
+
+    #[async_trait]
+    impl SigningKeyStore for InMemorySigningKeyStore {
+        async fn live(&self) -> Result<Vec<StoredSigningKey>, AuthError> {
+            let keys = self.keys.lock().unwrap();
+            Ok(keys.iter().filter(|k| k.status != SigningKeyStatus::Retired).cloned().collect())
+        }
+
+        async fn insert(&self, key: &StoredSigningKey) -> Result<bool, AuthError> {
+            let mut keys = self.keys.lock().unwrap();
+            let single = matches!(key.status, SigningKeyStatus::Active | SigningKeyStatus::Published);
+            if keys.iter().any(|k| k.kid == key.kid || (single && k.status == key.status)) {
+                return Ok(false);
+            }
+            keys.push(key.clone());
+            Ok(true)
+        }
+
+        async fn promote(
+            &self,
+            kid: &str,
+            now: DateTime<Utc>,
+            retiring_until: DateTime<Utc>,
+        ) -> Result<bool, AuthError> {
+            let mut keys = self.keys.lock().unwrap();
+            if !keys.iter().any(|k| k.kid == kid && k.status == SigningKeyStatus::Published) {
+                return Ok(false);
+            }
+            for k in keys.iter_mut() {
+                if k.status == SigningKeyStatus::Active {
+                    k.status = SigningKeyStatus::Retiring;
+                    k.not_after = Some(retiring_until);
+                } else if k.kid == kid {
+                    k.status = SigningKeyStatus::Active;
+                    k.activated_at = Some(now);
+                }
+            }
+            Ok(true)
+        }
+
+        async fn retire_expired(&self, now: DateTime<Utc>) -> Result<u64, AuthError> {
+            let mut keys = self.keys.lock().unwrap();
+            let mut retired = 0;
+            for k in keys.iter_mut() {
+                if k.status == SigningKeyStatus::Retiring && k.not_after.is_some_and(|t| t <= now) {
+                    k.status = SigningKeyStatus::Retired;
+                    k.private_pem_enc = None;
+                    retired += 1;
+                }
+            }
+            Ok(retired)
+        }
+    }
+
+    fn policy() -> RotationPolicy {
+        RotationPolicy {
+            rotate_every: Duration::days(7),
+            publish_lead: Duration::hours(1),
+            retire_after: Duration::minutes(15),
+            check_every: std::time::Duration::from_secs(60),
+        }
+    }
+
+    fn rotator(store: &Arc<InMemorySigningKeyStore>) -> KeyRotator {
+        KeyRotator::new(store.clone(), Arc::new(LocalKek::new([9u8; 32])), policy())
+    }
+
+    fn minter_for(ring: KeyRing) -> Es256TokenMinter {
+        Es256TokenMinter::from_key_ring(
+            EsKeyMaterial {
+                private_pem: ring.active.private_pem,
+                public_pem: ring.active.public_pem,
+                key_id: ring.active.key_id,
+                issuer: "https://auth.test".into(),
+                audience: "core-platform".into(),
+            },
+            ring.others,
+        )
+        .unwrap()
+    }
+
+    fn statuses(store: &InMemorySigningKeyStore) -> Vec<SigningKeyStatus> {
+        store.keys.lock().unwrap().iter().map(|k| k.status).collect()
+    }
+
+    #[tokio::test]
+    async fn bootstrap_generates_an_active_key_once_and_wraps_it() {
+        let store = Arc::new(InMemorySigningKeyStore::default());
+        let now = Utc::now();
+        let first = rotator(&store).bootstrap(None, Vec::new(), now).await.unwrap();
+        let second = rotator(&store).bootstrap(None, Vec::new(), now).await.unwrap();
+
+        assert_eq!(first.active.key_id, second.active.key_id);
+        assert!(first.others.is_empty());
+        let stored = store.keys.lock().unwrap()[0].clone();
+        let wrapped = stored.private_pem_enc.unwrap();
+        assert!(!wrapped.windows(11).any(|w| w == b"PRIVATE KEY"));
+    }
+
+    #[tokio::test]
+    async fn bootstrap_keeps_the_seed_kid() {
+        let store = Arc::new(InMemorySigningKeyStore::default());
+        let mut seed = generate_key().unwrap();
+        seed.key_id = "auth-es256-1".into();
+        let ring = rotator(&store).bootstrap(Some(seed), Vec::new(), Utc::now()).await.unwrap();
+        assert_eq!(ring.active.key_id, "auth-es256-1");
+    }
+
+    #[tokio::test]
+    async fn a_key_is_published_then_promoted_then_retired() {
+        let store = Arc::new(InMemorySigningKeyStore::default());
+        let rotator = rotator(&store);
+        let t0 = Utc::now();
+        let ring = rotator.bootstrap(None, Vec::new(), t0).await.unwrap();
+        let first_kid = ring.active.key_id.clone();
+        let minter = minter_for(ring);
+
+        // Not yet inside the publish lead: nothing happens.
+        rotator.sync(&minter, t0 + Duration::days(6)).await.unwrap();
+        assert_eq!(statuses(&store), vec![SigningKeyStatus::Active]);
+
+        // Inside the lead: the successor is published and joins the JWKS, but
+        // the old key keeps signing.
+        let published_at = t0 + Duration::days(7) - Duration::minutes(30);
+        rotator.sync(&minter, published_at).await.unwrap();
+        assert_eq!(statuses(&store), vec![SigningKeyStatus::Active, SigningKeyStatus::Published]);
+        assert_eq!(minter.jwks().unwrap().keys.len(), 2);
+        assert_eq!(minter.active_kid(), first_kid);
+
+        // Due, but the successor has not been published for a full lead yet.
+        rotator.sync(&minter, t0 + Duration::days(7)).await.unwrap();
+        assert_eq!(minter.active_kid(), first_kid);
+
+        // Promoted: the successor signs, the old key still verifies.
+        let promoted_at = published_at + Duration::hours(1);
+        rotator.sync(&minter, promoted_at).await.unwrap();
+        assert_eq!(statuses(&store), vec![SigningKeyStatus::Retiring, SigningKeyStatus::Active]);
+        assert_ne!(minter.active_kid(), first_kid);
+        assert!(minter.ring_kids().contains(&first_kid));
+
+        // Retired after `retire_after`: out of the ring and the JWKS.
+        rotator.sync(&minter, promoted_at + Duration::minutes(15)).await.unwrap();
+        assert_eq!(statuses(&store), vec![SigningKeyStatus::Retired, SigningKeyStatus::Active]);
+        assert!(store.keys.lock().unwrap()[0].private_pem_enc.is_none());
+        assert_eq!(minter.ring_kids().len(), 1);
+        assert_eq!(minter.jwks().unwrap().keys.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn a_replica_picks_up_a_rotation_another_replica_performed() {
+        let store = Arc::new(InMemorySigningKeyStore::default());
+        let t0 = Utc::now();
+        let leader = rotator(&store);
+        let follower = rotator(&store);
+        let minter = minter_for(follower.bootstrap(None, Vec::new(), t0).await.unwrap());
+
+        let published_at = t0 + Duration::days(7) - Duration::minutes(30);
+        leader.tick(published_at).await.unwrap();
+        leader.tick(published_at + Duration::hours(1)).await.unwrap();
+
+        // The follower's own tick finds nothing left to do, but reinstalls.
+        let before = minter.active_kid();
+        follower.sync(&minter, published_at + Duration::hours(1)).await.unwrap();
+        assert_ne!(minter.active_kid(), before);
+        assert_eq!(statuses(&store), vec![SigningKeyStatus::Retiring, SigningKeyStatus::Active]);
+    }
+}
@@ -4,7 +4,20 @@
 //! edge hot path; the format is swappable for PASETO v4 behind the same
 //! [`TokenMinter`](crate::application::port::TokenMinter) port without touching
 //! the handlers.
+//!
+//! The signing key rotates on a schedule ([`key_rotation`]): the ring lives in
+//! `signing_keys` ([`signing_key_store`]), private halves wrapped by a
+//! KMS-shaped custodian ([`key_custody`]), and each replica's minter swaps in
+//! the new ring live — no restart, no redeploy.
 
 pub mod es256_token_minter;
+pub mod key_custody;
+pub mod key_rotation;
+pub mod signing_key_store;
 
-pub use es256_token_minter::{Es256TokenMinter, EsKeyMaterial, EsVerifyingKey, Jwk, Jwks};
+pub use es256_token_minter::{
+    Es256TokenMinter, EsKeyMaterial, EsSigningKey, EsVerifyingKey, Jwk, Jwks,
+};
+pub use key_custody::{KEY_LEN, LocalKek, SigningKeyCustodian};
+pub use key_rotation::{KeyRing, KeyRotator, RotationPolicy};
+pub use signing_key_store::{SigningKeyStatus, SigningKeyStore, StoredSigningKey};
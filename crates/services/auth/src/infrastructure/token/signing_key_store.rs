@@ -0,0 +1,95 @@
+//! Durable home of the signing-key ring (`signing_keys`), as the key rotator
+//! sees it.
+//!
+//! A key moves `published → active → retiring → retired`, never backwards.
+//! At most one key is `active` and at most one `published`; the store enforces
+//! both (partial unique indexes in Postgres), so replicas racing the same
+//! rotation step settle on a single winner and the losers simply re-read.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use crate::error::AuthError;
+
+/// Lifecycle stage of a signing key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum SigningKeyStatus {
+    /// In the JWKS so verifiers learn it, but not yet signing.
+    Published,
+    /// The one key signing new tokens.
+    Active,
+    /// Superseded; still in the JWKS until the last token it signed expires.
+    Retiring,
+    /// Out of the ring for good; its private half has been dropped.
+    Retired,
+}
+
+impl SigningKeyStatus {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Published => "published",
+            Self::Active => "active",
+            Self::Retiring => "retiring",
+            Self::Retired => "retired",
+        }
+    }
+}
+
+impl TryFrom<&str> for SigningKeyStatus {
+    type Error = AuthError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "published" => Ok(Self::Published),
+            "active" => Ok(Self::Active),
+            "retiring" => Ok(Self::Retiring),
+            "retired" => Ok(Self::Retired),
+            other => Err(AuthError::DomainViolation {
+                field: "signing_key.status".into(),
+                message: format!("unknown status '{other}'"),
+            }),
+        }
+    }
+}
+
+/// One row of the ring. `private_pem_enc` is the custodian-wrapped private PEM;
+/// `None` for a verify-only key (seeded from config without its private half,
+/// or retired).
+#[derive(Debug, Clone)]
+pub struct StoredSigningKey {
+    pub kid: String,
+    pub public_pem: String,
+    pub private_pem_enc: Option<Vec<u8>>,
+    pub status: SigningKeyStatus,
+    pub created_at: DateTime<Utc>,
+    /// When the key became `active` (the rotation clock runs from here).
+    pub activated_at: Option<DateTime<Utc>>,
+    /// For a `retiring` key: when the last token it can have signed expires.
+    pub not_after: Option<DateTime<Utc>>,
+}
+
+/// Persistence for the signing-key ring. Every mutation is a single guarded
+/// statement or transaction, safe to run concurrently from every replica.
+#[async_trait]
+pub trait SigningKeyStore: Send + Sync + 'static {
+    /// Every key that is not `retired`, oldest first.
+    async fn live(&self) -> Result<Vec<StoredSigningKey>, AuthError>;
+
+    /// Inserts `key`. `false` when a uniqueness rule turned it away — another
+    /// replica already holds the `active` / `published` slot (or the `kid`).
+    async fn insert(&self, key: &StoredSigningKey) -> Result<bool, AuthError>;
+
+    /// Atomically makes the `published` key `kid` active and moves the current
+    /// active key to `retiring` until `retiring_until`. `false` when `kid` is no
+    /// longer `published` (another replica promoted it first).
+    async fn promote(
+        &self,
+        kid: &str,
+        now: DateTime<Utc>,
+        retiring_until: DateTime<Utc>,
+    ) -> Result<bool, AuthError>;
+
+    /// Retires every `retiring` key whose `not_after` has passed, dropping its
+    /// wrapped private half. Returns how many were retired.
+    async fn retire_expired(&self, now: DateTime<Utc>) -> Result<u64, AuthError>;
+}
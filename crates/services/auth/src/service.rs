@@ -8,7 +8,7 @@ use std::sync::Arc;
 use async_trait::async_trait;
 use postgres_storage::PostgresConfig;
 use redis_storage::RedisConfig;
-use service_runtime::{HealthProbe, InfraRegistry, Service};
+use service_runtime::{AccessPolicy, HealthProbe, InfraRegistry, Service};
 use tonic::service::RoutesBuilder;
 use tonic_reflection::server::Builder as ReflectionBuilder;
 use transport::kafka::config::client::KafkaClientConfig;
@@ -17,6 +17,7 @@ use crate::app::{App, Backends};
 use crate::config::AuthConfig;
 use crate::infrastructure::grpc::handler::{AuthServiceHandler, AuthServiceServer};
 use crate::infrastructure::grpc::server::FILE_DESCRIPTOR_SET;
+use crate::infrastructure::http::jwks::{self, JwksSource};
 
 /// The concrete tonic server type, named once so the health key and the
 /// reflection registration agree.
@@ -55,27 +56,25 @@ impl Service for AuthService {
         let jwks_addr: std::net::SocketAddr = std::env::var("AUTH_JWKS_HTTP_ADDR")
             .unwrap_or_else(|_| "0.0.0.0:8081".to_owned())
             .parse()?;
-        let jwks_json = app.jwks_json.clone();
+        let jwks_source: Arc<dyn JwksSource> = app.minter.clone();
         let listener = tokio::net::TcpListener::bind(jwks_addr).await?;
         tracing::info!(%jwks_addr, "auth.jwks http listener bound");
         tokio::spawn(async move {
-            if let Err(error) =
-                axum::serve(listener, crate::infrastructure::http::jwks::router(jwks_json)).await
-            {
+            if let Err(error) = axum::serve(listener, jwks::router(jwks_source)).await {
                 tracing::error!(%error, "auth.jwks http listener exited");
             }
         });
 
         // Outbox relay: the only path from committed auth events to the
-        // broker. Interval is env-tunable; 1s keeps worst-case event latency
-        // well under the audit plane's freshness expectations.
-        let relay_interval = std::time::Duration::from_millis(
-            std::env::var("AUTH_OUTBOX_RELAY_INTERVAL_MS")
-                .ok()
-                .and_then(|v| v.parse().ok())
-                .unwrap_or(1_000),
-        );
-        tokio::spawn(app.relay.clone().run(relay_interval));
+        // broker. Tuned by the shared `OUTBOX_RELAY_*` env vars; the 1s default
+        // keeps worst-case event latency well under the audit plane's freshness
+        // expectations.
+        tokio::spawn(app.relay.clone().run());
+
+        // Signing-key rotation: every replica ticks against the shared
+        // `signing_keys` ring (store uniqueness arbitrates) and swaps the new
+        // ring into its minter — the JWKS above follows without a restart.
+        tokio::spawn(app.rotator.clone().run(app.minter.clone()));
 
         Ok(Self { app })
     }
@@ -87,6 +86,21 @@ impl Service for AuthService {
         ]
     }
 
+    fn access_policy(&self) -> AccessPolicy {
+        // Login/VerifySecondFactor/Refresh mint the token, so they can't
+        // require one; StepUp strengthens the caller's existing one.
+        AccessPolicy::for_service(Self::GRPC_SERVICE_NAME)
+            .public("Login")
+            .public("VerifySecondFactor")
+            .public("Refresh")
+            .authenticated("StepUp")
+            .authenticated("Logout")
+            .authenticated("LogoutAllSessions")
+            .authenticated("ListSessions")
+            .authenticated("RevokeSession")
+            .internal("Introspect")
+    }
+
     fn register(self, routes: &mut RoutesBuilder) -> anyhow::Result<()> {
         let reflection = ReflectionBuilder::configure()
             .register_encoded_file_descriptor_set(FILE_DESCRIPTOR_SET)
@@ -20,16 +20,17 @@ use uuid::Uuid;
 use auth::app::{App, AppDeps};
 use auth::application::port::{
     AccountActivation, AccountDirectory, AccountSnapshot, AuthnGrant, EventPublisher,
-    IdentityProvider, NormalizedClaims,
+    IdentityProvider, NormalizedClaims, SecondFactor, SecondFactorCheck,
 };
 use auth::application::SessionPolicy;
 use auth::domain::value_object::{AccountId, IdpSubject, Permission};
 use auth::error::AuthError;
-use auth::infrastructure::cache::RedisSessionCache;
+use auth::infrastructure::cache::{RedisMfaChallengeStore, RedisSessionCache};
 use auth::infrastructure::event::LogEventPublisher;
 use auth::infrastructure::grpc::handler::{proto, AuthServiceHandler};
 use auth::infrastructure::persistence::{
-    PgRefreshTokenRepository, PgSessionRepository, PgSubjectLinkRepository,
+    PgKnownDeviceRepository, PgRefreshTokenRepository, PgSessionRepository,
+    PgSubjectLinkRepository,
 };
 use auth::infrastructure::token::{Es256TokenMinter, EsKeyMaterial};
 
@@ -68,7 +69,7 @@ impl IdentityProvider for StubIdp {
 }
 
 /// `account` stub: provisions a stable account id per subject and reports every
-/// account active with a fixed permission set.
+/// account active with a fixed permission set and no MFA enrolment.
 struct StubDirectory {
     accounts: Mutex<HashMap<IdpSubject, AccountId>>,
 }
@@ -86,8 +87,17 @@ impl AccountDirectory for StubDirectory {
         Ok(AccountSnapshot {
             activation: AccountActivation::Active,
             permissions: vec![Permission::new("posts:write")],
+            mfa_enforced: false,
         })
     }
+
+    async fn verify_second_factor(
+        &self,
+        _account_id: &AccountId,
+        _factor: &SecondFactor,
+    ) -> Result<SecondFactorCheck, AuthError> {
+        Err(AuthError::SecondFactorNotEnrolled)
+    }
 }
 
 // ── Harness ──────────────────────────────────────────────────────────────────
@@ -138,10 +148,12 @@ impl Harness {
             directory: Arc::new(StubDirectory { accounts: Mutex::new(HashMap::new()) }),
             links: Arc::new(PgSubjectLinkRepository::new(tx.clone())),
             sessions: Arc::new(PgSessionRepository::new(tx.clone())),
+            known_devices: Arc::new(PgKnownDeviceRepository::new(tx.clone())),
             refresh_tokens: Arc::new(PgRefreshTokenRepository::new(tx.clone())),
             cache: Arc::new(RedisSessionCache::new(redis.clone())),
             minter: Arc::new(minter),
             publisher: Arc::new(LogEventPublisher) as Arc<dyn EventPublisher>,
+            challenges: Arc::new(RedisMfaChallengeStore::new(redis.clone())),
             policy: SessionPolicy::new(
                 ChronoDuration::minutes(10),
                 ChronoDuration::minutes(30),
@@ -156,8 +168,16 @@ impl Harness {
     // ── RPC helpers ──────────────────────────────────────────────────────────
 
     pub async fn login(&self, username: &str) -> Result<proto::LoginResponse, Status> {
+        self.login_from(username, None).await
+    }
+
+    pub async fn login_from(
+        &self,
+        username: &str,
+        device: Option<proto::DeviceContext>,
+    ) -> Result<proto::LoginResponse, Status> {
         let request = Request::new(proto::LoginRequest {
-            device: None,
+            device,
             grant_type: proto::GrantType::Password as i32,
             credential: Some(proto::login_request::Credential::Password(proto::PasswordGrant {
                 username: username.to_owned(),
@@ -168,9 +188,17 @@ impl Harness {
     }
 
     pub async fn refresh(&self, refresh_token: &str) -> Result<proto::RefreshResponse, Status> {
+        self.refresh_from(refresh_token, None).await
+    }
+
+    pub async fn refresh_from(
+        &self,
+        refresh_token: &str,
+        device: Option<proto::DeviceContext>,
+    ) -> Result<proto::RefreshResponse, Status> {
         let request = Request::new(proto::RefreshRequest {
             refresh_token: refresh_token.to_owned(),
-            device: None,
+            device,
         });
         self.handler.refresh(request).await.map(|r| r.into_inner())
     }
@@ -202,6 +230,20 @@ impl Harness {
         self.handler.list_sessions(request).await.map(|r| r.into_inner())
     }
 
+    /// `RevokeSession` as the holder of `access_token` (sent as the bearer).
+    pub async fn revoke_session(
+        &self,
+        access_token: &str,
+        session_id: &str,
+    ) -> Result<proto::RevokeSessionResponse, Status> {
+        let mut request =
+            Request::new(proto::RevokeSessionRequest { session_id: session_id.to_owned() });
+        request
+            .metadata_mut()
+            .insert("authorization", format!("Bearer {access_token}").parse().unwrap());
+        self.handler.revoke_session(request).await.map(|r| r.into_inner())
+    }
+
     // ── Direct DB assertions ─────────────────────────────────────────────────
 
     pub async fn count_active_sessions(&self, account_id: &str) -> i64 {
@@ -213,6 +255,15 @@ impl Harness {
             .expect("count active sessions")
     }
 
+    pub async fn count_known_devices(&self, account_id: &str) -> i64 {
+        let id = Uuid::parse_str(account_id).unwrap();
+        sqlx::query_scalar("SELECT COUNT(*) FROM known_devices WHERE account_id = $1")
+            .bind(id)
+            .fetch_one(&self.pool)
+            .await
+            .expect("count known devices")
+    }
+
     pub async fn count_subject_links(&self, account_id: &str) -> i64 {
         let id = Uuid::parse_str(account_id).unwrap();
         sqlx::query_scalar("SELECT COUNT(*) FROM subject_links WHERE account_id = $1")
@@ -223,6 +274,17 @@ impl Harness {
     }
 }
 
+/// A device context as the edge would forward it.
+pub fn device(device_id: &str, ip_address: &str, country: &str) -> proto::DeviceContext {
+    proto::DeviceContext {
+        user_agent: "it-agent/1.0".to_owned(),
+        ip_address: ip_address.to_owned(),
+        device_id: device_id.to_owned(),
+        country: country.to_owned(),
+        city: String::new(),
+    }
+}
+
 /// A fresh random username (⇒ a fresh subject ⇒ a fresh account).
 pub fn random_user() -> String {
     format!("user-{}", Uuid::now_v7())
@@ -0,0 +1,67 @@
+//! The device-management surface: devices remembered per account, last-seen
+//! context persisted on refresh, and targeted revocation of one session.
+
+use tonic::Code;
+
+use crate::auth_it::harness::{device, random_user, Harness};
+
+#[tokio::test]
+async fn devices_are_remembered_once_per_account() {
+    let h = Harness::start().await;
+    let user = random_user();
+    let first = h.login_from(&user, Some(device("dev-phone", "203.0.113.7", "FR"))).await.expect("login");
+    h.login_from(&user, Some(device("dev-phone", "203.0.113.8", "FR"))).await.expect("same device");
+    h.login_from(&user, Some(device("dev-laptop", "198.51.100.2", "DE"))).await.expect("new device");
+
+    assert_eq!(h.count_known_devices(&first.account_id).await, 2);
+}
+
+#[tokio::test]
+async fn refresh_persists_the_last_seen_context() {
+    let h = Harness::start().await;
+    let login = h
+        .login_from(&random_user(), Some(device("dev-phone", "203.0.113.7", "FR")))
+        .await
+        .expect("login");
+    let tokens = login.tokens.unwrap();
+
+    h.refresh_from(&tokens.refresh_token, Some(device("dev-phone", "198.51.100.9", "be")))
+        .await
+        .expect("refresh");
+
+    let listed = h.list_sessions(&login.account_id).await.expect("list");
+    let [session] = listed.sessions.as_slice() else { panic!("one session") };
+    let issued_on = session.device.as_ref().expect("device");
+    let last_seen = session.last_seen.as_ref().expect("last seen");
+    assert_eq!((issued_on.ip_address.as_str(), issued_on.country.as_str()), ("203.0.113.7", "FR"));
+    assert_eq!((last_seen.ip_address.as_str(), last_seen.country.as_str()), ("198.51.100.9", "BE"));
+    assert!(session.last_seen_at.is_some());
+
+    let [group] = listed.devices.as_slice() else { panic!("one device") };
+    assert_eq!(group.device_id, "dev-phone");
+    assert_eq!(group.session_ids, std::slice::from_ref(&session.session_id));
+}
+
+#[tokio::test]
+async fn revoke_session_ends_only_the_named_session_of_the_callers_account() {
+    let h = Harness::start().await;
+    let user = random_user();
+    let phone = h.login_from(&user, Some(device("dev-phone", "203.0.113.7", "FR"))).await.expect("phone");
+    let account_id = phone.account_id.clone();
+    let laptop = h.login_from(&user, Some(device("dev-laptop", "198.51.100.2", "FR"))).await.expect("laptop");
+    let (phone, laptop) = (phone.tokens.unwrap(), laptop.tokens.unwrap());
+
+    // Someone else's token cannot reach the account's sessions.
+    let other = h.login(&random_user()).await.expect("other").tokens.unwrap();
+    let status = h.revoke_session(&other.access_token, &phone.session_id).await.unwrap_err();
+    assert_eq!(status.code(), Code::NotFound);
+
+    let revoked = h.revoke_session(&laptop.access_token, &phone.session_id).await.expect("revoke");
+    assert!(revoked.success);
+    let remaining = h.list_sessions(&account_id).await.expect("list");
+    let ids: Vec<_> = remaining.sessions.iter().map(|s| s.session_id.as_str()).collect();
+    assert_eq!(ids, [laptop.session_id.as_str()]);
+
+    // The revoked session's refresh token is dead too.
+    assert!(h.refresh(&phone.refresh_token).await.is_err());
+}
@@ -1,7 +1,9 @@
 //! Live scenarios driving the real Postgres + Redis graph through the gRPC handler.
 
+pub mod device_sessions;
 pub mod global_logout;
 pub mod lifecycle;
 pub mod persistence_roundtrip;
 pub mod refresh_reuse;
+pub mod signing_key_rotation;
 pub mod outbox_relay;
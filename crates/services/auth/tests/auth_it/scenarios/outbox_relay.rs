@@ -8,9 +8,9 @@ use std::sync::{Arc, Mutex};
 use async_trait::async_trait;
 use auth::application::port::EventPublisher;
 use auth::domain::event::DomainEvent;
-use auth::error::AuthError;
-use auth::infrastructure::event::outbox_relay::OutboxRelay;
-use auth::infrastructure::event::pg_outbox_publisher::PgOutboxPublisher;
+use auth::infrastructure::event::{PgOutboxPublisher, OUTBOX_PREFIX};
+use outbox::{OutboxError, OutboxRecord, OutboxRelay, OutboxSink, OutboxTable, PgOutbox, RelayConfig};
+use postgres_storage::TransactionManager;
 use uuid::Uuid;
 
 use crate::auth_it::harness::Harness;
@@ -19,27 +19,27 @@ use crate::auth_it::harness::Harness;
 #[derive(Default)]
 struct FlakySink {
     down: AtomicBool,
-    seen: Mutex<Vec<String>>,
+    seen: Mutex<Vec<OutboxRecord>>,
 }
 
 #[async_trait]
-impl EventPublisher for FlakySink {
-    async fn publish(&self, event: &DomainEvent) -> Result<(), AuthError> {
+impl OutboxSink for FlakySink {
+    async fn publish(&self, record: &OutboxRecord) -> Result<(), OutboxError> {
         if self.down.load(Ordering::SeqCst) {
-            return Err(AuthError::EventPublishFailed("sink down".into()));
+            return Err(OutboxError::Publish("sink down".into()));
         }
-        self.seen.lock().unwrap().push(event.event_type().to_owned());
+        self.seen.lock().unwrap().push(record.clone());
         Ok(())
     }
 }
 
-fn sample_event() -> DomainEvent {
+fn sample_event(account: Uuid) -> DomainEvent {
     use auth::domain::event::SessionIssued;
     use auth::domain::value_object::{AccountId, Generation, IdpSubject, SessionId};
     let now = chrono::Utc::now();
     DomainEvent::SessionIssued(SessionIssued {
         session_id: SessionId::new(),
-        account_id: AccountId::from_uuid(Uuid::now_v7()),
+        account_id: AccountId::from_uuid(account),
         subject: IdpSubject::new("https://idp.test", "sub-1").expect("subject"),
         generation: Generation::from_i64(0),
         issued_at: now,
@@ -60,15 +60,21 @@ async fn pending(pool: &sqlx::PgPool) -> i64 {
 #[tokio::test]
 async fn outbox_survives_sink_outage_and_drains_in_order() {
     let h = Harness::start().await;
-    let outbox = PgOutboxPublisher::new(h.pool.clone());
+    let table = OutboxTable::new(OUTBOX_PREFIX).expect("table");
+    let outbox = PgOutboxPublisher::new(PgOutbox::new(
+        table.clone(),
+        TransactionManager::new(h.pool.clone()),
+    ));
     let sink = Arc::new(FlakySink::default());
-    let relay = OutboxRelay::new(h.pool.clone(), sink.clone());
+    let relay = OutboxRelay::new(h.pool.clone(), table, sink.clone(), RelayConfig::default());
 
     // Enqueue while the "broker" is down — the handler-visible publish must
-    // still succeed (that's the whole decoupling).
+    // still succeed (that's the whole decoupling). One account, so all three
+    // events share a key and must come out in enqueue order.
     sink.down.store(true, Ordering::SeqCst);
+    let account = Uuid::now_v7();
     for _ in 0..3 {
-        outbox.publish(&sample_event()).await.expect("enqueue must not depend on the sink");
+        outbox.publish(&sample_event(account)).await.expect("enqueue must not depend on the sink");
     }
     assert_eq!(pending(&h.pool).await, 3);
 
@@ -81,7 +87,13 @@ async fn outbox_survives_sink_outage_and_drains_in_order() {
     sink.down.store(false, Ordering::SeqCst);
     assert_eq!(relay.tick().await.expect("drain"), 3);
     assert_eq!(pending(&h.pool).await, 0, "published rows must be deleted");
-    assert_eq!(sink.seen.lock().unwrap().len(), 3);
+    let seen = sink.seen.lock().unwrap().clone();
+    assert_eq!(seen.len(), 3);
+    assert!(seen.windows(2).all(|w| w[0].id < w[1].id), "per-account order must hold");
+    let headers = seen[0].wire_headers();
+    assert_eq!(headers["event_type"], "auth.session_issued");
+    assert_eq!(headers["account_id"], account.to_string());
+    assert_eq!(seen[0].topic, "auth.v1.events");
 
     // Idempotent when empty.
     assert_eq!(relay.tick().await.expect("empty tick"), 0);
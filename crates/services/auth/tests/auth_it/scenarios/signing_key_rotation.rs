@@ -0,0 +1,96 @@
+//! Scheduled signing-key rotation against the real `signing_keys` table: the
+//! lifecycle walks published → active → retiring → retired, the partial unique
+//! indexes keep a racing replica from opening a second active key, and the
+//! stored private half is wrapped (then dropped on retirement).
+
+use std::sync::Arc;
+
+use auth::infrastructure::persistence::PgSigningKeyStore;
+use auth::infrastructure::token::{
+    Es256TokenMinter, EsKeyMaterial, KeyRotator, LocalKek, RotationPolicy,
+};
+use chrono::{Duration, Utc};
+use postgres_storage::TransactionManager;
+
+use crate::auth_it::harness::Harness;
+
+fn rotator(h: &Harness) -> KeyRotator {
+    KeyRotator::new(
+        Arc::new(PgSigningKeyStore::new(TransactionManager::new(h.pool.clone()))),
+        Arc::new(LocalKek::new([3u8; 32])),
+        RotationPolicy {
+            rotate_every: Duration::days(30),
+            publish_lead: Duration::days(1),
+            retire_after: Duration::minutes(15),
+            check_every: std::time::Duration::from_secs(60),
+        },
+    )
+}
+
+async fn statuses(h: &Harness) -> Vec<String> {
+    sqlx::query_scalar("SELECT status FROM signing_keys ORDER BY created_at, kid")
+        .fetch_all(&h.pool)
+        .await
+        .expect("signing key statuses")
+}
+
+// One test on purpose: the ring is service-global, so concurrent scenarios on
+// the shared database would step on each other.
+#[tokio::test]
+async fn signing_key_walks_the_full_lifecycle() {
+    let h = Harness::start().await;
+    sqlx::query("DELETE FROM signing_keys").execute(&h.pool).await.unwrap();
+    let t0 = Utc::now();
+
+    // Two replicas bootstrap against an empty table: one active key results.
+    let (a, b) = (rotator(&h), rotator(&h));
+    let (ring_a, ring_b) =
+        tokio::join!(a.bootstrap(None, Vec::new(), t0), b.bootstrap(None, Vec::new(), t0));
+    let ring = ring_a.expect("bootstrap a");
+    assert_eq!(ring.active.key_id, ring_b.expect("bootstrap b").active.key_id);
+    assert_eq!(statuses(&h).await, vec!["active"]);
+
+    let wrapped: Vec<u8> = sqlx::query_scalar("SELECT private_pem_enc FROM signing_keys")
+        .fetch_one(&h.pool)
+        .await
+        .unwrap();
+    assert!(!wrapped.windows(11).any(|w| w == b"PRIVATE KEY"));
+
+    let first_kid = ring.active.key_id.clone();
+    let minter = Es256TokenMinter::from_key_ring(
+        EsKeyMaterial {
+            private_pem: ring.active.private_pem,
+            public_pem: ring.active.public_pem,
+            key_id: ring.active.key_id,
+            issuer: "https://auth.core-platform".to_owned(),
+            audience: "core-platform".to_owned(),
+        },
+        ring.others,
+    )
+    .unwrap();
+
+    // Publish inside the lead; both replicas try, one row results.
+    let published_at = t0 + Duration::days(29) + Duration::hours(1);
+    a.tick(published_at).await.unwrap();
+    b.tick(published_at).await.unwrap();
+    assert_eq!(statuses(&h).await, vec!["active", "published"]);
+
+    // Promote after a full lead; the minter follows without a restart.
+    let promoted_at = published_at + Duration::days(1);
+    a.sync(&minter, promoted_at).await.unwrap();
+    assert_eq!(statuses(&h).await, vec!["retiring", "active"]);
+    assert_ne!(minter.active_kid(), first_kid);
+    assert_eq!(minter.jwks().unwrap().keys.len(), 2);
+
+    // Retire once the last token the old key signed has expired.
+    a.sync(&minter, promoted_at + Duration::minutes(15)).await.unwrap();
+    assert_eq!(statuses(&h).await, vec!["retired", "active"]);
+    let retired_private: Option<Vec<u8>> =
+        sqlx::query_scalar("SELECT private_pem_enc FROM signing_keys WHERE kid = $1")
+            .bind(&first_kid)
+            .fetch_one(&h.pool)
+            .await
+            .unwrap();
+    assert!(retired_private.is_none());
+    assert_eq!(minter.jwks().unwrap().keys.len(), 1);
+}
@@ -10,11 +10,14 @@ use std::collections::HashMap;
 
 use auth::application::SessionPolicy;
 use auth::application::port::TokenMinter;
-use auth::domain::value_object::{AccessTokenClaims, AccountId, Generation, Permission, SessionId};
+use auth::domain::value_object::{
+    AccessTokenClaims, AccountId, AuthMethod, Generation, Permission, SessionId,
+};
 use auth::infrastructure::token::{Es256TokenMinter, EsKeyMaterial};
 
 use auth_context::{
-    AuthContextConfig, ClaimsExtractor, CurrentPrincipal, JwksCache, JwtDecoder,
+    AuthContextConfig, ClaimsExtractor, CurrentPrincipal, JwksCache, JwtDecoder, OidcClaims,
+    OidcClaimsExtractor,
 };
 use chrono::{Duration, Utc};
 use jsonwebtoken::{Algorithm, DecodingKey};
@@ -72,11 +75,16 @@ fn minter(private_pem: &[u8], public_pem: &[u8], audience: &str) -> Es256TokenMi
     .unwrap()
 }
 
-async fn decoder(public_pem: &[u8]) -> JwtDecoder<EdgeClaims, EdgeExtractor> {
+async fn key_cache(public_pem: &[u8]) -> JwksCache {
     let cache = JwksCache::new();
     let mut keys = HashMap::new();
     keys.insert(KID.to_owned(), DecodingKey::from_ec_pem(public_pem).unwrap());
     cache.replace(keys).await;
+    cache
+}
+
+async fn decoder(public_pem: &[u8]) -> JwtDecoder<EdgeClaims, EdgeExtractor> {
+    let cache = key_cache(public_pem).await;
 
     let config = AuthContextConfig {
         expected_issuer: Some(ISSUER.to_owned()),
@@ -104,6 +112,8 @@ async fn minted_edge_token_is_verified_by_auth_context() {
         permissions: vec![Permission::new("posts:write"), Permission::new("ROLE_ADMIN")],
         issued_at: Utc::now(),
         expires_at: Utc::now() + Duration::minutes(10),
+        methods: vec![AuthMethod::Password],
+        auth_time: Utc::now(),
     };
 
     let (private_pem, public_pem) = keypair();
@@ -130,7 +140,50 @@ async fn auth_context_rejects_a_token_for_a_different_audience() {
         permissions: vec![],
         issued_at: Utc::now(),
         expires_at: Utc::now() + Duration::minutes(10),
+        methods: vec![AuthMethod::Password],
+        auth_time: Utc::now(),
     };
     let token = other.mint_access(&claims).await.unwrap();
     assert!(decoder(&public_pem).await.decode(&token).await.is_err());
 }
+
+#[tokio::test]
+async fn stepped_up_token_satisfies_the_runtime_step_up_check() {
+    // The service runtime's AuthLayer decodes edge tokens as `OidcClaims` and
+    // asks `authenticated_within(STEP_UP_ACR, ..)` for step-up rules.
+    let now = Utc::now();
+    let (private_pem, public_pem) = keypair();
+    let minter = minter(&private_pem, &public_pem, AUDIENCE);
+    let config = AuthContextConfig {
+        expected_issuer: Some(ISSUER.to_owned()),
+        expected_audience: Some(AUDIENCE.to_owned()),
+        ..AuthContextConfig::default()
+    };
+    let oidc = JwtDecoder::with_algorithms(
+        &config,
+        key_cache(&public_pem).await,
+        OidcClaimsExtractor::default(),
+        vec![Algorithm::ES256],
+    );
+
+    let mut claims = AccessTokenClaims {
+        account_id: AccountId::from_uuid(Uuid::now_v7()),
+        session_id: SessionId::new(),
+        generation: Generation::INITIAL,
+        permissions: vec![],
+        issued_at: now,
+        expires_at: now + Duration::minutes(10),
+        methods: vec![AuthMethod::Password, AuthMethod::Otp],
+        auth_time: now - Duration::minutes(2),
+    };
+    let principal = oidc.decode(&minter.mint_access(&claims).await.unwrap()).await.unwrap();
+    let raw: &OidcClaims = &principal.raw_claims;
+    assert_eq!(raw.amr.as_deref(), Some(&["pwd".to_owned(), "otp".to_owned(), "mfa".to_owned()][..]));
+    assert!(raw.authenticated_within(service_runtime::STEP_UP_ACR, 300, now.timestamp()));
+
+    // A single-factor session never passes, however fresh.
+    claims.methods = vec![AuthMethod::Password];
+    claims.auth_time = now;
+    let principal = oidc.decode(&minter.mint_access(&claims).await.unwrap()).await.unwrap();
+    assert!(!principal.raw_claims.authenticated_within(service_runtime::STEP_UP_ACR, 300, now.timestamp()));
+}
@@ -20,6 +20,8 @@
 //! - **global_logout** — a generation bump invalidates every session's token.
 //! - **persistence_roundtrip** — sessions, refresh-token lineage, and the subject
 //!   link are durably written.
+//! - **signing_key_rotation** — the signing key is published, promoted and
+//!   retired in `signing_keys`; racing replicas settle on one winner.
 #![cfg(feature = "integration-auth")]
 
 mod auth_it;
@@ -6,40 +6,48 @@
 //! live integration harness drive the exact same assembly, so the suite tests the
 //! graph that ships rather than a parallel re-wiring.
 //!
-//! The event-publisher choice is *derived*, not injected: when [`Backends::kafka`]
-//! is `Some`, the durable [`KafkaEventPublisher`] is used and the per-pod
-//! [`VisibilityWorker`] is spawned; when it is `None`, the in-process
-//! [`LogEventPublisher`] is used and no broker is required. This is what lets the
-//! integration scenarios that don't exercise Kafka boot without one.
+//! The repositories write every domain event into `chat.outbox` in the same
+//! logged batch as the rows that produced it. Whether those rows are relayed is
+//! *derived*, not injected: when [`Backends::kafka`] is `Some`, a relayed
+//! [`ChatOutbox`] is used and its outbox relay and the per-pod
+//! [`VisibilityWorker`] are spawned; when it is `None`, the outbox only logs the
+//! events and no broker is required. This is what lets the integration scenarios
+//! that don't exercise Kafka boot without one.
 
 use std::sync::Arc;
 
 use cqrs::command::{CommandBusBuilder, InMemoryCommandBus};
+use cqrs::middleware::{
+    IdempotencyCommandBus, IdempotencyLayer, MetricsCommandBus, MetricsLayer,
+    MetricsQueryBus, MiddlewarePipeline,
+};
 use cqrs::query::{InMemoryQueryBus, QueryBusBuilder};
+use idempotency::RedisIdempotencyStore;
 use redis_storage::{RedisClient, RedisClientBuilder, RedisConfig, RedisSubscriberBuilder};
+use outbox::{KafkaOutboxSink, RelayConfig, ScyllaOutbox, ScyllaOutboxRelay, ScyllaOutboxTable};
 use scylla_storage::{ScyllaClient, ScyllaConfig, ScyllaSessionBuilder};
 use transport::kafka::config::client::KafkaClientConfig;
 use transport::kafka::config::producer::ProducerConfig;
 use transport::kafka::producer::KafkaProducerBuilder;
 
 use crate::application::command::{
-    CreateConversationCommand, CreateConversationHandler, JoinAsMemberCommand, JoinAsMemberHandler,
-    MarkReadCommand, MarkReadHandler, SendMessageCommand, SendMessageHandler, SubscribeCommand,
-    SubscribeHandler, ToggleVisibilityCommand, ToggleVisibilityHandler, UnsubscribeCommand,
-    UnsubscribeHandler,
+    CreateConversationCommand, CreateConversationHandler, EraseSubjectDataCommand,
+    EraseSubjectDataHandler, JoinAsMemberCommand, JoinAsMemberHandler, MarkReadCommand,
+    MarkReadHandler, SendMessageCommand, SendMessageHandler, SubscribeCommand, SubscribeHandler,
+    ToggleVisibilityCommand, ToggleVisibilityHandler, UnsubscribeCommand, UnsubscribeHandler,
 };
 use crate::application::port::{
-    ConversationRepository, EventPublisher, HotTailCache, MemberRepository, PresenceStore,
+    ConversationRepository, HotTailCache, MemberRepository, PresenceStore,
     ReceiptStore, RoutingRegistry,
 };
 use crate::application::query::{
-    GetHistoryHandler, GetHistoryQuery, ListMembersHandler, ListMembersQuery,
+    ExportSubjectDataHandler, ExportSubjectDataQuery, GetHistoryHandler, GetHistoryQuery, ListMembersHandler, ListMembersQuery,
     ListSubscriptionsHandler, ListSubscriptionsQuery,
 };
 use crate::infrastructure::cache::{
     RedisHotTailCache, RedisPresenceStore, RedisReceiptStore, RedisRoutingRegistry,
 };
-use crate::infrastructure::event::{KafkaEventPublisher, LogEventPublisher};
+use crate::infrastructure::event::{ChatOutbox, OUTBOX_KEYSPACE};
 use crate::infrastructure::grpc::handler::chat_handler::StreamingParams;
 use crate::infrastructure::grpc::handler::ChatServiceHandler;
 use crate::infrastructure::persistence::{
@@ -54,14 +62,26 @@ use crate::infrastructure::worker::VisibilityWorker;
 
 /// Storage/transport endpoints the graph is wired against.
 ///
-/// `kafka` is optional: its presence selects the durable publisher and enables
-/// the [`VisibilityWorker`]; its absence selects the in-process log publisher.
+/// `kafka` is optional: its presence has the outbox enqueue events and enables
+/// its relay and the [`VisibilityWorker`]; its absence has the outbox log them.
 pub struct Backends {
     pub scylla: ScyllaConfig,
     pub redis:  RedisConfig,
     pub kafka:  Option<KafkaClientConfig>,
 }
 
+/// The command bus every entrypoint dispatches through: the registered handlers
+/// behind an [`IdempotencyLayer`] on the service's Redis, so a retried `message_id`
+/// runs once across replicas. It fails open: a Redis outage costs deduplication,
+/// not the write path.
+/// A [`MetricsLayer`] outermost times every dispatch, replays included.
+pub type AppCommandBus =
+    MetricsCommandBus<IdempotencyCommandBus<InMemoryCommandBus, RedisIdempotencyStore>>;
+
+/// The query bus every read path dispatches through: the registered handlers,
+/// timed by a [`MetricsLayer`].
+pub type AppQueryBus = MetricsQueryBus<InMemoryQueryBus>;
+
 /// The tuning surface threaded through the graph. Production fills this from
 /// [`ChatConfig`](crate::config::ChatConfig); integration scenarios shrink the
 /// buffers/TTLs to make overflow and liveness assertions complete in seconds.
@@ -87,7 +107,10 @@ pub struct AppConfig {
 /// handles a test asserts against. The handler holds the *same* `Arc`s exposed
 /// here, so a scenario reads the live state the handler mutates.
 pub struct App {
-    pub handler:           ChatServiceHandler<InMemoryCommandBus, InMemoryQueryBus>,
+    pub handler:           ChatServiceHandler<Arc<AppCommandBus>, AppQueryBus>,
+    /// The bus behind `handler`, shared with the subject-erasure consumer that
+    /// [`crate::service`] spawns.
+    pub command_bus:       Arc<AppCommandBus>,
     /// Live storage clients, retained so the runtime's readiness loop can probe
     /// their liveness (see [`crate::service`]).
     pub scylla:            Arc<ScyllaClient>,
@@ -104,7 +127,7 @@ impl App {
     /// Builds storage clients from `backends`, assembles the repositories, cache,
     /// routing, CQRS buses, and streaming registries, and spawns the per-pod
     /// background tasks (plane subscriber, registry reapers, and — when Kafka is
-    /// configured — the visibility worker).
+    /// configured — the outbox relay and the visibility worker).
     pub async fn build(
         config:   &AppConfig,
         backends: Backends,
@@ -116,14 +139,38 @@ impl App {
         let redis_client = RedisClientBuilder::new(redis.clone()).build().await?;
         let redis_subscriber = RedisSubscriberBuilder::new(redis).build().await?;
 
+        // ── Outbox (relayed when Kafka is configured) ───────────────────────
+        let table = ScyllaOutboxTable::new(OUTBOX_KEYSPACE)?;
+        let store = ScyllaOutbox::new(Arc::clone(&scylla_client), table.clone());
+        let outbox = match &kafka {
+            Some(cfg) => {
+                // The repositories enqueue into chat.outbox; the relay forwards to
+                // the broker. Leased slots make one relay per pod safe.
+                let producer = KafkaProducerBuilder::new(ProducerConfig::new(cfg.clone())).build()?;
+                let relay = ScyllaOutboxRelay::new(
+                    Arc::clone(&scylla_client),
+                    table,
+                    Arc::new(KafkaOutboxSink::new(producer)),
+                    RelayConfig::from_env(),
+                );
+                tokio::spawn(relay.run());
+                ChatOutbox::relayed(store)
+            }
+            None => ChatOutbox::log_only(store),
+        };
+
         // ── Repositories ─────────────────────────────────────────────────────
-        let conversation_repo =
-            Arc::new(ScyllaConversationRepository::new(Arc::clone(&scylla_client)));
+        let conversation_repo = Arc::new(ScyllaConversationRepository::new(
+            Arc::clone(&scylla_client),
+            outbox.clone(),
+        ));
         let message_repo = Arc::new(ScyllaMessageRepository::new(
             Arc::clone(&scylla_client),
+            outbox.clone(),
             config.message_bucket_hours,
         ));
-        let member_repo = Arc::new(ScyllaMemberRepository::new(Arc::clone(&scylla_client)));
+        let member_repo =
+            Arc::new(ScyllaMemberRepository::new(Arc::clone(&scylla_client), outbox));
         let subscription_repo =
             Arc::new(ScyllaSubscriptionRepository::new(Arc::clone(&scylla_client)));
 
@@ -155,42 +202,46 @@ impl App {
             config.audience_ttl_secs,
         ));
 
-        // ── CQRS buses (publisher derived from Kafka presence) ───────────────
-        let command_bus = match &kafka {
-            Some(cfg) => {
-                let producer = KafkaProducerBuilder::new(ProducerConfig::new(cfg.clone())).build()?;
-                build_command_bus(
-                    Arc::new(KafkaEventPublisher::new(producer)),
-                    &conversation_repo,
-                    &message_repo,
-                    &member_repo,
-                    &subscription_repo,
-                )?
-            }
-            None => build_command_bus(
-                Arc::new(LogEventPublisher),
-                &conversation_repo,
-                &message_repo,
-                &member_repo,
-                &subscription_repo,
-            )?,
-        };
+        // ── CQRS buses ───────────────────────────────────────────────────────
+        let handlers = build_command_bus(
+            &conversation_repo,
+            &message_repo,
+            &member_repo,
+            &subscription_repo,
+            &hot_tail,
+        )?;
+        let command_bus = Arc::new(MiddlewarePipeline::new(handlers)
+            .layer(
+                IdempotencyLayer::new(RedisIdempotencyStore::new(redis_client.clone(), "chat"))
+                    .fail_open(),
+            )
+            .layer(MetricsLayer::new())
+            .build());
 
-        let query_bus = QueryBusBuilder::new()
-            .register::<GetHistoryQuery, _>(GetHistoryHandler {
-                conversation_repo: Arc::clone(&conversation_repo),
-                member_repo:       Arc::clone(&member_repo),
-                message_repo:      Arc::clone(&message_repo),
-                max_page_size:     config.max_page_size,
-            })?
-            .register::<ListMembersQuery, _>(ListMembersHandler {
-                member_repo: Arc::clone(&member_repo),
-            })?
-            .register::<ListSubscriptionsQuery, _>(ListSubscriptionsHandler {
-                subscription_repo: Arc::clone(&subscription_repo),
-                max_page_size:     config.max_page_size,
-            })?
-            .build();
+        let query_bus = MiddlewarePipeline::new(
+            QueryBusBuilder::new()
+                .register::<GetHistoryQuery, _>(GetHistoryHandler {
+                    conversation_repo: Arc::clone(&conversation_repo),
+                    member_repo:       Arc::clone(&member_repo),
+                    message_repo:      Arc::clone(&message_repo),
+                    max_page_size:     config.max_page_size,
+                })?
+                .register::<ListMembersQuery, _>(ListMembersHandler {
+                    member_repo: Arc::clone(&member_repo),
+                })?
+                .register::<ListSubscriptionsQuery, _>(ListSubscriptionsHandler {
+                    subscription_repo: Arc::clone(&subscription_repo),
+                    max_page_size:     config.max_page_size,
+                })?
+                .register::<ExportSubjectDataQuery, _>(ExportSubjectDataHandler {
+                    member_repo:       Arc::clone(&member_repo),
+                    message_repo:      Arc::clone(&message_repo),
+                    subscription_repo: Arc::clone(&subscription_repo),
+                })?
+                .build(),
+        )
+        .query_layer(MetricsLayer::new())
+        .build();
 
         // ── VisibilityWorker (Kafka path): cluster-wide Audience-Plane teardown
         if let Some(cfg) = &kafka {
@@ -215,7 +266,7 @@ impl App {
         };
 
         let handler = ChatServiceHandler::new(
-            command_bus,
+            Arc::clone(&command_bus),
             query_bus,
             Arc::clone(&fanout) as Arc<dyn Fanout>,
             Arc::clone(&plane_subscriber) as Arc<dyn PlaneAttach>,
@@ -231,6 +282,7 @@ impl App {
 
         Ok(Self {
             handler,
+            command_bus,
             scylla: scylla_client,
             redis: redis_client,
             presence: presence as Arc<dyn PresenceStore>,
@@ -243,35 +295,29 @@ impl App {
     }
 }
 
-/// Builds the command bus generically over the event publisher so the same wiring
-/// serves both the durable Kafka-backed run and the log-backed run.
-fn build_command_bus<EP: EventPublisher>(
-    publisher:         Arc<EP>,
+/// Registers every command handler over the shared repositories.
+fn build_command_bus(
     conversation_repo: &Arc<ScyllaConversationRepository>,
     message_repo:      &Arc<ScyllaMessageRepository>,
     member_repo:       &Arc<ScyllaMemberRepository>,
     subscription_repo: &Arc<ScyllaSubscriptionRepository>,
+    hot_tail:          &Arc<RedisHotTailCache>,
 ) -> Result<InMemoryCommandBus, Box<dyn std::error::Error>> {
     Ok(CommandBusBuilder::new()
         .register::<CreateConversationCommand, _>(CreateConversationHandler {
             conversation_repo: Arc::clone(conversation_repo),
-            member_repo:       Arc::clone(member_repo),
-            publisher:         Arc::clone(&publisher),
         })?
         .register::<SendMessageCommand, _>(SendMessageHandler {
             member_repo:  Arc::clone(member_repo),
             message_repo: Arc::clone(message_repo),
-            publisher:    Arc::clone(&publisher),
         })?
         .register::<ToggleVisibilityCommand, _>(ToggleVisibilityHandler {
             conversation_repo: Arc::clone(conversation_repo),
             member_repo:       Arc::clone(member_repo),
-            publisher:         Arc::clone(&publisher),
         })?
         .register::<JoinAsMemberCommand, _>(JoinAsMemberHandler {
             conversation_repo: Arc::clone(conversation_repo),
             member_repo:       Arc::clone(member_repo),
-            publisher:         Arc::clone(&publisher),
         })?
         .register::<SubscribeCommand, _>(SubscribeHandler {
             conversation_repo: Arc::clone(conversation_repo),
@@ -281,5 +327,12 @@ fn build_command_bus<EP: EventPublisher>(
             subscription_repo: Arc::clone(subscription_repo),
         })?
         .register::<MarkReadCommand, _>(MarkReadHandler { member_repo: Arc::clone(member_repo) })?
+        .register::<EraseSubjectDataCommand, _>(EraseSubjectDataHandler {
+            conversation_repo: Arc::clone(conversation_repo),
+            member_repo:       Arc::clone(member_repo),
+            message_repo:      Arc::clone(message_repo),
+            subscription_repo: Arc::clone(subscription_repo),
+            hot_tail:          Arc::clone(hot_tail),
+        })?
         .build())
 }
@@ -3,7 +3,7 @@ use std::sync::Arc;
 use cqrs::{Command, CommandHandler, Envelope};
 use validate_core::{FieldViolation, Validate};
 
-use crate::application::port::{ConversationRepository, EventPublisher, MemberRepository};
+use crate::application::port::ConversationRepository;
 use crate::domain::aggregate::{Conversation, Participant};
 use crate::domain::value_object::{ConversationId, ConversationKind, ProfileId, Role};
 use crate::error::ChatError;
@@ -20,7 +20,9 @@ pub struct CreateConversationCommand {
     pub owner_id:        String,
 }
 
-impl Command for CreateConversationCommand {}
+impl Command for CreateConversationCommand {
+    type Output = ();
+}
 
 impl Validate for CreateConversationCommand {
     fn validate(&self) -> Result<(), Vec<FieldViolation>> {
@@ -39,18 +41,13 @@ impl Validate for CreateConversationCommand {
     }
 }
 
-pub struct CreateConversationHandler<CR, MR, EP> {
+pub struct CreateConversationHandler<CR> {
     pub conversation_repo: Arc<CR>,
-    pub member_repo:       Arc<MR>,
-    pub publisher:         Arc<EP>,
 }
 
-impl<CR, MR, EP> CommandHandler<CreateConversationCommand>
-    for CreateConversationHandler<CR, MR, EP>
+impl<CR> CommandHandler<CreateConversationCommand> for CreateConversationHandler<CR>
 where
     CR: ConversationRepository,
-    MR: MemberRepository,
-    EP: EventPublisher,
 {
     type Error = ChatError;
 
@@ -64,17 +61,13 @@ where
         let owner_id = ProfileId::try_from(cmd.owner_id.as_str())?;
         let kind     = ConversationKind::try_from(cmd.kind as i8)?;
 
-        let conversation = Conversation::create(id, kind, owner_id);
-        let owner        = Participant::new(owner_id, Role::Owner)?;
-
-        // Durable state first: the aggregate row, then the owner roster row.
-        self.conversation_repo.insert(&conversation).await?;
-        self.member_repo.insert(&id, &owner).await?;
+        let mut conversation = Conversation::create(id, kind, owner_id);
+        let owner            = Participant::new(owner_id, Role::Owner)?;
 
-        let mut conversation = conversation;
-        for event in conversation.take_events() {
-            self.publisher.publish_conversation(&event).await?;
-        }
+        // The aggregate row, the owner roster row and `ConversationCreated` are
+        // one batch.
+        let events = conversation.take_events();
+        self.conversation_repo.insert(&conversation, &owner, &events).await?;
 
         Ok(())
     }
@@ -0,0 +1,155 @@
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use cqrs::{Command, CommandHandler, Envelope};
+use uuid::Uuid;
+use validate_core::Validate;
+
+use crate::application::port::{
+    ConversationRepository, HotTailCache, MemberRepository, MessageRepository,
+    SubscriptionRepository,
+};
+use crate::domain::value_object::{ConversationId, ProfileId};
+use crate::error::ChatError;
+
+/// Page size used for every walk during an erasure.
+const ERASURE_PAGE_SIZE: i32 = 100;
+
+/// GDPR erasure of the subject's chat footprint, driven by account's
+/// `gdpr_deletion_requested`:
+///
+/// - every message their profiles sent is scrubbed in the log (body and media
+///   reference blanked, the row kept so replies still resolve), the touched hot
+///   tails are dropped, and the sender mirror is deleted;
+/// - their profiles leave every conversation they are a member of, through
+///   [`Conversation::release_member`](crate::domain::aggregate::Conversation::release_member)
+///   so the member count and `MemberLeft` stay right. An owner cannot leave, so
+///   an owned conversation keeps the (pseudonymised) owner on its roster;
+/// - every Audience-Plane subscription is removed.
+///
+/// The mirror is deleted last, so a failed run is redelivered with the message
+/// list intact; every other step is idempotent.
+pub struct EraseSubjectDataCommand {
+    pub profile_ids: Vec<String>,
+}
+
+impl Command for EraseSubjectDataCommand {
+    type Output = ();
+}
+
+impl Validate for EraseSubjectDataCommand {}
+
+pub struct EraseSubjectDataHandler<CR, MR, MSG, SR, HT> {
+    pub conversation_repo: Arc<CR>,
+    pub member_repo:       Arc<MR>,
+    pub message_repo:      Arc<MSG>,
+    pub subscription_repo: Arc<SR>,
+    pub hot_tail:          Arc<HT>,
+}
+
+impl<CR, MR, MSG, SR, HT> EraseSubjectDataHandler<CR, MR, MSG, SR, HT>
+where
+    CR:  ConversationRepository,
+    MR:  MemberRepository,
+    MSG: MessageRepository,
+    SR:  SubscriptionRepository,
+    HT:  HotTailCache,
+{
+    async fn scrub_messages(&self, sender_id: &ProfileId) -> Result<(), ChatError> {
+        let mut touched: HashSet<Uuid> = HashSet::new();
+        let mut cursor: Option<(i64, Uuid)> = None;
+        loop {
+            let (page, next) = self
+                .message_repo
+                .list_by_sender(sender_id, ERASURE_PAGE_SIZE, cursor)
+                .await?;
+            for sent in page {
+                let conversation_id = ConversationId::from_uuid(sent.conversation_id);
+                self.message_repo
+                    .scrub(&conversation_id, sent.message.message_id, sent.message.created_at)
+                    .await?;
+                touched.insert(sent.conversation_id);
+            }
+            match next {
+                Some(c) => cursor = Some(c),
+                None => break,
+            }
+        }
+
+        // The tail is only a read offload; a stale one is dropped, not patched.
+        for conversation_id in touched {
+            let _ = self.hot_tail.invalidate(&ConversationId::from_uuid(conversation_id)).await;
+        }
+        self.message_repo.delete_sent(sender_id).await
+    }
+
+    async fn leave_conversations(&self, member_id: &ProfileId) -> Result<(), ChatError> {
+        let mut cursor: Option<Uuid> = None;
+        loop {
+            let (page, next) = self
+                .member_repo
+                .list_by_member(member_id, ERASURE_PAGE_SIZE, cursor)
+                .await?;
+            for membership in page {
+                let conversation_id = membership.conversation_id;
+                let Some(mut conversation) = self.conversation_repo.find(&conversation_id).await?
+                else {
+                    // Dangling reverse-index row: only the roster entry is left.
+                    self.member_repo.delete(&conversation_id, member_id).await?;
+                    continue;
+                };
+                if conversation.owner_id() == *member_id {
+                    continue;
+                }
+                conversation.release_member(*member_id)?;
+                let events = conversation.take_events();
+                self.conversation_repo.release(&conversation, member_id, &events).await?;
+            }
+            match next {
+                Some(c) => cursor = Some(c),
+                None => break,
+            }
+        }
+        Ok(())
+    }
+
+    async fn unsubscribe_all(&self, subscriber_id: &ProfileId) -> Result<(), ChatError> {
+        let mut cursor: Option<Uuid> = None;
+        loop {
+            let (ids, next) = self
+                .subscription_repo
+                .list_by_user(subscriber_id, ERASURE_PAGE_SIZE, cursor)
+                .await?;
+            for conversation_id in ids {
+                self.subscription_repo.unsubscribe(&conversation_id, subscriber_id).await?;
+            }
+            match next {
+                Some(c) => cursor = Some(c),
+                None => break,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<CR, MR, MSG, SR, HT> CommandHandler<EraseSubjectDataCommand>
+    for EraseSubjectDataHandler<CR, MR, MSG, SR, HT>
+where
+    CR:  ConversationRepository,
+    MR:  MemberRepository,
+    MSG: MessageRepository,
+    SR:  SubscriptionRepository,
+    HT:  HotTailCache,
+{
+    type Error = ChatError;
+
+    async fn handle(&self, envelope: Envelope<EraseSubjectDataCommand>) -> Result<(), ChatError> {
+        for raw in &envelope.payload.profile_ids {
+            let profile_id = ProfileId::try_from(raw.as_str())?;
+            self.scrub_messages(&profile_id).await?;
+            self.leave_conversations(&profile_id).await?;
+            self.unsubscribe_all(&profile_id).await?;
+        }
+        Ok(())
+    }
+}
@@ -3,7 +3,7 @@ use std::sync::Arc;
 use cqrs::{Command, CommandHandler, Envelope};
 use validate_core::{FieldViolation, Validate};
 
-use crate::application::port::{ConversationRepository, EventPublisher, MemberRepository};
+use crate::application::port::{ConversationRepository, MemberRepository};
 use crate::domain::aggregate::Participant;
 use crate::domain::value_object::{ConversationId, ProfileId, Role};
 use crate::error::ChatError;
@@ -17,7 +17,9 @@ pub struct JoinAsMemberCommand {
     pub profile_id:      String,
 }
 
-impl Command for JoinAsMemberCommand {}
+impl Command for JoinAsMemberCommand {
+    type Output = ();
+}
 
 impl Validate for JoinAsMemberCommand {
     fn validate(&self) -> Result<(), Vec<FieldViolation>> {
@@ -36,17 +38,15 @@ impl Validate for JoinAsMemberCommand {
     }
 }
 
-pub struct JoinAsMemberHandler<CR, MR, EP> {
+pub struct JoinAsMemberHandler<CR, MR> {
     pub conversation_repo: Arc<CR>,
     pub member_repo:       Arc<MR>,
-    pub publisher:         Arc<EP>,
 }
 
-impl<CR, MR, EP> CommandHandler<JoinAsMemberCommand> for JoinAsMemberHandler<CR, MR, EP>
+impl<CR, MR> CommandHandler<JoinAsMemberCommand> for JoinAsMemberHandler<CR, MR>
 where
     CR: ConversationRepository,
     MR: MemberRepository,
-    EP: EventPublisher,
 {
     type Error = ChatError;
 
@@ -75,12 +75,8 @@ where
         conversation.admit_member(profile_id, Role::Member)?;
         let participant = Participant::new(profile_id, Role::Member)?;
 
-        self.member_repo.insert(&conversation_id, &participant).await?;
-        self.conversation_repo.update(&conversation).await?;
-
-        for event in conversation.take_events() {
-            self.publisher.publish_conversation(&event).await?;
-        }
+        let events = conversation.take_events();
+        self.conversation_repo.admit(&conversation, &participant, &events).await?;
 
         Ok(())
     }
@@ -18,7 +18,9 @@ pub struct MarkReadCommand {
     pub message_id:      String,
 }
 
-impl Command for MarkReadCommand {}
+impl Command for MarkReadCommand {
+    type Output = ();
+}
 
 impl Validate for MarkReadCommand {
     fn validate(&self) -> Result<(), Vec<FieldViolation>> {
@@ -1,4 +1,5 @@
 pub mod create_conversation;
+pub mod erase_subject_data;
 pub mod join_as_member;
 pub mod mark_read;
 pub mod send_message;
@@ -6,6 +7,7 @@ pub mod subscribe;
 pub mod toggle_visibility;
 
 pub use create_conversation::{CreateConversationCommand, CreateConversationHandler};
+pub use erase_subject_data::{EraseSubjectDataCommand, EraseSubjectDataHandler};
 pub use join_as_member::{JoinAsMemberCommand, JoinAsMemberHandler};
 pub use mark_read::{MarkReadCommand, MarkReadHandler};
 pub use send_message::{SendMessageCommand, SendMessageHandler};
@@ -3,7 +3,7 @@ use std::sync::Arc;
 use cqrs::{Command, CommandHandler, Envelope};
 use validate_core::{FieldViolation, Validate};
 
-use crate::application::port::{EventPublisher, MemberRepository, MessageRepository};
+use crate::application::port::{MemberRepository, MessageRepository};
 use crate::domain::aggregate::Message;
 use crate::domain::event::{MessageEvent, MessageSentEvent};
 use crate::domain::value_object::{
@@ -27,7 +27,9 @@ pub struct SendMessageCommand {
     pub reply_to:        Option<String>,
 }
 
-impl Command for SendMessageCommand {}
+impl Command for SendMessageCommand {
+    type Output = ();
+}
 
 impl Validate for SendMessageCommand {
     fn validate(&self) -> Result<(), Vec<FieldViolation>> {
@@ -49,17 +51,15 @@ impl Validate for SendMessageCommand {
     }
 }
 
-pub struct SendMessageHandler<MR, MSG, EP> {
+pub struct SendMessageHandler<MR, MSG> {
     pub member_repo:  Arc<MR>,
     pub message_repo: Arc<MSG>,
-    pub publisher:    Arc<EP>,
 }
 
-impl<MR, MSG, EP> CommandHandler<SendMessageCommand> for SendMessageHandler<MR, MSG, EP>
+impl<MR, MSG> CommandHandler<SendMessageCommand> for SendMessageHandler<MR, MSG>
 where
     MR:  MemberRepository,
     MSG: MessageRepository,
-    EP:  EventPublisher,
 {
     type Error = ChatError;
 
@@ -106,10 +106,8 @@ where
             reply_to,
         )?;
 
-        // Durable write first; the event is the seam the routing layer forks into
-        // the Member-Plane broadcast and the Audience-Plane shadow.
-        self.message_repo.insert(&message).await?;
-
+        // The event is the seam the routing layer forks into the Member-Plane
+        // broadcast and the Audience-Plane shadow; it is written with the message.
         let event = MessageEvent::Sent(MessageSentEvent {
             conversation_id: conversation_id.as_str(),
             message_id:      message.id().as_str(),
@@ -120,7 +118,7 @@ where
             reply_to:        message.reply_to().map(|m| m.as_str()),
             created_at_ms:   message.created_at().timestamp_millis(),
         });
-        self.publisher.publish_message(&event).await?;
+        self.message_repo.insert(&message, &event).await?;
 
         Ok(())
     }
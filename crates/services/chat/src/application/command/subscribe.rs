@@ -17,7 +17,9 @@ pub struct SubscribeCommand {
     pub subscriber_id:   String,
 }
 
-impl Command for SubscribeCommand {}
+impl Command for SubscribeCommand {
+    type Output = ();
+}
 
 impl Validate for SubscribeCommand {
     fn validate(&self) -> Result<(), Vec<FieldViolation>> {
@@ -83,7 +85,9 @@ pub struct UnsubscribeCommand {
     pub subscriber_id:   String,
 }
 
-impl Command for UnsubscribeCommand {}
+impl Command for UnsubscribeCommand {
+    type Output = ();
+}
 
 impl Validate for UnsubscribeCommand {
     fn validate(&self) -> Result<(), Vec<FieldViolation>> {
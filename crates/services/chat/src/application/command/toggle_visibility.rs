@@ -3,7 +3,7 @@ use std::sync::Arc;
 use cqrs::{Command, CommandHandler, Envelope};
 use validate_core::{FieldViolation, Validate};
 
-use crate::application::port::{ConversationRepository, EventPublisher, MemberRepository};
+use crate::application::port::{ConversationRepository, MemberRepository};
 use crate::domain::value_object::{ConversationId, ProfileId};
 use crate::error::ChatError;
 
@@ -18,7 +18,9 @@ pub struct ToggleVisibilityCommand {
     pub make_public:     bool,
 }
 
-impl Command for ToggleVisibilityCommand {}
+impl Command for ToggleVisibilityCommand {
+    type Output = ();
+}
 
 impl Validate for ToggleVisibilityCommand {
     fn validate(&self) -> Result<(), Vec<FieldViolation>> {
@@ -37,18 +39,15 @@ impl Validate for ToggleVisibilityCommand {
     }
 }
 
-pub struct ToggleVisibilityHandler<CR, MR, EP> {
+pub struct ToggleVisibilityHandler<CR, MR> {
     pub conversation_repo: Arc<CR>,
     pub member_repo:       Arc<MR>,
-    pub publisher:         Arc<EP>,
 }
 
-impl<CR, MR, EP> CommandHandler<ToggleVisibilityCommand>
-    for ToggleVisibilityHandler<CR, MR, EP>
+impl<CR, MR> CommandHandler<ToggleVisibilityCommand> for ToggleVisibilityHandler<CR, MR>
 where
     CR: ConversationRepository,
     MR: MemberRepository,
-    EP: EventPublisher,
 {
     type Error = ChatError;
 
@@ -92,11 +91,8 @@ where
             conversation.unpublish()?;
         }
 
-        self.conversation_repo.update(&conversation).await?;
-
-        for event in conversation.take_events() {
-            self.publisher.publish_conversation(&event).await?;
-        }
+        let events = conversation.take_events();
+        self.conversation_repo.update(&conversation, &events).await?;
 
         Ok(())
     }
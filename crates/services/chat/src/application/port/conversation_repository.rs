@@ -1,22 +1,56 @@
 use async_trait::async_trait;
 
-use crate::domain::aggregate::Conversation;
-use crate::domain::value_object::ConversationId;
+use crate::domain::aggregate::{Conversation, Participant};
+use crate::domain::event::DomainEvent;
+use crate::domain::value_object::{ConversationId, ProfileId};
 use crate::error::ChatError;
 
 /// Persistence port for the [`Conversation`] aggregate (`chat.conversations`).
 ///
 /// Backed by a single point-read/point-write per call — the aggregate is small
 /// and bounded by design, and the audience is never loaded through it.
+///
+/// Every write is one logged batch carrying the transition's `events` in
+/// `chat.outbox`, so an event exists exactly when its write does. A transition
+/// that also moves a roster row (create, admit, release) writes that row in the
+/// same batch, so the member count never disagrees with the roster.
 #[async_trait]
 pub trait ConversationRepository: Send + Sync + 'static {
-    /// Inserts a freshly created conversation.
-    async fn insert(&self, conversation: &Conversation) -> Result<(), ChatError>;
+    /// Inserts a freshly created conversation together with its owner's roster
+    /// row.
+    async fn insert(
+        &self,
+        conversation: &Conversation,
+        owner:        &Participant,
+        events:       &[DomainEvent],
+    ) -> Result<(), ChatError>;
 
     /// Persists the mutable aggregate state after a transition: `visibility`,
     /// `public_since`, `member_count`, and `updated_at`. The immutable columns
     /// (`kind`, `owner_id`, `created_at`) are not rewritten.
-    async fn update(&self, conversation: &Conversation) -> Result<(), ChatError>;
+    async fn update(
+        &self,
+        conversation: &Conversation,
+        events:       &[DomainEvent],
+    ) -> Result<(), ChatError>;
+
+    /// [`ConversationRepository::update`] plus the roster row of a newly
+    /// admitted `participant`.
+    async fn admit(
+        &self,
+        conversation: &Conversation,
+        participant:  &Participant,
+        events:       &[DomainEvent],
+    ) -> Result<(), ChatError>;
+
+    /// [`ConversationRepository::update`] plus the removal of `member_id`'s
+    /// roster row.
+    async fn release(
+        &self,
+        conversation: &Conversation,
+        member_id:    &ProfileId,
+        events:       &[DomainEvent],
+    ) -> Result<(), ChatError>;
 
     /// Reconstitutes the aggregate by id, or `None` if it does not exist.
     async fn find(&self, id: &ConversationId) -> Result<Option<Conversation>, ChatError>;
@@ -42,4 +42,8 @@ pub trait HotTailCache: Send + Sync + 'static {
 
     /// Whether a tail cache currently exists for the conversation (warm check).
     async fn exists(&self, conversation_id: &ConversationId) -> Result<bool, ChatError>;
+
+    /// Drops the conversation's tail so stale bodies stop being served; the next
+    /// read rebuilds it from ScyllaDB.
+    async fn invalidate(&self, conversation_id: &ConversationId) -> Result<(), ChatError>;
 }
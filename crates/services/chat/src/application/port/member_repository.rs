@@ -1,23 +1,32 @@
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
 
 use crate::domain::aggregate::Participant;
-use crate::domain::value_object::{ConversationId, MessageId, ProfileId};
+use crate::domain::value_object::{ConversationId, MessageId, ProfileId, Role};
 use crate::error::ChatError;
 
+/// One conversation a profile is a Member-Plane participant of, read from the
+/// reverse index (`chat.conversations_by_member`).
+#[derive(Debug, Clone)]
+pub struct Membership {
+    pub conversation_id: ConversationId,
+    pub role:            Role,
+    pub joined_at:       DateTime<Utc>,
+}
+
 /// Persistence port for the bounded Member Plane roster
 /// (`chat.members_by_conversation`).
 ///
-/// All operations are single-partition: the roster is bounded (<= 500), so the
-/// full-roster `list` is a safe, token-aware read.
+/// All roster operations are single-partition: the roster is bounded (<= 500),
+/// so the full-roster `list` is a safe, token-aware read. Joining and leaving
+/// change the conversation's member count too, so those roster writes go through
+/// [`ConversationRepository`](super::ConversationRepository); `delete` is for a
+/// roster row whose conversation is gone. Each roster row is written in the same
+/// logged batch as its `conversations_by_member` reverse-index entry behind
+/// `list_by_member`.
 #[async_trait]
 pub trait MemberRepository: Send + Sync + 'static {
-    /// Adds a participant to the roster.
-    async fn insert(
-        &self,
-        conversation_id: &ConversationId,
-        participant:     &Participant,
-    ) -> Result<(), ChatError>;
-
     /// Reads a single participant, or `None` if the profile is not a member.
     /// This is the authorization probe on the write/admin path.
     async fn find(
@@ -40,10 +49,21 @@ pub trait MemberRepository: Send + Sync + 'static {
         conversation_id: &ConversationId,
     ) -> Result<Vec<Participant>, ChatError>;
 
-    /// Removes a participant from the roster.
+    /// Removes a participant from the roster of a conversation that no longer
+    /// exists.
     async fn delete(
         &self,
         conversation_id: &ConversationId,
         member_id:       &ProfileId,
     ) -> Result<(), ChatError>;
+
+    /// Lists the conversations `member_id` participates in, paginated by
+    /// `conversation_id` clustering like
+    /// [`SubscriptionRepository::list_by_user`](super::SubscriptionRepository::list_by_user).
+    async fn list_by_member(
+        &self,
+        member_id: &ProfileId,
+        limit:     i32,
+        cursor:    Option<Uuid>,
+    ) -> Result<(Vec<Membership>, Option<Uuid>), ChatError>;
 }
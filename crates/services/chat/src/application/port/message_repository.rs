@@ -3,7 +3,8 @@ use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
 use crate::domain::aggregate::Message;
-use crate::domain::value_object::{ContentType, ConversationId};
+use crate::domain::event::MessageEvent;
+use crate::domain::value_object::{ContentType, ConversationId, ProfileId};
 use crate::error::ChatError;
 
 /// Read projection of a message row, returned by history queries. A flat DTO of
@@ -19,12 +20,21 @@ pub struct MessageSummary {
     pub created_at:   DateTime<Utc>,
 }
 
+/// A message sent by a given profile, read from the sender mirror
+/// (`chat.messages_by_sender`), which carries the conversation it belongs to.
+#[derive(Debug, Clone)]
+pub struct SentMessage {
+    pub conversation_id: Uuid,
+    pub message:         MessageSummary,
+}
+
 /// Persistence port for the time-bucketed message log
-/// (`chat.messages_by_conversation`).
+/// (`chat.messages_by_conversation`) and its sender mirror.
 #[async_trait]
 pub trait MessageRepository: Send + Sync + 'static {
-    /// Durably appends a message to its conversation's current time bucket.
-    async fn insert(&self, message: &Message) -> Result<(), ChatError>;
+    /// Durably appends a message to its conversation's current time bucket, in
+    /// one logged batch with its sender-mirror row and `event` in `chat.outbox`.
+    async fn insert(&self, message: &Message, event: &MessageEvent) -> Result<(), ChatError>;
 
     /// Reads one page of history, newest-first, walking time buckets as needed
     /// to fill `limit`.
@@ -45,4 +55,27 @@ pub trait MessageRepository: Send + Sync + 'static {
         cursor:               Option<(i64, Uuid)>,
         floor_created_at_ms:  Option<i64>,
     ) -> Result<(Vec<MessageSummary>, Option<(i64, Uuid)>), ChatError>;
+
+    /// Reads one page of the messages `sender_id` sent, newest-first, from the
+    /// sender mirror. `cursor` has the same `(created_at_ms, message_id)` shape
+    /// as [`MessageRepository::list_history`].
+    async fn list_by_sender(
+        &self,
+        sender_id: &ProfileId,
+        limit:     i32,
+        cursor:    Option<(i64, Uuid)>,
+    ) -> Result<(Vec<SentMessage>, Option<(i64, Uuid)>), ChatError>;
+
+    /// Blanks the body and media reference of one logged message (GDPR
+    /// erasure). The row keeps its sender, type and place in history, so
+    /// replies and read receipts pointing at it still resolve. Idempotent.
+    async fn scrub(
+        &self,
+        conversation_id: &ConversationId,
+        message_id:      Uuid,
+        created_at:      DateTime<Utc>,
+    ) -> Result<(), ChatError>;
+
+    /// Drops `sender_id`'s whole partition of the sender mirror. Idempotent.
+    async fn delete_sent(&self, sender_id: &ProfileId) -> Result<(), ChatError>;
 }
@@ -1,5 +1,4 @@
 pub mod conversation_repository;
-pub mod event_publisher;
 pub mod hot_tail_cache;
 pub mod member_repository;
 pub mod message_repository;
@@ -9,10 +8,9 @@ pub mod routing_registry;
 pub mod subscription_repository;
 
 pub use conversation_repository::ConversationRepository;
-pub use event_publisher::EventPublisher;
 pub use hot_tail_cache::HotTailCache;
-pub use member_repository::MemberRepository;
-pub use message_repository::{MessageRepository, MessageSummary};
+pub use member_repository::{MemberRepository, Membership};
+pub use message_repository::{MessageRepository, MessageSummary, SentMessage};
 pub use presence_store::PresenceStore;
 pub use receipt_store::ReceiptStore;
 pub use routing_registry::RoutingRegistry;
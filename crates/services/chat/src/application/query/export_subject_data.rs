@@ -0,0 +1,149 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use cqrs::{Envelope, Query, QueryHandler};
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::application::port::{MemberRepository, MessageRepository, SubscriptionRepository};
+use crate::domain::value_object::ProfileId;
+use crate::error::ChatError;
+
+/// Page size used for every walk during an export.
+const EXPORT_PAGE_SIZE: i32 = 100;
+
+/// GDPR Art. 20 export of the subject's chat footprint: the conversations each
+/// of their profiles participates in or subscribes to, and every message those
+/// profiles sent. Other participants' messages are not the subject's data and
+/// are left out.
+pub struct ExportSubjectDataQuery {
+    pub profile_ids: Vec<String>,
+}
+
+impl Query for ExportSubjectDataQuery {
+    type Response = SubjectDataExport;
+}
+
+/// This service's slice of a data-subject export.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct SubjectDataExport {
+    pub memberships:   Vec<MembershipExport>,
+    pub subscriptions: Vec<SubscriptionExport>,
+    pub messages:      Vec<MessageExport>,
+}
+
+impl SubjectDataExport {
+    pub fn record_count(&self) -> u32 {
+        (self.memberships.len() + self.subscriptions.len() + self.messages.len()) as u32
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct MembershipExport {
+    pub profile_id:      String,
+    pub conversation_id: String,
+    pub role:            &'static str,
+    pub joined_at:       DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SubscriptionExport {
+    pub profile_id:      String,
+    pub conversation_id: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct MessageExport {
+    pub message_id:      String,
+    pub conversation_id: String,
+    pub sender_id:       String,
+    pub content_type:    &'static str,
+    pub body:            String,
+    pub media_ref:       Option<String>,
+    pub reply_to:        Option<String>,
+    pub created_at:      DateTime<Utc>,
+}
+
+pub struct ExportSubjectDataHandler<MR, MSG, SR> {
+    pub member_repo:       Arc<MR>,
+    pub message_repo:      Arc<MSG>,
+    pub subscription_repo: Arc<SR>,
+}
+
+impl<MR, MSG, SR> QueryHandler<ExportSubjectDataQuery> for ExportSubjectDataHandler<MR, MSG, SR>
+where
+    MR:  MemberRepository,
+    MSG: MessageRepository,
+    SR:  SubscriptionRepository,
+{
+    type Error = ChatError;
+
+    async fn handle(
+        &self,
+        envelope: Envelope<ExportSubjectDataQuery>,
+    ) -> Result<SubjectDataExport, ChatError> {
+        let mut export = SubjectDataExport::default();
+
+        for raw in &envelope.payload.profile_ids {
+            let profile_id = ProfileId::try_from(raw.as_str())?;
+
+            let mut cursor: Option<Uuid> = None;
+            loop {
+                let (page, next) = self
+                    .member_repo
+                    .list_by_member(&profile_id, EXPORT_PAGE_SIZE, cursor)
+                    .await?;
+                export.memberships.extend(page.into_iter().map(|m| MembershipExport {
+                    profile_id:      raw.clone(),
+                    conversation_id: m.conversation_id.as_str(),
+                    role:            m.role.as_str(),
+                    joined_at:       m.joined_at,
+                }));
+                match next {
+                    Some(c) => cursor = Some(c),
+                    None => break,
+                }
+            }
+
+            let mut cursor: Option<Uuid> = None;
+            loop {
+                let (ids, next) = self
+                    .subscription_repo
+                    .list_by_user(&profile_id, EXPORT_PAGE_SIZE, cursor)
+                    .await?;
+                export.subscriptions.extend(ids.into_iter().map(|c| SubscriptionExport {
+                    profile_id:      raw.clone(),
+                    conversation_id: c.as_str(),
+                }));
+                match next {
+                    Some(c) => cursor = Some(c),
+                    None => break,
+                }
+            }
+
+            let mut cursor: Option<(i64, Uuid)> = None;
+            loop {
+                let (page, next) = self
+                    .message_repo
+                    .list_by_sender(&profile_id, EXPORT_PAGE_SIZE, cursor)
+                    .await?;
+                export.messages.extend(page.into_iter().map(|s| MessageExport {
+                    message_id:      s.message.message_id.to_string(),
+                    conversation_id: s.conversation_id.to_string(),
+                    sender_id:       s.message.sender_id.to_string(),
+                    content_type:    s.message.content_type.as_str(),
+                    body:            s.message.body,
+                    media_ref:       s.message.media_ref,
+                    reply_to:        s.message.reply_to.map(|r| r.to_string()),
+                    created_at:      s.message.created_at,
+                }));
+                match next {
+                    Some(c) => cursor = Some(c),
+                    None => break,
+                }
+            }
+        }
+
+        Ok(export)
+    }
+}
@@ -1,7 +1,9 @@
+pub mod export_subject_data;
 pub mod get_history;
 pub mod list_members;
 pub mod list_subscriptions;
 
+pub use export_subject_data::{ExportSubjectDataHandler, ExportSubjectDataQuery, SubjectDataExport};
 pub use get_history::{GetHistoryHandler, GetHistoryQuery, MessagePage};
 pub use list_members::{ListMembersHandler, ListMembersQuery, MemberView};
 pub use list_subscriptions::{
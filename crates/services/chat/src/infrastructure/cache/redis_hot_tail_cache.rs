@@ -122,6 +122,16 @@ impl HotTailCache for RedisHotTailCache {
             .await
             .map_err(redis_err)
     }
+
+    async fn invalidate(&self, conversation_id: &ConversationId) -> Result<(), ChatError> {
+        let _: i64 = self
+            .client
+            .inner
+            .del(tail_key(conversation_id))
+            .await
+            .map_err(redis_err)?;
+        Ok(())
+    }
 }
 
 fn encode(m: &MessageSummary) -> Result<String, ChatError> {
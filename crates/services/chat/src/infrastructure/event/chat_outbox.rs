@@ -0,0 +1,166 @@
+//! Chat's events as `chat.outbox` rows.
+//!
+//! The repositories write each event into the same logged batch as the rows that
+//! produced it, so an acknowledged write always carries its event and a failed
+//! one carries none; the shared [`outbox::ScyllaOutboxRelay`] forwards the rows
+//! to Kafka. A broker outage no longer loses an event whose write succeeded.
+
+use outbox::{OutboxError, OutboxMessage, ScyllaOutbox, ScyllaOutboxBatch};
+use serde::Serialize;
+
+use crate::domain::event::{DomainEvent, MessageEvent};
+use crate::error::ChatError;
+
+const TOPIC_CREATED:      &str = "chat.conversation.created";
+const TOPIC_PUBLISHED:    &str = "chat.conversation.published";
+const TOPIC_UNPUBLISHED:  &str = "chat.conversation.unpublished";
+const TOPIC_MEMBER_JOINED: &str = "chat.member.joined";
+const TOPIC_MEMBER_LEFT:  &str = "chat.member.left";
+const TOPIC_MESSAGE_SENT: &str = "chat.message.sent";
+
+/// A failed batch is a failed write — the chat rows were in it; only a payload
+/// that cannot serialize is reported as a publish failure.
+pub(crate) fn outbox_err(e: OutboxError) -> ChatError {
+    match e {
+        OutboxError::ScyllaStorage(e) => ChatError::Scylla(e),
+        other => ChatError::EventPublishFailed { message: format!("outbox enqueue: {other}") },
+    }
+}
+
+/// The `chat.outbox` handle the repositories batch their writes through. Cheap
+/// to clone.
+///
+/// Each event family is keyed by `conversation_id` so all events for one
+/// conversation land on the same partition and preserve per-conversation
+/// ordering. Downstream services (notification fan-out to subscribers, analytics)
+/// consume these topics; the chat service itself consumes
+/// `chat.conversation.unpublished` to tear down the Audience Plane cluster-wide.
+///
+/// Without a broker ([`ChatOutbox::log_only`]) the batches still carry the rows
+/// but no outbox row is added — with no relay draining them they would only pile
+/// up — and the events are logged instead.
+#[derive(Clone)]
+pub struct ChatOutbox {
+    outbox:  ScyllaOutbox,
+    relayed: bool,
+}
+
+impl ChatOutbox {
+    /// Enqueues every event for the relay to forward.
+    pub fn relayed(outbox: ScyllaOutbox) -> Self {
+        Self { outbox, relayed: true }
+    }
+
+    /// Logs every event instead of enqueueing it; for runs with no broker.
+    pub fn log_only(outbox: ScyllaOutbox) -> Self {
+        Self { outbox, relayed: false }
+    }
+
+    /// Starts the logged batch a repository write goes into.
+    pub(crate) fn batch(&self) -> ScyllaOutboxBatch<'_> {
+        self.outbox.batch()
+    }
+
+    /// Adds one row per conversation-lifecycle event to `batch`.
+    pub(crate) fn enqueue_conversation(
+        &self,
+        batch:  &mut ScyllaOutboxBatch<'_>,
+        events: &[DomainEvent],
+    ) -> Result<(), ChatError> {
+        if !self.relayed {
+            events.iter().for_each(|e| log_event("conversation", e));
+            return Ok(());
+        }
+        let messages = events
+            .iter()
+            .map(conversation_message)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(outbox_err)?;
+        batch.enqueue(&messages).map_err(outbox_err)?;
+        Ok(())
+    }
+
+    /// Adds the row for a message event to `batch`.
+    pub(crate) fn enqueue_message(
+        &self,
+        batch: &mut ScyllaOutboxBatch<'_>,
+        event: &MessageEvent,
+    ) -> Result<(), ChatError> {
+        if !self.relayed {
+            log_event("message", event);
+            return Ok(());
+        }
+        let message = match event {
+            MessageEvent::Sent(e) => {
+                keyed(TOPIC_MESSAGE_SENT, &e.conversation_id, "MessageSent", e)
+            }
+        }
+        .map_err(outbox_err)?;
+        batch.enqueue(&[message]).map_err(outbox_err)?;
+        Ok(())
+    }
+}
+
+fn conversation_message(event: &DomainEvent) -> Result<OutboxMessage, OutboxError> {
+    match event {
+        DomainEvent::ConversationCreated(e) => {
+            keyed(TOPIC_CREATED, &e.conversation_id, "ConversationCreated", e)
+        }
+        DomainEvent::ConversationPublished(e) => {
+            keyed(TOPIC_PUBLISHED, &e.conversation_id, "ConversationPublished", e)
+        }
+        DomainEvent::ConversationUnpublished(e) => {
+            keyed(TOPIC_UNPUBLISHED, &e.conversation_id, "ConversationUnpublished", e)
+        }
+        DomainEvent::MemberJoined(e) => {
+            keyed(TOPIC_MEMBER_JOINED, &e.conversation_id, "MemberJoined", e)
+        }
+        DomainEvent::MemberLeft(e) => {
+            keyed(TOPIC_MEMBER_LEFT, &e.conversation_id, "MemberLeft", e)
+        }
+    }
+}
+
+/// The bare event, keyed by `conversation_id`, with a `conversation_id` header.
+fn keyed<T: Serialize>(
+    topic:           &str,
+    conversation_id: &str,
+    event_type:      &str,
+    payload:         &T,
+) -> Result<OutboxMessage, OutboxError> {
+    Ok(OutboxMessage::new(topic, conversation_id, event_type, payload)?
+        .with_header("conversation_id", conversation_id))
+}
+
+fn log_event<T: Serialize>(family: &str, event: &T) {
+    match serde_json::to_string(event) {
+        Ok(json) => tracing::debug!(event = %json, family, "chat event (no broker)"),
+        Err(e) => tracing::warn!(error = %e, family, "failed to serialize chat event"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use event_topology::conformance::producer_violations;
+
+    use super::*;
+    use crate::domain::event::{
+        ConversationCreatedEvent, ConversationPublishedEvent, ConversationUnpublishedEvent,
+        MemberJoinedEvent, MemberLeftEvent, MessageSentEvent,
+    };
+
+    /// Each chat topic carries exactly the payload registered for it in event-topology.
+    #[test]
+    fn chat_events_conform_to_the_registered_schemas() {
+        let violations: Vec<String> = [
+            producer_violations::<ConversationCreatedEvent>(TOPIC_CREATED),
+            producer_violations::<ConversationPublishedEvent>(TOPIC_PUBLISHED),
+            producer_violations::<ConversationUnpublishedEvent>(TOPIC_UNPUBLISHED),
+            producer_violations::<MemberJoinedEvent>(TOPIC_MEMBER_JOINED),
+            producer_violations::<MemberLeftEvent>(TOPIC_MEMBER_LEFT),
+            producer_violations::<MessageSentEvent>(TOPIC_MESSAGE_SENT),
+        ]
+        .concat();
+        assert!(violations.is_empty(), "{violations:#?}");
+    }
+}
@@ -1,5 +1,7 @@
-pub mod kafka_event_publisher;
-pub mod log_event_publisher;
+pub mod chat_outbox;
 
-pub use kafka_event_publisher::KafkaEventPublisher;
-pub use log_event_publisher::LogEventPublisher;
+pub use chat_outbox::ChatOutbox;
+
+/// The keyspace whose `outbox` / `outbox_lease` / `outbox_member` tables carry
+/// chat's pending events.
+pub const OUTBOX_KEYSPACE: &str = "chat";
@@ -22,7 +22,7 @@ use crate::application::port::{
     RoutingRegistry,
 };
 use crate::application::query::{
-    GetHistoryQuery, ListMembersQuery, ListSubscriptionsQuery, MemberView as QueryMemberView,
+    ExportSubjectDataQuery, GetHistoryQuery, ListMembersQuery, SubjectDataExport, ListSubscriptionsQuery, MemberView as QueryMemberView,
 };
 use crate::domain::value_object::{ContentType, ConversationId, MessageId, ProfileId};
 use crate::error::ChatError;
@@ -368,6 +368,26 @@ where
         }))
     }
 
+    async fn export_subject_data(
+        &self,
+        request: Request<proto::ExportSubjectDataRequest>,
+    ) -> Result<Response<proto::ExportSubjectDataResponse>, Status> {
+        let req   = request.into_inner();
+        let query = ExportSubjectDataQuery { profile_ids: req.profile_ids };
+        let export: SubjectDataExport = self
+            .query_bus
+            .dispatch(Envelope::new(Uuid::now_v7(), query))
+            .await
+            .map_err(cqrs_to_status)?;
+
+        let document = serde_json::to_vec(&export)
+            .map_err(|e| Status::internal(format!("encode export document: {e}")))?;
+        Ok(Response::new(proto::ExportSubjectDataResponse {
+            document,
+            record_count: export.record_count(),
+        }))
+    }
+
     // ── Streaming ─────────────────────────────────────────────────────────────
 
     async fn stream_conversation(
@@ -727,6 +747,13 @@ where
         self.list_subscriptions(request).await
     }
 
+    async fn export_subject_data(
+        &self,
+        request: Request<proto::ExportSubjectDataRequest>,
+    ) -> Result<Response<proto::ExportSubjectDataResponse>, Status> {
+        self.export_subject_data(request).await
+    }
+
     async fn stream_conversation(
         &self,
         request: Request<proto::StreamConversationRequest>,
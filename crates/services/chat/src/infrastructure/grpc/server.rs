@@ -1,7 +1,6 @@
 use std::net::SocketAddr;
+use std::sync::Arc;
 
-use cqrs::command::InMemoryCommandBus;
-use cqrs::query::InMemoryQueryBus;
 use redis_storage::RedisConfig;
 use scylla_storage::ScyllaConfig;
 use tonic::transport::Server;
@@ -9,7 +8,7 @@ use tonic_health::server::health_reporter;
 use tonic_reflection::server::Builder as ReflectionBuilder;
 use transport::kafka::config::client::KafkaClientConfig;
 
-use crate::app::{App, AppConfig, Backends};
+use crate::app::{App, AppCommandBus, AppConfig, AppQueryBus, Backends};
 use crate::config::ChatConfig;
 use crate::infrastructure::grpc::handler::{ChatServiceHandler, ChatServiceServer};
 
@@ -50,7 +49,7 @@ pub async fn serve(addr: SocketAddr) -> Result<(), Box<dyn std::error::Error>> {
 
     let (health_reporter, health_service) = health_reporter();
     health_reporter
-        .set_serving::<ChatServiceServer<ChatServiceHandler<InMemoryCommandBus, InMemoryQueryBus>>>()
+        .set_serving::<ChatServiceServer<ChatServiceHandler<Arc<AppCommandBus>, AppQueryBus>>>()
         .await;
 
     let reflection = ReflectionBuilder::configure()
@@ -1,5 +1,6 @@
 pub mod bucket;
 pub mod model;
+pub mod reverse_index_backfill;
 pub mod scylla_conversation_repository;
 pub mod scylla_member_repository;
 pub mod scylla_message_repository;
@@ -7,6 +8,7 @@ pub mod scylla_subscription_repository;
 pub mod statement;
 pub mod time;
 
+pub use reverse_index_backfill::ReverseIndexBackfill;
 pub use scylla_conversation_repository::ScyllaConversationRepository;
 pub use scylla_member_repository::ScyllaMemberRepository;
 pub use scylla_message_repository::ScyllaMessageRepository;
@@ -18,3 +18,18 @@ pub struct MessageRow {
     pub media_ref:    Option<String>,
     pub reply_to:     Option<Uuid>,
 }
+
+/// ScyllaDB row type for `chat.messages_by_sender` reads. Same shape as
+/// [`MessageRow`] plus the owning conversation; `sender_id` is the partition
+/// key the caller already holds.
+#[derive(Debug, DeserializeRow)]
+#[scylla(flavor = "enforce_order")]
+pub struct SentMessageRow {
+    pub created_at:      CqlTimestamp,
+    pub message_id:      Uuid,
+    pub conversation_id: Uuid,
+    pub content_type:    i8,
+    pub body:            Option<String>,
+    pub media_ref:       Option<String>,
+    pub reply_to:        Option<Uuid>,
+}
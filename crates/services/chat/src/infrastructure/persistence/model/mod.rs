@@ -4,4 +4,4 @@ pub mod message_row;
 
 pub use conversation_row::ConversationRow;
 pub use member_row::MemberRow;
-pub use message_row::MessageRow;
+pub use message_row::{MessageRow, SentMessageRow};
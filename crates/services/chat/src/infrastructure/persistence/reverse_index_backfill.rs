@@ -0,0 +1,280 @@
+//! One-time backfill of chat's data-subject reverse indexes from their base
+//! tables: `chat.messages_by_sender` from `chat.messages_by_conversation`, and
+//! `chat.conversations_by_member` from `chat.members_by_conversation`.
+//!
+//! Both indexes only hold rows written since they shipped, so an erasure walking
+//! them would silently miss every older message and membership.
+//! [`ReverseIndexBackfill::run`] walks each base table over the token ring and
+//! writes the missing entries; the subject-erasure consumer does not start until
+//! both are recorded complete, so no erasure is confirmed against a partial
+//! index.
+//!
+//! Each entry is written `USING TIMESTAMP` of its base row's own insert, so a
+//! leave or an erasure that lands while the walk is in flight still shadows the
+//! entry it copies. Every replica runs the walk at boot: finished token ranges
+//! are recorded in `chat.index_backfills` and each replica starts at a different
+//! range, so they mostly split the work, and a restart resumes instead of
+//! starting over.
+
+use std::sync::Arc;
+
+use chrono::Utc;
+use futures::StreamExt;
+use scylla::value::CqlTimestamp;
+use scylla_storage::{ScyllaClient, ScyllaStorageError};
+use uuid::Uuid;
+
+use crate::error::ChatError;
+use crate::infrastructure::persistence::statement::{analytical, scylla_err, strict};
+
+/// Token-ring slices walked (and checkpointed) independently.
+const RANGES: i32 = 256;
+
+/// Rows fetched per page of a base-table walk.
+const PAGE_SIZE: i32 = 500;
+
+/// A reverse index and the base table it is rebuilt from.
+#[derive(Clone, Copy)]
+enum Index {
+    MessagesBySender,
+    ConversationsByMember,
+}
+
+impl Index {
+    fn name(self) -> &'static str {
+        match self {
+            Self::MessagesBySender => "messages_by_sender",
+            Self::ConversationsByMember => "conversations_by_member",
+        }
+    }
+}
+
+pub struct ReverseIndexBackfill {
+    client: Arc<ScyllaClient>,
+}
+
+impl ReverseIndexBackfill {
+    pub fn new(client: Arc<ScyllaClient>) -> Self {
+        Self { client }
+    }
+
+    /// Backfills every index not yet recorded complete. Idempotent; on error the
+    /// caller re-runs it and the finished ranges are skipped.
+    pub async fn run(&self) -> Result<(), ChatError> {
+        for index in [Index::MessagesBySender, Index::ConversationsByMember] {
+            self.backfill(index).await?;
+        }
+        Ok(())
+    }
+
+    /// Walks every range of `index` no replica has finished yet, then records it
+    /// as complete. Returns at once when it already is.
+    async fn backfill(&self, index: Index) -> Result<(), ChatError> {
+        if self.is_complete(index).await? {
+            return Ok(());
+        }
+        let start = (Uuid::new_v4().as_u128() % RANGES as u128) as i32;
+        for offset in 0..RANGES {
+            let range_id = (start + offset) % RANGES;
+            if self.range_done(index, range_id).await? {
+                continue;
+            }
+            match index {
+                Index::MessagesBySender => self.walk_messages(range_id).await?,
+                Index::ConversationsByMember => self.walk_members(range_id).await?,
+            }
+            self.execute(
+                "INSERT INTO chat.index_backfills (index_name, range_id) VALUES (?, ?)",
+                (index.name(), range_id),
+            )
+            .await?;
+        }
+        self.execute(
+            "UPDATE chat.index_backfills SET completed_at = ? WHERE index_name = ?",
+            (CqlTimestamp(Utc::now().timestamp_millis()), index.name()),
+        )
+        .await?;
+        tracing::info!(index = index.name(), "reverse-index backfill complete");
+        Ok(())
+    }
+
+    async fn is_complete(&self, index: Index) -> Result<bool, ChatError> {
+        let rows = self
+            .client
+            .session
+            .execute_unpaged(
+                strict(
+                    &self.client,
+                    "SELECT completed_at FROM chat.index_backfills WHERE index_name = ? LIMIT 1",
+                ),
+                (index.name(),),
+            )
+            .await
+            .map_err(scylla_err)?
+            .into_rows_result()
+            .map_err(|e| pager_err(e.to_string()))?
+            .rows::<(Option<CqlTimestamp>,)>()
+            .map_err(|e| pager_err(e.to_string()))?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| pager_err(e.to_string()))?;
+        Ok(matches!(rows.first(), Some((Some(_),))))
+    }
+
+    async fn range_done(&self, index: Index, range_id: i32) -> Result<bool, ChatError> {
+        let result = self
+            .client
+            .session
+            .execute_unpaged(
+                strict(
+                    &self.client,
+                    "SELECT range_id FROM chat.index_backfills \
+                     WHERE index_name = ? AND range_id = ?",
+                ),
+                (index.name(), range_id),
+            )
+            .await
+            .map_err(scylla_err)?
+            .into_rows_result()
+            .map_err(|e| pager_err(e.to_string()))?;
+        Ok(result.rows_num() > 0)
+    }
+
+    /// Copies one token range of `messages_by_conversation` into the sender
+    /// mirror.
+    async fn walk_messages(&self, range_id: i32) -> Result<(), ChatError> {
+        let (low, high) = token_range(range_id);
+        let mut scan = analytical(
+            &self.client,
+            "SELECT sender_id, created_at, message_id, conversation_id, content_type, \
+                    body, media_ref, reply_to, WRITETIME(sender_id) \
+             FROM chat.messages_by_conversation \
+             WHERE token(conversation_id, bucket) >= ? AND token(conversation_id, bucket) <= ?",
+        );
+        scan.set_page_size(PAGE_SIZE);
+        type Row = (
+            Uuid,
+            CqlTimestamp,
+            Uuid,
+            Uuid,
+            i8,
+            Option<String>,
+            Option<String>,
+            Option<Uuid>,
+            Option<i64>,
+        );
+        let mut rows = self
+            .client
+            .session
+            .execute_iter(scan, (low, high))
+            .await
+            .map_err(|e| pager_err(e.to_string()))?
+            .rows_stream::<Row>()
+            .map_err(|e| pager_err(e.to_string()))?;
+        while let Some(row) = rows.next().await {
+            let (
+                sender_id,
+                created_at,
+                message_id,
+                conversation_id,
+                content_type,
+                body,
+                media_ref,
+                reply_to,
+                written_at,
+            ) = row.map_err(|e| pager_err(e.to_string()))?;
+            self.execute(
+                "INSERT INTO chat.messages_by_sender \
+                 (sender_id, created_at, message_id, conversation_id, content_type, \
+                  body, media_ref, reply_to) \
+                 VALUES (?, ?, ?, ?, ?, ?, ?, ?) USING TIMESTAMP ?",
+                (
+                    sender_id,
+                    created_at,
+                    message_id,
+                    conversation_id,
+                    content_type,
+                    body,
+                    media_ref,
+                    reply_to,
+                    written_at.unwrap_or_default(),
+                ),
+            )
+            .await?;
+        }
+        Ok(())
+    }
+
+    /// Copies one token range of `members_by_conversation` into the member
+    /// index.
+    async fn walk_members(&self, range_id: i32) -> Result<(), ChatError> {
+        let (low, high) = token_range(range_id);
+        let mut scan = analytical(
+            &self.client,
+            "SELECT member_id, conversation_id, role, joined_at, WRITETIME(joined_at) \
+             FROM chat.members_by_conversation \
+             WHERE token(conversation_id) >= ? AND token(conversation_id) <= ?",
+        );
+        scan.set_page_size(PAGE_SIZE);
+        let mut rows = self
+            .client
+            .session
+            .execute_iter(scan, (low, high))
+            .await
+            .map_err(|e| pager_err(e.to_string()))?
+            .rows_stream::<(Uuid, Uuid, i8, CqlTimestamp, Option<i64>)>()
+            .map_err(|e| pager_err(e.to_string()))?;
+        while let Some(row) = rows.next().await {
+            let (member_id, conversation_id, role, joined_at, written_at) =
+                row.map_err(|e| pager_err(e.to_string()))?;
+            self.execute(
+                "INSERT INTO chat.conversations_by_member \
+                 (member_id, conversation_id, role, joined_at) \
+                 VALUES (?, ?, ?, ?) USING TIMESTAMP ?",
+                (member_id, conversation_id, role, joined_at, written_at.unwrap_or_default()),
+            )
+            .await?;
+        }
+        Ok(())
+    }
+
+    async fn execute(
+        &self,
+        cql:    &str,
+        values: impl scylla::serialize::row::SerializeRow,
+    ) -> Result<(), ChatError> {
+        self.client
+            .session
+            .execute_unpaged(strict(&self.client, cql), values)
+            .await
+            .map_err(scylla_err)?;
+        Ok(())
+    }
+}
+
+/// The inclusive Murmur3 token bounds of slice `range_id` of [`RANGES`].
+fn token_range(range_id: i32) -> (i64, i64) {
+    let span = (1i128 << 64) / RANGES as i128;
+    let low = i64::MIN as i128 + span * range_id as i128;
+    let high = if range_id == RANGES - 1 { i64::MAX as i128 } else { low + span - 1 };
+    (low as i64, high as i64)
+}
+
+/// A paged walk surfaces driver errors the storage mapping has no variant for;
+/// they are transient from the backfill's point of view, which simply re-runs.
+fn pager_err(message: String) -> ChatError {
+    ChatError::Scylla(ScyllaStorageError::Transport { message })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn token_ranges_tile_the_ring() {
+        assert_eq!(token_range(0).0, i64::MIN);
+        assert_eq!(token_range(RANGES - 1).1, i64::MAX);
+        for range_id in 1..RANGES {
+            assert_eq!(token_range(range_id).0, token_range(range_id - 1).1 + 1);
+        }
+    }
+}
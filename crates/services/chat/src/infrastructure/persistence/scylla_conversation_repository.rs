@@ -1,82 +1,130 @@
 use std::sync::Arc;
 
 use async_trait::async_trait;
+use outbox::ScyllaOutboxBatch;
 use scylla_storage::ScyllaClient;
 
 use crate::application::port::ConversationRepository;
-use crate::domain::aggregate::Conversation;
+use crate::domain::aggregate::{Conversation, Participant};
+use crate::domain::event::DomainEvent;
 use crate::domain::value_object::{
     ConversationId, ConversationKind, MessageId, ProfileId, Visibility,
 };
 use crate::error::ChatError;
+use crate::infrastructure::event::chat_outbox::outbox_err;
+use crate::infrastructure::event::ChatOutbox;
 use crate::infrastructure::persistence::model::ConversationRow;
+use crate::infrastructure::persistence::scylla_member_repository::{
+    add_roster_row, remove_roster_row,
+};
 use crate::infrastructure::persistence::statement::{fast, row_err, scylla_err, strict};
 use crate::infrastructure::persistence::time::{to_cql, to_utc};
 
 /// ScyllaDB adapter for the [`Conversation`] aggregate (`chat.conversations`).
+///
+/// Every write is one logged batch: the `conversations` row, any roster row the
+/// transition moves, and the transition's events in `chat.outbox`.
 pub struct ScyllaConversationRepository {
     client: Arc<ScyllaClient>,
+    outbox: ChatOutbox,
 }
 
 impl ScyllaConversationRepository {
-    pub fn new(client: Arc<ScyllaClient>) -> Self {
-        Self { client }
+    pub fn new(client: Arc<ScyllaClient>, outbox: ChatOutbox) -> Self {
+        Self { client, outbox }
+    }
+
+    /// Adds the rewrite of the mutable columns to `batch`; kind/owner_id/created_at
+    /// are immutable.
+    fn add_update(&self, batch: &mut ScyllaOutboxBatch<'_>, c: &Conversation) {
+        batch.write(
+            strict(
+                &self.client,
+                "UPDATE chat.conversations \
+                 SET visibility = ?, public_since = ?, member_count = ?, updated_at = ? \
+                 WHERE conversation_id = ?",
+            ),
+            (
+                c.visibility().as_tinyint(),
+                c.public_since().map(|m| m.as_uuid()),
+                c.member_count() as i32,
+                to_cql(c.updated_at()),
+                c.id().as_uuid(),
+            ),
+        );
+    }
+
+    /// Adds `events` to `batch` and applies it.
+    async fn apply(
+        &self,
+        mut batch: ScyllaOutboxBatch<'_>,
+        events:    &[DomainEvent],
+    ) -> Result<(), ChatError> {
+        self.outbox.enqueue_conversation(&mut batch, events)?;
+        batch.execute().await.map_err(outbox_err)
     }
 }
 
 #[async_trait]
 impl ConversationRepository for ScyllaConversationRepository {
-    async fn insert(&self, c: &Conversation) -> Result<(), ChatError> {
-        let stmt = strict(
-            &self.client,
-            "INSERT INTO chat.conversations \
-             (conversation_id, kind, visibility, owner_id, member_count, public_since, \
-              created_at, updated_at) \
-             VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
+    async fn insert(
+        &self,
+        c:      &Conversation,
+        owner:  &Participant,
+        events: &[DomainEvent],
+    ) -> Result<(), ChatError> {
+        let mut batch = self.outbox.batch();
+        batch.write(
+            strict(
+                &self.client,
+                "INSERT INTO chat.conversations \
+                 (conversation_id, kind, visibility, owner_id, member_count, public_since, \
+                  created_at, updated_at) \
+                 VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
+            ),
+            (
+                c.id().as_uuid(),
+                c.kind().as_tinyint(),
+                c.visibility().as_tinyint(),
+                c.owner_id().as_uuid(),
+                c.member_count() as i32,
+                c.public_since().map(|m| m.as_uuid()),
+                to_cql(c.created_at()),
+                to_cql(c.updated_at()),
+            ),
         );
-        self.client
-            .session
-            .execute_unpaged(
-                stmt,
-                (
-                    c.id().as_uuid(),
-                    c.kind().as_tinyint(),
-                    c.visibility().as_tinyint(),
-                    c.owner_id().as_uuid(),
-                    c.member_count() as i32,
-                    c.public_since().map(|m| m.as_uuid()),
-                    to_cql(c.created_at()),
-                    to_cql(c.updated_at()),
-                ),
-            )
-            .await
-            .map_err(scylla_err)?;
-        Ok(())
+        add_roster_row(&self.client, &mut batch, &c.id(), owner);
+        self.apply(batch, events).await
     }
 
-    async fn update(&self, c: &Conversation) -> Result<(), ChatError> {
-        // Only mutable columns are rewritten; kind/owner_id/created_at are immutable.
-        let stmt = strict(
-            &self.client,
-            "UPDATE chat.conversations \
-             SET visibility = ?, public_since = ?, member_count = ?, updated_at = ? \
-             WHERE conversation_id = ?",
-        );
-        self.client
-            .session
-            .execute_unpaged(
-                stmt,
-                (
-                    c.visibility().as_tinyint(),
-                    c.public_since().map(|m| m.as_uuid()),
-                    c.member_count() as i32,
-                    to_cql(c.updated_at()),
-                    c.id().as_uuid(),
-                ),
-            )
-            .await
-            .map_err(scylla_err)?;
-        Ok(())
+    async fn update(&self, c: &Conversation, events: &[DomainEvent]) -> Result<(), ChatError> {
+        let mut batch = self.outbox.batch();
+        self.add_update(&mut batch, c);
+        self.apply(batch, events).await
+    }
+
+    async fn admit(
+        &self,
+        c:           &Conversation,
+        participant: &Participant,
+        events:      &[DomainEvent],
+    ) -> Result<(), ChatError> {
+        let mut batch = self.outbox.batch();
+        add_roster_row(&self.client, &mut batch, &c.id(), participant);
+        self.add_update(&mut batch, c);
+        self.apply(batch, events).await
+    }
+
+    async fn release(
+        &self,
+        c:         &Conversation,
+        member_id: &ProfileId,
+        events:    &[DomainEvent],
+    ) -> Result<(), ChatError> {
+        let mut batch = self.outbox.batch();
+        remove_roster_row(&self.client, &mut batch, &c.id(), member_id);
+        self.add_update(&mut batch, c);
+        self.apply(batch, events).await
     }
 
     async fn find(&self, id: &ConversationId) -> Result<Option<Conversation>, ChatError> {
@@ -1,12 +1,17 @@
 use std::sync::Arc;
 
 use async_trait::async_trait;
+use scylla::value::CqlTimestamp;
+use outbox::ScyllaOutboxBatch;
 use scylla_storage::ScyllaClient;
+use uuid::Uuid;
 
-use crate::application::port::MemberRepository;
+use crate::application::port::{MemberRepository, Membership};
 use crate::domain::aggregate::Participant;
 use crate::domain::value_object::{ConversationId, MessageId, ProfileId, Role};
 use crate::error::ChatError;
+use crate::infrastructure::event::chat_outbox::outbox_err;
+use crate::infrastructure::event::ChatOutbox;
 use crate::infrastructure::persistence::model::MemberRow;
 use crate::infrastructure::persistence::statement::{fast, row_err, scylla_err, strict};
 use crate::infrastructure::persistence::time::{to_cql, to_utc};
@@ -17,44 +22,82 @@ const MEMBER_COLS: &str = "member_id, role, joined_at, last_read";
 /// (`chat.members_by_conversation`).
 pub struct ScyllaMemberRepository {
     client: Arc<ScyllaClient>,
+    outbox: ChatOutbox,
 }
 
 impl ScyllaMemberRepository {
-    pub fn new(client: Arc<ScyllaClient>) -> Self {
-        Self { client }
+    pub fn new(client: Arc<ScyllaClient>, outbox: ChatOutbox) -> Self {
+        Self { client, outbox }
     }
 }
 
-#[async_trait]
-impl MemberRepository for ScyllaMemberRepository {
-    async fn insert(
-        &self,
-        conversation_id: &ConversationId,
-        p:               &Participant,
-    ) -> Result<(), ChatError> {
-        let stmt = strict(
-            &self.client,
+/// Adds `p`'s roster row and its `conversations_by_member` reverse-index entry
+/// to `batch`.
+pub(crate) fn add_roster_row(
+    client:          &ScyllaClient,
+    batch:           &mut ScyllaOutboxBatch<'_>,
+    conversation_id: &ConversationId,
+    p:               &Participant,
+) {
+    batch.write(
+        strict(
+            client,
             "INSERT INTO chat.members_by_conversation \
              (conversation_id, member_id, role, joined_at, last_read) \
              VALUES (?, ?, ?, ?, ?)",
-        );
-        self.client
-            .session
-            .execute_unpaged(
-                stmt,
-                (
-                    conversation_id.as_uuid(),
-                    p.profile_id().as_uuid(),
-                    p.role().as_tinyint(),
-                    to_cql(p.joined_at()),
-                    p.last_read().map(|m| m.as_uuid()),
-                ),
-            )
-            .await
-            .map_err(scylla_err)?;
-        Ok(())
-    }
+        ),
+        (
+            conversation_id.as_uuid(),
+            p.profile_id().as_uuid(),
+            p.role().as_tinyint(),
+            to_cql(p.joined_at()),
+            p.last_read().map(|m| m.as_uuid()),
+        ),
+    );
+    batch.write(
+        strict(
+            client,
+            "INSERT INTO chat.conversations_by_member \
+             (member_id, conversation_id, role, joined_at) \
+             VALUES (?, ?, ?, ?)",
+        ),
+        (
+            p.profile_id().as_uuid(),
+            conversation_id.as_uuid(),
+            p.role().as_tinyint(),
+            to_cql(p.joined_at()),
+        ),
+    );
+}
+
+/// Adds the deletion of `member_id`'s roster row and reverse-index entry to
+/// `batch`.
+pub(crate) fn remove_roster_row(
+    client:          &ScyllaClient,
+    batch:           &mut ScyllaOutboxBatch<'_>,
+    conversation_id: &ConversationId,
+    member_id:       &ProfileId,
+) {
+    batch.write(
+        strict(
+            client,
+            "DELETE FROM chat.members_by_conversation \
+             WHERE conversation_id = ? AND member_id = ?",
+        ),
+        (conversation_id.as_uuid(), member_id.as_uuid()),
+    );
+    batch.write(
+        strict(
+            client,
+            "DELETE FROM chat.conversations_by_member \
+             WHERE member_id = ? AND conversation_id = ?",
+        ),
+        (member_id.as_uuid(), conversation_id.as_uuid()),
+    );
+}
 
+#[async_trait]
+impl MemberRepository for ScyllaMemberRepository {
     async fn find(
         &self,
         conversation_id: &ConversationId,
@@ -142,17 +185,64 @@ impl MemberRepository for ScyllaMemberRepository {
         conversation_id: &ConversationId,
         member_id:       &ProfileId,
     ) -> Result<(), ChatError> {
-        let stmt = strict(
-            &self.client,
-            "DELETE FROM chat.members_by_conversation \
-             WHERE conversation_id = ? AND member_id = ?",
-        );
-        self.client
-            .session
-            .execute_unpaged(stmt, (conversation_id.as_uuid(), member_id.as_uuid()))
-            .await
-            .map_err(scylla_err)?;
-        Ok(())
+        let mut batch = self.outbox.batch();
+        remove_roster_row(&self.client, &mut batch, conversation_id, member_id);
+        batch.execute().await.map_err(outbox_err)
+    }
+
+    async fn list_by_member(
+        &self,
+        member_id: &ProfileId,
+        limit:     i32,
+        cursor:    Option<Uuid>,
+    ) -> Result<(Vec<Membership>, Option<Uuid>), ChatError> {
+        let result = if let Some(after) = cursor {
+            let stmt = fast(
+                &self.client,
+                "SELECT conversation_id, role, joined_at FROM chat.conversations_by_member \
+                 WHERE member_id = ? AND conversation_id > ? LIMIT ?",
+            );
+            self.client
+                .session
+                .execute_unpaged(stmt, (member_id.as_uuid(), after, limit))
+                .await
+        } else {
+            let stmt = fast(
+                &self.client,
+                "SELECT conversation_id, role, joined_at FROM chat.conversations_by_member \
+                 WHERE member_id = ? LIMIT ?",
+            );
+            self.client
+                .session
+                .execute_unpaged(stmt, (member_id.as_uuid(), limit))
+                .await
+        };
+        let rows: Vec<(Uuid, i8, CqlTimestamp)> = result
+            .map_err(scylla_err)?
+            .into_rows_result()
+            .map_err(|e| row_err("member.list_by_member:rows", e))?
+            .rows::<(Uuid, i8, CqlTimestamp)>()
+            .map_err(|e| row_err("member.list_by_member:iter", e))?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| row_err("member.list_by_member:deser", e))?;
+
+        let next = if rows.len() == limit.max(0) as usize {
+            rows.last().map(|(id, _, _)| *id)
+        } else {
+            None
+        };
+
+        let memberships = rows
+            .into_iter()
+            .map(|(id, role, joined_at)| {
+                Ok(Membership {
+                    conversation_id: ConversationId::from_uuid(id),
+                    role:            Role::try_from(role)?,
+                    joined_at:       to_utc(joined_at),
+                })
+            })
+            .collect::<Result<Vec<_>, ChatError>>()?;
+        Ok((memberships, next))
     }
 }
 
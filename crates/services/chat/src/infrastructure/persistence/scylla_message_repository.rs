@@ -1,37 +1,44 @@
 use std::sync::Arc;
 
 use async_trait::async_trait;
-use chrono::Utc;
+use chrono::{DateTime, Utc};
 use scylla::serialize::row::SerializeRow;
 use scylla::statement::unprepared::Statement;
 use scylla::value::CqlTimestamp;
 use scylla_storage::ScyllaClient;
 use uuid::Uuid;
 
-use crate::application::port::{MessageRepository, MessageSummary};
+use crate::application::port::{MessageRepository, MessageSummary, SentMessage};
 use crate::domain::aggregate::Message;
-use crate::domain::value_object::{ContentType, ConversationId};
+use crate::domain::event::MessageEvent;
+use crate::domain::value_object::{ContentType, ConversationId, ProfileId};
 use crate::error::ChatError;
+use crate::infrastructure::event::chat_outbox::outbox_err;
+use crate::infrastructure::event::ChatOutbox;
 use crate::infrastructure::persistence::bucket::{message_bucket, MAX_BUCKET_WALK};
-use crate::infrastructure::persistence::model::MessageRow;
+use crate::infrastructure::persistence::model::{MessageRow, SentMessageRow};
 use crate::infrastructure::persistence::statement::{fast, row_err, scylla_err, strict};
 use crate::infrastructure::persistence::time::{to_cql, to_utc};
 
 const HISTORY_COLS: &str =
     "created_at, message_id, sender_id, content_type, body, media_ref, reply_to";
 
+const SENT_COLS: &str =
+    "created_at, message_id, conversation_id, content_type, body, media_ref, reply_to";
+
 /// ScyllaDB adapter for the time-bucketed message log
 /// (`chat.messages_by_conversation`).
 pub struct ScyllaMessageRepository {
     client:       Arc<ScyllaClient>,
+    outbox:       ChatOutbox,
     /// Bucket window in hours; must match the writer's value cluster-wide so a
     /// message is always read from the partition it was written to.
     bucket_hours: u32,
 }
 
 impl ScyllaMessageRepository {
-    pub fn new(client: Arc<ScyllaClient>, bucket_hours: u32) -> Self {
-        Self { client, bucket_hours }
+    pub fn new(client: Arc<ScyllaClient>, outbox: ChatOutbox, bucket_hours: u32) -> Self {
+        Self { client, outbox, bucket_hours }
     }
 
     /// Executes a profiled statement and collects the resulting message rows.
@@ -116,36 +123,54 @@ impl ScyllaMessageRepository {
 
 #[async_trait]
 impl MessageRepository for ScyllaMessageRepository {
-    async fn insert(&self, m: &Message) -> Result<(), ChatError> {
+    async fn insert(&self, m: &Message, event: &MessageEvent) -> Result<(), ChatError> {
         let bucket = message_bucket(m.created_at().timestamp_millis(), self.bucket_hours);
 
         // Strict (LocalQuorum): member writes are the durable source of truth.
-        let stmt = strict(
-            &self.client,
-            "INSERT INTO chat.messages_by_conversation \
-             (conversation_id, bucket, created_at, message_id, sender_id, content_type, \
-              body, media_ref, reply_to) \
-             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)",
+        // The log row, the sender mirror row for data-subject requests and the
+        // fan-out event land together or not at all.
+        let mut batch = self.outbox.batch();
+        batch.write(
+            strict(
+                &self.client,
+                "INSERT INTO chat.messages_by_conversation \
+                 (conversation_id, bucket, created_at, message_id, sender_id, content_type, \
+                  body, media_ref, reply_to) \
+                 VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)",
+            ),
+            (
+                m.conversation_id().as_uuid(),
+                bucket,
+                to_cql(m.created_at()),
+                m.id().as_uuid(),
+                m.sender_id().as_uuid(),
+                m.content_type().as_tinyint(),
+                m.content().as_str(),
+                m.media_ref(),
+                m.reply_to().map(|r| r.as_uuid()),
+            ),
         );
-        self.client
-            .session
-            .execute_unpaged(
-                stmt,
-                (
-                    m.conversation_id().as_uuid(),
-                    bucket,
-                    to_cql(m.created_at()),
-                    m.id().as_uuid(),
-                    m.sender_id().as_uuid(),
-                    m.content_type().as_tinyint(),
-                    m.content().as_str(),
-                    m.media_ref(),
-                    m.reply_to().map(|r| r.as_uuid()),
-                ),
-            )
-            .await
-            .map_err(scylla_err)?;
-        Ok(())
+        batch.write(
+            strict(
+                &self.client,
+                "INSERT INTO chat.messages_by_sender \
+                 (sender_id, created_at, message_id, conversation_id, content_type, \
+                  body, media_ref, reply_to) \
+                 VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
+            ),
+            (
+                m.sender_id().as_uuid(),
+                to_cql(m.created_at()),
+                m.id().as_uuid(),
+                m.conversation_id().as_uuid(),
+                m.content_type().as_tinyint(),
+                m.content().as_str(),
+                m.media_ref(),
+                m.reply_to().map(|r| r.as_uuid()),
+            ),
+        );
+        self.outbox.enqueue_message(&mut batch, event)?;
+        batch.execute().await.map_err(outbox_err)
     }
 
     async fn list_history(
@@ -199,6 +224,105 @@ impl MessageRepository for ScyllaMessageRepository {
 
         Ok((out, next))
     }
+
+    async fn list_by_sender(
+        &self,
+        sender_id: &ProfileId,
+        limit:     i32,
+        cursor:    Option<(i64, Uuid)>,
+    ) -> Result<(Vec<SentMessage>, Option<(i64, Uuid)>), ChatError> {
+        let result = if let Some((cts, cid)) = cursor {
+            let stmt = fast(&self.client, &format!(
+                "SELECT {SENT_COLS} FROM chat.messages_by_sender \
+                 WHERE sender_id = ? AND (created_at, message_id) < (?, ?) \
+                 LIMIT ?"
+            ));
+            self.client
+                .session
+                .execute_unpaged(stmt, (sender_id.as_uuid(), CqlTimestamp(cts), cid, limit))
+                .await
+        } else {
+            let stmt = fast(&self.client, &format!(
+                "SELECT {SENT_COLS} FROM chat.messages_by_sender \
+                 WHERE sender_id = ? \
+                 LIMIT ?"
+            ));
+            self.client
+                .session
+                .execute_unpaged(stmt, (sender_id.as_uuid(), limit))
+                .await
+        };
+        let rows = result
+            .map_err(scylla_err)?
+            .into_rows_result()
+            .map_err(|e| row_err("message.by_sender:rows", e))?
+            .rows::<SentMessageRow>()
+            .map_err(|e| row_err("message.by_sender:iter", e))?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| row_err("message.by_sender:deser", e))?;
+
+        let next = if rows.len() == limit.max(0) as usize {
+            rows.last().map(|r| (r.created_at.0, r.message_id))
+        } else {
+            None
+        };
+
+        let sender = sender_id.as_uuid();
+        let sent = rows
+            .into_iter()
+            .map(|r| {
+                Ok(SentMessage {
+                    conversation_id: r.conversation_id,
+                    message: MessageSummary {
+                        message_id:   r.message_id,
+                        sender_id:    sender,
+                        content_type: ContentType::try_from(r.content_type)?,
+                        body:         r.body.unwrap_or_default(),
+                        media_ref:    r.media_ref,
+                        reply_to:     r.reply_to,
+                        created_at:   to_utc(r.created_at),
+                    },
+                })
+            })
+            .collect::<Result<Vec<_>, ChatError>>()?;
+        Ok((sent, next))
+    }
+
+    async fn scrub(
+        &self,
+        conversation_id: &ConversationId,
+        message_id:      Uuid,
+        created_at:      DateTime<Utc>,
+    ) -> Result<(), ChatError> {
+        let bucket = message_bucket(created_at.timestamp_millis(), self.bucket_hours);
+        let stmt = strict(
+            &self.client,
+            "UPDATE chat.messages_by_conversation SET body = '', media_ref = null \
+             WHERE conversation_id = ? AND bucket = ? AND created_at = ? AND message_id = ?",
+        );
+        self.client
+            .session
+            .execute_unpaged(
+                stmt,
+                (conversation_id.as_uuid(), bucket, to_cql(created_at), message_id),
+            )
+            .await
+            .map_err(scylla_err)?;
+        Ok(())
+    }
+
+    async fn delete_sent(&self, sender_id: &ProfileId) -> Result<(), ChatError> {
+        let stmt = strict(
+            &self.client,
+            "DELETE FROM chat.messages_by_sender WHERE sender_id = ?",
+        );
+        self.client
+            .session
+            .execute_unpaged(stmt, (sender_id.as_uuid(),))
+            .await
+            .map_err(scylla_err)?;
+        Ok(())
+    }
 }
 
 fn message_summary(r: MessageRow) -> Result<MessageSummary, ChatError> {
@@ -12,28 +12,53 @@
 //! The probe abstraction ([`HealthProbe`]) lives in the `health` foundation crate,
 //! so each storage crate exposes a ready-made probe for its client — no per-service
 //! closures.
+//!
+//! GDPR erasure is wired here too, because it reports to account: the shared
+//! [`subject_erasure`] consumer on `account.v1.events` erases the subject's chat
+//! footprint and confirms over `ConfirmSubjectErasure` (resilience binding
+//! `account`, endpoint `CHAT_ACCOUNT_GRPC_ENDPOINT`). The consumer starts only once the
+//! `messages_by_sender` and `conversations_by_member` backfills have completed,
+//! so every erasure walks indexes that cover the subject's older rows too.
 
 use std::sync::Arc;
+use std::time::Duration;
 
 use async_trait::async_trait;
-use cqrs::command::InMemoryCommandBus;
-use cqrs::query::InMemoryQueryBus;
 use infra_config::InfraRegistry;
 use redis_storage::RedisConfig;
 use scylla_storage::ScyllaConfig;
-use service_runtime::{HealthProbe, Service};
+use service_runtime::{AccessPolicy, HealthProbe, Service, TrafficAttributes};
+use subject_erasure::{
+    run_subject_erasure_consumer, DeletionRequest, EraseCommand, ErasureReporter, GrpcErasureReporter,
+};
 use tonic::service::RoutesBuilder;
 use tonic_reflection::server::Builder as ReflectionBuilder;
-use transport::kafka::config::client::KafkaClientConfig;
+use transport::grpc::client::{GrpcClientBuilder, GrpcClientConfig};
+use transport::kafka::config::{ConsumerConfig, KafkaClientConfig, ProducerConfig};
+use transport::kafka::consumer::{KafkaConsumerBuilder, KafkaConsumerHandle};
+use transport::kafka::producer::{KafkaProducerBuilder, KafkaProducerHandle};
 
-use crate::app::{App, AppConfig, Backends};
+use crate::app::{App, AppCommandBus, AppConfig, AppQueryBus, Backends};
+use crate::application::command::EraseSubjectDataCommand;
 use crate::config::ChatConfig;
 use crate::infrastructure::grpc::handler::{ChatServiceHandler, ChatServiceServer};
 use crate::infrastructure::grpc::server::FILE_DESCRIPTOR_SET;
+use crate::infrastructure::persistence::ReverseIndexBackfill;
+
+/// Account lifecycle stream carrying GDPR deletion requests.
+const ACCOUNT_EVENTS_TOPIC: &str = "account.v1.events";
+/// Consumer group for chat's part of a GDPR erasure.
+const SUBJECT_ERASURE_GROUP: &str = "chat-subject-erasure";
+/// Chat's name in account's erasure participant list.
+const ERASURE_PARTICIPANT: &str = "chat";
+/// Resilience binding of the erasure confirmations sent to account.
+const ACCOUNT_DEPENDENCY: &str = "account";
+/// Backoff before respawning the consumer after the runner returns.
+const CONSUMER_RESPAWN_BACKOFF: Duration = Duration::from_secs(5);
 
 /// The concrete tonic server type for chat, named once so both the health key
 /// and the reflection registration agree.
-type ChatServer = ChatServiceServer<ChatServiceHandler<InMemoryCommandBus, InMemoryQueryBus>>;
+type ChatServer = ChatServiceServer<ChatServiceHandler<Arc<AppCommandBus>, AppQueryBus>>;
 
 /// The chat service as hosted by [`service_runtime`]. Owns the wired [`App`]
 /// until it is consumed into the gRPC router.
@@ -47,10 +72,10 @@ impl Service for ChatService {
     const VERSION: &'static str = env!("CARGO_PKG_VERSION");
     const GRPC_SERVICE_NAME: &'static str = <ChatServer as tonic::server::NamedService>::NAME;
 
-    async fn build(_infra: Arc<InfraRegistry>) -> anyhow::Result<Self> {
-        // chat reads its tuning from the environment today; `_infra` is the seam
-        // for migrating these knobs onto hot-reloadable `[traffic]`/`[cache]`
-        // sections later without touching this signature.
+    async fn build(infra: Arc<InfraRegistry>) -> anyhow::Result<Self> {
+        // chat reads its tuning from the environment today; `infra` only backs
+        // the account channel, and is the seam for migrating these knobs onto
+        // hot-reloadable `[traffic]`/`[cache]` sections later.
         let config = ChatConfig::from_env();
 
         let app_config = AppConfig {
@@ -79,6 +104,23 @@ impl Service for ChatService {
             .await
             .map_err(|e| anyhow::anyhow!("chat app build: {e}"))?;
 
+        // The channel connects lazily; account being down only delays the
+        // confirmation (the consumer retries).
+        let account = GrpcClientBuilder::new(
+            GrpcClientConfig::new(
+                std::env::var("CHAT_ACCOUNT_GRPC_ENDPOINT")
+                    .unwrap_or_else(|_| "http://localhost:50059".to_owned()),
+            )
+            .with_dependency(ACCOUNT_DEPENDENCY),
+        )
+        .build_from_registry_lazy(&infra.resilience())
+        .map_err(|e| anyhow::anyhow!("chat account channel: {e}"))?;
+        spawn_subject_erasure_consumer(
+            ReverseIndexBackfill::new(Arc::clone(&app.scylla)),
+            Arc::clone(&app.command_bus),
+            Arc::new(GrpcErasureReporter::new(account, ERASURE_PARTICIPANT)),
+        );
+
         Ok(Self { app })
     }
 
@@ -89,6 +131,32 @@ impl Service for ChatService {
         ]
     }
 
+    fn access_policy(&self) -> AccessPolicy {
+        AccessPolicy::for_service(Self::GRPC_SERVICE_NAME)
+            .authenticated("CreateConversation")
+            .authenticated("ToggleVisibility")
+            .authenticated("JoinAsMember")
+            .authenticated("Subscribe")
+            .authenticated("Unsubscribe")
+            .authenticated("SendMessage")
+            .authenticated("MarkRead")
+            .authenticated("SendTyping")
+            .authenticated("Heartbeat")
+            .authenticated("GetHistory")
+            .authenticated("ListMembers")
+            .authenticated("ListSubscriptions")
+            .authenticated("StreamConversation")
+            .authenticated("StreamPublic")
+            // Account's GDPR data-export pipeline.
+            .internal("ExportSubjectData")
+    }
+
+    fn traffic_attributes(&self) -> TrafficAttributes {
+        // Field numbers from `chat.v1.SendMessageRequest`.
+        TrafficAttributes::for_service(Self::GRPC_SERVICE_NAME)
+            .field("SendMessage", "conversation_id", 1)
+    }
+
     fn register(self, routes: &mut RoutesBuilder) -> anyhow::Result<()> {
         let reflection = ReflectionBuilder::configure()
             .register_encoded_file_descriptor_set(FILE_DESCRIPTOR_SET)
@@ -99,3 +167,55 @@ impl Service for ChatService {
         Ok(())
     }
 }
+
+/// Runs `backfill` to completion, then spawns the supervised subject-erasure
+/// consumer, respawning after [`CONSUMER_RESPAWN_BACKOFF`] whenever the runner
+/// returns. Until the backfill completes, deletion requests wait in Kafka.
+fn spawn_subject_erasure_consumer(
+    backfill:    ReverseIndexBackfill,
+    command_bus: Arc<AppCommandBus>,
+    reporter:    Arc<dyn ErasureReporter>,
+) {
+    let eraser = Arc::new(EraseCommand::new(command_bus, |request: &DeletionRequest| {
+        EraseSubjectDataCommand { profile_ids: request.profile_ids.clone() }
+    }));
+    tokio::spawn(async move {
+        while let Err(error) = backfill.run().await {
+            tracing::warn!(%error, "reverse-index backfill failed; retrying");
+            tokio::time::sleep(CONSUMER_RESPAWN_BACKOFF).await;
+        }
+        loop {
+            match build_consumer() {
+                Ok((consumer, producer)) => {
+                    run_subject_erasure_consumer(
+                        ERASURE_PARTICIPANT,
+                        consumer,
+                        Arc::clone(&eraser),
+                        Arc::clone(&reporter),
+                        producer,
+                    )
+                    .await;
+                    tracing::warn!("subject-erasure consumer exited; respawning after backoff");
+                }
+                Err(error) => {
+                    tracing::error!(%error, "failed to build subject-erasure consumer; retrying");
+                }
+            }
+            tokio::time::sleep(CONSUMER_RESPAWN_BACKOFF).await;
+        }
+    });
+}
+
+/// Builds the manual-commit consumer (subscribed to `account.v1.events`) and the
+/// dead-letter producer the runner needs.
+fn build_consumer() -> anyhow::Result<(KafkaConsumerHandle, KafkaProducerHandle)> {
+    let kafka = KafkaClientConfig::from_env();
+    let consumer = KafkaConsumerBuilder::new(ConsumerConfig::new(kafka.clone(), SUBJECT_ERASURE_GROUP))
+        .subscribe(ACCOUNT_EVENTS_TOPIC)
+        .build()
+        .map_err(|e| anyhow::anyhow!("build subject-erasure consumer: {e}"))?;
+    let producer = KafkaProducerBuilder::new(ProducerConfig::new(kafka))
+        .build()
+        .map_err(|e| anyhow::anyhow!("build subject-erasure dead-letter producer: {e}"))?;
+    Ok((consumer, producer))
+}
@@ -28,13 +28,11 @@ use redis_storage::RedisConfig;
 use scylla_storage::ScyllaConfig;
 use transport::kafka::config::client::KafkaClientConfig;
 
-use chat::app::{App, AppConfig, Backends};
+use chat::app::{App, AppCommandBus, AppConfig, AppQueryBus, Backends};
 use chat::application::port::{HotTailCache, PresenceStore, RoutingRegistry};
 use chat::infrastructure::grpc::handler::ChatServiceHandler;
 use chat::infrastructure::streaming::ConversationBroadcastRegistry;
 
-use cqrs::command::InMemoryCommandBus;
-use cqrs::query::InMemoryQueryBus;
 
 // ── Re-exports the scenarios drive the service through ───────────────────────
 
@@ -97,7 +95,7 @@ impl Default for HarnessOptions {
 
 /// A fully-wired chat service bound to ephemeral infra, plus assertion handles.
 pub struct TestHarness {
-    pub handler:           ChatServiceHandler<InMemoryCommandBus, InMemoryQueryBus>,
+    pub handler:           ChatServiceHandler<Arc<AppCommandBus>, AppQueryBus>,
     pub presence:          Arc<dyn PresenceStore>,
     pub routing:           Arc<dyn RoutingRegistry>,
     pub hot_tail:          Arc<dyn HotTailCache>,
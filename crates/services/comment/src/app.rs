@@ -1,82 +1,135 @@
 //! The comment service's composition root.
 //!
-//! [`App::build`] is *pure composition*: a ScyllaDB config and a
-//! [`CommentEventPublisher`] in, a fully-wired CQRS graph out. It binds no socket
-//! and reads no environment, so the production entrypoint
-//! ([`crate::infrastructure::grpc::server::serve`]) and the live integration
-//! harness assemble the exact same graph.
+//! [`App::build`] is *pure composition*: a ScyllaDB config in, a fully-wired CQRS
+//! graph out. It binds no socket and reads no environment, so the production
+//! entrypoint ([`crate::infrastructure::grpc::server::serve`]) and the live
+//! integration harness assemble the exact same graph.
 //!
-//! The event publisher is a generic parameter (the chat/post pattern): production
-//! passes the Kafka publisher; the integration harness passes an in-process no-op,
-//! so the dual-table and tombstone-vs-purge scenarios run without a broker.
+//! Events never leave through a separate publish: the repository writes them into
+//! `comment.outbox` in the same logged batch as the comment rows. Production
+//! spawns the relay that forwards them (over the same client, via
+//! [`App::assemble`]); the integration harness spawns none, so the dual-table
+//! and tombstone-vs-purge scenarios run without a broker.
 
 use std::sync::Arc;
 
 use cqrs::command::{CommandBusBuilder, InMemoryCommandBus};
+use cqrs::middleware::{
+    IdempotencyCommandBus, IdempotencyLayer, MetricsCommandBus, MetricsLayer, MetricsQueryBus,
+    MiddlewarePipeline,
+};
 use cqrs::query::{InMemoryQueryBus, QueryBusBuilder};
+use idempotency::{ScyllaIdempotencyStore, ScyllaIdempotencyTable};
+use outbox::{ScyllaOutbox, ScyllaOutboxTable};
 use scylla_storage::{ScyllaClient, ScyllaConfig, ScyllaSessionBuilder};
 
 use crate::application::command::create_comment::{CreateCommentCommand, CreateCommentHandler};
 use crate::application::command::delete_comment::{DeleteCommentCommand, DeleteCommentHandler};
-use crate::application::port::CommentEventPublisher;
+use crate::application::command::erase_subject_data::{
+    EraseSubjectDataCommand, EraseSubjectDataHandler,
+};
+use crate::application::query::export_subject_data::{
+    ExportSubjectDataHandler, ExportSubjectDataQuery,
+};
 use crate::application::query::get_comment::{GetCommentHandler, GetCommentQuery};
 use crate::application::query::list_replies::{ListRepliesHandler, ListRepliesQuery};
 use crate::application::query::list_top_level::{ListTopLevelHandler, ListTopLevelQuery};
 use crate::infrastructure::persistence::ScyllaCommentRepository;
+use crate::infrastructure::publisher::{CommentOutbox, OUTBOX_KEYSPACE};
 
 /// Storage endpoints the graph is wired against. Comment is ScyllaDB-only; its
-/// events are emitted through the injected publisher.
+/// events go to `comment.outbox`.
 pub struct Backends {
     pub scylla: ScyllaConfig,
 }
 
+/// The command bus every entrypoint dispatches through: the registered handlers
+/// behind an [`IdempotencyLayer`] on the `comment.idempotency` table, so a retried
+/// `message_id` or client key is deduplicated across replicas. Comment has no Redis;
+/// the store claims with lightweight transactions and expires rows by TTL.
+/// A [`MetricsLayer`] outermost times every dispatch, replays included.
+pub type AppCommandBus =
+    MetricsCommandBus<IdempotencyCommandBus<InMemoryCommandBus, ScyllaIdempotencyStore>>;
+
+/// The query bus every read path dispatches through: the registered handlers,
+/// timed by a [`MetricsLayer`].
+pub type AppQueryBus = MetricsQueryBus<InMemoryQueryBus>;
+
 /// A fully-wired comment service bound to its backends. The buses exposed here
 /// are the *same* instances the handlers are registered into; `GetComment` reads
 /// the canonical `comments` table while `ListTopLevel`/`ListReplies` read the
 /// `comments_by_post` thread index, so the query bus proves their consistency.
 pub struct App {
-    pub command_bus: Arc<InMemoryCommandBus>,
-    pub query_bus:   Arc<InMemoryQueryBus>,
+    pub command_bus: Arc<AppCommandBus>,
+    pub query_bus:   Arc<AppQueryBus>,
     /// Live storage client, retained so the runtime's readiness loop can probe
     /// its liveness (see [`crate::service`]).
     pub scylla:      Arc<ScyllaClient>,
 }
 
 impl App {
-    /// Builds the ScyllaDB client and repository, then the CQRS buses with every
-    /// comment command and query registered against the supplied `publisher`.
-    pub async fn build<P: CommentEventPublisher>(
-        backends:  Backends,
-        publisher: Arc<P>,
-    ) -> Result<Self, Box<dyn std::error::Error>> {
+    /// Builds the ScyllaDB client, then [`App::assemble`]s the graph on it over
+    /// `comment.outbox`.
+    pub async fn build(backends: Backends) -> Result<Self, Box<dyn std::error::Error>> {
         let scylla_client = Arc::new(ScyllaSessionBuilder::new(backends.scylla).build().await?);
-        let repository = Arc::new(ScyllaCommentRepository::new(Arc::clone(&scylla_client)));
+        let outbox = CommentOutbox::new(ScyllaOutbox::new(
+            Arc::clone(&scylla_client),
+            ScyllaOutboxTable::new(OUTBOX_KEYSPACE)?,
+        ));
+        Self::assemble(scylla_client, outbox)
+    }
 
+    /// Builds the repository over `outbox` on an existing client, then the CQRS
+    /// buses with every comment command and query registered. The production
+    /// entrypoints use this directly, so the relay shares the repository's
+    /// client.
+    pub fn assemble(
+        scylla_client: Arc<ScyllaClient>,
+        outbox:        CommentOutbox,
+    ) -> Result<Self, Box<dyn std::error::Error>> {
+        let repository =
+            Arc::new(ScyllaCommentRepository::new(Arc::clone(&scylla_client), outbox));
+
+        let handlers = CommandBusBuilder::new()
+            .register::<CreateCommentCommand, _>(CreateCommentHandler {
+                repository: Arc::clone(&repository),
+            })?
+            .register::<DeleteCommentCommand, _>(DeleteCommentHandler {
+                repository: Arc::clone(&repository),
+            })?
+            .register::<EraseSubjectDataCommand, _>(EraseSubjectDataHandler {
+                repository: Arc::clone(&repository),
+            })?
+            .build();
         let command_bus = Arc::new(
-            CommandBusBuilder::new()
-                .register::<CreateCommentCommand, _>(CreateCommentHandler {
-                    repository: Arc::clone(&repository),
-                    publisher:  Arc::clone(&publisher),
-                })?
-                .register::<DeleteCommentCommand, _>(DeleteCommentHandler {
-                    repository: Arc::clone(&repository),
-                    publisher:  Arc::clone(&publisher),
-                })?
+            MiddlewarePipeline::new(handlers)
+                .layer(IdempotencyLayer::new(ScyllaIdempotencyStore::new(
+                    Arc::clone(&scylla_client),
+                    ScyllaIdempotencyTable::new("comment")?,
+                )))
+                .layer(MetricsLayer::new())
                 .build(),
         );
 
         let query_bus = Arc::new(
-            QueryBusBuilder::new()
-                .register::<GetCommentQuery, _>(GetCommentHandler {
-                    repository: Arc::clone(&repository),
-                })?
-                .register::<ListTopLevelQuery, _>(ListTopLevelHandler {
-                    repository: Arc::clone(&repository),
-                })?
-                .register::<ListRepliesQuery, _>(ListRepliesHandler {
-                    repository: Arc::clone(&repository),
-                })?
-                .build(),
+            MiddlewarePipeline::new(
+                QueryBusBuilder::new()
+                    .register::<GetCommentQuery, _>(GetCommentHandler {
+                        repository: Arc::clone(&repository),
+                    })?
+                    .register::<ListTopLevelQuery, _>(ListTopLevelHandler {
+                        repository: Arc::clone(&repository),
+                    })?
+                    .register::<ListRepliesQuery, _>(ListRepliesHandler {
+                        repository: Arc::clone(&repository),
+                    })?
+                    .register::<ExportSubjectDataQuery, _>(ExportSubjectDataHandler {
+                        repository: Arc::clone(&repository),
+                    })?
+                    .build(),
+            )
+            .query_layer(MetricsLayer::new())
+            .build(),
         );
 
         Ok(Self { command_bus, query_bus, scylla: scylla_client })
@@ -1,10 +1,11 @@
 use std::sync::Arc;
 
 use cqrs::{Command, CommandHandler, Envelope};
+use serde::{Deserialize, Serialize};
 use validate_core::{FieldViolation, Validate};
 
 use crate::{
-    application::port::{CommentEventPublisher, CommentRepository},
+    application::port::CommentRepository,
     domain::{
         aggregate::Comment,
         entity::GifAttachment,
@@ -26,7 +27,17 @@ pub struct CreateCommentCommand {
     pub gif_height: Option<u32>,
 }
 
-impl Command for CreateCommentCommand {}
+/// What a successful `CreateComment` hands back. Recorded by the idempotency
+/// layer, so a client retrying under the same `idempotency-key` gets the id of the
+/// comment the first attempt created.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CreatedComment {
+    pub comment_id: String,
+}
+
+impl Command for CreateCommentCommand {
+    type Output = CreatedComment;
+}
 
 impl Validate for CreateCommentCommand {
     fn validate(&self) -> Result<(), Vec<FieldViolation>> {
@@ -53,19 +64,17 @@ impl Validate for CreateCommentCommand {
     }
 }
 
-pub struct CreateCommentHandler<R, P> {
+pub struct CreateCommentHandler<R> {
     pub repository: Arc<R>,
-    pub publisher:  Arc<P>,
 }
 
-impl<R, P> CommandHandler<CreateCommentCommand> for CreateCommentHandler<R, P>
+impl<R> CommandHandler<CreateCommentCommand> for CreateCommentHandler<R>
 where
     R: CommentRepository,
-    P: CommentEventPublisher,
 {
     type Error = CommentError;
 
-    async fn handle(&self, envelope: Envelope<CreateCommentCommand>) -> Result<(), CommentError> {
+    async fn handle(&self, envelope: Envelope<CreateCommentCommand>) -> Result<CreatedComment, CommentError> {
         let cmd = &envelope.payload;
 
         let comment_id = CommentId::try_from(cmd.comment_id.as_str())?;
@@ -100,11 +109,8 @@ where
             gif,
         )?;
 
-        self.repository.insert(&comment).await?;
-
-        for event in comment.take_events() {
-            self.publisher.publish(&event).await?;
-        }
+        let events = comment.take_events();
+        self.repository.insert(&comment, &events).await?;
 
         tracing::debug!(
             comment_id = %cmd.comment_id,
@@ -114,7 +120,7 @@ where
             "comment created"
         );
 
-        Ok(())
+        Ok(CreatedComment { comment_id: cmd.comment_id.clone() })
     }
 }
 
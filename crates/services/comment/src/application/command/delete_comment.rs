@@ -4,7 +4,7 @@ use cqrs::{Command, CommandHandler, Envelope};
 use validate_core::{FieldViolation, Validate};
 
 use crate::{
-    application::port::{CommentEventPublisher, CommentRepository},
+    application::port::CommentRepository,
     domain::{
         aggregate::DeletionStrategy,
         value_object::{CommentId, ProfileId},
@@ -17,7 +17,9 @@ pub struct DeleteCommentCommand {
     pub author_id:  String,
 }
 
-impl Command for DeleteCommentCommand {}
+impl Command for DeleteCommentCommand {
+    type Output = ();
+}
 
 impl Validate for DeleteCommentCommand {
     fn validate(&self) -> Result<(), Vec<FieldViolation>> {
@@ -32,15 +34,13 @@ impl Validate for DeleteCommentCommand {
     }
 }
 
-pub struct DeleteCommentHandler<R, P> {
+pub struct DeleteCommentHandler<R> {
     pub repository: Arc<R>,
-    pub publisher:  Arc<P>,
 }
 
-impl<R, P> CommandHandler<DeleteCommentCommand> for DeleteCommentHandler<R, P>
+impl<R> CommandHandler<DeleteCommentCommand> for DeleteCommentHandler<R>
 where
     R: CommentRepository,
-    P: CommentEventPublisher,
 {
     type Error = CommentError;
 
@@ -67,17 +67,18 @@ where
             .await?;
 
         let strategy = comment.delete(has_replies)?;
+        let events = comment.take_events();
 
         match strategy {
             DeletionStrategy::Tombstone => {
-                self.repository.soft_delete(&comment).await?;
+                self.repository.soft_delete(&comment, &events).await?;
                 tracing::debug!(
                     comment_id = %comment_id,
                     "comment tombstoned (has active replies)"
                 );
             }
             DeletionStrategy::Purge => {
-                self.repository.purge(&comment).await?;
+                self.repository.purge(&comment, &events).await?;
                 tracing::debug!(
                     comment_id = %comment_id,
                     "comment purged (leaf node)"
@@ -85,10 +86,6 @@ where
             }
         }
 
-        for event in comment.take_events() {
-            self.publisher.publish(&event).await?;
-        }
-
         Ok(())
     }
 }
@@ -0,0 +1,73 @@
+use std::sync::Arc;
+
+use cqrs::{Command, CommandHandler, Envelope};
+use validate_core::Validate;
+
+use crate::{
+    application::port::CommentRepository,
+    domain::{aggregate::DeletionStrategy, value_object::ProfileId},
+    error::CommentError,
+};
+
+/// Page size used to walk `comments_by_author` during an erasure.
+const ERASURE_PAGE_SIZE: i32 = 100;
+
+/// GDPR erasure of every comment written by the subject's profiles, driven by
+/// account's `gdpr_deletion_requested`. Each live comment goes through the
+/// regular deletion strategy: tombstoned (content nulled) when it has replies,
+/// purged otherwise. Already-deleted comments carry no content, so a
+/// redelivery finds nothing left to erase.
+pub struct EraseSubjectDataCommand {
+    pub profile_ids: Vec<String>,
+}
+
+impl Command for EraseSubjectDataCommand {
+    type Output = ();
+}
+
+impl Validate for EraseSubjectDataCommand {}
+
+pub struct EraseSubjectDataHandler<R> {
+    pub repository: Arc<R>,
+}
+
+impl<R> CommandHandler<EraseSubjectDataCommand> for EraseSubjectDataHandler<R>
+where
+    R: CommentRepository,
+{
+    type Error = CommentError;
+
+    async fn handle(&self, envelope: Envelope<EraseSubjectDataCommand>) -> Result<(), CommentError> {
+        for profile_id in &envelope.payload.profile_ids {
+            let author_id = ProfileId::try_from(profile_id.as_str())?;
+            let mut page_token: Option<String> = None;
+            loop {
+                let (ids, next) = self
+                    .repository
+                    .list_ids_by_author(&author_id, ERASURE_PAGE_SIZE, page_token.as_deref())
+                    .await?;
+                for id in ids {
+                    let Some(mut comment) = self.repository.find_by_id(&id).await? else {
+                        continue;
+                    };
+                    if comment.deleted_at().is_some() {
+                        continue;
+                    }
+                    let has_replies =
+                        self.repository.has_active_replies(comment.post_id(), &id).await?;
+                    let strategy = comment.delete(has_replies)?;
+                    let events = comment.take_events();
+                    match strategy {
+                        DeletionStrategy::Tombstone => self.repository.soft_delete(&comment, &events).await?,
+                        DeletionStrategy::Purge => self.repository.purge(&comment, &events).await?,
+                    }
+                }
+                match next {
+                    Some(token) => page_token = Some(token),
+                    None => break,
+                }
+            }
+        }
+        Ok(())
+    }
+}
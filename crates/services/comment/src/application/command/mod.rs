@@ -1,2 +1,3 @@
 pub mod create_comment;
 pub mod delete_comment;
+pub mod erase_subject_data;
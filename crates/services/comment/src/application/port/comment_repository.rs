@@ -4,6 +4,7 @@ use chrono::{DateTime, Utc};
 use crate::{
     domain::{
         aggregate::Comment,
+        event::DomainEvent,
         value_object::{CommentId, CommentStatus, PostId, ProfileId},
     },
     error::CommentError,
@@ -24,12 +25,15 @@ pub struct CommentSummary {
     pub created_at: DateTime<Utc>,
 }
 
+/// Every write takes the events it produced and stores them atomically with the
+/// rows — they are published from there, so an event exists exactly when its
+/// write does.
 #[async_trait]
 pub trait CommentRepository: Send + Sync + 'static {
-    /// Inserts a new comment into both `comment.comments` and
-    /// `comment.comments_by_post`. Both writes must succeed atomically from the
-    /// caller's perspective — failure leaves retry responsibility with the caller.
-    async fn insert(&self, comment: &Comment) -> Result<(), CommentError>;
+    /// Inserts a new comment into `comment.comments`, `comment.comments_by_post`
+    /// and `comment.comments_by_author`, all or none. A failure leaves retry
+    /// responsibility with the caller.
+    async fn insert(&self, comment: &Comment, events: &[DomainEvent]) -> Result<(), CommentError>;
 
     /// Point-reads a comment by its ID from `comment.comments`.
     async fn find_by_id(&self, id: &CommentId) -> Result<Option<Comment>, CommentError>;
@@ -44,10 +48,10 @@ pub trait CommentRepository: Send + Sync + 'static {
     ) -> Result<bool, CommentError>;
 
     /// Soft-deletes: updates status and nulls content fields in both tables.
-    async fn soft_delete(&self, comment: &Comment) -> Result<(), CommentError>;
+    async fn soft_delete(&self, comment: &Comment, events: &[DomainEvent]) -> Result<(), CommentError>;
 
-    /// Physical delete: removes rows from both tables.
-    async fn purge(&self, comment: &Comment) -> Result<(), CommentError>;
+    /// Physical delete: removes the comment's rows from every table.
+    async fn purge(&self, comment: &Comment, events: &[DomainEvent]) -> Result<(), CommentError>;
 
     /// Paginates top-level comments for a post from `comments_by_post`,
     /// ordered by `created_at DESC`. Returns `(summaries, next_page_token)`.
@@ -67,4 +71,13 @@ pub trait CommentRepository: Send + Sync + 'static {
         limit:      i32,
         page_token: Option<&str>,
     ) -> Result<(Vec<CommentSummary>, Option<String>), CommentError>;
+
+    /// Paginates the ids of every comment `author_id` wrote, newest-first, from
+    /// `comments_by_author`. Returns `(ids, next_page_token)`.
+    async fn list_ids_by_author(
+        &self,
+        author_id:  &ProfileId,
+        limit:      i32,
+        page_token: Option<&str>,
+    ) -> Result<(Vec<CommentId>, Option<String>), CommentError>;
 }
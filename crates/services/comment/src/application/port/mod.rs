@@ -1,5 +1,3 @@
 pub mod comment_repository;
-pub mod event_publisher;
 
 pub use comment_repository::{CommentRepository, CommentSummary};
-pub use event_publisher::CommentEventPublisher;
@@ -0,0 +1,105 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use cqrs::{Envelope, Query, QueryHandler};
+use serde::Serialize;
+
+use crate::{
+    application::port::CommentRepository,
+    domain::{aggregate::Comment, value_object::{CommentStatus, ProfileId}},
+    error::CommentError,
+};
+
+/// Page size used to walk `comments_by_author` during an export.
+const EXPORT_PAGE_SIZE: i32 = 100;
+
+/// GDPR Art. 20 export of every comment written by the subject's profiles,
+/// read back from `comments` (tombstoned ones carry no content).
+pub struct ExportSubjectDataQuery {
+    pub profile_ids: Vec<String>,
+}
+
+impl Query for ExportSubjectDataQuery {
+    type Response = SubjectDataExport;
+}
+
+/// This service's slice of a data-subject export.
+#[derive(Debug, Clone, Serialize)]
+pub struct SubjectDataExport {
+    pub comments: Vec<CommentExport>,
+}
+
+impl SubjectDataExport {
+    pub fn record_count(&self) -> u32 {
+        self.comments.len() as u32
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CommentExport {
+    pub comment_id: String,
+    pub post_id:    String,
+    pub author_id:  String,
+    pub parent_id:  Option<String>,
+    pub status:     &'static str,
+    pub body:       Option<String>,
+    pub gif_url:    Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub deleted_at: Option<DateTime<Utc>>,
+}
+
+impl From<&Comment> for CommentExport {
+    fn from(c: &Comment) -> Self {
+        Self {
+            comment_id: c.id().as_str(),
+            post_id:    c.post_id().as_str(),
+            author_id:  c.author_id().as_str(),
+            parent_id:  c.parent_id().map(|id| id.as_str()),
+            status:     match c.status() {
+                CommentStatus::Published => "published",
+                CommentStatus::Deleted   => "deleted",
+            },
+            body:       c.body().map(|b| b.as_str().to_owned()),
+            gif_url:    c.gif().map(|g| g.gif_url.clone()),
+            created_at: c.created_at(),
+            updated_at: c.updated_at(),
+            deleted_at: c.deleted_at(),
+        }
+    }
+}
+
+pub struct ExportSubjectDataHandler<R> {
+    pub repository: Arc<R>,
+}
+
+impl<R: CommentRepository> QueryHandler<ExportSubjectDataQuery> for ExportSubjectDataHandler<R> {
+    type Error = CommentError;
+
+    async fn handle(
+        &self,
+        envelope: Envelope<ExportSubjectDataQuery>,
+    ) -> Result<SubjectDataExport, CommentError> {
+        let mut comments = Vec::new();
+        for profile_id in &envelope.payload.profile_ids {
+            let author_id = ProfileId::try_from(profile_id.as_str())?;
+            let mut page_token: Option<String> = None;
+            loop {
+                let (ids, next) = self
+                    .repository
+                    .list_ids_by_author(&author_id, EXPORT_PAGE_SIZE, page_token.as_deref())
+                    .await?;
+                for id in ids {
+                    if let Some(comment) = self.repository.find_by_id(&id).await? {
+                        comments.push(CommentExport::from(&comment));
+                    }
+                }
+                match next {
+                    Some(token) => page_token = Some(token),
+                    None => break,
+                }
+            }
+        }
+        Ok(SubjectDataExport { comments })
+    }
+}
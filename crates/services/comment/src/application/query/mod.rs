@@ -1,3 +1,4 @@
+pub mod export_subject_data;
 pub mod get_comment;
 pub mod list_replies;
 pub mod list_top_level;
@@ -2,6 +2,8 @@ use tonic::{Request, Response, Status};
 use uuid::Uuid;
 
 use cqrs::{CommandBus, Envelope, QueryBus};
+use service_runtime::inject_into_envelope;
+use transport::grpc::{idempotency_key, payload_fingerprint};
 
 use crate::application::command::{
     create_comment::CreateCommentCommand,
@@ -9,6 +11,7 @@ use crate::application::command::{
 };
 use crate::application::port::CommentSummary;
 use crate::application::query::{
+    export_subject_data::{ExportSubjectDataQuery, SubjectDataExport},
     get_comment::GetCommentQuery,
     list_replies::ListRepliesQuery,
     list_top_level::ListTopLevelQuery,
@@ -52,7 +55,9 @@ where
         &self,
         request: Request<proto::CreateCommentRequest>,
     ) -> Result<Response<proto::CreateCommentResponse>, Status> {
-        let req = request.into_inner();
+        let key         = idempotency_key(request.metadata())?;
+        let req         = request.into_inner();
+        let fingerprint = payload_fingerprint(&req);
 
         let comment_id = if req.comment_id.is_empty() {
             Uuid::now_v7().to_string()
@@ -61,7 +66,7 @@ where
         };
 
         let cmd = CreateCommentCommand {
-            comment_id: comment_id.clone(),
+            comment_id,
             post_id:    req.post_id.clone(),
             author_id:  req.author_id,
             parent_id:  Some(req.parent_id).filter(|s| !s.is_empty()),
@@ -72,12 +77,19 @@ where
             gif_height: if req.gif_height == 0 { None } else { Some(req.gif_height) },
         };
 
+        let mut envelope = Envelope::new(Uuid::now_v7(), cmd);
+        inject_into_envelope(&mut envelope);
+        if let Some(key) = key {
+            envelope = envelope.with_idempotency_key(key, fingerprint);
+        }
+
+        // On a retry the output is the first attempt's, not this request's id.
         self.command_bus
-            .dispatch(Envelope::new(Uuid::now_v7(), cmd))
+            .dispatch(envelope)
             .await
-            .map(|_| Response::new(proto::CreateCommentResponse {
-                comment_id,
-                post_id: req.post_id,
+            .map(|created| Response::new(proto::CreateCommentResponse {
+                comment_id: created.comment_id,
+                post_id:    req.post_id,
             }))
             .map_err(cqrs_to_status)
     }
@@ -164,6 +176,25 @@ where
             next_token: next.unwrap_or_default(),
         }))
     }
+
+    pub async fn export_subject_data(
+        &self,
+        request: Request<proto::ExportSubjectDataRequest>,
+    ) -> Result<Response<proto::ExportSubjectDataResponse>, Status> {
+        let query = ExportSubjectDataQuery { profile_ids: request.into_inner().profile_ids };
+        let export: SubjectDataExport = self
+            .query_bus
+            .dispatch(Envelope::new(Uuid::now_v7(), query))
+            .await
+            .map_err(cqrs_to_status)?;
+
+        let document = serde_json::to_vec(&export)
+            .map_err(|e| Status::internal(format!("encode export document: {e}")))?;
+        Ok(Response::new(proto::ExportSubjectDataResponse {
+            document,
+            record_count: export.record_count(),
+        }))
+    }
 }
 
 // ── Proto trait implementation ────────────────────────────────────────────────
@@ -208,6 +239,13 @@ where
     ) -> Result<Response<proto::ListCommentsResponse>, Status> {
         self.list_replies(request).await
     }
+
+    async fn export_subject_data(
+        &self,
+        request: Request<proto::ExportSubjectDataRequest>,
+    ) -> Result<Response<proto::ExportSubjectDataResponse>, Status> {
+        self.export_subject_data(request).await
+    }
 }
 
 // ── Conversion helpers ────────────────────────────────────────────────────────
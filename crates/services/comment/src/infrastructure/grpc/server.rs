@@ -1,9 +1,8 @@
 use std::net::SocketAddr;
 use std::sync::Arc;
 
-use cqrs::command::InMemoryCommandBus;
-use cqrs::query::InMemoryQueryBus;
-use scylla_storage::ScyllaConfig;
+use outbox::{KafkaOutboxSink, RelayConfig};
+use scylla_storage::{ScyllaConfig, ScyllaSessionBuilder};
 use tonic::transport::Server;
 use tonic_health::server::health_reporter;
 use tonic_reflection::server::Builder as ReflectionBuilder;
@@ -11,11 +10,11 @@ use transport::kafka::config::client::KafkaClientConfig;
 use transport::kafka::config::producer::ProducerConfig;
 use transport::kafka::producer::builder::KafkaProducerBuilder;
 
-use crate::app::{App, Backends};
+use crate::app::{App, AppCommandBus, AppQueryBus};
 use crate::infrastructure::grpc::handler::comment_service_handler::{
     CommentServiceHandler, CommentServiceServer,
 };
-use crate::infrastructure::publisher::KafkaCommentEventPublisher;
+use crate::infrastructure::publisher::scylla_outbox;
 
 pub const FILE_DESCRIPTOR_SET: &[u8] =
     comment_api::FILE_DESCRIPTOR_SET;
@@ -23,20 +22,26 @@ pub const FILE_DESCRIPTOR_SET: &[u8] =
 /// The gRPC handler type the server serves: the buses are shared by `Arc` (the
 /// same instances the composition root retains).
 type ServingHandler =
-    CommentServiceHandler<Arc<InMemoryCommandBus>, Arc<InMemoryQueryBus>>;
+    CommentServiceHandler<Arc<AppCommandBus>, Arc<AppQueryBus>>;
 
 /// Bootstraps and runs the comment gRPC server.
 ///
-/// Builds the Kafka event publisher, then the full service graph via the shared
-/// composition root ([`App::build`]), and serves until shutdown.
+/// Builds the outbox and its relay, then the full service graph via the
+/// shared composition root ([`App::assemble`]), and serves until shutdown.
 pub async fn serve(addr: SocketAddr) -> Result<(), Box<dyn std::error::Error>> {
-    // The Kafka producer backs the outbound event publisher.
+    let scylla = Arc::new(ScyllaSessionBuilder::new(ScyllaConfig::from_env()).build().await?);
+
+    // The Kafka producer backs the relay's sink; the repository only enqueues.
     let producer = KafkaProducerBuilder::new(ProducerConfig::new(KafkaClientConfig::from_env()))
         .build()?;
-    let publisher = Arc::new(KafkaCommentEventPublisher::new(producer));
+    let (outbox, relay) = scylla_outbox(
+        &scylla,
+        Arc::new(KafkaOutboxSink::new(producer)),
+        RelayConfig::from_env(),
+    )?;
 
-    let backends = Backends { scylla: ScyllaConfig::from_env() };
-    let app = App::build(backends, publisher).await?;
+    let app = App::assemble(scylla, outbox)?;
+    tokio::spawn(relay.run());
 
     // ── gRPC server ───────────────────────────────────────────────────────────
 
@@ -1,4 +1,6 @@
+pub mod author_index_backfill;
 pub mod model;
 pub mod scylla_comment_repository;
 
+pub use author_index_backfill::AuthorIndexBackfill;
 pub use scylla_comment_repository::ScyllaCommentRepository;
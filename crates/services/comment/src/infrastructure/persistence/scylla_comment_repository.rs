@@ -6,15 +6,19 @@ use chrono::{DateTime, TimeZone, Utc};
 use scylla::observability::history::HistoryListener;
 use scylla::statement::unprepared::Statement;
 use scylla::value::CqlTimestamp;
+use outbox::ScyllaOutboxBatch;
 use scylla_storage::{ProfileKind as ScyllaProfileKind, ScyllaClient, ScyllaStorageError};
 use uuid::Uuid;
 
 use crate::application::port::{CommentRepository, CommentSummary};
 use crate::domain::aggregate::Comment;
 use crate::domain::entity::GifAttachment;
+use crate::domain::event::DomainEvent;
 use crate::domain::value_object::{CommentBody, CommentId, CommentStatus, PostId, ProfileId};
 use crate::error::CommentError;
 use crate::infrastructure::persistence::model::{CommentFeedRow, CommentRow};
+use crate::infrastructure::publisher::comment_outbox::outbox_err;
+use crate::infrastructure::publisher::CommentOutbox;
 
 /// Sentinel UUID stored in `comments_by_post.parent_id` for top-level comments.
 /// Using nil UUID ensures top-level rows sort before all reply slots and allows
@@ -28,6 +32,14 @@ struct FeedPageToken {
     created_at_ms: i64,
 }
 
+/// `comments_by_author` cursor: the full clustering key of the last row, so a
+/// page never splits comments that share a millisecond.
+#[derive(serde::Serialize, serde::Deserialize)]
+struct AuthorPageToken {
+    created_at_ms: i64,
+    comment_id:    Uuid,
+}
+
 // ── Error helpers ─────────────────────────────────────────────────────────────
 
 fn scylla_err(e: scylla::errors::ExecutionError) -> CommentError {
@@ -139,15 +151,48 @@ fn encode_page_token(created_at_ms: i64) -> String {
     URL_SAFE_NO_PAD.encode(json)
 }
 
+fn decode_author_page_token(page_token: Option<&str>) -> Result<Option<AuthorPageToken>, CommentError> {
+    page_token
+        .map(|t| {
+            let bytes = URL_SAFE_NO_PAD
+                .decode(t)
+                .map_err(|_| token_err("invalid base64 encoding"))?;
+            serde_json::from_slice(&bytes)
+                .map_err(|_| token_err("invalid page token format"))
+        })
+        .transpose()
+}
+
+fn encode_author_page_token(created_at_ms: i64, comment_id: Uuid) -> String {
+    let tok  = AuthorPageToken { created_at_ms, comment_id };
+    let json = serde_json::to_vec(&tok).unwrap_or_default();
+    URL_SAFE_NO_PAD.encode(json)
+}
+
 // ── Repository ────────────────────────────────────────────────────────────────
 
+/// Every write is one logged batch: the `comments` row, the index entries it
+/// touches and the write's events in `comment.outbox`. ScyllaDB applies all of
+/// it or none of it, so the indexes never drift from the row and an event exists
+/// exactly when its write does.
 pub struct ScyllaCommentRepository {
     client: Arc<ScyllaClient>,
+    outbox: CommentOutbox,
 }
 
 impl ScyllaCommentRepository {
-    pub fn new(client: Arc<ScyllaClient>) -> Self {
-        Self { client }
+    pub fn new(client: Arc<ScyllaClient>, outbox: CommentOutbox) -> Self {
+        Self { client, outbox }
+    }
+
+    /// Adds `events` to `batch` and applies it.
+    async fn apply(
+        &self,
+        mut batch: ScyllaOutboxBatch<'_>,
+        events:    &[DomainEvent],
+    ) -> Result<(), CommentError> {
+        self.outbox.enqueue(&mut batch, events)?;
+        batch.execute().await.map_err(outbox_err)
     }
 
     fn strict_stmt(&self, cql: &str) -> Statement {
@@ -185,75 +230,81 @@ impl ScyllaCommentRepository {
 impl CommentRepository for ScyllaCommentRepository {
     // ── insert ────────────────────────────────────────────────────────────────
 
-    async fn insert(&self, comment: &Comment) -> Result<(), CommentError> {
+    async fn insert(&self, comment: &Comment, events: &[DomainEvent]) -> Result<(), CommentError> {
         let parent_uuid = comment
             .parent_id()
             .map(CommentId::as_uuid)
             .unwrap_or(NIL_UUID);
 
-        let gif_id     = comment.gif().map(|g| g.gif_id.as_str());
-        let gif_url    = comment.gif().map(|g| g.gif_url.as_str());
+        let body       = comment.body().map(|b| b.as_str().to_owned());
+        let gif_id     = comment.gif().map(|g| g.gif_id.clone());
+        let gif_url    = comment.gif().map(|g| g.gif_url.clone());
         let gif_width  = comment.gif().map(|g| g.gif_width as i32);
         let gif_height = comment.gif().map(|g| g.gif_height as i32);
 
-        // Write to source-of-truth table first.
-        let stmt_main = self.strict_stmt(
-            "INSERT INTO comment.comments \
-             (comment_id, post_id, author_id, parent_id, status, body, \
-              gif_id, gif_url, gif_width, gif_height, created_at, updated_at, deleted_at) \
-             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+        let mut batch = self.outbox.batch();
+
+        // Source-of-truth table.
+        batch.write(
+            self.strict_stmt(
+                "INSERT INTO comment.comments \
+                 (comment_id, post_id, author_id, parent_id, status, body, \
+                  gif_id, gif_url, gif_width, gif_height, created_at, updated_at, deleted_at) \
+                 VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+            ),
+            (
+                comment.id().as_uuid(),
+                comment.post_id().as_uuid(),
+                comment.author_id().as_uuid(),
+                parent_uuid,
+                comment.status().as_tinyint(),
+                body.clone(),
+                gif_id,
+                gif_url.clone(),
+                gif_width,
+                gif_height,
+                dt_ms(comment.created_at()),
+                dt_ms(comment.updated_at()),
+                comment.deleted_at().map(dt_ms),
+            ),
         );
-        self.client
-            .session
-            .execute_unpaged(
-                stmt_main,
-                (
-                    comment.id().as_uuid(),
-                    comment.post_id().as_uuid(),
-                    comment.author_id().as_uuid(),
-                    parent_uuid,
-                    comment.status().as_tinyint(),
-                    comment.body().map(CommentBody::as_str),
-                    gif_id,
-                    gif_url,
-                    gif_width,
-                    gif_height,
-                    dt_ms(comment.created_at()),
-                    dt_ms(comment.updated_at()),
-                    comment.deleted_at().map(dt_ms),
-                ),
-            )
-            .await
-            .map_err(scylla_err)?;
 
-        // Write to feed table.
-        let stmt_feed = self.strict_stmt(
-            "INSERT INTO comment.comments_by_post \
-             (post_id, parent_id, created_at, comment_id, author_id, status, \
-              body, gif_url, gif_width, gif_height) \
-             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+        // Feed table.
+        batch.write(
+            self.strict_stmt(
+                "INSERT INTO comment.comments_by_post \
+                 (post_id, parent_id, created_at, comment_id, author_id, status, \
+                  body, gif_url, gif_width, gif_height) \
+                 VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+            ),
+            (
+                comment.post_id().as_uuid(),
+                parent_uuid,
+                dt_ms(comment.created_at()),
+                comment.id().as_uuid(),
+                comment.author_id().as_uuid(),
+                comment.status().as_tinyint(),
+                body,
+                gif_url,
+                gif_width,
+                gif_height,
+            ),
+        );
+
+        // Author index.
+        batch.write(
+            self.strict_stmt(
+                "INSERT INTO comment.comments_by_author (author_id, created_at, comment_id) \
+                 VALUES (?, ?, ?)",
+            ),
+            (
+                comment.author_id().as_uuid(),
+                dt_ms(comment.created_at()),
+                comment.id().as_uuid(),
+            ),
         );
-        self.client
-            .session
-            .execute_unpaged(
-                stmt_feed,
-                (
-                    comment.post_id().as_uuid(),
-                    parent_uuid,
-                    dt_ms(comment.created_at()),
-                    comment.id().as_uuid(),
-                    comment.author_id().as_uuid(),
-                    comment.status().as_tinyint(),
-                    comment.body().map(CommentBody::as_str),
-                    gif_url,
-                    gif_width,
-                    gif_height,
-                ),
-            )
-            .await
-            .map_err(scylla_err)?;
 
-        Ok(())
+        self.apply(batch, events).await
     }
 
     // ── find_by_id ────────────────────────────────────────────────────────────
@@ -307,7 +358,7 @@ impl CommentRepository for ScyllaCommentRepository {
 
     // ── soft_delete ───────────────────────────────────────────────────────────
 
-    async fn soft_delete(&self, comment: &Comment) -> Result<(), CommentError> {
+    async fn soft_delete(&self, comment: &Comment, events: &[DomainEvent]) -> Result<(), CommentError> {
         let parent_uuid = comment
             .parent_id()
             .map(CommentId::as_uuid)
@@ -317,83 +368,77 @@ impl CommentRepository for ScyllaCommentRepository {
         let updated_at = dt_ms(comment.updated_at());
         let status     = comment.status().as_tinyint();
 
+        let mut batch = self.outbox.batch();
+
         // Null all content in source-of-truth.
-        let stmt_main = self.strict_stmt(
-            "UPDATE comment.comments SET \
-             status = ?, body = null, gif_id = null, gif_url = null, \
-             gif_width = null, gif_height = null, \
-             updated_at = ?, deleted_at = ? \
-             WHERE comment_id = ?",
+        batch.write(
+            self.strict_stmt(
+                "UPDATE comment.comments SET \
+                 status = ?, body = null, gif_id = null, gif_url = null, \
+                 gif_width = null, gif_height = null, \
+                 updated_at = ?, deleted_at = ? \
+                 WHERE comment_id = ?",
+            ),
+            (status, updated_at, deleted_at, comment.id().as_uuid()),
         );
-        self.client
-            .session
-            .execute_unpaged(
-                stmt_main,
-                (status, updated_at, deleted_at, comment.id().as_uuid()),
-            )
-            .await
-            .map_err(scylla_err)?;
 
         // Null content in feed table.
-        let stmt_feed = self.strict_stmt(
-            "UPDATE comment.comments_by_post SET \
-             status = ?, body = null, gif_url = null, gif_width = null, gif_height = null \
-             WHERE post_id = ? AND parent_id = ? AND created_at = ? AND comment_id = ?",
+        batch.write(
+            self.strict_stmt(
+                "UPDATE comment.comments_by_post SET \
+                 status = ?, body = null, gif_url = null, gif_width = null, gif_height = null \
+                 WHERE post_id = ? AND parent_id = ? AND created_at = ? AND comment_id = ?",
+            ),
+            (
+                status,
+                comment.post_id().as_uuid(),
+                parent_uuid,
+                dt_ms(comment.created_at()),
+                comment.id().as_uuid(),
+            ),
         );
-        self.client
-            .session
-            .execute_unpaged(
-                stmt_feed,
-                (
-                    status,
-                    comment.post_id().as_uuid(),
-                    parent_uuid,
-                    dt_ms(comment.created_at()),
-                    comment.id().as_uuid(),
-                ),
-            )
-            .await
-            .map_err(scylla_err)?;
 
-        Ok(())
+        self.apply(batch, events).await
     }
 
     // ── purge ─────────────────────────────────────────────────────────────────
 
-    async fn purge(&self, comment: &Comment) -> Result<(), CommentError> {
+    async fn purge(&self, comment: &Comment, events: &[DomainEvent]) -> Result<(), CommentError> {
         let parent_uuid = comment
             .parent_id()
             .map(CommentId::as_uuid)
             .unwrap_or(NIL_UUID);
 
-        let stmt_main = self.strict_stmt(
-            "DELETE FROM comment.comments WHERE comment_id = ?",
+        let mut batch = self.outbox.batch();
+        batch.write(
+            self.strict_stmt("DELETE FROM comment.comments WHERE comment_id = ?"),
+            (comment.id().as_uuid(),),
         );
-        self.client
-            .session
-            .execute_unpaged(stmt_main, (comment.id().as_uuid(),))
-            .await
-            .map_err(scylla_err)?;
-
-        let stmt_feed = self.strict_stmt(
-            "DELETE FROM comment.comments_by_post \
-             WHERE post_id = ? AND parent_id = ? AND created_at = ? AND comment_id = ?",
+        batch.write(
+            self.strict_stmt(
+                "DELETE FROM comment.comments_by_post \
+                 WHERE post_id = ? AND parent_id = ? AND created_at = ? AND comment_id = ?",
+            ),
+            (
+                comment.post_id().as_uuid(),
+                parent_uuid,
+                dt_ms(comment.created_at()),
+                comment.id().as_uuid(),
+            ),
+        );
+        batch.write(
+            self.strict_stmt(
+                "DELETE FROM comment.comments_by_author \
+                 WHERE author_id = ? AND created_at = ? AND comment_id = ?",
+            ),
+            (
+                comment.author_id().as_uuid(),
+                dt_ms(comment.created_at()),
+                comment.id().as_uuid(),
+            ),
         );
-        self.client
-            .session
-            .execute_unpaged(
-                stmt_feed,
-                (
-                    comment.post_id().as_uuid(),
-                    parent_uuid,
-                    dt_ms(comment.created_at()),
-                    comment.id().as_uuid(),
-                ),
-            )
-            .await
-            .map_err(scylla_err)?;
 
-        Ok(())
+        self.apply(batch, events).await
     }
 
     // ── list_top_level ────────────────────────────────────────────────────────
@@ -512,6 +557,87 @@ impl CommentRepository for ScyllaCommentRepository {
 
         build_page(rows, limit as usize)
     }
+
+    // ── list_ids_by_author ────────────────────────────────────────────────────
+
+    async fn list_ids_by_author(
+        &self,
+        author_id:  &ProfileId,
+        limit:      i32,
+        page_token: Option<&str>,
+    ) -> Result<(Vec<CommentId>, Option<String>), CommentError> {
+        // i32 for the CQL int32 LIMIT — same fix as list_top_level above.
+        let limit = limit.clamp(1, 100);
+        let token = decode_author_page_token(page_token)?;
+
+        // Keyset on (created_at DESC, comment_id ASC): finish the tie group the
+        // last page stopped in, then continue strictly older. A bare
+        // `created_at < ?` would skip comments sharing the boundary millisecond.
+        let mut rows = Vec::new();
+        if let Some(ref tok) = token {
+            let stmt = self.fast_stmt(
+                "SELECT created_at, comment_id FROM comment.comments_by_author \
+                 WHERE author_id = ? AND created_at = ? AND comment_id > ? \
+                 LIMIT ?",
+            );
+            let result = self
+                .client
+                .session
+                .execute_unpaged(
+                    stmt,
+                    (author_id.as_uuid(), CqlTimestamp(tok.created_at_ms), tok.comment_id, limit),
+                )
+                .await;
+            rows.extend(author_rows(result)?);
+        }
+        let remaining = limit - rows.len() as i32;
+        if remaining > 0 {
+            let result = if let Some(ref tok) = token {
+                let stmt = self.fast_stmt(
+                    "SELECT created_at, comment_id FROM comment.comments_by_author \
+                     WHERE author_id = ? AND created_at < ? \
+                     LIMIT ?",
+                );
+                self.client
+                    .session
+                    .execute_unpaged(stmt, (author_id.as_uuid(), CqlTimestamp(tok.created_at_ms), remaining))
+                    .await
+            } else {
+                let stmt = self.fast_stmt(
+                    "SELECT created_at, comment_id FROM comment.comments_by_author \
+                     WHERE author_id = ? \
+                     LIMIT ?",
+                );
+                self.client
+                    .session
+                    .execute_unpaged(stmt, (author_id.as_uuid(), remaining))
+                    .await
+            };
+            rows.extend(author_rows(result)?);
+        }
+
+        let next_token = match rows.last() {
+            Some((created_at, comment_id)) if rows.len() == limit as usize => {
+                Some(encode_author_page_token(created_at.0, *comment_id))
+            }
+            _ => None,
+        };
+        let ids = rows.into_iter().map(|(_, id)| CommentId::from_uuid(id)).collect();
+        Ok((ids, next_token))
+    }
+}
+
+fn author_rows(
+    result: Result<scylla::response::query_result::QueryResult, scylla::errors::ExecutionError>,
+) -> Result<Vec<(CqlTimestamp, Uuid)>, CommentError> {
+    result
+        .map_err(scylla_err)?
+        .into_rows_result()
+        .map_err(|e| row_err("list_ids_by_author:rows", e))?
+        .rows::<(CqlTimestamp, Uuid)>()
+        .map_err(|e| row_err("list_ids_by_author:iter", e))?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| row_err("list_ids_by_author:deser", e))
 }
 
 fn build_page(
@@ -0,0 +1,100 @@
+//! Comment's events as `comment.outbox` rows.
+//!
+//! The repository writes each event into the same logged batch as the comment
+//! rows that produced it, so an acknowledged write always carries its event and
+//! a failed one carries none; the shared [`outbox::ScyllaOutboxRelay`] forwards
+//! the rows to Kafka. A broker outage no longer fails a written comment, and the
+//! engagement / notification consumers never miss the event of one that was
+//! written.
+
+use outbox::{OutboxError, OutboxMessage, ScyllaOutbox, ScyllaOutboxBatch};
+
+use crate::domain::event::DomainEvent;
+use crate::error::CommentError;
+
+// `CommentCreated` and `CommentDeleted` go to their own topics, keyed by
+// `comment_id`; engagement's `CommentEventConsumer` reads both to drive its
+// comment counters.
+const TOPIC_CREATED: &str = "comment.created";
+const TOPIC_DELETED: &str = "comment.deleted";
+
+/// A failed batch is a failed write — the comment rows were in it; only a
+/// payload that cannot serialize is reported as a publish failure.
+pub(crate) fn outbox_err(e: OutboxError) -> CommentError {
+    match e {
+        OutboxError::ScyllaStorage(e) => CommentError::Storage(e),
+        other => CommentError::EventPublishFailed { message: format!("outbox enqueue: {other}") },
+    }
+}
+
+/// The `comment.outbox` handle the repository batches its writes through.
+/// Cheap to clone.
+#[derive(Clone)]
+pub struct CommentOutbox {
+    outbox: ScyllaOutbox,
+}
+
+impl CommentOutbox {
+    pub fn new(outbox: ScyllaOutbox) -> Self {
+        Self { outbox }
+    }
+
+    /// Starts the logged batch a repository write goes into.
+    pub(crate) fn batch(&self) -> ScyllaOutboxBatch<'_> {
+        self.outbox.batch()
+    }
+
+    /// Adds one row per event to `batch`.
+    pub(crate) fn enqueue(
+        &self,
+        batch:  &mut ScyllaOutboxBatch<'_>,
+        events: &[DomainEvent],
+    ) -> Result<(), CommentError> {
+        let messages = events
+            .iter()
+            .map(message)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(outbox_err)?;
+        batch.enqueue(&messages).map_err(outbox_err)?;
+        Ok(())
+    }
+}
+
+/// The per-type record: the bare inner event, keyed by `comment_id`, with
+/// `comment_id` / `post_id` / `author_id` headers.
+fn message(event: &DomainEvent) -> Result<OutboxMessage, OutboxError> {
+    let (message, comment_id, post_id, author_id) = match event {
+        DomainEvent::CommentCreated(e) => (
+            OutboxMessage::new(TOPIC_CREATED, e.comment_id.clone(), "CommentCreated", e)?,
+            &e.comment_id,
+            &e.post_id,
+            &e.author_id,
+        ),
+        DomainEvent::CommentDeleted(e) => (
+            OutboxMessage::new(TOPIC_DELETED, e.comment_id.clone(), "CommentDeleted", e)?,
+            &e.comment_id,
+            &e.post_id,
+            &e.author_id,
+        ),
+    };
+    Ok(message
+        .with_header("comment_id", comment_id.as_str())
+        .with_header("post_id",    post_id.as_str())
+        .with_header("author_id",  author_id.as_str()))
+}
+
+#[cfg(test)]
+mod tests {
+    use event_topology::conformance::producer_violations;
+
+    use super::*;
+    use crate::domain::event::{CommentCreatedEvent, CommentDeletedEvent};
+
+    /// Each comment topic carries exactly the payload registered for it in event-topology.
+    #[test]
+    fn comment_events_conform_to_the_registered_schemas() {
+        let mut violations = producer_violations::<CommentCreatedEvent>(TOPIC_CREATED);
+        violations.extend(producer_violations::<CommentDeletedEvent>(TOPIC_DELETED));
+        assert!(violations.is_empty(), "{violations:#?}");
+    }
+}
@@ -1,3 +1,28 @@
-pub mod kafka_event_publisher;
+pub mod comment_outbox;
 
-pub use kafka_event_publisher::KafkaCommentEventPublisher;
+use std::sync::Arc;
+
+use outbox::{
+    OutboxError, OutboxSink, RelayConfig, ScyllaOutbox, ScyllaOutboxRelay, ScyllaOutboxTable,
+};
+use scylla_storage::ScyllaClient;
+
+pub use comment_outbox::CommentOutbox;
+
+/// The keyspace whose `outbox` / `outbox_lease` / `outbox_member` tables carry
+/// comment's pending events.
+pub const OUTBOX_KEYSPACE: &str = "comment";
+
+/// The outbox the repository writes through and the relay that drains it to
+/// `sink`, both over `scylla` — shared by the runtime adapter and the standalone
+/// gRPC entrypoint.
+pub fn scylla_outbox(
+    scylla: &Arc<ScyllaClient>,
+    sink:   Arc<dyn OutboxSink>,
+    config: RelayConfig,
+) -> Result<(CommentOutbox, ScyllaOutboxRelay), OutboxError> {
+    let table = ScyllaOutboxTable::new(OUTBOX_KEYSPACE)?;
+    let relay = ScyllaOutboxRelay::new(Arc::clone(scylla), table.clone(), sink, config);
+    let outbox = CommentOutbox::new(ScyllaOutbox::new(Arc::clone(scylla), table));
+    Ok((outbox, relay))
+}
@@ -1,28 +1,53 @@
 //! Adapts the comment composition root to the fleet [`service_runtime::Service`]
-//! contract. Comment is ScyllaDB-only and publishes domain events through the
-//! durable Kafka publisher.
+//! contract. Comment is ScyllaDB-only: domain events are enqueued into
+//! `comment.outbox` and the relay spawned here forwards them to Kafka.
+//!
+//! Its one inbound integration is GDPR erasure: the shared [`subject_erasure`]
+//! consumer on `account.v1.events` deletes the subject's comments and confirms
+//! to account over `ConfirmSubjectErasure` (resilience binding `account`,
+//! endpoint `COMMENT_ACCOUNT_GRPC_ENDPOINT`). The consumer starts only once the
+//! `comments_by_author` backfill has completed, so every erasure walks an index
+//! that covers the subject's older comments too.
 
 use std::sync::Arc;
+use std::time::Duration;
 
 use async_trait::async_trait;
-use cqrs::command::InMemoryCommandBus;
-use cqrs::query::InMemoryQueryBus;
-use scylla_storage::ScyllaConfig;
-use service_runtime::{HealthProbe, InfraRegistry, Service};
+use outbox::{KafkaOutboxSink, RelayConfig};
+use scylla_storage::{ScyllaConfig, ScyllaSessionBuilder};
+use service_runtime::{AccessPolicy, HealthProbe, InfraRegistry, Service, TrafficAttributes};
+use subject_erasure::{
+    run_subject_erasure_consumer, DeletionRequest, EraseCommand, ErasureReporter, GrpcErasureReporter,
+};
 use tonic::service::RoutesBuilder;
 use tonic_reflection::server::Builder as ReflectionBuilder;
-use transport::kafka::config::{KafkaClientConfig, ProducerConfig};
-use transport::kafka::producer::KafkaProducerBuilder;
+use transport::grpc::client::{GrpcClientBuilder, GrpcClientConfig};
+use transport::kafka::config::{ConsumerConfig, KafkaClientConfig, ProducerConfig};
+use transport::kafka::consumer::{KafkaConsumerBuilder, KafkaConsumerHandle};
+use transport::kafka::producer::{KafkaProducerBuilder, KafkaProducerHandle};
 
-use crate::app::{App, Backends};
+use crate::app::{App, AppCommandBus, AppQueryBus};
+use crate::application::command::erase_subject_data::EraseSubjectDataCommand;
 use crate::infrastructure::grpc::handler::comment_service_handler::{
     CommentServiceHandler, CommentServiceServer,
 };
 use crate::infrastructure::grpc::server::FILE_DESCRIPTOR_SET;
-use crate::infrastructure::publisher::KafkaCommentEventPublisher;
+use crate::infrastructure::persistence::AuthorIndexBackfill;
+use crate::infrastructure::publisher::scylla_outbox;
+
+/// Account lifecycle stream carrying GDPR deletion requests.
+const ACCOUNT_EVENTS_TOPIC: &str = "account.v1.events";
+/// Consumer group for comment's part of a GDPR erasure.
+const SUBJECT_ERASURE_GROUP: &str = "comment-subject-erasure";
+/// Comment's name in account's erasure participant list.
+const ERASURE_PARTICIPANT: &str = "comment";
+/// Resilience binding of the erasure confirmations sent to account.
+const ACCOUNT_DEPENDENCY: &str = "account";
+/// Backoff before respawning the consumer after the runner returns.
+const CONSUMER_RESPAWN_BACKOFF: Duration = Duration::from_secs(5);
 
 type CommentServer =
-    CommentServiceServer<CommentServiceHandler<Arc<InMemoryCommandBus>, Arc<InMemoryQueryBus>>>;
+    CommentServiceServer<CommentServiceHandler<Arc<AppCommandBus>, Arc<AppQueryBus>>>;
 
 /// The comment service as hosted by [`service_runtime`].
 pub struct CommentService {
@@ -35,18 +60,42 @@ impl Service for CommentService {
     const VERSION: &'static str = env!("CARGO_PKG_VERSION");
     const GRPC_SERVICE_NAME: &'static str = <CommentServer as tonic::server::NamedService>::NAME;
 
-    async fn build(_infra: Arc<InfraRegistry>) -> anyhow::Result<Self> {
-        let backends = Backends {
-            scylla: ScyllaConfig::from_env(),
-        };
+    async fn build(infra: Arc<InfraRegistry>) -> anyhow::Result<Self> {
+        let scylla = Arc::new(
+            ScyllaSessionBuilder::new(ScyllaConfig::from_env())
+                .build()
+                .await
+                .map_err(|e| anyhow::anyhow!("comment scylla session: {e}"))?,
+        );
 
         let producer = KafkaProducerBuilder::new(ProducerConfig::new(KafkaClientConfig::from_env()))
             .build()?;
-        let publisher = Arc::new(KafkaCommentEventPublisher::new(producer));
+        let (outbox, relay) = scylla_outbox(
+            &scylla,
+            Arc::new(KafkaOutboxSink::new(producer)),
+            RelayConfig::from_env(),
+        )?;
 
-        let app = App::build(backends, publisher)
-            .await
+        let app = App::assemble(scylla, outbox)
             .map_err(|e| anyhow::anyhow!("comment app build: {e}"))?;
+        tokio::spawn(relay.run());
+
+        // The channel connects lazily; account being down only delays the
+        // confirmation (the consumer retries).
+        let account = GrpcClientBuilder::new(
+            GrpcClientConfig::new(
+                std::env::var("COMMENT_ACCOUNT_GRPC_ENDPOINT")
+                    .unwrap_or_else(|_| "http://localhost:50059".to_owned()),
+            )
+            .with_dependency(ACCOUNT_DEPENDENCY),
+        )
+        .build_from_registry_lazy(&infra.resilience())
+        .map_err(|e| anyhow::anyhow!("comment account channel: {e}"))?;
+        spawn_subject_erasure_consumer(
+            AuthorIndexBackfill::new(Arc::clone(&app.scylla)),
+            Arc::clone(&app.command_bus),
+            Arc::new(GrpcErasureReporter::new(account, ERASURE_PARTICIPANT)),
+        );
 
         Ok(Self { app })
     }
@@ -55,6 +104,22 @@ impl Service for CommentService {
         vec![scylla_storage::health::probe(Arc::clone(&self.app.scylla))]
     }
 
+    fn access_policy(&self) -> AccessPolicy {
+        AccessPolicy::for_service(Self::GRPC_SERVICE_NAME)
+            .authenticated("CreateComment")
+            .authenticated("DeleteComment")
+            .authenticated("GetComment")
+            .authenticated("ListTopLevel")
+            .authenticated("ListReplies")
+            // Account's GDPR data-export pipeline.
+            .internal("ExportSubjectData")
+    }
+
+    fn traffic_attributes(&self) -> TrafficAttributes {
+        // Field numbers from `comment.v1.CreateCommentRequest`.
+        TrafficAttributes::for_service(Self::GRPC_SERVICE_NAME).field("CreateComment", "post_id", 2)
+    }
+
     fn register(self, routes: &mut RoutesBuilder) -> anyhow::Result<()> {
         let handler = CommentServiceHandler::new(
             Arc::clone(&self.app.command_bus),
@@ -69,3 +134,55 @@ impl Service for CommentService {
         Ok(())
     }
 }
+
+/// Runs `backfill` to completion, then spawns the supervised subject-erasure
+/// consumer, respawning after [`CONSUMER_RESPAWN_BACKOFF`] whenever the runner
+/// returns. Until the backfill completes, deletion requests wait in Kafka.
+fn spawn_subject_erasure_consumer(
+    backfill:    AuthorIndexBackfill,
+    command_bus: Arc<AppCommandBus>,
+    reporter:    Arc<dyn ErasureReporter>,
+) {
+    let eraser = Arc::new(EraseCommand::new(command_bus, |request: &DeletionRequest| {
+        EraseSubjectDataCommand { profile_ids: request.profile_ids.clone() }
+    }));
+    tokio::spawn(async move {
+        while let Err(error) = backfill.run().await {
+            tracing::warn!(%error, "comments_by_author backfill failed; retrying");
+            tokio::time::sleep(CONSUMER_RESPAWN_BACKOFF).await;
+        }
+        loop {
+            match build_consumer() {
+                Ok((consumer, producer)) => {
+                    run_subject_erasure_consumer(
+                        ERASURE_PARTICIPANT,
+                        consumer,
+                        Arc::clone(&eraser),
+                        Arc::clone(&reporter),
+                        producer,
+                    )
+                    .await;
+                    tracing::warn!("subject-erasure consumer exited; respawning after backoff");
+                }
+                Err(error) => {
+                    tracing::error!(%error, "failed to build subject-erasure consumer; retrying");
+                }
+            }
+            tokio::time::sleep(CONSUMER_RESPAWN_BACKOFF).await;
+        }
+    });
+}
+
+/// Builds the manual-commit consumer (subscribed to `account.v1.events`) and the
+/// dead-letter producer the runner needs.
+fn build_consumer() -> anyhow::Result<(KafkaConsumerHandle, KafkaProducerHandle)> {
+    let kafka = KafkaClientConfig::from_env();
+    let consumer = KafkaConsumerBuilder::new(ConsumerConfig::new(kafka.clone(), SUBJECT_ERASURE_GROUP))
+        .subscribe(ACCOUNT_EVENTS_TOPIC)
+        .build()
+        .map_err(|e| anyhow::anyhow!("build subject-erasure consumer: {e}"))?;
+    let producer = KafkaProducerBuilder::new(ProducerConfig::new(kafka))
+        .build()
+        .map_err(|e| anyhow::anyhow!("build subject-erasure dead-letter producer: {e}"))?;
+    Ok((consumer, producer))
+}
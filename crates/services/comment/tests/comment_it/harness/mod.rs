@@ -1,28 +1,23 @@
 //! Integration harness: boots an ephemeral ScyllaDB container, wires a real
 //! comment graph against it through the production composition root, and exposes
-//! the buses for assertions. The event publisher is an in-process no-op.
+//! the buses for assertions. No relay runs: events stay in `comment.outbox`.
 #![allow(dead_code)]
 
 use std::sync::Arc;
 use std::time::Duration;
 
-use async_trait::async_trait;
 use uuid::Uuid;
 
-use cqrs::command::InMemoryCommandBus;
-use cqrs::query::InMemoryQueryBus;
 use cqrs::{CommandBus, CqrsError, Envelope, QueryBus};
 use scylla_storage::ScyllaConfig;
 
-use comment::app::{App, Backends};
+use comment::app::{App, AppCommandBus, AppQueryBus, Backends};
 use comment::application::command::create_comment::CreateCommentCommand;
 use comment::application::command::delete_comment::DeleteCommentCommand;
-use comment::application::port::{CommentEventPublisher, CommentSummary};
+use comment::application::port::CommentSummary;
 use comment::application::query::get_comment::GetCommentQuery;
 use comment::application::query::list_replies::ListRepliesQuery;
 use comment::application::query::list_top_level::ListTopLevelQuery;
-use comment::domain::event::DomainEvent;
-use comment::error::CommentError;
 
 pub use comment::domain::aggregate::Comment;
 pub use comment::domain::value_object::CommentStatus;
@@ -37,26 +32,15 @@ const KEYSPACE: &str = "comment";
 /// On-disk migration assets, resolved against *this* crate's manifest.
 const MIGRATIONS_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/migrations");
 
-/// A no-op event publisher: these scenarios assert on ScyllaDB, not on the Kafka
-/// contract.
-struct NoopPublisher;
-
-#[async_trait]
-impl CommentEventPublisher for NoopPublisher {
-    async fn publish(&self, _event: &DomainEvent) -> Result<(), CommentError> {
-        Ok(())
-    }
-}
-
 /// A fully-wired comment service bound to ephemeral infra, plus the buses.
 pub struct TestHarness {
-    pub command_bus: Arc<InMemoryCommandBus>,
-    pub query_bus:   Arc<InMemoryQueryBus>,
+    pub command_bus: Arc<AppCommandBus>,
+    pub query_bus:   Arc<AppQueryBus>,
 }
 
 impl TestHarness {
     /// Boots/reuses the shared ScyllaDB container, applies migrations, and
-    /// assembles the service graph with a no-op publisher.
+    /// assembles the service graph through the production composition root.
     pub async fn start() -> Self {
         let scylla_cp = test_support::containers::scylla_ready(KEYSPACE, MIGRATIONS_DIR).await;
 
@@ -68,9 +52,7 @@ impl TestHarness {
             },
         };
 
-        let app = App::build(backends, Arc::new(NoopPublisher))
-            .await
-            .expect("integration: build comment app");
+        let app = App::build(backends).await.expect("integration: build comment app");
 
         Self { command_bus: app.command_bus, query_bus: app.query_bus }
     }
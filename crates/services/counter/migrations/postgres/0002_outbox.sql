@@ -0,0 +1,35 @@
+-- Outbox for counter.v1.popularity (shared `outbox` crate schema — a verbatim
+-- rendering of OutboxTable::new("counter").ddl()).
+--
+-- WHY: the flush loop published popularity snapshots straight to Kafka right
+-- after committing the window to the ledger. A broker blip failed the flush
+-- tick even though the window had landed. Snapshots are now enqueued here (in
+-- the ledger's database) and relayed to Kafka, keyed `<kind>:<id>` so that
+-- per-entity order holds.
+CREATE TABLE IF NOT EXISTS counter_outbox (
+    id            UUID        PRIMARY KEY,
+    seq           BIGSERIAL,
+    topic         TEXT        NOT NULL,
+    aggregate_key TEXT        NOT NULL,
+    slot          INT         NOT NULL,
+    event_type    TEXT        NOT NULL,
+    payload       JSONB       NOT NULL,
+    headers       JSONB       NOT NULL DEFAULT '{}',
+    attempts      INT         NOT NULL DEFAULT 0,
+    last_error    TEXT,
+    created_at    TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+CREATE INDEX IF NOT EXISTS counter_outbox_drain_order
+    ON counter_outbox (slot, seq);
+
+CREATE TABLE IF NOT EXISTS counter_outbox_lease (
+    slot       INT         PRIMARY KEY,
+    owner      TEXT        NOT NULL DEFAULT '',
+    expires_at TIMESTAMPTZ NOT NULL DEFAULT 'epoch'
+);
+
+CREATE TABLE IF NOT EXISTS counter_outbox_member (
+    owner      TEXT        PRIMARY KEY,
+    expires_at TIMESTAMPTZ NOT NULL
+);
@@ -29,6 +29,9 @@ pub struct Ports {
     pub ledger: Arc<dyn CounterLedger>,
     pub series: Arc<dyn TimeSeriesStore>,
     pub redis: RedisClient,
+    /// The ledger's transaction manager — the worker enqueues popularity signals
+    /// into `counter_outbox` on the same database.
+    pub tx: TransactionManager,
 }
 
 impl Ports {
@@ -47,7 +50,7 @@ impl Ports {
         let scylla = Arc::new(ScyllaSessionBuilder::new(scylla).build().await?);
 
         let store: Arc<dyn CounterStore> = Arc::new(RedisCounterStore::new(redis.clone()));
-        let ledger: Arc<dyn CounterLedger> = Arc::new(PgCounterLedger::new(tx));
+        let ledger: Arc<dyn CounterLedger> = Arc::new(PgCounterLedger::new(tx.clone()));
         let series: Arc<dyn TimeSeriesStore> =
             Arc::new(ScyllaTimeSeriesStore::new(scylla, window));
 
@@ -56,6 +59,7 @@ impl Ports {
             ledger,
             series,
             redis,
+            tx,
         })
     }
 }
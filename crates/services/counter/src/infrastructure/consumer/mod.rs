@@ -1,8 +1,9 @@
 //! The worker's ingestion path — the async command side.
 //!
-//! Each consumer runs on the shared at-least-once
-//! [`run_consumer`](transport::kafka::consumer::run_consumer) runner (manual commit,
-//! bounded retry + jitter, DLQ on poison/exhaustion). A decoded event is distilled
+//! Each consumer runs on the shared at-least-once runner in its keyed concurrent
+//! mode, [`run_consumer_keyed`](transport::kafka::consumer::run_consumer_keyed)
+//! (manual low-watermark commit, bounded retry + jitter, DLQ on poison/exhaustion):
+//! folds for different entities proceed in parallel, each entity's in order. A decoded event is distilled
 //! into [`Observation`]s and folded into the **shared** [`WindowAggregator`]; a
 //! separate [`run_flush_loop`] drains closed windows on a ticker and fans them out
 //! through the [`DeltaFlusher`], then publishes the coarse popularity signal for the
@@ -20,7 +21,9 @@ use std::time::Duration;
 
 use chrono::Utc;
 use tokio::sync::Mutex;
-use transport::kafka::consumer::{KafkaConsumerHandle, ProcessOutcome, RetryPolicy, run_consumer};
+use transport::kafka::consumer::{
+    KafkaConsumerHandle, KeyedConcurrency, ProcessOutcome, RetryPolicy, run_consumer_keyed,
+};
 use transport::kafka::envelope::ConsumablePayload;
 use transport::kafka::producer::KafkaProducerHandle;
 
@@ -39,7 +42,7 @@ impl transport::kafka::consumer::ClassifyError for CounterError {
 
 /// Runs a fold consumer: decode each event into observations and fold them into the
 /// shared aggregator. `map` is the per-topic distillation (`map_view`, `map_reaction`,
-/// …); `run_consumer` owns deserialization, so poison bytes dead-letter before they
+/// …); the runner owns deserialization, so poison bytes dead-letter before they
 /// reach `map`.
 pub async fn run_fold_consumer<T, M>(
     label: &'static str,
@@ -53,7 +56,8 @@ pub async fn run_fold_consumer<T, M>(
 {
     tracing::info!(label, "counter consumer started");
     let policy = RetryPolicy::default();
-    let result = run_consumer::<T, _>(&consumer, &producer, &policy, move |event| {
+    let concurrency = KeyedConcurrency::from_env();
+    let result = run_consumer_keyed::<T, _>(&consumer, &producer, &policy, &concurrency, move |event| {
         let aggregator = Arc::clone(&aggregator);
         let event = event.clone();
         Box::pin(async move {
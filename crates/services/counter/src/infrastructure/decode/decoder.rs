@@ -191,6 +191,25 @@ mod tests {
         assert!(obs.is_empty());
     }
 
+    #[test]
+    fn protobuf_replacement_keeps_its_old_kind() {
+        use engagement_api::{reaction_event, ReactionEvent, ReactionKind, ReactionUpserted};
+        use transport::kafka::FromProto;
+
+        let wire = ReactionWire::from_proto(ReactionEvent {
+            event: Some(reaction_event::Event::Upserted(ReactionUpserted {
+                post_id: "p1".into(),
+                old_kind: Some(ReactionKind::Heart as i32),
+                event_at_ms: 1,
+                ..Default::default()
+            })),
+        })
+        .unwrap();
+        let ReactionWire::Upserted(e) = &wire else { panic!("expected an upsert") };
+        assert_eq!(e.old_kind.as_deref(), Some("heart"));
+        assert!(map_reaction(wire).unwrap().is_empty());
+    }
+
     #[test]
     fn removed_reaction_is_minus_one_like() {
         let obs = map_reaction(ReactionWire::Removed(ReactionRemovedWire {
@@ -3,7 +3,9 @@
 //! Counter must not depend on the `engagement` / `social-graph` crates (a sideways
 //! services→services edge the tiering forbids), so it owns its read schema:
 //! minimal, lenient structs that match the published JSON. Extra fields are
-//! ignored, so an additive change upstream never breaks a consumer.
+//! ignored, so an additive change upstream never breaks a consumer. Where a
+//! stream is published as protobuf, the DTO also maps from the upstream
+//! `*-api` contract message (a contracts edge, which the tiering allows).
 //!
 //! Integration reality (mirrors `search`'s honesty about thin events):
 //! * `view` / `impression` / `click` are **counter-owned firehose schemas** — no
@@ -11,12 +13,17 @@
 //!   these shapes. They are notifications, and counts need nothing more than the
 //!   `(entity, actor?, time)` they carry — no hydration.
 //! * `engagement.reactions` **matches the live upstream schema** (`engagement`
-//!   publishes it today, internally tagged on `event_type`, snake_case).
+//!   publishes `engagement.v1.ReactionEvent`; records from before the protobuf
+//!   switch are JSON, internally tagged on `event_type`, snake_case). Consumed
+//!   as `Proto<ReactionWire>`, which reads both.
 //! * `social-graph` follow events are a **counter-owned schema** pending an
 //!   upstream follow stream (an upstream prerequisite, like `profile.v1.events`
 //!   is for search).
 
+use engagement_api::{reaction_event, ReactionEvent, ReactionKind};
 use serde::Deserialize;
+use transport::error::CodecError;
+use transport::kafka::FromProto;
 
 // ── view / impression / click — counter-owned firehose schema ─────────────────
 
@@ -56,6 +63,35 @@ pub struct ReactionRemovedWire {
     pub event_at_ms: i64,
 }
 
+impl FromProto for ReactionWire {
+    type Message = ReactionEvent;
+
+    fn from_proto(message: ReactionEvent) -> Result<Self, CodecError> {
+        match message.event {
+            Some(reaction_event::Event::Upserted(e)) => Ok(Self::Upserted(ReactionUpsertedWire {
+                post_id: e.post_id,
+                old_kind: e.old_kind.map(reaction_kind_name),
+                event_at_ms: e.event_at_ms,
+            })),
+            Some(reaction_event::Event::Removed(e)) => Ok(Self::Removed(ReactionRemovedWire {
+                post_id: e.post_id,
+                event_at_ms: e.event_at_ms,
+            })),
+            None => Err(CodecError::ProtobufMapping("ReactionEvent.event is unset".into())),
+        }
+    }
+}
+
+/// The JSON form of a reaction kind (`heart`, `fire`, …). Lenient like the rest
+/// of the read schema: only the presence of `old_kind` matters here, so a kind
+/// this build does not know keeps its ordinal rather than failing the record.
+fn reaction_kind_name(v: i32) -> String {
+    match ReactionKind::try_from(v) {
+        Ok(kind) => kind.as_str_name().trim_start_matches("REACTION_KIND_").to_ascii_lowercase(),
+        Err(_) => v.to_string(),
+    }
+}
+
 // ── social-graph follow — counter-owned schema (upstream stream is a prereq) ──
 
 #[derive(Debug, Clone, Deserialize)]
@@ -223,7 +223,7 @@ fn entity_to_proto(e: &EntityRef) -> proto::EntityRef {
     }
 }
 
-fn entity_kind_to_proto(kind: EntityKind) -> i32 {
+pub(crate) fn entity_kind_to_proto(kind: EntityKind) -> i32 {
     use proto::CounterEntityType as P;
     (match kind {
         EntityKind::Post => P::Post,
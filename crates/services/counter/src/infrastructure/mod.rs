@@ -4,7 +4,7 @@
 //! * [`redis_counter_store`] — hot tier (fred, Lua-issued HLL/sorted-set ops)
 //! * [`pg_counter_ledger`] — warm tier (sqlx, idempotent window-keyed UPSERT)
 //! * [`scylla_time_series`] — cold tier (Scylla TWCS counter rollups)
-//! * [`kafka_signal_publisher`] — the `counter.v1.popularity` producer
+//! * [`outbox_signal_publisher`] — the `counter.v1.popularity` producer (outbox-backed)
 //! * [`decode`] — counter-owned wire DTOs + the pure wire→`Observation` mappers
 //!
 //! Per the integration-test standard, the storage/transport adapters are
@@ -14,13 +14,13 @@
 pub mod consumer;
 pub mod decode;
 pub mod grpc;
-pub mod kafka_signal_publisher;
+pub mod outbox_signal_publisher;
 pub mod pg_counter_ledger;
 pub mod reconcile;
 pub mod redis_counter_store;
 pub mod scylla_time_series;
 
-pub use kafka_signal_publisher::{KafkaSignalPublisher, PopularityEvent};
+pub use outbox_signal_publisher::{OutboxSignalPublisher, PopularityEvent};
 pub use pg_counter_ledger::PgCounterLedger;
 pub use redis_counter_store::RedisCounterStore;
 pub use scylla_time_series::ScyllaTimeSeriesStore;
@@ -0,0 +1,109 @@
+//! The outbound popularity signal (`counter.v1.popularity`), via the outbox.
+//!
+//! The only thing counter-analytics publishes. A coarse, slow-loop emission
+//! consumed by `search` (its `PopularityScore` ranking input) and `timeline`.
+//!
+//! The flush loop commits the window to the Postgres ledger and then publishes.
+//! The snapshot is now enqueued into `counter_outbox`, in the ledger's database,
+//! and the shared `outbox` relay forwards it to Kafka. A broker blip no longer
+//! fails the flush. Before, that failure made the window re-drain and re-publish
+//! on the next tick. Per-entity order holds because the row is keyed like the
+//! Kafka record (`<kind>:<id>`).
+//!
+//! The row stores the JSON [`PopularityEvent`]; the relay's sink transcodes it to
+//! `counter.v1.PopularityUpdated` on the way out (see [`ToProto`]), unless the
+//! `COUNTER_POPULARITY_CONTENT_TYPE` escape hatch pins the stream to JSON.
+
+use async_trait::async_trait;
+use outbox::{OutboxError, OutboxMessage, PgOutbox};
+use serde::{Deserialize, Serialize};
+use transport::kafka::ToProto;
+
+use crate::application::port::SignalPublisher;
+use crate::domain::{EntityKind, EntityRef, PopularityScore};
+use crate::error::CounterError;
+use crate::infrastructure::grpc::handler::{entity_kind_to_proto, proto};
+
+pub const TOPIC_POPULARITY: &str = "counter.v1.popularity";
+
+/// Prefix of the `counter_outbox` table (and its `_lease` / `_member` companions).
+pub const OUTBOX_PREFIX: &str = "counter";
+
+/// Selects the `counter.v1.popularity` encoding (`json` or `protobuf`, default
+/// `json`). Consumers learn protobuf in the same release, so the relay only opts
+/// in once every consumer is upgraded; the default flips in a later release.
+pub const POPULARITY_CONTENT_TYPE_ENV: &str = "COUNTER_POPULARITY_CONTENT_TYPE";
+
+/// The routing type stored on each row (and sent as the `event_type` header).
+const EVENT_TYPE: &str = "counter.popularity_updated";
+
+/// The wire payload of a popularity snapshot. Deliberately tiny: a reference and a
+/// coarse score — no per-actor data, nothing volatile.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PopularityEvent {
+    pub entity_type: String,
+    pub entity_id: String,
+    pub score: f64,
+}
+
+impl ToProto for PopularityEvent {
+    type Message = proto::PopularityUpdated;
+
+    fn to_proto(&self) -> proto::PopularityUpdated {
+        // The row was written from an `EntityKind`, so the discriminant parses.
+        let entity_type = EntityKind::try_from_str(&self.entity_type)
+            .map_or(proto::CounterEntityType::Unspecified as i32, entity_kind_to_proto);
+        proto::PopularityUpdated {
+            entity: Some(proto::EntityRef { entity_type, id: self.entity_id.clone() }),
+            score: self.score,
+        }
+    }
+}
+
+fn enqueue_err(e: OutboxError) -> CounterError {
+    CounterError::SignalPublishFailed {
+        reason: e.to_string(),
+    }
+}
+
+pub struct OutboxSignalPublisher {
+    outbox: PgOutbox,
+}
+
+impl OutboxSignalPublisher {
+    pub fn new(outbox: PgOutbox) -> Self {
+        Self { outbox }
+    }
+}
+
+#[async_trait]
+impl SignalPublisher for OutboxSignalPublisher {
+    async fn publish_popularity(
+        &self,
+        entity: &EntityRef,
+        score: PopularityScore,
+    ) -> Result<(), CounterError> {
+        let key = format!("{}:{}", entity.kind.as_str(), entity.id.as_str());
+        let payload = PopularityEvent {
+            entity_type: entity.kind.as_str().to_owned(),
+            entity_id: entity.id.as_str().to_owned(),
+            score: score.value(),
+        };
+        let message = OutboxMessage::new(TOPIC_POPULARITY, key, EVENT_TYPE, &payload)
+            .map_err(enqueue_err)?
+            .with_header("entity_type", entity.kind.as_str());
+        self.outbox.enqueue_detached(&[message]).await.map_err(enqueue_err)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The JSON form of `counter.v1.popularity` is exactly the payload registered for it in event-topology.
+    #[test]
+    fn popularity_events_conform_to_the_registered_schema() {
+        let violations = event_topology::conformance::producer_violations::<PopularityEvent>(TOPIC_POPULARITY);
+        assert!(violations.is_empty(), "{violations:#?}");
+    }
+}
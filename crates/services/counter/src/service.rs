@@ -6,7 +6,7 @@
 //!   the storage ports, composes the gRPC read handler, serves `counter.v1` on
 //!   :50064, and reports Redis liveness. No consumers, no aggregation.
 //! * [`CounterWorkerService`] (`counter-worker`) — the stream processor. Builds the
-//!   ports + the Kafka signal producer, spawns the five supervised firehose/domain
+//!   ports + the outbox-backed signal publisher and its relay, spawns the five supervised firehose/domain
 //!   consumers folding into a shared [`WindowAggregator`], and runs the drain/flush
 //!   loop that fans windows out across the tiers and publishes popularity. Exposes
 //!   no domain RPC (only health + reflection on its port).
@@ -18,7 +18,7 @@ use anyhow::Context;
 use async_trait::async_trait;
 use fred::interfaces::LuaInterface;
 use redis_storage::RedisClient;
-use service_runtime::{FnProbe, HealthProbe, InfraRegistry, Service};
+use service_runtime::{AccessPolicy, FnProbe, HealthProbe, InfraRegistry, Service};
 use tokio::sync::Mutex;
 use tonic::service::RoutesBuilder;
 use tonic_reflection::server::Builder as ReflectionBuilder;
@@ -26,6 +26,7 @@ use transport::kafka::config::{ConsumerConfig, KafkaClientConfig, ProducerConfig
 use transport::kafka::consumer::{KafkaConsumerBuilder, KafkaConsumerHandle};
 use transport::kafka::producer::{KafkaProducerBuilder, KafkaProducerHandle};
 
+use outbox::{KafkaOutboxSink, OutboxRelay, OutboxTable, PgOutbox, RelayConfig};
 use tonic::transport::Channel;
 
 use crate::app::{Ports, compose_read};
@@ -42,8 +43,12 @@ use crate::infrastructure::decode::{
 use crate::infrastructure::grpc::{
     CounterServiceHandler, CounterServiceServer, FILE_DESCRIPTOR_SET,
 };
-use crate::infrastructure::kafka_signal_publisher::KafkaSignalPublisher;
+use crate::infrastructure::outbox_signal_publisher::{
+    OutboxSignalPublisher, PopularityEvent, OUTBOX_PREFIX, POPULARITY_CONTENT_TYPE_ENV,
+    TOPIC_POPULARITY,
+};
 use transport::kafka::envelope::ConsumablePayload;
+use transport::kafka::{ContentType, Proto};
 
 const VIEW_TOPIC: &str = "view.v1.events";
 const IMPRESSION_TOPIC: &str = "impression.v1.events";
@@ -104,6 +109,13 @@ impl Service for CounterReadService {
         vec![redis_probe(self.redis.clone())]
     }
 
+    fn access_policy(&self) -> AccessPolicy {
+        AccessPolicy::for_service(Self::GRPC_SERVICE_NAME)
+            .authenticated("BatchGetCounters")
+            .authenticated("GetTrending")
+            .authenticated("GetTimeSeries")
+    }
+
     fn register(self, routes: &mut RoutesBuilder) -> anyhow::Result<()> {
         let reflection = ReflectionBuilder::configure()
             .register_encoded_file_descriptor_set(FILE_DESCRIPTOR_SET)
@@ -149,11 +161,21 @@ impl Service for CounterWorkerService {
             .await
             .map_err(|e| anyhow::anyhow!("counter worker ports build: {e}"))?;
 
-        // Outbound signal producer (built once; the popularity loop reuses it).
+        // Outbound signal: the popularity loop enqueues into counter_outbox; the
+        // relay (built once over the one producer) forwards to Kafka.
         let producer = KafkaProducerBuilder::new(ProducerConfig::new(kafka))
             .build()
             .context("build popularity producer")?;
-        let publisher: Arc<dyn SignalPublisher> = Arc::new(KafkaSignalPublisher::new(producer));
+        let table = OutboxTable::new(OUTBOX_PREFIX).context("counter outbox table")?;
+        let relay = OutboxRelay::new(
+            ports.tx.pool().clone(),
+            table.clone(),
+            Arc::new(popularity_sink(producer)),
+            RelayConfig::from_env(),
+        );
+        tokio::spawn(relay.run());
+        let publisher: Arc<dyn SignalPublisher> =
+            Arc::new(OutboxSignalPublisher::new(PgOutbox::new(table, ports.tx.clone())));
 
         let flusher = Arc::new(DeltaFlusher::new(
             Arc::clone(&ports.store),
@@ -173,12 +195,12 @@ impl Service for CounterWorkerService {
             map_impression,
         );
         spawn_consumer::<HitWire, _>(CLICK_TOPIC, CLICK_GROUP, "click", &aggregator, map_click);
-        spawn_consumer::<ReactionWire, _>(
+        spawn_consumer::<Proto<ReactionWire>, _>(
             REACTION_TOPIC,
             REACTION_GROUP,
             "reaction",
             &aggregator,
-            map_reaction,
+            |wire| map_reaction(wire.into_inner()),
         );
         spawn_consumer::<FollowWire, _>(
             FOLLOW_TOPIC,
@@ -286,6 +308,16 @@ fn spawn_consumer<T, M>(
     });
 }
 
+/// The relay's sink: `counter.v1.popularity` goes out as JSON until
+/// `COUNTER_POPULARITY_CONTENT_TYPE=protobuf` opts it in.
+fn popularity_sink(producer: KafkaProducerHandle) -> KafkaOutboxSink {
+    let sink = KafkaOutboxSink::new(producer);
+    match ContentType::from_env(POPULARITY_CONTENT_TYPE_ENV, ContentType::Json) {
+        ContentType::Protobuf => sink.with_protobuf::<PopularityEvent>(TOPIC_POPULARITY),
+        ContentType::Json => sink,
+    }
+}
+
 /// Builds a manual-commit consumer (subscribed to `topic`) and the dead-letter
 /// producer the runner needs. Kafka config is read fresh per respawn.
 fn build_consumer(
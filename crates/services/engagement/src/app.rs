@@ -17,16 +17,27 @@ use std::sync::Arc;
 use std::time::Duration;
 
 use cqrs::command::{CommandBusBuilder, InMemoryCommandBus};
+use cqrs::middleware::{
+    IdempotencyCommandBus, IdempotencyLayer, MetricsCommandBus, MetricsLayer,
+    MetricsQueryBus, MiddlewarePipeline,
+};
 use cqrs::query::{InMemoryQueryBus, QueryBusBuilder};
+use idempotency::RedisIdempotencyStore;
 use redis_storage::{RedisClient, RedisClientBuilder, RedisConfig};
-use scylla_storage::{ScyllaConfig, ScyllaSessionBuilder};
+use scylla_storage::{ScyllaClient, ScyllaConfig, ScyllaSessionBuilder};
 use transport::kafka::config::client::KafkaClientConfig;
 
+use crate::application::command::erase_subject_data::{
+    EraseSubjectDataCommand, EraseSubjectDataHandler,
+};
 use crate::application::command::record_share::{RecordShareCommand, RecordShareHandler};
 use crate::application::command::record_view::{RecordViewCommand, RecordViewHandler};
 use crate::application::command::remove_reaction::{RemoveReactionCommand, RemoveReactionHandler};
 use crate::application::command::upsert_reaction::{UpsertReactionCommand, UpsertReactionHandler};
 use crate::application::port::{EngagementEventPublisher, ScoreStore};
+use crate::application::query::export_subject_data::{
+    ExportSubjectDataHandler, ExportSubjectDataQuery,
+};
 use crate::application::query::get_post_engagement::{
     GetPostEngagementHandler, GetPostEngagementQuery,
 };
@@ -40,30 +51,47 @@ use crate::infrastructure::worker::{
 
 /// Storage/transport endpoints the graph is wired against.
 ///
-/// `kafka` is optional: `Some` builds the ScyllaDB ledger and spawns the
-/// write-behind, counter-flush, and comment-consumer workers; `None` leaves the
-/// Redis hot path driveable directly with no ScyllaDB or broker.
+/// `kafka` is optional: `Some` builds the ScyllaDB ledger, spawns the
+/// write-behind, counter-flush, and comment-consumer workers, and serves the
+/// ledger-backed data-subject export and erasure; `None` leaves the Redis hot
+/// path driveable directly with no ScyllaDB or broker.
 pub struct Backends {
     pub scylla: ScyllaConfig,
     pub redis:  RedisConfig,
     pub kafka:  Option<KafkaClientConfig>,
 }
 
+/// The command bus every entrypoint dispatches through: the registered handlers
+/// behind an [`IdempotencyLayer`] on the service's Redis, so a retried `message_id`
+/// runs once across replicas. It fails open: a Redis outage costs deduplication,
+/// not the write path.
+/// A [`MetricsLayer`] outermost times every dispatch, replays included.
+pub type AppCommandBus =
+    MetricsCommandBus<IdempotencyCommandBus<InMemoryCommandBus, RedisIdempotencyStore>>;
+
+/// The query bus every read path dispatches through: the registered handlers,
+/// timed by a [`MetricsLayer`].
+pub type AppQueryBus = MetricsQueryBus<InMemoryQueryBus>;
+
 /// A fully-wired engagement service bound to its backends. The buses and the
 /// Redis score store exposed here are the *same* instances the handlers hold.
 pub struct App {
-    pub command_bus: Arc<InMemoryCommandBus>,
-    pub query_bus:   Arc<InMemoryQueryBus>,
+    pub command_bus: Arc<AppCommandBus>,
+    pub query_bus:   Arc<AppQueryBus>,
     pub score_store: Arc<dyn ScoreStore>,
     /// Live Redis client (the always-on hot path), retained so the runtime's
     /// readiness loop can probe it (see [`crate::service`]). ScyllaDB is only the
     /// async write-behind ledger and is not part of the serving readiness gate.
     pub redis:       RedisClient,
+    /// The ledger's ScyllaDB client, present on the Kafka path only; the
+    /// subject-erasure consumer backfills its reverse index over it.
+    pub scylla:      Option<Arc<ScyllaClient>>,
 }
 
 impl App {
     /// Builds the Redis score store and CQRS buses; when Kafka is configured,
-    /// also builds the ScyllaDB ledger and spawns the write-behind workers.
+    /// also builds the ScyllaDB ledger, registers the export query and erasure
+    /// command over it, and spawns the write-behind workers.
     pub async fn build<P: EngagementEventPublisher>(
         backends:  Backends,
         weights:   Arc<ReactionWeightsConfig>,
@@ -77,40 +105,67 @@ impl App {
         let score_store =
             Arc::new(RedisScoreStore::new(redis_client.clone(), dirty_tracker.clone()));
 
+        // ── ScyllaDB ledger (Kafka path) ─────────────────────────────────────
+        let scylla_client = if kafka.is_some() {
+            Some(Arc::new(ScyllaSessionBuilder::new(scylla).build().await?))
+        } else {
+            None
+        };
+        let ledger = scylla_client
+            .as_ref()
+            .map(|client| Arc::new(ScyllaReactionLedger::new(Arc::clone(client))));
+
         // ── CQRS buses ───────────────────────────────────────────────────────
+        let mut commands = CommandBusBuilder::new()
+            .register::<UpsertReactionCommand, _>(UpsertReactionHandler {
+                score_store: Arc::clone(&score_store),
+                publisher:   Arc::clone(&publisher),
+                weights:     Arc::clone(&weights),
+            })?
+            .register::<RemoveReactionCommand, _>(RemoveReactionHandler {
+                score_store: Arc::clone(&score_store),
+                publisher:   Arc::clone(&publisher),
+            })?
+            .register::<RecordViewCommand, _>(RecordViewHandler {
+                score_store: Arc::clone(&score_store),
+            })?
+            .register::<RecordShareCommand, _>(RecordShareHandler {
+                score_store: Arc::clone(&score_store),
+            })?;
+        if let Some(ledger) = &ledger {
+            commands = commands.register::<EraseSubjectDataCommand, _>(EraseSubjectDataHandler {
+                score_store: Arc::clone(&score_store),
+                ledger:      Arc::clone(ledger),
+                publisher:   Arc::clone(&publisher),
+            })?;
+        }
         let command_bus = Arc::new(
-            CommandBusBuilder::new()
-                .register::<UpsertReactionCommand, _>(UpsertReactionHandler {
-                    score_store: Arc::clone(&score_store),
-                    publisher:   Arc::clone(&publisher),
-                    weights:     Arc::clone(&weights),
-                })?
-                .register::<RemoveReactionCommand, _>(RemoveReactionHandler {
-                    score_store: Arc::clone(&score_store),
-                    publisher:   Arc::clone(&publisher),
-                })?
-                .register::<RecordViewCommand, _>(RecordViewHandler {
-                    score_store: Arc::clone(&score_store),
-                })?
-                .register::<RecordShareCommand, _>(RecordShareHandler {
-                    score_store: Arc::clone(&score_store),
-                })?
+            MiddlewarePipeline::new(commands.build())
+                .layer(
+                    IdempotencyLayer::new(RedisIdempotencyStore::new(redis_client.clone(), "engagement"))
+                        .fail_open(),
+                )
+                .layer(MetricsLayer::new())
                 .build(),
         );
 
+        let mut queries = QueryBusBuilder::new()
+            .register::<GetPostEngagementQuery, _>(GetPostEngagementHandler {
+                score_store: Arc::clone(&score_store),
+            })?;
+        if let Some(ledger) = &ledger {
+            queries = queries.register::<ExportSubjectDataQuery, _>(ExportSubjectDataHandler {
+                ledger: Arc::clone(ledger),
+            })?;
+        }
         let query_bus = Arc::new(
-            QueryBusBuilder::new()
-                .register::<GetPostEngagementQuery, _>(GetPostEngagementHandler {
-                    score_store: Arc::clone(&score_store),
-                })?
+            MiddlewarePipeline::new(queries.build())
+                .query_layer(MetricsLayer::new())
                 .build(),
         );
 
         // ── Write-behind workers (Kafka path) ────────────────────────────────
-        if let Some(kafka_client) = kafka {
-            let scylla_client = Arc::new(ScyllaSessionBuilder::new(scylla).build().await?);
-            let ledger = Arc::new(ScyllaReactionLedger::new(scylla_client));
-
+        if let (Some(kafka_client), Some(ledger)) = (kafka, ledger) {
             tokio::spawn(
                 ReactionWriteBehindWorker::new(
                     kafka_client.clone(),
@@ -144,6 +199,7 @@ impl App {
             query_bus,
             score_store: score_store as Arc<dyn ScoreStore>,
             redis: redis_client,
+            scylla: scylla_client,
         })
     }
 }
@@ -0,0 +1,78 @@
+use std::sync::Arc;
+
+use cqrs::{Command, CommandHandler, Envelope};
+use validate_core::Validate;
+
+use crate::application::port::{EngagementEventPublisher, ReactionLedger, ScoreStore};
+use crate::domain::aggregate::Reaction;
+use crate::domain::event::reaction_event::ReactionKafkaEvent;
+use crate::domain::value_object::{PostId, ProfileId};
+use crate::error::EngagementError;
+
+/// Page size used to walk `reactions_by_profile` during an erasure.
+const ERASURE_PAGE_SIZE: i32 = 100;
+
+/// GDPR erasure of every reaction the subject's profiles hold, driven by
+/// account's `gdpr_deletion_requested`. Each reaction is pulled out of the
+/// Redis score (publishing the usual `Removed` event when it was live there)
+/// and deleted from the ledger directly, so the erasure does not wait on the
+/// write-behind. Views and shares are anonymous counters and are kept.
+///
+/// The ledger walk is the source of the reaction list, so a redelivery finds
+/// nothing left and does nothing.
+pub struct EraseSubjectDataCommand {
+    pub profile_ids: Vec<String>,
+}
+
+impl Command for EraseSubjectDataCommand {
+    type Output = ();
+}
+
+impl Validate for EraseSubjectDataCommand {}
+
+pub struct EraseSubjectDataHandler<S, L, P> {
+    pub score_store: Arc<S>,
+    pub ledger:      Arc<L>,
+    pub publisher:   Arc<P>,
+}
+
+impl<S, L, P> CommandHandler<EraseSubjectDataCommand> for EraseSubjectDataHandler<S, L, P>
+where
+    S: ScoreStore,
+    L: ReactionLedger,
+    P: EngagementEventPublisher,
+{
+    type Error = EngagementError;
+
+    async fn handle(&self, envelope: Envelope<EraseSubjectDataCommand>) -> Result<(), EngagementError> {
+        for profile_id in &envelope.payload.profile_ids {
+            let profile_id = ProfileId::try_from(profile_id.as_str())?;
+            // The page token is the last `post_id` returned, so deleting the
+            // rows already read does not shift the next page.
+            let mut page_token: Option<String> = None;
+            loop {
+                let (rows, next) = self
+                    .ledger
+                    .list_by_profile(&profile_id, ERASURE_PAGE_SIZE, page_token.as_deref())
+                    .await?;
+                for row in rows {
+                    let post_id = PostId::from_uuid(row.post_id);
+                    if let Some((kind, weight)) =
+                        self.score_store.atomic_remove_reaction(&post_id, &profile_id).await?
+                    {
+                        let event = Reaction::build_removed_event(&post_id, &profile_id, kind, weight);
+                        self.publisher
+                            .publish_reaction_event(&ReactionKafkaEvent::Removed(event))
+                            .await?;
+                    }
+                    self.ledger.remove(&post_id, &profile_id).await?;
+                }
+                match next {
+                    Some(token) => page_token = Some(token),
+                    None => break,
+                }
+            }
+        }
+        Ok(())
+    }
+}
@@ -1,3 +1,4 @@
+pub mod erase_subject_data;
 pub mod record_share;
 pub mod record_view;
 pub mod remove_reaction;
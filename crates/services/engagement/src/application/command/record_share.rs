@@ -11,7 +11,9 @@ pub struct RecordShareCommand {
     pub post_id: String,
 }
 
-impl Command for RecordShareCommand {}
+impl Command for RecordShareCommand {
+    type Output = ();
+}
 
 impl Validate for RecordShareCommand {
     fn validate(&self) -> Result<(), Vec<FieldViolation>> {
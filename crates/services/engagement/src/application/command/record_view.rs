@@ -11,7 +11,9 @@ pub struct RecordViewCommand {
     pub post_id: String,
 }
 
-impl Command for RecordViewCommand {}
+impl Command for RecordViewCommand {
+    type Output = ();
+}
 
 impl Validate for RecordViewCommand {
     fn validate(&self) -> Result<(), Vec<FieldViolation>> {
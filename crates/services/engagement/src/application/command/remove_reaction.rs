@@ -14,7 +14,9 @@ pub struct RemoveReactionCommand {
     pub profile_id: String,
 }
 
-impl Command for RemoveReactionCommand {}
+impl Command for RemoveReactionCommand {
+    type Output = ();
+}
 
 impl Validate for RemoveReactionCommand {
     fn validate(&self) -> Result<(), Vec<FieldViolation>> {
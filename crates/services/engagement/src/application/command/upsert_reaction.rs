@@ -17,7 +17,9 @@ pub struct UpsertReactionCommand {
     pub kind:       i32,
 }
 
-impl Command for UpsertReactionCommand {}
+impl Command for UpsertReactionCommand {
+    type Output = ();
+}
 
 impl Validate for UpsertReactionCommand {
     fn validate(&self) -> Result<(), Vec<FieldViolation>> {
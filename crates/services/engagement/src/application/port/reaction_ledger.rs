@@ -34,6 +34,15 @@ pub trait ReactionLedger: Send + Sync + 'static {
         post_id: &PostId,
     ) -> Result<Vec<ReactionRow>, EngagementError>;
 
+    /// Paginated scan of `reactions_by_profile` — every reaction `profile_id`
+    /// currently holds. The page token is the last `post_id` returned.
+    async fn list_by_profile(
+        &self,
+        profile_id: &ProfileId,
+        limit:      i32,
+        page_token: Option<&str>,
+    ) -> Result<(Vec<ReactionRow>, Option<String>), EngagementError>;
+
     /// Applies a view/share/comment counter delta to the ScyllaDB counter table.
     async fn apply_interaction_delta(
         &self,
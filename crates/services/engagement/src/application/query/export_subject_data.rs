@@ -0,0 +1,82 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use cqrs::{Envelope, Query, QueryHandler};
+use serde::Serialize;
+
+use crate::application::port::ReactionLedger;
+use crate::domain::value_object::{ProfileId, ReactionKind};
+use crate::error::EngagementError;
+
+/// Page size used to walk `reactions_by_profile` during an export.
+const EXPORT_PAGE_SIZE: i32 = 100;
+
+/// GDPR Art. 20 export of every reaction the subject's profiles currently hold,
+/// read from the durable ledger (views and shares are anonymous counters and
+/// carry no personal data).
+pub struct ExportSubjectDataQuery {
+    pub profile_ids: Vec<String>,
+}
+
+impl Query for ExportSubjectDataQuery {
+    type Response = SubjectDataExport;
+}
+
+/// This service's slice of a data-subject export.
+#[derive(Debug, Clone, Serialize)]
+pub struct SubjectDataExport {
+    pub reactions: Vec<ReactionExport>,
+}
+
+impl SubjectDataExport {
+    pub fn record_count(&self) -> u32 {
+        self.reactions.len() as u32
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ReactionExport {
+    pub post_id:    String,
+    pub profile_id: String,
+    pub kind:       ReactionKind,
+    pub reacted_at: DateTime<Utc>,
+}
+
+pub struct ExportSubjectDataHandler<L> {
+    pub ledger: Arc<L>,
+}
+
+impl<L: ReactionLedger> QueryHandler<ExportSubjectDataQuery> for ExportSubjectDataHandler<L> {
+    type Error = EngagementError;
+
+    async fn handle(
+        &self,
+        envelope: Envelope<ExportSubjectDataQuery>,
+    ) -> Result<SubjectDataExport, EngagementError> {
+        let mut reactions = Vec::new();
+        for profile_id in &envelope.payload.profile_ids {
+            let profile_id = ProfileId::try_from(profile_id.as_str())?;
+            let mut page_token: Option<String> = None;
+            loop {
+                let (rows, next) = self
+                    .ledger
+                    .list_by_profile(&profile_id, EXPORT_PAGE_SIZE, page_token.as_deref())
+                    .await?;
+                for row in rows {
+                    reactions.push(ReactionExport {
+                        post_id:    row.post_id.to_string(),
+                        profile_id: row.profile_id.to_string(),
+                        kind:       ReactionKind::from_tinyint(row.kind)?,
+                        reacted_at: DateTime::from_timestamp_millis(row.reacted_at.0)
+                            .unwrap_or_default(),
+                    });
+                }
+                match next {
+                    Some(token) => page_token = Some(token),
+                    None => break,
+                }
+            }
+        }
+        Ok(SubjectDataExport { reactions })
+    }
+}
@@ -1 +1,2 @@
+pub mod export_subject_data;
 pub mod get_post_engagement;
@@ -64,6 +64,11 @@ impl ReactionKind {
         }
     }
 
+    /// Returns the proto enum ordinal (matching the proto ReactionKind enum).
+    pub fn as_proto(self) -> i32 {
+        i32::from(self.as_tinyint())
+    }
+
     /// Converts a proto enum ordinal (1-based, matching the proto ReactionKind enum) to domain type.
     pub fn from_proto(v: i32) -> Result<Self, EngagementError> {
         match v {
@@ -10,6 +10,7 @@ use crate::application::command::{
     upsert_reaction::UpsertReactionCommand,
 };
 use crate::application::port::PostEngagementSnapshot;
+use crate::application::query::export_subject_data::{ExportSubjectDataQuery, SubjectDataExport};
 use crate::application::query::get_post_engagement::GetPostEngagementQuery;
 use crate::domain::value_object::ReactionKind;
 
@@ -117,6 +118,26 @@ where
 
         Ok(Response::new(snapshot_to_proto(req.post_id, snapshot)))
     }
+
+    pub async fn export_subject_data(
+        &self,
+        request: Request<proto::ExportSubjectDataRequest>,
+    ) -> Result<Response<proto::ExportSubjectDataResponse>, Status> {
+        let req   = request.into_inner();
+        let query = ExportSubjectDataQuery { profile_ids: req.profile_ids };
+        let export: SubjectDataExport = self
+            .query_bus
+            .dispatch(Envelope::new(Uuid::now_v7(), query))
+            .await
+            .map_err(cqrs_to_status)?;
+
+        let document = serde_json::to_vec(&export)
+            .map_err(|e| Status::internal(format!("encode export document: {e}")))?;
+        Ok(Response::new(proto::ExportSubjectDataResponse {
+            document,
+            record_count: export.record_count(),
+        }))
+    }
 }
 
 // ── Proto trait implementation ────────────────────────────────────────────────
@@ -161,6 +182,13 @@ where
     ) -> Result<Response<proto::PostEngagementView>, Status> {
         self.get_post_engagement(request).await
     }
+
+    async fn export_subject_data(
+        &self,
+        request: Request<proto::ExportSubjectDataRequest>,
+    ) -> Result<Response<proto::ExportSubjectDataResponse>, Status> {
+        self.export_subject_data(request).await
+    }
 }
 
 // ── Conversion helpers ────────────────────────────────────────────────────────
@@ -1,8 +1,6 @@
 use std::net::SocketAddr;
 use std::sync::Arc;
 
-use cqrs::command::InMemoryCommandBus;
-use cqrs::query::InMemoryQueryBus;
 use tonic::transport::Server;
 use tonic_health::server::health_reporter;
 use tonic_reflection::server::Builder as ReflectionBuilder;
@@ -13,7 +11,7 @@ use transport::kafka::config::client::KafkaClientConfig;
 use transport::kafka::config::producer::ProducerConfig;
 use transport::kafka::producer::builder::KafkaProducerBuilder;
 
-use crate::app::{App, Backends};
+use crate::app::{App, AppCommandBus, AppQueryBus, Backends};
 use crate::config::ReactionWeightsConfig;
 use crate::infrastructure::grpc::handler::engagement_handler::{
     EngagementServiceHandler, EngagementServiceServer,
@@ -27,7 +25,7 @@ pub const FILE_DESCRIPTOR_SET: &[u8] =
 /// The gRPC handler type the server serves: the buses are shared by `Arc` (the
 /// same instances the composition root retains).
 type ServingHandler =
-    EngagementServiceHandler<Arc<InMemoryCommandBus>, Arc<InMemoryQueryBus>>;
+    EngagementServiceHandler<Arc<AppCommandBus>, Arc<AppQueryBus>>;
 
 /// Bootstraps and runs the engagement gRPC server.
 ///
@@ -1,4 +1,6 @@
 pub mod model;
+pub mod reaction_index_backfill;
 pub mod scylla_reaction_ledger;
 
+pub use reaction_index_backfill::ReactionIndexBackfill;
 pub use scylla_reaction_ledger::ScyllaReactionLedger;
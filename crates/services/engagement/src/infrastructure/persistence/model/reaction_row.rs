@@ -2,7 +2,8 @@ use scylla::DeserializeRow;
 use scylla::value::CqlTimestamp;
 use uuid::Uuid;
 
-/// ScyllaDB row type for `engagement.post_reactions`.
+/// ScyllaDB row type for `engagement.post_reactions` (and its
+/// `reactions_by_profile` mirror, which selects the same columns).
 #[derive(Debug, DeserializeRow)]
 pub struct ReactionRow {
     pub post_id:    Uuid,
@@ -0,0 +1,207 @@
+//! One-time backfill of `engagement.reactions_by_profile` from
+//! `engagement.post_reactions`.
+//!
+//! The reverse index only holds reactions written since it shipped, so an
+//! erasure walking it would silently miss every older reaction.
+//! [`ReactionIndexBackfill::run`] walks the ledger over the token ring and writes
+//! the missing entries; the subject-erasure consumer does not start until it has
+//! recorded completion, so no erasure is confirmed against a partial index.
+//!
+//! Each entry is written `USING TIMESTAMP` of the ledger row's own write, so a
+//! removal that lands while the walk is in flight still shadows the entry it
+//! copies. Every replica runs the walk at boot: finished token ranges are
+//! recorded in `engagement.index_backfills` and each replica starts at a
+//! different range, so they mostly split the work, and a restart resumes instead
+//! of starting over.
+
+use std::sync::Arc;
+
+use chrono::Utc;
+use futures::StreamExt;
+use scylla::observability::history::HistoryListener;
+use scylla::statement::unprepared::Statement;
+use scylla::value::CqlTimestamp;
+use scylla_storage::{ProfileKind as ScyllaProfileKind, ScyllaClient, ScyllaStorageError};
+use uuid::Uuid;
+
+use crate::error::EngagementError;
+
+const INDEX: &str = "reactions_by_profile";
+
+/// Token-ring slices walked (and checkpointed) independently.
+const RANGES: i32 = 256;
+
+/// Rows fetched per page of the ledger walk.
+const PAGE_SIZE: i32 = 500;
+
+pub struct ReactionIndexBackfill {
+    client: Arc<ScyllaClient>,
+}
+
+impl ReactionIndexBackfill {
+    pub fn new(client: Arc<ScyllaClient>) -> Self {
+        Self { client }
+    }
+
+    /// Walks every range no replica has finished yet, then records the index as
+    /// complete. Returns at once when it already is. Idempotent; on error the
+    /// caller re-runs it and the finished ranges are skipped.
+    pub async fn run(&self) -> Result<(), EngagementError> {
+        if self.is_complete().await? {
+            return Ok(());
+        }
+        let start = (Uuid::new_v4().as_u128() % RANGES as u128) as i32;
+        for offset in 0..RANGES {
+            let range_id = (start + offset) % RANGES;
+            if self.range_done(range_id).await? {
+                continue;
+            }
+            self.walk(range_id).await?;
+            self.execute(
+                "INSERT INTO engagement.index_backfills (index_name, range_id) VALUES (?, ?)",
+                (INDEX, range_id),
+            )
+            .await?;
+        }
+        self.execute(
+            "UPDATE engagement.index_backfills SET completed_at = ? WHERE index_name = ?",
+            (CqlTimestamp(Utc::now().timestamp_millis()), INDEX),
+        )
+        .await?;
+        tracing::info!(index = INDEX, "reverse-index backfill complete");
+        Ok(())
+    }
+
+    async fn is_complete(&self) -> Result<bool, EngagementError> {
+        let rows = self
+            .client
+            .session
+            .execute_unpaged(
+                self.stmt(
+                    "SELECT completed_at FROM engagement.index_backfills \
+                     WHERE index_name = ? LIMIT 1",
+                    ScyllaProfileKind::Strict,
+                    "strict",
+                ),
+                (INDEX,),
+            )
+            .await
+            .map_err(scylla_err)?
+            .into_rows_result()
+            .map_err(|e| pager_err(e.to_string()))?
+            .rows::<(Option<CqlTimestamp>,)>()
+            .map_err(|e| pager_err(e.to_string()))?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| pager_err(e.to_string()))?;
+        Ok(matches!(rows.first(), Some((Some(_),))))
+    }
+
+    async fn range_done(&self, range_id: i32) -> Result<bool, EngagementError> {
+        let result = self
+            .client
+            .session
+            .execute_unpaged(
+                self.stmt(
+                    "SELECT range_id FROM engagement.index_backfills \
+                     WHERE index_name = ? AND range_id = ?",
+                    ScyllaProfileKind::Strict,
+                    "strict",
+                ),
+                (INDEX, range_id),
+            )
+            .await
+            .map_err(scylla_err)?
+            .into_rows_result()
+            .map_err(|e| pager_err(e.to_string()))?;
+        Ok(result.rows_num() > 0)
+    }
+
+    /// Copies one token range of `post_reactions` into the index.
+    async fn walk(&self, range_id: i32) -> Result<(), EngagementError> {
+        let (low, high) = token_range(range_id);
+        let mut scan = self.stmt(
+            "SELECT profile_id, post_id, kind, weight, reacted_at, WRITETIME(kind) \
+             FROM engagement.post_reactions \
+             WHERE token(post_id) >= ? AND token(post_id) <= ?",
+            ScyllaProfileKind::Analytical,
+            "analytical",
+        );
+        scan.set_page_size(PAGE_SIZE);
+        let mut rows = self
+            .client
+            .session
+            .execute_iter(scan, (low, high))
+            .await
+            .map_err(|e| pager_err(e.to_string()))?
+            .rows_stream::<(Uuid, Uuid, i8, i32, CqlTimestamp, Option<i64>)>()
+            .map_err(|e| pager_err(e.to_string()))?;
+        while let Some(row) = rows.next().await {
+            let (profile_id, post_id, kind, weight, reacted_at, written_at) =
+                row.map_err(|e| pager_err(e.to_string()))?;
+            self.execute(
+                "INSERT INTO engagement.reactions_by_profile \
+                 (profile_id, post_id, kind, weight, reacted_at) \
+                 VALUES (?, ?, ?, ?, ?) USING TIMESTAMP ?",
+                (profile_id, post_id, kind, weight, reacted_at, written_at.unwrap_or_default()),
+            )
+            .await?;
+        }
+        Ok(())
+    }
+
+    async fn execute(
+        &self,
+        cql:    &str,
+        values: impl scylla::serialize::row::SerializeRow,
+    ) -> Result<(), EngagementError> {
+        self.client
+            .session
+            .execute_unpaged(self.stmt(cql, ScyllaProfileKind::Strict, "strict"), values)
+            .await
+            .map_err(scylla_err)?;
+        Ok(())
+    }
+
+    fn stmt(&self, cql: &str, kind: ScyllaProfileKind, label: &str) -> Statement {
+        let mut s = Statement::new(cql);
+        s.set_execution_profile_handle(Some(
+            self.client.profiles.get(kind).clone().into_handle_with_label(label.to_string()),
+        ));
+        s.set_history_listener(
+            Arc::clone(&self.client.history_listener) as Arc<dyn HistoryListener>,
+        );
+        s
+    }
+}
+
+/// The inclusive Murmur3 token bounds of slice `range_id` of [`RANGES`].
+fn token_range(range_id: i32) -> (i64, i64) {
+    let span = (1i128 << 64) / RANGES as i128;
+    let low = i64::MIN as i128 + span * range_id as i128;
+    let high = if range_id == RANGES - 1 { i64::MAX as i128 } else { low + span - 1 };
+    (low as i64, high as i64)
+}
+
+fn scylla_err(e: scylla::errors::ExecutionError) -> EngagementError {
+    EngagementError::Scylla(ScyllaStorageError::from(e))
+}
+
+/// A paged walk surfaces driver errors the storage mapping has no variant for;
+/// they are transient from the backfill's point of view, which simply re-runs.
+fn pager_err(message: String) -> EngagementError {
+    EngagementError::Scylla(ScyllaStorageError::Transport { message })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn token_ranges_tile_the_ring() {
+        assert_eq!(token_range(0).0, i64::MIN);
+        assert_eq!(token_range(RANGES - 1).1, i64::MAX);
+        for range_id in 1..RANGES {
+            assert_eq!(token_range(range_id).0, token_range(range_id - 1).1 + 1);
+        }
+    }
+}
@@ -2,6 +2,7 @@ use std::sync::Arc;
 
 use async_trait::async_trait;
 use scylla::observability::history::HistoryListener;
+use scylla::statement::batch::{Batch, BatchType};
 use scylla::statement::unprepared::Statement;
 use scylla::value::CqlTimestamp;
 use scylla_storage::{ProfileKind as ScyllaProfileKind, ScyllaClient, ScyllaStorageError};
@@ -47,6 +48,26 @@ impl ScyllaReactionLedger {
         s
     }
 
+    /// A logged batch of `cql` statements on the Strict profile, so a ledger row
+    /// and its `reactions_by_profile` entry land together or not at all.
+    fn strict_batch(&self, cql: &[&str]) -> Batch {
+        let mut b = Batch::new(BatchType::Logged);
+        for c in cql {
+            b.append_statement(Statement::new(*c));
+        }
+        b.set_execution_profile_handle(Some(
+            self.client
+                .profiles
+                .get(ScyllaProfileKind::Strict)
+                .clone()
+                .into_handle_with_label("strict".to_string()),
+        ));
+        b.set_history_listener(
+            Arc::clone(&self.client.history_listener) as Arc<dyn HistoryListener>,
+        );
+        b
+    }
+
     fn fast_stmt(&self, cql: &str) -> Statement {
         let mut s = Statement::new(cql);
         s.set_execution_profile_handle(Some(
@@ -73,21 +94,34 @@ impl ReactionLedger for ScyllaReactionLedger {
         weight:      i64,
         event_at_ms: i64,
     ) -> Result<(), EngagementError> {
-        let stmt = self.strict_stmt(
+        let batch = self.strict_batch(&[
             "INSERT INTO engagement.post_reactions \
              (post_id, profile_id, kind, weight, reacted_at) \
              VALUES (?, ?, ?, ?, ?)",
-        );
+            "INSERT INTO engagement.reactions_by_profile \
+             (profile_id, post_id, kind, weight, reacted_at) \
+             VALUES (?, ?, ?, ?, ?)",
+        ]);
+        let reacted_at = CqlTimestamp(event_at_ms);
         self.client
             .session
-            .execute_unpaged(
-                stmt,
+            .batch(
+                &batch,
                 (
-                    post_id.as_uuid(),
-                    profile_id.as_uuid(),
-                    kind.as_tinyint(),
-                    weight as i32,
-                    CqlTimestamp(event_at_ms),
+                    (
+                        post_id.as_uuid(),
+                        profile_id.as_uuid(),
+                        kind.as_tinyint(),
+                        weight as i32,
+                        reacted_at,
+                    ),
+                    (
+                        profile_id.as_uuid(),
+                        post_id.as_uuid(),
+                        kind.as_tinyint(),
+                        weight as i32,
+                        reacted_at,
+                    ),
                 ),
             )
             .await
@@ -101,13 +135,21 @@ impl ReactionLedger for ScyllaReactionLedger {
         post_id:    &PostId,
         profile_id: &ProfileId,
     ) -> Result<(), EngagementError> {
-        let stmt = self.strict_stmt(
+        let batch = self.strict_batch(&[
             "DELETE FROM engagement.post_reactions \
              WHERE post_id = ? AND profile_id = ?",
-        );
+            "DELETE FROM engagement.reactions_by_profile \
+             WHERE profile_id = ? AND post_id = ?",
+        ]);
         self.client
             .session
-            .execute_unpaged(stmt, (post_id.as_uuid(), profile_id.as_uuid()))
+            .batch(
+                &batch,
+                (
+                    (post_id.as_uuid(), profile_id.as_uuid()),
+                    (profile_id.as_uuid(), post_id.as_uuid()),
+                ),
+            )
             .await
             .map_err(scylla_err)?;
 
@@ -138,6 +180,54 @@ impl ReactionLedger for ScyllaReactionLedger {
         Ok(rows)
     }
 
+    async fn list_by_profile(
+        &self,
+        profile_id: &ProfileId,
+        limit:      i32,
+        page_token: Option<&str>,
+    ) -> Result<(Vec<ReactionRow>, Option<String>), EngagementError> {
+        // CQL LIMIT binds as a 32-bit int.
+        let limit = limit.clamp(1, 100);
+
+        let result = if let Some(token) = page_token {
+            let last_post_id = Uuid::parse_str(token)
+                .map_err(|e| row_err("list_by_profile:page_token", e))?;
+            let stmt = self.fast_stmt(
+                "SELECT post_id, profile_id, kind, weight, reacted_at \
+                 FROM engagement.reactions_by_profile \
+                 WHERE profile_id = ? AND post_id > ? LIMIT ?",
+            );
+            self.client
+                .session
+                .execute_unpaged(stmt, (profile_id.as_uuid(), last_post_id, limit))
+                .await
+        } else {
+            let stmt = self.fast_stmt(
+                "SELECT post_id, profile_id, kind, weight, reacted_at \
+                 FROM engagement.reactions_by_profile \
+                 WHERE profile_id = ? LIMIT ?",
+            );
+            self.client
+                .session
+                .execute_unpaged(stmt, (profile_id.as_uuid(), limit))
+                .await
+        };
+        let rows = result
+            .map_err(scylla_err)?
+            .into_rows_result()
+            .map_err(|e| row_err("list_by_profile:rows", e))?
+            .rows::<ReactionRow>()
+            .map_err(|e| row_err("list_by_profile:iter", e))?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| row_err("list_by_profile:deser", e))?;
+
+        let next_token = match rows.last() {
+            Some(row) if rows.len() == limit as usize => Some(row.post_id.to_string()),
+            _ => None,
+        };
+        Ok((rows, next_token))
+    }
+
     async fn apply_interaction_delta(
         &self,
         post_id:       &PostId,
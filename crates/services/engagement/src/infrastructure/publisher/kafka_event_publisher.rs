@@ -1,7 +1,7 @@
 use async_trait::async_trait;
 use transport::{
     error::TransportError,
-    kafka::{envelope::EventEnvelope, producer::handle::KafkaProducerHandle},
+    kafka::{envelope::EventEnvelope, producer::handle::KafkaProducerHandle, ContentType},
 };
 
 use crate::application::port::EngagementEventPublisher;
@@ -10,13 +10,20 @@ use crate::error::EngagementError;
 
 const TOPIC_REACTIONS: &str = "engagement.reactions";
 
+/// Selects the `engagement.reactions` encoding (`json` or `protobuf`, default
+/// `json`). Consumers learn protobuf in the same release, so the producer only
+/// opts in once every consumer is upgraded; the default flips in a later release.
+const CONTENT_TYPE_ENV: &str = "ENGAGEMENT_REACTIONS_CONTENT_TYPE";
+
 pub struct KafkaEngagementEventPublisher {
-    producer: KafkaProducerHandle,
+    producer:     KafkaProducerHandle,
+    content_type: ContentType,
 }
 
 impl KafkaEngagementEventPublisher {
     pub fn new(producer: KafkaProducerHandle) -> Self {
-        Self { producer }
+        let content_type = ContentType::from_env(CONTENT_TYPE_ENV, ContentType::Json);
+        Self { producer, content_type }
     }
 }
 
@@ -41,6 +48,22 @@ impl EngagementEventPublisher for KafkaEngagementEventPublisher {
             .with_header("post_id",    post_id)
             .with_header("profile_id", profile_id);
 
-        self.producer.publish(envelope).await.map_err(transport_err)
+        match self.content_type {
+            ContentType::Protobuf => self.producer.publish_proto(envelope).await,
+            ContentType::Json     => self.producer.publish(envelope).await,
+        }
+        .map_err(transport_err)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The JSON form of `engagement.reactions` is exactly the payload registered for it in event-topology.
+    #[test]
+    fn reaction_events_conform_to_the_registered_schema() {
+        let violations = event_topology::conformance::producer_violations::<ReactionKafkaEvent>(TOPIC_REACTIONS);
+        assert!(violations.is_empty(), "{violations:#?}");
     }
 }
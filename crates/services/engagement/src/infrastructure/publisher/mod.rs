@@ -1,3 +1,4 @@
 pub mod kafka_event_publisher;
+pub mod reaction_proto;
 
 pub use kafka_event_publisher::KafkaEngagementEventPublisher;
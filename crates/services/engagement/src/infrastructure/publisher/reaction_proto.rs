@@ -0,0 +1,106 @@
+//! Protobuf wire form of [`ReactionKafkaEvent`] (`engagement.v1.ReactionEvent`).
+//!
+//! The publisher encodes with [`ToProto`]; the write-behind worker decodes with
+//! [`FromProto`] via `Proto<ReactionKafkaEvent>`, which still accepts the JSON
+//! records published before the switch.
+
+use engagement_api::{reaction_event, ReactionEvent, ReactionRemoved, ReactionUpserted};
+use transport::error::CodecError;
+use transport::kafka::{FromProto, ToProto};
+
+use crate::domain::event::reaction_event::{
+    ReactionKafkaEvent, ReactionRemovedEvent, ReactionUpsertedEvent,
+};
+use crate::domain::value_object::ReactionKind;
+
+impl ToProto for ReactionKafkaEvent {
+    type Message = ReactionEvent;
+
+    fn to_proto(&self) -> ReactionEvent {
+        let event = match self {
+            Self::Upserted(e) => reaction_event::Event::Upserted(ReactionUpserted {
+                post_id:     e.post_id.clone(),
+                profile_id:  e.profile_id.clone(),
+                new_kind:    e.new_kind.as_proto(),
+                new_weight:  e.new_weight,
+                old_kind:    e.old_kind.map(ReactionKind::as_proto),
+                old_weight:  e.old_weight,
+                event_at_ms: e.event_at_ms,
+            }),
+            Self::Removed(e) => reaction_event::Event::Removed(ReactionRemoved {
+                post_id:     e.post_id.clone(),
+                profile_id:  e.profile_id.clone(),
+                kind:        e.kind.as_proto(),
+                weight:      e.weight,
+                event_at_ms: e.event_at_ms,
+            }),
+        };
+        ReactionEvent { event: Some(event) }
+    }
+}
+
+impl FromProto for ReactionKafkaEvent {
+    type Message = ReactionEvent;
+
+    fn from_proto(message: ReactionEvent) -> Result<Self, CodecError> {
+        match message.event {
+            Some(reaction_event::Event::Upserted(e)) => Ok(Self::Upserted(ReactionUpsertedEvent {
+                post_id:     e.post_id,
+                profile_id:  e.profile_id,
+                new_kind:    kind(e.new_kind)?,
+                new_weight:  e.new_weight,
+                old_kind:    e.old_kind.map(kind).transpose()?,
+                old_weight:  e.old_weight,
+                event_at_ms: e.event_at_ms,
+            })),
+            Some(reaction_event::Event::Removed(e)) => Ok(Self::Removed(ReactionRemovedEvent {
+                post_id:     e.post_id,
+                profile_id:  e.profile_id,
+                kind:        kind(e.kind)?,
+                weight:      e.weight,
+                event_at_ms: e.event_at_ms,
+            })),
+            None => Err(CodecError::ProtobufMapping("ReactionEvent.event is unset".into())),
+        }
+    }
+}
+
+fn kind(v: i32) -> Result<ReactionKind, CodecError> {
+    ReactionKind::from_proto(v).map_err(|e| CodecError::ProtobufMapping(e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use transport::codec::proto_encode;
+    use transport::kafka::envelope::ConsumablePayload;
+    use transport::kafka::{ContentType, Proto};
+
+    use super::*;
+
+    #[test]
+    fn an_upsert_survives_the_protobuf_round_trip() {
+        let event = ReactionKafkaEvent::Upserted(ReactionUpsertedEvent {
+            post_id:     "p1".into(),
+            profile_id:  "u1".into(),
+            new_kind:    ReactionKind::Fire,
+            new_weight:  3,
+            old_kind:    Some(ReactionKind::Heart),
+            old_weight:  Some(1),
+            event_at_ms: 42,
+        });
+        let bytes = proto_encode(&event.to_proto()).unwrap();
+
+        let Proto(decoded) = Proto::<ReactionKafkaEvent>::decode(ContentType::Protobuf, &bytes).unwrap();
+        let ReactionKafkaEvent::Upserted(e) = decoded else { panic!("expected an upsert") };
+        assert_eq!((e.new_kind, e.old_kind, e.old_weight), (ReactionKind::Fire, Some(ReactionKind::Heart), Some(1)));
+        assert_eq!(e.event_at_ms, 42);
+    }
+
+    #[test]
+    fn legacy_json_records_still_decode() {
+        let json = br#"{"event_type":"removed","post_id":"p1","profile_id":"u1","kind":"sad","weight":2,"event_at_ms":7}"#;
+        let Proto(decoded) = Proto::<ReactionKafkaEvent>::decode(ContentType::Json, json).unwrap();
+        assert!(matches!(decoded, ReactionKafkaEvent::Removed(e) if e.kind == ReactionKind::Sad));
+    }
+
+}
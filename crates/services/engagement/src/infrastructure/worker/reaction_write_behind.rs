@@ -5,6 +5,7 @@ use transport::kafka::consumer::{run_consumer, ProcessOutcome, RetryPolicy};
 use transport::kafka::config::client::KafkaClientConfig;
 use transport::kafka::config::consumer::{AutoOffsetReset, ConsumerConfig};
 use transport::kafka::producer::KafkaProducerHandle;
+use transport::kafka::Proto;
 
 use crate::application::port::ReactionLedger;
 use crate::domain::event::reaction_event::ReactionKafkaEvent;
@@ -78,7 +79,7 @@ impl<L: ReactionLedger> ReactionWriteBehindWorker<L> {
         // The ledger UPSERT is idempotent (last-write-wins) and removals are no-ops,
         // so transient failures are safe to retry before dead-lettering.
         let policy = RetryPolicy::default();
-        run_consumer::<ReactionKafkaEvent, _>(&handle, producer, &policy, move |event| {
+        run_consumer::<Proto<ReactionKafkaEvent>, _>(&handle, producer, &policy, move |event| {
             let worker = Arc::clone(&self);
             Box::pin(async move { ProcessOutcome::from_result(worker.process(event).await) })
         })
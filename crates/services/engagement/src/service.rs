@@ -5,29 +5,55 @@
 //! write-behind ledger driven by Kafka workers spawned inside [`App::build`].
 //! Readiness therefore gates on Redis only. Reaction weights are loaded from the
 //! externalized weights config.
+//!
+//! GDPR erasure is the one worker spawned here rather than in [`App::build`],
+//! because it reports to account: the shared [`subject_erasure`] consumer on
+//! `account.v1.events` erases the subject's reactions and confirms over
+//! `ConfirmSubjectErasure` (resilience binding `account`, endpoint
+//! `ENGAGEMENT_ACCOUNT_GRPC_ENDPOINT`). On the
+//! ledger path the consumer starts only once the `reactions_by_profile`
+//! backfill has completed, so every erasure walks an index that covers the
+//! subject's older reactions too.
 
 use std::sync::Arc;
+use std::time::Duration;
 
 use async_trait::async_trait;
-use cqrs::command::InMemoryCommandBus;
-use cqrs::query::InMemoryQueryBus;
 use redis_storage::RedisConfig;
 use scylla_storage::ScyllaConfig;
-use service_runtime::{HealthProbe, InfraRegistry, Service};
+use service_runtime::{AccessPolicy, HealthProbe, InfraRegistry, Service};
+use subject_erasure::{
+    run_subject_erasure_consumer, DeletionRequest, EraseCommand, ErasureReporter, GrpcErasureReporter,
+};
 use tonic::service::RoutesBuilder;
 use tonic_reflection::server::Builder as ReflectionBuilder;
-use transport::kafka::config::{KafkaClientConfig, ProducerConfig};
-use transport::kafka::producer::KafkaProducerBuilder;
+use transport::grpc::client::{GrpcClientBuilder, GrpcClientConfig};
+use transport::kafka::config::{ConsumerConfig, KafkaClientConfig, ProducerConfig};
+use transport::kafka::consumer::{KafkaConsumerBuilder, KafkaConsumerHandle};
+use transport::kafka::producer::{KafkaProducerBuilder, KafkaProducerHandle};
 
-use crate::app::{App, Backends};
+use crate::app::{App, AppCommandBus, AppQueryBus, Backends};
+use crate::application::command::erase_subject_data::EraseSubjectDataCommand;
 use crate::config::ReactionWeightsConfig;
+use crate::infrastructure::persistence::ReactionIndexBackfill;
 use crate::infrastructure::grpc::handler::engagement_handler::EngagementServiceServer;
 use crate::infrastructure::grpc::handler::EngagementServiceHandler;
 use crate::infrastructure::grpc::server::FILE_DESCRIPTOR_SET;
 use crate::infrastructure::publisher::KafkaEngagementEventPublisher;
 
+/// Account lifecycle stream carrying GDPR deletion requests.
+const ACCOUNT_EVENTS_TOPIC: &str = "account.v1.events";
+/// Consumer group for engagement's part of a GDPR erasure.
+const SUBJECT_ERASURE_GROUP: &str = "engagement-subject-erasure";
+/// Engagement's name in account's erasure participant list.
+const ERASURE_PARTICIPANT: &str = "engagement";
+/// Resilience binding of the erasure confirmations sent to account.
+const ACCOUNT_DEPENDENCY: &str = "account";
+/// Backoff before respawning the consumer after the runner returns.
+const CONSUMER_RESPAWN_BACKOFF: Duration = Duration::from_secs(5);
+
 type EngagementServer =
-    EngagementServiceServer<EngagementServiceHandler<Arc<InMemoryCommandBus>, Arc<InMemoryQueryBus>>>;
+    EngagementServiceServer<EngagementServiceHandler<Arc<AppCommandBus>, Arc<AppQueryBus>>>;
 
 /// The engagement service as hosted by [`service_runtime`].
 pub struct EngagementService {
@@ -41,7 +67,7 @@ impl Service for EngagementService {
     const GRPC_SERVICE_NAME: &'static str =
         <EngagementServer as tonic::server::NamedService>::NAME;
 
-    async fn build(_infra: Arc<InfraRegistry>) -> anyhow::Result<Self> {
+    async fn build(infra: Arc<InfraRegistry>) -> anyhow::Result<Self> {
         let backends = Backends {
             scylla: ScyllaConfig::from_env(),
             redis:  RedisConfig::from_env(),
@@ -61,6 +87,23 @@ impl Service for EngagementService {
             .await
             .map_err(|e| anyhow::anyhow!("engagement app build: {e}"))?;
 
+        // The channel connects lazily; account being down only delays the
+        // confirmation (the consumer retries).
+        let account = GrpcClientBuilder::new(
+            GrpcClientConfig::new(
+                std::env::var("ENGAGEMENT_ACCOUNT_GRPC_ENDPOINT")
+                    .unwrap_or_else(|_| "http://localhost:50059".to_owned()),
+            )
+            .with_dependency(ACCOUNT_DEPENDENCY),
+        )
+        .build_from_registry_lazy(&infra.resilience())
+        .map_err(|e| anyhow::anyhow!("engagement account channel: {e}"))?;
+        spawn_subject_erasure_consumer(
+            app.scylla.clone().map(ReactionIndexBackfill::new),
+            Arc::clone(&app.command_bus),
+            Arc::new(GrpcErasureReporter::new(account, ERASURE_PARTICIPANT)),
+        );
+
         Ok(Self { app })
     }
 
@@ -68,6 +111,17 @@ impl Service for EngagementService {
         vec![redis_storage::health::probe(self.app.redis.clone())]
     }
 
+    fn access_policy(&self) -> AccessPolicy {
+        AccessPolicy::for_service(Self::GRPC_SERVICE_NAME)
+            .authenticated("UpsertReaction")
+            .authenticated("RemoveReaction")
+            .authenticated("RecordView")
+            .authenticated("RecordShare")
+            .authenticated("GetPostEngagement")
+            // Account's GDPR data-export pipeline.
+            .internal("ExportSubjectData")
+    }
+
     fn register(self, routes: &mut RoutesBuilder) -> anyhow::Result<()> {
         let handler = EngagementServiceHandler::new(
             Arc::clone(&self.app.command_bus),
@@ -82,3 +136,58 @@ impl Service for EngagementService {
         Ok(())
     }
 }
+
+/// Runs `backfill` (when there is a ledger) to completion, then spawns the
+/// supervised subject-erasure consumer, respawning after
+/// [`CONSUMER_RESPAWN_BACKOFF`] whenever the runner returns. Until the backfill
+/// completes, deletion requests wait in Kafka.
+fn spawn_subject_erasure_consumer(
+    backfill:    Option<ReactionIndexBackfill>,
+    command_bus: Arc<AppCommandBus>,
+    reporter:    Arc<dyn ErasureReporter>,
+) {
+    let eraser = Arc::new(EraseCommand::new(command_bus, |request: &DeletionRequest| {
+        EraseSubjectDataCommand { profile_ids: request.profile_ids.clone() }
+    }));
+    tokio::spawn(async move {
+        if let Some(backfill) = backfill {
+            while let Err(error) = backfill.run().await {
+                tracing::warn!(%error, "reactions_by_profile backfill failed; retrying");
+                tokio::time::sleep(CONSUMER_RESPAWN_BACKOFF).await;
+            }
+        }
+        loop {
+            match build_consumer() {
+                Ok((consumer, producer)) => {
+                    run_subject_erasure_consumer(
+                        ERASURE_PARTICIPANT,
+                        consumer,
+                        Arc::clone(&eraser),
+                        Arc::clone(&reporter),
+                        producer,
+                    )
+                    .await;
+                    tracing::warn!("subject-erasure consumer exited; respawning after backoff");
+                }
+                Err(error) => {
+                    tracing::error!(%error, "failed to build subject-erasure consumer; retrying");
+                }
+            }
+            tokio::time::sleep(CONSUMER_RESPAWN_BACKOFF).await;
+        }
+    });
+}
+
+/// Builds the manual-commit consumer (subscribed to `account.v1.events`) and the
+/// dead-letter producer the runner needs.
+fn build_consumer() -> anyhow::Result<(KafkaConsumerHandle, KafkaProducerHandle)> {
+    let kafka = KafkaClientConfig::from_env();
+    let consumer = KafkaConsumerBuilder::new(ConsumerConfig::new(kafka.clone(), SUBJECT_ERASURE_GROUP))
+        .subscribe(ACCOUNT_EVENTS_TOPIC)
+        .build()
+        .map_err(|e| anyhow::anyhow!("build subject-erasure consumer: {e}"))?;
+    let producer = KafkaProducerBuilder::new(ProducerConfig::new(kafka))
+        .build()
+        .map_err(|e| anyhow::anyhow!("build subject-erasure dead-letter producer: {e}"))?;
+    Ok((consumer, producer))
+}
@@ -14,13 +14,11 @@ use std::time::Duration;
 use async_trait::async_trait;
 use uuid::Uuid;
 
-use cqrs::command::InMemoryCommandBus;
-use cqrs::query::InMemoryQueryBus;
 use cqrs::{CommandBus, CqrsError, Envelope, QueryBus};
 use redis_storage::RedisConfig;
 use scylla_storage::ScyllaConfig;
 
-use engagement::app::{App, Backends};
+use engagement::app::{App, AppCommandBus, AppQueryBus, Backends};
 use engagement::application::command::record_view::RecordViewCommand;
 use engagement::application::command::remove_reaction::RemoveReactionCommand;
 use engagement::application::command::upsert_reaction::UpsertReactionCommand;
@@ -53,8 +51,8 @@ impl EngagementEventPublisher for NoopPublisher {
 
 /// A fully-wired engagement service bound to ephemeral Redis, plus the buses.
 pub struct TestHarness {
-    pub command_bus: Arc<InMemoryCommandBus>,
-    pub query_bus:   Arc<InMemoryQueryBus>,
+    pub command_bus: Arc<AppCommandBus>,
+    pub query_bus:   Arc<AppQueryBus>,
 }
 
 impl TestHarness {
@@ -113,7 +111,7 @@ impl TestHarness {
 /// Dispatches an upsert on a shared bus — a free function so scenarios can fire
 /// many concurrently from spawned tasks.
 pub async fn dispatch_upsert(
-    command_bus: Arc<InMemoryCommandBus>,
+    command_bus: Arc<AppCommandBus>,
     post_id:     String,
     profile_id:  String,
     kind:        i32,
@@ -123,7 +121,7 @@ pub async fn dispatch_upsert(
 }
 
 /// Dispatches a view record on a shared bus.
-pub async fn dispatch_view(command_bus: Arc<InMemoryCommandBus>, post_id: String) -> Result<(), CqrsError> {
+pub async fn dispatch_view(command_bus: Arc<AppCommandBus>, post_id: String) -> Result<(), CqrsError> {
     command_bus
         .dispatch(Envelope::new(Uuid::now_v7(), RecordViewCommand { post_id }))
         .await
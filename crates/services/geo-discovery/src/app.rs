@@ -14,7 +14,12 @@
 use std::sync::Arc;
 
 use cqrs::command::{CommandBusBuilder, InMemoryCommandBus};
+use cqrs::middleware::{
+    IdempotencyCommandBus, IdempotencyLayer, MetricsCommandBus, MetricsLayer,
+    MetricsQueryBus, MiddlewarePipeline,
+};
 use cqrs::query::{InMemoryQueryBus, QueryBusBuilder};
+use idempotency::RedisIdempotencyStore;
 use redis_storage::{RedisClient, RedisClientBuilder, RedisConfig};
 use scylla_storage::{ScyllaClient, ScyllaConfig, ScyllaSessionBuilder};
 use transport::kafka::config::client::KafkaClientConfig;
@@ -43,13 +48,25 @@ pub struct Backends {
     pub kafka:  Option<KafkaClientConfig>,
 }
 
+/// The command bus every entrypoint dispatches through: the registered handlers
+/// behind an [`IdempotencyLayer`] on the service's Redis, so a retried `message_id`
+/// runs once across replicas. It fails open: a Redis outage costs deduplication,
+/// not the write path.
+/// A [`MetricsLayer`] outermost times every dispatch, replays included.
+pub type AppCommandBus =
+    MetricsCommandBus<IdempotencyCommandBus<InMemoryCommandBus, RedisIdempotencyStore>>;
+
+/// The query bus every read path dispatches through: the registered handlers,
+/// timed by a [`MetricsLayer`].
+pub type AppQueryBus = MetricsQueryBus<InMemoryQueryBus>;
+
 /// A fully-wired geo-discovery service bound to its backends. The buses exposed
 /// here are the *same* instances the handlers are registered into; the
 /// `QueryTile` query reads the spatial index, card cache, and tile repository, so
 /// the query bus proves the end-to-end index→query round-trip.
 pub struct App {
-    pub command_bus: Arc<InMemoryCommandBus>,
-    pub query_bus:   Arc<InMemoryQueryBus>,
+    pub command_bus: Arc<AppCommandBus>,
+    pub query_bus:   Arc<AppQueryBus>,
     /// Live storage clients, retained so the runtime's readiness loop can probe
     /// their liveness (see [`crate::service`]).
     pub scylla:      Arc<ScyllaClient>,
@@ -74,35 +91,46 @@ impl App {
         let pin_store = Arc::new(RedisPinStore::new(redis_client.clone()));
         let tile_repository = Arc::new(ScyllaTileRepository::new(Arc::clone(&scylla_client)));
 
+        let handlers = CommandBusBuilder::new()
+            .register::<IndexPostCommand, _>(IndexPostHandler {
+                spatial_index:        Arc::clone(&spatial_index),
+                card_store:           Arc::clone(&card_store),
+                tile_repository:      Arc::clone(&tile_repository),
+                pin_store:            Arc::clone(&pin_store),
+                card_cache_threshold: cfg.card_cache_threshold,
+            })?
+            .register::<UpdateViralityWithTilesCommand, _>(UpdateViralityWithTilesHandler {
+                spatial_index:   Arc::clone(&spatial_index),
+                tile_repository: Arc::clone(&tile_repository),
+            })?
+            .build();
         let command_bus = Arc::new(
-            CommandBusBuilder::new()
-                .register::<IndexPostCommand, _>(IndexPostHandler {
-                    spatial_index:        Arc::clone(&spatial_index),
-                    card_store:           Arc::clone(&card_store),
-                    tile_repository:      Arc::clone(&tile_repository),
-                    pin_store:            Arc::clone(&pin_store),
-                    card_cache_threshold: cfg.card_cache_threshold,
-                })?
-                .register::<UpdateViralityWithTilesCommand, _>(UpdateViralityWithTilesHandler {
-                    spatial_index:   Arc::clone(&spatial_index),
-                    tile_repository: Arc::clone(&tile_repository),
-                })?
+            MiddlewarePipeline::new(handlers)
+                .layer(
+                    IdempotencyLayer::new(RedisIdempotencyStore::new(redis_client.clone(), "geo-discovery"))
+                        .fail_open(),
+                )
+                .layer(MetricsLayer::new())
                 .build(),
         );
 
         let query_bus = Arc::new(
-            QueryBusBuilder::new()
-                // Radar (pan): Redis-only, returns lightweight pins.
-                .register::<QueryTileQuery, _>(QueryTileHandler {
-                    spatial_index: Arc::clone(&spatial_index),
-                    pin_store:     Arc::clone(&pin_store),
-                })?
-                // Focus (tap): hydrates full cards, Redis + ScyllaDB fallback.
-                .register::<GetGeoTimelineQuery, _>(GetGeoTimelineHandler {
-                    card_store:      Arc::clone(&card_store),
-                    tile_repository: Arc::clone(&tile_repository),
-                })?
-                .build(),
+            MiddlewarePipeline::new(
+                QueryBusBuilder::new()
+                    // Radar (pan): Redis-only, returns lightweight pins.
+                    .register::<QueryTileQuery, _>(QueryTileHandler {
+                        spatial_index: Arc::clone(&spatial_index),
+                        pin_store:     Arc::clone(&pin_store),
+                    })?
+                    // Focus (tap): hydrates full cards, Redis + ScyllaDB fallback.
+                    .register::<GetGeoTimelineQuery, _>(GetGeoTimelineHandler {
+                        card_store:      Arc::clone(&card_store),
+                        tile_repository: Arc::clone(&tile_repository),
+                    })?
+                    .build(),
+            )
+            .query_layer(MetricsLayer::new())
+            .build(),
         );
 
         // ── Background workers (Kafka path) ──────────────────────────────────
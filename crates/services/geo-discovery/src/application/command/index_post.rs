@@ -38,7 +38,9 @@ pub struct IndexPostCommand {
     pub author_tier:       u8,
 }
 
-impl Command for IndexPostCommand {}
+impl Command for IndexPostCommand {
+    type Output = ();
+}
 
 impl Validate for IndexPostCommand {
     fn validate(&self) -> Result<(), Vec<FieldViolation>> {
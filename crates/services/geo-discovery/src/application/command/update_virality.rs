@@ -34,7 +34,9 @@ pub struct UpdateViralityWithTilesCommand {
     pub h3_index_r9: i64,
 }
 
-impl Command for UpdateViralityWithTilesCommand {}
+impl Command for UpdateViralityWithTilesCommand {
+    type Output = ();
+}
 
 impl Validate for UpdateViralityWithTilesCommand {
     fn validate(&self) -> Result<(), Vec<FieldViolation>> {
@@ -1,7 +1,6 @@
 use std::net::SocketAddr;
 use std::sync::Arc;
 
-use cqrs::query::InMemoryQueryBus;
 use tonic::transport::Server;
 use tonic_health::server::health_reporter;
 use tonic_reflection::server::Builder as ReflectionBuilder;
@@ -10,7 +9,7 @@ use redis_storage::RedisConfig;
 use scylla_storage::ScyllaConfig;
 use transport::kafka::config::client::KafkaClientConfig;
 
-use crate::app::{App, Backends};
+use crate::app::{App, AppQueryBus, Backends};
 use crate::config::GeoDiscoveryConfig;
 use crate::infrastructure::grpc::handler::{GeoDiscoveryHandler, GeoDiscoveryServiceServer};
 
@@ -20,7 +19,7 @@ pub const FILE_DESCRIPTOR_SET: &[u8] =
 
 /// The gRPC handler type the server serves: the query bus is shared by `Arc`
 /// (the same instance the composition root retains).
-type ServingHandler = GeoDiscoveryHandler<Arc<InMemoryQueryBus>>;
+type ServingHandler = GeoDiscoveryHandler<Arc<AppQueryBus>>;
 
 /// Bootstraps and runs the geo-discovery gRPC server.
 ///
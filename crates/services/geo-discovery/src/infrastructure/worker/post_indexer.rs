@@ -13,11 +13,23 @@ use crate::infrastructure::cache::{RedisCardStore, RedisGeoSpatialIndex, RedisPi
 use crate::infrastructure::persistence::ScyllaTileRepository;
 use crate::infrastructure::worker::build_dlq_producer;
 
-const TOPIC: &str = "post.published";
+const TOPIC: &str = "post.v1.events";
 
-/// Kafka event schema for `post.published`.
+/// A `post.v1.events` record, internally tagged on `type`. Only publication
+/// indexes a post: `PostUpdated` carries no location or card content, and a
+/// deleted post's pin and card age out with its retention TTL, so both decode to
+/// [`PostEvent::Other`] and commit without work.
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type")]
+pub enum PostEvent {
+    PostPublished(PostPublishedEvent),
+    #[serde(other)]
+    Other,
+}
+
+/// The `PostPublished` variant of `post.v1.events`.
 ///
-/// Published by `services/post` when a post transitions to Published status.
+/// Emitted by `services/post` when a post transitions to Published status.
 /// `services/post` only emits POST-owned data: it does not carry the author's
 /// display name or avatar (those are profile-owned and joined separately from
 /// `profile.v1.events`). This struct mirrors that contract — every field beyond
@@ -58,8 +70,8 @@ pub struct PostPublishedEvent {
     pub author_tier:     u8,
 }
 
-/// Long-lived background worker that consumes `post.published` events and
-/// indexes each post into the spatial index and card store.
+/// Long-lived background worker that consumes `post.v1.events` and indexes each
+/// published post into the spatial index and card store.
 ///
 /// Delivery semantics: at-least-once (auto-commit enabled). All writes are
 /// idempotent (ZADD + cap Lua, ScyllaDB INSERT with no IF conditions), so
@@ -140,7 +152,7 @@ where
         tracing::info!(topic = TOPIC, group = %self.group_id, "post indexer consumer started");
 
         let policy = RetryPolicy::default();
-        run_consumer::<PostPublishedEvent, _>(&handle, producer, &policy, move |event| {
+        run_consumer::<PostEvent, _>(&handle, producer, &policy, move |event| {
             let worker = Arc::clone(&self);
             Box::pin(async move { ProcessOutcome::from_result(worker.process(event).await) })
         })
@@ -148,17 +160,21 @@ where
         .map_err(|e| e.to_string())
     }
 
-    async fn process(&self, event: &PostPublishedEvent) -> Result<(), crate::error::GeoDiscoveryError> {
+    async fn process(&self, event: &PostEvent) -> Result<(), crate::error::GeoDiscoveryError> {
         use cqrs::{CommandHandler, Envelope};
         use uuid::Uuid;
 
+        let PostEvent::PostPublished(event) = event else {
+            return Ok(());
+        };
+
         // A post without a location is not a map post — skip spatial indexing.
         // lat/lng are emitted together by services/post, so a partial pair is
         // treated as "no location" rather than an error.
         let (Some(lat), Some(lng)) = (event.lat, event.lng) else {
             tracing::debug!(
                 post_id = %event.post_id,
-                "post.published carried no location — skipping geo indexing"
+                "PostPublished carried no location — skipping geo indexing"
             );
             return Ok(());
         };
@@ -175,7 +191,7 @@ where
             post_id:           event.post_id.clone(),
             // services/post emits the author identity as `profile_id`.
             author_id:         event.profile_id.clone(),
-            // Display name + avatar are NOT carried on post.published (profile-owned).
+            // Display name + avatar are NOT carried on PostPublished (profile-owned).
             // They are backfilled from profile.v1.events by a separate consumer;
             // until that join runs, the card renders them empty.
             author_handle:     String::new(),
@@ -1,11 +1,14 @@
 use std::sync::Arc;
 
+use counter_api::{CounterEntityType, PopularityUpdated};
 use serde::Deserialize;
+use transport::error::CodecError;
 use transport::kafka::consumer::builder::KafkaConsumerBuilder;
 use transport::kafka::consumer::{run_consumer, ProcessOutcome, RetryPolicy};
 use transport::kafka::config::client::KafkaClientConfig;
 use transport::kafka::config::consumer::{AutoOffsetReset, ConsumerConfig};
 use transport::kafka::producer::KafkaProducerHandle;
+use transport::kafka::{FromProto, Proto};
 
 use crate::application::command::UpdateViralityWithTilesCommand;
 use crate::application::port::{SpatialIndex, TileRepository};
@@ -29,6 +32,9 @@ const ENTITY_TYPE_POST: &str = "post";
 /// `{ entity_type, entity_id, score }`; geo filters for `entity_type == "post"`,
 /// maps `entity_id → post_id` and `score → new_score`, and commits every other
 /// entity kind as a no-op.
+///
+/// Counter publishes it as `counter.v1.PopularityUpdated`; records from before
+/// the protobuf switch are the JSON form. `Proto<PopularityEvent>` reads both.
 #[derive(Debug, Deserialize)]
 pub struct PopularityEvent {
     pub entity_type: String,
@@ -36,6 +42,30 @@ pub struct PopularityEvent {
     pub score:       f64,
 }
 
+impl FromProto for PopularityEvent {
+    type Message = PopularityUpdated;
+
+    fn from_proto(message: PopularityUpdated) -> Result<Self, CodecError> {
+        let entity = message
+            .entity
+            .ok_or_else(|| CodecError::ProtobufMapping("PopularityUpdated.entity is unset".into()))?;
+        Ok(Self {
+            entity_type: entity_type_name(entity.entity_type),
+            entity_id:   entity.id,
+            score:       message.score,
+        })
+    }
+}
+
+/// The JSON discriminant (`post`, `profile`, …) of a proto entity type; an
+/// unknown or unset type maps to `""`, which no consumer routes.
+fn entity_type_name(v: i32) -> String {
+    match CounterEntityType::try_from(v) {
+        Ok(CounterEntityType::Unspecified) | Err(_) => String::new(),
+        Ok(kind) => kind.as_str_name().trim_start_matches("COUNTER_ENTITY_TYPE_").to_ascii_lowercase(),
+    }
+}
+
 /// Long-lived background worker that consumes `counter.v1.popularity` snapshots
 /// (filtered to `entity_type == "post"`) and propagates new virality scores to
 /// both ScyllaDB and Redis ZSETs.
@@ -115,7 +145,7 @@ where
         tracing::info!(topic = TOPIC, group = %self.group_id, "score updater consumer started");
 
         let policy = RetryPolicy::default();
-        run_consumer::<PopularityEvent, _>(&handle, producer, &policy, move |event| {
+        run_consumer::<Proto<PopularityEvent>, _>(&handle, producer, &policy, move |event| {
             let worker = Arc::clone(&self);
             Box::pin(async move { ProcessOutcome::from_result(worker.process(event).await) })
         })
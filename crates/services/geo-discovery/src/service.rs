@@ -8,20 +8,19 @@
 use std::sync::Arc;
 
 use async_trait::async_trait;
-use cqrs::query::InMemoryQueryBus;
 use redis_storage::RedisConfig;
 use scylla_storage::ScyllaConfig;
-use service_runtime::{HealthProbe, InfraRegistry, Service};
+use service_runtime::{AccessPolicy, HealthProbe, InfraRegistry, Service};
 use tonic::service::RoutesBuilder;
 use tonic_reflection::server::Builder as ReflectionBuilder;
 use transport::kafka::config::KafkaClientConfig;
 
-use crate::app::{App, Backends};
+use crate::app::{App, AppQueryBus, Backends};
 use crate::config::GeoDiscoveryConfig;
 use crate::infrastructure::grpc::handler::{GeoDiscoveryHandler, GeoDiscoveryServiceServer};
 use crate::infrastructure::grpc::server::FILE_DESCRIPTOR_SET;
 
-type GeoServer = GeoDiscoveryServiceServer<GeoDiscoveryHandler<Arc<InMemoryQueryBus>>>;
+type GeoServer = GeoDiscoveryServiceServer<GeoDiscoveryHandler<Arc<AppQueryBus>>>;
 
 /// The geo-discovery service as hosted by [`service_runtime`].
 pub struct GeoDiscoveryService {
@@ -56,6 +55,12 @@ impl Service for GeoDiscoveryService {
         ]
     }
 
+    fn access_policy(&self) -> AccessPolicy {
+        AccessPolicy::for_service(Self::GRPC_SERVICE_NAME)
+            .authenticated("QueryTile")
+            .authenticated("GetGeoTimeline")
+    }
+
     fn register(self, routes: &mut RoutesBuilder) -> anyhow::Result<()> {
         let handler = GeoDiscoveryHandler::new(Arc::clone(&self.app.query_bus));
         let reflection = ReflectionBuilder::configure()
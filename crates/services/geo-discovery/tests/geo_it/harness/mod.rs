@@ -9,13 +9,11 @@ use std::time::Duration;
 
 use uuid::Uuid;
 
-use cqrs::command::InMemoryCommandBus;
-use cqrs::query::InMemoryQueryBus;
 use cqrs::{CommandBus, Envelope, QueryBus};
 use redis_storage::RedisConfig;
 use scylla_storage::ScyllaConfig;
 
-use geo_discovery::app::{App, Backends};
+use geo_discovery::app::{App, AppCommandBus, AppQueryBus, Backends};
 use geo_discovery::application::command::IndexPostCommand;
 use geo_discovery::application::query::get_geo_timeline::{GetGeoTimelineQuery, GetGeoTimelineResult};
 use geo_discovery::application::query::query_tile::{QueryTileQuery, QueryTileResult};
@@ -38,8 +36,8 @@ pub const ZOOM_R9: i32 = 15;
 
 /// A fully-wired geo-discovery service bound to ephemeral infra, plus the buses.
 pub struct TestHarness {
-    pub command_bus: Arc<InMemoryCommandBus>,
-    pub query_bus:   Arc<InMemoryQueryBus>,
+    pub command_bus: Arc<AppCommandBus>,
+    pub query_bus:   Arc<AppQueryBus>,
 }
 
 impl TestHarness {
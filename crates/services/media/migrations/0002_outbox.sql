@@ -0,0 +1,35 @@
+-- Transactional outbox for media.v1.events (shared `outbox` crate schema — a
+-- verbatim rendering of OutboxTable::new("media").ddl()).
+--
+-- WHY: handlers committed the asset row and then published straight to Kafka.
+-- A broker hiccup after the commit failed the RPC for an upload that had in
+-- fact landed, and the AssetUploaded event that drives the Plane B processing
+-- pipeline was simply gone — the asset stayed `uploaded` forever. Events are
+-- now enqueued here (same database as the asset rows) and relayed to Kafka.
+CREATE TABLE IF NOT EXISTS media_outbox (
+    id            UUID        PRIMARY KEY,
+    seq           BIGSERIAL,
+    topic         TEXT        NOT NULL,
+    aggregate_key TEXT        NOT NULL,
+    slot          INT         NOT NULL,
+    event_type    TEXT        NOT NULL,
+    payload       JSONB       NOT NULL,
+    headers       JSONB       NOT NULL DEFAULT '{}',
+    attempts      INT         NOT NULL DEFAULT 0,
+    last_error    TEXT,
+    created_at    TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+CREATE INDEX IF NOT EXISTS media_outbox_drain_order
+    ON media_outbox (slot, seq);
+
+CREATE TABLE IF NOT EXISTS media_outbox_lease (
+    slot       INT         PRIMARY KEY,
+    owner      TEXT        NOT NULL DEFAULT '',
+    expires_at TIMESTAMPTZ NOT NULL DEFAULT 'epoch'
+);
+
+CREATE TABLE IF NOT EXISTS media_outbox_member (
+    owner      TEXT        PRIMARY KEY,
+    expires_at TIMESTAMPTZ NOT NULL
+);
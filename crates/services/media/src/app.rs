@@ -10,6 +10,9 @@
 
 use std::sync::Arc;
 
+use outbox::{
+    KafkaOutboxSink, LogOutboxSink, OutboxRelay, OutboxSink, OutboxTable, PgOutbox, RelayConfig,
+};
 use postgres_storage::{PgPoolBuilder, PostgresConfig, TransactionManager};
 use redis_storage::{RedisClient, RedisClientBuilder, RedisConfig};
 use sqlx::PgPool;
@@ -19,19 +22,19 @@ use transport::kafka::config::producer::ProducerConfig;
 use transport::kafka::producer::KafkaProducerBuilder;
 
 use crate::application::command::{
-    ApplyModerationHandler, CommitUploadHandler, DeleteAssetHandler, IssueUploadTicketHandler,
-    ProcessAssetHandler, TranscodeAssetHandler,
+    ApplyModerationHandler, CommitUploadHandler, DeleteAssetHandler, EraseSubjectDataHandler,
+    IssueUploadTicketHandler, ProcessAssetHandler, TranscodeAssetHandler,
 };
 use crate::application::port::{
     AssetRepository, CdnGateway, DeliveryCache, EventPublisher, ImageProcessor, MalwareScanner,
     MediaProbe, ModerationScreen, ObjectStore, VideoTranscoder,
 };
-use crate::application::query::{GetAssetHandler, ResolveDeliveryHandler};
+use crate::application::query::{ExportSubjectDataHandler, GetAssetHandler, ResolveDeliveryHandler};
 use crate::application::MediaPolicy;
 use crate::config::MediaConfig;
 use crate::infrastructure::cache::RedisDeliveryCache;
 use crate::infrastructure::cdn::CloudFrontCdnGateway;
-use crate::infrastructure::event::{KafkaEventPublisher, LogEventPublisher};
+use crate::infrastructure::event::{PgOutboxPublisher, OUTBOX_PREFIX};
 use crate::infrastructure::grpc::MediaServiceHandler;
 use crate::infrastructure::persistence::PgAssetRepository;
 use crate::infrastructure::probe::{DispatchingMediaProbe, ImageMediaProbe, VideoMediaProbe};
@@ -55,23 +58,27 @@ pub struct AppDeps {
     pub policy: MediaPolicy,
 }
 
-/// Backend connection configs. `kafka` is optional: absent ⇒ the log publisher.
+/// Backend connection configs. `kafka` is optional: absent ⇒ the relay logs
+/// instead of publishing.
 pub struct Backends {
     pub postgres: PostgresConfig,
     pub redis: RedisConfig,
     pub kafka: Option<KafkaClientConfig>,
 }
 
-/// A fully-wired media service. Retains the storage clients (for liveness probes)
-/// and the process + moderation handlers (for the self-spawned consumers).
+/// A fully-wired media service. Retains the storage clients (for liveness probes),
+/// the process + moderation handlers (for the self-spawned consumers), and the
+/// outbox relay (spawned by both binaries — its slot leases make that safe).
 pub struct App {
     pub handler: MediaServiceHandler,
     pub process: Arc<ProcessAssetHandler>,
     pub transcode: Arc<TranscodeAssetHandler>,
     pub apply_moderation: Arc<ApplyModerationHandler>,
+    pub erase_subject_data: Arc<EraseSubjectDataHandler>,
     pub pool: PgPool,
     pub redis: RedisClient,
     pub store: Arc<S3Client>,
+    pub relay: OutboxRelay,
 }
 
 impl App {
@@ -102,15 +109,21 @@ impl App {
             Arc::clone(&deps.cache),
             Arc::clone(&deps.cdn),
         ));
-        MediaServiceHandler::new(issue, commit, delete, process, get, resolve)
+        let export = Arc::new(ExportSubjectDataHandler::new(Arc::clone(&deps.assets)));
+        MediaServiceHandler::new(issue, commit, delete, process, get, resolve, export)
     }
 
-    /// Builds the process + moderation handlers shared between the gRPC handler and
-    /// the consumers (a single instance of each, over the same ports).
+    /// Builds the process + moderation + erasure handlers shared between the gRPC
+    /// handler and the consumers (a single instance of each, over the same ports).
     #[allow(clippy::type_complexity)]
     fn build_workers(
         deps: &AppDeps,
-    ) -> (Arc<ProcessAssetHandler>, Arc<TranscodeAssetHandler>, Arc<ApplyModerationHandler>) {
+    ) -> (
+        Arc<ProcessAssetHandler>,
+        Arc<TranscodeAssetHandler>,
+        Arc<ApplyModerationHandler>,
+        Arc<EraseSubjectDataHandler>,
+    ) {
         let process = Arc::new(ProcessAssetHandler::new(
             Arc::clone(&deps.assets),
             Arc::clone(&deps.scanner),
@@ -135,7 +148,17 @@ impl App {
             Arc::clone(&deps.cache),
             Arc::clone(&deps.publisher),
         ));
-        (process, transcode, apply_moderation)
+        let erase_subject_data = Arc::new(EraseSubjectDataHandler::new(
+            Arc::clone(&deps.assets),
+            Arc::new(DeleteAssetHandler::new(
+                Arc::clone(&deps.assets),
+                Arc::clone(&deps.store),
+                Arc::clone(&deps.cdn),
+                Arc::clone(&deps.cache),
+                Arc::clone(&deps.publisher),
+            )),
+        ));
+        (process, transcode, apply_moderation, erase_subject_data)
     }
 
     /// Builds the concrete adapter graph from config + backend connections.
@@ -151,13 +174,19 @@ impl App {
         // Its HTTP client carries the object-store hard timeout.
         let store = Arc::new(S3Client::new(config.s3)?);
 
-        let publisher: Arc<dyn EventPublisher> = match backends.kafka {
+        // Handlers enqueue into media_outbox (same database as the asset rows);
+        // the relay forwards to the broker.
+        let sink: Arc<dyn OutboxSink> = match backends.kafka {
             Some(cfg) => {
                 let producer = KafkaProducerBuilder::new(ProducerConfig::new(cfg)).build()?;
-                Arc::new(KafkaEventPublisher::new(producer))
+                Arc::new(KafkaOutboxSink::new(producer))
             }
-            None => Arc::new(LogEventPublisher),
+            None => Arc::new(LogOutboxSink),
         };
+        let table = OutboxTable::new(OUTBOX_PREFIX)?;
+        let relay = OutboxRelay::new(pool.clone(), table.clone(), sink, RelayConfig::from_env());
+        let publisher: Arc<dyn EventPublisher> =
+            Arc::new(PgOutboxPublisher::new(PgOutbox::new(table, tx.clone())));
 
         // Lazy connect: dials `moderation` on first use, so a cold start does not
         // require the gate to be up at boot.
@@ -184,9 +213,19 @@ impl App {
             policy: config.policy,
         };
 
-        let (process, transcode, apply_moderation) = App::build_workers(&deps);
+        let (process, transcode, apply_moderation, erase_subject_data) = App::build_workers(&deps);
         let handler = App::compose(deps, Arc::clone(&process));
-        Ok(App { handler, process, transcode, apply_moderation, pool, redis, store })
+        Ok(App {
+            handler,
+            process,
+            transcode,
+            apply_moderation,
+            erase_subject_data,
+            pool,
+            redis,
+            store,
+            relay,
+        })
     }
 }
 
@@ -213,7 +252,7 @@ mod tests {
             publisher: fx.publisher.clone(),
             policy: fx.policy.clone(),
         };
-        let (process, _transcode, _apply) = App::build_workers(&deps);
+        let (process, _transcode, _apply, _erase) = App::build_workers(&deps);
         App::compose(deps, process)
     }
 
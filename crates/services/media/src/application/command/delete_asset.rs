@@ -4,6 +4,7 @@ use chrono::{DateTime, Utc};
 use cqrs::Envelope;
 
 use crate::application::port::{AssetRepository, CdnGateway, DeliveryCache, EventPublisher, ObjectStore};
+use crate::domain::aggregate::Asset;
 use crate::domain::value_object::{AssetId, OwnerId, StorageKey};
 use crate::error::MediaError;
 
@@ -22,8 +23,12 @@ pub struct DeleteOutcome {
 
 /// Deletes an asset: the domain `delete` is attempted **first** so a legal hold
 /// (`LegalHoldActive`, MED-7003) blocks erasure before any byte is touched. On a
-/// real delete it purges every object (master + renditions + staging), invalidates
-/// the CDN, drops the cache, tombstones the row, and emits `AssetDeleted`.
+/// real delete it purges the asset's objects, invalidates the CDN, drops the
+/// cache, tombstones the row, and emits `AssetDeleted`.
+///
+/// The purge is refcount-aware: rendition objects are content-addressed, so
+/// another live asset with the same bytes shares them. They are only removed once
+/// no other asset holds the content hash; the per-asset staging object always is.
 pub struct DeleteAssetHandler {
     assets: Arc<dyn AssetRepository>,
     store: Arc<dyn ObjectStore>,
@@ -60,12 +65,28 @@ impl DeleteAssetHandler {
             return Err(MediaError::AssetNotFound { id: cmd.asset_id.as_str() });
         }
 
+        self.purge(&mut asset, now).await?;
+        Ok(DeleteOutcome { deleted: true })
+    }
+
+    /// The delete itself, shared with the GDPR erasure of a whole owner
+    /// ([`EraseSubjectDataHandler`](super::EraseSubjectDataHandler)). Ownership
+    /// is the caller's check.
+    pub(crate) async fn purge(&self, asset: &mut Asset, now: DateTime<Utc>) -> Result<(), MediaError> {
         // Legal-hold guard fires here, before any byte is purged.
         asset.delete(now)?;
 
-        // Purge bytes: every rendition object + the staging object.
-        let mut keys: Vec<StorageKey> =
-            asset.renditions().iter().map(|r| r.storage_key().clone()).collect();
+        // Purge bytes: the staging object, plus the renditions unless another
+        // asset still holds the same content.
+        let shared = match asset.content_hash() {
+            Some(hash) => self.assets.content_hash_in_use(hash, &asset.id()).await?,
+            None => false,
+        };
+        let mut keys: Vec<StorageKey> = if shared {
+            Vec::new()
+        } else {
+            asset.renditions().iter().map(|r| r.storage_key().clone()).collect()
+        };
         keys.push(StorageKey::staging(asset.id()));
         for key in &keys {
             self.store.delete(key).await?;
@@ -73,18 +94,18 @@ impl DeleteAssetHandler {
         self.cdn.invalidate(&keys).await?;
         self.cache.invalidate(&asset.id()).await?;
 
-        self.assets.save(&asset).await?;
+        self.assets.save(asset).await?;
         for event in asset.drain_events() {
             self.publisher.publish(&event).await?;
         }
-        Ok(DeleteOutcome { deleted: true })
+        Ok(())
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::application::fakes::{t0, Fixture};
+    use crate::application::fakes::{owner, t0, Fixture};
     use crate::domain::value_object::{AssetState, MediaKind};
     use uuid::Uuid;
 
@@ -126,6 +147,27 @@ mod tests {
         assert_eq!(asset.state(), AssetState::Ready);
     }
 
+    #[tokio::test]
+    async fn renditions_shared_with_another_live_asset_are_kept() {
+        let fx = Fixture::new();
+        let hash = "a".repeat(64);
+        let kept = fx.seed_ready_asset(&hash).await;
+        let deleted = fx.seed_ready_asset(&hash).await;
+        let shared_key = fx.assets.find_by_id(&kept).await.unwrap().unwrap().renditions()[0]
+            .storage_key()
+            .clone();
+
+        fx.delete_handler().handle(env(deleted, owner()), t0()).await.unwrap();
+
+        // Only the deleted asset's own staging object was purged.
+        assert_eq!(fx.cdn.invalidated_keys(), vec![StorageKey::staging(deleted).as_str().to_owned()]);
+        assert!(!fx.cdn.invalidated_keys().contains(&shared_key.as_str().to_owned()));
+
+        // Once the last holder goes, the shared renditions go with it.
+        fx.delete_handler().handle(env(kept, owner()), t0()).await.unwrap();
+        assert!(fx.cdn.invalidated_keys().contains(&shared_key.as_str().to_owned()));
+    }
+
     #[tokio::test]
     async fn a_non_owner_cannot_delete_and_sees_not_found() {
         let fx = Fixture::new();
@@ -0,0 +1,135 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use cqrs::Envelope;
+
+use super::DeleteAssetHandler;
+use crate::application::port::AssetRepository;
+use crate::domain::value_object::{AssetId, AssetState, OwnerId};
+use crate::error::MediaError;
+
+/// Page size used to walk an owner's assets during an erasure.
+const ERASURE_PAGE_SIZE: u32 = 100;
+
+/// GDPR erasure of every asset the subject's account owns, driven by account's
+/// `gdpr_deletion_requested`.
+#[derive(Debug, Clone)]
+pub struct EraseSubjectDataCommand {
+    pub owner_id: OwnerId,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ErasureOutcome {
+    /// Assets deleted by this run.
+    pub deleted: u32,
+    /// Assets kept because a legal hold is active on them.
+    pub held: u32,
+}
+
+/// Deletes each of the owner's assets through [`DeleteAssetHandler::purge`], so
+/// bytes another asset still shares survive. Already-deleted assets are skipped,
+/// which makes a redelivered request a no-op. A legal hold overrides erasure
+/// (compliance preservation, as for an owner delete): the held asset is kept and
+/// counted, and the rest of the walk carries on.
+pub struct EraseSubjectDataHandler {
+    assets: Arc<dyn AssetRepository>,
+    delete: Arc<DeleteAssetHandler>,
+}
+
+impl EraseSubjectDataHandler {
+    pub fn new(assets: Arc<dyn AssetRepository>, delete: Arc<DeleteAssetHandler>) -> Self {
+        Self { assets, delete }
+    }
+
+    pub async fn handle(
+        &self,
+        envelope: Envelope<EraseSubjectDataCommand>,
+        now: DateTime<Utc>,
+    ) -> Result<ErasureOutcome, MediaError> {
+        let owner = envelope.payload.owner_id;
+        let mut outcome = ErasureOutcome { deleted: 0, held: 0 };
+        let mut after: Option<AssetId> = None;
+        loop {
+            let page = self.assets.list_by_owner(&owner, after.as_ref(), ERASURE_PAGE_SIZE).await?;
+            let Some(last) = page.last() else { break };
+            after = Some(last.id());
+            let full = page.len() as u32 == ERASURE_PAGE_SIZE;
+
+            for mut asset in page {
+                if asset.state() == AssetState::Deleted {
+                    continue;
+                }
+                match self.delete.purge(&mut asset, now).await {
+                    Ok(()) => outcome.deleted += 1,
+                    Err(MediaError::LegalHoldActive) => outcome.held += 1,
+                    Err(e) => return Err(e),
+                }
+            }
+            if !full {
+                break;
+            }
+        }
+        Ok(outcome)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::fakes::{owner, t0, Fixture};
+    use crate::domain::value_object::MediaKind;
+    use uuid::Uuid;
+
+    fn erase(fx: &Fixture) -> EraseSubjectDataHandler {
+        EraseSubjectDataHandler::new(Arc::clone(&fx.assets) as _, Arc::new(fx.delete_handler()))
+    }
+
+    fn env(owner_id: OwnerId) -> Envelope<EraseSubjectDataCommand> {
+        Envelope::new(Uuid::now_v7(), EraseSubjectDataCommand { owner_id })
+    }
+
+    #[tokio::test]
+    async fn deletes_every_asset_of_the_owner_and_is_idempotent() {
+        let fx = Fixture::new();
+        let (first, _) = fx.ready_asset(MediaKind::PostImage).await;
+        let second = fx.reserve_only(MediaKind::Avatar).await;
+
+        let out = erase(&fx).handle(env(owner()), t0()).await.unwrap();
+        assert_eq!(out, ErasureOutcome { deleted: 2, held: 0 });
+        for id in [first, second] {
+            let asset = fx.assets.find_by_id(&id).await.unwrap().unwrap();
+            assert_eq!(asset.state(), AssetState::Deleted);
+        }
+
+        let again = erase(&fx).handle(env(owner()), t0()).await.unwrap();
+        assert_eq!(again, ErasureOutcome { deleted: 0, held: 0 });
+    }
+
+    #[tokio::test]
+    async fn a_held_asset_is_kept_and_the_rest_erased() {
+        let fx = Fixture::new();
+        let (held, _) = fx.ready_asset(MediaKind::PostImage).await;
+        let other = fx.reserve_only(MediaKind::Avatar).await;
+        {
+            let mut a = fx.assets.find_by_id(&held).await.unwrap().unwrap();
+            a.place_legal_hold(t0());
+            fx.assets.save(&a).await.unwrap();
+        }
+
+        let out = erase(&fx).handle(env(owner()), t0()).await.unwrap();
+        assert_eq!(out, ErasureOutcome { deleted: 1, held: 1 });
+        assert_eq!(fx.assets.find_by_id(&held).await.unwrap().unwrap().state(), AssetState::Ready);
+        assert_eq!(fx.assets.find_by_id(&other).await.unwrap().unwrap().state(), AssetState::Deleted);
+    }
+
+    #[tokio::test]
+    async fn other_owners_assets_are_untouched() {
+        let fx = Fixture::new();
+        let (asset_id, _) = fx.ready_asset(MediaKind::PostImage).await;
+        let stranger = OwnerId::from_uuid(Uuid::from_u128(999));
+
+        let out = erase(&fx).handle(env(stranger), t0()).await.unwrap();
+        assert_eq!(out, ErasureOutcome { deleted: 0, held: 0 });
+        assert_eq!(fx.assets.find_by_id(&asset_id).await.unwrap().unwrap().state(), AssetState::Ready);
+    }
+}
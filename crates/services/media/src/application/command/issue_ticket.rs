@@ -2,6 +2,7 @@ use std::sync::Arc;
 
 use chrono::{DateTime, Utc};
 use cqrs::Envelope;
+use infra_config::FlagContext;
 
 use crate::application::policy::MediaPolicy;
 use crate::application::port::{AssetRepository, ObjectStore, PresignedUpload};
@@ -68,9 +69,10 @@ impl IssueUploadTicketHandler {
         let cmd = envelope.payload;
         let constraints = UploadConstraints::for_kind(cmd.kind);
 
-        // Dedup short-circuit (fork B) — only when enabled and a hash is supplied.
-        if self.policy.dedup_enabled
-            && let Some(sha) = cmd.content_sha256.as_deref()
+        // Dedup short-circuit (fork B) — only when enabled for this owner and a hash
+        // is supplied.
+        if let Some(sha) = cmd.content_sha256.as_deref()
+            && self.policy.dedup.is_enabled_for(&FlagContext::principal(&cmd.owner_id.as_str()))
         {
             let hash = ContentHash::new(sha)?;
             if let Some(existing) = self.assets.find_ready_by_content_hash(&hash).await? {
@@ -122,6 +124,7 @@ impl IssueUploadTicketHandler {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use infra_config::Flag;
     use crate::application::fakes::{t0, Fixture, TEST_HASH};
     use uuid::Uuid;
 
@@ -178,7 +181,7 @@ mod tests {
     #[tokio::test]
     async fn dedup_when_enabled_short_circuits_onto_existing_bytes() {
         let mut fx = Fixture::new();
-        fx.policy.dedup_enabled = true;
+        fx.policy.dedup = Flag::fixed(true);
         let existing = fx.seed_ready_asset(TEST_HASH).await;
 
         let mut c = cmd();
@@ -6,6 +6,7 @@
 pub mod apply_moderation;
 pub mod commit_upload;
 pub mod delete_asset;
+pub mod erase_subject_data;
 pub mod issue_ticket;
 pub mod process_asset;
 pub mod transcode_asset;
@@ -15,6 +16,7 @@ pub use apply_moderation::{
 };
 pub use commit_upload::{CommitUploadCommand, CommitUploadHandler};
 pub use delete_asset::{DeleteAssetCommand, DeleteAssetHandler, DeleteOutcome};
+pub use erase_subject_data::{EraseSubjectDataCommand, EraseSubjectDataHandler, ErasureOutcome};
 pub use issue_ticket::{
     IssueUploadTicketCommand, IssueUploadTicketHandler, IssueUploadTicketOutcome, PreparedUpload,
 };
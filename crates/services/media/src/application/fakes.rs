@@ -44,7 +44,8 @@ pub fn t0() -> DateTime<Utc> {
 /// shares one content hash.
 pub const TEST_HASH: &str = "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855";
 
-fn owner() -> OwnerId {
+/// The owner every scenario builder reserves under.
+pub fn owner() -> OwnerId {
     OwnerId::from_uuid(Uuid::from_u128(7))
 }
 
@@ -97,6 +98,35 @@ impl AssetRepository for InMemoryAssetRepository {
             .find(|a| a.state() == AssetState::Ready && a.content_hash() == Some(hash))
             .cloned())
     }
+
+    async fn content_hash_in_use(
+        &self,
+        hash: &ContentHash,
+        except: &AssetId,
+    ) -> Result<bool, MediaError> {
+        Ok(self.assets.lock().unwrap().values().any(|a| {
+            a.id() != *except && a.state() != AssetState::Deleted && a.content_hash() == Some(hash)
+        }))
+    }
+
+    async fn list_by_owner(
+        &self,
+        owner: &OwnerId,
+        after: Option<&AssetId>,
+        limit: u32,
+    ) -> Result<Vec<Asset>, MediaError> {
+        let mut owned: Vec<Asset> = self
+            .assets
+            .lock()
+            .unwrap()
+            .values()
+            .filter(|a| a.owner_id() == *owner && after.is_none_or(|id| a.id().as_uuid() > id.as_uuid()))
+            .cloned()
+            .collect();
+        owned.sort_by_key(|a| a.id().as_uuid());
+        owned.truncate(limit as usize);
+        Ok(owned)
+    }
 }
 
 // ─── DeliveryCache ───────────────────────────────────────────────────────────────
@@ -557,6 +587,10 @@ impl Fixture {
         super::query::GetAssetHandler::new(Arc::clone(&self.assets) as _)
     }
 
+    pub fn export_subject_data_handler(&self) -> super::query::ExportSubjectDataHandler {
+        super::query::ExportSubjectDataHandler::new(Arc::clone(&self.assets) as _)
+    }
+
     pub fn resolve_delivery_handler(&self) -> super::query::ResolveDeliveryHandler {
         super::query::ResolveDeliveryHandler::new(
             Arc::clone(&self.assets) as _,
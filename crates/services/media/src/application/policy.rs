@@ -1,6 +1,11 @@
 use std::time::Duration as StdDuration;
 
 use chrono::Duration;
+use infra_config::Flag;
+
+/// `[flags]` entry gating [`MediaPolicy::dedup`]; when the deployment has a `[flags]`
+/// section it supersedes `MEDIA_DEDUP_ENABLED`.
+pub const DEDUP_FLAG: &str = "media.upload_dedup";
 
 /// Tunable policy the media handlers run under. Injected at the composition root
 /// (`MediaConfig::from_env`, Phase 5); the domain ships sane defaults so behaviour
@@ -11,11 +16,13 @@ pub struct MediaPolicy {
     pub upload_ticket_ttl: Duration,
     /// Lifetime of a minted signed (private) delivery URL.
     pub signed_url_ttl: Duration,
-    /// **Content-hash dedup gate (fork B).** When false (the default), every upload
-    /// gets a fresh asset + ticket; when true, an incoming upload whose declared
-    /// SHA-256 matches an existing READY asset short-circuits to it. Off until the
-    /// refcount-aware purge path has live integration coverage.
-    pub dedup_enabled: bool,
+    /// **Content-hash dedup gate (fork B).** When off (the default), every upload
+    /// gets a fresh asset + ticket; when on, an incoming upload whose declared
+    /// SHA-256 matches an existing READY asset short-circuits to it. Evaluated per
+    /// owner, so the `media.upload_dedup` flag can roll it out to a share of
+    /// uploaders. Off until the refcount-aware purge path has live integration
+    /// coverage.
+    pub dedup: Flag,
     /// Hard timeout for the pre-publish moderation Screen call — a slow gate must
     /// not wedge the pipeline (fail-closed on elapse for CSAM-class).
     pub screen_timeout: StdDuration,
@@ -28,7 +35,7 @@ impl MediaPolicy {
         Self {
             upload_ticket_ttl: Duration::minutes(15),
             signed_url_ttl: Duration::minutes(5),
-            dedup_enabled: false,
+            dedup: Flag::fixed(false),
             screen_timeout: StdDuration::from_millis(200),
         }
     }
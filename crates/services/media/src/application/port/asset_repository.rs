@@ -5,7 +5,7 @@
 use async_trait::async_trait;
 
 use crate::domain::aggregate::Asset;
-use crate::domain::value_object::{AssetId, ContentHash};
+use crate::domain::value_object::{AssetId, ContentHash, OwnerId};
 use crate::error::MediaError;
 
 #[async_trait]
@@ -22,4 +22,22 @@ pub trait AssetRepository: Send + Sync + 'static {
         &self,
         hash: &ContentHash,
     ) -> Result<Option<Asset>, MediaError>;
+
+    /// Refcount check for the content-addressed rendition objects: whether any
+    /// asset other than `except`, not yet deleted, holds these bytes. Purging the
+    /// shared objects is only safe once this is `false`.
+    async fn content_hash_in_use(
+        &self,
+        hash: &ContentHash,
+        except: &AssetId,
+    ) -> Result<bool, MediaError>;
+
+    /// One page of `owner`'s assets in id (UUIDv7, i.e. creation) order, strictly
+    /// after `after` when given. Backs the data-subject export walk.
+    async fn list_by_owner(
+        &self,
+        owner: &OwnerId,
+        after: Option<&AssetId>,
+        limit: u32,
+    ) -> Result<Vec<Asset>, MediaError>;
 }
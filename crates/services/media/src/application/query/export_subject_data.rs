@@ -0,0 +1,125 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use cqrs::{Envelope, Query, QueryHandler};
+use serde::Serialize;
+
+use crate::application::port::AssetRepository;
+use crate::domain::aggregate::Asset;
+use crate::domain::value_object::{AssetId, Dimensions, OwnerId};
+use crate::error::MediaError;
+
+/// Page size used to walk an owner's assets during an export.
+const EXPORT_PAGE_SIZE: u32 = 100;
+
+/// GDPR Art. 20 export of the metadata of every asset the subject's account
+/// owns. Bytes are not inlined: each entry carries its `asset_id`, which
+/// `ResolveDelivery` turns into a URL while the asset is still deliverable.
+#[derive(Debug, Clone)]
+pub struct ExportSubjectDataQuery {
+    pub owner_id: OwnerId,
+}
+
+impl Query for ExportSubjectDataQuery {
+    type Response = SubjectDataExport;
+}
+
+/// This service's slice of a data-subject export.
+#[derive(Debug, Clone, Serialize)]
+pub struct SubjectDataExport {
+    pub assets: Vec<AssetExport>,
+}
+
+impl SubjectDataExport {
+    pub fn record_count(&self) -> u32 {
+        self.assets.len() as u32
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct AssetExport {
+    pub asset_id:     String,
+    pub kind:         &'static str,
+    pub state:        &'static str,
+    pub mime_type:    Option<String>,
+    pub byte_size:    Option<u64>,
+    pub dimensions:   Option<Dimensions>,
+    pub content_hash: Option<String>,
+    pub created_at:   DateTime<Utc>,
+    pub updated_at:   DateTime<Utc>,
+}
+
+impl From<&Asset> for AssetExport {
+    fn from(a: &Asset) -> Self {
+        Self {
+            asset_id:     a.id().as_str(),
+            kind:         a.kind().as_str(),
+            state:        a.state().as_str(),
+            mime_type:    a.mime_type().map(|m| m.as_str().to_owned()),
+            byte_size:    a.byte_size(),
+            dimensions:   a.dimensions(),
+            content_hash: a.content_hash().map(|h| h.as_str().to_owned()),
+            created_at:   a.created_at(),
+            updated_at:   a.updated_at(),
+        }
+    }
+}
+
+pub struct ExportSubjectDataHandler {
+    assets: Arc<dyn AssetRepository>,
+}
+
+impl ExportSubjectDataHandler {
+    pub fn new(assets: Arc<dyn AssetRepository>) -> Self {
+        Self { assets }
+    }
+}
+
+impl QueryHandler<ExportSubjectDataQuery> for ExportSubjectDataHandler {
+    type Error = MediaError;
+
+    async fn handle(
+        &self,
+        envelope: Envelope<ExportSubjectDataQuery>,
+    ) -> Result<SubjectDataExport, Self::Error> {
+        let owner = envelope.payload.owner_id;
+        let mut assets = Vec::new();
+        let mut after: Option<AssetId> = None;
+        loop {
+            let page = self.assets.list_by_owner(&owner, after.as_ref(), EXPORT_PAGE_SIZE).await?;
+            let full = page.len() == EXPORT_PAGE_SIZE as usize;
+            after = page.last().map(Asset::id);
+            assets.extend(page.iter().map(AssetExport::from));
+            if !full {
+                break;
+            }
+        }
+        Ok(SubjectDataExport { assets })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::fakes::Fixture;
+    use crate::domain::value_object::MediaKind;
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn exports_every_asset_of_the_owner_only() {
+        let fx = Fixture::new();
+        let (first, owner) = fx.ready_asset(MediaKind::PostImage).await;
+        let second = fx.reserve_only(MediaKind::Avatar).await;
+
+        let env = Envelope::new(Uuid::now_v7(), ExportSubjectDataQuery { owner_id: owner });
+        let export = fx.export_subject_data_handler().handle(env).await.unwrap();
+        assert_eq!(export.record_count(), 2);
+        let ids: Vec<_> = export.assets.iter().map(|a| a.asset_id.clone()).collect();
+        assert!(ids.contains(&first.as_str()) && ids.contains(&second.as_str()));
+
+        let stranger = OwnerId::from_uuid(Uuid::from_u128(99));
+        let env = Envelope::new(Uuid::now_v7(), ExportSubjectDataQuery { owner_id: stranger });
+        let export = fx.export_subject_data_handler().handle(env).await.unwrap();
+        assert_eq!(export.record_count(), 0);
+    }
+}
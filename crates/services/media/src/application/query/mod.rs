@@ -1,11 +1,14 @@
 //! Read use-cases — `cqrs::QueryHandler` implementations that ride the query bus.
 //! `GetAsset` returns the metadata SoR; `ResolveDelivery` brokers CDN/signed URLs
 //! and fails OPEN (a not-ready or partially-resolvable asset yields a placeholder
-//! marked `degraded`, never an error on the read path).
+//! marked `degraded`, never an error on the read path). `ExportSubjectData` walks an
+//! owner's assets for account's GDPR data-export pipeline.
 
+pub mod export_subject_data;
 pub mod get_asset;
 pub mod resolve_delivery;
 
+pub use export_subject_data::{ExportSubjectDataHandler, ExportSubjectDataQuery};
 pub use get_asset::{GetAssetHandler, GetAssetQuery};
 pub use resolve_delivery::{
     DeliveredMediaView, DeliveredRenditionView, ResolveDeliveryHandler, ResolveDeliveryQuery,
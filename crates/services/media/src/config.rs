@@ -6,6 +6,7 @@
 use std::time::Duration as StdDuration;
 
 use chrono::Duration;
+use infra_config::Flag;
 
 use crate::application::MediaPolicy;
 use crate::infrastructure::store::S3Config;
@@ -18,6 +19,9 @@ pub struct MediaConfig {
     pub cdn_base_url: String,
     /// gRPC endpoint of `moderation` (the pre-publish Screen gate).
     pub screen_endpoint: String,
+    /// gRPC endpoint of the `account` service, where completed GDPR erasures are
+    /// confirmed (`ConfirmSubjectErasure`).
+    pub account_endpoint: String,
     pub policy: MediaPolicy,
 }
 
@@ -26,7 +30,7 @@ impl MediaConfig {
         let policy = MediaPolicy {
             upload_ticket_ttl: Duration::seconds(env_u64("MEDIA_UPLOAD_TICKET_TTL_SECS", 900) as i64),
             signed_url_ttl: Duration::seconds(env_u64("MEDIA_SIGNED_URL_TTL_SECS", 300) as i64),
-            dedup_enabled: env_bool("MEDIA_DEDUP_ENABLED", false),
+            dedup: Flag::fixed(env_bool("MEDIA_DEDUP_ENABLED", false)),
             screen_timeout: StdDuration::from_millis(env_u64("MEDIA_SCREEN_TIMEOUT_MS", 200)),
         };
         let endpoint = env_or("MEDIA_OBJECT_STORE_ENDPOINT", "http://localhost:9000");
@@ -47,6 +51,7 @@ impl MediaConfig {
             s3,
             cdn_base_url: env_or("MEDIA_CDN_BASE_URL", "http://localhost:9000/media"),
             screen_endpoint: env_or("MEDIA_SCREEN_GRPC_ENDPOINT", "http://localhost:50061"),
+            account_endpoint: env_or("MEDIA_ACCOUNT_GRPC_ENDPOINT", "http://localhost:50059"),
             policy,
         }
     }
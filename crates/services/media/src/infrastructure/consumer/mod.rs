@@ -17,6 +17,14 @@ pub use moderation_consumer::run_moderation_consumer;
 pub use process_consumer::run_process_consumer;
 pub use transcode_consumer::run_transcode_consumer;
 
+use chrono::Utc;
+use cqrs::Envelope;
+use subject_erasure::{DeletionRequest, SubjectEraser};
+use tracing::warn;
+use uuid::Uuid;
+
+use crate::application::command::{EraseSubjectDataCommand, EraseSubjectDataHandler};
+use crate::domain::value_object::OwnerId;
 use crate::error::MediaError;
 
 /// Lets the at-least-once runner classify a failure: delegate to the error's own
@@ -28,3 +36,20 @@ impl transport::kafka::consumer::ClassifyError for MediaError {
         <Self as error::AppError>::is_retryable(self)
     }
 }
+
+/// Media's erase step on the shared [`subject_erasure`] consumer. Assets are
+/// owned by the account, so the request's `profile_ids` are not needed; an
+/// account id that is not a valid owner id is poison.
+impl SubjectEraser for EraseSubjectDataHandler {
+    type Error = MediaError;
+
+    async fn erase(&self, request: &DeletionRequest) -> Result<(), MediaError> {
+        let owner_id = OwnerId::try_from(request.account_id.as_str())?;
+        let command = EraseSubjectDataCommand { owner_id };
+        let outcome = self.handle(Envelope::new(Uuid::now_v7(), command), Utc::now()).await?;
+        if outcome.held > 0 {
+            warn!(account_id = %request.account_id, held = outcome.held, "assets under legal hold kept");
+        }
+        Ok(())
+    }
+}
@@ -18,7 +18,7 @@ use crate::error::MediaError;
 /// snake_case variant name (e.g. `enforcement_applied`).
 #[derive(Debug, Deserialize)]
 #[serde(tag = "type", rename_all = "snake_case")]
-enum ModerationWireEvent {
+pub enum ModerationWireEvent {
     EnforcementApplied { subject: WireSubject, action: String },
     EnforcementReversed { subject: WireSubject },
     #[serde(other)]
@@ -26,7 +26,7 @@ enum ModerationWireEvent {
 }
 
 #[derive(Debug, Deserialize)]
-struct WireSubject {
+pub struct WireSubject {
     entity_type: String,
     entity_id: String,
 }
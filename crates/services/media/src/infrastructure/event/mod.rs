@@ -3,13 +3,29 @@
 //! Every domain event is keyed by `asset_id` so an asset's lifecycle keeps
 //! per-partition order — `AssetReady` can never be delivered ahead of the
 //! `AssetUploaded` it follows, and `AssetDeleted` is always last. The `event_type`
-//! header carries the dotted routing key.
+//! header carries the dotted routing key. Handlers enqueue into `media_outbox`
+//! ([`PgOutboxPublisher`]); the shared `outbox` relay publishes to Kafka.
 
-pub mod kafka_event_publisher;
 pub mod log_event_publisher;
+pub mod pg_outbox_publisher;
 
-pub use kafka_event_publisher::KafkaEventPublisher;
 pub use log_event_publisher::LogEventPublisher;
+pub use pg_outbox_publisher::PgOutboxPublisher;
 
 /// The single Kafka topic every media domain event is published to.
 pub const TOPIC_MEDIA_EVENTS: &str = "media.v1.events";
+
+/// Prefix of the `media_outbox` table (and its `_lease` / `_member` companions).
+pub const OUTBOX_PREFIX: &str = "media";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// `media.v1.events` carries exactly the payload registered for it in event-topology.
+    #[test]
+    fn media_events_conform_to_the_registered_schema() {
+        let violations = event_topology::conformance::producer_violations::<crate::domain::event::DomainEvent>(TOPIC_MEDIA_EVENTS);
+        assert!(violations.is_empty(), "{violations:#?}");
+    }
+}
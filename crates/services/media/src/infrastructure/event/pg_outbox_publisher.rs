@@ -0,0 +1,43 @@
+//! Outbox-backed [`EventPublisher`]: enqueue-to-Postgres instead of
+//! publish-to-broker.
+//!
+//! Handlers still persist the asset first and publish after, but the "publish"
+//! now lands in `media_outbox` — the same database the asset row was just
+//! written to — and the shared [`outbox::OutboxRelay`] forwards it to Kafka. A
+//! broker outage no longer fails a committed upload, and the Plane B pipeline
+//! (which consumes `media.v1.events`) no longer misses an `AssetUploaded` whose
+//! direct publish failed after the commit.
+
+use async_trait::async_trait;
+use outbox::{OutboxError, OutboxMessage, PgOutbox};
+
+use crate::application::port::EventPublisher;
+use crate::domain::event::DomainEvent;
+use crate::error::MediaError;
+
+use super::TOPIC_MEDIA_EVENTS;
+
+pub struct PgOutboxPublisher {
+    outbox: PgOutbox,
+}
+
+impl PgOutboxPublisher {
+    pub fn new(outbox: PgOutbox) -> Self {
+        Self { outbox }
+    }
+}
+
+fn enqueue_err(e: OutboxError) -> MediaError {
+    MediaError::EventPublishFailed(format!("outbox enqueue: {e}"))
+}
+
+#[async_trait]
+impl EventPublisher for PgOutboxPublisher {
+    async fn publish(&self, event: &DomainEvent) -> Result<(), MediaError> {
+        let key = event.asset_id().as_str();
+        let message = OutboxMessage::new(TOPIC_MEDIA_EVENTS, key.clone(), event.event_type(), event)
+            .map_err(enqueue_err)?
+            .with_header("asset_id", key);
+        self.outbox.enqueue_detached(&[message]).await.map_err(enqueue_err)
+    }
+}
@@ -17,7 +17,8 @@ use crate::application::command::{
     ProcessAssetCommand, ProcessAssetHandler,
 };
 use crate::application::query::{
-    DeliveredMediaView, GetAssetHandler, GetAssetQuery, ResolveDeliveryHandler, ResolveDeliveryQuery,
+    DeliveredMediaView, ExportSubjectDataHandler, ExportSubjectDataQuery, GetAssetHandler,
+    GetAssetQuery, ResolveDeliveryHandler, ResolveDeliveryQuery,
 };
 use crate::domain::aggregate::{Asset, Rendition};
 use crate::domain::value_object::{
@@ -37,6 +38,7 @@ pub struct MediaServiceHandler {
     process: Arc<ProcessAssetHandler>,
     get: Arc<GetAssetHandler>,
     resolve: Arc<ResolveDeliveryHandler>,
+    export: Arc<ExportSubjectDataHandler>,
 }
 
 impl MediaServiceHandler {
@@ -47,8 +49,9 @@ impl MediaServiceHandler {
         process: Arc<ProcessAssetHandler>,
         get: Arc<GetAssetHandler>,
         resolve: Arc<ResolveDeliveryHandler>,
+        export: Arc<ExportSubjectDataHandler>,
     ) -> Self {
-        Self { issue, commit, delete, process, get, resolve }
+        Self { issue, commit, delete, process, get, resolve, export }
     }
 
     pub async fn issue_upload_ticket(
@@ -123,6 +126,28 @@ impl MediaServiceHandler {
         Ok(Response::new(proto::GetAssetResponse { asset: Some(asset_to_proto(&asset)) }))
     }
 
+    pub async fn export_subject_data(
+        &self,
+        request: Request<proto::ExportSubjectDataRequest>,
+    ) -> Result<Response<proto::ExportSubjectDataResponse>, Status> {
+        let req = request.into_inner();
+        // Assets are owned by the account, not a profile.
+        let query = ExportSubjectDataQuery {
+            owner_id: OwnerId::try_from(req.account_id.as_str()).map_err(to_status)?,
+        };
+        let export = self
+            .export
+            .handle(Envelope::new(Uuid::now_v7(), query))
+            .await
+            .map_err(to_status)?;
+        let document = serde_json::to_vec(&export)
+            .map_err(|e| Status::internal(format!("encode export document: {e}")))?;
+        Ok(Response::new(proto::ExportSubjectDataResponse {
+            document,
+            record_count: export.record_count(),
+        }))
+    }
+
     pub async fn delete_asset(
         &self,
         request: Request<proto::DeleteAssetRequest>,
@@ -37,6 +37,13 @@ impl MediaService for MediaServiceHandler {
         self.get_asset(request).await
     }
 
+    async fn export_subject_data(
+        &self,
+        request: Request<proto::ExportSubjectDataRequest>,
+    ) -> Result<Response<proto::ExportSubjectDataResponse>, Status> {
+        self.export_subject_data(request).await
+    }
+
     async fn delete_asset(
         &self,
         request: Request<proto::DeleteAssetRequest>,
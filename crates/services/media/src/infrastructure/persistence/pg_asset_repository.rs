@@ -4,7 +4,7 @@ use sqlx::types::Json;
 
 use crate::application::port::AssetRepository;
 use crate::domain::aggregate::Asset;
-use crate::domain::value_object::{AssetId, ContentHash};
+use crate::domain::value_object::{AssetId, ContentHash, OwnerId};
 use crate::error::MediaError;
 
 use super::storage_err;
@@ -78,4 +78,43 @@ impl AssetRepository for PgAssetRepository {
         .map_err(storage_err)?;
         Ok(row.map(|r| r.doc.0))
     }
+
+    async fn content_hash_in_use(
+        &self,
+        hash: &ContentHash,
+        except: &AssetId,
+    ) -> Result<bool, MediaError> {
+        // Served by idx_assets_content_hash.
+        let in_use: bool = sqlx::query_scalar(
+            "SELECT EXISTS (SELECT 1 FROM assets \
+             WHERE content_hash = $1 AND id <> $2 AND state <> 'deleted')",
+        )
+        .bind(hash.as_str())
+        .bind(except.as_uuid())
+        .fetch_one(self.tx.pool())
+        .await
+        .map_err(storage_err)?;
+        Ok(in_use)
+    }
+
+    async fn list_by_owner(
+        &self,
+        owner: &OwnerId,
+        after: Option<&AssetId>,
+        limit: u32,
+    ) -> Result<Vec<Asset>, MediaError> {
+        // Served by idx_assets_owner; `after` is NULL on the first page.
+        let rows = sqlx::query_as::<_, AssetDocRow>(
+            "SELECT doc FROM assets \
+             WHERE owner_id = $1 AND ($2::uuid IS NULL OR id > $2) \
+             ORDER BY id LIMIT $3",
+        )
+        .bind(owner.as_uuid())
+        .bind(after.map(|a| a.as_uuid()))
+        .bind(i64::from(limit))
+        .fetch_all(self.tx.pool())
+        .await
+        .map_err(storage_err)?;
+        Ok(rows.into_iter().map(|r| r.doc.0).collect())
+    }
 }
@@ -1,12 +1,16 @@
 //! Adapts the media composition root to the fleet [`service_runtime::Service`]
 //! contract. Maps env → config, defers to [`App::build`], self-spawns the inbound
-//! consumers (the Plane B processing worker + the moderation takedown consumer),
-//! registers the concrete tonic service, and reports Postgres + Redis + object-store
-//! liveness.
+//! consumers (the Plane B processing worker, the moderation takedown consumer and
+//! the GDPR subject-erasure consumer), registers the concrete tonic service, and
+//! reports Postgres + Redis + object-store liveness.
 //!
 //! The processing consumer is the worker role; in production it can be scaled as a
 //! separate deployment of this same image (more replicas absorbing transcode load
 //! without touching the control-plane RPC latency).
+//!
+//! The erasure consumer is the shared [`subject_erasure`] one; it confirms each
+//! erasure to account over `ConfirmSubjectErasure`, on a channel resolved from
+//! the `account` resilience binding.
 
 use std::sync::Arc;
 use std::time::Duration;
@@ -15,17 +19,20 @@ use anyhow::Context;
 use async_trait::async_trait;
 use postgres_storage::PostgresConfig;
 use redis_storage::RedisConfig;
-use service_runtime::{FnProbe, HealthProbe, InfraRegistry, Service};
+use service_runtime::{AccessPolicy, FnProbe, HealthProbe, InfraRegistry, Service};
+use subject_erasure::{run_subject_erasure_consumer, ErasureReporter, GrpcErasureReporter};
 use tonic::service::RoutesBuilder;
 use tonic_reflection::server::Builder as ReflectionBuilder;
+use transport::grpc::client::{GrpcClientBuilder, GrpcClientConfig};
 use transport::kafka::config::{ConsumerConfig, KafkaClientConfig, ProducerConfig};
 use transport::kafka::consumer::{KafkaConsumerBuilder, KafkaConsumerHandle};
 use transport::kafka::producer::{KafkaProducerBuilder, KafkaProducerHandle};
 
 use crate::app::{App, Backends};
 use crate::application::command::{
-    ApplyModerationHandler, ProcessAssetHandler, TranscodeAssetHandler,
+    ApplyModerationHandler, EraseSubjectDataHandler, ProcessAssetHandler, TranscodeAssetHandler,
 };
+use crate::application::policy::DEDUP_FLAG;
 use crate::config::MediaConfig;
 use crate::infrastructure::consumer::{
     run_moderation_consumer, run_process_consumer, run_transcode_consumer,
@@ -39,6 +46,12 @@ const PROCESS_GROUP: &str = "media-processor";
 const TRANSCODE_GROUP: &str = "media-transcoder";
 const MODERATION_TOPIC: &str = "moderation.v1.events";
 const MODERATION_GROUP: &str = "media-moderation-consumer";
+const ACCOUNT_TOPIC: &str = "account.v1.events";
+const SUBJECT_ERASURE_GROUP: &str = "media-subject-erasure";
+/// Media's name in account's erasure participant list.
+const ERASURE_PARTICIPANT: &str = "media";
+/// Resilience binding of the erasure confirmations sent to account.
+const ACCOUNT_DEPENDENCY: &str = "account";
 /// Backoff before respawning a consumer after the runner returns.
 const CONSUMER_RESPAWN_BACKOFF: Duration = Duration::from_secs(5);
 
@@ -55,21 +68,40 @@ impl Service for MediaService {
     const VERSION: &'static str = env!("CARGO_PKG_VERSION");
     const GRPC_SERVICE_NAME: &'static str = <MediaServer as tonic::server::NamedService>::NAME;
 
-    async fn build(_infra: Arc<InfraRegistry>) -> anyhow::Result<Self> {
-        let config = MediaConfig::from_env();
+    async fn build(infra: Arc<InfraRegistry>) -> anyhow::Result<Self> {
+        let mut config = MediaConfig::from_env();
+        // Upload dedup follows the hot-reloaded `[flags]` entry when the deployment
+        // has one, else the boot-time env switch.
+        if let Some(flags) = infra.flags() {
+            config.policy.dedup = flags.flag(DEDUP_FLAG);
+        }
         let backends = Backends {
             postgres: PostgresConfig::from_env(),
             redis: RedisConfig::from_env(),
             kafka: Some(KafkaClientConfig::from_env()),
         };
+        // The channel connects lazily; account being down only delays the
+        // confirmation (the consumer retries).
+        let account = GrpcClientBuilder::new(
+            GrpcClientConfig::new(config.account_endpoint.clone())
+                .with_dependency(ACCOUNT_DEPENDENCY),
+        )
+        .build_from_registry_lazy(&infra.resilience())
+        .map_err(|e| anyhow::anyhow!("media account channel: {e}"))?;
 
         let app = App::build(config, backends)
             .await
             .map_err(|e| anyhow::anyhow!("media app build: {e}"))?;
 
-        // Plane B pipeline (off media.v1.events) + moderation takedowns.
+        // Plane B pipeline (off media.v1.events) + moderation takedowns + GDPR
+        // erasure (off account.v1.events).
         spawn_process_consumer(Arc::clone(&app.process));
         spawn_moderation_consumer(Arc::clone(&app.apply_moderation));
+        spawn_subject_erasure_consumer(
+            Arc::clone(&app.erase_subject_data),
+            Arc::new(GrpcErasureReporter::new(account, ERASURE_PARTICIPANT)),
+        );
+        tokio::spawn(app.relay.clone().run());
 
         Ok(Self { app })
     }
@@ -78,6 +110,20 @@ impl Service for MediaService {
         liveness_probes(&self.app)
     }
 
+    fn access_policy(&self) -> AccessPolicy {
+        AccessPolicy::for_service(Self::GRPC_SERVICE_NAME)
+            .authenticated("IssueUploadTicket")
+            .authenticated("CommitUpload")
+            .authenticated("AbortUpload")
+            .authenticated("GetAsset")
+            .authenticated("DeleteAsset")
+            .authenticated("ResolveDelivery")
+            .authenticated("BatchResolveDelivery")
+            .require("Reprocess", "media:reprocess")
+            // Account's GDPR data-export pipeline.
+            .internal("ExportSubjectData")
+    }
+
     fn register(self, routes: &mut RoutesBuilder) -> anyhow::Result<()> {
         let reflection = ReflectionBuilder::configure()
             .register_encoded_file_descriptor_set(FILE_DESCRIPTOR_SET)
@@ -118,6 +164,9 @@ impl Service for MediaWorkerService {
 
         // The only consumer this binary runs: video AssetUploaded → HLS transcode.
         spawn_transcode_consumer(Arc::clone(&app.transcode));
+        // Transcode results are enqueued into the same outbox; this binary drains
+        // it too, so rendition events do not wait on media-server's availability.
+        tokio::spawn(app.relay.clone().run());
 
         Ok(Self { app })
     }
@@ -185,6 +234,34 @@ fn spawn_moderation_consumer(handler: Arc<ApplyModerationHandler>) {
     });
 }
 
+/// Spawns the supervised GDPR subject-erasure consumer.
+fn spawn_subject_erasure_consumer(
+    handler: Arc<EraseSubjectDataHandler>,
+    reporter: Arc<dyn ErasureReporter>,
+) {
+    tokio::spawn(async move {
+        loop {
+            match build_consumer(ACCOUNT_TOPIC, SUBJECT_ERASURE_GROUP) {
+                Ok((consumer, producer)) => {
+                    run_subject_erasure_consumer(
+                        ERASURE_PARTICIPANT,
+                        consumer,
+                        Arc::clone(&handler),
+                        Arc::clone(&reporter),
+                        producer,
+                    )
+                    .await;
+                    tracing::warn!("media subject-erasure consumer exited; respawning after backoff");
+                }
+                Err(error) => {
+                    tracing::error!(%error, "failed to build subject-erasure consumer; retrying")
+                }
+            }
+            tokio::time::sleep(CONSUMER_RESPAWN_BACKOFF).await;
+        }
+    });
+}
+
 /// Spawns the supervised video transcode consumer (the `media-worker` role).
 fn spawn_transcode_consumer(handler: Arc<TranscodeAssetHandler>) {
     tokio::spawn(async move {
@@ -11,6 +11,7 @@ use async_trait::async_trait;
 use chrono::Utc;
 use cqrs::{Envelope, QueryHandler};
 use image::{DynamicImage, ImageFormat, Rgb, RgbImage};
+use infra_config::Flag;
 use sha2::{Digest, Sha256};
 use uuid::Uuid;
 
@@ -147,7 +148,7 @@ impl Harness {
 
         let policy = MediaPolicy::standard();
         let mut dedup_policy = MediaPolicy::standard();
-        dedup_policy.dedup_enabled = true;
+        dedup_policy.dedup = Flag::fixed(true);
 
         let assets: Arc<PgAssetRepository> = Arc::new(PgAssetRepository::new(tx));
         let cache = Arc::new(RedisDeliveryCache::new(redis));
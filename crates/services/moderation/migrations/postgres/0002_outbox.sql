@@ -0,0 +1,35 @@
+-- Transactional outbox for moderation.v1.events (shared `outbox` crate schema —
+-- a verbatim rendering of OutboxTable::new("moderation").ddl()).
+--
+-- WHY: a decision or penalty committed here and was then published straight to
+-- Kafka. When the publish failed, the RPC failed too, yet the enforcement had
+-- already committed. Plane B never heard about it, and the hidden post or the
+-- lifted restriction stayed stale. Events are now enqueued here (same database
+-- as the decision rows) and relayed to Kafka.
+CREATE TABLE IF NOT EXISTS moderation_outbox (
+    id            UUID        PRIMARY KEY,
+    seq           BIGSERIAL,
+    topic         TEXT        NOT NULL,
+    aggregate_key TEXT        NOT NULL,
+    slot          INT         NOT NULL,
+    event_type    TEXT        NOT NULL,
+    payload       JSONB       NOT NULL,
+    headers       JSONB       NOT NULL DEFAULT '{}',
+    attempts      INT         NOT NULL DEFAULT 0,
+    last_error    TEXT,
+    created_at    TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+CREATE INDEX IF NOT EXISTS moderation_outbox_drain_order
+    ON moderation_outbox (slot, seq);
+
+CREATE TABLE IF NOT EXISTS moderation_outbox_lease (
+    slot       INT         PRIMARY KEY,
+    owner      TEXT        NOT NULL DEFAULT '',
+    expires_at TIMESTAMPTZ NOT NULL DEFAULT 'epoch'
+);
+
+CREATE TABLE IF NOT EXISTS moderation_outbox_member (
+    owner      TEXT        PRIMARY KEY,
+    expires_at TIMESTAMPTZ NOT NULL
+);
@@ -9,6 +9,9 @@
 
 use std::sync::Arc;
 
+use outbox::{
+    KafkaOutboxSink, LogOutboxSink, OutboxRelay, OutboxSink, OutboxTable, PgOutbox, RelayConfig,
+};
 use postgres_storage::{PgPoolBuilder, PostgresConfig, TransactionManager};
 use redis_storage::{RedisClient, RedisClientBuilder, RedisConfig};
 use scylla_storage::{ScyllaClient, ScyllaConfig, ScyllaSessionBuilder};
@@ -34,9 +37,7 @@ use crate::config::ModerationConfig;
 use crate::infrastructure::cache::{RedisEnforcementProjection, RedisScreenCorpus};
 use crate::infrastructure::classifier::LogClassifierGateway;
 use crate::infrastructure::directory::GrpcAccountDirectory;
-use crate::infrastructure::event::{
-    FanoutEventPublisher, KafkaEventPublisher, LogEventPublisher,
-};
+use crate::infrastructure::event::{FanoutEventPublisher, PgOutboxPublisher, OUTBOX_PREFIX};
 use crate::infrastructure::grpc::ModerationServiceHandler;
 use crate::infrastructure::history::ScyllaEvidenceHistory;
 use crate::infrastructure::persistence::{
@@ -59,7 +60,8 @@ pub struct AppDeps {
     pub policy: ModerationPolicy,
 }
 
-/// Backend connection configs. `kafka` is optional: absent ⇒ the log publisher.
+/// Backend connection configs. `kafka` is optional: absent ⇒ the relay logs
+/// instead of publishing.
 pub struct Backends {
     pub postgres: PostgresConfig,
     pub scylla: ScyllaConfig,
@@ -69,7 +71,7 @@ pub struct Backends {
 
 /// A fully-wired moderation service. Retains the storage clients so the runtime
 /// builds liveness probes over the same connections, and the ingestion handlers
-/// so the service self-spawns the Plane A consumers.
+/// so the service self-spawns the Plane A consumers (and the outbox relay).
 pub struct App {
     pub handler: ModerationServiceHandler,
     pub ingest_report: Arc<IngestReportHandler>,
@@ -77,6 +79,7 @@ pub struct App {
     pub pool: PgPool,
     pub scylla: Arc<ScyllaClient>,
     pub redis: RedisClient,
+    pub relay: OutboxRelay,
 }
 
 impl App {
@@ -149,15 +152,20 @@ impl App {
         let scylla = Arc::new(ScyllaSessionBuilder::new(backends.scylla).build().await?);
         let redis = RedisClientBuilder::new(backends.redis).build().await?;
 
-        // Kafka is the authoritative Plane B notification; the Scylla evidence
-        // history is a best-effort audit sink composed alongside it.
-        let primary: Arc<dyn EventPublisher> = match backends.kafka {
+        // The outbox (relayed to Kafka) is the authoritative Plane B
+        // notification; the Scylla evidence history is a best-effort audit sink
+        // composed alongside it.
+        let sink: Arc<dyn OutboxSink> = match backends.kafka {
             Some(cfg) => {
                 let producer = KafkaProducerBuilder::new(ProducerConfig::new(cfg)).build()?;
-                Arc::new(KafkaEventPublisher::new(producer))
+                Arc::new(KafkaOutboxSink::new(producer))
             }
-            None => Arc::new(LogEventPublisher),
+            None => Arc::new(LogOutboxSink),
         };
+        let table = OutboxTable::new(OUTBOX_PREFIX)?;
+        let relay = OutboxRelay::new(pool.clone(), table.clone(), sink, RelayConfig::from_env());
+        let primary: Arc<dyn EventPublisher> =
+            Arc::new(PgOutboxPublisher::new(PgOutbox::new(table, tx.clone())));
         let history: Arc<dyn EventPublisher> = Arc::new(ScyllaEvidenceHistory::new(scylla.clone()));
         let publisher: Arc<dyn EventPublisher> =
             Arc::new(FanoutEventPublisher::new(primary, vec![history]));
@@ -197,7 +205,7 @@ impl App {
         ));
 
         let handler = App::compose(deps);
-        Ok(App { handler, ingest_report, ingest_signal, pool, scylla, redis })
+        Ok(App { handler, ingest_report, ingest_signal, pool, scylla, redis, relay })
     }
 }
 
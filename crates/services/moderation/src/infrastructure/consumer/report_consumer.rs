@@ -15,7 +15,7 @@ use crate::error::ModerationError;
 
 /// Payload on `moderation.reports` (a user-submitted abuse report).
 #[derive(Debug, Deserialize)]
-struct ReportEvent {
+pub struct ReportEvent {
     reporter_id: String,
     entity_type: String,
     entity_id: String,
@@ -15,7 +15,7 @@ use crate::error::ModerationError;
 
 /// Payload on `moderation.signals` (a classifier verdict).
 #[derive(Debug, Deserialize)]
-struct SignalEvent {
+pub struct SignalEvent {
     entity_type: String,
     entity_id: String,
     actor_id: String,
@@ -7,8 +7,8 @@ use crate::application::port::EventPublisher;
 use crate::domain::event::DomainEvent;
 use crate::error::ModerationError;
 
-/// Fans a domain event out to one **authoritative** sink (Kafka — the Plane B
-/// denormalization notification, whose failure must surface) and zero or more
+/// Fans a domain event out to one **authoritative** sink (the Kafka-bound outbox —
+/// the Plane B denormalization notification, whose failure must surface) and zero or more
 /// **best-effort** sinks (the Scylla evidence history — an audit projection whose
 /// transient failure must not fail the moderation operation, only be logged).
 pub struct FanoutEventPublisher {
@@ -3,14 +3,31 @@
 //! Every domain event is keyed by `actor_id` so an actor's moderation events keep
 //! per-partition order — a reversal can never be delivered ahead of the
 //! application it reverses. The `event_type` header carries the dotted routing key.
+//! Events are enqueued into `moderation_outbox` ([`PgOutboxPublisher`]); the
+//! shared `outbox` relay publishes them to Kafka.
 
 pub mod fanout_event_publisher;
-pub mod kafka_event_publisher;
 pub mod log_event_publisher;
+pub mod pg_outbox_publisher;
 
 pub use fanout_event_publisher::FanoutEventPublisher;
-pub use kafka_event_publisher::KafkaEventPublisher;
 pub use log_event_publisher::LogEventPublisher;
+pub use pg_outbox_publisher::PgOutboxPublisher;
 
 /// The single Kafka topic every moderation domain event is published to.
 pub const TOPIC_MODERATION_EVENTS: &str = "moderation.v1.events";
+
+/// Prefix of the `moderation_outbox` table (and its `_lease` / `_member` companions).
+pub const OUTBOX_PREFIX: &str = "moderation";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// `moderation.v1.events` carries exactly the payload registered for it in event-topology.
+    #[test]
+    fn moderation_events_conform_to_the_registered_schema() {
+        let violations = event_topology::conformance::producer_violations::<crate::domain::event::DomainEvent>(TOPIC_MODERATION_EVENTS);
+        assert!(violations.is_empty(), "{violations:#?}");
+    }
+}
@@ -0,0 +1,43 @@
+//! Outbox-backed [`EventPublisher`] — the authoritative sink behind the fan-out.
+//!
+//! Decisions, penalties and appeal outcomes are committed to Postgres first; the
+//! event that tells Plane B to re-denormalize (hide the post, lift the
+//! restriction) is then enqueued into `moderation_outbox` in the same database
+//! and forwarded by the shared [`outbox::OutboxRelay`]. A broker outage used to
+//! fail the RPC *after* the enforcement had committed, leaving the projection
+//! stale until someone replayed it by hand.
+
+use async_trait::async_trait;
+use outbox::{OutboxError, OutboxMessage, PgOutbox};
+
+use crate::application::port::EventPublisher;
+use crate::domain::event::DomainEvent;
+use crate::error::ModerationError;
+
+use super::TOPIC_MODERATION_EVENTS;
+
+pub struct PgOutboxPublisher {
+    outbox: PgOutbox,
+}
+
+impl PgOutboxPublisher {
+    pub fn new(outbox: PgOutbox) -> Self {
+        Self { outbox }
+    }
+}
+
+fn enqueue_err(e: OutboxError) -> ModerationError {
+    ModerationError::EventPublishFailed(format!("outbox enqueue: {e}"))
+}
+
+#[async_trait]
+impl EventPublisher for PgOutboxPublisher {
+    async fn publish(&self, event: &DomainEvent) -> Result<(), ModerationError> {
+        let key = event.actor_id().as_str();
+        let message =
+            OutboxMessage::new(TOPIC_MODERATION_EVENTS, key.clone(), event.event_type(), event)
+                .map_err(enqueue_err)?
+                .with_header("actor_id", key);
+        self.outbox.enqueue_detached(&[message]).await.map_err(enqueue_err)
+    }
+}
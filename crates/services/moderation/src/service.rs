@@ -12,7 +12,7 @@ use async_trait::async_trait;
 use postgres_storage::PostgresConfig;
 use redis_storage::RedisConfig;
 use scylla_storage::ScyllaConfig;
-use service_runtime::{HealthProbe, InfraRegistry, Service};
+use service_runtime::{AccessPolicy, HealthProbe, InfraRegistry, Service};
 use tonic::service::RoutesBuilder;
 use tonic_reflection::server::Builder as ReflectionBuilder;
 use transport::kafka::config::{ConsumerConfig, KafkaClientConfig, ProducerConfig};
@@ -42,6 +42,9 @@ pub struct ModerationService {
     app: App,
 }
 
+/// Reviewer permission for the mutating ops RPCs and the review queue.
+const MODERATION_DECIDE: &str = "moderation:decide";
+
 #[async_trait]
 impl Service for ModerationService {
     const NAME: &'static str = "moderation";
@@ -65,6 +68,8 @@ impl Service for ModerationService {
         // Plane A inbound integration: user reports + classifier signals.
         spawn_report_consumer(Arc::clone(&app.ingest_report));
         spawn_signal_consumer(Arc::clone(&app.ingest_signal));
+        // Plane B outbound: moderation_outbox → moderation.v1.events.
+        tokio::spawn(app.relay.clone().run());
 
         Ok(Self { app })
     }
@@ -77,6 +82,20 @@ impl Service for ModerationService {
         ]
     }
 
+    fn access_policy(&self) -> AccessPolicy {
+        // Screen is the media/post gate; the ops RPCs ban/suspend/remove (I6).
+        AccessPolicy::for_service(Self::GRPC_SERVICE_NAME)
+            .internal("Screen")
+            .internal("GetEnforcementState")
+            .authenticated("FileAppeal")
+            .authenticated("GetStatementOfReasons")
+            .require("OpenCase", MODERATION_DECIDE)
+            .require("AssignCase", MODERATION_DECIDE)
+            .require("DecideCase", MODERATION_DECIDE)
+            .require("ListQueue", MODERATION_DECIDE)
+            .require("ResolveAppeal", MODERATION_DECIDE)
+    }
+
     fn register(self, routes: &mut RoutesBuilder) -> anyhow::Result<()> {
         let reflection = ReflectionBuilder::configure()
             .register_encoded_file_descriptor_set(FILE_DESCRIPTOR_SET)
@@ -20,7 +20,12 @@ use std::sync::Arc;
 use std::time::Duration;
 
 use cqrs::command::{CommandBusBuilder, InMemoryCommandBus};
+use cqrs::middleware::{
+    IdempotencyCommandBus, IdempotencyLayer, MetricsCommandBus, MetricsLayer,
+    MetricsQueryBus, MiddlewarePipeline,
+};
 use cqrs::query::{InMemoryQueryBus, QueryBusBuilder};
+use idempotency::RedisIdempotencyStore;
 use redis_storage::{RedisClient, RedisClientBuilder, RedisConfig};
 use scylla_storage::{ScyllaClient, ScyllaConfig, ScyllaSessionBuilder};
 use transport::kafka::config::client::KafkaClientConfig;
@@ -30,12 +35,18 @@ use transport::kafka::producer::KafkaProducerBuilder;
 use crate::application::command::create_notification::{
     CreateNotificationCommand, CreateNotificationHandler,
 };
+use crate::application::command::erase_subject_data::{
+    EraseSubjectDataCommand, EraseSubjectDataHandler,
+};
 use crate::application::command::mark_read::{
     MarkAllReadCommand, MarkAllReadHandler, MarkReadCommand, MarkReadHandler,
 };
 use crate::application::port::{
     BlockCache, NotificationEventPublisher, NotificationRepository, UnreadCounter,
 };
+use crate::application::query::export_subject_data::{
+    ExportSubjectDataHandler, ExportSubjectDataQuery,
+};
 use crate::application::query::get_unread_count::{GetUnreadCountHandler, GetUnreadCountQuery};
 use crate::application::query::list_notifications::{
     ListNotificationsHandler, ListNotificationsQuery,
@@ -60,12 +71,24 @@ pub struct Backends {
     pub kafka:  Option<KafkaClientConfig>,
 }
 
+/// The command bus every entrypoint dispatches through: the registered handlers
+/// behind an [`IdempotencyLayer`] on the service's Redis, so a retried `message_id`
+/// runs once across replicas. It fails open: a Redis outage costs deduplication,
+/// not the write path.
+/// A [`MetricsLayer`] outermost times every dispatch, replays included.
+pub type AppCommandBus =
+    MetricsCommandBus<IdempotencyCommandBus<InMemoryCommandBus, RedisIdempotencyStore>>;
+
+/// The query bus every read path dispatches through: the registered handlers,
+/// timed by a [`MetricsLayer`].
+pub type AppQueryBus = MetricsQueryBus<InMemoryQueryBus>;
+
 /// A fully-wired notification service bound to its backends, plus the shared
 /// `Arc` handles a scenario asserts against. The buses, broadcast registry, and
 /// counter exposed here are the *same* instances the handlers and workers hold.
 pub struct App {
-    pub command_bus:     Arc<InMemoryCommandBus>,
-    pub query_bus:       Arc<InMemoryQueryBus>,
+    pub command_bus:     Arc<AppCommandBus>,
+    pub query_bus:       Arc<AppQueryBus>,
     pub stream_registry: Arc<BroadcastRegistry>,
     pub counter:         Arc<dyn UnreadCounter>,
     pub repository:      Arc<dyn NotificationRepository>,
@@ -114,37 +137,55 @@ impl App {
         };
 
         // ── CQRS buses ───────────────────────────────────────────────────────
+        let handlers = CommandBusBuilder::new()
+            .register::<CreateNotificationCommand, _>(CreateNotificationHandler {
+                repository:      Arc::clone(&repository),
+                block_cache:     Arc::clone(&block_cache),
+                counter:         Arc::clone(&counter),
+                stream_registry: Arc::clone(&stream_registry),
+                publisher:       Arc::clone(&publisher),
+            })?
+            .register::<MarkReadCommand, _>(MarkReadHandler {
+                repository: Arc::clone(&repository),
+                counter:    Arc::clone(&counter),
+            })?
+            .register::<MarkAllReadCommand, _>(MarkAllReadHandler {
+                repository: Arc::clone(&repository),
+                counter:    Arc::clone(&counter),
+            })?
+            .register::<EraseSubjectDataCommand, _>(EraseSubjectDataHandler {
+                repository: Arc::clone(&repository),
+                counter:    Arc::clone(&counter),
+            })?
+            .build();
         let command_bus = Arc::new(
-            CommandBusBuilder::new()
-                .register::<CreateNotificationCommand, _>(CreateNotificationHandler {
-                    repository:      Arc::clone(&repository),
-                    block_cache:     Arc::clone(&block_cache),
-                    counter:         Arc::clone(&counter),
-                    stream_registry: Arc::clone(&stream_registry),
-                    publisher:       Arc::clone(&publisher),
-                })?
-                .register::<MarkReadCommand, _>(MarkReadHandler {
-                    repository: Arc::clone(&repository),
-                    counter:    Arc::clone(&counter),
-                })?
-                .register::<MarkAllReadCommand, _>(MarkAllReadHandler {
-                    repository: Arc::clone(&repository),
-                    counter:    Arc::clone(&counter),
-                })?
+            MiddlewarePipeline::new(handlers)
+                .layer(
+                    IdempotencyLayer::new(RedisIdempotencyStore::new(redis_client.clone(), "notification"))
+                        .fail_open(),
+                )
+                .layer(MetricsLayer::new())
                 .build(),
         );
 
         let query_bus = Arc::new(
-            QueryBusBuilder::new()
-                .register::<ListNotificationsQuery, _>(ListNotificationsHandler {
-                    repository:    Arc::clone(&repository),
-                    counter:       Arc::clone(&counter),
-                    max_page_size: config.max_page_size,
-                })?
-                .register::<GetUnreadCountQuery, _>(GetUnreadCountHandler {
-                    counter: Arc::clone(&counter),
-                })?
-                .build(),
+            MiddlewarePipeline::new(
+                QueryBusBuilder::new()
+                    .register::<ListNotificationsQuery, _>(ListNotificationsHandler {
+                        repository:    Arc::clone(&repository),
+                        counter:       Arc::clone(&counter),
+                        max_page_size: config.max_page_size,
+                    })?
+                    .register::<GetUnreadCountQuery, _>(GetUnreadCountHandler {
+                        counter: Arc::clone(&counter),
+                    })?
+                    .register::<ExportSubjectDataQuery, _>(ExportSubjectDataHandler {
+                        repository: Arc::clone(&repository),
+                    })?
+                    .build(),
+            )
+            .query_layer(MetricsLayer::new())
+            .build(),
         );
 
         // ── Background workers (Kafka path) ──────────────────────────────────
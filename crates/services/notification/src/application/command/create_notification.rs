@@ -29,7 +29,9 @@ pub struct CreateNotificationCommand {
     pub subject_id:        String,
 }
 
-impl Command for CreateNotificationCommand {}
+impl Command for CreateNotificationCommand {
+    type Output = ();
+}
 
 impl Validate for CreateNotificationCommand {
     fn validate(&self) -> Result<(), Vec<FieldViolation>> {
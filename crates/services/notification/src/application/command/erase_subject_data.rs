@@ -0,0 +1,57 @@
+use std::sync::Arc;
+
+use cqrs::{Command, CommandHandler, Envelope};
+use validate_core::Validate;
+
+use crate::application::port::{NotificationRepository, UnreadCounter};
+use crate::domain::value_object::ProfileId;
+use crate::error::NotificationError;
+
+/// GDPR erasure of the activity feed of each of the subject's profiles, driven
+/// by account's `gdpr_deletion_requested`: the whole feed partition is dropped
+/// and both unread-counter layers are reset. Every step is idempotent, so a
+/// redelivery is harmless.
+///
+/// Notifications the subject *caused* in other people's feeds belong to the
+/// recipient and are kept; they resolve the sender through profile, which
+/// pseudonymises it in the same erasure.
+pub struct EraseSubjectDataCommand {
+    pub profile_ids: Vec<String>,
+}
+
+impl Command for EraseSubjectDataCommand {
+    type Output = ();
+}
+
+impl Validate for EraseSubjectDataCommand {}
+
+pub struct EraseSubjectDataHandler<R, U> {
+    pub repository: Arc<R>,
+    pub counter:    Arc<U>,
+}
+
+impl<R, U> CommandHandler<EraseSubjectDataCommand> for EraseSubjectDataHandler<R, U>
+where
+    R: NotificationRepository,
+    U: UnreadCounter,
+{
+    type Error = NotificationError;
+
+    async fn handle(
+        &self,
+        envelope: Envelope<EraseSubjectDataCommand>,
+    ) -> Result<(), NotificationError> {
+        for profile_id in &envelope.payload.profile_ids {
+            let profile_id = ProfileId::try_from(profile_id.as_str())?;
+
+            self.repository.delete_feed(&profile_id).await?;
+            tokio::try_join!(
+                self.repository.reset_counter(&profile_id),
+                self.counter.reset(&profile_id),
+            )?;
+
+            tracing::debug!(profile_id = %profile_id, "notification feed erased");
+        }
+        Ok(())
+    }
+}
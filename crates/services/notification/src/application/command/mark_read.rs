@@ -20,7 +20,9 @@ pub struct MarkReadCommand {
     pub created_at_ms:   i64,
 }
 
-impl Command for MarkReadCommand {}
+impl Command for MarkReadCommand {
+    type Output = ();
+}
 
 impl Validate for MarkReadCommand {
     fn validate(&self) -> Result<(), Vec<FieldViolation>> {
@@ -89,7 +91,9 @@ pub struct MarkAllReadCommand {
     pub profile_id: String,
 }
 
-impl Command for MarkAllReadCommand {}
+impl Command for MarkAllReadCommand {
+    type Output = ();
+}
 
 impl Validate for MarkAllReadCommand {
     fn validate(&self) -> Result<(), Vec<FieldViolation>> {
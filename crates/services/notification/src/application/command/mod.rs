@@ -1,2 +1,3 @@
 pub mod create_notification;
+pub mod erase_subject_data;
 pub mod mark_read;
@@ -60,6 +60,10 @@ pub trait NotificationRepository: Send + Sync + 'static {
     /// Deletes the ScyllaDB counter row for `profile_id`, effectively resetting to 0.
     async fn reset_counter(&self, profile_id: &ProfileId) -> Result<(), NotificationError>;
 
+    /// Deletes the whole `notifications_by_profile` partition of `profile_id`
+    /// (GDPR erasure). Idempotent.
+    async fn delete_feed(&self, profile_id: &ProfileId) -> Result<(), NotificationError>;
+
     /// Reads the current ScyllaDB unread counter value. Used as Redis fallback.
     async fn read_counter(&self, profile_id: &ProfileId) -> Result<i64, NotificationError>;
 }
@@ -0,0 +1,98 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use cqrs::{Envelope, Query, QueryHandler};
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::application::port::{NotificationRepository, NotificationSummary};
+use crate::application::query::list_notifications::decode_cursor;
+use crate::domain::value_object::{ProfileId, SubjectKind};
+use crate::error::NotificationError;
+
+/// Page size used to walk `notifications_by_profile` during an export.
+const EXPORT_PAGE_SIZE: i32 = 100;
+
+/// GDPR Art. 20 export of the activity feed of each of the subject's profiles.
+pub struct ExportSubjectDataQuery {
+    pub profile_ids: Vec<String>,
+}
+
+impl Query for ExportSubjectDataQuery {
+    type Response = SubjectDataExport;
+}
+
+/// This service's slice of a data-subject export.
+#[derive(Debug, Clone, Serialize)]
+pub struct SubjectDataExport {
+    pub notifications: Vec<NotificationExport>,
+}
+
+impl SubjectDataExport {
+    pub fn record_count(&self) -> u32 {
+        self.notifications.len() as u32
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct NotificationExport {
+    pub notification_id:   String,
+    pub profile_id:        String,
+    pub kind:              &'static str,
+    pub sender_profile_id: String,
+    pub sender_count:      i32,
+    pub subject_kind:      &'static str,
+    pub subject_id:        String,
+    pub created_at:        DateTime<Utc>,
+    pub is_read:           bool,
+}
+
+impl From<&NotificationSummary> for NotificationExport {
+    fn from(n: &NotificationSummary) -> Self {
+        Self {
+            notification_id:   n.notification_id.to_string(),
+            profile_id:        n.target_profile_id.to_string(),
+            kind:              n.kind.as_str(),
+            sender_profile_id: n.sender_profile_id.to_string(),
+            sender_count:      n.sender_count,
+            subject_kind:      match n.subject_kind {
+                SubjectKind::Post    => "post",
+                SubjectKind::Comment => "comment",
+            },
+            subject_id:        n.subject_id.to_string(),
+            created_at:        n.created_at,
+            is_read:           n.is_read,
+        }
+    }
+}
+
+pub struct ExportSubjectDataHandler<R> {
+    pub repository: Arc<R>,
+}
+
+impl<R: NotificationRepository> QueryHandler<ExportSubjectDataQuery> for ExportSubjectDataHandler<R> {
+    type Error = NotificationError;
+
+    async fn handle(
+        &self,
+        envelope: Envelope<ExportSubjectDataQuery>,
+    ) -> Result<SubjectDataExport, NotificationError> {
+        let mut notifications = Vec::new();
+        for profile_id in &envelope.payload.profile_ids {
+            let profile_id = ProfileId::try_from(profile_id.as_str())?;
+            let mut cursor: Option<(i64, Uuid)> = None;
+            loop {
+                let (page, next) = self
+                    .repository
+                    .list_paginated(&profile_id, EXPORT_PAGE_SIZE, cursor)
+                    .await?;
+                notifications.extend(page.iter().map(NotificationExport::from));
+                match next {
+                    Some(token) => cursor = Some(decode_cursor(&token)?),
+                    None => break,
+                }
+            }
+        }
+        Ok(SubjectDataExport { notifications })
+    }
+}
@@ -64,7 +64,7 @@ where
 
 /// Decodes a page cursor from its string representation.
 /// Format: `"{created_at_ms}_{notification_id}"`.
-fn decode_cursor(token: &str) -> Result<(i64, Uuid), NotificationError> {
+pub fn decode_cursor(token: &str) -> Result<(i64, Uuid), NotificationError> {
     let (ts_str, id_str) = token.split_once('_').ok_or_else(|| {
         NotificationError::InvalidPageToken { token: token.to_owned() }
     })?;
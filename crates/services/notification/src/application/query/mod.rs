@@ -1,2 +1,3 @@
+pub mod export_subject_data;
 pub mod get_unread_count;
 pub mod list_notifications;
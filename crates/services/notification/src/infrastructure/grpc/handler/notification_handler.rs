@@ -12,6 +12,7 @@ use cqrs::{CommandBus, Envelope, QueryBus};
 use crate::application::command::mark_read::{MarkAllReadCommand, MarkReadCommand};
 use crate::application::port::{NotificationSummary, StreamRegistry};
 use crate::application::query::{
+    export_subject_data::{ExportSubjectDataQuery, SubjectDataExport},
     get_unread_count::GetUnreadCountQuery,
     list_notifications::ListNotificationsQuery,
 };
@@ -94,6 +95,26 @@ where
         Ok(Response::new(proto::GetUnreadCountResponse { unread_count: count }))
     }
 
+    pub async fn export_subject_data(
+        &self,
+        request: Request<proto::ExportSubjectDataRequest>,
+    ) -> Result<Response<proto::ExportSubjectDataResponse>, Status> {
+        let req   = request.into_inner();
+        let query = ExportSubjectDataQuery { profile_ids: req.profile_ids };
+        let export: SubjectDataExport = self
+            .query_bus
+            .dispatch(Envelope::new(Uuid::now_v7(), query))
+            .await
+            .map_err(cqrs_to_status)?;
+
+        let document = serde_json::to_vec(&export)
+            .map_err(|e| Status::internal(format!("encode export document: {e}")))?;
+        Ok(Response::new(proto::ExportSubjectDataResponse {
+            document,
+            record_count: export.record_count(),
+        }))
+    }
+
     pub async fn mark_read(
         &self,
         request: Request<proto::MarkReadRequest>,
@@ -203,6 +224,13 @@ where
     ) -> Result<Response<Self::StreamNotificationsStream>, Status> {
         self.stream_notifications(request).await
     }
+
+    async fn export_subject_data(
+        &self,
+        request: Request<proto::ExportSubjectDataRequest>,
+    ) -> Result<Response<proto::ExportSubjectDataResponse>, Status> {
+        self.export_subject_data(request).await
+    }
 }
 
 // ── Conversion helpers ────────────────────────────────────────────────────────
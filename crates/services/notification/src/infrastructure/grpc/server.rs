@@ -1,8 +1,6 @@
 use std::net::SocketAddr;
 use std::sync::Arc;
 
-use cqrs::command::InMemoryCommandBus;
-use cqrs::query::InMemoryQueryBus;
 use redis_storage::RedisConfig;
 use scylla_storage::ScyllaConfig;
 use tonic::transport::Server;
@@ -10,7 +8,7 @@ use tonic_health::server::health_reporter;
 use tonic_reflection::server::Builder as ReflectionBuilder;
 use transport::kafka::config::client::KafkaClientConfig;
 
-use crate::app::{App, Backends};
+use crate::app::{App, AppCommandBus, AppQueryBus, Backends};
 use crate::config::NotificationConfig;
 use crate::infrastructure::grpc::handler::notification_handler::{
     NotificationServiceHandler, NotificationServiceServer,
@@ -23,7 +21,7 @@ pub const FILE_DESCRIPTOR_SET: &[u8] =
 /// The gRPC handler type the server serves: the buses are shared by `Arc` (the
 /// same instances the composition root retains and the workers use).
 type ServingHandler =
-    NotificationServiceHandler<Arc<InMemoryCommandBus>, Arc<InMemoryQueryBus>, BroadcastRegistry>;
+    NotificationServiceHandler<Arc<AppCommandBus>, Arc<AppQueryBus>, BroadcastRegistry>;
 
 /// Bootstraps and runs the notification gRPC server.
 ///
@@ -269,6 +269,19 @@ impl NotificationRepository for ScyllaNotificationRepository {
         Ok(())
     }
 
+    async fn delete_feed(&self, profile_id: &ProfileId) -> Result<(), NotificationError> {
+        let stmt = self.strict_stmt(
+            "DELETE FROM notification.notifications_by_profile \
+             WHERE target_profile_id = ?",
+        );
+        self.client
+            .session
+            .execute_unpaged(stmt, (profile_id.as_uuid(),))
+            .await
+            .map_err(scylla_err)?;
+        Ok(())
+    }
+
     async fn read_counter(&self, profile_id: &ProfileId) -> Result<i64, NotificationError> {
         let stmt = self.fast_stmt(
             "SELECT unread_count FROM notification.notification_unread_counters \
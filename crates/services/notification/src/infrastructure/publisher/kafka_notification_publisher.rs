@@ -1,5 +1,5 @@
 use async_trait::async_trait;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use transport::error::TransportError;
 use transport::kafka::envelope::EventEnvelope;
 use transport::kafka::producer::KafkaProducerHandle;
@@ -11,11 +11,10 @@ use crate::error::NotificationError;
 /// identity-scoped `notif` channel and forwards `payload` verbatim.
 const TOPIC: &str = "notification.v1.events";
 
-/// The wire body. Field names MUST match realtime's `NotificationWire` decode
-/// struct — this is the (name-and-shape) contract the event-topology registry
-/// wires but does not itself enforce. Owned (not borrowed) because the producer
-/// requires a `'static` payload.
-#[derive(Serialize)]
+/// The wire body, registered in the event-topology payload schemas; realtime's
+/// `NotificationWire` is checked against that registration. Owned (not
+/// borrowed) because the producer requires a `'static` payload.
+#[derive(Serialize, Deserialize)]
 struct Wire {
     recipient_id:    String,
     notification_id: String,
@@ -100,4 +99,11 @@ mod tests {
         // connections); it must not appear as an unexpected null-typed field.
         assert!(v.get("device_id").is_none());
     }
+
+    /// The wire body is exactly the payload registered for the topic in event-topology.
+    #[test]
+    fn wire_conforms_to_the_registered_schema() {
+        let violations = event_topology::conformance::producer_violations::<Wire>(TOPIC);
+        assert!(violations.is_empty(), "{violations:#?}");
+    }
 }
@@ -4,7 +4,7 @@ use fred::interfaces::KeysInterface;
 use redis_storage::RedisClient;
 use serde::Deserialize;
 use transport::kafka::consumer::builder::KafkaConsumerBuilder;
-use transport::kafka::consumer::{run_consumer, ProcessOutcome, RetryPolicy};
+use transport::kafka::consumer::{run_consumer_keyed, KeyedConcurrency, ProcessOutcome, RetryPolicy};
 use transport::kafka::config::client::KafkaClientConfig;
 use transport::kafka::config::consumer::{AutoOffsetReset, ConsumerConfig};
 use transport::kafka::producer::KafkaProducerHandle;
@@ -144,7 +144,8 @@ where
         // `process` already folds intentional suppressions into `Ok`, so they commit
         // cleanly; transient failures retry then dead-letter; poison is dead-lettered.
         let policy = RetryPolicy::default();
-        run_consumer::<CommentEventPayload, _>(&handle, producer, &policy, move |event| {
+        let concurrency = KeyedConcurrency::from_env();
+        run_consumer_keyed::<CommentEventPayload, _>(&handle, producer, &policy, &concurrency, move |event| {
             let worker = Arc::clone(&self);
             Box::pin(async move { ProcessOutcome::from_result(worker.process(event).await) })
         })
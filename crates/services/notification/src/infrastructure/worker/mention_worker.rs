@@ -6,7 +6,7 @@ use redis_storage::RedisClient;
 use regex::Regex;
 use serde::Deserialize;
 use transport::kafka::consumer::builder::KafkaConsumerBuilder;
-use transport::kafka::consumer::{run_consumer, ProcessOutcome, RetryPolicy};
+use transport::kafka::consumer::{run_consumer_keyed, KeyedConcurrency, ProcessOutcome, RetryPolicy};
 use transport::kafka::config::client::KafkaClientConfig;
 use transport::kafka::config::consumer::{AutoOffsetReset, ConsumerConfig};
 use transport::kafka::producer::KafkaProducerHandle;
@@ -22,7 +22,7 @@ use crate::domain::value_object::{
 use crate::error::NotificationError;
 use crate::infrastructure::worker::build_dlq_producer;
 
-const TOPIC: &str = "post.published";
+const TOPIC: &str = "post.v1.events";
 
 // ── Mention regex ─────────────────────────────────────────────────────────────
 
@@ -35,11 +35,24 @@ static MENTION_REGEX: Lazy<Regex> = Lazy::new(|| {
 
 // ── Payload shape ─────────────────────────────────────────────────────────────
 
-/// Minimal projection of `post.published` Kafka events.
+/// A `post.v1.events` record, internally tagged on `type`. Only publication
+/// matters here: `PostUpdated` / `PostDeleted` decode to [`PostEvent::Other`] and
+/// commit without work.
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type")]
+pub enum PostEvent {
+    PostPublished(PostPublishedPayload),
+    #[serde(other)]
+    Other,
+}
+
+/// Minimal projection of the `PostPublished` variant.
 /// Unknown fields from the post service schema are silently ignored.
 #[derive(Debug, Deserialize)]
 pub struct PostPublishedPayload {
     pub post_id:   String,
+    /// The post's author — `profile_id` on the wire.
+    #[serde(rename = "profile_id")]
     pub author_id: String,
     /// The raw post caption, used for mention token extraction.
     pub caption:   Option<String>,
@@ -57,7 +70,7 @@ fn post_author_key(post_id: &str) -> String {
 
 // ── Worker ────────────────────────────────────────────────────────────────────
 
-/// Consumes `post.published` events and:
+/// Consumes `PostPublished` events from `post.v1.events` and:
 ///
 /// 1. Caches `notification:pa:{post_id}` → `author_profile_id` in Redis so the
 ///    `ReactionNotificationWorker` can resolve the notification target without a
@@ -148,7 +161,8 @@ where
         // caption), so those commit cleanly; transient failures retry then
         // dead-letter, and malformed ids are dead-lettered as poison.
         let policy = RetryPolicy::default();
-        run_consumer::<PostPublishedPayload, _>(&handle, producer, &policy, move |event| {
+        let concurrency = KeyedConcurrency::from_env();
+        run_consumer_keyed::<PostEvent, _>(&handle, producer, &policy, &concurrency, move |event| {
             let worker = Arc::clone(&self);
             Box::pin(async move { ProcessOutcome::from_result(worker.process(event).await) })
         })
@@ -156,7 +170,11 @@ where
         .map_err(|e| e.to_string())
     }
 
-    async fn process(&self, event: &PostPublishedPayload) -> Result<(), NotificationError> {
+    async fn process(&self, event: &PostEvent) -> Result<(), NotificationError> {
+        let PostEvent::PostPublished(event) = event else {
+            return Ok(());
+        };
+
         // Step 1: cache post author for the reaction worker.
         self.cache_post_author(&event.post_id, &event.author_id).await;
 
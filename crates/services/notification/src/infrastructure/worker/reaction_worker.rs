@@ -3,12 +3,15 @@ use std::sync::Arc;
 
 use fred::interfaces::{KeysInterface, LuaInterface, SortedSetsInterface};
 use redis_storage::RedisClient;
+use engagement_api::{reaction_event, ReactionEvent};
 use serde::{Deserialize, Serialize};
+use transport::error::CodecError;
 use transport::kafka::consumer::builder::KafkaConsumerBuilder;
-use transport::kafka::consumer::{run_consumer, ProcessOutcome, RetryPolicy};
+use transport::kafka::consumer::{run_consumer_keyed, KeyedConcurrency, ProcessOutcome, RetryPolicy};
 use transport::kafka::config::client::KafkaClientConfig;
 use transport::kafka::config::consumer::{AutoOffsetReset, ConsumerConfig};
 use transport::kafka::producer::KafkaProducerHandle;
+use transport::kafka::{FromProto, Proto};
 use uuid::Uuid;
 
 use crate::application::port::{BlockCache, NotificationRepository, StreamRegistry, UnreadCounter};
@@ -108,28 +111,51 @@ return 1
 "#;
 
 // ── Payload shape (from engagement.reactions topic) ───────────────────────────
+//
+// Published as `engagement.v1.ReactionEvent`; records from before the protobuf
+// switch are the tagged JSON below. `Proto<ReactionKafkaEvent>` reads both.
 
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(tag = "event_type", rename_all = "snake_case")]
-enum ReactionKafkaEvent {
+pub enum ReactionKafkaEvent {
     Upserted(ReactionUpsertedPayload),
     Removed(ReactionRemovedPayload),
 }
 
 #[derive(Debug, Deserialize, Serialize)]
-struct ReactionUpsertedPayload {
+pub struct ReactionUpsertedPayload {
     pub post_id:     String,
     pub profile_id:  String,
     pub event_at_ms: i64,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
-struct ReactionRemovedPayload {
+pub struct ReactionRemovedPayload {
     pub post_id:     String,
     pub profile_id:  String,
     pub event_at_ms: i64,
 }
 
+impl FromProto for ReactionKafkaEvent {
+    type Message = ReactionEvent;
+
+    fn from_proto(message: ReactionEvent) -> Result<Self, CodecError> {
+        match message.event {
+            Some(reaction_event::Event::Upserted(e)) => Ok(Self::Upserted(ReactionUpsertedPayload {
+                post_id:     e.post_id,
+                profile_id:  e.profile_id,
+                event_at_ms: e.event_at_ms,
+            })),
+            Some(reaction_event::Event::Removed(e)) => Ok(Self::Removed(ReactionRemovedPayload {
+                post_id:     e.post_id,
+                profile_id:  e.profile_id,
+                event_at_ms: e.event_at_ms,
+            })),
+            None => Err(CodecError::ProtobufMapping("ReactionEvent.event is unset".into())),
+        }
+    }
+}
+
 // ── Key builders ──────────────────────────────────────────────────────────────
 
 fn hot_key(subject_id: &SubjectId) -> String {
@@ -228,7 +254,8 @@ where
         tracing::info!(topic = TOPIC, group = %self.group_id, "reaction notification consumer started");
 
         let policy = RetryPolicy::default();
-        run_consumer::<ReactionKafkaEvent, _>(&handle, producer, &policy, move |event| {
+        let concurrency = KeyedConcurrency::from_env();
+        run_consumer_keyed::<Proto<ReactionKafkaEvent>, _>(&handle, producer, &policy, &concurrency, move |event| {
             let worker = Arc::clone(&self);
             Box::pin(async move { worker.process_one(event).await })
         })
@@ -240,8 +267,9 @@ where
     /// batch and flush it. Removals, malformed ids, self-reactions and suppressions are
     /// no-ops that succeed; a transient write failure is retried then dead-lettered.
     ///
-    /// The stream yields one message at a time, so cross-event collapse is handled by
-    /// the Redis-backed window in `accumulate_in_window`, not this in-memory batch.
+    /// The keyed runner hands over one message per call (and runs different keys
+    /// concurrently), so cross-event collapse is handled by the Redis-backed window in
+    /// `accumulate_in_window`, not this in-memory batch.
     async fn process_one(&self, event: &ReactionKafkaEvent) -> ProcessOutcome {
         let mut batch: HashMap<CollapseKey, CollapseBuffer> = HashMap::new();
         self.accumulate(event, &mut batch).await;
@@ -3,28 +3,48 @@
 //!
 //! The handler is generic over its broadcast registry (for the streaming RPC) in
 //! addition to the command/query buses; all three are the live instances `App`
-//! exposes. Kafka workers are spawned inside [`App::build`].
+//! exposes. Kafka workers are spawned inside [`App::build`], except the shared
+//! [`subject_erasure`] consumer: it reports to account, so it is spawned here
+//! with the `ConfirmSubjectErasure` channel (resilience binding `account`,
+//! endpoint `NOTIFICATION_ACCOUNT_GRPC_ENDPOINT`).
 
 use std::sync::Arc;
+use std::time::Duration;
 
 use async_trait::async_trait;
-use cqrs::command::InMemoryCommandBus;
-use cqrs::query::InMemoryQueryBus;
 use redis_storage::RedisConfig;
 use scylla_storage::ScyllaConfig;
-use service_runtime::{HealthProbe, InfraRegistry, Service};
+use service_runtime::{AccessPolicy, HealthProbe, InfraRegistry, Service};
+use subject_erasure::{
+    run_subject_erasure_consumer, DeletionRequest, EraseCommand, ErasureReporter, GrpcErasureReporter,
+};
 use tonic::service::RoutesBuilder;
 use tonic_reflection::server::Builder as ReflectionBuilder;
-use transport::kafka::config::KafkaClientConfig;
+use transport::grpc::client::{GrpcClientBuilder, GrpcClientConfig};
+use transport::kafka::config::{ConsumerConfig, KafkaClientConfig, ProducerConfig};
+use transport::kafka::consumer::{KafkaConsumerBuilder, KafkaConsumerHandle};
+use transport::kafka::producer::{KafkaProducerBuilder, KafkaProducerHandle};
 
-use crate::app::{App, Backends};
+use crate::app::{App, AppCommandBus, AppQueryBus, Backends};
+use crate::application::command::erase_subject_data::EraseSubjectDataCommand;
 use crate::config::NotificationConfig;
 use crate::infrastructure::grpc::handler::{NotificationServiceHandler, NotificationServiceServer};
 use crate::infrastructure::grpc::server::FILE_DESCRIPTOR_SET;
 use crate::infrastructure::streaming::BroadcastRegistry;
 
+/// Account lifecycle stream carrying GDPR deletion requests.
+const ACCOUNT_EVENTS_TOPIC: &str = "account.v1.events";
+/// Consumer group for notification's part of a GDPR erasure.
+const SUBJECT_ERASURE_GROUP: &str = "notification-subject-erasure";
+/// Notification's name in account's erasure participant list.
+const ERASURE_PARTICIPANT: &str = "notification";
+/// Resilience binding of the erasure confirmations sent to account.
+const ACCOUNT_DEPENDENCY: &str = "account";
+/// Backoff before respawning the consumer after the runner returns.
+const CONSUMER_RESPAWN_BACKOFF: Duration = Duration::from_secs(5);
+
 type NotificationServer = NotificationServiceServer<
-    NotificationServiceHandler<Arc<InMemoryCommandBus>, Arc<InMemoryQueryBus>, BroadcastRegistry>,
+    NotificationServiceHandler<Arc<AppCommandBus>, Arc<AppQueryBus>, BroadcastRegistry>,
 >;
 
 /// The notification service as hosted by [`service_runtime`].
@@ -39,7 +59,7 @@ impl Service for NotificationService {
     const GRPC_SERVICE_NAME: &'static str =
         <NotificationServer as tonic::server::NamedService>::NAME;
 
-    async fn build(_infra: Arc<InfraRegistry>) -> anyhow::Result<Self> {
+    async fn build(infra: Arc<InfraRegistry>) -> anyhow::Result<Self> {
         let config = Arc::new(NotificationConfig::from_env());
         let backends = Backends {
             scylla: ScyllaConfig::from_env(),
@@ -51,6 +71,22 @@ impl Service for NotificationService {
             .await
             .map_err(|e| anyhow::anyhow!("notification app build: {e}"))?;
 
+        // The channel connects lazily; account being down only delays the
+        // confirmation (the consumer retries).
+        let account = GrpcClientBuilder::new(
+            GrpcClientConfig::new(
+                std::env::var("NOTIFICATION_ACCOUNT_GRPC_ENDPOINT")
+                    .unwrap_or_else(|_| "http://localhost:50059".to_owned()),
+            )
+            .with_dependency(ACCOUNT_DEPENDENCY),
+        )
+        .build_from_registry_lazy(&infra.resilience())
+        .map_err(|e| anyhow::anyhow!("notification account channel: {e}"))?;
+        spawn_subject_erasure_consumer(
+            Arc::clone(&app.command_bus),
+            Arc::new(GrpcErasureReporter::new(account, ERASURE_PARTICIPANT)),
+        );
+
         Ok(Self { app })
     }
 
@@ -61,6 +97,17 @@ impl Service for NotificationService {
         ]
     }
 
+    fn access_policy(&self) -> AccessPolicy {
+        AccessPolicy::for_service(Self::GRPC_SERVICE_NAME)
+            .authenticated("ListNotifications")
+            .authenticated("GetUnreadCount")
+            .authenticated("MarkRead")
+            .authenticated("MarkAllRead")
+            .authenticated("StreamNotifications")
+            // Account's GDPR data-export pipeline.
+            .internal("ExportSubjectData")
+    }
+
     fn register(self, routes: &mut RoutesBuilder) -> anyhow::Result<()> {
         let handler = NotificationServiceHandler::new(
             Arc::clone(&self.app.command_bus),
@@ -76,3 +123,46 @@ impl Service for NotificationService {
         Ok(())
     }
 }
+
+/// Spawns the supervised subject-erasure consumer, respawning after
+/// [`CONSUMER_RESPAWN_BACKOFF`] whenever the runner returns.
+fn spawn_subject_erasure_consumer(command_bus: Arc<AppCommandBus>, reporter: Arc<dyn ErasureReporter>) {
+    let eraser = Arc::new(EraseCommand::new(command_bus, |request: &DeletionRequest| {
+        EraseSubjectDataCommand { profile_ids: request.profile_ids.clone() }
+    }));
+    tokio::spawn(async move {
+        loop {
+            match build_consumer() {
+                Ok((consumer, producer)) => {
+                    run_subject_erasure_consumer(
+                        ERASURE_PARTICIPANT,
+                        consumer,
+                        Arc::clone(&eraser),
+                        Arc::clone(&reporter),
+                        producer,
+                    )
+                    .await;
+                    tracing::warn!("subject-erasure consumer exited; respawning after backoff");
+                }
+                Err(error) => {
+                    tracing::error!(%error, "failed to build subject-erasure consumer; retrying");
+                }
+            }
+            tokio::time::sleep(CONSUMER_RESPAWN_BACKOFF).await;
+        }
+    });
+}
+
+/// Builds the manual-commit consumer (subscribed to `account.v1.events`) and the
+/// dead-letter producer the runner needs.
+fn build_consumer() -> anyhow::Result<(KafkaConsumerHandle, KafkaProducerHandle)> {
+    let kafka = KafkaClientConfig::from_env();
+    let consumer = KafkaConsumerBuilder::new(ConsumerConfig::new(kafka.clone(), SUBJECT_ERASURE_GROUP))
+        .subscribe(ACCOUNT_EVENTS_TOPIC)
+        .build()
+        .map_err(|e| anyhow::anyhow!("build subject-erasure consumer: {e}"))?;
+    let producer = KafkaProducerBuilder::new(ProducerConfig::new(kafka))
+        .build()
+        .map_err(|e| anyhow::anyhow!("build subject-erasure dead-letter producer: {e}"))?;
+    Ok((consumer, producer))
+}
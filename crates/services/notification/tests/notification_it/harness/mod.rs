@@ -15,14 +15,12 @@ use std::time::Duration;
 use futures::Stream;
 use uuid::Uuid;
 
-use cqrs::command::InMemoryCommandBus;
-use cqrs::query::InMemoryQueryBus;
 use cqrs::{CommandBus, Envelope};
 use redis_storage::RedisConfig;
 use scylla_storage::ScyllaConfig;
 use tonic::{Request, Status};
 
-use notification::app::{App, Backends};
+use notification::app::{App, AppCommandBus, AppQueryBus, Backends};
 use notification::application::command::create_notification::CreateNotificationCommand;
 use notification::application::port::UnreadCounter;
 use notification::config::NotificationConfig;
@@ -47,7 +45,7 @@ pub const SUBJECT_POST: i32 = 1;
 
 /// The concrete gRPC handler type, with both buses shared by `Arc`.
 pub type Handler =
-    NotificationServiceHandler<Arc<InMemoryCommandBus>, Arc<InMemoryQueryBus>, BroadcastRegistry>;
+    NotificationServiceHandler<Arc<AppCommandBus>, Arc<AppQueryBus>, BroadcastRegistry>;
 
 /// Concrete shape of the handler's boxed server-streaming response.
 pub type ResponseStream =
@@ -56,7 +54,7 @@ pub type ResponseStream =
 /// A fully-wired notification service bound to ephemeral infra, plus handles.
 pub struct TestHarness {
     pub handler:         Handler,
-    pub command_bus:     Arc<InMemoryCommandBus>,
+    pub command_bus:     Arc<AppCommandBus>,
     pub stream_registry: Arc<BroadcastRegistry>,
     pub counter:         Arc<dyn UnreadCounter>,
 }
@@ -123,7 +121,7 @@ impl TestHarness {
 /// Dispatches a create on a shared bus — a free function so scenarios can fire
 /// many concurrently from spawned tasks.
 pub async fn dispatch_create(
-    command_bus: Arc<InMemoryCommandBus>,
+    command_bus: Arc<AppCommandBus>,
     target:      String,
     sender:      String,
 ) -> Result<(), cqrs::CqrsError> {
@@ -1,45 +1,70 @@
 //! The post service's composition root.
 //!
-//! [`App::build`] is *pure composition*: a ScyllaDB config and an
-//! [`EventPublisher`] in, a fully-wired CQRS graph out. It binds no socket and
-//! reads no environment, so a binary entrypoint and the live integration harness
-//! assemble the exact same graph.
+//! [`App::build`] is *pure composition*: a ScyllaDB config in, a fully-wired CQRS
+//! graph out. It binds no socket and reads no environment, so a binary entrypoint
+//! and the live integration harness assemble the exact same graph.
 //!
-//! The event publisher is a generic parameter, mirroring the chat pattern:
-//! production passes the Kafka publisher; the integration harness passes an
-//! in-process capturing fake, so the create→publish→delete lifecycle and its
+//! Events never leave through a separate publish: the repository writes them into
+//! `post.outbox` in the same logged batch as the post rows. The serving binary
+//! spawns the relay that forwards them; the integration harness spawns none and
+//! reads `post.outbox` directly, so the create→publish→delete lifecycle and its
 //! emitted events are asserted without a broker.
 
 use std::sync::Arc;
 
 use cqrs::command::{CommandBusBuilder, InMemoryCommandBus};
+use cqrs::middleware::{
+    IdempotencyCommandBus, IdempotencyLayer, MetricsCommandBus, MetricsLayer, MetricsQueryBus,
+    MiddlewarePipeline,
+};
 use cqrs::query::{InMemoryQueryBus, QueryBusBuilder};
+use idempotency::{ScyllaIdempotencyStore, ScyllaIdempotencyTable};
+use outbox::{ScyllaOutbox, ScyllaOutboxTable};
 use scylla_storage::{ScyllaClient, ScyllaConfig, ScyllaSessionBuilder};
 
 use crate::application::command::create_post::{CreatePostCommand, CreatePostHandler};
 use crate::application::command::delete_post::{DeletePostCommand, DeletePostHandler};
+use crate::application::command::erase_subject_data::{
+    EraseSubjectDataCommand, EraseSubjectDataHandler,
+};
 use crate::application::command::publish_post::{PublishPostCommand, PublishPostHandler};
 use crate::application::command::update_post::{UpdatePostCommand, UpdatePostHandler};
-use crate::application::port::{AuthorTierStore, EventPublisher};
+use crate::application::port::AuthorTierStore;
+use crate::application::query::export_subject_data::{
+    ExportSubjectDataHandler, ExportSubjectDataQuery,
+};
 use crate::application::query::get_post::{GetPostHandler, GetPostQuery};
 use crate::application::query::list_posts_by_profile::{
     ListPostsByProfileHandler, ListPostsByProfileQuery,
 };
 use crate::infrastructure::persistence::{ScyllaAuthorTierStore, ScyllaPostRepository};
+use crate::infrastructure::publisher::{PostOutbox, OUTBOX_KEYSPACE};
 
-/// Storage endpoints the graph is wired against. Post has no Redis and emits its
-/// events through the injected [`EventPublisher`], so only ScyllaDB is needed.
+/// Storage endpoints the graph is wired against. Post has no Redis and its events
+/// go to `post.outbox`, so only ScyllaDB is needed.
 pub struct Backends {
     pub scylla: ScyllaConfig,
 }
 
+/// The command bus every entrypoint dispatches through: the registered handlers
+/// behind an [`IdempotencyLayer`] on the `post.idempotency` table, so a retried
+/// `message_id` or client key is deduplicated across replicas. Post has no Redis;
+/// the store claims with lightweight transactions and expires rows by TTL.
+/// A [`MetricsLayer`] outermost times every dispatch, replays included.
+pub type AppCommandBus =
+    MetricsCommandBus<IdempotencyCommandBus<InMemoryCommandBus, ScyllaIdempotencyStore>>;
+
+/// The query bus every read path dispatches through: the registered handlers,
+/// timed by a [`MetricsLayer`].
+pub type AppQueryBus = MetricsQueryBus<InMemoryQueryBus>;
+
 /// A fully-wired post service bound to its backends. The buses exposed here are
 /// the *same* instances the handlers are registered into; the two read paths
 /// (`GetPost` over `posts`, `ListPostsByProfile` over `posts_by_profile`) let a
 /// scenario assert dual-table consistency through the query bus alone.
 pub struct App {
-    pub command_bus: Arc<InMemoryCommandBus>,
-    pub query_bus:   Arc<InMemoryQueryBus>,
+    pub command_bus: Arc<AppCommandBus>,
+    pub query_bus:   Arc<AppQueryBus>,
     /// Live storage client, retained so the runtime's readiness loop can probe
     /// its liveness (see [`crate::service`]).
     pub scylla:      Arc<ScyllaClient>,
@@ -49,48 +74,73 @@ pub struct App {
 }
 
 impl App {
-    /// Builds the ScyllaDB client and repository, then the CQRS buses with every
-    /// post command and query registered against the supplied `publisher`.
-    pub async fn build<P: EventPublisher>(
-        backends:  Backends,
-        publisher: Arc<P>,
-    ) -> Result<Self, Box<dyn std::error::Error>> {
+    /// Builds the ScyllaDB client, then [`App::assemble`]s the graph on it with
+    /// `post.outbox` enqueueing on `post.v1.events` only.
+    pub async fn build(backends: Backends) -> Result<Self, Box<dyn std::error::Error>> {
         let scylla_client = Arc::new(ScyllaSessionBuilder::new(backends.scylla).build().await?);
-        let repository = Arc::new(ScyllaPostRepository::new(Arc::clone(&scylla_client)));
+        let outbox = PostOutbox::new(ScyllaOutbox::new(
+            Arc::clone(&scylla_client),
+            ScyllaOutboxTable::new(OUTBOX_KEYSPACE)?,
+        ));
+        Self::assemble(scylla_client, outbox)
+    }
+
+    /// Builds the repository over `outbox` on an existing client, then the CQRS
+    /// buses with every post command and query registered. The serving binary
+    /// uses this directly, so it can set the outbox's dual-publish flag and run
+    /// the relay on the same client.
+    pub fn assemble(
+        scylla_client: Arc<ScyllaClient>,
+        outbox:        PostOutbox,
+    ) -> Result<Self, Box<dyn std::error::Error>> {
+        let repository = Arc::new(ScyllaPostRepository::new(Arc::clone(&scylla_client), outbox));
         let author_tier_store: Arc<dyn AuthorTierStore> =
             Arc::new(ScyllaAuthorTierStore::new(Arc::clone(&scylla_client)));
 
+        let handlers = CommandBusBuilder::new()
+            .register::<CreatePostCommand, _>(CreatePostHandler {
+                repository: Arc::clone(&repository),
+            })?
+            .register::<PublishPostCommand, _>(PublishPostHandler {
+                repository:        Arc::clone(&repository),
+                author_tier_store: Arc::clone(&author_tier_store),
+            })?
+            .register::<UpdatePostCommand, _>(UpdatePostHandler {
+                repository: Arc::clone(&repository),
+            })?
+            .register::<DeletePostCommand, _>(DeletePostHandler {
+                repository: Arc::clone(&repository),
+            })?
+            .register::<EraseSubjectDataCommand, _>(EraseSubjectDataHandler {
+                repository: Arc::clone(&repository),
+            })?
+            .build();
         let command_bus = Arc::new(
-            CommandBusBuilder::new()
-                .register::<CreatePostCommand, _>(CreatePostHandler {
-                    repository: Arc::clone(&repository),
-                    publisher:  Arc::clone(&publisher),
-                })?
-                .register::<PublishPostCommand, _>(PublishPostHandler {
-                    repository:        Arc::clone(&repository),
-                    publisher:         Arc::clone(&publisher),
-                    author_tier_store: Arc::clone(&author_tier_store),
-                })?
-                .register::<UpdatePostCommand, _>(UpdatePostHandler {
-                    repository: Arc::clone(&repository),
-                    publisher:  Arc::clone(&publisher),
-                })?
-                .register::<DeletePostCommand, _>(DeletePostHandler {
-                    repository: Arc::clone(&repository),
-                    publisher:  Arc::clone(&publisher),
-                })?
+            MiddlewarePipeline::new(handlers)
+                .layer(IdempotencyLayer::new(ScyllaIdempotencyStore::new(
+                    Arc::clone(&scylla_client),
+                    ScyllaIdempotencyTable::new("post")?,
+                )))
+                .layer(MetricsLayer::new())
                 .build(),
         );
 
         let query_bus = Arc::new(
-            QueryBusBuilder::new()
-                .register::<GetPostQuery, _>(GetPostHandler {
-                    repository: Arc::clone(&repository),
-                })?
-                .register::<ListPostsByProfileQuery, _>(ListPostsByProfileHandler {
-                    repository: Arc::clone(&repository),
-                })?
-                .build(),
+            MiddlewarePipeline::new(
+                QueryBusBuilder::new()
+                    .register::<GetPostQuery, _>(GetPostHandler {
+                        repository: Arc::clone(&repository),
+                    })?
+                    .register::<ListPostsByProfileQuery, _>(ListPostsByProfileHandler {
+                        repository: Arc::clone(&repository),
+                    })?
+                    .register::<ExportSubjectDataQuery, _>(ExportSubjectDataHandler {
+                        repository: Arc::clone(&repository),
+                    })?
+                    .build(),
+            )
+            .query_layer(MetricsLayer::new())
+            .build(),
         );
 
         Ok(Self { command_bus, query_bus, scylla: scylla_client, author_tier_store })
@@ -1,10 +1,11 @@
 use std::sync::Arc;
 
 use cqrs::{Command, CommandHandler, Envelope};
+use serde::{Deserialize, Serialize};
 use validate_core::{FieldViolation, Validate};
 
 use crate::{
-    application::port::{EventPublisher, PostRepository},
+    application::port::PostRepository,
     domain::{
         aggregate::Post,
         entity::MediaAttachment,
@@ -37,7 +38,17 @@ pub struct CreatePostCommand {
     pub location:    Option<(f64, f64)>,
 }
 
-impl Command for CreatePostCommand {}
+/// What a successful `CreatePost` hands back. Recorded by the idempotency layer,
+/// so a client retrying under the same `idempotency-key` gets the id of the post
+/// the first attempt created rather than the one its retry would have minted.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CreatedPost {
+    pub post_id: String,
+}
+
+impl Command for CreatePostCommand {
+    type Output = CreatedPost;
+}
 
 impl Validate for CreatePostCommand {
     fn validate(&self) -> Result<(), Vec<FieldViolation>> {
@@ -78,19 +89,17 @@ pub(crate) fn parse_attachments(inputs: &[AttachmentInput]) -> Result<Vec<MediaA
         .collect()
 }
 
-pub struct CreatePostHandler<R, P> {
+pub struct CreatePostHandler<R> {
     pub repository: Arc<R>,
-    pub publisher:  Arc<P>,
 }
 
-impl<R, P> CommandHandler<CreatePostCommand> for CreatePostHandler<R, P>
+impl<R> CommandHandler<CreatePostCommand> for CreatePostHandler<R>
 where
     R: PostRepository,
-    P: EventPublisher,
 {
     type Error = PostError;
 
-    async fn handle(&self, envelope: Envelope<CreatePostCommand>) -> Result<(), PostError> {
+    async fn handle(&self, envelope: Envelope<CreatePostCommand>) -> Result<CreatedPost, PostError> {
         let cmd = &envelope.payload;
 
         let post_id    = PostId::try_from(cmd.post_id.as_str())?;
@@ -124,8 +133,9 @@ where
             .map(|(lat, lng)| GeoPoint::new(lat, lng))
             .transpose()?;
 
-        let post = Post::create(post_id, profile_id, kind, caption, attachments, parent_id, root_id, cmd.audio_ref.clone(), location)?;
-        self.repository.insert(&post).await?;
-        Ok(())
+        let mut post = Post::create(post_id, profile_id, kind, caption, attachments, parent_id, root_id, cmd.audio_ref.clone(), location)?;
+        let events = post.take_events();
+        self.repository.insert(&post, &events).await?;
+        Ok(CreatedPost { post_id: post.id().as_str() })
     }
 }
@@ -4,7 +4,7 @@ use cqrs::{Command, CommandHandler, Envelope};
 use validate_core::{FieldViolation, Validate};
 
 use crate::{
-    application::port::{EventPublisher, PostRepository},
+    application::port::PostRepository,
     domain::value_object::{PostId, ProfileId},
     error::PostError,
 };
@@ -14,7 +14,9 @@ pub struct DeletePostCommand {
     pub profile_id: String,
 }
 
-impl Command for DeletePostCommand {}
+impl Command for DeletePostCommand {
+    type Output = ();
+}
 
 impl Validate for DeletePostCommand {
     fn validate(&self) -> Result<(), Vec<FieldViolation>> {
@@ -29,15 +31,13 @@ impl Validate for DeletePostCommand {
     }
 }
 
-pub struct DeletePostHandler<R, P> {
+pub struct DeletePostHandler<R> {
     pub repository: Arc<R>,
-    pub publisher:  Arc<P>,
 }
 
-impl<R, P> CommandHandler<DeletePostCommand> for DeletePostHandler<R, P>
+impl<R> CommandHandler<DeletePostCommand> for DeletePostHandler<R>
 where
     R: PostRepository,
-    P: EventPublisher,
 {
     type Error = PostError;
 
@@ -58,11 +58,7 @@ where
         }
 
         post.delete()?;
-        self.repository.update_lifecycle(&post).await?;
-
-        for event in post.take_events() {
-            self.publisher.publish(&event).await?;
-        }
-        Ok(())
+        let events = post.take_events();
+        self.repository.update_lifecycle(&post, &events).await
     }
 }
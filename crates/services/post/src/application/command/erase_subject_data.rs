@@ -0,0 +1,66 @@
+use std::sync::Arc;
+
+use cqrs::{Command, CommandHandler, Envelope};
+use validate_core::Validate;
+
+use crate::{
+    application::port::PostRepository,
+    domain::value_object::ProfileId,
+    error::PostError,
+};
+
+/// Page size used to walk `posts_by_profile` during an erasure.
+const ERASURE_PAGE_SIZE: i32 = 100;
+
+/// GDPR erasure of every post authored by the subject's profiles, driven by
+/// account's `gdpr_deletion_requested`. Each post is tombstoned and stripped of
+/// its content (see [`Post::erase`](crate::domain::aggregate::Post::erase)); a
+/// redelivery finds nothing left to erase.
+pub struct EraseSubjectDataCommand {
+    pub profile_ids: Vec<String>,
+}
+
+impl Command for EraseSubjectDataCommand {
+    type Output = ();
+}
+
+impl Validate for EraseSubjectDataCommand {}
+
+pub struct EraseSubjectDataHandler<R> {
+    pub repository: Arc<R>,
+}
+
+impl<R> CommandHandler<EraseSubjectDataCommand> for EraseSubjectDataHandler<R>
+where
+    R: PostRepository,
+{
+    type Error = PostError;
+
+    async fn handle(&self, envelope: Envelope<EraseSubjectDataCommand>) -> Result<(), PostError> {
+        for profile_id in &envelope.payload.profile_ids {
+            let profile_id = ProfileId::try_from(profile_id.as_str())?;
+            let mut page_token: Option<String> = None;
+            loop {
+                let (page, next) = self
+                    .repository
+                    .list_by_profile(&profile_id, ERASURE_PAGE_SIZE, page_token.as_deref())
+                    .await?;
+                for summary in page {
+                    let Some(mut post) = self.repository.find_by_id(&summary.post_id).await? else {
+                        continue;
+                    };
+                    if !post.erase()? {
+                        continue;
+                    }
+                    let events = post.take_events();
+                    self.repository.erase_content(&post, &events).await?;
+                }
+                match next {
+                    Some(token) => page_token = Some(token),
+                    None => break,
+                }
+            }
+        }
+        Ok(())
+    }
+}
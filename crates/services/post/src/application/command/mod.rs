@@ -1,4 +1,5 @@
 pub mod create_post;
 pub mod delete_post;
+pub mod erase_subject_data;
 pub mod publish_post;
 pub mod update_post;
@@ -4,7 +4,7 @@ use cqrs::{Command, CommandHandler, Envelope};
 use validate_core::{FieldViolation, Validate};
 
 use crate::{
-    application::port::{AuthorTierStore, EventPublisher, PostRepository},
+    application::port::{AuthorTierStore, PostRepository},
     domain::{event::DomainEvent, value_object::{PostId, ProfileId}},
     error::PostError,
 };
@@ -14,7 +14,9 @@ pub struct PublishPostCommand {
     pub profile_id: String,
 }
 
-impl Command for PublishPostCommand {}
+impl Command for PublishPostCommand {
+    type Output = ();
+}
 
 impl Validate for PublishPostCommand {
     fn validate(&self) -> Result<(), Vec<FieldViolation>> {
@@ -29,16 +31,14 @@ impl Validate for PublishPostCommand {
     }
 }
 
-pub struct PublishPostHandler<R, P> {
+pub struct PublishPostHandler<R> {
     pub repository:        Arc<R>,
-    pub publisher:         Arc<P>,
     pub author_tier_store: Arc<dyn AuthorTierStore>,
 }
 
-impl<R, P> CommandHandler<PublishPostCommand> for PublishPostHandler<R, P>
+impl<R> CommandHandler<PublishPostCommand> for PublishPostHandler<R>
 where
     R: PostRepository,
-    P: EventPublisher,
 {
     type Error = PostError;
 
@@ -59,7 +59,6 @@ where
         }
 
         post.publish()?;
-        self.repository.update_lifecycle(&post).await?;
 
         // Stamp the author's current tier (denormalized from profile.v1.events)
         // onto the published event so timeline routes VIP authors to its read path.
@@ -72,12 +71,12 @@ where
             }
         };
 
-        for mut event in post.take_events() {
-            if let DomainEvent::PostPublished(ref mut e) = event {
+        let mut events = post.take_events();
+        for event in &mut events {
+            if let DomainEvent::PostPublished(e) = event {
                 e.author_tier = author_tier;
             }
-            self.publisher.publish(&event).await?;
         }
-        Ok(())
+        self.repository.update_lifecycle(&post, &events).await
     }
 }
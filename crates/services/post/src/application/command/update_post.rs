@@ -6,7 +6,7 @@ use validate_core::{FieldViolation, Validate};
 use crate::{
     application::{
         command::create_post::{AttachmentInput, parse_attachments},
-        port::{EventPublisher, PostRepository},
+        port::PostRepository,
     },
     domain::value_object::{Caption, PostId, ProfileId},
     error::PostError,
@@ -19,7 +19,9 @@ pub struct UpdatePostCommand {
     pub attachments: Vec<AttachmentInput>,
 }
 
-impl Command for UpdatePostCommand {}
+impl Command for UpdatePostCommand {
+    type Output = ();
+}
 
 impl Validate for UpdatePostCommand {
     fn validate(&self) -> Result<(), Vec<FieldViolation>> {
@@ -34,15 +36,13 @@ impl Validate for UpdatePostCommand {
     }
 }
 
-pub struct UpdatePostHandler<R, P> {
+pub struct UpdatePostHandler<R> {
     pub repository: Arc<R>,
-    pub publisher:  Arc<P>,
 }
 
-impl<R, P> CommandHandler<UpdatePostCommand> for UpdatePostHandler<R, P>
+impl<R> CommandHandler<UpdatePostCommand> for UpdatePostHandler<R>
 where
     R: PostRepository,
-    P: EventPublisher,
 {
     type Error = PostError;
 
@@ -66,11 +66,7 @@ where
         let attachments = parse_attachments(&cmd.attachments)?;
 
         post.update(caption, attachments)?;
-        self.repository.update_content(&post).await?;
-
-        for event in post.take_events() {
-            self.publisher.publish(&event).await?;
-        }
-        Ok(())
+        let events = post.take_events();
+        self.repository.update_content(&post, &events).await
     }
 }
@@ -1,7 +1,5 @@
 pub mod author_tier_store;
-pub mod event_publisher;
 pub mod post_repository;
 
 pub use author_tier_store::AuthorTierStore;
-pub use event_publisher::EventPublisher;
 pub use post_repository::{PostRepository, PostSummary};
@@ -1,7 +1,7 @@
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use crate::{
-    domain::{aggregate::Post, value_object::{PostId, PostKind, PostStatus, ProfileId}},
+    domain::{aggregate::Post, event::DomainEvent, value_object::{PostId, PostKind, PostStatus, ProfileId}},
     error::PostError,
 };
 
@@ -12,11 +12,17 @@ pub struct PostSummary {
     pub created_at: DateTime<Utc>,
 }
 
+/// Every write takes the events it produced and stores them atomically with the
+/// rows — they are published from there, so an event exists exactly when its
+/// write does.
 #[async_trait]
 pub trait PostRepository: Send + Sync + 'static {
-    async fn insert(&self, post: &Post) -> Result<(), PostError>;
-    async fn update_content(&self, post: &Post) -> Result<(), PostError>;
-    async fn update_lifecycle(&self, post: &Post) -> Result<(), PostError>;
+    async fn insert(&self, post: &Post, events: &[DomainEvent]) -> Result<(), PostError>;
+    async fn update_content(&self, post: &Post, events: &[DomainEvent]) -> Result<(), PostError>;
+    async fn update_lifecycle(&self, post: &Post, events: &[DomainEvent]) -> Result<(), PostError>;
+    /// Overwrites every authored column (caption, attachments, audio, location)
+    /// and the lifecycle with the aggregate's erased state.
+    async fn erase_content(&self, post: &Post, events: &[DomainEvent]) -> Result<(), PostError>;
     async fn find_by_id(&self, id: &PostId) -> Result<Option<Post>, PostError>;
     async fn list_by_profile(
         &self,
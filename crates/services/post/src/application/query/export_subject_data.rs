@@ -0,0 +1,108 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use cqrs::{Envelope, Query, QueryHandler};
+use serde::Serialize;
+
+use crate::{
+    application::port::PostRepository,
+    domain::{aggregate::Post, entity::MediaAttachment, value_object::ProfileId},
+    error::PostError,
+};
+
+/// Page size used to walk `posts_by_profile` during an export.
+const EXPORT_PAGE_SIZE: i32 = 100;
+
+/// GDPR Art. 20 export of every post authored by the subject's profiles, with
+/// full content read back from `posts`.
+pub struct ExportSubjectDataQuery {
+    pub profile_ids: Vec<String>,
+}
+
+impl Query for ExportSubjectDataQuery {
+    type Response = SubjectDataExport;
+}
+
+/// This service's slice of a data-subject export.
+#[derive(Debug, Clone, Serialize)]
+pub struct SubjectDataExport {
+    pub posts: Vec<PostExport>,
+}
+
+impl SubjectDataExport {
+    pub fn record_count(&self) -> u32 {
+        self.posts.len() as u32
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PostExport {
+    pub post_id:      String,
+    pub profile_id:   String,
+    pub kind:         String,
+    pub status:       String,
+    pub caption:      String,
+    pub attachments:  Vec<MediaAttachment>,
+    pub parent_id:    Option<String>,
+    pub audio_id:     Option<String>,
+    pub location:     Option<(f64, f64)>,
+    pub created_at:   DateTime<Utc>,
+    pub updated_at:   DateTime<Utc>,
+    pub published_at: Option<DateTime<Utc>>,
+    pub deleted_at:   Option<DateTime<Utc>>,
+}
+
+impl From<&Post> for PostExport {
+    fn from(p: &Post) -> Self {
+        Self {
+            post_id:      p.id().as_str(),
+            profile_id:   p.profile_id().as_str(),
+            kind:         p.kind().to_string(),
+            status:       p.status().as_str().to_owned(),
+            caption:      p.caption().as_str().to_owned(),
+            attachments:  p.attachments().to_vec(),
+            parent_id:    p.parent_id().map(|id| id.as_str()),
+            audio_id:     p.audio_ref().map(|a| a.audio_id.as_str()),
+            location:     p.location().map(|g| (g.lat(), g.lng())),
+            created_at:   p.created_at(),
+            updated_at:   p.updated_at(),
+            published_at: p.published_at(),
+            deleted_at:   p.deleted_at(),
+        }
+    }
+}
+
+pub struct ExportSubjectDataHandler<R> {
+    pub repository: Arc<R>,
+}
+
+impl<R: PostRepository> QueryHandler<ExportSubjectDataQuery> for ExportSubjectDataHandler<R> {
+    type Error = PostError;
+
+    async fn handle(
+        &self,
+        envelope: Envelope<ExportSubjectDataQuery>,
+    ) -> Result<SubjectDataExport, PostError> {
+        let mut posts = Vec::new();
+        for profile_id in &envelope.payload.profile_ids {
+            let profile_id = ProfileId::try_from(profile_id.as_str())?;
+            let mut page_token: Option<String> = None;
+            loop {
+                let (page, next) = self
+                    .repository
+                    .list_by_profile(&profile_id, EXPORT_PAGE_SIZE, page_token.as_deref())
+                    .await?;
+                for summary in page {
+                    if let Some(post) = self.repository.find_by_id(&summary.post_id).await? {
+                        posts.push(PostExport::from(&post));
+                    }
+                }
+                match next {
+                    Some(token) => page_token = Some(token),
+                    None => break,
+                }
+            }
+        }
+        Ok(SubjectDataExport { posts })
+    }
+}
@@ -1,2 +1,3 @@
+pub mod export_subject_data;
 pub mod get_post;
 pub mod list_posts_by_profile;
@@ -185,6 +185,30 @@ impl Post {
         Ok(now)
     }
 
+    /// GDPR erasure: deletes the post if it is still live, then strips what the
+    /// author wrote (caption, attachments, audio, location). The tombstone keeps
+    /// its id and threading so replies by other people stay attached. Returns
+    /// `false` when there was nothing left to erase.
+    pub fn erase(&mut self) -> Result<bool, PostError> {
+        if self.status == PostStatus::Deleted
+            && self.caption.as_str().is_empty()
+            && self.attachments.is_empty()
+            && self.audio_ref.is_none()
+            && self.location.is_none()
+        {
+            return Ok(false);
+        }
+        if self.status != PostStatus::Deleted {
+            self.delete()?;
+        }
+        self.caption = Caption::empty();
+        self.attachments.clear();
+        self.audio_ref = None;
+        self.location = None;
+        self.updated_at = Utc::now();
+        Ok(true)
+    }
+
     pub fn take_events(&mut self) -> Vec<DomainEvent> {
         std::mem::take(&mut self.pending_events)
     }
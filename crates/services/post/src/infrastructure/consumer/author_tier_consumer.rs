@@ -14,7 +14,7 @@ use crate::domain::value_object::ProfileId;
 /// `{"type": ...}` stream). Only `ProfileTierChanged` is acted on; all other
 /// variants deserialize and are skipped.
 #[derive(Debug, Deserialize)]
-struct ProfileV1Event {
+pub struct ProfileV1Event {
     #[serde(rename = "type")]
     event_type: String,
     #[serde(default)]
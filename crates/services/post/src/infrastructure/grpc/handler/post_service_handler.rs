@@ -2,6 +2,8 @@ use tonic::{Request, Response, Status};
 use uuid::Uuid;
 
 use cqrs::{CommandBus, Envelope, QueryBus};
+use service_runtime::inject_into_envelope;
+use transport::grpc::{idempotency_key, payload_fingerprint};
 
 use crate::application::command::{
     create_post::CreatePostCommand,
@@ -12,6 +14,7 @@ use crate::application::command::{
 use crate::application::command::create_post::AttachmentInput;
 use crate::application::port::PostSummary;
 use crate::application::query::{
+    export_subject_data::{ExportSubjectDataQuery, SubjectDataExport},
     get_post::GetPostQuery,
     list_posts_by_profile::ListPostsByProfileQuery,
 };
@@ -55,15 +58,15 @@ where
         &self,
         request: Request<proto::CreatePostRequest>,
     ) -> Result<Response<proto::CreatePostResponse>, Status> {
+        let key         = idempotency_key(request.metadata())?;
         let req         = request.into_inner();
-        let post_id     = PostId::new_v7();
-        let post_id_str = post_id.as_str();
-        let profile_id  = req.profile_id.clone();
+        let fingerprint = payload_fingerprint(&req);
+        let profile_id = req.profile_id.clone();
 
         let audio_ref = proto_audio_ref_to_domain(req.audio_ref)?;
 
         let cmd = CreatePostCommand {
-            post_id:     post_id_str.clone(),
+            post_id:     PostId::new_v7().as_str(),
             profile_id:  req.profile_id,
             kind:        req.kind,
             caption:     req.caption,
@@ -74,11 +77,18 @@ where
             location:    req.location.map(|g| (g.lat, g.lng)),
         };
 
+        let mut envelope = Envelope::new(Uuid::now_v7(), cmd);
+        inject_into_envelope(&mut envelope);
+        if let Some(key) = key {
+            envelope = envelope.with_idempotency_key(key, fingerprint);
+        }
+
+        // On a retry the output is the first attempt's, not this request's id.
         self.command_bus
-            .dispatch(Envelope::new(Uuid::now_v7(), cmd))
+            .dispatch(envelope)
             .await
-            .map(|_| Response::new(proto::CreatePostResponse {
-                post_id:    post_id_str,
+            .map(|created| Response::new(proto::CreatePostResponse {
+                post_id: created.post_id,
                 profile_id,
             }))
             .map_err(cqrs_to_status)
@@ -178,6 +188,26 @@ where
             next_token: next.unwrap_or_default(),
         }))
     }
+
+    pub async fn export_subject_data(
+        &self,
+        request: Request<proto::ExportSubjectDataRequest>,
+    ) -> Result<Response<proto::ExportSubjectDataResponse>, Status> {
+        let req   = request.into_inner();
+        let query = ExportSubjectDataQuery { profile_ids: req.profile_ids };
+        let export: SubjectDataExport = self
+            .query_bus
+            .dispatch(Envelope::new(Uuid::now_v7(), query))
+            .await
+            .map_err(cqrs_to_status)?;
+
+        let document = serde_json::to_vec(&export)
+            .map_err(|e| Status::internal(format!("encode export document: {e}")))?;
+        Ok(Response::new(proto::ExportSubjectDataResponse {
+            document,
+            record_count: export.record_count(),
+        }))
+    }
 }
 
 // ── Proto conversion helpers ──────────────────────────────────────────────────
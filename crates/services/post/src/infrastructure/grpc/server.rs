@@ -60,4 +60,11 @@ where
     ) -> Result<Response<proto::ListPostsByProfileResponse>, Status> {
         self.list_posts_by_profile(request).await
     }
+
+    async fn export_subject_data(
+        &self,
+        request: Request<proto::ExportSubjectDataRequest>,
+    ) -> Result<Response<proto::ExportSubjectDataResponse>, Status> {
+        self.export_subject_data(request).await
+    }
 }
@@ -6,14 +6,18 @@ use chrono::{DateTime, TimeZone, Utc};
 use scylla::observability::history::HistoryListener;
 use scylla::statement::unprepared::Statement;
 use scylla::value::CqlTimestamp;
+use outbox::ScyllaOutboxBatch;
 use scylla_storage::{ProfileKind as ScyllaProfileKind, ScyllaClient, ScyllaStorageError};
 
 use crate::application::port::{PostRepository, PostSummary};
 use crate::domain::aggregate::Post;
 use crate::domain::entity::MediaAttachment;
+use crate::domain::event::DomainEvent;
 use crate::domain::value_object::{AudioId, AudioKind, AudioReference, Caption, GeoPoint, PostId, PostKind, PostStatus, ProfileId};
 use crate::error::PostError;
 use crate::infrastructure::persistence::model::{PostProfileRow, PostRow};
+use crate::infrastructure::publisher::post_outbox::outbox_err;
+use crate::infrastructure::publisher::PostOutbox;
 
 // ── Page-token ────────────────────────────────────────────────────────────────
 
@@ -44,13 +48,34 @@ fn token_err(field: &'static str, msg: &'static str) -> PostError {
 
 // ── Repository ────────────────────────────────────────────────────────────────
 
+/// Every write is one logged batch: the `posts` row, the `posts_by_profile`
+/// entry it touches, and the write's events in `post.outbox`. ScyllaDB applies
+/// all of it or none of it, so the index never drifts from the row and an event
+/// exists exactly when its write does.
 pub struct ScyllaPostRepository {
     client: Arc<ScyllaClient>,
+    outbox: PostOutbox,
 }
 
 impl ScyllaPostRepository {
-    pub fn new(client: Arc<ScyllaClient>) -> Self {
-        Self { client }
+    pub fn new(client: Arc<ScyllaClient>, outbox: PostOutbox) -> Self {
+        Self { client, outbox }
+    }
+
+    /// Mirrors the post's status onto its `posts_by_profile` entry.
+    fn write_index_status(&self, batch: &mut ScyllaOutboxBatch<'_>, post: &Post) {
+        batch.write(
+            self.strict_stmt(
+                "UPDATE post.posts_by_profile SET status = ? \
+                 WHERE profile_id = ? AND created_at = ? AND post_id = ?",
+            ),
+            (
+                post.status().as_tinyint(),
+                post.profile_id().as_uuid(),
+                Self::dt_ms(post.created_at()),
+                post.id().as_uuid(),
+            ),
+        );
     }
 
     fn fast_stmt(&self, cql: &str) -> Statement {
@@ -196,133 +221,131 @@ fn decode_profile_token(page_token: Option<&str>) -> Result<Option<ProfilePageTo
 impl PostRepository for ScyllaPostRepository {
     // ── insert ────────────────────────────────────────────────────────────────
 
-    async fn insert(&self, post: &Post) -> Result<(), PostError> {
+    async fn insert(&self, post: &Post, events: &[DomainEvent]) -> Result<(), PostError> {
         let attachments_json = Self::ser_attachments(post)?;
 
-        let stmt_posts = self.strict_stmt(
-            "INSERT INTO post.posts \
-             (post_id, profile_id, kind, status, caption, attachments, \
-              parent_id, root_id, created_at, updated_at, published_at, deleted_at, \
-              audio_id, audio_kind, lat, lng) \
-             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+        let mut batch = self.outbox.batch();
+        batch.write(
+            self.strict_stmt(
+                "INSERT INTO post.posts \
+                 (post_id, profile_id, kind, status, caption, attachments, \
+                  parent_id, root_id, created_at, updated_at, published_at, deleted_at, \
+                  audio_id, audio_kind, lat, lng) \
+                 VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+            ),
+            (
+                post.id().as_uuid(),
+                post.profile_id().as_uuid(),
+                post.kind().as_tinyint(),
+                post.status().as_tinyint(),
+                post.caption().as_str().to_owned(),
+                attachments_json,
+                post.parent_id().map(PostId::as_uuid),
+                post.root_id().map(PostId::as_uuid),
+                Self::dt_ms(post.created_at()),
+                Self::dt_ms(post.updated_at()),
+                post.published_at().map(Self::dt_ms),
+                post.deleted_at().map(Self::dt_ms),
+                post.audio_ref().map(|a| a.audio_id.as_uuid()),
+                post.audio_ref().map(|a| a.audio_kind.as_tinyint()),
+                post.location().map(|g| g.lat()),
+                post.location().map(|g| g.lng()),
+            ),
         );
-        self.client
-            .session
-            .execute_unpaged(
-                stmt_posts,
-                (
-                    post.id().as_uuid(),
-                    post.profile_id().as_uuid(),
-                    post.kind().as_tinyint(),
-                    post.status().as_tinyint(),
-                    post.caption().as_str(),
-                    attachments_json.as_str(),
-                    post.parent_id().map(PostId::as_uuid),
-                    post.root_id().map(PostId::as_uuid),
-                    Self::dt_ms(post.created_at()),
-                    Self::dt_ms(post.updated_at()),
-                    post.published_at().map(Self::dt_ms),
-                    post.deleted_at().map(Self::dt_ms),
-                    post.audio_ref().map(|a| a.audio_id.as_uuid()),
-                    post.audio_ref().map(|a| a.audio_kind.as_tinyint()),
-                    post.location().map(|g| g.lat()),
-                    post.location().map(|g| g.lng()),
-                ),
-            )
-            .await
-            .map_err(scylla_err)?;
-
-        let stmt_index = self.strict_stmt(
-            "INSERT INTO post.posts_by_profile \
-             (profile_id, created_at, post_id, kind, status) \
-             VALUES (?, ?, ?, ?, ?)",
+        batch.write(
+            self.strict_stmt(
+                "INSERT INTO post.posts_by_profile \
+                 (profile_id, created_at, post_id, kind, status) \
+                 VALUES (?, ?, ?, ?, ?)",
+            ),
+            (
+                post.profile_id().as_uuid(),
+                Self::dt_ms(post.created_at()),
+                post.id().as_uuid(),
+                post.kind().as_tinyint(),
+                post.status().as_tinyint(),
+            ),
         );
-        self.client
-            .session
-            .execute_unpaged(
-                stmt_index,
-                (
-                    post.profile_id().as_uuid(),
-                    Self::dt_ms(post.created_at()),
-                    post.id().as_uuid(),
-                    post.kind().as_tinyint(),
-                    post.status().as_tinyint(),
-                ),
-            )
-            .await
-            .map_err(scylla_err)?;
-
-        Ok(())
+        self.outbox.enqueue(&mut batch, events)?;
+        batch.execute().await.map_err(outbox_err)
     }
 
     // ── update_content ────────────────────────────────────────────────────────
 
-    async fn update_content(&self, post: &Post) -> Result<(), PostError> {
+    async fn update_content(&self, post: &Post, events: &[DomainEvent]) -> Result<(), PostError> {
         let attachments_json = Self::ser_attachments(post)?;
 
-        let stmt = self.strict_stmt(
-            "UPDATE post.posts SET caption = ?, attachments = ?, updated_at = ? \
-             WHERE post_id = ?",
+        let mut batch = self.outbox.batch();
+        batch.write(
+            self.strict_stmt(
+                "UPDATE post.posts SET caption = ?, attachments = ?, updated_at = ? \
+                 WHERE post_id = ?",
+            ),
+            (
+                post.caption().as_str().to_owned(),
+                attachments_json,
+                Self::dt_ms(post.updated_at()),
+                post.id().as_uuid(),
+            ),
         );
-        self.client
-            .session
-            .execute_unpaged(
-                stmt,
-                (
-                    post.caption().as_str(),
-                    attachments_json.as_str(),
-                    Self::dt_ms(post.updated_at()),
-                    post.id().as_uuid(),
-                ),
-            )
-            .await
-            .map_err(scylla_err)?;
-
-        Ok(())
+        self.outbox.enqueue(&mut batch, events)?;
+        batch.execute().await.map_err(outbox_err)
     }
 
-    // ── update_lifecycle ──────────────────────────────────────────────────────
+    // ── erase_content ─────────────────────────────────────────────────────────
 
-    async fn update_lifecycle(&self, post: &Post) -> Result<(), PostError> {
-        let stmt_posts = self.strict_stmt(
-            "UPDATE post.posts \
-             SET status = ?, updated_at = ?, published_at = ?, deleted_at = ? \
-             WHERE post_id = ?",
-        );
-        self.client
-            .session
-            .execute_unpaged(
-                stmt_posts,
-                (
-                    post.status().as_tinyint(),
-                    Self::dt_ms(post.updated_at()),
-                    post.published_at().map(Self::dt_ms),
-                    post.deleted_at().map(Self::dt_ms),
-                    post.id().as_uuid(),
-                ),
-            )
-            .await
-            .map_err(scylla_err)?;
+    async fn erase_content(&self, post: &Post, events: &[DomainEvent]) -> Result<(), PostError> {
+        let attachments_json = Self::ser_attachments(post)?;
 
-        let stmt_index = self.strict_stmt(
-            "UPDATE post.posts_by_profile SET status = ? \
-             WHERE profile_id = ? AND created_at = ? AND post_id = ?",
+        // Content and tombstone in one batch: a redelivered erasure either finds
+        // the post fully erased or not erased at all.
+        let mut batch = self.outbox.batch();
+        batch.write(
+            self.strict_stmt(
+                "UPDATE post.posts SET caption = ?, attachments = ?, audio_id = ?, audio_kind = ?, \
+                 lat = ?, lng = ?, status = ?, updated_at = ?, published_at = ?, deleted_at = ? \
+                 WHERE post_id = ?",
+            ),
+            (
+                post.caption().as_str().to_owned(),
+                attachments_json,
+                post.audio_ref().map(|a| a.audio_id.as_uuid()),
+                post.audio_ref().map(|a| a.audio_kind.as_tinyint()),
+                post.location().map(|g| g.lat()),
+                post.location().map(|g| g.lng()),
+                post.status().as_tinyint(),
+                Self::dt_ms(post.updated_at()),
+                post.published_at().map(Self::dt_ms),
+                post.deleted_at().map(Self::dt_ms),
+                post.id().as_uuid(),
+            ),
         );
-        self.client
-            .session
-            .execute_unpaged(
-                stmt_index,
-                (
-                    post.status().as_tinyint(),
-                    post.profile_id().as_uuid(),
-                    Self::dt_ms(post.created_at()),
-                    post.id().as_uuid(),
-                ),
-            )
-            .await
-            .map_err(scylla_err)?;
+        self.write_index_status(&mut batch, post);
+        self.outbox.enqueue(&mut batch, events)?;
+        batch.execute().await.map_err(outbox_err)
+    }
 
-        Ok(())
+    // ── update_lifecycle ──────────────────────────────────────────────────────
+
+    async fn update_lifecycle(&self, post: &Post, events: &[DomainEvent]) -> Result<(), PostError> {
+        let mut batch = self.outbox.batch();
+        batch.write(
+            self.strict_stmt(
+                "UPDATE post.posts \
+                 SET status = ?, updated_at = ?, published_at = ?, deleted_at = ? \
+                 WHERE post_id = ?",
+            ),
+            (
+                post.status().as_tinyint(),
+                Self::dt_ms(post.updated_at()),
+                post.published_at().map(Self::dt_ms),
+                post.deleted_at().map(Self::dt_ms),
+                post.id().as_uuid(),
+            ),
+        );
+        self.write_index_status(&mut batch, post);
+        self.outbox.enqueue(&mut batch, events)?;
+        batch.execute().await.map_err(outbox_err)
     }
 
     // ── find_by_id ────────────────────────────────────────────────────────────
@@ -1,3 +1,7 @@
-pub mod kafka_event_publisher;
+pub mod post_outbox;
 
-pub use kafka_event_publisher::KafkaEventPublisher;
+pub use post_outbox::{PostOutbox, LEGACY_TOPICS_FLAG};
+
+/// The keyspace whose `outbox` / `outbox_lease` / `outbox_member` tables carry
+/// post's pending events.
+pub const OUTBOX_KEYSPACE: &str = "post";
@@ -0,0 +1,225 @@
+//! Post's events as `post.outbox` rows.
+//!
+//! The repository writes each event into the same logged batch as the post rows
+//! that produced it, so an acknowledged write always carries its events and a
+//! failed one carries none; the shared [`outbox::ScyllaOutboxRelay`] forwards the
+//! rows to Kafka. A broker outage no longer fails a written post, and timeline /
+//! search / counter never miss a `PostPublished` for a post that was written.
+
+use infra_config::Flag;
+use outbox::{OutboxError, OutboxMessage, ScyllaOutbox, ScyllaOutboxBatch};
+
+use crate::domain::event::DomainEvent;
+use crate::error::PostError;
+
+// The unified, versioned stream (the fleet convention, like `moderation.v1.events`
+// / `profile.v1.events`): the whole internally-tagged `DomainEvent`, keyed by
+// `post_id`. Every in-repo consumer reads this stream.
+const TOPIC_V1: &str = "post.v1.events";
+
+// Retired per-type topics (bare payloads). Emitted only while the dual-publish
+// flag is on, for consumers outside this repo still cutting over; they are no
+// longer in the topic registry, so the provisioner does not create them.
+const TOPIC_PUBLISHED: &str = "post.published";
+const TOPIC_UPDATED:   &str = "post.updated";
+const TOPIC_DELETED:   &str = "post.deleted";
+
+/// `[flags]` entry for the transition: on also enqueues each event on its retired
+/// per-type topic. Read per write, so a config push starts or stops the dual
+/// publish without a restart.
+pub const LEGACY_TOPICS_FLAG: &str = "post.dual_publish_legacy_topics";
+
+/// Env fallback for [`LEGACY_TOPICS_FLAG`] in deployments without a `[flags]`
+/// section. Off by default.
+pub const LEGACY_TOPICS_ENV: &str = "POST_DUAL_PUBLISH_LEGACY_TOPICS";
+
+/// A failed batch is a failed write — the post rows were in it; only a payload
+/// that cannot serialize is reported against the outbox.
+pub(crate) fn outbox_err(e: OutboxError) -> PostError {
+    match e {
+        OutboxError::ScyllaStorage(e) => PostError::Storage(e),
+        other => PostError::DomainViolation {
+            field:   "outbox".to_owned(),
+            message: other.to_string(),
+        },
+    }
+}
+
+/// The `post.outbox` handle the repository batches its writes through. Cheap to
+/// clone.
+#[derive(Clone)]
+pub struct PostOutbox {
+    outbox:        ScyllaOutbox,
+    legacy_topics: Flag,
+}
+
+impl PostOutbox {
+    /// Enqueues on `post.v1.events` only.
+    pub fn new(outbox: ScyllaOutbox) -> Self {
+        Self { outbox, legacy_topics: Flag::fixed(false) }
+    }
+
+    /// Also enqueue every event on its retired per-type topic (dual-publish)
+    /// while `flag` is on.
+    pub fn with_legacy_topics(mut self, flag: Flag) -> Self {
+        self.legacy_topics = flag;
+        self
+    }
+
+    /// Reads the dual-publish switch from [`LEGACY_TOPICS_ENV`] (`1`/`true`/`yes`/`on`).
+    pub fn legacy_topics_from_env() -> bool {
+        std::env::var(LEGACY_TOPICS_ENV)
+            .map(|v| matches!(v.to_ascii_lowercase().as_str(), "1" | "true" | "yes" | "on"))
+            .unwrap_or(false)
+    }
+
+    /// Starts the logged batch a repository write goes into.
+    pub(crate) fn batch(&self) -> ScyllaOutboxBatch<'_> {
+        self.outbox.batch()
+    }
+
+    /// Adds the rows for `events` to `batch`. When dual-publishing, both records
+    /// of an event — `post.v1.events` and the retired topic — go in, so a consumer
+    /// of either stream never sees one without the other.
+    pub(crate) fn enqueue(
+        &self,
+        batch:  &mut ScyllaOutboxBatch<'_>,
+        events: &[DomainEvent],
+    ) -> Result<(), PostError> {
+        let legacy_topics = self.legacy_topics.is_enabled();
+        for event in events {
+            let messages = messages(event, legacy_topics).map_err(outbox_err)?;
+            batch.enqueue(&messages).map_err(outbox_err)?;
+        }
+        Ok(())
+    }
+}
+
+/// The `post.v1.events` record, followed by the retired per-type record (the bare
+/// inner event) when `legacy_topics` is on. `DomainEvent` is `#[serde(tag = "type")]`,
+/// so the v1 payload is `{"type":"PostPublished", ...}`. Every record is keyed by
+/// `post_id` and carries `post_id` / `profile_id` headers.
+fn messages(event: &DomainEvent, legacy_topics: bool) -> Result<Vec<OutboxMessage>, OutboxError> {
+    let (post_id, profile_id, event_type) = match event {
+        DomainEvent::PostPublished(e) => (&e.post_id, &e.profile_id, "PostPublished"),
+        DomainEvent::PostUpdated(e) => (&e.post_id, &e.profile_id, "PostUpdated"),
+        DomainEvent::PostDeleted(e) => (&e.post_id, &e.profile_id, "PostDeleted"),
+    };
+    let mut messages = vec![OutboxMessage::new(TOPIC_V1, post_id.clone(), event_type, event)?];
+    if legacy_topics {
+        messages.push(match event {
+            DomainEvent::PostPublished(e) => OutboxMessage::new(TOPIC_PUBLISHED, post_id.clone(), event_type, e)?,
+            DomainEvent::PostUpdated(e) => OutboxMessage::new(TOPIC_UPDATED, post_id.clone(), event_type, e)?,
+            DomainEvent::PostDeleted(e) => OutboxMessage::new(TOPIC_DELETED, post_id.clone(), event_type, e)?,
+        });
+    }
+    Ok(messages
+        .into_iter()
+        .map(|m| m.with_header("post_id", post_id.clone()).with_header("profile_id", profile_id.clone()))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use event_topology::conformance::producer_violations;
+
+    use super::*;
+    use crate::domain::event::{PostDeletedEvent, PostPublishedEvent};
+
+    /// Locks the `post.v1.events` wire shape the `search` decoder depends on:
+    /// internally tagged on `type`, with the fields flattened alongside it.
+    #[test]
+    fn post_v1_payload_is_internally_tagged() {
+        let event = DomainEvent::PostPublished(PostPublishedEvent {
+            post_id:         "post-1".to_owned(),
+            profile_id:      "prof-9".to_owned(),
+            kind:            "text".to_owned(),
+            published_at_ms: 1_700_000_000_000,
+            author_tier:     0,
+            audio_id:        None,
+            audio_kind:      None,
+            caption:         "hello world".to_owned(),
+            thumbnail_url:   Some("https://cdn/t.jpg".to_owned()),
+            lat:             Some(48.8566),
+            lng:             Some(2.3522),
+        });
+        let value = serde_json::to_value(&event).expect("serialize");
+        assert_eq!(value["type"], "PostPublished");
+        assert_eq!(value["post_id"], "post-1");
+        assert_eq!(value["profile_id"], "prof-9");
+        assert_eq!(value["published_at_ms"], 1_700_000_000_000_i64);
+    }
+
+    /// By default an event goes to `post.v1.events` only; with the dual-publish
+    /// switch it also goes to its retired topic. Every record is keyed by `post_id`.
+    #[test]
+    fn events_go_to_the_v1_stream_and_to_the_legacy_topic_only_when_dual_publishing() {
+        let event = DomainEvent::PostDeleted(PostDeletedEvent {
+            post_id:    "post-1".to_owned(),
+            profile_id: "prof-9".to_owned(),
+            deleted_at_ms: 1_700_000_000_000,
+        });
+        let routes = |legacy_topics| -> Vec<(String, String)> {
+            messages(&event, legacy_topics)
+                .expect("messages")
+                .iter()
+                .map(|m| (m.topic().to_owned(), m.key().to_owned()))
+                .collect()
+        };
+        let route = |topic: &str| (topic.to_owned(), "post-1".to_owned());
+
+        assert_eq!(routes(false), vec![route("post.v1.events")]);
+        assert_eq!(routes(true), vec![route("post.v1.events"), route("post.deleted")]);
+    }
+
+    /// Locks the geo-discovery denormalization carried on `PostPublished`:
+    /// caption, cover thumbnail, and optional location. Absent location must be
+    /// omitted from the wire payload (geo-discovery skips indexing in that case).
+    #[test]
+    fn post_published_carries_geo_denormalization() {
+        let with_location = serde_json::to_value(&PostPublishedEvent {
+            post_id:         "post-1".to_owned(),
+            profile_id:      "prof-9".to_owned(),
+            kind:            "text".to_owned(),
+            published_at_ms: 1_700_000_000_000,
+            author_tier:     0,
+            audio_id:        None,
+            audio_kind:      None,
+            caption:         "at the beach".to_owned(),
+            thumbnail_url:   Some("https://cdn/t.jpg".to_owned()),
+            lat:             Some(48.8566),
+            lng:             Some(2.3522),
+        })
+        .expect("serialize");
+        assert_eq!(with_location["caption"], "at the beach");
+        assert_eq!(with_location["thumbnail_url"], "https://cdn/t.jpg");
+        assert_eq!(with_location["lat"], 48.8566);
+        assert_eq!(with_location["lng"], 2.3522);
+
+        let no_location = serde_json::to_value(&PostPublishedEvent {
+            post_id:         "post-2".to_owned(),
+            profile_id:      "prof-9".to_owned(),
+            kind:            "text".to_owned(),
+            published_at_ms: 1_700_000_000_000,
+            author_tier:     0,
+            audio_id:        None,
+            audio_kind:      None,
+            caption:         String::new(),
+            thumbnail_url:   None,
+            lat:             None,
+            lng:             None,
+        })
+        .expect("serialize");
+        assert!(no_location.get("lat").is_none(), "absent lat must be omitted");
+        assert!(no_location.get("lng").is_none(), "absent lng must be omitted");
+        assert!(no_location.get("thumbnail_url").is_none(), "absent thumbnail must be omitted");
+        assert_eq!(no_location["caption"], "");
+    }
+
+    /// `post.v1.events` carries exactly the payload registered for it in event-topology.
+    #[test]
+    fn post_events_conform_to_the_registered_schema() {
+        let violations = producer_violations::<DomainEvent>(TOPIC_V1);
+        assert!(violations.is_empty(), "{violations:#?}");
+    }
+}
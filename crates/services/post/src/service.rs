@@ -1,38 +1,56 @@
 //! Adapts the post composition root to the fleet [`service_runtime::Service`]
-//! contract. Post is ScyllaDB-only and always publishes domain events through the
-//! durable Kafka publisher.
+//! contract. Post is ScyllaDB-only: domain events are enqueued into `post.outbox`
+//! and the relay spawned here forwards them to Kafka.
+//!
+//! Post takes part in GDPR erasure: a consumer on `account.v1.events` tombstones
+//! the subject's posts and confirms to account over `ConfirmSubjectErasure`
+//! (resilience binding `account`, endpoint `POST_ACCOUNT_GRPC_ENDPOINT`). Both
+//! halves run on the shared [`subject_erasure`] consumer.
 
 use std::sync::Arc;
 use std::time::Duration;
 
 use async_trait::async_trait;
-use cqrs::command::InMemoryCommandBus;
-use cqrs::query::InMemoryQueryBus;
-use scylla_storage::ScyllaConfig;
-use service_runtime::{HealthProbe, InfraRegistry, Service};
+use infra_config::Flag;
+use outbox::{KafkaOutboxSink, RelayConfig, ScyllaOutbox, ScyllaOutboxRelay, ScyllaOutboxTable};
+use scylla_storage::{ScyllaConfig, ScyllaSessionBuilder};
+use service_runtime::{AccessPolicy, HealthProbe, InfraRegistry, Service};
+use subject_erasure::{
+    run_subject_erasure_consumer, DeletionRequest, EraseCommand, ErasureReporter, GrpcErasureReporter,
+};
 use tonic::service::RoutesBuilder;
 use tonic_reflection::server::Builder as ReflectionBuilder;
+use transport::grpc::client::{GrpcClientBuilder, GrpcClientConfig};
 use transport::kafka::config::{ConsumerConfig, KafkaClientConfig, ProducerConfig};
 use transport::kafka::consumer::{KafkaConsumerBuilder, KafkaConsumerHandle};
 use transport::kafka::producer::{KafkaProducerBuilder, KafkaProducerHandle};
 
-use crate::app::{App, Backends};
+use crate::app::{App, AppCommandBus, AppQueryBus};
+use crate::application::command::erase_subject_data::EraseSubjectDataCommand;
 use crate::application::port::AuthorTierStore;
 use crate::infrastructure::consumer::run_author_tier_consumer;
 use crate::infrastructure::grpc::handler::post_service_handler::PostServiceServer;
 use crate::infrastructure::grpc::handler::PostServiceHandler;
 use crate::infrastructure::grpc::server::FILE_DESCRIPTOR_SET;
-use crate::infrastructure::publisher::KafkaEventPublisher;
+use crate::infrastructure::publisher::{PostOutbox, LEGACY_TOPICS_FLAG, OUTBOX_KEYSPACE};
 
 /// The profile event stream post denormalizes author tier from.
 const PROFILE_EVENTS_TOPIC: &str = "profile.v1.events";
 /// Consumer group for post's author-tier projection consumer.
 const AUTHOR_TIER_GROUP: &str = "post-author-tier";
+/// Account lifecycle stream carrying GDPR deletion requests.
+const ACCOUNT_EVENTS_TOPIC: &str = "account.v1.events";
+/// Consumer group for post's part of a GDPR erasure.
+const SUBJECT_ERASURE_GROUP: &str = "post-subject-erasure";
+/// Post's name in account's erasure participant list.
+const ERASURE_PARTICIPANT: &str = "post";
+/// Resilience binding of the erasure confirmations sent to account.
+const ACCOUNT_DEPENDENCY: &str = "account";
 /// Backoff before respawning the consumer after the runner returns.
 const CONSUMER_RESPAWN_BACKOFF: Duration = Duration::from_secs(5);
 
 type PostServer =
-    PostServiceServer<PostServiceHandler<Arc<InMemoryCommandBus>, Arc<InMemoryQueryBus>>>;
+    PostServiceServer<PostServiceHandler<Arc<AppCommandBus>, Arc<AppQueryBus>>>;
 
 /// The post service as hosted by [`service_runtime`].
 pub struct PostService {
@@ -45,23 +63,59 @@ impl Service for PostService {
     const VERSION: &'static str = env!("CARGO_PKG_VERSION");
     const GRPC_SERVICE_NAME: &'static str = <PostServer as tonic::server::NamedService>::NAME;
 
-    async fn build(_infra: Arc<InfraRegistry>) -> anyhow::Result<Self> {
-        let backends = Backends {
-            scylla: ScyllaConfig::from_env(),
-        };
+    async fn build(infra: Arc<InfraRegistry>) -> anyhow::Result<Self> {
+        let scylla = Arc::new(
+            ScyllaSessionBuilder::new(ScyllaConfig::from_env())
+                .build()
+                .await
+                .map_err(|e| anyhow::anyhow!("post scylla session: {e}"))?,
+        );
 
+        // The repository writes events into post.outbox in the same batch as the
+        // post rows; the relay forwards them to the broker.
         let producer = KafkaProducerBuilder::new(ProducerConfig::new(KafkaClientConfig::from_env()))
             .build()?;
-        let publisher = Arc::new(KafkaEventPublisher::new(producer));
+        let table = ScyllaOutboxTable::new(OUTBOX_KEYSPACE)?;
+        let relay = ScyllaOutboxRelay::new(
+            Arc::clone(&scylla),
+            table.clone(),
+            Arc::new(KafkaOutboxSink::new(producer)),
+            RelayConfig::from_env(),
+        );
+        // Dual publishing follows the `[flags]` entry when the deployment has one,
+        // else the boot-time env switch.
+        let legacy_topics = match infra.flags() {
+            Some(flags) => flags.flag(LEGACY_TOPICS_FLAG),
+            None => Flag::fixed(PostOutbox::legacy_topics_from_env()),
+        };
+        let outbox = PostOutbox::new(ScyllaOutbox::new(Arc::clone(&scylla), table))
+            .with_legacy_topics(legacy_topics);
 
-        let app = App::build(backends, publisher)
-            .await
+        let app = App::assemble(scylla, outbox)
             .map_err(|e| anyhow::anyhow!("post app build: {e}"))?;
+        tokio::spawn(relay.run());
 
         // Inbound integration: profile tier signal → denormalized author-tier
         // projection (read on the publish path to stamp posts).
         spawn_author_tier_consumer(Arc::clone(&app.author_tier_store));
 
+        // Inbound integration: GDPR deletion request → tombstoned posts, confirmed
+        // to account. The channel connects lazily; account being down only delays
+        // the confirmation.
+        let account = GrpcClientBuilder::new(
+            GrpcClientConfig::new(
+                std::env::var("POST_ACCOUNT_GRPC_ENDPOINT")
+                    .unwrap_or_else(|_| "http://localhost:50059".to_owned()),
+            )
+            .with_dependency(ACCOUNT_DEPENDENCY),
+        )
+        .build_from_registry_lazy(&infra.resilience())
+        .map_err(|e| anyhow::anyhow!("post account channel: {e}"))?;
+        spawn_subject_erasure_consumer(
+            Arc::clone(&app.command_bus),
+            Arc::new(GrpcErasureReporter::new(account, ERASURE_PARTICIPANT)),
+        );
+
         Ok(Self { app })
     }
 
@@ -69,6 +123,19 @@ impl Service for PostService {
         vec![scylla_storage::health::probe(Arc::clone(&self.app.scylla))]
     }
 
+    fn access_policy(&self) -> AccessPolicy {
+        // GetPost also serves search hydration, which calls without a user token;
+        // ExportSubjectData serves account's GDPR data-export pipeline.
+        AccessPolicy::for_service(Self::GRPC_SERVICE_NAME)
+            .authenticated("CreatePost")
+            .authenticated("PublishPost")
+            .authenticated("UpdatePost")
+            .authenticated("DeletePost")
+            .authenticated("ListPostsByProfile")
+            .internal_or_authenticated("GetPost")
+            .internal("ExportSubjectData")
+    }
+
     fn register(self, routes: &mut RoutesBuilder) -> anyhow::Result<()> {
         let handler = PostServiceHandler::new(
             Arc::clone(&self.app.command_bus),
@@ -89,7 +156,7 @@ impl Service for PostService {
 fn spawn_author_tier_consumer(store: Arc<dyn AuthorTierStore>) {
     tokio::spawn(async move {
         loop {
-            match build_author_tier_consumer() {
+            match build_consumer(PROFILE_EVENTS_TOPIC, AUTHOR_TIER_GROUP) {
                 Ok((consumer, producer)) => {
                     run_author_tier_consumer(consumer, Arc::clone(&store), producer).await;
                     tracing::warn!("author-tier consumer exited; respawning after backoff");
@@ -103,16 +170,48 @@ fn spawn_author_tier_consumer(store: Arc<dyn AuthorTierStore>) {
     });
 }
 
-/// Builds the manual-commit consumer (subscribed to `profile.v1.events`) and the
-/// dead-letter producer the runner needs.
-fn build_author_tier_consumer() -> anyhow::Result<(KafkaConsumerHandle, KafkaProducerHandle)> {
+/// Builds a manual-commit consumer (subscribed to `topic`) and the dead-letter
+/// producer the runner needs.
+fn build_consumer(
+    topic: &str,
+    group: &str,
+) -> anyhow::Result<(KafkaConsumerHandle, KafkaProducerHandle)> {
     let kafka = KafkaClientConfig::from_env();
-    let consumer = KafkaConsumerBuilder::new(ConsumerConfig::new(kafka.clone(), AUTHOR_TIER_GROUP))
-        .subscribe(PROFILE_EVENTS_TOPIC)
+    let consumer = KafkaConsumerBuilder::new(ConsumerConfig::new(kafka.clone(), group))
+        .subscribe(topic)
         .build()
-        .map_err(|e| anyhow::anyhow!("build author-tier consumer: {e}"))?;
+        .map_err(|e| anyhow::anyhow!("build consumer for {topic}: {e}"))?;
     let producer = KafkaProducerBuilder::new(ProducerConfig::new(kafka))
         .build()
-        .map_err(|e| anyhow::anyhow!("build author-tier dead-letter producer: {e}"))?;
+        .map_err(|e| anyhow::anyhow!("build dead-letter producer for {topic}: {e}"))?;
     Ok((consumer, producer))
 }
+
+/// Spawns the supervised subject-erasure consumer (`account.v1.events` →
+/// tombstoned posts), respawning after a backoff whenever the runner returns.
+fn spawn_subject_erasure_consumer(command_bus: Arc<AppCommandBus>, reporter: Arc<dyn ErasureReporter>) {
+    let eraser = Arc::new(EraseCommand::new(command_bus, |request: &DeletionRequest| {
+        EraseSubjectDataCommand { profile_ids: request.profile_ids.clone() }
+    }));
+    tokio::spawn(async move {
+        loop {
+            match build_consumer(ACCOUNT_EVENTS_TOPIC, SUBJECT_ERASURE_GROUP) {
+                Ok((consumer, producer)) => {
+                    run_subject_erasure_consumer(
+                        ERASURE_PARTICIPANT,
+                        consumer,
+                        Arc::clone(&eraser),
+                        Arc::clone(&reporter),
+                        producer,
+                    )
+                    .await;
+                    tracing::warn!("subject-erasure consumer exited; respawning after backoff");
+                }
+                Err(error) => {
+                    tracing::error!(%error, "failed to build subject-erasure consumer; retrying");
+                }
+            }
+            tokio::time::sleep(CONSUMER_RESPAWN_BACKOFF).await;
+        }
+    });
+}
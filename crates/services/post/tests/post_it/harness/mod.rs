@@ -1,6 +1,9 @@
 //! Integration harness: boots the shared infra, wires a real post graph against
 //! it through the production composition root, and exposes the buses plus the
-//! capturing publisher for assertions.
+//! `post.outbox` rows for assertions.
+//!
+//! No relay runs, so every event a scenario's writes produced stays in
+//! `post.outbox`; [`TestHarness::events`] reads them back per post.
 //!
 //! Reads go through the query bus: `GetPost` reads the `posts` table and
 //! `ListPostsByProfile` reads `posts_by_profile`, so querying both is how a
@@ -12,13 +15,12 @@ use std::time::Duration;
 
 use uuid::Uuid;
 
-use cqrs::command::InMemoryCommandBus;
-use cqrs::query::InMemoryQueryBus;
 use cqrs::{CommandBus, CqrsError, Envelope, QueryBus};
-use scylla_storage::ScyllaConfig;
+use outbox::ScyllaOutboxTable;
+use scylla_storage::{ScyllaClient, ScyllaConfig};
 
-use post::app::{App, Backends};
-use post::application::command::create_post::CreatePostCommand;
+use post::app::{App, AppCommandBus, AppQueryBus, Backends};
+use post::application::command::create_post::{CreatePostCommand, CreatedPost};
 use post::application::command::delete_post::DeletePostCommand;
 use post::application::command::publish_post::PublishPostCommand;
 use post::application::query::get_post::GetPostQuery;
@@ -29,8 +31,6 @@ pub use post::domain::aggregate::Post;
 pub use post::domain::value_object::PostStatus;
 pub use test_support::await_until;
 
-use crate::post_it::fakes::CapturingPublisher;
-
 /// Generous default patience for a cross-component assertion (ScyllaDB
 /// dual-table write visibility).
 pub const DEADLINE: Duration = Duration::from_secs(10);
@@ -45,14 +45,14 @@ pub const KIND_TEXT_ONLY: i32 = 1;
 
 /// A fully-wired post service bound to ephemeral infra, plus assertion handles.
 pub struct TestHarness {
-    pub command_bus: Arc<InMemoryCommandBus>,
-    pub query_bus:   Arc<InMemoryQueryBus>,
-    pub publisher:   Arc<CapturingPublisher>,
+    pub command_bus: Arc<AppCommandBus>,
+    pub query_bus:   Arc<AppQueryBus>,
+    pub scylla:      Arc<ScyllaClient>,
 }
 
 impl TestHarness {
     /// Boots/reuses the shared ScyllaDB container, applies migrations, and
-    /// assembles the service graph with a capturing event publisher.
+    /// assembles the service graph through the production composition root.
     pub async fn start() -> Self {
         let scylla_cp = test_support::containers::scylla_ready(KEYSPACE, MIGRATIONS_DIR).await;
 
@@ -64,12 +64,31 @@ impl TestHarness {
             },
         };
 
-        let publisher = Arc::new(CapturingPublisher::new());
-        let app = App::build(backends, Arc::clone(&publisher))
-            .await
-            .expect("integration: build post app");
+        let app = App::build(backends).await.expect("integration: build post app");
 
-        Self { command_bus: app.command_bus, query_bus: app.query_bus, publisher }
+        Self { command_bus: app.command_bus, query_bus: app.query_bus, scylla: app.scylla }
+    }
+
+    /// The `event_type` of every `post.outbox` row keyed by `post_id`, in
+    /// enqueue order.
+    pub async fn events(&self, post_id: &str) -> Vec<String> {
+        let slot = ScyllaOutboxTable::new(KEYSPACE).expect("outbox table").slot_for(post_id);
+        self.scylla
+            .session
+            .query_unpaged(
+                "SELECT aggregate_key, event_type FROM post.outbox WHERE slot = ?",
+                (slot,),
+            )
+            .await
+            .expect("read post.outbox")
+            .into_rows_result()
+            .expect("post.outbox rows")
+            .rows::<(String, String)>()
+            .expect("post.outbox row shape")
+            .map(|row| row.expect("post.outbox row"))
+            .filter(|(key, _)| key == post_id)
+            .map(|(_, event_type)| event_type)
+            .collect()
     }
 
     /// Creates a `TextOnly` post, expecting success.
@@ -127,10 +146,23 @@ impl TestHarness {
 /// Dispatches a create on a shared bus — a free function so scenarios can fire
 /// many concurrently from spawned tasks.
 pub async fn dispatch_create(
-    command_bus: Arc<InMemoryCommandBus>,
+    command_bus: Arc<AppCommandBus>,
     post_id:     String,
     profile_id:  String,
-) -> Result<(), CqrsError> {
+) -> Result<CreatedPost, CqrsError> {
+    dispatch_create_keyed(command_bus, post_id, profile_id, None).await
+}
+
+/// [`dispatch_create`] carrying an optional client idempotency key, as the gRPC
+/// handler sets it from the `idempotency-key` header.
+pub async fn dispatch_create_keyed(
+    command_bus: Arc<AppCommandBus>,
+    post_id:     String,
+    profile_id:  String,
+    key:         Option<&str>,
+) -> Result<CreatedPost, CqrsError> {
+    // What the client sent — the post id is minted per attempt, as the handler does.
+    let fingerprint = profile_id.clone();
     let cmd = CreatePostCommand {
         post_id,
         profile_id,
@@ -142,7 +174,11 @@ pub async fn dispatch_create(
         audio_ref:   None,
         location:    None,
     };
-    command_bus.dispatch(Envelope::new(Uuid::now_v7(), cmd)).await
+    let mut envelope = Envelope::new(Uuid::now_v7(), cmd);
+    if let Some(key) = key {
+        envelope = envelope.with_idempotency_key(key, fingerprint);
+    }
+    command_bus.dispatch(envelope).await
 }
 
 /// A fresh random id (UUID string) usable as a post_id or profile_id.
@@ -1,5 +1,4 @@
-//! Post live integration suite: fakes, harness, and scenarios.
+//! Post live integration suite: harness and scenarios.
 
-pub mod fakes;
 pub mod harness;
 pub mod scenarios;
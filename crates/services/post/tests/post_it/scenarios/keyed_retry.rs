@@ -0,0 +1,47 @@
+//! Scenario — a client retry carrying the same `idempotency-key`.
+//!
+//! The gRPC handler mints a fresh post id per attempt, so a retried create
+//! arrives with a *different* id under the *same* key. The retry must answer
+//! with the first attempt's id and write nothing: the author's listing holds
+//! exactly one post. This is the idempotency axis.
+
+use std::sync::Arc;
+
+use crate::post_it::harness::{self, TestHarness, DEADLINE};
+
+#[tokio::test]
+async fn a_keyed_retry_returns_the_original_post_id() {
+    let h = TestHarness::start().await;
+
+    let profile_id = harness::random_id();
+    let key = harness::random_id();
+
+    let first = harness::dispatch_create_keyed(
+        Arc::clone(&h.command_bus),
+        harness::random_id(),
+        profile_id.clone(),
+        Some(&key),
+    )
+    .await
+    .expect("create_post");
+    let retry = harness::dispatch_create_keyed(
+        Arc::clone(&h.command_bus),
+        harness::random_id(),
+        profile_id.clone(),
+        Some(&key),
+    )
+    .await
+    .expect("retried create_post");
+
+    assert_eq!(retry, first, "the retry replays the recorded response");
+
+    harness::await_until("posts_by_profile lists the created post", DEADLINE, || {
+        let h = &h;
+        let profile_id = profile_id.clone();
+        async move { !h.list(&profile_id).await.is_empty() }
+    })
+    .await;
+    let listed = h.list(&profile_id).await;
+    assert_eq!(listed.len(), 1, "the retry wrote no second post");
+    assert_eq!(listed[0].post_id.as_str(), first.post_id);
+}
@@ -1,5 +1,7 @@
 //! Scenario groups for the post live suite, mapping to the testing standard's
-//! axes: concurrency / dual-table consistency and lifecycle event emission.
+//! axes: concurrency / dual-table consistency, lifecycle event emission and
+//! keyed-retry idempotency.
 
 mod dual_table_consistency;
+mod keyed_retry;
 mod lifecycle_events;
@@ -4,38 +4,48 @@
 //! graph out. It binds no socket and reads no environment, so a binary entrypoint
 //! and the live integration harness assemble the exact same graph.
 //!
-//! Profile publishes its lifecycle events to `profile.v1.events` via an injected
-//! [`EventPublisher`]: each command handler drains the aggregate's pending events
-//! after the durable write and publishes them. The publisher is injected (Kafka in
-//! the binary, a no-op in broker-free composition) so the graph itself needs no
-//! broker. Its one inbound Kafka touchpoint (the account-event consumer) is wired
-//! separately by the serving binary against [`App::command_bus`].
+//! Profile's lifecycle events for `profile.v1.events` never leave through a
+//! separate publish: the repository writes them into `profile.outbox` in the same
+//! logged batch as the profile row. The serving binary spawns the relay that
+//! forwards them; the integration harness spawns none and reads `profile.outbox`
+//! directly, so the graph itself needs no broker. Its one inbound Kafka touchpoint
+//! (the account-event consumer) is wired separately by the serving binary
+//! against [`App::command_bus`], together with the reporter that confirms GDPR
+//! erasures back to account.
 
 use std::sync::Arc;
 
 use cqrs::command::{CommandBusBuilder, InMemoryCommandBus};
+use cqrs::middleware::{
+    IdempotencyCommandBus, IdempotencyLayer, MetricsCommandBus, MetricsLayer,
+    MetricsQueryBus, MiddlewarePipeline,
+};
 use cqrs::query::{InMemoryQueryBus, QueryBusBuilder};
+use idempotency::RedisIdempotencyStore;
 use redis_storage::{RedisClient, RedisClientBuilder, RedisConfig};
 use infra_config::CacheRegistry;
+use outbox::{ScyllaOutbox, ScyllaOutboxTable};
 use scylla_storage::{ScyllaClient, ScyllaConfig, ScyllaSessionBuilder};
 
 use crate::application::command::{
     ChangeHandleCommand, ChangeHandleHandler, CreateProfileCommand, CreateProfileHandler,
-    DeleteProfileCommand, DeleteProfileHandler, HideProfileCommand, HideProfileHandler,
+    DeleteProfileCommand, DeleteProfileHandler, EraseSubjectDataCommand, EraseSubjectDataHandler,
+    HideProfileCommand, HideProfileHandler,
     RestoreProfileCommand, RestoreProfileHandler, SetProfileTierCommand, SetProfileTierHandler,
     SetVisibilityCommand, SetVisibilityHandler, UpdateAvatarCommand, UpdateAvatarHandler,
     UpdateBannerCommand, UpdateBannerHandler, UpdateProfileCommand, UpdateProfileHandler,
     VerifyProfileCommand, VerifyProfileHandler,
 };
-use crate::application::port::{EventPublisher, ProfileCache, ProfileRepository};
+use crate::application::port::{ProfileCache, ProfileRepository};
 use crate::application::query::{
-    GetProfileByHandleHandler, GetProfileByHandleQuery, GetProfileByIdHandler, GetProfileByIdQuery,
+    ExportSubjectDataHandler, ExportSubjectDataQuery, GetProfileByHandleHandler, GetProfileByHandleQuery, GetProfileByIdHandler, GetProfileByIdQuery,
     ListProfilesByAccountHandler, ListProfilesByAccountQuery,
 };
 use crate::infrastructure::cache::{
     RedisProfileCache, HANDLE_CACHE_NAMESPACE, PROFILE_CACHE_NAMESPACE,
 };
 use crate::infrastructure::persistence::ScyllaProfileRepository;
+use crate::infrastructure::publisher::{ProfileOutbox, OUTBOX_KEYSPACE};
 
 /// Storage endpoints the graph is wired against.
 pub struct Backends {
@@ -43,12 +53,24 @@ pub struct Backends {
     pub redis:  RedisConfig,
 }
 
+/// The command bus every entrypoint dispatches through: the registered handlers
+/// behind an [`IdempotencyLayer`] on the service's Redis, so a retried `message_id`
+/// runs once across replicas. It fails open: a Redis outage costs deduplication,
+/// not the write path.
+/// A [`MetricsLayer`] outermost times every dispatch, replays included.
+pub type AppCommandBus =
+    MetricsCommandBus<IdempotencyCommandBus<InMemoryCommandBus, RedisIdempotencyStore>>;
+
+/// The query bus every read path dispatches through: the registered handlers,
+/// timed by a [`MetricsLayer`].
+pub type AppQueryBus = MetricsQueryBus<InMemoryQueryBus>;
+
 /// A fully-wired profile service bound to its backends, plus the shared `Arc`
 /// handles a scenario asserts against. The repository and cache exposed here are
 /// the *same* instances the command/query handlers hold.
 pub struct App {
-    pub command_bus: Arc<InMemoryCommandBus>,
-    pub query_bus:   Arc<InMemoryQueryBus>,
+    pub command_bus: Arc<AppCommandBus>,
+    pub query_bus:   Arc<AppQueryBus>,
     pub repository:  Arc<dyn ProfileRepository>,
     pub cache:       Arc<dyn ProfileCache>,
     /// Live storage clients, retained so the runtime's readiness loop can probe
@@ -67,19 +89,33 @@ impl App {
     pub async fn build(
         backends: Backends,
         cache_registry: Arc<CacheRegistry>,
-        publisher: Arc<dyn EventPublisher>,
     ) -> Result<Self, Box<dyn std::error::Error>> {
         let Backends { scylla, redis } = backends;
+        let scylla_client = Arc::new(ScyllaSessionBuilder::new(scylla).build().await?);
+        let outbox = ProfileOutbox::new(ScyllaOutbox::new(
+            Arc::clone(&scylla_client),
+            ScyllaOutboxTable::new(OUTBOX_KEYSPACE)?,
+        ));
+        Self::assemble(scylla_client, redis, cache_registry, outbox).await
+    }
 
+    /// [`App::build`] on an existing ScyllaDB client and outbox. The serving
+    /// binary uses this directly, so the relay drains the outbox the repository
+    /// writes through, on the same client.
+    pub async fn assemble(
+        scylla_client: Arc<ScyllaClient>,
+        redis: RedisConfig,
+        cache_registry: Arc<CacheRegistry>,
+        outbox: ProfileOutbox,
+    ) -> Result<Self, Box<dyn std::error::Error>> {
         // ── Storage clients ──────────────────────────────────────────────────
-        let scylla_client = Arc::new(ScyllaSessionBuilder::new(scylla).build().await?);
         let redis_client = Arc::new(RedisClientBuilder::new(redis).build().await?);
 
         // ── Adapters (exposed behind their ports) ────────────────────────────
         // `Arc::clone` rather than move: the readiness loop probes the same live
         // clients the repository and cache use.
         let repository: Arc<dyn ProfileRepository> =
-            Arc::new(ScyllaProfileRepository::new(Arc::clone(&scylla_client)));
+            Arc::new(ScyllaProfileRepository::new(Arc::clone(&scylla_client), outbox));
         let cache: Arc<dyn ProfileCache> = Arc::new(RedisProfileCache::new(
             Arc::clone(&redis_client),
             cache_registry.profile_for(PROFILE_CACHE_NAMESPACE),
@@ -87,81 +123,88 @@ impl App {
         ));
 
         // ── Command bus ──────────────────────────────────────────────────────
+        let handlers = CommandBusBuilder::new()
+            .register::<CreateProfileCommand, _>(CreateProfileHandler::new(
+                Arc::clone(&repository),
+                Arc::clone(&cache),
+            ))?
+            .register::<UpdateProfileCommand, _>(UpdateProfileHandler::new(
+                Arc::clone(&repository),
+                Arc::clone(&cache),
+            ))?
+            .register::<ChangeHandleCommand, _>(ChangeHandleHandler::new(
+                Arc::clone(&repository),
+                Arc::clone(&cache),
+            ))?
+            .register::<UpdateAvatarCommand, _>(UpdateAvatarHandler::new(
+                Arc::clone(&repository),
+                Arc::clone(&cache),
+            ))?
+            .register::<UpdateBannerCommand, _>(UpdateBannerHandler::new(
+                Arc::clone(&repository),
+                Arc::clone(&cache),
+            ))?
+            .register::<SetVisibilityCommand, _>(SetVisibilityHandler::new(
+                Arc::clone(&repository),
+                Arc::clone(&cache),
+            ))?
+            .register::<VerifyProfileCommand, _>(VerifyProfileHandler::new(
+                Arc::clone(&repository),
+                Arc::clone(&cache),
+            ))?
+            .register::<HideProfileCommand, _>(HideProfileHandler::new(
+                Arc::clone(&repository),
+                Arc::clone(&cache),
+            ))?
+            .register::<RestoreProfileCommand, _>(RestoreProfileHandler::new(
+                Arc::clone(&repository),
+                Arc::clone(&cache),
+            ))?
+            .register::<DeleteProfileCommand, _>(DeleteProfileHandler::new(
+                Arc::clone(&repository),
+                Arc::clone(&cache),
+            ))?
+            .register::<SetProfileTierCommand, _>(SetProfileTierHandler::new(
+                Arc::clone(&repository),
+                Arc::clone(&cache),
+            ))?
+            .register::<EraseSubjectDataCommand, _>(EraseSubjectDataHandler::new(
+                Arc::clone(&repository),
+                Arc::clone(&cache),
+            ))?
+            .build();
         let command_bus = Arc::new(
-            CommandBusBuilder::new()
-                .register::<CreateProfileCommand, _>(CreateProfileHandler::new(
-                    Arc::clone(&repository),
-                    Arc::clone(&cache),
-                    Arc::clone(&publisher),
-                ))?
-                .register::<UpdateProfileCommand, _>(UpdateProfileHandler::new(
-                    Arc::clone(&repository),
-                    Arc::clone(&cache),
-                    Arc::clone(&publisher),
-                ))?
-                .register::<ChangeHandleCommand, _>(ChangeHandleHandler::new(
-                    Arc::clone(&repository),
-                    Arc::clone(&cache),
-                    Arc::clone(&publisher),
-                ))?
-                .register::<UpdateAvatarCommand, _>(UpdateAvatarHandler::new(
-                    Arc::clone(&repository),
-                    Arc::clone(&cache),
-                    Arc::clone(&publisher),
-                ))?
-                .register::<UpdateBannerCommand, _>(UpdateBannerHandler::new(
-                    Arc::clone(&repository),
-                    Arc::clone(&cache),
-                    Arc::clone(&publisher),
-                ))?
-                .register::<SetVisibilityCommand, _>(SetVisibilityHandler::new(
-                    Arc::clone(&repository),
-                    Arc::clone(&cache),
-                    Arc::clone(&publisher),
-                ))?
-                .register::<VerifyProfileCommand, _>(VerifyProfileHandler::new(
-                    Arc::clone(&repository),
-                    Arc::clone(&cache),
-                    Arc::clone(&publisher),
-                ))?
-                .register::<HideProfileCommand, _>(HideProfileHandler::new(
-                    Arc::clone(&repository),
-                    Arc::clone(&cache),
-                    Arc::clone(&publisher),
-                ))?
-                .register::<RestoreProfileCommand, _>(RestoreProfileHandler::new(
-                    Arc::clone(&repository),
-                    Arc::clone(&cache),
-                    Arc::clone(&publisher),
-                ))?
-                .register::<DeleteProfileCommand, _>(DeleteProfileHandler::new(
-                    Arc::clone(&repository),
-                    Arc::clone(&cache),
-                    Arc::clone(&publisher),
-                ))?
-                .register::<SetProfileTierCommand, _>(SetProfileTierHandler::new(
-                    Arc::clone(&repository),
-                    Arc::clone(&cache),
-                    Arc::clone(&publisher),
-                ))?
+            MiddlewarePipeline::new(handlers)
+                .layer(
+                    IdempotencyLayer::new(RedisIdempotencyStore::new((*redis_client).clone(), "profile"))
+                        .fail_open(),
+                )
+                .layer(MetricsLayer::new())
                 .build(),
         );
 
         // ── Query bus ────────────────────────────────────────────────────────
         let query_bus = Arc::new(
-            QueryBusBuilder::new()
-                .register::<GetProfileByIdQuery, _>(GetProfileByIdHandler::new(
-                    Arc::clone(&repository),
-                    Arc::clone(&cache),
-                ))?
-                .register::<GetProfileByHandleQuery, _>(GetProfileByHandleHandler::new(
-                    Arc::clone(&repository),
-                    Arc::clone(&cache),
-                ))?
-                .register::<ListProfilesByAccountQuery, _>(ListProfilesByAccountHandler::new(
-                    Arc::clone(&repository),
-                ))?
-                .build(),
+            MiddlewarePipeline::new(
+                QueryBusBuilder::new()
+                    .register::<GetProfileByIdQuery, _>(GetProfileByIdHandler::new(
+                        Arc::clone(&repository),
+                        Arc::clone(&cache),
+                    ))?
+                    .register::<GetProfileByHandleQuery, _>(GetProfileByHandleHandler::new(
+                        Arc::clone(&repository),
+                        Arc::clone(&cache),
+                    ))?
+                    .register::<ListProfilesByAccountQuery, _>(ListProfilesByAccountHandler::new(
+                        Arc::clone(&repository),
+                    ))?
+                    .register::<ExportSubjectDataQuery, _>(ExportSubjectDataHandler::new(
+                        Arc::clone(&repository),
+                    ))?
+                    .build(),
+            )
+            .query_layer(MetricsLayer::new())
+            .build(),
         );
 
         Ok(Self {
@@ -3,7 +3,7 @@ use std::sync::Arc;
 use cqrs::{Command, CommandHandler, Envelope};
 use validate_core::{FieldViolation, Validate};
 
-use crate::application::port::{EventPublisher, ProfileCache, ProfileRepository};
+use crate::application::port::{ProfileCache, ProfileRepository};
 use crate::domain::value_object::{Handle, ProfileId};
 use crate::error::ProfileError;
 
@@ -13,7 +13,9 @@ pub struct ChangeHandleCommand {
     pub new_handle: String,
 }
 
-impl Command for ChangeHandleCommand {}
+impl Command for ChangeHandleCommand {
+    type Output = ();
+}
 
 impl Validate for ChangeHandleCommand {
     fn validate(&self) -> Result<(), Vec<FieldViolation>> {
@@ -31,12 +33,11 @@ impl Validate for ChangeHandleCommand {
 pub struct ChangeHandleHandler {
     repo: Arc<dyn ProfileRepository>,
     cache: Arc<dyn ProfileCache>,
-    publisher: Arc<dyn EventPublisher>,
 }
 
 impl ChangeHandleHandler {
-    pub fn new(repo: Arc<dyn ProfileRepository>, cache: Arc<dyn ProfileCache>, publisher: Arc<dyn EventPublisher>) -> Self {
-        Self { repo, cache, publisher }
+    pub fn new(repo: Arc<dyn ProfileRepository>, cache: Arc<dyn ProfileCache>) -> Self {
+        Self { repo, cache }
     }
 }
 
@@ -74,11 +75,8 @@ impl CommandHandler<ChangeHandleCommand> for ChangeHandleHandler {
         }
 
         self.repo.tombstone_handle(&old_handle).await?;
-        self.repo.save(&profile).await?;
-
-        for event in profile.drain_events() {
-            self.publisher.publish(&event).await?;
-        }
+        let events = profile.drain_events();
+        self.repo.save(&profile, &events).await?;
 
         let _ = self.cache.invalidate_handle(old_handle.as_str()).await;
         let _ = self.cache.invalidate_handle(new_handle.as_str()).await;
@@ -3,7 +3,7 @@ use std::sync::Arc;
 use cqrs::{Command, CommandHandler, Envelope};
 use validate_core::{FieldViolation, Validate};
 
-use crate::application::port::{EventPublisher, ProfileCache, ProfileRepository};
+use crate::application::port::{ProfileCache, ProfileRepository};
 use crate::domain::aggregate::{Profile, ProfileCreateParams};
 use crate::domain::value_object::{AccountId, AvatarUrl, BannerUrl, Bio, DisplayName, Handle, Locale, ProfileKind};
 use crate::error::ProfileError;
@@ -20,7 +20,9 @@ pub struct CreateProfileCommand {
     pub locale: String,
 }
 
-impl Command for CreateProfileCommand {}
+impl Command for CreateProfileCommand {
+    type Output = ();
+}
 
 impl Validate for CreateProfileCommand {
     fn validate(&self) -> Result<(), Vec<FieldViolation>> {
@@ -46,12 +48,11 @@ impl Validate for CreateProfileCommand {
 pub struct CreateProfileHandler {
     repo: Arc<dyn ProfileRepository>,
     cache: Arc<dyn ProfileCache>,
-    publisher: Arc<dyn EventPublisher>,
 }
 
 impl CreateProfileHandler {
-    pub fn new(repo: Arc<dyn ProfileRepository>, cache: Arc<dyn ProfileCache>, publisher: Arc<dyn EventPublisher>) -> Self {
-        Self { repo, cache, publisher }
+    pub fn new(repo: Arc<dyn ProfileRepository>, cache: Arc<dyn ProfileCache>) -> Self {
+        Self { repo, cache }
     }
 }
 
@@ -86,21 +87,17 @@ impl CommandHandler<CreateProfileCommand> for CreateProfileHandler {
             correlation_id: envelope.correlation_id,
         });
 
-        self.repo.save(&profile).await?;
-        self.repo.save_account_index(&profile).await?;
-
         // LWT claim — if this returns false another concurrent create raced us.
-        // The profile row is already written; we must surface the conflict.
+        // Claim before the save: ProfileCreated rides in the save's batch, so a
+        // lost race must fail before anything is written.
         let claimed = self.repo.claim_handle(&handle, profile.id(), account_id).await?;
         if !claimed {
             return Err(ProfileError::HandleAlreadyTaken { handle: handle.as_str().to_owned() });
         }
 
-        // Publish only after the claim succeeds — a lost race must not emit a
-        // phantom ProfileCreated.
-        for event in profile.drain_events() {
-            self.publisher.publish(&event).await?;
-        }
+        let events = profile.drain_events();
+        self.repo.save(&profile, &events).await?;
+        self.repo.save_account_index(&profile).await?;
 
         let _ = self.cache.invalidate_account_profiles(&account_id).await;
 
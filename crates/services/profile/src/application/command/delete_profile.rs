@@ -3,7 +3,7 @@ use std::sync::Arc;
 use cqrs::{Command, CommandHandler, Envelope};
 use validate_core::{FieldViolation, Validate};
 
-use crate::application::port::{EventPublisher, ProfileCache, ProfileRepository};
+use crate::application::port::{ProfileCache, ProfileRepository};
 use crate::domain::value_object::ProfileId;
 use crate::error::ProfileError;
 
@@ -12,7 +12,9 @@ pub struct DeleteProfileCommand {
     pub profile_id: String,
 }
 
-impl Command for DeleteProfileCommand {}
+impl Command for DeleteProfileCommand {
+    type Output = ();
+}
 
 impl Validate for DeleteProfileCommand {
     fn validate(&self) -> Result<(), Vec<FieldViolation>> {
@@ -27,12 +29,11 @@ impl Validate for DeleteProfileCommand {
 pub struct DeleteProfileHandler {
     repo: Arc<dyn ProfileRepository>,
     cache: Arc<dyn ProfileCache>,
-    publisher: Arc<dyn EventPublisher>,
 }
 
 impl DeleteProfileHandler {
-    pub fn new(repo: Arc<dyn ProfileRepository>, cache: Arc<dyn ProfileCache>, publisher: Arc<dyn EventPublisher>) -> Self {
-        Self { repo, cache, publisher }
+    pub fn new(repo: Arc<dyn ProfileRepository>, cache: Arc<dyn ProfileCache>) -> Self {
+        Self { repo, cache }
     }
 }
 
@@ -53,11 +54,8 @@ impl CommandHandler<DeleteProfileCommand> for DeleteProfileHandler {
         let account_id = profile.account_id();
 
         profile.delete(envelope.correlation_id)?;
-        self.repo.save(&profile).await?;
-
-        for event in profile.drain_events() {
-            self.publisher.publish(&event).await?;
-        }
+        let events = profile.drain_events();
+        self.repo.save(&profile, &events).await?;
 
         // Tombstone the handle so it enters the 30-day reservation window.
         self.repo.tombstone_handle(&handle).await?;
@@ -0,0 +1,102 @@
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use cqrs::{Command, CommandHandler, Envelope};
+use validate_core::{FieldViolation, Validate};
+
+use crate::application::port::{ProfileCache, ProfileRepository};
+use crate::domain::value_object::{AccountId, ProfileId};
+use crate::error::ProfileError;
+
+/// Page size used to walk `profiles_by_account` during an erasure.
+const ERASURE_PAGE_SIZE: i32 = 100;
+
+/// GDPR erasure of every profile an account owns, driven by account's
+/// `gdpr_deletion_requested`. `profile_ids` is the snapshot account took when the
+/// request was made; the `profiles_by_account` index is walked as well, so a
+/// profile created after the snapshot is not missed. Re-running is a no-op.
+#[derive(Debug, Clone)]
+pub struct EraseSubjectDataCommand {
+    pub account_id: String,
+    pub profile_ids: Vec<String>,
+}
+
+impl Command for EraseSubjectDataCommand {
+    type Output = ();
+}
+
+impl Validate for EraseSubjectDataCommand {
+    fn validate(&self) -> Result<(), Vec<FieldViolation>> {
+        let mut violations = Vec::new();
+        if self.account_id.trim().is_empty() {
+            violations.push(FieldViolation::new("account_id", "VAL-3090", "account_id must not be empty"));
+        }
+        if violations.is_empty() { Ok(()) } else { Err(violations) }
+    }
+}
+
+pub struct EraseSubjectDataHandler {
+    repo: Arc<dyn ProfileRepository>,
+    cache: Arc<dyn ProfileCache>,
+}
+
+impl EraseSubjectDataHandler {
+    pub fn new(repo: Arc<dyn ProfileRepository>, cache: Arc<dyn ProfileCache>) -> Self {
+        Self { repo, cache }
+    }
+}
+
+impl CommandHandler<EraseSubjectDataCommand> for EraseSubjectDataHandler {
+    type Error = ProfileError;
+
+    async fn handle(&self, envelope: Envelope<EraseSubjectDataCommand>) -> Result<(), Self::Error> {
+        let cmd = &envelope.payload;
+        let account_id = AccountId::try_from(cmd.account_id.as_str())?;
+
+        let mut ids = HashSet::new();
+        for raw in &cmd.profile_ids {
+            ids.insert(ProfileId::try_from(raw.as_str())?);
+        }
+        let mut page_token: Option<String> = None;
+        loop {
+            let (page, next) = self
+                .repo
+                .list_by_account(&account_id, ERASURE_PAGE_SIZE, page_token.as_deref())
+                .await?;
+            ids.extend(page.into_iter().map(|summary| summary.profile_id));
+            match next {
+                Some(token) => page_token = Some(token),
+                None => break,
+            }
+        }
+
+        for id in ids {
+            let Some(mut profile) = self.repo.find_by_id(&id).await? else {
+                continue;
+            };
+            // The snapshot is account's word; never erase someone else's profile.
+            if profile.account_id() != account_id {
+                continue;
+            }
+            let was_live = profile.deleted_at().is_none();
+            if !profile.erase(envelope.correlation_id)? {
+                continue;
+            }
+            let events = profile.drain_events();
+            self.repo.save(&profile, &events).await?;
+
+            // A live profile's handle enters the reservation window as on a
+            // plain delete; a deleted one's is already tombstoned.
+            if was_live {
+                self.repo.tombstone_handle(profile.handle()).await?;
+            }
+            self.repo.delete_account_index(&profile).await?;
+
+            let _ = self.cache.invalidate_by_id(&id).await;
+            let _ = self.cache.invalidate_handle(profile.handle().as_str()).await;
+        }
+        let _ = self.cache.invalidate_account_profiles(&account_id).await;
+
+        Ok(())
+    }
+}
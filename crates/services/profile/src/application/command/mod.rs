@@ -1,6 +1,7 @@
 pub mod change_handle;
 pub mod create_profile;
 pub mod delete_profile;
+pub mod erase_subject_data;
 pub mod hide_profile;
 pub mod restore_profile;
 pub mod set_tier;
@@ -13,6 +14,7 @@ pub mod verify_profile;
 pub use change_handle::{ChangeHandleCommand, ChangeHandleHandler};
 pub use create_profile::{CreateProfileCommand, CreateProfileHandler};
 pub use delete_profile::{DeleteProfileCommand, DeleteProfileHandler};
+pub use erase_subject_data::{EraseSubjectDataCommand, EraseSubjectDataHandler};
 pub use hide_profile::{HideProfileCommand, HideProfileHandler};
 pub use restore_profile::{RestoreProfileCommand, RestoreProfileHandler};
 pub use set_tier::{SetProfileTierCommand, SetProfileTierHandler};
@@ -3,7 +3,7 @@ use std::sync::Arc;
 use cqrs::{Command, CommandHandler, Envelope};
 use validate_core::{FieldViolation, Validate};
 
-use crate::application::port::{EventPublisher, ProfileCache, ProfileRepository};
+use crate::application::port::{ProfileCache, ProfileRepository};
 use crate::domain::value_object::ProfileId;
 use crate::error::ProfileError;
 
@@ -12,7 +12,9 @@ pub struct RestoreProfileCommand {
     pub profile_id: String,
 }
 
-impl Command for RestoreProfileCommand {}
+impl Command for RestoreProfileCommand {
+    type Output = ();
+}
 
 impl Validate for RestoreProfileCommand {
     fn validate(&self) -> Result<(), Vec<FieldViolation>> {
@@ -27,12 +29,11 @@ impl Validate for RestoreProfileCommand {
 pub struct RestoreProfileHandler {
     repo: Arc<dyn ProfileRepository>,
     cache: Arc<dyn ProfileCache>,
-    publisher: Arc<dyn EventPublisher>,
 }
 
 impl RestoreProfileHandler {
-    pub fn new(repo: Arc<dyn ProfileRepository>, cache: Arc<dyn ProfileCache>, publisher: Arc<dyn EventPublisher>) -> Self {
-        Self { repo, cache, publisher }
+    pub fn new(repo: Arc<dyn ProfileRepository>, cache: Arc<dyn ProfileCache>) -> Self {
+        Self { repo, cache }
     }
 }
 
@@ -50,11 +51,8 @@ impl CommandHandler<RestoreProfileCommand> for RestoreProfileHandler {
             .ok_or_else(|| ProfileError::ProfileNotFound { id: cmd.profile_id.clone() })?;
 
         profile.restore(envelope.correlation_id)?;
-        self.repo.save(&profile).await?;
-
-        for event in profile.drain_events() {
-            self.publisher.publish(&event).await?;
-        }
+        let events = profile.drain_events();
+        self.repo.save(&profile, &events).await?;
         let _ = self.cache.invalidate_by_id(&id).await;
         let _ = self.cache.invalidate_account_profiles(&profile.account_id()).await;
 
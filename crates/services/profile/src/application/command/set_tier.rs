@@ -3,7 +3,7 @@ use std::sync::Arc;
 use cqrs::{Command, CommandHandler, Envelope};
 use validate_core::{FieldViolation, Validate};
 
-use crate::application::port::{EventPublisher, ProfileCache, ProfileRepository};
+use crate::application::port::{ProfileCache, ProfileRepository};
 use crate::domain::value_object::ProfileId;
 use crate::error::ProfileError;
 
@@ -16,7 +16,9 @@ pub struct SetProfileTierCommand {
     pub tier: u8,
 }
 
-impl Command for SetProfileTierCommand {}
+impl Command for SetProfileTierCommand {
+    type Output = ();
+}
 
 impl Validate for SetProfileTierCommand {
     fn validate(&self) -> Result<(), Vec<FieldViolation>> {
@@ -34,12 +36,11 @@ impl Validate for SetProfileTierCommand {
 pub struct SetProfileTierHandler {
     repo: Arc<dyn ProfileRepository>,
     cache: Arc<dyn ProfileCache>,
-    publisher: Arc<dyn EventPublisher>,
 }
 
 impl SetProfileTierHandler {
-    pub fn new(repo: Arc<dyn ProfileRepository>, cache: Arc<dyn ProfileCache>, publisher: Arc<dyn EventPublisher>) -> Self {
-        Self { repo, cache, publisher }
+    pub fn new(repo: Arc<dyn ProfileRepository>, cache: Arc<dyn ProfileCache>) -> Self {
+        Self { repo, cache }
     }
 }
 
@@ -59,16 +60,13 @@ impl CommandHandler<SetProfileTierCommand> for SetProfileTierHandler {
         profile.set_tier(cmd.tier, envelope.correlation_id)?;
 
         // Idempotent: an unchanged tier produces no event (and no version bump), so
-        // there is nothing to persist — skip the LWT UPDATE entirely.
+        // there is nothing to persist — skip the version claim and UPDATE entirely.
         let events = profile.drain_events();
         if events.is_empty() {
             return Ok(());
         }
 
-        self.repo.save(&profile).await?;
-        for event in &events {
-            self.publisher.publish(event).await?;
-        }
+        self.repo.save(&profile, &events).await?;
         let _ = self.cache.invalidate_by_id(&id).await;
 
         Ok(())
@@ -3,7 +3,7 @@ use std::sync::Arc;
 use cqrs::{Command, CommandHandler, Envelope};
 use validate_core::{FieldViolation, Validate};
 
-use crate::application::port::{EventPublisher, ProfileCache, ProfileRepository};
+use crate::application::port::{ProfileCache, ProfileRepository};
 use crate::domain::value_object::{ProfileId, ProfileVisibility};
 use crate::error::ProfileError;
 
@@ -13,7 +13,9 @@ pub struct SetVisibilityCommand {
     pub visibility: String,
 }
 
-impl Command for SetVisibilityCommand {}
+impl Command for SetVisibilityCommand {
+    type Output = ();
+}
 
 impl Validate for SetVisibilityCommand {
     fn validate(&self) -> Result<(), Vec<FieldViolation>> {
@@ -31,12 +33,11 @@ impl Validate for SetVisibilityCommand {
 pub struct SetVisibilityHandler {
     repo: Arc<dyn ProfileRepository>,
     cache: Arc<dyn ProfileCache>,
-    publisher: Arc<dyn EventPublisher>,
 }
 
 impl SetVisibilityHandler {
-    pub fn new(repo: Arc<dyn ProfileRepository>, cache: Arc<dyn ProfileCache>, publisher: Arc<dyn EventPublisher>) -> Self {
-        Self { repo, cache, publisher }
+    pub fn new(repo: Arc<dyn ProfileRepository>, cache: Arc<dyn ProfileCache>) -> Self {
+        Self { repo, cache }
     }
 }
 
@@ -55,11 +56,8 @@ impl CommandHandler<SetVisibilityCommand> for SetVisibilityHandler {
             .ok_or_else(|| ProfileError::ProfileNotFound { id: cmd.profile_id.clone() })?;
 
         profile.set_visibility(visibility, envelope.correlation_id)?;
-        self.repo.save(&profile).await?;
-
-        for event in profile.drain_events() {
-            self.publisher.publish(&event).await?;
-        }
+        let events = profile.drain_events();
+        self.repo.save(&profile, &events).await?;
         let _ = self.cache.invalidate_by_id(&id).await;
         let _ = self.cache.invalidate_account_profiles(&profile.account_id()).await;
 
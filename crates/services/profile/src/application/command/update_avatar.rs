@@ -3,7 +3,7 @@ use std::sync::Arc;
 use cqrs::{Command, CommandHandler, Envelope};
 use validate_core::{FieldViolation, Validate};
 
-use crate::application::port::{EventPublisher, ProfileCache, ProfileRepository};
+use crate::application::port::{ProfileCache, ProfileRepository};
 use crate::domain::value_object::{AvatarUrl, ProfileId};
 use crate::error::ProfileError;
 
@@ -14,7 +14,9 @@ pub struct UpdateAvatarCommand {
     pub avatar_url: Option<String>,
 }
 
-impl Command for UpdateAvatarCommand {}
+impl Command for UpdateAvatarCommand {
+    type Output = ();
+}
 
 impl Validate for UpdateAvatarCommand {
     fn validate(&self) -> Result<(), Vec<FieldViolation>> {
@@ -29,12 +31,11 @@ impl Validate for UpdateAvatarCommand {
 pub struct UpdateAvatarHandler {
     repo: Arc<dyn ProfileRepository>,
     cache: Arc<dyn ProfileCache>,
-    publisher: Arc<dyn EventPublisher>,
 }
 
 impl UpdateAvatarHandler {
-    pub fn new(repo: Arc<dyn ProfileRepository>, cache: Arc<dyn ProfileCache>, publisher: Arc<dyn EventPublisher>) -> Self {
-        Self { repo, cache, publisher }
+    pub fn new(repo: Arc<dyn ProfileRepository>, cache: Arc<dyn ProfileCache>) -> Self {
+        Self { repo, cache }
     }
 }
 
@@ -57,11 +58,8 @@ impl CommandHandler<UpdateAvatarCommand> for UpdateAvatarHandler {
         };
 
         profile.update_avatar(url, envelope.correlation_id)?;
-        self.repo.save(&profile).await?;
-
-        for event in profile.drain_events() {
-            self.publisher.publish(&event).await?;
-        }
+        let events = profile.drain_events();
+        self.repo.save(&profile, &events).await?;
         let _ = self.cache.invalidate_by_id(&id).await;
         let _ = self.cache.invalidate_account_profiles(&profile.account_id()).await;
 
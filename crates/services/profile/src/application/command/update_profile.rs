@@ -3,7 +3,7 @@ use std::sync::Arc;
 use cqrs::{Command, CommandHandler, Envelope};
 use validate_core::{FieldViolation, Validate};
 
-use crate::application::port::{EventPublisher, ProfileCache, ProfileRepository};
+use crate::application::port::{ProfileCache, ProfileRepository};
 use crate::domain::entity::ProfileLink;
 use crate::domain::value_object::{Bio, DisplayName, Locale, ProfileId, WebsiteUrl};
 use crate::error::ProfileError;
@@ -19,7 +19,9 @@ pub struct UpdateProfileCommand {
     pub custom_links: Vec<(String, String)>,
 }
 
-impl Command for UpdateProfileCommand {}
+impl Command for UpdateProfileCommand {
+    type Output = ();
+}
 
 impl Validate for UpdateProfileCommand {
     fn validate(&self) -> Result<(), Vec<FieldViolation>> {
@@ -37,12 +39,11 @@ impl Validate for UpdateProfileCommand {
 pub struct UpdateProfileHandler {
     repo: Arc<dyn ProfileRepository>,
     cache: Arc<dyn ProfileCache>,
-    publisher: Arc<dyn EventPublisher>,
 }
 
 impl UpdateProfileHandler {
-    pub fn new(repo: Arc<dyn ProfileRepository>, cache: Arc<dyn ProfileCache>, publisher: Arc<dyn EventPublisher>) -> Self {
-        Self { repo, cache, publisher }
+    pub fn new(repo: Arc<dyn ProfileRepository>, cache: Arc<dyn ProfileCache>) -> Self {
+        Self { repo, cache }
     }
 }
 
@@ -81,11 +82,8 @@ impl CommandHandler<UpdateProfileCommand> for UpdateProfileHandler {
 
         profile.update(display_name, bio, website_url, locale, custom_links, envelope.correlation_id)?;
 
-        self.repo.save(&profile).await?;
-
-        for event in profile.drain_events() {
-            self.publisher.publish(&event).await?;
-        }
+        let events = profile.drain_events();
+        self.repo.save(&profile, &events).await?;
         let _ = self.cache.invalidate_by_id(&id).await;
         let _ = self.cache.invalidate_account_profiles(&profile.account_id()).await;
 
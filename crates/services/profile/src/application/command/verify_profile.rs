@@ -3,7 +3,7 @@ use std::sync::Arc;
 use cqrs::{Command, CommandHandler, Envelope};
 use validate_core::{FieldViolation, Validate};
 
-use crate::application::port::{EventPublisher, ProfileCache, ProfileRepository};
+use crate::application::port::{ProfileCache, ProfileRepository};
 use crate::domain::value_object::{ProfileId, VerificationKind};
 use crate::error::ProfileError;
 
@@ -13,7 +13,9 @@ pub struct VerifyProfileCommand {
     pub verification_kind: String,
 }
 
-impl Command for VerifyProfileCommand {}
+impl Command for VerifyProfileCommand {
+    type Output = ();
+}
 
 impl Validate for VerifyProfileCommand {
     fn validate(&self) -> Result<(), Vec<FieldViolation>> {
@@ -31,12 +33,11 @@ impl Validate for VerifyProfileCommand {
 pub struct VerifyProfileHandler {
     repo: Arc<dyn ProfileRepository>,
     cache: Arc<dyn ProfileCache>,
-    publisher: Arc<dyn EventPublisher>,
 }
 
 impl VerifyProfileHandler {
-    pub fn new(repo: Arc<dyn ProfileRepository>, cache: Arc<dyn ProfileCache>, publisher: Arc<dyn EventPublisher>) -> Self {
-        Self { repo, cache, publisher }
+    pub fn new(repo: Arc<dyn ProfileRepository>, cache: Arc<dyn ProfileCache>) -> Self {
+        Self { repo, cache }
     }
 }
 
@@ -55,11 +56,8 @@ impl CommandHandler<VerifyProfileCommand> for VerifyProfileHandler {
             .ok_or_else(|| ProfileError::ProfileNotFound { id: cmd.profile_id.clone() })?;
 
         profile.verify(kind, envelope.correlation_id)?;
-        self.repo.save(&profile).await?;
-
-        for event in profile.drain_events() {
-            self.publisher.publish(&event).await?;
-        }
+        let events = profile.drain_events();
+        self.repo.save(&profile, &events).await?;
         let _ = self.cache.invalidate_by_id(&id).await;
 
         Ok(())
@@ -1,7 +1,5 @@
-pub mod event_publisher;
 pub mod profile_cache;
 pub mod profile_repository;
 
-pub use event_publisher::EventPublisher;
 pub use profile_cache::{ProfileCache, ProfileLinkView, ProfileView};
 pub use profile_repository::{ProfileRepository, ProfileSummary};
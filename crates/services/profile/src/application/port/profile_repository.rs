@@ -2,6 +2,7 @@ use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 
 use crate::domain::aggregate::Profile;
+use crate::domain::event::DomainEvent;
 use crate::domain::value_object::{AccountId, Handle, ProfileId};
 use crate::error::ProfileError;
 
@@ -29,14 +30,19 @@ pub struct ProfileSummary {
 /// - `profile.profile_handles` — globally unique handle → profile_id index with LWT semantics.
 ///
 /// Implementations must enforce handle uniqueness via ScyllaDB LWT (`IF NOT EXISTS`) and
-/// optimistic concurrency on the main table via LWT (`IF version = ?`).
+/// optimistic concurrency on the main table via an LWT claim on the next version
+/// (`profile.profile_versions`, `IF NOT EXISTS`).
 #[async_trait]
 pub trait ProfileRepository: Send + Sync + 'static {
-    /// Persist the profile aggregate.
+    /// Persist the profile aggregate together with the events the change
+    /// produced: the row and one `profile.outbox` row per event are written in
+    /// one logged batch, so the events land exactly when the write does.
     ///
-    /// Version == 0 triggers an INSERT; any other version triggers a LWT UPDATE.
-    /// Returns [`ProfileError::ConcurrentModification`] when the LWT is not applied.
-    async fn save(&self, profile: &Profile) -> Result<(), ProfileError>;
+    /// Version == 0 triggers an INSERT; any other version first claims that
+    /// version via LWT, then UPDATEs; the claim is released again when the batch
+    /// fails, so a retry can take it. Returns
+    /// [`ProfileError::ConcurrentModification`] when the claim is not applied.
+    async fn save(&self, profile: &Profile, events: &[DomainEvent]) -> Result<(), ProfileError>;
 
     async fn find_by_id(&self, id: &ProfileId) -> Result<Option<Profile>, ProfileError>;
 
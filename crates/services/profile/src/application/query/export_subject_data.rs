@@ -0,0 +1,73 @@
+use std::sync::Arc;
+
+use cqrs::{Envelope, Query, QueryHandler};
+use serde::Serialize;
+
+use crate::application::port::{ProfileRepository, ProfileView};
+use crate::domain::value_object::AccountId;
+use crate::error::ProfileError;
+
+/// Page size used to walk `profiles_by_account` during an export.
+const EXPORT_PAGE_SIZE: i32 = 100;
+
+/// GDPR Art. 20 export of every profile the account owns, read from the
+/// repository (never the cache) so the subject gets the system-of-record state.
+#[derive(Debug, Clone)]
+pub struct ExportSubjectDataQuery {
+    pub account_id: String,
+}
+
+/// This service's slice of a data-subject export.
+#[derive(Debug, Clone, Serialize)]
+pub struct SubjectDataExport {
+    pub profiles: Vec<ProfileView>,
+}
+
+impl SubjectDataExport {
+    pub fn record_count(&self) -> u32 {
+        self.profiles.len() as u32
+    }
+}
+
+impl Query for ExportSubjectDataQuery {
+    type Response = SubjectDataExport;
+}
+
+pub struct ExportSubjectDataHandler {
+    repo: Arc<dyn ProfileRepository>,
+}
+
+impl ExportSubjectDataHandler {
+    pub fn new(repo: Arc<dyn ProfileRepository>) -> Self {
+        Self { repo }
+    }
+}
+
+impl QueryHandler<ExportSubjectDataQuery> for ExportSubjectDataHandler {
+    type Error = ProfileError;
+
+    async fn handle(&self, envelope: Envelope<ExportSubjectDataQuery>) -> Result<SubjectDataExport, Self::Error> {
+        let account_id = AccountId::try_from(envelope.payload.account_id.as_str())?;
+
+        let mut profiles = Vec::new();
+        let mut page_token: Option<String> = None;
+        loop {
+            let (page, next) = self
+                .repo
+                .list_by_account(&account_id, EXPORT_PAGE_SIZE, page_token.as_deref())
+                .await?;
+            for summary in page {
+                // The index row may briefly outlive a deleted profile; skip it.
+                if let Some(profile) = self.repo.find_by_id(&summary.profile_id).await? {
+                    profiles.push(ProfileView::from(&profile));
+                }
+            }
+            match next {
+                Some(token) => page_token = Some(token),
+                None => break,
+            }
+        }
+
+        Ok(SubjectDataExport { profiles })
+    }
+}
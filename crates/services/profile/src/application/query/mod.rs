@@ -1,7 +1,9 @@
+pub mod export_subject_data;
 pub mod get_profile_by_handle;
 pub mod get_profile_by_id;
 pub mod list_profiles_by_account;
 
+pub use export_subject_data::{ExportSubjectDataHandler, ExportSubjectDataQuery, SubjectDataExport};
 pub use get_profile_by_handle::{GetProfileByHandleHandler, GetProfileByHandleQuery};
 pub use get_profile_by_id::{GetProfileByIdHandler, GetProfileByIdQuery};
 pub use list_profiles_by_account::{ListProfilesByAccountHandler, ListProfilesByAccountQuery};
@@ -13,6 +13,9 @@ use crate::domain::value_object::{
 };
 use crate::error::ProfileError;
 
+/// Display name a profile is pseudonymised to by [`Profile::erase`].
+pub const ERASED_DISPLAY_NAME: &str = "Deleted profile";
+
 pub struct ProfileCreateParams {
     pub account_id: AccountId,
     pub handle: Handle,
@@ -351,6 +354,42 @@ impl Profile {
         Ok(())
     }
 
+    /// GDPR erasure: deletes the profile if it is still live, then strips every
+    /// field the person authored. The row survives only as a pseudonymous
+    /// tombstone (id, account, handle for the reservation window). Returns
+    /// `false` when the profile was already erased, so a redelivery writes nothing.
+    pub fn erase(&mut self, correlation_id: Uuid) -> Result<bool, ProfileError> {
+        if self.is_erased() {
+            return Ok(false);
+        }
+        if self.status != ProfileStatus::Deleted {
+            self.delete(correlation_id)?;
+        } else {
+            self.touch_now();
+        }
+        self.display_name = DisplayName::new(ERASED_DISPLAY_NAME)?;
+        self.bio = None;
+        self.avatar_url = None;
+        self.banner_url = None;
+        self.website_url = None;
+        self.custom_links.clear();
+        self.timezone = None;
+        self.suspension_reason = None;
+        Ok(true)
+    }
+
+    fn is_erased(&self) -> bool {
+        self.status == ProfileStatus::Deleted
+            && self.display_name.as_str() == ERASED_DISPLAY_NAME
+            && self.bio.is_none()
+            && self.avatar_url.is_none()
+            && self.banner_url.is_none()
+            && self.website_url.is_none()
+            && self.custom_links.is_empty()
+            && self.timezone.is_none()
+            && self.suspension_reason.is_none()
+    }
+
     /// Set the author tier (denormalized from `social-graph.author_tier_changed`).
     /// Idempotent: an unchanged tier is a no-op that emits nothing. A new tier is
     /// persisted and re-emitted on `profile.v1.events` (`TierChanged`). Values
@@ -490,6 +529,25 @@ mod tests {
         assert_eq!(p.version(), version_before);
     }
 
+    #[test]
+    fn erase_deletes_and_pseudonymises_once() {
+        let mut p = sample_profile();
+        p.update_avatar(Some(AvatarUrl::new("https://cdn.example.com/a.png").unwrap()), Uuid::now_v7())
+            .unwrap();
+        p.drain_events();
+
+        assert!(p.erase(Uuid::now_v7()).unwrap());
+        assert_eq!(p.status(), ProfileStatus::Deleted);
+        assert_eq!(p.display_name().as_str(), ERASED_DISPLAY_NAME);
+        assert!(p.avatar_url().is_none());
+        let events = p.drain_events();
+        assert!(matches!(events.as_slice(), [DomainEvent::ProfileDeleted(_)]));
+
+        let version = p.version();
+        assert!(!p.erase(Uuid::now_v7()).unwrap());
+        assert_eq!(p.version(), version);
+    }
+
     #[test]
     fn set_tier_rejects_unknown_tier() {
         let mut p = sample_profile();
@@ -1,38 +1,50 @@
 use std::sync::Arc;
 
+use chrono::{DateTime, Utc};
 use serde::Deserialize;
 use tracing::{error, info};
 use uuid::Uuid;
 
 use cqrs::{CommandBus, Envelope};
 use error::AppError;
+use subject_erasure::{erase_and_confirm, DeletionRequest, EraseCommand, ErasureReporter};
 use transport::kafka::consumer::{
     run_consumer, KafkaConsumerHandle, ProcessOutcome, RetryPolicy,
 };
 use transport::kafka::producer::KafkaProducerHandle;
 
-use crate::application::command::{HideProfileCommand, RestoreProfileCommand};
+use crate::application::command::{EraseSubjectDataCommand, HideProfileCommand, RestoreProfileCommand};
 
 /// Kafka event payload published by the account service on `account.v1.events`.
 ///
-/// Only the fields relevant to profile masking/restoration are deserialized.
-/// Unknown event kinds are silently ignored.
+/// Only the fields relevant to profile masking/restoration and GDPR erasure are
+/// deserialized. Unknown event kinds are silently ignored. `kind` is account's
+/// `type` tag (snake_case, e.g. `account_suspended`).
 #[derive(Debug, Deserialize)]
-struct AccountEvent {
-    #[serde(rename = "event_kind")]
+pub struct AccountEvent {
+    #[serde(rename = "type")]
     kind: String,
     account_id: String,
     #[serde(default)]
     reason: Option<String>,
+    /// Identifies the deletion request on `gdpr_deletion_requested`.
+    #[serde(default)]
+    requested_at: Option<DateTime<Utc>>,
+    /// The subject's profiles as account resolved them for the deletion request.
+    #[serde(default)]
+    profile_ids: Vec<String>,
 }
 
 /// Runs the account event consumer on the shared at-least-once runner.
 ///
 /// Subscribes (via the supplied handle) to `account.v1.events` and translates
-/// account lifecycle events into profile masking/restoration commands. The runner
-/// owns the decode → process → retry → dead-letter → commit loop: transient
-/// dispatch failures are retried with backoff then dead-lettered, poison records
-/// are dead-lettered immediately, and unknown event kinds are committed as no-ops.
+/// account lifecycle events into profile masking/restoration commands, and
+/// `gdpr_deletion_requested` into profile's part of a GDPR erasure, run through
+/// the shared [`subject_erasure::erase_and_confirm`] and confirmed through
+/// `reporter`. The runner owns the decode → process → retry → dead-letter →
+/// commit loop: transient dispatch failures are retried with backoff then
+/// dead-lettered, poison records are dead-lettered immediately, and unknown
+/// event kinds are committed as no-ops.
 ///
 /// The function is generic over `CB` because `CommandBus` is not object-safe
 /// (its `dispatch` method is generic over `C: Command`). The handle must be built
@@ -41,6 +53,7 @@ struct AccountEvent {
 pub async fn run_account_event_consumer<CB: CommandBus + 'static>(
     consumer: KafkaConsumerHandle,
     command_bus: CB,
+    reporter: Arc<dyn ErasureReporter>,
     producer: KafkaProducerHandle,
 ) {
     info!("account event consumer started");
@@ -52,7 +65,8 @@ pub async fn run_account_event_consumer<CB: CommandBus + 'static>(
 
     let result = run_consumer::<AccountEvent, _>(&consumer, &producer, &policy, move |event| {
         let command_bus = Arc::clone(&command_bus);
-        Box::pin(async move { process_event(command_bus.as_ref(), event).await })
+        let reporter = Arc::clone(&reporter);
+        Box::pin(async move { process_event(&command_bus, reporter.as_ref(), event).await })
     })
     .await;
 
@@ -65,12 +79,16 @@ pub async fn run_account_event_consumer<CB: CommandBus + 'static>(
 /// the result. Unknown event kinds are intentional no-ops (`Done`, so they commit
 /// rather than dead-letter); a transient dispatch failure is retried then
 /// dead-lettered, and a permanent one is dead-lettered immediately.
-async fn process_event<CB: CommandBus>(command_bus: &CB, event: &AccountEvent) -> ProcessOutcome {
+async fn process_event<CB: CommandBus + 'static>(
+    command_bus: &Arc<CB>,
+    reporter: &dyn ErasureReporter,
+    event: &AccountEvent,
+) -> ProcessOutcome {
     let correlation_id = Uuid::now_v7();
 
     let dispatch = match event.kind.as_str() {
-        "AccountSuspended" | "AccountDeleted" => {
-            let masking_reason = if event.kind == "AccountDeleted" {
+        "account_suspended" | "account_deleted" => {
+            let masking_reason = if event.kind == "account_deleted" {
                 "account_deleted"
             } else {
                 "account_suspended"
@@ -87,13 +105,31 @@ async fn process_event<CB: CommandBus>(command_bus: &CB, event: &AccountEvent) -
             command_bus.dispatch(Envelope::new(correlation_id, cmd)).await
         }
 
-        "AccountActivated" | "AccountReactivated" => {
+        "account_activated" => {
             let cmd = RestoreProfileCommand {
                 profile_id: event.account_id.clone(),
             };
             command_bus.dispatch(Envelope::new(correlation_id, cmd)).await
         }
 
+        "gdpr_deletion_requested" => {
+            let Some(requested_at) = event.requested_at else {
+                return ProcessOutcome::Reject("gdpr_deletion_requested without requested_at".to_owned());
+            };
+            let request = DeletionRequest {
+                account_id:  event.account_id.clone(),
+                requested_at,
+                profile_ids: event.profile_ids.clone(),
+            };
+            let eraser = EraseCommand::new(Arc::clone(command_bus), |request: &DeletionRequest| {
+                EraseSubjectDataCommand {
+                    account_id:  request.account_id.clone(),
+                    profile_ids: request.profile_ids.clone(),
+                }
+            });
+            return erase_and_confirm(&eraser, reporter, &request).await;
+        }
+
         other => {
             tracing::trace!(event_kind = other, "ignoring unknown account event kind");
             return ProcessOutcome::Done;
@@ -14,7 +14,7 @@ use crate::application::command::SetProfileTierCommand;
 /// Kafka payload on `social-graph.author_tier_changed` (produced by social-graph
 /// when a follow/unfollow crosses a follower-count tier boundary).
 #[derive(Debug, Deserialize)]
-struct AuthorTierChangedEvent {
+pub struct AuthorTierChangedEvent {
     profile_id: String,
     /// 0=Standard, 1=Premium, 2=Vip.
     new_tier: u8,
@@ -11,7 +11,8 @@ use crate::application::command::{
 };
 use crate::application::port::{ProfileSummary, ProfileView};
 use crate::application::query::{
-    GetProfileByHandleQuery, GetProfileByIdQuery, ListProfilesByAccountQuery,
+    ExportSubjectDataQuery, GetProfileByHandleQuery, GetProfileByIdQuery,
+    ListProfilesByAccountQuery, SubjectDataExport,
 };
 
 // ── Proto inclusion ───────────────────────────────────────────────────────────
@@ -301,6 +302,26 @@ where
             next_page_token: next_page_token.unwrap_or_default(),
         }))
     }
+
+    pub async fn export_subject_data(
+        &self,
+        request: Request<proto::ExportSubjectDataRequest>,
+    ) -> Result<Response<proto::ExportSubjectDataResponse>, Status> {
+        let req = request.into_inner();
+        let query = ExportSubjectDataQuery { account_id: req.account_id };
+        let export: SubjectDataExport = self
+            .query_bus
+            .dispatch(Envelope::new(Uuid::now_v7(), query))
+            .await
+            .map_err(cqrs_error_to_status)?;
+
+        let document = serde_json::to_vec(&export)
+            .map_err(|e| Status::internal(format!("encode export document: {e}")))?;
+        Ok(Response::new(proto::ExportSubjectDataResponse {
+            document,
+            record_count: export.record_count(),
+        }))
+    }
 }
 
 // ── Proto conversion helpers ──────────────────────────────────────────────────
@@ -109,4 +109,11 @@ where
     ) -> Result<Response<proto::ListProfilesByAccountResponse>, Status> {
         self.list_profiles_by_account(request).await
     }
+
+    async fn export_subject_data(
+        &self,
+        request: Request<proto::ExportSubjectDataRequest>,
+    ) -> Result<Response<proto::ExportSubjectDataResponse>, Status> {
+        self.export_subject_data(request).await
+    }
 }
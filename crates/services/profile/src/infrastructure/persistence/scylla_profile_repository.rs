@@ -14,9 +14,12 @@ use scylla_storage::{ProfileKind as ScyllaProfileKind, ScyllaClient, ScyllaStora
 use crate::application::port::{ProfileRepository, ProfileSummary};
 use crate::domain::aggregate::Profile;
 use crate::domain::entity::ProfileLink;
+use crate::domain::event::DomainEvent;
 use crate::domain::value_object::{AccountId, Handle, ProfileId};
 use crate::error::ProfileError;
 use crate::infrastructure::persistence::model::ProfileRow;
+use crate::infrastructure::publisher::profile_outbox::outbox_err;
+use crate::infrastructure::publisher::ProfileOutbox;
 
 // ── Page-token type ───────────────────────────────────────────────────────────
 
@@ -60,16 +63,11 @@ struct ProfileInsert {
     tier:              i8,
 }
 
-/// Values for the 21-column LWT UPDATE of `profile.profiles`.
+/// Values for the 21-column UPDATE of `profile.profiles`.
 ///
-/// Field order matches the SET clause then WHERE + IF clause.
+/// Field order matches the SET clause then the WHERE clause.
 #[derive(SerializeRow)]
-// skip_name_checks is REQUIRED with enforce_order here: binding is positional
-// but the driver still name-checks by default, and these field names diverge
-// from the bind markers on purpose (`version` appears twice in the statement —
-// SET's new value vs IF's expected value — so the fields are new_version /
-// expected_version). Same class as the soak's social-graph FollowRow finding.
-#[scylla(flavor = "enforce_order", skip_name_checks)]
+#[scylla(flavor = "enforce_order")]
 struct ProfileUpdate {
     handle:            String,
     display_name:      String,
@@ -90,9 +88,8 @@ struct ProfileUpdate {
     updated_at:        CqlTimestamp,
     deleted_at:        Option<CqlTimestamp>,
     tier:              i8,
-    new_version:       i64,
+    version:           i64,
     profile_id:        Uuid,
-    expected_version:  i64,
 }
 
 // ── Error helpers ─────────────────────────────────────────────────────────────
@@ -132,11 +129,12 @@ fn row_err(ctx: &'static str, e: impl ToString) -> ProfileError {
 
 pub struct ScyllaProfileRepository {
     client: Arc<ScyllaClient>,
+    outbox: ProfileOutbox,
 }
 
 impl ScyllaProfileRepository {
-    pub fn new(client: Arc<ScyllaClient>) -> Self {
-        Self { client }
+    pub fn new(client: Arc<ScyllaClient>, outbox: ProfileOutbox) -> Self {
+        Self { client, outbox }
     }
 
     fn fast_stmt(&self, cql: &str) -> Statement {
@@ -165,6 +163,26 @@ impl ScyllaProfileRepository {
         s
     }
 
+    /// Best-effort release of the version claim taken by a `save` whose batch
+    /// failed. Conditional like the claim, so both go through Paxos; if it fails
+    /// too, the claim's TTL frees the version.
+    async fn release_version_claim(&self, profile: &Profile) {
+        let release = self.strict_stmt(
+            "DELETE FROM profile.profile_versions WHERE profile_id = ? AND version = ? IF EXISTS",
+        );
+        if let Err(e) = self.client.session
+            .execute_unpaged(release, (profile.id().as_uuid(), profile.version()))
+            .await
+        {
+            tracing::warn!(
+                profile_id = %profile.id(),
+                version = profile.version(),
+                error = %e,
+                "profile version claim not released",
+            );
+        }
+    }
+
     fn dt_ms(dt: chrono::DateTime<Utc>) -> CqlTimestamp {
         CqlTimestamp(dt.timestamp_millis())
     }
@@ -182,7 +200,8 @@ impl ScyllaProfileRepository {
 
 #[async_trait]
 impl ProfileRepository for ScyllaProfileRepository {
-    async fn save(&self, profile: &Profile) -> Result<(), ProfileError> {
+    async fn save(&self, profile: &Profile, events: &[DomainEvent]) -> Result<(), ProfileError> {
+        let mut batch = self.outbox.batch();
         if profile.version() == 0 {
             let stmt = self.strict_stmt(
                 "INSERT INTO profile.profiles \
@@ -218,9 +237,27 @@ impl ProfileRepository for ScyllaProfileRepository {
                 deleted_at:        profile.deleted_at().map(Self::dt_ms),
                 tier:              profile.tier() as i8,
             };
-            self.client.session.execute_unpaged(stmt, values).await.map_err(scylla_err)?;
+            batch.write(stmt, values);
         } else {
-            // LWT UPDATE: `version()` was already incremented by `touch()`.
+            // `version()` was already incremented by `touch()`. Claim it first:
+            // the conditional check cannot join the logged batch below, and the
+            // claim row is what makes a second writer that loaded the same
+            // version lose.
+            let claim = self.strict_stmt(
+                "INSERT INTO profile.profile_versions (profile_id, version) \
+                 VALUES (?, ?) IF NOT EXISTS",
+            );
+            let result = self.client.session
+                .execute_unpaged(claim, (profile.id().as_uuid(), profile.version()))
+                .await.map_err(scylla_err)?;
+            let applied = lwt_applied(
+                result.into_rows_result().map_err(|e| row_err("save_claim_rows", e))?,
+                "save_claim_deser",
+            )?;
+            if !applied {
+                return Err(ProfileError::ConcurrentModification);
+            }
+
             let stmt = self.strict_stmt(
                 "UPDATE profile.profiles \
                  SET handle = ?, display_name = ?, bio = ?, avatar_url = ?, banner_url = ?, \
@@ -228,8 +265,7 @@ impl ProfileRepository for ScyllaProfileRepository {
                      verification_kind = ?, locale = ?, timezone = ?, status = ?, \
                      suspension_reason = ?, masked_at = ?, masking_reason = ?, \
                      updated_at = ?, deleted_at = ?, tier = ?, version = ? \
-                 WHERE profile_id = ? \
-                 IF version = ?",
+                 WHERE profile_id = ?",
             );
             let values = ProfileUpdate {
                 handle:            profile.handle().as_str().to_owned(),
@@ -251,21 +287,20 @@ impl ProfileRepository for ScyllaProfileRepository {
                 updated_at:        Self::dt_ms(profile.updated_at()),
                 deleted_at:        profile.deleted_at().map(Self::dt_ms),
                 tier:              profile.tier() as i8,
-                new_version:       profile.version(),
+                version:           profile.version(),
                 profile_id:        profile.id().as_uuid(),
-                expected_version:  profile.version() - 1,
             };
-            let result = self.client.session
-                .execute_unpaged(stmt, values).await.map_err(scylla_err)?;
-            let applied = lwt_applied(
-                result.into_rows_result().map_err(|e| row_err("save_ltw_rows", e))?,
-                "save_ltw_deser",
-            )?;
-            if !applied {
-                return Err(ProfileError::ConcurrentModification);
-            }
+            batch.write(stmt, values);
         }
-        Ok(())
+        self.outbox.enqueue(&mut batch, events)?;
+        let written = batch.execute().await.map_err(outbox_err);
+        if written.is_err() && profile.version() != 0 {
+            // The row is still at the previous version, so a retry claims this
+            // one again: release it rather than fail every retry with
+            // ConcurrentModification until the claim's TTL runs out.
+            self.release_version_claim(profile).await;
+        }
+        written
     }
 
     async fn find_by_id(&self, id: &ProfileId) -> Result<Option<Profile>, ProfileError> {
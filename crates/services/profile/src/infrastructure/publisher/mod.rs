@@ -1,24 +1,10 @@
 //! Outbound event publishing for `profile.v1.events`.
 
-pub mod kafka_event_publisher;
+pub mod profile_outbox;
 pub mod wire;
 
-pub use kafka_event_publisher::KafkaProfileEventPublisher;
+pub use profile_outbox::ProfileOutbox;
 
-use async_trait::async_trait;
-
-use crate::application::port::EventPublisher;
-use crate::domain::event::DomainEvent;
-use crate::error::ProfileError;
-
-/// A no-op publisher for broker-free composition (tests, no-Kafka deployments).
-/// Drained events are dropped — the durable write in the system of record is the
-/// source of truth.
-pub struct NoopEventPublisher;
-
-#[async_trait]
-impl EventPublisher for NoopEventPublisher {
-    async fn publish(&self, _event: &DomainEvent) -> Result<(), ProfileError> {
-        Ok(())
-    }
-}
+/// The keyspace whose `outbox` / `outbox_lease` / `outbox_member` tables carry
+/// profile's pending events.
+pub const OUTBOX_KEYSPACE: &str = "profile";
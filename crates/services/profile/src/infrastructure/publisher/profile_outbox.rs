@@ -0,0 +1,68 @@
+//! Profile's events as `profile.outbox` rows.
+//!
+//! The repository writes each event into the same logged batch as the profile
+//! row that produced it, so an acknowledged save always carries its event and a
+//! failed one carries none; the shared [`outbox::ScyllaOutboxRelay`] forwards the
+//! rows to Kafka. A broker outage no longer fails a written profile, and `post`
+//! never misses the tier change of a profile that was written.
+
+use outbox::{OutboxError, OutboxMessage, ScyllaOutbox, ScyllaOutboxBatch};
+
+use super::wire::ProfileEventWire;
+use crate::domain::event::DomainEvent;
+use crate::error::ProfileError;
+
+/// The single versioned topic carrying every profile lifecycle event
+/// (moderation-service convention), keyed by `profile_id`.
+const TOPIC: &str = "profile.v1.events";
+
+/// A failed batch is a failed save — the profile row was in it; only a payload
+/// that cannot serialize is reported as a publish failure.
+pub(crate) fn outbox_err(e: OutboxError) -> ProfileError {
+    match e {
+        OutboxError::ScyllaStorage(e) => ProfileError::Storage(e),
+        other => ProfileError::DomainViolation {
+            field: "event_publish".to_owned(),
+            message: other.to_string(),
+        },
+    }
+}
+
+/// The `profile.outbox` handle the repository batches its writes through.
+/// Cheap to clone.
+#[derive(Clone)]
+pub struct ProfileOutbox {
+    outbox: ScyllaOutbox,
+}
+
+impl ProfileOutbox {
+    pub fn new(outbox: ScyllaOutbox) -> Self {
+        Self { outbox }
+    }
+
+    /// Starts the logged batch a repository write goes into.
+    pub(crate) fn batch(&self) -> ScyllaOutboxBatch<'_> {
+        self.outbox.batch()
+    }
+
+    /// Adds one row per event to `batch`.
+    pub(crate) fn enqueue(
+        &self,
+        batch: &mut ScyllaOutboxBatch<'_>,
+        events: &[DomainEvent],
+    ) -> Result<(), ProfileError> {
+        let messages = events
+            .iter()
+            .map(message)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(outbox_err)?;
+        batch.enqueue(&messages).map_err(outbox_err)?;
+        Ok(())
+    }
+}
+
+fn message(event: &DomainEvent) -> Result<OutboxMessage, OutboxError> {
+    let wire = ProfileEventWire::from(event);
+    let key = wire.profile_id().to_owned();
+    OutboxMessage::new(TOPIC, key, wire.event_type(), &wire)
+}
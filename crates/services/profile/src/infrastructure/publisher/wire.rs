@@ -131,3 +131,15 @@ impl From<&DomainEvent> for ProfileEventWire {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// `profile.v1.events` carries exactly the payload registered for it in event-topology.
+    #[test]
+    fn profile_events_conform_to_the_registered_schema() {
+        let violations = event_topology::conformance::producer_violations::<ProfileEventWire>("profile.v1.events");
+        assert!(violations.is_empty(), "{violations:#?}");
+    }
+}
@@ -7,30 +7,35 @@
 //! the account-event consumer — which is self-spawned in [`build`](ProfileService::build)
 //! (mirroring how chat spawns its workers in `App::build`), so the single runtime
 //! entrypoint needs no service-specific hooks.
+//!
+//! The same consumer runs profile's part of a GDPR erasure and confirms it to
+//! account over `ConfirmSubjectErasure` (resilience binding `account`, endpoint
+//! `PROFILE_ACCOUNT_GRPC_ENDPOINT`), through the shared [`subject_erasure`]
+//! erase-and-confirm step.
 
 use std::sync::Arc;
 use std::time::Duration;
 
 use anyhow::Context;
 use async_trait::async_trait;
-use cqrs::command::InMemoryCommandBus;
-use cqrs::query::InMemoryQueryBus;
 use infra_config::InfraRegistry;
 use redis_storage::RedisConfig;
-use scylla_storage::ScyllaConfig;
-use service_runtime::{HealthProbe, Service};
+use outbox::{KafkaOutboxSink, RelayConfig, ScyllaOutbox, ScyllaOutboxRelay, ScyllaOutboxTable};
+use scylla_storage::{ScyllaConfig, ScyllaSessionBuilder};
+use service_runtime::{AccessPolicy, HealthProbe, Service};
+use subject_erasure::{ErasureReporter, GrpcErasureReporter};
 use tonic::service::RoutesBuilder;
 use tonic_reflection::server::Builder as ReflectionBuilder;
+use transport::grpc::client::{GrpcClientBuilder, GrpcClientConfig};
 use transport::kafka::config::{ConsumerConfig, KafkaClientConfig, ProducerConfig};
 use transport::kafka::consumer::{KafkaConsumerBuilder, KafkaConsumerHandle};
 use transport::kafka::producer::{KafkaProducerBuilder, KafkaProducerHandle};
 
-use crate::app::{App, Backends};
-use crate::application::port::EventPublisher;
+use crate::app::{App, AppCommandBus, AppQueryBus};
 use crate::infrastructure::consumer::{run_account_event_consumer, run_author_tier_consumer};
 use crate::infrastructure::grpc::server::FILE_DESCRIPTOR_SET;
 use crate::infrastructure::grpc::{ProfileServiceHandler, ProfileServiceServer};
-use crate::infrastructure::publisher::KafkaProfileEventPublisher;
+use crate::infrastructure::publisher::{ProfileOutbox, OUTBOX_KEYSPACE};
 
 /// Kafka topic carrying the account lifecycle events profile reacts to.
 const ACCOUNT_EVENTS_TOPIC: &str = "account.v1.events";
@@ -40,6 +45,10 @@ const ACCOUNT_EVENTS_GROUP: &str = "profile-account-events";
 const AUTHOR_TIER_TOPIC: &str = "social-graph.author_tier_changed";
 /// Consumer group for profile's author-tier consumer.
 const AUTHOR_TIER_GROUP: &str = "profile-author-tier";
+/// Resilience binding of the erasure confirmations sent to account.
+const ACCOUNT_DEPENDENCY: &str = "account";
+/// Profile's name in account's erasure participant list.
+const ERASURE_PARTICIPANT: &str = "profile";
 /// Backoff before respawning the consumer after the runner returns.
 const CONSUMER_RESPAWN_BACKOFF: Duration = Duration::from_secs(5);
 
@@ -47,7 +56,7 @@ const CONSUMER_RESPAWN_BACKOFF: Duration = Duration::from_secs(5);
 /// which implement the bus traits), named once so the health key and reflection
 /// registration agree.
 type ProfileServer =
-    ProfileServiceServer<ProfileServiceHandler<Arc<InMemoryCommandBus>, Arc<InMemoryQueryBus>>>;
+    ProfileServiceServer<ProfileServiceHandler<Arc<AppCommandBus>, Arc<AppQueryBus>>>;
 
 /// The profile service as hosted by [`service_runtime`].
 pub struct ProfileService {
@@ -61,28 +70,54 @@ impl Service for ProfileService {
     const GRPC_SERVICE_NAME: &'static str = <ProfileServer as tonic::server::NamedService>::NAME;
 
     async fn build(infra: Arc<InfraRegistry>) -> anyhow::Result<Self> {
-        let backends = Backends {
-            scylla: ScyllaConfig::from_env(),
-            redis:  RedisConfig::from_env(),
-        };
+        let scylla = Arc::new(
+            ScyllaSessionBuilder::new(ScyllaConfig::from_env())
+                .build()
+                .await
+                .map_err(|e| anyhow::anyhow!("profile scylla session: {e}"))?,
+        );
 
         // Profile's cache TTLs are externalized: the `[cache]` section is required.
         let cache_registry = infra
             .cache()
             .context("profile requires a [cache] section in infrastructure.toml")?;
 
-        // Outbound: profile lifecycle events → `profile.v1.events`.
+        // Outbound: profile lifecycle events → `profile.outbox` → relay →
+        // `profile.v1.events`.
         let producer = KafkaProducerBuilder::new(ProducerConfig::new(KafkaClientConfig::from_env()))
             .build()
             .context("build profile event producer")?;
-        let publisher: Arc<dyn EventPublisher> = Arc::new(KafkaProfileEventPublisher::new(producer));
+        let table = ScyllaOutboxTable::new(OUTBOX_KEYSPACE)?;
+        let relay = ScyllaOutboxRelay::new(
+            Arc::clone(&scylla),
+            table.clone(),
+            Arc::new(KafkaOutboxSink::new(producer)),
+            RelayConfig::from_env(),
+        );
+        let outbox = ProfileOutbox::new(ScyllaOutbox::new(Arc::clone(&scylla), table));
 
-        let app = App::build(backends, cache_registry, publisher)
+        let app = App::assemble(scylla, RedisConfig::from_env(), cache_registry, outbox)
             .await
             .map_err(|e| anyhow::anyhow!("profile app build: {e}"))?;
-
-        // Inbound integration: account lifecycle → profile masking/restoration.
-        spawn_account_event_consumer(Arc::clone(&app.command_bus));
+        tokio::spawn(relay.run());
+
+        // Erasure confirmations connect lazily: account being down only delays
+        // them (the consumer retries), it never blocks boot.
+        let account = GrpcClientBuilder::new(
+            GrpcClientConfig::new(
+                std::env::var("PROFILE_ACCOUNT_GRPC_ENDPOINT")
+                    .unwrap_or_else(|_| "http://localhost:50059".to_owned()),
+            )
+            .with_dependency(ACCOUNT_DEPENDENCY),
+        )
+        .build_from_registry_lazy(&infra.resilience())
+        .map_err(|e| anyhow::anyhow!("profile account channel: {e}"))?;
+        let reporter: Arc<dyn ErasureReporter> =
+            Arc::new(GrpcErasureReporter::new(account, ERASURE_PARTICIPANT));
+
+        // Inbound integration: account lifecycle → profile masking/restoration
+        // and GDPR erasure.
+        spawn_account_event_consumer(Arc::clone(&app.command_bus), reporter);
         // Inbound integration: author-tier signal → denormalized profile tier.
         spawn_author_tier_consumer(Arc::clone(&app.command_bus));
 
@@ -96,6 +131,27 @@ impl Service for ProfileService {
         ]
     }
 
+    fn access_policy(&self) -> AccessPolicy {
+        // Hide/Restore follow account lifecycle events; GetProfileById also serves
+        // search hydration. Account resolves a subject's profiles and exports them
+        // for GDPR data-portability requests.
+        AccessPolicy::for_service(Self::GRPC_SERVICE_NAME)
+            .authenticated("CreateProfile")
+            .authenticated("UpdateProfile")
+            .authenticated("ChangeHandle")
+            .authenticated("UpdateAvatar")
+            .authenticated("UpdateBanner")
+            .authenticated("SetVisibility")
+            .internal("RestoreProfile")
+            .authenticated("DeleteProfile")
+            .authenticated("GetProfileByHandle")
+            .internal_or_authenticated("ListProfilesByAccount")
+            .internal_or_authenticated("GetProfileById")
+            .internal("ExportSubjectData")
+            .internal("HideProfile")
+            .require("VerifyProfile", "profile:verify")
+    }
+
     fn register(self, routes: &mut RoutesBuilder) -> anyhow::Result<()> {
         // The buses are shared behind `Arc`, which implements the bus traits.
         let handler = ProfileServiceHandler::new(
@@ -116,12 +172,18 @@ impl Service for ProfileService {
 /// Spawns the supervised account-event consumer. It rebuilds its Kafka handles
 /// and restarts after [`CONSUMER_RESPAWN_BACKOFF`] whenever the runner returns
 /// (stream end or unrecoverable broker/DLQ error), per the runner's contract.
-fn spawn_account_event_consumer(command_bus: Arc<InMemoryCommandBus>) {
+fn spawn_account_event_consumer(command_bus: Arc<AppCommandBus>, reporter: Arc<dyn ErasureReporter>) {
     tokio::spawn(async move {
         loop {
             match build_account_event_consumer() {
                 Ok((consumer, producer)) => {
-                    run_account_event_consumer(consumer, Arc::clone(&command_bus), producer).await;
+                    run_account_event_consumer(
+                        consumer,
+                        Arc::clone(&command_bus),
+                        Arc::clone(&reporter),
+                        producer,
+                    )
+                    .await;
                     tracing::warn!("account event consumer exited; respawning after backoff");
                 }
                 Err(error) => {
@@ -151,7 +213,7 @@ fn build_account_event_consumer(
 
 /// Spawns the supervised author-tier consumer (social-graph → denormalized tier),
 /// respawning after a backoff whenever the runner returns.
-fn spawn_author_tier_consumer(command_bus: Arc<InMemoryCommandBus>) {
+fn spawn_author_tier_consumer(command_bus: Arc<AppCommandBus>) {
     tokio::spawn(async move {
         loop {
             match build_author_tier_consumer() {
@@ -1,27 +1,26 @@
 //! Integration harness: boots the shared infra, wires a real profile graph
 //! against it through the production composition root, and exposes the buses plus
 //! the repository and Redis cache handles for assertions.
+//!
+//! No relay runs, so every event a scenario's writes produced stays in
+//! `profile.outbox`; [`TestHarness::events`] reads them back per profile.
 #![allow(dead_code)]
 
-use std::sync::{Arc, Mutex};
+use std::sync::Arc;
 use std::time::Duration;
 
 use uuid::Uuid;
 
-use cqrs::command::InMemoryCommandBus;
-use cqrs::query::InMemoryQueryBus;
 use cqrs::{CommandBus, CqrsError, Envelope, QueryBus};
 use redis_storage::RedisConfig;
 use infra_config::{CacheRegistry, InfrastructureConfig};
-use scylla_storage::ScyllaConfig;
+use outbox::ScyllaOutboxTable;
+use scylla_storage::{ScyllaClient, ScyllaConfig};
 
-use profile::app::{App, Backends};
+use profile::app::{App, AppCommandBus, AppQueryBus, Backends};
 use profile::application::command::{CreateProfileCommand, UpdateProfileCommand};
-use profile::application::port::{EventPublisher, ProfileCache, ProfileRepository};
+use profile::application::port::{ProfileCache, ProfileRepository};
 use profile::application::query::{GetProfileByHandleQuery, GetProfileByIdQuery};
-use profile::domain::event::DomainEvent;
-use profile::error::ProfileError;
-use profile::infrastructure::publisher::wire::ProfileEventWire;
 
 pub use profile::application::port::profile_cache::ProfileView;
 pub use profile::domain::value_object::ProfileId;
@@ -57,35 +56,13 @@ ttl_secs = 600
 "handle-lookup" = "handles"
 "#;
 
-/// Captures the `type` tag of every published profile event, so a scenario can
-/// assert the durable write emitted the right `profile.v1.events` notification.
-#[derive(Default)]
-pub struct CapturingEventPublisher {
-    events: Mutex<Vec<String>>,
-}
-
-#[async_trait::async_trait]
-impl EventPublisher for CapturingEventPublisher {
-    async fn publish(&self, event: &DomainEvent) -> Result<(), ProfileError> {
-        let kind = ProfileEventWire::from(event).event_type();
-        self.events.lock().unwrap().push(kind.to_owned());
-        Ok(())
-    }
-}
-
-impl CapturingEventPublisher {
-    pub fn published(&self) -> Vec<String> {
-        self.events.lock().unwrap().clone()
-    }
-}
-
 /// A fully-wired profile service bound to ephemeral infra, plus assertion handles.
 pub struct TestHarness {
-    pub command_bus: Arc<InMemoryCommandBus>,
-    pub query_bus:   Arc<InMemoryQueryBus>,
+    pub command_bus: Arc<AppCommandBus>,
+    pub query_bus:   Arc<AppQueryBus>,
     pub repository:  Arc<dyn ProfileRepository>,
     pub cache:       Arc<dyn ProfileCache>,
-    pub publisher:   Arc<CapturingEventPublisher>,
+    pub scylla:      Arc<ScyllaClient>,
 }
 
 impl TestHarness {
@@ -112,24 +89,41 @@ impl TestHarness {
             CacheRegistry::from_section(cache_section).expect("integration: resolve cache registry"),
         );
 
-        let publisher = Arc::new(CapturingEventPublisher::default());
-        let app = App::build(
-            backends,
-            cache_registry,
-            Arc::clone(&publisher) as Arc<dyn EventPublisher>,
-        )
-        .await
-        .expect("integration: build profile app");
+        let app = App::build(backends, cache_registry)
+            .await
+            .expect("integration: build profile app");
 
         Self {
             command_bus: app.command_bus,
             query_bus:   app.query_bus,
             repository:  app.repository,
             cache:       app.cache,
-            publisher,
+            scylla:      app.scylla,
         }
     }
 
+    /// The `event_type` of every `profile.outbox` row keyed by `profile_id`, in
+    /// enqueue order.
+    pub async fn events(&self, profile_id: &str) -> Vec<String> {
+        let slot = ScyllaOutboxTable::new(KEYSPACE).expect("outbox table").slot_for(profile_id);
+        self.scylla
+            .session
+            .execute_unpaged(
+                "SELECT aggregate_key, event_type FROM profile.outbox WHERE slot = ?",
+                (slot,),
+            )
+            .await
+            .expect("read profile.outbox")
+            .into_rows_result()
+            .expect("profile.outbox rows")
+            .rows::<(String, String)>()
+            .expect("profile.outbox row shape")
+            .map(|row| row.expect("profile.outbox row"))
+            .filter(|(key, _)| key == profile_id)
+            .map(|(_, event_type)| event_type)
+            .collect()
+    }
+
     /// Creates a profile, expecting success.
     pub async fn create(&self, account_id: &str, handle: &str, display_name: &str) {
         dispatch_create(Arc::clone(&self.command_bus), account_id, handle, display_name)
@@ -173,7 +167,7 @@ impl TestHarness {
 /// Dispatches a create on a shared bus — a free function so scenarios can fire
 /// many concurrently from spawned tasks.
 pub async fn dispatch_create(
-    command_bus:  Arc<InMemoryCommandBus>,
+    command_bus:  Arc<AppCommandBus>,
     account_id:   &str,
     handle:       &str,
     display_name: &str,
@@ -1,8 +1,9 @@
 //! Scenario — outbound event emission.
 //!
-//! Every mutating command must publish its lifecycle event to `profile.v1.events`
-//! *after* the durable write, so downstream consumers (notably search) can react.
-//! The harness injects a capturing publisher that records each event's `type` tag.
+//! Every mutating command must write its lifecycle event for `profile.v1.events`
+//! into `profile.outbox` in the same batch as the profile row, so downstream
+//! consumers (notably search) can react. No relay runs in the harness, so the
+//! rows stay in the outbox for the scenario to read.
 
 use crate::profile_it::harness::{self, TestHarness};
 
@@ -13,17 +14,13 @@ async fn create_then_update_emit_their_lifecycle_events() {
     let account = harness::random_account_id();
 
     h.create(&account, &handle, "Alice").await;
-    assert!(
-        h.publisher.published().contains(&"ProfileCreated".to_owned()),
-        "create must emit ProfileCreated, got {:?}",
-        h.publisher.published()
-    );
-
     let view = h.get_by_handle(&handle).await.expect("profile exists after create");
+    assert_eq!(h.events(&view.id).await, ["ProfileCreated"], "create must emit ProfileCreated");
+
     h.update_display(&view.id, "Alice Updated").await;
-    assert!(
-        h.publisher.published().contains(&"ProfileUpdated".to_owned()),
-        "update must emit ProfileUpdated, got {:?}",
-        h.publisher.published()
+    assert_eq!(
+        h.events(&view.id).await,
+        ["ProfileCreated", "ProfileUpdated"],
+        "update must emit ProfileUpdated"
     );
 }
@@ -6,6 +6,8 @@
 //! crates (a sideways services→services edge the tiering forbids), so it owns its
 //! read schema: minimal, lenient structs that match the published JSON (extra
 //! fields ignored, so an additive upstream change never breaks the consumer).
+//! A stream published as protobuf also maps from the producer's `*-api`
+//! contract message — a contracts edge, which the tiering allows.
 //!
 //! Integration reality (honest about upstream readiness): only the
 //! `notification.v1.events` mapping is concrete here — `notification` publishes a
@@ -16,7 +18,10 @@
 //! `payload` verbatim and never interprets it.
 
 use chrono::{DateTime, Utc};
+use counter_api::{CounterEntityType, PopularityUpdated};
 use serde::Deserialize;
+use transport::error::CodecError;
+use transport::kafka::FromProto;
 
 use crate::application::DeliverableEvent;
 use crate::domain::{ChannelClass, ChannelKey, ChannelRef, DeviceId, UserId};
@@ -78,6 +83,8 @@ pub fn map_notification(
 
 /// The coarse popularity signal `counter` publishes — entity-addressed, no
 /// recipient. `score` and `entity_type` ride along as the client-facing payload.
+/// Published as `counter.v1.PopularityUpdated` (JSON before the protobuf switch);
+/// consumed as `Proto<PopularityWire>`, which reads both.
 #[derive(Debug, Clone, Deserialize)]
 pub struct PopularityWire {
     pub entity_type: String,
@@ -85,6 +92,30 @@ pub struct PopularityWire {
     pub score: f64,
 }
 
+impl FromProto for PopularityWire {
+    type Message = PopularityUpdated;
+
+    fn from_proto(message: PopularityUpdated) -> Result<Self, CodecError> {
+        let entity = message
+            .entity
+            .ok_or_else(|| CodecError::ProtobufMapping("PopularityUpdated.entity is unset".into()))?;
+        Ok(Self {
+            entity_type: entity_type_name(entity.entity_type),
+            entity_id: entity.id,
+            score: message.score,
+        })
+    }
+}
+
+/// The JSON discriminant (`post`, `profile`, …) of a proto entity type; an
+/// unknown or unset type maps to `""`, which no consumer routes.
+fn entity_type_name(v: i32) -> String {
+    match CounterEntityType::try_from(v) {
+        Ok(CounterEntityType::Unspecified) | Err(_) => String::new(),
+        Ok(kind) => kind.as_str_name().trim_start_matches("COUNTER_ENTITY_TYPE_").to_ascii_lowercase(),
+    }
+}
+
 /// Map a popularity signal to a **public broadcast** on `counter:<entity_id>` —
 /// delivered to every connection viewing that entity. Fire-and-forget (latest
 /// wins). An empty entity id is unroutable and skipped (`Ok(None)`).
@@ -212,6 +243,19 @@ mod tests {
         assert_eq!(ev.event_type, "counter.popularity");
     }
 
+    #[test]
+    fn protobuf_popularity_maps_back_to_the_json_discriminant() {
+        let wire = PopularityWire::from_proto(PopularityUpdated {
+            entity: Some(counter_api::EntityRef {
+                entity_type: CounterEntityType::Hashtag as i32,
+                id: "rust".to_owned(),
+            }),
+            score: 1.5,
+        })
+        .unwrap();
+        assert_eq!((wire.entity_type.as_str(), wire.entity_id.as_str()), ("hashtag", "rust"));
+    }
+
     #[test]
     fn maps_post_event_to_a_public_feed_broadcast() {
         let wire = PostWire {
@@ -23,6 +23,7 @@ use tonic_reflection::server::Builder as ReflectionBuilder;
 use transport::kafka::config::{ConsumerConfig, KafkaClientConfig, ProducerConfig};
 use transport::kafka::consumer::{KafkaConsumerBuilder, KafkaConsumerHandle};
 use transport::kafka::producer::{KafkaProducerBuilder, KafkaProducerHandle};
+use transport::kafka::Proto;
 
 use crate::app::Adapters;
 use crate::application::FanOutHandler;
@@ -146,12 +147,12 @@ impl Service for RealtimeDispatcherService {
             &handler,
             map_notification,
         );
-        spawn_fanout_consumer::<PopularityWire, _>(
+        spawn_fanout_consumer::<Proto<PopularityWire>, _>(
             POPULARITY_TOPIC,
             POPULARITY_GROUP,
             "popularity",
             &handler,
-            map_counter_popularity,
+            |wire| map_counter_popularity(wire.into_inner()),
         );
         spawn_fanout_consumer::<PostWire, _>(
             POST_TOPIC,
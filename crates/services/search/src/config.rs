@@ -12,6 +12,9 @@ pub struct SearchConfig {
     pub post_endpoint: String,
     /// gRPC endpoint of the `profile` service — same role for `profile.v1.events`.
     pub profile_endpoint: String,
+    /// gRPC endpoint of the `account` service, where completed GDPR purges are
+    /// confirmed (`ConfirmSubjectErasure`).
+    pub account_endpoint: String,
     /// Per-request deadline on hydration RPCs. Hydration runs inside a Kafka
     /// consumer, so an unbounded call would stall the partition indefinitely.
     pub hydrate_rpc_timeout: std::time::Duration,
@@ -40,6 +43,7 @@ impl SearchConfig {
             opensearch,
             post_endpoint: env_or("SEARCH_POST_GRPC_ENDPOINT", "http://localhost:50056"),
             profile_endpoint: env_or("SEARCH_PROFILE_GRPC_ENDPOINT", "http://localhost:50052"),
+            account_endpoint: env_or("SEARCH_ACCOUNT_GRPC_ENDPOINT", "http://localhost:50059"),
             hydrate_rpc_timeout: env_ms("SEARCH_HYDRATE_RPC_TIMEOUT_MS", 5_000),
             hydrate_connect_timeout: env_ms("SEARCH_HYDRATE_CONNECT_TIMEOUT_MS", 2_000),
         }
@@ -6,7 +6,8 @@
 //! Three consumers ship here: `post.v1.events` and `profile.v1.events` (content,
 //! hydrated via gRPC before projection; profile owner-masking is a no-hydration
 //! visibility flip), and `moderation.v1.events` (visibility transitions, no
-//! hydration).
+//! hydration). The `account.v1.events` GDPR purge runs on the shared
+//! [`subject_erasure`] consumer.
 
 pub mod moderation_consumer;
 pub mod post_consumer;
@@ -16,7 +17,14 @@ pub use moderation_consumer::run_moderation_consumer;
 pub use post_consumer::run_post_consumer;
 pub use profile_consumer::run_profile_consumer;
 
+use chrono::Utc;
+use cqrs::Envelope;
+use subject_erasure::{DeletionRequest, SubjectEraser};
+use uuid::Uuid;
+
+use crate::application::command::ProjectionHandler;
 use crate::error::SearchError;
+use crate::infrastructure::decode::map_gdpr_deletion;
 
 /// Lets the at-least-once runner classify a failure: delegate to the error's own
 /// [`AppError::is_retryable`](error::AppError::is_retryable) verdict — transient
@@ -27,3 +35,19 @@ impl transport::kafka::consumer::ClassifyError for SearchError {
         <Self as error::AppError>::is_retryable(self)
     }
 }
+
+/// Search's erase step on the shared [`subject_erasure`] consumer: deep-purges
+/// every document the account's profiles authored (their profile documents and
+/// posts). A purge is a `delete_by_query`, so a retried request finds nothing
+/// left.
+impl SubjectEraser for ProjectionHandler {
+    type Error = SearchError;
+
+    async fn erase(&self, request: &DeletionRequest) -> Result<(), SearchError> {
+        let now = Utc::now();
+        for purge in map_gdpr_deletion(request) {
+            self.apply(Envelope::new(Uuid::now_v7(), purge), now).await?;
+        }
+        Ok(())
+    }
+}
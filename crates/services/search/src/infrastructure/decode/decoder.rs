@@ -4,11 +4,12 @@
 //! are marked for hydration, and everything search ignores collapses to `Ignore`.
 
 use chrono::{DateTime, TimeZone, Utc};
+use subject_erasure::DeletionRequest;
 
 use super::wire::{ModerationWireEvent, PostWireEvent, ProfileWireEvent};
 use crate::domain::{
-    EntityDeletion, EntityKind, ModerationEvent, PostEvent, ProfileEvent, SourceEvent,
-    VisibilityChange,
+    ComplianceEvent, EntityDeletion, EntityKind, ModerationEvent, PostEvent, ProfileEvent,
+    SourceEvent, VisibilityChange,
 };
 use crate::error::SearchError;
 
@@ -179,6 +180,20 @@ pub fn map_profile(event: ProfileWireEvent) -> Decoded {
     }
 }
 
+/// Map a GDPR deletion request to one deep purge per profile of the account: a
+/// profile is the author of its own document and of every post it published.
+pub fn map_gdpr_deletion(request: &DeletionRequest) -> Vec<SourceEvent> {
+    request
+        .profile_ids
+        .iter()
+        .map(|profile_id| {
+            SourceEvent::Compliance(ComplianceEvent::ActorPurged {
+                author_id: profile_id.clone(),
+            })
+        })
+        .collect()
+}
+
 fn ms_to_revision(ms: i64) -> u64 {
     if ms < 0 { 0 } else { ms as u64 }
 }
@@ -322,4 +337,24 @@ mod tests {
         let json = br#"{"type":"SomethingNew","profile_id":"prof-1"}"#;
         assert_eq!(decode_profile(json).unwrap(), Decoded::Ignore);
     }
+
+    #[test]
+    fn gdpr_deletion_purges_every_profile_of_the_account() {
+        let request = DeletionRequest {
+            account_id: "acc-1".to_owned(),
+            requested_at: Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap(),
+            profile_ids: vec!["prof-1".to_owned(), "prof-2".to_owned()],
+        };
+        assert_eq!(
+            map_gdpr_deletion(&request),
+            vec![
+                SourceEvent::Compliance(ComplianceEvent::ActorPurged {
+                    author_id: "prof-1".to_owned()
+                }),
+                SourceEvent::Compliance(ComplianceEvent::ActorPurged {
+                    author_id: "prof-2".to_owned()
+                }),
+            ]
+        );
+    }
 }
@@ -1,16 +1,23 @@
 //! Adapts the search composition root to the fleet [`service_runtime::Service`]
 //! contract. Maps env → config, defers to [`App::build`], self-spawns the
-//! ingestion consumers (post + moderation), registers the concrete tonic service,
-//! and reports OpenSearch liveness via a ping probe.
+//! ingestion consumers (post + profile + moderation) and the GDPR subject-erasure
+//! consumer, registers the concrete tonic service, and reports OpenSearch liveness
+//! via a ping probe.
+//!
+//! The erasure consumer is the shared [`subject_erasure`] one; it confirms each
+//! purge to account over `ConfirmSubjectErasure`, on a channel resolved from the
+//! `account` resilience binding.
 
 use std::sync::Arc;
 use std::time::Duration;
 
 use anyhow::Context;
 use async_trait::async_trait;
-use service_runtime::{FnProbe, HealthProbe, InfraRegistry, Service};
+use service_runtime::{AccessPolicy, FnProbe, HealthProbe, InfraRegistry, Service};
+use subject_erasure::{run_subject_erasure_consumer, ErasureReporter, GrpcErasureReporter};
 use tonic::service::RoutesBuilder;
 use tonic_reflection::server::Builder as ReflectionBuilder;
+use transport::grpc::client::{GrpcClientBuilder, GrpcClientConfig};
 use transport::kafka::config::{ConsumerConfig, KafkaClientConfig, ProducerConfig};
 use transport::kafka::consumer::{KafkaConsumerBuilder, KafkaConsumerHandle};
 use transport::kafka::producer::{KafkaProducerBuilder, KafkaProducerHandle};
@@ -30,6 +37,12 @@ const PROFILE_TOPIC: &str = "profile.v1.events";
 const PROFILE_GROUP: &str = "search-profile-indexer";
 const MODERATION_TOPIC: &str = "moderation.v1.events";
 const MODERATION_GROUP: &str = "search-moderation-indexer";
+const ACCOUNT_TOPIC: &str = "account.v1.events";
+const SUBJECT_ERASURE_GROUP: &str = "search-subject-erasure";
+/// Search's name in account's erasure participant list.
+const ERASURE_PARTICIPANT: &str = "search";
+/// Resilience binding of the erasure confirmations sent to account.
+const ACCOUNT_DEPENDENCY: &str = "account";
 /// Backoff before respawning a consumer after the runner returns.
 const CONSUMER_RESPAWN_BACKOFF: Duration = Duration::from_secs(5);
 
@@ -46,8 +59,16 @@ impl Service for SearchService {
     const VERSION: &'static str = env!("CARGO_PKG_VERSION");
     const GRPC_SERVICE_NAME: &'static str = <SearchServer as tonic::server::NamedService>::NAME;
 
-    async fn build(_infra: Arc<InfraRegistry>) -> anyhow::Result<Self> {
+    async fn build(infra: Arc<InfraRegistry>) -> anyhow::Result<Self> {
         let config = SearchConfig::from_env();
+        // The channel connects lazily; account being down only delays the
+        // confirmation (the consumer retries).
+        let account = GrpcClientBuilder::new(
+            GrpcClientConfig::new(config.account_endpoint.clone())
+                .with_dependency(ACCOUNT_DEPENDENCY),
+        )
+        .build_from_registry_lazy(&infra.resilience())
+        .map_err(|e| anyhow::anyhow!("search account channel: {e}"))?;
         let app = App::build(config)
             .await
             .map_err(|e| anyhow::anyhow!("search app build: {e}"))?;
@@ -56,6 +77,10 @@ impl Service for SearchService {
         spawn_post_consumer(Arc::clone(&app.projection), Arc::clone(&app.hydrator));
         spawn_profile_consumer(Arc::clone(&app.projection), Arc::clone(&app.hydrator));
         spawn_moderation_consumer(Arc::clone(&app.projection));
+        spawn_subject_erasure_consumer(
+            Arc::clone(&app.projection),
+            Arc::new(GrpcErasureReporter::new(account, ERASURE_PARTICIPANT)),
+        );
 
         Ok(Self { app })
     }
@@ -68,6 +93,13 @@ impl Service for SearchService {
         }))]
     }
 
+    fn access_policy(&self) -> AccessPolicy {
+        AccessPolicy::for_service(Self::GRPC_SERVICE_NAME)
+            .authenticated("Search")
+            .authenticated("Suggest")
+            .authenticated("MultiSearch")
+    }
+
     fn register(self, routes: &mut RoutesBuilder) -> anyhow::Result<()> {
         let reflection = ReflectionBuilder::configure()
             .register_encoded_file_descriptor_set(FILE_DESCRIPTOR_SET)
@@ -144,6 +176,34 @@ fn spawn_moderation_consumer(projection: Arc<ProjectionHandler>) {
     });
 }
 
+/// Spawns the supervised GDPR subject-erasure consumer.
+fn spawn_subject_erasure_consumer(
+    projection: Arc<ProjectionHandler>,
+    reporter: Arc<dyn ErasureReporter>,
+) {
+    tokio::spawn(async move {
+        loop {
+            match build_consumer(ACCOUNT_TOPIC, SUBJECT_ERASURE_GROUP) {
+                Ok((consumer, producer)) => {
+                    run_subject_erasure_consumer(
+                        ERASURE_PARTICIPANT,
+                        consumer,
+                        Arc::clone(&projection),
+                        Arc::clone(&reporter),
+                        producer,
+                    )
+                    .await;
+                    tracing::warn!("subject-erasure consumer exited; respawning after backoff");
+                }
+                Err(error) => {
+                    tracing::error!(%error, "failed to build subject-erasure consumer; retrying")
+                }
+            }
+            tokio::time::sleep(CONSUMER_RESPAWN_BACKOFF).await;
+        }
+    });
+}
+
 /// Builds a manual-commit consumer (subscribed to `topic`) and the dead-letter
 /// producer the runner needs.
 fn build_consumer(
@@ -1,34 +1,44 @@
 //! The social-graph service's composition root.
 //!
-//! [`App::build`] is *pure composition*: storage configs and an [`EventPublisher`]
-//! in, a fully-wired CQRS graph out. It binds no socket and reads no environment,
-//! so a binary entrypoint and the live integration harness assemble the exact
-//! same graph.
+//! [`App::build`] is *pure composition*: storage configs in, a fully-wired CQRS
+//! graph out. It binds no socket and reads no environment, so a binary entrypoint
+//! and the live integration harness assemble the exact same graph.
 //!
-//! The event publisher is injected as a trait object (the handlers already hold
-//! `Arc<dyn EventPublisher>`): production passes the Kafka publisher; the
-//! integration harness passes an in-process no-op, so the adjacency-consistency
-//! and block-override scenarios run without a broker.
+//! Follow, unfollow and block events never leave through a separate publish: the
+//! repository writes them into `social_graph.outbox` in the same logged batch as
+//! the adjacency rows. The author-tier signal, derived from the Redis follower
+//! counter after the write, is enqueued into the same outbox through the
+//! [`EventPublisher`] port. The serving binary spawns the relay that drains it;
+//! the integration harness spawns none, so the adjacency-consistency and
+//! block-override scenarios run without a broker.
 
 use std::sync::Arc;
 
 use cqrs::command::{CommandBusBuilder, InMemoryCommandBus};
+use cqrs::middleware::{
+    IdempotencyCommandBus, IdempotencyLayer, MetricsCommandBus, MetricsLayer,
+    MetricsQueryBus, MiddlewarePipeline,
+};
 use cqrs::query::{InMemoryQueryBus, QueryBusBuilder};
+use idempotency::RedisIdempotencyStore;
+use outbox::{ScyllaOutbox, ScyllaOutboxTable};
 use redis_storage::{RedisClient, RedisClientBuilder, RedisConfig};
 use scylla_storage::{ScyllaClient, ScyllaConfig, ScyllaSessionBuilder};
 
 use crate::application::command::{
-    BlockProfileCommand, BlockProfileHandler, FollowProfileCommand, FollowProfileHandler,
-    UnblockProfileCommand, UnblockProfileHandler, UnfollowProfileCommand, UnfollowProfileHandler,
+    BlockProfileCommand, BlockProfileHandler, EraseSubjectDataCommand, EraseSubjectDataHandler,
+    FollowProfileCommand, FollowProfileHandler, UnblockProfileCommand, UnblockProfileHandler,
+    UnfollowProfileCommand, UnfollowProfileHandler,
 };
 use crate::application::port::{EventPublisher, SocialGraphCache, SocialGraphRepository};
 use crate::application::query::{
-    GetRelationStatusHandler, GetRelationStatusQuery, ListBlocksHandler, ListBlocksQuery,
+    ExportSubjectDataHandler, ExportSubjectDataQuery, GetRelationStatusHandler, GetRelationStatusQuery, ListBlocksHandler, ListBlocksQuery,
     ListFollowersHandler, ListFollowersQuery, ListFollowingHandler, ListFollowingQuery,
 };
 use crate::domain::value_object::TierThresholds;
 use crate::infrastructure::cache::RedisSocialGraphCache;
 use crate::infrastructure::persistence::ScyllaSocialGraphRepository;
+use crate::infrastructure::publisher::{SocialGraphOutbox, OUTBOX_KEYSPACE};
 
 /// Storage endpoints the graph is wired against.
 pub struct Backends {
@@ -36,13 +46,25 @@ pub struct Backends {
     pub redis:  RedisConfig,
 }
 
+/// The command bus every entrypoint dispatches through: the registered handlers
+/// behind an [`IdempotencyLayer`] on the service's Redis, so a retried `message_id`
+/// runs once across replicas. It fails open: a Redis outage costs deduplication,
+/// not the write path.
+/// A [`MetricsLayer`] outermost times every dispatch, replays included.
+pub type AppCommandBus =
+    MetricsCommandBus<IdempotencyCommandBus<InMemoryCommandBus, RedisIdempotencyStore>>;
+
+/// The query bus every read path dispatches through: the registered handlers,
+/// timed by a [`MetricsLayer`].
+pub type AppQueryBus = MetricsQueryBus<InMemoryQueryBus>;
+
 /// A fully-wired social-graph service bound to its backends. The buses exposed
 /// here are the *same* instances the handlers are registered into; the
 /// `ListFollowers`/`ListFollowing` queries read the separate adjacency tables, so
 /// the query bus alone proves their consistency.
 pub struct App {
-    pub command_bus: Arc<InMemoryCommandBus>,
-    pub query_bus:   Arc<InMemoryQueryBus>,
+    pub command_bus: Arc<AppCommandBus>,
+    pub query_bus:   Arc<AppQueryBus>,
     /// Live storage clients, retained so the runtime's readiness loop can probe
     /// their liveness (see [`crate::service`]).
     pub scylla:      Arc<ScyllaClient>,
@@ -50,61 +72,93 @@ pub struct App {
 }
 
 impl App {
-    /// Builds storage clients from `backends`, assembles the ScyllaDB repository
-    /// and Redis cache, and registers every social-graph command and query
-    /// against the supplied `publisher`.
+    /// Builds the ScyllaDB client and the outbox over it from `backends`, then
+    /// [`App::assemble`]s the graph on them.
     pub async fn build(
         backends:        Backends,
-        publisher:       Arc<dyn EventPublisher>,
         tier_thresholds: TierThresholds,
     ) -> Result<Self, Box<dyn std::error::Error>> {
         let Backends { scylla, redis } = backends;
 
         let scylla_client = Arc::new(ScyllaSessionBuilder::new(scylla).build().await?);
+        let outbox = SocialGraphOutbox::new(ScyllaOutbox::new(
+            Arc::clone(&scylla_client),
+            ScyllaOutboxTable::new(OUTBOX_KEYSPACE)?,
+        ));
+        Self::assemble(scylla_client, redis, outbox, tier_thresholds).await
+    }
+
+    /// Builds the Redis client, assembles the ScyllaDB repository (on an existing
+    /// client and `outbox`) and Redis cache, and registers every social-graph
+    /// command and query. The serving binary uses this directly, so the relay
+    /// drains the outbox the repository writes through, on the same client.
+    pub async fn assemble(
+        scylla_client:   Arc<ScyllaClient>,
+        redis:           RedisConfig,
+        outbox:          SocialGraphOutbox,
+        tier_thresholds: TierThresholds,
+    ) -> Result<Self, Box<dyn std::error::Error>> {
         let redis_client = Arc::new(RedisClientBuilder::new(redis).build().await?);
 
+        let publisher: Arc<dyn EventPublisher> = Arc::new(outbox.clone());
         let repo: Arc<dyn SocialGraphRepository> =
-            Arc::new(ScyllaSocialGraphRepository::new(Arc::clone(&scylla_client)));
+            Arc::new(ScyllaSocialGraphRepository::new(Arc::clone(&scylla_client), outbox));
         let cache: Arc<dyn SocialGraphCache> =
             Arc::new(RedisSocialGraphCache::new(Arc::clone(&redis_client)));
 
+        let handlers = CommandBusBuilder::new()
+            .register::<FollowProfileCommand, _>(FollowProfileHandler::new(
+                Arc::clone(&repo),
+                Arc::clone(&cache),
+                Arc::clone(&publisher),
+                tier_thresholds,
+            ))?
+            .register::<UnfollowProfileCommand, _>(UnfollowProfileHandler::new(
+                Arc::clone(&repo),
+                Arc::clone(&cache),
+                Arc::clone(&publisher),
+                tier_thresholds,
+            ))?
+            .register::<BlockProfileCommand, _>(BlockProfileHandler::new(
+                Arc::clone(&repo),
+                Arc::clone(&cache),
+            ))?
+            .register::<UnblockProfileCommand, _>(UnblockProfileHandler::new(
+                Arc::clone(&repo),
+                Arc::clone(&cache),
+            ))?
+            .register::<EraseSubjectDataCommand, _>(EraseSubjectDataHandler::new(
+                Arc::clone(&repo),
+                Arc::clone(&cache),
+                Arc::clone(&publisher),
+                tier_thresholds,
+            ))?
+            .build();
         let command_bus = Arc::new(
-            CommandBusBuilder::new()
-                .register::<FollowProfileCommand, _>(FollowProfileHandler::new(
-                    Arc::clone(&repo),
-                    Arc::clone(&cache),
-                    Arc::clone(&publisher),
-                    tier_thresholds,
-                ))?
-                .register::<UnfollowProfileCommand, _>(UnfollowProfileHandler::new(
-                    Arc::clone(&repo),
-                    Arc::clone(&cache),
-                    Arc::clone(&publisher),
-                    tier_thresholds,
-                ))?
-                .register::<BlockProfileCommand, _>(BlockProfileHandler::new(
-                    Arc::clone(&repo),
-                    Arc::clone(&cache),
-                    Arc::clone(&publisher),
-                ))?
-                .register::<UnblockProfileCommand, _>(UnblockProfileHandler::new(
-                    Arc::clone(&repo),
-                    Arc::clone(&cache),
-                    Arc::clone(&publisher),
-                ))?
+            MiddlewarePipeline::new(handlers)
+                .layer(
+                    IdempotencyLayer::new(RedisIdempotencyStore::new((*redis_client).clone(), "social-graph"))
+                        .fail_open(),
+                )
+                .layer(MetricsLayer::new())
                 .build(),
         );
 
         let query_bus = Arc::new(
-            QueryBusBuilder::new()
-                .register::<GetRelationStatusQuery, _>(GetRelationStatusHandler::new(
-                    Arc::clone(&repo),
-                    Arc::clone(&cache),
-                ))?
-                .register::<ListFollowersQuery, _>(ListFollowersHandler::new(Arc::clone(&repo)))?
-                .register::<ListFollowingQuery, _>(ListFollowingHandler::new(Arc::clone(&repo)))?
-                .register::<ListBlocksQuery, _>(ListBlocksHandler::new(Arc::clone(&repo)))?
-                .build(),
+            MiddlewarePipeline::new(
+                QueryBusBuilder::new()
+                    .register::<GetRelationStatusQuery, _>(GetRelationStatusHandler::new(
+                        Arc::clone(&repo),
+                        Arc::clone(&cache),
+                    ))?
+                    .register::<ListFollowersQuery, _>(ListFollowersHandler::new(Arc::clone(&repo)))?
+                    .register::<ListFollowingQuery, _>(ListFollowingHandler::new(Arc::clone(&repo)))?
+                    .register::<ListBlocksQuery, _>(ListBlocksHandler::new(Arc::clone(&repo)))?
+                    .register::<ExportSubjectDataQuery, _>(ExportSubjectDataHandler::new(Arc::clone(&repo)))?
+                    .build(),
+            )
+            .query_layer(MetricsLayer::new())
+            .build(),
         );
 
         Ok(Self { command_bus, query_bus, scylla: scylla_client, redis: redis_client })
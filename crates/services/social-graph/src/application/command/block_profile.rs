@@ -3,7 +3,7 @@ use std::sync::Arc;
 use cqrs::{Command, CommandHandler, Envelope};
 use validate_core::{FieldViolation, Validate};
 
-use crate::application::port::{EventPublisher, SocialGraphCache, SocialGraphRepository};
+use crate::application::port::{SocialGraphCache, SocialGraphRepository};
 use crate::domain::value_object::ProfileId;
 use crate::error::SocialGraphError;
 
@@ -13,7 +13,9 @@ pub struct BlockProfileCommand {
     pub target_id: String,
 }
 
-impl Command for BlockProfileCommand {}
+impl Command for BlockProfileCommand {
+    type Output = ();
+}
 
 impl Validate for BlockProfileCommand {
     fn validate(&self) -> Result<(), Vec<FieldViolation>> {
@@ -29,18 +31,16 @@ impl Validate for BlockProfileCommand {
 }
 
 pub struct BlockProfileHandler {
-    repo:      Arc<dyn SocialGraphRepository>,
-    cache:     Arc<dyn SocialGraphCache>,
-    publisher: Arc<dyn EventPublisher>,
+    repo:  Arc<dyn SocialGraphRepository>,
+    cache: Arc<dyn SocialGraphCache>,
 }
 
 impl BlockProfileHandler {
     pub fn new(
-        repo:      Arc<dyn SocialGraphRepository>,
-        cache:     Arc<dyn SocialGraphCache>,
-        publisher: Arc<dyn EventPublisher>,
+        repo:  Arc<dyn SocialGraphRepository>,
+        cache: Arc<dyn SocialGraphCache>,
     ) -> Self {
-        Self { repo, cache, publisher }
+        Self { repo, cache }
     }
 }
 
@@ -66,13 +66,15 @@ impl CommandHandler<BlockProfileCommand> for BlockProfileHandler {
         let severed = relation.block()?;
         let now     = chrono::Utc::now();
 
-        // Persist the block first, then sever any existing follows in parallel.
-        self.repo.persist_block(&actor_id, &target_id, now).await?;
+        // Persist the block (and ProfileBlocked) first, then sever any existing
+        // follows in parallel.
+        let events = relation.take_events();
+        self.repo.persist_block(&actor_id, &target_id, now, &events).await?;
 
         let (r1, r2) = tokio::join!(
             async {
                 if let Some(ts) = severed.actor_to_target {
-                    self.repo.delete_follow(&actor_id, &target_id, ts).await?;
+                    self.repo.delete_follow(&actor_id, &target_id, ts, &[]).await?;
                     let _ = self.cache.remove_following(&actor_id, &target_id).await;
                     let _ = self.cache.decr_followers_count(&target_id).await;
                     let _ = self.cache.decr_following_count(&actor_id).await;
@@ -81,7 +83,7 @@ impl CommandHandler<BlockProfileCommand> for BlockProfileHandler {
             },
             async {
                 if let Some(ts) = severed.target_to_actor {
-                    self.repo.delete_follow(&target_id, &actor_id, ts).await?;
+                    self.repo.delete_follow(&target_id, &actor_id, ts, &[]).await?;
                     let _ = self.cache.remove_following(&target_id, &actor_id).await;
                     let _ = self.cache.decr_followers_count(&actor_id).await;
                     let _ = self.cache.decr_following_count(&target_id).await;
@@ -94,10 +96,6 @@ impl CommandHandler<BlockProfileCommand> for BlockProfileHandler {
 
         let _ = self.cache.add_block(&actor_id, &target_id).await;
 
-        for event in relation.take_events() {
-            let _ = self.publisher.publish(&event).await;
-        }
-
         Ok(())
     }
 }
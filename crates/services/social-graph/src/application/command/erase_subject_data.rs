@@ -0,0 +1,151 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use cqrs::{Command, CommandHandler, Envelope};
+use validate_core::Validate;
+
+use crate::application::port::{EventPublisher, SocialGraphCache, SocialGraphRepository};
+use crate::domain::event::{AuthorTierChanged, DomainEvent, ProfileUnfollowed};
+use crate::domain::value_object::{AuthorTier, ProfileId, TierThresholds};
+use crate::error::SocialGraphError;
+
+/// Page size used to walk the adjacency tables during an erasure.
+const ERASURE_PAGE_SIZE: i32 = 100;
+
+/// GDPR erasure of every edge the subject's profiles take part in, driven by
+/// account's `gdpr_deletion_requested`: follows in both directions and the
+/// blocks the subject placed. Blocks *against* the subject belong to the
+/// blocker and are kept.
+///
+/// Edges are deleted straight from the adjacency rows rather than through
+/// `Relation::unfollow`, so a half-written edge is still removed. A redelivery
+/// finds the tables already empty and does nothing.
+#[derive(Debug, Clone)]
+pub struct EraseSubjectDataCommand {
+    pub profile_ids: Vec<String>,
+}
+
+impl Command for EraseSubjectDataCommand {
+    type Output = ();
+}
+
+impl Validate for EraseSubjectDataCommand {}
+
+pub struct EraseSubjectDataHandler {
+    repo:            Arc<dyn SocialGraphRepository>,
+    cache:           Arc<dyn SocialGraphCache>,
+    publisher:       Arc<dyn EventPublisher>,
+    tier_thresholds: TierThresholds,
+}
+
+impl EraseSubjectDataHandler {
+    pub fn new(
+        repo:            Arc<dyn SocialGraphRepository>,
+        cache:           Arc<dyn SocialGraphCache>,
+        publisher:       Arc<dyn EventPublisher>,
+        tier_thresholds: TierThresholds,
+    ) -> Self {
+        Self { repo, cache, publisher, tier_thresholds }
+    }
+
+    /// Removes the `follower → followee` edge followed since `followed_at`, with
+    /// the same cache and event side effects as an unfollow.
+    async fn sever_follow(
+        &self,
+        follower_id: &ProfileId,
+        followee_id: &ProfileId,
+        followed_at: DateTime<Utc>,
+    ) -> Result<(), SocialGraphError> {
+        // Timeline engines drop the subject's posts from follower feeds on this.
+        let unfollowed = DomainEvent::ProfileUnfollowed(ProfileUnfollowed {
+            actor_id:      *follower_id,
+            target_id:     *followee_id,
+            unfollowed_at: Utc::now(),
+        });
+        self.repo.delete_follow(follower_id, followee_id, followed_at, &[unfollowed]).await?;
+
+        let _ = self.cache.remove_following(follower_id, followee_id).await;
+        let _ = self.cache.decr_following_count(follower_id).await;
+
+        if let Ok(new_count) = self.cache.decr_followers_count(followee_id).await
+            && let Some(new_tier) =
+                AuthorTier::crossing(new_count + 1, new_count, self.tier_thresholds)
+        {
+            let _ = self
+                .publisher
+                .publish(&DomainEvent::AuthorTierChanged(AuthorTierChanged {
+                    profile_id: *followee_id,
+                    new_tier,
+                    follower_count: new_count,
+                    changed_at: Utc::now(),
+                }))
+                .await;
+        }
+
+        Ok(())
+    }
+}
+
+impl CommandHandler<EraseSubjectDataCommand> for EraseSubjectDataHandler {
+    type Error = SocialGraphError;
+
+    async fn handle(
+        &self,
+        envelope: Envelope<EraseSubjectDataCommand>,
+    ) -> Result<(), Self::Error> {
+        for profile_id in &envelope.payload.profile_ids {
+            let subject = ProfileId::try_from(profile_id.as_str())?;
+
+            // Pages are keyed on the last row returned, so deleting the rows
+            // already read does not shift the next page.
+            let mut page_token: Option<String> = None;
+            loop {
+                let (edges, next) = self
+                    .repo
+                    .list_following(&subject, ERASURE_PAGE_SIZE, page_token.as_deref())
+                    .await?;
+                for edge in edges {
+                    self.sever_follow(&subject, &edge.profile_id, edge.followed_at).await?;
+                }
+                match next {
+                    Some(token) => page_token = Some(token),
+                    None => break,
+                }
+            }
+
+            let mut page_token: Option<String> = None;
+            loop {
+                let (edges, next) = self
+                    .repo
+                    .list_followers(&subject, ERASURE_PAGE_SIZE, page_token.as_deref())
+                    .await?;
+                for edge in edges {
+                    self.sever_follow(&edge.profile_id, &subject, edge.followed_at).await?;
+                }
+                match next {
+                    Some(token) => page_token = Some(token),
+                    None => break,
+                }
+            }
+
+            let mut page_token: Option<String> = None;
+            loop {
+                let (edges, next) = self
+                    .repo
+                    .list_blocks(&subject, ERASURE_PAGE_SIZE, page_token.as_deref())
+                    .await?;
+                for edge in edges {
+                    // Unblocks have no downstream fan-out (the outbox drops
+                    // ProfileUnblocked), so there is nothing to publish.
+                    self.repo.delete_block(&subject, &edge.blockee_id, &[]).await?;
+                    let _ = self.cache.remove_block(&subject, &edge.blockee_id).await;
+                }
+                match next {
+                    Some(token) => page_token = Some(token),
+                    None => break,
+                }
+            }
+        }
+        Ok(())
+    }
+}
@@ -15,7 +15,9 @@ pub struct FollowProfileCommand {
     pub target_id: String,
 }
 
-impl Command for FollowProfileCommand {}
+impl Command for FollowProfileCommand {
+    type Output = ();
+}
 
 impl Validate for FollowProfileCommand {
     fn validate(&self) -> Result<(), Vec<FieldViolation>> {
@@ -70,8 +72,10 @@ impl CommandHandler<FollowProfileCommand> for FollowProfileHandler {
         // Domain invariant enforcement (block-gate + idempotency guard).
         let followed_at = relation.follow()?;
 
-        // Persist the follow edge across the three adjacency tables.
-        self.repo.persist_follow(&actor_id, &target_id, followed_at).await?;
+        // Persist the follow edge across the three adjacency tables, with the
+        // ProfileFollowed event timeline fan-out engines consume in the same batch.
+        let events = relation.take_events();
+        self.repo.persist_follow(&actor_id, &target_id, followed_at, &events).await?;
 
         // Update Redis side-effects (best-effort; errors are logged, not surfaced).
         let _ = self.cache.add_following(&actor_id, &target_id).await;
@@ -96,11 +100,6 @@ impl CommandHandler<FollowProfileCommand> for FollowProfileHandler {
                 .await;
         }
 
-        // Publish domain event to Kafka for downstream timeline fan-out engines.
-        for event in relation.take_events() {
-            let _ = self.publisher.publish(&event).await;
-        }
-
         Ok(())
     }
 }
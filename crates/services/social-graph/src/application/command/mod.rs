@@ -1,9 +1,11 @@
 pub mod block_profile;
+pub mod erase_subject_data;
 pub mod follow_profile;
 pub mod unblock_profile;
 pub mod unfollow_profile;
 
 pub use block_profile::{BlockProfileCommand, BlockProfileHandler};
+pub use erase_subject_data::{EraseSubjectDataCommand, EraseSubjectDataHandler};
 pub use follow_profile::{FollowProfileCommand, FollowProfileHandler};
 pub use unblock_profile::{UnblockProfileCommand, UnblockProfileHandler};
 pub use unfollow_profile::{UnfollowProfileCommand, UnfollowProfileHandler};
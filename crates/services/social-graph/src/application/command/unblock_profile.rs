@@ -3,7 +3,7 @@ use std::sync::Arc;
 use cqrs::{Command, CommandHandler, Envelope};
 use validate_core::{FieldViolation, Validate};
 
-use crate::application::port::{EventPublisher, SocialGraphCache, SocialGraphRepository};
+use crate::application::port::{SocialGraphCache, SocialGraphRepository};
 use crate::domain::value_object::ProfileId;
 use crate::error::SocialGraphError;
 
@@ -13,7 +13,9 @@ pub struct UnblockProfileCommand {
     pub target_id: String,
 }
 
-impl Command for UnblockProfileCommand {}
+impl Command for UnblockProfileCommand {
+    type Output = ();
+}
 
 impl Validate for UnblockProfileCommand {
     fn validate(&self) -> Result<(), Vec<FieldViolation>> {
@@ -29,19 +31,16 @@ impl Validate for UnblockProfileCommand {
 }
 
 pub struct UnblockProfileHandler {
-    repo:      Arc<dyn SocialGraphRepository>,
-    cache:     Arc<dyn SocialGraphCache>,
-    #[allow(dead_code)]
-    publisher: Arc<dyn EventPublisher>,
+    repo:  Arc<dyn SocialGraphRepository>,
+    cache: Arc<dyn SocialGraphCache>,
 }
 
 impl UnblockProfileHandler {
     pub fn new(
-        repo:      Arc<dyn SocialGraphRepository>,
-        cache:     Arc<dyn SocialGraphCache>,
-        publisher: Arc<dyn EventPublisher>,
+        repo:  Arc<dyn SocialGraphRepository>,
+        cache: Arc<dyn SocialGraphCache>,
     ) -> Self {
-        Self { repo, cache, publisher }
+        Self { repo, cache }
     }
 }
 
@@ -62,14 +61,14 @@ impl CommandHandler<UnblockProfileCommand> for UnblockProfileHandler {
         // Domain guard: must be blocking (returns NotBlocked if not).
         relation.unblock()?;
 
-        self.repo.delete_block(&actor_id, &target_id).await?;
+        // ProfileUnblocked is intentionally not published to Kafka: the outbox
+        // drops it. Unblocking has no downstream fan-out consequence for
+        // timeline engines.
+        let events = relation.take_events();
+        self.repo.delete_block(&actor_id, &target_id, &events).await?;
 
         let _ = self.cache.remove_block(&actor_id, &target_id).await;
 
-        // ProfileUnblocked is intentionally not published to Kafka.
-        // Unblocking has no downstream fan-out consequence for timeline engines.
-        let _ = relation.take_events();
-
         Ok(())
     }
 }
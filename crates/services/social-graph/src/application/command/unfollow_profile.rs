@@ -15,7 +15,9 @@ pub struct UnfollowProfileCommand {
     pub target_id: String,
 }
 
-impl Command for UnfollowProfileCommand {}
+impl Command for UnfollowProfileCommand {
+    type Output = ();
+}
 
 impl Validate for UnfollowProfileCommand {
     fn validate(&self) -> Result<(), Vec<FieldViolation>> {
@@ -65,8 +67,9 @@ impl CommandHandler<UnfollowProfileCommand> for UnfollowProfileHandler {
         // `unfollow()` returns the `followed_at` timestamp needed for the DELETE.
         let followed_at = relation.unfollow()?;
 
-        // Triple DELETE: follow_status + following + followers.
-        self.repo.delete_follow(&actor_id, &target_id, followed_at).await?;
+        // Triple DELETE: follow_status + following + followers, with ProfileUnfollowed.
+        let events = relation.take_events();
+        self.repo.delete_follow(&actor_id, &target_id, followed_at, &events).await?;
 
         let _ = self.cache.remove_following(&actor_id, &target_id).await;
         let _ = self.cache.decr_following_count(&actor_id).await;
@@ -89,10 +92,6 @@ impl CommandHandler<UnfollowProfileCommand> for UnfollowProfileHandler {
                 .await;
         }
 
-        for event in relation.take_events() {
-            let _ = self.publisher.publish(&event).await;
-        }
-
         Ok(())
     }
 }
@@ -3,20 +3,19 @@ use async_trait::async_trait;
 use crate::domain::event::DomainEvent;
 use crate::error::SocialGraphError;
 
-/// Outbound Kafka event publisher port.
+/// Outbound port for signals that are not part of a graph write.
 ///
-/// Implemented by [`crate::infrastructure::publisher::kafka_event_publisher::KafkaEventPublisher`].
+/// Implemented by [`crate::infrastructure::publisher::SocialGraphOutbox`].
 ///
-/// # Topic mapping
+/// Follow, unfollow and block events never go through here: the repository
+/// writes them in the same batch as the adjacency rows (see
+/// [`crate::application::port::SocialGraphRepository`]). What is left is the
+/// author-tier signal, which is derived from the Redis follower counter *after*
+/// the write and so has no batch to join.
 ///
-/// | Event             | Kafka topic                  | Key               |
-/// |-------------------|------------------------------|-------------------|
-/// | ProfileFollowed   | `social-graph.followed`      | `{actor}:{target}`|
-/// | ProfileUnfollowed | `social-graph.unfollowed`    | `{actor}:{target}`|
-/// | ProfileBlocked    | `social-graph.blocked`       | `{actor}:{target}`|
-///
-/// `ProfileUnblocked` is intentionally not published downstream. Unblocking is
-/// a user-local operation with no fan-out consequence for timeline engines.
+/// | Event             | Kafka topic                        | Key          |
+/// |-------------------|------------------------------------|--------------|
+/// | AuthorTierChanged | `social-graph.author_tier_changed` | `profile_id` |
 #[async_trait]
 pub trait EventPublisher: Send + Sync + 'static {
     async fn publish(&self, event: &DomainEvent) -> Result<(), SocialGraphError>;
@@ -3,6 +3,7 @@ use chrono::{DateTime, Utc};
 
 use crate::domain::aggregate::Relation;
 use crate::domain::entity::{BlockEdge, FollowEdge};
+use crate::domain::event::DomainEvent;
 use crate::domain::value_object::ProfileId;
 use crate::error::SocialGraphError;
 
@@ -11,6 +12,11 @@ use crate::error::SocialGraphError;
 /// All methods are point-lookups or narrow partition scans — no ALLOW FILTERING.
 /// The four ScyllaDB tables (followers, following, follow_status, blocks) are
 /// partitioned to guarantee O(1) single-row access and O(page) list scans.
+///
+/// Every write takes the `events` the change produced and writes them to
+/// `social_graph.outbox` in the same logged batch as its rows, so an event lands
+/// exactly when its write does. Events not published downstream
+/// (`ProfileUnblocked`) are accepted and dropped.
 #[async_trait]
 pub trait SocialGraphRepository: Send + Sync + 'static {
     /// Loads the full bidirectional relationship context for `(actor, target)`.
@@ -26,7 +32,7 @@ pub trait SocialGraphRepository: Send + Sync + 'static {
         target_id: &ProfileId,
     ) -> Result<Relation, SocialGraphError>;
 
-    /// Writes a follow edge across three tables atomically as an unlogged batch:
+    /// Writes a follow edge across three tables atomically as a logged batch:
     ///   - `following`     INSERT (follower_id, followed_at, followee_id)
     ///   - `followers`     INSERT (followee_id, followed_at, follower_id)
     ///   - `follow_status` INSERT (follower_id, followee_id, followed_at)
@@ -35,6 +41,7 @@ pub trait SocialGraphRepository: Send + Sync + 'static {
         actor_id:    &ProfileId,
         target_id:   &ProfileId,
         followed_at: DateTime<Utc>,
+        events:      &[DomainEvent],
     ) -> Result<(), SocialGraphError>;
 
     /// Deletes a follow edge from three tables.
@@ -46,6 +53,7 @@ pub trait SocialGraphRepository: Send + Sync + 'static {
         actor_id:    &ProfileId,
         target_id:   &ProfileId,
         followed_at: DateTime<Utc>,
+        events:      &[DomainEvent],
     ) -> Result<(), SocialGraphError>;
 
     /// Writes a block record to the `blocks` table.
@@ -54,6 +62,7 @@ pub trait SocialGraphRepository: Send + Sync + 'static {
         blocker_id: &ProfileId,
         blockee_id: &ProfileId,
         blocked_at: DateTime<Utc>,
+        events:     &[DomainEvent],
     ) -> Result<(), SocialGraphError>;
 
     /// Deletes a block record from the `blocks` table.
@@ -61,6 +70,7 @@ pub trait SocialGraphRepository: Send + Sync + 'static {
         &self,
         blocker_id: &ProfileId,
         blockee_id: &ProfileId,
+        events:     &[DomainEvent],
     ) -> Result<(), SocialGraphError>;
 
     /// Paginated scan of the `followers` table (fan-in adjacency list).
@@ -0,0 +1,123 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use cqrs::{Envelope, Query, QueryHandler};
+use serde::Serialize;
+
+use crate::application::port::SocialGraphRepository;
+use crate::domain::value_object::ProfileId;
+use crate::error::SocialGraphError;
+
+/// Page size used to walk each edge list during an export.
+const EXPORT_PAGE_SIZE: i32 = 100;
+
+/// GDPR Art. 20 export of every follow and block edge touching the subject's
+/// profiles.
+#[derive(Debug, Clone)]
+pub struct ExportSubjectDataQuery {
+    pub profile_ids: Vec<String>,
+}
+
+impl Query for ExportSubjectDataQuery {
+    type Response = SubjectDataExport;
+}
+
+/// This service's slice of a data-subject export.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct SubjectDataExport {
+    pub following: Vec<EdgeExport>,
+    pub followers: Vec<EdgeExport>,
+    pub blocks:    Vec<EdgeExport>,
+}
+
+impl SubjectDataExport {
+    pub fn record_count(&self) -> u32 {
+        (self.following.len() + self.followers.len() + self.blocks.len()) as u32
+    }
+}
+
+/// One edge, seen from the subject's profile (`profile_id`) towards `other_id`.
+#[derive(Debug, Clone, Serialize)]
+pub struct EdgeExport {
+    pub profile_id: String,
+    pub other_id:   String,
+    pub since:      DateTime<Utc>,
+}
+
+pub struct ExportSubjectDataHandler {
+    repo: Arc<dyn SocialGraphRepository>,
+}
+
+impl ExportSubjectDataHandler {
+    pub fn new(repo: Arc<dyn SocialGraphRepository>) -> Self {
+        Self { repo }
+    }
+}
+
+impl QueryHandler<ExportSubjectDataQuery> for ExportSubjectDataHandler {
+    type Error = SocialGraphError;
+
+    async fn handle(
+        &self,
+        envelope: Envelope<ExportSubjectDataQuery>,
+    ) -> Result<SubjectDataExport, Self::Error> {
+        let mut export = SubjectDataExport::default();
+
+        for raw in &envelope.payload.profile_ids {
+            let profile_id = ProfileId::try_from(raw.as_str())?;
+
+            let mut page_token: Option<String> = None;
+            loop {
+                let (edges, next) = self
+                    .repo
+                    .list_following(&profile_id, EXPORT_PAGE_SIZE, page_token.as_deref())
+                    .await?;
+                export.following.extend(edges.into_iter().map(|e| EdgeExport {
+                    profile_id: raw.clone(),
+                    other_id:   e.profile_id.as_str(),
+                    since:      e.followed_at,
+                }));
+                match next {
+                    Some(token) => page_token = Some(token),
+                    None => break,
+                }
+            }
+
+            let mut page_token: Option<String> = None;
+            loop {
+                let (edges, next) = self
+                    .repo
+                    .list_followers(&profile_id, EXPORT_PAGE_SIZE, page_token.as_deref())
+                    .await?;
+                export.followers.extend(edges.into_iter().map(|e| EdgeExport {
+                    profile_id: raw.clone(),
+                    other_id:   e.profile_id.as_str(),
+                    since:      e.followed_at,
+                }));
+                match next {
+                    Some(token) => page_token = Some(token),
+                    None => break,
+                }
+            }
+
+            let mut page_token: Option<String> = None;
+            loop {
+                let (edges, next) = self
+                    .repo
+                    .list_blocks(&profile_id, EXPORT_PAGE_SIZE, page_token.as_deref())
+                    .await?;
+                export.blocks.extend(edges.into_iter().map(|e| EdgeExport {
+                    profile_id: raw.clone(),
+                    other_id:   e.blockee_id.as_str(),
+                    since:      e.blocked_at,
+                }));
+                match next {
+                    Some(token) => page_token = Some(token),
+                    None => break,
+                }
+            }
+        }
+
+        Ok(export)
+    }
+}
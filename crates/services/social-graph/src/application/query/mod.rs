@@ -1,8 +1,10 @@
+pub mod export_subject_data;
 pub mod get_relation_status;
 pub mod list_blocks;
 pub mod list_followers;
 pub mod list_following;
 
+pub use export_subject_data::{ExportSubjectDataQuery, ExportSubjectDataHandler};
 pub use get_relation_status::{GetRelationStatusQuery, GetRelationStatusHandler};
 pub use list_blocks::{ListBlocksQuery, ListBlocksHandler};
 pub use list_followers::{ListFollowersQuery, ListFollowersHandler};
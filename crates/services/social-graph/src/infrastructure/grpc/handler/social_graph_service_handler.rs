@@ -8,8 +8,9 @@ use crate::application::command::{
     BlockProfileCommand, FollowProfileCommand, UnblockProfileCommand, UnfollowProfileCommand,
 };
 use crate::application::query::{
-    GetRelationStatusQuery, ListBlocksQuery, ListFollowersQuery, ListFollowingQuery,
+    ExportSubjectDataQuery, GetRelationStatusQuery, ListBlocksQuery, ListFollowersQuery, ListFollowingQuery,
 };
+use crate::application::query::export_subject_data::SubjectDataExport;
 use crate::application::query::get_relation_status::RelationStatusView;
 use crate::domain::entity::{BlockEdge, FollowEdge};
 use crate::domain::value_object::RelationStatus;
@@ -220,6 +221,26 @@ where
             next_page_token: next.unwrap_or_default(),
         }))
     }
+
+    pub async fn export_subject_data(
+        &self,
+        request: Request<proto::ExportSubjectDataRequest>,
+    ) -> Result<Response<proto::ExportSubjectDataResponse>, Status> {
+        let req   = request.into_inner();
+        let query = ExportSubjectDataQuery { profile_ids: req.profile_ids };
+        let export: SubjectDataExport = self
+            .query_bus
+            .dispatch(Envelope::new(Uuid::now_v7(), query))
+            .await
+            .map_err(cqrs_to_status)?;
+
+        let document = serde_json::to_vec(&export)
+            .map_err(|e| Status::internal(format!("encode export document: {e}")))?;
+        Ok(Response::new(proto::ExportSubjectDataResponse {
+            document,
+            record_count: export.record_count(),
+        }))
+    }
 }
 
 // ── Proto conversion helpers ──────────────────────────────────────────────────
@@ -75,4 +75,11 @@ where
     ) -> Result<Response<proto::ListBlocksResponse>, Status> {
         self.list_blocks(request).await
     }
+
+    async fn export_subject_data(
+        &self,
+        request: Request<proto::ExportSubjectDataRequest>,
+    ) -> Result<Response<proto::ExportSubjectDataResponse>, Status> {
+        self.export_subject_data(request).await
+    }
 }
@@ -4,7 +4,6 @@ use async_trait::async_trait;
 use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine as _};
 use chrono::{TimeZone, Utc};
 use scylla::observability::history::HistoryListener;
-use scylla::statement::batch::{Batch, BatchType};
 use scylla::statement::unprepared::Statement;
 use scylla::value::CqlTimestamp;
 use scylla::DeserializeRow;
@@ -15,9 +14,12 @@ use scylla_storage::{ProfileKind as ScyllaProfileKind, ScyllaClient, ScyllaStora
 use crate::application::port::SocialGraphRepository;
 use crate::domain::aggregate::{Relation, RelationContext};
 use crate::domain::entity::{BlockEdge, FollowEdge};
+use crate::domain::event::DomainEvent;
 use crate::domain::value_object::ProfileId;
 use crate::error::SocialGraphError;
 use crate::infrastructure::persistence::model::{BlockRow, FollowRow};
+use crate::infrastructure::publisher::social_graph_outbox::outbox_err;
+use crate::infrastructure::publisher::SocialGraphOutbox;
 
 // ── Page-token types ──────────────────────────────────────────────────────────
 
@@ -55,11 +57,12 @@ fn token_err(field: &'static str, msg: &'static str) -> SocialGraphError {
 
 pub struct ScyllaSocialGraphRepository {
     client: Arc<ScyllaClient>,
+    outbox: SocialGraphOutbox,
 }
 
 impl ScyllaSocialGraphRepository {
-    pub fn new(client: Arc<ScyllaClient>) -> Self {
-        Self { client }
+    pub fn new(client: Arc<ScyllaClient>, outbox: SocialGraphOutbox) -> Self {
+        Self { client, outbox }
     }
 
     fn fast_stmt(&self, cql: &str) -> Statement {
@@ -92,27 +95,6 @@ impl ScyllaSocialGraphRepository {
         s
     }
 
-    /// Builds an empty **logged** batch carrying the Strict execution profile and
-    /// the OTel history listener. A logged batch guarantees that all appended
-    /// statements eventually apply atomically even if the coordinator dies
-    /// mid-write — the consistency primitive that keeps the three denormalized
-    /// follow tables from diverging into a split-brain graph. Callers append the
-    /// statements and supply positional `BatchValues`.
-    fn strict_batch(&self) -> Batch {
-        let mut batch = Batch::new(BatchType::Logged);
-        batch.set_execution_profile_handle(Some(
-            self.client
-                .profiles
-                .get(ScyllaProfileKind::Strict)
-                .clone()
-                .into_handle_with_label("strict-batch".to_string()),
-        ));
-        batch.set_history_listener(
-            Arc::clone(&self.client.history_listener) as Arc<dyn HistoryListener>,
-        );
-        batch
-    }
-
     fn dt_ms(dt: chrono::DateTime<Utc>) -> CqlTimestamp {
         CqlTimestamp(dt.timestamp_millis())
     }
@@ -220,6 +202,7 @@ impl SocialGraphRepository for ScyllaSocialGraphRepository {
         actor_id:    &ProfileId,
         target_id:   &ProfileId,
         followed_at: chrono::DateTime<Utc>,
+        events:      &[DomainEvent],
     ) -> Result<(), SocialGraphError> {
         let ts = Self::dt_ms(followed_at);
 
@@ -228,37 +211,35 @@ impl SocialGraphRepository for ScyllaSocialGraphRepository {
         // appear, or none do. The identical `ts` across all three is what makes the
         // later clustering-key DELETE in `delete_follow` able to find the rows.
         //
-        // The batch spans two partitions (follow_status + following key on
-        // follower_id; followers keys on followee_id), so it is a genuine
-        // multi-partition logged batch — heavier than three unlogged writes, but the
-        // atomicity is required to keep the follow graph consistent at scale.
-        let mut batch = self.strict_batch();
-        batch.append_statement(
-            "INSERT INTO social_graph.follow_status \
-             (follower_id, followee_id, followed_at) VALUES (?, ?, ?)",
-        );
-        batch.append_statement(
-            "INSERT INTO social_graph.following \
-             (follower_id, followed_at, followee_id) VALUES (?, ?, ?)",
-        );
-        batch.append_statement(
-            "INSERT INTO social_graph.followers \
-             (followee_id, followed_at, follower_id) VALUES (?, ?, ?)",
-        );
-
-        let values = (
+        // The batch spans several partitions (follow_status + following key on
+        // follower_id; followers keys on followee_id; the event's outbox slot), so
+        // it is a genuine multi-partition logged batch — heavier than unlogged
+        // writes, but the atomicity is required to keep the follow graph and its
+        // event consistent at scale.
+        let mut batch = self.outbox.batch();
+        batch.write(
+            self.strict_stmt(
+                "INSERT INTO social_graph.follow_status \
+                 (follower_id, followee_id, followed_at) VALUES (?, ?, ?)",
+            ),
             (actor_id.as_uuid(), target_id.as_uuid(), ts),
+        );
+        batch.write(
+            self.strict_stmt(
+                "INSERT INTO social_graph.following \
+                 (follower_id, followed_at, followee_id) VALUES (?, ?, ?)",
+            ),
             (actor_id.as_uuid(), ts, target_id.as_uuid()),
+        );
+        batch.write(
+            self.strict_stmt(
+                "INSERT INTO social_graph.followers \
+                 (followee_id, followed_at, follower_id) VALUES (?, ?, ?)",
+            ),
             (target_id.as_uuid(), ts, actor_id.as_uuid()),
         );
-
-        self.client
-            .session
-            .batch(&batch, values)
-            .await
-            .map_err(scylla_err)?;
-
-        Ok(())
+        self.outbox.enqueue(&mut batch, events)?;
+        batch.execute().await.map_err(outbox_err)
     }
 
     // ── delete_follow ─────────────────────────────────────────────────────────
@@ -268,6 +249,7 @@ impl SocialGraphRepository for ScyllaSocialGraphRepository {
         actor_id:    &ProfileId,
         target_id:   &ProfileId,
         followed_at: chrono::DateTime<Utc>,
+        events:      &[DomainEvent],
     ) -> Result<(), SocialGraphError> {
         // `followed_at` MUST be the exact timestamp stored at follow time — it is the
         // clustering key for the following/followers rows, and a mismatched value
@@ -277,33 +259,30 @@ impl SocialGraphRepository for ScyllaSocialGraphRepository {
 
         // Remove all three rows atomically in a logged batch (mirrors persist_follow)
         // so an interrupted unfollow can never leave the graph half-deleted.
-        let mut batch = self.strict_batch();
-        batch.append_statement(
-            "DELETE FROM social_graph.follow_status \
-             WHERE follower_id = ? AND followee_id = ?",
-        );
-        batch.append_statement(
-            "DELETE FROM social_graph.following \
-             WHERE follower_id = ? AND followed_at = ? AND followee_id = ?",
-        );
-        batch.append_statement(
-            "DELETE FROM social_graph.followers \
-             WHERE followee_id = ? AND followed_at = ? AND follower_id = ?",
-        );
-
-        let values = (
+        let mut batch = self.outbox.batch();
+        batch.write(
+            self.strict_stmt(
+                "DELETE FROM social_graph.follow_status \
+                 WHERE follower_id = ? AND followee_id = ?",
+            ),
             (actor_id.as_uuid(), target_id.as_uuid()),
+        );
+        batch.write(
+            self.strict_stmt(
+                "DELETE FROM social_graph.following \
+                 WHERE follower_id = ? AND followed_at = ? AND followee_id = ?",
+            ),
             (actor_id.as_uuid(), ts, target_id.as_uuid()),
+        );
+        batch.write(
+            self.strict_stmt(
+                "DELETE FROM social_graph.followers \
+                 WHERE followee_id = ? AND followed_at = ? AND follower_id = ?",
+            ),
             (target_id.as_uuid(), ts, actor_id.as_uuid()),
         );
-
-        self.client
-            .session
-            .batch(&batch, values)
-            .await
-            .map_err(scylla_err)?;
-
-        Ok(())
+        self.outbox.enqueue(&mut batch, events)?;
+        batch.execute().await.map_err(outbox_err)
     }
 
     // ── persist_block ─────────────────────────────────────────────────────────
@@ -313,20 +292,18 @@ impl SocialGraphRepository for ScyllaSocialGraphRepository {
         blocker_id: &ProfileId,
         blockee_id: &ProfileId,
         blocked_at: chrono::DateTime<Utc>,
+        events:     &[DomainEvent],
     ) -> Result<(), SocialGraphError> {
-        let stmt = self.strict_stmt(
-            "INSERT INTO social_graph.blocks \
-             (blocker_id, blockee_id, blocked_at) VALUES (?, ?, ?)",
+        let mut batch = self.outbox.batch();
+        batch.write(
+            self.strict_stmt(
+                "INSERT INTO social_graph.blocks \
+                 (blocker_id, blockee_id, blocked_at) VALUES (?, ?, ?)",
+            ),
+            (blocker_id.as_uuid(), blockee_id.as_uuid(), Self::dt_ms(blocked_at)),
         );
-        self.client
-            .session
-            .execute_unpaged(
-                stmt,
-                (blocker_id.as_uuid(), blockee_id.as_uuid(), Self::dt_ms(blocked_at)),
-            )
-            .await
-            .map_err(scylla_err)?;
-        Ok(())
+        self.outbox.enqueue(&mut batch, events)?;
+        batch.execute().await.map_err(outbox_err)
     }
 
     // ── delete_block ──────────────────────────────────────────────────────────
@@ -335,17 +312,18 @@ impl SocialGraphRepository for ScyllaSocialGraphRepository {
         &self,
         blocker_id: &ProfileId,
         blockee_id: &ProfileId,
+        events:     &[DomainEvent],
     ) -> Result<(), SocialGraphError> {
-        let stmt = self.strict_stmt(
-            "DELETE FROM social_graph.blocks \
-             WHERE blocker_id = ? AND blockee_id = ?",
+        let mut batch = self.outbox.batch();
+        batch.write(
+            self.strict_stmt(
+                "DELETE FROM social_graph.blocks \
+                 WHERE blocker_id = ? AND blockee_id = ?",
+            ),
+            (blocker_id.as_uuid(), blockee_id.as_uuid()),
         );
-        self.client
-            .session
-            .execute_unpaged(stmt, (blocker_id.as_uuid(), blockee_id.as_uuid()))
-            .await
-            .map_err(scylla_err)?;
-        Ok(())
+        self.outbox.enqueue(&mut batch, events)?;
+        batch.execute().await.map_err(outbox_err)
     }
 
     // ── list_followers ────────────────────────────────────────────────────────
@@ -1,3 +1,7 @@
-pub mod kafka_event_publisher;
+pub mod social_graph_outbox;
 
-pub use kafka_event_publisher::KafkaEventPublisher;
+pub use social_graph_outbox::SocialGraphOutbox;
+
+/// The keyspace whose `outbox` / `outbox_lease` / `outbox_member` tables carry
+/// social-graph's pending events.
+pub const OUTBOX_KEYSPACE: &str = "social_graph";
@@ -0,0 +1,150 @@
+//! Social-graph's events as `social_graph.outbox` rows.
+//!
+//! Follow, unfollow and block events are written by the repository into the same
+//! logged batch as the adjacency rows that produced them, so an acknowledged
+//! write always carries its event and a failed one carries none. The author-tier
+//! signal is derived from the Redis follower counter after the write, so it has
+//! no batch to join and is enqueued on its own through [`EventPublisher`]. The
+//! shared [`outbox::ScyllaOutboxRelay`] forwards both to Kafka.
+
+use async_trait::async_trait;
+use outbox::{OutboxError, OutboxMessage, ScyllaOutbox, ScyllaOutboxBatch};
+use serde::{Deserialize, Serialize};
+
+use crate::application::port::EventPublisher;
+use crate::domain::event::DomainEvent;
+use crate::domain::value_object::ProfileId;
+use crate::error::SocialGraphError;
+
+const TOPIC_FOLLOWED:   &str = "social-graph.followed";
+const TOPIC_UNFOLLOWED: &str = "social-graph.unfollowed";
+const TOPIC_BLOCKED:    &str = "social-graph.blocked";
+/// The author-tier signal `profile` consumes (then persists + re-emits on
+/// `profile.v1.events` for `post` to denormalize). Keyed by profile id.
+const TOPIC_AUTHOR_TIER_CHANGED: &str = "social-graph.author_tier_changed";
+
+/// A failed batch is a failed write — the adjacency rows were in it; only a
+/// payload that cannot serialize is reported as an outbox violation.
+pub(crate) fn outbox_err(e: OutboxError) -> SocialGraphError {
+    match e {
+        OutboxError::ScyllaStorage(e) => SocialGraphError::Storage(e),
+        other => SocialGraphError::DomainViolation {
+            field:   "outbox".to_owned(),
+            message: other.to_string(),
+        },
+    }
+}
+
+/// The `social_graph.outbox` handle the repository batches its writes through.
+/// Cheap to clone.
+#[derive(Clone)]
+pub struct SocialGraphOutbox {
+    outbox: ScyllaOutbox,
+}
+
+impl SocialGraphOutbox {
+    pub fn new(outbox: ScyllaOutbox) -> Self {
+        Self { outbox }
+    }
+
+    /// Starts the logged batch a repository write goes into.
+    pub(crate) fn batch(&self) -> ScyllaOutboxBatch<'_> {
+        self.outbox.batch()
+    }
+
+    /// Adds one row per published event to `batch`.
+    pub(crate) fn enqueue(
+        &self,
+        batch:  &mut ScyllaOutboxBatch<'_>,
+        events: &[DomainEvent],
+    ) -> Result<(), SocialGraphError> {
+        let mut messages = Vec::with_capacity(events.len());
+        for event in events {
+            messages.extend(message(event).map_err(outbox_err)?);
+        }
+        batch.enqueue(&messages).map_err(outbox_err)?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl EventPublisher for SocialGraphOutbox {
+    async fn publish(&self, event: &DomainEvent) -> Result<(), SocialGraphError> {
+        match message(event).map_err(outbox_err)? {
+            Some(message) => self.outbox.enqueue(&[message]).await.map_err(outbox_err),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Wire payload for `social-graph.author_tier_changed`. `new_tier` is the shared
+/// `u8` taxonomy (0=Standard, 1=Premium, 2=Vip).
+#[derive(Serialize, Deserialize)]
+struct AuthorTierChangedWire {
+    profile_id:    String,
+    new_tier:      u8,
+    follower_count: i64,
+    changed_at_ms: i64,
+}
+
+/// The record for `event`, or `None` for events not published downstream.
+fn message(event: &DomainEvent) -> Result<Option<OutboxMessage>, OutboxError> {
+    let message = match event {
+        DomainEvent::ProfileFollowed(e) => {
+            relationship(TOPIC_FOLLOWED, "ProfileFollowed", &e.actor_id, &e.target_id, e)?
+        }
+        DomainEvent::ProfileUnfollowed(e) => {
+            relationship(TOPIC_UNFOLLOWED, "ProfileUnfollowed", &e.actor_id, &e.target_id, e)?
+        }
+        DomainEvent::ProfileBlocked(e) => {
+            relationship(TOPIC_BLOCKED, "ProfileBlocked", &e.actor_id, &e.target_id, e)?
+        }
+        // ProfileUnblocked is not published downstream per the interface contract.
+        DomainEvent::ProfileUnblocked(_) => return Ok(None),
+        DomainEvent::AuthorTierChanged(e) => {
+            let wire = AuthorTierChangedWire {
+                profile_id:     e.profile_id.as_str(),
+                new_tier:       e.new_tier.as_u8(),
+                follower_count: e.follower_count,
+                changed_at_ms:  e.changed_at.timestamp_millis(),
+            };
+            OutboxMessage::new(TOPIC_AUTHOR_TIER_CHANGED, e.profile_id.as_str(), "AuthorTierChanged", &wire)?
+                .with_header("profile_id", e.profile_id.as_str())
+                .with_header("new_tier",   e.new_tier.as_u8().to_string())
+        }
+    };
+    Ok(Some(message))
+}
+
+/// A follow / unfollow / block record, keyed `actor:target` so one pair's
+/// relationship changes stay ordered.
+fn relationship<T: Serialize>(
+    topic:      &str,
+    event_type: &str,
+    actor_id:   &ProfileId,
+    target_id:  &ProfileId,
+    payload:    &T,
+) -> Result<OutboxMessage, OutboxError> {
+    let key = format!("{actor_id}:{target_id}");
+    Ok(OutboxMessage::new(topic, key, event_type, payload)?
+        .with_header("actor_id",  actor_id.as_str())
+        .with_header("target_id", target_id.as_str()))
+}
+
+#[cfg(test)]
+mod tests {
+    use event_topology::conformance::producer_violations;
+
+    use super::*;
+    use crate::domain::event::{ProfileBlocked, ProfileFollowed, ProfileUnfollowed};
+
+    /// Each social-graph topic carries exactly the payload registered for it in event-topology.
+    #[test]
+    fn social_graph_events_conform_to_the_registered_schemas() {
+        let mut violations = producer_violations::<ProfileFollowed>(TOPIC_FOLLOWED);
+        violations.extend(producer_violations::<ProfileUnfollowed>(TOPIC_UNFOLLOWED));
+        violations.extend(producer_violations::<ProfileBlocked>(TOPIC_BLOCKED));
+        violations.extend(producer_violations::<AuthorTierChangedWire>(TOPIC_AUTHOR_TIER_CHANGED));
+        assert!(violations.is_empty(), "{violations:#?}");
+    }
+}
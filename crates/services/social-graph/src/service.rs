@@ -2,21 +2,32 @@
 //! [`service_runtime::Service`] contract so the shared runtime can host it.
 //!
 //! Domain wiring stays in [`crate::app`]; this module maps env → config, builds
-//! the concrete Kafka event publisher, defers to [`App::build`], registers the
-//! gRPC services, and exposes the backend health probes.
+//! the ScyllaDB outbox and spawns its Kafka relay, defers to
+//! [`App::assemble`], registers the gRPC services, and exposes the backend health
+//! probes.
+//!
+//! Its one inbound integration is GDPR erasure: the shared [`subject_erasure`]
+//! consumer on `account.v1.events` deletes the subject's edges and confirms to
+//! account over `ConfirmSubjectErasure` (resilience binding `account`, endpoint
+//! `SOCIAL_GRAPH_ACCOUNT_GRPC_ENDPOINT`).
 
 use std::sync::Arc;
+use std::time::Duration;
 
 use async_trait::async_trait;
-use cqrs::command::InMemoryCommandBus;
-use cqrs::query::InMemoryQueryBus;
 use redis_storage::RedisConfig;
-use scylla_storage::ScyllaConfig;
-use service_runtime::{HealthProbe, InfraRegistry, Service};
+use outbox::{KafkaOutboxSink, RelayConfig, ScyllaOutbox, ScyllaOutboxRelay, ScyllaOutboxTable};
+use scylla_storage::{ScyllaConfig, ScyllaSessionBuilder};
+use service_runtime::{AccessPolicy, HealthProbe, InfraRegistry, Service};
+use subject_erasure::{
+    run_subject_erasure_consumer, DeletionRequest, EraseCommand, ErasureReporter, GrpcErasureReporter,
+};
 use tonic::service::RoutesBuilder;
 use tonic_reflection::server::Builder as ReflectionBuilder;
-use transport::kafka::config::{KafkaClientConfig, ProducerConfig};
-use transport::kafka::producer::KafkaProducerBuilder;
+use transport::grpc::client::{GrpcClientBuilder, GrpcClientConfig};
+use transport::kafka::config::{ConsumerConfig, KafkaClientConfig, ProducerConfig};
+use transport::kafka::consumer::{KafkaConsumerBuilder, KafkaConsumerHandle};
+use transport::kafka::producer::{KafkaProducerBuilder, KafkaProducerHandle};
 
 use crate::domain::value_object::TierThresholds;
 
@@ -25,6 +36,17 @@ use crate::domain::value_object::TierThresholds;
 const DEFAULT_PREMIUM_FOLLOWERS: i64 = 10_000;
 const DEFAULT_VIP_FOLLOWERS: i64 = 1_000_000;
 
+/// Account lifecycle stream carrying GDPR deletion requests.
+const ACCOUNT_EVENTS_TOPIC: &str = "account.v1.events";
+/// Consumer group for social-graph's part of a GDPR erasure.
+const SUBJECT_ERASURE_GROUP: &str = "social-graph-subject-erasure";
+/// Social-graph's name in account's erasure participant list.
+const ERASURE_PARTICIPANT: &str = "social-graph";
+/// Resilience binding of the erasure confirmations sent to account.
+const ACCOUNT_DEPENDENCY: &str = "account";
+/// Backoff before respawning the consumer after the runner returns.
+const CONSUMER_RESPAWN_BACKOFF: Duration = Duration::from_secs(5);
+
 fn tier_thresholds_from_env() -> TierThresholds {
     TierThresholds::new(
         env_i64("SOCIAL_GRAPH_PREMIUM_FOLLOWER_THRESHOLD", DEFAULT_PREMIUM_FOLLOWERS),
@@ -36,15 +58,15 @@ fn env_i64(key: &str, default: i64) -> i64 {
     std::env::var(key).ok().and_then(|v| v.parse().ok()).unwrap_or(default)
 }
 
-use crate::app::{App, Backends};
-use crate::application::port::EventPublisher;
+use crate::app::{App, AppCommandBus, AppQueryBus};
+use crate::application::command::EraseSubjectDataCommand;
 use crate::infrastructure::grpc::handler::social_graph_service_handler::SocialGraphServiceServer;
 use crate::infrastructure::grpc::handler::SocialGraphServiceHandler;
 use crate::infrastructure::grpc::server::FILE_DESCRIPTOR_SET;
-use crate::infrastructure::publisher::KafkaEventPublisher;
+use crate::infrastructure::publisher::{SocialGraphOutbox, OUTBOX_KEYSPACE};
 
 type SocialGraphServer =
-    SocialGraphServiceServer<SocialGraphServiceHandler<Arc<InMemoryCommandBus>, Arc<InMemoryQueryBus>>>;
+    SocialGraphServiceServer<SocialGraphServiceHandler<Arc<AppCommandBus>, Arc<AppQueryBus>>>;
 
 /// The social-graph service as hosted by [`service_runtime`].
 pub struct SocialGraphService {
@@ -58,21 +80,48 @@ impl Service for SocialGraphService {
     const GRPC_SERVICE_NAME: &'static str =
         <SocialGraphServer as tonic::server::NamedService>::NAME;
 
-    async fn build(_infra: Arc<InfraRegistry>) -> anyhow::Result<Self> {
-        let backends = Backends {
-            scylla: ScyllaConfig::from_env(),
-            redis:  RedisConfig::from_env(),
-        };
+    async fn build(infra: Arc<InfraRegistry>) -> anyhow::Result<Self> {
+        let scylla = Arc::new(
+            ScyllaSessionBuilder::new(ScyllaConfig::from_env())
+                .build()
+                .await
+                .map_err(|e| anyhow::anyhow!("social-graph scylla session: {e}"))?,
+        );
 
-        // Social-graph always publishes downstream events; build the durable
-        // Kafka publisher from env.
+        // Social-graph always publishes downstream events: the repository writes
+        // them into social_graph.outbox with the edges, and the relay forwards
+        // them to the broker.
         let producer = KafkaProducerBuilder::new(ProducerConfig::new(KafkaClientConfig::from_env()))
             .build()?;
-        let publisher: Arc<dyn EventPublisher> = Arc::new(KafkaEventPublisher::new(producer));
+        let table = ScyllaOutboxTable::new(OUTBOX_KEYSPACE)?;
+        let relay = ScyllaOutboxRelay::new(
+            Arc::clone(&scylla),
+            table.clone(),
+            Arc::new(KafkaOutboxSink::new(producer)),
+            RelayConfig::from_env(),
+        );
+        let outbox = SocialGraphOutbox::new(ScyllaOutbox::new(Arc::clone(&scylla), table));
 
-        let app = App::build(backends, publisher, tier_thresholds_from_env())
+        let app = App::assemble(scylla, RedisConfig::from_env(), outbox, tier_thresholds_from_env())
             .await
             .map_err(|e| anyhow::anyhow!("social-graph app build: {e}"))?;
+        tokio::spawn(relay.run());
+
+        // The channel connects lazily; account being down only delays the
+        // confirmation (the consumer retries).
+        let account = GrpcClientBuilder::new(
+            GrpcClientConfig::new(
+                std::env::var("SOCIAL_GRAPH_ACCOUNT_GRPC_ENDPOINT")
+                    .unwrap_or_else(|_| "http://localhost:50059".to_owned()),
+            )
+            .with_dependency(ACCOUNT_DEPENDENCY),
+        )
+        .build_from_registry_lazy(&infra.resilience())
+        .map_err(|e| anyhow::anyhow!("social-graph account channel: {e}"))?;
+        spawn_subject_erasure_consumer(
+            Arc::clone(&app.command_bus),
+            Arc::new(GrpcErasureReporter::new(account, ERASURE_PARTICIPANT)),
+        );
 
         Ok(Self { app })
     }
@@ -84,6 +133,21 @@ impl Service for SocialGraphService {
         ]
     }
 
+    fn access_policy(&self) -> AccessPolicy {
+        // The relation reads also serve timeline fan-out and counter reconciliation.
+        AccessPolicy::for_service(Self::GRPC_SERVICE_NAME)
+            .authenticated("Follow")
+            .authenticated("Unfollow")
+            .authenticated("Block")
+            .authenticated("Unblock")
+            .authenticated("ListBlocks")
+            .internal_or_authenticated("GetRelationStatus")
+            .internal_or_authenticated("ListFollowers")
+            .internal_or_authenticated("ListFollowing")
+            // Account's GDPR data-export pipeline.
+            .internal("ExportSubjectData")
+    }
+
     fn register(self, routes: &mut RoutesBuilder) -> anyhow::Result<()> {
         let handler = SocialGraphServiceHandler::new(
             Arc::clone(&self.app.command_bus),
@@ -98,3 +162,46 @@ impl Service for SocialGraphService {
         Ok(())
     }
 }
+
+/// Spawns the supervised subject-erasure consumer, respawning after
+/// [`CONSUMER_RESPAWN_BACKOFF`] whenever the runner returns.
+fn spawn_subject_erasure_consumer(command_bus: Arc<AppCommandBus>, reporter: Arc<dyn ErasureReporter>) {
+    let eraser = Arc::new(EraseCommand::new(command_bus, |request: &DeletionRequest| {
+        EraseSubjectDataCommand { profile_ids: request.profile_ids.clone() }
+    }));
+    tokio::spawn(async move {
+        loop {
+            match build_consumer() {
+                Ok((consumer, producer)) => {
+                    run_subject_erasure_consumer(
+                        ERASURE_PARTICIPANT,
+                        consumer,
+                        Arc::clone(&eraser),
+                        Arc::clone(&reporter),
+                        producer,
+                    )
+                    .await;
+                    tracing::warn!("subject-erasure consumer exited; respawning after backoff");
+                }
+                Err(error) => {
+                    tracing::error!(%error, "failed to build subject-erasure consumer; retrying");
+                }
+            }
+            tokio::time::sleep(CONSUMER_RESPAWN_BACKOFF).await;
+        }
+    });
+}
+
+/// Builds the manual-commit consumer (subscribed to `account.v1.events`) and the
+/// dead-letter producer the runner needs.
+fn build_consumer() -> anyhow::Result<(KafkaConsumerHandle, KafkaProducerHandle)> {
+    let kafka = KafkaClientConfig::from_env();
+    let consumer = KafkaConsumerBuilder::new(ConsumerConfig::new(kafka.clone(), SUBJECT_ERASURE_GROUP))
+        .subscribe(ACCOUNT_EVENTS_TOPIC)
+        .build()
+        .map_err(|e| anyhow::anyhow!("build subject-erasure consumer: {e}"))?;
+    let producer = KafkaProducerBuilder::new(ProducerConfig::new(kafka))
+        .build()
+        .map_err(|e| anyhow::anyhow!("build subject-erasure dead-letter producer: {e}"))?;
+    Ok((consumer, producer))
+}
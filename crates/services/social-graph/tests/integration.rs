@@ -10,8 +10,8 @@
 //!
 //! It boots ephemeral ScyllaDB and Redis containers via `test-support`, applies
 //! the `.cql` migrations (single-node RF=1), and drives the service through the
-//! production composition root ([`social_graph::app::App`]) with an in-process
-//! no-op event publisher:
+//! production composition root ([`social_graph::app::App`]) with no outbox relay
+//! running:
 //!
 //! - **adjacency consistency** — a follow lands in both the `followers` and
 //!   `following` adjacency tables, and concurrent follows of one target leave the
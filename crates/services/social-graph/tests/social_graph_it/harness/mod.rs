@@ -1,27 +1,22 @@
 //! Integration harness: boots the shared infra, wires a real social-graph graph
 //! against it through the production composition root, and exposes the buses for
-//! assertions. The event publisher is an in-process no-op (these scenarios assert
-//! on the persisted adjacency tables, not on emitted events).
+//! assertions. No relay runs, so emitted events simply stay in
+//! `social_graph.outbox` (these scenarios assert on the persisted adjacency
+//! tables, not on emitted events).
 #![allow(dead_code)]
 
 use std::sync::Arc;
 use std::time::Duration;
 
-use async_trait::async_trait;
 use uuid::Uuid;
 
-use cqrs::command::InMemoryCommandBus;
-use cqrs::query::InMemoryQueryBus;
 use cqrs::{CommandBus, CqrsError, Envelope, QueryBus};
 use redis_storage::RedisConfig;
 use scylla_storage::ScyllaConfig;
 
-use social_graph::app::{App, Backends};
+use social_graph::app::{App, AppCommandBus, AppQueryBus, Backends};
 use social_graph::application::command::{BlockProfileCommand, FollowProfileCommand};
-use social_graph::application::port::EventPublisher;
 use social_graph::application::query::{ListFollowersQuery, ListFollowingQuery};
-use social_graph::domain::event::DomainEvent;
-use social_graph::error::SocialGraphError;
 
 pub use social_graph::domain::value_object::ProfileId;
 pub use test_support::await_until;
@@ -35,26 +30,15 @@ const KEYSPACE: &str = "social_graph";
 /// On-disk migration assets, resolved against *this* crate's manifest.
 const MIGRATIONS_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/migrations");
 
-/// A no-op event publisher: the graph-consistency scenarios assert on ScyllaDB,
-/// not on the Kafka contract.
-struct NoopPublisher;
-
-#[async_trait]
-impl EventPublisher for NoopPublisher {
-    async fn publish(&self, _event: &DomainEvent) -> Result<(), SocialGraphError> {
-        Ok(())
-    }
-}
-
 /// A fully-wired social-graph service bound to ephemeral infra, plus the buses.
 pub struct TestHarness {
-    pub command_bus: Arc<InMemoryCommandBus>,
-    pub query_bus:   Arc<InMemoryQueryBus>,
+    pub command_bus: Arc<AppCommandBus>,
+    pub query_bus:   Arc<AppQueryBus>,
 }
 
 impl TestHarness {
     /// Boots/reuses the shared containers, applies migrations, and assembles the
-    /// service graph with a no-op event publisher.
+    /// service graph.
     pub async fn start() -> Self {
         let scylla_cp = test_support::containers::scylla_ready(KEYSPACE, MIGRATIONS_DIR).await;
         let redis_endpoint = test_support::containers::redis_endpoint().await;
@@ -70,7 +54,6 @@ impl TestHarness {
 
         let app = App::build(
             backends,
-            Arc::new(NoopPublisher) as Arc<dyn EventPublisher>,
             social_graph::domain::value_object::TierThresholds::new(10_000, 1_000_000),
         )
         .await
@@ -126,7 +109,7 @@ impl TestHarness {
 /// many concurrently from spawned tasks. Returns the dispatch result so a
 /// scenario can assert a block-gated re-follow is rejected.
 pub async fn dispatch_follow(
-    command_bus: Arc<InMemoryCommandBus>,
+    command_bus: Arc<AppCommandBus>,
     actor_id:    String,
     target_id:   String,
 ) -> Result<(), CqrsError> {
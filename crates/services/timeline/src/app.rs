@@ -22,7 +22,12 @@ use std::collections::HashSet;
 use std::sync::{Arc, Mutex};
 
 use cqrs::command::{CommandBusBuilder, InMemoryCommandBus};
+use cqrs::middleware::{
+    IdempotencyCommandBus, IdempotencyLayer, MetricsCommandBus, MetricsLayer,
+    MetricsQueryBus, MiddlewarePipeline,
+};
 use cqrs::query::{InMemoryQueryBus, QueryBusBuilder};
+use idempotency::RedisIdempotencyStore;
 use redis_storage::{RedisClient, RedisClientBuilder, RedisConfig};
 use scylla_storage::{ScyllaClient, ScyllaConfig, ScyllaSessionBuilder};
 use tokio::sync::Semaphore;
@@ -64,6 +69,18 @@ pub struct Backends {
     pub kafka:  Option<KafkaClientConfig>,
 }
 
+/// The command bus every entrypoint dispatches through: the registered handlers
+/// behind an [`IdempotencyLayer`] on the service's Redis, so a retried `message_id`
+/// runs once across replicas. It fails open: a Redis outage costs deduplication,
+/// not the write path.
+/// A [`MetricsLayer`] outermost times every dispatch, replays included.
+pub type AppCommandBus =
+    MetricsCommandBus<IdempotencyCommandBus<InMemoryCommandBus, RedisIdempotencyStore>>;
+
+/// The query bus every read path dispatches through: the registered handlers,
+/// timed by a [`MetricsLayer`].
+pub type AppQueryBus = MetricsQueryBus<InMemoryQueryBus>;
+
 /// The tuning surface threaded through the graph. Production fills this from
 /// [`TimelineConfig`](crate::config::TimelineConfig); scenarios shrink the caps
 /// and TTLs to force eviction and warm-flag expiry in seconds.
@@ -92,8 +109,8 @@ pub struct AppConfig {
 /// handles a scenario asserts against. The buses, cache adapters, and
 /// repositories exposed here are the *same* instances the handlers hold.
 pub struct App {
-    pub command_bus:      Arc<InMemoryCommandBus>,
-    pub query_bus:        Arc<InMemoryQueryBus>,
+    pub command_bus:      Arc<AppCommandBus>,
+    pub query_bus:        Arc<AppQueryBus>,
     pub feed_store:       Arc<dyn FeedStore>,
     pub vip_registry:     Arc<dyn VipRegistry>,
     pub tier_cache:       Arc<dyn TierCache>,
@@ -138,81 +155,92 @@ impl App {
         let audio_feed_repo = Arc::new(ScyllaAudioFeedRepository::new(Arc::clone(&scylla_client)));
 
         // ── Command bus ──────────────────────────────────────────────────────
+        let handlers = CommandBusBuilder::new()
+            .register::<IngestPostPublishedCommand, _>(IngestPostPublishedHandler {
+                feed_store:             Arc::clone(&feed_store),
+                vip_registry:           Arc::clone(&vip_registry),
+                feed_repository:        Arc::clone(&feed_repository),
+                author_post_repo:       Arc::clone(&author_post_repo),
+                tier_cache:             Arc::clone(&tier_cache),
+                social_graph:           Arc::clone(&social_graph),
+                audio_feed_repo:        Arc::clone(&audio_feed_repo),
+                audio_feed_store:       Arc::clone(&audio_feed_store),
+                feed_cap:               config.feed_cap,
+                vip_registry_cap:       config.vip_registry_cap,
+                vip_registry_ttl_secs:  config.vip_registry_ttl_secs,
+                tier_cache_ttl_secs:    config.tier_cache_ttl_secs,
+                social_graph_page_size: config.social_graph_page_size,
+                audio_feed_cap:         config.audio_feed_cap,
+            })?
+            .register::<RemovePostCommand, _>(RemovePostHandler {
+                feed_store:       Arc::clone(&feed_store),
+                vip_registry:     Arc::clone(&vip_registry),
+                feed_repository:  Arc::clone(&feed_repository),
+                author_post_repo: Arc::clone(&author_post_repo),
+                tier_cache:       Arc::clone(&tier_cache),
+            })?
+            .register::<BackfillFollowCommand, _>(BackfillFollowHandler {
+                feed_store:       Arc::clone(&feed_store),
+                feed_repository:  Arc::clone(&feed_repository),
+                author_post_repo: Arc::clone(&author_post_repo),
+                tier_cache:       Arc::clone(&tier_cache),
+                following_store:  Arc::clone(&following_store),
+                feed_cap:         config.feed_cap,
+                backfill_limit:   config.backfill_limit,
+            })?
+            .register::<PruneFollowCommand, _>(PruneFollowHandler {
+                feed_store:       Arc::clone(&feed_store),
+                feed_repository:  Arc::clone(&feed_repository),
+                tier_cache:       Arc::clone(&tier_cache),
+                following_store:  Arc::clone(&following_store),
+            })?
+            .register::<IngestAudioIndexCommand, _>(IngestAudioIndexHandler {
+                audio_feed_repo:  Arc::clone(&audio_feed_repo),
+                audio_feed_store: Arc::clone(&audio_feed_store),
+                audio_feed_cap:   config.audio_feed_cap,
+            })?
+            .build();
         let command_bus = Arc::new(
-            CommandBusBuilder::new()
-                .register::<IngestPostPublishedCommand, _>(IngestPostPublishedHandler {
-                    feed_store:             Arc::clone(&feed_store),
-                    vip_registry:           Arc::clone(&vip_registry),
-                    feed_repository:        Arc::clone(&feed_repository),
-                    author_post_repo:       Arc::clone(&author_post_repo),
-                    tier_cache:             Arc::clone(&tier_cache),
-                    social_graph:           Arc::clone(&social_graph),
-                    audio_feed_repo:        Arc::clone(&audio_feed_repo),
-                    audio_feed_store:       Arc::clone(&audio_feed_store),
-                    feed_cap:               config.feed_cap,
-                    vip_registry_cap:       config.vip_registry_cap,
-                    vip_registry_ttl_secs:  config.vip_registry_ttl_secs,
-                    tier_cache_ttl_secs:    config.tier_cache_ttl_secs,
-                    social_graph_page_size: config.social_graph_page_size,
-                    audio_feed_cap:         config.audio_feed_cap,
-                })?
-                .register::<RemovePostCommand, _>(RemovePostHandler {
-                    feed_store:       Arc::clone(&feed_store),
-                    vip_registry:     Arc::clone(&vip_registry),
-                    feed_repository:  Arc::clone(&feed_repository),
-                    author_post_repo: Arc::clone(&author_post_repo),
-                    tier_cache:       Arc::clone(&tier_cache),
-                })?
-                .register::<BackfillFollowCommand, _>(BackfillFollowHandler {
-                    feed_store:       Arc::clone(&feed_store),
-                    feed_repository:  Arc::clone(&feed_repository),
-                    author_post_repo: Arc::clone(&author_post_repo),
-                    tier_cache:       Arc::clone(&tier_cache),
-                    following_store:  Arc::clone(&following_store),
-                    feed_cap:         config.feed_cap,
-                    backfill_limit:   config.backfill_limit,
-                })?
-                .register::<PruneFollowCommand, _>(PruneFollowHandler {
-                    feed_store:       Arc::clone(&feed_store),
-                    feed_repository:  Arc::clone(&feed_repository),
-                    tier_cache:       Arc::clone(&tier_cache),
-                    following_store:  Arc::clone(&following_store),
-                })?
-                .register::<IngestAudioIndexCommand, _>(IngestAudioIndexHandler {
-                    audio_feed_repo:  Arc::clone(&audio_feed_repo),
-                    audio_feed_store: Arc::clone(&audio_feed_store),
-                    audio_feed_cap:   config.audio_feed_cap,
-                })?
+            MiddlewarePipeline::new(handlers)
+                .layer(
+                    IdempotencyLayer::new(RedisIdempotencyStore::new(redis_client.clone(), "timeline"))
+                        .fail_open(),
+                )
+                .layer(MetricsLayer::new())
                 .build(),
         );
 
         // ── Query bus ────────────────────────────────────────────────────────
         let query_bus = Arc::new(
-            QueryBusBuilder::new()
-                .register::<GetFollowingFeedQuery, _>(GetFollowingFeedHandler {
-                    feed_store:             Arc::clone(&feed_store),
-                    vip_registry:           Arc::clone(&vip_registry),
-                    feed_repository:        Arc::clone(&feed_repository),
-                    author_post_repo:       Arc::clone(&author_post_repo),
-                    tier_cache:             Arc::clone(&tier_cache),
-                    following_store:        Arc::clone(&following_store),
-                    social_graph:           Arc::clone(&social_graph),
-                    max_page_size:          config.max_page_size,
-                    feed_cap:               config.feed_cap,
-                    vip_registry_cap:       config.vip_registry_cap,
-                    vip_registry_ttl_secs:  config.vip_registry_ttl_secs,
-                    warm_ttl_secs:          config.warm_ttl_secs,
-                    social_graph_page_size: config.social_graph_page_size,
-                    max_vip_merge_sources:  config.max_vip_merge_sources,
-                    warm_semaphore:         Arc::new(Semaphore::new(config.warm_max_concurrency)),
-                    warming:                Arc::new(Mutex::new(HashSet::new())),
-                })?
-                .register::<GetAudioFeedQuery, _>(GetAudioFeedHandler {
-                    audio_feed_store: Arc::clone(&audio_feed_store),
-                    audio_feed_repo:  Arc::clone(&audio_feed_repo),
-                    max_page_size:    config.max_page_size,
-                })?
-                .build(),
+            MiddlewarePipeline::new(
+                QueryBusBuilder::new()
+                    .register::<GetFollowingFeedQuery, _>(GetFollowingFeedHandler {
+                        feed_store:             Arc::clone(&feed_store),
+                        vip_registry:           Arc::clone(&vip_registry),
+                        feed_repository:        Arc::clone(&feed_repository),
+                        author_post_repo:       Arc::clone(&author_post_repo),
+                        tier_cache:             Arc::clone(&tier_cache),
+                        following_store:        Arc::clone(&following_store),
+                        social_graph:           Arc::clone(&social_graph),
+                        max_page_size:          config.max_page_size,
+                        feed_cap:               config.feed_cap,
+                        vip_registry_cap:       config.vip_registry_cap,
+                        vip_registry_ttl_secs:  config.vip_registry_ttl_secs,
+                        warm_ttl_secs:          config.warm_ttl_secs,
+                        social_graph_page_size: config.social_graph_page_size,
+                        max_vip_merge_sources:  config.max_vip_merge_sources,
+                        warm_semaphore:         Arc::new(Semaphore::new(config.warm_max_concurrency)),
+                        warming:                Arc::new(Mutex::new(HashSet::new())),
+                    })?
+                    .register::<GetAudioFeedQuery, _>(GetAudioFeedHandler {
+                        audio_feed_store: Arc::clone(&audio_feed_store),
+                        audio_feed_repo:  Arc::clone(&audio_feed_repo),
+                        max_page_size:    config.max_page_size,
+                    })?
+                    .build(),
+            )
+            .query_layer(MetricsLayer::new())
+            .build(),
         );
 
         // ── Ingestion workers (Kafka path) ───────────────────────────────────
@@ -28,7 +28,9 @@ pub struct BackfillFollowCommand {
     pub followee_id: String,
 }
 
-impl Command for BackfillFollowCommand {}
+impl Command for BackfillFollowCommand {
+    type Output = ();
+}
 
 impl Validate for BackfillFollowCommand {
     fn validate(&self) -> Result<(), Vec<FieldViolation>> {
@@ -14,7 +14,9 @@ pub struct IngestAudioIndexCommand {
     pub published_at_ms: i64,
 }
 
-impl Command for IngestAudioIndexCommand {}
+impl Command for IngestAudioIndexCommand {
+    type Output = ();
+}
 
 impl Validate for IngestAudioIndexCommand {
     fn validate(&self) -> Result<(), Vec<FieldViolation>> {
@@ -18,7 +18,9 @@ pub struct IngestPostPublishedCommand {
     pub audio_id:        Option<String>,
 }
 
-impl Command for IngestPostPublishedCommand {}
+impl Command for IngestPostPublishedCommand {
+    type Output = ();
+}
 
 impl Validate for IngestPostPublishedCommand {
     fn validate(&self) -> Result<(), Vec<FieldViolation>> {
@@ -32,7 +32,9 @@ pub struct PruneFollowCommand {
     pub followee_id: String,
 }
 
-impl Command for PruneFollowCommand {}
+impl Command for PruneFollowCommand {
+    type Output = ();
+}
 
 impl Validate for PruneFollowCommand {
     fn validate(&self) -> Result<(), Vec<FieldViolation>> {
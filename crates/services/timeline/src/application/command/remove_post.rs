@@ -9,7 +9,7 @@ use crate::application::port::{
 use crate::domain::value_object::{AuthorId, AuthorTier, FanOutMode, PostId};
 use crate::error::TimelineError;
 
-/// Triggered by `PostDeletedWorker` when a `post.deleted` Kafka event arrives.
+/// Triggered by `PostDeletedWorker` when a `PostDeleted` event arrives on `post.v1.events`.
 ///
 /// Deletion strategy by tier:
 ///   Vip: ZREM from `timeline:vip:{author_id}` + DELETE from `posts_by_author`.
@@ -30,7 +30,9 @@ pub struct RemovePostCommand {
     pub published_at_ms: i64,
 }
 
-impl Command for RemovePostCommand {}
+impl Command for RemovePostCommand {
+    type Output = ();
+}
 
 impl Validate for RemovePostCommand {
     fn validate(&self) -> Result<(), Vec<FieldViolation>> {
@@ -10,7 +10,7 @@ use crate::error::TimelineError;
 /// All hot-path reads are served by Redis. This port is called:
 ///   - On write: fan-out INSERT per follower (background, fire-and-forget)
 ///   - On cold-start: range scan per profile to rebuild Redis ZSET
-///   - On post.deleted: DELETE per post_id (best-effort, background)
+///   - On PostDeleted: DELETE per post_id (best-effort, background)
 ///   - On follow.deleted: range scan + DELETE per author_id in a partition
 #[async_trait]
 pub trait FeedRepository: Send + Sync + 'static {
@@ -40,7 +40,7 @@ pub trait FeedRepository: Send + Sync + 'static {
         limit:      i32,
     ) -> Result<Vec<FeedEntry>, TimelineError>;
 
-    /// Deletes a specific (profile, post) entry. Used on post.deleted for
+    /// Deletes a specific (profile, post) entry. Used on PostDeleted for
     /// followers of Standard/Premium authors. Idempotent (no-op if absent).
     async fn delete(
         &self,
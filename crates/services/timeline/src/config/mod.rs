@@ -61,7 +61,8 @@ pub struct TimelineConfig {
     /// `post.v1.events` stream).
     pub kafka_group_post_published: String,
 
-    /// Kafka consumer group ID for the post.deleted worker.
+    /// Kafka consumer group ID for the post-deletion worker (`PostDeleted` on
+    /// `post.v1.events`).
     pub kafka_group_post_deleted: String,
 
     /// Kafka consumer group ID for the social-graph.followed worker.
@@ -1,4 +1,6 @@
 use async_trait::async_trait;
+use resilience::hedge::HedgeLayer;
+use tower::{service_fn, Layer, Service, ServiceExt};
 use transport::grpc::client::ResilientChannel;
 
 use crate::application::port::SocialGraphClient;
@@ -24,6 +26,10 @@ use sg_proto::{
 /// configured from the `social-graph` resilience binding and hot-reloaded with it (see
 /// [`crate::service`]). The breaker/timeout therefore wrap every paginated call below.
 ///
+/// `ListFollowing` pages are additionally hedged (from the same binding's `hedge` policy):
+/// they sit on the cold-start feed read path, where one slow replica stalls the whole
+/// rebuild. The follower fan-out runs in a Kafka worker with no latency SLA, so it isn't.
+///
 /// Both `list_all_followers` and `list_all_following` paginate internally
 /// until `next_page_token` is empty, returning the complete flattened list.
 /// This is safe because:
@@ -32,11 +38,12 @@ use sg_proto::{
 ///     the `TIMELINE_WARM_TTL_SECS` (default 24h) window.
 pub struct SocialGraphGrpcClient {
     channel: ResilientChannel,
+    following_hedge: HedgeLayer,
 }
 
 impl SocialGraphGrpcClient {
-    pub fn new(channel: ResilientChannel) -> Self {
-        Self { channel }
+    pub fn new(channel: ResilientChannel, following_hedge: HedgeLayer) -> Self {
+        Self { channel, following_hedge }
     }
 
     fn client(&self) -> SocialGraphServiceClient<ResilientChannel> {
@@ -89,18 +96,30 @@ impl SocialGraphClient for SocialGraphGrpcClient {
         profile_id: &ProfileId,
         page_size:  i32,
     ) -> Result<Vec<AuthorId>, TimelineError> {
-        let mut client       = self.client();
+        let client           = self.client();
+        let mut list_page    = self.following_hedge.layer(service_fn(
+            move |req: ListFollowingRequest| {
+                let mut client = client.clone();
+                async move { client.list_following(req).await }
+            },
+        ));
         let mut all_ids      = Vec::new();
         let mut page_token   = String::new();
         let follower_id_str  = profile_id.to_string();
 
         loop {
-            let resp = client
-                .list_following(ListFollowingRequest {
-                    follower_id: follower_id_str.clone(),
-                    limit:       page_size,
-                    page_token:  page_token.clone(),
-                })
+            let request = ListFollowingRequest {
+                follower_id: follower_id_str.clone(),
+                limit:       page_size,
+                page_token:  page_token.clone(),
+            };
+            let resp = list_page
+                .ready()
+                .await
+                .map_err(|e| TimelineError::SocialGraphClientError {
+                    message: e.to_string(),
+                })?
+                .call(request)
                 .await
                 .map_err(|e| TimelineError::SocialGraphClientError {
                     message: e.to_string(),
@@ -2,7 +2,7 @@ use std::sync::Arc;
 
 use serde::Deserialize;
 use transport::kafka::consumer::builder::KafkaConsumerBuilder;
-use transport::kafka::consumer::{run_consumer, RetryPolicy};
+use transport::kafka::consumer::{run_consumer_keyed, KeyedConcurrency, RetryPolicy};
 use transport::kafka::config::client::KafkaClientConfig;
 use transport::kafka::config::consumer::{AutoOffsetReset, ConsumerConfig};
 use transport::kafka::producer::KafkaProducerHandle;
@@ -20,7 +20,7 @@ const TOPIC: &str = "social-graph.followed";
 /// Published by services/social-graph when a profile follows another profile.
 /// `actor_id` is the follower; `target_id` is the new followee.
 #[derive(Debug, Deserialize)]
-struct ProfileFollowedEvent {
+pub struct ProfileFollowedEvent {
     pub actor_id:  String,
     pub target_id: String,
 }
@@ -88,7 +88,8 @@ impl<CB: CommandBus + 'static> FollowCreatedWorker<CB> {
         tracing::info!(topic = TOPIC, group = %self.group_id, "consumer started");
 
         let policy = RetryPolicy::default();
-        run_consumer::<ProfileFollowedEvent, _>(&handle, producer, &policy, move |event| {
+        let concurrency = KeyedConcurrency::from_env();
+        run_consumer_keyed::<ProfileFollowedEvent, _>(&handle, producer, &policy, &concurrency, move |event| {
             let worker = Arc::clone(&self);
             Box::pin(async move { dispatch_outcome(worker.process(event).await) })
         })
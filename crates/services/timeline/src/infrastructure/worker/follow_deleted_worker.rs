@@ -2,7 +2,7 @@ use std::sync::Arc;
 
 use serde::Deserialize;
 use transport::kafka::consumer::builder::KafkaConsumerBuilder;
-use transport::kafka::consumer::{run_consumer, RetryPolicy};
+use transport::kafka::consumer::{run_consumer_keyed, KeyedConcurrency, RetryPolicy};
 use transport::kafka::config::client::KafkaClientConfig;
 use transport::kafka::config::consumer::{AutoOffsetReset, ConsumerConfig};
 use transport::kafka::producer::KafkaProducerHandle;
@@ -17,7 +17,7 @@ const TOPIC: &str = "social-graph.unfollowed";
 
 /// Kafka event schema for `social-graph.unfollowed`.
 #[derive(Debug, Deserialize)]
-struct ProfileUnfollowedEvent {
+pub struct ProfileUnfollowedEvent {
     pub actor_id:  String,
     pub target_id: String,
 }
@@ -85,7 +85,8 @@ impl<CB: CommandBus + 'static> FollowDeletedWorker<CB> {
         tracing::info!(topic = TOPIC, group = %self.group_id, "consumer started");
 
         let policy = RetryPolicy::default();
-        run_consumer::<ProfileUnfollowedEvent, _>(&handle, producer, &policy, move |event| {
+        let concurrency = KeyedConcurrency::from_env();
+        run_consumer_keyed::<ProfileUnfollowedEvent, _>(&handle, producer, &policy, &concurrency, move |event| {
             let worker = Arc::clone(&self);
             Box::pin(async move { dispatch_outcome(worker.process(event).await) })
         })
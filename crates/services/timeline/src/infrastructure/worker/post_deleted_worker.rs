@@ -1,8 +1,7 @@
 use std::sync::Arc;
 
-use serde::Deserialize;
 use transport::kafka::consumer::builder::KafkaConsumerBuilder;
-use transport::kafka::consumer::{run_consumer, RetryPolicy};
+use transport::kafka::consumer::{run_consumer_keyed, KeyedConcurrency, RetryPolicy};
 use transport::kafka::config::client::KafkaClientConfig;
 use transport::kafka::config::consumer::{AutoOffsetReset, ConsumerConfig};
 use transport::kafka::producer::KafkaProducerHandle;
@@ -11,29 +10,19 @@ use uuid::Uuid;
 use cqrs::{CommandBus, CqrsError, Envelope};
 
 use crate::application::command::remove_post::RemovePostCommand;
+use crate::infrastructure::worker::post_published_worker::PostV1Event;
 use crate::infrastructure::worker::{build_dlq_producer, dispatch_outcome};
 
-const TOPIC: &str = "post.deleted";
+const TOPIC: &str = "post.v1.events";
 
-/// Kafka event schema for `post.deleted`.
+/// Tears deleted posts out of feeds. Reads the unified `post.v1.events` stream
+/// under its own consumer group and acts on **PostDeleted** only; the other
+/// variants commit without work (publication is `post_published_worker`'s).
 ///
-/// Published by services/post when a post is soft-deleted or hard-deleted.
-/// `author_tier` and `published_at_ms` may be absent in legacy schemas;
-/// defaults are applied for backwards compatibility.
-#[derive(Debug, Deserialize)]
-struct PostDeletedEvent {
-    pub post_id:  String,
-    #[serde(alias = "author_id")]
-    pub profile_id: String,
-    #[serde(default)]
-    pub author_tier: u8,
-    /// Needed for ScyllaDB DELETE (clustering key). Absent → 0 (best-effort delete).
-    #[serde(default)]
-    pub published_at_ms: i64,
-    #[serde(alias = "deleted_at_ms")]
-    pub _deleted_at_ms: Option<i64>,
-}
-
+/// `PostDeleted` carries neither `author_tier` nor `published_at_ms`, so both
+/// decode to their defaults: the removal is routed as Standard and the ScyllaDB
+/// DELETE is best-effort on the clustering key, as it was on the retired
+/// `post.deleted` topic.
 pub struct PostDeletedWorker<CB> {
     kafka_config: KafkaClientConfig,
     command_bus:  Arc<CB>,
@@ -89,7 +78,8 @@ impl<CB: CommandBus + 'static> PostDeletedWorker<CB> {
         tracing::info!(topic = TOPIC, group = %self.group_id, "consumer started");
 
         let policy = RetryPolicy::default();
-        run_consumer::<PostDeletedEvent, _>(&handle, producer, &policy, move |event| {
+        let concurrency = KeyedConcurrency::from_env();
+        run_consumer_keyed::<PostV1Event, _>(&handle, producer, &policy, &concurrency, move |event| {
             let worker = Arc::clone(&self);
             Box::pin(async move { dispatch_outcome(worker.process(event).await) })
         })
@@ -97,7 +87,10 @@ impl<CB: CommandBus + 'static> PostDeletedWorker<CB> {
         .map_err(|e| e.to_string())
     }
 
-    async fn process(&self, event: &PostDeletedEvent) -> Result<(), CqrsError> {
+    async fn process(&self, event: &PostV1Event) -> Result<(), CqrsError> {
+        if !event.is_deleted() {
+            return Ok(());
+        }
         let cmd = RemovePostCommand {
             post_id:         event.post_id.clone(),
             author_id:       event.profile_id.clone(),
@@ -107,3 +100,28 @@ impl<CB: CommandBus + 'static> PostDeletedWorker<CB> {
         self.command_bus.dispatch(Envelope::new(Uuid::now_v7(), cmd)).await
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_v1_post_deleted_events_are_removals() {
+        let deleted: PostV1Event = serde_json::from_str(
+            r#"{"type":"PostDeleted","post_id":"p1","profile_id":"a1","deleted_at_ms":1750000000000}"#,
+        )
+        .unwrap();
+        assert!(deleted.is_deleted());
+        assert_eq!(deleted.post_id, "p1");
+        assert_eq!(deleted.profile_id, "a1");
+        assert_eq!(deleted.published_at_ms, 0); // not on the wire → best-effort delete
+
+        for json in [
+            r#"{"type":"PostPublished","post_id":"p1","profile_id":"a1","published_at_ms":1}"#,
+            r#"{"type":"PostUpdated","post_id":"p1","profile_id":"a1","updated_at_ms":1}"#,
+        ] {
+            let ev: PostV1Event = serde_json::from_str(json).unwrap();
+            assert!(!ev.is_deleted());
+        }
+    }
+}
@@ -2,7 +2,7 @@ use std::sync::Arc;
 
 use serde::Deserialize;
 use transport::kafka::consumer::builder::KafkaConsumerBuilder;
-use transport::kafka::consumer::{run_consumer, RetryPolicy};
+use transport::kafka::consumer::{run_consumer_keyed, KeyedConcurrency, RetryPolicy};
 use transport::kafka::config::client::KafkaClientConfig;
 use transport::kafka::config::consumer::{AutoOffsetReset, ConsumerConfig};
 use transport::kafka::producer::KafkaProducerHandle;
@@ -26,7 +26,7 @@ const TOPIC: &str = "post.v1.events";
 /// is dormant. If/when post emits `author_tier` (the documented
 /// profile→geo-discovery→post chain), this worker honours it with no change.
 #[derive(Debug, Deserialize)]
-struct PostV1Event {
+pub struct PostV1Event {
     #[serde(rename = "type")]
     pub event_type: String,
     #[serde(default)]
@@ -44,9 +44,13 @@ struct PostV1Event {
 }
 
 impl PostV1Event {
-    fn is_published(&self) -> bool {
+    pub(crate) fn is_published(&self) -> bool {
         self.event_type == "PostPublished"
     }
+
+    pub(crate) fn is_deleted(&self) -> bool {
+        self.event_type == "PostDeleted"
+    }
 }
 
 /// Long-lived Kafka consumer for the unified `post.v1.events` stream. Each
@@ -112,7 +116,8 @@ impl<CB: CommandBus + 'static> PostPublishedWorker<CB> {
         tracing::info!(topic = TOPIC, group = %self.group_id, "consumer started");
 
         let policy = RetryPolicy::default();
-        run_consumer::<PostV1Event, _>(&handle, producer, &policy, move |event| {
+        let concurrency = KeyedConcurrency::from_env();
+        run_consumer_keyed::<PostV1Event, _>(&handle, producer, &policy, &concurrency, move |event| {
             let worker = Arc::clone(&self);
             Box::pin(async move { dispatch_outcome(worker.process(event).await) })
         })
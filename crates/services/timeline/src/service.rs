@@ -13,22 +13,21 @@
 use std::sync::Arc;
 
 use async_trait::async_trait;
-use cqrs::query::InMemoryQueryBus;
 use redis_storage::RedisConfig;
 use scylla_storage::ScyllaConfig;
-use service_runtime::{HealthProbe, InfraRegistry, Service};
+use service_runtime::{AccessPolicy, HealthProbe, InfraRegistry, Service};
 use tonic::service::RoutesBuilder;
 use tonic_reflection::server::Builder as ReflectionBuilder;
 use transport::grpc::client::{GrpcClientBuilder, GrpcClientConfig};
 use transport::kafka::config::KafkaClientConfig;
 
-use crate::app::{App, AppConfig, Backends};
+use crate::app::{App, AppConfig, AppQueryBus, Backends};
 use crate::config::TimelineConfig;
 use crate::infrastructure::client::SocialGraphGrpcClient;
 use crate::infrastructure::grpc::handler::{TimelineServiceHandler, TimelineServiceServer};
 use crate::infrastructure::grpc::server::FILE_DESCRIPTOR_SET;
 
-type TimelineServer = TimelineServiceServer<TimelineServiceHandler<Arc<InMemoryQueryBus>>>;
+type TimelineServer = TimelineServiceServer<TimelineServiceHandler<Arc<AppQueryBus>>>;
 
 /// Logical dependency name for the outbound social-graph channel — the key its
 /// resilience profile is bound to under `[resilience.bindings]` in `infrastructure.toml`
@@ -77,13 +76,15 @@ impl Service for TimelineService {
         // social-graph is not yet reachable (the connection opens on first RPC),
         // and the timeout + circuit-breaker stack — resolved from the
         // `social-graph` binding and shared across the fleet — wraps every call.
+        // Following-list reads are also hedged per that binding's `hedge` policy.
+        let profile = infra.resilience().profile_for(SOCIAL_GRAPH_DEPENDENCY);
         let channel = GrpcClientBuilder::new(
             GrpcClientConfig::new(cfg.social_graph_endpoint.clone())
                 .with_dependency(SOCIAL_GRAPH_DEPENDENCY),
         )
         .build_from_registry_lazy(&infra.resilience())
         .map_err(|e| anyhow::anyhow!("build social-graph client: {e}"))?;
-        let social_graph = Arc::new(SocialGraphGrpcClient::new(channel));
+        let social_graph = Arc::new(SocialGraphGrpcClient::new(channel, profile.hedge_layer()));
 
         let app = App::build(&app_config, backends, social_graph)
             .await
@@ -99,6 +100,12 @@ impl Service for TimelineService {
         ]
     }
 
+    fn access_policy(&self) -> AccessPolicy {
+        AccessPolicy::for_service(Self::GRPC_SERVICE_NAME)
+            .authenticated("GetFollowingFeed")
+            .authenticated("GetAudioFeed")
+    }
+
     fn register(self, routes: &mut RoutesBuilder) -> anyhow::Result<()> {
         let handler = TimelineServiceHandler::new(Arc::clone(&self.app.query_bus));
         let reflection = ReflectionBuilder::configure()
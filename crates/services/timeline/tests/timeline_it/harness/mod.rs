@@ -12,13 +12,11 @@ use std::time::Duration;
 
 use uuid::Uuid;
 
-use cqrs::command::InMemoryCommandBus;
-use cqrs::query::InMemoryQueryBus;
 use cqrs::{CommandBus, Envelope, QueryBus};
 use redis_storage::RedisConfig;
 use scylla_storage::ScyllaConfig;
 
-use timeline::app::{App, AppConfig, Backends};
+use timeline::app::{App, AppCommandBus, AppConfig, AppQueryBus, Backends};
 use timeline::application::command::ingest_post_published::IngestPostPublishedCommand;
 use timeline::application::port::{FeedStore, FollowingStore, TierCache, VipRegistry};
 use timeline::application::query::get_following_feed::{FollowingFeedPage, GetFollowingFeedQuery};
@@ -54,8 +52,8 @@ impl Default for HarnessOptions {
 
 /// A fully-wired timeline service bound to ephemeral infra, plus assertion handles.
 pub struct TestHarness {
-    pub command_bus:     Arc<InMemoryCommandBus>,
-    pub query_bus:       Arc<InMemoryQueryBus>,
+    pub command_bus:     Arc<AppCommandBus>,
+    pub query_bus:       Arc<AppQueryBus>,
     pub feed_store:      Arc<dyn FeedStore>,
     pub vip_registry:    Arc<dyn VipRegistry>,
     pub tier_cache:      Arc<dyn TierCache>,
@@ -142,7 +140,7 @@ impl TestHarness {
 /// Dispatches a following-feed read on a shared bus — a free function so scenarios
 /// can fire many concurrently from spawned tasks.
 pub async fn dispatch_following(
-    query_bus:  Arc<InMemoryQueryBus>,
+    query_bus:  Arc<AppQueryBus>,
     profile_id: String,
 ) -> Result<FollowingFeedPage, cqrs::CqrsError> {
     let query = GetFollowingFeedQuery { profile_id, limit: 50, page_token: None };
@@ -8,7 +8,7 @@
 #   * <name>-media-s3     {access_key, secret_key}                 (rusty-s3 static keys)
 #   * <name>-scylla-s3    {access_key, secret_key}                 (scylla-manager-agent backups)
 #   * <name>-audit-crypto {object/witness S3 keys, kek_base64, signing_key_base64}
-#   * <name>-auth-secrets {ES256 signing PEM pair, keycloak_client_secret}
+#   * <name>-auth-secrets {ES256 signing PEM pair, signing_kek_base64, keycloak_client_secret}
 #
 # STAGING v1 PATH: static IAM keys (rusty-s3 cannot use IRSA web-identity) and the
 # env-KEK / signing key are GENERATED HERE and live in Terraform state. Prod's
@@ -201,6 +201,13 @@ resource "tls_private_key" "auth_signing" {
   ecdsa_curve = "P256"
 }
 
+# Wraps the signing keys auth generates on rotation before they reach the
+# signing_keys table (the env-KEK custodian; KMS custody is the same deferral as
+# audit's).
+resource "random_bytes" "auth_signing_kek" {
+  length = 32
+}
+
 resource "random_password" "keycloak_client_secret" {
   length  = 40
   special = false
@@ -244,6 +251,7 @@ resource "aws_secretsmanager_secret_version" "auth_secrets" {
     # live on the staging bring-up).
     signing_private_pem    = tls_private_key.auth_signing.private_key_pem_pkcs8
     signing_public_pem     = tls_private_key.auth_signing.public_key_pem
+    signing_kek_base64     = random_bytes.auth_signing_kek.base64
     keycloak_client_secret = random_password.keycloak_client_secret.result
   })
 }